@@ -0,0 +1,416 @@
+//! ### English
+//! Criterion baselines for the lock-free queues and the triple-buffer frame pipeline.
+//!
+//! Run with `cargo bench --features bench`. Covers:
+//! - `InputEventQueue`: SPSC vs MPSC, batched and with concurrent producers.
+//! - `VsyncCallbackQueue`: enqueue + tick, on the ring buffer and on the overflow list.
+//! - `SharedFrameState`: acquire/publish without and with a concurrent producer.
+//! - `frame_loop`: a synthetic host/Servo loop (input -> vsync tick -> publish -> acquire/release).
+//!
+//! ### 中文
+//! 无锁队列与三缓冲帧管线的 criterion 基线。
+//!
+//! 使用 `cargo bench --features bench` 运行。覆盖：
+//! - `InputEventQueue`：SPSC 与 MPSC，批量及多生产者并发。
+//! - `VsyncCallbackQueue`：入队 + tick，分别走 ring buffer 与 overflow 链表。
+//! - `SharedFrameState`：无并发 / 有并发生产者时的 acquire/publish。
+//! - `frame_loop`：合成的宿主/Servo 循环（输入 -> vsync tick -> publish -> acquire/release）。
+
+use std::hint::black_box;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use dpi::PhysicalSize;
+use xian_web_engine::bench::{
+    InputEventQueue, SLOT_FREE, SLOT_READY, SLOT_RENDERING, SharedFrameState, TRIPLE_BUFFER_COUNT,
+    VsyncCallbackQueue, XIAN_WEB_ENGINE_COLORSPACE_SRGB, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE,
+    XianWebEngineInputEvent,
+};
+
+/// ### English
+/// Input events pushed per batch (a busy host frame; well below the queue capacity).
+///
+/// ### 中文
+/// 每批 push 的输入事件数（繁忙的宿主帧；远小于队列容量）。
+const INPUT_BATCH: usize = 64;
+
+/// ### English
+/// Producer thread counts used for the contended input queue benchmark.
+///
+/// ### 中文
+/// 输入队列并发基准使用的生产者线程数。
+const INPUT_PRODUCERS: [usize; 3] = [1, 2, 4];
+
+/// ### English
+/// Capacity of the benchmarked vsync queue ring buffer.
+///
+/// ### 中文
+/// 被测 vsync 队列 ring buffer 的容量。
+const VSYNC_CAPACITY: usize = 64;
+
+/// ### English
+/// Frame size used for every `SharedFrameState`.
+///
+/// ### 中文
+/// 所有 `SharedFrameState` 使用的帧尺寸。
+const FRAME_SIZE: PhysicalSize<u32> = PhysicalSize::new(1280, 720);
+
+/// ### English
+/// Builds `count` mouse-move events.
+///
+/// #### Parameters
+/// - `count`: Number of events.
+///
+/// ### 中文
+/// 构造 `count` 个鼠标移动事件。
+///
+/// #### 参数
+/// - `count`：事件数。
+fn mouse_moves(count: usize) -> Vec<XianWebEngineInputEvent> {
+    (0..count)
+        .map(|i| XianWebEngineInputEvent {
+            kind: XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE,
+            x: i as f32,
+            y: i as f32,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// ### English
+/// Reserves a back slot the way the rendering context does: a FREE slot first, otherwise the
+/// older READY slot.
+///
+/// #### Parameters
+/// - `state`: Shared frame state.
+/// - `current_back`: Slot rendered last (never reserved again immediately).
+///
+/// ### 中文
+/// 按渲染上下文的方式预留 back 槽位：优先 FREE 槽位，否则抢占较旧的 READY 槽位。
+///
+/// #### 参数
+/// - `state`：共享帧状态。
+/// - `current_back`：上一次渲染的槽位（不会被立即再次预留）。
+fn reserve_back_slot(state: &SharedFrameState, current_back: usize) -> Option<usize> {
+    let candidates = (1..TRIPLE_BUFFER_COUNT).map(|i| (current_back + i) % TRIPLE_BUFFER_COUNT);
+    for slot in candidates.clone() {
+        if state
+            .compare_exchange_state(slot, SLOT_FREE, SLOT_RENDERING)
+            .is_ok()
+        {
+            return Some(slot);
+        }
+    }
+    let mut ready: Vec<usize> = candidates
+        .filter(|&slot| state.slot_state(slot) == SLOT_READY)
+        .collect();
+    ready.sort_by_key(|&slot| state.slot_seq_relaxed(slot));
+    ready.into_iter().find(|&slot| {
+        state
+            .compare_exchange_state(slot, SLOT_READY, SLOT_RENDERING)
+            .is_ok()
+    })
+}
+
+/// ### English
+/// Renders (reserves + publishes) one frame; returns the published slot.
+///
+/// #### Parameters
+/// - `state`: Shared frame state.
+/// - `current_back`: Slot rendered last.
+/// - `frame_seq`: Sequence number of the new frame.
+///
+/// ### 中文
+/// 渲染（预留 + 发布）一帧；返回已发布的槽位。
+///
+/// #### 参数
+/// - `state`：共享帧状态。
+/// - `current_back`：上一次渲染的槽位。
+/// - `frame_seq`：新帧的序号。
+fn produce_frame(state: &SharedFrameState, current_back: usize, frame_seq: u64) -> Option<usize> {
+    let slot = reserve_back_slot(state, current_back)?;
+    state.publish(slot, 0, frame_seq, XIAN_WEB_ENGINE_COLORSPACE_SRGB);
+    Some(slot)
+}
+
+/// ### English
+/// `InputEventQueue`: one batch pushed and drained on one thread, then a contended run with
+/// concurrent producers (SPSC only allows one).
+///
+/// ### 中文
+/// `InputEventQueue`：在同一线程 push 并 drain 一批事件；以及多生产者并发的场景（SPSC 仅允许一个生产者）。
+fn input_queue(c: &mut Criterion) {
+    let events = mouse_moves(INPUT_BATCH);
+    let mut group = c.benchmark_group("input_queue/batch");
+    group.throughput(Throughput::Elements(INPUT_BATCH as u64));
+    for (name, single_producer) in [("spsc", true), ("mpsc", false)] {
+        let queue = InputEventQueue::new(single_producer);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let pushed = queue.try_push_slice(black_box(&events));
+                for _ in 0..pushed {
+                    black_box(queue.pop());
+                }
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("input_queue/contended");
+    group.throughput(Throughput::Elements(INPUT_BATCH as u64));
+    for (name, single_producer) in [("spsc", true), ("mpsc", false)] {
+        for producers in INPUT_PRODUCERS {
+            if single_producer && producers > 1 {
+                continue;
+            }
+            group.bench_with_input(BenchmarkId::new(name, producers), &producers, |b, &n| {
+                b.iter_custom(|iters| {
+                    contended_input(single_producer, n, iters * INPUT_BATCH as u64)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+/// ### English
+/// Pushes `total` events from `producers` threads and drains them on the calling thread; returns
+/// the time until the last event was popped.
+///
+/// #### Parameters
+/// - `single_producer`: Queue mode.
+/// - `producers`: Number of producer threads.
+/// - `total`: Total events to transfer.
+///
+/// ### 中文
+/// 由 `producers` 个线程 push 共 `total` 个事件，并在调用线程 drain；返回直到最后一个事件被 pop 的耗时。
+///
+/// #### 参数
+/// - `single_producer`：队列模式。
+/// - `producers`：生产者线程数。
+/// - `total`：传输的事件总数。
+fn contended_input(single_producer: bool, producers: usize, total: u64) -> Duration {
+    let queue = Arc::new(InputEventQueue::new(single_producer));
+    let events = Arc::new(mouse_moves(INPUT_BATCH));
+    let per_producer = total.div_ceil(producers as u64);
+    let start = Instant::now();
+    let handles: Vec<_> = (0..producers)
+        .map(|_| {
+            let queue = queue.clone();
+            let events = events.clone();
+            thread::spawn(move || {
+                let mut remaining = per_producer as usize;
+                while remaining > 0 {
+                    let batch = remaining.min(events.len());
+                    let pushed = queue.try_push_slice(&events[..batch]);
+                    if pushed == 0 {
+                        thread::yield_now();
+                    }
+                    remaining -= pushed;
+                }
+            })
+        })
+        .collect();
+
+    let mut popped = 0;
+    while popped < per_producer * producers as u64 {
+        match queue.pop() {
+            Some(event) => {
+                black_box(event);
+                popped += 1;
+            }
+            None => std::hint::spin_loop(),
+        }
+    }
+    let elapsed = start.elapsed();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    elapsed
+}
+
+/// ### English
+/// `VsyncCallbackQueue`: enqueue + tick of a batch that fits the ring buffer, and of one that spills
+/// into the overflow list.
+///
+/// ### 中文
+/// `VsyncCallbackQueue`：放得进 ring buffer 的一批回调，以及溢出到 overflow 链表的一批回调的入队 + tick。
+fn vsync_queue(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vsync_queue");
+    for (name, batch) in [
+        ("ring", VSYNC_CAPACITY / 2),
+        ("overflow", VSYNC_CAPACITY * 4),
+    ] {
+        let queue = VsyncCallbackQueue::with_capacity(VSYNC_CAPACITY);
+        let counter = Arc::new(AtomicU64::new(0));
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for _ in 0..batch {
+                    let counter = counter.clone();
+                    queue.push(Box::new(move || {
+                        counter.fetch_add(1, Ordering::Relaxed);
+                    }));
+                }
+                queue.tick();
+            })
+        });
+    }
+
+    let queue = VsyncCallbackQueue::with_capacity(VSYNC_CAPACITY);
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("empty_tick", |b| b.iter(|| queue.tick()));
+    group.finish();
+}
+
+/// ### English
+/// `SharedFrameState`: publish + acquire + release on one thread, and consumer acquire/release while
+/// another thread publishes as fast as it can.
+///
+/// ### 中文
+/// `SharedFrameState`：在同一线程 publish + acquire + release；以及另一线程全速 publish 时消费者的
+/// acquire/release。
+fn frame_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame_state");
+    group.throughput(Throughput::Elements(1));
+
+    let state = SharedFrameState::new(FRAME_SIZE);
+    let mut back = 0;
+    let mut frame_seq = 0;
+    group.bench_function("publish_acquire_release", |b| {
+        b.iter(|| {
+            frame_seq += 1;
+            if let Some(slot) = produce_frame(&state, back, frame_seq) {
+                back = slot;
+            }
+            if let Some(frame) = state.try_acquire_front() {
+                state.release_slot(black_box(frame).slot, 0);
+            }
+        })
+    });
+
+    group.bench_function("acquire_contended", |b| {
+        b.iter_custom(|iters| {
+            let state = Arc::new(SharedFrameState::new(FRAME_SIZE));
+            let stop = Arc::new(AtomicBool::new(false));
+            let producer = {
+                let state = state.clone();
+                let stop = stop.clone();
+                thread::spawn(move || {
+                    let mut back = 0;
+                    let mut frame_seq = 0;
+                    while !stop.load(Ordering::Relaxed) {
+                        frame_seq += 1;
+                        match produce_frame(&state, back, frame_seq) {
+                            Some(slot) => back = slot,
+                            None => std::hint::spin_loop(),
+                        }
+                    }
+                })
+            };
+
+            let start = Instant::now();
+            let mut acquired = 0;
+            while acquired < iters {
+                if let Some(frame) = state.try_acquire_front() {
+                    state.release_slot(black_box(frame).slot, 0);
+                    acquired += 1;
+                }
+            }
+            let elapsed = start.elapsed();
+            stop.store(true, Ordering::Relaxed);
+            let _ = producer.join();
+            elapsed
+        })
+    });
+    group.finish();
+}
+
+/// ### English
+/// End-to-end synthetic frame loop. The host thread pushes a batch of input, ticks vsync, and waits
+/// for and releases the resulting frame; the "Servo" thread drains input on each vsync callback and
+/// publishes a frame. Measures host-observed time per frame.
+///
+/// ### 中文
+/// 端到端合成帧循环。宿主线程 push 一批输入、tick vsync，然后等待并释放由此产生的帧；“Servo” 线程在每次
+/// vsync 回调时 drain 输入并发布一帧。度量宿主侧观察到的每帧耗时。
+fn frame_loop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame_loop");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("input_vsync_publish_acquire", |b| {
+        b.iter_custom(|iters| {
+            let input = Arc::new(InputEventQueue::new(true));
+            let vsync = Arc::new(VsyncCallbackQueue::with_capacity(VSYNC_CAPACITY));
+            let state = Arc::new(SharedFrameState::new(FRAME_SIZE));
+            let vsyncs = Arc::new(AtomicU64::new(0));
+            let stop = Arc::new(AtomicBool::new(false));
+            let events = mouse_moves(INPUT_BATCH);
+
+            let servo = {
+                let input = input.clone();
+                let vsync = vsync.clone();
+                let state = state.clone();
+                let vsyncs = vsyncs.clone();
+                let stop = stop.clone();
+                thread::spawn(move || {
+                    let mut back = 0;
+                    let mut frame_seq = 0;
+                    let mut seen = 0;
+                    let request_vsync = |vsync: &VsyncCallbackQueue| {
+                        let vsyncs = vsyncs.clone();
+                        vsync.push(Box::new(move || {
+                            vsyncs.fetch_add(1, Ordering::Release);
+                        }));
+                    };
+                    request_vsync(&vsync);
+                    while !stop.load(Ordering::Relaxed) {
+                        let current = vsyncs.load(Ordering::Acquire);
+                        if current == seen {
+                            std::hint::spin_loop();
+                            continue;
+                        }
+                        seen = current;
+                        while let Some(event) = input.pop() {
+                            black_box(event);
+                        }
+                        frame_seq += 1;
+                        if let Some(slot) = produce_frame(&state, back, frame_seq) {
+                            back = slot;
+                        }
+                        request_vsync(&vsync);
+                    }
+                })
+            };
+
+            let start = Instant::now();
+            let mut last_seq = 0;
+            for _ in 0..iters {
+                input.try_push_slice(&events);
+                vsync.tick();
+                loop {
+                    if let Some(frame) = state.try_acquire_front() {
+                        let seq = state.slot_seq_relaxed(frame.slot);
+                        state.release_slot(frame.slot, 0);
+                        if seq > last_seq {
+                            last_seq = seq;
+                            break;
+                        }
+                    }
+                    std::hint::spin_loop();
+                    vsync.tick();
+                }
+            }
+            let elapsed = start.elapsed();
+            stop.store(true, Ordering::Relaxed);
+            let _ = servo.join();
+            elapsed
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, input_queue, vsync_queue, frame_state, frame_loop);
+criterion_main!(benches);
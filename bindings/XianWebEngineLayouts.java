@@ -0,0 +1,3072 @@
+/*
+ * Generated by `cargo xtask bindings` from the Rust FFI surface. Do not edit.
+ */
+
+import java.lang.foreign.FunctionDescriptor;
+import java.lang.foreign.MemoryLayout;
+import java.lang.foreign.StructLayout;
+import java.lang.foreign.ValueLayout;
+
+/**
+ * Panama layouts, function descriptors and constants of the Xian Web Engine C ABI.
+ */
+public final class XianWebEngineLayouts {
+    private XianWebEngineLayouts() {}
+
+    /**
+     * Frames carry producer fences ({@code XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE} opts out).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_PRODUCER_FENCE = 1L;
+
+    /**
+     * Released frames accept consumer fences.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_CONSUMER_FENCE = 2L;
+
+    /**
+     * {@code XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8} slot textures and colorspace overrides (still subject to
+     * driver support at view creation).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SRGB = 4L;
+
+    /**
+     * {@code XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F} slot textures.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_HALF_FLOAT = 8L;
+
+    /**
+     * Transparent views ({@code xian_web_engine_view_set_background} honors alpha).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_TRANSPARENT_VIEWS = 16L;
+
+    /**
+     * {@code XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X} and {@code XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS}.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SUPERSAMPLE_MIPMAPS = 32L;
+
+    /**
+     * Views can be sharded across several Servo threads ({@code servo_threads}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SERVO_SHARDS = 64L;
+
+    /**
+     * Slow-script detection ({@code xian_web_engine_view_set_slow_script_policy}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SLOW_SCRIPT = 128L;
+
+    /**
+     * The library can create its shared GL context on this platform (Windows, Linux and macOS for now).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SHARED_CONTEXT = 256L;
+
+    /**
+     * Thread priority / CPU affinity controls take effect (Windows only for now).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_THREAD_SCHEDULING = 512L;
+
+    /**
+     * Log callback ({@code xian_web_engine_set_log_callback}) with FFI argument validation reports.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_LOG_CALLBACK = 1024L;
+
+    /**
+     * View string getters ({@code xian_web_engine_view_get_url}/{@code _get_title}/{@code _get_last_error}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VIEW_STRINGS = 2048L;
+
+    /**
+     * View compositor ({@code xian_web_engine_composite_views}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_COMPOSITOR = 4096L;
+
+    /**
+     * Frame-available callback ({@code xian_web_engine_set_frame_callback}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_FRAME_CALLBACK = 8192L;
+
+    /**
+     * Vulkan external-memory frames ({@code XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT}); the GL driver must
+     * additionally expose {@code GL_EXT_memory_object} / {@code GL_EXT_semaphore}.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VULKAN_EXPORT = 16384L;
+
+    /**
+     * EGL shared contexts for GLES-only hosts ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_EGL} with
+     * {@code xian_web_engine_create_with_flags}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_EGL = 32768L;
+
+    /**
+     * Main-thread trampoline for GLFW window calls ({@code xian_web_engine_set_main_thread_trampoline}),
+     * required for the shared context on macOS.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_MAIN_THREAD_TRAMPOLINE = 65536L;
+
+    /**
+     * GL debug output forwarded to the log callback ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG}); the
+     * driver must additionally expose {@code GL_KHR_debug} (or GL 4.3 / GLES 3.2).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_GL_DEBUG = 131072L;
+
+    /**
+     * Paranoid GL mode ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL}) with GL errors reported in
+     * {@code XianWebEngineHealth}.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_PARANOID_GL = 262144L;
+
+    /**
+     * Device sensor bridge ({@code xian_web_engine_view_update_sensors} → {@code deviceorientation} /
+     * {@code devicemotion}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SENSORS = 524288L;
+
+    /**
+     * Page scroll snapshot and restore ({@code xian_web_engine_view_get_scroll} /
+     * {@code xian_web_engine_view_set_scroll}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SCROLL = 1048576L;
+
+    /**
+     * Text selection and copy ({@code xian_web_engine_view_select_all},
+     * {@code xian_web_engine_view_request_selected_text}, {@code xian_web_engine_view_copy_selection}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SELECTION = 2097152L;
+
+    /**
+     * Page console capture ({@code xian_web_engine_view_set_console_capture} → {@code CONSOLE_MESSAGE} events).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_CONSOLE_CAPTURE = 4194304L;
+
+    /**
+     * {@code PAGE_ERROR} / {@code RESOURCE_ERROR} events for uncaught exceptions and failed subresource loads.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_PAGE_ERRORS = 8388608L;
+
+    /**
+     * Per-view network activity counter ({@code xian_web_engine_view_is_loading} /
+     * {@code xian_web_engine_view_get_in_flight_requests}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_NETWORK_ACTIVITY = 16777216L;
+
+    /**
+     * Connection and bandwidth limits for HTTP(S) traffic ({@code xian_web_engine_set_network_limits}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_NETWORK_LIMITS = 33554432L;
+
+    /**
+     * Offline and cache-only network modes ({@code xian_web_engine_set_network_mode}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_NETWORK_MODE = 67108864L;
+
+    /**
+     * Embedder DNS resolver callback ({@code xian_web_engine_set_dns_resolver}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_DNS_RESOLVER = 134217728L;
+
+    /**
+     * Host functions callable from pages ({@code xian_web_engine_register_host_function}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_HOST_FUNCTIONS = 268435456L;
+
+    /**
+     * Binary messages between host and page ({@code xian_web_engine_view_post_binary}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_BINARY_CHANNEL = 536870912L;
+
+    /**
+     * Embedder GL textures readable by pages ({@code xian_web_engine_register_texture}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_TEXTURE_SHARING = 1073741824L;
+
+    /**
+     * Host texture frame streams into pages ({@code xian_web_engine_push_external_frame}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_EXTERNAL_FRAMES = 2147483648L;
+
+    /**
+     * Moving a view to another size/render configuration without reloading ({@code xian_web_engine_move_view}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VIEW_MOVE = 4294967296L;
+
+    /**
+     * Freezing views to reclaim GPU memory ({@code xian_web_engine_freeze_view} / {@code xian_web_engine_thaw_view}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VIEW_FREEZE = 8589934592L;
+
+    /**
+     * Chrome trace export of engine spans ({@code xian_web_engine_dump_trace}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_TRACE = 17179869184L;
+
+    /**
+     * Servo time profiler toggles ({@code xian_web_engine_set_profiling}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_PROFILING = 34359738368L;
+
+    /**
+     * Recording and timed replay of view input, resizes and navigations
+     * ({@code xian_web_engine_view_start_recording}, {@code xian_web_engine_view_replay}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_REPLAY = 68719476736L;
+
+    /**
+     * Golden-image rendering tests ({@code xian_web_engine_run_golden_tests}; builds with the {@code golden-tests}
+     * feature only).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS = 137438953472L;
+
+    /**
+     * Monotonic queue drop counters ({@code xian_web_engine_view_get_queue_stats}, and {@code commands_dropped} /
+     * {@code pending_overflows} in {@code XianWebEngineHealth}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_QUEUE_STATS = 274877906944L;
+
+    /**
+     * View liveness and explicit close ({@code xian_web_engine_view_is_alive}, {@code xian_web_engine_close_view}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VIEW_CLOSE = 549755813888L;
+
+    /**
+     * Generational view handle table ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE = 1099511627776L;
+
+    /**
+     * Input synthesis for automated UI testing ({@code xian_web_engine_click_at}, {@code xian_web_engine_type_text}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_INPUT_SYNTHESIS = 2199023255552L;
+
+    /**
+     * Per-view refresh driver pause and frame stepping for debugging
+     * ({@code xian_web_engine_debug_pause_view}, {@code xian_web_engine_debug_step_frame}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_DEBUG_PAUSE = 4398046511104L;
+
+    /**
+     * Non-blocking view creation with a completion callback ({@code xian_web_engine_create_view_async}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_ASYNC_CREATE = 8796093022208L;
+
+    /**
+     * Engine initialization progress callback and cache preloading
+     * ({@code xian_web_engine_set_init_progress_callback}, {@code xian_web_engine_preload}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_INIT_PROGRESS = 17592186044416L;
+
+    /**
+     * Configurable timeouts of blocking calls ({@code xian_web_engine_create_with_config},
+     * {@code XIAN_WEB_ENGINE_STATUS_TIMED_OUT}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_ENGINE_CONFIG = 35184372088832L;
+
+    /**
+     * Storage isolation groups chosen at view creation ({@code xian_web_engine_view_create_in_storage_group}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_STORAGE_GROUPS = 70368744177664L;
+
+    /**
+     * Per-view homography applied to pointer input ({@code xian_web_engine_set_view_input_transform}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_INPUT_TRANSFORM = 140737488355328L;
+
+    /**
+     * Resize debouncing with an immediate override ({@code xian_web_engine_view_set_resize_debounce},
+     * {@code xian_web_engine_view_resize_immediate}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_RESIZE_DEBOUNCE = 281474976710656L;
+
+    /**
+     * Producer fence statistics ({@code xian_web_engine_get_fence_stats}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_FENCE_STATS = 562949953421312L;
+
+    /**
+     * Orphaning resize ({@code XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_ORPHANING_RESIZE = 1125899906842624L;
+
+    /**
+     * Frame present timestamps ({@code XianWebEngineFrame.present_time_ns},
+     * {@code xian_web_engine_monotonic_time_ns}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_FRAME_TIMESTAMPS = 2251799813685248L;
+
+    /**
+     * Host display timing for vsync-driven views ({@code xian_web_engine_set_vsync_info}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_VSYNC_INFO = 4503599627370496L;
+
+    /**
+     * Drift-free fixed-interval refresh with fractional rates
+     * ({@code xian_web_engine_view_group_create_fractional}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_FRACTIONAL_FPS = 9007199254740992L;
+
+    /**
+     * Engine idle mode ({@code xian_web_engine_set_idle}, {@code XIAN_WEB_ENGINE_IDLE_FLAG_*}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_ENGINE_IDLE = 18014398509481984L;
+
+    /**
+     * Memory pressure signal ({@code xian_web_engine_notify_memory_pressure}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_MEMORY_PRESSURE = 36028797018963968L;
+
+    /**
+     * Image size limits with downscaling ({@code xian_web_engine_set_image_limits},
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_IMAGE_LIMITS = 72057594037927936L;
+
+    /**
+     * Process-wide page JavaScript heap limit ({@code xian_web_engine_set_heap_limit},
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_HEAP_LIMIT = 144115188075855872L;
+
+    /**
+     * Out-of-process page content ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS},
+     * {@code xian_web_engine_run_content_process}); not available on Windows.
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS = 288230376151711744L;
+
+    /**
+     * WebDriver endpoint for automated tests ({@code xian_web_engine_enable_webdriver}; builds with the
+     * {@code webdriver} feature only).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER = 576460752303423488L;
+
+    /**
+     * Frame streaming over HTTP for remote debugging ({@code xian_web_engine_view_start_screencast}; builds
+     * with the {@code screencast} feature only).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST = 1152921504606846976L;
+
+    /**
+     * Per-view media feature overrides ({@code xian_web_engine_view_set_color_scheme},
+     * {@code xian_web_engine_view_set_reduced_motion}, {@code xian_web_engine_view_set_forced_colors}).
+     */
+    public static final long XIAN_WEB_ENGINE_CAPABILITY_MEDIA_FEATURES = 2305843009213693952L;
+
+    /**
+     * Events were dropped because the per-view queue was full; {@code value0} = number of dropped events since
+     * the previous poll ({@code XianWebEngineViewQueueStats::events_dropped} keeps the running total).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_DROPPED = 1;
+
+    /**
+     * A page export finished; {@code value0}/{@code value1} = exported width/height, text = output path.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE = 2;
+
+    /**
+     * An accessibility tree snapshot is ready; text = JSON document
+     * ({@code {"url", "devicePixelRatio", "root": {"role", "name", "bounds": [x, y, w, h], "children", ...}}}).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_ACCESSIBILITY_TREE = 3;
+
+    /**
+     * A pause/resume-all-media request finished; {@code value0} = media element count, {@code value1} = elements still
+     * playing.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_STATE = 4;
+
+    /**
+     * The page's media session playback state changed; {@code value0} = {@code 1} playing, {@code 0} paused, {@code -1} none.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_PLAYBACK = 5;
+
+    /**
+     * A session save finished; text = opaque session blob for {@code xian_web_engine_view_create_from_session}.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_SESSION_SAVED = 6;
+
+    /**
+     * The page requested a permission; {@code request_id} = ID to pass to
+     * {@code xian_web_engine_view_respond_permission}, {@code value0} = feature ({@code XIAN_WEB_ENGINE_PERMISSION_*}),
+     * {@code value1} = milliseconds until the request is denied automatically.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST = 7;
+
+    /**
+     * The page entered ({@code value0 = 1}) or left ({@code value0 = 0}) pointer lock; while locked the embedder
+     * should capture the cursor and send {@code XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE} events.
+     *
+     * Entering requires transient user activation and a granted {@code PERMISSION_REQUEST} with feature
+     * {@code XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK}.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK = 8;
+
+    /**
+     * A host drag ended with a drop; text = drop effect chosen by the page ({@code "none"} if the page did not
+     * accept the data).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE = 9;
+
+    /**
+     * The page opened a file chooser ({@code <input type=file>}); {@code request_id} = ID to pass to
+     * {@code xian_web_engine_view_provide_files}, {@code value0} = {@code 1} if several files may be selected,
+     * text = comma-separated accept filters (empty if any file is accepted).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER = 10;
+
+    /**
+     * A script blocked the page longer than the slow-script timeout; {@code value0} = milliseconds the page
+     * has been unresponsive, {@code value1} = {@code 1} if the page was unloaded and the view answers again
+     * ({@code SLOW_SCRIPT_POLICY_STOP}), {@code 0} if the script is still running.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT = 11;
+
+    /**
+     * A selected-text request finished; text = selected text (empty if nothing is selected).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_SELECTED_TEXT = 12;
+
+    /**
+     * A copy request finished; text = copied text, which the embedder should place on its clipboard
+     * (the engine does not own the system clipboard).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_COPY = 13;
+
+    /**
+     * The page logged to the console (only while {@code xian_web_engine_view_set_console_capture} is enabled);
+     * {@code value0} = level ({@code XIAN_WEB_ENGINE_LOG_LEVEL_*}), {@code value1} = source line ({@code 0} if unknown),
+     * text = source URL, a newline, then the message.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE = 14;
+
+    /**
+     * The page threw an uncaught exception or left a promise rejection unhandled; {@code value0} = source
+     * line, {@code value1} = column ({@code 0} if unknown), text = source URL, a newline, then the message (with
+     * stack when available).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_PAGE_ERROR = 15;
+
+    /**
+     * A subresource (element, {@code fetch} or XHR) failed to load; {@code value0} = HTTP status ({@code 0} for network
+     * errors or when unknown), text = resource URL, a newline, then what loaded it ({@code img}, {@code script},
+     * {@code fetch}, {@code xhr}, ...) with the error if any.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_RESOURCE_ERROR = 16;
+
+    /**
+     * The page sent a binary message with {@code xianHost.sendBinary}; {@code request_id} = message ID,
+     * {@code value0} = channel, {@code value1} = length in bytes. Read it with
+     * {@code xian_web_engine_view_get_binary_message} and release it with
+     * {@code xian_web_engine_view_release_binary_message}.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_BINARY_MESSAGE = 17;
+
+    /**
+     * The engine is done with a frame pushed by {@code xian_web_engine_push_external_frame} and the texture
+     * may be rendered into again; {@code value0} = stream ID, {@code value1} = texture ID. {@code status} is {@code OK} if the
+     * page received the frame and {@code FAILED} if it was dropped (stream not open or texture unreadable).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_EXTERNAL_FRAME_RELEASED = 18;
+
+    /**
+     * A replay started by {@code xian_web_engine_view_replay} ended; {@code value0} = number of actions replayed.
+     * {@code status} is {@code OK} if every recorded action was replayed and {@code FAILED} if a newer replay replaced it.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED = 19;
+
+    /**
+     * An image larger than the limits set by {@code xian_web_engine_set_image_limits} was replaced with a
+     * downscaled copy; {@code value0} = original width, {@code value1} = original height (pixels),
+     * text = image URL.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED = 20;
+
+    /**
+     * The page exceeded the JavaScript heap limit ({@code xian_web_engine_set_heap_limit}); {@code value0} = limit
+     * in MiB, {@code value1} = {@code 1} if the page's script thread crashed, {@code 0} if the engine unloaded the page
+     * ({@code about:blank}).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT = 21;
+
+    /**
+     * The view's page crashed (with {@code XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS}, its content process
+     * died); text = crash reason, then a newline and the backtrace when available. Navigate the view
+     * to load a page again.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED = 22;
+
+    /**
+     * The page handled an input event sent by {@code xian_web_engine_view_send_tracked_input_event};
+     * {@code request_id} = the event's correlation ID, {@code value0} = {@code 1} if the page consumed the event (a
+     * widget was hit or a handler took it) and {@code 0} if it fell through, {@code value1} = mask of
+     * {@code XIAN_WEB_ENGINE_INPUT_RESULT_*} flags.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT = 23;
+
+    /**
+     * Input result flag: a page handler called {@code preventDefault()} on the event.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_RESULT_DEFAULT_PREVENTED = 1;
+
+    /**
+     * Input result flag: the page consumed the event (for example a wheel event scrolled it).
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_RESULT_CONSUMED = 2;
+
+    /**
+     * Input result flag: a pointer event landed on a page element other than the root ({@code <html>} or
+     * {@code <body>}), so it hit content rather than empty space.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT = 4;
+
+    /**
+     * An editable element gained or lost focus; {@code value0} = {@code XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*} of the
+     * focused element ({@code NONE} when focus left the editable element), {@code value1} = caret position in
+     * characters ({@code -1} if unknown). Read the element's rectangle with
+     * {@code xian_web_engine_view_get_text_input} to place an on-screen keyboard or IME composition window.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT = 24;
+
+    /**
+     * Text input type: no editable element has focus.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE = 0;
+
+    /**
+     * Text input type: single-line text field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEXT = 1;
+
+    /**
+     * Text input type: multi-line text ({@code <textarea>} or {@code contenteditable}).
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_MULTILINE = 2;
+
+    /**
+     * Text input type: password field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_PASSWORD = 3;
+
+    /**
+     * Text input type: number field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NUMBER = 4;
+
+    /**
+     * Text input type: email address field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_EMAIL = 5;
+
+    /**
+     * Text input type: telephone number field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEL = 6;
+
+    /**
+     * Text input type: URL field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_URL = 7;
+
+    /**
+     * Text input type: search field.
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_SEARCH = 8;
+
+    /**
+     * Text input type: other field (date, time, color, ...).
+     */
+    public static final int XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_OTHER = 9;
+
+    /**
+     * The text payload did not fit into the caller's buffer and was truncated.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_TEXT_TRUNCATED = 1;
+
+    /**
+     * Success.
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_OK = 0;
+
+    /**
+     * Generic failure (details in the text payload when available).
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_FAILED = -1;
+
+    /**
+     * An I/O error occurred (details in the text payload).
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_IO_ERROR = -3;
+
+    /**
+     * The request was rejected because its command lane is full; retry later.
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_QUEUE_FULL = -4;
+
+    /**
+     * The request was rejected because the engine is shutting down.
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN = -5;
+
+    /**
+     * The engine did not answer within the configured timeout (see {@code XianWebEngineConfig}).
+     */
+    public static final int XIAN_WEB_ENGINE_STATUS_TIMED_OUT = -6;
+
+    /**
+     * Permission feature: Geolocation.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_GEOLOCATION = 1L;
+
+    /**
+     * Permission feature: Desktop notifications.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_NOTIFICATIONS = 2L;
+
+    /**
+     * Permission feature: Push messages.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_PUSH = 3L;
+
+    /**
+     * Permission feature: Web MIDI.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_MIDI = 4L;
+
+    /**
+     * Permission feature: Camera capture.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_CAMERA = 5L;
+
+    /**
+     * Permission feature: Microphone capture.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_MICROPHONE = 6L;
+
+    /**
+     * Permission feature: Audio output device selection.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_SPEAKER = 7L;
+
+    /**
+     * Permission feature: Media device enumeration.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_DEVICE_INFO = 8L;
+
+    /**
+     * Permission feature: Background sync.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_BACKGROUND_SYNC = 9L;
+
+    /**
+     * Permission feature: Web Bluetooth.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_BLUETOOTH = 10L;
+
+    /**
+     * Permission feature: Persistent storage.
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_PERSISTENT_STORAGE = 11L;
+
+    /**
+     * Permission feature: Pointer lock (requested by the engine's pointer lock emulation).
+     */
+    public static final long XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK = 12L;
+
+    /**
+     * Unsafe mode: skip Java-side consumer fences (fastest but may overwrite textures still in use).
+     *
+     * In this mode, pass {@code consumer_fences = NULL} (or 0 fences) to {@code xian_web_engine_views_release_frames}
+     * (the consumer fence is ignored).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE = 1;
+
+    /**
+     * Hint: the embedder guarantees a single input-producer thread (enables a faster push path).
+     * If this guarantee is violated (multiple producer threads), behavior is undefined.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER = 2;
+
+    /**
+     * Unsafe mode: skip producer-side fences ({@code GLsync}) for new frames (lower overhead).
+     *
+     * In this mode, {@code XianWebEngineFrame.producer_fence} will always be {@code 0}, and the embedder must ensure it
+     * does not sample incomplete frames (e.g., by using other synchronization).
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE = 4;
+
+    /**
+     * Quality: render at 2x the view size (supersampling) so small HUD text stays crisp once the
+     * consumer downsamples the frame.
+     *
+     * Equivalent to creating the view with a render scale of {@code 2.0} (see
+     * {@code xian_web_engine_view_set_render_scale}); acquired frames are twice the view size.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X = 8;
+
+    /**
+     * Quality: allocate mipmapped slot textures (trilinear {@code LINEAR_MIPMAP_LINEAR} minification) and
+     * regenerate the mip chain on every present.
+     *
+     * Removes shimmering when the host draws the view scaled down or at an angle in 3D space, at the
+     * cost of one {@code glGenerateMipmap} per frame on the Servo thread.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS = 16;
+
+    /**
+     * Interop: back the slot textures with Vulkan images exported through external memory, so a Vulkan
+     * renderer can consume the frames (see {@code xian_web_engine_view_get_vulkan_slot}).
+     *
+     * Requires {@code xian_web_engine_set_vulkan_device} to have been called, and cannot be combined with
+     * {@code XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE}.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT = 32;
+
+    /**
+     * Performance: resize by orphaning instead of reallocating every slot under the resizing flag.
+     *
+     * The back slot and every FREE slot get freshly allocated textures swapped in (the old ones are
+     * parked in the slot's texture pool, never re-specified while in flight); READY and held slots are
+     * left alone and switched over the next time the producer takes them. The consumer keeps acquiring
+     * throughout (frames report their own size, so a few old-size frames may follow the resize) and the
+     * producer never waits on consumer fences. Ignored with {@code XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT},
+     * whose slot images cannot be swapped.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE = 64;
+
+    /**
+     * Bit offset of the slot texture color format ({@code XIAN_WEB_ENGINE_COLOR_FORMAT_*}) inside the view
+     * flags: {@code flags | (format << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT)}.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT = 8;
+
+    /**
+     * Mask of the slot texture color format bits inside the view flags.
+     */
+    public static final int XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK = 3840;
+
+    /**
+     * Engine flag: create the Servo-side contexts through EGL instead of GLFW (ANGLE on Windows,
+     * GLES-only drivers on ARM).
+     *
+     * With this flag, the handle passed to {@code xian_web_engine_create_with_flags} is the host's
+     * {@code EGLContext} (not a {@code GLFWwindow*}) and {@code xian_web_engine_set_egl_api} must have been called.
+     * Only OpenGL ES host contexts are supported.
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_EGL = 1;
+
+    /**
+     * Engine flag: create the Servo-side contexts as debug contexts and forward their
+     * {@code GL_KHR_debug} messages to the log callback.
+     *
+     * Capture is also enabled without this flag when the host context is already a debug context
+     * (the offscreen context mirrors it). Severities map to log levels (high → ERROR, medium → WARN,
+     * low → INFO, notification → DEBUG), so the log callback's {@code max_level} filters them.
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG = 2;
+
+    /**
+     * Engine flag: "paranoid GL" mode. Every rendering-context operation is followed by
+     * {@code glGetError}; the first error of each frame is logged and recorded in
+     * {@code XianWebEngineHealth} ({@code gl_error_frames}, {@code last_gl_error}, {@code last_gl_error_op}).
+     *
+     * Meant for diagnosing black or corrupt textures on unusual drivers; {@code glGetError} stalls the GL
+     * pipeline on many drivers, so leave it off in normal builds.
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL = 4;
+
+    /**
+     * Engine flag: hand out this engine's views as generational handle table IDs instead of raw
+     * pointers ({@code XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE}).
+     *
+     * The IDs travel through the same {@code XianWebEngineView*} parameters, so bindings need no other
+     * changes, but they must be treated as opaque values and never dereferenced. Every call validates
+     * the ID, so a stale one (use after {@code xian_web_engine_view_destroy}, double destroy) is rejected and
+     * logged, and the call returns its failure value instead of invoking undefined behavior. Meant for
+     * GC-based hosts whose finalizers may race with explicit destruction; the lookup costs one
+     * uncontended read lock per call. The table is process-global and shared by every engine created
+     * with this flag.
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE = 8;
+
+    /**
+     * Engine flag: run page content in separate processes (Servo's multiprocess mode; not supported on
+     * Windows, {@code XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS}).
+     *
+     * A crashing content process then takes down only its pages: each affected view gets a
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED} event while the Servo thread and other views keep running.
+     * Servo applies the mode to every view of the engine (it cannot be chosen per view), so put
+     * untrusted views in a separate engine created with this flag. Servo has no setting for the
+     * content process executable: it re-runs the host executable with {@code --content-process <token>}, and
+     * the host's entry point must pass that token to {@code xian_web_engine_run_content_process}. Engine
+     * creation therefore fails on Windows and when the host executable is a {@code java}/{@code javaw} launcher,
+     * which cannot do this; use a native launcher that embeds the JVM.
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS = 16;
+
+    /**
+     * Engine flag: always route the engine's HTTP(S) traffic through its loopback proxy.
+     *
+     * Without this flag the proxy only runs if a DNS resolver callback or network limit was installed
+     * before the engine was created. The proxy cannot be added later, so create the engine with this
+     * flag if it may later need {@code xian_web_engine_set_dns_resolver},
+     * {@code xian_web_engine_set_network_limits} or connection-level enforcement of
+     * {@code xian_web_engine_set_network_mode} (without a proxy, {@code CACHE_ONLY} behaves like {@code OFFLINE}).
+     */
+    public static final int XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY = 32;
+
+    /**
+     * Sandbox restriction: block script execution in the view's document.
+     *
+     * Sandbox flags are checked with {@code xian_web_engine_view_set_sandbox}; {@code 0} means "not sandboxed".
+     * Servo only sandboxes nested browsing contexts, so this restriction cannot be enforced on a
+     * view's top-level document and is rejected.
+     */
+    public static final int XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS = 1;
+
+    /**
+     * Sandbox restriction: block form submission (cannot be enforced on a top-level document;
+     * rejected).
+     */
+    public static final int XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS = 2;
+
+    /**
+     * Sandbox restriction: block nested browsing contexts ({@code iframe}/{@code frame}/{@code object}/{@code embed};
+     * cannot be enforced on a top-level document; rejected).
+     */
+    public static final int XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES = 4;
+
+    /**
+     * Sandbox restriction: block popups and auxiliary windows (always enforced: the engine never opens
+     * them).
+     */
+    public static final int XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS = 8;
+
+    /**
+     * Sandbox restriction: treat the document as a unique opaque origin (no cookies/storage access;
+     * cannot be enforced on a top-level document; rejected).
+     */
+    public static final int XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN = 16;
+
+    /**
+     * Export format: PNG image of the whole scrollable document.
+     */
+    public static final int XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG = 0;
+
+    /**
+     * Export format: PDF paginating an image of the whole scrollable document into A4-proportioned
+     * pages.
+     */
+    public static final int XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF = 1;
+
+    /**
+     * User script injection time: run when the document starts loading (before page scripts).
+     */
+    public static final int XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START = 0;
+
+    /**
+     * User script injection time: run once the DOM is parsed ({@code DOMContentLoaded}).
+     */
+    public static final int XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END = 1;
+
+    /**
+     * User script world: the page's main JavaScript world (the only world Servo supports).
+     */
+    public static final int XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN = 0;
+
+    /**
+     * Background policy (default): inactive views are hidden but their timers and animation frames
+     * keep running.
+     */
+    public static final int XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL = 0;
+
+    /**
+     * Background policy: inactive views are hidden and throttled; the page's native timers are
+     * cancelled and due timer and animation-frame callbacks run at most once per second (an interval
+     * that came due several times in between runs once).
+     */
+    public static final int XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ = 1;
+
+    /**
+     * Background policy: inactive views are hidden and throttled; the page's native timers are
+     * cancelled and nothing runs until the view becomes active again, when every timer is re-armed
+     * with its remaining delay (overdue ones fire once).
+     */
+    public static final int XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND = 2;
+
+    /**
+     * Network mode (default): pages may use the network.
+     */
+    public static final int XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE = 0;
+
+    /**
+     * Network mode: every network request ({@code http}, {@code https}, {@code ws}, {@code wss}, {@code ftp}) fails and no
+     * connection leaves the engine; local content ({@code data:}, {@code blob:}, {@code file:}, engine resources) still
+     * loads.
+     */
+    public static final int XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE = 1;
+
+    /**
+     * Network mode: like offline, except {@code http(s)} requests Servo's HTTP cache can answer without
+     * contacting the server still succeed; everything else (misses, stale entries needing
+     * revalidation) fails as a network error.
+     */
+    public static final int XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY = 2;
+
+    /**
+     * Slow-script policy: no detection (default).
+     */
+    public static final int XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE = 0;
+
+    /**
+     * Slow-script policy: report a {@code SLOW_SCRIPT} event when a script blocks the page for longer than
+     * the timeout.
+     */
+    public static final int XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY = 1;
+
+    /**
+     * Slow-script policy: unload the page (navigate to {@code about:blank}) and report a {@code SLOW_SCRIPT} event
+     * once the view answers again or another timeout has passed.
+     */
+    public static final int XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP = 2;
+
+    /**
+     * Timeout policy: give up on a blocking wait once its timeout expires and report
+     * {@code XIAN_WEB_ENGINE_STATUS_TIMED_OUT} (default).
+     */
+    public static final int XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST = 0;
+
+    /**
+     * Timeout policy: blocking waits never time out (the timeouts are ignored); a stuck Servo thread
+     * blocks the caller indefinitely.
+     */
+    public static final int XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER = 1;
+
+    /**
+     * Storage group: the view uses the engine's own storage, stored in the engine config directory
+     * (default).
+     *
+     * Any other value passed to {@code xian_web_engine_view_create_in_storage_group} names a group: views of
+     * the same group share their storage, views of different groups do not see each other's. A named
+     * group is stored in {@code <config_dir>/storage-groups/<group>}.
+     */
+    public static final int XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT = 0;
+
+    /**
+     * Storage group: the view gets ephemeral storage, isolated from every other group, never written
+     * to disk and discarded when the engine is destroyed (e.g. untrusted server content). Views of
+     * this group share it with each other.
+     */
+    public static final int XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL = 0xFFFFFFFF;
+
+    /**
+     * Slot texture color format: sRGB-encoded RGBA8 when the context supports sRGB framebuffers,
+     * otherwise linear RGBA8 (default). {@code XianWebEngineFrame.color_format} reports the resolved format.
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO = 0;
+
+    /**
+     * Slot texture color format: {@code GL_RGBA8}, written without sRGB encoding.
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8 = 1;
+
+    /**
+     * Slot texture color format: 8-bit BGRA ({@code GL_RGBA8} storage specified with {@code GL_BGRA} pixel data),
+     * for hosts that read back or share frames with BGRA-based compositors.
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8 = 2;
+
+    /**
+     * Slot texture color format: {@code GL_SRGB8_ALPHA8} (view creation fails if sRGB framebuffers are not
+     * supported).
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 = 3;
+
+    /**
+     * Slot texture color format: {@code GL_RGBA16F} (linear half-float, for HDR pipelines).
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F = 4;
+
+    /**
+     * Output colorspace: follow the color format (sRGB-encode writes to {@code SRGB8_A8} textures, write other
+     * formats as-is). Default.
+     */
+    public static final int XIAN_WEB_ENGINE_COLORSPACE_AUTO = 0;
+
+    /**
+     * Output colorspace: sRGB-encode writes ({@code GL_FRAMEBUFFER_SRGB}; requires {@code SRGB8_A8} textures).
+     * Sampling the texture through an sRGB view decodes back to linear automatically.
+     */
+    public static final int XIAN_WEB_ENGINE_COLORSPACE_SRGB = 1;
+
+    /**
+     * Output colorspace: write values as-is without sRGB encoding, for hosts that apply their own
+     * transfer function during gamma-correct compositing.
+     */
+    public static final int XIAN_WEB_ENGINE_COLORSPACE_LINEAR = 2;
+
+    /**
+     * {@code prefers-color-scheme} reported to pages: {@code light}. Default.
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT = 0;
+
+    /**
+     * {@code prefers-color-scheme} reported to pages: {@code dark}.
+     */
+    public static final int XIAN_WEB_ENGINE_COLOR_SCHEME_DARK = 1;
+
+    /**
+     * Profiling category: Servo's time profiler; a per-category timing summary is written to the
+     * output path when Servo shuts down.
+     */
+    public static final int XIAN_WEB_ENGINE_PROFILING_TIME = 1;
+
+    /**
+     * Profiling category: Servo's per-event timeline, written as an HTML trace next to the output path
+     * ({@code <output_path>.html}). Implies {@code XIAN_WEB_ENGINE_PROFILING_TIME}.
+     */
+    public static final int XIAN_WEB_ENGINE_PROFILING_TRACE = 2;
+
+    /**
+     * Idle flag: when entering idle, also release memory as for
+     * {@code XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE}.
+     */
+    public static final int XIAN_WEB_ENGINE_IDLE_FLAG_RELEASE_MEMORY = 1;
+
+    /**
+     * Memory pressure level: free engine-side pools and the spare slot textures views keep for fast
+     * resizes.
+     */
+    public static final int XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE = 1;
+
+    /**
+     * Memory pressure level: everything {@code XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE} frees, plus Servo's
+     * in-memory HTTP cache.
+     */
+    public static final int XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL = 2;
+
+    /**
+     * Golden test flag: write the captured frames as the new goldens instead of comparing them.
+     */
+    public static final int XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE = 1;
+
+    /**
+     * A Servo thread finished building its Servo instance.
+     */
+    public static final int XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT = 1;
+
+    /**
+     * A Servo thread created its shared GL (or EGL) context.
+     */
+    public static final int XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT = 2;
+
+    /**
+     * Every Servo thread is running; engine creation is about to return.
+     */
+    public static final int XIAN_WEB_ENGINE_INIT_PHASE_READY = 3;
+
+    /**
+     * A Servo thread precompiled its WebRender shaders. Servo does this when the first view of the
+     * thread is created, so this phase arrives after {@code READY} (during {@code xian_web_engine_preload}, a
+     * prewarm or the first view creation).
+     */
+    public static final int XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED = 4;
+
+    /**
+     * Input kind: mouse move.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE = 1;
+
+    /**
+     * Input kind: mouse button.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON = 2;
+
+    /**
+     * Input kind: wheel.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_KIND_WHEEL = 3;
+
+    /**
+     * Input kind: keyboard.
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_KIND_KEY = 4;
+
+    /**
+     * Input kind: relative mouse motion while the page holds pointer lock ({@code x}/{@code y} = delta).
+     */
+    public static final int XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE = 5;
+
+    /**
+     * Errors, including rejected FFI arguments.
+     */
+    public static final int XIAN_WEB_ENGINE_LOG_LEVEL_ERROR = 1;
+
+    /**
+     * Recoverable problems.
+     */
+    public static final int XIAN_WEB_ENGINE_LOG_LEVEL_WARN = 2;
+
+    /**
+     * Informational messages.
+     */
+    public static final int XIAN_WEB_ENGINE_LOG_LEVEL_INFO = 3;
+
+    /**
+     * Debugging details.
+     */
+    public static final int XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG = 4;
+
+    /**
+     * Every FFI call with its arguments (debug builds only).
+     */
+    public static final int XIAN_WEB_ENGINE_LOG_LEVEL_TRACE = 5;
+
+    /**
+     * Consumer fence value meaning "the release semaphore of this slot was signaled".
+     *
+     * Vulkan consumers pass it to {@code xian_web_engine_views_release_frames} instead of a {@code GLsync}.
+     */
+    public static final long XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE = 0xFFFFFFFFFFFFFFFFL;
+
+    /**
+     * GL operation tag: rendering-context creation (slot textures, depth buffer).
+     */
+    public static final int XIAN_WEB_ENGINE_GL_OP_CREATE = 1;
+
+    /**
+     * GL operation tag: preparing the back slot for rendering (sRGB state, FBO binding).
+     */
+    public static final int XIAN_WEB_ENGINE_GL_OP_PREPARE = 2;
+
+    /**
+     * GL operation tag: presenting a frame (WebRender output, mipmaps, fence).
+     */
+    public static final int XIAN_WEB_ENGINE_GL_OP_PRESENT = 3;
+
+    /**
+     * GL operation tag: resizing slot textures.
+     */
+    public static final int XIAN_WEB_ENGINE_GL_OP_RESIZE = 4;
+
+    /**
+     * GL operation tag: reading pixels back (screenshots).
+     */
+    public static final int XIAN_WEB_ENGINE_GL_OP_READ_PIXELS = 5;
+
+    /**
+     * C ABI version for {@code xian_web_engine}.
+     */
+    public static final int XIAN_WEB_ENGINE_ABI_VERSION = 2;
+
+    /**
+     * Oldest ABI version whose bindings can still drive this library (struct layouts unchanged since).
+     *
+     * ABI 2 grew {@code XianWebEngineFrame} from 24 to 48 bytes, so ABI 1 bindings would pass arrays too
+     * small for {@code xian_web_engine_views_acquire_frames}.
+     */
+    public static final int XIAN_WEB_ENGINE_ABI_MIN_COMPATIBLE = 2;
+
+    /**
+     * Where one view is drawn by {@code xian_web_engine_composite_views}.
+     *
+     * Coordinates are in pixels of the current GL viewport with the origin at its top-left corner.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_COMPOSITE_RECT_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_FLOAT.withName("x"),
+            ValueLayout.JAVA_FLOAT.withName("y"),
+            ValueLayout.JAVA_FLOAT.withName("width"),
+            ValueLayout.JAVA_FLOAT.withName("height"),
+            ValueLayout.JAVA_FLOAT.withName("opacity")
+    ).withName("XianWebEngineCompositeRect");
+
+    /**
+     * EGL entry point and display provided by the embedder (e.g. LWJGL's {@code EGL} class or
+     * {@code glfwGetEGLDisplay}).
+     *
+     * All fields are raw addresses ({@code usize}) and must be non-zero when installing.
+     */
+    public static final StructLayout EMBEDDER_EGL_API_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("egl_get_proc_address"),
+            ValueLayout.JAVA_LONG.withName("egl_display")
+    ).withName("EmbedderEglApi");
+
+    /**
+     * Per-pool worker thread counts for Servo, passed at engine creation.
+     *
+     * Every field is optional: {@code 0} falls back to the engine-wide {@code thread_pool_cap} default.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_THREAD_POOL_CONFIG_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_INT.withName("layout_threads"),
+            ValueLayout.JAVA_INT.withName("async_runtime_threads"),
+            ValueLayout.JAVA_INT.withName("resource_threads"),
+            ValueLayout.JAVA_INT.withName("image_cache_threads"),
+            ValueLayout.JAVA_INT.withName("webrender_threads"),
+            ValueLayout.JAVA_INT.withName("storage_threads"),
+            ValueLayout.JAVA_INT.withName("servo_threads")
+    ).withName("XianWebEngineThreadPoolConfig");
+
+    /**
+     * Engine configuration ({@code xian_web_engine_config_default} fills in the defaults).
+     *
+     * Timeouts bound how long a blocking call waits for a Servo thread; {@code 0} uses the 30-second default.
+     * A call that times out fails with {@code XIAN_WEB_ENGINE_STATUS_TIMED_OUT}.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_CONFIG_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_INT.withName("init_timeout_ms"),
+            ValueLayout.JAVA_INT.withName("view_timeout_ms"),
+            ValueLayout.JAVA_INT.withName("command_timeout_ms"),
+            ValueLayout.JAVA_INT.withName("timeout_policy")
+    ).withName("XianWebEngineConfig");
+
+    /**
+     * Network limits applied to all HTTP(S) traffic ({@code 0} in a field means unlimited).
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_NETWORK_LIMITS_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("max_bytes_per_second"),
+            ValueLayout.JAVA_INT.withName("max_connections"),
+            MemoryLayout.paddingLayout(4)
+    ).withName("XianWebEngineNetworkLimits");
+
+    /**
+     * Image size limits applied to every view of an engine ({@code 0} in a field means unlimited).
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_IMAGE_LIMITS_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("max_pixels"),
+            ValueLayout.JAVA_INT.withName("max_width"),
+            ValueLayout.JAVA_INT.withName("max_height")
+    ).withName("XianWebEngineImageLimits");
+
+    /**
+     * Image load statistics of an engine, filled by {@code xian_web_engine_get_image_load_stats}.
+     *
+     * With several Servo threads the values are summed; each thread keeps its own image cache and
+     * table of loaded URLs.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_IMAGE_LOAD_STATS_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("image_loads"),
+            ValueLayout.JAVA_LONG.withName("repeat_loads"),
+            ValueLayout.JAVA_LONG.withName("cross_view_repeat_loads"),
+            ValueLayout.JAVA_LONG.withName("dropped_urls"),
+            ValueLayout.JAVA_LONG.withName("distinct_urls"),
+            ValueLayout.JAVA_LONG.withName("distinct_url_bytes")
+    ).withName("XianWebEngineImageLoadStats");
+
+    /**
+     * Health snapshot of the engine, filled by {@code xian_web_engine_get_health}.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_HEALTH_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("millis_since_heartbeat"),
+            ValueLayout.JAVA_INT.withName("queued_commands"),
+            ValueLayout.JAVA_INT.withName("pending_views"),
+            ValueLayout.JAVA_LONG.withName("gl_error_frames"),
+            ValueLayout.JAVA_INT.withName("last_gl_error"),
+            ValueLayout.JAVA_INT.withName("last_gl_error_op"),
+            ValueLayout.JAVA_LONG.withName("commands_dropped"),
+            ValueLayout.JAVA_LONG.withName("pending_overflows")
+    ).withName("XianWebEngineHealth");
+
+    /**
+     * Producer fence statistics of the process, filled by {@code xian_web_engine_get_fence_stats}.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_FENCE_STATS_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("created"),
+            ValueLayout.JAVA_LONG.withName("deleted"),
+            ValueLayout.JAVA_DOUBLE.withName("created_per_second"),
+            ValueLayout.JAVA_DOUBLE.withName("deleted_per_second")
+    ).withName("XianWebEngineFenceStats");
+
+    /**
+     * One view event.
+     *
+     * The meaning of {@code value0}/{@code value1} depends on {@code kind} (see {@code XIAN_WEB_ENGINE_VIEW_EVENT_*}).
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_VIEW_EVENT_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_INT.withName("kind"),
+            ValueLayout.JAVA_INT.withName("status"),
+            ValueLayout.JAVA_LONG.withName("request_id"),
+            ValueLayout.JAVA_LONG.withName("value0"),
+            ValueLayout.JAVA_LONG.withName("value1"),
+            ValueLayout.JAVA_INT.withName("text_offset"),
+            ValueLayout.JAVA_INT.withName("text_len"),
+            ValueLayout.JAVA_INT.withName("flags"),
+            ValueLayout.JAVA_INT.withName("_reserved")
+    ).withName("XianWebEngineViewEvent");
+
+    /**
+     * Monotonic delivery counters of one view's queues, filled by {@code xian_web_engine_view_get_queue_stats}.
+     *
+     * Every counter starts at 0 when the view is created and only grows. Compare a snapshot with the
+     * previous one: if a {@code *_dropped} counter moved, something was lost in between, and the embedder
+     * should resync the state it mirrors (e.g. re-query the URL/title) instead of trusting its
+     * incremental view.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_VIEW_QUEUE_STATS_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("event_generation"),
+            ValueLayout.JAVA_LONG.withName("events_dropped"),
+            ValueLayout.JAVA_LONG.withName("input_generation"),
+            ValueLayout.JAVA_LONG.withName("input_dropped"),
+            ValueLayout.JAVA_LONG.withName("binary_messages_dropped")
+    ).withName("XianWebEngineViewQueueStats");
+
+    /**
+     * One acquired frame returned to the embedder (Java thread).
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_FRAME_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_INT.withName("slot"),
+            ValueLayout.JAVA_INT.withName("texture_id"),
+            ValueLayout.JAVA_LONG.withName("producer_fence"),
+            ValueLayout.JAVA_INT.withName("width"),
+            ValueLayout.JAVA_INT.withName("height"),
+            ValueLayout.JAVA_INT.withName("frames_skipped"),
+            ValueLayout.JAVA_INT.withName("color_format"),
+            ValueLayout.JAVA_INT.withName("colorspace"),
+            ValueLayout.JAVA_INT.withName("_reserved"),
+            ValueLayout.JAVA_LONG.withName("present_time_ns")
+    ).withName("XianWebEngineFrame");
+
+    /**
+     * Function pointer table for GLFW symbols provided by the embedder (e.g., Java/LWJGL).
+     *
+     * All fields are raw addresses ({@code usize}) and must be non-zero when installing.
+     */
+    public static final StructLayout EMBEDDER_GLFW_API_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("glfw_get_proc_address"),
+            ValueLayout.JAVA_LONG.withName("glfw_make_context_current"),
+            ValueLayout.JAVA_LONG.withName("glfw_default_window_hints"),
+            ValueLayout.JAVA_LONG.withName("glfw_window_hint"),
+            ValueLayout.JAVA_LONG.withName("glfw_get_window_attrib"),
+            ValueLayout.JAVA_LONG.withName("glfw_create_window"),
+            ValueLayout.JAVA_LONG.withName("glfw_destroy_window")
+    ).withName("EmbedderGlfwApi");
+
+    /**
+     * One input event in a single struct.
+     *
+     * All fields are numeric to avoid UTF-8 parsing / allocation on the Rust hot path.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_INPUT_EVENT_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_INT.withName("kind"),
+            ValueLayout.JAVA_FLOAT.withName("x"),
+            ValueLayout.JAVA_FLOAT.withName("y"),
+            ValueLayout.JAVA_INT.withName("modifiers"),
+            ValueLayout.JAVA_INT.withName("mouse_button"),
+            ValueLayout.JAVA_INT.withName("mouse_action"),
+            ValueLayout.JAVA_DOUBLE.withName("wheel_delta_x"),
+            ValueLayout.JAVA_DOUBLE.withName("wheel_delta_y"),
+            ValueLayout.JAVA_DOUBLE.withName("wheel_delta_z"),
+            ValueLayout.JAVA_INT.withName("wheel_mode"),
+            ValueLayout.JAVA_INT.withName("key_state"),
+            ValueLayout.JAVA_INT.withName("key_location"),
+            ValueLayout.JAVA_INT.withName("repeat"),
+            ValueLayout.JAVA_INT.withName("is_composing"),
+            ValueLayout.JAVA_INT.withName("key_codepoint"),
+            ValueLayout.JAVA_INT.withName("glfw_key"),
+            MemoryLayout.paddingLayout(4)
+    ).withName("XianWebEngineInputEvent");
+
+    /**
+     * Vulkan device provided by the embedder (e.g. VulkanMod through LWJGL).
+     *
+     * All fields are raw addresses ({@code usize}) and must be non-zero when installing. The device must
+     * have {@code VK_KHR_external_memory_fd} + {@code VK_KHR_external_semaphore_fd} (Linux) or
+     * {@code VK_KHR_external_memory_win32} + {@code VK_KHR_external_semaphore_win32} (Windows) enabled, and must
+     * be the GPU the GL context runs on.
+     */
+    public static final StructLayout EMBEDDER_VULKAN_DEVICE_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("get_instance_proc_addr"),
+            ValueLayout.JAVA_LONG.withName("instance"),
+            ValueLayout.JAVA_LONG.withName("physical_device"),
+            ValueLayout.JAVA_LONG.withName("device")
+    ).withName("EmbedderVulkanDevice");
+
+    /**
+     * Vulkan import description of one slot image.
+     *
+     * Handles stay owned by the engine: duplicate them ({@code dup} / {@code DuplicateHandle}) before passing them
+     * to an import call that takes ownership. The image is created as 2D, optimal tiling, exclusive
+     * sharing, usage {@code TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT}, with dedicated memory.
+     */
+    public static final StructLayout XIAN_WEB_ENGINE_VULKAN_SLOT_LAYOUT = MemoryLayout.structLayout(
+            ValueLayout.JAVA_LONG.withName("memory_handle"),
+            ValueLayout.JAVA_LONG.withName("allocation_size"),
+            ValueLayout.JAVA_LONG.withName("ready_semaphore"),
+            ValueLayout.JAVA_LONG.withName("release_semaphore"),
+            ValueLayout.JAVA_INT.withName("handle_type"),
+            ValueLayout.JAVA_INT.withName("vk_format"),
+            ValueLayout.JAVA_INT.withName("width"),
+            ValueLayout.JAVA_INT.withName("height"),
+            ValueLayout.JAVA_INT.withName("mip_levels"),
+            ValueLayout.JAVA_INT.withName("memory_type_index"),
+            ValueLayout.JAVA_INT.withName("generation"),
+            ValueLayout.JAVA_INT.withName("reserved")
+    ).withName("XianWebEngineVulkanSlot");
+
+    /**
+     * DNS resolver callback: resolves the NUL-terminated host name {@code host} and writes one IPv4 or IPv6
+     * address literal (NUL-terminated, e.g. {@code 192.168.1.20} or {@code ::1}) into {@code out_address}, which holds
+     * {@code out_capacity} bytes. Returns {@code false} to fall back to the system resolver. Called on proxy worker
+     * threads, possibly concurrently.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DNS_RESOLVE_FN_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Frame callback: receives the {@code frame_seq} of the frame that was just published. Invoked on the
+     * Servo thread right after publish; it must return quickly, must not call back into the engine
+     * for the same view's frames (acquire from the host render thread instead) and must not replace or
+     * remove its own view's callback (that waits for the running call and would deadlock).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_FRAME_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Task entry point handed to the trampoline; the embedder calls it on the main thread with the
+     * {@code task_data} it received.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_MAIN_THREAD_TASK_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS);
+
+    /**
+     * Embedder trampoline: run {@code task(task_data)} on the main thread, either immediately or on the
+     * next main-loop iteration. It may be called from any thread and must not block until the task
+     * has run.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_MAIN_THREAD_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Init progress callback: receives a {@code XIAN_WEB_ENGINE_INIT_PHASE_*} phase, in increasing order per
+     * Servo thread. Per-thread phases are reported once per Servo thread. May be invoked from any
+     * thread.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_INIT_PROGRESS_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Log callback: receives a {@code XIAN_WEB_ENGINE_LOG_LEVEL_*} level and a NUL-terminated UTF-8 message
+     * that is only valid for the duration of the call. May be invoked from any thread.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_LOG_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Host function callback: receives the call ID, the function name and the arguments as
+     * NUL-terminated UTF-8 JSON (all strings valid only for the duration of the call).
+     *
+     * Invoked on the Servo thread; it must return quickly and answer later with
+     * {@code xian_web_engine_view_respond_host_call} (from any thread).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_HOST_FUNCTION_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Completion callback of {@code xian_web_engine_create_view_async}: {@code status} is
+     * {@code XIAN_WEB_ENGINE_STATUS_OK} once the view exists, or {@code XIAN_WEB_ENGINE_STATUS_FAILED} if it could
+     * not be created (the reason is logged).
+     *
+     * Invoked once, on the Servo thread (or on the thread shutting the engine down); it must return
+     * quickly.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CREATED_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Completion callback of {@code xian_web_engine_capture_element}. On {@code XIAN_WEB_ENGINE_STATUS_OK},
+     * {@code pixels} holds {@code width * height} RGBA8 pixels (tightly packed, top row first, in the view's
+     * color format encoding); otherwise {@code width} and {@code height} are {@code 0}, {@code pixels} is NULL and the reason
+     * is logged. {@code pixels} is only valid during the call.
+     *
+     * Invoked exactly once, on the Servo thread (or, with {@code XIAN_WEB_ENGINE_STATUS_FAILED}, on the
+     * thread that closes the view or shuts the engine down first); it must return quickly.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_ELEMENT_CAPTURE_FN_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Returns the C ABI version.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_ABI_VERSION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT);
+
+    /**
+     * Returns whether bindings written against ABI {@code version} can use this library.
+     *
+     * The library accepts every version from the last struct-layout change up to its own version;
+     * bindings newer than the library are rejected, since they may call functions it does not export.
+     * Call this once after loading and fall back (or report a clear error) when it returns {@code false}.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_REQUIRE_ABI_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.JAVA_INT);
+
+    /**
+     * Returns the {@code XIAN_WEB_ENGINE_CAPABILITY_*} bits supported by this build on this platform.
+     *
+     * Unknown bits must be ignored; bits are only ever added.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_GET_CAPABILITIES_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG);
+
+    /**
+     * Draws the latest frames of {@code count} views into {@code target_fbo} and returns how many were drawn.
+     *
+     * Views are drawn in array order (back to front) as premultiplied-alpha quads at {@code rects[i]}, in
+     * pixels of the current GL viewport with a top-left origin. Each view shows its newest frame, or the
+     * one it showed last time if no newer frame is ready; the compositor keeps that frame acquired and
+     * releases it with a consumer fence once replaced, so do not acquire/release these views yourself.
+     * Views no longer passed in are released on the next call. sRGB conversion follows the view's
+     * colorspace and the encoding of the target attachment. {@code views[i]} may be NULL to skip an entry;
+     * {@code target_fbo = 0} draws into the default framebuffer. GL state changed for drawing is restored.
+     *
+     * Must be called on the host render thread with a GL context current that shares objects with the
+     * engine. The compositor's GL objects belong to the first thread that calls this; destroy the
+     * engine on that thread to free them.
+     *
+     * Returns {@code 0} if an argument is NULL/invalid or the compositor cannot be created.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_COMPOSITE_VIEWS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Installs the process-wide DNS resolver callback ({@code NULL} removes it), for environments that block
+     * system DNS or need split-horizon names for LAN servers.
+     *
+     * May be installed or replaced at any time: engines running a loopback proxy send their HTTP(S)
+     * traffic through it, and it asks the callback for every new target connection and falls back to
+     * the system resolver when it returns {@code false} or no callback is installed. An engine runs a proxy
+     * if it was created with {@code XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY} or while a callback or
+     * network limit was installed; other engines keep using the system resolver. TLS is still verified
+     * against the original host name. DNS-over-HTTPS can be implemented inside the callback. WebSocket
+     * connections always use the system resolver.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_DNS_RESOLVER_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Starts dragging {@code len} bytes of UTF-8 data with the given MIME type (NUL-terminated) over the view.
+     *
+     * Follow with {@code xian_web_engine_view_drag_move} as the cursor moves, then
+     * {@code xian_web_engine_view_drop} or {@code xian_web_engine_view_cancel_drag}. Starting a new drag replaces the
+     * current one.
+     *
+     * Returns {@code false} if an argument is NULL, the data is not valid UTF-8, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_BEGIN_DRAG_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Moves the current drag to {@code (x, y)} in device pixels.
+     *
+     * Returns {@code false} if {@code view} is NULL, a coordinate is not finite, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_DRAG_MOVE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_FLOAT, ValueLayout.JAVA_FLOAT);
+
+    /**
+     * Drops the current drag at {@code (x, y)} in device pixels.
+     *
+     * The outcome is reported as a {@code XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE} event whose text is the drop
+     * effect ({@code "none"} if the page rejected the data, so the item can stay in the inventory).
+     *
+     * Returns the request ID, or {@code 0} on failure.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_DROP_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.JAVA_FLOAT, ValueLayout.JAVA_FLOAT);
+
+    /**
+     * Cancels the current drag (e.g. the cursor left the view).
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CANCEL_DRAG_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Installs the embedder's {@code eglGetProcAddress} and {@code EGLDisplay} (once per process).
+     *
+     * This must be called before {@code xian_web_engine_create_with_flags} with
+     * {@code XIAN_WEB_ENGINE_ENGINE_FLAG_EGL}. The display must be the one that owns the {@code EGLContext}
+     * passed to it (e.g. {@code glfwGetEGLDisplay()} when GLFW runs on ANGLE).
+     *
+     * Returns {@code true} on success.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_EGL_API_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Creates an engine bound to a Java-created GLFW OpenGL context.
+     *
+     * {@code resources_dir} and {@code config_dir} are optional NUL-terminated UTF-8 strings.
+     * Passing NULL or an empty string means "unset".
+     *
+     * {@code thread_pool_cap} controls the maximum worker threads used by Servo's internal thread pools.
+     * - {@code 0} means "no cap" (use CPU parallelism).
+     * - Otherwise, Servo thread pools are capped to {@code min(CPU, thread_pool_cap)}.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CREATE_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Same as {@code xian_web_engine_create}, but also sizes Servo's worker pools individually.
+     *
+     * {@code thread_pools} is optional (NULL = all defaults). Each non-zero field sets the worker count of one
+     * pool (layout/style, async runtime, network, image decoding, WebRender, storage); {@code 0} fields fall
+     * back to the {@code thread_pool_cap} rule. Use small values on low-end machines so the game and the
+     * browser do not oversubscribe the CPU.
+     *
+     * {@code servo_threads} shards views across several Servo threads, isolating heavy pages from the rest.
+     * Views created in a group live on the group's thread; other views are spread round-robin.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CREATE_WITH_THREAD_POOLS_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Same as {@code xian_web_engine_create_with_thread_pools}, but also takes engine creation flags
+     * ({@code XIAN_WEB_ENGINE_ENGINE_FLAG_*}).
+     *
+     * With {@code XIAN_WEB_ENGINE_ENGINE_FLAG_EGL}, {@code shared_context} is the host's {@code EGLContext} and the Servo
+     * threads create their contexts through the EGL API installed by {@code xian_web_engine_set_egl_api}
+     * (e.g. Minecraft running on ANGLE or a GLES-only ARM driver). Otherwise it is the
+     * {@code GLFWwindow*} as for {@code xian_web_engine_create}. {@code XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG} creates
+     * debug contexts and forwards their GL debug messages to the log callback.
+     * {@code XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE} hands out the engine's views as validated IDs instead
+     * of pointers (through the process-global handle table).
+     * {@code XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS} runs page content in separate processes (fails on
+     * Windows and under a {@code java}/{@code javaw} launcher, see the flag).
+     * {@code XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY} routes HTTP(S) through the loopback proxy even if no
+     * resolver or network limit is installed yet.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CREATE_WITH_FLAGS_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Same as {@code xian_web_engine_create_with_flags}, but also takes an engine configuration (NULL = all
+     * defaults; see {@code xian_web_engine_config_default}).
+     *
+     * The configuration sets how long engine creation, blocking view creation and other blocking
+     * requests wait for the Servo threads, or disables those timeouts. When a wait times out the call
+     * fails and {@code xian_web_engine_last_command_status} returns {@code XIAN_WEB_ENGINE_STATUS_TIMED_OUT} on the
+     * calling thread.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CREATE_WITH_CONFIG_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Fills {@code config} with the default engine configuration (30-second timeouts, fail fast), to be
+     * adjusted and passed to {@code xian_web_engine_create_with_config}.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CONFIG_DEFAULT_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS);
+
+    /**
+     * Destroys an engine created by {@code xian_web_engine_create}.
+     *
+     * This shuts down the dedicated Servo thread and destroys any remaining views/resources created by
+     * this engine. Do not use any views after destroying the engine.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DESTROY_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS);
+
+    /**
+     * Runs a Servo content process for an engine created with
+     * {@code XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS}.
+     *
+     * Servo starts content processes by re-running the host executable with the arguments
+     * {@code --content-process <token>}. The host's entry point must detect them and call this function with
+     * the token before initializing anything else; it returns when the content process exits, after
+     * which the process should exit too.
+     *
+     * Returns {@code false} right away if {@code token} is NULL/invalid or multiprocess mode is not supported on
+     * this platform (Windows).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_RUN_CONTENT_PROCESS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Drains pending vsync callbacks (Java-driven refresh).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_TICK_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS);
+
+    /**
+     * Enters or leaves engine idle mode (e.g. while the game shows its pause menu).
+     *
+     * While idle, Servo threads coalesce their wakes and spin at most a few times per second, and
+     * fixed-interval views stop refreshing, so background CPU use drops to near zero. Input and
+     * commands still reach the views, with up to a quarter second of extra latency. Vsync-driven
+     * views keep following {@code xian_web_engine_tick}; stop ticking to pause them too.
+     *
+     * #### Parameters
+     * - {@code idle}: Whether the engine is idle.
+     * - {@code idle_flags}: {@code XIAN_WEB_ENGINE_IDLE_FLAG_*} applied when entering idle ({@code 0} = none).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_IDLE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN, ValueLayout.JAVA_INT);
+
+    /**
+     * Tells the engine the host is low on memory (e.g. the JVM is close to its heap limit or the OS
+     * signalled low memory) so it releases what it can.
+     *
+     * {@code XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE} frees the recycled vsync overflow nodes and every
+     * view's spare slot textures; {@code XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL} also clears Servo's
+     * in-memory HTTP cache. Servo exposes no image-cache or garbage-collection hook, so decoded
+     * images and script heaps are left to Servo. Views keep running; released memory is reallocated on
+     * demand.
+     *
+     * #### Parameters
+     * - {@code level}: {@code XIAN_WEB_ENGINE_MEMORY_PRESSURE_*}.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_NOTIFY_MEMORY_PRESSURE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Starts a WebDriver server on {@code port} so QA can script the embedded UI of the running client with
+     * standard W3C WebDriver tooling (Selenium, WebdriverIO, ...).
+     *
+     * The server is Servo's; it drives the views of the first Servo thread (all views unless
+     * {@code servo_threads} is above {@code 1}). Views are created by the host, so window management commands
+     * (new window, window rect) are not honored. Calling again with the same port does nothing. Only
+     * builds with the {@code webdriver} feature support it ({@code XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER}); the
+     * server accepts unauthenticated connections, so never enable it in release clients.
+     *
+     * Returns {@code false} if {@code engine} is NULL, {@code port} is {@code 0} or cannot be bound (e.g. already in use), the
+     * server is already listening on another port, WebDriver is not built in, or the engine is
+     * shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_ENABLE_WEBDRIVER_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_SHORT);
+
+    /**
+     * Reports the display timing to vsync-driven views so Servo starts frames at the predicted vsync
+     * instants instead of whenever {@code xian_web_engine_tick} runs.
+     *
+     * Keep calling {@code xian_web_engine_tick}: it still drains callbacks queued before the timing was set.
+     * Call again when the refresh rate changes or the prediction drifts.
+     *
+     * #### Parameters
+     * - {@code refresh_rate_hz}: Display refresh rate in {@code (0, 1000]} ({@code 0} returns to tick-driven frames).
+     * - {@code next_vsync_nanos}: Time of an upcoming vsync on the {@code xian_web_engine_monotonic_time_ns}
+     *   clock ({@code 0} = phase unknown, vsyncs are assumed to start now).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_VSYNC_INFO_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_DOUBLE, ValueLayout.JAVA_LONG);
+
+    /**
+     * Installs the process-wide engine initialization progress callback ({@code NULL} removes it).
+     *
+     * Install it before {@code xian_web_engine_create*} to drive a loading screen: each Servo thread reports
+     * {@code XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT} and {@code XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT}, then
+     * {@code XIAN_WEB_ENGINE_INIT_PHASE_READY} is reported once right before creation returns.
+     * {@code XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED} follows per Servo thread when its first view is
+     * created (e.g. by {@code xian_web_engine_preload}). The callback runs on the Servo threads or the
+     * creating thread and must return quickly.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_INIT_PROGRESS_CALLBACK_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Warms the network and image caches for {@code count} known UI URLs (NUL-terminated UTF-8, absolute),
+     * e.g. while a loading screen is shown. Returns immediately.
+     *
+     * A hidden view requests every URL once and stays open until the next call or engine destruction;
+     * if no view exists yet, creating it also precompiles WebRender shaders.
+     *
+     * Returns {@code false} if an argument is invalid, a URL cannot be parsed, or the engine is shutting
+     * down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_PRELOAD_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Swaps the Servo resources directory at runtime (e.g. after a resource pack change).
+     *
+     * The swap happens on the Servo thread; resources read afterwards come from {@code path}, while resources
+     * Servo already loaded keep their current contents.
+     *
+     * Returns {@code false} if an argument is NULL/invalid, {@code path} is not a directory, or the engine is shutting
+     * down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_RESOURCES_DIR_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Swaps the Servo resources to a zip archive ({@code .zip}/{@code .jar}/{@code .pak}) at {@code path}.
+     *
+     * The archive is read into memory and entries are inflated on demand; resource files are looked up
+     * at the archive root or under {@code resources/}. Like {@code xian_web_engine_set_resources_dir}, the swap
+     * happens on the Servo thread and affects resources read afterwards.
+     *
+     * Returns {@code false} if an argument is NULL/invalid, the archive cannot be read or parsed, or the engine
+     * is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_RESOURCES_ARCHIVE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Same as {@code xian_web_engine_set_resources_archive}, but takes the archive as {@code len} bytes in memory
+     * (e.g. read from a mod jar on the Java side). The bytes are copied.
+     *
+     * Returns {@code false} if an argument is NULL/invalid, the archive cannot be parsed, or the engine is
+     * shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_RESOURCES_ARCHIVE_BYTES_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Sets the OS scheduling of the engine's own threads (the Servo thread and the refresh scheduler
+     * thread), e.g. to keep web work off the cores used by the game's render thread.
+     *
+     * - {@code servo_thread_priority}: Windows {@code THREAD_PRIORITY_*} value ({@code -2} lowest .. {@code 2} highest,
+     *   {@code 0} = normal).
+     * - {@code affinity_mask}: bit N allows logical CPU N; {@code 0} keeps the current affinity.
+     *
+     * Applied asynchronously and best-effort (Windows {@code SetThreadPriority}/{@code SetThreadAffinityMask});
+     * ignored on other platforms. Servo's internal worker pools are not affected.
+     *
+     * Returns {@code false} if {@code engine} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_THREAD_CONFIG_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_LONG);
+
+    /**
+     * Registers a font from {@code len} bytes in memory (TTF/OTF/WOFF/WOFF2) under the NUL-terminated UTF-8
+     * CSS family name {@code family_name}, so pages can use it via {@code font-family} without installing it.
+     *
+     * The font applies to every view (existing views from their next load). The bytes are copied.
+     *
+     * Returns {@code false} if an argument is NULL/empty/invalid or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_REGISTER_FONT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Configures engine caches.
+     *
+     * - {@code cache_dir}: optional NUL-terminated UTF-8 directory for files the engine writes to disk itself
+     *   (e.g. staged uploads); NULL or empty keeps the current directory (default: a folder in the system
+     *   temp directory).
+     * - {@code max_bytes}: HTTP cache budget. Servo's HTTP cache lives in memory and has no size limit setting,
+     *   so {@code 0} disables it and any other value enables it.
+     *
+     * Returns {@code false} if {@code engine} is NULL, {@code cache_dir} cannot be created, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_CACHE_CONFIG_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Sets a hard JavaScript heap limit for pages, protecting the engine's process from a leaking page
+     * (e.g. server-provided content).
+     *
+     * The limit is Servo's process-wide {@code js_mem_max} preference: it is shared by every engine in the
+     * process (the last call wins) and applies to the script event loop of every page whose event loop
+     * starts afterwards; pages already running keep the limit they started with. A page that runs out
+     * of it stops running scripts: the engine unloads it ({@code about:blank}) and queues a
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT} event, which also reports a page whose script thread
+     * crashed instead. {@code limit_mb = 0} removes the limit (default).
+     *
+     * Returns {@code false} if {@code engine} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_HEAP_LIMIT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Clears Servo's HTTP cache and deletes the files staged in the engine cache directory.
+     *
+     * Returns {@code false} if {@code engine} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CLEAR_HTTP_CACHE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Limits the network traffic pages can generate, so a web UI cannot saturate the player's connection
+     * (e.g. during multiplayer).
+     *
+     * The limits are enforced by the loopback proxy that carries all HTTP(S) traffic, so they cover
+     * documents, subresources, {@code fetch} / XHR and workers of every engine running a proxy (engines
+     * created with {@code XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY} or after limits or a resolver were
+     * installed), and take effect immediately: at most
+     * {@code max_connections} server connections are open at once (the rest wait; idle keep-alive
+     * connections are closed for them), and reads from servers are paced to {@code max_bytes_per_second} in
+     * total.
+     * WebSocket connections are not limited. The limits are process-wide: every engine shares them and
+     * the last call wins. A {@code 0} field disables that limit; all-zero limits (the default) remove the
+     * enforcement entirely.
+     *
+     * Returns {@code false} if {@code engine} or {@code limits} is NULL, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_NETWORK_LIMITS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Limits the size of images pages display, so a page showing a huge image (e.g. 16k × 16k) does
+     * not spend a frame's worth of time and VRAM on it.
+     *
+     * From each view's next navigation, every {@code <img>} wider than {@code max_width}, taller than
+     * {@code max_height} or with more than {@code max_pixels} pixels is scaled down (keeping its aspect ratio and
+     * layout size) before it is shown, and a {@code XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED} event is
+     * queued. Servo still decodes the original once, and cross-origin images are left as they are.
+     * How many images decode at once is set by {@code image_cache_threads} in the thread pool config. A {@code 0}
+     * field disables that limit; all-zero limits (the default) remove the enforcement entirely.
+     *
+     * Returns {@code false} if {@code engine} or {@code limits} is NULL, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_IMAGE_LIMITS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Writes the image load statistics of the engine into {@code out}: how many image loads pages made, how
+     * many of them loaded a URL already loaded on the same Servo thread (which Servo's image cache can
+     * serve without decoding again), how many of those repeats came from another view than the first
+     * load, and the distinct URLs loaded with their size at 4 bytes per pixel.
+     *
+     * Loads are reported for {@code <img>} elements (not CSS images, {@code data:} or {@code blob:} URLs) and counted
+     * per Servo thread, since each one has its own image cache. The statistics describe what pages
+     * load; Servo exposes neither the contents nor the size of its image cache. Safe to call from any
+     * thread.
+     *
+     * Returns {@code false} if {@code engine} or {@code out} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_GET_IMAGE_LOAD_STATS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Switches the engine network mode ({@code XIAN_WEB_ENGINE_NETWORK_MODE_*}), e.g. to guarantee no
+     * traffic leaves the game while the player is offline.
+     *
+     * {@code OFFLINE} fails every {@code http(s)} / {@code ws(s)} / {@code ftp} request; {@code CACHE_ONLY} only serves {@code http(s)}
+     * responses Servo's HTTP cache holds and can use without contacting the server. Local schemes
+     * ({@code data:}, {@code blob:}, {@code file:}, engine resources) always work. Besides cancelling requests, the
+     * engine's loopback proxy refuses every connection to a server and closes the open ones while not
+     * online; an engine running without a proxy (see {@code XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY})
+     * relies on request cancellation alone and treats {@code CACHE_ONLY} as {@code OFFLINE}. The mode applies to
+     * every view immediately, including the pages currently shown. Blocked requests show up to the
+     * page as network errors.
+     *
+     * WebRTC is switched off through Servo's {@code dom_webrtc_enabled} preference, which is process-wide:
+     * while any engine of the process is not online, documents created anywhere in the process have no
+     * {@code RTCPeerConnection}; documents created earlier keep theirs.
+     *
+     * Returns {@code false} if {@code engine} is NULL, {@code mode} is unknown, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_NETWORK_MODE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Writes a health snapshot of the engine into {@code out}, so the host can detect a stuck Servo thread
+     * (e.g. a hung script) and show a "web UI not responding" overlay or destroy the engine.
+     *
+     * {@code millis_since_heartbeat} is how long the Servo thread has been busy in its current loop iteration
+     * ({@code 0} while idle). Safe to call from any thread, even while the Servo thread is stuck.
+     *
+     * Returns {@code false} if {@code engine} or {@code out} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_GET_HEALTH_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Writes the process-wide producer fence statistics into {@code out}: how many {@code GLsync} fences the
+     * Servo threads of every engine created and deleted, in total and per second.
+     *
+     * Fences are not pooled (a {@code GLsync} cannot be re-armed), so {@code created_per_second} tracks the
+     * published frame rate of fenced views. The rates cover the last window of at least one second and
+     * roll over on query, so poll about once per second. Safe to call from any thread.
+     *
+     * Returns {@code false} if {@code out} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_GET_FENCE_STATS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Writes the most recent tracing spans to {@code path} as a Chrome trace JSON file (open it in
+     * {@code chrome://tracing} or Perfetto) to diagnose frame hitches end-to-end.
+     *
+     * Spans are always recorded into a fixed process-wide ring (the latest 16384 are kept): Servo
+     * command drains, {@code spin_event_loop}, paint and present on the Servo thread(s), and frame acquires
+     * on the embedder's threads. Safe to call from any thread.
+     *
+     * Returns {@code false} if an argument is NULL/invalid or the file cannot be written.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DUMP_TRACE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Turns Servo's time profiler on or off without rebuilding the library.
+     *
+     * {@code categories} is a combination of {@code XIAN_WEB_ENGINE_PROFILING_*} ({@code 0} turns profiling off);
+     * {@code output_path} is required unless {@code categories} is {@code 0}. Servo reads its profiler options once when
+     * it starts, so the setting is process-wide and applies to engines created afterwards: to profile a
+     * running UI, call this and then recreate the engine (destroy + create restarts Servo). Reports are
+     * written when that Servo instance shuts down. Engines already running are not affected, so the
+     * call takes no engine handle.
+     *
+     * Returns {@code false} if {@code categories} has unknown bits or {@code output_path} is NULL/invalid while
+     * profiling is requested.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_PROFILING_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Returns the status of the last request the calling thread sent to the engine
+     * ({@code XIAN_WEB_ENGINE_STATUS_*}).
+     *
+     * Call it after a request function returned {@code false} to tell a transient rejection apart from a
+     * permanent one:
+     *
+     * - {@code XIAN_WEB_ENGINE_STATUS_QUEUE_FULL}: the request's command lane is full; retry later.
+     * - {@code XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN}: the engine is shutting down.
+     * - {@code XIAN_WEB_ENGINE_STATUS_TIMED_OUT}: a blocking call (engine or view creation, view group
+     *   creation) gave up waiting for a Servo thread (see {@code xian_web_engine_create_with_config}).
+     *
+     * View destruction and engine shutdown use an unbounded urgent lane and are never rejected for
+     * capacity; bulky requests (fonts, exports, user scripts) use a small lane so they cannot crowd out
+     * ordinary ones.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_LAST_COMMAND_STATUS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT);
+
+    /**
+     * Polls queued events of a view into {@code out_events} (at most {@code capacity} entries).
+     *
+     * Text payloads are copied into {@code text_buf} and referenced by {@code text_offset/text_len}; {@code text_buf} may
+     * be NULL when {@code text_buf_capacity = 0}. An event whose text does not fit into the remaining buffer is
+     * kept for the next call. Returns the number of events written.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_POLL_EVENTS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Writes the monotonic delivery counters of {@code view}'s queues into {@code out}.
+     *
+     * Event and input queues are bounded and drop on overflow. Keep the previous snapshot and compare:
+     * if {@code events_dropped}, {@code input_dropped} or {@code binary_messages_dropped} grew, something was lost since
+     * then and the embedder should resync instead of silently desyncing (e.g. re-query the URL/title
+     * with {@code xian_web_engine_view_get_url} / {@code xian_web_engine_view_get_title}). Safe to call from any
+     * thread.
+     *
+     * Returns {@code false} if {@code view} or {@code out} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_QUEUE_STATS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Tries to acquire the latest READY frames for a batch of views.
+     *
+     * This function is "compact": it only writes successfully acquired frames.
+     *
+     * - {@code views} is an array of {@code count} view pointers.
+     * - {@code out_view_indices} receives the corresponding input view index for each acquired frame.
+     * - {@code out_frames} receives the acquired frames, packed densely from 0..return_value.
+     * - Both output arrays must have capacity for at least {@code count} entries.
+     *
+     * Returns the number of acquired frames written.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEWS_ACQUIRE_FRAMES_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Releases a batch of previously acquired frame slots for multiple views.
+     *
+     * If {@code consumer_fences} is NULL, all fences are treated as 0.
+     * If non-NULL, each fence must be a {@code GLsync} created by the embedder *after* sampling the texture.
+     * Ownership transfers to Rust and the embedder must NOT delete it; Rust will delete it after the
+     * producer sees it signaled.
+     *
+     * If a fence value is {@code 0}, the slot becomes immediately reusable; the embedder must ensure the
+     * texture is no longer in use by the GPU before releasing (e.g., via other synchronization).
+     *
+     * If a view was created with {@code XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE}, its corresponding
+     * consumer fence MUST be 0 (ignored).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEWS_RELEASE_FRAMES_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Pins a frame acquired from {@code view} so it can be kept across many game frames (e.g. for map render
+     * caching or screenshots) without starving the view.
+     *
+     * A held frame is already never overwritten, but while one slot is kept the producer has only two
+     * left and would keep reclaiming the newest READY frame ahead of painting, so the next acquire
+     * often finds nothing. A pinned slot is left out of rotation instead: the producer runs
+     * double-buffered on the other two slots and only takes the previous frame once the new one is
+     * done, so {@code xian_web_engine_views_acquire_frames} keeps returning fresh frames. Releasing a pinned
+     * slot is ignored; call {@code xian_web_engine_unpin_view_frame} first, then release it as usual. Unpin
+     * and release every pinned frame before destroying the view.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code slot} is out of range, or the slot is not currently held.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_PIN_VIEW_FRAME_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Unpins a frame pinned by {@code xian_web_engine_pin_view_frame}. The frame is held again: release it
+     * with {@code xian_web_engine_views_release_frames} as usual once done with it.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code slot} is out of range, or the slot is not pinned.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_UNPIN_VIEW_FRAME_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Installs the view's frame-available callback ({@code NULL} removes it).
+     *
+     * The callback runs on the Servo thread right after a new frame is published and receives its
+     * {@code frame_seq}, so an embedder can wake its render thread only when there is something to acquire
+     * instead of polling every frame. Acquire/release still happen on the render thread. The callback
+     * must return quickly and must not replace or remove its own view's callback. Replacing or
+     * removing the callback (including destroying the view, which removes it) waits for a call in
+     * flight, so once this function returns the previous callback is not running and will not run
+     * again, and its {@code user_data} may be freed.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_FRAME_CALLBACK_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Returns the current time on the engine monotonic clock, in nanoseconds: the clock
+     * {@code XianWebEngineFrame.present_time_ns} is measured on.
+     *
+     * Subtract a frame's {@code present_time_ns} from it to get the frame's age, or sample it next to the
+     * host's own clock once to convert between the two. Safe to call from any thread.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_MONOTONIC_TIME_NS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG);
+
+    /**
+     * Installs an embedder-provided GLFW function table.
+     *
+     * This must be called before {@code xian_web_engine_create}.
+     * The engine will not attempt to locate {@code glfw3.dll} / {@code libglfw.so} by name.
+     *
+     * All function pointers must come from the same GLFW library instance that produced the
+     * {@code GLFWwindow*} passed to {@code xian_web_engine_create}.
+     *
+     * Returns {@code true} on success.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_GLFW_API_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Installs the main-thread trampoline (once per process, before {@code xian_web_engine_create}).
+     *
+     * GLFW only allows window creation/destruction on the main thread, and macOS enforces it. With a
+     * trampoline installed, the Servo threads hand those calls to {@code callback}, which must arrange for
+     * {@code task(task_data)} to run on the main thread (immediately or on the next frame) without waiting
+     * for it. While the main thread is blocked in {@code xian_web_engine_create*} or
+     * {@code xian_web_engine_destroy}, it runs the queued calls itself, so those must be called on the main
+     * thread too. Required on macOS; optional elsewhere.
+     *
+     * Returns {@code false} if {@code callback} is NULL or a trampoline is already installed.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_MAIN_THREAD_TRAMPOLINE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Runs the golden-image rendering tests on the calling thread and returns the number of failed
+     * cases, or {@code -1} if an argument is NULL/invalid or the pages cannot be listed. Only exported by
+     * builds with the {@code golden-tests} feature ({@code XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS}).
+     *
+     * Create {@code engine} on a context nobody presents (a hidden GLFW window or a surfaceless EGL context).
+     * Every {@code *.html} page in {@code pages_dir} (e.g. the bundled {@code tests/golden/pages}) is loaded into a fresh
+     * {@code width}x{@code height} view, once as {@code RGBA8} and once as {@code SRGB8_A8}; after {@code load} and two animation
+     * frames the frame is read back as for PNG export and compared against {@code <page>.png} /
+     * {@code <page>.srgb.png} in {@code golden_dir}, allowing {@code tolerance} (0..=255) per channel. Captured frames and,
+     * on mismatch, {@code <case>.diff.png} (offending pixels in red) are written to {@code output_dir}; each case is
+     * logged. {@code XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE} in {@code flags} stores the captured frames as the new
+     * goldens instead.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_RUN_GOLDEN_TESTS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates a view group and returns its non-zero ID ({@code 0} on failure).
+     *
+     * Views created into the group share one refresh driver, so their frames are scheduled in the same
+     * tick and published together. {@code target_fps = 0} means the group is driven by external vsync
+     * ({@code xian_web_engine_tick}).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GROUP_CREATE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates a view group ticking at a fractional rate of {@code fps_numerator / fps_denominator} frames
+     * per second and returns its non-zero ID ({@code 0} on failure).
+     *
+     * Use it to match a display whose refresh rate is not a whole number (e.g. {@code 14386 / 100} for
+     * 143.86 Hz, {@code 60000 / 1001} for 59.94 Hz). Ticks follow an exact frame grid, so long-running
+     * views do not drift against the display. {@code fps_numerator = 0} means the group is driven by
+     * external vsync, as with {@code xian_web_engine_view_group_create}.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GROUP_CREATE_FRACTIONAL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Destroys a view group.
+     *
+     * Views already created into the group keep running on the shared tick; no new views can join.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GROUP_DESTROY_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates one view inside a view group (refresh rate comes from the group).
+     *
+     * Returns NULL if {@code engine} is NULL or the group does not exist.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CREATE_IN_GROUP_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Registers a host function the page can call as {@code window.xianHost.call(name, args)} ({@code NULL}
+     * {@code callback} unregisters it); registrations survive navigations.
+     *
+     * {@code args} is serialized with {@code JSON.stringify} ({@code undefined} becomes {@code null}). The callback runs on the
+     * Servo thread and must return quickly; answer each call with
+     * {@code xian_web_engine_view_respond_host_call}. Calls to unregistered names are rejected at once, and
+     * calls still unanswered when the page navigates away are dropped.
+     *
+     * Returns {@code false} if an argument is invalid or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_REGISTER_HOST_FUNCTION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Answers a host function call: {@code success} resolves the page's Promise with {@code result} parsed as JSON
+     * ({@code NULL} or empty resolves with {@code undefined}); otherwise the Promise is rejected with an {@code Error}
+     * whose message is {@code result}. Unknown or stale call IDs are ignored.
+     *
+     * Returns {@code false} if an argument is invalid or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RESPOND_HOST_CALL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Sends {@code len} bytes to the page, which receives them as an {@code ArrayBuffer}:
+     * {@code xianHost.addEventListener("binary", e => draw(e.channel, e.data))}. The bytes are copied into a
+     * pooled buffer, so {@code data} can be reused as soon as this returns. Messages are delivered in order
+     * and dropped if no page is listening.
+     *
+     * Returns {@code false} if an argument is invalid, {@code len} exceeds 16 MiB, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_POST_BINARY_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Looks up a binary message the page sent with {@code xianHost.sendBinary(channel, typedArray)}
+     * (announced by a {@code BINARY_MESSAGE} event) and writes its address and length to {@code out_data} /
+     * {@code out_len}. The bytes are owned by the engine and stay valid until
+     * {@code xian_web_engine_view_release_binary_message}.
+     *
+     * Returns {@code false} if an argument is invalid or the message is unknown or already released.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_BINARY_MESSAGE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Releases a binary message received from the page; its buffer returns to the view's pool. Unreleased
+     * messages count against a limit of 256, beyond which further page messages are dropped.
+     *
+     * Returns {@code false} if {@code view} is NULL or the message is unknown or already released.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RELEASE_BINARY_MESSAGE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Registers a GL texture of the embedder context ({@code GL_TEXTURE_2D}, e.g. the player's skin or a map)
+     * under {@code name} for every view ({@code texture} 0 unregisters it). Pages fetch it with
+     * {@code await xianHost.getTexture(name)}, which resolves to an {@code ImageBitmap} that can be drawn on a
+     * canvas or uploaded with {@code texImage2D}.
+     *
+     * Each request reads the texture back on the Servo thread's shared context and copies it into the
+     * page (Servo cannot import foreign textures into WebGL), so it reflects the texture at the time of
+     * the request; fetch again after the embedder updates it. Set {@code flip_y} for render targets whose
+     * row 0 is the bottom row. The texture must stay alive while registered.
+     *
+     * Returns {@code false} if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+     * the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_REGISTER_TEXTURE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Pushes a frame the embedder rendered into {@code texture_id} ({@code GL_TEXTURE_2D} of the embedder context,
+     * row 0 at the bottom as rendered into an FBO) to stream {@code stream_id} of the page, which receives it
+     * as an {@code ImageBitmap}: {@code xianHost.openStream(id).attach(canvas)} or the stream's {@code frame} event.
+     *
+     * This mirrors the triple buffer with the roles swapped. Insert a {@code GLsync} after rendering and
+     * pass it as {@code fence} (or 0), and flush; the engine waits for it on the GPU, copies the texture and
+     * deletes the fence. An {@code EXTERNAL_FRAME_RELEASED} event then reports that the texture may be
+     * rendered into again, so rotating two or three textures per stream avoids stalls. Frames for
+     * streams the page has not opened are released without being read. Keep frames small: each one is
+     * copied through the CPU.
+     *
+     * Returns {@code false} if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+     * the engine is shutting down; the caller still owns {@code fence} in that case.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_PUSH_EXTERNAL_FRAME_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_LONG);
+
+    /**
+     * Sends a batch of input events to a view.
+     *
+     * Pointer positions are mapped through the view's input transform first, if one is set
+     * ({@code xian_web_engine_set_view_input_transform}).
+     *
+     * Returns the number of accepted events (may be less than {@code count} if the queue is full).
+     * If the view is inactive, events are treated as accepted and dropped (fast path).
+     * Unknown event kinds are treated as accepted and dropped (and reported through the log callback).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SEND_INPUT_EVENTS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Sends one mouse button, wheel or key event and reports how the page handled it, so embedders can
+     * pass clicks on empty page areas through to the game.
+     *
+     * The event is dispatched after the input sent before it (its pointer position is mapped through
+     * the view's input transform like {@code xian_web_engine_view_send_input_events}). Once the page handled
+     * it, a {@code XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT} event with {@code request_id = correlation_id} reports
+     * whether the page consumed it; pointer events are also hit tested against the page's elements
+     * ({@code XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT}). Input sent with
+     * {@code xian_web_engine_view_send_input_events} afterwards may be dispatched first, so send the
+     * matching release through this function too. Events sent while the view is inactive are dropped
+     * without a result.
+     *
+     * Returns {@code false} if {@code view} or {@code event} is NULL, the event kind is not tracked, {@code correlation_id} is
+     * {@code 0}, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SEND_TRACKED_INPUT_EVENT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Sets the view's input transform: a row-major 3x3 homography (9 floats) mapping host coordinates
+     * {@code (x, y, 1)} to view pixels {@code (x', y', w)}, divided by {@code w}. Once set, the pointer positions of
+     * mouse move, button and wheel events passed to {@code xian_web_engine_view_send_input_events} are in host
+     * coordinates, so a view textured onto an in-world 3D quad can take raw screen or ray hit
+     * coordinates. Relative mouse deltas and keys are not affected; points on the homography's
+     * vanishing line are sent off the view at {@code (-1, -1)}.
+     *
+     * {@code matrix = NULL} clears the transform (identity). Returns {@code false} (leaving the transform unchanged)
+     * if {@code view} is NULL or a matrix entry is not finite.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_VIEW_INPUT_TRANSFORM_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Synthesizes a left click at {@code (x, y)} (logical pixels): a mouse move onto the point, then a button
+     * press and release, fed on the Servo thread with human-like delays so pages see the same event
+     * order as from a real user. Clicks and typed text are queued one after another.
+     *
+     * Meant for integration tests and macros; synthesized input is not recorded. Returns {@code false} if
+     * {@code view} is NULL, a coordinate is not finite, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CLICK_AT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_FLOAT, ValueLayout.JAVA_FLOAT);
+
+    /**
+     * Synthesizes typing the NUL-terminated UTF-8 {@code text}: one key press and release per character, fed
+     * on the Servo thread with human-like delays into the focused element. {@code '\n'} and {@code '\t'} press
+     * Enter and Tab; other control characters are skipped. Clicks and typed text are queued one after
+     * another.
+     *
+     * Meant for integration tests and macros; synthesized input is not recorded. Returns {@code false} if an
+     * argument is NULL/invalid, {@code text} has nothing to type, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_TYPE_TEXT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Installs the process-wide log callback ({@code NULL} removes it).
+     *
+     * Messages at {@code max_level} or less verbose ({@code XIAN_WEB_ENGINE_LOG_LEVEL_*}) are forwarded. Rejected
+     * FFI arguments are reported at {@code ERROR} with the function and argument name; debug builds of the
+     * library additionally report every FFI call with its arguments at {@code TRACE}. The callback may run on
+     * any thread and may still be running briefly after it is replaced.
+     *
+     * Returns {@code false} if {@code max_level} is not a defined level.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_LOG_CALLBACK_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates {@code count} hidden, parked views ahead of time so opening a UI screen does not pay the
+     * WebView + GL resource creation cost.
+     *
+     * Blocks until the views are created. Returns the number of views added to the pool.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_PREWARM_VIEWS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Takes one prewarmed view, resizes it to {@code width x height} (0 keeps its size), and activates it.
+     *
+     * The view keeps the {@code target_fps}/{@code view_flags} it was prewarmed with. Returns NULL when the pool is
+     * empty; destroy the result with {@code xian_web_engine_view_destroy} as usual.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_TAKE_PREWARMED_VIEW_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Starts recording every input event, resize and navigation delivered to {@code view} through this API
+     * ({@code xian_web_engine_view_send_input_events}, {@code xian_web_engine_view_resize},
+     * {@code xian_web_engine_view_load_url}, ...) into {@code path}, with timestamps. A recording already running on
+     * the view is finished and replaced.
+     *
+     * Input is recorded as accepted by the view (events dropped by a full queue are not recorded).
+     * Returns {@code false} if an argument is NULL/invalid or the file cannot be created.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_START_RECORDING_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Stops recording {@code view} and flushes the recording file (destroying the view also closes it).
+     *
+     * Returns {@code false} if {@code view} is NULL, nothing was being recorded, or the file could not be written.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_STOP_RECORDING_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Replays a recording made with {@code xian_web_engine_view_start_recording} into {@code view}, feeding every
+     * action back with its recorded timing, measured from when the Servo thread picks up the request.
+     * Use a freshly created view (and engine) of the recorded size for a faithful reproduction.
+     *
+     * A {@code XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED} event is queued after the last action. Starting
+     * another replay on the view replaces the running one. Replayed actions are not recorded again, and
+     * input is dropped while the view is inactive, as it is for live input.
+     *
+     * Returns {@code false} if an argument is NULL/invalid, the file cannot be read or is not a recording, or
+     * the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_REPLAY_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Starts streaming {@code view}'s frames on {@code port}: open {@code http://<host>:<port>/} in a browser to watch
+     * the view live. A screencast already running on the view is replaced (use a different port, or
+     * stop it first).
+     *
+     * Frames are captured through the PNG export readback path while the view is active and not
+     * frozen, at most {@code max_fps} times per second ({@code 0} = 10; capped at 60), and sent as a
+     * {@code multipart/x-mixed-replace} stream of PNG images to at most 4 clients. The server listens on
+     * every interface without authentication, so only use it in development builds; builds with the
+     * {@code screencast} feature support it ({@code XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST}).
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code port} is {@code 0}, the port cannot be bound, screencasting is not
+     * built in, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_START_SCREENCAST_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_SHORT, ValueLayout.JAVA_INT);
+
+    /**
+     * Stops {@code view}'s screencast and closes its server (destroying the view also stops it).
+     *
+     * Returns {@code false} if {@code view} is NULL, screencasting is not built in, or the engine is shutting
+     * down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_STOP_SCREENCAST_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Updates the view's device orientation and, optionally, its acceleration.
+     *
+     * - {@code orientation}: 4 floats, the rotation quaternion {@code [x, y, z, w]} from the Earth frame
+     *   (X east, Y north, Z up) to the device frame (X right, Y up, Z out of the screen). It is
+     *   normalized and reported as {@code deviceorientation} {@code alpha}/{@code beta}/{@code gamma} in degrees.
+     * - {@code acceleration}: 3 floats {@code [x, y, z]} in m/s² including gravity, in the device frame, reported
+     *   as {@code devicemotion} {@code accelerationIncludingGravity}; NULL fires only {@code deviceorientation}.
+     *
+     * Call it once per host frame while the effect is wanted. Returns {@code false} if {@code view}/{@code orientation}
+     * is NULL, a value is not finite, the quaternion is zero, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_UPDATE_SENSORS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Copies the URL of the view's current page into {@code buf} and returns its length (see the module
+     * docs). Empty until the first navigation commits.
+     *
+     * Returns {@code 0} if an argument is NULL/invalid.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_URL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Copies the title of the view's current page into {@code buf} and returns its length (see the module
+     * docs). Empty if the page has no title.
+     *
+     * Returns {@code 0} if an argument is NULL/invalid.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_TITLE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Copies the message of the view's most recent failure (invalid URL, failed export, page crash)
+     * into {@code buf} and returns its length (see the module docs). Empty if nothing has failed yet; the
+     * message is kept until the next failure.
+     *
+     * Returns {@code 0} if an argument is NULL/invalid.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_LAST_ERROR_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates one view.
+     *
+     * {@code target_fps = 0} means the view is driven by external vsync ({@code xian_web_engine_tick}).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CREATE_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates one view in a storage group ({@code XIAN_WEB_ENGINE_STORAGE_GROUP_*} or any embedder-chosen ID):
+     * views created with the same group share cookies, {@code localStorage}, {@code sessionStorage}, IndexedDB and
+     * the HTTP cache per origin (e.g. all pages of the mod's own site), views of other groups cannot
+     * see them, and {@code XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL} keeps the storage in memory only
+     * (e.g. untrusted server content).
+     *
+     * Every group runs its own Servo instance (thread, GL context and storage), started when the
+     * group's first view is created; that call blocks like engine creation.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CREATE_IN_STORAGE_GROUP_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates one view without blocking: returns a provisional handle right away and reports through
+     * {@code callback} once the Servo thread has created the view, so the calling (render) thread never
+     * stalls on the Servo thread the way {@code xian_web_engine_view_create} can (for up to the engine's
+     * configured {@code view_timeout_ms}, or indefinitely under
+     * {@code XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER}).
+     *
+     * The handle is usable immediately. Input, resizes and {@code load_url} are queued as usual; other
+     * requests are held back and applied once the view exists; closing or destroying it before then
+     * destroys the view as soon as it is created. No frames are published and
+     * {@code xian_web_engine_view_is_alive} returns {@code false} until creation has finished. If creation fails,
+     * requests through the handle fail; destroy it as usual.
+     *
+     * {@code callback} may be NULL. Returns NULL (and never invokes {@code callback}) if an argument is invalid or
+     * the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CREATE_VIEW_ASYNC_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Destroys a view created by {@code xian_web_engine_view_create} and frees the handle.
+     *
+     * Release acquired frames before destroying the view, and do not sample any textures from this
+     * view afterwards. GL deletion of the slot textures is deferred until every release fence has
+     * signaled, so GPU work already submitted against them stays valid; frames still held at
+     * destruction keep their textures alive for at most one more second.
+     *
+     * If the view was already closed with {@code xian_web_engine_close_view}, this only frees the handle.
+     * {@code view} must not be used after this call.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_DESTROY_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS);
+
+    /**
+     * Closes a view: the page is torn down on the Servo thread exactly as by
+     * {@code xian_web_engine_view_destroy}, but the handle stays valid until {@code xian_web_engine_view_destroy}
+     * frees it.
+     *
+     * This decouples the view's lifetime from the handle's, for GC-based hosts: close the view
+     * deterministically (e.g. when its screen closes) and let the object that owns the pointer free
+     * the handle whenever it is collected. Other references may keep calling through the handle in
+     * the meantime; requests then fail or are ignored, no frames are acquired, and
+     * {@code xian_web_engine_view_is_alive} returns {@code false}. The same rules as for destroying apply to held
+     * frames and textures.
+     *
+     * Returns {@code false} if {@code view} is NULL or already closed.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CLOSE_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Returns whether the view behind {@code view} still exists: it was neither closed with
+     * {@code xian_web_engine_close_view} nor lost to engine shutdown. Commands to a view are matched by its
+     * ID and a per-view token, so a view ID reused after close never makes a stale handle look alive.
+     *
+     * Safe to call from any thread for as long as the handle has not been destroyed. Returns {@code false} if
+     * {@code view} is NULL, or if it is a handle-table ID that was already destroyed.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_IS_ALIVE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Sets whether the view is active (active views render and accept input).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_ACTIVE_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_BYTE);
+
+    /**
+     * Requests navigation to the given URL.
+     *
+     * The URL must be a NUL-terminated UTF-8 string.
+     *
+     * Return value:
+     * - {@code false} if {@code view}/{@code url} is NULL or the string is not valid UTF-8.
+     * - {@code true} otherwise (the request is recorded and coalesced; URL parsing happens on the Servo thread).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_LOAD_URL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Requests a resize (in pixels).
+     *
+     * This call is coalesced: only the latest size is kept until the Servo thread drains it (and, with
+     * a resize debounce set, until resizes stop for the debounce period).
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RESIZE_DESC = FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Sets the view's resize debounce: resizes are held back until none arrived for {@code debounce_ms}, then
+     * only the latest size is applied, avoiding a slot texture reallocation per step while a panel is
+     * dragged. Until then frames keep the previous size ({@code XianWebEngineFrame} reports it), so the host
+     * draws them stretched. {@code 0} (default) applies every resize immediately.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_RESIZE_DEBOUNCE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Resizes the view (in pixels) immediately, bypassing the resize debounce (e.g. when a drag ends);
+     * a resize held back by the debounce is dropped.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RESIZE_IMMEDIATE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Checks per-view sandbox restrictions ({@code XIAN_WEB_ENGINE_SANDBOX_*} bits; {@code 0} means none).
+     *
+     * Servo applies sandboxing to nested browsing contexts ({@code <iframe sandbox>}), not to the top-level
+     * document of a WebView, so only restrictions the engine enforces itself are accepted:
+     * {@code XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS} always holds, because the engine never opens popups or
+     * auxiliary windows. The other restrictions cannot be enforced on the page the view loads and are
+     * rejected instead of being silently ignored; show untrusted HTML (e.g. server MOTD) inside an
+     * {@code <iframe sandbox>} of a page the embedder controls.
+     *
+     * Returns {@code false} if {@code view} is NULL or {@code sandbox_flags} contains unknown bits or a restriction that
+     * cannot be enforced.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_SANDBOX_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Requests an asynchronous export of the current page ({@code XIAN_WEB_ENGINE_EXPORT_FORMAT_*}).
+     *
+     * The capture is taken on the Servo thread and encoded/written on a helper thread; completion is
+     * reported through {@code xian_web_engine_view_poll_events} as an {@code EXPORT_COMPLETE} event carrying the
+     * returned request ID. Servo has no print pipeline yet, so the whole document is captured by
+     * scrolling it viewport by viewport and stitching the screenshots (fixed/sticky elements repeat
+     * per viewport, and the original scroll offset is restored); PDF output paginates that image into
+     * A4-proportioned pages.
+     *
+     * Returns the request ID, or {@code 0} if an argument is invalid or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_EXPORT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Captures the first element matching {@code css_selector} (NUL-terminated UTF-8), so hosts can
+     * rasterize individual widgets (e.g. item tooltips) into their own atlases.
+     *
+     * The element's bounding rectangle is resolved on the Servo thread and clipped to the viewport,
+     * then only that region of the back slot is read back once the pending frame has been painted.
+     * Parts of the element outside the viewport are not captured, and whatever is painted over it
+     * (overlapping elements, the default background) is captured with it. An invalid selector, no
+     * match, or an element entirely outside the viewport fail.
+     *
+     * {@code callback} is invoked exactly once if this returns {@code true}, and never if it returns {@code false}
+     * because an argument is invalid. Returns {@code false} (after invoking {@code callback} with
+     * {@code XIAN_WEB_ENGINE_STATUS_FAILED}) if the view was closed, the command queue is full or the engine
+     * is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CAPTURE_ELEMENT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Requests an asynchronous snapshot of the page's accessibility tree.
+     *
+     * The result is reported through {@code xian_web_engine_view_poll_events} as an {@code ACCESSIBILITY_TREE}
+     * event whose text is a JSON document (roles, accessible names, and bounds in CSS pixels relative to
+     * the viewport). Hosts can use it for controller/keyboard navigation or screen-reader integration.
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_REQUEST_ACCESSIBILITY_TREE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Pauses every playing {@code <audio>}/{@code <video>} element of the page (including same-origin iframes).
+     *
+     * Completion is reported as a {@code MEDIA_STATE} event carrying the returned request ID. Media session
+     * playback changes are reported separately as {@code MEDIA_PLAYBACK} events.
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_PAUSE_ALL_MEDIA_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Resumes the media elements paused by {@code xian_web_engine_view_pause_all_media}.
+     *
+     * Media the page paused on its own stays paused. Completion is reported as a {@code MEDIA_STATE} event.
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RESUME_ALL_MEDIA_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Requests an opaque session blob (history entries, current URL, scroll offset) for this view.
+     *
+     * The blob is reported as the text of a {@code SESSION_SAVED} event carrying the returned request ID and can
+     * be passed to {@code xian_web_engine_view_create_from_session} later (e.g. when a world is reloaded).
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SAVE_SESSION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Selects the contents of the focused text field, or the whole document if no text field has focus.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SELECT_ALL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Requests the page's selected text.
+     *
+     * The text is reported as a {@code SELECTED_TEXT} event carrying the returned request ID (empty if nothing
+     * is selected). Selections inside password fields are never reported.
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_REQUEST_SELECTED_TEXT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Copies the page's selection, e.g. for a host-drawn copy button over a read-only view.
+     *
+     * The page's {@code copy} handlers run first; the selected text is then reported as a {@code COPY} event
+     * carrying the returned request ID. The engine does not touch the system clipboard: the embedder
+     * places the text there itself (e.g. with {@code glfwSetClipboardString}).
+     *
+     * Returns the request ID, or {@code 0} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_COPY_SELECTION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_LONG, ValueLayout.ADDRESS);
+
+    /**
+     * Enables or disables capturing the page's {@code console.*} output (disabled by default).
+     *
+     * While enabled, every console call is reported as a {@code CONSOLE_MESSAGE} event with its level, source
+     * URL and line, so page errors can be forwarded to the game log without attaching devtools. Calls
+     * made while capture is disabled are not reported retroactively.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_CONSOLE_CAPTURE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Creates one view and restores a session blob from a {@code SESSION_SAVED} event.
+     *
+     * {@code session} must be a NUL-terminated UTF-8 string. The back/forward history is rebuilt by loading
+     * the saved entries in order and going back to the saved current entry, whose scroll offset is
+     * re-applied after it has loaded.
+     *
+     * Returns NULL if an argument is invalid, the blob is malformed, or view creation fails.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_CREATE_FROM_SESSION_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Creates a view showing the same page as {@code view}, e.g. to split a page into a second panel.
+     *
+     * This is the {@code xian_web_engine_view_create_from_session} path without the {@code SESSION_SAVED} round
+     * trip: the source's current URL and scroll offset are taken from its published snapshots, the
+     * page is loaded again (from Servo's HTTP cache where it can be) and the scroll offset re-applied
+     * after loading. Script state, form input and back/forward entries are not copied, and the clone
+     * does not join the source's view group. {@code view_flags} apply to the clone only.
+     *
+     * Returns NULL if an argument is invalid, the source has no document yet, or view creation fails.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_CLONE_VIEW_DESC = FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Sets the default page background as {@code 0xRRGGBBAA} (e.g. {@code 0x00000000} for a transparent HUD).
+     *
+     * The color applies from the next navigation and only where the page does not set its own
+     * background.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_BACKGROUND_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Renders the view at a different internal resolution (dynamic resolution scaling) while the page
+     * keeps the same CSS viewport size.
+     *
+     * {@code scale < 1.0} trades sharpness for frame rate on heavy pages; {@code scale > 1.0} supersamples. The value
+     * is clamped to {@code 0.25..=2.0}. Input coordinates and {@code xian_web_engine_view_resize} stay in logical
+     * pixels; acquired frames report the scaled texture size, so sample them over the view's logical
+     * quad.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code scale} is not a positive finite number, or the engine is
+     * shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_RENDER_SCALE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_FLOAT);
+
+    /**
+     * Decouples the page's CSS layout size from the view's texture size, like a mobile viewport meta
+     * tag: the page lays out {@code css_width} x {@code css_height} CSS px and is scaled to fill the texture, so a
+     * 4K texture can render a fixed 1280 px wide layout and physical resizes only rescale it instead
+     * of reflowing the page.
+     *
+     * A {@code 0} dimension follows the view size on that axis ({@code css_width = 1280, css_height = 0} keeps the
+     * width fixed and the aspect ratio of the view). When both are set and the aspect ratios differ,
+     * the page gets at least the requested size on both axes. {@code (0, 0)} removes the override. Input
+     * coordinates stay in view pixels and are mapped onto the scaled page.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_VIEW_VIEWPORT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Moves the view to a new size and render configuration without reloading the page, e.g. when the
+     * host switches between a windowed panel and a fullscreen browser.
+     *
+     * {@code new_flags} may change {@code XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X}, {@code XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS}
+     * and the color format; {@code UNSAFE_NO_CONSUMER_FENCE}, {@code INPUT_SINGLE_PRODUCER},
+     * {@code UNSAFE_NO_PRODUCER_FENCE}, {@code VULKAN_EXPORT} and {@code ORPHANING_RESIZE} are fixed at creation and must
+     * match. The render
+     * scale is reset to the one implied by {@code SUPERSAMPLE_2X}. Slot textures are reallocated in place, so
+     * frames acquired after the move report the new size and color format; a frame still held keeps
+     * the old one until it is released. If the shared context cannot provide the new color format, the
+     * old one is kept and a warning is logged.
+     *
+     * Returns {@code false} if {@code view} is NULL, a fixed flag differs, the color format is unknown, or the
+     * engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_MOVE_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Freezes the view to reclaim GPU memory: its three slot textures are shrunk to 1x1 and the WebView
+     * is hidden and throttled, while the page, its scripts and its state stay alive.
+     *
+     * While frozen, {@code xian_web_engine_views_acquire_frames} acquires nothing for the view. Release held frames before
+     * freezing; a slot still held keeps its texture until the view is thawed. Resizes and moves are
+     * recorded and applied on thaw.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_FREEZE_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Thaws a view frozen by {@code xian_web_engine_freeze_view}: the slot textures are recreated at the
+     * current size and an active view repaints right away, so the next acquire gets a fresh frame.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_THAW_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Enters ({@code enabled = true}) or leaves snapshot mode, for static panels that only change on demand.
+     *
+     * In snapshot mode the page stops running (the WebView is hidden and throttled and its timers are
+     * suspended) and nothing is painted, but the last frame stays acquirable indefinitely: it is
+     * pinned so it is never reclaimed, and releasing it makes it READY again, so
+     * {@code xian_web_engine_views_acquire_frames} keeps returning it every time. Release it with a consumer
+     * fence of {@code 0} to reacquire it immediately; with a fence it becomes acquirable again once the
+     * engine sees the fence signaled. Use {@code xian_web_engine_view_refresh_snapshot} to render a fresh
+     * frame. Leaving snapshot mode resumes the page and repaints.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_SNAPSHOT_MODE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Renders a fresh frame of a view in snapshot mode: the page runs for about 100 ms (timers,
+     * animation frames and layout catch up), then its last frame is pinned in place of the previous
+     * one. Ignored if the view is not in snapshot mode.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_REFRESH_SNAPSHOT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Pauses the view's refresh driver for debugging: CSS animations, transitions and
+     * {@code requestAnimationFrame} callbacks stop advancing until {@code xian_web_engine_debug_step_frame} lets a
+     * frame through, so a misbehaving animation can be inspected frame by frame (e.g. with captures).
+     * Input, navigation and repaints not driven by the refresh driver keep working.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DEBUG_PAUSE_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Resumes a view paused with {@code xian_web_engine_debug_pause_view}; animations continue from the
+     * frame they were held at.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DEBUG_RESUME_VIEW_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Advances a view paused with {@code xian_web_engine_debug_pause_view} by one frame, on the next tick of
+     * its refresh driver (vsync, fixed interval or group). Ignored if the view is not paused or its
+     * animation clock is paused ({@code xian_web_engine_set_view_time_paused}).
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_DEBUG_STEP_FRAME_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Overrides the view's output colorspace, so a host doing its own gamma-correct compositing does
+     * not apply gamma twice:
+     *
+     * - {@code XIAN_WEB_ENGINE_COLORSPACE_AUTO} (default): follow the color format (sRGB-encode only for
+     *   {@code XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8}).
+     * - {@code XIAN_WEB_ENGINE_COLORSPACE_SRGB}: sRGB-encode writes (requires an {@code SRGB8_A8} view).
+     * - {@code XIAN_WEB_ENGINE_COLORSPACE_LINEAR}: write values as-is.
+     *
+     * Applies from the next frame; {@code XianWebEngineFrame.colorspace} reports the colorspace of each frame.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code colorspace} is unknown or unsupported by the view's color
+     * format, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_COLORSPACE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Sets the {@code prefers-color-scheme} media feature the view's pages see, so they follow the game's
+     * theme:
+     *
+     * - {@code XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT} (default): {@code light}.
+     * - {@code XIAN_WEB_ENGINE_COLOR_SCHEME_DARK}: {@code dark}.
+     *
+     * Applies immediately: CSS {@code @media} rules and {@code matchMedia} lists (with their {@code change} events)
+     * follow, and the setting persists across navigations.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code scheme} is unknown, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_COLOR_SCHEME_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Sets whether the view's pages see {@code prefers-reduced-motion: reduce} (default: {@code no-preference}),
+     * mirroring the game's accessibility setting.
+     *
+     * Servo does not evaluate this media feature in CSS, so the engine answers it in {@code matchMedia}
+     * queries (firing {@code change} on lists whose result flips) and, while on, also disables CSS
+     * animations, transitions and smooth scrolling. Applies to the current page immediately and
+     * persists across navigations.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_REDUCED_MOTION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Sets whether the view's pages see {@code forced-colors: active} (default: {@code none}), mirroring the
+     * game's high-contrast setting.
+     *
+     * Servo has no forced-colors mode, so the engine only answers the media feature in {@code matchMedia}
+     * queries (firing {@code change} on lists whose result flips); pages that restyle themselves from script
+     * adapt, CSS {@code @media (forced-colors)} rules do not. Applies to the current page immediately and
+     * persists across navigations.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_FORCED_COLORS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Pauses or resumes the view's animation clock, so in-world UI panels visually pause while the
+     * game is paused.
+     *
+     * While paused, the view's refresh driver holds the frame callback Servo requests, so
+     * {@code requestAnimationFrame} callbacks, CSS animations and transitions stop advancing, and
+     * {@code performance.now()} stands still. On resume the held callback runs on the next frame and the
+     * page's timeline is offset by the time spent paused: {@code performance.now()} and
+     * {@code requestAnimationFrame} timestamps continue exactly where they stopped, and CSS animations
+     * (held with {@code animation-play-state: paused}) resume from the same point. CSS transitions follow
+     * Servo's own clock and jump to where they would be had they kept running; {@code Date} and timers are
+     * not affected. Combines with the debug pause ({@code xian_web_engine_debug_pause_view}): frames flow
+     * again once neither holds them. Applies immediately and persists across navigations.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_VIEW_TIME_PAUSED_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Sets how the view behaves while inactive (see {@code xian_web_engine_view_set_active}):
+     *
+     * - {@code XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL} (default): hidden, timers and animation frames keep
+     *   running.
+     * - {@code XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ}: hidden and throttled, native timers are
+     *   cancelled and due callbacks run at most once per second.
+     * - {@code XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND}: hidden and throttled, native timers are cancelled
+     *   and re-armed with their remaining delay once the view becomes active again.
+     *
+     * A cleared timer or cancelled animation frame never runs afterwards, and a throttled view holds
+     * at most one pending run per live timer or frame request.
+     *
+     * Takes effect immediately if the view is already inactive.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code policy} is unknown, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_BACKGROUND_POLICY_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Sets how the engine reacts when a script blocks the view's page for longer than {@code timeout_ms}
+     * (a misbehaving page would otherwise stall every view sharing its script event loop):
+     *
+     * - {@code XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE} (default): no detection.
+     * - {@code XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY}: emit a {@code XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT} event.
+     * - {@code XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP}: unload the page ({@code about:blank}) and emit the event.
+     *
+     * Detection pings the page every {@code timeout_ms}, so a stall is reported between {@code timeout_ms} and
+     * {@code 2 * timeout_ms} after it starts. Servo gives embedders no way to interrupt a running script, so
+     * a stop only takes effect if the page can still be navigated away from. The event's {@code value1} is
+     * {@code 1} only once the view answers again, which is waited for up to another {@code timeout_ms}; a script
+     * that keeps the page from unloading is reported with {@code value1 = 0}.
+     *
+     * Returns {@code false} if {@code view} is NULL, {@code policy} is unknown, {@code timeout_ms} is {@code 0} for an enabled policy,
+     * or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_SLOW_SCRIPT_POLICY_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Injects a user stylesheet (NUL-terminated UTF-8 CSS) into every subsequent load of this view.
+     *
+     * Use it to theme pages (dark mode, custom fonts) without modifying their content. Stylesheets
+     * accumulate; each call adds one more.
+     *
+     * Returns {@code false} if {@code view}/{@code css} is NULL, the string is not valid UTF-8, or the engine is shutting
+     * down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_INJECT_USER_STYLESHEET_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Registers a user script (NUL-terminated UTF-8 JavaScript) run automatically on every subsequent
+     * navigation of this view, like a WebExtension content script.
+     *
+     * - {@code injection_time}: {@code XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START} runs before page scripts;
+     *   {@code XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END} runs once the DOM is parsed (the source is wrapped
+     *   in a function, so top-level declarations stay local; assign to {@code window} to export them).
+     * - {@code world}: must be {@code XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN} (Servo has no isolated worlds), so
+     *   globals the script defines (e.g. {@code window.mc}) are visible to the page.
+     *
+     * Returns {@code false} if an argument is invalid/unsupported or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_ADD_USER_SCRIPT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.JAVA_INT);
+
+    /**
+     * Answers a permission request (geolocation, notifications, camera, ...) reported by a
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST} event.
+     *
+     * Requests not answered within the event's {@code value1} milliseconds are denied automatically, as are
+     * requests still pending when the view is destroyed.
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down; unknown or expired IDs are
+     * ignored.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_RESPOND_PERMISSION_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Releases pointer lock held by the page, e.g. when the player presses Escape or the game opens a
+     * menu.
+     *
+     * Pointer lock is requested by the page ({@code element.requestPointerLock()}) during transient user
+     * activation, asked through a {@code XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK} permission request, and
+     * reported as a {@code XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK} event once granted; while locked, send
+     * mouse motion as {@code XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE} deltas. Leaving the lock is reported
+     * with the same event ({@code value0 = 0}).
+     *
+     * Returns {@code false} if {@code view} is NULL or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_EXIT_POINTER_LOCK_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Answers a {@code XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER} event with {@code count} file paths (NUL-terminated
+     * UTF-8). {@code count = 0} cancels the chooser; single-file inputs use only the first path.
+     *
+     * Returns {@code false} if an argument is invalid or the engine is shutting down; unknown IDs are ignored.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_PROVIDE_FILES_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.JAVA_INT);
+
+    /**
+     * Answers a {@code XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER} event with an in-memory file of {@code len} bytes named
+     * {@code name} (NUL-terminated UTF-8), e.g. a screenshot the game just took.
+     *
+     * The data is written to a file under the system temp directory before being selected.
+     *
+     * Returns {@code false} if an argument is invalid, the file cannot be written, or the engine is shutting
+     * down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_PROVIDE_FILE_DATA_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG);
+
+    /**
+     * Writes the page scroll offset (CSS px) into {@code out_x} / {@code out_y}.
+     *
+     * The value is a snapshot the page reports whenever it scrolls, so this never blocks on the Servo
+     * thread; it may lag a scroll still in flight. It survives hiding the view, so hosts can save it
+     * before {@code xian_web_engine_view_set_active(view, 0)} and restore it with
+     * {@code xian_web_engine_view_set_scroll} later.
+     *
+     * Returns {@code false} if {@code view}, {@code out_x} or {@code out_y} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_SCROLL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Returns the {@code XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*} of the view's focused editable element, and
+     * writes its rectangle {@code [x, y, width, height]} in view pixels into {@code out_rect} (4 entries; may be
+     * NULL) so the host can place an on-screen keyboard or its IME composition window next to it.
+     *
+     * Like the scroll offset, this is a snapshot updated whenever focus moves (announced by
+     * {@code XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT}), so it never blocks on the Servo thread. Coordinates
+     * are saturated to 16 bits. Returns {@code XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE} (with an all-zero
+     * rectangle) if no editable element has focus or {@code view} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_TEXT_INPUT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS, ValueLayout.ADDRESS);
+
+    /**
+     * Scrolls the page to {@code (x, y)} in CSS px; {@code smooth} animates the scroll instead of jumping.
+     *
+     * Returns {@code false} if {@code view} is NULL, a coordinate is not finite, or the engine is shutting down.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_SET_SCROLL_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_FLOAT, ValueLayout.JAVA_FLOAT, ValueLayout.JAVA_BOOLEAN);
+
+    /**
+     * Returns whether the view has network activity (the main document is loading or HTTP(S)
+     * subresource requests are in flight), e.g. to draw a loading spinner.
+     *
+     * This reads a counter the engine keeps per view (requests are counted when Servo's networking
+     * delegate lets them through and until they complete or fail), so it is cheap enough to call
+     * every frame and needs no event subscription. Returns {@code false} if {@code view} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_IS_LOADING_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Returns the number of in-flight network requests of the view (HTTP(S) subresource requests,
+     * plus one while the main document is loading).
+     *
+     * Returns {@code 0} if {@code view} is NULL.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_IN_FLIGHT_REQUESTS_DESC = FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+
+    /**
+     * Installs the embedder's Vulkan device (once per process).
+     *
+     * Views created with {@code XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT} allocate their slot images on this
+     * device. It must be the GPU the GL context runs on, and must have {@code VK_KHR_external_memory_fd} +
+     * {@code VK_KHR_external_semaphore_fd} (Linux) or {@code VK_KHR_external_memory_win32} +
+     * {@code VK_KHR_external_semaphore_win32} (Windows) enabled. The device must outlive the engine.
+     *
+     * Returns {@code true} on success.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_SET_VULKAN_DEVICE_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS);
+
+    /**
+     * Writes the Vulkan import description of {@code slot} into {@code out_slot}.
+     *
+     * Consumer contract for views created with {@code XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT}:
+     *
+     * - Import the memory/semaphores once per {@code generation} (re-query after acquire when
+     *   {@code XianWebEngineFrame.texture_id} changes). Handles stay owned by the engine; duplicate them
+     *   before an import call that takes ownership.
+     * - After acquiring a frame, wait on {@code ready_semaphore} before sampling the image, which is in
+     *   {@code SHADER_READ_ONLY_OPTIMAL} layout (acquire it from the external queue family).
+     * - When done, leave the image in {@code SHADER_READ_ONLY_OPTIMAL}, submit a signal of
+     *   {@code release_semaphore}, then release the frame with {@code XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE} as
+     *   its consumer fence. Every acquired frame must be released this way.
+     *
+     * Returns {@code false} if the view has no Vulkan export or the slot has no image.
+     */
+    public static final FunctionDescriptor XIAN_WEB_ENGINE_VIEW_GET_VULKAN_SLOT_DESC = FunctionDescriptor.of(ValueLayout.JAVA_BOOLEAN, ValueLayout.ADDRESS, ValueLayout.JAVA_INT, ValueLayout.ADDRESS);
+}
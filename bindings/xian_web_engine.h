@@ -0,0 +1,3263 @@
+/*
+ * Generated by `cargo xtask bindings` from the Rust FFI surface. Do not edit.
+ */
+
+#ifndef XIAN_WEB_ENGINE_H
+#define XIAN_WEB_ENGINE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Frames carry producer fences (`XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE` opts out).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_PRODUCER_FENCE 1ull
+/**
+ * Released frames accept consumer fences.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_CONSUMER_FENCE 2ull
+/**
+ * `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8` slot textures and colorspace overrides (still subject to
+ * driver support at view creation).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SRGB 4ull
+/**
+ * `XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F` slot textures.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_HALF_FLOAT 8ull
+/**
+ * Transparent views (`xian_web_engine_view_set_background` honors alpha).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_TRANSPARENT_VIEWS 16ull
+/**
+ * `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X` and `XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SUPERSAMPLE_MIPMAPS 32ull
+/**
+ * Views can be sharded across several Servo threads (`servo_threads`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SERVO_SHARDS 64ull
+/**
+ * Slow-script detection (`xian_web_engine_view_set_slow_script_policy`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SLOW_SCRIPT 128ull
+/**
+ * The library can create its shared GL context on this platform (Windows, Linux and macOS for now).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SHARED_CONTEXT 256ull
+/**
+ * Thread priority / CPU affinity controls take effect (Windows only for now).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_THREAD_SCHEDULING 512ull
+/**
+ * Log callback (`xian_web_engine_set_log_callback`) with FFI argument validation reports.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_LOG_CALLBACK 1024ull
+/**
+ * View string getters (`xian_web_engine_view_get_url`/`_get_title`/`_get_last_error`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VIEW_STRINGS 2048ull
+/**
+ * View compositor (`xian_web_engine_composite_views`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_COMPOSITOR 4096ull
+/**
+ * Frame-available callback (`xian_web_engine_set_frame_callback`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_FRAME_CALLBACK 8192ull
+/**
+ * Vulkan external-memory frames (`XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`); the GL driver must
+ * additionally expose `GL_EXT_memory_object` / `GL_EXT_semaphore`.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VULKAN_EXPORT 16384ull
+/**
+ * EGL shared contexts for GLES-only hosts (`XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` with
+ * `xian_web_engine_create_with_flags`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_EGL 32768ull
+/**
+ * Main-thread trampoline for GLFW window calls (`xian_web_engine_set_main_thread_trampoline`),
+ * required for the shared context on macOS.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_MAIN_THREAD_TRAMPOLINE 65536ull
+/**
+ * GL debug output forwarded to the log callback (`XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG`); the
+ * driver must additionally expose `GL_KHR_debug` (or GL 4.3 / GLES 3.2).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_GL_DEBUG 131072ull
+/**
+ * Paranoid GL mode (`XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL`) with GL errors reported in
+ * `XianWebEngineHealth`.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_PARANOID_GL 262144ull
+/**
+ * Device sensor bridge (`xian_web_engine_view_update_sensors` → `deviceorientation` /
+ * `devicemotion`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SENSORS 524288ull
+/**
+ * Page scroll snapshot and restore (`xian_web_engine_view_get_scroll` /
+ * `xian_web_engine_view_set_scroll`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SCROLL 1048576ull
+/**
+ * Text selection and copy (`xian_web_engine_view_select_all`,
+ * `xian_web_engine_view_request_selected_text`, `xian_web_engine_view_copy_selection`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SELECTION 2097152ull
+/**
+ * Page console capture (`xian_web_engine_view_set_console_capture` → `CONSOLE_MESSAGE` events).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_CONSOLE_CAPTURE 4194304ull
+/**
+ * `PAGE_ERROR` / `RESOURCE_ERROR` events for uncaught exceptions and failed subresource loads.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_PAGE_ERRORS 8388608ull
+/**
+ * Per-view network activity counter (`xian_web_engine_view_is_loading` /
+ * `xian_web_engine_view_get_in_flight_requests`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_NETWORK_ACTIVITY 16777216ull
+/**
+ * Connection and bandwidth limits for HTTP(S) traffic (`xian_web_engine_set_network_limits`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_NETWORK_LIMITS 33554432ull
+/**
+ * Offline and cache-only network modes (`xian_web_engine_set_network_mode`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_NETWORK_MODE 67108864ull
+/**
+ * Embedder DNS resolver callback (`xian_web_engine_set_dns_resolver`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_DNS_RESOLVER 134217728ull
+/**
+ * Host functions callable from pages (`xian_web_engine_register_host_function`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_HOST_FUNCTIONS 268435456ull
+/**
+ * Binary messages between host and page (`xian_web_engine_view_post_binary`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_BINARY_CHANNEL 536870912ull
+/**
+ * Embedder GL textures readable by pages (`xian_web_engine_register_texture`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_TEXTURE_SHARING 1073741824ull
+/**
+ * Host texture frame streams into pages (`xian_web_engine_push_external_frame`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_EXTERNAL_FRAMES 2147483648ull
+/**
+ * Moving a view to another size/render configuration without reloading (`xian_web_engine_move_view`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VIEW_MOVE 4294967296ull
+/**
+ * Freezing views to reclaim GPU memory (`xian_web_engine_freeze_view` / `xian_web_engine_thaw_view`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VIEW_FREEZE 8589934592ull
+/**
+ * Chrome trace export of engine spans (`xian_web_engine_dump_trace`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_TRACE 17179869184ull
+/**
+ * Servo time profiler toggles (`xian_web_engine_set_profiling`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_PROFILING 34359738368ull
+/**
+ * Recording and timed replay of view input, resizes and navigations
+ * (`xian_web_engine_view_start_recording`, `xian_web_engine_view_replay`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_REPLAY 68719476736ull
+/**
+ * Golden-image rendering tests (`xian_web_engine_run_golden_tests`; builds with the `golden-tests`
+ * feature only).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS 137438953472ull
+/**
+ * Monotonic queue drop counters (`xian_web_engine_view_get_queue_stats`, and `commands_dropped` /
+ * `pending_overflows` in `XianWebEngineHealth`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_QUEUE_STATS 274877906944ull
+/**
+ * View liveness and explicit close (`xian_web_engine_view_is_alive`, `xian_web_engine_close_view`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VIEW_CLOSE 549755813888ull
+/**
+ * Generational view handle table (`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE 1099511627776ull
+/**
+ * Input synthesis for automated UI testing (`xian_web_engine_click_at`, `xian_web_engine_type_text`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_INPUT_SYNTHESIS 2199023255552ull
+/**
+ * Per-view refresh driver pause and frame stepping for debugging
+ * (`xian_web_engine_debug_pause_view`, `xian_web_engine_debug_step_frame`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_DEBUG_PAUSE 4398046511104ull
+/**
+ * Non-blocking view creation with a completion callback (`xian_web_engine_create_view_async`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_ASYNC_CREATE 8796093022208ull
+/**
+ * Engine initialization progress callback and cache preloading
+ * (`xian_web_engine_set_init_progress_callback`, `xian_web_engine_preload`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_INIT_PROGRESS 17592186044416ull
+/**
+ * Configurable timeouts of blocking calls (`xian_web_engine_create_with_config`,
+ * `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_ENGINE_CONFIG 35184372088832ull
+/**
+ * Storage isolation groups chosen at view creation (`xian_web_engine_view_create_in_storage_group`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_STORAGE_GROUPS 70368744177664ull
+/**
+ * Per-view homography applied to pointer input (`xian_web_engine_set_view_input_transform`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_INPUT_TRANSFORM 140737488355328ull
+/**
+ * Resize debouncing with an immediate override (`xian_web_engine_view_set_resize_debounce`,
+ * `xian_web_engine_view_resize_immediate`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_RESIZE_DEBOUNCE 281474976710656ull
+/**
+ * Producer fence statistics (`xian_web_engine_get_fence_stats`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_FENCE_STATS 562949953421312ull
+/**
+ * Orphaning resize (`XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_ORPHANING_RESIZE 1125899906842624ull
+/**
+ * Frame present timestamps (`XianWebEngineFrame.present_time_ns`,
+ * `xian_web_engine_monotonic_time_ns`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_FRAME_TIMESTAMPS 2251799813685248ull
+/**
+ * Host display timing for vsync-driven views (`xian_web_engine_set_vsync_info`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_VSYNC_INFO 4503599627370496ull
+/**
+ * Drift-free fixed-interval refresh with fractional rates
+ * (`xian_web_engine_view_group_create_fractional`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_FRACTIONAL_FPS 9007199254740992ull
+/**
+ * Engine idle mode (`xian_web_engine_set_idle`, `XIAN_WEB_ENGINE_IDLE_FLAG_*`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_ENGINE_IDLE 18014398509481984ull
+/**
+ * Memory pressure signal (`xian_web_engine_notify_memory_pressure`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_MEMORY_PRESSURE 36028797018963968ull
+/**
+ * Image size limits with downscaling (`xian_web_engine_set_image_limits`,
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_IMAGE_LIMITS 72057594037927936ull
+/**
+ * Process-wide page JavaScript heap limit (`xian_web_engine_set_heap_limit`,
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_HEAP_LIMIT 144115188075855872ull
+/**
+ * Out-of-process page content (`XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`,
+ * `xian_web_engine_run_content_process`); not available on Windows.
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS 288230376151711744ull
+/**
+ * WebDriver endpoint for automated tests (`xian_web_engine_enable_webdriver`; builds with the
+ * `webdriver` feature only).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER 576460752303423488ull
+/**
+ * Frame streaming over HTTP for remote debugging (`xian_web_engine_view_start_screencast`; builds
+ * with the `screencast` feature only).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST 1152921504606846976ull
+/**
+ * Per-view media feature overrides (`xian_web_engine_view_set_color_scheme`,
+ * `xian_web_engine_view_set_reduced_motion`, `xian_web_engine_view_set_forced_colors`).
+ */
+#define XIAN_WEB_ENGINE_CAPABILITY_MEDIA_FEATURES 2305843009213693952ull
+/**
+ * Events were dropped because the per-view queue was full; `value0` = number of dropped events since
+ * the previous poll (`XianWebEngineViewQueueStats::events_dropped` keeps the running total).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_DROPPED 1u
+/**
+ * A page export finished; `value0`/`value1` = exported width/height, text = output path.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE 2u
+/**
+ * An accessibility tree snapshot is ready; text = JSON document
+ * (`{"url", "devicePixelRatio", "root": {"role", "name", "bounds": [x, y, w, h], "children", ...}}`).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_ACCESSIBILITY_TREE 3u
+/**
+ * A pause/resume-all-media request finished; `value0` = media element count, `value1` = elements still
+ * playing.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_STATE 4u
+/**
+ * The page's media session playback state changed; `value0` = `1` playing, `0` paused, `-1` none.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_PLAYBACK 5u
+/**
+ * A session save finished; text = opaque session blob for `xian_web_engine_view_create_from_session`.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_SESSION_SAVED 6u
+/**
+ * The page requested a permission; `request_id` = ID to pass to
+ * `xian_web_engine_view_respond_permission`, `value0` = feature (`XIAN_WEB_ENGINE_PERMISSION_*`),
+ * `value1` = milliseconds until the request is denied automatically.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST 7u
+/**
+ * The page entered (`value0 = 1`) or left (`value0 = 0`) pointer lock; while locked the embedder
+ * should capture the cursor and send `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` events.
+ *
+ * Entering requires transient user activation and a granted `PERMISSION_REQUEST` with feature
+ * `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK`.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK 8u
+/**
+ * A host drag ended with a drop; text = drop effect chosen by the page (`"none"` if the page did not
+ * accept the data).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE 9u
+/**
+ * The page opened a file chooser (`<input type=file>`); `request_id` = ID to pass to
+ * `xian_web_engine_view_provide_files`, `value0` = `1` if several files may be selected,
+ * text = comma-separated accept filters (empty if any file is accepted).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER 10u
+/**
+ * A script blocked the page longer than the slow-script timeout; `value0` = milliseconds the page
+ * has been unresponsive, `value1` = `1` if the page was unloaded and the view answers again
+ * (`SLOW_SCRIPT_POLICY_STOP`), `0` if the script is still running.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT 11u
+/**
+ * A selected-text request finished; text = selected text (empty if nothing is selected).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_SELECTED_TEXT 12u
+/**
+ * A copy request finished; text = copied text, which the embedder should place on its clipboard
+ * (the engine does not own the system clipboard).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_COPY 13u
+/**
+ * The page logged to the console (only while `xian_web_engine_view_set_console_capture` is enabled);
+ * `value0` = level (`XIAN_WEB_ENGINE_LOG_LEVEL_*`), `value1` = source line (`0` if unknown),
+ * text = source URL, a newline, then the message.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE 14u
+/**
+ * The page threw an uncaught exception or left a promise rejection unhandled; `value0` = source
+ * line, `value1` = column (`0` if unknown), text = source URL, a newline, then the message (with
+ * stack when available).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_PAGE_ERROR 15u
+/**
+ * A subresource (element, `fetch` or XHR) failed to load; `value0` = HTTP status (`0` for network
+ * errors or when unknown), text = resource URL, a newline, then what loaded it (`img`, `script`,
+ * `fetch`, `xhr`, ...) with the error if any.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_RESOURCE_ERROR 16u
+/**
+ * The page sent a binary message with `xianHost.sendBinary`; `request_id` = message ID,
+ * `value0` = channel, `value1` = length in bytes. Read it with
+ * `xian_web_engine_view_get_binary_message` and release it with
+ * `xian_web_engine_view_release_binary_message`.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_BINARY_MESSAGE 17u
+/**
+ * The engine is done with a frame pushed by `xian_web_engine_push_external_frame` and the texture
+ * may be rendered into again; `value0` = stream ID, `value1` = texture ID. `status` is `OK` if the
+ * page received the frame and `FAILED` if it was dropped (stream not open or texture unreadable).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_EXTERNAL_FRAME_RELEASED 18u
+/**
+ * A replay started by `xian_web_engine_view_replay` ended; `value0` = number of actions replayed.
+ * `status` is `OK` if every recorded action was replayed and `FAILED` if a newer replay replaced it.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED 19u
+/**
+ * An image larger than the limits set by `xian_web_engine_set_image_limits` was replaced with a
+ * downscaled copy; `value0` = original width, `value1` = original height (pixels),
+ * text = image URL.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED 20u
+/**
+ * The page exceeded the JavaScript heap limit (`xian_web_engine_set_heap_limit`); `value0` = limit
+ * in MiB, `value1` = `1` if the page's script thread crashed, `0` if the engine unloaded the page
+ * (`about:blank`).
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT 21u
+/**
+ * The view's page crashed (with `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`, its content process
+ * died); text = crash reason, then a newline and the backtrace when available. Navigate the view
+ * to load a page again.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED 22u
+/**
+ * The page handled an input event sent by `xian_web_engine_view_send_tracked_input_event`;
+ * `request_id` = the event's correlation ID, `value0` = `1` if the page consumed the event (a
+ * widget was hit or a handler took it) and `0` if it fell through, `value1` = mask of
+ * `XIAN_WEB_ENGINE_INPUT_RESULT_*` flags.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT 23u
+/**
+ * Input result flag: a page handler called `preventDefault()` on the event.
+ */
+#define XIAN_WEB_ENGINE_INPUT_RESULT_DEFAULT_PREVENTED 1u
+/**
+ * Input result flag: the page consumed the event (for example a wheel event scrolled it).
+ */
+#define XIAN_WEB_ENGINE_INPUT_RESULT_CONSUMED 2u
+/**
+ * Input result flag: a pointer event landed on a page element other than the root (`<html>` or
+ * `<body>`), so it hit content rather than empty space.
+ */
+#define XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT 4u
+/**
+ * An editable element gained or lost focus; `value0` = `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the
+ * focused element (`NONE` when focus left the editable element), `value1` = caret position in
+ * characters (`-1` if unknown). Read the element's rectangle with
+ * `xian_web_engine_view_get_text_input` to place an on-screen keyboard or IME composition window.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT 24u
+/**
+ * Text input type: no editable element has focus.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE 0u
+/**
+ * Text input type: single-line text field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEXT 1u
+/**
+ * Text input type: multi-line text (`<textarea>` or `contenteditable`).
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_MULTILINE 2u
+/**
+ * Text input type: password field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_PASSWORD 3u
+/**
+ * Text input type: number field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NUMBER 4u
+/**
+ * Text input type: email address field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_EMAIL 5u
+/**
+ * Text input type: telephone number field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEL 6u
+/**
+ * Text input type: URL field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_URL 7u
+/**
+ * Text input type: search field.
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_SEARCH 8u
+/**
+ * Text input type: other field (date, time, color, ...).
+ */
+#define XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_OTHER 9u
+/**
+ * The text payload did not fit into the caller's buffer and was truncated.
+ */
+#define XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_TEXT_TRUNCATED 1u
+/**
+ * Success.
+ */
+#define XIAN_WEB_ENGINE_STATUS_OK 0
+/**
+ * Generic failure (details in the text payload when available).
+ */
+#define XIAN_WEB_ENGINE_STATUS_FAILED (-1)
+/**
+ * An I/O error occurred (details in the text payload).
+ */
+#define XIAN_WEB_ENGINE_STATUS_IO_ERROR (-3)
+/**
+ * The request was rejected because its command lane is full; retry later.
+ */
+#define XIAN_WEB_ENGINE_STATUS_QUEUE_FULL (-4)
+/**
+ * The request was rejected because the engine is shutting down.
+ */
+#define XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN (-5)
+/**
+ * The engine did not answer within the configured timeout (see `XianWebEngineConfig`).
+ */
+#define XIAN_WEB_ENGINE_STATUS_TIMED_OUT (-6)
+/**
+ * Permission feature: Geolocation.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_GEOLOCATION 1ll
+/**
+ * Permission feature: Desktop notifications.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_NOTIFICATIONS 2ll
+/**
+ * Permission feature: Push messages.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_PUSH 3ll
+/**
+ * Permission feature: Web MIDI.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_MIDI 4ll
+/**
+ * Permission feature: Camera capture.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_CAMERA 5ll
+/**
+ * Permission feature: Microphone capture.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_MICROPHONE 6ll
+/**
+ * Permission feature: Audio output device selection.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_SPEAKER 7ll
+/**
+ * Permission feature: Media device enumeration.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_DEVICE_INFO 8ll
+/**
+ * Permission feature: Background sync.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_BACKGROUND_SYNC 9ll
+/**
+ * Permission feature: Web Bluetooth.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_BLUETOOTH 10ll
+/**
+ * Permission feature: Persistent storage.
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_PERSISTENT_STORAGE 11ll
+/**
+ * Permission feature: Pointer lock (requested by the engine's pointer lock emulation).
+ */
+#define XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK 12ll
+/**
+ * Unsafe mode: skip Java-side consumer fences (fastest but may overwrite textures still in use).
+ *
+ * In this mode, pass `consumer_fences = NULL` (or 0 fences) to `xian_web_engine_views_release_frames`
+ * (the consumer fence is ignored).
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE 1u
+/**
+ * Hint: the embedder guarantees a single input-producer thread (enables a faster push path).
+ * If this guarantee is violated (multiple producer threads), behavior is undefined.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER 2u
+/**
+ * Unsafe mode: skip producer-side fences (`GLsync`) for new frames (lower overhead).
+ *
+ * In this mode, `XianWebEngineFrame.producer_fence` will always be `0`, and the embedder must ensure it
+ * does not sample incomplete frames (e.g., by using other synchronization).
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE 4u
+/**
+ * Quality: render at 2x the view size (supersampling) so small HUD text stays crisp once the
+ * consumer downsamples the frame.
+ *
+ * Equivalent to creating the view with a render scale of `2.0` (see
+ * `xian_web_engine_view_set_render_scale`); acquired frames are twice the view size.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X 8u
+/**
+ * Quality: allocate mipmapped slot textures (trilinear `LINEAR_MIPMAP_LINEAR` minification) and
+ * regenerate the mip chain on every present.
+ *
+ * Removes shimmering when the host draws the view scaled down or at an angle in 3D space, at the
+ * cost of one `glGenerateMipmap` per frame on the Servo thread.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS 16u
+/**
+ * Interop: back the slot textures with Vulkan images exported through external memory, so a Vulkan
+ * renderer can consume the frames (see `xian_web_engine_view_get_vulkan_slot`).
+ *
+ * Requires `xian_web_engine_set_vulkan_device` to have been called, and cannot be combined with
+ * `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE`.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT 32u
+/**
+ * Performance: resize by orphaning instead of reallocating every slot under the resizing flag.
+ *
+ * The back slot and every FREE slot get freshly allocated textures swapped in (the old ones are
+ * parked in the slot's texture pool, never re-specified while in flight); READY and held slots are
+ * left alone and switched over the next time the producer takes them. The consumer keeps acquiring
+ * throughout (frames report their own size, so a few old-size frames may follow the resize) and the
+ * producer never waits on consumer fences. Ignored with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`,
+ * whose slot images cannot be swapped.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE 64u
+/**
+ * Bit offset of the slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`) inside the view
+ * flags: `flags | (format << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT)`.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT 8u
+/**
+ * Mask of the slot texture color format bits inside the view flags.
+ */
+#define XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK 3840u
+/**
+ * Engine flag: create the Servo-side contexts through EGL instead of GLFW (ANGLE on Windows,
+ * GLES-only drivers on ARM).
+ *
+ * With this flag, the handle passed to `xian_web_engine_create_with_flags` is the host's
+ * `EGLContext` (not a `GLFWwindow*`) and `xian_web_engine_set_egl_api` must have been called.
+ * Only OpenGL ES host contexts are supported.
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_EGL 1u
+/**
+ * Engine flag: create the Servo-side contexts as debug contexts and forward their
+ * `GL_KHR_debug` messages to the log callback.
+ *
+ * Capture is also enabled without this flag when the host context is already a debug context
+ * (the offscreen context mirrors it). Severities map to log levels (high → ERROR, medium → WARN,
+ * low → INFO, notification → DEBUG), so the log callback's `max_level` filters them.
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG 2u
+/**
+ * Engine flag: "paranoid GL" mode. Every rendering-context operation is followed by
+ * `glGetError`; the first error of each frame is logged and recorded in
+ * `XianWebEngineHealth` (`gl_error_frames`, `last_gl_error`, `last_gl_error_op`).
+ *
+ * Meant for diagnosing black or corrupt textures on unusual drivers; `glGetError` stalls the GL
+ * pipeline on many drivers, so leave it off in normal builds.
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL 4u
+/**
+ * Engine flag: hand out this engine's views as generational handle table IDs instead of raw
+ * pointers (`XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE`).
+ *
+ * The IDs travel through the same `XianWebEngineView*` parameters, so bindings need no other
+ * changes, but they must be treated as opaque values and never dereferenced. Every call validates
+ * the ID, so a stale one (use after `xian_web_engine_view_destroy`, double destroy) is rejected and
+ * logged, and the call returns its failure value instead of invoking undefined behavior. Meant for
+ * GC-based hosts whose finalizers may race with explicit destruction; the lookup costs one
+ * uncontended read lock per call. The table is process-global and shared by every engine created
+ * with this flag.
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE 8u
+/**
+ * Engine flag: run page content in separate processes (Servo's multiprocess mode; not supported on
+ * Windows, `XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS`).
+ *
+ * A crashing content process then takes down only its pages: each affected view gets a
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED` event while the Servo thread and other views keep running.
+ * Servo applies the mode to every view of the engine (it cannot be chosen per view), so put
+ * untrusted views in a separate engine created with this flag. Servo has no setting for the
+ * content process executable: it re-runs the host executable with `--content-process <token>`, and
+ * the host's entry point must pass that token to `xian_web_engine_run_content_process`. Engine
+ * creation therefore fails on Windows and when the host executable is a `java`/`javaw` launcher,
+ * which cannot do this; use a native launcher that embeds the JVM.
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS 16u
+/**
+ * Engine flag: always route the engine's HTTP(S) traffic through its loopback proxy.
+ *
+ * Without this flag the proxy only runs if a DNS resolver callback or network limit was installed
+ * before the engine was created. The proxy cannot be added later, so create the engine with this
+ * flag if it may later need `xian_web_engine_set_dns_resolver`,
+ * `xian_web_engine_set_network_limits` or connection-level enforcement of
+ * `xian_web_engine_set_network_mode` (without a proxy, `CACHE_ONLY` behaves like `OFFLINE`).
+ */
+#define XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY 32u
+/**
+ * Sandbox restriction: block script execution in the view's document.
+ *
+ * Sandbox flags are checked with `xian_web_engine_view_set_sandbox`; `0` means "not sandboxed".
+ * Servo only sandboxes nested browsing contexts, so this restriction cannot be enforced on a
+ * view's top-level document and is rejected.
+ */
+#define XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS 1u
+/**
+ * Sandbox restriction: block form submission (cannot be enforced on a top-level document;
+ * rejected).
+ */
+#define XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS 2u
+/**
+ * Sandbox restriction: block nested browsing contexts (`iframe`/`frame`/`object`/`embed`;
+ * cannot be enforced on a top-level document; rejected).
+ */
+#define XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES 4u
+/**
+ * Sandbox restriction: block popups and auxiliary windows (always enforced: the engine never opens
+ * them).
+ */
+#define XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS 8u
+/**
+ * Sandbox restriction: treat the document as a unique opaque origin (no cookies/storage access;
+ * cannot be enforced on a top-level document; rejected).
+ */
+#define XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN 16u
+/**
+ * Export format: PNG image of the whole scrollable document.
+ */
+#define XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG 0u
+/**
+ * Export format: PDF paginating an image of the whole scrollable document into A4-proportioned
+ * pages.
+ */
+#define XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF 1u
+/**
+ * User script injection time: run when the document starts loading (before page scripts).
+ */
+#define XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START 0u
+/**
+ * User script injection time: run once the DOM is parsed (`DOMContentLoaded`).
+ */
+#define XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END 1u
+/**
+ * User script world: the page's main JavaScript world (the only world Servo supports).
+ */
+#define XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN 0u
+/**
+ * Background policy (default): inactive views are hidden but their timers and animation frames
+ * keep running.
+ */
+#define XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL 0u
+/**
+ * Background policy: inactive views are hidden and throttled; the page's native timers are
+ * cancelled and due timer and animation-frame callbacks run at most once per second (an interval
+ * that came due several times in between runs once).
+ */
+#define XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ 1u
+/**
+ * Background policy: inactive views are hidden and throttled; the page's native timers are
+ * cancelled and nothing runs until the view becomes active again, when every timer is re-armed
+ * with its remaining delay (overdue ones fire once).
+ */
+#define XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND 2u
+/**
+ * Network mode (default): pages may use the network.
+ */
+#define XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE 0u
+/**
+ * Network mode: every network request (`http`, `https`, `ws`, `wss`, `ftp`) fails and no
+ * connection leaves the engine; local content (`data:`, `blob:`, `file:`, engine resources) still
+ * loads.
+ */
+#define XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE 1u
+/**
+ * Network mode: like offline, except `http(s)` requests Servo's HTTP cache can answer without
+ * contacting the server still succeed; everything else (misses, stale entries needing
+ * revalidation) fails as a network error.
+ */
+#define XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY 2u
+/**
+ * Slow-script policy: no detection (default).
+ */
+#define XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE 0u
+/**
+ * Slow-script policy: report a `SLOW_SCRIPT` event when a script blocks the page for longer than
+ * the timeout.
+ */
+#define XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY 1u
+/**
+ * Slow-script policy: unload the page (navigate to `about:blank`) and report a `SLOW_SCRIPT` event
+ * once the view answers again or another timeout has passed.
+ */
+#define XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP 2u
+/**
+ * Timeout policy: give up on a blocking wait once its timeout expires and report
+ * `XIAN_WEB_ENGINE_STATUS_TIMED_OUT` (default).
+ */
+#define XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST 0u
+/**
+ * Timeout policy: blocking waits never time out (the timeouts are ignored); a stuck Servo thread
+ * blocks the caller indefinitely.
+ */
+#define XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER 1u
+/**
+ * Storage group: the view uses the engine's own storage, stored in the engine config directory
+ * (default).
+ *
+ * Any other value passed to `xian_web_engine_view_create_in_storage_group` names a group: views of
+ * the same group share their storage, views of different groups do not see each other's. A named
+ * group is stored in `<config_dir>/storage-groups/<group>`.
+ */
+#define XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT 0u
+/**
+ * Storage group: the view gets ephemeral storage, isolated from every other group, never written
+ * to disk and discarded when the engine is destroyed (e.g. untrusted server content). Views of
+ * this group share it with each other.
+ */
+#define XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL 4294967295u
+/**
+ * Slot texture color format: sRGB-encoded RGBA8 when the context supports sRGB framebuffers,
+ * otherwise linear RGBA8 (default). `XianWebEngineFrame.color_format` reports the resolved format.
+ */
+#define XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO 0u
+/**
+ * Slot texture color format: `GL_RGBA8`, written without sRGB encoding.
+ */
+#define XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8 1u
+/**
+ * Slot texture color format: 8-bit BGRA (`GL_RGBA8` storage specified with `GL_BGRA` pixel data),
+ * for hosts that read back or share frames with BGRA-based compositors.
+ */
+#define XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8 2u
+/**
+ * Slot texture color format: `GL_SRGB8_ALPHA8` (view creation fails if sRGB framebuffers are not
+ * supported).
+ */
+#define XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 3u
+/**
+ * Slot texture color format: `GL_RGBA16F` (linear half-float, for HDR pipelines).
+ */
+#define XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F 4u
+/**
+ * Output colorspace: follow the color format (sRGB-encode writes to `SRGB8_A8` textures, write other
+ * formats as-is). Default.
+ */
+#define XIAN_WEB_ENGINE_COLORSPACE_AUTO 0u
+/**
+ * Output colorspace: sRGB-encode writes (`GL_FRAMEBUFFER_SRGB`; requires `SRGB8_A8` textures).
+ * Sampling the texture through an sRGB view decodes back to linear automatically.
+ */
+#define XIAN_WEB_ENGINE_COLORSPACE_SRGB 1u
+/**
+ * Output colorspace: write values as-is without sRGB encoding, for hosts that apply their own
+ * transfer function during gamma-correct compositing.
+ */
+#define XIAN_WEB_ENGINE_COLORSPACE_LINEAR 2u
+/**
+ * `prefers-color-scheme` reported to pages: `light`. Default.
+ */
+#define XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT 0u
+/**
+ * `prefers-color-scheme` reported to pages: `dark`.
+ */
+#define XIAN_WEB_ENGINE_COLOR_SCHEME_DARK 1u
+/**
+ * Profiling category: Servo's time profiler; a per-category timing summary is written to the
+ * output path when Servo shuts down.
+ */
+#define XIAN_WEB_ENGINE_PROFILING_TIME 1u
+/**
+ * Profiling category: Servo's per-event timeline, written as an HTML trace next to the output path
+ * (`<output_path>.html`). Implies `XIAN_WEB_ENGINE_PROFILING_TIME`.
+ */
+#define XIAN_WEB_ENGINE_PROFILING_TRACE 2u
+/**
+ * Idle flag: when entering idle, also release memory as for
+ * `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE`.
+ */
+#define XIAN_WEB_ENGINE_IDLE_FLAG_RELEASE_MEMORY 1u
+/**
+ * Memory pressure level: free engine-side pools and the spare slot textures views keep for fast
+ * resizes.
+ */
+#define XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE 1u
+/**
+ * Memory pressure level: everything `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` frees, plus Servo's
+ * in-memory HTTP cache.
+ */
+#define XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL 2u
+/**
+ * Golden test flag: write the captured frames as the new goldens instead of comparing them.
+ */
+#define XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE 1u
+/**
+ * A Servo thread finished building its Servo instance.
+ */
+#define XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT 1u
+/**
+ * A Servo thread created its shared GL (or EGL) context.
+ */
+#define XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT 2u
+/**
+ * Every Servo thread is running; engine creation is about to return.
+ */
+#define XIAN_WEB_ENGINE_INIT_PHASE_READY 3u
+/**
+ * A Servo thread precompiled its WebRender shaders. Servo does this when the first view of the
+ * thread is created, so this phase arrives after `READY` (during `xian_web_engine_preload`, a
+ * prewarm or the first view creation).
+ */
+#define XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED 4u
+/**
+ * Input kind: mouse move.
+ */
+#define XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE 1u
+/**
+ * Input kind: mouse button.
+ */
+#define XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON 2u
+/**
+ * Input kind: wheel.
+ */
+#define XIAN_WEB_ENGINE_INPUT_KIND_WHEEL 3u
+/**
+ * Input kind: keyboard.
+ */
+#define XIAN_WEB_ENGINE_INPUT_KIND_KEY 4u
+/**
+ * Input kind: relative mouse motion while the page holds pointer lock (`x`/`y` = delta).
+ */
+#define XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE 5u
+/**
+ * Errors, including rejected FFI arguments.
+ */
+#define XIAN_WEB_ENGINE_LOG_LEVEL_ERROR 1u
+/**
+ * Recoverable problems.
+ */
+#define XIAN_WEB_ENGINE_LOG_LEVEL_WARN 2u
+/**
+ * Informational messages.
+ */
+#define XIAN_WEB_ENGINE_LOG_LEVEL_INFO 3u
+/**
+ * Debugging details.
+ */
+#define XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG 4u
+/**
+ * Every FFI call with its arguments (debug builds only).
+ */
+#define XIAN_WEB_ENGINE_LOG_LEVEL_TRACE 5u
+/**
+ * Consumer fence value meaning "the release semaphore of this slot was signaled".
+ *
+ * Vulkan consumers pass it to `xian_web_engine_views_release_frames` instead of a `GLsync`.
+ */
+#define XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE 18446744073709551615ull
+/**
+ * GL operation tag: rendering-context creation (slot textures, depth buffer).
+ */
+#define XIAN_WEB_ENGINE_GL_OP_CREATE 1u
+/**
+ * GL operation tag: preparing the back slot for rendering (sRGB state, FBO binding).
+ */
+#define XIAN_WEB_ENGINE_GL_OP_PREPARE 2u
+/**
+ * GL operation tag: presenting a frame (WebRender output, mipmaps, fence).
+ */
+#define XIAN_WEB_ENGINE_GL_OP_PRESENT 3u
+/**
+ * GL operation tag: resizing slot textures.
+ */
+#define XIAN_WEB_ENGINE_GL_OP_RESIZE 4u
+/**
+ * GL operation tag: reading pixels back (screenshots).
+ */
+#define XIAN_WEB_ENGINE_GL_OP_READ_PIXELS 5u
+/**
+ * C ABI version for `xian_web_engine`.
+ */
+#define XIAN_WEB_ENGINE_ABI_VERSION 2u
+/**
+ * Oldest ABI version whose bindings can still drive this library (struct layouts unchanged since).
+ *
+ * ABI 2 grew `XianWebEngineFrame` from 24 to 48 bytes, so ABI 1 bindings would pass arrays too
+ * small for `xian_web_engine_views_acquire_frames`.
+ */
+#define XIAN_WEB_ENGINE_ABI_MIN_COMPATIBLE 2u
+
+typedef struct XianWebEngine XianWebEngine;
+typedef struct XianWebEngineView XianWebEngineView;
+typedef struct XianWebEngineCompositeRect XianWebEngineCompositeRect;
+typedef struct EmbedderEglApi EmbedderEglApi;
+typedef struct XianWebEngineThreadPoolConfig XianWebEngineThreadPoolConfig;
+typedef struct XianWebEngineConfig XianWebEngineConfig;
+typedef struct XianWebEngineNetworkLimits XianWebEngineNetworkLimits;
+typedef struct XianWebEngineImageLimits XianWebEngineImageLimits;
+typedef struct XianWebEngineImageLoadStats XianWebEngineImageLoadStats;
+typedef struct XianWebEngineHealth XianWebEngineHealth;
+typedef struct XianWebEngineFenceStats XianWebEngineFenceStats;
+typedef struct XianWebEngineViewEvent XianWebEngineViewEvent;
+typedef struct XianWebEngineViewQueueStats XianWebEngineViewQueueStats;
+typedef struct XianWebEngineFrame XianWebEngineFrame;
+typedef struct EmbedderGlfwApi EmbedderGlfwApi;
+typedef struct XianWebEngineInputEvent XianWebEngineInputEvent;
+typedef struct EmbedderVulkanDevice EmbedderVulkanDevice;
+typedef struct XianWebEngineVulkanSlot XianWebEngineVulkanSlot;
+
+/**
+ * DNS resolver callback: resolves the NUL-terminated host name `host` and writes one IPv4 or IPv6
+ * address literal (NUL-terminated, e.g. `192.168.1.20` or `::1`) into `out_address`, which holds
+ * `out_capacity` bytes. Returns `false` to fall back to the system resolver. Called on proxy worker
+ * threads, possibly concurrently.
+ */
+typedef bool (*XianWebEngineDnsResolveFn)(void *user_data, const char *host, char *out_address, uintptr_t out_capacity);
+
+/**
+ * Frame callback: receives the `frame_seq` of the frame that was just published. Invoked on the
+ * Servo thread right after publish; it must return quickly, must not call back into the engine
+ * for the same view's frames (acquire from the host render thread instead) and must not replace or
+ * remove its own view's callback (that waits for the running call and would deadlock).
+ */
+typedef void (*XianWebEngineFrameFn)(void *user_data, uint64_t frame_seq);
+
+/**
+ * Task entry point handed to the trampoline; the embedder calls it on the main thread with the
+ * `task_data` it received.
+ */
+typedef void (*XianWebEngineMainThreadTaskFn)(void *task_data);
+
+/**
+ * Embedder trampoline: run `task(task_data)` on the main thread, either immediately or on the
+ * next main-loop iteration. It may be called from any thread and must not block until the task
+ * has run.
+ */
+typedef void (*XianWebEngineMainThreadFn)(XianWebEngineMainThreadTaskFn task, void *task_data, void *user_data);
+
+/**
+ * Init progress callback: receives a `XIAN_WEB_ENGINE_INIT_PHASE_*` phase, in increasing order per
+ * Servo thread. Per-thread phases are reported once per Servo thread. May be invoked from any
+ * thread.
+ */
+typedef void (*XianWebEngineInitProgressFn)(void *user_data, uint32_t phase);
+
+/**
+ * Log callback: receives a `XIAN_WEB_ENGINE_LOG_LEVEL_*` level and a NUL-terminated UTF-8 message
+ * that is only valid for the duration of the call. May be invoked from any thread.
+ */
+typedef void (*XianWebEngineLogFn)(void *user_data, uint32_t level, const char *message);
+
+/**
+ * Host function callback: receives the call ID, the function name and the arguments as
+ * NUL-terminated UTF-8 JSON (all strings valid only for the duration of the call).
+ *
+ * Invoked on the Servo thread; it must return quickly and answer later with
+ * `xian_web_engine_view_respond_host_call` (from any thread).
+ */
+typedef void (*XianWebEngineHostFunctionFn)(void *user_data, uint64_t call_id, const char *name, const char *args_json);
+
+/**
+ * Completion callback of `xian_web_engine_create_view_async`: `status` is
+ * `XIAN_WEB_ENGINE_STATUS_OK` once the view exists, or `XIAN_WEB_ENGINE_STATUS_FAILED` if it could
+ * not be created (the reason is logged).
+ *
+ * Invoked once, on the Servo thread (or on the thread shutting the engine down); it must return
+ * quickly.
+ */
+typedef void (*XianWebEngineViewCreatedFn)(void *user_data, int32_t status);
+
+/**
+ * Completion callback of `xian_web_engine_capture_element`. On `XIAN_WEB_ENGINE_STATUS_OK`,
+ * `pixels` holds `width * height` RGBA8 pixels (tightly packed, top row first, in the view's
+ * color format encoding); otherwise `width` and `height` are `0`, `pixels` is NULL and the reason
+ * is logged. `pixels` is only valid during the call.
+ *
+ * Invoked exactly once, on the Servo thread (or, with `XIAN_WEB_ENGINE_STATUS_FAILED`, on the
+ * thread that closes the view or shuts the engine down first); it must return quickly.
+ */
+typedef void (*XianWebEngineElementCaptureFn)(void *user_data, int32_t status, uint32_t width, uint32_t height, const uint8_t *pixels);
+
+/**
+ * Where one view is drawn by `xian_web_engine_composite_views`.
+ *
+ * Coordinates are in pixels of the current GL viewport with the origin at its top-left corner.
+ */
+struct XianWebEngineCompositeRect {
+    /**
+     * Left edge in pixels.
+     */
+    float x;
+    /**
+     * Top edge in pixels.
+     */
+    float y;
+    /**
+     * Width in pixels (views with a non-positive size are skipped).
+     */
+    float width;
+    /**
+     * Height in pixels (views with a non-positive size are skipped).
+     */
+    float height;
+    /**
+     * Opacity multiplied into the view (`0.0..=1.0`).
+     */
+    float opacity;
+};
+
+/**
+ * EGL entry point and display provided by the embedder (e.g. LWJGL's `EGL` class or
+ * `glfwGetEGLDisplay`).
+ *
+ * All fields are raw addresses (`usize`) and must be non-zero when installing.
+ */
+struct EmbedderEglApi {
+    /**
+     * Pointer to `eglGetProcAddress`; it must resolve core EGL entry points (EGL 1.5 or
+     * `EGL_KHR_get_all_proc_addresses`, which ANGLE provides).
+     */
+    uintptr_t egl_get_proc_address;
+    /**
+     * `EGLDisplay` that owns the host's context.
+     */
+    uintptr_t egl_display;
+};
+
+/**
+ * Per-pool worker thread counts for Servo, passed at engine creation.
+ *
+ * Every field is optional: `0` falls back to the engine-wide `thread_pool_cap` default.
+ */
+struct XianWebEngineThreadPoolConfig {
+    /**
+     * Parallel layout/style (Stylo) workers.
+     */
+    uint32_t layout_threads;
+    /**
+     * Async runtime workers (script-side fetches, timers and other async tasks).
+     */
+    uint32_t async_runtime_threads;
+    /**
+     * Network resource workers.
+     */
+    uint32_t resource_threads;
+    /**
+     * Image decoding workers.
+     */
+    uint32_t image_cache_threads;
+    /**
+     * WebRender workers (scene building / rasterization).
+     */
+    uint32_t webrender_threads;
+    /**
+     * Storage workers (IndexedDB and Web Storage).
+     */
+    uint32_t storage_threads;
+    /**
+     * Servo threads that views are sharded across (`0` = one).
+     *
+     * Each thread runs its own Servo instance (with its own worker pools sized as above) and its own
+     * shared offscreen GL context, so a heavy page only stalls the views on the same thread.
+     */
+    uint32_t servo_threads;
+};
+
+/**
+ * Engine configuration (`xian_web_engine_config_default` fills in the defaults).
+ *
+ * Timeouts bound how long a blocking call waits for a Servo thread; `0` uses the 30-second default.
+ * A call that times out fails with `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`.
+ */
+struct XianWebEngineConfig {
+    /**
+     * How long engine creation waits for each Servo thread to initialize, in milliseconds.
+     */
+    uint32_t init_timeout_ms;
+    /**
+     * How long a blocking view creation waits for the view, in milliseconds.
+     */
+    uint32_t view_timeout_ms;
+    /**
+     * How long other blocking requests (e.g. view group creation) wait for their reply, in
+     * milliseconds.
+     */
+    uint32_t command_timeout_ms;
+    /**
+     * `XIAN_WEB_ENGINE_TIMEOUT_POLICY_*`: fail fast (default) or wait forever.
+     */
+    uint32_t timeout_policy;
+};
+
+/**
+ * Network limits applied to all HTTP(S) traffic (`0` in a field means unlimited).
+ */
+struct XianWebEngineNetworkLimits {
+    /**
+     * Download budget in bytes per second, shared by every connection; reads from servers are
+     * paced to stay under it.
+     */
+    uint64_t max_bytes_per_second;
+    /**
+     * Maximum concurrent server connections; further connections wait for a free slot, and idle
+     * keep-alive connections are closed while any are waiting.
+     */
+    uint32_t max_connections;
+};
+
+/**
+ * Image size limits applied to every view of an engine (`0` in a field means unlimited).
+ */
+struct XianWebEngineImageLimits {
+    /**
+     * Largest image area in pixels (width × height); larger images are scaled down to fit.
+     */
+    uint64_t max_pixels;
+    /**
+     * Largest image width in pixels.
+     */
+    uint32_t max_width;
+    /**
+     * Largest image height in pixels.
+     */
+    uint32_t max_height;
+};
+
+/**
+ * Image load statistics of an engine, filled by `xian_web_engine_get_image_load_stats`.
+ *
+ * With several Servo threads the values are summed; each thread keeps its own image cache and
+ * table of loaded URLs.
+ */
+struct XianWebEngineImageLoadStats {
+    /**
+     * Image loads reported by pages (monotonic).
+     */
+    uint64_t image_loads;
+    /**
+     * Loads of a URL already loaded on the same Servo thread (monotonic); `repeat_loads /
+     * image_loads` is the share of loads Servo's image cache can serve.
+     */
+    uint64_t repeat_loads;
+    /**
+     * Repeat loads of a URL first loaded by another view (monotonic).
+     */
+    uint64_t cross_view_repeat_loads;
+    /**
+     * URLs dropped from the table, least recently loaded first, to keep it at 16384 URLs per
+     * Servo thread (monotonic); a later load of a dropped URL counts as a first load.
+     */
+    uint64_t dropped_urls;
+    /**
+     * Distinct URLs currently in the table.
+     */
+    uint64_t distinct_urls;
+    /**
+     * Decoded size those URLs would take at 4 bytes per pixel of their natural size, in bytes.
+     */
+    uint64_t distinct_url_bytes;
+};
+
+/**
+ * Health snapshot of the engine, filled by `xian_web_engine_get_health`.
+ */
+struct XianWebEngineHealth {
+    /**
+     * Milliseconds the Servo thread has been busy in its current loop iteration (`0` = idle).
+     *
+     * A value that keeps growing means the thread is stuck (e.g. a hung script); hosts can show a
+     * "web UI not responding" overlay or destroy the engine.
+     */
+    uint64_t millis_since_heartbeat;
+    /**
+     * Control commands queued for the Servo thread but not yet processed.
+     */
+    uint32_t queued_commands;
+    /**
+     * Views with pending work (input/resize/navigation) not yet processed.
+     */
+    uint32_t pending_views;
+    /**
+     * Frames that hit at least one GL error (`XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL` only;
+     * always `0` otherwise).
+     */
+    uint64_t gl_error_frames;
+    /**
+     * First `glGetError` value of the latest failing frame (`0` = none).
+     */
+    uint32_t last_gl_error;
+    /**
+     * Operation that raised `last_gl_error` (`XIAN_WEB_ENGINE_GL_OP_*`, `0` = none).
+     */
+    uint32_t last_gl_error_op;
+    /**
+     * Control commands rejected with `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL` since the engine was created
+     * (monotonic).
+     */
+    uint64_t commands_dropped;
+    /**
+     * Times the pending-view queue overflowed since the engine was created (monotonic). No work is
+     * lost (the Servo thread then scans every view), but a growing value means wakeups are arriving
+     * faster than they are drained.
+     */
+    uint64_t pending_overflows;
+};
+
+/**
+ * Producer fence statistics of the process, filled by `xian_web_engine_get_fence_stats`.
+ */
+struct XianWebEngineFenceStats {
+    /**
+     * Producer fences created since the process started (monotonic).
+     */
+    uint64_t created;
+    /**
+     * Producer fences deleted since the process started (monotonic).
+     */
+    uint64_t deleted;
+    /**
+     * Producer fences created per second over the last window of at least one second (`0` until
+     * the second query).
+     */
+    double created_per_second;
+    /**
+     * Producer fences deleted per second over the last window of at least one second (`0` until
+     * the second query).
+     */
+    double deleted_per_second;
+};
+
+/**
+ * One view event.
+ *
+ * The meaning of `value0`/`value1` depends on `kind` (see `XIAN_WEB_ENGINE_VIEW_EVENT_*`).
+ */
+struct XianWebEngineViewEvent {
+    /**
+     * Event kind (one of `XIAN_WEB_ENGINE_VIEW_EVENT_*`).
+     */
+    uint32_t kind;
+    /**
+     * Status code (`XIAN_WEB_ENGINE_STATUS_OK` or a negative error code).
+     */
+    int32_t status;
+    /**
+     * Request ID this event completes (`0` for unsolicited events).
+     */
+    uint64_t request_id;
+    /**
+     * First kind-specific value.
+     */
+    int64_t value0;
+    /**
+     * Second kind-specific value.
+     */
+    int64_t value1;
+    /**
+     * Byte offset of the UTF-8 text payload inside the caller's text buffer.
+     */
+    uint32_t text_offset;
+    /**
+     * Byte length of the text payload (`0` if none).
+     */
+    uint32_t text_len;
+    /**
+     * Event flags (`XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_*`).
+     */
+    uint32_t flags;
+    /**
+     * Reserved (always 0).
+     */
+    uint32_t _reserved;
+};
+
+/**
+ * Monotonic delivery counters of one view's queues, filled by `xian_web_engine_view_get_queue_stats`.
+ *
+ * Every counter starts at 0 when the view is created and only grows. Compare a snapshot with the
+ * previous one: if a `*_dropped` counter moved, something was lost in between, and the embedder
+ * should resync the state it mirrors (e.g. re-query the URL/title) instead of trusting its
+ * incremental view.
+ */
+struct XianWebEngineViewQueueStats {
+    /**
+     * View events produced so far (queued or dropped); the generation of the event queue.
+     */
+    uint64_t event_generation;
+    /**
+     * View events dropped because the event queue was full.
+     */
+    uint64_t events_dropped;
+    /**
+     * Input events submitted through `xian_web_engine_view_send_input_events` (accepted or not).
+     */
+    uint64_t input_generation;
+    /**
+     * Submitted input events rejected because the input queue was full.
+     */
+    uint64_t input_dropped;
+    /**
+     * Binary messages from the page dropped because too many were left unreleased.
+     */
+    uint64_t binary_messages_dropped;
+};
+
+/**
+ * One acquired frame returned to the embedder (Java thread).
+ */
+struct XianWebEngineFrame {
+    /**
+     * Triple-buffer slot index (0..=2).
+     */
+    uint32_t slot;
+    /**
+     * GL texture ID containing the frame. It can change between frames of the same slot (e.g. after
+     * a resize), so read it from every acquired frame.
+     */
+    uint32_t texture_id;
+    /**
+     * Producer fence handle (`GLsync` cast to `u64`), or 0 if unavailable.
+     *
+     * The embedder should wait on this fence before sampling the texture to avoid reading an
+     * incomplete frame. If it is `0`, the embedder must provide its own synchronization if needed.
+     *
+     * Recommended (GPU wait, does not block the Java thread):
+     * ```c
+     * if (frame.producer_fence != 0) {
+     *   glWaitSync((GLsync)frame.producer_fence, 0, GL_TIMEOUT_IGNORED);
+     * }
+     * // ...sample frame.texture_id...
+     * ```
+     *
+     * Optional (CPU wait, blocks the Java thread; usually only for debugging):
+     * ```c
+     * if (frame.producer_fence != 0) {
+     *   // GL_SYNC_FLUSH_COMMANDS_BIT only flushes the *current* context.
+     *   glClientWaitSync((GLsync)frame.producer_fence, GL_SYNC_FLUSH_COMMANDS_BIT, 1000000000ULL);
+     * }
+     * ```
+     *
+     * Ownership: this sync object is owned by Rust; the embedder may wait on it, but must NOT
+     * delete it (Rust will delete it when the slot is recycled/destroyed).
+     */
+    uint64_t producer_fence;
+    /**
+     * Frame width in pixels.
+     */
+    uint32_t width;
+    /**
+     * Frame height in pixels.
+     */
+    uint32_t height;
+    /**
+     * Frames produced since this view's previous acquire that were never acquired (`0` = keeping up).
+     *
+     * A consistently non-zero value means the consumer is falling behind the producer and may want
+     * to lower its composite rate or the view's resolution.
+     */
+    uint32_t frames_skipped;
+    /**
+     * Color format of `texture_id` (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`; `AUTO` is resolved to
+     * `SRGB8_A8` or `RGBA8`).
+     */
+    uint32_t color_format;
+    /**
+     * Effective output colorspace of the frame: `XIAN_WEB_ENGINE_COLORSPACE_SRGB` (values were
+     * sRGB-encoded on write) or `XIAN_WEB_ENGINE_COLORSPACE_LINEAR` (values were written as-is).
+     */
+    uint32_t colorspace;
+    /**
+     * Reserved (always 0).
+     */
+    uint32_t _reserved;
+    /**
+     * When the producer presented the frame, in nanoseconds on the engine monotonic clock (read the
+     * current value with `xian_web_engine_monotonic_time_ns`).
+     *
+     * Hosts can align web animation timing with game-world interpolation, e.g. by measuring how old
+     * the frame is when it is composited.
+     */
+    uint64_t present_time_ns;
+};
+
+/**
+ * Function pointer table for GLFW symbols provided by the embedder (e.g., Java/LWJGL).
+ *
+ * All fields are raw addresses (`usize`) and must be non-zero when installing.
+ */
+struct EmbedderGlfwApi {
+    /**
+     * Pointer to `glfwGetProcAddress`.
+     */
+    uintptr_t glfw_get_proc_address;
+    /**
+     * Pointer to `glfwMakeContextCurrent`.
+     */
+    uintptr_t glfw_make_context_current;
+    /**
+     * Pointer to `glfwDefaultWindowHints`.
+     */
+    uintptr_t glfw_default_window_hints;
+    /**
+     * Pointer to `glfwWindowHint`.
+     */
+    uintptr_t glfw_window_hint;
+    /**
+     * Pointer to `glfwGetWindowAttrib`.
+     */
+    uintptr_t glfw_get_window_attrib;
+    /**
+     * Pointer to `glfwCreateWindow`.
+     */
+    uintptr_t glfw_create_window;
+    /**
+     * Pointer to `glfwDestroyWindow`.
+     */
+    uintptr_t glfw_destroy_window;
+};
+
+/**
+ * One input event in a single struct.
+ *
+ * All fields are numeric to avoid UTF-8 parsing / allocation on the Rust hot path.
+ */
+struct XianWebEngineInputEvent {
+    /**
+     * Event kind (one of `XIAN_WEB_ENGINE_INPUT_KIND_*`).
+     */
+    uint32_t kind;
+    /**
+     * Cursor X in device pixels (for pointer-related events; horizontal delta for relative moves).
+     */
+    float x;
+    /**
+     * Cursor Y in device pixels (for pointer-related events; vertical delta for relative moves).
+     */
+    float y;
+    /**
+     * Modifier bitmask (embedder-defined; mapped to Servo modifiers on the Servo thread).
+     */
+    uint32_t modifiers;
+    /**
+     * Mouse button (GLFW button value).
+     */
+    uint32_t mouse_button;
+    /**
+     * Mouse button action (`0` = down, otherwise up).
+     */
+    uint32_t mouse_action;
+    /**
+     * Wheel delta X.
+     */
+    double wheel_delta_x;
+    /**
+     * Wheel delta Y.
+     */
+    double wheel_delta_y;
+    /**
+     * Wheel delta Z.
+     */
+    double wheel_delta_z;
+    /**
+     * Wheel mode (`0` = pixel, `1` = line, `2` = page).
+     */
+    uint32_t wheel_mode;
+    /**
+     * Key state (`0` = down, otherwise up).
+     */
+    uint32_t key_state;
+    /**
+     * Key location (`0` = standard, `1` = left, `2` = right, `3` = numpad).
+     */
+    uint32_t key_location;
+    /**
+     * Repeat flag (`0` = not repeat, otherwise repeat).
+     */
+    uint32_t repeat;
+    /**
+     * IME composing flag (`0` = false, otherwise true).
+     */
+    uint32_t is_composing;
+    /**
+     * Unicode codepoint for the typed character (0 if unknown).
+     */
+    uint32_t key_codepoint;
+    /**
+     * Raw GLFW key code.
+     */
+    uint32_t glfw_key;
+};
+
+/**
+ * Vulkan device provided by the embedder (e.g. VulkanMod through LWJGL).
+ *
+ * All fields are raw addresses (`usize`) and must be non-zero when installing. The device must
+ * have `VK_KHR_external_memory_fd` + `VK_KHR_external_semaphore_fd` (Linux) or
+ * `VK_KHR_external_memory_win32` + `VK_KHR_external_semaphore_win32` (Windows) enabled, and must
+ * be the GPU the GL context runs on.
+ */
+struct EmbedderVulkanDevice {
+    /**
+     * Pointer to `vkGetInstanceProcAddr`.
+     */
+    uintptr_t get_instance_proc_addr;
+    /**
+     * `VkInstance` the device was created from.
+     */
+    uintptr_t instance;
+    /**
+     * `VkPhysicalDevice` of the device.
+     */
+    uintptr_t physical_device;
+    /**
+     * `VkDevice` the images and semaphores are created on.
+     */
+    uintptr_t device;
+};
+
+/**
+ * Vulkan import description of one slot image.
+ *
+ * Handles stay owned by the engine: duplicate them (`dup` / `DuplicateHandle`) before passing them
+ * to an import call that takes ownership. The image is created as 2D, optimal tiling, exclusive
+ * sharing, usage `TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT`, with dedicated memory.
+ */
+struct XianWebEngineVulkanSlot {
+    /**
+     * Exported memory handle (file descriptor on Linux, `HANDLE` on Windows).
+     */
+    uint64_t memory_handle;
+    /**
+     * Size of the dedicated allocation in bytes.
+     */
+    uint64_t allocation_size;
+    /**
+     * Exported handle of the `ready` semaphore (wait on it after acquire).
+     */
+    uint64_t ready_semaphore;
+    /**
+     * Exported handle of the `release` semaphore (signal it before release).
+     */
+    uint64_t release_semaphore;
+    /**
+     * `VkExternalMemoryHandleTypeFlagBits` / `VkExternalSemaphoreHandleTypeFlagBits` of the handles.
+     */
+    uint32_t handle_type;
+    /**
+     * `VkFormat` of the image.
+     */
+    uint32_t vk_format;
+    /**
+     * Image width in pixels.
+     */
+    uint32_t width;
+    /**
+     * Image height in pixels.
+     */
+    uint32_t height;
+    /**
+     * Number of mip levels.
+     */
+    uint32_t mip_levels;
+    /**
+     * Memory type index used for the allocation.
+     */
+    uint32_t memory_type_index;
+    /**
+     * Image generation (`0` = no image); changes whenever the slot image is reallocated.
+     */
+    uint32_t generation;
+    /**
+     * Reserved (always `0`).
+     */
+    uint32_t reserved;
+};
+
+/**
+ * Returns the C ABI version.
+ */
+uint32_t xian_web_engine_abi_version(void);
+
+/**
+ * Returns whether bindings written against ABI `version` can use this library.
+ *
+ * The library accepts every version from the last struct-layout change up to its own version;
+ * bindings newer than the library are rejected, since they may call functions it does not export.
+ * Call this once after loading and fall back (or report a clear error) when it returns `false`.
+ */
+bool xian_web_engine_require_abi(uint32_t version);
+
+/**
+ * Returns the `XIAN_WEB_ENGINE_CAPABILITY_*` bits supported by this build on this platform.
+ *
+ * Unknown bits must be ignored; bits are only ever added.
+ */
+uint64_t xian_web_engine_get_capabilities(void);
+
+/**
+ * Draws the latest frames of `count` views into `target_fbo` and returns how many were drawn.
+ *
+ * Views are drawn in array order (back to front) as premultiplied-alpha quads at `rects[i]`, in
+ * pixels of the current GL viewport with a top-left origin. Each view shows its newest frame, or the
+ * one it showed last time if no newer frame is ready; the compositor keeps that frame acquired and
+ * releases it with a consumer fence once replaced, so do not acquire/release these views yourself.
+ * Views no longer passed in are released on the next call. sRGB conversion follows the view's
+ * colorspace and the encoding of the target attachment. `views[i]` may be NULL to skip an entry;
+ * `target_fbo = 0` draws into the default framebuffer. GL state changed for drawing is restored.
+ *
+ * Must be called on the host render thread with a GL context current that shares objects with the
+ * engine. The compositor's GL objects belong to the first thread that calls this; destroy the
+ * engine on that thread to free them.
+ *
+ * Returns `0` if an argument is NULL/invalid or the compositor cannot be created.
+ */
+uint32_t xian_web_engine_composite_views(XianWebEngine *engine, XianWebEngineView *const *views, const XianWebEngineCompositeRect *rects, uint32_t count, uint32_t target_fbo);
+
+/**
+ * Installs the process-wide DNS resolver callback (`NULL` removes it), for environments that block
+ * system DNS or need split-horizon names for LAN servers.
+ *
+ * May be installed or replaced at any time: engines running a loopback proxy send their HTTP(S)
+ * traffic through it, and it asks the callback for every new target connection and falls back to
+ * the system resolver when it returns `false` or no callback is installed. An engine runs a proxy
+ * if it was created with `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` or while a callback or
+ * network limit was installed; other engines keep using the system resolver. TLS is still verified
+ * against the original host name. DNS-over-HTTPS can be implemented inside the callback. WebSocket
+ * connections always use the system resolver.
+ */
+void xian_web_engine_set_dns_resolver(XianWebEngineDnsResolveFn callback, void *user_data);
+
+/**
+ * Starts dragging `len` bytes of UTF-8 data with the given MIME type (NUL-terminated) over the view.
+ *
+ * Follow with `xian_web_engine_view_drag_move` as the cursor moves, then
+ * `xian_web_engine_view_drop` or `xian_web_engine_view_cancel_drag`. Starting a new drag replaces the
+ * current one.
+ *
+ * Returns `false` if an argument is NULL, the data is not valid UTF-8, or the engine is shutting down.
+ */
+bool xian_web_engine_view_begin_drag(XianWebEngineView *view, const char *mime, const uint8_t *bytes, uintptr_t len);
+
+/**
+ * Moves the current drag to `(x, y)` in device pixels.
+ *
+ * Returns `false` if `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+ */
+bool xian_web_engine_view_drag_move(XianWebEngineView *view, float x, float y);
+
+/**
+ * Drops the current drag at `(x, y)` in device pixels.
+ *
+ * The outcome is reported as a `XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE` event whose text is the drop
+ * effect (`"none"` if the page rejected the data, so the item can stay in the inventory).
+ *
+ * Returns the request ID, or `0` on failure.
+ */
+uint64_t xian_web_engine_view_drop(XianWebEngineView *view, float x, float y);
+
+/**
+ * Cancels the current drag (e.g. the cursor left the view).
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_cancel_drag(XianWebEngineView *view);
+
+/**
+ * Installs the embedder's `eglGetProcAddress` and `EGLDisplay` (once per process).
+ *
+ * This must be called before `xian_web_engine_create_with_flags` with
+ * `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`. The display must be the one that owns the `EGLContext`
+ * passed to it (e.g. `glfwGetEGLDisplay()` when GLFW runs on ANGLE).
+ *
+ * Returns `true` on success.
+ */
+bool xian_web_engine_set_egl_api(const EmbedderEglApi *api);
+
+/**
+ * Creates an engine bound to a Java-created GLFW OpenGL context.
+ *
+ * `resources_dir` and `config_dir` are optional NUL-terminated UTF-8 strings.
+ * Passing NULL or an empty string means "unset".
+ *
+ * `thread_pool_cap` controls the maximum worker threads used by Servo's internal thread pools.
+ * - `0` means "no cap" (use CPU parallelism).
+ * - Otherwise, Servo thread pools are capped to `min(CPU, thread_pool_cap)`.
+ */
+XianWebEngine *xian_web_engine_create(void *glfw_shared_window, uint32_t default_width, uint32_t default_height, const char *resources_dir, const char *config_dir, uint32_t thread_pool_cap);
+
+/**
+ * Same as `xian_web_engine_create`, but also sizes Servo's worker pools individually.
+ *
+ * `thread_pools` is optional (NULL = all defaults). Each non-zero field sets the worker count of one
+ * pool (layout/style, async runtime, network, image decoding, WebRender, storage); `0` fields fall
+ * back to the `thread_pool_cap` rule. Use small values on low-end machines so the game and the
+ * browser do not oversubscribe the CPU.
+ *
+ * `servo_threads` shards views across several Servo threads, isolating heavy pages from the rest.
+ * Views created in a group live on the group's thread; other views are spread round-robin.
+ */
+XianWebEngine *xian_web_engine_create_with_thread_pools(void *glfw_shared_window, uint32_t default_width, uint32_t default_height, const char *resources_dir, const char *config_dir, uint32_t thread_pool_cap, const XianWebEngineThreadPoolConfig *thread_pools);
+
+/**
+ * Same as `xian_web_engine_create_with_thread_pools`, but also takes engine creation flags
+ * (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+ *
+ * With `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`, `shared_context` is the host's `EGLContext` and the Servo
+ * threads create their contexts through the EGL API installed by `xian_web_engine_set_egl_api`
+ * (e.g. Minecraft running on ANGLE or a GLES-only ARM driver). Otherwise it is the
+ * `GLFWwindow*` as for `xian_web_engine_create`. `XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG` creates
+ * debug contexts and forwards their GL debug messages to the log callback.
+ * `XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE` hands out the engine's views as validated IDs instead
+ * of pointers (through the process-global handle table).
+ * `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS` runs page content in separate processes (fails on
+ * Windows and under a `java`/`javaw` launcher, see the flag).
+ * `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` routes HTTP(S) through the loopback proxy even if no
+ * resolver or network limit is installed yet.
+ */
+XianWebEngine *xian_web_engine_create_with_flags(void *shared_context, uint32_t default_width, uint32_t default_height, const char *resources_dir, const char *config_dir, uint32_t thread_pool_cap, const XianWebEngineThreadPoolConfig *thread_pools, uint32_t engine_flags);
+
+/**
+ * Same as `xian_web_engine_create_with_flags`, but also takes an engine configuration (NULL = all
+ * defaults; see `xian_web_engine_config_default`).
+ *
+ * The configuration sets how long engine creation, blocking view creation and other blocking
+ * requests wait for the Servo threads, or disables those timeouts. When a wait times out the call
+ * fails and `xian_web_engine_last_command_status` returns `XIAN_WEB_ENGINE_STATUS_TIMED_OUT` on the
+ * calling thread.
+ */
+XianWebEngine *xian_web_engine_create_with_config(void *shared_context, uint32_t default_width, uint32_t default_height, const char *resources_dir, const char *config_dir, uint32_t thread_pool_cap, const XianWebEngineThreadPoolConfig *thread_pools, uint32_t engine_flags, const XianWebEngineConfig *config);
+
+/**
+ * Fills `config` with the default engine configuration (30-second timeouts, fail fast), to be
+ * adjusted and passed to `xian_web_engine_create_with_config`.
+ */
+void xian_web_engine_config_default(XianWebEngineConfig *config);
+
+/**
+ * Destroys an engine created by `xian_web_engine_create`.
+ *
+ * This shuts down the dedicated Servo thread and destroys any remaining views/resources created by
+ * this engine. Do not use any views after destroying the engine.
+ */
+void xian_web_engine_destroy(XianWebEngine *engine);
+
+/**
+ * Runs a Servo content process for an engine created with
+ * `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`.
+ *
+ * Servo starts content processes by re-running the host executable with the arguments
+ * `--content-process <token>`. The host's entry point must detect them and call this function with
+ * the token before initializing anything else; it returns when the content process exits, after
+ * which the process should exit too.
+ *
+ * Returns `false` right away if `token` is NULL/invalid or multiprocess mode is not supported on
+ * this platform (Windows).
+ */
+bool xian_web_engine_run_content_process(const char *token);
+
+/**
+ * Drains pending vsync callbacks (Java-driven refresh).
+ */
+void xian_web_engine_tick(XianWebEngine *engine);
+
+/**
+ * Enters or leaves engine idle mode (e.g. while the game shows its pause menu).
+ *
+ * While idle, Servo threads coalesce their wakes and spin at most a few times per second, and
+ * fixed-interval views stop refreshing, so background CPU use drops to near zero. Input and
+ * commands still reach the views, with up to a quarter second of extra latency. Vsync-driven
+ * views keep following `xian_web_engine_tick`; stop ticking to pause them too.
+ *
+ * #### Parameters
+ * - `idle`: Whether the engine is idle.
+ * - `idle_flags`: `XIAN_WEB_ENGINE_IDLE_FLAG_*` applied when entering idle (`0` = none).
+ */
+bool xian_web_engine_set_idle(XianWebEngine *engine, bool idle, uint32_t idle_flags);
+
+/**
+ * Tells the engine the host is low on memory (e.g. the JVM is close to its heap limit or the OS
+ * signalled low memory) so it releases what it can.
+ *
+ * `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` frees the recycled vsync overflow nodes and every
+ * view's spare slot textures; `XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL` also clears Servo's
+ * in-memory HTTP cache. Servo exposes no image-cache or garbage-collection hook, so decoded
+ * images and script heaps are left to Servo. Views keep running; released memory is reallocated on
+ * demand.
+ *
+ * #### Parameters
+ * - `level`: `XIAN_WEB_ENGINE_MEMORY_PRESSURE_*`.
+ */
+bool xian_web_engine_notify_memory_pressure(XianWebEngine *engine, uint32_t level);
+
+/**
+ * Starts a WebDriver server on `port` so QA can script the embedded UI of the running client with
+ * standard W3C WebDriver tooling (Selenium, WebdriverIO, ...).
+ *
+ * The server is Servo's; it drives the views of the first Servo thread (all views unless
+ * `servo_threads` is above `1`). Views are created by the host, so window management commands
+ * (new window, window rect) are not honored. Calling again with the same port does nothing. Only
+ * builds with the `webdriver` feature support it (`XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER`); the
+ * server accepts unauthenticated connections, so never enable it in release clients.
+ *
+ * Returns `false` if `engine` is NULL, `port` is `0` or cannot be bound (e.g. already in use), the
+ * server is already listening on another port, WebDriver is not built in, or the engine is
+ * shutting down.
+ */
+bool xian_web_engine_enable_webdriver(XianWebEngine *engine, uint16_t port);
+
+/**
+ * Reports the display timing to vsync-driven views so Servo starts frames at the predicted vsync
+ * instants instead of whenever `xian_web_engine_tick` runs.
+ *
+ * Keep calling `xian_web_engine_tick`: it still drains callbacks queued before the timing was set.
+ * Call again when the refresh rate changes or the prediction drifts.
+ *
+ * #### Parameters
+ * - `refresh_rate_hz`: Display refresh rate in `(0, 1000]` (`0` returns to tick-driven frames).
+ * - `next_vsync_nanos`: Time of an upcoming vsync on the `xian_web_engine_monotonic_time_ns`
+ *   clock (`0` = phase unknown, vsyncs are assumed to start now).
+ */
+bool xian_web_engine_set_vsync_info(XianWebEngine *engine, double refresh_rate_hz, uint64_t next_vsync_nanos);
+
+/**
+ * Installs the process-wide engine initialization progress callback (`NULL` removes it).
+ *
+ * Install it before `xian_web_engine_create*` to drive a loading screen: each Servo thread reports
+ * `XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT` and `XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT`, then
+ * `XIAN_WEB_ENGINE_INIT_PHASE_READY` is reported once right before creation returns.
+ * `XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED` follows per Servo thread when its first view is
+ * created (e.g. by `xian_web_engine_preload`). The callback runs on the Servo threads or the
+ * creating thread and must return quickly.
+ */
+void xian_web_engine_set_init_progress_callback(XianWebEngineInitProgressFn callback, void *user_data);
+
+/**
+ * Warms the network and image caches for `count` known UI URLs (NUL-terminated UTF-8, absolute),
+ * e.g. while a loading screen is shown. Returns immediately.
+ *
+ * A hidden view requests every URL once and stays open until the next call or engine destruction;
+ * if no view exists yet, creating it also precompiles WebRender shaders.
+ *
+ * Returns `false` if an argument is invalid, a URL cannot be parsed, or the engine is shutting
+ * down.
+ */
+bool xian_web_engine_preload(XianWebEngine *engine, const char *const *urls, uint32_t count);
+
+/**
+ * Swaps the Servo resources directory at runtime (e.g. after a resource pack change).
+ *
+ * The swap happens on the Servo thread; resources read afterwards come from `path`, while resources
+ * Servo already loaded keep their current contents.
+ *
+ * Returns `false` if an argument is NULL/invalid, `path` is not a directory, or the engine is shutting
+ * down.
+ */
+bool xian_web_engine_set_resources_dir(XianWebEngine *engine, const char *path);
+
+/**
+ * Swaps the Servo resources to a zip archive (`.zip`/`.jar`/`.pak`) at `path`.
+ *
+ * The archive is read into memory and entries are inflated on demand; resource files are looked up
+ * at the archive root or under `resources/`. Like `xian_web_engine_set_resources_dir`, the swap
+ * happens on the Servo thread and affects resources read afterwards.
+ *
+ * Returns `false` if an argument is NULL/invalid, the archive cannot be read or parsed, or the engine
+ * is shutting down.
+ */
+bool xian_web_engine_set_resources_archive(XianWebEngine *engine, const char *path);
+
+/**
+ * Same as `xian_web_engine_set_resources_archive`, but takes the archive as `len` bytes in memory
+ * (e.g. read from a mod jar on the Java side). The bytes are copied.
+ *
+ * Returns `false` if an argument is NULL/invalid, the archive cannot be parsed, or the engine is
+ * shutting down.
+ */
+bool xian_web_engine_set_resources_archive_bytes(XianWebEngine *engine, const uint8_t *bytes, uintptr_t len);
+
+/**
+ * Sets the OS scheduling of the engine's own threads (the Servo thread and the refresh scheduler
+ * thread), e.g. to keep web work off the cores used by the game's render thread.
+ *
+ * - `servo_thread_priority`: Windows `THREAD_PRIORITY_*` value (`-2` lowest .. `2` highest,
+ *   `0` = normal).
+ * - `affinity_mask`: bit N allows logical CPU N; `0` keeps the current affinity.
+ *
+ * Applied asynchronously and best-effort (Windows `SetThreadPriority`/`SetThreadAffinityMask`);
+ * ignored on other platforms. Servo's internal worker pools are not affected.
+ *
+ * Returns `false` if `engine` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_set_thread_config(XianWebEngine *engine, int32_t servo_thread_priority, uint64_t affinity_mask);
+
+/**
+ * Registers a font from `len` bytes in memory (TTF/OTF/WOFF/WOFF2) under the NUL-terminated UTF-8
+ * CSS family name `family_name`, so pages can use it via `font-family` without installing it.
+ *
+ * The font applies to every view (existing views from their next load). The bytes are copied.
+ *
+ * Returns `false` if an argument is NULL/empty/invalid or the engine is shutting down.
+ */
+bool xian_web_engine_register_font(XianWebEngine *engine, const char *family_name, const uint8_t *bytes, uintptr_t len);
+
+/**
+ * Configures engine caches.
+ *
+ * - `cache_dir`: optional NUL-terminated UTF-8 directory for files the engine writes to disk itself
+ *   (e.g. staged uploads); NULL or empty keeps the current directory (default: a folder in the system
+ *   temp directory).
+ * - `max_bytes`: HTTP cache budget. Servo's HTTP cache lives in memory and has no size limit setting,
+ *   so `0` disables it and any other value enables it.
+ *
+ * Returns `false` if `engine` is NULL, `cache_dir` cannot be created, or the engine is shutting down.
+ */
+bool xian_web_engine_set_cache_config(XianWebEngine *engine, const char *cache_dir, uint64_t max_bytes);
+
+/**
+ * Sets a hard JavaScript heap limit for pages, protecting the engine's process from a leaking page
+ * (e.g. server-provided content).
+ *
+ * The limit is Servo's process-wide `js_mem_max` preference: it is shared by every engine in the
+ * process (the last call wins) and applies to the script event loop of every page whose event loop
+ * starts afterwards; pages already running keep the limit they started with. A page that runs out
+ * of it stops running scripts: the engine unloads it (`about:blank`) and queues a
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT` event, which also reports a page whose script thread
+ * crashed instead. `limit_mb = 0` removes the limit (default).
+ *
+ * Returns `false` if `engine` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_set_heap_limit(XianWebEngine *engine, uint32_t limit_mb);
+
+/**
+ * Clears Servo's HTTP cache and deletes the files staged in the engine cache directory.
+ *
+ * Returns `false` if `engine` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_clear_http_cache(XianWebEngine *engine);
+
+/**
+ * Limits the network traffic pages can generate, so a web UI cannot saturate the player's connection
+ * (e.g. during multiplayer).
+ *
+ * The limits are enforced by the loopback proxy that carries all HTTP(S) traffic, so they cover
+ * documents, subresources, `fetch` / XHR and workers of every engine running a proxy (engines
+ * created with `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` or after limits or a resolver were
+ * installed), and take effect immediately: at most
+ * `max_connections` server connections are open at once (the rest wait; idle keep-alive
+ * connections are closed for them), and reads from servers are paced to `max_bytes_per_second` in
+ * total.
+ * WebSocket connections are not limited. The limits are process-wide: every engine shares them and
+ * the last call wins. A `0` field disables that limit; all-zero limits (the default) remove the
+ * enforcement entirely.
+ *
+ * Returns `false` if `engine` or `limits` is NULL, or the engine is shutting down.
+ */
+bool xian_web_engine_set_network_limits(XianWebEngine *engine, const XianWebEngineNetworkLimits *limits);
+
+/**
+ * Limits the size of images pages display, so a page showing a huge image (e.g. 16k × 16k) does
+ * not spend a frame's worth of time and VRAM on it.
+ *
+ * From each view's next navigation, every `<img>` wider than `max_width`, taller than
+ * `max_height` or with more than `max_pixels` pixels is scaled down (keeping its aspect ratio and
+ * layout size) before it is shown, and a `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED` event is
+ * queued. Servo still decodes the original once, and cross-origin images are left as they are.
+ * How many images decode at once is set by `image_cache_threads` in the thread pool config. A `0`
+ * field disables that limit; all-zero limits (the default) remove the enforcement entirely.
+ *
+ * Returns `false` if `engine` or `limits` is NULL, or the engine is shutting down.
+ */
+bool xian_web_engine_set_image_limits(XianWebEngine *engine, const XianWebEngineImageLimits *limits);
+
+/**
+ * Writes the image load statistics of the engine into `out`: how many image loads pages made, how
+ * many of them loaded a URL already loaded on the same Servo thread (which Servo's image cache can
+ * serve without decoding again), how many of those repeats came from another view than the first
+ * load, and the distinct URLs loaded with their size at 4 bytes per pixel.
+ *
+ * Loads are reported for `<img>` elements (not CSS images, `data:` or `blob:` URLs) and counted
+ * per Servo thread, since each one has its own image cache. The statistics describe what pages
+ * load; Servo exposes neither the contents nor the size of its image cache. Safe to call from any
+ * thread.
+ *
+ * Returns `false` if `engine` or `out` is NULL.
+ */
+bool xian_web_engine_get_image_load_stats(XianWebEngine *engine, XianWebEngineImageLoadStats *out);
+
+/**
+ * Switches the engine network mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`), e.g. to guarantee no
+ * traffic leaves the game while the player is offline.
+ *
+ * `OFFLINE` fails every `http(s)` / `ws(s)` / `ftp` request; `CACHE_ONLY` only serves `http(s)`
+ * responses Servo's HTTP cache holds and can use without contacting the server. Local schemes
+ * (`data:`, `blob:`, `file:`, engine resources) always work. Besides cancelling requests, the
+ * engine's loopback proxy refuses every connection to a server and closes the open ones while not
+ * online; an engine running without a proxy (see `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`)
+ * relies on request cancellation alone and treats `CACHE_ONLY` as `OFFLINE`. The mode applies to
+ * every view immediately, including the pages currently shown. Blocked requests show up to the
+ * page as network errors.
+ *
+ * WebRTC is switched off through Servo's `dom_webrtc_enabled` preference, which is process-wide:
+ * while any engine of the process is not online, documents created anywhere in the process have no
+ * `RTCPeerConnection`; documents created earlier keep theirs.
+ *
+ * Returns `false` if `engine` is NULL, `mode` is unknown, or the engine is shutting down.
+ */
+bool xian_web_engine_set_network_mode(XianWebEngine *engine, uint32_t mode);
+
+/**
+ * Writes a health snapshot of the engine into `out`, so the host can detect a stuck Servo thread
+ * (e.g. a hung script) and show a "web UI not responding" overlay or destroy the engine.
+ *
+ * `millis_since_heartbeat` is how long the Servo thread has been busy in its current loop iteration
+ * (`0` while idle). Safe to call from any thread, even while the Servo thread is stuck.
+ *
+ * Returns `false` if `engine` or `out` is NULL.
+ */
+bool xian_web_engine_get_health(XianWebEngine *engine, XianWebEngineHealth *out);
+
+/**
+ * Writes the process-wide producer fence statistics into `out`: how many `GLsync` fences the
+ * Servo threads of every engine created and deleted, in total and per second.
+ *
+ * Fences are not pooled (a `GLsync` cannot be re-armed), so `created_per_second` tracks the
+ * published frame rate of fenced views. The rates cover the last window of at least one second and
+ * roll over on query, so poll about once per second. Safe to call from any thread.
+ *
+ * Returns `false` if `out` is NULL.
+ */
+bool xian_web_engine_get_fence_stats(XianWebEngineFenceStats *out);
+
+/**
+ * Writes the most recent tracing spans to `path` as a Chrome trace JSON file (open it in
+ * `chrome://tracing` or Perfetto) to diagnose frame hitches end-to-end.
+ *
+ * Spans are always recorded into a fixed process-wide ring (the latest 16384 are kept): Servo
+ * command drains, `spin_event_loop`, paint and present on the Servo thread(s), and frame acquires
+ * on the embedder's threads. Safe to call from any thread.
+ *
+ * Returns `false` if an argument is NULL/invalid or the file cannot be written.
+ */
+bool xian_web_engine_dump_trace(XianWebEngine *engine, const char *path);
+
+/**
+ * Turns Servo's time profiler on or off without rebuilding the library.
+ *
+ * `categories` is a combination of `XIAN_WEB_ENGINE_PROFILING_*` (`0` turns profiling off);
+ * `output_path` is required unless `categories` is `0`. Servo reads its profiler options once when
+ * it starts, so the setting is process-wide and applies to engines created afterwards: to profile a
+ * running UI, call this and then recreate the engine (destroy + create restarts Servo). Reports are
+ * written when that Servo instance shuts down. Engines already running are not affected, so the
+ * call takes no engine handle.
+ *
+ * Returns `false` if `categories` has unknown bits or `output_path` is NULL/invalid while
+ * profiling is requested.
+ */
+bool xian_web_engine_set_profiling(uint32_t categories, const char *output_path);
+
+/**
+ * Returns the status of the last request the calling thread sent to the engine
+ * (`XIAN_WEB_ENGINE_STATUS_*`).
+ *
+ * Call it after a request function returned `false` to tell a transient rejection apart from a
+ * permanent one:
+ *
+ * - `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL`: the request's command lane is full; retry later.
+ * - `XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN`: the engine is shutting down.
+ * - `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`: a blocking call (engine or view creation, view group
+ *   creation) gave up waiting for a Servo thread (see `xian_web_engine_create_with_config`).
+ *
+ * View destruction and engine shutdown use an unbounded urgent lane and are never rejected for
+ * capacity; bulky requests (fonts, exports, user scripts) use a small lane so they cannot crowd out
+ * ordinary ones.
+ */
+int32_t xian_web_engine_last_command_status(void);
+
+/**
+ * Polls queued events of a view into `out_events` (at most `capacity` entries).
+ *
+ * Text payloads are copied into `text_buf` and referenced by `text_offset/text_len`; `text_buf` may
+ * be NULL when `text_buf_capacity = 0`. An event whose text does not fit into the remaining buffer is
+ * kept for the next call. Returns the number of events written.
+ */
+uint32_t xian_web_engine_view_poll_events(XianWebEngineView *view, XianWebEngineViewEvent *out_events, uint32_t capacity, uint8_t *text_buf, uint32_t text_buf_capacity);
+
+/**
+ * Writes the monotonic delivery counters of `view`'s queues into `out`.
+ *
+ * Event and input queues are bounded and drop on overflow. Keep the previous snapshot and compare:
+ * if `events_dropped`, `input_dropped` or `binary_messages_dropped` grew, something was lost since
+ * then and the embedder should resync instead of silently desyncing (e.g. re-query the URL/title
+ * with `xian_web_engine_view_get_url` / `xian_web_engine_view_get_title`). Safe to call from any
+ * thread.
+ *
+ * Returns `false` if `view` or `out` is NULL.
+ */
+bool xian_web_engine_view_get_queue_stats(XianWebEngineView *view, XianWebEngineViewQueueStats *out);
+
+/**
+ * Tries to acquire the latest READY frames for a batch of views.
+ *
+ * This function is "compact": it only writes successfully acquired frames.
+ *
+ * - `views` is an array of `count` view pointers.
+ * - `out_view_indices` receives the corresponding input view index for each acquired frame.
+ * - `out_frames` receives the acquired frames, packed densely from 0..return_value.
+ * - Both output arrays must have capacity for at least `count` entries.
+ *
+ * Returns the number of acquired frames written.
+ */
+uint32_t xian_web_engine_views_acquire_frames(XianWebEngineView *const *views, uint32_t *out_view_indices, XianWebEngineFrame *out_frames, uint32_t count);
+
+/**
+ * Releases a batch of previously acquired frame slots for multiple views.
+ *
+ * If `consumer_fences` is NULL, all fences are treated as 0.
+ * If non-NULL, each fence must be a `GLsync` created by the embedder *after* sampling the texture.
+ * Ownership transfers to Rust and the embedder must NOT delete it; Rust will delete it after the
+ * producer sees it signaled.
+ *
+ * If a fence value is `0`, the slot becomes immediately reusable; the embedder must ensure the
+ * texture is no longer in use by the GPU before releasing (e.g., via other synchronization).
+ *
+ * If a view was created with `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE`, its corresponding
+ * consumer fence MUST be 0 (ignored).
+ */
+void xian_web_engine_views_release_frames(XianWebEngineView *const *views, const uint32_t *slots, const uint64_t *consumer_fences, uint32_t count);
+
+/**
+ * Pins a frame acquired from `view` so it can be kept across many game frames (e.g. for map render
+ * caching or screenshots) without starving the view.
+ *
+ * A held frame is already never overwritten, but while one slot is kept the producer has only two
+ * left and would keep reclaiming the newest READY frame ahead of painting, so the next acquire
+ * often finds nothing. A pinned slot is left out of rotation instead: the producer runs
+ * double-buffered on the other two slots and only takes the previous frame once the new one is
+ * done, so `xian_web_engine_views_acquire_frames` keeps returning fresh frames. Releasing a pinned
+ * slot is ignored; call `xian_web_engine_unpin_view_frame` first, then release it as usual. Unpin
+ * and release every pinned frame before destroying the view.
+ *
+ * Returns `false` if `view` is NULL, `slot` is out of range, or the slot is not currently held.
+ */
+bool xian_web_engine_pin_view_frame(XianWebEngineView *view, uint32_t slot);
+
+/**
+ * Unpins a frame pinned by `xian_web_engine_pin_view_frame`. The frame is held again: release it
+ * with `xian_web_engine_views_release_frames` as usual once done with it.
+ *
+ * Returns `false` if `view` is NULL, `slot` is out of range, or the slot is not pinned.
+ */
+bool xian_web_engine_unpin_view_frame(XianWebEngineView *view, uint32_t slot);
+
+/**
+ * Installs the view's frame-available callback (`NULL` removes it).
+ *
+ * The callback runs on the Servo thread right after a new frame is published and receives its
+ * `frame_seq`, so an embedder can wake its render thread only when there is something to acquire
+ * instead of polling every frame. Acquire/release still happen on the render thread. The callback
+ * must return quickly and must not replace or remove its own view's callback. Replacing or
+ * removing the callback (including destroying the view, which removes it) waits for a call in
+ * flight, so once this function returns the previous callback is not running and will not run
+ * again, and its `user_data` may be freed.
+ */
+void xian_web_engine_set_frame_callback(XianWebEngineView *view, XianWebEngineFrameFn callback, void *user_data);
+
+/**
+ * Returns the current time on the engine monotonic clock, in nanoseconds: the clock
+ * `XianWebEngineFrame.present_time_ns` is measured on.
+ *
+ * Subtract a frame's `present_time_ns` from it to get the frame's age, or sample it next to the
+ * host's own clock once to convert between the two. Safe to call from any thread.
+ */
+uint64_t xian_web_engine_monotonic_time_ns(void);
+
+/**
+ * Installs an embedder-provided GLFW function table.
+ *
+ * This must be called before `xian_web_engine_create`.
+ * The engine will not attempt to locate `glfw3.dll` / `libglfw.so` by name.
+ *
+ * All function pointers must come from the same GLFW library instance that produced the
+ * `GLFWwindow*` passed to `xian_web_engine_create`.
+ *
+ * Returns `true` on success.
+ */
+bool xian_web_engine_set_glfw_api(const EmbedderGlfwApi *api);
+
+/**
+ * Installs the main-thread trampoline (once per process, before `xian_web_engine_create`).
+ *
+ * GLFW only allows window creation/destruction on the main thread, and macOS enforces it. With a
+ * trampoline installed, the Servo threads hand those calls to `callback`, which must arrange for
+ * `task(task_data)` to run on the main thread (immediately or on the next frame) without waiting
+ * for it. While the main thread is blocked in `xian_web_engine_create*` or
+ * `xian_web_engine_destroy`, it runs the queued calls itself, so those must be called on the main
+ * thread too. Required on macOS; optional elsewhere.
+ *
+ * Returns `false` if `callback` is NULL or a trampoline is already installed.
+ */
+bool xian_web_engine_set_main_thread_trampoline(XianWebEngineMainThreadFn callback, void *user_data);
+
+/**
+ * Runs the golden-image rendering tests on the calling thread and returns the number of failed
+ * cases, or `-1` if an argument is NULL/invalid or the pages cannot be listed. Only exported by
+ * builds with the `golden-tests` feature (`XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS`).
+ *
+ * Create `engine` on a context nobody presents (a hidden GLFW window or a surfaceless EGL context).
+ * Every `*.html` page in `pages_dir` (e.g. the bundled `tests/golden/pages`) is loaded into a fresh
+ * `width`x`height` view, once as `RGBA8` and once as `SRGB8_A8`; after `load` and two animation
+ * frames the frame is read back as for PNG export and compared against `<page>.png` /
+ * `<page>.srgb.png` in `golden_dir`, allowing `tolerance` (0..=255) per channel. Captured frames and,
+ * on mismatch, `<case>.diff.png` (offending pixels in red) are written to `output_dir`; each case is
+ * logged. `XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE` in `flags` stores the captured frames as the new
+ * goldens instead.
+ */
+int32_t xian_web_engine_run_golden_tests(XianWebEngine *engine, const char *pages_dir, const char *golden_dir, const char *output_dir, uint32_t width, uint32_t height, uint32_t tolerance, uint32_t flags);
+
+/**
+ * Creates a view group and returns its non-zero ID (`0` on failure).
+ *
+ * Views created into the group share one refresh driver, so their frames are scheduled in the same
+ * tick and published together. `target_fps = 0` means the group is driven by external vsync
+ * (`xian_web_engine_tick`).
+ */
+uint32_t xian_web_engine_view_group_create(XianWebEngine *engine, uint32_t target_fps);
+
+/**
+ * Creates a view group ticking at a fractional rate of `fps_numerator / fps_denominator` frames
+ * per second and returns its non-zero ID (`0` on failure).
+ *
+ * Use it to match a display whose refresh rate is not a whole number (e.g. `14386 / 100` for
+ * 143.86 Hz, `60000 / 1001` for 59.94 Hz). Ticks follow an exact frame grid, so long-running
+ * views do not drift against the display. `fps_numerator = 0` means the group is driven by
+ * external vsync, as with `xian_web_engine_view_group_create`.
+ */
+uint32_t xian_web_engine_view_group_create_fractional(XianWebEngine *engine, uint32_t fps_numerator, uint32_t fps_denominator);
+
+/**
+ * Destroys a view group.
+ *
+ * Views already created into the group keep running on the shared tick; no new views can join.
+ */
+void xian_web_engine_view_group_destroy(XianWebEngine *engine, uint32_t group);
+
+/**
+ * Creates one view inside a view group (refresh rate comes from the group).
+ *
+ * Returns NULL if `engine` is NULL or the group does not exist.
+ */
+XianWebEngineView *xian_web_engine_view_create_in_group(XianWebEngine *engine, uint32_t group, uint32_t width, uint32_t height, uint32_t view_flags);
+
+/**
+ * Registers a host function the page can call as `window.xianHost.call(name, args)` (`NULL`
+ * `callback` unregisters it); registrations survive navigations.
+ *
+ * `args` is serialized with `JSON.stringify` (`undefined` becomes `null`). The callback runs on the
+ * Servo thread and must return quickly; answer each call with
+ * `xian_web_engine_view_respond_host_call`. Calls to unregistered names are rejected at once, and
+ * calls still unanswered when the page navigates away are dropped.
+ *
+ * Returns `false` if an argument is invalid or the engine is shutting down.
+ */
+bool xian_web_engine_register_host_function(XianWebEngineView *view, const char *name, XianWebEngineHostFunctionFn callback, void *user_data);
+
+/**
+ * Answers a host function call: `success` resolves the page's Promise with `result` parsed as JSON
+ * (`NULL` or empty resolves with `undefined`); otherwise the Promise is rejected with an `Error`
+ * whose message is `result`. Unknown or stale call IDs are ignored.
+ *
+ * Returns `false` if an argument is invalid or the engine is shutting down.
+ */
+bool xian_web_engine_view_respond_host_call(XianWebEngineView *view, uint64_t call_id, bool success, const char *result);
+
+/**
+ * Sends `len` bytes to the page, which receives them as an `ArrayBuffer`:
+ * `xianHost.addEventListener("binary", e => draw(e.channel, e.data))`. The bytes are copied into a
+ * pooled buffer, so `data` can be reused as soon as this returns. Messages are delivered in order
+ * and dropped if no page is listening.
+ *
+ * Returns `false` if an argument is invalid, `len` exceeds 16 MiB, or the engine is shutting down.
+ */
+bool xian_web_engine_view_post_binary(XianWebEngineView *view, uint32_t channel, const uint8_t *data, uintptr_t len);
+
+/**
+ * Looks up a binary message the page sent with `xianHost.sendBinary(channel, typedArray)`
+ * (announced by a `BINARY_MESSAGE` event) and writes its address and length to `out_data` /
+ * `out_len`. The bytes are owned by the engine and stay valid until
+ * `xian_web_engine_view_release_binary_message`.
+ *
+ * Returns `false` if an argument is invalid or the message is unknown or already released.
+ */
+bool xian_web_engine_view_get_binary_message(XianWebEngineView *view, uint64_t message_id, const uint8_t **out_data, uintptr_t *out_len);
+
+/**
+ * Releases a binary message received from the page; its buffer returns to the view's pool. Unreleased
+ * messages count against a limit of 256, beyond which further page messages are dropped.
+ *
+ * Returns `false` if `view` is NULL or the message is unknown or already released.
+ */
+bool xian_web_engine_view_release_binary_message(XianWebEngineView *view, uint64_t message_id);
+
+/**
+ * Registers a GL texture of the embedder context (`GL_TEXTURE_2D`, e.g. the player's skin or a map)
+ * under `name` for every view (`texture` 0 unregisters it). Pages fetch it with
+ * `await xianHost.getTexture(name)`, which resolves to an `ImageBitmap` that can be drawn on a
+ * canvas or uploaded with `texImage2D`.
+ *
+ * Each request reads the texture back on the Servo thread's shared context and copies it into the
+ * page (Servo cannot import foreign textures into WebGL), so it reflects the texture at the time of
+ * the request; fetch again after the embedder updates it. Set `flip_y` for render targets whose
+ * row 0 is the bottom row. The texture must stay alive while registered.
+ *
+ * Returns `false` if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+ * the engine is shutting down.
+ */
+bool xian_web_engine_register_texture(XianWebEngine *engine, const char *name, uint32_t texture, uint32_t width, uint32_t height, bool flip_y);
+
+/**
+ * Pushes a frame the embedder rendered into `texture_id` (`GL_TEXTURE_2D` of the embedder context,
+ * row 0 at the bottom as rendered into an FBO) to stream `stream_id` of the page, which receives it
+ * as an `ImageBitmap`: `xianHost.openStream(id).attach(canvas)` or the stream's `frame` event.
+ *
+ * This mirrors the triple buffer with the roles swapped. Insert a `GLsync` after rendering and
+ * pass it as `fence` (or 0), and flush; the engine waits for it on the GPU, copies the texture and
+ * deletes the fence. An `EXTERNAL_FRAME_RELEASED` event then reports that the texture may be
+ * rendered into again, so rotating two or three textures per stream avoids stalls. Frames for
+ * streams the page has not opened are released without being read. Keep frames small: each one is
+ * copied through the CPU.
+ *
+ * Returns `false` if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+ * the engine is shutting down; the caller still owns `fence` in that case.
+ */
+bool xian_web_engine_push_external_frame(XianWebEngineView *view, uint32_t stream_id, uint32_t texture_id, uint32_t width, uint32_t height, uint64_t fence);
+
+/**
+ * Sends a batch of input events to a view.
+ *
+ * Pointer positions are mapped through the view's input transform first, if one is set
+ * (`xian_web_engine_set_view_input_transform`).
+ *
+ * Returns the number of accepted events (may be less than `count` if the queue is full).
+ * If the view is inactive, events are treated as accepted and dropped (fast path).
+ * Unknown event kinds are treated as accepted and dropped (and reported through the log callback).
+ */
+uint32_t xian_web_engine_view_send_input_events(XianWebEngineView *view, const XianWebEngineInputEvent *events, uint32_t count);
+
+/**
+ * Sends one mouse button, wheel or key event and reports how the page handled it, so embedders can
+ * pass clicks on empty page areas through to the game.
+ *
+ * The event is dispatched after the input sent before it (its pointer position is mapped through
+ * the view's input transform like `xian_web_engine_view_send_input_events`). Once the page handled
+ * it, a `XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT` event with `request_id = correlation_id` reports
+ * whether the page consumed it; pointer events are also hit tested against the page's elements
+ * (`XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT`). Input sent with
+ * `xian_web_engine_view_send_input_events` afterwards may be dispatched first, so send the
+ * matching release through this function too. Events sent while the view is inactive are dropped
+ * without a result.
+ *
+ * Returns `false` if `view` or `event` is NULL, the event kind is not tracked, `correlation_id` is
+ * `0`, or the engine is shutting down.
+ */
+bool xian_web_engine_view_send_tracked_input_event(XianWebEngineView *view, const XianWebEngineInputEvent *event, uint64_t correlation_id);
+
+/**
+ * Sets the view's input transform: a row-major 3x3 homography (9 floats) mapping host coordinates
+ * `(x, y, 1)` to view pixels `(x', y', w)`, divided by `w`. Once set, the pointer positions of
+ * mouse move, button and wheel events passed to `xian_web_engine_view_send_input_events` are in host
+ * coordinates, so a view textured onto an in-world 3D quad can take raw screen or ray hit
+ * coordinates. Relative mouse deltas and keys are not affected; points on the homography's
+ * vanishing line are sent off the view at `(-1, -1)`.
+ *
+ * `matrix = NULL` clears the transform (identity). Returns `false` (leaving the transform unchanged)
+ * if `view` is NULL or a matrix entry is not finite.
+ */
+bool xian_web_engine_set_view_input_transform(XianWebEngineView *view, const float *matrix);
+
+/**
+ * Synthesizes a left click at `(x, y)` (logical pixels): a mouse move onto the point, then a button
+ * press and release, fed on the Servo thread with human-like delays so pages see the same event
+ * order as from a real user. Clicks and typed text are queued one after another.
+ *
+ * Meant for integration tests and macros; synthesized input is not recorded. Returns `false` if
+ * `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+ */
+bool xian_web_engine_click_at(XianWebEngineView *view, float x, float y);
+
+/**
+ * Synthesizes typing the NUL-terminated UTF-8 `text`: one key press and release per character, fed
+ * on the Servo thread with human-like delays into the focused element. `'\n'` and `'\t'` press
+ * Enter and Tab; other control characters are skipped. Clicks and typed text are queued one after
+ * another.
+ *
+ * Meant for integration tests and macros; synthesized input is not recorded. Returns `false` if an
+ * argument is NULL/invalid, `text` has nothing to type, or the engine is shutting down.
+ */
+bool xian_web_engine_type_text(XianWebEngineView *view, const char *text);
+
+/**
+ * Installs the process-wide log callback (`NULL` removes it).
+ *
+ * Messages at `max_level` or less verbose (`XIAN_WEB_ENGINE_LOG_LEVEL_*`) are forwarded. Rejected
+ * FFI arguments are reported at `ERROR` with the function and argument name; debug builds of the
+ * library additionally report every FFI call with its arguments at `TRACE`. The callback may run on
+ * any thread and may still be running briefly after it is replaced.
+ *
+ * Returns `false` if `max_level` is not a defined level.
+ */
+bool xian_web_engine_set_log_callback(XianWebEngineLogFn callback, void *user_data, uint32_t max_level);
+
+/**
+ * Creates `count` hidden, parked views ahead of time so opening a UI screen does not pay the
+ * WebView + GL resource creation cost.
+ *
+ * Blocks until the views are created. Returns the number of views added to the pool.
+ */
+uint32_t xian_web_engine_prewarm_views(XianWebEngine *engine, uint32_t count, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags);
+
+/**
+ * Takes one prewarmed view, resizes it to `width x height` (0 keeps its size), and activates it.
+ *
+ * The view keeps the `target_fps`/`view_flags` it was prewarmed with. Returns NULL when the pool is
+ * empty; destroy the result with `xian_web_engine_view_destroy` as usual.
+ */
+XianWebEngineView *xian_web_engine_take_prewarmed_view(XianWebEngine *engine, uint32_t width, uint32_t height);
+
+/**
+ * Starts recording every input event, resize and navigation delivered to `view` through this API
+ * (`xian_web_engine_view_send_input_events`, `xian_web_engine_view_resize`,
+ * `xian_web_engine_view_load_url`, ...) into `path`, with timestamps. A recording already running on
+ * the view is finished and replaced.
+ *
+ * Input is recorded as accepted by the view (events dropped by a full queue are not recorded).
+ * Returns `false` if an argument is NULL/invalid or the file cannot be created.
+ */
+bool xian_web_engine_view_start_recording(XianWebEngineView *view, const char *path);
+
+/**
+ * Stops recording `view` and flushes the recording file (destroying the view also closes it).
+ *
+ * Returns `false` if `view` is NULL, nothing was being recorded, or the file could not be written.
+ */
+bool xian_web_engine_view_stop_recording(XianWebEngineView *view);
+
+/**
+ * Replays a recording made with `xian_web_engine_view_start_recording` into `view`, feeding every
+ * action back with its recorded timing, measured from when the Servo thread picks up the request.
+ * Use a freshly created view (and engine) of the recorded size for a faithful reproduction.
+ *
+ * A `XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED` event is queued after the last action. Starting
+ * another replay on the view replaces the running one. Replayed actions are not recorded again, and
+ * input is dropped while the view is inactive, as it is for live input.
+ *
+ * Returns `false` if an argument is NULL/invalid, the file cannot be read or is not a recording, or
+ * the engine is shutting down.
+ */
+bool xian_web_engine_view_replay(XianWebEngineView *view, const char *path);
+
+/**
+ * Starts streaming `view`'s frames on `port`: open `http://<host>:<port>/` in a browser to watch
+ * the view live. A screencast already running on the view is replaced (use a different port, or
+ * stop it first).
+ *
+ * Frames are captured through the PNG export readback path while the view is active and not
+ * frozen, at most `max_fps` times per second (`0` = 10; capped at 60), and sent as a
+ * `multipart/x-mixed-replace` stream of PNG images to at most 4 clients. The server listens on
+ * every interface without authentication, so only use it in development builds; builds with the
+ * `screencast` feature support it (`XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST`).
+ *
+ * Returns `false` if `view` is NULL, `port` is `0`, the port cannot be bound, screencasting is not
+ * built in, or the engine is shutting down.
+ */
+bool xian_web_engine_view_start_screencast(XianWebEngineView *view, uint16_t port, uint32_t max_fps);
+
+/**
+ * Stops `view`'s screencast and closes its server (destroying the view also stops it).
+ *
+ * Returns `false` if `view` is NULL, screencasting is not built in, or the engine is shutting
+ * down.
+ */
+bool xian_web_engine_view_stop_screencast(XianWebEngineView *view);
+
+/**
+ * Updates the view's device orientation and, optionally, its acceleration.
+ *
+ * - `orientation`: 4 floats, the rotation quaternion `[x, y, z, w]` from the Earth frame
+ *   (X east, Y north, Z up) to the device frame (X right, Y up, Z out of the screen). It is
+ *   normalized and reported as `deviceorientation` `alpha`/`beta`/`gamma` in degrees.
+ * - `acceleration`: 3 floats `[x, y, z]` in m/s² including gravity, in the device frame, reported
+ *   as `devicemotion` `accelerationIncludingGravity`; NULL fires only `deviceorientation`.
+ *
+ * Call it once per host frame while the effect is wanted. Returns `false` if `view`/`orientation`
+ * is NULL, a value is not finite, the quaternion is zero, or the engine is shutting down.
+ */
+bool xian_web_engine_view_update_sensors(XianWebEngineView *view, const float *orientation, const float *acceleration);
+
+/**
+ * Copies the URL of the view's current page into `buf` and returns its length (see the module
+ * docs). Empty until the first navigation commits.
+ *
+ * Returns `0` if an argument is NULL/invalid.
+ */
+uint32_t xian_web_engine_view_get_url(XianWebEngineView *view, uint8_t *buf, uint32_t capacity);
+
+/**
+ * Copies the title of the view's current page into `buf` and returns its length (see the module
+ * docs). Empty if the page has no title.
+ *
+ * Returns `0` if an argument is NULL/invalid.
+ */
+uint32_t xian_web_engine_view_get_title(XianWebEngineView *view, uint8_t *buf, uint32_t capacity);
+
+/**
+ * Copies the message of the view's most recent failure (invalid URL, failed export, page crash)
+ * into `buf` and returns its length (see the module docs). Empty if nothing has failed yet; the
+ * message is kept until the next failure.
+ *
+ * Returns `0` if an argument is NULL/invalid.
+ */
+uint32_t xian_web_engine_view_get_last_error(XianWebEngineView *view, uint8_t *buf, uint32_t capacity);
+
+/**
+ * Creates one view.
+ *
+ * `target_fps = 0` means the view is driven by external vsync (`xian_web_engine_tick`).
+ */
+XianWebEngineView *xian_web_engine_view_create(XianWebEngine *engine, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags);
+
+/**
+ * Creates one view in a storage group (`XIAN_WEB_ENGINE_STORAGE_GROUP_*` or any embedder-chosen ID):
+ * views created with the same group share cookies, `localStorage`, `sessionStorage`, IndexedDB and
+ * the HTTP cache per origin (e.g. all pages of the mod's own site), views of other groups cannot
+ * see them, and `XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL` keeps the storage in memory only
+ * (e.g. untrusted server content).
+ *
+ * Every group runs its own Servo instance (thread, GL context and storage), started when the
+ * group's first view is created; that call blocks like engine creation.
+ */
+XianWebEngineView *xian_web_engine_view_create_in_storage_group(XianWebEngine *engine, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags, uint32_t storage_group);
+
+/**
+ * Creates one view without blocking: returns a provisional handle right away and reports through
+ * `callback` once the Servo thread has created the view, so the calling (render) thread never
+ * stalls on the Servo thread the way `xian_web_engine_view_create` can (for up to the engine's
+ * configured `view_timeout_ms`, or indefinitely under
+ * `XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER`).
+ *
+ * The handle is usable immediately. Input, resizes and `load_url` are queued as usual; other
+ * requests are held back and applied once the view exists; closing or destroying it before then
+ * destroys the view as soon as it is created. No frames are published and
+ * `xian_web_engine_view_is_alive` returns `false` until creation has finished. If creation fails,
+ * requests through the handle fail; destroy it as usual.
+ *
+ * `callback` may be NULL. Returns NULL (and never invokes `callback`) if an argument is invalid or
+ * the engine is shutting down.
+ */
+XianWebEngineView *xian_web_engine_create_view_async(XianWebEngine *engine, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags, XianWebEngineViewCreatedFn callback, void *user_data);
+
+/**
+ * Destroys a view created by `xian_web_engine_view_create` and frees the handle.
+ *
+ * Release acquired frames before destroying the view, and do not sample any textures from this
+ * view afterwards. GL deletion of the slot textures is deferred until every release fence has
+ * signaled, so GPU work already submitted against them stays valid; frames still held at
+ * destruction keep their textures alive for at most one more second.
+ *
+ * If the view was already closed with `xian_web_engine_close_view`, this only frees the handle.
+ * `view` must not be used after this call.
+ */
+void xian_web_engine_view_destroy(XianWebEngineView *view);
+
+/**
+ * Closes a view: the page is torn down on the Servo thread exactly as by
+ * `xian_web_engine_view_destroy`, but the handle stays valid until `xian_web_engine_view_destroy`
+ * frees it.
+ *
+ * This decouples the view's lifetime from the handle's, for GC-based hosts: close the view
+ * deterministically (e.g. when its screen closes) and let the object that owns the pointer free
+ * the handle whenever it is collected. Other references may keep calling through the handle in
+ * the meantime; requests then fail or are ignored, no frames are acquired, and
+ * `xian_web_engine_view_is_alive` returns `false`. The same rules as for destroying apply to held
+ * frames and textures.
+ *
+ * Returns `false` if `view` is NULL or already closed.
+ */
+bool xian_web_engine_close_view(XianWebEngineView *view);
+
+/**
+ * Returns whether the view behind `view` still exists: it was neither closed with
+ * `xian_web_engine_close_view` nor lost to engine shutdown. Commands to a view are matched by its
+ * ID and a per-view token, so a view ID reused after close never makes a stale handle look alive.
+ *
+ * Safe to call from any thread for as long as the handle has not been destroyed. Returns `false` if
+ * `view` is NULL, or if it is a handle-table ID that was already destroyed.
+ */
+bool xian_web_engine_view_is_alive(XianWebEngineView *view);
+
+/**
+ * Sets whether the view is active (active views render and accept input).
+ */
+void xian_web_engine_view_set_active(XianWebEngineView *view, uint8_t active);
+
+/**
+ * Requests navigation to the given URL.
+ *
+ * The URL must be a NUL-terminated UTF-8 string.
+ *
+ * Return value:
+ * - `false` if `view`/`url` is NULL or the string is not valid UTF-8.
+ * - `true` otherwise (the request is recorded and coalesced; URL parsing happens on the Servo thread).
+ */
+bool xian_web_engine_view_load_url(XianWebEngineView *view, const char *url);
+
+/**
+ * Requests a resize (in pixels).
+ *
+ * This call is coalesced: only the latest size is kept until the Servo thread drains it (and, with
+ * a resize debounce set, until resizes stop for the debounce period).
+ */
+void xian_web_engine_view_resize(XianWebEngineView *view, uint32_t width, uint32_t height);
+
+/**
+ * Sets the view's resize debounce: resizes are held back until none arrived for `debounce_ms`, then
+ * only the latest size is applied, avoiding a slot texture reallocation per step while a panel is
+ * dragged. Until then frames keep the previous size (`XianWebEngineFrame` reports it), so the host
+ * draws them stretched. `0` (default) applies every resize immediately.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_resize_debounce(XianWebEngineView *view, uint32_t debounce_ms);
+
+/**
+ * Resizes the view (in pixels) immediately, bypassing the resize debounce (e.g. when a drag ends);
+ * a resize held back by the debounce is dropped.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_resize_immediate(XianWebEngineView *view, uint32_t width, uint32_t height);
+
+/**
+ * Checks per-view sandbox restrictions (`XIAN_WEB_ENGINE_SANDBOX_*` bits; `0` means none).
+ *
+ * Servo applies sandboxing to nested browsing contexts (`<iframe sandbox>`), not to the top-level
+ * document of a WebView, so only restrictions the engine enforces itself are accepted:
+ * `XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS` always holds, because the engine never opens popups or
+ * auxiliary windows. The other restrictions cannot be enforced on the page the view loads and are
+ * rejected instead of being silently ignored; show untrusted HTML (e.g. server MOTD) inside an
+ * `<iframe sandbox>` of a page the embedder controls.
+ *
+ * Returns `false` if `view` is NULL or `sandbox_flags` contains unknown bits or a restriction that
+ * cannot be enforced.
+ */
+bool xian_web_engine_view_set_sandbox(XianWebEngineView *view, uint32_t sandbox_flags);
+
+/**
+ * Requests an asynchronous export of the current page (`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`).
+ *
+ * The capture is taken on the Servo thread and encoded/written on a helper thread; completion is
+ * reported through `xian_web_engine_view_poll_events` as an `EXPORT_COMPLETE` event carrying the
+ * returned request ID. Servo has no print pipeline yet, so the whole document is captured by
+ * scrolling it viewport by viewport and stitching the screenshots (fixed/sticky elements repeat
+ * per viewport, and the original scroll offset is restored); PDF output paginates that image into
+ * A4-proportioned pages.
+ *
+ * Returns the request ID, or `0` if an argument is invalid or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_export(XianWebEngineView *view, const char *path, uint32_t format);
+
+/**
+ * Captures the first element matching `css_selector` (NUL-terminated UTF-8), so hosts can
+ * rasterize individual widgets (e.g. item tooltips) into their own atlases.
+ *
+ * The element's bounding rectangle is resolved on the Servo thread and clipped to the viewport,
+ * then only that region of the back slot is read back once the pending frame has been painted.
+ * Parts of the element outside the viewport are not captured, and whatever is painted over it
+ * (overlapping elements, the default background) is captured with it. An invalid selector, no
+ * match, or an element entirely outside the viewport fail.
+ *
+ * `callback` is invoked exactly once if this returns `true`, and never if it returns `false`
+ * because an argument is invalid. Returns `false` (after invoking `callback` with
+ * `XIAN_WEB_ENGINE_STATUS_FAILED`) if the view was closed, the command queue is full or the engine
+ * is shutting down.
+ */
+bool xian_web_engine_capture_element(XianWebEngineView *view, const char *css_selector, XianWebEngineElementCaptureFn callback, void *user_data);
+
+/**
+ * Requests an asynchronous snapshot of the page's accessibility tree.
+ *
+ * The result is reported through `xian_web_engine_view_poll_events` as an `ACCESSIBILITY_TREE`
+ * event whose text is a JSON document (roles, accessible names, and bounds in CSS pixels relative to
+ * the viewport). Hosts can use it for controller/keyboard navigation or screen-reader integration.
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_request_accessibility_tree(XianWebEngineView *view);
+
+/**
+ * Pauses every playing `<audio>`/`<video>` element of the page (including same-origin iframes).
+ *
+ * Completion is reported as a `MEDIA_STATE` event carrying the returned request ID. Media session
+ * playback changes are reported separately as `MEDIA_PLAYBACK` events.
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_pause_all_media(XianWebEngineView *view);
+
+/**
+ * Resumes the media elements paused by `xian_web_engine_view_pause_all_media`.
+ *
+ * Media the page paused on its own stays paused. Completion is reported as a `MEDIA_STATE` event.
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_resume_all_media(XianWebEngineView *view);
+
+/**
+ * Requests an opaque session blob (history entries, current URL, scroll offset) for this view.
+ *
+ * The blob is reported as the text of a `SESSION_SAVED` event carrying the returned request ID and can
+ * be passed to `xian_web_engine_view_create_from_session` later (e.g. when a world is reloaded).
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_save_session(XianWebEngineView *view);
+
+/**
+ * Selects the contents of the focused text field, or the whole document if no text field has focus.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_select_all(XianWebEngineView *view);
+
+/**
+ * Requests the page's selected text.
+ *
+ * The text is reported as a `SELECTED_TEXT` event carrying the returned request ID (empty if nothing
+ * is selected). Selections inside password fields are never reported.
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_request_selected_text(XianWebEngineView *view);
+
+/**
+ * Copies the page's selection, e.g. for a host-drawn copy button over a read-only view.
+ *
+ * The page's `copy` handlers run first; the selected text is then reported as a `COPY` event
+ * carrying the returned request ID. The engine does not touch the system clipboard: the embedder
+ * places the text there itself (e.g. with `glfwSetClipboardString`).
+ *
+ * Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+ */
+uint64_t xian_web_engine_view_copy_selection(XianWebEngineView *view);
+
+/**
+ * Enables or disables capturing the page's `console.*` output (disabled by default).
+ *
+ * While enabled, every console call is reported as a `CONSOLE_MESSAGE` event with its level, source
+ * URL and line, so page errors can be forwarded to the game log without attaching devtools. Calls
+ * made while capture is disabled are not reported retroactively.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_console_capture(XianWebEngineView *view, bool enabled);
+
+/**
+ * Creates one view and restores a session blob from a `SESSION_SAVED` event.
+ *
+ * `session` must be a NUL-terminated UTF-8 string. The back/forward history is rebuilt by loading
+ * the saved entries in order and going back to the saved current entry, whose scroll offset is
+ * re-applied after it has loaded.
+ *
+ * Returns NULL if an argument is invalid, the blob is malformed, or view creation fails.
+ */
+XianWebEngineView *xian_web_engine_view_create_from_session(XianWebEngine *engine, const char *session, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags);
+
+/**
+ * Creates a view showing the same page as `view`, e.g. to split a page into a second panel.
+ *
+ * This is the `xian_web_engine_view_create_from_session` path without the `SESSION_SAVED` round
+ * trip: the source's current URL and scroll offset are taken from its published snapshots, the
+ * page is loaded again (from Servo's HTTP cache where it can be) and the scroll offset re-applied
+ * after loading. Script state, form input and back/forward entries are not copied, and the clone
+ * does not join the source's view group. `view_flags` apply to the clone only.
+ *
+ * Returns NULL if an argument is invalid, the source has no document yet, or view creation fails.
+ */
+XianWebEngineView *xian_web_engine_clone_view(XianWebEngine *engine, XianWebEngineView *view, uint32_t width, uint32_t height, uint32_t target_fps, uint32_t view_flags);
+
+/**
+ * Sets the default page background as `0xRRGGBBAA` (e.g. `0x00000000` for a transparent HUD).
+ *
+ * The color applies from the next navigation and only where the page does not set its own
+ * background.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_background(XianWebEngineView *view, uint32_t rgba);
+
+/**
+ * Renders the view at a different internal resolution (dynamic resolution scaling) while the page
+ * keeps the same CSS viewport size.
+ *
+ * `scale < 1.0` trades sharpness for frame rate on heavy pages; `scale > 1.0` supersamples. The value
+ * is clamped to `0.25..=2.0`. Input coordinates and `xian_web_engine_view_resize` stay in logical
+ * pixels; acquired frames report the scaled texture size, so sample them over the view's logical
+ * quad.
+ *
+ * Returns `false` if `view` is NULL, `scale` is not a positive finite number, or the engine is
+ * shutting down.
+ */
+bool xian_web_engine_view_set_render_scale(XianWebEngineView *view, float scale);
+
+/**
+ * Decouples the page's CSS layout size from the view's texture size, like a mobile viewport meta
+ * tag: the page lays out `css_width` x `css_height` CSS px and is scaled to fill the texture, so a
+ * 4K texture can render a fixed 1280 px wide layout and physical resizes only rescale it instead
+ * of reflowing the page.
+ *
+ * A `0` dimension follows the view size on that axis (`css_width = 1280, css_height = 0` keeps the
+ * width fixed and the aspect ratio of the view). When both are set and the aspect ratios differ,
+ * the page gets at least the requested size on both axes. `(0, 0)` removes the override. Input
+ * coordinates stay in view pixels and are mapped onto the scaled page.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_set_view_viewport(XianWebEngineView *view, uint32_t css_width, uint32_t css_height);
+
+/**
+ * Moves the view to a new size and render configuration without reloading the page, e.g. when the
+ * host switches between a windowed panel and a fullscreen browser.
+ *
+ * `new_flags` may change `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X`, `XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`
+ * and the color format; `UNSAFE_NO_CONSUMER_FENCE`, `INPUT_SINGLE_PRODUCER`,
+ * `UNSAFE_NO_PRODUCER_FENCE`, `VULKAN_EXPORT` and `ORPHANING_RESIZE` are fixed at creation and must
+ * match. The render
+ * scale is reset to the one implied by `SUPERSAMPLE_2X`. Slot textures are reallocated in place, so
+ * frames acquired after the move report the new size and color format; a frame still held keeps
+ * the old one until it is released. If the shared context cannot provide the new color format, the
+ * old one is kept and a warning is logged.
+ *
+ * Returns `false` if `view` is NULL, a fixed flag differs, the color format is unknown, or the
+ * engine is shutting down.
+ */
+bool xian_web_engine_move_view(XianWebEngineView *view, uint32_t new_width, uint32_t new_height, uint32_t new_flags);
+
+/**
+ * Freezes the view to reclaim GPU memory: its three slot textures are shrunk to 1x1 and the WebView
+ * is hidden and throttled, while the page, its scripts and its state stay alive.
+ *
+ * While frozen, `xian_web_engine_views_acquire_frames` acquires nothing for the view. Release held frames before
+ * freezing; a slot still held keeps its texture until the view is thawed. Resizes and moves are
+ * recorded and applied on thaw.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_freeze_view(XianWebEngineView *view);
+
+/**
+ * Thaws a view frozen by `xian_web_engine_freeze_view`: the slot textures are recreated at the
+ * current size and an active view repaints right away, so the next acquire gets a fresh frame.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_thaw_view(XianWebEngineView *view);
+
+/**
+ * Enters (`enabled = true`) or leaves snapshot mode, for static panels that only change on demand.
+ *
+ * In snapshot mode the page stops running (the WebView is hidden and throttled and its timers are
+ * suspended) and nothing is painted, but the last frame stays acquirable indefinitely: it is
+ * pinned so it is never reclaimed, and releasing it makes it READY again, so
+ * `xian_web_engine_views_acquire_frames` keeps returning it every time. Release it with a consumer
+ * fence of `0` to reacquire it immediately; with a fence it becomes acquirable again once the
+ * engine sees the fence signaled. Use `xian_web_engine_view_refresh_snapshot` to render a fresh
+ * frame. Leaving snapshot mode resumes the page and repaints.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_snapshot_mode(XianWebEngineView *view, bool enabled);
+
+/**
+ * Renders a fresh frame of a view in snapshot mode: the page runs for about 100 ms (timers,
+ * animation frames and layout catch up), then its last frame is pinned in place of the previous
+ * one. Ignored if the view is not in snapshot mode.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_refresh_snapshot(XianWebEngineView *view);
+
+/**
+ * Pauses the view's refresh driver for debugging: CSS animations, transitions and
+ * `requestAnimationFrame` callbacks stop advancing until `xian_web_engine_debug_step_frame` lets a
+ * frame through, so a misbehaving animation can be inspected frame by frame (e.g. with captures).
+ * Input, navigation and repaints not driven by the refresh driver keep working.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_debug_pause_view(XianWebEngineView *view);
+
+/**
+ * Resumes a view paused with `xian_web_engine_debug_pause_view`; animations continue from the
+ * frame they were held at.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_debug_resume_view(XianWebEngineView *view);
+
+/**
+ * Advances a view paused with `xian_web_engine_debug_pause_view` by one frame, on the next tick of
+ * its refresh driver (vsync, fixed interval or group). Ignored if the view is not paused or its
+ * animation clock is paused (`xian_web_engine_set_view_time_paused`).
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_debug_step_frame(XianWebEngineView *view);
+
+/**
+ * Overrides the view's output colorspace, so a host doing its own gamma-correct compositing does
+ * not apply gamma twice:
+ *
+ * - `XIAN_WEB_ENGINE_COLORSPACE_AUTO` (default): follow the color format (sRGB-encode only for
+ *   `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8`).
+ * - `XIAN_WEB_ENGINE_COLORSPACE_SRGB`: sRGB-encode writes (requires an `SRGB8_A8` view).
+ * - `XIAN_WEB_ENGINE_COLORSPACE_LINEAR`: write values as-is.
+ *
+ * Applies from the next frame; `XianWebEngineFrame.colorspace` reports the colorspace of each frame.
+ *
+ * Returns `false` if `view` is NULL, `colorspace` is unknown or unsupported by the view's color
+ * format, or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_colorspace(XianWebEngineView *view, uint32_t colorspace);
+
+/**
+ * Sets the `prefers-color-scheme` media feature the view's pages see, so they follow the game's
+ * theme:
+ *
+ * - `XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT` (default): `light`.
+ * - `XIAN_WEB_ENGINE_COLOR_SCHEME_DARK`: `dark`.
+ *
+ * Applies immediately: CSS `@media` rules and `matchMedia` lists (with their `change` events)
+ * follow, and the setting persists across navigations.
+ *
+ * Returns `false` if `view` is NULL, `scheme` is unknown, or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_color_scheme(XianWebEngineView *view, uint32_t scheme);
+
+/**
+ * Sets whether the view's pages see `prefers-reduced-motion: reduce` (default: `no-preference`),
+ * mirroring the game's accessibility setting.
+ *
+ * Servo does not evaluate this media feature in CSS, so the engine answers it in `matchMedia`
+ * queries (firing `change` on lists whose result flips) and, while on, also disables CSS
+ * animations, transitions and smooth scrolling. Applies to the current page immediately and
+ * persists across navigations.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_reduced_motion(XianWebEngineView *view, bool reduce);
+
+/**
+ * Sets whether the view's pages see `forced-colors: active` (default: `none`), mirroring the
+ * game's high-contrast setting.
+ *
+ * Servo has no forced-colors mode, so the engine only answers the media feature in `matchMedia`
+ * queries (firing `change` on lists whose result flips); pages that restyle themselves from script
+ * adapt, CSS `@media (forced-colors)` rules do not. Applies to the current page immediately and
+ * persists across navigations.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_forced_colors(XianWebEngineView *view, bool active);
+
+/**
+ * Pauses or resumes the view's animation clock, so in-world UI panels visually pause while the
+ * game is paused.
+ *
+ * While paused, the view's refresh driver holds the frame callback Servo requests, so
+ * `requestAnimationFrame` callbacks, CSS animations and transitions stop advancing, and
+ * `performance.now()` stands still. On resume the held callback runs on the next frame and the
+ * page's timeline is offset by the time spent paused: `performance.now()` and
+ * `requestAnimationFrame` timestamps continue exactly where they stopped, and CSS animations
+ * (held with `animation-play-state: paused`) resume from the same point. CSS transitions follow
+ * Servo's own clock and jump to where they would be had they kept running; `Date` and timers are
+ * not affected. Combines with the debug pause (`xian_web_engine_debug_pause_view`): frames flow
+ * again once neither holds them. Applies immediately and persists across navigations.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_set_view_time_paused(XianWebEngineView *view, bool paused);
+
+/**
+ * Sets how the view behaves while inactive (see `xian_web_engine_view_set_active`):
+ *
+ * - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL` (default): hidden, timers and animation frames keep
+ *   running.
+ * - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ`: hidden and throttled, native timers are
+ *   cancelled and due callbacks run at most once per second.
+ * - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND`: hidden and throttled, native timers are cancelled
+ *   and re-armed with their remaining delay once the view becomes active again.
+ *
+ * A cleared timer or cancelled animation frame never runs afterwards, and a throttled view holds
+ * at most one pending run per live timer or frame request.
+ *
+ * Takes effect immediately if the view is already inactive.
+ *
+ * Returns `false` if `view` is NULL, `policy` is unknown, or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_background_policy(XianWebEngineView *view, uint32_t policy);
+
+/**
+ * Sets how the engine reacts when a script blocks the view's page for longer than `timeout_ms`
+ * (a misbehaving page would otherwise stall every view sharing its script event loop):
+ *
+ * - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE` (default): no detection.
+ * - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY`: emit a `XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT` event.
+ * - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP`: unload the page (`about:blank`) and emit the event.
+ *
+ * Detection pings the page every `timeout_ms`, so a stall is reported between `timeout_ms` and
+ * `2 * timeout_ms` after it starts. Servo gives embedders no way to interrupt a running script, so
+ * a stop only takes effect if the page can still be navigated away from. The event's `value1` is
+ * `1` only once the view answers again, which is waited for up to another `timeout_ms`; a script
+ * that keeps the page from unloading is reported with `value1 = 0`.
+ *
+ * Returns `false` if `view` is NULL, `policy` is unknown, `timeout_ms` is `0` for an enabled policy,
+ * or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_slow_script_policy(XianWebEngineView *view, uint32_t policy, uint32_t timeout_ms);
+
+/**
+ * Injects a user stylesheet (NUL-terminated UTF-8 CSS) into every subsequent load of this view.
+ *
+ * Use it to theme pages (dark mode, custom fonts) without modifying their content. Stylesheets
+ * accumulate; each call adds one more.
+ *
+ * Returns `false` if `view`/`css` is NULL, the string is not valid UTF-8, or the engine is shutting
+ * down.
+ */
+bool xian_web_engine_view_inject_user_stylesheet(XianWebEngineView *view, const char *css);
+
+/**
+ * Registers a user script (NUL-terminated UTF-8 JavaScript) run automatically on every subsequent
+ * navigation of this view, like a WebExtension content script.
+ *
+ * - `injection_time`: `XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START` runs before page scripts;
+ *   `XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END` runs once the DOM is parsed (the source is wrapped
+ *   in a function, so top-level declarations stay local; assign to `window` to export them).
+ * - `world`: must be `XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN` (Servo has no isolated worlds), so
+ *   globals the script defines (e.g. `window.mc`) are visible to the page.
+ *
+ * Returns `false` if an argument is invalid/unsupported or the engine is shutting down.
+ */
+bool xian_web_engine_view_add_user_script(XianWebEngineView *view, const char *script, uint32_t injection_time, uint32_t world);
+
+/**
+ * Answers a permission request (geolocation, notifications, camera, ...) reported by a
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST` event.
+ *
+ * Requests not answered within the event's `value1` milliseconds are denied automatically, as are
+ * requests still pending when the view is destroyed.
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down; unknown or expired IDs are
+ * ignored.
+ */
+bool xian_web_engine_view_respond_permission(XianWebEngineView *view, uint64_t request_id, bool allow);
+
+/**
+ * Releases pointer lock held by the page, e.g. when the player presses Escape or the game opens a
+ * menu.
+ *
+ * Pointer lock is requested by the page (`element.requestPointerLock()`) during transient user
+ * activation, asked through a `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK` permission request, and
+ * reported as a `XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK` event once granted; while locked, send
+ * mouse motion as `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` deltas. Leaving the lock is reported
+ * with the same event (`value0 = 0`).
+ *
+ * Returns `false` if `view` is NULL or the engine is shutting down.
+ */
+bool xian_web_engine_view_exit_pointer_lock(XianWebEngineView *view);
+
+/**
+ * Answers a `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` event with `count` file paths (NUL-terminated
+ * UTF-8). `count = 0` cancels the chooser; single-file inputs use only the first path.
+ *
+ * Returns `false` if an argument is invalid or the engine is shutting down; unknown IDs are ignored.
+ */
+bool xian_web_engine_view_provide_files(XianWebEngineView *view, uint64_t request_id, const char *const *paths, uint32_t count);
+
+/**
+ * Answers a `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` event with an in-memory file of `len` bytes named
+ * `name` (NUL-terminated UTF-8), e.g. a screenshot the game just took.
+ *
+ * The data is written to a file under the system temp directory before being selected.
+ *
+ * Returns `false` if an argument is invalid, the file cannot be written, or the engine is shutting
+ * down.
+ */
+bool xian_web_engine_view_provide_file_data(XianWebEngineView *view, uint64_t request_id, const char *name, const uint8_t *bytes, uintptr_t len);
+
+/**
+ * Writes the page scroll offset (CSS px) into `out_x` / `out_y`.
+ *
+ * The value is a snapshot the page reports whenever it scrolls, so this never blocks on the Servo
+ * thread; it may lag a scroll still in flight. It survives hiding the view, so hosts can save it
+ * before `xian_web_engine_view_set_active(view, 0)` and restore it with
+ * `xian_web_engine_view_set_scroll` later.
+ *
+ * Returns `false` if `view`, `out_x` or `out_y` is NULL.
+ */
+bool xian_web_engine_view_get_scroll(XianWebEngineView *view, float *out_x, float *out_y);
+
+/**
+ * Returns the `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the view's focused editable element, and
+ * writes its rectangle `[x, y, width, height]` in view pixels into `out_rect` (4 entries; may be
+ * NULL) so the host can place an on-screen keyboard or its IME composition window next to it.
+ *
+ * Like the scroll offset, this is a snapshot updated whenever focus moves (announced by
+ * `XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT`), so it never blocks on the Servo thread. Coordinates
+ * are saturated to 16 bits. Returns `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE` (with an all-zero
+ * rectangle) if no editable element has focus or `view` is NULL.
+ */
+uint32_t xian_web_engine_view_get_text_input(XianWebEngineView *view, int32_t *out_rect);
+
+/**
+ * Scrolls the page to `(x, y)` in CSS px; `smooth` animates the scroll instead of jumping.
+ *
+ * Returns `false` if `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+ */
+bool xian_web_engine_view_set_scroll(XianWebEngineView *view, float x, float y, bool smooth);
+
+/**
+ * Returns whether the view has network activity (the main document is loading or HTTP(S)
+ * subresource requests are in flight), e.g. to draw a loading spinner.
+ *
+ * This reads a counter the engine keeps per view (requests are counted when Servo's networking
+ * delegate lets them through and until they complete or fail), so it is cheap enough to call
+ * every frame and needs no event subscription. Returns `false` if `view` is NULL.
+ */
+bool xian_web_engine_view_is_loading(XianWebEngineView *view);
+
+/**
+ * Returns the number of in-flight network requests of the view (HTTP(S) subresource requests,
+ * plus one while the main document is loading).
+ *
+ * Returns `0` if `view` is NULL.
+ */
+uint32_t xian_web_engine_view_get_in_flight_requests(XianWebEngineView *view);
+
+/**
+ * Installs the embedder's Vulkan device (once per process).
+ *
+ * Views created with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` allocate their slot images on this
+ * device. It must be the GPU the GL context runs on, and must have `VK_KHR_external_memory_fd` +
+ * `VK_KHR_external_semaphore_fd` (Linux) or `VK_KHR_external_memory_win32` +
+ * `VK_KHR_external_semaphore_win32` (Windows) enabled. The device must outlive the engine.
+ *
+ * Returns `true` on success.
+ */
+bool xian_web_engine_set_vulkan_device(const EmbedderVulkanDevice *device);
+
+/**
+ * Writes the Vulkan import description of `slot` into `out_slot`.
+ *
+ * Consumer contract for views created with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`:
+ *
+ * - Import the memory/semaphores once per `generation` (re-query after acquire when
+ *   `XianWebEngineFrame.texture_id` changes). Handles stay owned by the engine; duplicate them
+ *   before an import call that takes ownership.
+ * - After acquiring a frame, wait on `ready_semaphore` before sampling the image, which is in
+ *   `SHADER_READ_ONLY_OPTIMAL` layout (acquire it from the external queue family).
+ * - When done, leave the image in `SHADER_READ_ONLY_OPTIMAL`, submit a signal of
+ *   `release_semaphore`, then release the frame with `XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE` as
+ *   its consumer fence. Every acquired frame must be released this way.
+ *
+ * Returns `false` if the view has no Vulkan export or the slot has no image.
+ */
+bool xian_web_engine_view_get_vulkan_slot(XianWebEngineView *view, uint32_t slot, XianWebEngineVulkanSlot *out_slot);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
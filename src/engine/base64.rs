@@ -0,0 +1,83 @@
+//! ### English
+//! Base64 (RFC 4648, padded) used to move binary data in and out of pages and to build the loopback
+//! proxy credentials.
+//!
+//! ### 中文
+//! 用于在页面内外传递二进制数据以及构造回环代理凭据的 Base64（RFC 4648，带填充）。
+
+/// ### English
+/// Base64 alphabet (RFC 4648, with padding).
+///
+/// ### 中文
+/// Base64 字母表（RFC 4648，带填充）。
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// ### English
+/// Encodes `data` as padded base64.
+///
+/// #### Parameters
+/// - `data`: Bytes to encode.
+///
+/// ### 中文
+/// 将 `data` 编码为带填充的 base64。
+///
+/// #### 参数
+/// - `data`：要编码的字节。
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// ### English
+/// Decodes padded or unpadded base64 into `out` (appending). Returns `false` on invalid input, in
+/// which case `out` may hold a partial result.
+///
+/// #### Parameters
+/// - `text`: Base64 text.
+/// - `out`: Destination buffer.
+///
+/// ### 中文
+/// 将带或不带填充的 base64 解码并追加到 `out`。输入非法时返回 `false`，此时 `out` 中可能只有部分结果。
+///
+/// #### 参数
+/// - `text`：base64 文本。
+/// - `out`：目标缓冲区。
+pub(crate) fn decode_into(text: &str, out: &mut Vec<u8>) -> bool {
+    let text = text.trim_end_matches('=').as_bytes();
+    out.reserve(text.len() / 4 * 3 + 2);
+    let mut n = 0u32;
+    let mut bits = 0u32;
+    for &c in text {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return false,
+        };
+        n = (n << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+            n &= (1 << bits) - 1;
+        }
+    }
+    bits < 6
+}
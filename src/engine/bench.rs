@@ -0,0 +1,36 @@
+//! ### English
+//! Internals exposed to the criterion benchmarks under `benches/` (`bench` feature only).
+//!
+//! Not part of the supported API: the C ABI in `ffi` is the only interface embedders should use.
+//!
+//! ### 中文
+//! 暴露给 `benches/` 下 criterion 基准测试的内部实现（仅限 `bench` feature）。
+//!
+//! 不属于受支持的 API：宿主应当只使用 `ffi` 中的 C ABI。
+
+pub use super::flags::XIAN_WEB_ENGINE_COLORSPACE_SRGB;
+pub use super::frame::{AcquiredFrame, SharedFrameState, TRIPLE_BUFFER_COUNT};
+pub use super::input::InputEventQueue;
+pub use super::input_types::{XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XianWebEngineInputEvent};
+pub use super::vsync::VsyncCallbackQueue;
+
+/// ### English
+/// Slot state: owned by nobody, may be reserved by the producer.
+///
+/// ### 中文
+/// 槽位状态：无人持有，可被生产者预留。
+pub const SLOT_FREE: u8 = super::frame::SLOT_FREE;
+
+/// ### English
+/// Slot state: published by the producer, may be acquired by the consumer.
+///
+/// ### 中文
+/// 槽位状态：已由生产者发布，可被消费者 acquire。
+pub const SLOT_READY: u8 = super::frame::SLOT_READY;
+
+/// ### English
+/// Slot state: reserved by the producer and being rendered.
+///
+/// ### 中文
+/// 槽位状态：已被生产者预留，正在渲染。
+pub const SLOT_RENDERING: u8 = super::frame::SLOT_RENDERING;
@@ -0,0 +1,571 @@
+//! ### English
+//! Capability bits reported by `xian_web_engine_get_capabilities`.
+//!
+//! Bindings test these bits to feature-detect optional behavior instead of hard-failing when loaded
+//! against an older or newer library. Bits are only ever added, never reassigned.
+//!
+//! ### 中文
+//! 由 `xian_web_engine_get_capabilities` 上报的能力位。
+//!
+//! 绑定层通过检测这些位来探测可选功能，而不是在加载到较旧/较新的库时直接失败。能力位只会新增，
+//! 永远不会被重新分配。
+
+/// ### English
+/// Frames carry producer fences (`XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE` opts out).
+///
+/// ### 中文
+/// 帧携带生产者 fence（可通过 `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE` 关闭）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_PRODUCER_FENCE: u64 = 1 << 0;
+
+/// ### English
+/// Released frames accept consumer fences.
+///
+/// ### 中文
+/// 释放帧时可传入消费者 fence。
+pub const XIAN_WEB_ENGINE_CAPABILITY_CONSUMER_FENCE: u64 = 1 << 1;
+
+/// ### English
+/// `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8` slot textures and colorspace overrides (still subject to
+/// driver support at view creation).
+///
+/// ### 中文
+/// 支持 `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8` 槽位纹理与色彩空间覆盖（创建 view 时仍取决于驱动支持）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SRGB: u64 = 1 << 2;
+
+/// ### English
+/// `XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F` slot textures.
+///
+/// ### 中文
+/// 支持 `XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F` 槽位纹理。
+pub const XIAN_WEB_ENGINE_CAPABILITY_HALF_FLOAT: u64 = 1 << 3;
+
+/// ### English
+/// Transparent views (`xian_web_engine_view_set_background` honors alpha).
+///
+/// ### 中文
+/// 支持透明 view（`xian_web_engine_view_set_background` 会遵循 alpha）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_TRANSPARENT_VIEWS: u64 = 1 << 4;
+
+/// ### English
+/// `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X` and `XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`.
+///
+/// ### 中文
+/// 支持 `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X` 与 `XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SUPERSAMPLE_MIPMAPS: u64 = 1 << 5;
+
+/// ### English
+/// Views can be sharded across several Servo threads (`servo_threads`).
+///
+/// ### 中文
+/// 支持将 view 分片到多个 Servo 线程（`servo_threads`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SERVO_SHARDS: u64 = 1 << 6;
+
+/// ### English
+/// Slow-script detection (`xian_web_engine_view_set_slow_script_policy`).
+///
+/// ### 中文
+/// 支持慢脚本检测（`xian_web_engine_view_set_slow_script_policy`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SLOW_SCRIPT: u64 = 1 << 7;
+
+/// ### English
+/// The library can create its shared GL context on this platform (Windows, Linux and macOS for now).
+///
+/// ### 中文
+/// 该库可在当前平台创建共享 GL 上下文（目前支持 Windows、Linux 与 macOS）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SHARED_CONTEXT: u64 = 1 << 8;
+
+/// ### English
+/// Thread priority / CPU affinity controls take effect (Windows only for now).
+///
+/// ### 中文
+/// 线程优先级 / CPU 亲和性控制会生效（目前仅 Windows）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_THREAD_SCHEDULING: u64 = 1 << 9;
+
+/// ### English
+/// Log callback (`xian_web_engine_set_log_callback`) with FFI argument validation reports.
+///
+/// ### 中文
+/// 支持日志回调（`xian_web_engine_set_log_callback`）及 FFI 参数校验报告。
+pub const XIAN_WEB_ENGINE_CAPABILITY_LOG_CALLBACK: u64 = 1 << 10;
+
+/// ### English
+/// View string getters (`xian_web_engine_view_get_url`/`_get_title`/`_get_last_error`).
+///
+/// ### 中文
+/// 支持 view 字符串 getter（`xian_web_engine_view_get_url`/`_get_title`/`_get_last_error`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VIEW_STRINGS: u64 = 1 << 11;
+
+/// ### English
+/// View compositor (`xian_web_engine_composite_views`).
+///
+/// ### 中文
+/// 支持 view 合成器（`xian_web_engine_composite_views`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_COMPOSITOR: u64 = 1 << 12;
+
+/// ### English
+/// Frame-available callback (`xian_web_engine_set_frame_callback`).
+///
+/// ### 中文
+/// 支持帧可用回调（`xian_web_engine_set_frame_callback`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_FRAME_CALLBACK: u64 = 1 << 13;
+
+/// ### English
+/// Vulkan external-memory frames (`XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`); the GL driver must
+/// additionally expose `GL_EXT_memory_object` / `GL_EXT_semaphore`.
+///
+/// ### 中文
+/// 支持 Vulkan 外部内存帧（`XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`）；GL 驱动还需提供
+/// `GL_EXT_memory_object` / `GL_EXT_semaphore`。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VULKAN_EXPORT: u64 = 1 << 14;
+
+/// ### English
+/// EGL shared contexts for GLES-only hosts (`XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` with
+/// `xian_web_engine_create_with_flags`).
+///
+/// ### 中文
+/// 支持面向仅 GLES 宿主的 EGL 共享上下文（`xian_web_engine_create_with_flags` 搭配
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_EGL: u64 = 1 << 15;
+
+/// ### English
+/// Main-thread trampoline for GLFW window calls (`xian_web_engine_set_main_thread_trampoline`),
+/// required for the shared context on macOS.
+///
+/// ### 中文
+/// 支持用于 GLFW window 调用的主线程 trampoline（`xian_web_engine_set_main_thread_trampoline`），
+/// macOS 上的共享上下文需要它。
+pub const XIAN_WEB_ENGINE_CAPABILITY_MAIN_THREAD_TRAMPOLINE: u64 = 1 << 16;
+
+/// ### English
+/// GL debug output forwarded to the log callback (`XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG`); the
+/// driver must additionally expose `GL_KHR_debug` (or GL 4.3 / GLES 3.2).
+///
+/// ### 中文
+/// 支持将 GL debug 输出转发给日志回调（`XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG`）；驱动还需提供
+/// `GL_KHR_debug`（或 GL 4.3 / GLES 3.2）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_GL_DEBUG: u64 = 1 << 17;
+
+/// ### English
+/// Paranoid GL mode (`XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL`) with GL errors reported in
+/// `XianWebEngineHealth`.
+///
+/// ### 中文
+/// 支持 paranoid GL 模式（`XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL`），GL 错误通过
+/// `XianWebEngineHealth` 上报。
+pub const XIAN_WEB_ENGINE_CAPABILITY_PARANOID_GL: u64 = 1 << 18;
+
+/// ### English
+/// Device sensor bridge (`xian_web_engine_view_update_sensors` → `deviceorientation` /
+/// `devicemotion`).
+///
+/// ### 中文
+/// 支持设备传感器桥接（`xian_web_engine_view_update_sensors` → `deviceorientation` /
+/// `devicemotion`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SENSORS: u64 = 1 << 19;
+
+/// ### English
+/// Page scroll snapshot and restore (`xian_web_engine_view_get_scroll` /
+/// `xian_web_engine_view_set_scroll`).
+///
+/// ### 中文
+/// 支持页面滚动快照与恢复（`xian_web_engine_view_get_scroll` / `xian_web_engine_view_set_scroll`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SCROLL: u64 = 1 << 20;
+
+/// ### English
+/// Text selection and copy (`xian_web_engine_view_select_all`,
+/// `xian_web_engine_view_request_selected_text`, `xian_web_engine_view_copy_selection`).
+///
+/// ### 中文
+/// 支持文本选区与复制（`xian_web_engine_view_select_all`、`xian_web_engine_view_request_selected_text`、
+/// `xian_web_engine_view_copy_selection`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SELECTION: u64 = 1 << 21;
+
+/// ### English
+/// Page console capture (`xian_web_engine_view_set_console_capture` → `CONSOLE_MESSAGE` events).
+///
+/// ### 中文
+/// 支持捕获页面 console 输出（`xian_web_engine_view_set_console_capture` → `CONSOLE_MESSAGE` 事件）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_CONSOLE_CAPTURE: u64 = 1 << 22;
+
+/// ### English
+/// `PAGE_ERROR` / `RESOURCE_ERROR` events for uncaught exceptions and failed subresource loads.
+///
+/// ### 中文
+/// 支持针对未捕获异常与子资源加载失败的 `PAGE_ERROR` / `RESOURCE_ERROR` 事件。
+pub const XIAN_WEB_ENGINE_CAPABILITY_PAGE_ERRORS: u64 = 1 << 23;
+
+/// ### English
+/// Per-view network activity counter (`xian_web_engine_view_is_loading` /
+/// `xian_web_engine_view_get_in_flight_requests`).
+///
+/// ### 中文
+/// 支持每 view 的网络活动计数（`xian_web_engine_view_is_loading` /
+/// `xian_web_engine_view_get_in_flight_requests`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_NETWORK_ACTIVITY: u64 = 1 << 24;
+
+/// ### English
+/// Connection and bandwidth limits for HTTP(S) traffic (`xian_web_engine_set_network_limits`).
+///
+/// ### 中文
+/// 支持针对 HTTP(S) 流量的连接数与带宽限制（`xian_web_engine_set_network_limits`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_NETWORK_LIMITS: u64 = 1 << 25;
+
+/// ### English
+/// Offline and cache-only network modes (`xian_web_engine_set_network_mode`).
+///
+/// ### 中文
+/// 支持离线与仅缓存网络模式（`xian_web_engine_set_network_mode`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_NETWORK_MODE: u64 = 1 << 26;
+
+/// ### English
+/// Embedder DNS resolver callback (`xian_web_engine_set_dns_resolver`).
+///
+/// ### 中文
+/// 支持宿主 DNS 解析回调（`xian_web_engine_set_dns_resolver`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_DNS_RESOLVER: u64 = 1 << 27;
+
+/// ### English
+/// Host functions callable from pages (`xian_web_engine_register_host_function`).
+///
+/// ### 中文
+/// 支持可由页面调用的宿主函数（`xian_web_engine_register_host_function`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_HOST_FUNCTIONS: u64 = 1 << 28;
+
+/// ### English
+/// Binary messages between host and page (`xian_web_engine_view_post_binary`).
+///
+/// ### 中文
+/// 支持宿主与页面之间的二进制消息（`xian_web_engine_view_post_binary`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_BINARY_CHANNEL: u64 = 1 << 29;
+
+/// ### English
+/// Embedder GL textures readable by pages (`xian_web_engine_register_texture`).
+///
+/// ### 中文
+/// 支持页面读取宿主 GL 纹理（`xian_web_engine_register_texture`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_TEXTURE_SHARING: u64 = 1 << 30;
+
+/// ### English
+/// Host texture frame streams into pages (`xian_web_engine_push_external_frame`).
+///
+/// ### 中文
+/// 支持将宿主纹理帧流式推送到页面（`xian_web_engine_push_external_frame`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_EXTERNAL_FRAMES: u64 = 1 << 31;
+
+/// ### English
+/// Moving a view to another size/render configuration without reloading (`xian_web_engine_move_view`).
+///
+/// ### 中文
+/// 支持在不重新加载的情况下将 view 迁移到其他尺寸/渲染配置（`xian_web_engine_move_view`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VIEW_MOVE: u64 = 1 << 32;
+
+/// ### English
+/// Freezing views to reclaim GPU memory (`xian_web_engine_freeze_view` / `xian_web_engine_thaw_view`).
+///
+/// ### 中文
+/// 支持冻结 view 以回收显存（`xian_web_engine_freeze_view` / `xian_web_engine_thaw_view`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VIEW_FREEZE: u64 = 1 << 33;
+
+/// ### English
+/// Chrome trace export of engine spans (`xian_web_engine_dump_trace`).
+///
+/// ### 中文
+/// 支持以 Chrome trace 格式导出引擎 span（`xian_web_engine_dump_trace`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_TRACE: u64 = 1 << 34;
+
+/// ### English
+/// Servo time profiler toggles (`xian_web_engine_set_profiling`).
+///
+/// ### 中文
+/// 支持开关 Servo 时间分析器（`xian_web_engine_set_profiling`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_PROFILING: u64 = 1 << 35;
+
+/// ### English
+/// Recording and timed replay of view input, resizes and navigations
+/// (`xian_web_engine_view_start_recording`, `xian_web_engine_view_replay`).
+///
+/// ### 中文
+/// 支持录制并按时间回放 view 的输入、resize 与导航（`xian_web_engine_view_start_recording`、
+/// `xian_web_engine_view_replay`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_REPLAY: u64 = 1 << 36;
+
+/// ### English
+/// Golden-image rendering tests (`xian_web_engine_run_golden_tests`; builds with the `golden-tests`
+/// feature only).
+///
+/// ### 中文
+/// 支持 golden 图像渲染测试（`xian_web_engine_run_golden_tests`；仅限启用 `golden-tests` feature 的构建）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS: u64 = 1 << 37;
+
+/// ### English
+/// Monotonic queue drop counters (`xian_web_engine_view_get_queue_stats`, and `commands_dropped` /
+/// `pending_overflows` in `XianWebEngineHealth`).
+///
+/// ### 中文
+/// 单调递增的队列丢弃计数（`xian_web_engine_view_get_queue_stats`，以及 `XianWebEngineHealth` 中的
+/// `commands_dropped` / `pending_overflows`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_QUEUE_STATS: u64 = 1 << 38;
+
+/// ### English
+/// View liveness and explicit close (`xian_web_engine_view_is_alive`, `xian_web_engine_close_view`).
+///
+/// ### 中文
+/// 支持 view 存活查询与显式关闭（`xian_web_engine_view_is_alive`、`xian_web_engine_close_view`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VIEW_CLOSE: u64 = 1 << 39;
+
+/// ### English
+/// Generational view handle table (`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`).
+///
+/// ### 中文
+/// 分代 view 句柄表（`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE: u64 = 1 << 40;
+
+/// ### English
+/// Input synthesis for automated UI testing (`xian_web_engine_click_at`, `xian_web_engine_type_text`).
+///
+/// ### 中文
+/// 用于 UI 自动化测试的输入合成（`xian_web_engine_click_at`、`xian_web_engine_type_text`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_INPUT_SYNTHESIS: u64 = 1 << 41;
+
+/// ### English
+/// Per-view refresh driver pause and frame stepping for debugging
+/// (`xian_web_engine_debug_pause_view`, `xian_web_engine_debug_step_frame`).
+///
+/// ### 中文
+/// 用于调试的每 view refresh driver 暂停与逐帧单步（`xian_web_engine_debug_pause_view`、
+/// `xian_web_engine_debug_step_frame`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_DEBUG_PAUSE: u64 = 1 << 42;
+
+/// ### English
+/// Non-blocking view creation with a completion callback (`xian_web_engine_create_view_async`).
+///
+/// ### 中文
+/// 带完成回调的非阻塞 view 创建（`xian_web_engine_create_view_async`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_ASYNC_CREATE: u64 = 1 << 43;
+
+/// ### English
+/// Engine initialization progress callback and cache preloading
+/// (`xian_web_engine_set_init_progress_callback`, `xian_web_engine_preload`).
+///
+/// ### 中文
+/// 引擎初始化进度回调与缓存预加载（`xian_web_engine_set_init_progress_callback`、`xian_web_engine_preload`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_INIT_PROGRESS: u64 = 1 << 44;
+
+/// ### English
+/// Configurable timeouts of blocking calls (`xian_web_engine_create_with_config`,
+/// `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`).
+///
+/// ### 中文
+/// 可配置的阻塞调用超时（`xian_web_engine_create_with_config`、`XIAN_WEB_ENGINE_STATUS_TIMED_OUT`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_ENGINE_CONFIG: u64 = 1 << 45;
+
+/// ### English
+/// Storage isolation groups chosen at view creation (`xian_web_engine_view_create_in_storage_group`).
+///
+/// ### 中文
+/// 在创建 view 时选择的存储隔离组（`xian_web_engine_view_create_in_storage_group`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_STORAGE_GROUPS: u64 = 1 << 46;
+
+/// ### English
+/// Per-view homography applied to pointer input (`xian_web_engine_set_view_input_transform`).
+///
+/// ### 中文
+/// 应用于指针输入的每 view 单应矩阵（`xian_web_engine_set_view_input_transform`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_INPUT_TRANSFORM: u64 = 1 << 47;
+
+/// ### English
+/// Resize debouncing with an immediate override (`xian_web_engine_view_set_resize_debounce`,
+/// `xian_web_engine_view_resize_immediate`).
+///
+/// ### 中文
+/// 带立即应用选项的 resize 防抖（`xian_web_engine_view_set_resize_debounce`、
+/// `xian_web_engine_view_resize_immediate`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_RESIZE_DEBOUNCE: u64 = 1 << 48;
+
+/// ### English
+/// Producer fence statistics (`xian_web_engine_get_fence_stats`).
+///
+/// ### 中文
+/// 生产者 fence 统计（`xian_web_engine_get_fence_stats`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_FENCE_STATS: u64 = 1 << 49;
+
+/// ### English
+/// Orphaning resize (`XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE`).
+///
+/// ### 中文
+/// 孤立式 resize（`XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_ORPHANING_RESIZE: u64 = 1 << 50;
+
+/// ### English
+/// Frame present timestamps (`XianWebEngineFrame.present_time_ns`,
+/// `xian_web_engine_monotonic_time_ns`).
+///
+/// ### 中文
+/// 帧 present 时间戳（`XianWebEngineFrame.present_time_ns`、`xian_web_engine_monotonic_time_ns`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_FRAME_TIMESTAMPS: u64 = 1 << 51;
+
+/// ### English
+/// Host display timing for vsync-driven views (`xian_web_engine_set_vsync_info`).
+///
+/// ### 中文
+/// 为 vsync 驱动的 view 提供宿主显示时序（`xian_web_engine_set_vsync_info`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_VSYNC_INFO: u64 = 1 << 52;
+
+/// ### English
+/// Drift-free fixed-interval refresh with fractional rates
+/// (`xian_web_engine_view_group_create_fractional`).
+///
+/// ### 中文
+/// 无漂移的固定间隔 refresh 与小数帧率（`xian_web_engine_view_group_create_fractional`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_FRACTIONAL_FPS: u64 = 1 << 53;
+
+/// ### English
+/// Engine idle mode (`xian_web_engine_set_idle`, `XIAN_WEB_ENGINE_IDLE_FLAG_*`).
+///
+/// ### 中文
+/// 引擎空闲模式（`xian_web_engine_set_idle`、`XIAN_WEB_ENGINE_IDLE_FLAG_*`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_ENGINE_IDLE: u64 = 1 << 54;
+
+/// ### English
+/// Memory pressure signal (`xian_web_engine_notify_memory_pressure`).
+///
+/// ### 中文
+/// 内存压力信号（`xian_web_engine_notify_memory_pressure`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_MEMORY_PRESSURE: u64 = 1 << 55;
+
+/// ### English
+/// Image size limits with downscaling (`xian_web_engine_set_image_limits`,
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED`).
+///
+/// ### 中文
+/// 带缩放的图片尺寸限制（`xian_web_engine_set_image_limits`、
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_IMAGE_LIMITS: u64 = 1 << 56;
+
+/// ### English
+/// Process-wide page JavaScript heap limit (`xian_web_engine_set_heap_limit`,
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT`).
+///
+/// ### 中文
+/// 进程级的页面 JavaScript 堆上限（`xian_web_engine_set_heap_limit`、
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_HEAP_LIMIT: u64 = 1 << 57;
+
+/// ### English
+/// Out-of-process page content (`XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`,
+/// `xian_web_engine_run_content_process`); not available on Windows.
+///
+/// ### 中文
+/// 进程外页面内容（`XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`、`xian_web_engine_run_content_process`）；
+/// Windows 上不可用。
+pub const XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS: u64 = 1 << 58;
+
+/// ### English
+/// WebDriver endpoint for automated tests (`xian_web_engine_enable_webdriver`; builds with the
+/// `webdriver` feature only).
+///
+/// ### 中文
+/// 用于自动化测试的 WebDriver 端点（`xian_web_engine_enable_webdriver`；仅限启用 `webdriver` feature 的
+/// 构建）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER: u64 = 1 << 59;
+
+/// ### English
+/// Frame streaming over HTTP for remote debugging (`xian_web_engine_view_start_screencast`; builds
+/// with the `screencast` feature only).
+///
+/// ### 中文
+/// 通过 HTTP 推流帧以进行远程调试（`xian_web_engine_view_start_screencast`；仅限启用 `screencast`
+/// feature 的构建）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST: u64 = 1 << 60;
+
+/// ### English
+/// Per-view media feature overrides (`xian_web_engine_view_set_color_scheme`,
+/// `xian_web_engine_view_set_reduced_motion`, `xian_web_engine_view_set_forced_colors`).
+///
+/// ### 中文
+/// 每 view 的媒体特性覆盖（`xian_web_engine_view_set_color_scheme`、
+/// `xian_web_engine_view_set_reduced_motion`、`xian_web_engine_view_set_forced_colors`）。
+pub const XIAN_WEB_ENGINE_CAPABILITY_MEDIA_FEATURES: u64 = 1 << 61;
+
+/// ### English
+/// Returns the capability bits of this build on this platform.
+///
+/// ### 中文
+/// 返回本构建在当前平台上的能力位。
+pub(crate) fn capabilities() -> u64 {
+    let mut bits = XIAN_WEB_ENGINE_CAPABILITY_PRODUCER_FENCE
+        | XIAN_WEB_ENGINE_CAPABILITY_CONSUMER_FENCE
+        | XIAN_WEB_ENGINE_CAPABILITY_SRGB
+        | XIAN_WEB_ENGINE_CAPABILITY_HALF_FLOAT
+        | XIAN_WEB_ENGINE_CAPABILITY_TRANSPARENT_VIEWS
+        | XIAN_WEB_ENGINE_CAPABILITY_SUPERSAMPLE_MIPMAPS
+        | XIAN_WEB_ENGINE_CAPABILITY_SERVO_SHARDS
+        | XIAN_WEB_ENGINE_CAPABILITY_SLOW_SCRIPT
+        | XIAN_WEB_ENGINE_CAPABILITY_LOG_CALLBACK
+        | XIAN_WEB_ENGINE_CAPABILITY_VIEW_STRINGS
+        | XIAN_WEB_ENGINE_CAPABILITY_FRAME_CALLBACK
+        | XIAN_WEB_ENGINE_CAPABILITY_EGL
+        | XIAN_WEB_ENGINE_CAPABILITY_MAIN_THREAD_TRAMPOLINE
+        | XIAN_WEB_ENGINE_CAPABILITY_GL_DEBUG
+        | XIAN_WEB_ENGINE_CAPABILITY_PARANOID_GL
+        | XIAN_WEB_ENGINE_CAPABILITY_SENSORS
+        | XIAN_WEB_ENGINE_CAPABILITY_SCROLL
+        | XIAN_WEB_ENGINE_CAPABILITY_SELECTION
+        | XIAN_WEB_ENGINE_CAPABILITY_CONSOLE_CAPTURE
+        | XIAN_WEB_ENGINE_CAPABILITY_PAGE_ERRORS
+        | XIAN_WEB_ENGINE_CAPABILITY_NETWORK_ACTIVITY
+        | XIAN_WEB_ENGINE_CAPABILITY_NETWORK_LIMITS
+        | XIAN_WEB_ENGINE_CAPABILITY_NETWORK_MODE
+        | XIAN_WEB_ENGINE_CAPABILITY_DNS_RESOLVER
+        | XIAN_WEB_ENGINE_CAPABILITY_HOST_FUNCTIONS
+        | XIAN_WEB_ENGINE_CAPABILITY_BINARY_CHANNEL
+        | XIAN_WEB_ENGINE_CAPABILITY_TEXTURE_SHARING
+        | XIAN_WEB_ENGINE_CAPABILITY_EXTERNAL_FRAMES
+        | XIAN_WEB_ENGINE_CAPABILITY_VIEW_MOVE
+        | XIAN_WEB_ENGINE_CAPABILITY_VIEW_FREEZE
+        | XIAN_WEB_ENGINE_CAPABILITY_TRACE
+        | XIAN_WEB_ENGINE_CAPABILITY_PROFILING
+        | XIAN_WEB_ENGINE_CAPABILITY_REPLAY
+        | XIAN_WEB_ENGINE_CAPABILITY_QUEUE_STATS
+        | XIAN_WEB_ENGINE_CAPABILITY_VIEW_CLOSE
+        | XIAN_WEB_ENGINE_CAPABILITY_INPUT_SYNTHESIS
+        | XIAN_WEB_ENGINE_CAPABILITY_DEBUG_PAUSE
+        | XIAN_WEB_ENGINE_CAPABILITY_ASYNC_CREATE
+        | XIAN_WEB_ENGINE_CAPABILITY_INIT_PROGRESS
+        | XIAN_WEB_ENGINE_CAPABILITY_ENGINE_CONFIG
+        | XIAN_WEB_ENGINE_CAPABILITY_STORAGE_GROUPS
+        | XIAN_WEB_ENGINE_CAPABILITY_INPUT_TRANSFORM
+        | XIAN_WEB_ENGINE_CAPABILITY_RESIZE_DEBOUNCE
+        | XIAN_WEB_ENGINE_CAPABILITY_FENCE_STATS
+        | XIAN_WEB_ENGINE_CAPABILITY_ORPHANING_RESIZE
+        | XIAN_WEB_ENGINE_CAPABILITY_FRAME_TIMESTAMPS
+        | XIAN_WEB_ENGINE_CAPABILITY_VSYNC_INFO
+        | XIAN_WEB_ENGINE_CAPABILITY_FRACTIONAL_FPS
+        | XIAN_WEB_ENGINE_CAPABILITY_ENGINE_IDLE
+        | XIAN_WEB_ENGINE_CAPABILITY_MEMORY_PRESSURE
+        | XIAN_WEB_ENGINE_CAPABILITY_IMAGE_LIMITS
+        | XIAN_WEB_ENGINE_CAPABILITY_HEAP_LIMIT
+        | XIAN_WEB_ENGINE_CAPABILITY_MEDIA_FEATURES
+        | XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE;
+    if cfg!(any(windows, target_os = "linux", target_os = "macos")) {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_SHARED_CONTEXT | XIAN_WEB_ENGINE_CAPABILITY_COMPOSITOR;
+    }
+    if cfg!(any(windows, target_os = "linux")) {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_VULKAN_EXPORT;
+    }
+    if cfg!(windows) {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_THREAD_SCHEDULING;
+    } else {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS;
+    }
+    if cfg!(feature = "golden-tests") {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS;
+    }
+    if cfg!(feature = "webdriver") {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER;
+    }
+    if cfg!(feature = "screencast") {
+        bits |= XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST;
+    }
+    bits
+}
@@ -0,0 +1,33 @@
+//! ### English
+//! Engine monotonic clock shared by frame timestamps and the host.
+//!
+//! Timestamps count nanoseconds from a process-wide origin fixed on first use, so the host can read
+//! the same clock (`xian_web_engine_monotonic_time_ns`) and relate frame present times to its own
+//! timeline.
+//!
+//! ### 中文
+//! 帧时间戳与宿主共用的引擎单调时钟。
+//!
+//! 时间戳为自首次使用时确定的进程级起点以来的纳秒数，宿主可读取同一时钟
+//!（`xian_web_engine_monotonic_time_ns`），从而把帧的 present 时间换算到自己的时间线上。
+
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/// ### English
+/// Clock origin (set by the first read).
+///
+/// ### 中文
+/// 时钟起点（由第一次读取设置）。
+static ORIGIN: OnceLock<Instant> = OnceLock::new();
+
+/// ### English
+/// Returns the current engine monotonic time in nanoseconds (never `0`, which means "unset").
+///
+/// ### 中文
+/// 返回当前的引擎单调时间（纳秒；不会为 `0`，`0` 表示“未设置”）。
+#[inline]
+pub(crate) fn monotonic_nanos() -> u64 {
+    let origin = ORIGIN.get_or_init(Instant::now);
+    (origin.elapsed().as_nanos() as u64).max(1)
+}
@@ -0,0 +1,22 @@
+//! ### English
+//! Embedder-provided DNS resolution and traffic limits.
+//!
+//! Servo's networking resolves host names with the system resolver and offers no hook to replace
+//! it, nor to cap or pace its connections. Engines that need either therefore route their HTTP(S)
+//! traffic through a loopback proxy that resolves each target host with the installed callback
+//! (falling back to the system resolver), applies the process-wide traffic limits and then tunnels
+//! the connection unchanged.
+//!
+//! ### 中文
+//! 由宿主提供的 DNS 解析与流量限制。
+//!
+//! Servo 的网络层使用系统解析器解析主机名，既没有提供替换的钩子，也无法限制其连接数或速率。因此需要这些功能的
+//! 引擎会将 HTTP(S) 流量经由一个回环代理转发：代理使用已安装的回调解析每个目标主机（失败时回退到系统解析器），
+//! 执行进程级流量限制，然后原样转发连接。
+mod proxy;
+mod resolver;
+mod traffic;
+
+pub(crate) use proxy::Proxy;
+pub(crate) use resolver::{XianWebEngineDnsResolveFn, set_dns_resolver};
+pub(crate) use traffic::set_traffic_limits;
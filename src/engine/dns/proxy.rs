@@ -0,0 +1,753 @@
+//! ### English
+//! Loopback HTTP proxy that carries all of Servo's HTTP(S) traffic.
+//!
+//! Target hosts are resolved with the embedder resolver when one is installed, and every upstream
+//! connection is subject to the traffic limits. `CONNECT host:port` requests (HTTPS) are tunnelled
+//! byte for byte, so TLS still terminates in Servo and certificates are verified against the
+//! original host name. Plain HTTP requests in absolute form are rewritten to origin form and
+//! forwarded with `Connection: close`.
+//!
+//! A Servo thread only runs a proxy when its engine needs one: when created with
+//! `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`, or while a resolver callback or traffic limit is
+//! installed. While its engine is not online, the proxy refuses every upstream connection and
+//! closes the live ones, so nothing Servo's HTTP stack sends leaves the process; responses already
+//! in Servo's HTTP cache are still served.
+//!
+//! The proxy only serves requests carrying its per-proxy secret, generated from the OS random
+//! source and handed to Servo as the user info of the proxy URI, so other local processes cannot
+//! use the loopback port. Connections are served by a fixed pool of `WORKERS` threads; accepted
+//! connections wait in a queue of `BACKLOG` entries (idle tunnels are closed for them) and are
+//! dropped when it is full.
+//!
+//! ### 中文
+//! 承载 Servo 全部 HTTP(S) 流量的回环 HTTP 代理。
+//!
+//! 安装了宿主解析器时使用其解析目标主机，且每个上游连接都受流量限制约束。`CONNECT host:port` 请求（HTTPS）
+//! 按字节原样转发，因此 TLS 仍在 Servo 中终止，证书也按原始主机名校验。绝对形式的明文 HTTP 请求会被改写为
+//! origin 形式，并附加 `Connection: close` 后转发。
+//!
+//! Servo 线程只在其引擎需要时才运行代理：以 `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` 创建，或已安装
+//! 解析回调或流量限制。其引擎不在线时，代理拒绝所有上游连接并关闭存活的连接，因此 Servo 的 HTTP 栈发出的
+//! 任何内容都不会离开进程；已在 Servo HTTP 缓存中的响应仍可被提供。
+//!
+//! 代理只处理携带其专属密钥的请求；密钥取自操作系统随机源，并作为代理 URI 的 user info 交给 Servo，
+//! 因此本机其他进程无法使用该回环端口。连接由固定的 `WORKERS` 个线程组成的线程池处理；已接受的连接在容量为
+//! `BACKLOG` 的队列中等待（会为其关闭空闲隧道），队列满时直接丢弃。
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use url::{Position, Url};
+
+use crate::engine::base64;
+
+use super::resolver::{resolve, resolver_installed};
+use super::traffic::{ConnectionSlot, IDLE_RELEASE, connections_waiting, limits_set, throttle};
+
+/// ### English
+/// Number of worker threads serving the connections of one proxy.
+///
+/// ### 中文
+/// 处理单个代理连接的工作线程数。
+const WORKERS: usize = 32;
+
+/// ### English
+/// Accepted connections that may wait for a free worker; further ones are dropped.
+///
+/// ### 中文
+/// 可等待空闲工作线程的已接受连接数；超出的连接会被丢弃。
+const BACKLOG: usize = 64;
+
+/// ### English
+/// User name of the proxy credentials.
+///
+/// ### 中文
+/// 代理凭据的用户名。
+const PROXY_USER: &str = "xian";
+
+/// ### English
+/// Largest request head accepted from Servo.
+///
+/// ### 中文
+/// 从 Servo 接收的请求头的最大长度。
+const MAX_HEAD_BYTES: usize = 64 * 1024;
+
+/// ### English
+/// Timeout for connecting to one resolved address.
+///
+/// ### 中文
+/// 连接单个已解析地址的超时时间。
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// ### English
+/// Read timeout of spliced connections, i.e. how often an idle tunnel checks whether to close.
+///
+/// ### 中文
+/// 转发中连接的读取超时，即空闲隧道检查是否应关闭的间隔。
+const IDLE_POLL: Duration = Duration::from_millis(500);
+
+/// ### English
+/// Size of the buffer used to copy tunnelled bytes.
+///
+/// ### 中文
+/// 复制隧道字节所用缓冲区的大小。
+const COPY_CHUNK_BYTES: usize = 16 * 1024;
+
+/// ### English
+/// Response sent when the target cannot be resolved or reached.
+///
+/// ### 中文
+/// 无法解析或连接目标时发送的响应。
+const BAD_GATEWAY: &[u8] =
+    b"HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// ### English
+/// Response sent when a request lacks the proxy credentials.
+///
+/// ### 中文
+/// 请求未携带代理凭据时发送的响应。
+const AUTHENTICATION_REQUIRED: &[u8] = b"HTTP/1.1 407 Proxy Authentication Required\r\n\
+    Proxy-Authenticate: Basic realm=\"xian\"\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// ### English
+/// Loopback proxy of one Servo thread; stops accepting connections when dropped.
+///
+/// ### 中文
+/// 单个 Servo 线程的回环代理；drop 时停止接受连接。
+pub(crate) struct Proxy {
+    /// ### English
+    /// Proxy URI with credentials (`http://xian:<secret>@127.0.0.1:<port>`).
+    ///
+    /// ### 中文
+    /// 带凭据的代理 URI（`http://xian:<secret>@127.0.0.1:<port>`）。
+    uri: String,
+    /// ### English
+    /// Listener address, connected to once on drop to wake the accept loop.
+    ///
+    /// ### 中文
+    /// 监听地址；drop 时连接一次以唤醒接受循环。
+    address: SocketAddr,
+    /// ### English
+    /// State shared with the accept loop and connection threads.
+    ///
+    /// ### 中文
+    /// 与接受循环及连接线程共享的状态。
+    state: Arc<ProxyState>,
+}
+
+/// ### English
+/// State shared by a proxy and its connection threads.
+///
+/// ### 中文
+/// 代理及其连接线程共享的状态。
+struct ProxyState {
+    /// ### English
+    /// Set when the proxy is dropped.
+    ///
+    /// ### 中文
+    /// 代理被 drop 时置位。
+    closed: AtomicBool,
+    /// ### English
+    /// Expected `Proxy-Authorization` value (`Basic <base64 of xian:secret>`).
+    ///
+    /// ### 中文
+    /// 期望的 `Proxy-Authorization` 值（`Basic <xian:secret 的 base64>`）。
+    credentials: String,
+    /// ### English
+    /// Accepted connections waiting for a worker.
+    ///
+    /// ### 中文
+    /// 等待工作线程的已接受连接数。
+    queued: AtomicUsize,
+    /// ### English
+    /// Online flag and live tunnels.
+    ///
+    /// ### 中文
+    /// 在线标志与存活的隧道。
+    tunnels: Mutex<Tunnels>,
+}
+
+/// ### English
+/// Live tunnels of a proxy, keyed by tunnel ID.
+///
+/// ### 中文
+/// 代理存活的隧道，以隧道 ID 为键。
+struct Tunnels {
+    /// ### English
+    /// Whether upstream connections may be opened.
+    ///
+    /// ### 中文
+    /// 是否允许打开上游连接。
+    online: bool,
+    /// ### English
+    /// Next tunnel ID.
+    ///
+    /// ### 中文
+    /// 下一个隧道 ID。
+    next_id: u64,
+    /// ### English
+    /// Client side of every live tunnel (shut down to close the tunnel).
+    ///
+    /// ### 中文
+    /// 每个存活隧道的客户端连接（关闭它即可关闭隧道）。
+    open: HashMap<u64, TcpStream>,
+}
+
+impl Proxy {
+    /// ### English
+    /// Starts a proxy on an ephemeral loopback port if the engine needs one: `forced`, or a
+    /// resolver callback or traffic limit is installed.
+    ///
+    /// Returns `None` if no proxy is needed, or if the secret, the loopback listener or the threads
+    /// could not be created.
+    ///
+    /// #### Parameters
+    /// - `forced`: Whether the engine was created with `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`.
+    ///
+    /// ### 中文
+    /// 若引擎需要代理（`forced`，或已安装解析回调或流量限制），则在临时回环端口上启动代理。
+    ///
+    /// 若不需要代理，或无法生成密钥、创建回环监听或线程，则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `forced`：引擎是否以 `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` 创建。
+    pub(crate) fn start_if_needed(forced: bool) -> Option<Self> {
+        if !forced && !resolver_installed() && !limits_set() {
+            return None;
+        }
+        let mut secret = [0u8; 16];
+        getrandom::fill(&mut secret).ok()?;
+        let secret: String = secret.iter().map(|byte| format!("{byte:02x}")).collect();
+        let credentials = base64::encode(format!("{PROXY_USER}:{secret}").as_bytes());
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).ok()?;
+        let address = listener.local_addr().ok()?;
+        let state = Arc::new(ProxyState {
+            closed: AtomicBool::new(false),
+            credentials: format!("Basic {credentials}"),
+            queued: AtomicUsize::new(0),
+            tunnels: Mutex::new(Tunnels {
+                online: true,
+                next_id: 0,
+                open: HashMap::new(),
+            }),
+        });
+        let (sender, receiver) = mpsc::sync_channel(BACKLOG);
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..WORKERS {
+            let receiver = Arc::clone(&receiver);
+            let state = Arc::clone(&state);
+            thread::Builder::new()
+                .name("xian-dns-proxy-conn".to_string())
+                .spawn(move || work(&receiver, &state))
+                .ok()?;
+        }
+        let serve_state = Arc::clone(&state);
+        thread::Builder::new()
+            .name("xian-dns-proxy".to_string())
+            .spawn(move || serve(listener, sender, serve_state))
+            .ok()?;
+        Some(Self {
+            uri: format!("http://{PROXY_USER}:{secret}@{address}"),
+            address,
+            state,
+        })
+    }
+
+    /// ### English
+    /// Returns the proxy URI with credentials (`http://xian:<secret>@127.0.0.1:<port>`).
+    ///
+    /// ### 中文
+    /// 返回带凭据的代理 URI（`http://xian:<secret>@127.0.0.1:<port>`）。
+    pub(crate) fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// ### English
+    /// Allows or refuses upstream connections; refusing also closes every live tunnel.
+    ///
+    /// #### Parameters
+    /// - `online`: Whether upstream connections may be opened.
+    ///
+    /// ### 中文
+    /// 允许或拒绝上游连接；拒绝时还会关闭所有存活的隧道。
+    ///
+    /// #### 参数
+    /// - `online`：是否允许打开上游连接。
+    pub(crate) fn set_online(&self, online: bool) {
+        self.state.set_online(online);
+    }
+}
+
+impl Drop for Proxy {
+    fn drop(&mut self) {
+        self.state.closed.store(true, Ordering::Release);
+        self.state.set_online(false);
+        let _ = TcpStream::connect_timeout(&self.address, CONNECT_TIMEOUT);
+    }
+}
+
+impl ProxyState {
+    /// ### English
+    /// Sets the online flag, closing every live tunnel when going offline.
+    ///
+    /// #### Parameters
+    /// - `online`: Whether upstream connections may be opened.
+    ///
+    /// ### 中文
+    /// 设置在线标志；转为离线时关闭所有存活的隧道。
+    ///
+    /// #### 参数
+    /// - `online`：是否允许打开上游连接。
+    fn set_online(&self, online: bool) {
+        let Ok(mut tunnels) = self.tunnels.lock() else {
+            return;
+        };
+        tunnels.online = online;
+        if !online {
+            for (_, client) in tunnels.open.drain() {
+                let _ = client.shutdown(Shutdown::Both);
+            }
+        }
+    }
+}
+
+/// ### English
+/// Registration of one live tunnel; removed on drop.
+///
+/// ### 中文
+/// 一个存活隧道的登记；drop 时移除。
+struct Tunnel<'a> {
+    /// ### English
+    /// Owning proxy state.
+    ///
+    /// ### 中文
+    /// 所属代理的状态。
+    state: &'a ProxyState,
+    /// ### English
+    /// Tunnel ID.
+    ///
+    /// ### 中文
+    /// 隧道 ID。
+    id: u64,
+}
+
+impl<'a> Tunnel<'a> {
+    /// ### English
+    /// Registers `client` as a live tunnel; returns `None` while the proxy is offline.
+    ///
+    /// #### Parameters
+    /// - `state`: Proxy state.
+    /// - `client`: Connection from Servo.
+    ///
+    /// ### 中文
+    /// 将 `client` 登记为存活隧道；代理离线时返回 `None`。
+    ///
+    /// #### 参数
+    /// - `state`：代理状态。
+    /// - `client`：来自 Servo 的连接。
+    fn open(state: &'a ProxyState, client: &TcpStream) -> Option<Self> {
+        let client = client.try_clone().ok()?;
+        let mut tunnels = state.tunnels.lock().ok()?;
+        if !tunnels.online {
+            return None;
+        }
+        let id = tunnels.next_id;
+        tunnels.next_id += 1;
+        tunnels.open.insert(id, client);
+        Some(Self { state, id })
+    }
+
+    /// ### English
+    /// Returns whether the tunnel is still live (not closed by going offline).
+    ///
+    /// ### 中文
+    /// 返回隧道是否仍存活（未因转为离线而被关闭）。
+    fn is_open(&self) -> bool {
+        self.state
+            .tunnels
+            .lock()
+            .is_ok_and(|tunnels| tunnels.open.contains_key(&self.id))
+    }
+}
+
+impl Drop for Tunnel<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut tunnels) = self.state.tunnels.lock() {
+            tunnels.open.remove(&self.id);
+        }
+    }
+}
+
+/// ### English
+/// Accept loop: queues every connection for the workers until the proxy is dropped; connections
+/// that find the queue full are closed at once. Returning drops `sender`, which stops the workers.
+///
+/// #### Parameters
+/// - `listener`: Loopback listener.
+/// - `sender`: Queue feeding the workers.
+/// - `state`: Proxy state.
+///
+/// ### 中文
+/// 接受循环：将每个连接排入工作线程队列，直到代理被 drop；遇到队列已满的连接会被立即关闭。返回时会 drop
+/// `sender`，从而停止工作线程。
+///
+/// #### 参数
+/// - `listener`：回环监听。
+/// - `sender`：为工作线程供给连接的队列。
+/// - `state`：代理状态。
+fn serve(listener: TcpListener, sender: SyncSender<TcpStream>, state: Arc<ProxyState>) {
+    for client in listener.incoming().flatten() {
+        if state.closed.load(Ordering::Acquire) {
+            return;
+        }
+        state.queued.fetch_add(1, Ordering::Relaxed);
+        if sender.try_send(client).is_err() {
+            state.queued.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// ### English
+/// Worker loop: serves queued connections one at a time until the accept loop stops.
+///
+/// #### Parameters
+/// - `receiver`: Queue shared by the workers.
+/// - `state`: Proxy state.
+///
+/// ### 中文
+/// 工作线程循环：逐个处理排队的连接，直到接受循环停止。
+///
+/// #### 参数
+/// - `receiver`：工作线程共享的队列。
+/// - `state`：代理状态。
+fn work(receiver: &Mutex<Receiver<TcpStream>>, state: &Arc<ProxyState>) {
+    loop {
+        let next = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(client) = next else {
+            return;
+        };
+        state.queued.fetch_sub(1, Ordering::Relaxed);
+        let _ = handle(client, state);
+    }
+}
+
+/// ### English
+/// Returns whether `value` matches the expected credentials, comparing in constant time.
+///
+/// #### Parameters
+/// - `value`: `Proxy-Authorization` header value sent by the client.
+/// - `expected`: Expected header value.
+///
+/// ### 中文
+/// 以常数时间比较，返回 `value` 是否与期望的凭据一致。
+///
+/// #### 参数
+/// - `value`：客户端发送的 `Proxy-Authorization` 头的值。
+/// - `expected`：期望的头部值。
+fn credentials_match(value: &str, expected: &str) -> bool {
+    value.len() == expected.len()
+        && value
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// ### English
+/// Serves one proxy connection. Requests without the proxy credentials get `407`. While the proxy
+/// is offline the connection is closed without a response, which Servo reports as a network error.
+///
+/// #### Parameters
+/// - `client`: Connection from Servo.
+/// - `state`: Proxy state.
+///
+/// ### 中文
+/// 处理一个代理连接。未携带代理凭据的请求得到 `407`。代理离线时直接关闭连接而不作响应，Servo 会将其报告为
+/// 网络错误。
+///
+/// #### 参数
+/// - `client`：来自 Servo 的连接。
+/// - `state`：代理状态。
+fn handle(mut client: TcpStream, state: &Arc<ProxyState>) -> io::Result<()> {
+    let _ = client.set_nodelay(true);
+    let (head, body) = read_head(&mut client)?;
+    let Ok(head) = String::from_utf8(head) else {
+        return client.write_all(BAD_GATEWAY);
+    };
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().splitn(3, ' ');
+    let (Some(method), Some(target), Some(version)) = (
+        request_line.next(),
+        request_line.next(),
+        request_line.next(),
+    ) else {
+        return client.write_all(BAD_GATEWAY);
+    };
+    let authorized = lines.clone().any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("proxy-authorization")
+                && credentials_match(value.trim(), &state.credentials)
+        })
+    });
+    if !authorized {
+        return client.write_all(AUTHENTICATION_REQUIRED);
+    }
+
+    if method.eq_ignore_ascii_case("CONNECT") {
+        let Some((host, port)) = target.rsplit_once(':') else {
+            return client.write_all(BAD_GATEWAY);
+        };
+        let Some(tunnel) = Tunnel::open(state, &client) else {
+            return Ok(());
+        };
+        let _slot = ConnectionSlot::acquire();
+        if !tunnel.is_open() {
+            return Ok(());
+        }
+        let Some(mut upstream) = port.parse().ok().and_then(|port| connect(host, port)) else {
+            return client.write_all(BAD_GATEWAY);
+        };
+        client.write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")?;
+        upstream.write_all(&body)?;
+        return splice(client, upstream, state);
+    }
+
+    let Ok(url) = Url::parse(target) else {
+        return client.write_all(BAD_GATEWAY);
+    };
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return client.write_all(BAD_GATEWAY);
+    };
+    let Some(tunnel) = Tunnel::open(state, &client) else {
+        return Ok(());
+    };
+    let _slot = ConnectionSlot::acquire();
+    if !tunnel.is_open() {
+        return Ok(());
+    }
+    let Some(mut upstream) = connect(host, port) else {
+        return client.write_all(BAD_GATEWAY);
+    };
+
+    let mut forwarded = format!("{method} {} {version}\r\n", &url[Position::BeforePath..]);
+    for line in lines.filter(|line| !line.is_empty()) {
+        let name = line.split(':').next().unwrap_or_default().trim();
+        if name.eq_ignore_ascii_case("connection")
+            || name.eq_ignore_ascii_case("proxy-connection")
+            || name.eq_ignore_ascii_case("proxy-authorization")
+        {
+            continue;
+        }
+        forwarded.push_str(line);
+        forwarded.push_str("\r\n");
+    }
+    forwarded.push_str("Connection: close\r\n\r\n");
+    upstream.write_all(forwarded.as_bytes())?;
+    upstream.write_all(&body)?;
+    splice(client, upstream, state)
+}
+
+/// ### English
+/// Reads the request head (up to the blank line) and returns it with any body bytes read past it.
+///
+/// #### Parameters
+/// - `client`: Connection from Servo.
+///
+/// ### 中文
+/// 读取请求头（直到空行），并返回请求头以及越过空行读到的请求体字节。
+///
+/// #### 参数
+/// - `client`：来自 Servo 的连接。
+fn read_head(client: &mut TcpStream) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let mut buffer = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 4096];
+    loop {
+        let read = client.read(&mut chunk)?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let searched_from = buffer.len().saturating_sub(3);
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(end) = buffer[searched_from..]
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+        {
+            let body = buffer.split_off(searched_from + end + 4);
+            buffer.truncate(searched_from + end);
+            return Ok((buffer, body));
+        }
+        if buffer.len() > MAX_HEAD_BYTES {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+    }
+}
+
+/// ### English
+/// Connects to `host:port`, trying every resolved address in order.
+///
+/// #### Parameters
+/// - `host`: Target host.
+/// - `port`: Target port.
+///
+/// ### 中文
+/// 连接 `host:port`，依次尝试每个解析出的地址。
+///
+/// #### 参数
+/// - `host`：目标主机。
+/// - `port`：目标端口。
+fn connect(host: &str, port: u16) -> Option<TcpStream> {
+    let upstream = resolve(host, port)
+        .into_iter()
+        .find_map(|address| TcpStream::connect_timeout(&address, CONNECT_TIMEOUT).ok())?;
+    let _ = upstream.set_nodelay(true);
+    Some(upstream)
+}
+
+/// ### English
+/// Copies bytes in both directions until either side closes or the tunnel is released while idle.
+/// The uplink runs on a helper thread, so a proxy uses at most `2 * WORKERS` threads for tunnels.
+///
+/// #### Parameters
+/// - `client`: Connection from Servo.
+/// - `upstream`: Connection to the target.
+/// - `state`: Proxy state.
+///
+/// ### 中文
+/// 双向复制字节，直到任一端关闭，或隧道在空闲时被释放。上行方向在辅助线程上运行，因此一个代理用于隧道的线程
+/// 至多为 `2 * WORKERS` 个。
+///
+/// #### 参数
+/// - `client`：来自 Servo 的连接。
+/// - `upstream`：到目标的连接。
+/// - `state`：代理状态。
+fn splice(
+    mut client: TcpStream,
+    mut upstream: TcpStream,
+    state: &Arc<ProxyState>,
+) -> io::Result<()> {
+    client.set_read_timeout(Some(IDLE_POLL))?;
+    upstream.set_read_timeout(Some(IDLE_POLL))?;
+    let started = Instant::now();
+    let activity = Arc::new(AtomicU64::new(0));
+    let mut client_reader = client.try_clone()?;
+    let mut upstream_writer = upstream.try_clone()?;
+    let uplink_activity = Arc::clone(&activity);
+    let uplink_state = Arc::clone(state);
+    let uplink = thread::Builder::new()
+        .name("xian-dns-proxy-conn".to_string())
+        .spawn(move || {
+            let end = pump(
+                &mut client_reader,
+                &mut upstream_writer,
+                false,
+                started,
+                &uplink_activity,
+                &uplink_state,
+            );
+            let how = match end {
+                PumpEnd::Closed => Shutdown::Write,
+                PumpEnd::Released => Shutdown::Both,
+            };
+            let _ = upstream_writer.shutdown(how);
+            if end == PumpEnd::Released {
+                let _ = client_reader.shutdown(Shutdown::Both);
+            }
+        })?;
+    pump(&mut upstream, &mut client, true, started, &activity, state);
+    let _ = client.shutdown(Shutdown::Both);
+    let _ = upstream.shutdown(Shutdown::Both);
+    let _ = uplink.join();
+    Ok(())
+}
+
+/// ### English
+/// Why a pump stopped copying.
+///
+/// ### 中文
+/// 单向复制停止的原因。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PumpEnd {
+    /// ### English
+    /// The source closed or failed.
+    ///
+    /// ### 中文
+    /// 源端关闭或出错。
+    Closed,
+    /// ### English
+    /// The tunnel was idle while other connections waited for a slot or a worker.
+    ///
+    /// ### 中文
+    /// 隧道空闲且有其他连接在等待名额或工作线程。
+    Released,
+}
+
+/// ### English
+/// Copies bytes from `from` to `to` in one direction.
+///
+/// #### Parameters
+/// - `from`: Source stream (with `IDLE_POLL` as read timeout).
+/// - `to`: Destination stream.
+/// - `downlink`: Whether the bytes come from upstream and count against the bandwidth limit.
+/// - `started`: Time the tunnel was established.
+/// - `activity`: Milliseconds after `started` of the last transfer in either direction.
+/// - `state`: Proxy state (its queued connections count as waiting).
+///
+/// ### 中文
+/// 单向地将字节从 `from` 复制到 `to`。
+///
+/// #### 参数
+/// - `from`：源流（读取超时为 `IDLE_POLL`）。
+/// - `to`：目标流。
+/// - `downlink`：字节是否来自上游并计入带宽限制。
+/// - `started`：隧道建立的时间。
+/// - `activity`：任一方向最近一次传输距 `started` 的毫秒数。
+/// - `state`：代理状态（其排队的连接视为在等待）。
+fn pump(
+    from: &mut TcpStream,
+    to: &mut TcpStream,
+    downlink: bool,
+    started: Instant,
+    activity: &AtomicU64,
+    state: &ProxyState,
+) -> PumpEnd {
+    let elapsed_ms = || started.elapsed().as_millis() as u64;
+    let mut chunk = [0u8; COPY_CHUNK_BYTES];
+    loop {
+        match from.read(&mut chunk) {
+            Ok(0) => return PumpEnd::Closed,
+            Ok(read) => {
+                if downlink {
+                    throttle(read);
+                }
+                if to.write_all(&chunk[..read]).is_err() {
+                    return PumpEnd::Closed;
+                }
+                activity.store(elapsed_ms(), Ordering::Relaxed);
+            }
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                let idle_ms = elapsed_ms().saturating_sub(activity.load(Ordering::Relaxed));
+                let waiting = connections_waiting() || state.queued.load(Ordering::Relaxed) != 0;
+                if idle_ms >= IDLE_RELEASE.as_millis() as u64 && waiting {
+                    return PumpEnd::Released;
+                }
+            }
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(_) => return PumpEnd::Closed,
+        }
+    }
+}
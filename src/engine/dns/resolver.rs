@@ -0,0 +1,159 @@
+//! ### English
+//! Process-wide resolver callback installed by the embedder.
+//!
+//! ### 中文
+//! 宿主安装的进程级解析回调。
+
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::sync::RwLock;
+
+/// ### English
+/// Size of the buffer handed to the resolver callback for the address text.
+///
+/// ### 中文
+/// 交给解析回调用于写入地址文本的缓冲区大小。
+const ADDRESS_CAPACITY: usize = 64;
+
+/// ### English
+/// DNS resolver callback: resolves the NUL-terminated host name `host` and writes one IPv4 or IPv6
+/// address literal (NUL-terminated, e.g. `192.168.1.20` or `::1`) into `out_address`, which holds
+/// `out_capacity` bytes. Returns `false` to fall back to the system resolver. Called on proxy worker
+/// threads, possibly concurrently.
+///
+/// ### 中文
+/// DNS 解析回调：解析 NUL 结尾的主机名 `host`，并将一个 IPv4 或 IPv6 地址字面量（NUL 结尾，例如
+/// `192.168.1.20` 或 `::1`）写入容量为 `out_capacity` 字节的 `out_address`。返回 `false` 表示回退到
+/// 系统解析器。在代理工作线程上调用，可能并发调用。
+pub type XianWebEngineDnsResolveFn = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    host: *const c_char,
+    out_address: *mut c_char,
+    out_capacity: usize,
+) -> bool;
+
+/// ### English
+/// Installed callback and its user data.
+///
+/// ### 中文
+/// 已安装的回调及其 user data。
+#[derive(Clone, Copy)]
+struct Resolver {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    callback: XianWebEngineDnsResolveFn,
+    /// ### English
+    /// Opaque user data (stored as an address so the resolver is `Send + Sync`).
+    ///
+    /// ### 中文
+    /// 不透明 user data（以地址形式保存，使解析器满足 `Send + Sync`）。
+    user_data: usize,
+}
+
+/// ### English
+/// Currently installed resolver.
+///
+/// ### 中文
+/// 当前安装的解析器。
+static RESOLVER: RwLock<Option<Resolver>> = RwLock::new(None);
+
+/// ### English
+/// Installs (or removes, with `None`) the resolver callback.
+///
+/// #### Parameters
+/// - `callback`: Resolver callback, or `None` to use the system resolver only.
+/// - `user_data`: Opaque pointer passed back to `callback`.
+///
+/// ### 中文
+/// 安装（或以 `None` 移除）解析回调。
+///
+/// #### 参数
+/// - `callback`：解析回调；`None` 表示只使用系统解析器。
+/// - `user_data`：回传给 `callback` 的不透明指针。
+pub(crate) fn set_dns_resolver(
+    callback: Option<XianWebEngineDnsResolveFn>,
+    user_data: *mut c_void,
+) {
+    let Ok(mut resolver) = RESOLVER.write() else {
+        return;
+    };
+    *resolver = callback.map(|callback| Resolver {
+        callback,
+        user_data: user_data as usize,
+    });
+}
+
+/// ### English
+/// Asks the installed callback for the address of `host`.
+///
+/// The lock is released before the callback runs, so the callback may itself replace the resolver.
+///
+/// #### Parameters
+/// - `host`: Host name.
+///
+/// ### 中文
+/// 向已安装的回调查询 `host` 的地址。
+///
+/// 回调执行前会先释放锁，因此回调内部也可以替换解析器。
+///
+/// #### 参数
+/// - `host`：主机名。
+fn resolve_with_callback(host: &str) -> Option<IpAddr> {
+    let resolver = RESOLVER.read().ok().and_then(|resolver| *resolver)?;
+    let host = CString::new(host).ok()?;
+    let mut address = [0 as c_char; ADDRESS_CAPACITY];
+    let resolved = unsafe {
+        (resolver.callback)(
+            resolver.user_data as *mut c_void,
+            host.as_ptr(),
+            address.as_mut_ptr(),
+            address.len(),
+        )
+    };
+    if !resolved {
+        return None;
+    }
+    address[ADDRESS_CAPACITY - 1] = 0;
+    let address = unsafe { CStr::from_ptr(address.as_ptr()) };
+    address.to_str().ok()?.trim().parse().ok()
+}
+
+/// ### English
+/// Returns whether a resolver callback is installed.
+///
+/// ### 中文
+/// 返回是否安装了解析回调。
+pub(super) fn resolver_installed() -> bool {
+    RESOLVER.read().is_ok_and(|resolver| resolver.is_some())
+}
+
+/// ### English
+/// Resolves `host:port` to socket addresses: address literals are used as-is, host names go to the
+/// resolver callback first and then to the system resolver.
+///
+/// #### Parameters
+/// - `host`: Host name or address literal (IPv6 literals may be bracketed).
+/// - `port`: Target port.
+///
+/// ### 中文
+/// 将 `host:port` 解析为 socket 地址：地址字面量直接使用，主机名先交给解析回调，再回退到系统解析器。
+///
+/// #### 参数
+/// - `host`：主机名或地址字面量（IPv6 字面量可带方括号）。
+/// - `port`：目标端口。
+pub(super) fn resolve(host: &str, port: u16) -> Vec<SocketAddr> {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return vec![SocketAddr::new(ip, port)];
+    }
+    if let Some(ip) = resolve_with_callback(host) {
+        return vec![SocketAddr::new(ip, port)];
+    }
+    (host, port)
+        .to_socket_addrs()
+        .map(Iterator::collect)
+        .unwrap_or_default()
+}
@@ -0,0 +1,248 @@
+//! ### English
+//! Process-wide traffic limits enforced by the loopback proxy.
+//!
+//! Every HTTP(S) connection Servo opens goes through the proxy, so the limits hold for documents,
+//! subresources, fetch/XHR and workers alike (WebSockets connect directly). A connection slot is
+//! taken before the upstream connection is opened and released when the tunnel closes; while
+//! requests are waiting for a slot, tunnels idle for `IDLE_RELEASE` are closed so keep-alive
+//! connections cannot starve them. Bandwidth is a token bucket with one second of burst, charged
+//! for bytes received from upstream.
+//!
+//! ### 中文
+//! 由回环代理执行的进程级流量限制。
+//!
+//! Servo 打开的每个 HTTP(S) 连接都经过该代理，因此限制同样作用于文档、子资源、fetch/XHR 与 worker
+//! （WebSocket 直接连接）。打开上游连接前获取一个连接名额，隧道关闭时释放；当有请求在等待名额时，空闲达
+//! `IDLE_RELEASE` 的隧道会被关闭，避免 keep-alive 连接使其饥饿。带宽为允许一秒突发的令牌桶，按从上游
+//! 接收的字节计费。
+
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// ### English
+/// How long a tunnel may sit idle before it is closed for a waiting request.
+///
+/// ### 中文
+/// 有请求等待时，隧道在被关闭前可空闲的时长。
+pub(super) const IDLE_RELEASE: Duration = Duration::from_secs(2);
+
+/// ### English
+/// Limits and accounting shared by every proxy connection.
+///
+/// ### 中文
+/// 所有代理连接共享的限制与计数。
+struct Traffic {
+    /// ### English
+    /// Maximum concurrent upstream connections (`0` = unlimited).
+    ///
+    /// ### 中文
+    /// 最大并发上游连接数（`0` 表示不限制）。
+    max_connections: AtomicU32,
+    /// ### English
+    /// Maximum bytes per second received from upstream (`0` = unlimited).
+    ///
+    /// ### 中文
+    /// 每秒从上游接收的最大字节数（`0` 表示不限制）。
+    max_bytes_per_second: AtomicU64,
+    /// ### English
+    /// Number of open upstream connections.
+    ///
+    /// ### 中文
+    /// 已打开的上游连接数。
+    open: Mutex<u32>,
+    /// ### English
+    /// Signalled when a slot is released or the limits change.
+    ///
+    /// ### 中文
+    /// 释放名额或限制变化时发出通知。
+    released: Condvar,
+    /// ### English
+    /// Number of connections waiting for a slot.
+    ///
+    /// ### 中文
+    /// 等待名额的连接数。
+    waiting: AtomicU32,
+    /// ### English
+    /// Bandwidth token bucket.
+    ///
+    /// ### 中文
+    /// 带宽令牌桶。
+    bucket: Mutex<Bucket>,
+}
+
+/// ### English
+/// Token bucket state.
+///
+/// ### 中文
+/// 令牌桶状态。
+struct Bucket {
+    /// ### English
+    /// Available bytes (negative while in debt).
+    ///
+    /// ### 中文
+    /// 可用字节数（欠账时为负）。
+    tokens: f64,
+    /// ### English
+    /// Time `tokens` was last refilled.
+    ///
+    /// ### 中文
+    /// `tokens` 上次补充的时间。
+    refilled: Instant,
+}
+
+/// ### English
+/// Returns the process-wide traffic state.
+///
+/// ### 中文
+/// 返回进程级流量状态。
+fn traffic() -> &'static Traffic {
+    /// ### English
+    /// Process-wide traffic state.
+    ///
+    /// ### 中文
+    /// 进程级流量状态。
+    static TRAFFIC: OnceLock<Traffic> = OnceLock::new();
+    TRAFFIC.get_or_init(|| Traffic {
+        max_connections: AtomicU32::new(0),
+        max_bytes_per_second: AtomicU64::new(0),
+        open: Mutex::new(0),
+        released: Condvar::new(),
+        waiting: AtomicU32::new(0),
+        bucket: Mutex::new(Bucket {
+            tokens: 0.0,
+            refilled: Instant::now(),
+        }),
+    })
+}
+
+/// ### English
+/// Sets the traffic limits; connections waiting for a slot re-check immediately.
+///
+/// #### Parameters
+/// - `max_connections`: Maximum concurrent upstream connections (`0` = unlimited).
+/// - `max_bytes_per_second`: Maximum bytes per second received from upstream (`0` = unlimited).
+///
+/// ### 中文
+/// 设置流量限制；等待名额的连接会立即重新检查。
+///
+/// #### 参数
+/// - `max_connections`：最大并发上游连接数（`0` 表示不限制）。
+/// - `max_bytes_per_second`：每秒从上游接收的最大字节数（`0` 表示不限制）。
+pub(crate) fn set_traffic_limits(max_connections: u32, max_bytes_per_second: u64) {
+    let traffic = traffic();
+    traffic
+        .max_connections
+        .store(max_connections, Ordering::Relaxed);
+    traffic
+        .max_bytes_per_second
+        .store(max_bytes_per_second, Ordering::Relaxed);
+    {
+        let mut bucket = traffic
+            .bucket
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        bucket.tokens = bucket.tokens.min(max_bytes_per_second as f64);
+    }
+    let _open = traffic.open.lock().unwrap_or_else(PoisonError::into_inner);
+    traffic.released.notify_all();
+}
+
+/// ### English
+/// One held connection slot; released on drop.
+///
+/// ### 中文
+/// 一个已持有的连接名额；drop 时释放。
+pub(super) struct ConnectionSlot(());
+
+impl ConnectionSlot {
+    /// ### English
+    /// Waits for a free connection slot and takes it.
+    ///
+    /// ### 中文
+    /// 等待空闲连接名额并占用它。
+    pub(super) fn acquire() -> Self {
+        let traffic = traffic();
+        let mut open = traffic.open.lock().unwrap_or_else(PoisonError::into_inner);
+        let full = |open: u32| {
+            let max = traffic.max_connections.load(Ordering::Relaxed);
+            max != 0 && open >= max
+        };
+        if full(*open) {
+            traffic.waiting.fetch_add(1, Ordering::Relaxed);
+            while full(*open) {
+                open = traffic
+                    .released
+                    .wait(open)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+            traffic.waiting.fetch_sub(1, Ordering::Relaxed);
+        }
+        *open += 1;
+        Self(())
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        let traffic = traffic();
+        let mut open = traffic.open.lock().unwrap_or_else(PoisonError::into_inner);
+        *open = open.saturating_sub(1);
+        traffic.released.notify_one();
+    }
+}
+
+/// ### English
+/// Returns whether either traffic limit is set.
+///
+/// ### 中文
+/// 返回是否设置了任一流量限制。
+pub(super) fn limits_set() -> bool {
+    let traffic = traffic();
+    traffic.max_connections.load(Ordering::Relaxed) != 0
+        || traffic.max_bytes_per_second.load(Ordering::Relaxed) != 0
+}
+
+/// ### English
+/// Returns whether any connection is waiting for a slot.
+///
+/// ### 中文
+/// 返回是否有连接正在等待名额。
+pub(super) fn connections_waiting() -> bool {
+    traffic().waiting.load(Ordering::Relaxed) != 0
+}
+
+/// ### English
+/// Charges `bytes` received from upstream against the bandwidth limit, sleeping while in debt.
+///
+/// #### Parameters
+/// - `bytes`: Number of bytes just received.
+///
+/// ### 中文
+/// 将刚从上游接收的 `bytes` 计入带宽限制，欠账时休眠。
+///
+/// #### 参数
+/// - `bytes`：刚接收的字节数。
+pub(super) fn throttle(bytes: usize) {
+    let traffic = traffic();
+    let rate = traffic.max_bytes_per_second.load(Ordering::Relaxed);
+    if rate == 0 {
+        return;
+    }
+    let rate = rate as f64;
+    let debt = {
+        let mut bucket = traffic
+            .bucket
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled).as_secs_f64() * rate;
+        bucket.tokens = (bucket.tokens + refill).min(rate) - bytes as f64;
+        bucket.refilled = now;
+        -bucket.tokens
+    };
+    if debt > 0.0 {
+        thread::sleep(Duration::from_secs_f64(debt / rate));
+    }
+}
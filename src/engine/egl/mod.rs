@@ -0,0 +1,606 @@
+//! ### English
+//! Minimal EGL wrapper for GLES-only hosts (ANGLE on Windows, Mesa/ARM drivers elsewhere).
+//!
+//! Used instead of the GLFW loader when the engine is created with
+//! `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`: the Servo thread gets an EGL context that shares objects
+//! with the host's `EGLContext`.
+//!
+//! ### 中文
+//! 面向仅支持 GLES 宿主的最小 EGL 封装（Windows 上为 ANGLE，其他平台为 Mesa/ARM 驱动）。
+//!
+//! 当引擎以 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 创建时替代 GLFW loader：Servo 线程获得一个与宿主
+//! `EGLContext` 共享对象的 EGL 上下文。
+
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::OnceLock;
+
+/// ### English
+/// Raw `EGLDisplay` handle.
+///
+/// ### 中文
+/// 原始 `EGLDisplay` 句柄。
+type EglDisplay = *mut c_void;
+/// ### English
+/// Raw `EGLConfig` handle.
+///
+/// ### 中文
+/// 原始 `EGLConfig` 句柄。
+type EglConfig = *mut c_void;
+/// ### English
+/// Raw `EGLSurface` handle.
+///
+/// ### 中文
+/// 原始 `EGLSurface` 句柄。
+type EglSurface = *mut c_void;
+/// ### English
+/// Raw `EGLContext` handle used by this crate.
+///
+/// ### 中文
+/// 本 crate 使用的原始 `EGLContext` 句柄。
+pub type EglContextPtr = *mut c_void;
+
+/// ### English
+/// `EGLBoolean` / `EGLint` / `EGLenum` as used by the entry points below.
+///
+/// ### 中文
+/// 下列入口函数使用的 `EGLBoolean` / `EGLint` / `EGLenum`。
+type EglInt = i32;
+
+/// ### English
+/// Function pointer type for `eglGetProcAddress`.
+///
+/// ### 中文
+/// `eglGetProcAddress` 的函数指针类型。
+type EglGetProcAddress = unsafe extern "system" fn(*const c_char) -> *const c_void;
+/// ### English
+/// Function pointer type for `eglGetError`.
+///
+/// ### 中文
+/// `eglGetError` 的函数指针类型。
+type EglGetError = unsafe extern "system" fn() -> EglInt;
+/// ### English
+/// Function pointer type for `eglQueryString`.
+///
+/// ### 中文
+/// `eglQueryString` 的函数指针类型。
+type EglQueryString = unsafe extern "system" fn(EglDisplay, EglInt) -> *const c_char;
+/// ### English
+/// Function pointer type for `eglQueryContext`.
+///
+/// ### 中文
+/// `eglQueryContext` 的函数指针类型。
+type EglQueryContext =
+    unsafe extern "system" fn(EglDisplay, EglContextPtr, EglInt, *mut EglInt) -> u32;
+/// ### English
+/// Function pointer type for `eglChooseConfig`.
+///
+/// ### 中文
+/// `eglChooseConfig` 的函数指针类型。
+type EglChooseConfig = unsafe extern "system" fn(
+    EglDisplay,
+    *const EglInt,
+    *mut EglConfig,
+    EglInt,
+    *mut EglInt,
+) -> u32;
+/// ### English
+/// Function pointer type for `eglBindAPI`.
+///
+/// ### 中文
+/// `eglBindAPI` 的函数指针类型。
+type EglBindApi = unsafe extern "system" fn(u32) -> u32;
+/// ### English
+/// Function pointer type for `eglCreateContext`.
+///
+/// ### 中文
+/// `eglCreateContext` 的函数指针类型。
+type EglCreateContext =
+    unsafe extern "system" fn(EglDisplay, EglConfig, EglContextPtr, *const EglInt) -> EglContextPtr;
+/// ### English
+/// Function pointer type for `eglDestroyContext`.
+///
+/// ### 中文
+/// `eglDestroyContext` 的函数指针类型。
+type EglDestroyContext = unsafe extern "system" fn(EglDisplay, EglContextPtr) -> u32;
+/// ### English
+/// Function pointer type for `eglCreatePbufferSurface`.
+///
+/// ### 中文
+/// `eglCreatePbufferSurface` 的函数指针类型。
+type EglCreatePbufferSurface =
+    unsafe extern "system" fn(EglDisplay, EglConfig, *const EglInt) -> EglSurface;
+/// ### English
+/// Function pointer type for `eglDestroySurface`.
+///
+/// ### 中文
+/// `eglDestroySurface` 的函数指针类型。
+type EglDestroySurface = unsafe extern "system" fn(EglDisplay, EglSurface) -> u32;
+/// ### English
+/// Function pointer type for `eglMakeCurrent`.
+///
+/// ### 中文
+/// `eglMakeCurrent` 的函数指针类型。
+type EglMakeCurrent =
+    unsafe extern "system" fn(EglDisplay, EglSurface, EglSurface, EglContextPtr) -> u32;
+
+/// ### English
+/// `EGL_NONE`.
+///
+/// ### 中文
+/// `EGL_NONE`。
+const EGL_NONE: EglInt = 0x3038;
+/// ### English
+/// `EGL_CONFIG_ID`.
+///
+/// ### 中文
+/// `EGL_CONFIG_ID`。
+const EGL_CONFIG_ID: EglInt = 0x3028;
+/// ### English
+/// `EGL_EXTENSIONS`.
+///
+/// ### 中文
+/// `EGL_EXTENSIONS`。
+const EGL_EXTENSIONS: EglInt = 0x3055;
+/// ### English
+/// `EGL_HEIGHT`.
+///
+/// ### 中文
+/// `EGL_HEIGHT`。
+const EGL_HEIGHT: EglInt = 0x3056;
+/// ### English
+/// `EGL_WIDTH`.
+///
+/// ### 中文
+/// `EGL_WIDTH`。
+const EGL_WIDTH: EglInt = 0x3057;
+/// ### English
+/// `EGL_CONTEXT_CLIENT_TYPE`.
+///
+/// ### 中文
+/// `EGL_CONTEXT_CLIENT_TYPE`。
+const EGL_CONTEXT_CLIENT_TYPE: EglInt = 0x3097;
+/// ### English
+/// `EGL_CONTEXT_CLIENT_VERSION`.
+///
+/// ### 中文
+/// `EGL_CONTEXT_CLIENT_VERSION`。
+const EGL_CONTEXT_CLIENT_VERSION: EglInt = 0x3098;
+/// ### English
+/// `EGL_OPENGL_ES_API`.
+///
+/// ### 中文
+/// `EGL_OPENGL_ES_API`。
+const EGL_OPENGL_ES_API: EglInt = 0x30A0;
+/// ### English
+/// `EGL_CONTEXT_OPENGL_DEBUG` (EGL 1.5).
+///
+/// ### 中文
+/// `EGL_CONTEXT_OPENGL_DEBUG`（EGL 1.5）。
+const EGL_CONTEXT_OPENGL_DEBUG: EglInt = 0x31B0;
+/// ### English
+/// `EGL_TRUE`.
+///
+/// ### 中文
+/// `EGL_TRUE`。
+const EGL_TRUE: EglInt = 1;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// EGL entry point and display provided by the embedder (e.g. LWJGL's `EGL` class or
+/// `glfwGetEGLDisplay`).
+///
+/// All fields are raw addresses (`usize`) and must be non-zero when installing.
+///
+/// ### 中文
+/// 由宿主提供的 EGL 入口函数与 display（例如 LWJGL 的 `EGL` 类或 `glfwGetEGLDisplay`）。
+///
+/// 所有字段都是原始地址（`usize`），安装时必须全部为非 0。
+pub struct EmbedderEglApi {
+    /// ### English
+    /// Pointer to `eglGetProcAddress`; it must resolve core EGL entry points (EGL 1.5 or
+    /// `EGL_KHR_get_all_proc_addresses`, which ANGLE provides).
+    ///
+    /// ### 中文
+    /// 指向 `eglGetProcAddress` 的函数指针地址；它必须能解析 EGL 核心入口函数（EGL 1.5 或
+    /// `EGL_KHR_get_all_proc_addresses`，ANGLE 均支持）。
+    pub egl_get_proc_address: usize,
+    /// ### English
+    /// `EGLDisplay` that owns the host's context.
+    ///
+    /// ### 中文
+    /// 持有宿主上下文的 `EGLDisplay`。
+    pub egl_display: usize,
+}
+
+static EMBEDDER_EGL_API: OnceLock<EglApi> = OnceLock::new();
+
+/// ### English
+/// Installs the embedder-provided EGL entry point and display for this process.
+///
+/// This is a one-time installation backed by `OnceLock`; repeated calls return an error.
+///
+/// #### Parameters
+/// - `api`: Embedder EGL entry point and display.
+///
+/// ### 中文
+/// 为当前进程安装宿主提供的 EGL 入口函数与 display。
+///
+/// 该安装由 `OnceLock` 保证只执行一次；重复调用会返回错误。
+///
+/// #### 参数
+/// - `api`：宿主提供的 EGL 入口函数与 display。
+pub(crate) fn install_embedder_egl_api(api: EmbedderEglApi) -> Result<(), String> {
+    if api.egl_get_proc_address == 0 {
+        return Err("EmbedderEglApi.egl_get_proc_address is NULL".to_string());
+    }
+    if api.egl_display == 0 {
+        return Err("EmbedderEglApi.egl_display is NULL".to_string());
+    }
+
+    let get_proc_address =
+        unsafe { std::mem::transmute::<usize, EglGetProcAddress>(api.egl_get_proc_address) };
+    let load = |name: &CStr| {
+        let address = unsafe { get_proc_address(name.as_ptr()) };
+        if address.is_null() {
+            Err(format!(
+                "eglGetProcAddress could not resolve {}",
+                name.to_string_lossy()
+            ))
+        } else {
+            Ok(address)
+        }
+    };
+
+    let table = unsafe {
+        EglApi {
+            display: api.egl_display as EglDisplay,
+            get_proc_address,
+            get_error: std::mem::transmute::<*const c_void, EglGetError>(load(c"eglGetError")?),
+            query_string: std::mem::transmute::<*const c_void, EglQueryString>(load(
+                c"eglQueryString",
+            )?),
+            query_context: std::mem::transmute::<*const c_void, EglQueryContext>(load(
+                c"eglQueryContext",
+            )?),
+            choose_config: std::mem::transmute::<*const c_void, EglChooseConfig>(load(
+                c"eglChooseConfig",
+            )?),
+            bind_api: std::mem::transmute::<*const c_void, EglBindApi>(load(c"eglBindAPI")?),
+            create_context: std::mem::transmute::<*const c_void, EglCreateContext>(load(
+                c"eglCreateContext",
+            )?),
+            destroy_context: std::mem::transmute::<*const c_void, EglDestroyContext>(load(
+                c"eglDestroyContext",
+            )?),
+            create_pbuffer_surface: std::mem::transmute::<*const c_void, EglCreatePbufferSurface>(
+                load(c"eglCreatePbufferSurface")?,
+            ),
+            destroy_surface: std::mem::transmute::<*const c_void, EglDestroySurface>(load(
+                c"eglDestroySurface",
+            )?),
+            make_current: std::mem::transmute::<*const c_void, EglMakeCurrent>(load(
+                c"eglMakeCurrent",
+            )?),
+        }
+    };
+
+    EMBEDDER_EGL_API
+        .set(table)
+        .map_err(|_| "Embedder EGL API is already installed".to_string())?;
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+/// ### English
+/// Loaded minimal EGL API used by the engine (context creation/control + proc loading).
+///
+/// ### 中文
+/// 引擎使用的最小 EGL API（上下文创建/控制 + 函数指针加载）。
+pub struct EglApi {
+    /// ### English
+    /// Embedder `EGLDisplay`.
+    ///
+    /// ### 中文
+    /// 宿主的 `EGLDisplay`。
+    display: EglDisplay,
+    /// ### English
+    /// Function pointer: `eglGetProcAddress`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglGetProcAddress`。
+    get_proc_address: EglGetProcAddress,
+    /// ### English
+    /// Function pointer: `eglGetError`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglGetError`。
+    get_error: EglGetError,
+    /// ### English
+    /// Function pointer: `eglQueryString`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglQueryString`。
+    query_string: EglQueryString,
+    /// ### English
+    /// Function pointer: `eglQueryContext`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglQueryContext`。
+    query_context: EglQueryContext,
+    /// ### English
+    /// Function pointer: `eglChooseConfig`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglChooseConfig`。
+    choose_config: EglChooseConfig,
+    /// ### English
+    /// Function pointer: `eglBindAPI`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglBindAPI`。
+    bind_api: EglBindApi,
+    /// ### English
+    /// Function pointer: `eglCreateContext`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglCreateContext`。
+    create_context: EglCreateContext,
+    /// ### English
+    /// Function pointer: `eglDestroyContext`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglDestroyContext`。
+    destroy_context: EglDestroyContext,
+    /// ### English
+    /// Function pointer: `eglCreatePbufferSurface`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglCreatePbufferSurface`。
+    create_pbuffer_surface: EglCreatePbufferSurface,
+    /// ### English
+    /// Function pointer: `eglDestroySurface`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglDestroySurface`。
+    destroy_surface: EglDestroySurface,
+    /// ### English
+    /// Function pointer: `eglMakeCurrent`.
+    ///
+    /// ### 中文
+    /// 函数指针：`eglMakeCurrent`。
+    make_current: EglMakeCurrent,
+}
+
+unsafe impl Send for EglApi {}
+unsafe impl Sync for EglApi {}
+
+/// ### English
+/// Offscreen EGL context created by `EglApi::create_shared_context`.
+///
+/// ### 中文
+/// 由 `EglApi::create_shared_context` 创建的离屏 EGL 上下文。
+#[derive(Clone, Copy)]
+pub struct EglOffscreenContext {
+    /// ### English
+    /// Context sharing objects with the host context.
+    ///
+    /// ### 中文
+    /// 与宿主上下文共享对象的上下文。
+    pub context: EglContextPtr,
+    /// ### English
+    /// 1x1 pbuffer surface, or NULL when `EGL_KHR_surfaceless_context` is available.
+    ///
+    /// ### 中文
+    /// 1x1 pbuffer surface；支持 `EGL_KHR_surfaceless_context` 时为 NULL。
+    surface: EglSurface,
+}
+
+impl EglApi {
+    /// ### English
+    /// Returns the installed EGL API.
+    ///
+    /// An embedder-provided entry point must be installed via `xian_web_engine_set_egl_api`
+    /// before calling this.
+    ///
+    /// ### 中文
+    /// 返回已安装的 EGL API。
+    ///
+    /// 调用前必须由宿主通过 `xian_web_engine_set_egl_api` 安装入口函数。
+    #[inline]
+    pub fn load() -> Result<Self, String> {
+        EMBEDDER_EGL_API.get().copied().ok_or_else(|| {
+            "Embedder EGL API is not installed; call xian_web_engine_set_egl_api before xian_web_engine_create_with_flags"
+                .to_string()
+        })
+    }
+
+    /// ### English
+    /// Makes `offscreen` current on the calling thread (NULL context releases the current one).
+    ///
+    /// #### Parameters
+    /// - `offscreen`: Context/surface pair to bind.
+    ///
+    /// ### 中文
+    /// 将 `offscreen` 设为调用线程的 current 上下文（context 为 NULL 时释放当前上下文）。
+    ///
+    /// #### 参数
+    /// - `offscreen`：要绑定的 context/surface 对。
+    #[inline]
+    pub unsafe fn make_current(&self, offscreen: Option<&EglOffscreenContext>) {
+        let (surface, context) = offscreen
+            .map(|o| (o.surface, o.context))
+            .unwrap_or((std::ptr::null_mut(), std::ptr::null_mut()));
+        unsafe { (self.make_current)(self.display, surface, surface, context) };
+    }
+
+    /// ### English
+    /// Loads a GL/GLES function pointer via EGL.
+    ///
+    /// #### Parameters
+    /// - `name`: NUL-terminated proc name.
+    ///
+    /// ### 中文
+    /// 通过 EGL 加载 GL/GLES 函数指针。
+    ///
+    /// #### 参数
+    /// - `name`：以 NUL 结尾的函数名。
+    #[inline]
+    pub unsafe fn get_proc_address(&self, name: &CStr) -> *const c_void {
+        unsafe { (self.get_proc_address)(name.as_ptr()) }
+    }
+
+    /// ### English
+    /// Destroys an offscreen context created by this loader.
+    ///
+    /// #### Parameters
+    /// - `offscreen`: Context/surface pair to destroy.
+    ///
+    /// ### 中文
+    /// 销毁由本 loader 创建的离屏上下文。
+    ///
+    /// #### 参数
+    /// - `offscreen`：需要销毁的 context/surface 对。
+    pub unsafe fn destroy_context(&self, offscreen: &EglOffscreenContext) {
+        unsafe {
+            (self.destroy_context)(self.display, offscreen.context);
+            if !offscreen.surface.is_null() {
+                (self.destroy_surface)(self.display, offscreen.surface);
+            }
+        }
+    }
+
+    /// ### English
+    /// Creates an offscreen context with the same config, client API and version as `share`,
+    /// sharing its objects.
+    ///
+    /// The context is surfaceless when the display supports `EGL_KHR_surfaceless_context`,
+    /// otherwise it is bound to a 1x1 pbuffer. A requested debug context falls back to a regular
+    /// one when the implementation rejects `EGL_CONTEXT_OPENGL_DEBUG` (pre-1.5 EGL).
+    ///
+    /// #### Parameters
+    /// - `share`: Host `EGLContext` whose objects are shared.
+    /// - `debug`: Request a debug context.
+    ///
+    /// ### 中文
+    /// 创建一个与 `share` 使用相同 config、客户端 API 与版本的离屏上下文，并共享其对象。
+    ///
+    /// 若 display 支持 `EGL_KHR_surfaceless_context` 则不使用 surface，否则绑定一个 1x1 pbuffer。若实现
+    /// 不接受 `EGL_CONTEXT_OPENGL_DEBUG`（EGL 1.5 之前），请求的 debug 上下文会回退为普通上下文。
+    ///
+    /// #### 参数
+    /// - `share`：被共享对象的宿主 `EGLContext`。
+    /// - `debug`：是否请求 debug 上下文。
+    pub unsafe fn create_shared_context(
+        &self,
+        share: EglContextPtr,
+        debug: bool,
+    ) -> Result<EglOffscreenContext, String> {
+        let display = self.display;
+        let query = |attribute: EglInt| {
+            let mut value: EglInt = 0;
+            let ok = unsafe { (self.query_context)(display, share, attribute, &mut value) };
+            if ok == 0 {
+                Err(format!(
+                    "eglQueryContext(0x{attribute:04X}) failed: EGL error 0x{:04X}",
+                    unsafe { (self.get_error)() }
+                ))
+            } else {
+                Ok(value)
+            }
+        };
+        let config_id = query(EGL_CONFIG_ID)?;
+        let client_type = query(EGL_CONTEXT_CLIENT_TYPE)?;
+        let client_version = query(EGL_CONTEXT_CLIENT_VERSION)?;
+        if client_type != EGL_OPENGL_ES_API {
+            return Err(format!(
+                "EGL host context uses client API 0x{client_type:04X}; only OpenGL ES is supported"
+            ));
+        }
+
+        let config_attribs = [EGL_CONFIG_ID, config_id, EGL_NONE];
+        let mut config: EglConfig = std::ptr::null_mut();
+        let mut config_count: EglInt = 0;
+        let ok = unsafe {
+            (self.choose_config)(
+                display,
+                config_attribs.as_ptr(),
+                &mut config,
+                1,
+                &mut config_count,
+            )
+        };
+        if ok == 0 || config_count < 1 {
+            return Err(format!(
+                "eglChooseConfig found no config with id {config_id}"
+            ));
+        }
+
+        unsafe { (self.bind_api)(client_type as u32) };
+
+        let surfaceless = unsafe { self.has_extension(c"EGL_KHR_surfaceless_context") };
+        let surface = if surfaceless {
+            std::ptr::null_mut()
+        } else {
+            let pbuffer_attribs = [EGL_WIDTH, 1, EGL_HEIGHT, 1, EGL_NONE];
+            let surface =
+                unsafe { (self.create_pbuffer_surface)(display, config, pbuffer_attribs.as_ptr()) };
+            if surface.is_null() {
+                return Err(format!(
+                    "eglCreatePbufferSurface failed: EGL error 0x{:04X}",
+                    unsafe { (self.get_error)() }
+                ));
+            }
+            surface
+        };
+
+        let mut context = std::ptr::null_mut();
+        if debug {
+            let debug_attribs = [
+                EGL_CONTEXT_CLIENT_VERSION,
+                client_version,
+                EGL_CONTEXT_OPENGL_DEBUG,
+                EGL_TRUE,
+                EGL_NONE,
+            ];
+            context =
+                unsafe { (self.create_context)(display, config, share, debug_attribs.as_ptr()) };
+        }
+        if context.is_null() {
+            let context_attribs = [EGL_CONTEXT_CLIENT_VERSION, client_version, EGL_NONE];
+            context =
+                unsafe { (self.create_context)(display, config, share, context_attribs.as_ptr()) };
+        }
+        if context.is_null() {
+            let error = unsafe { (self.get_error)() };
+            if !surface.is_null() {
+                unsafe { (self.destroy_surface)(display, surface) };
+            }
+            return Err(format!("eglCreateContext failed: EGL error 0x{error:04X}"));
+        }
+
+        Ok(EglOffscreenContext { context, surface })
+    }
+
+    /// ### English
+    /// Returns whether the display advertises `name` in `EGL_EXTENSIONS`.
+    ///
+    /// #### Parameters
+    /// - `name`: Extension name.
+    ///
+    /// ### 中文
+    /// 返回 display 的 `EGL_EXTENSIONS` 是否包含 `name`。
+    ///
+    /// #### 参数
+    /// - `name`：扩展名。
+    unsafe fn has_extension(&self, name: &CStr) -> bool {
+        let extensions = unsafe { (self.query_string)(self.display, EGL_EXTENSIONS) };
+        if extensions.is_null() {
+            return false;
+        }
+        let extensions = unsafe { CStr::from_ptr(extensions) }.to_bytes();
+        extensions
+            .split(|&b| b == b' ')
+            .any(|ext| ext == name.to_bytes())
+    }
+}
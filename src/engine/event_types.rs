@@ -0,0 +1,582 @@
+//! ### English
+//! C ABI view event types (Servo thread -> embedder).
+//!
+//! Events are POD so Java/Panama can poll them in batches; any text payload is copied into a
+//! caller-provided byte buffer and referenced by offset/length.
+//!
+//! ### 中文
+//! C ABI view 事件类型（Servo 线程 -> 宿主）。
+//!
+//! 事件保持为 POD，方便 Java/Panama 批量轮询；文本载荷会被复制到调用方提供的字节缓冲区，并以偏移/长度引用。
+
+/// ### English
+/// One view event.
+///
+/// The meaning of `value0`/`value1` depends on `kind` (see `XIAN_WEB_ENGINE_VIEW_EVENT_*`).
+///
+/// ### 中文
+/// 单个 view 事件。
+///
+/// `value0`/`value1` 的含义取决于 `kind`（见 `XIAN_WEB_ENGINE_VIEW_EVENT_*`）。
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct XianWebEngineViewEvent {
+    /// ### English
+    /// Event kind (one of `XIAN_WEB_ENGINE_VIEW_EVENT_*`).
+    ///
+    /// ### 中文
+    /// 事件类型（`XIAN_WEB_ENGINE_VIEW_EVENT_*` 之一）。
+    pub kind: u32,
+    /// ### English
+    /// Status code (`XIAN_WEB_ENGINE_STATUS_OK` or a negative error code).
+    ///
+    /// ### 中文
+    /// 状态码（`XIAN_WEB_ENGINE_STATUS_OK` 或负数错误码）。
+    pub status: i32,
+    /// ### English
+    /// Request ID this event completes (`0` for unsolicited events).
+    ///
+    /// ### 中文
+    /// 该事件所完成请求的 ID（主动推送的事件为 `0`）。
+    pub request_id: u64,
+    /// ### English
+    /// First kind-specific value.
+    ///
+    /// ### 中文
+    /// 第一个与类型相关的值。
+    pub value0: i64,
+    /// ### English
+    /// Second kind-specific value.
+    ///
+    /// ### 中文
+    /// 第二个与类型相关的值。
+    pub value1: i64,
+    /// ### English
+    /// Byte offset of the UTF-8 text payload inside the caller's text buffer.
+    ///
+    /// ### 中文
+    /// UTF-8 文本载荷在调用方文本缓冲区中的字节偏移。
+    pub text_offset: u32,
+    /// ### English
+    /// Byte length of the text payload (`0` if none).
+    ///
+    /// ### 中文
+    /// 文本载荷的字节长度（无文本则为 `0`）。
+    pub text_len: u32,
+    /// ### English
+    /// Event flags (`XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 事件标记位（`XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_*`）。
+    pub flags: u32,
+    /// ### English
+    /// Reserved (always 0).
+    ///
+    /// ### 中文
+    /// 保留字段（始终为 0）。
+    pub _reserved: u32,
+}
+
+/// ### English
+/// Monotonic delivery counters of one view's queues, filled by `xian_web_engine_view_get_queue_stats`.
+///
+/// Every counter starts at 0 when the view is created and only grows. Compare a snapshot with the
+/// previous one: if a `*_dropped` counter moved, something was lost in between, and the embedder
+/// should resync the state it mirrors (e.g. re-query the URL/title) instead of trusting its
+/// incremental view.
+///
+/// ### 中文
+/// 单个 view 各队列的单调递增投递计数，由 `xian_web_engine_view_get_queue_stats` 填充。
+///
+/// 所有计数在 view 创建时为 0，且只增不减。将快照与上一次比较：若某个 `*_dropped` 计数发生变化，说明期间有内容
+/// 丢失，宿主应重新同步其镜像的状态（例如重新查询 URL/标题），而不是继续信任增量得到的状态。
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XianWebEngineViewQueueStats {
+    /// ### English
+    /// View events produced so far (queued or dropped); the generation of the event queue.
+    ///
+    /// ### 中文
+    /// 迄今产生的 view 事件数（已入队或被丢弃）；即事件队列的代数。
+    pub event_generation: u64,
+    /// ### English
+    /// View events dropped because the event queue was full.
+    ///
+    /// ### 中文
+    /// 因事件队列已满而丢弃的 view 事件数。
+    pub events_dropped: u64,
+    /// ### English
+    /// Input events submitted through `xian_web_engine_view_send_input_events` (accepted or not).
+    ///
+    /// ### 中文
+    /// 通过 `xian_web_engine_view_send_input_events` 提交的输入事件数（无论是否被接收）。
+    pub input_generation: u64,
+    /// ### English
+    /// Submitted input events rejected because the input queue was full.
+    ///
+    /// ### 中文
+    /// 因输入队列已满而被拒绝的已提交输入事件数。
+    pub input_dropped: u64,
+    /// ### English
+    /// Binary messages from the page dropped because too many were left unreleased.
+    ///
+    /// ### 中文
+    /// 因未释放的消息过多而丢弃的页面二进制消息数。
+    pub binary_messages_dropped: u64,
+}
+
+/// ### English
+/// Events were dropped because the per-view queue was full; `value0` = number of dropped events since
+/// the previous poll (`XianWebEngineViewQueueStats::events_dropped` keeps the running total).
+///
+/// ### 中文
+/// 由于 per-view 队列已满而丢弃了事件；`value0` = 自上次轮询以来丢弃的事件数
+/// （`XianWebEngineViewQueueStats::events_dropped` 保存累计值）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_DROPPED: u32 = 1;
+
+/// ### English
+/// A page export finished; `value0`/`value1` = exported width/height, text = output path.
+///
+/// ### 中文
+/// 页面导出完成；`value0`/`value1` = 导出宽/高，text = 输出路径。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE: u32 = 2;
+
+/// ### English
+/// An accessibility tree snapshot is ready; text = JSON document
+/// (`{"url", "devicePixelRatio", "root": {"role", "name", "bounds": [x, y, w, h], "children", ...}}`).
+///
+/// ### 中文
+/// 无障碍树快照已就绪；text = JSON 文档
+/// （`{"url", "devicePixelRatio", "root": {"role", "name", "bounds": [x, y, w, h], "children", ...}}`）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_ACCESSIBILITY_TREE: u32 = 3;
+
+/// ### English
+/// A pause/resume-all-media request finished; `value0` = media element count, `value1` = elements still
+/// playing.
+///
+/// ### 中文
+/// 暂停/恢复全部媒体的请求已完成；`value0` = 媒体元素数量，`value1` = 仍在播放的元素数量。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_STATE: u32 = 4;
+
+/// ### English
+/// The page's media session playback state changed; `value0` = `1` playing, `0` paused, `-1` none.
+///
+/// ### 中文
+/// 页面媒体会话的播放状态发生变化；`value0` = `1` 播放中、`0` 已暂停、`-1` 无。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_PLAYBACK: u32 = 5;
+
+/// ### English
+/// A session save finished; text = opaque session blob for `xian_web_engine_view_create_from_session`.
+///
+/// ### 中文
+/// 会话保存完成；text = 供 `xian_web_engine_view_create_from_session` 使用的不透明会话 blob。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_SESSION_SAVED: u32 = 6;
+
+/// ### English
+/// The page requested a permission; `request_id` = ID to pass to
+/// `xian_web_engine_view_respond_permission`, `value0` = feature (`XIAN_WEB_ENGINE_PERMISSION_*`),
+/// `value1` = milliseconds until the request is denied automatically.
+///
+/// ### 中文
+/// 页面请求了一项权限；`request_id` = 传给 `xian_web_engine_view_respond_permission` 的 ID，
+/// `value0` = 权限特性（`XIAN_WEB_ENGINE_PERMISSION_*`），`value1` = 距离自动拒绝的毫秒数。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST: u32 = 7;
+
+/// ### English
+/// The page entered (`value0 = 1`) or left (`value0 = 0`) pointer lock; while locked the embedder
+/// should capture the cursor and send `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` events.
+///
+/// Entering requires transient user activation and a granted `PERMISSION_REQUEST` with feature
+/// `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK`.
+///
+/// ### 中文
+/// 页面进入（`value0 = 1`）或退出（`value0 = 0`）pointer lock；锁定期间宿主应捕获光标并发送
+/// `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` 事件。
+///
+/// 进入锁定需要瞬时用户激活，且特性为 `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK` 的 `PERMISSION_REQUEST`
+/// 已被授予。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK: u32 = 8;
+
+/// ### English
+/// A host drag ended with a drop; text = drop effect chosen by the page (`"none"` if the page did not
+/// accept the data).
+///
+/// ### 中文
+/// 宿主拖拽以放下结束；text = 页面选择的 drop effect（若页面未接受数据则为 `"none"`）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE: u32 = 9;
+
+/// ### English
+/// The page opened a file chooser (`<input type=file>`); `request_id` = ID to pass to
+/// `xian_web_engine_view_provide_files`, `value0` = `1` if several files may be selected,
+/// text = comma-separated accept filters (empty if any file is accepted).
+///
+/// ### 中文
+/// 页面打开了文件选择器（`<input type=file>`）；`request_id` = 传给 `xian_web_engine_view_provide_files`
+/// 的 ID，`value0` = 可多选时为 `1`，text = 以逗号分隔的 accept 过滤器（接受任意文件时为空）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER: u32 = 10;
+
+/// ### English
+/// A script blocked the page longer than the slow-script timeout; `value0` = milliseconds the page
+/// has been unresponsive, `value1` = `1` if the page was unloaded and the view answers again
+/// (`SLOW_SCRIPT_POLICY_STOP`), `0` if the script is still running.
+///
+/// ### 中文
+/// 脚本阻塞页面超过慢脚本超时时长；`value0` = 页面无响应的毫秒数，`value1` = 页面已被卸载且 view 已重新应答时为
+/// `1`（`SLOW_SCRIPT_POLICY_STOP`），脚本仍在运行时为 `0`。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT: u32 = 11;
+
+/// ### English
+/// A selected-text request finished; text = selected text (empty if nothing is selected).
+///
+/// ### 中文
+/// 读取选中文本的请求已完成；text = 选中的文本（未选中任何内容时为空）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_SELECTED_TEXT: u32 = 12;
+
+/// ### English
+/// A copy request finished; text = copied text, which the embedder should place on its clipboard
+/// (the engine does not own the system clipboard).
+///
+/// ### 中文
+/// 复制请求已完成；text = 被复制的文本，宿主应将其写入自己的剪贴板（引擎不持有系统剪贴板）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_COPY: u32 = 13;
+
+/// ### English
+/// The page logged to the console (only while `xian_web_engine_view_set_console_capture` is enabled);
+/// `value0` = level (`XIAN_WEB_ENGINE_LOG_LEVEL_*`), `value1` = source line (`0` if unknown),
+/// text = source URL, a newline, then the message.
+///
+/// ### 中文
+/// 页面输出了 console 日志（仅在 `xian_web_engine_view_set_console_capture` 开启时）；`value0` = 级别
+/// （`XIAN_WEB_ENGINE_LOG_LEVEL_*`），`value1` = 源代码行号（未知时为 `0`），text = 源 URL、一个换行符，
+/// 随后是消息内容。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE: u32 = 14;
+
+/// ### English
+/// The page threw an uncaught exception or left a promise rejection unhandled; `value0` = source
+/// line, `value1` = column (`0` if unknown), text = source URL, a newline, then the message (with
+/// stack when available).
+///
+/// ### 中文
+/// 页面抛出了未捕获的异常，或存在未处理的 Promise 拒绝；`value0` = 源代码行号，`value1` = 列号（未知时为
+/// `0`），text = 源 URL、一个换行符，随后是消息（可用时附带调用栈）。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_PAGE_ERROR: u32 = 15;
+
+/// ### English
+/// A subresource (element, `fetch` or XHR) failed to load; `value0` = HTTP status (`0` for network
+/// errors or when unknown), text = resource URL, a newline, then what loaded it (`img`, `script`,
+/// `fetch`, `xhr`, ...) with the error if any.
+///
+/// ### 中文
+/// 子资源（元素、`fetch` 或 XHR）加载失败；`value0` = HTTP 状态码（网络错误或未知时为 `0`），
+/// text = 资源 URL、一个换行符，随后是发起加载者（`img`、`script`、`fetch`、`xhr` 等）及可能的错误信息。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_RESOURCE_ERROR: u32 = 16;
+
+/// ### English
+/// The page sent a binary message with `xianHost.sendBinary`; `request_id` = message ID,
+/// `value0` = channel, `value1` = length in bytes. Read it with
+/// `xian_web_engine_view_get_binary_message` and release it with
+/// `xian_web_engine_view_release_binary_message`.
+///
+/// ### 中文
+/// 页面通过 `xianHost.sendBinary` 发送了一条二进制消息；`request_id` = 消息 ID，`value0` = 通道号，
+/// `value1` = 字节长度。通过 `xian_web_engine_view_get_binary_message` 读取，并通过
+/// `xian_web_engine_view_release_binary_message` 释放。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_BINARY_MESSAGE: u32 = 17;
+
+/// ### English
+/// The engine is done with a frame pushed by `xian_web_engine_push_external_frame` and the texture
+/// may be rendered into again; `value0` = stream ID, `value1` = texture ID. `status` is `OK` if the
+/// page received the frame and `FAILED` if it was dropped (stream not open or texture unreadable).
+///
+/// ### 中文
+/// 引擎已用完通过 `xian_web_engine_push_external_frame` 推送的帧，可再次渲染该纹理；`value0` = 流 ID，
+/// `value1` = 纹理 ID。页面收到该帧时 `status` 为 `OK`；帧被丢弃（流未打开或纹理不可读）时为 `FAILED`。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_EXTERNAL_FRAME_RELEASED: u32 = 18;
+
+/// ### English
+/// A replay started by `xian_web_engine_view_replay` ended; `value0` = number of actions replayed.
+/// `status` is `OK` if every recorded action was replayed and `FAILED` if a newer replay replaced it.
+///
+/// ### 中文
+/// 由 `xian_web_engine_view_replay` 启动的回放已结束；`value0` = 已回放的动作数。全部录制动作均已回放时
+/// `status` 为 `OK`；被更新的回放替换时为 `FAILED`。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED: u32 = 19;
+
+/// ### English
+/// An image larger than the limits set by `xian_web_engine_set_image_limits` was replaced with a
+/// downscaled copy; `value0` = original width, `value1` = original height (pixels),
+/// text = image URL.
+///
+/// ### 中文
+/// 一张超出 `xian_web_engine_set_image_limits` 所设限制的图片已被替换为缩小后的副本；`value0` = 原始宽度，
+/// `value1` = 原始高度（像素），text = 图片 URL。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED: u32 = 20;
+
+/// ### English
+/// The page exceeded the JavaScript heap limit (`xian_web_engine_set_heap_limit`); `value0` = limit
+/// in MiB, `value1` = `1` if the page's script thread crashed, `0` if the engine unloaded the page
+/// (`about:blank`).
+///
+/// ### 中文
+/// 页面超出了 JavaScript 堆上限（`xian_web_engine_set_heap_limit`）；
+/// `value0` = 上限（MiB），`value1` = 页面脚本线程崩溃时为 `1`，由引擎卸载页面（`about:blank`）时为 `0`。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT: u32 = 21;
+
+/// ### English
+/// The view's page crashed (with `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`, its content process
+/// died); text = crash reason, then a newline and the backtrace when available. Navigate the view
+/// to load a page again.
+///
+/// ### 中文
+/// 该 view 的页面崩溃了（使用 `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS` 时为其内容进程退出）；text =
+/// 崩溃原因，可用时随后是一个换行符与调用栈。对该 view 发起导航即可重新加载页面。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED: u32 = 22;
+
+/// ### English
+/// The page handled an input event sent by `xian_web_engine_view_send_tracked_input_event`;
+/// `request_id` = the event's correlation ID, `value0` = `1` if the page consumed the event (a
+/// widget was hit or a handler took it) and `0` if it fell through, `value1` = mask of
+/// `XIAN_WEB_ENGINE_INPUT_RESULT_*` flags.
+///
+/// ### 中文
+/// 页面已处理通过 `xian_web_engine_view_send_tracked_input_event` 发送的输入事件；`request_id` = 事件的
+/// 关联 ID，`value0` = 页面消费了该事件（命中了控件或被处理器接管）时为 `1`，事件穿透时为 `0`，`value1` =
+/// `XIAN_WEB_ENGINE_INPUT_RESULT_*` 标志的掩码。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT: u32 = 23;
+
+/// ### English
+/// Input result flag: a page handler called `preventDefault()` on the event.
+///
+/// ### 中文
+/// 输入结果标志：页面处理器对该事件调用了 `preventDefault()`。
+pub const XIAN_WEB_ENGINE_INPUT_RESULT_DEFAULT_PREVENTED: u32 = 1 << 0;
+
+/// ### English
+/// Input result flag: the page consumed the event (for example a wheel event scrolled it).
+///
+/// ### 中文
+/// 输入结果标志：页面消费了该事件（例如滚轮事件使页面发生了滚动）。
+pub const XIAN_WEB_ENGINE_INPUT_RESULT_CONSUMED: u32 = 1 << 1;
+
+/// ### English
+/// Input result flag: a pointer event landed on a page element other than the root (`<html>` or
+/// `<body>`), so it hit content rather than empty space.
+///
+/// ### 中文
+/// 输入结果标志：指针事件落在根元素（`<html>` 或 `<body>`）以外的页面元素上，即命中了内容而非空白区域。
+pub const XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT: u32 = 1 << 2;
+
+/// ### English
+/// An editable element gained or lost focus; `value0` = `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the
+/// focused element (`NONE` when focus left the editable element), `value1` = caret position in
+/// characters (`-1` if unknown). Read the element's rectangle with
+/// `xian_web_engine_view_get_text_input` to place an on-screen keyboard or IME composition window.
+///
+/// ### 中文
+/// 可编辑元素获得或失去了焦点；`value0` = 获得焦点元素的 `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*`（焦点离开可编辑
+/// 元素时为 `NONE`），`value1` = 以字符计的光标位置（未知时为 `-1`）。通过
+/// `xian_web_engine_view_get_text_input` 读取该元素的矩形，以放置屏幕键盘或 IME 组字窗口。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT: u32 = 24;
+
+/// ### English
+/// Text input type: no editable element has focus.
+///
+/// ### 中文
+/// 文本输入类型：没有可编辑元素获得焦点。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE: u32 = 0;
+
+/// ### English
+/// Text input type: single-line text field.
+///
+/// ### 中文
+/// 文本输入类型：单行文本框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEXT: u32 = 1;
+
+/// ### English
+/// Text input type: multi-line text (`<textarea>` or `contenteditable`).
+///
+/// ### 中文
+/// 文本输入类型：多行文本（`<textarea>` 或 `contenteditable`）。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_MULTILINE: u32 = 2;
+
+/// ### English
+/// Text input type: password field.
+///
+/// ### 中文
+/// 文本输入类型：密码框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_PASSWORD: u32 = 3;
+
+/// ### English
+/// Text input type: number field.
+///
+/// ### 中文
+/// 文本输入类型：数字输入框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NUMBER: u32 = 4;
+
+/// ### English
+/// Text input type: email address field.
+///
+/// ### 中文
+/// 文本输入类型：电子邮件地址输入框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_EMAIL: u32 = 5;
+
+/// ### English
+/// Text input type: telephone number field.
+///
+/// ### 中文
+/// 文本输入类型：电话号码输入框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEL: u32 = 6;
+
+/// ### English
+/// Text input type: URL field.
+///
+/// ### 中文
+/// 文本输入类型：URL 输入框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_URL: u32 = 7;
+
+/// ### English
+/// Text input type: search field.
+///
+/// ### 中文
+/// 文本输入类型：搜索框。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_SEARCH: u32 = 8;
+
+/// ### English
+/// Text input type: other field (date, time, color, ...).
+///
+/// ### 中文
+/// 文本输入类型：其他输入框（日期、时间、颜色等）。
+pub const XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_OTHER: u32 = 9;
+
+/// ### English
+/// The text payload did not fit into the caller's buffer and was truncated.
+///
+/// ### 中文
+/// 文本载荷无法放入调用方缓冲区，已被截断。
+pub const XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_TEXT_TRUNCATED: u32 = 1 << 0;
+
+/// ### English
+/// Success.
+///
+/// ### 中文
+/// 成功。
+pub const XIAN_WEB_ENGINE_STATUS_OK: i32 = 0;
+
+/// ### English
+/// Generic failure (details in the text payload when available).
+///
+/// ### 中文
+/// 一般性失败（如有，详细信息见文本载荷）。
+pub const XIAN_WEB_ENGINE_STATUS_FAILED: i32 = -1;
+
+/// ### English
+/// An I/O error occurred (details in the text payload).
+///
+/// ### 中文
+/// 发生 I/O 错误（详细信息见文本载荷）。
+pub const XIAN_WEB_ENGINE_STATUS_IO_ERROR: i32 = -3;
+
+/// ### English
+/// The request was rejected because its command lane is full; retry later.
+///
+/// ### 中文
+/// 请求所在的命令通道已满而被拒绝；请稍后重试。
+pub const XIAN_WEB_ENGINE_STATUS_QUEUE_FULL: i32 = -4;
+
+/// ### English
+/// The request was rejected because the engine is shutting down.
+///
+/// ### 中文
+/// 引擎正在关闭，请求被拒绝。
+pub const XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN: i32 = -5;
+
+/// ### English
+/// The engine did not answer within the configured timeout (see `XianWebEngineConfig`).
+///
+/// ### 中文
+/// 引擎未在配置的超时时长内应答（见 `XianWebEngineConfig`）。
+pub const XIAN_WEB_ENGINE_STATUS_TIMED_OUT: i32 = -6;
+
+/// ### English
+/// Permission feature: Geolocation.
+///
+/// ### 中文
+/// 权限特性：地理位置。
+pub const XIAN_WEB_ENGINE_PERMISSION_GEOLOCATION: i64 = 1;
+
+/// ### English
+/// Permission feature: Desktop notifications.
+///
+/// ### 中文
+/// 权限特性：桌面通知。
+pub const XIAN_WEB_ENGINE_PERMISSION_NOTIFICATIONS: i64 = 2;
+
+/// ### English
+/// Permission feature: Push messages.
+///
+/// ### 中文
+/// 权限特性：推送消息。
+pub const XIAN_WEB_ENGINE_PERMISSION_PUSH: i64 = 3;
+
+/// ### English
+/// Permission feature: Web MIDI.
+///
+/// ### 中文
+/// 权限特性：Web MIDI。
+pub const XIAN_WEB_ENGINE_PERMISSION_MIDI: i64 = 4;
+
+/// ### English
+/// Permission feature: Camera capture.
+///
+/// ### 中文
+/// 权限特性：摄像头采集。
+pub const XIAN_WEB_ENGINE_PERMISSION_CAMERA: i64 = 5;
+
+/// ### English
+/// Permission feature: Microphone capture.
+///
+/// ### 中文
+/// 权限特性：麦克风采集。
+pub const XIAN_WEB_ENGINE_PERMISSION_MICROPHONE: i64 = 6;
+
+/// ### English
+/// Permission feature: Audio output device selection.
+///
+/// ### 中文
+/// 权限特性：音频输出设备选择。
+pub const XIAN_WEB_ENGINE_PERMISSION_SPEAKER: i64 = 7;
+
+/// ### English
+/// Permission feature: Media device enumeration.
+///
+/// ### 中文
+/// 权限特性：媒体设备枚举。
+pub const XIAN_WEB_ENGINE_PERMISSION_DEVICE_INFO: i64 = 8;
+
+/// ### English
+/// Permission feature: Background sync.
+///
+/// ### 中文
+/// 权限特性：后台同步。
+pub const XIAN_WEB_ENGINE_PERMISSION_BACKGROUND_SYNC: i64 = 9;
+
+/// ### English
+/// Permission feature: Web Bluetooth.
+///
+/// ### 中文
+/// 权限特性：Web 蓝牙。
+pub const XIAN_WEB_ENGINE_PERMISSION_BLUETOOTH: i64 = 10;
+
+/// ### English
+/// Permission feature: Persistent storage.
+///
+/// ### 中文
+/// 权限特性：持久化存储。
+pub const XIAN_WEB_ENGINE_PERMISSION_PERSISTENT_STORAGE: i64 = 11;
+
+/// ### English
+/// Permission feature: Pointer lock (requested by the engine's pointer lock emulation).
+///
+/// ### 中文
+/// 权限特性：pointer lock（由引擎的 pointer lock 模拟发起）。
+pub const XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK: i64 = 12;
@@ -0,0 +1,181 @@
+//! ### English
+//! Producer fence accounting.
+//!
+//! Every published frame inserts one producer `GLsync` and deletes it once the slot is reused. Sync
+//! objects cannot be pooled: a `GLsync` signals exactly once and GL has no call to re-arm it, so
+//! `glFenceSync` must create a fresh object per frame (views that do not need one can skip it with
+//! `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE`). These counters let the embedder measure the
+//! resulting driver churn at high frame rates.
+//!
+//! ### 中文
+//! 生产者 fence 统计。
+//!
+//! 每个发布的帧都会插入一个生产者 `GLsync`，并在槽位被复用时删除。同步对象无法池化：`GLsync` 只会 signal 一次，
+//! GL 也没有重新布置它的调用，因此每帧都必须由 `glFenceSync` 创建新对象（不需要 fence 的 view 可通过
+//! `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE` 跳过）。这些计数器让宿主能够测量高帧率下由此带来的驱动开销。
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// ### English
+/// Shortest interval the per-second rates are averaged over; more frequent queries return the
+/// previous rates.
+///
+/// ### 中文
+/// 每秒速率的最短平均区间；更频繁的查询返回上一次的速率。
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// ### English
+/// Producer fences created by this process.
+///
+/// ### 中文
+/// 本进程创建的生产者 fence 数。
+static CREATED: AtomicU64 = AtomicU64::new(0);
+
+/// ### English
+/// Producer fences deleted by this process.
+///
+/// ### 中文
+/// 本进程删除的生产者 fence 数。
+static DELETED: AtomicU64 = AtomicU64::new(0);
+
+/// ### English
+/// Current rate window (`None` until the first query).
+///
+/// ### 中文
+/// 当前速率窗口（首次查询之前为 `None`）。
+static WINDOW: Mutex<Option<RateWindow>> = Mutex::new(None);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// ### English
+/// Producer fence statistics of the process, filled by `xian_web_engine_get_fence_stats`.
+///
+/// ### 中文
+/// 本进程的生产者 fence 统计，由 `xian_web_engine_get_fence_stats` 填充。
+pub struct XianWebEngineFenceStats {
+    /// ### English
+    /// Producer fences created since the process started (monotonic).
+    ///
+    /// ### 中文
+    /// 自进程启动以来创建的生产者 fence 数（单调递增）。
+    pub created: u64,
+    /// ### English
+    /// Producer fences deleted since the process started (monotonic).
+    ///
+    /// ### 中文
+    /// 自进程启动以来删除的生产者 fence 数（单调递增）。
+    pub deleted: u64,
+    /// ### English
+    /// Producer fences created per second over the last window of at least one second (`0` until
+    /// the second query).
+    ///
+    /// ### 中文
+    /// 最近一个不短于 1 秒的窗口内每秒创建的生产者 fence 数（第二次查询之前为 `0`）。
+    pub created_per_second: f64,
+    /// ### English
+    /// Producer fences deleted per second over the last window of at least one second (`0` until
+    /// the second query).
+    ///
+    /// ### 中文
+    /// 最近一个不短于 1 秒的窗口内每秒删除的生产者 fence 数（第二次查询之前为 `0`）。
+    pub deleted_per_second: f64,
+}
+
+/// ### English
+/// Rate window shared by every query.
+///
+/// ### 中文
+/// 所有查询共享的速率窗口。
+struct RateWindow {
+    /// ### English
+    /// When the window started.
+    ///
+    /// ### 中文
+    /// 窗口开始的时间。
+    start: Instant,
+    /// ### English
+    /// `CREATED` at the start of the window.
+    ///
+    /// ### 中文
+    /// 窗口开始时的 `CREATED`。
+    created: u64,
+    /// ### English
+    /// `DELETED` at the start of the window.
+    ///
+    /// ### 中文
+    /// 窗口开始时的 `DELETED`。
+    deleted: u64,
+    /// ### English
+    /// `(created, deleted)` per second over the previous window.
+    ///
+    /// ### 中文
+    /// 上一个窗口内每秒的 `(created, deleted)`。
+    rates: (f64, f64),
+}
+
+/// ### English
+/// Records one producer fence created.
+///
+/// ### 中文
+/// 记录创建了一个生产者 fence。
+#[inline]
+pub(crate) fn record_created() {
+    CREATED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// ### English
+/// Records one producer fence deleted.
+///
+/// ### 中文
+/// 记录删除了一个生产者 fence。
+#[inline]
+pub(crate) fn record_deleted() {
+    DELETED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// ### English
+/// Returns the current totals and rolls the rate window over once it is at least one second old.
+///
+/// ### 中文
+/// 返回当前总数，并在速率窗口达到至少 1 秒时滚动到新窗口。
+pub(crate) fn fence_stats() -> XianWebEngineFenceStats {
+    let created = CREATED.load(Ordering::Relaxed);
+    let deleted = DELETED.load(Ordering::Relaxed);
+    let now = Instant::now();
+    let rates = match WINDOW.lock() {
+        Ok(mut window) => match window.as_mut() {
+            Some(window) => {
+                let elapsed = now.duration_since(window.start);
+                if elapsed >= RATE_WINDOW {
+                    let seconds = elapsed.as_secs_f64();
+                    window.rates = (
+                        created.saturating_sub(window.created) as f64 / seconds,
+                        deleted.saturating_sub(window.deleted) as f64 / seconds,
+                    );
+                    window.start = now;
+                    window.created = created;
+                    window.deleted = deleted;
+                }
+                window.rates
+            }
+            None => {
+                *window = Some(RateWindow {
+                    start: now,
+                    created,
+                    deleted,
+                    rates: (0.0, 0.0),
+                });
+                (0.0, 0.0)
+            }
+        },
+        Err(_) => (0.0, 0.0),
+    };
+    XianWebEngineFenceStats {
+        created,
+        deleted,
+        created_per_second: rates.0,
+        deleted_per_second: rates.1,
+    }
+}
@@ -41,3 +41,522 @@ pub const XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER: u32 = 1 << 1;
 /// 该模式下 `XianWebEngineFrame.producer_fence` 将始终为 `0`，宿主需自行保证不会采样到未完成的帧
 /// （例如使用其它同步机制）。
 pub const XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE: u32 = 1 << 2;
+
+/// ### English
+/// Quality: render at 2x the view size (supersampling) so small HUD text stays crisp once the
+/// consumer downsamples the frame.
+///
+/// Equivalent to creating the view with a render scale of `2.0` (see
+/// `xian_web_engine_view_set_render_scale`); acquired frames are twice the view size.
+///
+/// ### 中文
+/// 画质：以 view 尺寸的 2 倍渲染（超采样），使消费者缩小采样后小尺寸 HUD 文本依然清晰。
+///
+/// 等价于以 `2.0` 的渲染缩放创建 view（见 `xian_web_engine_view_set_render_scale`）；acquire 到的帧为
+/// view 尺寸的两倍。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X: u32 = 1 << 3;
+
+/// ### English
+/// Quality: allocate mipmapped slot textures (trilinear `LINEAR_MIPMAP_LINEAR` minification) and
+/// regenerate the mip chain on every present.
+///
+/// Removes shimmering when the host draws the view scaled down or at an angle in 3D space, at the
+/// cost of one `glGenerateMipmap` per frame on the Servo thread.
+///
+/// ### 中文
+/// 画质：为槽位纹理分配 mipmap（三线性 `LINEAR_MIPMAP_LINEAR` 缩小过滤），并在每次 present 时重新生成
+/// mip 链。
+///
+/// 可消除宿主在 3D 空间中缩小或倾斜绘制 view 时的闪烁，代价是 Servo 线程每帧一次 `glGenerateMipmap`。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS: u32 = 1 << 4;
+
+/// ### English
+/// Interop: back the slot textures with Vulkan images exported through external memory, so a Vulkan
+/// renderer can consume the frames (see `xian_web_engine_view_get_vulkan_slot`).
+///
+/// Requires `xian_web_engine_set_vulkan_device` to have been called, and cannot be combined with
+/// `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE`.
+///
+/// ### 中文
+/// 互操作：槽位纹理由通过外部内存导出的 Vulkan image 支撑，使 Vulkan 渲染器可以消费帧
+///（见 `xian_web_engine_view_get_vulkan_slot`）。
+///
+/// 需要先调用 `xian_web_engine_set_vulkan_device`，且不能与
+/// `XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE` 同时使用。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT: u32 = 1 << 5;
+
+/// ### English
+/// Performance: resize by orphaning instead of reallocating every slot under the resizing flag.
+///
+/// The back slot and every FREE slot get freshly allocated textures swapped in (the old ones are
+/// parked in the slot's texture pool, never re-specified while in flight); READY and held slots are
+/// left alone and switched over the next time the producer takes them. The consumer keeps acquiring
+/// throughout (frames report their own size, so a few old-size frames may follow the resize) and the
+/// producer never waits on consumer fences. Ignored with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`,
+/// whose slot images cannot be swapped.
+///
+/// ### 中文
+/// 性能：通过孤立（orphaning）方式 resize，而不是在 resizing 标记下重新分配所有槽位。
+///
+/// back 槽位与所有 FREE 槽位会换入新分配的纹理（旧纹理放入槽位的纹理池，绝不会在使用中被重新指定存储）；READY
+/// 与被持有的槽位保持不变，在生产者下次取得它们时再切换。消费者全程可以继续 acquire（帧会报告各自的尺寸，因此 resize
+/// 之后可能还会出现几帧旧尺寸的帧），生产者也不会等待 consumer fence。与
+/// `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` 同时使用时被忽略，因为其槽位 image 无法交换。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE: u32 = 1 << 6;
+
+/// ### English
+/// Bit offset of the slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`) inside the view
+/// flags: `flags | (format << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT)`.
+///
+/// ### 中文
+/// 槽位纹理颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`）在 view flags 中的位偏移：
+/// `flags | (format << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT)`。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT: u32 = 8;
+
+/// ### English
+/// Mask of the slot texture color format bits inside the view flags.
+///
+/// ### 中文
+/// view flags 中槽位纹理颜色格式所占位的掩码。
+pub const XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK: u32 = 0xF << 8;
+
+/// ### English
+/// Engine flag: create the Servo-side contexts through EGL instead of GLFW (ANGLE on Windows,
+/// GLES-only drivers on ARM).
+///
+/// With this flag, the handle passed to `xian_web_engine_create_with_flags` is the host's
+/// `EGLContext` (not a `GLFWwindow*`) and `xian_web_engine_set_egl_api` must have been called.
+/// Only OpenGL ES host contexts are supported.
+///
+/// ### 中文
+/// 引擎标志：通过 EGL 而非 GLFW 创建 Servo 侧上下文（Windows 上的 ANGLE、ARM 上仅支持 GLES 的驱动）。
+///
+/// 设置该标志时，传给 `xian_web_engine_create_with_flags` 的句柄是宿主的 `EGLContext`
+/// （而不是 `GLFWwindow*`），且必须先调用 `xian_web_engine_set_egl_api`。仅支持 OpenGL ES 宿主上下文。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_EGL: u32 = 1 << 0;
+
+/// ### English
+/// Engine flag: create the Servo-side contexts as debug contexts and forward their
+/// `GL_KHR_debug` messages to the log callback.
+///
+/// Capture is also enabled without this flag when the host context is already a debug context
+/// (the offscreen context mirrors it). Severities map to log levels (high → ERROR, medium → WARN,
+/// low → INFO, notification → DEBUG), so the log callback's `max_level` filters them.
+///
+/// ### 中文
+/// 引擎标志：将 Servo 侧上下文创建为 debug 上下文，并把其 `GL_KHR_debug` 消息转发给日志回调。
+///
+/// 若宿主上下文本身已是 debug 上下文（离屏上下文会沿用它），即使不设置该标志也会启用捕获。严重级别
+/// 映射为日志级别（high → ERROR、medium → WARN、low → INFO、notification → DEBUG），因此由日志回调的
+/// `max_level` 过滤。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG: u32 = 1 << 1;
+
+/// ### English
+/// Engine flag: "paranoid GL" mode. Every rendering-context operation is followed by
+/// `glGetError`; the first error of each frame is logged and recorded in
+/// `XianWebEngineHealth` (`gl_error_frames`, `last_gl_error`, `last_gl_error_op`).
+///
+/// Meant for diagnosing black or corrupt textures on unusual drivers; `glGetError` stalls the GL
+/// pipeline on many drivers, so leave it off in normal builds.
+///
+/// ### 中文
+/// 引擎标志：“paranoid GL” 模式。每次渲染上下文操作之后都会调用 `glGetError`；每帧的第一个错误会被
+/// 记录到日志以及 `XianWebEngineHealth`（`gl_error_frames`、`last_gl_error`、`last_gl_error_op`）中。
+///
+/// 用于诊断少见驱动上的黑屏或纹理损坏；`glGetError` 在许多驱动上会阻塞 GL 管线，正常构建中请保持关闭。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL: u32 = 1 << 2;
+
+/// ### English
+/// Engine flag: hand out this engine's views as generational handle table IDs instead of raw
+/// pointers (`XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE`).
+///
+/// The IDs travel through the same `XianWebEngineView*` parameters, so bindings need no other
+/// changes, but they must be treated as opaque values and never dereferenced. Every call validates
+/// the ID, so a stale one (use after `xian_web_engine_view_destroy`, double destroy) is rejected and
+/// logged, and the call returns its failure value instead of invoking undefined behavior. Meant for
+/// GC-based hosts whose finalizers may race with explicit destruction; the lookup costs one
+/// uncontended read lock per call. The table is process-global and shared by every engine created
+/// with this flag.
+///
+/// ### 中文
+/// 引擎标志：以分代句柄表 ID 而非原始指针的形式交出该引擎的 view
+/// （`XIAN_WEB_ENGINE_CAPABILITY_HANDLE_TABLE`）。
+///
+/// 这些 ID 通过同样的 `XianWebEngineView*` 参数传递，因此绑定层无需其他改动，但必须将其视为不透明值，
+/// 绝不能解引用。每次调用都会校验 ID，因此陈旧 ID（`xian_web_engine_view_destroy` 之后使用、重复销毁）
+/// 会被拒绝并记录日志，调用返回其失败值，而不会引发未定义行为。适用于终结器可能与显式销毁竞争的、基于 GC
+/// 的宿主；每次调用的查找开销为一次无竞争的读锁。该表是进程级的，由所有以该标志创建的引擎共享。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE: u32 = 1 << 3;
+
+/// ### English
+/// Engine flag: run page content in separate processes (Servo's multiprocess mode; not supported on
+/// Windows, `XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS`).
+///
+/// A crashing content process then takes down only its pages: each affected view gets a
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED` event while the Servo thread and other views keep running.
+/// Servo applies the mode to every view of the engine (it cannot be chosen per view), so put
+/// untrusted views in a separate engine created with this flag. Servo has no setting for the
+/// content process executable: it re-runs the host executable with `--content-process <token>`, and
+/// the host's entry point must pass that token to `xian_web_engine_run_content_process`. Engine
+/// creation therefore fails on Windows and when the host executable is a `java`/`javaw` launcher,
+/// which cannot do this; use a native launcher that embeds the JVM.
+///
+/// ### 中文
+/// 引擎标志：在独立进程中运行页面内容（Servo 的多进程模式；Windows 不支持，
+/// `XIAN_WEB_ENGINE_CAPABILITY_MULTIPROCESS`）。
+///
+/// 此时崩溃的内容进程只会拖垮它自己的页面：受影响的每个 view 会收到 `XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED`
+/// 事件，而 Servo 线程与其他 view 继续运行。Servo 将该模式应用于引擎的所有 view（无法按 view 选择），因此
+/// 请把不受信任的 view 放到以该标志创建的独立引擎中。Servo 没有用于指定内容进程可执行文件的设置：它以
+/// `--content-process <token>` 重新运行宿主可执行文件，宿主入口必须把该 token 传给
+/// `xian_web_engine_run_content_process`。因此在 Windows 上，或宿主可执行文件为无法做到这一点的
+/// `java`/`javaw` 启动器时，引擎创建会失败；请使用内嵌 JVM 的原生启动器。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS: u32 = 1 << 4;
+
+/// ### English
+/// Engine flag: always route the engine's HTTP(S) traffic through its loopback proxy.
+///
+/// Without this flag the proxy only runs if a DNS resolver callback or network limit was installed
+/// before the engine was created. The proxy cannot be added later, so create the engine with this
+/// flag if it may later need `xian_web_engine_set_dns_resolver`,
+/// `xian_web_engine_set_network_limits` or connection-level enforcement of
+/// `xian_web_engine_set_network_mode` (without a proxy, `CACHE_ONLY` behaves like `OFFLINE`).
+///
+/// ### 中文
+/// 引擎标志：始终让该引擎的 HTTP(S) 流量经由其回环代理转发。
+///
+/// 不设置该标志时，只有在引擎创建之前已安装 DNS 解析回调或网络限制，代理才会运行。代理无法事后添加，因此若
+/// 引擎之后可能需要 `xian_web_engine_set_dns_resolver`、`xian_web_engine_set_network_limits` 或
+/// `xian_web_engine_set_network_mode` 的连接层执行，请以该标志创建引擎（没有代理时 `CACHE_ONLY` 与 `OFFLINE`
+/// 行为相同）。
+pub const XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY: u32 = 1 << 5;
+
+/// ### English
+/// Sandbox restriction: block script execution in the view's document.
+///
+/// Sandbox flags are checked with `xian_web_engine_view_set_sandbox`; `0` means "not sandboxed".
+/// Servo only sandboxes nested browsing contexts, so this restriction cannot be enforced on a
+/// view's top-level document and is rejected.
+///
+/// ### 中文
+/// 沙箱限制：禁止在该 view 的文档中执行脚本。
+///
+/// 沙箱标志通过 `xian_web_engine_view_set_sandbox` 检查；`0` 表示“不启用沙箱”。Servo 只对嵌套浏览上下文
+/// 应用沙箱，因此该限制无法作用于 view 的顶层文档，会被拒绝。
+pub const XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS: u32 = 1 << 0;
+
+/// ### English
+/// Sandbox restriction: block form submission (cannot be enforced on a top-level document;
+/// rejected).
+///
+/// ### 中文
+/// 沙箱限制：禁止提交表单（无法作用于顶层文档；会被拒绝）。
+pub const XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS: u32 = 1 << 1;
+
+/// ### English
+/// Sandbox restriction: block nested browsing contexts (`iframe`/`frame`/`object`/`embed`;
+/// cannot be enforced on a top-level document; rejected).
+///
+/// ### 中文
+/// 沙箱限制：禁止嵌套浏览上下文（`iframe`/`frame`/`object`/`embed`；无法作用于顶层文档；会被拒绝）。
+pub const XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES: u32 = 1 << 2;
+
+/// ### English
+/// Sandbox restriction: block popups and auxiliary windows (always enforced: the engine never opens
+/// them).
+///
+/// ### 中文
+/// 沙箱限制：禁止弹窗与辅助窗口（始终生效：引擎从不打开它们）。
+pub const XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS: u32 = 1 << 3;
+
+/// ### English
+/// Sandbox restriction: treat the document as a unique opaque origin (no cookies/storage access;
+/// cannot be enforced on a top-level document; rejected).
+///
+/// ### 中文
+/// 沙箱限制：将文档视为唯一的不透明源（无法访问 cookie/存储；无法作用于顶层文档；会被拒绝）。
+pub const XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN: u32 = 1 << 4;
+
+/// ### English
+/// Export format: PNG image of the whole scrollable document.
+///
+/// ### 中文
+/// 导出格式：整个可滚动文档的 PNG 图像。
+pub const XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG: u32 = 0;
+
+/// ### English
+/// Export format: PDF paginating an image of the whole scrollable document into A4-proportioned
+/// pages.
+///
+/// ### 中文
+/// 导出格式：把整个可滚动文档的图像分页为 A4 比例页面的 PDF。
+pub const XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF: u32 = 1;
+
+/// ### English
+/// User script injection time: run when the document starts loading (before page scripts).
+///
+/// ### 中文
+/// 用户脚本注入时机：文档开始加载时执行（早于页面脚本）。
+pub const XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START: u32 = 0;
+
+/// ### English
+/// User script injection time: run once the DOM is parsed (`DOMContentLoaded`).
+///
+/// ### 中文
+/// 用户脚本注入时机：DOM 解析完成后（`DOMContentLoaded`）执行。
+pub const XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END: u32 = 1;
+
+/// ### English
+/// User script world: the page's main JavaScript world (the only world Servo supports).
+///
+/// ### 中文
+/// 用户脚本 world：页面的主 JavaScript world（Servo 唯一支持的 world）。
+pub const XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN: u32 = 0;
+
+/// ### English
+/// Background policy (default): inactive views are hidden but their timers and animation frames
+/// keep running.
+///
+/// ### 中文
+/// 后台策略（默认）：inactive 的 view 被隐藏，但其定时器与动画帧照常运行。
+pub const XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL: u32 = 0;
+
+/// ### English
+/// Background policy: inactive views are hidden and throttled; the page's native timers are
+/// cancelled and due timer and animation-frame callbacks run at most once per second (an interval
+/// that came due several times in between runs once).
+///
+/// ### 中文
+/// 后台策略：inactive 的 view 被隐藏并节流；页面的原生定时器会被取消，到期的定时器与动画帧回调每秒至多执行
+/// 一次（期间多次到期的 interval 只执行一次）。
+pub const XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ: u32 = 1;
+
+/// ### English
+/// Background policy: inactive views are hidden and throttled; the page's native timers are
+/// cancelled and nothing runs until the view becomes active again, when every timer is re-armed
+/// with its remaining delay (overdue ones fire once).
+///
+/// ### 中文
+/// 后台策略：inactive 的 view 被隐藏并节流；页面的原生定时器会被取消，在 view 重新变为 active 之前不执行任何
+/// 回调；恢复时每个定时器按剩余延迟重新设定（已过期的只触发一次）。
+pub const XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND: u32 = 2;
+
+/// ### English
+/// Network mode (default): pages may use the network.
+///
+/// ### 中文
+/// 网络模式（默认）：页面可以使用网络。
+pub const XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE: u32 = 0;
+
+/// ### English
+/// Network mode: every network request (`http`, `https`, `ws`, `wss`, `ftp`) fails and no
+/// connection leaves the engine; local content (`data:`, `blob:`, `file:`, engine resources) still
+/// loads.
+///
+/// ### 中文
+/// 网络模式：所有网络请求（`http`、`https`、`ws`、`wss`、`ftp`）都会失败，且不会有连接离开引擎；本地内容
+/// （`data:`、`blob:`、`file:`、引擎资源）仍可加载。
+pub const XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE: u32 = 1;
+
+/// ### English
+/// Network mode: like offline, except `http(s)` requests Servo's HTTP cache can answer without
+/// contacting the server still succeed; everything else (misses, stale entries needing
+/// revalidation) fails as a network error.
+///
+/// ### 中文
+/// 网络模式：与离线模式相同，但 Servo 的 HTTP 缓存无需联系服务器即可应答的 `http(s)` 请求仍会成功；
+/// 其余请求（未命中、需要重新验证的过期条目）均以网络错误失败。
+pub const XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY: u32 = 2;
+
+/// ### English
+/// Slow-script policy: no detection (default).
+///
+/// ### 中文
+/// 慢脚本策略：不检测（默认）。
+pub const XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE: u32 = 0;
+
+/// ### English
+/// Slow-script policy: report a `SLOW_SCRIPT` event when a script blocks the page for longer than
+/// the timeout.
+///
+/// ### 中文
+/// 慢脚本策略：当脚本阻塞页面超过超时时长时上报 `SLOW_SCRIPT` 事件。
+pub const XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY: u32 = 1;
+
+/// ### English
+/// Slow-script policy: unload the page (navigate to `about:blank`) and report a `SLOW_SCRIPT` event
+/// once the view answers again or another timeout has passed.
+///
+/// ### 中文
+/// 慢脚本策略：卸载页面（导航到 `about:blank`），并在 view 重新应答或又经过一个超时时长后上报 `SLOW_SCRIPT`
+/// 事件。
+pub const XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP: u32 = 2;
+
+/// ### English
+/// Timeout policy: give up on a blocking wait once its timeout expires and report
+/// `XIAN_WEB_ENGINE_STATUS_TIMED_OUT` (default).
+///
+/// ### 中文
+/// 超时策略：阻塞等待超过其超时时长后放弃，并报告 `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`（默认）。
+pub const XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST: u32 = 0;
+
+/// ### English
+/// Timeout policy: blocking waits never time out (the timeouts are ignored); a stuck Servo thread
+/// blocks the caller indefinitely.
+///
+/// ### 中文
+/// 超时策略：阻塞等待永不超时（忽略各超时设置）；Servo 线程卡住时调用方会无限期阻塞。
+pub const XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER: u32 = 1;
+
+/// ### English
+/// Storage group: the view uses the engine's own storage, stored in the engine config directory
+/// (default).
+///
+/// Any other value passed to `xian_web_engine_view_create_in_storage_group` names a group: views of
+/// the same group share their storage, views of different groups do not see each other's. A named
+/// group is stored in `<config_dir>/storage-groups/<group>`.
+///
+/// ### 中文
+/// 存储组：view 使用引擎自身的存储，保存在引擎配置目录中（默认）。
+///
+/// 传给 `xian_web_engine_view_create_in_storage_group` 的其他值均表示一个组：同组的 view 共享存储，不同组的
+/// view 互相看不到对方的存储。具名组保存在 `<config_dir>/storage-groups/<group>` 中。
+pub const XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT: u32 = 0;
+
+/// ### English
+/// Storage group: the view gets ephemeral storage, isolated from every other group, never written
+/// to disk and discarded when the engine is destroyed (e.g. untrusted server content). Views of
+/// this group share it with each other.
+///
+/// ### 中文
+/// 存储组：view 获得临时存储，与其他所有组隔离，从不写入磁盘，并在引擎销毁时丢弃（例如不受信任的服务器
+/// 内容）。该组的 view 之间共享这份存储。
+pub const XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL: u32 = 0xFFFF_FFFF;
+
+/// ### English
+/// Slot texture color format: sRGB-encoded RGBA8 when the context supports sRGB framebuffers,
+/// otherwise linear RGBA8 (default). `XianWebEngineFrame.color_format` reports the resolved format.
+///
+/// ### 中文
+/// 槽位纹理颜色格式：上下文支持 sRGB framebuffer 时为 sRGB 编码的 RGBA8，否则为线性 RGBA8（默认）。
+/// `XianWebEngineFrame.color_format` 会报告实际使用的格式。
+pub const XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO: u32 = 0;
+
+/// ### English
+/// Slot texture color format: `GL_RGBA8`, written without sRGB encoding.
+///
+/// ### 中文
+/// 槽位纹理颜色格式：`GL_RGBA8`，写入时不做 sRGB 编码。
+pub const XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8: u32 = 1;
+
+/// ### English
+/// Slot texture color format: 8-bit BGRA (`GL_RGBA8` storage specified with `GL_BGRA` pixel data),
+/// for hosts that read back or share frames with BGRA-based compositors.
+///
+/// ### 中文
+/// 槽位纹理颜色格式：8 位 BGRA（以 `GL_BGRA` 像素格式指定的 `GL_RGBA8` 存储），供需要读回或与基于 BGRA
+/// 的合成器共享帧的宿主使用。
+pub const XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8: u32 = 2;
+
+/// ### English
+/// Slot texture color format: `GL_SRGB8_ALPHA8` (view creation fails if sRGB framebuffers are not
+/// supported).
+///
+/// ### 中文
+/// 槽位纹理颜色格式：`GL_SRGB8_ALPHA8`（若不支持 sRGB framebuffer，则创建 view 失败）。
+pub const XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8: u32 = 3;
+
+/// ### English
+/// Slot texture color format: `GL_RGBA16F` (linear half-float, for HDR pipelines).
+///
+/// ### 中文
+/// 槽位纹理颜色格式：`GL_RGBA16F`（线性半精度浮点，用于 HDR 管线）。
+pub const XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F: u32 = 4;
+
+/// ### English
+/// Output colorspace: follow the color format (sRGB-encode writes to `SRGB8_A8` textures, write other
+/// formats as-is). Default.
+///
+/// ### 中文
+/// 输出色彩空间：跟随颜色格式（写入 `SRGB8_A8` 纹理时做 sRGB 编码，其它格式原样写入）。默认值。
+pub const XIAN_WEB_ENGINE_COLORSPACE_AUTO: u32 = 0;
+
+/// ### English
+/// Output colorspace: sRGB-encode writes (`GL_FRAMEBUFFER_SRGB`; requires `SRGB8_A8` textures).
+/// Sampling the texture through an sRGB view decodes back to linear automatically.
+///
+/// ### 中文
+/// 输出色彩空间：写入时做 sRGB 编码（`GL_FRAMEBUFFER_SRGB`；要求 `SRGB8_A8` 纹理）。
+/// 通过 sRGB 纹理采样时会自动解码回线性值。
+pub const XIAN_WEB_ENGINE_COLORSPACE_SRGB: u32 = 1;
+
+/// ### English
+/// Output colorspace: write values as-is without sRGB encoding, for hosts that apply their own
+/// transfer function during gamma-correct compositing.
+///
+/// ### 中文
+/// 输出色彩空间：不做 sRGB 编码、原样写入，供在 gamma 正确合成中自行施加传递函数的宿主使用。
+pub const XIAN_WEB_ENGINE_COLORSPACE_LINEAR: u32 = 2;
+
+/// ### English
+/// `prefers-color-scheme` reported to pages: `light`. Default.
+///
+/// ### 中文
+/// 向页面报告的 `prefers-color-scheme`：`light`。默认值。
+pub const XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT: u32 = 0;
+
+/// ### English
+/// `prefers-color-scheme` reported to pages: `dark`.
+///
+/// ### 中文
+/// 向页面报告的 `prefers-color-scheme`：`dark`。
+pub const XIAN_WEB_ENGINE_COLOR_SCHEME_DARK: u32 = 1;
+
+/// ### English
+/// Profiling category: Servo's time profiler; a per-category timing summary is written to the
+/// output path when Servo shuts down.
+///
+/// ### 中文
+/// 性能分析类别：Servo 时间分析器；Servo 关闭时将按类别汇总的耗时写入输出路径。
+pub const XIAN_WEB_ENGINE_PROFILING_TIME: u32 = 1 << 0;
+
+/// ### English
+/// Profiling category: Servo's per-event timeline, written as an HTML trace next to the output path
+/// (`<output_path>.html`). Implies `XIAN_WEB_ENGINE_PROFILING_TIME`.
+///
+/// ### 中文
+/// 性能分析类别：Servo 的逐事件时间线，以 HTML trace 写在输出路径旁（`<output_path>.html`）。
+/// 隐含 `XIAN_WEB_ENGINE_PROFILING_TIME`。
+pub const XIAN_WEB_ENGINE_PROFILING_TRACE: u32 = 1 << 1;
+
+/// ### English
+/// Idle flag: when entering idle, also release memory as for
+/// `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE`.
+///
+/// ### 中文
+/// 空闲标志：进入空闲时，同时按 `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` 释放内存。
+pub const XIAN_WEB_ENGINE_IDLE_FLAG_RELEASE_MEMORY: u32 = 1 << 0;
+
+/// ### English
+/// Memory pressure level: free engine-side pools and the spare slot textures views keep for fast
+/// resizes.
+///
+/// ### 中文
+/// 内存压力等级：释放引擎侧的池，以及各 view 为快速 resize 保留的备用槽位纹理。
+pub const XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE: u32 = 1;
+
+/// ### English
+/// Memory pressure level: everything `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` frees, plus Servo's
+/// in-memory HTTP cache.
+///
+/// ### 中文
+/// 内存压力等级：释放 `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` 的全部内容，外加 Servo 的内存 HTTP 缓存。
+pub const XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL: u32 = 2;
+
+/// ### English
+/// Golden test flag: write the captured frames as the new goldens instead of comparing them.
+///
+/// ### 中文
+/// golden 测试标志：将截取的帧写为新的 golden，而不是与之比较。
+#[cfg_attr(not(feature = "golden-tests"), allow(dead_code))]
+pub const XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE: u32 = 1 << 0;
@@ -0,0 +1,159 @@
+//! ### English
+//! Loom models of the producer/consumer protocol on `SharedFrameState`.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --lib loom_tests`; the frame atomics then
+//! resolve to loom's, so every interleaving of acquire/release/publish is explored.
+//!
+//! ### 中文
+//! `SharedFrameState` 上生产者/消费者协议的 loom 模型。
+//!
+//! 使用 `RUSTFLAGS="--cfg loom" cargo test --release --lib loom_tests` 运行；此时帧原子类型解析为 loom 的
+//! 实现，从而遍历 acquire/release/publish 的所有交错。
+
+use dpi::PhysicalSize;
+use loom::sync::Arc;
+use loom::thread;
+
+use super::{
+    SLOT_FREE, SLOT_HELD, SLOT_READY, SLOT_RELEASE_PENDING, SLOT_RENDERING, SharedFrameState,
+    TRIPLE_BUFFER_COUNT,
+};
+
+/// ### English
+/// Reserves a back slot the way the producer does (FREE first, then a non-snapshot READY).
+///
+/// #### Parameters
+/// - `shared`: State under test.
+/// - `back`: Slot published last.
+///
+/// ### 中文
+/// 以生产者的方式预留 back 槽位（优先 FREE，其次非快照的 READY）。
+///
+/// #### 参数
+/// - `shared`：被测状态。
+/// - `back`：最近发布的槽位。
+fn reserve(shared: &SharedFrameState, back: usize) -> Option<usize> {
+    let candidates = [
+        (back + 1) % TRIPLE_BUFFER_COUNT,
+        (back + 2) % TRIPLE_BUFFER_COUNT,
+    ];
+    if let Some(slot) = candidates
+        .into_iter()
+        .find(|&slot| shared.compare_exchange_state_relaxed(slot, SLOT_FREE, SLOT_RENDERING))
+    {
+        return Some(slot);
+    }
+    let snapshot = shared.snapshot_slot();
+    candidates.into_iter().find(|&slot| {
+        Some(slot) != snapshot
+            && shared.slot_state_relaxed(slot) == SLOT_READY
+            && shared.compare_exchange_state_relaxed(slot, SLOT_READY, SLOT_RENDERING)
+    })
+}
+
+/// ### English
+/// Renders and publishes `frames` frames, tagging each texture with its frame sequence.
+///
+/// #### Parameters
+/// - `shared`: State under test.
+/// - `frames`: Number of frames to attempt.
+///
+/// ### 中文
+/// 渲染并发布 `frames` 帧，并用帧序号标记每个纹理。
+///
+/// #### 参数
+/// - `shared`：被测状态。
+/// - `frames`：尝试的帧数。
+fn produce(shared: &SharedFrameState, frames: u64) {
+    let mut back = 0;
+    for seq in 1..=frames {
+        let Some(slot) = reserve(shared, back) else {
+            continue;
+        };
+        shared.set_texture_id(slot, seq as u32);
+        shared.publish(slot, 0, seq, 0);
+        back = slot;
+    }
+}
+
+#[test]
+fn acquire_never_observes_a_slot_being_rendered() {
+    loom::model(|| {
+        let shared = Arc::new(SharedFrameState::new(PhysicalSize::new(1, 1)));
+        let producer = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || produce(&shared, 3))
+        };
+
+        for _ in 0..2 {
+            if let Some(frame) = shared.try_acquire_front() {
+                assert_eq!(
+                    u64::from(frame.texture_id),
+                    shared.slot_seq_relaxed(frame.slot)
+                );
+                assert_eq!(shared.slot_state(frame.slot), SLOT_HELD);
+                shared.release_slot(frame.slot, 0);
+            }
+        }
+        producer.join().unwrap();
+    });
+}
+
+#[test]
+fn fenced_release_is_reclaimed_only_by_the_producer() {
+    loom::model(|| {
+        let shared = Arc::new(SharedFrameState::new(PhysicalSize::new(1, 1)));
+        produce(&shared, 1);
+        let frame = shared.try_acquire_front().expect("published frame");
+
+        let consumer = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || shared.release_slot(frame.slot, 1))
+        };
+        let producer = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                if shared
+                    .compare_exchange_state(frame.slot, SLOT_RELEASE_PENDING, SLOT_FREE)
+                    .is_ok()
+                {
+                    assert_eq!(shared.get_consumer_fence(frame.slot), 1);
+                    shared.clear_consumer_fence(frame.slot);
+                }
+            })
+        };
+        consumer.join().unwrap();
+        producer.join().unwrap();
+
+        let state = shared.slot_state(frame.slot);
+        assert!(matches!(state, SLOT_FREE | SLOT_RELEASE_PENDING), "{state}");
+    });
+}
+
+#[test]
+fn concurrent_reserve_and_acquire_never_share_a_slot() {
+    loom::model(|| {
+        let shared = Arc::new(SharedFrameState::new(PhysicalSize::new(1, 1)));
+        produce(&shared, 2);
+
+        let producer = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                let slot = reserve(&shared, 1);
+                if let Some(slot) = slot {
+                    assert_eq!(shared.slot_state(slot), SLOT_RENDERING);
+                }
+                slot
+            })
+        };
+        let acquired = shared.try_acquire_front();
+        let reserved = producer.join().unwrap();
+
+        if let (Some(frame), Some(slot)) = (acquired, reserved) {
+            assert_ne!(frame.slot, slot);
+        }
+        if let Some(frame) = acquired {
+            assert_eq!(shared.slot_state(frame.slot), SLOT_HELD);
+        }
+    });
+}
@@ -8,9 +8,18 @@
 //! Servo 线程（生产者）与 Java 线程（消费者）共享的无锁三缓冲帧状态。
 //!
 //! 热路径使用原子操作避免系统锁。
+#[cfg(all(test, loom))]
+mod loom_tests;
+mod notify;
 mod shared_state;
 mod slot;
+mod sync;
+#[cfg(all(test, not(loom)))]
+mod tests;
+#[cfg(any(debug_assertions, test))]
+mod transition;
 
+pub use notify::XianWebEngineFrameFn;
 pub use shared_state::SharedFrameState;
 
 /// ### English
@@ -25,6 +34,7 @@ pub(crate) const SLOT_READY: u8 = 1;
 pub(crate) const SLOT_HELD: u8 = 2;
 pub(crate) const SLOT_RELEASE_PENDING: u8 = 3;
 pub(crate) const SLOT_RENDERING: u8 = 4;
+pub(crate) const SLOT_PINNED: u8 = 5;
 
 /// ### English
 /// Metadata for one acquired frame (consumer side / Java thread).
@@ -32,7 +42,7 @@ pub(crate) const SLOT_RENDERING: u8 = 4;
 /// ### 中文
 /// 单个已获取帧的元数据（消费者侧 / Java 线程）。
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct AcquiredFrame {
+pub struct AcquiredFrame {
     /// ### English
     /// Triple-buffer slot index.
     ///
@@ -63,4 +73,28 @@ pub(crate) struct AcquiredFrame {
     /// ### 中文
     /// 帧高度（像素）。
     pub height: u32,
+    /// ### English
+    /// Frames published since the consumer's previous acquire that it never saw.
+    ///
+    /// ### 中文
+    /// 自消费者上一次 acquire 以来已发布、但消费者未取到的帧数。
+    pub frames_skipped: u32,
+    /// ### English
+    /// Slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`, never `AUTO`).
+    ///
+    /// ### 中文
+    /// 槽位纹理颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`，不会是 `AUTO`）。
+    pub color_format: u32,
+    /// ### English
+    /// Effective output colorspace of the frame (`XIAN_WEB_ENGINE_COLORSPACE_SRGB` or `_LINEAR`).
+    ///
+    /// ### 中文
+    /// 该帧实际的输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_SRGB` 或 `_LINEAR`）。
+    pub colorspace: u32,
+    /// ### English
+    /// Engine monotonic time the frame was presented at, in nanoseconds.
+    ///
+    /// ### 中文
+    /// 帧 present 时的引擎单调时间（纳秒）。
+    pub present_time_ns: u64,
 }
@@ -0,0 +1,121 @@
+//! ### English
+//! Frame-available notification for embedders that do not want to poll `acquire` every frame.
+//!
+//! ### 中文
+//! 帧可用通知，供不想每帧轮询 `acquire` 的宿主使用。
+
+use std::ffi::c_void;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// ### English
+/// Frame callback: receives the `frame_seq` of the frame that was just published. Invoked on the
+/// Servo thread right after publish; it must return quickly, must not call back into the engine
+/// for the same view's frames (acquire from the host render thread instead) and must not replace or
+/// remove its own view's callback (that waits for the running call and would deadlock).
+///
+/// ### 中文
+/// 帧回调：接收刚发布的帧的 `frame_seq`。在 Servo 线程上 publish 之后立即调用；回调必须尽快返回，
+/// 不应在回调中获取该 view 的帧（请在宿主渲染线程上 acquire），也不应替换或移除其所属 view 的回调
+/// （替换会等待正在执行的调用，从而造成死锁）。
+pub type XianWebEngineFrameFn = unsafe extern "C" fn(user_data: *mut c_void, frame_seq: u64);
+
+/// ### English
+/// Installed callback and its user data.
+///
+/// ### 中文
+/// 已安装的回调及其 user data。
+#[derive(Clone, Copy)]
+struct FrameSink {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    callback: XianWebEngineFrameFn,
+    /// ### English
+    /// Opaque user data (stored as an address so the sink is `Send + Sync`).
+    ///
+    /// ### 中文
+    /// 不透明 user data（以地址形式保存，使 sink 满足 `Send + Sync`）。
+    user_data: usize,
+}
+
+/// ### English
+/// Per-view frame callback slot, written by the embedder and read by the producer on publish.
+///
+/// ### 中文
+/// 每 view 的帧回调槽位：由宿主写入，生产者在 publish 时读取。
+#[derive(Default)]
+pub(super) struct FrameNotifier {
+    /// ### English
+    /// Whether a callback is installed (lets `publish` skip the lock when none is).
+    ///
+    /// ### 中文
+    /// 是否已安装回调（未安装时 `publish` 可跳过加锁）。
+    installed: AtomicBool,
+    /// ### English
+    /// Currently installed sink.
+    ///
+    /// ### 中文
+    /// 当前安装的 sink。
+    sink: RwLock<Option<FrameSink>>,
+}
+
+impl FrameNotifier {
+    /// ### English
+    /// Installs (or removes, with `None`) the frame callback.
+    ///
+    /// Waits for a call of the previous callback that is in flight, so once this returns the
+    /// previous callback is not running and will not run again.
+    ///
+    /// #### Parameters
+    /// - `callback`: Frame callback, or `None` to remove it.
+    /// - `user_data`: Opaque pointer passed back to `callback`.
+    ///
+    /// ### 中文
+    /// 安装（或以 `None` 移除）帧回调。
+    ///
+    /// 会等待上一个回调正在进行的调用结束，因此返回后上一个回调既不在执行，也不会再被调用。
+    ///
+    /// #### 参数
+    /// - `callback`：帧回调；`None` 表示移除。
+    /// - `user_data`：回传给 `callback` 的不透明指针。
+    pub(super) fn set(&self, callback: Option<XianWebEngineFrameFn>, user_data: *mut c_void) {
+        let Ok(mut sink) = self.sink.write() else {
+            return;
+        };
+        *sink = callback.map(|callback| FrameSink {
+            callback,
+            user_data: user_data as usize,
+        });
+        self.installed.store(sink.is_some(), Ordering::Release);
+    }
+
+    /// ### English
+    /// Invokes the installed callback (if any) for a newly published frame.
+    ///
+    /// The read lock is held while the callback runs, so `set` waits for the call to finish.
+    ///
+    /// #### Parameters
+    /// - `frame_seq`: Sequence number of the published frame.
+    ///
+    /// ### 中文
+    /// 为新发布的帧调用已安装的回调（若有）。
+    ///
+    /// 回调执行期间持有读锁，因此 `set` 会等待该调用结束。
+    ///
+    /// #### 参数
+    /// - `frame_seq`：已发布帧的序号。
+    pub(super) fn notify(&self, frame_seq: u64) {
+        if !self.installed.load(Ordering::Acquire) {
+            return;
+        }
+        let Ok(sink) = self.sink.read() else {
+            return;
+        };
+        if let Some(sink) = *sink {
+            unsafe { (sink.callback)(sink.user_data as *mut c_void, frame_seq) };
+        }
+    }
+}
@@ -8,10 +8,11 @@
 //!
 //! 将 READY 槽位提升为 HELD，并返回 `AcquiredFrame` 快照。
 
-use std::sync::atomic::Ordering;
-
 use dpi::PhysicalSize;
 
+use crate::engine::trace::{self, TRACE_SPAN_ACQUIRE};
+
+use super::super::sync::Ordering;
 use super::super::{AcquiredFrame, SLOT_HELD, SLOT_READY, TRIPLE_BUFFER_COUNT};
 use super::SharedFrameState;
 
@@ -22,6 +23,7 @@ impl SharedFrameState {
     /// ### 中文
     /// 尝试将最新的 READY 槽位 acquire 为 HELD（消费者侧）。
     pub fn try_acquire_front(&self) -> Option<AcquiredFrame> {
+        let _span = trace::span(TRACE_SPAN_ACQUIRE);
         if self.is_resizing() {
             return None;
         }
@@ -103,12 +105,17 @@ impl SharedFrameState {
     /// ### English
     /// Builds an `AcquiredFrame` snapshot for the given slot using Relaxed loads.
     ///
+    /// Also records the slot's frame sequence as the consumer's latest, deriving `frames_skipped`
+    /// from the gap to the previous acquire.
+    ///
     /// #### Parameters
     /// - `slot`: Slot index to snapshot.
     ///
     /// ### 中文
     /// 使用 Relaxed load 为指定槽位构造 `AcquiredFrame` 快照。
     ///
+    /// 同时把该槽位的帧序号记录为消费者最新值，并根据与上一次 acquire 的差值得出 `frames_skipped`。
+    ///
     /// #### 参数
     /// - `slot`：需要构造快照的槽位索引。
     fn acquired_frame(&self, slot: usize) -> AcquiredFrame {
@@ -117,12 +124,26 @@ impl SharedFrameState {
             slot_state.width.load(Ordering::Relaxed),
             slot_state.height.load(Ordering::Relaxed),
         );
+        let seq = slot_state.frame_seq.load(Ordering::Relaxed);
+        let previous = self
+            .frame_meta
+            .last_acquired_seq
+            .swap(seq, Ordering::Relaxed);
+        let frames_skipped = if previous == 0 || seq <= previous {
+            0
+        } else {
+            u32::try_from(seq - previous - 1).unwrap_or(u32::MAX)
+        };
         AcquiredFrame {
             slot,
             texture_id: slot_state.texture_id.load(Ordering::Relaxed),
             producer_fence: slot_state.producer_fence.load(Ordering::Relaxed),
             width: size.width,
             height: size.height,
+            frames_skipped,
+            color_format: self.color_format(),
+            colorspace: slot_state.colorspace.load(Ordering::Relaxed),
+            present_time_ns: slot_state.present_time_ns.load(Ordering::Relaxed),
         }
     }
 }
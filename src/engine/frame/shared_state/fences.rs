@@ -8,8 +8,7 @@
 //!
 //! 以原子方式在每个槽位存储生产者/消费者 fence 句柄（以 `u64` 表示）。
 
-use std::sync::atomic::Ordering;
-
+use super::super::sync::Ordering;
 use super::SharedFrameState;
 
 impl SharedFrameState {
@@ -1,11 +1,10 @@
 //! ### English
-//! Global flags stored in `SharedFrameState` (resizing / active).
+//! Global flags stored in `SharedFrameState` (resizing / active / snapshot pin / color format).
 //!
 //! ### 中文
-//! `SharedFrameState` 中存储的全局标记位（resizing / active）。
-
-use std::sync::atomic::Ordering;
+//! `SharedFrameState` 中存储的全局标记位（resizing / active / 快照固定 / 颜色格式）。
 
+use super::super::sync::Ordering;
 use super::SharedFrameState;
 
 impl SharedFrameState {
@@ -62,4 +61,63 @@ impl SharedFrameState {
     pub fn is_active(&self) -> bool {
         self.frame_meta.flags.active.load(Ordering::Relaxed) != 0
     }
+
+    /// ### English
+    /// Pins or unpins the latest READY frame (snapshot mode, producer-side).
+    ///
+    /// #### Parameters
+    /// - `pinned`: Whether the latest frame should stay acquirable.
+    ///
+    /// ### 中文
+    /// 固定或取消固定最新的 READY 帧（快照模式，生产者侧）。
+    ///
+    /// #### 参数
+    /// - `pinned`：最新帧是否应保持可 acquire。
+    pub fn set_snapshot_pin(&self, pinned: bool) {
+        self.frame_meta
+            .flags
+            .snapshot_pin
+            .store(u8::from(pinned), Ordering::Release);
+    }
+
+    /// ### English
+    /// Returns the slot of the pinned latest frame (`None` if not pinned or nothing was published).
+    ///
+    /// ### 中文
+    /// 返回被固定的最新帧所在槽位；未固定或尚未发布任何帧时返回 `None`。
+    pub fn snapshot_slot(&self) -> Option<usize> {
+        if self.frame_meta.flags.snapshot_pin.load(Ordering::Acquire) == 0 {
+            return None;
+        }
+        let packed = self.frame_meta.latest_packed.load(Ordering::Acquire);
+        let (latest, slot) = super::unpack_latest(packed);
+        (latest != 0).then_some(slot)
+    }
+
+    /// ### English
+    /// Records the resolved slot texture color format (producer-side, at context creation or
+    /// reconfiguration).
+    ///
+    /// #### Parameters
+    /// - `color_format`: Resolved `XIAN_WEB_ENGINE_COLOR_FORMAT_*` value.
+    ///
+    /// ### 中文
+    /// 记录实际使用的槽位纹理颜色格式（生产者侧，在创建或重新配置上下文时调用）。
+    ///
+    /// #### 参数
+    /// - `color_format`：已解析的 `XIAN_WEB_ENGINE_COLOR_FORMAT_*` 值。
+    pub fn set_color_format(&self, color_format: u32) {
+        self.frame_meta
+            .color_format
+            .store(color_format, Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Returns the resolved slot texture color format.
+    ///
+    /// ### 中文
+    /// 返回实际使用的槽位纹理颜色格式。
+    pub fn color_format(&self) -> u32 {
+        self.frame_meta.color_format.load(Ordering::Relaxed)
+    }
 }
@@ -10,17 +10,17 @@
 //! 由 Servo 线程（生产者）与 Java 线程（消费者）共享。
 //! 包含打包后的“latest READY 槽位”指针与全局标记位。
 
-use std::sync::atomic::{AtomicU8, AtomicU64};
-
 use dpi::PhysicalSize;
 
-use crate::engine::cache::{pad_after, pad_after2};
+use crate::engine::cache::{pad_after, pad_after3};
 
 use super::TRIPLE_BUFFER_COUNT;
+use super::notify::FrameNotifier;
 use super::slot::SlotAtomics;
+use super::sync::{AtomicU8, AtomicU32, AtomicU64};
 
 const CACHE_PAD_U64_BYTES: usize = pad_after::<AtomicU64>();
-const FRAME_FLAGS_PAD_BYTES: usize = pad_after2::<AtomicU8, AtomicU8>();
+const FRAME_FLAGS_PAD_BYTES: usize = pad_after3::<AtomicU8, AtomicU8, AtomicU8>();
 
 const SLOT_INDEX_BITS: u64 = 2;
 
@@ -80,6 +80,12 @@ pub struct SharedFrameState {
     /// ### 中文
     /// 全局元数据（latest 指针/标记位等）。
     frame_meta: FrameMeta,
+    /// ### English
+    /// Embedder callback invoked after each publish.
+    ///
+    /// ### 中文
+    /// 每次 publish 之后调用的宿主回调。
+    notifier: FrameNotifier,
 }
 
 #[repr(C, align(64))]
@@ -107,6 +113,18 @@ struct FrameMeta {
     /// ### 中文
     /// 由所有槽位共享的全局标记位。
     flags: FrameFlags,
+    /// ### English
+    /// Frame sequence of the consumer's last acquire (`0` = none yet; consumer-side only).
+    ///
+    /// ### 中文
+    /// 消费者上一次 acquire 的帧序号（`0` 表示尚未 acquire；仅消费者侧使用）。
+    last_acquired_seq: AtomicU64,
+    /// ### English
+    /// Resolved slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`).
+    ///
+    /// ### 中文
+    /// 实际使用的槽位纹理颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`）。
+    color_format: AtomicU32,
 }
 
 #[repr(C, align(64))]
@@ -129,6 +147,13 @@ struct FrameFlags {
     /// active 标记，用于节流渲染/输入（非 0 = active）。
     active: AtomicU8,
     /// ### English
+    /// Snapshot pin flag: while non-zero, the latest READY frame is never reclaimed and releasing
+    /// it returns it to READY.
+    ///
+    /// ### 中文
+    /// 快照固定标记：非 0 时最新的 READY 帧不会被回收，release 后会回到 READY。
+    snapshot_pin: AtomicU8,
+    /// ### English
     /// Padding for cache-line separation.
     ///
     /// ### 中文
@@ -151,9 +176,13 @@ impl SharedFrameState {
                 flags: FrameFlags {
                     resizing: AtomicU8::new(0),
                     active: AtomicU8::new(1),
+                    snapshot_pin: AtomicU8::new(0),
                     _padding: [0; FRAME_FLAGS_PAD_BYTES],
                 },
+                last_acquired_seq: AtomicU64::new(0),
+                color_format: AtomicU32::new(0),
             },
+            notifier: FrameNotifier::default(),
         }
     }
 }
@@ -161,6 +190,7 @@ impl SharedFrameState {
 mod acquire;
 mod fences;
 mod flags;
+mod pin;
 mod publish;
 mod release;
 mod state;
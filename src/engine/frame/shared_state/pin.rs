@@ -0,0 +1,67 @@
+//! ### English
+//! Consumer-side pinning of held slots for `SharedFrameState`.
+//!
+//! A pinned slot is a HELD frame the consumer keeps across many frames; the producer leaves it out
+//! of rotation and runs double-buffered on the other two slots until it is unpinned.
+//!
+//! ### 中文
+//! `SharedFrameState` 的消费者侧持有槽位固定逻辑。
+//!
+//! 被固定的槽位是消费者跨多帧保留的 HELD 帧；在取消固定之前，生产者将其排除在轮转之外，并在另外两个槽位上以
+//! 双缓冲方式运行。
+
+use super::super::sync::Ordering;
+use super::super::{SLOT_HELD, SLOT_PINNED, TRIPLE_BUFFER_COUNT};
+use super::SharedFrameState;
+
+impl SharedFrameState {
+    /// ### English
+    /// Pins a slot held by the consumer (HELD -> PINNED); returns whether it was held.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slot index previously acquired by the consumer.
+    ///
+    /// ### 中文
+    /// 固定一个被消费者持有的槽位（HELD -> PINNED）；返回该槽位是否处于持有状态。
+    ///
+    /// #### 参数
+    /// - `slot`：消费者之前 acquire 的槽位索引。
+    pub fn pin_slot(&self, slot: usize) -> bool {
+        slot < TRIPLE_BUFFER_COUNT
+            && self.slots[slot]
+                .state
+                .compare_exchange(SLOT_HELD, SLOT_PINNED, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+    }
+
+    /// ### English
+    /// Unpins a pinned slot back to HELD (PINNED -> HELD); returns whether it was pinned. The
+    /// consumer releases it afterwards as usual.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slot index previously pinned by the consumer.
+    ///
+    /// ### 中文
+    /// 将被固定的槽位恢复为 HELD（PINNED -> HELD）；返回该槽位是否处于固定状态。之后由消费者照常释放。
+    ///
+    /// #### 参数
+    /// - `slot`：消费者之前固定的槽位索引。
+    pub fn unpin_slot(&self, slot: usize) -> bool {
+        slot < TRIPLE_BUFFER_COUNT
+            && self.slots[slot]
+                .state
+                .compare_exchange(SLOT_PINNED, SLOT_HELD, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+    }
+
+    /// ### English
+    /// Returns whether the consumer has pinned any slot (Relaxed loads; producer-side heuristic).
+    ///
+    /// ### 中文
+    /// 返回消费者是否固定了任意槽位（Relaxed 读取；生产者侧启发式用）。
+    pub fn has_pinned_slot(&self) -> bool {
+        self.slots
+            .iter()
+            .any(|slot| slot.state.load(Ordering::Relaxed) == SLOT_PINNED)
+    }
+}
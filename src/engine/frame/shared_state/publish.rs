@@ -8,32 +8,46 @@
 //!
 //! 将槽位标记为 READY，并更新全局 “latest” 指针。
 
-use std::sync::atomic::Ordering;
+use std::ffi::c_void;
 
 use dpi::PhysicalSize;
 
-use super::super::{SLOT_READY, TRIPLE_BUFFER_COUNT};
+use crate::engine::clock;
+
+use super::super::sync::Ordering;
+#[cfg(debug_assertions)]
+use super::super::transition::debug_assert_slot_transition;
+use super::super::{SLOT_READY, TRIPLE_BUFFER_COUNT, XianWebEngineFrameFn};
 use super::SharedFrameState;
 
 impl SharedFrameState {
     /// ### English
-    /// Publishes a rendered slot as READY and updates the global "latest" pointer.
+    /// Publishes a rendered slot as READY stamped with the current engine monotonic time, updates the
+    /// global "latest" pointer and invokes the frame callback (if installed).
     ///
     /// #### Parameters
     /// - `slot`: Slot index that was rendered.
     /// - `producer_fence`: Producer fence handle (`GLsync` cast to `u64`), or 0 if disabled/unavailable.
     /// - `new_frame_seq`: New frame sequence number (must be non-zero).
+    /// - `colorspace`: Effective output colorspace of the frame (`XIAN_WEB_ENGINE_COLORSPACE_*`).
     ///
     /// ### 中文
-    /// 将渲染完成的槽位发布为 READY，并更新全局 “latest” 指针。
+    /// 将渲染完成的槽位以当前引擎单调时间为时间戳发布为 READY，更新全局 “latest” 指针，并调用帧回调（若已安装）。
     ///
     /// #### 参数
     /// - `slot`：已渲染完成的槽位索引。
     /// - `producer_fence`：生产者 fence 句柄（`GLsync` 转 `u64`），不可用/禁用则为 0。
     /// - `new_frame_seq`：新的帧序号（必须非 0）。
-    pub fn publish(&self, slot: usize, producer_fence: u64, new_frame_seq: u64) {
+    /// - `colorspace`：该帧实际的输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_*`）。
+    pub fn publish(&self, slot: usize, producer_fence: u64, new_frame_seq: u64, colorspace: u32) {
         let slot_state = &self.slots[slot];
+        #[cfg(debug_assertions)]
+        debug_assert_slot_transition(slot, slot_state.state.load(Ordering::Relaxed), SLOT_READY);
         slot_state.frame_seq.store(new_frame_seq, Ordering::Relaxed);
+        slot_state.colorspace.store(colorspace, Ordering::Relaxed);
+        slot_state
+            .present_time_ns
+            .store(clock::monotonic_nanos(), Ordering::Relaxed);
         slot_state
             .producer_fence
             .store(producer_fence, Ordering::Relaxed);
@@ -41,6 +55,28 @@ impl SharedFrameState {
         self.frame_meta
             .latest_packed
             .store(super::pack_latest(new_frame_seq, slot), Ordering::Release);
+        self.notifier.notify(new_frame_seq);
+    }
+
+    /// ### English
+    /// Installs (or removes, with `None`) the callback invoked after each publish.
+    ///
+    /// #### Parameters
+    /// - `callback`: Frame callback, or `None` to remove it.
+    /// - `user_data`: Opaque pointer passed back to `callback`.
+    ///
+    /// ### 中文
+    /// 安装（或以 `None` 移除）每次 publish 之后调用的回调。
+    ///
+    /// #### 参数
+    /// - `callback`：帧回调；`None` 表示移除。
+    /// - `user_data`：回传给 `callback` 的不透明指针。
+    pub fn set_frame_callback(
+        &self,
+        callback: Option<XianWebEngineFrameFn>,
+        user_data: *mut c_void,
+    ) {
+        self.notifier.set(callback, user_data);
     }
 
     /// ### English
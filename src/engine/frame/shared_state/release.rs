@@ -1,22 +1,25 @@
 //! ### English
 //! Consumer-side release helpers for `SharedFrameState`.
 //!
-//! Releases a HELD slot back to FREE, optionally recording a consumer fence.
+//! Releases a HELD slot back to FREE, optionally recording a consumer fence. The pinned snapshot
+//! frame goes back to READY instead, so it stays acquirable.
 //!
 //! ### 中文
 //! `SharedFrameState` 的消费者侧 release 辅助方法。
 //!
-//! 将 HELD 槽位释放回 FREE，并可选记录 consumer fence。
+//! 将 HELD 槽位释放回 FREE，并可选记录 consumer fence。被固定的快照帧则回到 READY，从而保持可 acquire。
 
-use std::sync::atomic::Ordering;
-
-use super::super::{SLOT_FREE, SLOT_HELD, SLOT_RELEASE_PENDING, TRIPLE_BUFFER_COUNT};
+use super::super::sync::Ordering;
+use super::super::{SLOT_FREE, SLOT_HELD, SLOT_READY, SLOT_RELEASE_PENDING, TRIPLE_BUFFER_COUNT};
 use super::SharedFrameState;
 
 impl SharedFrameState {
     /// ### English
     /// Releases a previously acquired slot, optionally recording a consumer fence.
     ///
+    /// Releasing the pinned snapshot frame without a fence makes it READY again right away; with a
+    /// fence it stays RELEASE_PENDING until the producer sees the fence signaled and restores it.
+    ///
     /// #### Parameters
     /// - `slot`: Slot index previously acquired by the consumer.
     /// - `consumer_fence`: Consumer fence handle (`GLsync` cast to `u64`), or 0 to release immediately.
@@ -24,6 +27,9 @@ impl SharedFrameState {
     /// ### 中文
     /// 释放之前 acquire 的槽位，并可选记录 consumer fence。
     ///
+    /// 不带 fence 释放被固定的快照帧时，它会立即重新变为 READY；带 fence 时它保持 RELEASE_PENDING，直到生产者
+    /// 确认 fence 已 signal 后将其恢复。
+    ///
     /// #### 参数
     /// - `slot`：消费者之前 acquire 的槽位索引。
     /// - `consumer_fence`：consumer fence 句柄（`GLsync` 转 `u64`），为 0 则立即释放。
@@ -33,9 +39,14 @@ impl SharedFrameState {
         }
 
         if consumer_fence == 0 {
+            let released = if self.snapshot_slot() == Some(slot) {
+                SLOT_READY
+            } else {
+                SLOT_FREE
+            };
             if self.slots[slot]
                 .state
-                .compare_exchange(SLOT_HELD, SLOT_FREE, Ordering::Relaxed, Ordering::Relaxed)
+                .compare_exchange(SLOT_HELD, released, Ordering::Relaxed, Ordering::Relaxed)
                 .is_ok()
             {
                 self.clear_consumer_fence(slot);
@@ -8,8 +8,9 @@
 //!
 //! 提供热路径读取与 CAS 辅助方法，并使用精心选择的内存序。
 
-use std::sync::atomic::Ordering;
-
+use super::super::sync::Ordering;
+#[cfg(debug_assertions)]
+use super::super::transition::debug_assert_slot_transition;
 use super::SharedFrameState;
 
 impl SharedFrameState {
@@ -74,6 +75,8 @@ impl SharedFrameState {
     /// - `current`：期望的当前状态。
     /// - `new`：CAS 成功时写入的新状态。
     pub fn compare_exchange_state(&self, slot: usize, current: u8, new: u8) -> Result<u8, u8> {
+        #[cfg(debug_assertions)]
+        debug_assert_slot_transition(slot, current, new);
         self.slots[slot]
             .state
             .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
@@ -95,6 +98,8 @@ impl SharedFrameState {
     /// - `current`：期望的当前状态。
     /// - `new`：CAS 成功时写入的新状态。
     pub fn compare_exchange_state_relaxed(&self, slot: usize, current: u8, new: u8) -> bool {
+        #[cfg(debug_assertions)]
+        debug_assert_slot_transition(slot, current, new);
         self.slots[slot]
             .state
             .compare_exchange(current, new, Ordering::Relaxed, Ordering::Relaxed)
@@ -104,6 +109,9 @@ impl SharedFrameState {
     /// ### English
     /// Stores a slot state with Release ordering.
     ///
+    /// Only valid for slots currently owned by the producer; debug builds verify the transition
+    /// against the previous state.
+    ///
     /// #### Parameters
     /// - `slot`: Slot index to update.
     /// - `state`: State value to store.
@@ -111,10 +119,14 @@ impl SharedFrameState {
     /// ### 中文
     /// 以 Release 顺序写入槽位状态。
     ///
+    /// 仅适用于当前由生产者持有的槽位；debug 构建会基于旧状态校验该转换。
+    ///
     /// #### 参数
     /// - `slot`：要更新的槽位索引。
     /// - `state`：要写入的状态值。
     pub fn store_state(&self, slot: usize, state: u8) {
+        #[cfg(debug_assertions)]
+        debug_assert_slot_transition(slot, self.slot_state_relaxed(slot), state);
         self.slots[slot].state.store(state, Ordering::Release);
     }
 }
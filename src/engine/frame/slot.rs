@@ -4,11 +4,10 @@
 //! ### 中文
 //! 三缓冲帧状态中“每槽位”的原子存储结构。
 
-use std::sync::atomic::{AtomicU8, AtomicU32, AtomicU64};
-
 use dpi::PhysicalSize;
 
 use super::SLOT_FREE;
+use super::sync::{AtomicU8, AtomicU32, AtomicU64};
 
 #[repr(C, align(64))]
 /// ### English
@@ -59,6 +58,18 @@ pub(super) struct SlotAtomics {
     /// ### 中文
     /// 该槽位缓存的帧高度（像素）。
     pub(super) height: AtomicU32,
+    /// ### English
+    /// Effective output colorspace of the published frame (`XIAN_WEB_ENGINE_COLORSPACE_*`).
+    ///
+    /// ### 中文
+    /// 已发布帧的实际输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_*`）。
+    pub(super) colorspace: AtomicU32,
+    /// ### English
+    /// Engine monotonic time the frame was published at, in nanoseconds (`0` = never published).
+    ///
+    /// ### 中文
+    /// 帧发布时的引擎单调时间（纳秒；`0` 表示从未发布）。
+    pub(super) present_time_ns: AtomicU64,
 }
 
 impl SlotAtomics {
@@ -82,6 +93,8 @@ impl SlotAtomics {
             frame_seq: AtomicU64::new(0),
             width: AtomicU32::new(initial_size.width),
             height: AtomicU32::new(initial_size.height),
+            colorspace: AtomicU32::new(0),
+            present_time_ns: AtomicU64::new(0),
         }
     }
 }
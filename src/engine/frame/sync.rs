@@ -0,0 +1,17 @@
+//! ### English
+//! Atomic primitives used by the triple-buffer frame state.
+//!
+//! Resolves to `std::sync::atomic` in normal builds and to `loom::sync::atomic` when compiled with
+//! `--cfg loom`, so the slot-state machine can be explored under a model checker without touching
+//! the hot-path code.
+//!
+//! ### 中文
+//! 三缓冲帧状态使用的原子原语。
+//!
+//! 常规构建下解析为 `std::sync::atomic`；使用 `--cfg loom` 编译时解析为 `loom::sync::atomic`，
+//! 从而无需改动热路径代码即可在模型检查器下遍历槽位状态机。
+
+#[cfg(loom)]
+pub(super) use loom::sync::atomic::{AtomicU8, AtomicU32, AtomicU64, Ordering};
+#[cfg(not(loom))]
+pub(super) use std::sync::atomic::{AtomicU8, AtomicU32, AtomicU64, Ordering};
@@ -0,0 +1,449 @@
+//! ### English
+//! Property tests for the slot-state transition table and `SharedFrameState`.
+//!
+//! The table is checked exhaustively; the shared state is driven through long pseudo-random
+//! producer/consumer operation sequences (single-threaded, mirroring the producer's reserve
+//! strategy) and checked against the ownership invariants after every step.
+//!
+//! ### 中文
+//! 槽位状态转换表与 `SharedFrameState` 的性质测试。
+//!
+//! 转换表被穷举检查；共享状态则由长的伪随机生产者/消费者操作序列驱动（单线程，复刻生产者的预留策略），并在
+//! 每一步之后校验所有权不变量。
+
+use std::collections::VecDeque;
+
+use dpi::PhysicalSize;
+
+use super::transition::is_valid_slot_transition;
+use super::{
+    SLOT_FREE, SLOT_HELD, SLOT_PINNED, SLOT_READY, SLOT_RELEASE_PENDING, SLOT_RENDERING,
+    SharedFrameState, TRIPLE_BUFFER_COUNT,
+};
+
+const STATES: [u8; 6] = [
+    SLOT_FREE,
+    SLOT_READY,
+    SLOT_HELD,
+    SLOT_RELEASE_PENDING,
+    SLOT_RENDERING,
+    SLOT_PINNED,
+];
+
+const CONSUMER_STATES: [u8; 3] = [SLOT_HELD, SLOT_RELEASE_PENDING, SLOT_PINNED];
+
+const SEEDS: [u64; 8] = [
+    1,
+    0x9E37_79B9_7F4A_7C15,
+    0xDEAD_BEEF,
+    0x0123_4567_89AB_CDEF,
+    42,
+    7_777_777,
+    0xC0FF_EE00,
+    u64::MAX,
+];
+
+const STEPS: usize = 20_000;
+
+/// ### English
+/// Minimal xorshift64* generator so the sequences are reproducible without extra dependencies.
+///
+/// ### 中文
+/// 最小的 xorshift64* 生成器，使序列可复现且无需额外依赖。
+struct Rng(u64);
+
+impl Rng {
+    /// ### English
+    /// Returns the next pseudo-random value below `bound`.
+    ///
+    /// #### Parameters
+    /// - `bound`: Exclusive upper bound (non-zero).
+    ///
+    /// ### 中文
+    /// 返回下一个小于 `bound` 的伪随机值。
+    ///
+    /// #### 参数
+    /// - `bound`：不包含的上界（非 0）。
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) % bound
+    }
+}
+
+/// ### English
+/// Returns every state reachable from `start` through legal transitions.
+///
+/// #### Parameters
+/// - `start`: State to explore from.
+///
+/// ### 中文
+/// 返回从 `start` 出发经合法转换可达的所有状态。
+///
+/// #### 参数
+/// - `start`：起始状态。
+fn reachable_from(start: u8) -> Vec<u8> {
+    let mut seen = vec![start];
+    let mut queue = VecDeque::from([start]);
+    while let Some(from) = queue.pop_front() {
+        for to in STATES {
+            if is_valid_slot_transition(from, to) && !seen.contains(&to) {
+                seen.push(to);
+                queue.push_back(to);
+            }
+        }
+    }
+    seen
+}
+
+#[test]
+fn consumer_owned_slots_never_enter_rendering() {
+    for from in CONSUMER_STATES {
+        assert!(
+            !is_valid_slot_transition(from, SLOT_RENDERING),
+            "{from} -> RENDERING"
+        );
+    }
+    for from in STATES {
+        if is_valid_slot_transition(from, SLOT_RENDERING) {
+            assert!(
+                matches!(from, SLOT_FREE | SLOT_READY | SLOT_RENDERING),
+                "{from}"
+            );
+        }
+    }
+}
+
+#[test]
+fn rendering_slots_never_enter_consumer_states() {
+    for to in CONSUMER_STATES {
+        assert!(
+            !is_valid_slot_transition(SLOT_RENDERING, to),
+            "RENDERING -> {to}"
+        );
+    }
+}
+
+#[test]
+fn consumer_takes_slots_only_from_ready_or_pinned() {
+    for from in STATES {
+        if from != SLOT_HELD && is_valid_slot_transition(from, SLOT_HELD) {
+            assert!(matches!(from, SLOT_READY | SLOT_PINNED), "{from} -> HELD");
+        }
+        if from != SLOT_PINNED && is_valid_slot_transition(from, SLOT_PINNED) {
+            assert_eq!(from, SLOT_HELD, "{from} -> PINNED");
+        }
+    }
+}
+
+#[test]
+fn every_state_is_reachable_and_can_return_to_free() {
+    let from_free = reachable_from(SLOT_FREE);
+    for state in STATES {
+        assert!(from_free.contains(&state), "{state} unreachable from FREE");
+        assert!(
+            reachable_from(state).contains(&SLOT_FREE),
+            "{state} cannot return to FREE"
+        );
+    }
+}
+
+#[test]
+fn unknown_states_are_rejected() {
+    for unknown in [6u8, 7, 0x7F, u8::MAX] {
+        for state in STATES {
+            assert!(!is_valid_slot_transition(unknown, state));
+            assert!(!is_valid_slot_transition(state, unknown));
+        }
+        assert!(!is_valid_slot_transition(unknown, unknown));
+    }
+}
+
+/// ### English
+/// Test-side model of one producer and one consumer driving a `SharedFrameState`.
+///
+/// ### 中文
+/// 测试侧驱动 `SharedFrameState` 的单生产者、单消费者模型。
+struct Harness {
+    /// ### English
+    /// State under test.
+    ///
+    /// ### 中文
+    /// 被测状态。
+    shared: SharedFrameState,
+    /// ### English
+    /// Slot the producer is currently rendering into.
+    ///
+    /// ### 中文
+    /// 生产者当前正在渲染的槽位。
+    rendering: Option<usize>,
+    /// ### English
+    /// Slot the producer published last (its `current_back`).
+    ///
+    /// ### 中文
+    /// 生产者最近发布的槽位（即其 `current_back`）。
+    back: usize,
+    /// ### English
+    /// Last published frame sequence.
+    ///
+    /// ### 中文
+    /// 最近发布的帧序号。
+    seq: u64,
+    /// ### English
+    /// Slot the consumer holds, with whether it is pinned.
+    ///
+    /// ### 中文
+    /// 消费者持有的槽位，以及其是否被固定。
+    held: Option<(usize, bool)>,
+    /// ### English
+    /// Last observed state of every slot.
+    ///
+    /// ### 中文
+    /// 每个槽位最近一次观察到的状态。
+    observed: [u8; TRIPLE_BUFFER_COUNT],
+}
+
+impl Harness {
+    /// ### English
+    /// Creates a harness around a fresh shared state.
+    ///
+    /// ### 中文
+    /// 基于全新的共享状态创建测试模型。
+    fn new() -> Self {
+        Self {
+            shared: SharedFrameState::new(PhysicalSize::new(4, 4)),
+            rendering: None,
+            back: 0,
+            seq: 0,
+            held: None,
+            observed: [SLOT_FREE; TRIPLE_BUFFER_COUNT],
+        }
+    }
+
+    /// ### English
+    /// Reserves a back slot with the same FREE / older-READY / reclaim order the producer uses.
+    ///
+    /// ### 中文
+    /// 以与生产者相同的 FREE / 较旧 READY / 回收 顺序预留 back 槽位。
+    fn reserve(&mut self) {
+        if self.rendering.is_some() {
+            return;
+        }
+        let candidates = [
+            (self.back + 1) % TRIPLE_BUFFER_COUNT,
+            (self.back + 2) % TRIPLE_BUFFER_COUNT,
+        ];
+        let shared = &self.shared;
+        let take_free =
+            |slot: usize| shared.compare_exchange_state_relaxed(slot, SLOT_FREE, SLOT_RENDERING);
+        let mut reserved = candidates.into_iter().find(|&slot| take_free(slot));
+        if reserved.is_none() {
+            let snapshot = shared.snapshot_slot();
+            let mut ready: Vec<usize> = candidates
+                .into_iter()
+                .filter(|&slot| shared.slot_state_relaxed(slot) == SLOT_READY)
+                .collect();
+            ready.sort_by_key(|&slot| shared.slot_seq_relaxed(slot));
+            reserved = ready.into_iter().find(|&slot| {
+                Some(slot) != snapshot
+                    && shared.compare_exchange_state_relaxed(slot, SLOT_READY, SLOT_RENDERING)
+            });
+        }
+        if reserved.is_none() {
+            self.reclaim();
+            let shared = &self.shared;
+            reserved = candidates.into_iter().find(|&slot| {
+                shared.compare_exchange_state_relaxed(slot, SLOT_FREE, SLOT_RENDERING)
+            });
+        }
+        self.rendering = reserved;
+    }
+
+    /// ### English
+    /// Returns RELEASE_PENDING slots whose consumer fence is treated as signaled.
+    ///
+    /// ### 中文
+    /// 回收 RELEASE_PENDING 槽位（其 consumer fence 视为已 signal）。
+    fn reclaim(&self) {
+        let snapshot = self.shared.snapshot_slot();
+        for slot in 0..TRIPLE_BUFFER_COUNT {
+            if self.shared.slot_state(slot) != SLOT_RELEASE_PENDING {
+                continue;
+            }
+            let released = if snapshot == Some(slot) {
+                SLOT_READY
+            } else {
+                SLOT_FREE
+            };
+            if self
+                .shared
+                .compare_exchange_state(slot, SLOT_RELEASE_PENDING, released)
+                .is_ok()
+            {
+                self.shared.clear_consumer_fence(slot);
+            }
+        }
+    }
+
+    /// ### English
+    /// Publishes the slot being rendered, tagging its texture with the frame sequence.
+    ///
+    /// ### 中文
+    /// 发布正在渲染的槽位，并用帧序号标记其纹理。
+    fn publish(&mut self) {
+        let Some(slot) = self.rendering.take() else {
+            return;
+        };
+        self.seq += 1;
+        self.shared.set_texture_id(slot, self.seq as u32);
+        self.shared.publish(slot, 0, self.seq, 0);
+        self.back = slot;
+    }
+
+    /// ### English
+    /// Abandons the slot being rendered (RENDERING -> FREE).
+    ///
+    /// ### 中文
+    /// 放弃正在渲染的槽位（RENDERING -> FREE）。
+    fn abandon(&mut self) {
+        if let Some(slot) = self.rendering.take() {
+            self.shared.store_state(slot, SLOT_FREE);
+        }
+    }
+
+    /// ### English
+    /// Acquires the front frame if the consumer holds nothing.
+    ///
+    /// ### 中文
+    /// 消费者未持有任何帧时 acquire 前台帧。
+    fn acquire(&mut self) {
+        if self.held.is_some() {
+            return;
+        }
+        if let Some(frame) = self.shared.try_acquire_front() {
+            assert_eq!(
+                u64::from(frame.texture_id),
+                self.shared.slot_seq_relaxed(frame.slot)
+            );
+            assert_ne!(
+                Some(frame.slot),
+                self.rendering,
+                "acquired the slot being rendered"
+            );
+            self.held = Some((frame.slot, false));
+        }
+    }
+
+    /// ### English
+    /// Releases the held slot, with or without a consumer fence.
+    ///
+    /// #### Parameters
+    /// - `fenced`: Whether to release with a (non-zero) consumer fence.
+    ///
+    /// ### 中文
+    /// 释放持有的槽位，可带或不带 consumer fence。
+    ///
+    /// #### 参数
+    /// - `fenced`：是否携带（非 0 的）consumer fence 释放。
+    fn release(&mut self, fenced: bool) {
+        if let Some((slot, false)) = self.held {
+            self.shared.release_slot(slot, u64::from(fenced));
+            self.held = None;
+        }
+    }
+
+    /// ### English
+    /// Toggles the pin on the held slot.
+    ///
+    /// ### 中文
+    /// 切换持有槽位的固定状态。
+    fn toggle_pin(&mut self) {
+        match self.held {
+            Some((slot, false)) => {
+                assert!(self.shared.pin_slot(slot));
+                self.held = Some((slot, true));
+            }
+            Some((slot, true)) => {
+                assert!(self.shared.unpin_slot(slot));
+                self.held = Some((slot, false));
+            }
+            None => {}
+        }
+    }
+
+    /// ### English
+    /// Checks the ownership invariants and that every observed change follows legal transitions
+    /// (one operation may chain several edges, e.g. reclaim then reserve).
+    ///
+    /// ### 中文
+    /// 校验所有权不变量，并确认每次观察到的变化都沿合法转换发生（一次操作可能串联多条边，例如先回收再预留）。
+    fn check(&mut self) {
+        let mut rendering = 0;
+        for slot in 0..TRIPLE_BUFFER_COUNT {
+            let state = self.shared.slot_state(slot);
+            let previous = self.observed[slot];
+            assert!(
+                reachable_from(previous).contains(&state),
+                "slot {slot}: {previous} -> {state}"
+            );
+            self.observed[slot] = state;
+
+            if state == SLOT_RENDERING {
+                rendering += 1;
+                assert_eq!(
+                    self.rendering,
+                    Some(slot),
+                    "slot {slot} rendering unexpectedly"
+                );
+            }
+            match self.held {
+                Some((held, pinned)) if held == slot => {
+                    let expected = if pinned { SLOT_PINNED } else { SLOT_HELD };
+                    assert_eq!(
+                        state, expected,
+                        "held slot {slot} changed under the consumer"
+                    );
+                }
+                _ => assert!(
+                    !matches!(state, SLOT_HELD | SLOT_PINNED),
+                    "slot {slot} held without an owner"
+                ),
+            }
+        }
+        assert!(rendering <= 1, "{rendering} slots rendering at once");
+    }
+}
+
+#[test]
+fn random_producer_consumer_sequences_keep_ownership() {
+    for seed in SEEDS {
+        let mut rng = Rng(seed);
+        let mut harness = Harness::new();
+        for _ in 0..STEPS {
+            match rng.below(10) {
+                0 | 1 => harness.reserve(),
+                2 | 3 => harness.publish(),
+                4 => harness.abandon(),
+                5 | 6 => harness.acquire(),
+                7 => harness.release(rng.below(2) == 1),
+                8 => harness.toggle_pin(),
+                _ => harness.shared.set_snapshot_pin(rng.below(4) == 0),
+            }
+            harness.check();
+        }
+    }
+}
+
+#[test]
+fn consumer_sees_the_newest_frame_when_idle() {
+    let mut harness = Harness::new();
+    for _ in 0..64 {
+        harness.reserve();
+        harness.publish();
+        harness.check();
+    }
+    harness.acquire();
+    let (slot, _) = harness.held.expect("a published frame must be acquirable");
+    assert_eq!(harness.shared.slot_seq_relaxed(slot), harness.seq);
+}
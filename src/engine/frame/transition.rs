@@ -0,0 +1,82 @@
+//! ### English
+//! Slot-state transition table for the triple buffer.
+//!
+//! The producer owns `SLOT_FREE -> SLOT_RENDERING -> SLOT_READY` and may reclaim a stale READY slot;
+//! the consumer owns `SLOT_READY -> SLOT_HELD -> (SLOT_RELEASE_PENDING ->) SLOT_FREE`. Any edge from
+//! HELD/RELEASE_PENDING into RENDERING would let the producer overwrite a texture the consumer is
+//! still sampling, so it is rejected here. A pinned snapshot frame returns from HELD (or
+//! RELEASE_PENDING, once its consumer fence signals) to READY so it stays acquirable. The consumer
+//! may also pin a HELD slot (`SLOT_HELD <-> SLOT_PINNED`) to keep it across many frames; the
+//! producer never takes a PINNED slot.
+//!
+//! ### 中文
+//! 三缓冲槽位状态转换表。
+//!
+//! 生产者负责 `SLOT_FREE -> SLOT_RENDERING -> SLOT_READY`，并可回收过期的 READY 槽位；
+//! 消费者负责 `SLOT_READY -> SLOT_HELD -> (SLOT_RELEASE_PENDING ->) SLOT_FREE`。
+//! 任何从 HELD/RELEASE_PENDING 进入 RENDERING 的边都会让生产者覆盖消费者仍在采样的纹理，因此在此被拒绝。
+//! 被固定的快照帧会从 HELD（或在其 consumer fence signal 后从 RELEASE_PENDING）回到 READY，从而保持可
+//! acquire。消费者也可以固定 HELD 槽位（`SLOT_HELD <-> SLOT_PINNED`）以跨多帧保留它；生产者永不取得 PINNED
+//! 槽位。
+
+use super::{SLOT_FREE, SLOT_HELD, SLOT_PINNED, SLOT_READY, SLOT_RELEASE_PENDING, SLOT_RENDERING};
+
+#[inline]
+/// ### English
+/// Returns whether `from -> to` is a legal slot-state transition (self-transitions are legal).
+///
+/// #### Parameters
+/// - `from`: Current slot state (`SLOT_*`).
+/// - `to`: Target slot state (`SLOT_*`).
+///
+/// ### 中文
+/// 返回 `from -> to` 是否为合法的槽位状态转换（自转换视为合法）。
+///
+/// #### 参数
+/// - `from`：当前槽位状态（`SLOT_*`）。
+/// - `to`：目标槽位状态（`SLOT_*`）。
+pub(crate) const fn is_valid_slot_transition(from: u8, to: u8) -> bool {
+    if from == to {
+        return from <= SLOT_RENDERING;
+    }
+    matches!(
+        (from, to),
+        (SLOT_FREE, SLOT_RENDERING)
+            | (SLOT_RENDERING, SLOT_READY)
+            | (SLOT_RENDERING, SLOT_FREE)
+            | (SLOT_READY, SLOT_HELD)
+            | (SLOT_READY, SLOT_RENDERING)
+            | (SLOT_HELD, SLOT_FREE)
+            | (SLOT_HELD, SLOT_READY)
+            | (SLOT_HELD, SLOT_RELEASE_PENDING)
+            | (SLOT_HELD, SLOT_PINNED)
+            | (SLOT_PINNED, SLOT_HELD)
+            | (SLOT_RELEASE_PENDING, SLOT_FREE)
+            | (SLOT_RELEASE_PENDING, SLOT_READY)
+    )
+}
+
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+/// ### English
+/// Debug-only assertion that `from -> to` is a legal slot-state transition.
+///
+/// #### Parameters
+/// - `slot`: Slot index (reported on failure).
+/// - `from`: Current slot state.
+/// - `to`: Target slot state.
+///
+/// ### 中文
+/// 仅在 debug 构建下断言 `from -> to` 为合法的槽位状态转换。
+///
+/// #### 参数
+/// - `slot`：槽位索引（失败时输出）。
+/// - `from`：当前槽位状态。
+/// - `to`：目标槽位状态。
+pub(crate) fn debug_assert_slot_transition(slot: usize, from: u8, to: u8) {
+    debug_assert!(
+        is_valid_slot_transition(from, to),
+        "illegal triple-buffer transition on slot {slot}: {from} -> {to}"
+    );
+}
@@ -1,16 +1,22 @@
 //! ### English
-//! Windows implementation of the minimal GLFW symbol loader.
+//! Minimal GLFW symbol loader for Windows (WGL), Linux (GLX/EGL on X11 or Wayland) and macOS
+//! (CGL/NSGL).
 //!
-//! Uses an embedder-provided function table (`EmbedderGlfwApi`) instead of dynamic library lookup.
+//! Uses an embedder-provided function table (`EmbedderGlfwApi`) instead of dynamic library lookup,
+//! so the same code works with whatever GLFW build LWJGL loaded on each platform.
 //!
 //! ### 中文
-//! 最小 GLFW 符号 loader 的 Windows 实现。
+//! 适用于 Windows（WGL）、Linux（X11 或 Wayland 上的 GLX/EGL）与 macOS（CGL/NSGL）的最小 GLFW
+//! 符号 loader。
 //!
-//! 使用宿主提供的函数表（`EmbedderGlfwApi`），不做动态库按名查找。
+//! 使用宿主提供的函数表（`EmbedderGlfwApi`），不做动态库按名查找，因此各平台上都能配合 LWJGL
+//! 加载的任意 GLFW 构建工作。
 
 use std::ffi::{CStr, c_char, c_int, c_void};
 use std::sync::OnceLock;
 
+use super::main_thread::run_on_main_thread;
+
 #[repr(C)]
 /// ### English
 /// Opaque GLFW window type (`GLFWwindow`).
@@ -265,17 +271,24 @@ impl GlfwApi {
     /// ### English
     /// Destroys a GLFW window created by this loader.
     ///
+    /// Runs on the main thread through the embedder trampoline when one is installed; if the main
+    /// thread does not respond in time the window is leaked rather than destroyed off-thread.
+    ///
     /// #### Parameters
     /// - `window`: Window pointer to destroy.
     ///
     /// ### 中文
     /// 销毁由本 loader 创建的 GLFW window。
     ///
+    /// 若已安装宿主 trampoline，则通过它在主线程上执行；主线程未及时响应时宁可泄漏该 window，
+    /// 也不会在其他线程上销毁它。
+    ///
     /// #### 参数
     /// - `window`：需要销毁的 window 指针。
-    #[inline]
     pub unsafe fn destroy_window(&self, window: *mut GLFWwindow) {
-        unsafe { (self.glfw_destroy_window)(window) };
+        let destroy = self.glfw_destroy_window;
+        let window = window as usize;
+        let _ = run_on_main_thread(move || unsafe { destroy(window as *mut GLFWwindow) });
     }
 
     /// ### English
@@ -284,14 +297,92 @@ impl GlfwApi {
     /// This is used so the Servo thread can render into textures that the Java context can
     /// sample (shared objects within the same share group).
     ///
+    /// The new context mirrors the attributes of `share`, including the creation API (native
+    /// WGL/GLX or EGL), robustness, release behavior and no-error mode: GLX and EGL refuse to
+    /// share objects between contexts whose reset-notification strategy or no-error mode differ.
+    ///
+    /// The hints and the window creation run on the main thread through the embedder trampoline
+    /// when one is installed (required on macOS). On macOS a CGL share group only spans pixel
+    /// formats on the same renderer, so creation is retried with automatic graphics switching
+    /// enabled when the first attempt is rejected.
+    ///
+    /// #### Parameters
+    /// - `share`: Window whose context is shared.
+    /// - `debug`: Request a debug context even if `share` is not one.
+    ///
     /// ### 中文
     /// 创建一个不可见的 1x1 离屏 window，使其 GL 上下文与 `share` 共享对象。
     ///
     /// 用于让 Servo 线程渲染到纹理，并由 Java 上下文采样（同一 share group 共享对象）。
+    ///
+    /// 新上下文会沿用 `share` 的属性，包括创建 API（原生 WGL/GLX 或 EGL）、robustness、release
+    /// behavior 与 no-error 模式：GLX 与 EGL 不允许在 reset 通知策略或 no-error 模式不同的上下文之间
+    /// 共享对象。
+    ///
+    /// 若已安装宿主 trampoline，hint 设置与 window 创建会通过它在主线程上执行（macOS 上必需）。
+    /// macOS 上 CGL share group 只能覆盖同一 renderer 上的像素格式，因此首次创建被拒绝时会启用自动显卡
+    /// 切换后重试。
+    ///
+    /// #### 参数
+    /// - `share`：被共享上下文的 window。
+    /// - `debug`：即使 `share` 不是 debug 上下文也请求 debug 上下文。
     pub unsafe fn create_shared_offscreen_window(
         &self,
         share: *mut GLFWwindow,
+        debug: bool,
     ) -> Result<*mut GLFWwindow, String> {
+        let api = *self;
+        let share = share as usize;
+        let window = run_on_main_thread(move || {
+            let share = share as *mut GLFWwindow;
+            let mut window = unsafe { api.create_offscreen_window_now(share, debug, false) };
+            if window.is_null() && cfg!(target_os = "macos") {
+                window = unsafe { api.create_offscreen_window_now(share, debug, true) };
+            }
+            window as usize
+        })
+        .ok_or_else(|| {
+            "Timed out waiting for the main-thread trampoline to create the offscreen window"
+                .to_string()
+        })?;
+
+        if window == 0 {
+            return Err(
+                "glfwCreateWindow failed; ensure the shared window's context is valid".to_string(),
+            );
+        }
+        Ok(window as *mut GLFWwindow)
+    }
+
+    /// ### English
+    /// Sets the window hints and creates the offscreen window on the calling thread.
+    ///
+    /// On macOS, a legacy (< 3.2) host context is upgraded to a 3.2 forward-compatible core
+    /// profile: Servo needs core GL there, and legacy and core contexts on the same renderer can
+    /// still share objects.
+    ///
+    /// #### Parameters
+    /// - `share`: Window whose context is shared.
+    /// - `debug`: Request a debug context even if `share` is not one.
+    /// - `graphics_switching`: macOS only: allow offline renderers (automatic graphics switching).
+    ///
+    /// ### 中文
+    /// 在调用线程上设置 window hint 并创建离屏 window。
+    ///
+    /// 在 macOS 上，旧版（< 3.2）宿主上下文会被提升为 3.2 forward-compatible core profile：Servo 在
+    /// 该平台上需要 core GL，而同一 renderer 上的旧版与 core 上下文仍可共享对象。
+    ///
+    /// #### 参数
+    /// - `share`：被共享上下文的 window。
+    /// - `debug`：即使 `share` 不是 debug 上下文也请求 debug 上下文。
+    /// - `graphics_switching`：仅 macOS：允许离线 renderer（自动显卡切换）。
+    unsafe fn create_offscreen_window_now(
+        &self,
+        share: *mut GLFWwindow,
+        debug: bool,
+        graphics_switching: bool,
+    ) -> *mut GLFWwindow {
+        const GLFW_TRUE: c_int = 1;
         const GLFW_FALSE: c_int = 0;
 
         const GLFW_VISIBLE: c_int = 0x0002_0004;
@@ -303,7 +394,10 @@ impl GlfwApi {
         const GLFW_CONTEXT_VERSION_MINOR: c_int = 0x0002_2003;
         const GLFW_OPENGL_FORWARD_COMPAT: c_int = 0x0002_2006;
         const GLFW_OPENGL_DEBUG_CONTEXT: c_int = 0x0002_2007;
+        const GLFW_CONTEXT_ROBUSTNESS: c_int = 0x0002_2005;
         const GLFW_OPENGL_PROFILE: c_int = 0x0002_2008;
+        const GLFW_CONTEXT_RELEASE_BEHAVIOR: c_int = 0x0002_2009;
+        const GLFW_CONTEXT_NO_ERROR: c_int = 0x0002_200A;
         const GLFW_CONTEXT_CREATION_API: c_int = 0x0002_200B;
 
         let shared_client_api = unsafe { (self.glfw_get_window_attrib)(share, GLFW_CLIENT_API) };
@@ -318,6 +412,26 @@ impl GlfwApi {
             unsafe { (self.glfw_get_window_attrib)(share, GLFW_OPENGL_DEBUG_CONTEXT) };
         let shared_creation_api =
             unsafe { (self.glfw_get_window_attrib)(share, GLFW_CONTEXT_CREATION_API) };
+        let shared_robustness =
+            unsafe { (self.glfw_get_window_attrib)(share, GLFW_CONTEXT_ROBUSTNESS) };
+        let shared_release =
+            unsafe { (self.glfw_get_window_attrib)(share, GLFW_CONTEXT_RELEASE_BEHAVIOR) };
+        let shared_no_error =
+            unsafe { (self.glfw_get_window_attrib)(share, GLFW_CONTEXT_NO_ERROR) };
+
+        const GLFW_OPENGL_API: c_int = 0x0003_0001;
+        const GLFW_OPENGL_CORE_PROFILE: c_int = 0x0003_2001;
+        const GLFW_COCOA_GRAPHICS_SWITCHING: c_int = 0x0002_3003;
+
+        let (shared_major, shared_minor, shared_profile, shared_forward) =
+            if cfg!(target_os = "macos")
+                && shared_client_api == GLFW_OPENGL_API
+                && (shared_major, shared_minor) < (3, 2)
+            {
+                (3, 2, GLFW_OPENGL_CORE_PROFILE, GLFW_TRUE)
+            } else {
+                (shared_major, shared_minor, shared_profile, shared_forward)
+            };
 
         unsafe { (self.glfw_default_window_hints)() };
         unsafe { (self.glfw_window_hint)(GLFW_VISIBLE, GLFW_FALSE) };
@@ -337,22 +451,32 @@ impl GlfwApi {
             unsafe { (self.glfw_window_hint)(GLFW_OPENGL_PROFILE, shared_profile) };
         }
         unsafe { (self.glfw_window_hint)(GLFW_OPENGL_FORWARD_COMPAT, shared_forward) };
-        unsafe { (self.glfw_window_hint)(GLFW_OPENGL_DEBUG_CONTEXT, shared_debug) };
+        let debug = if debug { GLFW_TRUE } else { shared_debug };
+        unsafe { (self.glfw_window_hint)(GLFW_OPENGL_DEBUG_CONTEXT, debug) };
         if shared_creation_api != 0 {
             unsafe { (self.glfw_window_hint)(GLFW_CONTEXT_CREATION_API, shared_creation_api) };
         }
+        if shared_robustness != 0 {
+            unsafe { (self.glfw_window_hint)(GLFW_CONTEXT_ROBUSTNESS, shared_robustness) };
+        }
+        if shared_release != 0 {
+            unsafe { (self.glfw_window_hint)(GLFW_CONTEXT_RELEASE_BEHAVIOR, shared_release) };
+        }
+        unsafe { (self.glfw_window_hint)(GLFW_CONTEXT_NO_ERROR, shared_no_error) };
+        if cfg!(target_os = "macos") {
+            let value = if graphics_switching {
+                GLFW_TRUE
+            } else {
+                GLFW_FALSE
+            };
+            unsafe { (self.glfw_window_hint)(GLFW_COCOA_GRAPHICS_SWITCHING, value) };
+        }
 
         let title = c"xian_web_engine-offscreen";
         let window =
             unsafe { (self.glfw_create_window)(1, 1, title.as_ptr(), std::ptr::null_mut(), share) };
         unsafe { (self.glfw_default_window_hints)() };
-
-        if window.is_null() {
-            return Err(
-                "glfwCreateWindow failed; ensure the shared window's context is valid".to_string(),
-            );
-        }
-        Ok(window)
+        window
     }
 }
 
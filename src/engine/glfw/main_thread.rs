@@ -0,0 +1,266 @@
+//! ### English
+//! Main-thread trampoline for GLFW calls that must not run on the Servo thread.
+//!
+//! GLFW requires window creation/destruction (and window hints) on the main thread; macOS (Cocoa)
+//! enforces this. When the embedder installs a trampoline, those calls are queued here and the
+//! embedder is asked to run them on its main thread. While the main thread itself is blocked in
+//! engine creation or shutdown, it drains the queue directly so the two threads cannot deadlock.
+//! Without a trampoline the calls run inline, as before.
+//!
+//! ### 中文
+//! 用于不能在 Servo 线程上执行的 GLFW 调用的主线程 trampoline。
+//!
+//! GLFW 要求在主线程上创建/销毁 window（以及设置 window hint）；macOS（Cocoa）会强制执行这一点。
+//! 宿主安装 trampoline 后，这些调用会先进入此处的队列，再请宿主在其主线程上执行。若主线程本身正阻塞
+//! 在引擎创建或关闭中，则由它直接 drain 队列，避免两个线程互相死锁。未安装 trampoline 时这些调用
+//! 仍在当前线程直接执行。
+
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::engine::lockfree::OneShot;
+
+/// ### English
+/// Task entry point handed to the trampoline; the embedder calls it on the main thread with the
+/// `task_data` it received.
+///
+/// ### 中文
+/// 交给 trampoline 的任务入口；宿主需在主线程上以收到的 `task_data` 调用它。
+pub type XianWebEngineMainThreadTaskFn = unsafe extern "C" fn(task_data: *mut c_void);
+
+/// ### English
+/// Embedder trampoline: run `task(task_data)` on the main thread, either immediately or on the
+/// next main-loop iteration. It may be called from any thread and must not block until the task
+/// has run.
+///
+/// ### 中文
+/// 宿主 trampoline：在主线程上执行 `task(task_data)`（立即执行或在下一次主循环迭代中执行）。
+/// 它可能在任意线程上被调用，且不得阻塞等待任务执行完毕。
+pub type XianWebEngineMainThreadFn = unsafe extern "C" fn(
+    task: XianWebEngineMainThreadTaskFn,
+    task_data: *mut c_void,
+    user_data: *mut c_void,
+);
+
+/// ### English
+/// How long a queued call waits for the main thread before giving up.
+///
+/// ### 中文
+/// 排队的调用在放弃前等待主线程的时长。
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// ### English
+/// Installed embedder trampoline.
+///
+/// ### 中文
+/// 已安装的宿主 trampoline。
+struct Trampoline {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    callback: XianWebEngineMainThreadFn,
+    /// ### English
+    /// Opaque embedder pointer passed back to `callback` (stored as an address).
+    ///
+    /// ### 中文
+    /// 回传给 `callback` 的宿主不透明指针（以地址形式保存）。
+    user_data: usize,
+}
+
+static TRAMPOLINE: OnceLock<Trampoline> = OnceLock::new();
+
+/// ### English
+/// Calls waiting for the main thread.
+///
+/// ### 中文
+/// 等待主线程执行的调用。
+static PENDING: Mutex<VecDeque<Box<dyn FnOnce() + Send>>> = Mutex::new(VecDeque::new());
+
+/// ### English
+/// Thread currently blocked in `pump_until` (woken when a call is queued).
+///
+/// ### 中文
+/// 当前阻塞在 `pump_until` 中的线程（有调用入队时被唤醒）。
+static PUMP_THREAD: Mutex<Option<thread::Thread>> = Mutex::new(None);
+
+/// ### English
+/// Installs the embedder trampoline for this process.
+///
+/// This is a one-time installation backed by `OnceLock`; repeated calls return an error.
+///
+/// #### Parameters
+/// - `callback`: Embedder trampoline.
+/// - `user_data`: Opaque embedder pointer passed back to `callback`.
+///
+/// ### 中文
+/// 为当前进程安装宿主 trampoline。
+///
+/// 该安装由 `OnceLock` 保证只执行一次；重复调用会返回错误。
+///
+/// #### 参数
+/// - `callback`：宿主 trampoline。
+/// - `user_data`：回传给 `callback` 的宿主不透明指针。
+pub(crate) fn install_main_thread_trampoline(
+    callback: XianWebEngineMainThreadFn,
+    user_data: *mut c_void,
+) -> Result<(), String> {
+    TRAMPOLINE
+        .set(Trampoline {
+            callback,
+            user_data: user_data as usize,
+        })
+        .map_err(|_| "Main-thread trampoline is already installed".to_string())
+}
+
+/// ### English
+/// Runs `f` on the main thread and returns its result.
+///
+/// Without a trampoline `f` runs inline. Returns `None` if the main thread did not run it within
+/// 30 seconds (the call still runs later, its result is dropped).
+///
+/// #### Parameters
+/// - `f`: Call to run.
+///
+/// ### 中文
+/// 在主线程上执行 `f` 并返回其结果。
+///
+/// 未安装 trampoline 时直接在当前线程执行 `f`。若主线程在 30 秒内未执行，则返回 `None`（该调用
+/// 之后仍会执行，其结果被丢弃）。
+///
+/// #### 参数
+/// - `f`：要执行的调用。
+pub(crate) fn run_on_main_thread<R: Send + 'static>(
+    f: impl FnOnce() -> R + Send + 'static,
+) -> Option<R> {
+    let Some(trampoline) = TRAMPOLINE.get() else {
+        return Some(f());
+    };
+
+    let result = Arc::new(OneShot::new(thread::current()));
+    let sender = result.clone();
+    lock(&PENDING).push_back(Box::new(move || {
+        sender.send(f());
+    }));
+    if let Some(pump) = lock(&PUMP_THREAD).as_ref() {
+        pump.unpark();
+    }
+    unsafe {
+        (trampoline.callback)(
+            drain_task,
+            std::ptr::null_mut(),
+            trampoline.user_data as *mut c_void,
+        )
+    };
+
+    result.recv_timeout(MAIN_THREAD_TIMEOUT)
+}
+
+/// ### English
+/// Blocks the calling (main) thread until `poll` yields a value or `deadline` passes, running
+/// queued main-thread calls meanwhile.
+///
+/// Without a trampoline nothing is ever queued and this only polls.
+///
+/// #### Parameters
+/// - `deadline`: Give-up time (`None` waits indefinitely).
+/// - `poll`: Returns `Some` once the wait is over.
+///
+/// ### 中文
+/// 阻塞调用方（主）线程，直到 `poll` 返回值或超过 `deadline`，期间执行排队的主线程调用。
+///
+/// 未安装 trampoline 时不会有调用入队，此时仅做轮询。
+///
+/// #### 参数
+/// - `deadline`：放弃等待的时间点（`None` 表示无限等待）。
+/// - `poll`：等待结束时返回 `Some`。
+pub(crate) fn pump_until<T>(
+    deadline: Option<Instant>,
+    mut poll: impl FnMut() -> Option<T>,
+) -> Option<T> {
+    /// ### English
+    /// Poll interval bounding how long a finished wait can go unnoticed.
+    ///
+    /// ### 中文
+    /// 轮询间隔，限定等待结束后最多多久会被察觉。
+    const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+    let previous = lock(&PUMP_THREAD).replace(thread::current());
+    let value = loop {
+        drain_main_thread_calls();
+        if let Some(value) = poll() {
+            break Some(value);
+        }
+        let mut wait = POLL_INTERVAL;
+        if let Some(deadline) = deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                break None;
+            }
+            wait = wait.min(deadline - now);
+        }
+        thread::park_timeout(wait);
+    };
+    *lock(&PUMP_THREAD) = previous;
+    value
+}
+
+/// ### English
+/// Returns whether a trampoline is installed (queued calls need a pumping main thread).
+///
+/// ### 中文
+/// 返回是否已安装 trampoline（排队的调用需要主线程 pump）。
+#[inline]
+pub(crate) fn trampoline_installed() -> bool {
+    TRAMPOLINE.get().is_some()
+}
+
+/// ### English
+/// Runs every queued main-thread call.
+///
+/// ### 中文
+/// 执行所有排队的主线程调用。
+fn drain_main_thread_calls() {
+    loop {
+        let Some(call) = lock(&PENDING).pop_front() else {
+            return;
+        };
+        call();
+    }
+}
+
+/// ### English
+/// Task entry point handed to the embedder trampoline.
+///
+/// #### Parameters
+/// - `_task_data`: Unused (the queue holds the calls).
+///
+/// ### 中文
+/// 交给宿主 trampoline 的任务入口。
+///
+/// #### 参数
+/// - `_task_data`：未使用（调用保存在队列中）。
+unsafe extern "C" fn drain_task(_task_data: *mut c_void) {
+    drain_main_thread_calls();
+}
+
+/// ### English
+/// Locks `mutex`, ignoring poisoning (queued calls never leave the data inconsistent).
+///
+/// #### Parameters
+/// - `mutex`: Mutex to lock.
+///
+/// ### 中文
+/// 锁定 `mutex` 并忽略 poison（排队的调用不会使数据处于不一致状态）。
+///
+/// #### 参数
+/// - `mutex`：要锁定的互斥锁。
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
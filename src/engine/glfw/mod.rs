@@ -1,24 +1,31 @@
 //! ### English
-//! Minimal GLFW wrapper (Windows-only in this crate).
+//! Minimal GLFW wrapper (Windows, Linux and macOS in this crate).
 //!
-//! Used to create a shared offscreen OpenGL context on the Servo thread.
+//! Used to create a shared offscreen OpenGL context on the Servo thread. Window creation and
+//! destruction go through the main-thread trampoline when the embedder installs one.
 //!
 //! ### 中文
-//! 最小化的 GLFW 封装（本 crate 目前仅 Windows）。
+//! 最小化的 GLFW 封装（本 crate 目前支持 Windows、Linux 与 macOS）。
 //!
-//! 用于在 Servo 线程创建共享的离屏 OpenGL 上下文。
-#[cfg(windows)]
-mod windows;
+//! 用于在 Servo 线程创建共享的离屏 OpenGL 上下文。宿主安装主线程 trampoline 后，window 的创建与销毁
+//! 会经由它执行。
+#[cfg(any(windows, target_os = "linux", target_os = "macos"))]
+mod embedder;
 
-#[cfg(not(windows))]
+mod main_thread;
+
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 mod stub;
 
-#[cfg(windows)]
-pub use windows::{GlfwWindowPtr, LoadedGlfwApi};
+#[cfg(any(windows, target_os = "linux", target_os = "macos"))]
+pub use embedder::{GlfwWindowPtr, LoadedGlfwApi};
 
-#[cfg(not(windows))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub use stub::{GlfwWindowPtr, LoadedGlfwApi};
 
+pub use main_thread::XianWebEngineMainThreadFn;
+pub(crate) use main_thread::{install_main_thread_trampoline, pump_until, trampoline_installed};
+
 #[repr(C)]
 #[derive(Clone, Copy, Default)]
 /// ### English
@@ -89,14 +96,17 @@ pub struct EmbedderGlfwApi {
 /// #### 参数
 /// - `api`：宿主提供的 GLFW 必需符号函数指针表。
 pub(crate) fn install_embedder_glfw_api(api: EmbedderGlfwApi) -> Result<(), String> {
-    #[cfg(windows)]
+    #[cfg(any(windows, target_os = "linux", target_os = "macos"))]
     {
-        windows::install_embedder_glfw_api(api)
+        embedder::install_embedder_glfw_api(api)
     }
 
-    #[cfg(not(windows))]
+    #[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
     {
         let _ = api;
-        Err("Embedder-provided GLFW API is only supported on Windows in this crate".to_string())
+        Err(
+            "Embedder-provided GLFW API is only supported on Windows, Linux and macOS in this crate"
+                .to_string(),
+        )
     }
 }
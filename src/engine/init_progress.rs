@@ -0,0 +1,126 @@
+//! ### English
+//! Engine initialization progress sink.
+//!
+//! Engine creation blocks for as long as the Servo threads take to start, so the embedder installs
+//! one process-wide callback beforehand and drives its loading screen from the reported phases.
+//!
+//! ### 中文
+//! 引擎初始化进度 sink。
+//!
+//! 引擎创建会一直阻塞到 Servo 线程启动完成，因此宿主需预先安装一个进程级回调，并根据上报的阶段驱动加载界面。
+
+use std::ffi::c_void;
+use std::sync::RwLock;
+
+/// ### English
+/// A Servo thread finished building its Servo instance.
+///
+/// ### 中文
+/// 某个 Servo 线程已完成 Servo 实例的构建。
+pub const XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT: u32 = 1;
+
+/// ### English
+/// A Servo thread created its shared GL (or EGL) context.
+///
+/// ### 中文
+/// 某个 Servo 线程已创建其共享 GL（或 EGL）上下文。
+pub const XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT: u32 = 2;
+
+/// ### English
+/// Every Servo thread is running; engine creation is about to return.
+///
+/// ### 中文
+/// 所有 Servo 线程均已运行；引擎创建即将返回。
+pub const XIAN_WEB_ENGINE_INIT_PHASE_READY: u32 = 3;
+
+/// ### English
+/// A Servo thread precompiled its WebRender shaders. Servo does this when the first view of the
+/// thread is created, so this phase arrives after `READY` (during `xian_web_engine_preload`, a
+/// prewarm or the first view creation).
+///
+/// ### 中文
+/// 某个 Servo 线程已预编译其 WebRender shader。Servo 会在该线程创建第一个 view 时进行预编译，因此该阶段在
+/// `READY` 之后到达（在 `xian_web_engine_preload`、预热或第一次创建 view 时）。
+pub const XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED: u32 = 4;
+
+/// ### English
+/// Init progress callback: receives a `XIAN_WEB_ENGINE_INIT_PHASE_*` phase, in increasing order per
+/// Servo thread. Per-thread phases are reported once per Servo thread. May be invoked from any
+/// thread.
+///
+/// ### 中文
+/// 初始化进度回调：接收 `XIAN_WEB_ENGINE_INIT_PHASE_*` 阶段，同一 Servo 线程的阶段按递增顺序到达。按线程的阶段会为
+/// 每个 Servo 线程各上报一次。可能在任意线程上被调用。
+pub type XianWebEngineInitProgressFn = unsafe extern "C" fn(user_data: *mut c_void, phase: u32);
+
+/// ### English
+/// Installed callback and its user data.
+///
+/// ### 中文
+/// 已安装的回调及其 user data。
+#[derive(Clone, Copy)]
+struct InitProgressSink {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    callback: XianWebEngineInitProgressFn,
+    /// ### English
+    /// Opaque user data (stored as an address so the sink is `Send + Sync`).
+    ///
+    /// ### 中文
+    /// 不透明 user data（以地址形式保存，使 sink 满足 `Send + Sync`）。
+    user_data: usize,
+}
+
+/// ### English
+/// Currently installed sink.
+///
+/// ### 中文
+/// 当前安装的 sink。
+static SINK: RwLock<Option<InitProgressSink>> = RwLock::new(None);
+
+/// ### English
+/// Installs (or removes, with `None`) the init progress sink.
+///
+/// #### Parameters
+/// - `callback`: Progress callback, or `None` to stop reporting.
+/// - `user_data`: Opaque pointer passed back to `callback`.
+///
+/// ### 中文
+/// 安装（或以 `None` 移除）初始化进度 sink。
+///
+/// #### 参数
+/// - `callback`：进度回调；`None` 表示停止上报。
+/// - `user_data`：回传给 `callback` 的不透明指针。
+pub(crate) fn set_init_progress_sink(
+    callback: Option<XianWebEngineInitProgressFn>,
+    user_data: *mut c_void,
+) {
+    let Ok(mut sink) = SINK.write() else {
+        return;
+    };
+    *sink = callback.map(|callback| InitProgressSink {
+        callback,
+        user_data: user_data as usize,
+    });
+}
+
+/// ### English
+/// Reports one phase to the sink (the lock is released before the callback runs).
+///
+/// #### Parameters
+/// - `phase`: `XIAN_WEB_ENGINE_INIT_PHASE_*`.
+///
+/// ### 中文
+/// 向 sink 上报一个阶段（回调执行前会先释放锁）。
+///
+/// #### 参数
+/// - `phase`：`XIAN_WEB_ENGINE_INIT_PHASE_*`。
+pub(crate) fn report(phase: u32) {
+    let Some(sink) = SINK.read().ok().and_then(|sink| *sink) else {
+        return;
+    };
+    unsafe { (sink.callback)(sink.user_data as *mut c_void, phase) };
+}
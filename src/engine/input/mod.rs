@@ -9,6 +9,8 @@
 //! 旨在让 Java 线程与 Servo 线程之间的开销最小化。
 mod coalesced;
 mod queue;
+mod transform;
 
 pub use coalesced::{CoalescedMouseMove, CoalescedResize};
 pub use queue::InputEventQueue;
+pub use transform::{Homography, InputTransform};
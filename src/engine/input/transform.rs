@@ -0,0 +1,172 @@
+//! ### English
+//! Per-view input transform for views rendered onto in-world 3D surfaces.
+//!
+//! The embedder hands over a homography from its own coordinates (screen pixels, ray hit UVs, ...)
+//! to view pixels; pointer positions submitted through the input path are mapped through it before
+//! they are queued, so the host never has to project clicks itself.
+//!
+//! ### 中文
+//! 面向渲染到游戏内 3D 表面的 view 的每 view 输入变换。
+//!
+//! 宿主提供一个从其自身坐标（屏幕像素、射线命中 UV 等）到 view 像素的单应矩阵；经由输入路径提交的指针位置会在入队
+//! 前经过它映射，因此宿主无需自行投影点击。
+
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::engine::input_types::{
+    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE,
+    XIAN_WEB_ENGINE_INPUT_KIND_WHEEL, XianWebEngineInputEvent,
+};
+
+/// ### English
+/// Smallest `|w|` a projected point may have; points closer to the homography's vanishing line
+/// cannot be mapped.
+///
+/// ### 中文
+/// 投影点允许的最小 `|w|`；更接近单应矩阵消失线的点无法映射。
+const MIN_W: f64 = 1e-9;
+
+/// ### English
+/// Position given to pointer events whose point cannot be mapped (outside every view).
+///
+/// ### 中文
+/// 无法映射的指针事件所使用的位置（位于任何 view 之外）。
+const UNMAPPED: (f32, f32) = (-1.0, -1.0);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// ### English
+/// Row-major 3x3 homography mapping `(x, y, 1)` in host coordinates to `(x', y', w)` in view pixels.
+///
+/// ### 中文
+/// 行主序 3x3 单应矩阵：把宿主坐标中的 `(x, y, 1)` 映射为 view 像素中的 `(x', y', w)`。
+pub struct Homography([f64; 9]);
+
+impl Homography {
+    /// ### English
+    /// Builds a homography from nine row-major values; `None` if any value is not finite.
+    ///
+    /// #### Parameters
+    /// - `matrix`: Row-major 3x3 matrix.
+    ///
+    /// ### 中文
+    /// 由 9 个行主序数值构造单应矩阵；若任一数值不是有限值，则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `matrix`：行主序 3x3 矩阵。
+    pub fn new(matrix: &[f32; 9]) -> Option<Self> {
+        if !matrix.iter().all(|value| value.is_finite()) {
+            return None;
+        }
+        Some(Self(matrix.map(f64::from)))
+    }
+
+    /// ### English
+    /// Maps one host point to view pixels (`(-1, -1)`, outside the view, if it cannot be mapped).
+    ///
+    /// #### Parameters
+    /// - `x`: Host X.
+    /// - `y`: Host Y.
+    ///
+    /// ### 中文
+    /// 将一个宿主坐标点映射到 view 像素（无法映射时为位于 view 之外的 `(-1, -1)`）。
+    ///
+    /// #### 参数
+    /// - `x`：宿主 X。
+    /// - `y`：宿主 Y。
+    pub fn map_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let [a, b, c, d, e, f, g, h, i] = self.0;
+        let (x, y) = (f64::from(x), f64::from(y));
+        let w = g * x + h * y + i;
+        if !w.is_finite() || w.abs() < MIN_W {
+            return UNMAPPED;
+        }
+        let mapped_x = ((a * x + b * y + c) / w) as f32;
+        let mapped_y = ((d * x + e * y + f) / w) as f32;
+        if mapped_x.is_finite() && mapped_y.is_finite() {
+            (mapped_x, mapped_y)
+        } else {
+            UNMAPPED
+        }
+    }
+
+    /// ### English
+    /// Returns `event` with its pointer position mapped (mouse move, button and wheel events; other
+    /// kinds, including relative mouse deltas, are returned unchanged).
+    ///
+    /// #### Parameters
+    /// - `event`: Event as submitted by the embedder.
+    ///
+    /// ### 中文
+    /// 返回指针位置已映射的 `event`（鼠标移动、按键与滚轮事件；其他类型，包括相对鼠标增量，原样返回）。
+    ///
+    /// #### 参数
+    /// - `event`：宿主提交的事件。
+    pub fn map_event(&self, event: &XianWebEngineInputEvent) -> XianWebEngineInputEvent {
+        let mut event = *event;
+        if matches!(
+            event.kind,
+            XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE
+                | XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON
+                | XIAN_WEB_ENGINE_INPUT_KIND_WHEEL
+        ) {
+            (event.x, event.y) = self.map_point(event.x, event.y);
+        }
+        event
+    }
+}
+
+/// ### English
+/// Input transform of one view, set from any host thread and read on every input batch.
+///
+/// ### 中文
+/// 单个 view 的输入变换：可在任意宿主线程设置，每批输入都会读取。
+#[derive(Default)]
+pub struct InputTransform {
+    /// ### English
+    /// Whether a homography is set (lets batches skip the lock when none is).
+    ///
+    /// ### 中文
+    /// 是否设置了单应矩阵（未设置时各批次可跳过加锁）。
+    active: AtomicBool,
+    /// ### English
+    /// Current homography.
+    ///
+    /// ### 中文
+    /// 当前的单应矩阵。
+    homography: RwLock<Option<Homography>>,
+}
+
+impl InputTransform {
+    /// ### English
+    /// Sets (or clears, with `None`) the homography applied to subsequent input.
+    ///
+    /// #### Parameters
+    /// - `homography`: Host-to-view homography, or `None` for identity.
+    ///
+    /// ### 中文
+    /// 设置（或以 `None` 清除）应用于后续输入的单应矩阵。
+    ///
+    /// #### 参数
+    /// - `homography`：宿主到 view 的单应矩阵；`None` 表示恒等变换。
+    pub fn set(&self, homography: Option<Homography>) {
+        let Ok(mut current) = self.homography.write() else {
+            return;
+        };
+        *current = homography;
+        self.active.store(homography.is_some(), Ordering::Release);
+    }
+
+    /// ### English
+    /// Returns the current homography (`None` = identity).
+    ///
+    /// ### 中文
+    /// 返回当前的单应矩阵（`None` 表示恒等变换）。
+    #[inline]
+    pub fn get(&self) -> Option<Homography> {
+        if !self.active.load(Ordering::Acquire) {
+            return None;
+        }
+        self.homography.read().ok().and_then(|current| *current)
+    }
+}
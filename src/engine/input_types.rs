@@ -27,16 +27,16 @@ pub struct XianWebEngineInputEvent {
     /// 事件类型（`XIAN_WEB_ENGINE_INPUT_KIND_*` 之一）。
     pub kind: u32,
     /// ### English
-    /// Cursor X in device pixels (for pointer-related events).
+    /// Cursor X in device pixels (for pointer-related events; horizontal delta for relative moves).
     ///
     /// ### 中文
-    /// 光标 X（设备像素；用于指针相关事件）。
+    /// 光标 X（设备像素；用于指针相关事件；相对移动时为水平增量）。
     pub x: f32,
     /// ### English
-    /// Cursor Y in device pixels (for pointer-related events).
+    /// Cursor Y in device pixels (for pointer-related events; vertical delta for relative moves).
     ///
     /// ### 中文
-    /// 光标 Y（设备像素；用于指针相关事件）。
+    /// 光标 Y（设备像素；用于指针相关事件；相对移动时为垂直增量）。
     pub y: f32,
     /// ### English
     /// Modifier bitmask (embedder-defined; mapped to Servo modifiers on the Servo thread).
@@ -148,3 +148,10 @@ pub const XIAN_WEB_ENGINE_INPUT_KIND_WHEEL: u32 = 3;
 /// ### 中文
 /// 输入类型：键盘。
 pub const XIAN_WEB_ENGINE_INPUT_KIND_KEY: u32 = 4;
+
+/// ### English
+/// Input kind: relative mouse motion while the page holds pointer lock (`x`/`y` = delta).
+///
+/// ### 中文
+/// 输入类型：页面持有 pointer lock 时的相对鼠标移动（`x`/`y` = 增量）。
+pub const XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE: u32 = 5;
@@ -130,16 +130,35 @@ impl<T> OneShot<T> {
     /// #### 参数
     /// - `timeout`：最大等待时长。
     pub(crate) fn recv_timeout(&self, timeout: Duration) -> Option<T> {
-        let deadline = Instant::now() + timeout;
+        self.recv_until(Some(Instant::now() + timeout))
+    }
+
+    /// ### English
+    /// Receives the value, giving up at `deadline`.
+    ///
+    /// #### Parameters
+    /// - `deadline`: Give-up time (`None` waits indefinitely).
+    ///
+    /// ### 中文
+    /// 接收值，到达 `deadline` 时放弃。
+    ///
+    /// #### 参数
+    /// - `deadline`：放弃等待的时间点（`None` 表示无限等待）。
+    pub(crate) fn recv_until(&self, deadline: Option<Instant>) -> Option<T> {
         loop {
             if let Some(value) = self.try_recv() {
                 return Some(value);
             }
-            let now = Instant::now();
-            if now >= deadline {
-                return None;
+            match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    thread::park_timeout(deadline - now);
+                }
+                None => thread::park(),
             }
-            thread::park_timeout(deadline - now);
         }
     }
 }
@@ -0,0 +1,175 @@
+//! ### English
+//! Embedder logging sink.
+//!
+//! Diagnostics (invalid FFI arguments, debug-build call tracing) are routed to a single callback
+//! installed by the embedder, so they end up in the host's log instead of stderr.
+//!
+//! ### 中文
+//! 宿主日志 sink。
+//!
+//! 诊断信息（非法 FFI 参数、debug 构建下的调用追踪）会转发到宿主安装的唯一回调，
+//! 从而进入宿主日志而不是 stderr。
+
+use std::ffi::{CString, c_char, c_void};
+use std::fmt;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// ### English
+/// Errors, including rejected FFI arguments.
+///
+/// ### 中文
+/// 错误，包括被拒绝的 FFI 参数。
+pub const XIAN_WEB_ENGINE_LOG_LEVEL_ERROR: u32 = 1;
+
+/// ### English
+/// Recoverable problems.
+///
+/// ### 中文
+/// 可恢复的问题。
+pub const XIAN_WEB_ENGINE_LOG_LEVEL_WARN: u32 = 2;
+
+/// ### English
+/// Informational messages.
+///
+/// ### 中文
+/// 提示信息。
+pub const XIAN_WEB_ENGINE_LOG_LEVEL_INFO: u32 = 3;
+
+/// ### English
+/// Debugging details.
+///
+/// ### 中文
+/// 调试细节。
+pub const XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG: u32 = 4;
+
+/// ### English
+/// Every FFI call with its arguments (debug builds only).
+///
+/// ### 中文
+/// 每次 FFI 调用及其参数（仅 debug 构建）。
+pub const XIAN_WEB_ENGINE_LOG_LEVEL_TRACE: u32 = 5;
+
+/// ### English
+/// Log callback: receives a `XIAN_WEB_ENGINE_LOG_LEVEL_*` level and a NUL-terminated UTF-8 message
+/// that is only valid for the duration of the call. May be invoked from any thread.
+///
+/// ### 中文
+/// 日志回调：接收 `XIAN_WEB_ENGINE_LOG_LEVEL_*` 级别与仅在本次调用期间有效的 NUL 结尾 UTF-8 消息。
+/// 可能在任意线程上被调用。
+pub type XianWebEngineLogFn =
+    unsafe extern "C" fn(user_data: *mut c_void, level: u32, message: *const c_char);
+
+/// ### English
+/// Installed callback and its user data.
+///
+/// ### 中文
+/// 已安装的回调及其 user data。
+#[derive(Clone, Copy)]
+struct LogSink {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    callback: XianWebEngineLogFn,
+    /// ### English
+    /// Opaque user data (stored as an address so the sink is `Send + Sync`).
+    ///
+    /// ### 中文
+    /// 不透明 user data（以地址形式保存，使 sink 满足 `Send + Sync`）。
+    user_data: usize,
+}
+
+/// ### English
+/// Most verbose level forwarded to the sink (`0` = logging disabled).
+///
+/// ### 中文
+/// 转发给 sink 的最详细级别（`0` 表示关闭日志）。
+static MAX_LEVEL: AtomicU32 = AtomicU32::new(0);
+
+/// ### English
+/// Currently installed sink.
+///
+/// ### 中文
+/// 当前安装的 sink。
+static SINK: RwLock<Option<LogSink>> = RwLock::new(None);
+
+/// ### English
+/// Installs (or removes, with `None`) the log sink.
+///
+/// #### Parameters
+/// - `callback`: Log callback, or `None` to disable logging.
+/// - `user_data`: Opaque pointer passed back to `callback`.
+/// - `max_level`: Most verbose `XIAN_WEB_ENGINE_LOG_LEVEL_*` to forward.
+///
+/// ### 中文
+/// 安装（或以 `None` 移除）日志 sink。
+///
+/// #### 参数
+/// - `callback`：日志回调；`None` 表示关闭日志。
+/// - `user_data`：回传给 `callback` 的不透明指针。
+/// - `max_level`：转发的最详细 `XIAN_WEB_ENGINE_LOG_LEVEL_*`。
+pub(crate) fn set_log_sink(
+    callback: Option<XianWebEngineLogFn>,
+    user_data: *mut c_void,
+    max_level: u32,
+) {
+    let Ok(mut sink) = SINK.write() else {
+        return;
+    };
+    *sink = callback.map(|callback| LogSink {
+        callback,
+        user_data: user_data as usize,
+    });
+    let max_level = if sink.is_some() { max_level } else { 0 };
+    MAX_LEVEL.store(max_level, Ordering::Release);
+}
+
+/// ### English
+/// Returns whether messages at `level` are forwarded (cheap; check before formatting).
+///
+/// #### Parameters
+/// - `level`: `XIAN_WEB_ENGINE_LOG_LEVEL_*`.
+///
+/// ### 中文
+/// 返回 `level` 级别的消息是否会被转发（开销很小；可在格式化之前检查）。
+///
+/// #### 参数
+/// - `level`：`XIAN_WEB_ENGINE_LOG_LEVEL_*`。
+pub(crate) fn log_enabled(level: u32) -> bool {
+    level != 0 && level <= MAX_LEVEL.load(Ordering::Acquire)
+}
+
+/// ### English
+/// Forwards one message to the sink.
+///
+/// The lock is released before the callback runs, so the callback may itself replace the sink.
+///
+/// #### Parameters
+/// - `level`: `XIAN_WEB_ENGINE_LOG_LEVEL_*`.
+/// - `message`: Message text (interior NUL bytes are dropped).
+///
+/// ### 中文
+/// 将一条消息转发给 sink。
+///
+/// 回调执行前会先释放锁，因此回调内部也可以替换 sink。
+///
+/// #### 参数
+/// - `level`：`XIAN_WEB_ENGINE_LOG_LEVEL_*`。
+/// - `message`：消息文本（内部的 NUL 字节会被丢弃）。
+pub(crate) fn log(level: u32, message: fmt::Arguments<'_>) {
+    if !log_enabled(level) {
+        return;
+    }
+    let Some(sink) = SINK.read().ok().and_then(|sink| *sink) else {
+        return;
+    };
+
+    let mut bytes = message.to_string().into_bytes();
+    bytes.retain(|&byte| byte != 0);
+    let Ok(message) = CString::new(bytes) else {
+        return;
+    };
+    unsafe { (sink.callback)(sink.user_data as *mut c_void, level, message.as_ptr()) };
+}
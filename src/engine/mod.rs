@@ -3,24 +3,76 @@
 //!
 //! ### 中文
 //! 引擎内部模块（线程、渲染、输入、共享帧状态等）。
+mod base64;
+#[cfg(feature = "bench")]
+pub mod bench;
 pub(crate) mod cache;
+mod capabilities;
+pub(crate) mod clock;
+pub(crate) mod dns;
+mod egl;
+mod event_types;
+pub(crate) mod fence_stats;
 mod flags;
 mod frame;
 mod glfw;
+pub(crate) mod init_progress;
 mod input;
 mod input_types;
 pub(crate) mod lockfree;
+pub(crate) mod log;
+pub(crate) mod profiling;
 mod refresh;
 mod rendering;
 mod resources;
 mod runtime;
+mod thread_config;
+mod trace;
 mod vsync;
+mod watchdog;
 
-pub(crate) use frame::AcquiredFrame;
-pub(crate) use glfw::{EmbedderGlfwApi, install_embedder_glfw_api};
+pub(crate) use capabilities::capabilities;
+pub(crate) use egl::{EmbedderEglApi, install_embedder_egl_api};
+pub(crate) use event_types::{
+    XIAN_WEB_ENGINE_STATUS_FAILED, XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE,
+    XianWebEngineViewEvent, XianWebEngineViewQueueStats,
+};
+#[cfg(feature = "golden-tests")]
+pub(crate) use flags::XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE;
+pub(crate) use flags::{
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL, XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND,
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ, XIAN_WEB_ENGINE_COLOR_SCHEME_DARK,
+    XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT, XIAN_WEB_ENGINE_COLORSPACE_AUTO,
+    XIAN_WEB_ENGINE_COLORSPACE_LINEAR, XIAN_WEB_ENGINE_COLORSPACE_SRGB,
+    XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE, XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF,
+    XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG, XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY,
+    XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE, XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS, XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN, XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE,
+    XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP,
+    XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END, XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START,
+    XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN,
+};
+pub(crate) use frame::{AcquiredFrame, TRIPLE_BUFFER_COUNT, XianWebEngineFrameFn};
+pub(crate) use glfw::{
+    EmbedderGlfwApi, XianWebEngineMainThreadFn, install_embedder_glfw_api,
+    install_main_thread_trampoline,
+};
 pub(crate) use input_types::{
     XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
-    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XIAN_WEB_ENGINE_INPUT_KIND_WHEEL,
-    XianWebEngineInputEvent,
+    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE,
+    XIAN_WEB_ENGINE_INPUT_KIND_WHEEL, XianWebEngineInputEvent,
+};
+pub(crate) use rendering::{
+    EmbedderVulkanDevice, XianWebEngineVulkanSlot, install_embedder_vulkan_device,
+};
+#[cfg(feature = "golden-tests")]
+pub(crate) use runtime::GoldenRun;
+pub(crate) use runtime::{
+    EngineRuntime, WebEngineViewHandle, XianWebEngineCompositeRect, XianWebEngineConfig,
+    XianWebEngineHostFunctionFn, XianWebEngineImageLimits, XianWebEngineImageLoadStats,
+    XianWebEngineNetworkLimits,
 };
-pub(crate) use runtime::{EngineRuntime, WebEngineViewHandle};
+pub(crate) use thread_config::XianWebEngineThreadPoolConfig;
+pub(crate) use watchdog::XianWebEngineHealth;
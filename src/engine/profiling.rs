@@ -0,0 +1,103 @@
+//! ### English
+//! Process-wide Servo profiler settings.
+//!
+//! Servo reads its profiler options once, when it is built, so the settings here apply to every
+//! engine created afterwards; recreating an engine restarts Servo with the current settings.
+//!
+//! ### 中文
+//! 进程级的 Servo 性能分析器设置。
+//!
+//! Servo 仅在构建时读取一次性能分析选项，因此这里的设置作用于之后创建的每个引擎；重新创建引擎即可让 Servo
+//! 以当前设置重启。
+
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use super::flags::{XIAN_WEB_ENGINE_PROFILING_TIME, XIAN_WEB_ENGINE_PROFILING_TRACE};
+
+/// ### English
+/// Every known `XIAN_WEB_ENGINE_PROFILING_*` bit.
+///
+/// ### 中文
+/// 所有已知的 `XIAN_WEB_ENGINE_PROFILING_*` 位。
+const KNOWN_CATEGORIES: u32 = XIAN_WEB_ENGINE_PROFILING_TIME | XIAN_WEB_ENGINE_PROFILING_TRACE;
+
+/// ### English
+/// Requested profiler categories and output path.
+///
+/// ### 中文
+/// 请求的性能分析类别与输出路径。
+struct ProfilingConfig {
+    /// ### English
+    /// `XIAN_WEB_ENGINE_PROFILING_*` bits (non-zero).
+    ///
+    /// ### 中文
+    /// `XIAN_WEB_ENGINE_PROFILING_*` 位（非 0）。
+    categories: u32,
+    /// ### English
+    /// Output file of the timing summary.
+    ///
+    /// ### 中文
+    /// 耗时汇总的输出文件。
+    output_path: PathBuf,
+}
+
+/// ### English
+/// Current settings (`None` = profiling off).
+///
+/// ### 中文
+/// 当前设置（`None` 表示关闭性能分析）。
+static PROFILING: RwLock<Option<ProfilingConfig>> = RwLock::new(None);
+
+/// ### English
+/// Sets the profiler categories for Servo instances built from now on. Returns `false` for unknown
+/// category bits or a missing output path.
+///
+/// #### Parameters
+/// - `categories`: `XIAN_WEB_ENGINE_PROFILING_*` bits (`0` turns profiling off).
+/// - `output_path`: Output file (required when `categories` is non-zero).
+///
+/// ### 中文
+/// 设置此后构建的 Servo 实例的性能分析类别。类别位未知或缺少输出路径时返回 `false`。
+///
+/// #### 参数
+/// - `categories`：`XIAN_WEB_ENGINE_PROFILING_*` 位（`0` 表示关闭性能分析）。
+/// - `output_path`：输出文件（`categories` 非 0 时必填）。
+pub(crate) fn set_profiling(categories: u32, output_path: Option<PathBuf>) -> bool {
+    if categories & !KNOWN_CATEGORIES != 0 {
+        return false;
+    }
+    let config = match (categories, output_path) {
+        (0, _) => None,
+        (categories, Some(output_path)) => Some(ProfilingConfig {
+            categories,
+            output_path,
+        }),
+        (_, None) => return false,
+    };
+    let Ok(mut profiling) = PROFILING.write() else {
+        return false;
+    };
+    *profiling = config;
+    true
+}
+
+/// ### English
+/// Returns the `time_profiling` and `time_profiler_trace_path` Servo options for the current
+/// settings.
+///
+/// ### 中文
+/// 根据当前设置返回 Servo 的 `time_profiling` 与 `time_profiler_trace_path` 选项。
+pub(crate) fn servo_options() -> (Option<servo::OutputOptions>, Option<String>) {
+    let Ok(profiling) = PROFILING.read() else {
+        return (None, None);
+    };
+    let Some(config) = profiling.as_ref() else {
+        return (None, None);
+    };
+
+    let output = config.output_path.to_string_lossy().into_owned();
+    let trace_path = (config.categories & XIAN_WEB_ENGINE_PROFILING_TRACE != 0)
+        .then(|| format!("{output}.html"));
+    (Some(servo::OutputOptions::FileName(output)), trace_path)
+}
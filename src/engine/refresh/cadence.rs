@@ -0,0 +1,129 @@
+//! ### English
+//! Drift-free frame deadlines for fixed-interval refresh.
+//!
+//! Deadlines sit on a grid anchored at the driver's creation: frame `n` is due at
+//! `origin + n * denominator / numerator` seconds, computed exactly in integer nanoseconds. Each
+//! tick is scheduled from the previous deadline instead of `now + interval`, so rounding and
+//! scheduling latency never accumulate, and fractional rates (e.g. `14386 / 100` for 143.86 Hz)
+//! stay in phase with the display over long sessions. Frames that are already late are skipped
+//! rather than run back-to-back.
+//!
+//! ### 中文
+//! 固定间隔 refresh 的无漂移帧截止时间。
+//!
+//! 截止时间位于以 driver 创建时刻为原点的网格上：第 `n` 帧在 `origin + n * denominator / numerator` 秒到期，
+//! 以整数纳秒精确计算。每次 tick 都从上一次截止时间（而不是 `now + interval`）开始调度，因此取整误差与调度延迟
+//! 不会累积，小数帧率（例如 143.86 Hz 对应 `14386 / 100`）在长时间运行后仍与显示器保持同相。已经迟到的帧会被
+//! 跳过，而不是连续补跑。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// ### English
+/// Nanoseconds per second.
+///
+/// ### 中文
+/// 每秒的纳秒数。
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// ### English
+/// Frame grid of one fixed-interval driver.
+///
+/// ### 中文
+/// 单个固定间隔 driver 的帧网格。
+pub struct FrameCadence {
+    /// ### English
+    /// Time of frame `0`.
+    ///
+    /// ### 中文
+    /// 第 `0` 帧的时间。
+    origin: Instant,
+    /// ### English
+    /// Frames per `denominator` seconds.
+    ///
+    /// ### 中文
+    /// 每 `denominator` 秒的帧数。
+    numerator: u64,
+    /// ### English
+    /// Seconds per `numerator` frames.
+    ///
+    /// ### 中文
+    /// 每 `numerator` 帧的秒数。
+    denominator: u64,
+    /// ### English
+    /// Index of the last scheduled frame (`0` = none yet).
+    ///
+    /// ### 中文
+    /// 最近一次调度的帧序号（`0` 表示尚未调度）。
+    last_frame: AtomicU64,
+}
+
+impl FrameCadence {
+    /// ### English
+    /// Creates a cadence of `numerator / denominator` frames per second; `None` if either is `0`.
+    ///
+    /// #### Parameters
+    /// - `numerator`: Frames per `denominator` seconds.
+    /// - `denominator`: Seconds per `numerator` frames.
+    ///
+    /// ### 中文
+    /// 创建每秒 `numerator / denominator` 帧的节奏；任一参数为 `0` 时返回 `None`。
+    ///
+    /// #### 参数
+    /// - `numerator`：每 `denominator` 秒的帧数。
+    /// - `denominator`：每 `numerator` 帧的秒数。
+    pub fn new(numerator: u32, denominator: u32) -> Option<Self> {
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+        Some(Self {
+            origin: Instant::now(),
+            numerator: u64::from(numerator),
+            denominator: u64::from(denominator),
+            last_frame: AtomicU64::new(0),
+        })
+    }
+
+    /// ### English
+    /// Returns the deadline of the next frame: the one after the last scheduled frame, or the first
+    /// one still in the future if the driver fell behind or was idle.
+    ///
+    /// Callers schedule at most one frame at a time, so the frame index needs no stronger ordering.
+    ///
+    /// ### 中文
+    /// 返回下一帧的截止时间：上一次调度帧的下一帧；若 driver 落后或处于空闲，则为第一个仍在未来的帧。
+    ///
+    /// 调用方同一时刻最多只调度一帧，因此帧序号无需更强的内存序。
+    pub fn next_deadline(&self) -> Instant {
+        let elapsed = Instant::now()
+            .saturating_duration_since(self.origin)
+            .as_nanos();
+        let current = elapsed * u128::from(self.numerator)
+            / (NANOS_PER_SECOND * u128::from(self.denominator));
+        let current = u64::try_from(current).unwrap_or(u64::MAX - 1);
+        let frame = self
+            .last_frame
+            .load(Ordering::Relaxed)
+            .saturating_add(1)
+            .max(current + 1);
+        self.last_frame.store(frame, Ordering::Relaxed);
+        self.origin + self.frame_offset(frame)
+    }
+
+    /// ### English
+    /// Returns the time of `frame` relative to `origin`, rounded up to whole nanoseconds.
+    ///
+    /// #### Parameters
+    /// - `frame`: Frame index.
+    ///
+    /// ### 中文
+    /// 返回第 `frame` 帧相对 `origin` 的时间（向上取整到纳秒）。
+    ///
+    /// #### 参数
+    /// - `frame`：帧序号。
+    fn frame_offset(&self, frame: u64) -> Duration {
+        let nanos = (u128::from(frame) * u128::from(self.denominator) * NANOS_PER_SECOND)
+            .div_ceil(u128::from(self.numerator));
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
@@ -1,20 +1,21 @@
 //! ### English
-//! Fixed-interval refresh driver implementation (`target_fps != 0`).
+//! Fixed-interval refresh driver implementation (`target_fps != 0`); ticks follow a drift-free
+//! `FrameCadence`.
 //!
 //! ### 中文
-//! 固定间隔 refresh driver 实现（`target_fps != 0`）。
+//! 固定间隔 refresh driver 实现（`target_fps != 0`）；tick 遵循无漂移的 `FrameCadence`。
 
 use std::rc::Rc;
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering as AtomicOrdering},
 };
-use std::time::Duration;
 
 use servo::RefreshDriver;
 
 use crate::engine::lockfree::CoalescedBox;
 
+use super::cadence::FrameCadence;
 use super::scheduler::RefreshScheduler;
 
 /// ### English
@@ -30,11 +31,11 @@ pub struct FixedIntervalRefreshDriver {
     /// 共享的全局调度器（单个后台线程）。
     scheduler: Arc<RefreshScheduler>,
     /// ### English
-    /// Fixed frame duration used to schedule the next refresh.
+    /// Frame grid used to schedule the next refresh.
     ///
     /// ### 中文
-    /// 用于调度下一次 refresh 的固定帧间隔。
-    frame_duration: Duration,
+    /// 用于调度下一次 refresh 的帧网格。
+    cadence: Arc<FrameCadence>,
     /// ### English
     /// Coalesced state that keeps only the latest callback and limits scheduling to one tick.
     ///
@@ -49,18 +50,18 @@ impl FixedIntervalRefreshDriver {
     ///
     /// #### Parameters
     /// - `scheduler`: Shared refresh scheduler used to run ticks.
-    /// - `frame_duration`: Fixed interval between ticks.
+    /// - `cadence`: Frame grid the ticks follow.
     ///
     /// ### 中文
     /// 创建固定间隔 refresh driver。
     ///
     /// #### 参数
     /// - `scheduler`：用于执行 tick 的共享调度器。
-    /// - `frame_duration`：tick 的固定时间间隔。
-    pub fn new(scheduler: Arc<RefreshScheduler>, frame_duration: Duration) -> Rc<Self> {
+    /// - `cadence`：tick 遵循的帧网格。
+    pub fn new(scheduler: Arc<RefreshScheduler>, cadence: FrameCadence) -> Rc<Self> {
         Rc::new(Self {
             scheduler,
-            frame_duration,
+            cadence: Arc::new(cadence),
             coalesced: Arc::new(FixedIntervalCoalesced::new()),
         })
     }
@@ -82,7 +83,7 @@ impl RefreshDriver for FixedIntervalRefreshDriver {
     fn observe_next_frame(&self, start_frame_callback: Box<dyn Fn() + Send + 'static>) {
         self.coalesced.submit(
             self.scheduler.clone(),
-            self.frame_duration,
+            self.cadence.clone(),
             start_frame_callback,
         );
     }
@@ -207,7 +208,7 @@ impl FixedIntervalCoalesced {
     ///
     /// #### Parameters
     /// - `scheduler`: Scheduler used to run the tick.
-    /// - `delay`: Fixed delay between ticks.
+    /// - `cadence`: Frame grid the ticks follow.
     /// - `callback`: Callback to coalesce (latest wins).
     ///
     /// ### 中文
@@ -215,26 +216,52 @@ impl FixedIntervalCoalesced {
     ///
     /// #### 参数
     /// - `scheduler`：用于执行 tick 的调度器。
-    /// - `delay`：tick 的固定延迟间隔。
+    /// - `cadence`：tick 遵循的帧网格。
     /// - `callback`：要合并的回调（latest-wins）。
     #[inline]
     fn submit(
         self: &Arc<Self>,
         scheduler: Arc<RefreshScheduler>,
-        delay: Duration,
+        cadence: Arc<FrameCadence>,
         callback: Box<dyn Fn() + Send + 'static>,
     ) {
         self.set_callback(callback);
         if !self.scheduled.swap(true, AtomicOrdering::AcqRel) {
-            let state = self.clone();
-            let scheduler_for_tick = scheduler.clone();
-            scheduler.schedule(
-                delay,
-                Box::new(move || state.clone().tick(scheduler_for_tick.clone(), delay)),
-            );
+            self.schedule_tick(scheduler, cadence);
         }
     }
 
+    /// ### English
+    /// Schedules the next tick at the next deadline of `cadence`.
+    ///
+    /// #### Parameters
+    /// - `scheduler`: Scheduler used to run the tick.
+    /// - `cadence`: Frame grid the ticks follow.
+    ///
+    /// ### 中文
+    /// 在 `cadence` 的下一个截止时间安排下一次 tick。
+    ///
+    /// #### 参数
+    /// - `scheduler`：用于执行 tick 的调度器。
+    /// - `cadence`：tick 遵循的帧网格。
+    fn schedule_tick(
+        self: &Arc<Self>,
+        scheduler: Arc<RefreshScheduler>,
+        cadence: Arc<FrameCadence>,
+    ) {
+        let state = self.clone();
+        let scheduler_for_tick = scheduler.clone();
+        let deadline = cadence.next_deadline();
+        scheduler.schedule_at(
+            deadline,
+            Box::new(move || {
+                state
+                    .clone()
+                    .tick(scheduler_for_tick.clone(), cadence.clone())
+            }),
+        );
+    }
+
     /// ### English
     /// Executes the coalesced callback and re-arms scheduling if another callback arrives during
     /// execution.
@@ -244,7 +271,7 @@ impl FixedIntervalCoalesced {
     ///
     /// #### Parameters
     /// - `scheduler`: Scheduler used to run the next tick if re-armed.
-    /// - `delay`: Fixed delay between ticks.
+    /// - `cadence`: Frame grid the ticks follow.
     ///
     /// ### 中文
     /// 执行合并后的回调；若回调执行期间又有新回调提交，则会重新 arm 调度。
@@ -253,8 +280,8 @@ impl FixedIntervalCoalesced {
     ///
     /// #### 参数
     /// - `scheduler`：用于在需要时继续安排 tick 的调度器。
-    /// - `delay`：tick 的固定延迟间隔。
-    fn tick(self: Arc<Self>, scheduler: Arc<RefreshScheduler>, delay: Duration) {
+    /// - `cadence`：tick 遵循的帧网格。
+    fn tick(self: Arc<Self>, scheduler: Arc<RefreshScheduler>, cadence: Arc<FrameCadence>) {
         let callback = self.take_callback();
 
         self.scheduled.store(false, AtomicOrdering::Release);
@@ -264,12 +291,7 @@ impl FixedIntervalCoalesced {
         }
 
         if self.callback.is_pending() && !self.scheduled.swap(true, AtomicOrdering::AcqRel) {
-            let state = self.clone();
-            let scheduler_for_tick = scheduler.clone();
-            scheduler.schedule(
-                delay,
-                Box::new(move || state.clone().tick(scheduler_for_tick.clone(), delay)),
-            );
+            self.schedule_tick(scheduler, cadence);
         }
     }
 }
@@ -0,0 +1,219 @@
+//! ### English
+//! Shared refresh driver for a view group.
+//!
+//! Every view in a group hands its frame callback to the same driver; one tick (vsync or fixed
+//! interval) then runs all of them back-to-back so grouped views publish in the same frame.
+//!
+//! ### 中文
+//! view group 共享的 refresh driver。
+//!
+//! group 内每个 view 都把帧回调交给同一个 driver；一次 tick（vsync 或固定间隔）会连续执行全部回调，
+//! 使同组 view 在同一帧内发布。
+
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use servo::RefreshDriver;
+
+use crate::engine::lockfree::MpscQueue;
+use crate::engine::vsync::VsyncCallbackQueue;
+
+use super::cadence::FrameCadence;
+use super::scheduler::RefreshScheduler;
+use super::vsync_driver::submit_vsync_callback;
+
+/// ### English
+/// Tick source used by a view group.
+///
+/// ### 中文
+/// view group 使用的 tick 来源。
+enum GroupTickSource {
+    /// ### English
+    /// Ticks on the embedder vsync (`xian_web_engine_tick`).
+    ///
+    /// ### 中文
+    /// 由宿主 vsync（`xian_web_engine_tick`）驱动。
+    Vsync {
+        /// ### English
+        /// Vsync callback queue drained by the embedder tick.
+        ///
+        /// ### 中文
+        /// 由宿主 tick drain 的 vsync 回调队列。
+        queue: Arc<VsyncCallbackQueue>,
+        /// ### English
+        /// Scheduler used once the host reports its display timing.
+        ///
+        /// ### 中文
+        /// 宿主上报显示时序后使用的调度器。
+        scheduler: Arc<RefreshScheduler>,
+    },
+    /// ### English
+    /// Ticks on the shared refresh scheduler at a fixed interval.
+    ///
+    /// ### 中文
+    /// 由共享 refresh 调度器按固定间隔驱动。
+    FixedInterval {
+        /// ### English
+        /// Shared refresh scheduler used to run ticks.
+        ///
+        /// ### 中文
+        /// 用于执行 tick 的共享调度器。
+        scheduler: Arc<RefreshScheduler>,
+        /// ### English
+        /// Frame grid the ticks follow.
+        ///
+        /// ### 中文
+        /// tick 遵循的帧网格。
+        cadence: FrameCadence,
+    },
+}
+
+/// ### English
+/// Refresh driver shared by all views of one group (fans callbacks into a single tick).
+///
+/// ### 中文
+/// 同组所有 view 共享的 refresh driver（把多个回调汇聚到一次 tick）。
+pub struct GroupRefreshDriver {
+    /// ### English
+    /// Where group ticks are scheduled.
+    ///
+    /// ### 中文
+    /// group tick 的调度位置。
+    source: GroupTickSource,
+    /// ### English
+    /// Fan-in state shared with the scheduled tick closure.
+    ///
+    /// ### 中文
+    /// 与已调度 tick 闭包共享的汇聚状态。
+    fan_in: Arc<GroupFanIn>,
+}
+
+impl GroupRefreshDriver {
+    /// ### English
+    /// Creates a group driver ticked by the embedder vsync.
+    ///
+    /// #### Parameters
+    /// - `queue`: Vsync callback queue drained by the embedder tick.
+    /// - `scheduler`: Scheduler used once the host reports its display timing.
+    ///
+    /// ### 中文
+    /// 创建由宿主 vsync 驱动的 group driver。
+    ///
+    /// #### 参数
+    /// - `queue`：由宿主 tick drain 的 vsync 回调队列。
+    /// - `scheduler`：宿主上报显示时序后使用的调度器。
+    pub fn new_vsync(queue: Arc<VsyncCallbackQueue>, scheduler: Arc<RefreshScheduler>) -> Rc<Self> {
+        Rc::new(Self {
+            source: GroupTickSource::Vsync { queue, scheduler },
+            fan_in: Arc::new(GroupFanIn::new()),
+        })
+    }
+
+    /// ### English
+    /// Creates a group driver ticked at a fixed interval.
+    ///
+    /// #### Parameters
+    /// - `scheduler`: Shared refresh scheduler used to run ticks.
+    /// - `cadence`: Frame grid the ticks follow.
+    ///
+    /// ### 中文
+    /// 创建按固定间隔驱动的 group driver。
+    ///
+    /// #### 参数
+    /// - `scheduler`：用于执行 tick 的共享调度器。
+    /// - `cadence`：tick 遵循的帧网格。
+    pub fn new_fixed_interval(scheduler: Arc<RefreshScheduler>, cadence: FrameCadence) -> Rc<Self> {
+        Rc::new(Self {
+            source: GroupTickSource::FixedInterval { scheduler, cadence },
+            fan_in: Arc::new(GroupFanIn::new()),
+        })
+    }
+}
+
+impl RefreshDriver for GroupRefreshDriver {
+    /// ### English
+    /// Queues the callback for the next group tick and schedules that tick if needed.
+    ///
+    /// #### Parameters
+    /// - `start_frame_callback`: Callback executed on the next group tick.
+    ///
+    /// ### 中文
+    /// 将回调加入下一次 group tick，并在需要时安排该 tick。
+    ///
+    /// #### 参数
+    /// - `start_frame_callback`：在下一次 group tick 执行的回调。
+    fn observe_next_frame(&self, start_frame_callback: Box<dyn Fn() + Send + 'static>) {
+        self.fan_in.callbacks.push(start_frame_callback);
+        if self.fan_in.scheduled.swap(true, AtomicOrdering::AcqRel) {
+            return;
+        }
+
+        let fan_in = self.fan_in.clone();
+        match &self.source {
+            GroupTickSource::Vsync { queue, scheduler } => {
+                submit_vsync_callback(queue, scheduler, Box::new(move || fan_in.tick()))
+            }
+            GroupTickSource::FixedInterval { scheduler, cadence } => {
+                scheduler.schedule_at(cadence.next_deadline(), Box::new(move || fan_in.tick()))
+            }
+        }
+    }
+}
+
+/// ### English
+/// Callback fan-in: collects callbacks from all group members until the next tick.
+///
+/// ### 中文
+/// 回调汇聚器：收集所有组成员的回调直到下一次 tick。
+struct GroupFanIn {
+    /// ### English
+    /// Callbacks submitted since the last tick.
+    ///
+    /// ### 中文
+    /// 上次 tick 之后提交的回调。
+    callbacks: MpscQueue<Box<dyn Fn() + Send + 'static>>,
+    /// ### English
+    /// Whether a group tick is already scheduled.
+    ///
+    /// ### 中文
+    /// 是否已经安排了一次 group tick。
+    scheduled: AtomicBool,
+}
+
+impl GroupFanIn {
+    /// ### English
+    /// Creates an empty fan-in.
+    ///
+    /// ### 中文
+    /// 创建一个空的汇聚器。
+    #[inline]
+    fn new() -> Self {
+        Self {
+            callbacks: MpscQueue::new(),
+            scheduled: AtomicBool::new(false),
+        }
+    }
+
+    /// ### English
+    /// Runs every queued callback once.
+    ///
+    /// `scheduled` is cleared first so callbacks that observe the next frame re-arm a fresh tick
+    /// instead of being run again within this one.
+    ///
+    /// ### 中文
+    /// 执行所有已排队回调各一次。
+    ///
+    /// 先清除 `scheduled`，使回调中再次 observe 的请求安排新的 tick，而不是在本次 tick 中再次执行。
+    fn tick(&self) {
+        self.scheduled.store(false, AtomicOrdering::Release);
+
+        let mut batch = Vec::new();
+        while let Some(callback) = self.callbacks.pop() {
+            batch.push(callback);
+        }
+        for callback in batch {
+            callback();
+        }
+    }
+}
@@ -1,16 +1,25 @@
 //! ### English
 //! Servo `RefreshDriver` implementations.
 //!
-//! Supports external-vsync driven refresh (fast path for games) and fixed-interval refresh.
+//! Supports external-vsync driven refresh (fast path for games), fixed-interval refresh, and a
+//! group driver shared by several views; each view's driver is wrapped so it can be paused for
+//! debugging.
 //!
 //! ### 中文
 //! Servo `RefreshDriver` 的实现。
 //!
-//! 支持外部 vsync 驱动（游戏场景快路径）与固定间隔刷新。
+//! 支持外部 vsync 驱动（游戏场景快路径）、固定间隔刷新，以及多个 view 共享的 group driver；每个 view 的 driver
+//! 都会被包装，以便为调试而暂停。
+mod cadence;
 mod fixed_interval;
+mod group;
+mod pausable;
 mod scheduler;
 mod vsync_driver;
 
+pub use cadence::FrameCadence;
 pub use fixed_interval::FixedIntervalRefreshDriver;
+pub use group::GroupRefreshDriver;
+pub use pausable::PausableRefreshDriver;
 pub use scheduler::RefreshScheduler;
 pub use vsync_driver::VsyncRefreshDriver;
@@ -0,0 +1,183 @@
+//! ### English
+//! Per-view refresh driver wrapper that can hold frame callbacks, for debugging and to pause page
+//! time.
+//!
+//! While paused, the callback Servo hands over for the next frame is parked instead of forwarded, so
+//! animations and `requestAnimationFrame` stop; each debug step forwards one callback to the real
+//! driver. The debug pause and the page time pause are independent: callbacks flow again once
+//! neither holds them.
+//!
+//! ### 中文
+//! 可扣住帧回调的每 view refresh driver 包装，用于调试与暂停页面时间。
+//!
+//! 暂停期间，Servo 为下一帧交出的回调会被扣住而不转发，因此动画与 `requestAnimationFrame` 会停止；每次调试单步会把
+//! 一个回调转发给真正的 driver。调试暂停与页面时间暂停相互独立：两者都不再扣住时，回调才会恢复流动。
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use servo::RefreshDriver;
+
+/// ### English
+/// Frame callback handed over by Servo.
+///
+/// ### 中文
+/// Servo 交出的帧回调。
+type FrameCallback = Box<dyn Fn() + Send + 'static>;
+
+/// ### English
+/// Refresh driver forwarding to the view's real driver unless paused (Servo thread only).
+///
+/// ### 中文
+/// 除非处于暂停状态，否则转发给 view 真正 driver 的 refresh driver（仅 Servo 线程使用）。
+pub struct PausableRefreshDriver {
+    /// ### English
+    /// Real driver (vsync, fixed interval or group).
+    ///
+    /// ### 中文
+    /// 真正的 driver（vsync、固定间隔或 group）。
+    inner: Rc<dyn RefreshDriver>,
+    /// ### English
+    /// Whether frame callbacks are held for debugging (steps let them through one at a time).
+    ///
+    /// ### 中文
+    /// 是否为调试而扣住帧回调（单步可逐个放行）。
+    paused: Cell<bool>,
+    /// ### English
+    /// Whether frame callbacks are held because page time is paused (steps do not apply).
+    ///
+    /// ### 中文
+    /// 是否因页面时间暂停而扣住帧回调（单步不适用）。
+    time_paused: Cell<bool>,
+    /// ### English
+    /// Frames still allowed through while paused (requested steps with no callback parked yet).
+    ///
+    /// ### 中文
+    /// 暂停期间仍允许通过的帧数（请求单步时尚无被扣住的回调）。
+    steps: Cell<u32>,
+    /// ### English
+    /// Latest callback held while paused.
+    ///
+    /// ### 中文
+    /// 暂停期间扣住的最新回调。
+    parked: RefCell<Option<FrameCallback>>,
+}
+
+impl PausableRefreshDriver {
+    /// ### English
+    /// Wraps a view's refresh driver (not paused).
+    ///
+    /// #### Parameters
+    /// - `inner`: Real driver.
+    ///
+    /// ### 中文
+    /// 包装一个 view 的 refresh driver（初始不暂停）。
+    ///
+    /// #### 参数
+    /// - `inner`：真正的 driver。
+    pub fn new(inner: Rc<dyn RefreshDriver>) -> Rc<Self> {
+        Rc::new(Self {
+            inner,
+            paused: Cell::new(false),
+            time_paused: Cell::new(false),
+            steps: Cell::new(0),
+            parked: RefCell::new(None),
+        })
+    }
+
+    /// ### English
+    /// Debug-pauses or resumes; resuming drops pending steps and forwards the held callback unless
+    /// page time is paused too.
+    ///
+    /// #### Parameters
+    /// - `paused`: Whether to hold frame callbacks.
+    ///
+    /// ### 中文
+    /// 调试暂停或恢复；恢复时丢弃未用完的单步，并在页面时间未暂停时转发被扣住的回调。
+    ///
+    /// #### 参数
+    /// - `paused`：是否扣住帧回调。
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.set(paused);
+        if !paused {
+            self.steps.set(0);
+            if !self.time_paused.get() {
+                self.release();
+            }
+        }
+    }
+
+    /// ### English
+    /// Pauses or resumes page time; resuming forwards the held callback unless debug-paused too.
+    ///
+    /// #### Parameters
+    /// - `paused`: Whether to hold frame callbacks.
+    ///
+    /// ### 中文
+    /// 暂停或恢复页面时间；恢复时在未处于调试暂停时转发被扣住的回调。
+    ///
+    /// #### 参数
+    /// - `paused`：是否扣住帧回调。
+    pub fn set_time_paused(&self, paused: bool) {
+        self.time_paused.set(paused);
+        if !paused && !self.paused.get() {
+            self.release();
+        }
+    }
+
+    /// ### English
+    /// Lets one frame through while debug-paused: forwards the held callback, or the next one if
+    /// none is held yet. Ignored if not debug-paused or while page time is paused.
+    ///
+    /// ### 中文
+    /// 在调试暂停期间放行一帧：转发被扣住的回调；若尚无被扣住的回调，则放行下一个。未处于调试暂停或页面时间
+    /// 暂停时忽略。
+    pub fn step(&self) {
+        if self.paused.get() && !self.time_paused.get() && !self.release() {
+            self.steps.set(self.steps.get().saturating_add(1));
+        }
+    }
+
+    /// ### English
+    /// Forwards the held callback, if any, to the real driver; returns whether one was held.
+    ///
+    /// ### 中文
+    /// 将被扣住的回调（若有）转发给真正的 driver；返回是否存在被扣住的回调。
+    fn release(&self) -> bool {
+        let parked = self.parked.borrow_mut().take();
+        parked.is_some_and(|callback| {
+            self.inner.observe_next_frame(callback);
+            true
+        })
+    }
+}
+
+impl RefreshDriver for PausableRefreshDriver {
+    /// ### English
+    /// Forwards the callback, or holds it (replacing one already held) while page time is paused
+    /// or while debug-paused with no step pending.
+    ///
+    /// #### Parameters
+    /// - `start_frame_callback`: Callback executed on the next frame.
+    ///
+    /// ### 中文
+    /// 转发该回调；若页面时间暂停，或处于调试暂停且没有待用的单步，则扣住它（替换已扣住的回调）。
+    ///
+    /// #### 参数
+    /// - `start_frame_callback`：在下一帧执行的回调。
+    fn observe_next_frame(&self, start_frame_callback: FrameCallback) {
+        if self.time_paused.get() {
+            *self.parked.borrow_mut() = Some(start_frame_callback);
+            return;
+        }
+        if self.paused.get() {
+            let steps = self.steps.get();
+            if steps == 0 {
+                *self.parked.borrow_mut() = Some(start_frame_callback);
+                return;
+            }
+            self.steps.set(steps - 1);
+        }
+        self.inner.observe_next_frame(start_frame_callback);
+    }
+}
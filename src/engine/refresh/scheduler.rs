@@ -12,6 +12,7 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 use crate::engine::lockfree::{BoundedMpscQueue, MpscQueue};
+use crate::engine::thread_config::{self, ThreadConfig};
 
 /// ### English
 /// Hot-path ring capacity for the scheduler queue (power-of-two).
@@ -168,6 +169,12 @@ pub struct RefreshScheduler {
     /// 与调度线程共享的 shutdown 标记。
     shutdown: Arc<AtomicBool>,
     /// ### English
+    /// Suspend flag shared with the scheduler thread (due tasks wait until it is cleared).
+    ///
+    /// ### 中文
+    /// 与调度线程共享的挂起标记（到期任务会等待其被清除）。
+    suspended: Arc<AtomicBool>,
+    /// ### English
     /// Monotonic task sequence generator.
     ///
     /// ### 中文
@@ -197,9 +204,11 @@ impl RefreshScheduler {
         let queue = Arc::new(SchedulerQueue::new());
         let wake_pending = Arc::new(AtomicBool::new(false));
         let shutdown = Arc::new(AtomicBool::new(false));
+        let suspended = Arc::new(AtomicBool::new(false));
         let queue_for_thread = queue.clone();
         let wake_pending_for_thread = wake_pending.clone();
         let shutdown_for_thread = shutdown.clone();
+        let suspended_for_thread = suspended.clone();
         let join = thread::Builder::new()
             .name("XianRefreshDriver".to_string())
             .spawn(move || {
@@ -207,6 +216,7 @@ impl RefreshScheduler {
                     queue_for_thread,
                     wake_pending_for_thread,
                     shutdown_for_thread,
+                    suspended_for_thread,
                 )
             })
             .expect("failed to spawn refresh scheduler thread");
@@ -216,6 +226,7 @@ impl RefreshScheduler {
             queue,
             wake_pending,
             shutdown,
+            suspended,
             next_seq: AtomicU64::new(1),
             thread: thread_handle,
             join: Some(join),
@@ -236,9 +247,26 @@ impl RefreshScheduler {
     /// - `delay`：回调执行前的延迟时间。
     /// - `callback`：在调度线程执行的回调。
     pub fn schedule(&self, delay: Duration, callback: Box<dyn Fn() + Send + 'static>) {
+        self.schedule_at(Instant::now() + delay, callback);
+    }
+
+    /// ### English
+    /// Schedules one callback to run at `deadline` (immediately if it already passed).
+    ///
+    /// #### Parameters
+    /// - `deadline`: Time at which to run the callback.
+    /// - `callback`: Callback executed on the scheduler thread.
+    ///
+    /// ### 中文
+    /// 计划在 `deadline` 执行一个回调（若已过期则立即执行）。
+    ///
+    /// #### 参数
+    /// - `deadline`：回调的执行时间。
+    /// - `callback`：在调度线程执行的回调。
+    pub fn schedule_at(&self, deadline: Instant, callback: Box<dyn Fn() + Send + 'static>) {
         let seq = self.next_seq.fetch_add(1, AtomicOrdering::Relaxed);
         let task = ScheduledTask {
-            deadline: Instant::now() + delay,
+            deadline,
             seq,
             callback,
         };
@@ -247,6 +275,47 @@ impl RefreshScheduler {
             self.thread.unpark();
         }
     }
+
+    /// ### English
+    /// Applies priority/affinity settings to the scheduler thread (runs on that thread).
+    ///
+    /// #### Parameters
+    /// - `config`: Scheduling settings.
+    ///
+    /// ### 中文
+    /// 将优先级/亲和性设置应用到调度线程（在该线程上执行）。
+    ///
+    /// #### 参数
+    /// - `config`：调度设置。
+    pub fn set_thread_config(&self, config: ThreadConfig) {
+        self.schedule(
+            Duration::ZERO,
+            Box::new(move || {
+                thread_config::apply_to_current_thread(config);
+            }),
+        );
+    }
+
+    /// ### English
+    /// Suspends or resumes the scheduler; while suspended no callback runs (engine idle).
+    ///
+    /// Frames that came due while suspended run once on resume.
+    ///
+    /// #### Parameters
+    /// - `suspended`: Whether to suspend (`true`) or resume (`false`).
+    ///
+    /// ### 中文
+    /// 挂起或恢复调度器；挂起期间不执行任何回调（引擎空闲）。
+    ///
+    /// 挂起期间到期的帧会在恢复时执行一次。
+    ///
+    /// #### 参数
+    /// - `suspended`：挂起（`true`）或恢复（`false`）。
+    pub fn set_suspended(&self, suspended: bool) {
+        if self.suspended.swap(suspended, AtomicOrdering::AcqRel) != suspended {
+            self.thread.unpark();
+        }
+    }
 }
 
 impl Drop for RefreshScheduler {
@@ -273,6 +342,7 @@ impl Drop for RefreshScheduler {
 /// - `rx`: Lock-free message queue into the scheduler thread.
 /// - `wake_pending`: Coalesced wake flag shared with the producers.
 /// - `shutdown`: Shutdown flag shared with the producers.
+/// - `suspended`: Suspend flag shared with the owner.
 ///
 /// ### 中文
 /// 调度线程主循环。
@@ -281,10 +351,12 @@ impl Drop for RefreshScheduler {
 /// - `rx`：发送到调度线程的无锁消息队列。
 /// - `wake_pending`：与生产者共享的合并唤醒标记。
 /// - `shutdown`：与生产者共享的 shutdown 标记。
+/// - `suspended`：与所有者共享的挂起标记。
 fn run_scheduler(
     rx: Arc<SchedulerQueue>,
     wake_pending: Arc<AtomicBool>,
     shutdown: Arc<AtomicBool>,
+    suspended: Arc<AtomicBool>,
 ) {
     let mut queue: BinaryHeap<ScheduledTask> = BinaryHeap::new();
 
@@ -295,6 +367,11 @@ fn run_scheduler(
         if shutdown.load(AtomicOrdering::Acquire) {
             return;
         }
+        if suspended.load(AtomicOrdering::Acquire) {
+            wake_pending.store(false, AtomicOrdering::Release);
+            thread::park();
+            continue;
+        }
 
         let now = Instant::now();
         while let Some(next) = queue.peek() {
@@ -1,8 +1,9 @@
 //! ### English
-//! External-vsync driven refresh driver (Java-side tick).
+//! External-vsync driven refresh driver (Java-side tick, or predicted vsync instants once the host
+//! reports its display timing).
 //!
 //! ### 中文
-//! 外部 vsync 驱动的 refresh driver（Java 侧 tick）。
+//! 外部 vsync 驱动的 refresh driver（Java 侧 tick；宿主上报显示时序后改为预测的 vsync 时刻）。
 
 use std::rc::Rc;
 use std::sync::Arc;
@@ -11,6 +12,8 @@ use servo::RefreshDriver;
 
 use crate::engine::vsync::VsyncCallbackQueue;
 
+use super::scheduler::RefreshScheduler;
+
 /// ### English
 /// Refresh driver driven by an external vsync tick (Java side).
 ///
@@ -23,6 +26,12 @@ pub struct VsyncRefreshDriver {
     /// ### 中文
     /// 由 Java 侧 tick drain 的共享 vsync 回调队列。
     queue: Arc<VsyncCallbackQueue>,
+    /// ### English
+    /// Scheduler starting frames at predicted vsync instants once display timing is reported.
+    ///
+    /// ### 中文
+    /// 上报显示时序后，在预测的 vsync 时刻启动帧的调度器。
+    scheduler: Arc<RefreshScheduler>,
 }
 
 impl VsyncRefreshDriver {
@@ -31,30 +40,61 @@ impl VsyncRefreshDriver {
     ///
     /// #### Parameters
     /// - `queue`: Vsync callback queue drained by the embedder tick.
+    /// - `scheduler`: Scheduler used once the host reports its display timing.
     ///
     /// ### 中文
     /// 创建由 vsync 驱动的 refresh driver。
     ///
     /// #### 参数
     /// - `queue`：由宿主 tick drain 的 vsync 回调队列。
-    pub fn new(queue: Arc<VsyncCallbackQueue>) -> Rc<Self> {
-        Rc::new(Self { queue })
+    /// - `scheduler`：宿主上报显示时序后使用的调度器。
+    pub fn new(queue: Arc<VsyncCallbackQueue>, scheduler: Arc<RefreshScheduler>) -> Rc<Self> {
+        Rc::new(Self { queue, scheduler })
     }
 }
 
 impl RefreshDriver for VsyncRefreshDriver {
     /// ### English
-    /// Pushes the callback into the shared vsync callback queue (executed on Java tick).
+    /// Pushes the callback into the shared vsync callback queue (executed on Java tick), or
+    /// schedules it for the next predicted vsync when display timing is known.
     ///
     /// #### Parameters
     /// - `start_frame_callback`: Callback executed on the next embedder tick.
     ///
     /// ### 中文
-    /// 将回调 push 到共享 vsync 回调队列（在 Java tick 中执行）。
+    /// 将回调 push 到共享 vsync 回调队列（在 Java tick 中执行）；已知显示时序时，改为在下一次预测的 vsync 执行。
     ///
     /// #### 参数
     /// - `start_frame_callback`：在下一次宿主 tick 执行的回调。
     fn observe_next_frame(&self, start_frame_callback: Box<dyn Fn() + Send + 'static>) {
-        self.queue.push(start_frame_callback);
+        submit_vsync_callback(&self.queue, &self.scheduler, start_frame_callback);
+    }
+}
+
+/// ### English
+/// Runs `callback` on the next vsync: at the predicted instant on `scheduler` when the host
+/// reported its display timing, otherwise on the next host tick.
+///
+/// #### Parameters
+/// - `queue`: Vsync callback queue (and display timing) of the Servo thread.
+/// - `scheduler`: Refresh scheduler of the Servo thread.
+/// - `callback`: Frame callback.
+///
+/// ### 中文
+/// 在下一次 vsync 执行 `callback`：宿主已上报显示时序时，于预测时刻在 `scheduler` 上执行；否则在宿主下一次 tick
+/// 中执行。
+///
+/// #### 参数
+/// - `queue`：Servo 线程的 vsync 回调队列（及显示时序）。
+/// - `scheduler`：Servo 线程的 refresh 调度器。
+/// - `callback`：帧回调。
+pub(super) fn submit_vsync_callback(
+    queue: &VsyncCallbackQueue,
+    scheduler: &RefreshScheduler,
+    callback: Box<dyn Fn() + Send + 'static>,
+) {
+    match queue.timing().until_next_vsync() {
+        Some(delay) => scheduler.schedule(delay, callback),
+        None => queue.push(callback),
     }
 }
@@ -0,0 +1,294 @@
+//! ### English
+//! OpenGL render backend: plain GL slot textures synchronized with `GLsync` fences.
+//!
+//! Contexts without sync objects (OpenGL ES 2.0) finish the frame with `glFinish` instead.
+//!
+//! ### 中文
+//! OpenGL 渲染后端：普通 GL 槽位纹理，使用 `GLsync` fence 同步。
+//!
+//! 不支持同步对象的上下文（OpenGL ES 2.0）改为以 `glFinish` 完成该帧。
+
+use std::rc::Rc;
+
+use dpi::PhysicalSize;
+use gleam::gl::{self, Gl};
+use glow::HasContext as _;
+
+use crate::engine::fence_stats;
+
+use super::{RenderBackend, SlotTextureFormat};
+
+/// ### English
+/// Backend for hosts that sample the slot textures through a shared GL context.
+///
+/// ### 中文
+/// 供通过共享 GL 上下文采样槽位纹理的宿主使用的后端。
+pub(in crate::engine::rendering) struct GlBackend {
+    /// ### English
+    /// Whether `GLsync` fences are available on the shared context.
+    ///
+    /// ### 中文
+    /// 共享上下文是否可用 `GLsync` fence。
+    sync: bool,
+}
+
+impl GlBackend {
+    /// ### English
+    /// Creates the backend.
+    ///
+    /// #### Parameters
+    /// - `sync`: Whether `GLsync` fences are available (`false` on OpenGL ES 2.0).
+    ///
+    /// ### 中文
+    /// 创建该后端。
+    ///
+    /// #### 参数
+    /// - `sync`：是否可用 `GLsync` fence（OpenGL ES 2.0 上为 `false`）。
+    pub(in crate::engine::rendering) fn new(sync: bool) -> Self {
+        Self { sync }
+    }
+}
+
+impl RenderBackend for GlBackend {
+    /// ### English
+    /// Creates a GL texture with mutable storage for `slot`.
+    ///
+    /// ### 中文
+    /// 为 `slot` 创建一个可变存储的 GL 纹理。
+    fn allocate_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        _slot: usize,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String> {
+        let min_filter = if mipmaps {
+            gl::LINEAR_MIPMAP_LINEAR
+        } else {
+            gl::LINEAR
+        };
+
+        let texture_ids = gl.gen_textures(1);
+        gl.bind_texture(gl::TEXTURE_2D, texture_ids[0]);
+        specify_storage(gl, size, format);
+        gl.tex_parameter_i(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MAG_FILTER,
+            gl::LINEAR as gl::GLint,
+        );
+        gl.tex_parameter_i(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MIN_FILTER,
+            min_filter as gl::GLint,
+        );
+        gl.bind_texture(gl::TEXTURE_2D, 0);
+        Ok(texture_ids[0])
+    }
+
+    /// ### English
+    /// Re-specifies the texture storage and minification filter in place (the GL name is kept).
+    ///
+    /// ### 中文
+    /// 原地重新指定纹理存储与缩小过滤方式（保留 GL 名称）。
+    fn resize_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        _slot: usize,
+        texture_id: gl::GLuint,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String> {
+        let min_filter = if mipmaps {
+            gl::LINEAR_MIPMAP_LINEAR
+        } else {
+            gl::LINEAR
+        };
+
+        gl.bind_texture(gl::TEXTURE_2D, texture_id);
+        specify_storage(gl, size, format);
+        gl.tex_parameter_i(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MIN_FILTER,
+            min_filter as gl::GLint,
+        );
+        gl.bind_texture(gl::TEXTURE_2D, 0);
+        Ok(texture_id)
+    }
+
+    /// ### English
+    /// Deletes the GL texture.
+    ///
+    /// ### 中文
+    /// 删除 GL 纹理。
+    fn delete_texture(&self, gl: &Rc<dyn Gl>, _slot: usize, texture_id: gl::GLuint) {
+        gl.delete_textures(&[texture_id]);
+    }
+
+    /// ### English
+    /// Inserts a producer `GLsync` (if requested) and flushes so it can signal.
+    ///
+    /// Without sync objects the frame is finished with `glFinish` and no fence is returned.
+    ///
+    /// ### 中文
+    /// 插入生产者 `GLsync`（若需要）并 flush，使其能够 signal。
+    ///
+    /// 不支持同步对象时以 `glFinish` 完成该帧，且不返回 fence。
+    fn signal_frame(
+        &self,
+        glow: &glow::Context,
+        _slot: usize,
+        _texture_id: gl::GLuint,
+        producer_fence: bool,
+    ) -> u64 {
+        if !self.sync {
+            unsafe {
+                glow.finish();
+            }
+            return 0;
+        }
+        if producer_fence { fence_sync(glow) } else { 0 }
+    }
+
+    /// ### English
+    /// Deletes the producer `GLsync`.
+    ///
+    /// ### 中文
+    /// 删除生产者 `GLsync`。
+    fn delete_producer_fence(&self, glow: &glow::Context, fence: u64) {
+        if self.sync {
+            delete_sync(glow, fence);
+        }
+    }
+
+    /// ### English
+    /// Polls the consumer `GLsync` (always done without sync objects).
+    ///
+    /// ### 中文
+    /// 轮询 consumer `GLsync`（不支持同步对象时总是视为完成）。
+    fn consumer_done(
+        &self,
+        glow: &glow::Context,
+        _slot: usize,
+        _texture_id: gl::GLuint,
+        fence: u64,
+    ) -> bool {
+        !self.sync || sync_signaled(glow, fence)
+    }
+
+    /// ### English
+    /// Deletes the consumer `GLsync`.
+    ///
+    /// ### 中文
+    /// 删除 consumer `GLsync`。
+    fn delete_consumer_fence(&self, glow: &glow::Context, fence: u64) {
+        if self.sync {
+            delete_sync(glow, fence);
+        }
+    }
+
+    /// ### English
+    /// Plain GL textures can be pooled per slot.
+    ///
+    /// ### 中文
+    /// 普通 GL 纹理可以按槽位进行池化。
+    fn pools_textures(&self) -> bool {
+        true
+    }
+}
+
+/// ### English
+/// Specifies level 0 of the bound `TEXTURE_2D` with `size` and `format`.
+///
+/// #### Parameters
+/// - `gl`: GL API.
+/// - `size`: Texture size in pixels.
+/// - `format`: Color texture formats.
+///
+/// ### 中文
+/// 以 `size` 与 `format` 指定当前绑定 `TEXTURE_2D` 的第 0 级。
+///
+/// #### 参数
+/// - `gl`：GL API。
+/// - `size`：纹理尺寸（像素）。
+/// - `format`：颜色纹理格式。
+fn specify_storage(gl: &Rc<dyn Gl>, size: PhysicalSize<u32>, format: SlotTextureFormat) {
+    gl.tex_image_2d(
+        gl::TEXTURE_2D,
+        0,
+        format.internal_format,
+        size.width as gl::GLsizei,
+        size.height as gl::GLsizei,
+        0,
+        format.format,
+        format.ty,
+        None,
+    );
+}
+
+/// ### English
+/// Inserts a `GLsync` after the submitted commands, flushes, and returns it as `u64` (`0` on failure).
+///
+/// A fresh sync object is created every call: a `GLsync` signals once and cannot be re-armed.
+///
+/// #### Parameters
+/// - `glow`: glow API.
+///
+/// ### 中文
+/// 在已提交命令之后插入 `GLsync` 并 flush，以 `u64` 返回（失败返回 `0`）。
+///
+/// 每次调用都会创建新的同步对象：`GLsync` 只会 signal 一次，无法重新布置。
+///
+/// #### 参数
+/// - `glow`：glow API。
+pub(super) fn fence_sync(glow: &glow::Context) -> u64 {
+    let sync = unsafe { glow.fence_sync(glow::SYNC_GPU_COMMANDS_COMPLETE, 0) }.ok();
+    if sync.is_some() {
+        fence_stats::record_created();
+        unsafe {
+            glow.flush();
+        }
+    }
+    sync.map(|s| s.0 as usize as u64).unwrap_or(0)
+}
+
+/// ### English
+/// Deletes one `GLsync` represented as a `u64` handle.
+///
+/// #### Parameters
+/// - `glow`: glow API.
+/// - `fence`: Fence handle (`GLsync` cast to `u64`).
+///
+/// ### 中文
+/// 删除一个以 `u64` 句柄表示的 `GLsync`。
+///
+/// #### 参数
+/// - `glow`：glow API。
+/// - `fence`：fence 句柄（`GLsync` 转为 `u64`）。
+#[inline]
+pub(super) fn delete_sync(glow: &glow::Context, fence: u64) {
+    let sync = glow::NativeFence(fence as usize as *mut _);
+    unsafe {
+        glow.delete_sync(sync);
+    }
+}
+
+/// ### English
+/// Returns whether a `GLsync` has signaled (non-blocking).
+///
+/// #### Parameters
+/// - `glow`: glow API.
+/// - `fence`: Fence handle (`GLsync` cast to `u64`).
+///
+/// ### 中文
+/// 返回 `GLsync` 是否已 signal（非阻塞）。
+///
+/// #### 参数
+/// - `glow`：glow API。
+/// - `fence`：fence 句柄（`GLsync` 转为 `u64`）。
+pub(super) fn sync_signaled(glow: &glow::Context, fence: u64) -> bool {
+    let sync = glow::NativeFence(fence as usize as *mut _);
+    let status = unsafe { glow.client_wait_sync(sync, 0, 0) };
+    status == glow::ALREADY_SIGNALED || status == glow::CONDITION_SATISFIED
+}
@@ -0,0 +1,240 @@
+//! ### English
+//! Render backends: how slot textures are allocated, fenced and handed to the consumer.
+//!
+//! Servo always renders with OpenGL on the shared context. A backend decides what storage backs the
+//! slot textures and how completion is signaled: `GlBackend` uses plain GL textures and `GLsync`
+//! fences, `VulkanBackend` uses Vulkan images exported through external memory and semaphores so a
+//! Vulkan renderer can consume the frames directly.
+//!
+//! ### 中文
+//! 渲染后端：决定槽位纹理如何分配、如何同步以及如何交给消费者。
+//!
+//! Servo 始终在共享上下文上使用 OpenGL 渲染。后端决定槽位纹理的底层存储与完成信号：`GlBackend`
+//! 使用普通 GL 纹理与 `GLsync` fence，`VulkanBackend` 使用通过外部内存与 semaphore 导出的 Vulkan
+//! image，使 Vulkan 渲染器可以直接消费帧。
+use std::rc::Rc;
+
+use dpi::PhysicalSize;
+use gleam::gl::{self, Gl};
+
+mod gl_backend;
+mod vulkan;
+
+pub(in crate::engine::rendering) use gl_backend::GlBackend;
+pub(in crate::engine::rendering) use vulkan::VulkanBackend;
+pub use vulkan::{
+    EmbedderVulkanDevice, VulkanFrameExports, XianWebEngineVulkanSlot,
+    install_embedder_vulkan_device, vulkan_device_installed,
+};
+
+/// ### English
+/// GL formats used to allocate a slot's color texture.
+///
+/// ### 中文
+/// 分配槽位颜色纹理所用的 GL 格式。
+#[derive(Clone, Copy)]
+pub(in crate::engine::rendering) struct SlotTextureFormat {
+    /// ### English
+    /// Sized internal format (e.g. `GL_SRGB8_ALPHA8`).
+    ///
+    /// ### 中文
+    /// 带尺寸的内部格式（例如 `GL_SRGB8_ALPHA8`）。
+    pub(in crate::engine::rendering) internal_format: gl::GLint,
+    /// ### English
+    /// Pixel data format (e.g. `GL_RGBA` or `GL_BGRA`).
+    ///
+    /// ### 中文
+    /// 像素数据格式（例如 `GL_RGBA` 或 `GL_BGRA`）。
+    pub(in crate::engine::rendering) format: gl::GLenum,
+    /// ### English
+    /// Pixel data type (e.g. `GL_UNSIGNED_BYTE` or `GL_HALF_FLOAT`).
+    ///
+    /// ### 中文
+    /// 像素数据类型（例如 `GL_UNSIGNED_BYTE` 或 `GL_HALF_FLOAT`）。
+    pub(in crate::engine::rendering) ty: gl::GLenum,
+}
+
+/// ### English
+/// Storage and synchronization strategy of a triple-buffered rendering context.
+///
+/// All methods run on the Servo thread with the shared context current. Fence values are the `u64`
+/// handles stored in `SharedFrameState` (`0` = none).
+///
+/// ### 中文
+/// 三缓冲渲染上下文的存储与同步策略。
+///
+/// 所有方法都在 Servo 线程、共享上下文 current 时执行。fence 值即保存在 `SharedFrameState` 中的
+/// `u64` 句柄（`0` 表示无）。
+pub(in crate::engine::rendering) trait RenderBackend {
+    /// ### English
+    /// Allocates the color texture of `slot` and returns its GL name.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `size`: Texture size in pixels.
+    /// - `format`: Color texture formats.
+    /// - `mipmaps`: Whether the texture gets a mip chain.
+    ///
+    /// ### 中文
+    /// 分配 `slot` 的颜色纹理并返回其 GL 名称。
+    ///
+    /// #### 参数
+    /// - `gl`：共享上下文的 GL API。
+    /// - `slot`：槽位索引。
+    /// - `size`：纹理尺寸（像素）。
+    /// - `format`：颜色纹理格式。
+    /// - `mipmaps`：纹理是否带 mip 链。
+    fn allocate_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        slot: usize,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String>;
+
+    /// ### English
+    /// Resizes the color texture of `slot` and returns its (possibly new) GL name.
+    ///
+    /// On error the old texture is left untouched.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `texture_id`: Current texture.
+    /// - `size`: New size in pixels.
+    /// - `format`: Color texture formats.
+    /// - `mipmaps`: Whether the texture gets a mip chain.
+    ///
+    /// ### 中文
+    /// 调整 `slot` 颜色纹理的尺寸，并返回其（可能是新的）GL 名称。
+    ///
+    /// 出错时保持旧纹理不变。
+    ///
+    /// #### 参数
+    /// - `gl`：共享上下文的 GL API。
+    /// - `slot`：槽位索引。
+    /// - `texture_id`：当前纹理。
+    /// - `size`：新尺寸（像素）。
+    /// - `format`：颜色纹理格式。
+    /// - `mipmaps`：纹理是否带 mip 链。
+    fn resize_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        slot: usize,
+        texture_id: gl::GLuint,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String>;
+
+    /// ### English
+    /// Deletes the color texture of `slot` and any storage behind it.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `texture_id`: Texture to delete.
+    ///
+    /// ### 中文
+    /// 删除 `slot` 的颜色纹理及其底层存储。
+    ///
+    /// #### 参数
+    /// - `gl`：共享上下文的 GL API。
+    /// - `slot`：槽位索引。
+    /// - `texture_id`：要删除的纹理。
+    fn delete_texture(&self, gl: &Rc<dyn Gl>, slot: usize, texture_id: gl::GLuint);
+
+    /// ### English
+    /// Signals that rendering into `slot` is complete and returns the producer fence to publish.
+    ///
+    /// #### Parameters
+    /// - `glow`: glow API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `texture_id`: Texture of the slot.
+    /// - `producer_fence`: Whether to create a producer `GLsync`.
+    ///
+    /// ### 中文
+    /// 通知 `slot` 的渲染已完成，并返回需要发布的生产者 fence。
+    ///
+    /// #### 参数
+    /// - `glow`：共享上下文的 glow API。
+    /// - `slot`：槽位索引。
+    /// - `texture_id`：该槽位的纹理。
+    /// - `producer_fence`：是否创建生产者 `GLsync`。
+    fn signal_frame(
+        &self,
+        glow: &glow::Context,
+        slot: usize,
+        texture_id: gl::GLuint,
+        producer_fence: bool,
+    ) -> u64;
+
+    /// ### English
+    /// Deletes a producer fence returned by `signal_frame`.
+    ///
+    /// #### Parameters
+    /// - `glow`: glow API of the shared context.
+    /// - `fence`: Fence value (non-zero).
+    ///
+    /// ### 中文
+    /// 删除 `signal_frame` 返回的生产者 fence。
+    ///
+    /// #### 参数
+    /// - `glow`：共享上下文的 glow API。
+    /// - `fence`：fence 值（非 0）。
+    fn delete_producer_fence(&self, glow: &glow::Context, fence: u64);
+
+    /// ### English
+    /// Returns whether the consumer is done with `slot` according to its release fence (non-blocking).
+    ///
+    /// Once this returns `true` the slot may be rendered into again; the fence is not deleted here.
+    ///
+    /// #### Parameters
+    /// - `glow`: glow API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `texture_id`: Texture of the slot.
+    /// - `fence`: Consumer fence value (non-zero).
+    ///
+    /// ### 中文
+    /// 根据释放 fence 返回消费者是否已用完 `slot`（非阻塞）。
+    ///
+    /// 返回 `true` 后即可再次渲染该槽位；此处不会删除 fence。
+    ///
+    /// #### 参数
+    /// - `glow`：共享上下文的 glow API。
+    /// - `slot`：槽位索引。
+    /// - `texture_id`：该槽位的纹理。
+    /// - `fence`：consumer fence 值（非 0）。
+    fn consumer_done(
+        &self,
+        glow: &glow::Context,
+        slot: usize,
+        texture_id: gl::GLuint,
+        fence: u64,
+    ) -> bool;
+
+    /// ### English
+    /// Deletes a consumer fence handed over by the embedder.
+    ///
+    /// #### Parameters
+    /// - `glow`: glow API of the shared context.
+    /// - `fence`: Fence value (non-zero).
+    ///
+    /// ### 中文
+    /// 删除宿主移交的 consumer fence。
+    ///
+    /// #### 参数
+    /// - `glow`：共享上下文的 glow API。
+    /// - `fence`：fence 值（非 0）。
+    fn delete_consumer_fence(&self, glow: &glow::Context, fence: u64);
+
+    /// ### English
+    /// Returns whether a slot may keep spare color textures of other sizes and swap them in on resize
+    /// (`false` when each slot is tied to a single exported image).
+    ///
+    /// ### 中文
+    /// 返回槽位是否可以保留其他尺寸的备用颜色纹理，并在 resize 时换入（每个槽位只绑定一个导出 image 时为 `false`）。
+    fn pools_textures(&self) -> bool;
+}
@@ -0,0 +1,935 @@
+//! ### English
+//! Minimal Vulkan API used by the Vulkan backend, loaded from the embedder's device.
+//!
+//! The engine does not create its own Vulkan instance: the embedder installs `vkGetInstanceProcAddr`
+//! together with the instance/physical device/device its renderer uses, so exported images live on
+//! the device that consumes them.
+//!
+//! ### 中文
+//! Vulkan 后端使用的最小 Vulkan API，从宿主的 device 加载。
+//!
+//! 引擎不会自行创建 Vulkan instance：宿主安装 `vkGetInstanceProcAddr` 以及其渲染器使用的
+//! instance/physical device/device，使导出的 image 位于消费它们的 device 上。
+
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::OnceLock;
+
+use super::platform::{
+    ExternalHandle, INVALID_HANDLE, VK_GET_MEMORY_HANDLE, VK_GET_SEMAPHORE_HANDLE,
+    VK_STRUCTURE_TYPE_MEMORY_GET_HANDLE_INFO, VK_STRUCTURE_TYPE_SEMAPHORE_GET_HANDLE_INFO,
+};
+
+/// ### English
+/// `VkResult` success code.
+///
+/// ### 中文
+/// `VkResult` 成功码。
+const VK_SUCCESS: i32 = 0;
+/// ### English
+/// `VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO`。
+const VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO: i32 = 5;
+/// ### English
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO`。
+const VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO: i32 = 9;
+/// ### English
+/// `VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO`。
+const VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO: i32 = 14;
+/// ### English
+/// `VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO`。
+const VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO: i32 = 1_000_072_001;
+/// ### English
+/// `VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO`。
+const VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO: i32 = 1_000_072_002;
+/// ### English
+/// `VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO`。
+const VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO: i32 = 1_000_077_000;
+/// ### English
+/// `VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO`。
+const VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO: i32 = 1_000_127_001;
+/// ### English
+/// `VK_IMAGE_TYPE_2D`.
+///
+/// ### 中文
+/// `VK_IMAGE_TYPE_2D`。
+const VK_IMAGE_TYPE_2D: u32 = 1;
+/// ### English
+/// `VK_SAMPLE_COUNT_1_BIT`.
+///
+/// ### 中文
+/// `VK_SAMPLE_COUNT_1_BIT`。
+const VK_SAMPLE_COUNT_1_BIT: u32 = 1;
+/// ### English
+/// `VK_IMAGE_TILING_OPTIMAL`.
+///
+/// ### 中文
+/// `VK_IMAGE_TILING_OPTIMAL`。
+const VK_IMAGE_TILING_OPTIMAL: u32 = 0;
+/// ### English
+/// `VK_SHARING_MODE_EXCLUSIVE`.
+///
+/// ### 中文
+/// `VK_SHARING_MODE_EXCLUSIVE`。
+const VK_SHARING_MODE_EXCLUSIVE: u32 = 0;
+/// ### English
+/// `VK_IMAGE_LAYOUT_UNDEFINED`.
+///
+/// ### 中文
+/// `VK_IMAGE_LAYOUT_UNDEFINED`。
+const VK_IMAGE_LAYOUT_UNDEFINED: u32 = 0;
+/// ### English
+/// `VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT`.
+///
+/// ### 中文
+/// `VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT`。
+const VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT: u32 = 0x1;
+/// ### English
+/// `VK_MAX_MEMORY_TYPES`.
+///
+/// ### 中文
+/// `VK_MAX_MEMORY_TYPES`。
+const VK_MAX_MEMORY_TYPES: usize = 32;
+/// ### English
+/// `VK_MAX_MEMORY_HEAPS`.
+///
+/// ### 中文
+/// `VK_MAX_MEMORY_HEAPS`。
+const VK_MAX_MEMORY_HEAPS: usize = 16;
+
+/// ### English
+/// Usage of exported images: `TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT`.
+///
+/// ### 中文
+/// 导出 image 的用途：`TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT`。
+pub(super) const IMAGE_USAGE: u32 = 0x1 | 0x2 | 0x4 | 0x10;
+
+/// ### English
+/// Dispatchable Vulkan handle (`VkInstance`, `VkPhysicalDevice`, `VkDevice`).
+///
+/// ### 中文
+/// 可分派 Vulkan 句柄（`VkInstance`、`VkPhysicalDevice`、`VkDevice`）。
+type VkDispatchable = *mut c_void;
+/// ### English
+/// Generic Vulkan command pointer (`PFN_vkVoidFunction`).
+///
+/// ### 中文
+/// 通用 Vulkan 命令指针（`PFN_vkVoidFunction`）。
+type VkVoidFunction = unsafe extern "system" fn();
+/// ### English
+/// `vkGetInstanceProcAddr`.
+///
+/// ### 中文
+/// `vkGetInstanceProcAddr`。
+type VkGetInstanceProcAddr =
+    unsafe extern "system" fn(VkDispatchable, *const c_char) -> Option<VkVoidFunction>;
+/// ### English
+/// `vkGetDeviceProcAddr`.
+///
+/// ### 中文
+/// `vkGetDeviceProcAddr`。
+type VkGetDeviceProcAddr =
+    unsafe extern "system" fn(VkDispatchable, *const c_char) -> Option<VkVoidFunction>;
+/// ### English
+/// `vkGetPhysicalDeviceMemoryProperties`.
+///
+/// ### 中文
+/// `vkGetPhysicalDeviceMemoryProperties`。
+type VkGetPhysicalDeviceMemoryProperties =
+    unsafe extern "system" fn(VkDispatchable, *mut VkPhysicalDeviceMemoryProperties);
+/// ### English
+/// `vkCreateImage`.
+///
+/// ### 中文
+/// `vkCreateImage`。
+type VkCreateImage = unsafe extern "system" fn(
+    VkDispatchable,
+    *const VkImageCreateInfo,
+    *const c_void,
+    *mut u64,
+) -> i32;
+/// ### English
+/// `vkDestroyImage` / `vkFreeMemory` / `vkDestroySemaphore` (device, handle, allocator).
+///
+/// ### 中文
+/// `vkDestroyImage` / `vkFreeMemory` / `vkDestroySemaphore`（device、句柄、分配器）。
+type VkDestroyHandle = unsafe extern "system" fn(VkDispatchable, u64, *const c_void);
+/// ### English
+/// `vkGetImageMemoryRequirements`.
+///
+/// ### 中文
+/// `vkGetImageMemoryRequirements`。
+type VkGetImageMemoryRequirements =
+    unsafe extern "system" fn(VkDispatchable, u64, *mut VkMemoryRequirements);
+/// ### English
+/// `vkAllocateMemory`.
+///
+/// ### 中文
+/// `vkAllocateMemory`。
+type VkAllocateMemory = unsafe extern "system" fn(
+    VkDispatchable,
+    *const VkMemoryAllocateInfo,
+    *const c_void,
+    *mut u64,
+) -> i32;
+/// ### English
+/// `vkBindImageMemory`.
+///
+/// ### 中文
+/// `vkBindImageMemory`。
+type VkBindImageMemory = unsafe extern "system" fn(VkDispatchable, u64, u64, u64) -> i32;
+/// ### English
+/// `vkCreateSemaphore`.
+///
+/// ### 中文
+/// `vkCreateSemaphore`。
+type VkCreateSemaphore = unsafe extern "system" fn(
+    VkDispatchable,
+    *const VkSemaphoreCreateInfo,
+    *const c_void,
+    *mut u64,
+) -> i32;
+/// ### English
+/// `vkGetMemory{Fd,Win32Handle}KHR` / `vkGetSemaphore{Fd,Win32Handle}KHR`.
+///
+/// ### 中文
+/// `vkGetMemory{Fd,Win32Handle}KHR` / `vkGetSemaphore{Fd,Win32Handle}KHR`。
+type VkGetHandle =
+    unsafe extern "system" fn(VkDispatchable, *const VkGetHandleInfo, *mut ExternalHandle) -> i32;
+
+#[repr(C)]
+/// ### English
+/// `VkExtent3D`.
+///
+/// ### 中文
+/// `VkExtent3D`。
+struct VkExtent3D {
+    width: u32,
+    height: u32,
+    depth: u32,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkImageCreateInfo`.
+///
+/// ### 中文
+/// `VkImageCreateInfo`。
+struct VkImageCreateInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    flags: u32,
+    image_type: u32,
+    format: u32,
+    extent: VkExtent3D,
+    mip_levels: u32,
+    array_layers: u32,
+    samples: u32,
+    tiling: u32,
+    usage: u32,
+    sharing_mode: u32,
+    queue_family_index_count: u32,
+    p_queue_family_indices: *const u32,
+    initial_layout: u32,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkExternalMemoryImageCreateInfo` / `VkExportMemoryAllocateInfo` /
+/// `VkExportSemaphoreCreateInfo` (identical layouts).
+///
+/// ### 中文
+/// `VkExternalMemoryImageCreateInfo` / `VkExportMemoryAllocateInfo` /
+/// `VkExportSemaphoreCreateInfo`（布局相同）。
+struct VkHandleTypesInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    handle_types: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+/// ### English
+/// `VkMemoryRequirements`.
+///
+/// ### 中文
+/// `VkMemoryRequirements`。
+struct VkMemoryRequirements {
+    size: u64,
+    alignment: u64,
+    memory_type_bits: u32,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkMemoryDedicatedAllocateInfo`.
+///
+/// ### 中文
+/// `VkMemoryDedicatedAllocateInfo`。
+struct VkMemoryDedicatedAllocateInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    image: u64,
+    buffer: u64,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkMemoryAllocateInfo`.
+///
+/// ### 中文
+/// `VkMemoryAllocateInfo`。
+struct VkMemoryAllocateInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    allocation_size: u64,
+    memory_type_index: u32,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkSemaphoreCreateInfo`.
+///
+/// ### 中文
+/// `VkSemaphoreCreateInfo`。
+struct VkSemaphoreCreateInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    flags: u32,
+}
+
+#[repr(C)]
+/// ### English
+/// `VkMemoryGet{Fd,Win32Handle}InfoKHR` / `VkSemaphoreGet{Fd,Win32Handle}InfoKHR` (identical
+/// layouts).
+///
+/// ### 中文
+/// `VkMemoryGet{Fd,Win32Handle}InfoKHR` / `VkSemaphoreGet{Fd,Win32Handle}InfoKHR`（布局相同）。
+struct VkGetHandleInfo {
+    s_type: i32,
+    p_next: *const c_void,
+    object: u64,
+    handle_type: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// `VkMemoryType`.
+///
+/// ### 中文
+/// `VkMemoryType`。
+struct VkMemoryType {
+    property_flags: u32,
+    heap_index: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// `VkMemoryHeap`.
+///
+/// ### 中文
+/// `VkMemoryHeap`。
+struct VkMemoryHeap {
+    size: u64,
+    flags: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// `VkPhysicalDeviceMemoryProperties`.
+///
+/// ### 中文
+/// `VkPhysicalDeviceMemoryProperties`。
+struct VkPhysicalDeviceMemoryProperties {
+    memory_type_count: u32,
+    memory_types: [VkMemoryType; VK_MAX_MEMORY_TYPES],
+    memory_heap_count: u32,
+    memory_heaps: [VkMemoryHeap; VK_MAX_MEMORY_HEAPS],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// Vulkan device provided by the embedder (e.g. VulkanMod through LWJGL).
+///
+/// All fields are raw addresses (`usize`) and must be non-zero when installing. The device must
+/// have `VK_KHR_external_memory_fd` + `VK_KHR_external_semaphore_fd` (Linux) or
+/// `VK_KHR_external_memory_win32` + `VK_KHR_external_semaphore_win32` (Windows) enabled, and must
+/// be the GPU the GL context runs on.
+///
+/// ### 中文
+/// 宿主提供的 Vulkan device（例如通过 LWJGL 的 VulkanMod）。
+///
+/// 所有字段均为原始地址（`usize`），安装时必须非 0。该 device 必须启用
+/// `VK_KHR_external_memory_fd` + `VK_KHR_external_semaphore_fd`（Linux）或
+/// `VK_KHR_external_memory_win32` + `VK_KHR_external_semaphore_win32`（Windows），且必须与 GL 上下文
+/// 位于同一块 GPU。
+pub struct EmbedderVulkanDevice {
+    /// ### English
+    /// Pointer to `vkGetInstanceProcAddr`.
+    ///
+    /// ### 中文
+    /// 指向 `vkGetInstanceProcAddr` 的函数指针地址。
+    pub get_instance_proc_addr: usize,
+    /// ### English
+    /// `VkInstance` the device was created from.
+    ///
+    /// ### 中文
+    /// 创建该 device 的 `VkInstance`。
+    pub instance: usize,
+    /// ### English
+    /// `VkPhysicalDevice` of the device.
+    ///
+    /// ### 中文
+    /// 该 device 的 `VkPhysicalDevice`。
+    pub physical_device: usize,
+    /// ### English
+    /// `VkDevice` the images and semaphores are created on.
+    ///
+    /// ### 中文
+    /// 用于创建 image 与 semaphore 的 `VkDevice`。
+    pub device: usize,
+}
+
+#[derive(Clone, Copy)]
+/// ### English
+/// Vulkan commands loaded from the embedder's device.
+///
+/// ### 中文
+/// 从宿主 device 加载的 Vulkan 命令。
+pub(super) struct VulkanApi {
+    /// ### English
+    /// `VkDevice` (stored as an address so the table is `Send + Sync`).
+    ///
+    /// ### 中文
+    /// `VkDevice`（以地址形式保存，使函数表满足 `Send + Sync`）。
+    device: usize,
+    /// ### English
+    /// Memory types of the physical device.
+    ///
+    /// ### 中文
+    /// 物理设备的内存类型。
+    memory_properties: VkPhysicalDeviceMemoryProperties,
+    /// ### English
+    /// `vkCreateImage`.
+    ///
+    /// ### 中文
+    /// `vkCreateImage`。
+    create_image: VkCreateImage,
+    /// ### English
+    /// `vkDestroyImage`.
+    ///
+    /// ### 中文
+    /// `vkDestroyImage`。
+    destroy_image: VkDestroyHandle,
+    /// ### English
+    /// `vkGetImageMemoryRequirements`.
+    ///
+    /// ### 中文
+    /// `vkGetImageMemoryRequirements`。
+    get_image_memory_requirements: VkGetImageMemoryRequirements,
+    /// ### English
+    /// `vkAllocateMemory`.
+    ///
+    /// ### 中文
+    /// `vkAllocateMemory`。
+    allocate_memory: VkAllocateMemory,
+    /// ### English
+    /// `vkFreeMemory`.
+    ///
+    /// ### 中文
+    /// `vkFreeMemory`。
+    free_memory: VkDestroyHandle,
+    /// ### English
+    /// `vkBindImageMemory`.
+    ///
+    /// ### 中文
+    /// `vkBindImageMemory`。
+    bind_image_memory: VkBindImageMemory,
+    /// ### English
+    /// `vkCreateSemaphore`.
+    ///
+    /// ### 中文
+    /// `vkCreateSemaphore`。
+    create_semaphore: VkCreateSemaphore,
+    /// ### English
+    /// `vkDestroySemaphore`.
+    ///
+    /// ### 中文
+    /// `vkDestroySemaphore`。
+    destroy_semaphore: VkDestroyHandle,
+    /// ### English
+    /// `vkGetMemory{Fd,Win32Handle}KHR`.
+    ///
+    /// ### 中文
+    /// `vkGetMemory{Fd,Win32Handle}KHR`。
+    get_memory_handle: VkGetHandle,
+    /// ### English
+    /// `vkGetSemaphore{Fd,Win32Handle}KHR`.
+    ///
+    /// ### 中文
+    /// `vkGetSemaphore{Fd,Win32Handle}KHR`。
+    get_semaphore_handle: VkGetHandle,
+}
+
+static EMBEDDER_VULKAN_API: OnceLock<VulkanApi> = OnceLock::new();
+
+/// ### English
+/// Image allocated with exportable, dedicated memory.
+///
+/// ### 中文
+/// 使用可导出的专用内存分配的 image。
+pub(super) struct ExportableImage {
+    /// ### English
+    /// `VkImage`.
+    ///
+    /// ### 中文
+    /// `VkImage`。
+    pub(super) image: u64,
+    /// ### English
+    /// `VkDeviceMemory` bound to `image`.
+    ///
+    /// ### 中文
+    /// 绑定到 `image` 的 `VkDeviceMemory`。
+    pub(super) memory: u64,
+    /// ### English
+    /// Size of the allocation in bytes.
+    ///
+    /// ### 中文
+    /// 分配大小（字节）。
+    pub(super) allocation_size: u64,
+    /// ### English
+    /// Memory type index of the allocation.
+    ///
+    /// ### 中文
+    /// 分配所用的内存类型索引。
+    pub(super) memory_type_index: u32,
+}
+
+/// ### English
+/// Installs the embedder's Vulkan device for this process.
+///
+/// This is a one-time installation backed by `OnceLock`; repeated calls return an error.
+///
+/// #### Parameters
+/// - `device`: Embedder Vulkan device and loader entry point.
+///
+/// ### 中文
+/// 为当前进程安装宿主的 Vulkan device。
+///
+/// 该安装由 `OnceLock` 保证只执行一次；重复调用会返回错误。
+///
+/// #### 参数
+/// - `device`：宿主的 Vulkan device 及加载入口。
+pub fn install_embedder_vulkan_device(device: EmbedderVulkanDevice) -> Result<(), String> {
+    if device.get_instance_proc_addr == 0 {
+        return Err("EmbedderVulkanDevice.get_instance_proc_addr is NULL".to_string());
+    }
+    if device.instance == 0 {
+        return Err("EmbedderVulkanDevice.instance is NULL".to_string());
+    }
+    if device.physical_device == 0 {
+        return Err("EmbedderVulkanDevice.physical_device is NULL".to_string());
+    }
+    if device.device == 0 {
+        return Err("EmbedderVulkanDevice.device is NULL".to_string());
+    }
+
+    let get_instance_proc_addr = unsafe {
+        std::mem::transmute::<usize, VkGetInstanceProcAddr>(device.get_instance_proc_addr)
+    };
+    let instance = device.instance as VkDispatchable;
+    let vk_device = device.device as VkDispatchable;
+    let instance_proc = |name: &CStr| unsafe { get_instance_proc_addr(instance, name.as_ptr()) };
+
+    let get_device_proc_addr = instance_proc(c"vkGetDeviceProcAddr")
+        .ok_or_else(|| "vkGetDeviceProcAddr is not available".to_string())?;
+    let get_device_proc_addr =
+        unsafe { std::mem::transmute::<VkVoidFunction, VkGetDeviceProcAddr>(get_device_proc_addr) };
+    let get_memory_properties = instance_proc(c"vkGetPhysicalDeviceMemoryProperties")
+        .ok_or_else(|| "vkGetPhysicalDeviceMemoryProperties is not available".to_string())?;
+    let get_memory_properties = unsafe {
+        std::mem::transmute::<VkVoidFunction, VkGetPhysicalDeviceMemoryProperties>(
+            get_memory_properties,
+        )
+    };
+    let device_proc = |name: &CStr| {
+        unsafe { get_device_proc_addr(vk_device, name.as_ptr()) }.ok_or_else(|| {
+            format!(
+                "{} is not available on the installed Vulkan device",
+                name.to_string_lossy()
+            )
+        })
+    };
+
+    let mut memory_properties = VkPhysicalDeviceMemoryProperties::default();
+    unsafe {
+        get_memory_properties(
+            device.physical_device as VkDispatchable,
+            &mut memory_properties,
+        )
+    };
+
+    let table = unsafe {
+        VulkanApi {
+            device: device.device,
+            memory_properties,
+            create_image: std::mem::transmute::<VkVoidFunction, VkCreateImage>(device_proc(
+                c"vkCreateImage",
+            )?),
+            destroy_image: std::mem::transmute::<VkVoidFunction, VkDestroyHandle>(device_proc(
+                c"vkDestroyImage",
+            )?),
+            get_image_memory_requirements: std::mem::transmute::<
+                VkVoidFunction,
+                VkGetImageMemoryRequirements,
+            >(device_proc(
+                c"vkGetImageMemoryRequirements",
+            )?),
+            allocate_memory: std::mem::transmute::<VkVoidFunction, VkAllocateMemory>(device_proc(
+                c"vkAllocateMemory",
+            )?),
+            free_memory: std::mem::transmute::<VkVoidFunction, VkDestroyHandle>(device_proc(
+                c"vkFreeMemory",
+            )?),
+            bind_image_memory: std::mem::transmute::<VkVoidFunction, VkBindImageMemory>(
+                device_proc(c"vkBindImageMemory")?,
+            ),
+            create_semaphore: std::mem::transmute::<VkVoidFunction, VkCreateSemaphore>(
+                device_proc(c"vkCreateSemaphore")?,
+            ),
+            destroy_semaphore: std::mem::transmute::<VkVoidFunction, VkDestroyHandle>(device_proc(
+                c"vkDestroySemaphore",
+            )?),
+            get_memory_handle: std::mem::transmute::<VkVoidFunction, VkGetHandle>(device_proc(
+                VK_GET_MEMORY_HANDLE,
+            )?),
+            get_semaphore_handle: std::mem::transmute::<VkVoidFunction, VkGetHandle>(device_proc(
+                VK_GET_SEMAPHORE_HANDLE,
+            )?),
+        }
+    };
+
+    EMBEDDER_VULKAN_API
+        .set(table)
+        .map_err(|_| "Embedder Vulkan device is already installed".to_string())
+}
+
+/// ### English
+/// Returns whether a Vulkan device has been installed.
+///
+/// ### 中文
+/// 返回是否已安装 Vulkan device。
+pub fn vulkan_device_installed() -> bool {
+    EMBEDDER_VULKAN_API.get().is_some()
+}
+
+impl VulkanApi {
+    /// ### English
+    /// Returns the installed Vulkan API table.
+    ///
+    /// ### 中文
+    /// 返回已安装的 Vulkan API 函数表。
+    pub(super) fn load() -> Result<Self, String> {
+        EMBEDDER_VULKAN_API.get().copied().ok_or_else(|| {
+            "Embedder Vulkan device is not installed; call xian_web_engine_set_vulkan_device first"
+                .to_string()
+        })
+    }
+
+    /// ### English
+    /// Returns the device as a dispatchable handle.
+    ///
+    /// ### 中文
+    /// 以可分派句柄的形式返回 device。
+    #[inline]
+    fn device(&self) -> VkDispatchable {
+        self.device as VkDispatchable
+    }
+
+    /// ### English
+    /// Creates a 2D optimal-tiling image backed by exportable, dedicated device-local memory.
+    ///
+    /// #### Parameters
+    /// - `format`: `VkFormat`.
+    /// - `width`: Width in pixels.
+    /// - `height`: Height in pixels.
+    /// - `mip_levels`: Number of mip levels.
+    /// - `handle_type`: External memory handle type bit.
+    ///
+    /// ### 中文
+    /// 创建一个由可导出的专用 device-local 内存支撑的 2D optimal tiling image。
+    ///
+    /// #### 参数
+    /// - `format`：`VkFormat`。
+    /// - `width`：宽度（像素）。
+    /// - `height`：高度（像素）。
+    /// - `mip_levels`：mip 级数。
+    /// - `handle_type`：外部内存句柄类型位。
+    pub(super) fn create_exportable_image(
+        &self,
+        format: u32,
+        width: u32,
+        height: u32,
+        mip_levels: u32,
+        handle_type: u32,
+    ) -> Result<ExportableImage, String> {
+        let external_info = VkHandleTypesInfo {
+            s_type: VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+            p_next: std::ptr::null(),
+            handle_types: handle_type,
+        };
+        let create_info = VkImageCreateInfo {
+            s_type: VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO,
+            p_next: (&raw const external_info).cast(),
+            flags: 0,
+            image_type: VK_IMAGE_TYPE_2D,
+            format,
+            extent: VkExtent3D {
+                width,
+                height,
+                depth: 1,
+            },
+            mip_levels,
+            array_layers: 1,
+            samples: VK_SAMPLE_COUNT_1_BIT,
+            tiling: VK_IMAGE_TILING_OPTIMAL,
+            usage: IMAGE_USAGE,
+            sharing_mode: VK_SHARING_MODE_EXCLUSIVE,
+            queue_family_index_count: 0,
+            p_queue_family_indices: std::ptr::null(),
+            initial_layout: VK_IMAGE_LAYOUT_UNDEFINED,
+        };
+        let mut image = 0u64;
+        let result = unsafe {
+            (self.create_image)(self.device(), &create_info, std::ptr::null(), &mut image)
+        };
+        if result != VK_SUCCESS {
+            return Err(format!("vkCreateImage failed ({result})"));
+        }
+
+        let mut requirements = VkMemoryRequirements::default();
+        unsafe { (self.get_image_memory_requirements)(self.device(), image, &mut requirements) };
+        let Some(memory_type_index) = self.device_local_memory_type(requirements.memory_type_bits)
+        else {
+            unsafe { (self.destroy_image)(self.device(), image, std::ptr::null()) };
+            return Err("No device-local memory type fits the exported image".to_string());
+        };
+
+        let dedicated_info = VkMemoryDedicatedAllocateInfo {
+            s_type: VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO,
+            p_next: std::ptr::null(),
+            image,
+            buffer: 0,
+        };
+        let export_info = VkHandleTypesInfo {
+            s_type: VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO,
+            p_next: (&raw const dedicated_info).cast(),
+            handle_types: handle_type,
+        };
+        let allocate_info = VkMemoryAllocateInfo {
+            s_type: VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO,
+            p_next: (&raw const export_info).cast(),
+            allocation_size: requirements.size,
+            memory_type_index,
+        };
+        let mut memory = 0u64;
+        let result = unsafe {
+            (self.allocate_memory)(self.device(), &allocate_info, std::ptr::null(), &mut memory)
+        };
+        if result != VK_SUCCESS {
+            unsafe { (self.destroy_image)(self.device(), image, std::ptr::null()) };
+            return Err(format!("vkAllocateMemory failed ({result})"));
+        }
+
+        let result = unsafe { (self.bind_image_memory)(self.device(), image, memory, 0) };
+        let exported = ExportableImage {
+            image,
+            memory,
+            allocation_size: requirements.size,
+            memory_type_index,
+        };
+        if result != VK_SUCCESS {
+            self.destroy_image(&exported);
+            return Err(format!("vkBindImageMemory failed ({result})"));
+        }
+        Ok(exported)
+    }
+
+    /// ### English
+    /// Destroys an image created by `create_exportable_image` and frees its memory.
+    ///
+    /// #### Parameters
+    /// - `image`: Image to destroy.
+    ///
+    /// ### 中文
+    /// 销毁由 `create_exportable_image` 创建的 image 并释放其内存。
+    ///
+    /// #### 参数
+    /// - `image`：要销毁的 image。
+    pub(super) fn destroy_image(&self, image: &ExportableImage) {
+        unsafe {
+            (self.destroy_image)(self.device(), image.image, std::ptr::null());
+            (self.free_memory)(self.device(), image.memory, std::ptr::null());
+        }
+    }
+
+    /// ### English
+    /// Exports a new native handle to `memory` (owned by the caller).
+    ///
+    /// #### Parameters
+    /// - `memory`: `VkDeviceMemory` allocated as exportable.
+    /// - `handle_type`: External memory handle type bit.
+    ///
+    /// ### 中文
+    /// 为 `memory` 导出一个新的原生句柄（由调用方持有）。
+    ///
+    /// #### 参数
+    /// - `memory`：以可导出方式分配的 `VkDeviceMemory`。
+    /// - `handle_type`：外部内存句柄类型位。
+    pub(super) fn export_memory(
+        &self,
+        memory: u64,
+        handle_type: u32,
+    ) -> Result<ExternalHandle, String> {
+        let info = VkGetHandleInfo {
+            s_type: VK_STRUCTURE_TYPE_MEMORY_GET_HANDLE_INFO,
+            p_next: std::ptr::null(),
+            object: memory,
+            handle_type,
+        };
+        let mut handle = INVALID_HANDLE;
+        let result = unsafe { (self.get_memory_handle)(self.device(), &info, &mut handle) };
+        if result != VK_SUCCESS {
+            return Err(format!("Exporting image memory failed ({result})"));
+        }
+        Ok(handle)
+    }
+
+    /// ### English
+    /// Creates a binary semaphore that can be exported with `handle_type`.
+    ///
+    /// #### Parameters
+    /// - `handle_type`: External semaphore handle type bit.
+    ///
+    /// ### 中文
+    /// 创建一个可通过 `handle_type` 导出的二值 semaphore。
+    ///
+    /// #### 参数
+    /// - `handle_type`：外部 semaphore 句柄类型位。
+    pub(super) fn create_exportable_semaphore(&self, handle_type: u32) -> Result<u64, String> {
+        let export_info = VkHandleTypesInfo {
+            s_type: VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO,
+            p_next: std::ptr::null(),
+            handle_types: handle_type,
+        };
+        let create_info = VkSemaphoreCreateInfo {
+            s_type: VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO,
+            p_next: (&raw const export_info).cast(),
+            flags: 0,
+        };
+        let mut semaphore = 0u64;
+        let result = unsafe {
+            (self.create_semaphore)(
+                self.device(),
+                &create_info,
+                std::ptr::null(),
+                &mut semaphore,
+            )
+        };
+        if result != VK_SUCCESS {
+            return Err(format!("vkCreateSemaphore failed ({result})"));
+        }
+        Ok(semaphore)
+    }
+
+    /// ### English
+    /// Destroys a semaphore created by `create_exportable_semaphore`.
+    ///
+    /// #### Parameters
+    /// - `semaphore`: Semaphore to destroy.
+    ///
+    /// ### 中文
+    /// 销毁由 `create_exportable_semaphore` 创建的 semaphore。
+    ///
+    /// #### 参数
+    /// - `semaphore`：要销毁的 semaphore。
+    pub(super) fn destroy_semaphore(&self, semaphore: u64) {
+        unsafe { (self.destroy_semaphore)(self.device(), semaphore, std::ptr::null()) };
+    }
+
+    /// ### English
+    /// Exports a new native handle to `semaphore` (owned by the caller).
+    ///
+    /// #### Parameters
+    /// - `semaphore`: Semaphore created as exportable.
+    /// - `handle_type`: External semaphore handle type bit.
+    ///
+    /// ### 中文
+    /// 为 `semaphore` 导出一个新的原生句柄（由调用方持有）。
+    ///
+    /// #### 参数
+    /// - `semaphore`：以可导出方式创建的 semaphore。
+    /// - `handle_type`：外部 semaphore 句柄类型位。
+    pub(super) fn export_semaphore(
+        &self,
+        semaphore: u64,
+        handle_type: u32,
+    ) -> Result<ExternalHandle, String> {
+        let info = VkGetHandleInfo {
+            s_type: VK_STRUCTURE_TYPE_SEMAPHORE_GET_HANDLE_INFO,
+            p_next: std::ptr::null(),
+            object: semaphore,
+            handle_type,
+        };
+        let mut handle = INVALID_HANDLE;
+        let result = unsafe { (self.get_semaphore_handle)(self.device(), &info, &mut handle) };
+        if result != VK_SUCCESS {
+            return Err(format!("Exporting semaphore failed ({result})"));
+        }
+        Ok(handle)
+    }
+
+    /// ### English
+    /// Returns the first device-local memory type allowed by `type_bits`.
+    ///
+    /// #### Parameters
+    /// - `type_bits`: `VkMemoryRequirements::memoryTypeBits`.
+    ///
+    /// ### 中文
+    /// 返回 `type_bits` 允许的第一个 device-local 内存类型。
+    ///
+    /// #### 参数
+    /// - `type_bits`：`VkMemoryRequirements::memoryTypeBits`。
+    fn device_local_memory_type(&self, type_bits: u32) -> Option<u32> {
+        let count = (self.memory_properties.memory_type_count as usize).min(VK_MAX_MEMORY_TYPES);
+        (0..count as u32).find(|&index| {
+            type_bits & (1 << index) != 0
+                && self.memory_properties.memory_types[index as usize].property_flags
+                    & VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT
+                    != 0
+        })
+    }
+}
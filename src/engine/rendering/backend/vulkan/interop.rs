@@ -0,0 +1,409 @@
+//! ### English
+//! `GL_EXT_memory_object` / `GL_EXT_semaphore` entry points used to import Vulkan images and
+//! semaphores into the shared GL context.
+//!
+//! ### 中文
+//! 用于将 Vulkan image 与 semaphore 导入共享 GL 上下文的 `GL_EXT_memory_object` /
+//! `GL_EXT_semaphore` 入口函数。
+
+use std::ffi::{CStr, c_void};
+
+use gleam::gl;
+
+use super::platform::{
+    ExternalHandle, GL_HANDLE_EXTENSIONS, GL_HANDLE_TYPE, GL_IMPORT_MEMORY, GL_IMPORT_SEMAPHORE,
+};
+use crate::engine::rendering::GlfwSharedContext;
+
+/// ### English
+/// `GL_DEDICATED_MEMORY_OBJECT_EXT`.
+///
+/// ### 中文
+/// `GL_DEDICATED_MEMORY_OBJECT_EXT`。
+const GL_DEDICATED_MEMORY_OBJECT_EXT: gl::GLenum = 0x9581;
+/// ### English
+/// `GL_TEXTURE_TILING_EXT`.
+///
+/// ### 中文
+/// `GL_TEXTURE_TILING_EXT`。
+const GL_TEXTURE_TILING_EXT: gl::GLenum = 0x9580;
+/// ### English
+/// `GL_OPTIMAL_TILING_EXT`.
+///
+/// ### 中文
+/// `GL_OPTIMAL_TILING_EXT`。
+const GL_OPTIMAL_TILING_EXT: gl::GLint = 0x9584;
+/// ### English
+/// `GL_LAYOUT_SHADER_READ_ONLY_EXT`: layout the image is handed over in.
+///
+/// ### 中文
+/// `GL_LAYOUT_SHADER_READ_ONLY_EXT`：移交 image 时所处的布局。
+const GL_LAYOUT_SHADER_READ_ONLY_EXT: gl::GLenum = 0x9591;
+
+/// ### English
+/// `glCreateMemoryObjectsEXT` / `glGenSemaphoresEXT`.
+///
+/// ### 中文
+/// `glCreateMemoryObjectsEXT` / `glGenSemaphoresEXT`。
+type GlGenObjects = unsafe extern "system" fn(gl::GLsizei, *mut gl::GLuint);
+/// ### English
+/// `glDeleteMemoryObjectsEXT` / `glDeleteSemaphoresEXT`.
+///
+/// ### 中文
+/// `glDeleteMemoryObjectsEXT` / `glDeleteSemaphoresEXT`。
+type GlDeleteObjects = unsafe extern "system" fn(gl::GLsizei, *const gl::GLuint);
+/// ### English
+/// `glMemoryObjectParameterivEXT`.
+///
+/// ### 中文
+/// `glMemoryObjectParameterivEXT`。
+type GlMemoryObjectParameteriv =
+    unsafe extern "system" fn(gl::GLuint, gl::GLenum, *const gl::GLint);
+/// ### English
+/// `glTexStorageMem2DEXT`.
+///
+/// ### 中文
+/// `glTexStorageMem2DEXT`。
+type GlTexStorageMem2D = unsafe extern "system" fn(
+    gl::GLenum,
+    gl::GLsizei,
+    gl::GLenum,
+    gl::GLsizei,
+    gl::GLsizei,
+    gl::GLuint,
+    u64,
+);
+/// ### English
+/// `glImportMemory{Fd,Win32Handle}EXT`.
+///
+/// ### 中文
+/// `glImportMemory{Fd,Win32Handle}EXT`。
+type GlImportMemory = unsafe extern "system" fn(gl::GLuint, u64, gl::GLenum, ExternalHandle);
+/// ### English
+/// `glImportSemaphore{Fd,Win32Handle}EXT`.
+///
+/// ### 中文
+/// `glImportSemaphore{Fd,Win32Handle}EXT`。
+type GlImportSemaphore = unsafe extern "system" fn(gl::GLuint, gl::GLenum, ExternalHandle);
+/// ### English
+/// `glSignalSemaphoreEXT` / `glWaitSemaphoreEXT`.
+///
+/// ### 中文
+/// `glSignalSemaphoreEXT` / `glWaitSemaphoreEXT`。
+type GlSemaphoreOp = unsafe extern "system" fn(
+    gl::GLuint,
+    gl::GLuint,
+    *const gl::GLuint,
+    gl::GLuint,
+    *const gl::GLuint,
+    *const gl::GLenum,
+);
+
+/// ### English
+/// External memory/semaphore entry points of the shared GL context.
+///
+/// ### 中文
+/// 共享 GL 上下文的外部内存/semaphore 入口函数。
+pub(super) struct GlInterop {
+    /// ### English
+    /// `glCreateMemoryObjectsEXT`.
+    ///
+    /// ### 中文
+    /// `glCreateMemoryObjectsEXT`。
+    create_memory_objects: GlGenObjects,
+    /// ### English
+    /// `glDeleteMemoryObjectsEXT`.
+    ///
+    /// ### 中文
+    /// `glDeleteMemoryObjectsEXT`。
+    delete_memory_objects: GlDeleteObjects,
+    /// ### English
+    /// `glMemoryObjectParameterivEXT`.
+    ///
+    /// ### 中文
+    /// `glMemoryObjectParameterivEXT`。
+    memory_object_parameteriv: GlMemoryObjectParameteriv,
+    /// ### English
+    /// `glTexStorageMem2DEXT`.
+    ///
+    /// ### 中文
+    /// `glTexStorageMem2DEXT`。
+    tex_storage_mem_2d: GlTexStorageMem2D,
+    /// ### English
+    /// `glImportMemory{Fd,Win32Handle}EXT`.
+    ///
+    /// ### 中文
+    /// `glImportMemory{Fd,Win32Handle}EXT`。
+    import_memory: GlImportMemory,
+    /// ### English
+    /// `glGenSemaphoresEXT`.
+    ///
+    /// ### 中文
+    /// `glGenSemaphoresEXT`。
+    gen_semaphores: GlGenObjects,
+    /// ### English
+    /// `glDeleteSemaphoresEXT`.
+    ///
+    /// ### 中文
+    /// `glDeleteSemaphoresEXT`。
+    delete_semaphores: GlDeleteObjects,
+    /// ### English
+    /// `glImportSemaphore{Fd,Win32Handle}EXT`.
+    ///
+    /// ### 中文
+    /// `glImportSemaphore{Fd,Win32Handle}EXT`。
+    import_semaphore: GlImportSemaphore,
+    /// ### English
+    /// `glSignalSemaphoreEXT`.
+    ///
+    /// ### 中文
+    /// `glSignalSemaphoreEXT`。
+    signal_semaphore: GlSemaphoreOp,
+    /// ### English
+    /// `glWaitSemaphoreEXT`.
+    ///
+    /// ### 中文
+    /// `glWaitSemaphoreEXT`。
+    wait_semaphore: GlSemaphoreOp,
+}
+
+impl GlInterop {
+    /// ### English
+    /// Loads the entry points from the shared context (the context must be current).
+    ///
+    /// The extensions are checked first because GLX returns non-NULL pointers even for entry points
+    /// the driver does not implement. Fails with the name of the first missing extension or entry
+    /// point.
+    ///
+    /// #### Parameters
+    /// - `shared_ctx`: Shared GL context.
+    ///
+    /// ### 中文
+    /// 从共享上下文加载入口函数（上下文必须为 current）。
+    ///
+    /// 会先检查扩展，因为 GLX 即使对驱动未实现的入口函数也会返回非 NULL 指针。缺少扩展或入口函数时
+    /// 返回第一个缺失项的名称。
+    ///
+    /// #### 参数
+    /// - `shared_ctx`：共享 GL 上下文。
+    pub(super) fn load(shared_ctx: &GlfwSharedContext) -> Result<Self, String> {
+        for extension in ["GL_EXT_memory_object", "GL_EXT_semaphore"]
+            .into_iter()
+            .chain(GL_HANDLE_EXTENSIONS)
+        {
+            if !shared_ctx.has_extension(extension) {
+                return Err(format!(
+                    "{extension} is not supported by the shared context"
+                ));
+            }
+        }
+
+        let proc = |name: &CStr| {
+            let ptr = shared_ctx.proc_address(name);
+            if ptr.is_null() {
+                Err(format!(
+                    "{} is not available (GL_EXT_memory_object/GL_EXT_semaphore required)",
+                    name.to_string_lossy()
+                ))
+            } else {
+                Ok(ptr)
+            }
+        };
+
+        unsafe {
+            Ok(Self {
+                create_memory_objects: std::mem::transmute::<*const c_void, GlGenObjects>(proc(
+                    c"glCreateMemoryObjectsEXT",
+                )?),
+                delete_memory_objects: std::mem::transmute::<*const c_void, GlDeleteObjects>(proc(
+                    c"glDeleteMemoryObjectsEXT",
+                )?),
+                memory_object_parameteriv: std::mem::transmute::<
+                    *const c_void,
+                    GlMemoryObjectParameteriv,
+                >(proc(
+                    c"glMemoryObjectParameterivEXT",
+                )?),
+                tex_storage_mem_2d: std::mem::transmute::<*const c_void, GlTexStorageMem2D>(proc(
+                    c"glTexStorageMem2DEXT",
+                )?),
+                import_memory: std::mem::transmute::<*const c_void, GlImportMemory>(proc(
+                    GL_IMPORT_MEMORY,
+                )?),
+                gen_semaphores: std::mem::transmute::<*const c_void, GlGenObjects>(proc(
+                    c"glGenSemaphoresEXT",
+                )?),
+                delete_semaphores: std::mem::transmute::<*const c_void, GlDeleteObjects>(proc(
+                    c"glDeleteSemaphoresEXT",
+                )?),
+                import_semaphore: std::mem::transmute::<*const c_void, GlImportSemaphore>(proc(
+                    GL_IMPORT_SEMAPHORE,
+                )?),
+                signal_semaphore: std::mem::transmute::<*const c_void, GlSemaphoreOp>(proc(
+                    c"glSignalSemaphoreEXT",
+                )?),
+                wait_semaphore: std::mem::transmute::<*const c_void, GlSemaphoreOp>(proc(
+                    c"glWaitSemaphoreEXT",
+                )?),
+            })
+        }
+    }
+
+    /// ### English
+    /// Imports a dedicated memory allocation and returns the GL memory object.
+    ///
+    /// #### Parameters
+    /// - `size`: Allocation size in bytes.
+    /// - `handle`: Exported handle (ownership follows the platform import rules).
+    ///
+    /// ### 中文
+    /// 导入一块专用内存分配并返回 GL memory object。
+    ///
+    /// #### 参数
+    /// - `size`：分配大小（字节）。
+    /// - `handle`：导出的句柄（所有权遵循平台导入规则）。
+    pub(super) fn import_memory(&self, size: u64, handle: ExternalHandle) -> gl::GLuint {
+        let mut memory = 0;
+        let dedicated: gl::GLint = 1;
+        unsafe {
+            (self.create_memory_objects)(1, &mut memory);
+            (self.memory_object_parameteriv)(memory, GL_DEDICATED_MEMORY_OBJECT_EXT, &dedicated);
+            (self.import_memory)(memory, size, GL_HANDLE_TYPE, handle);
+        }
+        memory
+    }
+
+    /// ### English
+    /// Deletes a GL memory object.
+    ///
+    /// #### Parameters
+    /// - `memory`: Memory object to delete.
+    ///
+    /// ### 中文
+    /// 删除 GL memory object。
+    ///
+    /// #### 参数
+    /// - `memory`：要删除的 memory object。
+    pub(super) fn delete_memory(&self, memory: gl::GLuint) {
+        unsafe { (self.delete_memory_objects)(1, &memory) };
+    }
+
+    /// ### English
+    /// Specifies optimal-tiling storage of the bound `TEXTURE_2D` from an imported memory object.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API.
+    /// - `levels`: Number of mip levels.
+    /// - `internal_format`: Sized internal format.
+    /// - `width`: Width in pixels.
+    /// - `height`: Height in pixels.
+    /// - `memory`: Imported memory object.
+    ///
+    /// ### 中文
+    /// 以导入的 memory object 为当前绑定的 `TEXTURE_2D` 指定 optimal tiling 存储。
+    ///
+    /// #### 参数
+    /// - `gl`：GL API。
+    /// - `levels`：mip 级数。
+    /// - `internal_format`：带尺寸的内部格式。
+    /// - `width`：宽度（像素）。
+    /// - `height`：高度（像素）。
+    /// - `memory`：导入的 memory object。
+    pub(super) fn tex_storage(
+        &self,
+        gl: &dyn gl::Gl,
+        levels: gl::GLsizei,
+        internal_format: gl::GLenum,
+        width: gl::GLsizei,
+        height: gl::GLsizei,
+        memory: gl::GLuint,
+    ) {
+        gl.tex_parameter_i(gl::TEXTURE_2D, GL_TEXTURE_TILING_EXT, GL_OPTIMAL_TILING_EXT);
+        unsafe {
+            (self.tex_storage_mem_2d)(
+                gl::TEXTURE_2D,
+                levels,
+                internal_format,
+                width,
+                height,
+                memory,
+                0,
+            );
+        }
+    }
+
+    /// ### English
+    /// Imports an exported semaphore and returns the GL semaphore.
+    ///
+    /// #### Parameters
+    /// - `handle`: Exported handle (ownership follows the platform import rules).
+    ///
+    /// ### 中文
+    /// 导入一个导出的 semaphore 并返回 GL semaphore。
+    ///
+    /// #### 参数
+    /// - `handle`：导出的句柄（所有权遵循平台导入规则）。
+    pub(super) fn import_semaphore(&self, handle: ExternalHandle) -> gl::GLuint {
+        let mut semaphore = 0;
+        unsafe {
+            (self.gen_semaphores)(1, &mut semaphore);
+            (self.import_semaphore)(semaphore, GL_HANDLE_TYPE, handle);
+        }
+        semaphore
+    }
+
+    /// ### English
+    /// Deletes a GL semaphore.
+    ///
+    /// #### Parameters
+    /// - `semaphore`: Semaphore to delete.
+    ///
+    /// ### 中文
+    /// 删除 GL semaphore。
+    ///
+    /// #### 参数
+    /// - `semaphore`：要删除的 semaphore。
+    pub(super) fn delete_semaphore(&self, semaphore: gl::GLuint) {
+        unsafe { (self.delete_semaphores)(1, &semaphore) };
+    }
+
+    /// ### English
+    /// Signals `semaphore` after prior GL work, transitioning `texture` to shader-read-only layout.
+    ///
+    /// #### Parameters
+    /// - `semaphore`: GL semaphore.
+    /// - `texture`: Texture handed over to the Vulkan consumer.
+    ///
+    /// ### 中文
+    /// 在之前的 GL 工作完成后 signal `semaphore`，并将 `texture` 转换为 shader 只读布局。
+    ///
+    /// #### 参数
+    /// - `semaphore`：GL semaphore。
+    /// - `texture`：移交给 Vulkan 消费者的纹理。
+    pub(super) fn signal(&self, semaphore: gl::GLuint, texture: gl::GLuint) {
+        let layout = GL_LAYOUT_SHADER_READ_ONLY_EXT;
+        unsafe {
+            (self.signal_semaphore)(semaphore, 0, std::ptr::null(), 1, &texture, &layout);
+        }
+    }
+
+    /// ### English
+    /// Makes subsequent GL work wait for `semaphore`; `texture` is expected in shader-read-only layout.
+    ///
+    /// #### Parameters
+    /// - `semaphore`: GL semaphore.
+    /// - `texture`: Texture handed back by the Vulkan consumer.
+    ///
+    /// ### 中文
+    /// 使后续 GL 工作等待 `semaphore`；`texture` 应处于 shader 只读布局。
+    ///
+    /// #### 参数
+    /// - `semaphore`：GL semaphore。
+    /// - `texture`：Vulkan 消费者交还的纹理。
+    pub(super) fn wait(&self, semaphore: gl::GLuint, texture: gl::GLuint) {
+        let layout = GL_LAYOUT_SHADER_READ_ONLY_EXT;
+        unsafe {
+            (self.wait_semaphore)(semaphore, 0, std::ptr::null(), 1, &texture, &layout);
+        }
+    }
+}
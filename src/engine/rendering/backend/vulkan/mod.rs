@@ -0,0 +1,794 @@
+//! ### English
+//! Vulkan render backend: slot textures are Vulkan images exported through external memory.
+//!
+//! Each slot image is allocated on the embedder's Vulkan device with exportable dedicated memory and
+//! imported into the shared GL context (`GL_EXT_memory_object`), so Servo keeps rendering with GL
+//! while a Vulkan renderer (e.g. VulkanMod) samples the same memory. Every slot owns two exported
+//! binary semaphores:
+//!
+//! - `ready`: signaled by GL after the frame is rendered; the consumer waits on it after acquire.
+//! - `release`: signaled by the consumer when it is done; GL waits on it before rendering again.
+//!
+//! ### 中文
+//! Vulkan 渲染后端：槽位纹理是通过外部内存导出的 Vulkan image。
+//!
+//! 每个槽位 image 都在宿主 Vulkan device 上以可导出的专用内存分配，并导入共享 GL 上下文
+//!（`GL_EXT_memory_object`），因此 Servo 仍使用 GL 渲染，而 Vulkan 渲染器（例如 VulkanMod）可直接
+//! 采样同一块内存。每个槽位持有两个导出的二值 semaphore：
+//!
+//! - `ready`：帧渲染完成后由 GL signal；消费者 acquire 后等待它。
+//! - `release`：消费者用完后 signal；GL 再次渲染前等待它。
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use dpi::PhysicalSize;
+use gleam::gl::{self, Gl};
+use glow::HasContext as _;
+
+use crate::engine::frame::TRIPLE_BUFFER_COUNT;
+use crate::engine::rendering::GlfwSharedContext;
+
+use super::gl_backend::{delete_sync, fence_sync, sync_signaled};
+use super::{RenderBackend, SlotTextureFormat};
+
+mod api;
+mod interop;
+
+#[cfg(windows)]
+mod windows;
+
+#[cfg(not(windows))]
+mod unix;
+
+#[cfg(windows)]
+use windows as platform;
+
+#[cfg(not(windows))]
+use unix as platform;
+
+pub use api::{EmbedderVulkanDevice, install_embedder_vulkan_device, vulkan_device_installed};
+
+use api::{ExportableImage, VulkanApi};
+use interop::GlInterop;
+use platform::{ExternalHandle, VK_HANDLE_TYPE};
+
+/// ### English
+/// Consumer fence value meaning "the release semaphore of this slot was signaled".
+///
+/// Vulkan consumers pass it to `xian_web_engine_views_release_frames` instead of a `GLsync`.
+///
+/// ### 中文
+/// 表示“该槽位的 release semaphore 已被 signal”的 consumer fence 值。
+///
+/// Vulkan 消费者在 `xian_web_engine_views_release_frames` 中传入该值以代替 `GLsync`。
+pub const XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+
+/// ### English
+/// `VK_FORMAT_R8G8B8A8_UNORM`.
+///
+/// ### 中文
+/// `VK_FORMAT_R8G8B8A8_UNORM`。
+const VK_FORMAT_R8G8B8A8_UNORM: u32 = 37;
+/// ### English
+/// `VK_FORMAT_R8G8B8A8_SRGB`.
+///
+/// ### 中文
+/// `VK_FORMAT_R8G8B8A8_SRGB`。
+const VK_FORMAT_R8G8B8A8_SRGB: u32 = 43;
+/// ### English
+/// `VK_FORMAT_R16G16B16A16_SFLOAT`.
+///
+/// ### 中文
+/// `VK_FORMAT_R16G16B16A16_SFLOAT`。
+const VK_FORMAT_R16G16B16A16_SFLOAT: u32 = 97;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+/// ### English
+/// Vulkan import description of one slot image.
+///
+/// Handles stay owned by the engine: duplicate them (`dup` / `DuplicateHandle`) before passing them
+/// to an import call that takes ownership. The image is created as 2D, optimal tiling, exclusive
+/// sharing, usage `TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT`, with dedicated memory.
+///
+/// ### 中文
+/// 单个槽位 image 的 Vulkan 导入描述。
+///
+/// 句柄仍归引擎所有：传给会接管所有权的导入调用之前，请先复制（`dup` / `DuplicateHandle`）。
+/// image 以 2D、optimal tiling、独占共享模式创建，用途为
+/// `TRANSFER_SRC | TRANSFER_DST | SAMPLED | COLOR_ATTACHMENT`，并使用专用内存。
+pub struct XianWebEngineVulkanSlot {
+    /// ### English
+    /// Exported memory handle (file descriptor on Linux, `HANDLE` on Windows).
+    ///
+    /// ### 中文
+    /// 导出的内存句柄（Linux 为文件描述符，Windows 为 `HANDLE`）。
+    pub memory_handle: u64,
+    /// ### English
+    /// Size of the dedicated allocation in bytes.
+    ///
+    /// ### 中文
+    /// 专用内存分配的大小（字节）。
+    pub allocation_size: u64,
+    /// ### English
+    /// Exported handle of the `ready` semaphore (wait on it after acquire).
+    ///
+    /// ### 中文
+    /// `ready` semaphore 的导出句柄（acquire 后等待它）。
+    pub ready_semaphore: u64,
+    /// ### English
+    /// Exported handle of the `release` semaphore (signal it before release).
+    ///
+    /// ### 中文
+    /// `release` semaphore 的导出句柄（release 前 signal 它）。
+    pub release_semaphore: u64,
+    /// ### English
+    /// `VkExternalMemoryHandleTypeFlagBits` / `VkExternalSemaphoreHandleTypeFlagBits` of the handles.
+    ///
+    /// ### 中文
+    /// 句柄的 `VkExternalMemoryHandleTypeFlagBits` / `VkExternalSemaphoreHandleTypeFlagBits`。
+    pub handle_type: u32,
+    /// ### English
+    /// `VkFormat` of the image.
+    ///
+    /// ### 中文
+    /// image 的 `VkFormat`。
+    pub vk_format: u32,
+    /// ### English
+    /// Image width in pixels.
+    ///
+    /// ### 中文
+    /// image 宽度（像素）。
+    pub width: u32,
+    /// ### English
+    /// Image height in pixels.
+    ///
+    /// ### 中文
+    /// image 高度（像素）。
+    pub height: u32,
+    /// ### English
+    /// Number of mip levels.
+    ///
+    /// ### 中文
+    /// mip 级数。
+    pub mip_levels: u32,
+    /// ### English
+    /// Memory type index used for the allocation.
+    ///
+    /// ### 中文
+    /// 分配所用的内存类型索引。
+    pub memory_type_index: u32,
+    /// ### English
+    /// Image generation (`0` = no image); changes whenever the slot image is reallocated.
+    ///
+    /// ### 中文
+    /// image 代数（`0` 表示没有 image）；槽位 image 每次重新分配都会变化。
+    pub generation: u32,
+    /// ### English
+    /// Reserved (always `0`).
+    ///
+    /// ### 中文
+    /// 保留字段（始终为 `0`）。
+    pub reserved: u32,
+}
+
+/// ### English
+/// Slot image descriptions published by the Vulkan backend and read by the embedder.
+///
+/// ### 中文
+/// Vulkan 后端发布、供宿主读取的槽位 image 描述。
+#[derive(Default)]
+pub struct VulkanFrameExports {
+    /// ### English
+    /// Current description of each slot (`generation == 0` = not allocated).
+    ///
+    /// ### 中文
+    /// 每个槽位的当前描述（`generation == 0` 表示未分配）。
+    slots: Mutex<[XianWebEngineVulkanSlot; TRIPLE_BUFFER_COUNT]>,
+}
+
+impl VulkanFrameExports {
+    /// ### English
+    /// Returns the description of `slot`, or `None` if it has no image.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slot index (0..=2).
+    ///
+    /// ### 中文
+    /// 返回 `slot` 的描述；若该槽位没有 image 则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `slot`：槽位索引（0..=2）。
+    pub fn get(&self, slot: usize) -> Option<XianWebEngineVulkanSlot> {
+        let slots = self.slots.lock().ok()?;
+        slots.get(slot).copied().filter(|info| info.generation != 0)
+    }
+
+    /// ### English
+    /// Publishes the description of `slot`.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slot index.
+    /// - `info`: New description.
+    ///
+    /// ### 中文
+    /// 发布 `slot` 的描述。
+    ///
+    /// #### 参数
+    /// - `slot`：槽位索引。
+    /// - `info`：新的描述。
+    fn set(&self, slot: usize, info: XianWebEngineVulkanSlot) {
+        if let Ok(mut slots) = self.slots.lock() {
+            slots[slot] = info;
+        }
+    }
+}
+
+/// ### English
+/// Semaphore created on the Vulkan device and imported into GL.
+///
+/// ### 中文
+/// 在 Vulkan device 上创建并导入 GL 的 semaphore。
+struct ExportedSemaphore {
+    /// ### English
+    /// `VkSemaphore`.
+    ///
+    /// ### 中文
+    /// `VkSemaphore`。
+    vk: u64,
+    /// ### English
+    /// Imported GL semaphore.
+    ///
+    /// ### 中文
+    /// 导入后的 GL semaphore。
+    gl: gl::GLuint,
+    /// ### English
+    /// Handle exported for the consumer (owned by the engine).
+    ///
+    /// ### 中文
+    /// 为消费者导出的句柄（归引擎所有）。
+    consumer: ExternalHandle,
+}
+
+impl ExportedSemaphore {
+    /// ### English
+    /// Creates an exportable semaphore, imports it into GL and exports a consumer handle.
+    ///
+    /// #### Parameters
+    /// - `vk`: Vulkan API.
+    /// - `interop`: GL interop entry points.
+    ///
+    /// ### 中文
+    /// 创建可导出的 semaphore，将其导入 GL，并为消费者导出句柄。
+    ///
+    /// #### 参数
+    /// - `vk`：Vulkan API。
+    /// - `interop`：GL 互操作入口函数。
+    fn create(vk: &VulkanApi, interop: &GlInterop) -> Result<Self, String> {
+        let semaphore = vk.create_exportable_semaphore(VK_HANDLE_TYPE)?;
+        let gl_handle = match vk.export_semaphore(semaphore, VK_HANDLE_TYPE) {
+            Ok(handle) => handle,
+            Err(err) => {
+                vk.destroy_semaphore(semaphore);
+                return Err(err);
+            }
+        };
+        let gl_semaphore = interop.import_semaphore(gl_handle);
+        platform::after_gl_import(gl_handle);
+
+        match vk.export_semaphore(semaphore, VK_HANDLE_TYPE) {
+            Ok(consumer) => Ok(Self {
+                vk: semaphore,
+                gl: gl_semaphore,
+                consumer,
+            }),
+            Err(err) => {
+                interop.delete_semaphore(gl_semaphore);
+                vk.destroy_semaphore(semaphore);
+                Err(err)
+            }
+        }
+    }
+
+    /// ### English
+    /// Deletes the GL and Vulkan semaphores and closes the consumer handle.
+    ///
+    /// #### Parameters
+    /// - `vk`: Vulkan API.
+    /// - `interop`: GL interop entry points.
+    ///
+    /// ### 中文
+    /// 删除 GL 与 Vulkan semaphore，并关闭消费者句柄。
+    ///
+    /// #### 参数
+    /// - `vk`：Vulkan API。
+    /// - `interop`：GL 互操作入口函数。
+    fn destroy(&self, vk: &VulkanApi, interop: &GlInterop) {
+        interop.delete_semaphore(self.gl);
+        vk.destroy_semaphore(self.vk);
+        platform::close_handle(self.consumer);
+    }
+}
+
+/// ### English
+/// Per-slot synchronization objects.
+///
+/// ### 中文
+/// 每槽位的同步对象。
+struct SlotSync {
+    /// ### English
+    /// Semaphore signaled by GL when the frame is ready.
+    ///
+    /// ### 中文
+    /// 帧就绪时由 GL signal 的 semaphore。
+    ready: ExportedSemaphore,
+    /// ### English
+    /// Semaphore signaled by the consumer when it is done with the image.
+    ///
+    /// ### 中文
+    /// 消费者用完 image 时 signal 的 semaphore。
+    release: ExportedSemaphore,
+    /// ### English
+    /// Whether `ready` was signaled and not yet waited on by the consumer (binary semaphores must not
+    /// be signaled twice).
+    ///
+    /// ### 中文
+    /// `ready` 是否已 signal 且尚未被消费者等待（二值 semaphore 不能重复 signal）。
+    ready_pending: Cell<bool>,
+}
+
+/// ### English
+/// Vulkan image currently backing a slot texture.
+///
+/// ### 中文
+/// 当前支撑某个槽位纹理的 Vulkan image。
+struct SlotImage {
+    /// ### English
+    /// Vulkan image and its memory.
+    ///
+    /// ### 中文
+    /// Vulkan image 及其内存。
+    image: ExportableImage,
+    /// ### English
+    /// GL memory object imported from the image memory.
+    ///
+    /// ### 中文
+    /// 从 image 内存导入的 GL memory object。
+    gl_memory: gl::GLuint,
+    /// ### English
+    /// Memory handle exported for the consumer (owned by the engine).
+    ///
+    /// ### 中文
+    /// 为消费者导出的内存句柄（归引擎所有）。
+    consumer_memory: ExternalHandle,
+}
+
+/// ### English
+/// Backend whose slot textures live in exported Vulkan memory.
+///
+/// ### 中文
+/// 槽位纹理位于导出 Vulkan 内存中的后端。
+pub(in crate::engine::rendering) struct VulkanBackend {
+    /// ### English
+    /// Vulkan API of the embedder's device.
+    ///
+    /// ### 中文
+    /// 宿主 device 的 Vulkan API。
+    vk: VulkanApi,
+    /// ### English
+    /// GL external memory/semaphore entry points.
+    ///
+    /// ### 中文
+    /// GL 外部内存/semaphore 入口函数。
+    interop: GlInterop,
+    /// ### English
+    /// Current image of each slot.
+    ///
+    /// ### 中文
+    /// 每个槽位的当前 image。
+    images: RefCell<[Option<SlotImage>; TRIPLE_BUFFER_COUNT]>,
+    /// ### English
+    /// Synchronization objects of each slot (kept across resizes).
+    ///
+    /// ### 中文
+    /// 每个槽位的同步对象（resize 时保留）。
+    sync: [SlotSync; TRIPLE_BUFFER_COUNT],
+    /// ### English
+    /// Last image generation handed out.
+    ///
+    /// ### 中文
+    /// 最近一次分配的 image 代数。
+    generation: Cell<u32>,
+    /// ### English
+    /// Descriptions published to the embedder.
+    ///
+    /// ### 中文
+    /// 发布给宿主的描述。
+    exports: Arc<VulkanFrameExports>,
+}
+
+impl VulkanBackend {
+    /// ### English
+    /// Creates the backend on the shared context (which must be current).
+    ///
+    /// Fails if no Vulkan device is installed or the GL context lacks external memory support.
+    ///
+    /// #### Parameters
+    /// - `shared_ctx`: Shared GL context.
+    /// - `exports`: Descriptions published to the embedder.
+    ///
+    /// ### 中文
+    /// 在共享上下文（必须为 current）上创建后端。
+    ///
+    /// 若未安装 Vulkan device，或 GL 上下文不支持外部内存，则返回错误。
+    ///
+    /// #### 参数
+    /// - `shared_ctx`：共享 GL 上下文。
+    /// - `exports`：发布给宿主的描述。
+    pub(in crate::engine::rendering) fn new(
+        shared_ctx: &GlfwSharedContext,
+        exports: Arc<VulkanFrameExports>,
+    ) -> Result<Self, String> {
+        let vk = VulkanApi::load()?;
+        let interop = GlInterop::load(shared_ctx)?;
+
+        let mut sync = Vec::with_capacity(TRIPLE_BUFFER_COUNT);
+        let created = (0..TRIPLE_BUFFER_COUNT).try_for_each(|_| {
+            let ready = ExportedSemaphore::create(&vk, &interop)?;
+            let release = match ExportedSemaphore::create(&vk, &interop) {
+                Ok(release) => release,
+                Err(err) => {
+                    ready.destroy(&vk, &interop);
+                    return Err(err);
+                }
+            };
+            sync.push(SlotSync {
+                ready,
+                release,
+                ready_pending: Cell::new(false),
+            });
+            Ok(())
+        });
+        if let Err(err) = created {
+            for slot in &sync {
+                slot.ready.destroy(&vk, &interop);
+                slot.release.destroy(&vk, &interop);
+            }
+            return Err(err);
+        }
+        let Ok(sync) = <[SlotSync; TRIPLE_BUFFER_COUNT]>::try_from(sync) else {
+            return Err("Failed to create slot semaphores".to_string());
+        };
+
+        Ok(Self {
+            vk,
+            interop,
+            images: RefCell::new(Default::default()),
+            sync,
+            generation: Cell::new(0),
+            exports,
+        })
+    }
+
+    /// ### English
+    /// Allocates an exported image, imports it as a GL texture and publishes its description.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API of the shared context.
+    /// - `slot`: Slot index.
+    /// - `size`: Texture size in pixels.
+    /// - `format`: Color texture formats.
+    /// - `mipmaps`: Whether the texture gets a mip chain.
+    ///
+    /// ### 中文
+    /// 分配一个导出 image，将其导入为 GL 纹理并发布其描述。
+    ///
+    /// #### 参数
+    /// - `gl`：共享上下文的 GL API。
+    /// - `slot`：槽位索引。
+    /// - `size`：纹理尺寸（像素）。
+    /// - `format`：颜色纹理格式。
+    /// - `mipmaps`：纹理是否带 mip 链。
+    fn create_slot_image(
+        &self,
+        gl: &Rc<dyn Gl>,
+        slot: usize,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<(gl::GLuint, SlotImage), String> {
+        let vk_format = vk_format(format)?;
+        let width = size.width.max(1);
+        let height = size.height.max(1);
+        let mip_levels = if mipmaps {
+            u32::BITS - width.max(height).leading_zeros()
+        } else {
+            1
+        };
+
+        let image = self.vk.create_exportable_image(
+            vk_format,
+            width,
+            height,
+            mip_levels,
+            VK_HANDLE_TYPE,
+        )?;
+        let gl_handle = match self.vk.export_memory(image.memory, VK_HANDLE_TYPE) {
+            Ok(handle) => handle,
+            Err(err) => {
+                self.vk.destroy_image(&image);
+                return Err(err);
+            }
+        };
+        let gl_memory = self.interop.import_memory(image.allocation_size, gl_handle);
+        platform::after_gl_import(gl_handle);
+        let consumer_memory = match self.vk.export_memory(image.memory, VK_HANDLE_TYPE) {
+            Ok(handle) => handle,
+            Err(err) => {
+                self.interop.delete_memory(gl_memory);
+                self.vk.destroy_image(&image);
+                return Err(err);
+            }
+        };
+        let slot_image = SlotImage {
+            image,
+            gl_memory,
+            consumer_memory,
+        };
+
+        let min_filter = if mipmaps {
+            gl::LINEAR_MIPMAP_LINEAR
+        } else {
+            gl::LINEAR
+        };
+        while gl.get_error() != gl::NO_ERROR {}
+        let texture_ids = gl.gen_textures(1);
+        gl.bind_texture(gl::TEXTURE_2D, texture_ids[0]);
+        self.interop.tex_storage(
+            &**gl,
+            mip_levels as gl::GLsizei,
+            format.internal_format as gl::GLenum,
+            width as gl::GLsizei,
+            height as gl::GLsizei,
+            gl_memory,
+        );
+        gl.tex_parameter_i(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MAG_FILTER,
+            gl::LINEAR as gl::GLint,
+        );
+        gl.tex_parameter_i(
+            gl::TEXTURE_2D,
+            gl::TEXTURE_MIN_FILTER,
+            min_filter as gl::GLint,
+        );
+        gl.bind_texture(gl::TEXTURE_2D, 0);
+        let error = gl.get_error();
+        if error != gl::NO_ERROR {
+            gl.delete_textures(&texture_ids);
+            self.release_image(&slot_image);
+            return Err(format!(
+                "Importing the Vulkan image into GL failed (GL error 0x{error:04x})"
+            ));
+        }
+
+        let mut generation = self.generation.get().wrapping_add(1);
+        if generation == 0 {
+            generation = 1;
+        }
+        self.generation.set(generation);
+        self.exports.set(
+            slot,
+            XianWebEngineVulkanSlot {
+                memory_handle: platform::handle_to_u64(consumer_memory),
+                allocation_size: slot_image.image.allocation_size,
+                ready_semaphore: platform::handle_to_u64(self.sync[slot].ready.consumer),
+                release_semaphore: platform::handle_to_u64(self.sync[slot].release.consumer),
+                handle_type: VK_HANDLE_TYPE,
+                vk_format,
+                width,
+                height,
+                mip_levels,
+                memory_type_index: slot_image.image.memory_type_index,
+                generation,
+                reserved: 0,
+            },
+        );
+        Ok((texture_ids[0], slot_image))
+    }
+
+    /// ### English
+    /// Deletes the GL memory object, the Vulkan image and the consumer handle of a slot image.
+    ///
+    /// #### Parameters
+    /// - `image`: Slot image to release.
+    ///
+    /// ### 中文
+    /// 删除槽位 image 的 GL memory object、Vulkan image 与消费者句柄。
+    ///
+    /// #### 参数
+    /// - `image`：要释放的槽位 image。
+    fn release_image(&self, image: &SlotImage) {
+        self.interop.delete_memory(image.gl_memory);
+        self.vk.destroy_image(&image.image);
+        platform::close_handle(image.consumer_memory);
+    }
+}
+
+impl RenderBackend for VulkanBackend {
+    /// ### English
+    /// Allocates an exported Vulkan image for `slot` and returns the GL texture aliasing it.
+    ///
+    /// ### 中文
+    /// 为 `slot` 分配导出的 Vulkan image，并返回与之共享存储的 GL 纹理。
+    fn allocate_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        slot: usize,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String> {
+        let (texture_id, image) = self.create_slot_image(gl, slot, size, format, mipmaps)?;
+        if let Some(old) = self.images.borrow_mut()[slot].replace(image) {
+            self.release_image(&old);
+        }
+        Ok(texture_id)
+    }
+
+    /// ### English
+    /// Reallocates the slot image (external-memory textures are immutable) and returns the new texture.
+    ///
+    /// ### 中文
+    /// 重新分配槽位 image（外部内存纹理不可变），并返回新的纹理。
+    fn resize_texture(
+        &self,
+        gl: &Rc<dyn Gl>,
+        slot: usize,
+        texture_id: gl::GLuint,
+        size: PhysicalSize<u32>,
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<gl::GLuint, String> {
+        let new_texture_id = self.allocate_texture(gl, slot, size, format, mipmaps)?;
+        gl.delete_textures(&[texture_id]);
+        Ok(new_texture_id)
+    }
+
+    /// ### English
+    /// Deletes the GL texture and the Vulkan image behind it.
+    ///
+    /// ### 中文
+    /// 删除 GL 纹理及其背后的 Vulkan image。
+    fn delete_texture(&self, gl: &Rc<dyn Gl>, slot: usize, texture_id: gl::GLuint) {
+        gl.delete_textures(&[texture_id]);
+        if let Some(image) = self.images.borrow_mut()[slot].take() {
+            self.release_image(&image);
+        }
+        self.exports.set(slot, XianWebEngineVulkanSlot::default());
+    }
+
+    /// ### English
+    /// Signals the slot's `ready` semaphore (consuming a stale signal first) and flushes.
+    ///
+    /// ### 中文
+    /// signal 槽位的 `ready` semaphore（必要时先消耗之前未被等待的 signal），然后 flush。
+    fn signal_frame(
+        &self,
+        glow: &glow::Context,
+        slot: usize,
+        texture_id: gl::GLuint,
+        producer_fence: bool,
+    ) -> u64 {
+        let sync = &self.sync[slot];
+        if sync.ready_pending.replace(true) {
+            self.interop.wait(sync.ready.gl, texture_id);
+        }
+        self.interop.signal(sync.ready.gl, texture_id);
+
+        let fence = if producer_fence { fence_sync(glow) } else { 0 };
+        if fence == 0 {
+            unsafe {
+                glow.flush();
+            }
+        }
+        fence
+    }
+
+    /// ### English
+    /// Deletes the producer `GLsync`.
+    ///
+    /// ### 中文
+    /// 删除生产者 `GLsync`。
+    fn delete_producer_fence(&self, glow: &glow::Context, fence: u64) {
+        delete_sync(glow, fence);
+    }
+
+    /// ### English
+    /// Makes GL wait on the `release` semaphore for `XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE`;
+    /// polls other values as `GLsync` (GL consumers of the same view).
+    ///
+    /// ### 中文
+    /// 对 `XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE`，让 GL 等待 `release` semaphore；
+    /// 其他值按 `GLsync` 轮询（同一 view 的 GL 消费者）。
+    fn consumer_done(
+        &self,
+        glow: &glow::Context,
+        slot: usize,
+        texture_id: gl::GLuint,
+        fence: u64,
+    ) -> bool {
+        if fence != XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE {
+            return sync_signaled(glow, fence);
+        }
+        let sync = &self.sync[slot];
+        self.interop.wait(sync.release.gl, texture_id);
+        sync.ready_pending.set(false);
+        true
+    }
+
+    /// ### English
+    /// Deletes a consumer `GLsync` (the release-semaphore marker owns nothing).
+    ///
+    /// ### 中文
+    /// 删除 consumer `GLsync`（release semaphore 标记值不持有任何资源）。
+    fn delete_consumer_fence(&self, glow: &glow::Context, fence: u64) {
+        if fence != XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE {
+            delete_sync(glow, fence);
+        }
+    }
+
+    /// ### English
+    /// Each slot owns exactly one exported image, so slot textures are never pooled.
+    ///
+    /// ### 中文
+    /// 每个槽位只拥有一个导出的 image，因此槽位纹理从不池化。
+    fn pools_textures(&self) -> bool {
+        false
+    }
+}
+
+impl Drop for VulkanBackend {
+    /// ### English
+    /// Releases remaining slot images and the slot semaphores (the shared context must be current).
+    ///
+    /// ### 中文
+    /// 释放剩余的槽位 image 与槽位 semaphore（共享上下文必须为 current）。
+    fn drop(&mut self) {
+        let images = std::mem::take(self.images.get_mut());
+        for (slot, image) in images.into_iter().enumerate() {
+            if let Some(image) = image {
+                self.release_image(&image);
+            }
+            self.exports.set(slot, XianWebEngineVulkanSlot::default());
+        }
+        for sync in &self.sync {
+            sync.ready.destroy(&self.vk, &self.interop);
+            sync.release.destroy(&self.vk, &self.interop);
+        }
+    }
+}
+
+/// ### English
+/// Returns the `VkFormat` matching the slot texture storage.
+///
+/// #### Parameters
+/// - `format`: Color texture formats.
+///
+/// ### 中文
+/// 返回与槽位纹理存储对应的 `VkFormat`。
+///
+/// #### 参数
+/// - `format`：颜色纹理格式。
+fn vk_format(format: SlotTextureFormat) -> Result<u32, String> {
+    match format.internal_format as gl::GLenum {
+        gl::RGBA8 => Ok(VK_FORMAT_R8G8B8A8_UNORM),
+        gl::SRGB8_ALPHA8 => Ok(VK_FORMAT_R8G8B8A8_SRGB),
+        gl::RGBA16F => Ok(VK_FORMAT_R16G16B16A16_SFLOAT),
+        other => Err(format!(
+            "Color format 0x{other:04x} cannot be exported to Vulkan"
+        )),
+    }
+}
@@ -0,0 +1,138 @@
+//! ### English
+//! POSIX file-descriptor flavour of Vulkan/GL external handles (`OPAQUE_FD`).
+//!
+//! ### 中文
+//! Vulkan/GL 外部句柄的 POSIX 文件描述符实现（`OPAQUE_FD`）。
+
+unsafe extern "C" {
+    /// ### English
+    /// Closes a file descriptor; returns `0` on success.
+    ///
+    /// ### 中文
+    /// 关闭文件描述符；成功时返回 `0`。
+    fn close(fd: i32) -> i32;
+}
+
+/// ### English
+/// Native external handle (a file descriptor).
+///
+/// ### 中文
+/// 原生外部句柄（文件描述符）。
+pub(super) type ExternalHandle = i32;
+
+/// ### English
+/// Value of an invalid handle.
+///
+/// ### 中文
+/// 无效句柄的值。
+pub(super) const INVALID_HANDLE: ExternalHandle = -1;
+
+/// ### English
+/// `VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_BIT` / `VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT`.
+///
+/// ### 中文
+/// `VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_BIT` / `VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT`。
+pub(super) const VK_HANDLE_TYPE: u32 = 0x1;
+
+/// ### English
+/// `VK_STRUCTURE_TYPE_MEMORY_GET_FD_INFO_KHR`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_MEMORY_GET_FD_INFO_KHR`。
+pub(super) const VK_STRUCTURE_TYPE_MEMORY_GET_HANDLE_INFO: i32 = 1_000_074_002;
+
+/// ### English
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_GET_FD_INFO_KHR`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_GET_FD_INFO_KHR`。
+pub(super) const VK_STRUCTURE_TYPE_SEMAPHORE_GET_HANDLE_INFO: i32 = 1_000_079_001;
+
+/// ### English
+/// Device-level command exporting a memory handle.
+///
+/// ### 中文
+/// 导出内存句柄的 device 级命令。
+pub(super) const VK_GET_MEMORY_HANDLE: &std::ffi::CStr = c"vkGetMemoryFdKHR";
+
+/// ### English
+/// Device-level command exporting a semaphore handle.
+///
+/// ### 中文
+/// 导出 semaphore 句柄的 device 级命令。
+pub(super) const VK_GET_SEMAPHORE_HANDLE: &std::ffi::CStr = c"vkGetSemaphoreFdKHR";
+
+/// ### English
+/// `GL_HANDLE_TYPE_OPAQUE_FD_EXT`.
+///
+/// ### 中文
+/// `GL_HANDLE_TYPE_OPAQUE_FD_EXT`。
+pub(super) const GL_HANDLE_TYPE: u32 = 0x9586;
+
+/// ### English
+/// GL extensions required to import the platform handles.
+///
+/// ### 中文
+/// 导入平台句柄所需的 GL 扩展。
+pub(super) const GL_HANDLE_EXTENSIONS: [&str; 2] =
+    ["GL_EXT_memory_object_fd", "GL_EXT_semaphore_fd"];
+
+/// ### English
+/// GL command importing a memory handle.
+///
+/// ### 中文
+/// 导入内存句柄的 GL 命令。
+pub(super) const GL_IMPORT_MEMORY: &std::ffi::CStr = c"glImportMemoryFdEXT";
+
+/// ### English
+/// GL command importing a semaphore handle.
+///
+/// ### 中文
+/// 导入 semaphore 句柄的 GL 命令。
+pub(super) const GL_IMPORT_SEMAPHORE: &std::ffi::CStr = c"glImportSemaphoreFdEXT";
+
+/// ### English
+/// Called after GL imported `handle`: GL takes ownership of imported file descriptors, so nothing is
+/// left to close.
+///
+/// #### Parameters
+/// - `handle`: Imported handle.
+///
+/// ### 中文
+/// GL 导入 `handle` 之后调用：GL 会接管导入的文件描述符，因此无需关闭。
+///
+/// #### 参数
+/// - `handle`：已导入的句柄。
+pub(super) fn after_gl_import(_handle: ExternalHandle) {}
+
+/// ### English
+/// Closes a handle owned by the engine.
+///
+/// #### Parameters
+/// - `handle`: Handle to close (`INVALID_HANDLE` is ignored).
+///
+/// ### 中文
+/// 关闭引擎持有的句柄。
+///
+/// #### 参数
+/// - `handle`：要关闭的句柄（`INVALID_HANDLE` 会被忽略）。
+pub(super) fn close_handle(handle: ExternalHandle) {
+    if handle != INVALID_HANDLE {
+        unsafe { close(handle) };
+    }
+}
+
+/// ### English
+/// Widens a handle to the `u64` published to the embedder.
+///
+/// #### Parameters
+/// - `handle`: Native handle.
+///
+/// ### 中文
+/// 将句柄扩展为发布给宿主的 `u64`。
+///
+/// #### 参数
+/// - `handle`：原生句柄。
+pub(super) fn handle_to_u64(handle: ExternalHandle) -> u64 {
+    handle as u32 as u64
+}
@@ -0,0 +1,144 @@
+//! ### English
+//! Win32 NT-handle flavour of Vulkan/GL external handles (`OPAQUE_WIN32`).
+//!
+//! ### 中文
+//! Vulkan/GL 外部句柄的 Win32 NT 句柄实现（`OPAQUE_WIN32`）。
+
+use std::ffi::c_void;
+
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    /// ### English
+    /// Closes a kernel handle; returns non-zero on success.
+    ///
+    /// ### 中文
+    /// 关闭内核句柄；成功时返回非 0。
+    fn CloseHandle(handle: *mut c_void) -> i32;
+}
+
+/// ### English
+/// Native external handle (an NT `HANDLE`).
+///
+/// ### 中文
+/// 原生外部句柄（NT `HANDLE`）。
+pub(super) type ExternalHandle = *mut c_void;
+
+/// ### English
+/// Value of an invalid handle.
+///
+/// ### 中文
+/// 无效句柄的值。
+pub(super) const INVALID_HANDLE: ExternalHandle = std::ptr::null_mut();
+
+/// ### English
+/// `VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT` /
+/// `VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT`.
+///
+/// ### 中文
+/// `VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT` /
+/// `VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT`。
+pub(super) const VK_HANDLE_TYPE: u32 = 0x2;
+
+/// ### English
+/// `VK_STRUCTURE_TYPE_MEMORY_GET_WIN32_HANDLE_INFO_KHR`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_MEMORY_GET_WIN32_HANDLE_INFO_KHR`。
+pub(super) const VK_STRUCTURE_TYPE_MEMORY_GET_HANDLE_INFO: i32 = 1_000_073_003;
+
+/// ### English
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR`.
+///
+/// ### 中文
+/// `VK_STRUCTURE_TYPE_SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR`。
+pub(super) const VK_STRUCTURE_TYPE_SEMAPHORE_GET_HANDLE_INFO: i32 = 1_000_078_003;
+
+/// ### English
+/// Device-level command exporting a memory handle.
+///
+/// ### 中文
+/// 导出内存句柄的 device 级命令。
+pub(super) const VK_GET_MEMORY_HANDLE: &std::ffi::CStr = c"vkGetMemoryWin32HandleKHR";
+
+/// ### English
+/// Device-level command exporting a semaphore handle.
+///
+/// ### 中文
+/// 导出 semaphore 句柄的 device 级命令。
+pub(super) const VK_GET_SEMAPHORE_HANDLE: &std::ffi::CStr = c"vkGetSemaphoreWin32HandleKHR";
+
+/// ### English
+/// `GL_HANDLE_TYPE_OPAQUE_WIN32_EXT`.
+///
+/// ### 中文
+/// `GL_HANDLE_TYPE_OPAQUE_WIN32_EXT`。
+pub(super) const GL_HANDLE_TYPE: u32 = 0x9587;
+
+/// ### English
+/// GL extensions required to import the platform handles.
+///
+/// ### 中文
+/// 导入平台句柄所需的 GL 扩展。
+pub(super) const GL_HANDLE_EXTENSIONS: [&str; 2] =
+    ["GL_EXT_memory_object_win32", "GL_EXT_semaphore_win32"];
+
+/// ### English
+/// GL command importing a memory handle.
+///
+/// ### 中文
+/// 导入内存句柄的 GL 命令。
+pub(super) const GL_IMPORT_MEMORY: &std::ffi::CStr = c"glImportMemoryWin32HandleEXT";
+
+/// ### English
+/// GL command importing a semaphore handle.
+///
+/// ### 中文
+/// 导入 semaphore 句柄的 GL 命令。
+pub(super) const GL_IMPORT_SEMAPHORE: &std::ffi::CStr = c"glImportSemaphoreWin32HandleEXT";
+
+/// ### English
+/// Called after GL imported `handle`: Win32 imports do not take ownership, so the handle is closed.
+///
+/// #### Parameters
+/// - `handle`: Imported handle.
+///
+/// ### 中文
+/// GL 导入 `handle` 之后调用：Win32 导入不会接管句柄，因此在此关闭。
+///
+/// #### 参数
+/// - `handle`：已导入的句柄。
+pub(super) fn after_gl_import(handle: ExternalHandle) {
+    close_handle(handle);
+}
+
+/// ### English
+/// Closes a handle owned by the engine.
+///
+/// #### Parameters
+/// - `handle`: Handle to close (`INVALID_HANDLE` is ignored).
+///
+/// ### 中文
+/// 关闭引擎持有的句柄。
+///
+/// #### 参数
+/// - `handle`：要关闭的句柄（`INVALID_HANDLE` 会被忽略）。
+pub(super) fn close_handle(handle: ExternalHandle) {
+    if handle != INVALID_HANDLE {
+        unsafe { CloseHandle(handle) };
+    }
+}
+
+/// ### English
+/// Widens a handle to the `u64` published to the embedder.
+///
+/// #### Parameters
+/// - `handle`: Native handle.
+///
+/// ### 中文
+/// 将句柄扩展为发布给宿主的 `u64`。
+///
+/// #### 参数
+/// - `handle`：原生句柄。
+pub(super) fn handle_to_u64(handle: ExternalHandle) -> u64 {
+    handle as usize as u64
+}
@@ -1,14 +1,20 @@
 //! ### English
 //! Rendering module entry point.
 //!
-//! Splits the shared GLFW context and the triple-buffered rendering context into submodules.
+//! Splits the shared GLFW context, the render backends and the triple-buffered rendering context
+//! into submodules.
 //!
 //! ### 中文
 //! 渲染模块入口。
 //!
-//! 将共享 GLFW 上下文与三缓冲渲染上下文拆分到子模块。
+//! 将共享 GLFW 上下文、渲染后端与三缓冲渲染上下文拆分到子模块。
+mod backend;
 mod shared_context;
 mod triple_buffer;
 
+pub use backend::{
+    EmbedderVulkanDevice, VulkanFrameExports, XianWebEngineVulkanSlot,
+    install_embedder_vulkan_device, vulkan_device_installed,
+};
 pub use shared_context::GlfwSharedContext;
 pub use triple_buffer::{GlfwTripleBufferContextInit, GlfwTripleBufferRenderingContext};
@@ -1,15 +1,17 @@
 //! ### English
-//! Shared GLFW OpenGL context wrapper.
+//! Shared GLFW/EGL OpenGL context wrapper.
 //!
 //! Creates an offscreen shared context so the Servo thread can render into textures that the
-//! Java/GLFW context can sample.
+//! Java/GLFW context can sample. GLES-only hosts (ANGLE, ARM drivers) use an EGL context instead
+//! of a hidden GLFW window.
 //!
 //! ### 中文
-//! 共享 GLFW OpenGL 上下文封装。
+//! 共享 GLFW/EGL OpenGL 上下文封装。
 //!
-//! 创建离屏共享上下文，使 Servo 线程能渲染到纹理，供 Java/GLFW 上下文采样。
+//! 创建离屏共享上下文，使 Servo 线程能渲染到纹理，供 Java/GLFW 上下文采样。仅支持 GLES 的宿主
+//! （ANGLE、ARM 驱动）使用 EGL 上下文代替隐藏的 GLFW window。
 use std::cell::Cell;
-use std::ffi::{CStr, c_void};
+use std::ffi::{CStr, c_char, c_void};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -17,7 +19,17 @@ use gleam::gl::{self, Gl};
 use glow::HasContext as _;
 use surfman::Connection;
 
+use crate::engine::egl::{EglApi, EglOffscreenContext};
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_ENGINE_FLAG_EGL, XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG,
+    XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL,
+};
 use crate::engine::glfw;
+use crate::engine::log::{
+    XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG, XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+    XIAN_WEB_ENGINE_LOG_LEVEL_INFO, XIAN_WEB_ENGINE_LOG_LEVEL_WARN, log, log_enabled,
+};
+use crate::engine::watchdog::{GlErrorLog, XIAN_WEB_ENGINE_GL_OP_READ_PIXELS, gl_op_name};
 
 /// ### English
 /// Parses an OpenGL version string and returns `(major, minor)`.
@@ -60,67 +72,323 @@ fn parse_gl_version(version: &str) -> (u32, u32) {
     (major, minor)
 }
 
-thread_local! {
-    static CURRENT_GLFW_WINDOW: Cell<glfw::GlfwWindowPtr> =
-        const { Cell::new(std::ptr::null_mut()) };
+/// ### English
+/// `GL_CONTEXT_FLAG_DEBUG_BIT` in `GL_CONTEXT_FLAGS`.
+///
+/// ### 中文
+/// `GL_CONTEXT_FLAGS` 中的 `GL_CONTEXT_FLAG_DEBUG_BIT`。
+const GL_CONTEXT_FLAG_DEBUG_BIT: i32 = 0x0000_0002;
+
+/// ### English
+/// `GLDEBUGPROC`.
+///
+/// ### 中文
+/// `GLDEBUGPROC`。
+type GlDebugProc = extern "system" fn(
+    source: u32,
+    kind: u32,
+    id: u32,
+    severity: u32,
+    length: i32,
+    message: *const c_char,
+    user_param: *mut c_void,
+);
+
+/// ### English
+/// `glDebugMessageCallback` / `glDebugMessageCallbackKHR`.
+///
+/// ### 中文
+/// `glDebugMessageCallback` / `glDebugMessageCallbackKHR`。
+type GlDebugMessageCallbackFn =
+    unsafe extern "system" fn(callback: Option<GlDebugProc>, user_param: *const c_void);
+
+/// ### English
+/// Forwards one `GL_KHR_debug` message to the log sink, mapping its severity to a log level.
+///
+/// #### Parameters
+/// - `source`: `GL_DEBUG_SOURCE_*`.
+/// - `kind`: `GL_DEBUG_TYPE_*`.
+/// - `id`: Driver-specific message id.
+/// - `severity`: `GL_DEBUG_SEVERITY_*`.
+/// - `length`: Message length in bytes (negative when NUL-terminated).
+/// - `message`: Message text.
+/// - `_user_param`: Unused.
+///
+/// ### 中文
+/// 将一条 `GL_KHR_debug` 消息转发给日志 sink，并把其严重级别映射为日志级别。
+///
+/// #### 参数
+/// - `source`：`GL_DEBUG_SOURCE_*`。
+/// - `kind`：`GL_DEBUG_TYPE_*`。
+/// - `id`：驱动相关的消息 id。
+/// - `severity`：`GL_DEBUG_SEVERITY_*`。
+/// - `length`：消息字节长度（以 NUL 结尾时为负数）。
+/// - `message`：消息文本。
+/// - `_user_param`：未使用。
+extern "system" fn forward_gl_debug_message(
+    source: u32,
+    kind: u32,
+    id: u32,
+    severity: u32,
+    length: i32,
+    message: *const c_char,
+    _user_param: *mut c_void,
+) {
+    let level = match severity {
+        glow::DEBUG_SEVERITY_HIGH => XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+        glow::DEBUG_SEVERITY_MEDIUM => XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+        glow::DEBUG_SEVERITY_LOW => XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+        _ => XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+    };
+    if !log_enabled(level) {
+        return;
+    }
+    let source = match source {
+        glow::DEBUG_SOURCE_API => "api",
+        glow::DEBUG_SOURCE_WINDOW_SYSTEM => "window-system",
+        glow::DEBUG_SOURCE_SHADER_COMPILER => "shader-compiler",
+        glow::DEBUG_SOURCE_THIRD_PARTY => "third-party",
+        glow::DEBUG_SOURCE_APPLICATION => "application",
+        _ => "other",
+    };
+    let kind = match kind {
+        glow::DEBUG_TYPE_ERROR => "error",
+        glow::DEBUG_TYPE_DEPRECATED_BEHAVIOR => "deprecated",
+        glow::DEBUG_TYPE_UNDEFINED_BEHAVIOR => "undefined-behavior",
+        glow::DEBUG_TYPE_PORTABILITY => "portability",
+        glow::DEBUG_TYPE_PERFORMANCE => "performance",
+        glow::DEBUG_TYPE_MARKER => "marker",
+        _ => "other",
+    };
+    let message = if message.is_null() {
+        std::borrow::Cow::Borrowed("")
+    } else if length >= 0 {
+        let bytes = unsafe { std::slice::from_raw_parts(message.cast::<u8>(), length as usize) };
+        String::from_utf8_lossy(bytes)
+    } else {
+        unsafe { CStr::from_ptr(message) }.to_string_lossy()
+    };
+    log(
+        level,
+        format_args!("GL debug [{source}/{kind} 0x{id:X}]: {message}"),
+    );
 }
 
-#[inline]
 /// ### English
-/// Destroys the offscreen GLFW window/context and clears the per-thread current cache if needed.
+/// Enables synchronous `GL_KHR_debug` output on the current context and routes it to the log
+/// sink.
 ///
-/// This assumes the caller is on the thread allowed to manipulate the GLFW context.
+/// Synchronous output delivers each message on the Servo thread inside the offending call, so
+/// log order matches GL call order. The callback is a plain function with no user data, so it
+/// needs no teardown before the context is destroyed. Logs a warning when the driver lacks
+/// `GL_KHR_debug`.
 ///
 /// #### Parameters
-/// - `glfw`: Loaded GLFW API table.
-/// - `window`: Offscreen GLFW window to destroy (NULL is a no-op).
+/// - `offscreen`: Current (debug) context, used to load the entry point.
+/// - `glow`: glow API of that context.
 ///
 /// ### 中文
-/// 销毁离屏 GLFW window/context，并在需要时清理“每线程 current 缓存”。
+/// 在当前上下文上启用同步 `GL_KHR_debug` 输出，并将其路由到日志 sink。
 ///
-/// 该函数假设调用方位于允许操作该 GLFW 上下文的线程上。
+/// 同步输出会在出错的调用内部、于 Servo 线程上投递每条消息，因此日志顺序与 GL 调用顺序一致。回调是
+/// 不带 user data 的普通函数，因此在销毁上下文前无需清理。若驱动不支持 `GL_KHR_debug`，则记录一条警告。
 ///
 /// #### 参数
-/// - `glfw`：已加载的 GLFW API 表。
-/// - `window`：要销毁的离屏 window（NULL 则无操作）。
-fn destroy_offscreen_window(glfw: &glfw::LoadedGlfwApi, window: glfw::GlfwWindowPtr) {
-    if window.is_null() {
+/// - `offscreen`：当前（debug）上下文，用于加载入口函数。
+/// - `glow`：该上下文的 glow API。
+fn install_gl_debug_output(offscreen: &OffscreenContext, glow: &glow::Context) {
+    let mut entry = std::ptr::null();
+    if glow.supports_debug() {
+        entry = unsafe { offscreen.get_proc_address(c"glDebugMessageCallback") };
+        if entry.is_null() {
+            entry = unsafe { offscreen.get_proc_address(c"glDebugMessageCallbackKHR") };
+        }
+    }
+    if entry.is_null() {
+        log(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            format_args!("GL debug output requested, but the driver lacks GL_KHR_debug"),
+        );
         return;
     }
-
+    let debug_message_callback =
+        unsafe { std::mem::transmute::<*const c_void, GlDebugMessageCallbackFn>(entry) };
     unsafe {
-        glfw.make_current(std::ptr::null_mut());
+        glow.enable(glow::DEBUG_OUTPUT);
+        glow.enable(glow::DEBUG_OUTPUT_SYNCHRONOUS);
+        debug_message_callback(Some(forward_gl_debug_message), std::ptr::null());
     }
+}
 
-    CURRENT_GLFW_WINDOW.with(|current| {
-        if current.get() == window {
-            current.set(std::ptr::null_mut());
+thread_local! {
+    static CURRENT_CONTEXT: Cell<*mut c_void> = const { Cell::new(std::ptr::null_mut()) };
+}
+
+/// ### English
+/// Offscreen context owned by `GlfwSharedContext`, created through GLFW or EGL.
+///
+/// Dropping it releases the context from the calling thread and destroys it, which assumes the
+/// caller is on the thread allowed to manipulate the context.
+///
+/// ### 中文
+/// 由 `GlfwSharedContext` 持有的离屏上下文，通过 GLFW 或 EGL 创建。
+///
+/// Drop 时会从调用线程解绑并销毁该上下文；假设调用方位于允许操作该上下文的线程上。
+enum OffscreenContext {
+    /// ### English
+    /// Invisible 1x1 GLFW window sharing objects with the embedder window.
+    ///
+    /// ### 中文
+    /// 与宿主 window 共享对象的不可见 1x1 GLFW window。
+    Glfw {
+        /// ### English
+        /// Loaded minimal GLFW API used for context control and proc loading.
+        ///
+        /// ### 中文
+        /// 已加载的最小 GLFW API：用于上下文控制与函数指针加载。
+        glfw: glfw::LoadedGlfwApi,
+        /// ### English
+        /// Offscreen GLFW window that owns the shared GL context.
+        ///
+        /// ### 中文
+        /// 持有共享 GL 上下文的离屏 GLFW window。
+        window: glfw::GlfwWindowPtr,
+    },
+    /// ### English
+    /// EGL context sharing objects with the embedder `EGLContext`.
+    ///
+    /// ### 中文
+    /// 与宿主 `EGLContext` 共享对象的 EGL 上下文。
+    Egl {
+        /// ### English
+        /// Installed EGL API used for context control and proc loading.
+        ///
+        /// ### 中文
+        /// 已安装的 EGL API：用于上下文控制与函数指针加载。
+        egl: EglApi,
+        /// ### English
+        /// Offscreen EGL context/surface pair.
+        ///
+        /// ### 中文
+        /// 离屏 EGL context/surface 对。
+        offscreen: EglOffscreenContext,
+    },
+}
+
+impl OffscreenContext {
+    /// ### English
+    /// Creates the offscreen context selected by `engine_flags` and sharing objects with `shared`.
+    ///
+    /// #### Parameters
+    /// - `shared`: Embedder `GLFWwindow*`, or `EGLContext` with `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`.
+    /// - `engine_flags`: Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 按 `engine_flags` 选择方式创建离屏上下文，并与 `shared` 共享对象。
+    ///
+    /// #### 参数
+    /// - `shared`：宿主 `GLFWwindow*`；设置 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 时为 `EGLContext`。
+    /// - `engine_flags`：引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+    fn create(shared: *mut c_void, engine_flags: u32) -> Result<Self, String> {
+        let debug = (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG) != 0;
+        if (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_EGL) != 0 {
+            let egl = EglApi::load()?;
+            let offscreen = unsafe { egl.create_shared_context(shared, debug)? };
+            Ok(Self::Egl { egl, offscreen })
+        } else {
+            let glfw = glfw::LoadedGlfwApi::load()?;
+            let window = unsafe {
+                glfw.create_shared_offscreen_window(shared as glfw::GlfwWindowPtr, debug)?
+            };
+            Ok(Self::Glfw { glfw, window })
         }
-    });
+    }
 
-    unsafe {
-        glfw.destroy_window(window);
+    /// ### English
+    /// Returns the pointer identifying this context in the per-thread current cache.
+    ///
+    /// ### 中文
+    /// 返回在“每线程 current 缓存”中标识该上下文的指针。
+    #[inline]
+    fn key(&self) -> *mut c_void {
+        match self {
+            Self::Glfw { window, .. } => window.cast(),
+            Self::Egl { offscreen, .. } => offscreen.context,
+        }
+    }
+
+    /// ### English
+    /// Makes this context current on the calling thread (uncached).
+    ///
+    /// ### 中文
+    /// 使该上下文在调用线程上变为 current（不经过缓存）。
+    #[inline]
+    unsafe fn bind(&self) {
+        match self {
+            Self::Glfw { glfw, window } => unsafe { glfw.make_current(*window) },
+            Self::Egl { egl, offscreen } => unsafe { egl.make_current(Some(offscreen)) },
+        }
+    }
+
+    /// ### English
+    /// Loads a GL/GLES function pointer through the context API.
+    ///
+    /// #### Parameters
+    /// - `name`: NUL-terminated proc name.
+    ///
+    /// ### 中文
+    /// 通过上下文 API 加载 GL/GLES 函数指针。
+    ///
+    /// #### 参数
+    /// - `name`：以 NUL 结尾的函数名。
+    #[inline]
+    unsafe fn get_proc_address(&self, name: &CStr) -> *const c_void {
+        match self {
+            Self::Glfw { glfw, .. } => unsafe { glfw.get_proc_address(name) },
+            Self::Egl { egl, .. } => unsafe { egl.get_proc_address(name) },
+        }
+    }
+}
+
+impl Drop for OffscreenContext {
+    /// ### English
+    /// Releases the context from the calling thread, clears the current cache if needed, and
+    /// destroys the context.
+    ///
+    /// ### 中文
+    /// 将上下文从调用线程解绑，必要时清理 current 缓存，并销毁该上下文。
+    fn drop(&mut self) {
+        let key = self.key();
+        match self {
+            Self::Glfw { glfw, .. } => unsafe { glfw.make_current(std::ptr::null_mut()) },
+            Self::Egl { egl, .. } => unsafe { egl.make_current(None) },
+        }
+
+        CURRENT_CONTEXT.with(|current| {
+            if current.get() == key {
+                current.set(std::ptr::null_mut());
+            }
+        });
+
+        match self {
+            Self::Glfw { glfw, window } => unsafe { glfw.destroy_window(*window) },
+            Self::Egl { egl, offscreen } => unsafe { egl.destroy_context(offscreen) },
+        }
     }
 }
 
 /// ### English
-/// Owns the shared offscreen GLFW window/context and the GL function loaders.
+/// Owns the shared offscreen context (GLFW window or EGL context) and the GL function loaders.
 ///
 /// ### 中文
-/// 持有离屏 GLFW window/context 以及 GL 函数加载器。
+/// 持有共享离屏上下文（GLFW window 或 EGL 上下文）以及 GL 函数加载器。
 pub struct GlfwSharedContext {
     /// ### English
-    /// Loaded minimal GLFW API used for context control and proc loading.
-    ///
-    /// ### 中文
-    /// 已加载的最小 GLFW API：用于上下文控制与函数指针加载。
-    glfw: glfw::LoadedGlfwApi,
-    /// ### English
-    /// Offscreen GLFW window that owns the shared GL context (current on Servo thread).
+    /// Offscreen context that owns the shared GL context (current on Servo thread).
     ///
     /// ### 中文
-    /// 持有共享 GL 上下文的离屏 GLFW window（在 Servo 线程 current）。
-    glfw_window: glfw::GlfwWindowPtr,
+    /// 持有共享 GL 上下文的离屏上下文（在 Servo 线程 current）。
+    offscreen: OffscreenContext,
     /// ### English
     /// gleam GL API wrapper used by Servo/WebRender.
     ///
@@ -145,62 +413,64 @@ pub struct GlfwSharedContext {
     /// ### 中文
     /// 是否支持 sRGB framebuffer/纹理格式。
     srgb_supported: bool,
+    /// ### English
+    /// Whether this is an OpenGL ES context.
+    ///
+    /// ### 中文
+    /// 是否为 OpenGL ES 上下文。
+    is_gles: bool,
+    /// ### English
+    /// Whether `glFenceSync` is available (desktop GL and GLES 3.0+).
+    ///
+    /// ### 中文
+    /// 是否可用 `glFenceSync`（桌面 GL 与 GLES 3.0+）。
+    sync_supported: bool,
+    /// ### English
+    /// Shard GL error log; `Some` only in paranoid GL mode.
+    ///
+    /// ### 中文
+    /// 分片的 GL 错误记录；仅 paranoid GL 模式下为 `Some`。
+    gl_errors: Option<Arc<GlErrorLog>>,
 }
 
 impl GlfwSharedContext {
     /// ### English
-    /// Creates an offscreen GLFW window that shares objects with `glfw_shared_window`.
+    /// Creates an offscreen context that shares objects with `shared_context`.
     /// Must be called from the thread that will own the GL context (Servo thread).
     ///
+    /// The context is a hidden GLFW window by default, or an EGL context when `engine_flags`
+    /// contains `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`. Debug contexts (requested through
+    /// `XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG` or inherited from the host) forward their
+    /// `GL_KHR_debug` messages to the log sink.
+    ///
+    /// #### Parameters
+    /// - `shared_context`: Embedder `GLFWwindow*`, or `EGLContext` in EGL mode.
+    /// - `engine_flags`: Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+    /// - `gl_errors`: Shard GL error log (used with `XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL`).
+    ///
     /// ### 中文
-    /// 创建一个与 `glfw_shared_window` 共享 GL 对象的离屏 GLFW window。
+    /// 创建一个与 `shared_context` 共享 GL 对象的离屏上下文。
     /// 必须在将要持有 GL 上下文的线程（Servo 线程）中调用。
-    pub fn new(glfw_shared_window: *mut c_void) -> Result<Rc<Self>, String> {
-        let glfw = glfw::LoadedGlfwApi::load()?;
-        let glfw_shared_window = glfw_shared_window as glfw::GlfwWindowPtr;
-
-        let glfw_window = unsafe { glfw.create_shared_offscreen_window(glfw_shared_window)? };
+    ///
+    /// 默认使用隐藏的 GLFW window；`engine_flags` 含 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 时使用 EGL 上下文。
+    /// debug 上下文（通过 `XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG` 请求或沿用自宿主）会将其
+    /// `GL_KHR_debug` 消息转发给日志 sink。
+    ///
+    /// #### 参数
+    /// - `shared_context`：宿主 `GLFWwindow*`；EGL 模式下为 `EGLContext`。
+    /// - `engine_flags`：引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+    /// - `gl_errors`：分片的 GL 错误记录（配合 `XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL` 使用）。
+    pub fn new(
+        shared_context: *mut c_void,
+        engine_flags: u32,
+        gl_errors: Arc<GlErrorLog>,
+    ) -> Result<Rc<Self>, String> {
+        let offscreen = OffscreenContext::create(shared_context, engine_flags)?;
 
         unsafe {
-            glfw.make_current(glfw_window);
-        }
-        CURRENT_GLFW_WINDOW.with(|current| current.set(glfw_window));
-
-        /// ### English
-        /// Drop guard that destroys the offscreen window/context if `new()` fails part-way through.
-        ///
-        /// ### 中文
-        /// 用于 `new()` 中途失败时的 drop guard：负责销毁离屏 window/context。
-        struct OffscreenWindowGuard {
-            /// ### English
-            /// Loaded GLFW API table used to destroy the window.
-            ///
-            /// ### 中文
-            /// 已加载的 GLFW API 表，用于销毁该 window。
-            glfw: glfw::LoadedGlfwApi,
-            /// ### English
-            /// Offscreen window to destroy (NULL means already handed off).
-            ///
-            /// ### 中文
-            /// 需要销毁的离屏 window（NULL 表示已移交）。
-            window: glfw::GlfwWindowPtr,
+            offscreen.bind();
         }
-
-        impl Drop for OffscreenWindowGuard {
-            /// ### English
-            /// Cleans up the offscreen window if initialization fails before it is handed off.
-            ///
-            /// ### 中文
-            /// 若初始化在移交前失败，则清理离屏 window。
-            fn drop(&mut self) {
-                destroy_offscreen_window(&self.glfw, self.window);
-            }
-        }
-
-        let mut offscreen_guard = OffscreenWindowGuard {
-            glfw,
-            window: glfw_window,
-        };
+        CURRENT_CONTEXT.with(|current| current.set(offscreen.key()));
 
         #[cold]
         #[inline(never)]
@@ -208,16 +478,16 @@ impl GlfwSharedContext {
         /// Loads a GL proc by allocating a temporary NUL-terminated buffer on the heap.
         ///
         /// #### Parameters
-        /// - `glfw`: Loaded GLFW API table.
+        /// - `offscreen`: Offscreen context whose API loads the proc.
         /// - `bytes`: Proc name bytes (must not contain NUL).
         ///
         /// ### 中文
         /// 通过在堆上临时分配 NUL 结尾缓冲区来加载 GL 函数指针。
         ///
         /// #### 参数
-        /// - `glfw`：已加载的 GLFW API 表。
+        /// - `offscreen`：用于加载函数指针的离屏上下文。
         /// - `bytes`：函数名字节（不得包含 NUL）。
-        fn load_gl_proc_heap(glfw: &glfw::LoadedGlfwApi, bytes: &[u8]) -> *const c_void {
+        fn load_gl_proc_heap(offscreen: &OffscreenContext, bytes: &[u8]) -> *const c_void {
             if bytes.contains(&0) {
                 panic!("gl proc name contains NUL");
             }
@@ -225,7 +495,7 @@ impl GlfwSharedContext {
             buf.extend_from_slice(bytes);
             buf.push(0);
             let cstr = unsafe { CStr::from_bytes_with_nul_unchecked(&buf) };
-            unsafe { glfw.get_proc_address(cstr) }
+            unsafe { offscreen.get_proc_address(cstr) }
         }
 
         #[inline]
@@ -233,16 +503,16 @@ impl GlfwSharedContext {
         /// Loads a GL proc using a stack buffer when possible (falls back to heap for long names).
         ///
         /// #### Parameters
-        /// - `glfw`: Loaded GLFW API table.
+        /// - `offscreen`: Offscreen context whose API loads the proc.
         /// - `name`: Proc name (ASCII, must not contain NUL).
         ///
         /// ### 中文
         /// 尽可能使用栈缓冲区加载 GL 函数指针（名称过长时回退到堆分配）。
         ///
         /// #### 参数
-        /// - `glfw`：已加载的 GLFW API 表。
+        /// - `offscreen`：用于加载函数指针的离屏上下文。
         /// - `name`：函数名（ASCII，不得包含 NUL）。
-        fn load_gl_proc(glfw: &glfw::LoadedGlfwApi, name: &str) -> *const c_void {
+        fn load_gl_proc(offscreen: &OffscreenContext, name: &str) -> *const c_void {
             const STACK_BUF_SIZE: usize = 128;
 
             let bytes = name.as_bytes();
@@ -254,13 +524,14 @@ impl GlfwSharedContext {
                 buf[..bytes.len()].copy_from_slice(bytes);
                 buf[bytes.len()] = 0;
                 let cstr = unsafe { CStr::from_bytes_with_nul_unchecked(&buf[..bytes.len() + 1]) };
-                unsafe { glfw.get_proc_address(cstr) }
+                unsafe { offscreen.get_proc_address(cstr) }
             } else {
-                load_gl_proc_heap(glfw, bytes)
+                load_gl_proc_heap(offscreen, bytes)
             }
         }
 
-        let glow = unsafe { glow::Context::from_loader_function(|name| load_gl_proc(&glfw, name)) };
+        let glow =
+            unsafe { glow::Context::from_loader_function(|name| load_gl_proc(&offscreen, name)) };
 
         let gl_version = unsafe { glow.get_parameter_string(glow::VERSION) };
         let is_gles = gl_version.starts_with("OpenGL ES");
@@ -270,26 +541,41 @@ impl GlfwSharedContext {
         } else {
             major >= 3 || (major == 2 && minor >= 1)
         };
+        let sync_supported = !is_gles || major >= 3;
+
+        let has_context_flags = if is_gles {
+            (major, minor) >= (3, 2)
+        } else {
+            major >= 3
+        };
+        let debug_context = has_context_flags
+            && unsafe { glow.get_parameter_i32(glow::CONTEXT_FLAGS) } & GL_CONTEXT_FLAG_DEBUG_BIT
+                != 0;
+        if debug_context || (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG) != 0 {
+            install_gl_debug_output(&offscreen, &glow);
+        }
 
         let gl: Rc<dyn Gl> = unsafe {
             if is_gles {
-                gl::GlesFns::load_with(|name| load_gl_proc(&glfw, name))
+                gl::GlesFns::load_with(|name| load_gl_proc(&offscreen, name))
             } else {
-                gl::GlFns::load_with(|name| load_gl_proc(&glfw, name))
+                gl::GlFns::load_with(|name| load_gl_proc(&offscreen, name))
             }
         };
 
         let surfman_connection = Connection::new()
             .map_err(|err| format!("Failed to create surfman Connection: {err:?}"))?;
-        offscreen_guard.window = std::ptr::null_mut();
 
         Ok(Rc::new(Self {
-            glfw,
-            glfw_window,
+            offscreen,
             gl,
             glow: Arc::new(glow),
             surfman_connection,
             srgb_supported,
+            is_gles,
+            sync_supported,
+            gl_errors: ((engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL) != 0)
+                .then_some(gl_errors),
         }))
     }
 
@@ -300,15 +586,16 @@ impl GlfwSharedContext {
     /// 使该共享上下文在调用线程上变为 current。
     #[inline]
     pub(in crate::engine::rendering) fn make_current(&self) {
-        CURRENT_GLFW_WINDOW.with(|current| {
-            if current.get() == self.glfw_window {
+        let key = self.offscreen.key();
+        CURRENT_CONTEXT.with(|current| {
+            if current.get() == key {
                 return;
             }
 
             unsafe {
-                self.glfw.make_current(self.glfw_window);
+                self.offscreen.bind();
             }
-            current.set(self.glfw_window);
+            current.set(key);
         });
     }
 
@@ -351,15 +638,226 @@ impl GlfwSharedContext {
     pub(in crate::engine::rendering) fn supports_srgb(&self) -> bool {
         self.srgb_supported
     }
-}
 
-impl Drop for GlfwSharedContext {
     /// ### English
-    /// Ensures the offscreen window/context is destroyed on drop.
+    /// Looks up a GL/extension entry point on this context (NULL if unavailable).
+    ///
+    /// #### Parameters
+    /// - `name`: Entry point name.
     ///
     /// ### 中文
-    /// Drop 时销毁离屏 window/context。
-    fn drop(&mut self) {
-        destroy_offscreen_window(&self.glfw, self.glfw_window);
+    /// 在该上下文上查询 GL/扩展入口函数（不可用时返回 NULL）。
+    ///
+    /// #### 参数
+    /// - `name`：入口函数名。
+    #[inline]
+    pub(in crate::engine::rendering) fn proc_address(&self, name: &CStr) -> *const c_void {
+        unsafe { self.offscreen.get_proc_address(name) }
+    }
+
+    /// ### English
+    /// Returns whether this is an OpenGL ES context.
+    ///
+    /// ### 中文
+    /// 返回是否为 OpenGL ES 上下文。
+    #[inline]
+    pub(in crate::engine::rendering) fn is_gles(&self) -> bool {
+        self.is_gles
+    }
+
+    /// ### English
+    /// Returns whether `GLsync` fences are available (desktop GL, GLES 3.0+).
+    ///
+    /// ### 中文
+    /// 返回是否可用 `GLsync` fence（桌面 GL、GLES 3.0+）。
+    #[inline]
+    pub(in crate::engine::rendering) fn supports_sync(&self) -> bool {
+        self.sync_supported
+    }
+
+    /// ### English
+    /// Returns whether the context advertises the GL extension `name`.
+    ///
+    /// #### Parameters
+    /// - `name`: Extension name (e.g. `GL_EXT_texture_format_BGRA8888`).
+    ///
+    /// ### 中文
+    /// 返回上下文是否声明支持 GL 扩展 `name`。
+    ///
+    /// #### 参数
+    /// - `name`：扩展名（例如 `GL_EXT_texture_format_BGRA8888`）。
+    #[inline]
+    pub(in crate::engine::rendering) fn has_extension(&self, name: &str) -> bool {
+        self.glow.supported_extensions().contains(name)
+    }
+
+    /// ### English
+    /// Returns whether paranoid GL mode is on (errors are checked after every operation).
+    ///
+    /// ### 中文
+    /// 返回是否开启 paranoid GL 模式（每次操作后检查错误）。
+    #[inline]
+    pub(in crate::engine::rendering) fn paranoid_gl(&self) -> bool {
+        self.gl_errors.is_some()
+    }
+
+    /// ### English
+    /// Drains the GL error queue and returns the first error (`0` = none).
+    ///
+    /// Drivers may queue several error flags; all are cleared so the next check only sees new ones.
+    ///
+    /// ### 中文
+    /// 清空 GL 错误队列并返回第一个错误（`0` 表示无）。
+    ///
+    /// 驱动可能排队多个错误标志；这里会全部清除，使下一次检查只看到新的错误。
+    pub(in crate::engine::rendering) fn take_gl_error(&self) -> u32 {
+        let first = unsafe { self.glow.get_error() };
+        if first != glow::NO_ERROR {
+            for _ in 0..16 {
+                if unsafe { self.glow.get_error() } == glow::NO_ERROR {
+                    break;
+                }
+            }
+        }
+        first
+    }
+
+    /// ### English
+    /// Records the first GL error of a frame in the shard log and reports it to the log sink.
+    ///
+    /// #### Parameters
+    /// - `op`: `XIAN_WEB_ENGINE_GL_OP_*` of the operation that raised it.
+    /// - `error`: `glGetError` value.
+    ///
+    /// ### 中文
+    /// 将一帧中的第一个 GL 错误记录到分片日志，并上报给日志 sink。
+    ///
+    /// #### 参数
+    /// - `op`：触发该错误的操作（`XIAN_WEB_ENGINE_GL_OP_*`）。
+    /// - `error`：`glGetError` 返回值。
+    pub(in crate::engine::rendering) fn record_gl_error(&self, op: u32, error: u32) {
+        let Some(gl_errors) = &self.gl_errors else {
+            return;
+        };
+        gl_errors.record(op, error);
+        log(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            format_args!("GL error 0x{error:04X} in {}", gl_op_name(op)),
+        );
+    }
+
+    /// ### English
+    /// Reads an RGBA8 copy of an embedder texture (rows top to bottom), or `None` if the texture
+    /// cannot be attached or read (for example because it was deleted).
+    ///
+    /// The texture is attached to a temporary read framebuffer; the previous read framebuffer binding
+    /// is restored so WebRender's cached GL state stays valid. When `fence` is set, the GPU waits for
+    /// the embedder's rendering before the read and the fence is deleted afterwards (even on failure).
+    ///
+    /// #### Parameters
+    /// - `texture`: GL texture name (`GL_TEXTURE_2D`, shared with this context).
+    /// - `width`: Texture width in pixels.
+    /// - `height`: Texture height in pixels.
+    /// - `flip_y`: Whether row 0 of the texture is the bottom row (render targets) and must be
+    ///   flipped.
+    /// - `fence`: Embedder `GLsync` (cast to `u64`) signaled after rendering into the texture, or `0`.
+    ///
+    /// ### 中文
+    /// 读取宿主纹理的 RGBA8 副本（行序自上而下）；若纹理无法附加或读取（例如已被删除）则返回 `None`。
+    ///
+    /// 纹理会被附加到临时的读 framebuffer；之后恢复原先的读 framebuffer 绑定，使 WebRender 缓存的 GL
+    /// 状态保持有效。设置 `fence` 时，GPU 会在读取前等待宿主的渲染完成，之后删除该 fence（失败时也会删除）。
+    ///
+    /// #### 参数
+    /// - `texture`：GL 纹理名（`GL_TEXTURE_2D`，与该上下文共享）。
+    /// - `width`：纹理宽度（像素）。
+    /// - `height`：纹理高度（像素）。
+    /// - `flip_y`：纹理第 0 行是否为底行（渲染目标），需要翻转。
+    /// - `fence`：宿主在渲染纹理后创建的 `GLsync`（转为 `u64`），或 `0`。
+    pub fn read_texture(
+        &self,
+        texture: u32,
+        width: u32,
+        height: u32,
+        flip_y: bool,
+        fence: u64,
+    ) -> Option<Vec<u8>> {
+        self.make_current();
+        let gl = &self.gl;
+        let _ = self.take_gl_error();
+        if fence != 0 {
+            let sync = glow::NativeFence(fence as usize as *mut _);
+            unsafe {
+                self.glow.wait_sync(sync, 0, glow::TIMEOUT_IGNORED);
+                self.glow.delete_sync(sync);
+            }
+        }
+
+        let mut previous = [0];
+        unsafe { gl.get_integer_v(gl::READ_FRAMEBUFFER_BINDING, &mut previous) };
+        let framebuffer = gl.gen_framebuffers(1)[0];
+        gl.bind_framebuffer(gl::READ_FRAMEBUFFER, framebuffer);
+        gl.framebuffer_texture_2d(
+            gl::READ_FRAMEBUFFER,
+            gl::COLOR_ATTACHMENT0,
+            gl::TEXTURE_2D,
+            texture,
+            0,
+        );
+        let complete =
+            gl.check_frame_buffer_status(gl::READ_FRAMEBUFFER) == gl::FRAMEBUFFER_COMPLETE;
+        let pixels = complete.then(|| {
+            gl.read_pixels(
+                0,
+                0,
+                width as gl::GLsizei,
+                height as gl::GLsizei,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+            )
+        });
+        gl.bind_framebuffer(gl::READ_FRAMEBUFFER, previous[0] as gl::GLuint);
+        gl.delete_framebuffers(&[framebuffer]);
+
+        let error = self.take_gl_error();
+        if error != glow::NO_ERROR {
+            self.record_gl_error(XIAN_WEB_ENGINE_GL_OP_READ_PIXELS, error);
+            return None;
+        }
+        let mut pixels = pixels?;
+
+        if flip_y {
+            let stride = width as usize * 4;
+            let height = height as usize;
+            for y in 0..(height / 2) {
+                let top_start = y * stride;
+                let bottom_start = (height - y - 1) * stride;
+                let (head, tail) = pixels.split_at_mut(bottom_start);
+                head[top_start..top_start + stride].swap_with_slice(&mut tail[..stride]);
+            }
+        }
+        Some(pixels)
+    }
+
+    /// ### English
+    /// Deletes an embedder `GLsync` that will not be waited on.
+    ///
+    /// #### Parameters
+    /// - `fence`: Fence handle (`GLsync` cast to `u64`, `0` = none).
+    ///
+    /// ### 中文
+    /// 删除一个不会被等待的宿主 `GLsync`。
+    ///
+    /// #### 参数
+    /// - `fence`：fence 句柄（`GLsync` 转为 `u64`，`0` 表示无）。
+    pub fn delete_fence(&self, fence: u64) {
+        if fence == 0 {
+            return;
+        }
+        self.make_current();
+        unsafe {
+            self.glow
+                .delete_sync(glow::NativeFence(fence as usize as *mut _));
+        }
     }
 }
@@ -1,34 +1,15 @@
 //! ### English
-//! GL fence management for the triple-buffered rendering context.
+//! Fence management for the triple-buffered rendering context (delegated to the render backend).
 //!
 //! ### 中文
-//! 三缓冲渲染上下文的 GL fence 管理。
+//! 三缓冲渲染上下文的 fence 管理（委托给渲染后端）。
 
-use crate::engine::frame::{SLOT_FREE, SLOT_RELEASE_PENDING, TRIPLE_BUFFER_COUNT};
-use glow::HasContext as _;
+use crate::engine::fence_stats;
+use crate::engine::frame::{SLOT_FREE, SLOT_READY, SLOT_RELEASE_PENDING, TRIPLE_BUFFER_COUNT};
 
 use super::GlfwTripleBufferRenderingContext;
 
 impl GlfwTripleBufferRenderingContext {
-    /// ### English
-    /// Deletes one `GLsync` represented as a `u64` handle.
-    ///
-    /// #### Parameters
-    /// - `fence_value`: Fence handle (`GLsync` cast to `u64`).
-    ///
-    /// ### 中文
-    /// 删除一个以 `u64` 句柄表示的 `GLsync`。
-    ///
-    /// #### 参数
-    /// - `fence_value`：fence 句柄（`GLsync` 转为 `u64`）。
-    #[inline]
-    fn delete_sync_unsafe(&self, fence_value: u64) {
-        let sync = glow::NativeFence(fence_value as usize as *mut _);
-        unsafe {
-            self.glow.delete_sync(sync);
-        }
-    }
-
     /// ### English
     /// Deletes the producer fence for `slot` if present and clears it in shared state.
     ///
@@ -42,7 +23,8 @@ impl GlfwTripleBufferRenderingContext {
         if fence_value == 0 {
             return;
         }
-        self.delete_sync_unsafe(fence_value);
+        self.backend.delete_producer_fence(&self.glow, fence_value);
+        fence_stats::record_deleted();
         self.shared.clear_producer_fence(slot);
     }
 
@@ -59,30 +41,38 @@ impl GlfwTripleBufferRenderingContext {
         if fence_value == 0 {
             return;
         }
-        self.delete_sync_unsafe(fence_value);
+        self.backend.delete_consumer_fence(&self.glow, fence_value);
         self.shared.clear_consumer_fence(slot);
     }
 
     /// ### English
-    /// Reclaims slots in `SLOT_RELEASE_PENDING` by polling consumer fences (non-blocking).
+    /// Reclaims slots in `SLOT_RELEASE_PENDING` by asking the backend whether the consumer is done
+    /// (non-blocking).
     ///
-    /// Slots with signaled fences are transitioned back to `SLOT_FREE`.
+    /// Slots with signaled fences are transitioned back to `SLOT_FREE`, except the pinned snapshot
+    /// frame, which goes back to `SLOT_READY`.
     ///
     /// ### 中文
-    /// 通过轮询 consumer fence（非阻塞）回收处于 `SLOT_RELEASE_PENDING` 的槽位。
+    /// 通过询问后端消费者是否已用完（非阻塞）来回收处于 `SLOT_RELEASE_PENDING` 的槽位。
     ///
-    /// fence 已 signal 的槽位会被转换回 `SLOT_FREE`。
+    /// fence 已 signal 的槽位会被转换回 `SLOT_FREE`；被固定的快照帧则回到 `SLOT_READY`。
     pub(super) fn reclaim_release_pending_slots(&self) {
+        let snapshot = self.shared.snapshot_slot();
         for slot in 0..TRIPLE_BUFFER_COUNT {
             if self.shared.slot_state(slot) != SLOT_RELEASE_PENDING {
                 continue;
             }
+            let released = if snapshot == Some(slot) {
+                SLOT_READY
+            } else {
+                SLOT_FREE
+            };
 
             let consumer_fence = self.shared.get_consumer_fence(slot);
             if consumer_fence == 0 {
                 if self
                     .shared
-                    .compare_exchange_state(slot, SLOT_RELEASE_PENDING, SLOT_FREE)
+                    .compare_exchange_state(slot, SLOT_RELEASE_PENDING, released)
                     .is_ok()
                 {
                     self.shared.clear_consumer_fence(slot);
@@ -90,18 +80,21 @@ impl GlfwTripleBufferRenderingContext {
                 continue;
             }
 
-            let sync = glow::NativeFence(consumer_fence as usize as *mut _);
-            let status = unsafe { self.glow.client_wait_sync(sync, 0, 0) };
-            if status != glow::ALREADY_SIGNALED && status != glow::CONDITION_SATISFIED {
+            let texture_id = self.with_slots(|slots| slots[slot].texture_id);
+            if !self
+                .backend
+                .consumer_done(&self.glow, slot, texture_id, consumer_fence)
+            {
                 continue;
             }
 
             if self
                 .shared
-                .compare_exchange_state(slot, SLOT_RELEASE_PENDING, SLOT_FREE)
+                .compare_exchange_state(slot, SLOT_RELEASE_PENDING, released)
                 .is_ok()
             {
-                self.delete_sync_unsafe(consumer_fence);
+                self.backend
+                    .delete_consumer_fence(&self.glow, consumer_fence);
                 self.shared.clear_consumer_fence(slot);
             }
         }
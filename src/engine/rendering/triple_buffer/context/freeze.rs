@@ -0,0 +1,144 @@
+//! ### English
+//! Freezing: dropping the slot storage of an idle view while its context stays alive.
+//!
+//! ### 中文
+//! 冻结：在上下文保持存活的同时释放空闲 view 的槽位存储。
+
+use dpi::PhysicalSize;
+use gleam::gl;
+
+use crate::engine::frame::SLOT_FREE;
+use crate::engine::watchdog::XIAN_WEB_ENGINE_GL_OP_RESIZE;
+
+use super::GlfwTripleBufferRenderingContext;
+
+/// ### English
+/// Size the slot textures and depth-stencil buffer are shrunk to while frozen.
+///
+/// ### 中文
+/// 冻结期间槽位纹理与深度/模板缓冲缩小到的尺寸。
+const FROZEN_SIZE: PhysicalSize<u32> = PhysicalSize::new(1, 1);
+
+impl GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Freezes the context: every slot the producer can take is shrunk to 1x1 and every slot's spare
+    /// textures are deleted (releasing their GPU memory), the consumer stops acquiring and nothing is
+    /// painted until `thaw` (idempotent).
+    ///
+    /// A slot still held by the consumer keeps its storage until the context is thawed.
+    /// Must run on the Servo thread.
+    ///
+    /// ### 中文
+    /// 冻结该上下文：生产者可取得的槽位都会缩小为 1x1，所有槽位的备用纹理都会被删除（释放其显存），消费者停止
+    /// acquire，在 `thaw` 之前不再绘制（幂等）。
+    ///
+    /// 仍被消费者持有的槽位会保留其存储，直到上下文解冻。必须在 Servo 线程执行。
+    pub fn freeze(&self) {
+        if self.frozen.replace(true) {
+            return;
+        }
+
+        self.shared.set_resizing(true);
+        let _ = servo::RenderingContext::make_current(self);
+        if !self.unsafe_no_consumer_fence {
+            self.reclaim_release_pending_slots();
+        }
+        if let Some(slot) = self.reserved_next_back.take() {
+            self.shared.store_state(slot, SLOT_FREE);
+        }
+
+        self.resize_depth_stencil(FROZEN_SIZE);
+        self.reallocate_slots(FROZEN_SIZE);
+        self.with_slots_mut(|slots| {
+            for (slot, slot_data) in slots.iter_mut().enumerate() {
+                slot_data.drain_pool(&self.gl, &*self.backend, slot);
+            }
+        });
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_RESIZE);
+    }
+
+    /// ### English
+    /// Thaws a frozen context: slots are reallocated at the current size and the consumer may
+    /// acquire again once a new frame is presented (idempotent). Must run on the Servo thread.
+    ///
+    /// ### 中文
+    /// 解冻已冻结的上下文：槽位按当前尺寸重新分配，新帧呈现后消费者即可再次 acquire（幂等）。
+    /// 必须在 Servo 线程执行。
+    pub fn thaw(&self) {
+        if !self.frozen.replace(false) {
+            return;
+        }
+
+        let _ = servo::RenderingContext::make_current(self);
+        let size = self.size.get();
+        self.resize_depth_stencil(size);
+        self.reallocate_slots(size);
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_RESIZE);
+        self.shared.set_resizing(false);
+    }
+
+    /// ### English
+    /// Deletes every slot's spare textures, releasing their GPU memory without freezing the view
+    /// (the next resize to another size allocates again). Must run on the Servo thread.
+    ///
+    /// ### 中文
+    /// 删除所有槽位的备用纹理，在不冻结 view 的情况下释放其显存（之后 resize 到其他尺寸时会重新分配）。必须在
+    /// Servo 线程执行。
+    pub fn release_spare_textures(&self) {
+        let _ = servo::RenderingContext::make_current(self);
+        self.with_slots_mut(|slots| {
+            for (slot, slot_data) in slots.iter_mut().enumerate() {
+                slot_data.drain_pool(&self.gl, &*self.backend, slot);
+            }
+        });
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_RESIZE);
+    }
+
+    /// ### English
+    /// Returns whether the context is frozen.
+    ///
+    /// ### 中文
+    /// 返回该上下文是否处于冻结状态。
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.get()
+    }
+
+    /// ### English
+    /// Returns the size slot textures should be allocated at (1x1 while frozen).
+    ///
+    /// ### 中文
+    /// 返回槽位纹理应分配的尺寸（冻结期间为 1x1）。
+    pub(in crate::engine::rendering::triple_buffer) fn allocation_size(&self) -> PhysicalSize<u32> {
+        if self.frozen.get() {
+            FROZEN_SIZE
+        } else {
+            self.size.get()
+        }
+    }
+
+    /// ### English
+    /// Re-specifies the shared depth-stencil renderbuffer storage.
+    ///
+    /// #### Parameters
+    /// - `size`: New renderbuffer size.
+    ///
+    /// ### 中文
+    /// 重新指定共享深度/模板 renderbuffer 的存储。
+    ///
+    /// #### 参数
+    /// - `size`：新的 renderbuffer 尺寸。
+    pub(in crate::engine::rendering::triple_buffer) fn resize_depth_stencil(
+        &self,
+        size: PhysicalSize<u32>,
+    ) {
+        self.gl
+            .bind_renderbuffer(gl::RENDERBUFFER, self.depth_stencil_rb);
+        self.gl.renderbuffer_storage(
+            gl::RENDERBUFFER,
+            gl::DEPTH24_STENCIL8,
+            size.width as gl::GLsizei,
+            size.height as gl::GLsizei,
+        );
+        self.gl.bind_renderbuffer(gl::RENDERBUFFER, 0);
+    }
+}
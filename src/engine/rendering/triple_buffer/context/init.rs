@@ -6,12 +6,21 @@
 
 use std::cell::{Cell, UnsafeCell};
 use std::rc::Rc;
-use std::time::Duration;
 
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO, XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8,
+    XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8, XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F,
+    XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8, XIAN_WEB_ENGINE_COLORSPACE_AUTO,
+};
 use crate::engine::frame::{SLOT_RENDERING, TRIPLE_BUFFER_COUNT};
-use crate::engine::refresh::{FixedIntervalRefreshDriver, VsyncRefreshDriver};
+use crate::engine::refresh::{
+    FixedIntervalRefreshDriver, FrameCadence, PausableRefreshDriver, VsyncRefreshDriver,
+};
+use crate::engine::watchdog::XIAN_WEB_ENGINE_GL_OP_CREATE;
 use gleam::gl;
 
+use super::super::super::backend::{GlBackend, RenderBackend, SlotTextureFormat, VulkanBackend};
+use super::super::super::shared_context::GlfwSharedContext;
 use super::super::slot::TripleBufferSlot;
 use super::{GlfwTripleBufferContextInit, GlfwTripleBufferRenderingContext};
 
@@ -21,6 +30,9 @@ impl GlfwTripleBufferRenderingContext {
     ///
     /// Must be called on the Servo thread (the thread that owns `shared_ctx`).
     /// If `target_fps == 0`, refresh is driven by external vsync (`VsyncRefreshDriver`).
+    /// A group refresh driver, when provided, replaces both per-view drivers.
+    /// Slot textures come from the Vulkan backend when `vulkan_exports` is set, otherwise from the
+    /// GL backend.
     ///
     /// #### Parameters
     /// - `init`: Initialization bundle for the rendering context.
@@ -30,6 +42,8 @@ impl GlfwTripleBufferRenderingContext {
     ///
     /// 必须在 Servo 线程（持有 `shared_ctx` 的线程）调用。
     /// 若 `target_fps == 0`，则由外部 vsync（`VsyncRefreshDriver`）驱动刷新。
+    /// 若提供了 group refresh driver，则替代上述两种 per-view driver。
+    /// 设置了 `vulkan_exports` 时槽位纹理由 Vulkan 后端分配，否则由 GL 后端分配。
     ///
     /// #### 参数
     /// - `init`：渲染上下文的初始化参数包。
@@ -40,20 +54,27 @@ impl GlfwTripleBufferRenderingContext {
             shared,
             vsync_queue,
             target_fps,
+            color_format,
+            mipmaps,
+            orphaning_resize,
             unsafe_no_consumer_fence,
             unsafe_no_producer_fence,
             refresh_scheduler,
+            group_refresh_driver,
+            vulkan_exports,
         } = init;
 
         shared_ctx.make_current();
 
         let gl = shared_ctx.gl();
         let glow = shared_ctx.glow();
-        let use_srgb = shared_ctx.supports_srgb();
-        let internal_format = if use_srgb {
-            gl::SRGB8_ALPHA8 as gl::GLint
-        } else {
-            gl::RGBA as gl::GLint
+        let color_format = resolve_color_format(color_format, shared_ctx.supports_srgb())?;
+        let use_srgb = color_format == XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8;
+        let texture_format = slot_texture_format(color_format, &shared_ctx)?;
+        shared.set_color_format(color_format);
+        let backend: Box<dyn RenderBackend> = match vulkan_exports {
+            Some(exports) => Box::new(VulkanBackend::new(&shared_ctx, exports)?),
+            None => Box::new(GlBackend::new(shared_ctx.supports_sync())),
         };
 
         let renderbuffer_ids = gl.gen_renderbuffers(1);
@@ -67,32 +88,59 @@ impl GlfwTripleBufferRenderingContext {
         );
         gl.bind_renderbuffer(gl::RENDERBUFFER, 0);
 
-        let slots: [TripleBufferSlot; TRIPLE_BUFFER_COUNT] = std::array::from_fn(|_| {
-            TripleBufferSlot::new(&gl, depth_stencil_rb, initial_size, internal_format)
-        });
+        let mut created = Vec::with_capacity(TRIPLE_BUFFER_COUNT);
+        for slot in 0..TRIPLE_BUFFER_COUNT {
+            match TripleBufferSlot::new(
+                &gl,
+                &*backend,
+                slot,
+                depth_stencil_rb,
+                initial_size,
+                texture_format,
+                mipmaps,
+            ) {
+                Ok(created_slot) => created.push(created_slot),
+                Err(err) => {
+                    for (slot, created_slot) in created.iter().enumerate() {
+                        created_slot.delete(&gl, &*backend, slot);
+                    }
+                    gl.delete_renderbuffers(&[depth_stencil_rb]);
+                    return Err(err);
+                }
+            }
+        }
+        let slots: [TripleBufferSlot; TRIPLE_BUFFER_COUNT] = created
+            .try_into()
+            .map_err(|_| "Failed to create triple-buffer slots".to_string())?;
         for (i, slot) in slots.iter().enumerate() {
             shared.set_texture_id(i, slot.texture_id);
         }
 
-        let refresh_driver: Option<Rc<dyn servo::RefreshDriver>> = if target_fps == 0 {
-            Some(VsyncRefreshDriver::new(vsync_queue))
+        let refresh_driver: Rc<dyn servo::RefreshDriver> = if let Some(group) = group_refresh_driver
+        {
+            group
+        } else if target_fps == 0 {
+            let Some(refresh_scheduler) = refresh_scheduler else {
+                return Err("Missing RefreshScheduler for vsync refresh".to_string());
+            };
+            VsyncRefreshDriver::new(vsync_queue, refresh_scheduler)
         } else {
             let Some(refresh_scheduler) = refresh_scheduler else {
                 return Err("Missing RefreshScheduler for fixed-interval refresh".to_string());
             };
 
-            let fps = target_fps.max(1) as u64;
-            let nanos = (1_000_000_000u64 / fps).max(1);
-            let driver: Rc<dyn servo::RefreshDriver> =
-                FixedIntervalRefreshDriver::new(refresh_scheduler, Duration::from_nanos(nanos));
-            Some(driver)
+            let Some(cadence) = FrameCadence::new(target_fps, 1) else {
+                return Err(format!("Invalid target FPS {target_fps}"));
+            };
+            FixedIntervalRefreshDriver::new(refresh_scheduler, cadence)
         };
 
         let ctx = Self {
+            backend,
             shared_ctx,
             gl,
             glow,
-            refresh_driver,
+            refresh_driver: PausableRefreshDriver::new(refresh_driver),
             size: Cell::new(initial_size),
             depth_stencil_rb,
             slots: UnsafeCell::new(slots),
@@ -102,12 +150,103 @@ impl GlfwTripleBufferRenderingContext {
             shared,
             unsafe_no_consumer_fence,
             unsafe_no_producer_fence,
+            orphaning_resize,
             destroyed: Cell::new(false),
-            internal_format,
-            use_srgb,
+            frozen: Cell::new(false),
+            painting_held: Cell::new(false),
+            texture_format: Cell::new(texture_format),
+            mipmaps: Cell::new(mipmaps),
+            use_srgb: Cell::new(use_srgb),
+            texture_generation: Cell::new(0),
             srgb_enabled: Cell::new(false),
+            colorspace: Cell::new(XIAN_WEB_ENGINE_COLORSPACE_AUTO),
+            frame_gl_error: Cell::new(None),
         };
+        ctx.check_gl(XIAN_WEB_ENGINE_GL_OP_CREATE);
         ctx.shared.store_state(0, SLOT_RENDERING);
         Ok(ctx)
     }
 }
+
+/// ### English
+/// Resolves `XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO` and checks that sRGB output is available.
+///
+/// #### Parameters
+/// - `color_format`: Requested `XIAN_WEB_ENGINE_COLOR_FORMAT_*` value.
+/// - `supports_srgb`: Whether the shared context supports sRGB framebuffers.
+///
+/// ### 中文
+/// 解析 `XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO`，并检查 sRGB 输出是否可用。
+///
+/// #### 参数
+/// - `color_format`：请求的 `XIAN_WEB_ENGINE_COLOR_FORMAT_*` 值。
+/// - `supports_srgb`：共享上下文是否支持 sRGB framebuffer。
+pub(super) fn resolve_color_format(color_format: u32, supports_srgb: bool) -> Result<u32, String> {
+    match color_format {
+        XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO if supports_srgb => {
+            Ok(XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8)
+        }
+        XIAN_WEB_ENGINE_COLOR_FORMAT_AUTO => Ok(XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8),
+        XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 if !supports_srgb => {
+            Err("sRGB framebuffers are not supported by the shared context".to_string())
+        }
+        _ => Ok(color_format),
+    }
+}
+
+/// ### English
+/// Returns the GL formats used to allocate slot textures for a resolved color format.
+///
+/// On OpenGL ES, BGRA8 uses the unsized `GL_BGRA_EXT` internal format
+/// (`GL_EXT_texture_format_BGRA8888`) and RGBA16F requires a color-renderable half-float
+/// extension; an error is returned when the extension is missing.
+///
+/// #### Parameters
+/// - `color_format`: Resolved `XIAN_WEB_ENGINE_COLOR_FORMAT_*` value (not `AUTO`).
+/// - `shared_ctx`: Shared context (API flavor and extensions).
+///
+/// ### 中文
+/// 返回已解析颜色格式对应的槽位纹理 GL 格式。
+///
+/// 在 OpenGL ES 上，BGRA8 使用无尺寸的 `GL_BGRA_EXT` 内部格式（`GL_EXT_texture_format_BGRA8888`），
+/// RGBA16F 需要可渲染半精度浮点颜色的扩展；缺少扩展时返回错误。
+///
+/// #### 参数
+/// - `color_format`：已解析的 `XIAN_WEB_ENGINE_COLOR_FORMAT_*` 值（不是 `AUTO`）。
+/// - `shared_ctx`：共享上下文（API 类型与扩展）。
+pub(super) fn slot_texture_format(
+    color_format: u32,
+    shared_ctx: &GlfwSharedContext,
+) -> Result<SlotTextureFormat, String> {
+    let is_gles = shared_ctx.is_gles();
+    let (internal_format, format, ty) = match color_format {
+        XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8 if is_gles => {
+            if !shared_ctx.has_extension("GL_EXT_texture_format_BGRA8888") {
+                return Err(
+                    "BGRA8 slot textures need GL_EXT_texture_format_BGRA8888 on OpenGL ES"
+                        .to_string(),
+                );
+            }
+            (gl::BGRA, gl::BGRA, gl::UNSIGNED_BYTE)
+        }
+        XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F
+            if is_gles
+                && !shared_ctx.has_extension("GL_EXT_color_buffer_half_float")
+                && !shared_ctx.has_extension("GL_EXT_color_buffer_float") =>
+        {
+            return Err(
+                "RGBA16F slot textures need GL_EXT_color_buffer_(half_)float on OpenGL ES"
+                    .to_string(),
+            );
+        }
+        XIAN_WEB_ENGINE_COLOR_FORMAT_BGRA8 => (gl::RGBA8, gl::BGRA, gl::UNSIGNED_BYTE),
+        XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 => (gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE),
+        XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F => (gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT),
+        _ => (gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE),
+    };
+    Ok(SlotTextureFormat {
+        internal_format: internal_format as gl::GLint,
+        format,
+        ty,
+    })
+}
@@ -7,18 +7,23 @@ use std::cell::{Cell, UnsafeCell};
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::engine::flags::XIAN_WEB_ENGINE_COLORSPACE_LINEAR;
 use crate::engine::frame::{SharedFrameState, TRIPLE_BUFFER_COUNT};
-use crate::engine::refresh::RefreshScheduler;
+use crate::engine::refresh::{PausableRefreshDriver, RefreshScheduler};
 use crate::engine::vsync::VsyncCallbackQueue;
 use dpi::PhysicalSize;
 use gleam::gl::{self, Gl};
 
+use super::super::backend::{RenderBackend, SlotTextureFormat, VulkanFrameExports};
 use super::super::shared_context::GlfwSharedContext;
 use super::slot::TripleBufferSlot;
 
 mod fences;
+mod freeze;
 mod init;
+mod reconfigure;
 mod reserve;
+mod snapshot;
 mod teardown;
 
 /// ### English
@@ -58,6 +63,24 @@ pub struct GlfwTripleBufferContextInit {
     /// 固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
     pub target_fps: u32,
     /// ### English
+    /// Requested slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`, validated by caller).
+    ///
+    /// ### 中文
+    /// 请求的槽位纹理颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`，由调用方校验）。
+    pub color_format: u32,
+    /// ### English
+    /// Allocate mipmapped slot textures and regenerate mips on every present.
+    ///
+    /// ### 中文
+    /// 为槽位纹理分配 mipmap，并在每次 present 时重新生成。
+    pub mipmaps: bool,
+    /// ### English
+    /// Resize by orphaning the slot textures (GL backend only, see `orphan_slots`).
+    ///
+    /// ### 中文
+    /// 以孤立槽位纹理的方式 resize（仅 GL 后端，见 `orphan_slots`）。
+    pub orphaning_resize: bool,
+    /// ### English
     /// Unsafe mode: ignore consumer fences provided by the embedder.
     ///
     /// ### 中文
@@ -70,11 +93,25 @@ pub struct GlfwTripleBufferContextInit {
     /// 不安全模式：跳过新帧的生产者 fence。
     pub unsafe_no_producer_fence: bool,
     /// ### English
-    /// Optional shared refresh scheduler (used when `target_fps != 0`).
+    /// Optional shared refresh scheduler (required unless the view joins a view group; vsync views
+    /// use it once the host reports its display timing).
     ///
     /// ### 中文
-    /// 可选的共享 refresh 调度器（当 `target_fps != 0` 时使用）。
+    /// 可选的共享 refresh 调度器（除非 view 加入 view group，否则必需；vsync view 在宿主上报显示时序后使用）。
     pub refresh_scheduler: Option<Arc<RefreshScheduler>>,
+    /// ### English
+    /// Refresh driver shared by the view group, if the view belongs to one (overrides
+    /// `target_fps`).
+    ///
+    /// ### 中文
+    /// 若 view 属于某个 view group，则为该组共享的 refresh driver（优先于 `target_fps`）。
+    pub group_refresh_driver: Option<Rc<dyn servo::RefreshDriver>>,
+    /// ### English
+    /// Vulkan export table; when present, slot textures are allocated by the Vulkan backend.
+    ///
+    /// ### 中文
+    /// Vulkan 导出表；存在时由 Vulkan 后端分配槽位纹理。
+    pub vulkan_exports: Option<Arc<VulkanFrameExports>>,
 }
 
 /// ### English
@@ -83,6 +120,16 @@ pub struct GlfwTripleBufferContextInit {
 /// ### 中文
 /// 供 Servo 在独立线程使用的三缓冲离屏渲染上下文。
 pub struct GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Render backend that owns slot texture storage and frame synchronization.
+    ///
+    /// Declared first so it is dropped while the shared context is still alive.
+    ///
+    /// ### 中文
+    /// 持有槽位纹理存储与帧同步的渲染后端。
+    ///
+    /// 声明在最前，以保证其在共享上下文仍存活时被 drop。
+    pub(super) backend: Box<dyn RenderBackend>,
     /// ### English
     /// Shared offscreen GLFW context used by the Servo thread.
     ///
@@ -102,11 +149,11 @@ pub struct GlfwTripleBufferRenderingContext {
     /// 用于 fence/sync 操作的 glow GL API。
     pub(super) glow: Arc<glow::Context>,
     /// ### English
-    /// Optional refresh driver (external-vsync or fixed interval).
+    /// Refresh driver (external-vsync, fixed interval or group), pausable for debugging.
     ///
     /// ### 中文
-    /// 可选 refresh driver（外部 vsync 或固定间隔）。
-    pub(super) refresh_driver: Option<Rc<dyn servo::RefreshDriver>>,
+    /// refresh driver（外部 vsync、固定间隔或 group），可为调试而暂停。
+    pub(super) refresh_driver: Rc<PausableRefreshDriver>,
     /// ### English
     /// Current logical size of the rendering surface.
     ///
@@ -162,32 +209,154 @@ pub struct GlfwTripleBufferRenderingContext {
     /// 不安全模式：跳过生产者侧 fence（开销更低）。
     pub(super) unsafe_no_producer_fence: bool,
     /// ### English
+    /// Whether resizes orphan the slot textures (only honored when the backend pools textures).
+    ///
+    /// ### 中文
+    /// resize 是否孤立槽位纹理（仅在后端支持纹理池时生效）。
+    pub(super) orphaning_resize: bool,
+    /// ### English
     /// Guard flag to make GL teardown idempotent.
     ///
     /// ### 中文
     /// 防重入标记：保证 GL 资源销毁幂等。
     pub(super) destroyed: Cell<bool>,
     /// ### English
-    /// Internal format used for color attachments (sRGB or linear RGBA).
+    /// Whether the context is frozen (slot storage dropped, see `freeze`).
+    ///
+    /// ### 中文
+    /// 该上下文是否已冻结（槽位存储已释放，见 `freeze`）。
+    pub(super) frozen: Cell<bool>,
+    /// ### English
+    /// Whether painting is held by snapshot mode (outside of a refresh, see `set_snapshot`).
+    ///
+    /// ### 中文
+    /// 是否因快照模式暂停绘制（刷新期间除外，见 `set_snapshot`）。
+    pub(super) painting_held: Cell<bool>,
+    /// ### English
+    /// Formats used for color attachments (resolved from the view's color format).
+    ///
+    /// ### 中文
+    /// 颜色附件使用的格式（由 view 的颜色格式解析而来）。
+    pub(super) texture_format: Cell<SlotTextureFormat>,
+    /// ### English
+    /// Whether slot textures are mipmapped (mips regenerated in `present()`).
+    ///
+    /// ### 中文
+    /// 槽位纹理是否带 mipmap（在 `present()` 中重新生成）。
+    pub(super) mipmaps: Cell<bool>,
+    /// ### English
+    /// Whether slot textures use an sRGB format (sRGB framebuffer output is possible).
     ///
     /// ### 中文
-    /// 颜色附件使用的内部格式（sRGB 或线性 RGBA）。
-    pub(super) internal_format: gl::GLint,
+    /// 槽位纹理是否使用 sRGB 格式（可启用 sRGB framebuffer 输出）。
+    pub(super) use_srgb: Cell<bool>,
     /// ### English
-    /// Whether sRGB framebuffer output is enabled.
+    /// Bumped by `reconfigure`; slots allocated under an older generation are reallocated before
+    /// they are rendered into again.
     ///
     /// ### 中文
-    /// 是否启用 sRGB framebuffer 输出。
-    pub(super) use_srgb: bool,
+    /// 由 `reconfigure` 递增；在较旧代次下分配的槽位会在再次渲染前重新分配。
+    pub(super) texture_generation: Cell<u32>,
     /// ### English
     /// Cached sRGB state to avoid redundant GL state toggles.
     ///
     /// ### 中文
     /// 缓存的 sRGB 状态，避免重复切换 GL 状态。
     pub(super) srgb_enabled: Cell<bool>,
+    /// ### English
+    /// Requested output colorspace (`XIAN_WEB_ENGINE_COLORSPACE_*`).
+    ///
+    /// ### 中文
+    /// 请求的输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_*`）。
+    pub(super) colorspace: Cell<u32>,
+    /// ### English
+    /// First `(op, error)` GL error of the current frame (paranoid GL mode only).
+    ///
+    /// ### 中文
+    /// 当前帧的第一个 GL 错误 `(op, error)`（仅 paranoid GL 模式）。
+    pub(super) frame_gl_error: Cell<Option<(u32, u32)>>,
 }
 
 impl GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Sets the requested output colorspace (applied from the next frame).
+    ///
+    /// #### Parameters
+    /// - `colorspace`: `XIAN_WEB_ENGINE_COLORSPACE_*` value (validated by caller).
+    ///
+    /// ### 中文
+    /// 设置请求的输出色彩空间（从下一帧开始生效）。
+    ///
+    /// #### 参数
+    /// - `colorspace`：`XIAN_WEB_ENGINE_COLORSPACE_*` 值（由调用方校验）。
+    pub fn set_colorspace(&self, colorspace: u32) {
+        self.colorspace.set(colorspace);
+    }
+
+    /// ### English
+    /// Returns the view's refresh driver (paused and stepped by the debug pause commands).
+    ///
+    /// ### 中文
+    /// 返回该 view 的 refresh driver（由调试暂停命令暂停与单步）。
+    #[inline]
+    pub fn refresh_driver(&self) -> &PausableRefreshDriver {
+        &self.refresh_driver
+    }
+
+    /// ### English
+    /// Returns the shared GL context this rendering context draws on.
+    ///
+    /// ### 中文
+    /// 返回该渲染上下文所使用的共享 GL 上下文。
+    #[inline]
+    pub fn shared_context(&self) -> &Rc<GlfwSharedContext> {
+        &self.shared_ctx
+    }
+
+    /// ### English
+    /// Returns whether sRGB encoding should be enabled for the next frame.
+    ///
+    /// ### 中文
+    /// 返回下一帧是否应启用 sRGB 编码。
+    #[inline]
+    pub(super) fn wants_srgb(&self) -> bool {
+        self.use_srgb.get() && self.colorspace.get() != XIAN_WEB_ENGINE_COLORSPACE_LINEAR
+    }
+
+    /// ### English
+    /// In paranoid GL mode, checks `glGetError` after `op` and keeps the frame's first error.
+    ///
+    /// #### Parameters
+    /// - `op`: `XIAN_WEB_ENGINE_GL_OP_*` of the operation that just ran.
+    ///
+    /// ### 中文
+    /// paranoid GL 模式下，在 `op` 之后检查 `glGetError`，并保留该帧的第一个错误。
+    ///
+    /// #### 参数
+    /// - `op`：刚执行完的操作（`XIAN_WEB_ENGINE_GL_OP_*`）。
+    #[inline]
+    pub(super) fn check_gl(&self, op: u32) {
+        if !self.shared_ctx.paranoid_gl() {
+            return;
+        }
+        let error = self.shared_ctx.take_gl_error();
+        if error != 0 && self.frame_gl_error.get().is_none() {
+            self.frame_gl_error.set(Some((op, error)));
+        }
+    }
+
+    /// ### English
+    /// Records the frame's first GL error (if any) in the shard log and starts a new frame.
+    ///
+    /// ### 中文
+    /// 将该帧的第一个 GL 错误（若有）记录到分片日志，并开始新的一帧。
+    #[inline]
+    pub(super) fn finish_frame_gl_errors(&self) {
+        if let Some((op, error)) = self.frame_gl_error.take() {
+            self.shared_ctx.record_gl_error(op, error);
+        }
+    }
+
     /// ### English
     /// Runs `f` with a shared reference to the slot array.
     ///
@@ -0,0 +1,154 @@
+//! ### English
+//! In-place reconfiguration of the slot textures (color format / mipmaps) for view moves.
+//!
+//! ### 中文
+//! 为 view 迁移原地重新配置槽位纹理（颜色格式 / mipmap）。
+
+use dpi::PhysicalSize;
+
+use crate::engine::flags::XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8;
+use crate::engine::frame::{SLOT_FREE, SLOT_READY, SLOT_RENDERING};
+use crate::engine::watchdog::XIAN_WEB_ENGINE_GL_OP_RESIZE;
+
+use super::GlfwTripleBufferRenderingContext;
+use super::init::{resolve_color_format, slot_texture_format};
+
+impl GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Switches the slot textures to another color format and/or mipmap setting without recreating
+    /// the context (the Servo `WebView` keeps rendering into it).
+    ///
+    /// The back slot and every FREE/READY slot are reallocated immediately; a slot still held by the
+    /// consumer is reallocated the next time the producer renders into it. On error nothing changes.
+    /// Must run on the Servo thread.
+    ///
+    /// #### Parameters
+    /// - `color_format`: Requested `XIAN_WEB_ENGINE_COLOR_FORMAT_*` value (`AUTO` is resolved).
+    /// - `mipmaps`: Whether slot textures get a mip chain.
+    ///
+    /// ### 中文
+    /// 在不重建上下文的情况下（Servo `WebView` 继续渲染到该上下文），将槽位纹理切换到另一种颜色格式和/或
+    /// mipmap 设置。
+    ///
+    /// back 槽位及所有 FREE/READY 槽位会立即重新分配；仍被消费者持有的槽位会在生产者下次渲染到它时重新分配。
+    /// 出错时不做任何改动。必须在 Servo 线程执行。
+    ///
+    /// #### 参数
+    /// - `color_format`：请求的 `XIAN_WEB_ENGINE_COLOR_FORMAT_*` 值（会解析 `AUTO`）。
+    /// - `mipmaps`：槽位纹理是否带 mip 链。
+    pub fn reconfigure(&self, color_format: u32, mipmaps: bool) -> Result<(), String> {
+        let color_format = resolve_color_format(color_format, self.shared_ctx.supports_srgb())?;
+        if color_format == self.shared.color_format() && mipmaps == self.mipmaps.get() {
+            return Ok(());
+        }
+        let texture_format = slot_texture_format(color_format, &self.shared_ctx)?;
+
+        self.shared.set_resizing(true);
+        let _ = servo::RenderingContext::make_current(self);
+
+        self.texture_format.set(texture_format);
+        self.mipmaps.set(mipmaps);
+        self.use_srgb
+            .set(color_format == XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8);
+        self.texture_generation
+            .set(self.texture_generation.get().wrapping_add(1));
+        self.shared.set_color_format(color_format);
+        self.reallocate_slots(self.allocation_size());
+
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_RESIZE);
+        self.shared.set_resizing(self.frozen.get());
+        Ok(())
+    }
+
+    /// ### English
+    /// Reallocates the producer-owned back slot and every slot that can be locked from FREE/READY
+    /// to `new_size` and the current texture generation. The caller holds the "resizing" flag.
+    ///
+    /// #### Parameters
+    /// - `new_size`: Slot texture size.
+    ///
+    /// ### 中文
+    /// 将生产者持有的 back 槽位以及所有可从 FREE/READY 锁定的槽位重新分配到 `new_size` 与当前纹理代次。
+    /// 调用方需持有 “resizing” 标记。
+    ///
+    /// #### 参数
+    /// - `new_size`：槽位纹理尺寸。
+    pub(in crate::engine::rendering::triple_buffer) fn reallocate_slots(
+        &self,
+        new_size: PhysicalSize<u32>,
+    ) {
+        let back_slot = self.back_slot.get();
+        self.with_slots_mut(|slots| {
+            self.delete_producer_fence_if_any(back_slot);
+            if !self.unsafe_no_consumer_fence {
+                self.delete_consumer_fence_if_any(back_slot);
+            }
+            self.resize_slot(&mut slots[back_slot], back_slot, new_size);
+            self.shared.store_state(back_slot, SLOT_RENDERING);
+
+            for (slot, slot_data) in slots.iter_mut().enumerate() {
+                if slot == back_slot {
+                    continue;
+                }
+
+                let locked = self
+                    .shared
+                    .compare_exchange_state(slot, SLOT_READY, SLOT_RENDERING)
+                    .is_ok()
+                    || self
+                        .shared
+                        .compare_exchange_state(slot, SLOT_FREE, SLOT_RENDERING)
+                        .is_ok();
+                if !locked {
+                    continue;
+                }
+
+                self.delete_producer_fence_if_any(slot);
+                if !self.unsafe_no_consumer_fence {
+                    self.delete_consumer_fence_if_any(slot);
+                }
+                self.resize_slot(slot_data, slot, new_size);
+                self.shared.store_state(slot, SLOT_FREE);
+            }
+        });
+    }
+
+    /// ### English
+    /// Resizes by orphaning: the back slot and every FREE slot swap in textures of `new_size` (the
+    /// old ones are parked in the slot's texture pool), without raising the "resizing" flag or
+    /// touching any fence. READY and consumer-held slots keep their frame and are resized the next
+    /// time the producer takes them.
+    ///
+    /// #### Parameters
+    /// - `new_size`: Slot texture size.
+    ///
+    /// ### 中文
+    /// 以孤立方式 resize：back 槽位与所有 FREE 槽位换入 `new_size` 的纹理（旧纹理放入槽位的纹理池），不设置
+    /// “resizing” 标记，也不触碰任何 fence。READY 与被消费者持有的槽位保留其帧，在生产者下次取得它们时再 resize。
+    ///
+    /// #### 参数
+    /// - `new_size`：槽位纹理尺寸。
+    pub(in crate::engine::rendering::triple_buffer) fn orphan_slots(
+        &self,
+        new_size: PhysicalSize<u32>,
+    ) {
+        let back_slot = self.back_slot.get();
+        self.with_slots_mut(|slots| {
+            self.resize_slot(&mut slots[back_slot], back_slot, new_size);
+
+            for (slot, slot_data) in slots.iter_mut().enumerate() {
+                if slot == back_slot
+                    || self
+                        .shared
+                        .compare_exchange_state(slot, SLOT_FREE, SLOT_RENDERING)
+                        .is_err()
+                {
+                    continue;
+                }
+
+                self.resize_slot(slot_data, slot, new_size);
+                self.shared.store_state(slot, SLOT_FREE);
+            }
+        });
+    }
+}
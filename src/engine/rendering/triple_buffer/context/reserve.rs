@@ -4,7 +4,12 @@
 //! ### 中文
 //! 三缓冲渲染上下文的槽位预留逻辑。
 
+use dpi::PhysicalSize;
+
 use crate::engine::frame::{SLOT_FREE, SLOT_READY, SLOT_RENDERING, TRIPLE_BUFFER_COUNT};
+use crate::engine::log::{XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, log};
+
+use super::super::slot::TripleBufferSlot;
 
 use super::GlfwTripleBufferRenderingContext;
 
@@ -32,31 +37,76 @@ impl GlfwTripleBufferRenderingContext {
     /// ### English
     /// Ensures the GL resources for `slot` match the current desired size.
     ///
+    /// A failed resize is logged and leaves the slot at its previous size.
+    ///
     /// ### 中文
     /// 确保 `slot` 的 GL 资源尺寸与当前期望尺寸一致。
+    ///
+    /// resize 失败时会记录日志，槽位保持原尺寸。
     pub(in crate::engine::rendering::triple_buffer) fn ensure_slot_size(&self, slot: usize) {
         if slot >= TRIPLE_BUFFER_COUNT {
             return;
         }
 
-        let desired_size = self.size.get();
+        let desired_size = self.allocation_size();
         self.with_slots_mut(|slots| {
             let existing = &mut slots[slot];
-            if existing.size == desired_size {
+            if existing.size == desired_size && existing.generation == self.texture_generation.get()
+            {
                 return;
             }
 
-            existing.resize(&self.gl, desired_size, self.internal_format);
-            self.shared.set_slot_size(slot, desired_size);
+            self.resize_slot(existing, slot, desired_size);
         });
     }
 
+    /// ### English
+    /// Resizes one slot through the backend and publishes its texture id and size.
+    ///
+    /// #### Parameters
+    /// - `existing`: Slot resources to resize.
+    /// - `slot`: Slot index.
+    /// - `new_size`: New texture size.
+    ///
+    /// ### 中文
+    /// 通过后端 resize 单个槽位，并发布其纹理 ID 与尺寸。
+    ///
+    /// #### 参数
+    /// - `existing`：需要 resize 的槽位资源。
+    /// - `slot`：槽位索引。
+    /// - `new_size`：新的纹理尺寸。
+    pub(in crate::engine::rendering::triple_buffer) fn resize_slot(
+        &self,
+        existing: &mut TripleBufferSlot,
+        slot: usize,
+        new_size: PhysicalSize<u32>,
+    ) {
+        if let Err(err) = existing.resize(
+            &self.gl,
+            &*self.backend,
+            slot,
+            new_size,
+            self.texture_format.get(),
+            self.mipmaps.get(),
+            self.texture_generation.get(),
+        ) {
+            log(
+                XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+                format_args!("Failed to resize slot {slot}: {err}"),
+            );
+            return;
+        }
+        self.shared.set_texture_id(slot, existing.texture_id);
+        self.shared.set_slot_size(slot, new_size);
+    }
+
     /// ### English
     /// Tries to reserve the next back slot for the producer.
     ///
     /// Strategy (triple-buffer, two candidates besides `current_back`):
     /// - Fast path: reserve any FREE slot.
-    /// - Fallback: steal a READY slot, preferring the older READY to avoid stealing the newest frame.
+    /// - Fallback: steal a READY slot, preferring the older READY to avoid stealing the newest
+    ///   frame (the pinned snapshot frame is never stolen).
     /// - Safe mode: if no FREE/READY, poll consumer fences to reclaim RELEASE_PENDING and retry.
     ///
     /// ### 中文
@@ -64,7 +114,7 @@ impl GlfwTripleBufferRenderingContext {
     ///
     /// 策略（三缓冲，候选为 `current_back` 之外的两个槽位）：
     /// - 快路径：优先预留任意 FREE 槽位。
-    /// - 回退：抢占 READY 槽位，并优先抢占更旧的 READY，避免把最新帧从消费者手里抢走。
+    /// - 回退：抢占 READY 槽位，并优先抢占更旧的 READY，避免把最新帧从消费者手里抢走（被固定的快照帧永不被抢占）。
     /// - 安全模式：若没有 FREE/READY，则轮询 consumer fence 回收 RELEASE_PENDING，再重试。
     pub(in crate::engine::rendering::triple_buffer) fn try_reserve_next_back_slot(
         &self,
@@ -84,6 +134,7 @@ impl GlfwTripleBufferRenderingContext {
             }
         }
 
+        let snapshot = self.shared.snapshot_slot();
         let state_a = self.shared.slot_state_relaxed(slot_a);
         let state_b = self.shared.slot_state_relaxed(slot_b);
 
@@ -108,9 +159,10 @@ impl GlfwTripleBufferRenderingContext {
         }
 
         for slot in [first, second].into_iter().flatten() {
-            if self
-                .shared
-                .compare_exchange_state_relaxed(slot, SLOT_READY, SLOT_RENDERING)
+            if Some(slot) != snapshot
+                && self
+                    .shared
+                    .compare_exchange_state_relaxed(slot, SLOT_READY, SLOT_RENDERING)
             {
                 self.prepare_slot_for_rendering(slot);
                 return Some(slot);
@@ -147,14 +199,26 @@ impl GlfwTripleBufferRenderingContext {
     /// Tries to reserve the next back slot before Servo paints.
     ///
     /// This reduces the chance that `present()` fails due to a lack of slots when the consumer
-    /// is temporarily holding a texture.
+    /// is temporarily holding a texture. Returns `false` (nothing should be painted) while the
+    /// context is frozen or snapshot mode holds painting.
+    ///
+    /// While the consumer pins a slot the producer runs double-buffered and reserves nothing ahead:
+    /// the only other slot is usually the latest READY frame, which must stay acquirable while the
+    /// next frame is painted, so `present()` takes it only once the new frame is done.
     ///
     /// ### 中文
     /// 在 Servo paint 之前预留下一 back 槽位。
     ///
-    /// 这可降低 `present()` 因暂时没有可用槽位而失败的概率（例如消费者线程短暂持有纹理时）。
+    /// 这可降低 `present()` 因暂时没有可用槽位而失败的概率（例如消费者线程短暂持有纹理时）。上下文冻结或快照
+    /// 模式暂停绘制期间返回 `false`（不应绘制任何内容）。
+    ///
+    /// 消费者固定某个槽位期间，生产者以双缓冲方式运行且不提前预留：另一个槽位通常就是最新的 READY 帧，它在绘制
+    /// 下一帧期间必须保持可 acquire，因此 `present()` 只在新帧完成后才取得它。
     pub fn preflight_reserve_next_back_slot(&self) -> bool {
-        if self.reserved_next_back.get().is_some() {
+        if self.frozen.get() || self.painting_held.get() {
+            return false;
+        }
+        if self.reserved_next_back.get().is_some() || self.shared.has_pinned_slot() {
             return true;
         }
 
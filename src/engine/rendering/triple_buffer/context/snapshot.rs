@@ -0,0 +1,69 @@
+//! ### English
+//! Snapshot mode: pinning the latest frame so it stays acquirable while nothing is painted.
+//!
+//! ### 中文
+//! 快照模式：固定最新帧，使其在不绘制任何内容期间保持可 acquire。
+
+use crate::engine::frame::SLOT_RELEASE_PENDING;
+
+use super::GlfwTripleBufferRenderingContext;
+
+impl GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Enters or leaves snapshot mode (idempotent). While in it, the latest published frame is
+    /// pinned (never reclaimed; releasing it makes it READY again) and painting is held until
+    /// `set_painting_held(false)` opens a refresh. Must run on the Servo thread.
+    ///
+    /// #### Parameters
+    /// - `enabled`: Whether to enter snapshot mode.
+    ///
+    /// ### 中文
+    /// 进入或退出快照模式（幂等）。期间最新发布的帧被固定（不会被回收；release 后会重新变为 READY），且在
+    /// `set_painting_held(false)` 开启刷新之前暂停绘制。必须在 Servo 线程执行。
+    ///
+    /// #### 参数
+    /// - `enabled`：是否进入快照模式。
+    pub fn set_snapshot(&self, enabled: bool) {
+        self.shared.set_snapshot_pin(enabled);
+        self.painting_held.set(enabled);
+        if !enabled && !self.unsafe_no_consumer_fence {
+            let _ = servo::RenderingContext::make_current(self);
+            self.reclaim_release_pending_slots();
+        }
+    }
+
+    /// ### English
+    /// Holds or resumes painting without changing the pin (used to open a snapshot refresh).
+    ///
+    /// #### Parameters
+    /// - `held`: Whether painting is held.
+    ///
+    /// ### 中文
+    /// 暂停或恢复绘制，但不改变固定状态（用于开启一次快照刷新）。
+    ///
+    /// #### 参数
+    /// - `held`：是否暂停绘制。
+    pub fn set_painting_held(&self, held: bool) {
+        self.painting_held.set(held);
+    }
+
+    /// ### English
+    /// Makes the pinned frame READY again once the consumer fence it was released with has signaled
+    /// (non-blocking). Returns whether it is still waiting on that fence.
+    ///
+    /// ### 中文
+    /// 在被固定帧 release 时附带的 consumer fence signal 之后，使其重新变为 READY（非阻塞）。返回是否仍在等待
+    /// 该 fence。
+    pub fn restore_pinned_frame(&self) -> bool {
+        let Some(slot) = self.shared.snapshot_slot() else {
+            return false;
+        };
+        if self.shared.slot_state(slot) != SLOT_RELEASE_PENDING {
+            return false;
+        }
+
+        let _ = servo::RenderingContext::make_current(self);
+        self.reclaim_release_pending_slots();
+        self.shared.slot_state(slot) == SLOT_RELEASE_PENDING
+    }
+}
@@ -4,11 +4,48 @@
 //! ### 中文
 //! 三缓冲渲染上下文的 GL 资源销毁逻辑。
 
-use crate::engine::frame::TRIPLE_BUFFER_COUNT;
+use crate::engine::frame::{SLOT_HELD, SLOT_PINNED, SLOT_RELEASE_PENDING, TRIPLE_BUFFER_COUNT};
 
 use super::GlfwTripleBufferRenderingContext;
 
 impl GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// Returns whether the consumer is done with every slot texture (no slot held or pinned, and
+    /// every release fence signaled), so the GL resources can be deleted safely.
+    ///
+    /// Reclaims signaled release-pending slots as a side effect. Must run on the Servo thread.
+    ///
+    /// ### 中文
+    /// 返回消费者是否已不再使用任何槽位纹理（没有被持有或固定的槽位，且所有释放 fence 均已 signal），
+    /// 即 GL 资源可以被安全删除。
+    ///
+    /// 副作用：回收 fence 已 signal 的 release-pending 槽位。必须在 Servo 线程执行。
+    pub fn consumer_released(&self) -> bool {
+        if self.destroyed.get() {
+            return true;
+        }
+
+        self.shared.set_resizing(true);
+        let _ = servo::RenderingContext::make_current(self);
+        if !self.unsafe_no_consumer_fence {
+            self.reclaim_release_pending_slots();
+        }
+
+        (0..TRIPLE_BUFFER_COUNT).all(|slot| {
+            let state = self.shared.slot_state(slot);
+            state != SLOT_HELD && state != SLOT_PINNED && state != SLOT_RELEASE_PENDING
+        })
+    }
+
+    /// ### English
+    /// Returns whether the consumer pinned one of the slot frames.
+    ///
+    /// ### 中文
+    /// 返回消费者是否固定了某个槽位帧。
+    pub fn has_pinned_frame(&self) -> bool {
+        self.shared.has_pinned_slot()
+    }
+
     /// ### English
     /// Destroys all GL resources owned by this context (idempotent).
     ///
@@ -38,8 +75,8 @@ impl GlfwTripleBufferRenderingContext {
         }
 
         self.with_slots(|slots| {
-            for slot in slots.iter() {
-                slot.delete(&self.gl);
+            for (index, slot) in slots.iter().enumerate() {
+                slot.delete(&self.gl, &*self.backend, index);
             }
         });
 
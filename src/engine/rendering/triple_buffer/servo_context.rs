@@ -9,10 +9,14 @@ use std::sync::Arc;
 
 use dpi::PhysicalSize;
 use gleam::gl;
-use glow::HasContext as _;
 use surfman::Connection;
 
-use crate::engine::frame::{SLOT_FREE, SLOT_READY, SLOT_RENDERING};
+use crate::engine::flags::{XIAN_WEB_ENGINE_COLORSPACE_LINEAR, XIAN_WEB_ENGINE_COLORSPACE_SRGB};
+use crate::engine::trace::{self, TRACE_SPAN_PRESENT};
+use crate::engine::watchdog::{
+    XIAN_WEB_ENGINE_GL_OP_PREPARE, XIAN_WEB_ENGINE_GL_OP_PRESENT,
+    XIAN_WEB_ENGINE_GL_OP_READ_PIXELS, XIAN_WEB_ENGINE_GL_OP_RESIZE,
+};
 
 use super::context::GlfwTripleBufferRenderingContext;
 
@@ -30,7 +34,10 @@ impl servo::RenderingContext for GlfwTripleBufferRenderingContext {
     /// - `source_rectangle`：需要读回的设备像素矩形区域。
     fn read_to_image(&self, source_rectangle: servo::DeviceIntRect) -> Option<servo::RgbaImage> {
         let slot = self.back_slot.get();
-        self.with_slots(|slots| slots.get(slot)?.read_to_image(&self.gl, source_rectangle))
+        let image =
+            self.with_slots(|slots| slots.get(slot)?.read_to_image(&self.gl, source_rectangle));
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_READ_PIXELS);
+        image
     }
 
     /// ### English
@@ -47,104 +54,89 @@ impl servo::RenderingContext for GlfwTripleBufferRenderingContext {
     ///
     /// This sets a shared "resizing" flag to stop the consumer from acquiring while we mutate
     /// shared state, and prefers resizing the producer-owned back slot first (exclusive ownership).
+    /// While frozen only the size is recorded; `thaw` allocates at it. Views with orphaning resize
+    /// (on a backend that pools textures) skip the flag and swap textures instead (see `orphan_slots`).
     ///
     /// ### 中文
     /// 将所有槽位的 GL 资源 resize 到 `new_size`。
     ///
     /// 该过程会设置共享的 “resizing” 标记以阻止消费者 acquire，并优先 resize 生产者持有的 back 槽位
-    ///（生产者对其具有独占写权限）。
+    ///（生产者对其具有独占写权限）。冻结期间仅记录尺寸，由 `thaw` 按该尺寸分配。启用孤立 resize 的 view
+    ///（且后端支持纹理池）不设置该标记，而是交换纹理（见 `orphan_slots`）。
     fn resize(&self, new_size: PhysicalSize<u32>) {
         let old_size = self.size.get();
         if old_size == new_size {
             return;
         }
+        if self.is_frozen() {
+            self.size.set(new_size);
+            return;
+        }
 
-        self.shared.set_resizing(true);
+        let orphaning = self.orphaning_resize && self.backend.pools_textures();
+        if !orphaning {
+            self.shared.set_resizing(true);
+        }
         let _ = self.make_current();
 
-        let back_slot = self.back_slot.get();
-        self.with_slots_mut(|slots| {
-            self.gl
-                .bind_renderbuffer(gl::RENDERBUFFER, self.depth_stencil_rb);
-            self.gl.renderbuffer_storage(
-                gl::RENDERBUFFER,
-                gl::DEPTH24_STENCIL8,
-                new_size.width as gl::GLsizei,
-                new_size.height as gl::GLsizei,
-            );
-            self.gl.bind_renderbuffer(gl::RENDERBUFFER, 0);
-
-            self.delete_producer_fence_if_any(back_slot);
-            if !self.unsafe_no_consumer_fence {
-                self.delete_consumer_fence_if_any(back_slot);
-            }
-            slots[back_slot].resize(&self.gl, new_size, self.internal_format);
-            self.shared.set_slot_size(back_slot, new_size);
-            self.shared.store_state(back_slot, SLOT_RENDERING);
-
-            for (slot, slot_data) in slots.iter_mut().enumerate() {
-                if slot == back_slot {
-                    continue;
-                }
-
-                let locked = self
-                    .shared
-                    .compare_exchange_state(slot, SLOT_READY, SLOT_RENDERING)
-                    .is_ok()
-                    || self
-                        .shared
-                        .compare_exchange_state(slot, SLOT_FREE, SLOT_RENDERING)
-                        .is_ok();
-                if !locked {
-                    continue;
-                }
-
-                self.delete_producer_fence_if_any(slot);
-                if !self.unsafe_no_consumer_fence {
-                    self.delete_consumer_fence_if_any(slot);
-                }
-                slot_data.resize(&self.gl, new_size, self.internal_format);
-                self.shared.set_slot_size(slot, new_size);
-                self.shared.store_state(slot, SLOT_FREE);
-            }
-        });
+        self.resize_depth_stencil(new_size);
+        if orphaning {
+            self.orphan_slots(new_size);
+        } else {
+            self.reallocate_slots(new_size);
+        }
 
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_RESIZE);
         self.size.set(new_size);
-        self.shared.set_resizing(false);
+        if !orphaning {
+            self.shared.set_resizing(false);
+        }
     }
 
     /// ### English
     /// Prepares the current back slot for rendering (sRGB state + FBO binding).
     ///
-    /// The sRGB enable state is cached to avoid redundant driver calls.
+    /// The sRGB enable state is cached to avoid redundant driver calls. OpenGL ES has no
+    /// `GL_FRAMEBUFFER_SRGB` switch (sRGB attachments always encode), so it is left untouched.
     ///
     /// ### 中文
     /// 为渲染准备当前 back 槽位（sRGB 状态 + FBO 绑定）。
     ///
-    /// sRGB 启用状态会做缓存，以避免重复的驱动调用。
+    /// sRGB 启用状态会做缓存，以避免重复的驱动调用。OpenGL ES 没有 `GL_FRAMEBUFFER_SRGB` 开关
+    /// （sRGB 附件总是编码），因此不做改动。
     fn prepare_for_rendering(&self) {
-        if self.use_srgb {
-            if !self.srgb_enabled.replace(true) {
-                self.gl.enable(gl::FRAMEBUFFER_SRGB);
+        if !self.shared_ctx.is_gles() {
+            if self.wants_srgb() {
+                if !self.srgb_enabled.replace(true) {
+                    self.gl.enable(gl::FRAMEBUFFER_SRGB);
+                }
+            } else if self.srgb_enabled.replace(false) {
+                self.gl.disable(gl::FRAMEBUFFER_SRGB);
             }
-        } else if self.srgb_enabled.replace(false) {
-            self.gl.disable(gl::FRAMEBUFFER_SRGB);
         }
         let idx = self.back_slot.get();
         self.ensure_slot_size(idx);
         self.with_slots(|slots| slots[idx].bind(&self.gl));
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_PREPARE);
     }
 
     /// ### English
     /// Publishes the current back slot as READY and rotates to the next back slot.
     ///
-    /// When enabled, inserts a producer fence (`GLsync`) to let the consumer wait before sampling.
+    /// The render backend signals completion; when enabled, it also inserts a producer fence
+    /// (`GLsync`) to let the consumer wait before sampling.
+    /// Mipmapped views regenerate the slot's mip chain before the fence. In paranoid GL mode the
+    /// frame's first GL error (including WebRender's drawing) is recorded before publishing.
     ///
     /// ### 中文
     /// 将当前 back 槽位发布为 READY，并切换到下一 back 槽位。
     ///
-    /// 启用时会插入生产者 fence（`GLsync`），供消费者在采样前等待。
+    /// 由渲染后端发出完成信号；启用时还会插入生产者 fence（`GLsync`），供消费者在采样前等待。
+    /// 带 mipmap 的 view 会在 fence 之前重新生成该槽位的 mip 链。paranoid GL 模式下，会在发布之前记录
+    /// 该帧的第一个 GL 错误（包括 WebRender 绘制产生的错误）。
     fn present(&self) {
+        let _span = trace::span(TRACE_SPAN_PRESENT);
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_PRESENT);
         let current_back = self.back_slot.get();
 
         let next_back = self.reserved_next_back.take();
@@ -153,23 +145,31 @@ impl servo::RenderingContext for GlfwTripleBufferRenderingContext {
             return;
         };
 
-        let sync_value = if self.unsafe_no_producer_fence {
-            0
-        } else {
-            let sync = unsafe { self.glow.fence_sync(glow::SYNC_GPU_COMMANDS_COMPLETE, 0) }.ok();
-            if sync.is_some() {
-                unsafe {
-                    self.glow.flush();
-                }
-            }
-            sync.map(|s| s.0 as usize as u64).unwrap_or(0)
-        };
+        if self.mipmaps.get() {
+            self.with_slots(|slots| slots[current_back].generate_mipmaps(&self.gl));
+        }
+
+        let texture_id = self.with_slots(|slots| slots[current_back].texture_id);
+        let sync_value = self.backend.signal_frame(
+            &self.glow,
+            current_back,
+            texture_id,
+            !self.unsafe_no_producer_fence,
+        );
         let mut new_seq = self.next_frame_seq.get().wrapping_add(1);
         if new_seq == 0 {
             new_seq = 1;
         }
         self.next_frame_seq.set(new_seq);
-        self.shared.publish(current_back, sync_value, new_seq);
+        let colorspace = if self.srgb_enabled.get() {
+            XIAN_WEB_ENGINE_COLORSPACE_SRGB
+        } else {
+            XIAN_WEB_ENGINE_COLORSPACE_LINEAR
+        };
+        self.check_gl(XIAN_WEB_ENGINE_GL_OP_PRESENT);
+        self.finish_frame_gl_errors();
+        self.shared
+            .publish(current_back, sync_value, new_seq, colorspace);
 
         self.back_slot.set(next_back);
     }
@@ -212,11 +212,11 @@ impl servo::RenderingContext for GlfwTripleBufferRenderingContext {
     }
 
     /// ### English
-    /// Returns the optional refresh driver used to schedule frames.
+    /// Returns the refresh driver used to schedule frames.
     ///
     /// ### 中文
-    /// 返回用于调度帧的可选 refresh driver。
+    /// 返回用于调度帧的 refresh driver。
     fn refresh_driver(&self) -> Option<Rc<dyn servo::RefreshDriver>> {
-        self.refresh_driver.clone()
+        Some(self.refresh_driver.clone())
     }
 }
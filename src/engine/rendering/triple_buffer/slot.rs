@@ -1,14 +1,50 @@
 //! ### English
 //! Per-slot GL resources for triple-buffered offscreen rendering (FBO + texture).
 //!
+//! When the backend allows it, a slot keeps the color textures of its last few sizes: a resize back
+//! to one of them swaps that texture in instead of re-specifying storage, so toggling between panel
+//! sizes does not stall on driver allocations.
+//!
 //! ### 中文
 //! 三缓冲离屏渲染的每槽位 GL 资源（FBO + 纹理）。
+//!
+//! 在后端允许时，槽位会保留最近几种尺寸的颜色纹理：resize 回其中某个尺寸时直接换入该纹理，而不是重新指定存储，
+//! 因此在几种面板尺寸之间切换时不会卡在驱动分配上。
 
 use std::rc::Rc;
 
 use dpi::PhysicalSize;
 use gleam::gl::{self, Gl};
 
+use super::super::backend::{RenderBackend, SlotTextureFormat};
+
+/// ### English
+/// Spare color textures a slot keeps for other sizes.
+///
+/// ### 中文
+/// 槽位为其他尺寸保留的备用颜色纹理数量。
+const TEXTURE_POOL_CAPACITY: usize = 2;
+
+/// ### English
+/// Spare color texture of a slot (same format and generation as the slot's current texture).
+///
+/// ### 中文
+/// 槽位的备用颜色纹理（格式与代次与槽位当前纹理相同）。
+struct PooledTexture {
+    /// ### English
+    /// GL texture name.
+    ///
+    /// ### 中文
+    /// GL 纹理名称。
+    texture_id: gl::GLuint,
+    /// ### English
+    /// Allocated texture size (pixels).
+    ///
+    /// ### 中文
+    /// 纹理分配尺寸（像素）。
+    size: PhysicalSize<u32>,
+}
+
 /// ### English
 /// One triple-buffer slot containing an offscreen FBO and its color texture.
 ///
@@ -33,6 +69,18 @@ pub(super) struct TripleBufferSlot {
     /// ### 中文
     /// 当前纹理分配尺寸（像素）。
     pub(super) size: PhysicalSize<u32>,
+    /// ### English
+    /// Texture generation the color texture was allocated under (see `reconfigure`).
+    ///
+    /// ### 中文
+    /// 颜色纹理分配时所处的纹理代次（见 `reconfigure`）。
+    pub(super) generation: u32,
+    /// ### English
+    /// Spare textures of earlier sizes, most recently used first.
+    ///
+    /// ### 中文
+    /// 之前尺寸的备用纹理，最近使用的在前。
+    pool: Vec<PooledTexture>,
 }
 
 impl TripleBufferSlot {
@@ -41,58 +89,44 @@ impl TripleBufferSlot {
     ///
     /// #### Parameters
     /// - `gl`: GL API used to create resources.
+    /// - `backend`: Render backend allocating the color texture.
+    /// - `slot`: Slot index.
     /// - `depth_stencil_rb`: Shared depth-stencil renderbuffer ID to attach.
     /// - `size`: Initial texture size.
-    /// - `internal_format`: Color internal format (sRGB or linear RGBA).
+    /// - `format`: Color texture formats.
+    /// - `mipmaps`: Use trilinear minification (the mip chain is filled by `generate_mipmaps`).
     ///
     /// ### 中文
     /// 创建一个新槽位（FBO + 纹理），并绑定共享的深度/模板 renderbuffer。
     ///
     /// #### 参数
     /// - `gl`：用于创建资源的 GL API。
+    /// - `backend`：分配颜色纹理的渲染后端。
+    /// - `slot`：槽位索引。
     /// - `depth_stencil_rb`：需要绑定的共享深度/模板 renderbuffer ID。
     /// - `size`：初始纹理尺寸。
-    /// - `internal_format`：颜色内部格式（sRGB 或线性 RGBA）。
+    /// - `format`：颜色纹理格式。
+    /// - `mipmaps`：使用三线性缩小过滤（mip 链由 `generate_mipmaps` 填充）。
     pub(super) fn new(
         gl: &Rc<dyn Gl>,
+        backend: &dyn RenderBackend,
+        slot: usize,
         depth_stencil_rb: gl::GLuint,
         size: PhysicalSize<u32>,
-        internal_format: gl::GLint,
-    ) -> Self {
+        format: SlotTextureFormat,
+        mipmaps: bool,
+    ) -> Result<Self, String> {
+        let texture_id = backend.allocate_texture(gl, slot, size, format, mipmaps)?;
+
         let framebuffer_ids = gl.gen_framebuffers(1);
         gl.bind_framebuffer(gl::FRAMEBUFFER, framebuffer_ids[0]);
-
-        let texture_ids = gl.gen_textures(1);
-        gl.bind_texture(gl::TEXTURE_2D, texture_ids[0]);
-        gl.tex_image_2d(
-            gl::TEXTURE_2D,
-            0,
-            internal_format,
-            size.width as gl::GLsizei,
-            size.height as gl::GLsizei,
-            0,
-            gl::RGBA,
-            gl::UNSIGNED_BYTE,
-            None,
-        );
-        gl.tex_parameter_i(
-            gl::TEXTURE_2D,
-            gl::TEXTURE_MAG_FILTER,
-            gl::LINEAR as gl::GLint,
-        );
-        gl.tex_parameter_i(
-            gl::TEXTURE_2D,
-            gl::TEXTURE_MIN_FILTER,
-            gl::LINEAR as gl::GLint,
-        );
         gl.framebuffer_texture_2d(
             gl::FRAMEBUFFER,
             gl::COLOR_ATTACHMENT0,
             gl::TEXTURE_2D,
-            texture_ids[0],
+            texture_id,
             0,
         );
-        gl.bind_texture(gl::TEXTURE_2D, 0);
         gl.framebuffer_renderbuffer(
             gl::FRAMEBUFFER,
             gl::DEPTH_STENCIL_ATTACHMENT,
@@ -100,53 +134,136 @@ impl TripleBufferSlot {
             depth_stencil_rb,
         );
 
-        Self {
+        Ok(Self {
             framebuffer_id: framebuffer_ids[0],
-            texture_id: texture_ids[0],
+            texture_id,
             size,
-        }
+            generation: 0,
+            pool: Vec::with_capacity(TEXTURE_POOL_CAPACITY + 1),
+        })
     }
 
     /// ### English
-    /// Resizes the color texture storage if the size changed.
+    /// Resizes the color texture storage if the size or texture generation changed.
+    ///
+    /// A size-only change swaps in a pooled texture of the new size (or a freshly allocated one) when
+    /// the backend pools textures, parking the current one; otherwise the backend resizes the texture.
+    /// If the texture was replaced, the new one is attached to the framebuffer. On error the slot
+    /// keeps its old texture, size and generation.
     ///
     /// #### Parameters
     /// - `gl`: GL API used to resize resources.
+    /// - `backend`: Render backend owning the color texture.
+    /// - `slot`: Slot index.
     /// - `new_size`: New texture size.
-    /// - `internal_format`: Color internal format (sRGB or linear RGBA).
+    /// - `format`: Color texture formats.
+    /// - `mipmaps`: Whether the texture gets a mip chain.
+    /// - `generation`: Current texture generation; a different one forces reallocation.
     ///
     /// ### 中文
-    /// 当尺寸变化时，调整颜色纹理的存储大小。
+    /// 当尺寸或纹理代次变化时，调整颜色纹理的存储。
+    ///
+    /// 仅尺寸变化且后端支持纹理池时，会换入池中新尺寸的纹理（或新分配一个），并把当前纹理放回池中；否则由后端调整
+    /// 纹理尺寸。若纹理被替换，则将新纹理绑定到 framebuffer。出错时槽位保留旧纹理、旧尺寸与旧代次。
     ///
     /// #### 参数
     /// - `gl`：用于调整资源的 GL API。
+    /// - `backend`：持有颜色纹理的渲染后端。
+    /// - `slot`：槽位索引。
     /// - `new_size`：新的纹理尺寸。
-    /// - `internal_format`：颜色内部格式（sRGB 或线性 RGBA）。
+    /// - `format`：颜色纹理格式。
+    /// - `mipmaps`：纹理是否带 mip 链。
+    /// - `generation`：当前纹理代次；与槽位不同时强制重新分配。
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn resize(
         &mut self,
         gl: &Rc<dyn Gl>,
+        backend: &dyn RenderBackend,
+        slot: usize,
         new_size: PhysicalSize<u32>,
-        internal_format: gl::GLint,
-    ) {
-        if self.size == new_size {
-            return;
+        format: SlotTextureFormat,
+        mipmaps: bool,
+        generation: u32,
+    ) -> Result<(), String> {
+        if self.size == new_size && self.generation == generation {
+            return Ok(());
         }
 
-        gl.bind_texture(gl::TEXTURE_2D, self.texture_id);
-        gl.tex_image_2d(
-            gl::TEXTURE_2D,
-            0,
-            internal_format,
-            new_size.width as gl::GLsizei,
-            new_size.height as gl::GLsizei,
-            0,
-            gl::RGBA,
-            gl::UNSIGNED_BYTE,
-            None,
-        );
-        gl.bind_texture(gl::TEXTURE_2D, 0);
+        let texture_id = if self.generation == generation && backend.pools_textures() {
+            let texture_id = match self.pool.iter().position(|pooled| pooled.size == new_size) {
+                Some(index) => self.pool.remove(index).texture_id,
+                None => backend.allocate_texture(gl, slot, new_size, format, mipmaps)?,
+            };
+            self.pool.insert(
+                0,
+                PooledTexture {
+                    texture_id: self.texture_id,
+                    size: self.size,
+                },
+            );
+            if self.pool.len() > TEXTURE_POOL_CAPACITY
+                && let Some(evicted) = self.pool.pop()
+            {
+                backend.delete_texture(gl, slot, evicted.texture_id);
+            }
+            texture_id
+        } else {
+            self.drain_pool(gl, backend, slot);
+            backend.resize_texture(gl, slot, self.texture_id, new_size, format, mipmaps)?
+        };
+        if texture_id != self.texture_id {
+            gl.bind_framebuffer(gl::FRAMEBUFFER, self.framebuffer_id);
+            gl.framebuffer_texture_2d(
+                gl::FRAMEBUFFER,
+                gl::COLOR_ATTACHMENT0,
+                gl::TEXTURE_2D,
+                texture_id,
+                0,
+            );
+            self.texture_id = texture_id;
+        }
 
         self.size = new_size;
+        self.generation = generation;
+        Ok(())
+    }
+
+    /// ### English
+    /// Deletes the pooled spare textures (e.g. when freezing, so their memory is released too).
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API used to delete resources.
+    /// - `backend`: Render backend owning the textures.
+    /// - `slot`: Slot index.
+    ///
+    /// ### 中文
+    /// 删除池中的备用纹理（例如冻结时，使其显存也被释放）。
+    ///
+    /// #### 参数
+    /// - `gl`：用于删除资源的 GL API。
+    /// - `backend`：持有这些纹理的渲染后端。
+    /// - `slot`：槽位索引。
+    pub(super) fn drain_pool(&mut self, gl: &Rc<dyn Gl>, backend: &dyn RenderBackend, slot: usize) {
+        for pooled in self.pool.drain(..) {
+            backend.delete_texture(gl, slot, pooled.texture_id);
+        }
+    }
+
+    /// ### English
+    /// Regenerates the mip chain of the color texture from level 0.
+    ///
+    /// #### Parameters
+    /// - `gl`: GL API used to generate mipmaps.
+    ///
+    /// ### 中文
+    /// 基于第 0 级重新生成颜色纹理的 mip 链。
+    ///
+    /// #### 参数
+    /// - `gl`：用于生成 mipmap 的 GL API。
+    pub(super) fn generate_mipmaps(&self, gl: &Rc<dyn Gl>) {
+        gl.bind_texture(gl::TEXTURE_2D, self.texture_id);
+        gl.generate_mipmap(gl::TEXTURE_2D);
+        gl.bind_texture(gl::TEXTURE_2D, 0);
     }
 
     /// ### English
@@ -154,14 +271,21 @@ impl TripleBufferSlot {
     ///
     /// #### Parameters
     /// - `gl`: GL API used to delete resources.
+    /// - `backend`: Render backend owning the color texture.
+    /// - `slot`: Slot index.
     ///
     /// ### 中文
     /// 删除该槽位持有的 GL 资源。
     ///
     /// #### 参数
     /// - `gl`：用于删除资源的 GL API。
-    pub(super) fn delete(&self, gl: &Rc<dyn Gl>) {
-        gl.delete_textures(&[self.texture_id]);
+    /// - `backend`：持有颜色纹理的渲染后端。
+    /// - `slot`：槽位索引。
+    pub(super) fn delete(&self, gl: &Rc<dyn Gl>, backend: &dyn RenderBackend, slot: usize) {
+        for pooled in &self.pool {
+            backend.delete_texture(gl, slot, pooled.texture_id);
+        }
+        backend.delete_texture(gl, slot, self.texture_id);
         gl.delete_framebuffers(&[self.framebuffer_id]);
     }
 
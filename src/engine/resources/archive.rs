@@ -0,0 +1,288 @@
+//! ### English
+//! Zip-archive (`.zip`/`.jar`/`.pak`) `ResourceReader` for Servo.
+//!
+//! The archive is kept in memory and entries are inflated on demand, so resources can ship inside a
+//! mod jar without being extracted to disk. Only stored and deflated entries are supported (no
+//! Zip64, no encryption).
+//!
+//! ### 中文
+//! 基于 zip 归档（`.zip`/`.jar`/`.pak`）的 Servo `ResourceReader`。
+//!
+//! 归档保存在内存中，条目按需解压，因此资源可随 mod jar 分发而无需解压到磁盘。仅支持 stored 与 deflate
+//! 条目（不支持 Zip64 与加密）。
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// ### English
+/// Signature of the end-of-central-directory record.
+///
+/// ### 中文
+/// 中央目录结束记录的签名。
+const EOCD_SIGNATURE: u32 = 0x0605_4b50;
+
+/// ### English
+/// Signature of one central-directory file header.
+///
+/// ### 中文
+/// 中央目录文件头的签名。
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+
+/// ### English
+/// Signature of one local file header.
+///
+/// ### 中文
+/// 本地文件头的签名。
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+
+/// ### English
+/// Fixed size of the end-of-central-directory record (without comment).
+///
+/// ### 中文
+/// 中央目录结束记录的固定长度（不含注释）。
+const EOCD_LEN: usize = 22;
+
+/// ### English
+/// Directory prefix also searched for resource files (e.g. `resources/prefs.json`).
+///
+/// ### 中文
+/// 查找资源文件时额外搜索的目录前缀（例如 `resources/prefs.json`）。
+const RESOURCES_PREFIX: &str = "resources/";
+
+/// ### English
+/// Location and encoding of one archive entry.
+///
+/// ### 中文
+/// 单个归档条目的位置与编码信息。
+#[derive(Clone, Copy)]
+struct ArchiveEntry {
+    /// ### English
+    /// Offset of the entry's local file header.
+    ///
+    /// ### 中文
+    /// 条目本地文件头的偏移。
+    header_offset: usize,
+    /// ### English
+    /// Compression method (`0` = stored, `8` = deflate).
+    ///
+    /// ### 中文
+    /// 压缩方式（`0` = stored，`8` = deflate）。
+    method: u16,
+    /// ### English
+    /// Compressed size in bytes.
+    ///
+    /// ### 中文
+    /// 压缩后大小（字节）。
+    compressed_size: usize,
+    /// ### English
+    /// Uncompressed size in bytes.
+    ///
+    /// ### 中文
+    /// 解压后大小（字节）。
+    size: usize,
+}
+
+/// ### English
+/// In-memory zip archive serving Servo resource files.
+///
+/// ### 中文
+/// 在内存中提供 Servo 资源文件的 zip 归档。
+pub struct ArchiveResourceReader {
+    /// ### English
+    /// Raw archive bytes.
+    ///
+    /// ### 中文
+    /// 归档原始字节。
+    data: Vec<u8>,
+    /// ### English
+    /// Entries keyed by their path inside the archive.
+    ///
+    /// ### 中文
+    /// 按归档内路径索引的条目。
+    entries: HashMap<String, ArchiveEntry>,
+}
+
+impl ArchiveResourceReader {
+    /// ### English
+    /// Parses the central directory of an in-memory archive.
+    ///
+    /// #### Parameters
+    /// - `data`: Complete archive bytes.
+    ///
+    /// ### 中文
+    /// 解析内存中归档的中央目录。
+    ///
+    /// #### 参数
+    /// - `data`：完整的归档字节。
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String> {
+        let eocd = find_eocd(&data).ok_or("Not a zip archive")?;
+        let count = read_u16(&data, eocd + 10).ok_or("Truncated archive")? as usize;
+        let dir_offset = read_u32(&data, eocd + 16).ok_or("Truncated archive")? as usize;
+        if count == 0xFFFF || dir_offset == 0xFFFF_FFFF {
+            return Err("Zip64 archives are not supported".to_string());
+        }
+
+        let mut entries = HashMap::with_capacity(count);
+        let mut cursor = dir_offset;
+        for _ in 0..count {
+            if read_u32(&data, cursor) != Some(CENTRAL_HEADER_SIGNATURE) {
+                return Err("Corrupt central directory".to_string());
+            }
+            let field = |offset: usize| read_u32(&data, cursor + offset).map(|v| v as usize);
+            let short = |offset: usize| read_u16(&data, cursor + offset).map(|v| v as usize);
+            let (Some(flags), Some(method), Some(compressed_size), Some(size)) =
+                (short(8), short(10), field(20), field(24))
+            else {
+                return Err("Truncated archive".to_string());
+            };
+            let (Some(name_len), Some(extra_len), Some(comment_len), Some(header_offset)) =
+                (short(28), short(30), short(32), field(42))
+            else {
+                return Err("Truncated archive".to_string());
+            };
+            let name = data
+                .get(cursor + 46..cursor + 46 + name_len)
+                .ok_or("Truncated archive")?;
+
+            if flags & 1 == 0 && !name.ends_with(b"/") {
+                entries.insert(
+                    String::from_utf8_lossy(name).into_owned(),
+                    ArchiveEntry {
+                        header_offset,
+                        method: method as u16,
+                        compressed_size,
+                        size,
+                    },
+                );
+            }
+            cursor += 46 + name_len + extra_len + comment_len;
+        }
+
+        Ok(Self { data, entries })
+    }
+
+    /// ### English
+    /// Reads and parses an archive file.
+    ///
+    /// #### Parameters
+    /// - `path`: Archive file path.
+    ///
+    /// ### 中文
+    /// 读取并解析归档文件。
+    ///
+    /// #### 参数
+    /// - `path`：归档文件路径。
+    pub fn open(path: &std::path::Path) -> Result<Self, String> {
+        let data = std::fs::read(path).map_err(|err| err.to_string())?;
+        Self::from_bytes(data)
+    }
+
+    /// ### English
+    /// Returns the decompressed contents of one entry, or `None` if it is missing or unreadable.
+    ///
+    /// #### Parameters
+    /// - `name`: Entry path inside the archive.
+    ///
+    /// ### 中文
+    /// 返回单个条目解压后的内容；若条目不存在或无法读取则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `name`：条目在归档内的路径。
+    pub fn entry(&self, name: &str) -> Option<Vec<u8>> {
+        let entry = self.entries.get(name)?;
+        let header = entry.header_offset;
+        if read_u32(&self.data, header)? != LOCAL_HEADER_SIGNATURE {
+            return None;
+        }
+        let name_len = read_u16(&self.data, header + 26)? as usize;
+        let extra_len = read_u16(&self.data, header + 28)? as usize;
+        let start = header + 30 + name_len + extra_len;
+        let compressed = self.data.get(start..start + entry.compressed_size)?;
+
+        match entry.method {
+            0 => Some(compressed.to_vec()),
+            8 => miniz_oxide::inflate::decompress_to_vec_with_limit(compressed, entry.size).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl servo::resources::ResourceReaderMethods for ArchiveResourceReader {
+    /// ### English
+    /// Reads one Servo resource file from the archive root or its `resources/` directory.
+    ///
+    /// #### Parameters
+    /// - `file`: Resource identifier (provides the relative filename).
+    ///
+    /// Returns an empty buffer if the entry is missing (Servo treats missing resources as empty).
+    ///
+    /// ### 中文
+    /// 从归档根目录或其 `resources/` 目录读取一个 Servo 资源文件。
+    ///
+    /// #### 参数
+    /// - `file`：资源标识（提供相对文件名）。
+    ///
+    /// 条目不存在时返回空缓冲区（Servo 会把缺失资源视为空）。
+    fn read(&self, file: servo::resources::Resource) -> Vec<u8> {
+        let filename = file.filename();
+        self.entry(filename)
+            .or_else(|| self.entry(&format!("{RESOURCES_PREFIX}{filename}")))
+            .unwrap_or_default()
+    }
+
+    /// ### English
+    /// Returns the explicit file allowlist for sandboxing (nothing is read from disk).
+    ///
+    /// ### 中文
+    /// 返回 sandbox 的文件白名单（不会从磁盘读取任何文件）。
+    fn sandbox_access_files(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    /// ### English
+    /// Returns the directory allowlist for sandboxing (nothing is read from disk).
+    ///
+    /// ### 中文
+    /// 返回 sandbox 的目录白名单（不会从磁盘读取任何文件）。
+    fn sandbox_access_files_dirs(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+}
+
+/// ### English
+/// Locates the end-of-central-directory record (searching backwards over a trailing comment).
+///
+/// #### Parameters
+/// - `data`: Complete archive bytes.
+///
+/// ### 中文
+/// 定位中央目录结束记录（向前跳过可能存在的尾部注释）。
+///
+/// #### 参数
+/// - `data`：完整的归档字节。
+fn find_eocd(data: &[u8]) -> Option<usize> {
+    let last = data.len().checked_sub(EOCD_LEN)?;
+    let first = last.saturating_sub(u16::MAX as usize);
+    (first..=last)
+        .rev()
+        .find(|&offset| read_u32(data, offset) == Some(EOCD_SIGNATURE))
+}
+
+/// ### English
+/// Reads a little-endian `u16` at `offset`.
+///
+/// ### 中文
+/// 在 `offset` 处读取小端 `u16`。
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// ### English
+/// Reads a little-endian `u32` at `offset`.
+///
+/// ### 中文
+/// 在 `offset` 处读取小端 `u32`。
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
@@ -1,14 +1,25 @@
 //! ### English
 //! Resource reader integration for Servo.
 //!
-//! Allows configuring a directory-based `ResourceReader` from the embedder side.
+//! Allows configuring a directory- or archive-based `ResourceReader` from the embedder side.
 //!
 //! ### 中文
 //! Servo 的资源读取器集成。
 //!
-//! 允许宿主侧配置基于目录的 `ResourceReader`。
+//! 允许宿主侧配置基于目录或归档的 `ResourceReader`。
 use std::path::PathBuf;
 
+mod archive;
+
+pub use archive::ArchiveResourceReader;
+
+/// ### English
+/// Boxed resource reader that can be handed to the Servo thread.
+///
+/// ### 中文
+/// 可交给 Servo 线程的 boxed 资源读取器。
+pub type BoxedResourceReader = Box<dyn servo::resources::ResourceReaderMethods + Send + Sync>;
+
 /// ### English
 /// Directory-based `ResourceReader` for Servo.
 ///
@@ -87,5 +98,20 @@ impl servo::resources::ResourceReaderMethods for DirResourceReader {
 /// #### 参数
 /// - `resources_dir`：Servo 资源文件的根目录。
 pub fn set_resources_dir(resources_dir: PathBuf) {
-    servo::resources::set(Box::new(DirResourceReader::new(resources_dir)));
+    set_resource_reader(Box::new(DirResourceReader::new(resources_dir)));
+}
+
+/// ### English
+/// Installs a resource reader for Servo, replacing the current one.
+///
+/// #### Parameters
+/// - `reader`: Reader used to resolve Servo resource files.
+///
+/// ### 中文
+/// 为 Servo 安装资源读取器，替换当前读取器。
+///
+/// #### 参数
+/// - `reader`：用于解析 Servo 资源文件的读取器。
+pub fn set_resource_reader(reader: BoxedResourceReader) {
+    servo::resources::set(reader);
 }
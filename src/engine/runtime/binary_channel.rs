@@ -0,0 +1,208 @@
+//! ### English
+//! Per-view binary channel between the embedder and the page.
+//!
+//! Byte buffers travel in both directions without the UI code having to deal with base64: the page
+//! receives `ArrayBuffer`s and sends typed arrays. Buffers come from a small per-view pool, so
+//! streaming map tiles or item icons does not allocate per message once the pool is warm.
+//!
+//! ### 中文
+//! embedder 与页面之间的每 view 二进制通道。
+//!
+//! 字节缓冲区可双向传输，UI 代码无需处理 base64：页面收到的是 `ArrayBuffer`，发送的是类型化数组。缓冲区取自
+//! 每 view 的小型缓冲池，因此池预热后，流式传输地图瓦片或物品图标不会为每条消息分配内存。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// ### English
+/// Largest message accepted in either direction, in bytes.
+///
+/// ### 中文
+/// 两个方向上可接受的最大消息字节数。
+pub(crate) const MAX_BINARY_MESSAGE_BYTES: usize = 16 * 1024 * 1024;
+
+/// ### English
+/// Most free buffers kept in the pool.
+///
+/// ### 中文
+/// 缓冲池中最多保留的空闲缓冲区数量。
+const MAX_POOLED_BUFFERS: usize = 16;
+
+/// ### English
+/// Largest buffer capacity returned to the pool (bigger buffers are freed).
+///
+/// ### 中文
+/// 可归还到缓冲池的最大缓冲区容量（更大的缓冲区会被释放）。
+const MAX_POOLED_CAPACITY: usize = 4 * 1024 * 1024;
+
+/// ### English
+/// Most received messages the embedder has not released yet; further page messages are dropped.
+///
+/// ### 中文
+/// 宿主尚未释放的已接收消息的上限；超出后页面发送的消息会被丢弃。
+const MAX_UNRELEASED_MESSAGES: usize = 256;
+
+/// ### English
+/// Buffer pool and received messages of one view, shared by the handle and the Servo thread.
+///
+/// ### 中文
+/// 单个 view 的缓冲池与已接收消息，由句柄与 Servo 线程共享。
+pub(crate) struct BinaryChannel {
+    /// ### English
+    /// Free buffers (cleared, capacity kept).
+    ///
+    /// ### 中文
+    /// 空闲缓冲区（已清空，保留容量）。
+    pool: Mutex<Vec<Vec<u8>>>,
+    /// ### English
+    /// Messages received from the page, keyed by message ID, until the embedder releases them.
+    ///
+    /// ### 中文
+    /// 从页面收到的消息，按消息 ID 索引，直到宿主释放。
+    received: Mutex<HashMap<u64, Vec<u8>>>,
+    /// ### English
+    /// Next message ID (starts at 1).
+    ///
+    /// ### 中文
+    /// 下一个消息 ID（从 1 开始）。
+    next_id: AtomicU64,
+    /// ### English
+    /// Page messages dropped because too many were unreleased (never reset).
+    ///
+    /// ### 中文
+    /// 因未释放的消息过多而丢弃的页面消息数（从不清零）。
+    dropped: AtomicU64,
+}
+
+impl Default for BinaryChannel {
+    /// ### English
+    /// Creates an empty channel whose first message ID is 1.
+    ///
+    /// ### 中文
+    /// 创建一个空通道，首个消息 ID 为 1。
+    fn default() -> Self {
+        Self {
+            pool: Mutex::new(Vec::new()),
+            received: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+            dropped: AtomicU64::new(0),
+        }
+    }
+}
+
+impl BinaryChannel {
+    /// ### English
+    /// Takes an empty buffer from the pool (or allocates one).
+    ///
+    /// ### 中文
+    /// 从缓冲池取出一个空缓冲区（或新分配一个）。
+    pub(crate) fn buffer(&self) -> Vec<u8> {
+        self.pool
+            .lock()
+            .ok()
+            .and_then(|mut pool| pool.pop())
+            .unwrap_or_default()
+    }
+
+    /// ### English
+    /// Returns a buffer to the pool.
+    ///
+    /// #### Parameters
+    /// - `buffer`: Buffer no longer in use.
+    ///
+    /// ### 中文
+    /// 将缓冲区归还到缓冲池。
+    ///
+    /// #### 参数
+    /// - `buffer`：不再使用的缓冲区。
+    pub(crate) fn recycle(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() > MAX_POOLED_CAPACITY {
+            return;
+        }
+        buffer.clear();
+        if let Ok(mut pool) = self.pool.lock()
+            && pool.len() < MAX_POOLED_BUFFERS
+        {
+            pool.push(buffer);
+        }
+    }
+
+    /// ### English
+    /// Stores a message received from the page and returns its ID, or `None` (recycling the buffer)
+    /// if too many messages are still unreleased.
+    ///
+    /// #### Parameters
+    /// - `data`: Message bytes.
+    ///
+    /// ### 中文
+    /// 保存一条从页面收到的消息并返回其 ID；若未释放的消息过多，则回收缓冲区并返回 `None`。
+    ///
+    /// #### 参数
+    /// - `data`：消息字节。
+    pub(crate) fn receive(&self, data: Vec<u8>) -> Option<u64> {
+        let Ok(mut received) = self.received.lock() else {
+            return None;
+        };
+        if received.len() >= MAX_UNRELEASED_MESSAGES {
+            drop(received);
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            self.recycle(data);
+            return None;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        received.insert(id, data);
+        Some(id)
+    }
+
+    /// ### English
+    /// Returns the number of page messages dropped since creation.
+    ///
+    /// ### 中文
+    /// 返回自创建以来丢弃的页面消息数。
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns the address and length of a received message; the bytes stay valid until `release`.
+    ///
+    /// #### Parameters
+    /// - `id`: Message ID.
+    ///
+    /// ### 中文
+    /// 返回已接收消息的地址与长度；在 `release` 之前这些字节一直有效。
+    ///
+    /// #### 参数
+    /// - `id`：消息 ID。
+    pub(crate) fn message(&self, id: u64) -> Option<(*const u8, usize)> {
+        let received = self.received.lock().ok()?;
+        let data = received.get(&id)?;
+        Some((data.as_ptr(), data.len()))
+    }
+
+    /// ### English
+    /// Releases a received message, returning its buffer to the pool. Returns `false` for unknown
+    /// IDs.
+    ///
+    /// #### Parameters
+    /// - `id`: Message ID.
+    ///
+    /// ### 中文
+    /// 释放一条已接收的消息，并将其缓冲区归还到缓冲池。未知 ID 返回 `false`。
+    ///
+    /// #### 参数
+    /// - `id`：消息 ID。
+    pub(crate) fn release(&self, id: u64) -> bool {
+        let data = self
+            .received
+            .lock()
+            .ok()
+            .and_then(|mut received| received.remove(&id));
+        let Some(data) = data else {
+            return false;
+        };
+        self.recycle(data);
+        true
+    }
+}
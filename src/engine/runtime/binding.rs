@@ -0,0 +1,196 @@
+//! ### English
+//! Late binding of a view handle to the `(id, token)` the Servo thread allocates for it.
+//!
+//! A handle from `create_view` is bound before it is returned. A handle from `create_view_async` is
+//! returned unbound: per-view commands sent meanwhile wait in a backlog, pending work stays marked,
+//! and a close is remembered; all of it is forwarded once the Servo thread reports the view created.
+//!
+//! ### 中文
+//! 将 view 句柄延迟绑定到 Servo 线程为其分配的 `(id, token)`。
+//!
+//! `create_view` 返回的句柄在返回前即已绑定。`create_view_async` 返回的句柄尚未绑定：期间发送的 per-view 命令会在
+//! 积压队列中等待，pending work 保持标记，关闭请求会被记住；Servo 线程报告 view 创建完成后，这些都会被转发。
+
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+use super::coalesced::PendingWork;
+use super::command::{Command, ViewCommand};
+use super::pending::PendingIdQueue;
+use super::queue::CommandQueue;
+
+/// ### English
+/// What happened to an unbound handle while its view was being created.
+///
+/// ### 中文
+/// view 创建期间未绑定句柄上发生的操作。
+#[derive(Default)]
+struct Deferred {
+    /// ### English
+    /// Per-view commands to forward once bound, in send order.
+    ///
+    /// ### 中文
+    /// 绑定后要转发的 per-view 命令（按发送顺序）。
+    commands: Vec<ViewCommand>,
+    /// ### English
+    /// Whether the handle was closed (the view is destroyed right after creation).
+    ///
+    /// ### 中文
+    /// 句柄是否已被关闭（view 创建后会立即被销毁）。
+    closed: bool,
+}
+
+/// ### English
+/// The `(id, token)` of a view handle, known once the Servo thread created the view.
+///
+/// ### 中文
+/// view 句柄的 `(id, token)`；Servo 线程创建 view 后才可知。
+pub(super) struct ViewBinding {
+    /// ### English
+    /// View ID and token (unset until created, and forever if creation failed).
+    ///
+    /// ### 中文
+    /// view ID 与 token（创建前未设置；若创建失败则永远不会设置）。
+    ids: OnceLock<(u32, u64)>,
+    /// ### English
+    /// Deferred work (`None` once creation finished or failed).
+    ///
+    /// ### 中文
+    /// 延迟的操作（创建完成或失败后为 `None`）。
+    deferred: Mutex<Option<Deferred>>,
+}
+
+impl ViewBinding {
+    /// ### English
+    /// Creates a binding whose view is still being created.
+    ///
+    /// ### 中文
+    /// 创建一个 view 仍在创建中的绑定。
+    pub(super) fn unbound() -> Self {
+        Self {
+            ids: OnceLock::new(),
+            deferred: Mutex::new(Some(Deferred::default())),
+        }
+    }
+
+    /// ### English
+    /// Returns the view's `(id, token)`, or `None` while it is being created or if creation failed.
+    ///
+    /// ### 中文
+    /// 返回 view 的 `(id, token)`；创建中或创建失败时返回 `None`。
+    #[inline]
+    pub(super) fn ids(&self) -> Option<(u32, u64)> {
+        self.ids.get().copied()
+    }
+
+    /// ### English
+    /// Queues a command for the view that is still being created. Gives the command back if creation
+    /// finished meanwhile (send it directly if [`Self::ids`] is now set) or failed.
+    ///
+    /// #### Parameters
+    /// - `command`: Per-view command.
+    ///
+    /// ### 中文
+    /// 为仍在创建中的 view 排队一个命令。若创建在此期间已完成（此时 [`Self::ids`] 已设置，应直接发送）或失败，则交还
+    /// 该命令。
+    ///
+    /// #### 参数
+    /// - `command`：per-view 命令。
+    pub(super) fn defer(&self, command: ViewCommand) -> Result<(), ViewCommand> {
+        let Ok(mut deferred) = self.deferred.lock() else {
+            return Err(command);
+        };
+        match deferred.as_mut() {
+            Some(deferred) => {
+                deferred.commands.push(command);
+                Ok(())
+            }
+            None => Err(command),
+        }
+    }
+
+    /// ### English
+    /// Returns whether the view is still being created (used after marking pending work while
+    /// unbound: if creation finished meanwhile, the caller pushes the ID itself).
+    ///
+    /// ### 中文
+    /// 返回 view 是否仍在创建中（在未绑定时标记 pending work 之后使用：若创建已在此期间完成，则由调用方自行 push ID）。
+    pub(super) fn is_creating(&self) -> bool {
+        self.deferred
+            .lock()
+            .is_ok_and(|deferred| deferred.is_some())
+    }
+
+    /// ### English
+    /// Remembers a close of the view still being created. Returns `false` if creation already
+    /// finished (destroy it through [`Self::ids`]) or failed.
+    ///
+    /// ### 中文
+    /// 记住对仍在创建中的 view 的关闭请求。若创建已完成（应通过 [`Self::ids`] 销毁）或已失败，则返回 `false`。
+    pub(super) fn defer_close(&self) -> bool {
+        let Ok(mut deferred) = self.deferred.lock() else {
+            return false;
+        };
+        match deferred.as_mut() {
+            Some(deferred) => {
+                deferred.closed = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// ### English
+    /// Records the outcome of the creation and forwards the deferred work: the backlog (or a destroy
+    /// if the handle was closed) goes to the command queue, and marked pending work to the pending
+    /// queue.
+    ///
+    /// #### Parameters
+    /// - `ids`: `(id, token)` of the created view, or `None` if creation failed.
+    /// - `command_queue`: Command queue of the view's Servo thread.
+    /// - `pending`: The view's pending-work bits.
+    /// - `pending_queue`: Pending view-ID queue of the view's Servo thread.
+    /// - `thread_handle`: The view's Servo thread (unparked if anything was forwarded).
+    ///
+    /// ### 中文
+    /// 记录创建结果并转发延迟的操作：积压命令（若句柄已关闭则为销毁命令）进入命令队列，已标记的 pending work 进入
+    /// pending 队列。
+    ///
+    /// #### 参数
+    /// - `ids`：已创建 view 的 `(id, token)`；创建失败时为 `None`。
+    /// - `command_queue`：view 所在 Servo 线程的命令队列。
+    /// - `pending`：view 的 pending-work 位。
+    /// - `pending_queue`：view 所在 Servo 线程的 pending view-ID 队列。
+    /// - `thread_handle`：view 所在的 Servo 线程（有操作被转发时会被 unpark）。
+    pub(super) fn settle(
+        &self,
+        ids: Option<(u32, u64)>,
+        command_queue: &CommandQueue,
+        pending: &PendingWork,
+        pending_queue: &PendingIdQueue,
+        thread_handle: &thread::Thread,
+    ) {
+        let Ok(mut deferred) = self.deferred.lock() else {
+            return;
+        };
+        let Some(deferred) = deferred.take() else {
+            return;
+        };
+        let Some((id, token)) = ids else {
+            return;
+        };
+        let _ = self.ids.set((id, token));
+
+        if deferred.closed {
+            command_queue.push(Command::DestroyView { id, token });
+        } else {
+            for command in deferred.commands {
+                command_queue.push(Command::View { id, token, command });
+            }
+            if pending.is_marked() {
+                let _ = pending_queue.push(id);
+            }
+        }
+        thread_handle.unpark();
+    }
+}
@@ -3,15 +3,30 @@
 //!
 //! ### 中文
 //! 宿主线程与独立 Servo 线程之间的内部命令协议。
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use dpi::PhysicalSize;
+use url::Url;
 
 use crate::engine::frame::SharedFrameState;
 use crate::engine::input::{CoalescedMouseMove, CoalescedResize, InputEventQueue};
+use crate::engine::input_types::XianWebEngineInputEvent;
 use crate::engine::lockfree::OneShot;
+use crate::engine::rendering::VulkanFrameExports;
+use crate::engine::resources::BoxedResourceReader;
+use crate::engine::thread_config::ThreadConfig;
 
+use super::binary_channel::BinaryChannel;
 use super::coalesced::{CoalescedLoadUrl, PendingWork};
+use super::events::ViewEventQueue;
+use super::host_function::HostFunction;
+use super::image_limits::XianWebEngineImageLimits;
+use super::recording::RecordedEntry;
+#[cfg(feature = "screencast")]
+use super::screencast::ScreencastServer;
+use super::view_strings::ViewStrings;
 
 /// ### English
 /// Commands sent from embedder threads to the dedicated Servo thread.
@@ -42,15 +57,70 @@ pub(super) enum Command {
         /// ### 中文
         /// 每 view 的 pending work bitmask（用于合并唤醒与 push）。
         pending: Arc<PendingWork>,
+        /// ### English
+        /// Per-view event queue (Servo thread -> embedder).
+        ///
+        /// ### 中文
+        /// 每 view 的事件队列（Servo 线程 -> 宿主）。
+        events: Arc<ViewEventQueue>,
+        /// ### English
+        /// Per-view string snapshots (URL, title, last error).
+        ///
+        /// ### 中文
+        /// 每 view 的字符串快照（URL、标题、最近错误）。
+        strings: Arc<ViewStrings>,
+        /// ### English
+        /// Per-view binary channel (buffer pool and messages received from the page).
+        ///
+        /// ### 中文
+        /// 每 view 的二进制通道（缓冲池与从页面收到的消息）。
+        binary: Arc<BinaryChannel>,
         target_fps: u32,
+        /// ### English
+        /// View group to join (`0` = none; the group's refresh driver replaces `target_fps`).
+        ///
+        /// ### 中文
+        /// 要加入的 view group（`0` 表示不加入；group 的 refresh driver 优先于 `target_fps`）。
+        group: u32,
+        /// ### English
+        /// Initial render scale (internal pixels per logical pixel; `initial_size` is logical).
+        ///
+        /// ### 中文
+        /// 初始渲染缩放（每逻辑像素对应的内部像素数；`initial_size` 为逻辑尺寸）。
+        render_scale: f32,
+        /// ### English
+        /// Slot texture color format (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`).
+        ///
+        /// ### 中文
+        /// 槽位纹理颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`）。
+        color_format: u32,
+        /// ### English
+        /// Whether slot textures are mipmapped (regenerated on every present).
+        ///
+        /// ### 中文
+        /// 槽位纹理是否带 mipmap（每次 present 时重新生成）。
+        mipmaps: bool,
+        /// ### English
+        /// Whether resizes orphan the slot textures instead of reallocating them under the resizing
+        /// flag.
+        ///
+        /// ### 中文
+        /// resize 是否以孤立方式替换槽位纹理，而不是在 resizing 标记下重新分配。
+        orphaning_resize: bool,
+        /// ### English
+        /// Vulkan export table when the view uses the Vulkan backend.
+        ///
+        /// ### 中文
+        /// view 使用 Vulkan 后端时的 Vulkan 导出表。
+        vulkan_exports: Option<Arc<VulkanFrameExports>>,
         unsafe_no_consumer_fence: bool,
         unsafe_no_producer_fence: bool,
         /// ### English
-        /// One-shot response for reporting `(id, token)` or an error back to the caller.
+        /// Where `(id, token)` or an error is reported back.
         ///
         /// ### 中文
-        /// 一次性回包：把 `(id, token)` 或错误返回给调用方。
-        response: Arc<OneShot<Result<(u32, u64), String>>>,
+        /// `(id, token)` 或错误的回报目标。
+        response: CreateViewReply,
     },
     /// ### English
     /// Destroys a view and its GL resources on the Servo thread.
@@ -59,9 +129,620 @@ pub(super) enum Command {
     /// 在 Servo 线程销毁 view 并释放其 GL 资源。
     DestroyView { id: u32, token: u64 },
     /// ### English
+    /// Applies a per-view control request on the Servo thread.
+    ///
+    /// ### 中文
+    /// 在 Servo 线程应用一个 per-view 控制请求。
+    View {
+        id: u32,
+        token: u64,
+        command: ViewCommand,
+    },
+    /// ### English
+    /// Creates a view group with one shared refresh driver.
+    ///
+    /// ### 中文
+    /// 创建一个共享 refresh driver 的 view group。
+    CreateViewGroup {
+        /// ### English
+        /// Target FPS numerator for the shared driver (0 means external-vsync mode).
+        ///
+        /// ### 中文
+        /// 共享 driver 的目标 FPS 分子（0 表示外部 vsync 模式）。
+        target_fps: u32,
+        /// ### English
+        /// Target FPS denominator (the rate is `target_fps / fps_denominator`).
+        ///
+        /// ### 中文
+        /// 目标 FPS 分母（帧率为 `target_fps / fps_denominator`）。
+        fps_denominator: u32,
+        /// ### English
+        /// One-shot response carrying the new group ID or an error.
+        ///
+        /// ### 中文
+        /// 一次性回包：携带新 group ID 或错误。
+        response: Arc<OneShot<Result<u32, String>>>,
+    },
+    /// ### English
+    /// Starts the WebDriver server on this Servo thread.
+    ///
+    /// ### 中文
+    /// 在该 Servo 线程上启动 WebDriver 服务器。
+    EnableWebDriver {
+        /// ### English
+        /// TCP port to listen on.
+        ///
+        /// ### 中文
+        /// 要监听的 TCP 端口。
+        port: u16,
+        /// ### English
+        /// One-shot response carrying success or an error.
+        ///
+        /// ### 中文
+        /// 一次性回包：携带成功或错误。
+        response: Arc<OneShot<Result<(), String>>>,
+    },
+    /// ### English
+    /// Destroys a view group (members keep running with the shared driver).
+    ///
+    /// ### 中文
+    /// 销毁 view group（成员会继续使用共享 driver 运行）。
+    DestroyViewGroup { id: u32 },
+    /// ### English
+    /// Replaces the reader Servo loads its resource files from.
+    ///
+    /// ### 中文
+    /// 替换 Servo 加载资源文件所用的读取器。
+    SetResourceReader { reader: BoxedResourceReader },
+    /// ### English
+    /// Registers a font for every view under the given CSS family name.
+    ///
+    /// ### 中文
+    /// 以给定的 CSS family 名称为所有 view 注册字体。
+    RegisterFont { family: String, data: Vec<u8> },
+    /// ### English
+    /// Applies priority/affinity settings to the Servo thread and the refresh scheduler thread.
+    ///
+    /// ### 中文
+    /// 将优先级/亲和性设置应用到 Servo 线程与 refresh 调度线程。
+    SetThreadConfig { config: ThreadConfig },
+    /// ### English
+    /// Enables or disables Servo's HTTP cache.
+    ///
+    /// ### 中文
+    /// 启用或关闭 Servo 的 HTTP 缓存。
+    SetHttpCacheEnabled { enabled: bool },
+    /// ### English
+    /// Sets Servo's process-wide page JavaScript heap limit in MiB (`0` = unlimited), for pages
+    /// whose event loop starts afterwards.
+    ///
+    /// ### 中文
+    /// 设置 Servo 进程级的页面 JavaScript 堆上限（MiB；`0` 表示不限制），作用于之后启动事件循环的页面。
+    SetHeapLimit { limit_mb: u32 },
+    /// ### English
+    /// Replaces the image size limits enforced in every view (from the next navigation).
+    ///
+    /// ### 中文
+    /// 替换在每个 view 中执行的图片尺寸限制（从下一次导航开始生效）。
+    SetImageLimits { limits: XianWebEngineImageLimits },
+    /// ### English
+    /// Switches the network mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`) of every view at once.
+    ///
+    /// ### 中文
+    /// 立即切换所有 view 的网络模式（`XIAN_WEB_ENGINE_NETWORK_MODE_*`）。
+    SetNetworkMode { mode: u32 },
+    /// ### English
+    /// Registers (or, with texture `0`, removes) an embedder GL texture pages can fetch with
+    /// `xianHost.getTexture(name)`.
+    ///
+    /// ### 中文
+    /// 注册（纹理为 `0` 时移除）一个页面可通过 `xianHost.getTexture(name)` 获取的宿主 GL 纹理。
+    RegisterTexture {
+        name: String,
+        texture: u32,
+        width: u32,
+        height: u32,
+        flip_y: bool,
+    },
+    /// ### English
+    /// Clears Servo's HTTP cache.
+    ///
+    /// ### 中文
+    /// 清空 Servo 的 HTTP 缓存。
+    ClearHttpCache,
+    /// ### English
+    /// Enters or leaves engine idle mode.
+    ///
+    /// ### 中文
+    /// 进入或退出引擎空闲模式。
+    SetIdle {
+        /// ### English
+        /// Whether the engine is idle.
+        ///
+        /// ### 中文
+        /// 引擎是否空闲。
+        idle: bool,
+        /// ### English
+        /// Whether to release memory (as under moderate pressure) when entering idle.
+        ///
+        /// ### 中文
+        /// 进入空闲时是否释放内存（与中等内存压力时相同）。
+        release_memory: bool,
+    },
+    /// ### English
+    /// Releases caches and pooled memory in response to host memory pressure.
+    ///
+    /// ### 中文
+    /// 响应宿主的内存压力，释放缓存与池化内存。
+    MemoryPressure {
+        /// ### English
+        /// Whether the pressure is critical (also clears Servo's HTTP cache).
+        ///
+        /// ### 中文
+        /// 是否为严重压力（同时清空 Servo 的 HTTP 缓存）。
+        critical: bool,
+    },
+    /// ### English
     /// Shuts down the Servo thread.
     ///
     /// ### 中文
     /// 关闭 Servo 线程。
     Shutdown,
 }
+
+/// ### English
+/// Outcome of a `CreateView` command: the new view's `(id, token)`, or an error.
+///
+/// ### 中文
+/// `CreateView` 命令的结果：新 view 的 `(id, token)`，或错误。
+pub(super) type CreateViewResult = Result<(u32, u64), String>;
+
+/// ### English
+/// Where the outcome of a `CreateView` command is reported.
+///
+/// ### 中文
+/// `CreateView` 命令结果的回报目标。
+pub(super) enum CreateViewReply {
+    /// ### English
+    /// A caller blocked in `create_view`.
+    ///
+    /// ### 中文
+    /// 阻塞在 `create_view` 中的调用方。
+    Wait(Arc<OneShot<CreateViewResult>>),
+    /// ### English
+    /// Completion of `create_view_async` (binds the handle and notifies the embedder).
+    ///
+    /// ### 中文
+    /// `create_view_async` 的完成处理（绑定句柄并通知宿主）。
+    Callback(Box<dyn FnOnce(CreateViewResult) + Send>),
+}
+
+impl CreateViewReply {
+    /// ### English
+    /// Reports the outcome of the creation.
+    ///
+    /// #### Parameters
+    /// - `result`: `(id, token)` of the created view, or an error.
+    ///
+    /// ### 中文
+    /// 回报创建结果。
+    ///
+    /// #### 参数
+    /// - `result`：已创建 view 的 `(id, token)`，或错误。
+    pub(super) fn send(self, result: CreateViewResult) {
+        match self {
+            Self::Wait(response) => {
+                let _ = response.send(result);
+            }
+            Self::Callback(done) => done(result),
+        }
+    }
+}
+
+/// ### English
+/// Outcome of a `CaptureElement` request: the RGBA pixels of the element's region, or an error.
+///
+/// ### 中文
+/// `CaptureElement` 请求的结果：元素区域的 RGBA 像素，或错误。
+pub(super) type ElementCaptureResult = Result<servo::RgbaImage, String>;
+
+/// ### English
+/// Completion of a `CaptureElement` request, reported exactly once: dropping it unused (the view
+/// closed or the engine shut down first) reports an error.
+///
+/// ### 中文
+/// `CaptureElement` 请求的完成处理，恰好回报一次：未使用即被丢弃（view 先被关闭或引擎先关闭）时回报错误。
+pub(super) struct ElementCaptureReply(Option<Box<dyn FnOnce(ElementCaptureResult) + Send>>);
+
+impl ElementCaptureReply {
+    /// ### English
+    /// Wraps the completion callback.
+    ///
+    /// #### Parameters
+    /// - `done`: Callback receiving the outcome.
+    ///
+    /// ### 中文
+    /// 包装完成回调。
+    ///
+    /// #### 参数
+    /// - `done`：接收结果的回调。
+    pub(super) fn new(done: Box<dyn FnOnce(ElementCaptureResult) + Send>) -> Self {
+        Self(Some(done))
+    }
+
+    /// ### English
+    /// Reports the outcome of the capture.
+    ///
+    /// #### Parameters
+    /// - `result`: Captured pixels, or an error.
+    ///
+    /// ### 中文
+    /// 回报截取结果。
+    ///
+    /// #### 参数
+    /// - `result`：截取到的像素，或错误。
+    pub(super) fn send(mut self, result: ElementCaptureResult) {
+        if let Some(done) = self.0.take() {
+            done(result);
+        }
+    }
+}
+
+impl Drop for ElementCaptureReply {
+    /// ### English
+    /// Reports an error if the outcome was never sent.
+    ///
+    /// ### 中文
+    /// 若结果从未回报，则回报错误。
+    fn drop(&mut self) {
+        if let Some(done) = self.0.take() {
+            done(Err("View closed before the capture ran".to_string()));
+        }
+    }
+}
+
+/// ### English
+/// Per-view control requests (low-frequency; routed through the global command queue).
+///
+/// ### 中文
+/// per-view 控制请求（低频；经由全局命令队列转发）。
+pub(super) enum ViewCommand {
+    /// ### English
+    /// Exports the current page to a file; completion is reported as an `EXPORT_COMPLETE` event.
+    ///
+    /// ### 中文
+    /// 将当前页面导出到文件；完成后以 `EXPORT_COMPLETE` 事件上报。
+    Export {
+        request_id: u64,
+        path: PathBuf,
+        format: u32,
+    },
+    /// ### English
+    /// Reads back the viewport region of the first element matching a CSS selector.
+    ///
+    /// ### 中文
+    /// 读回首个匹配 CSS 选择器之元素所在的视口区域。
+    CaptureElement {
+        selector: String,
+        reply: ElementCaptureReply,
+    },
+    /// ### English
+    /// Snapshots the accessibility tree; the JSON is reported as an `ACCESSIBILITY_TREE` event.
+    ///
+    /// ### 中文
+    /// 生成无障碍树快照；JSON 以 `ACCESSIBILITY_TREE` 事件上报。
+    AccessibilityTree { request_id: u64 },
+    /// ### English
+    /// Pauses every playing media element, or resumes the ones paused this way; reported as a
+    /// `MEDIA_STATE` event.
+    ///
+    /// ### 中文
+    /// 暂停所有正在播放的媒体元素，或恢复以此方式暂停的元素；以 `MEDIA_STATE` 事件上报。
+    SetMediaPaused { request_id: u64, paused: bool },
+    /// ### English
+    /// Serializes the session (history + scroll offset); reported as a `SESSION_SAVED` event.
+    ///
+    /// ### 中文
+    /// 序列化会话（历史 + 滚动偏移）；以 `SESSION_SAVED` 事件上报。
+    SaveSession { request_id: u64 },
+    /// ### English
+    /// Rebuilds a saved history (entries loaded in order, then back to `current`) and scrolls the
+    /// current entry to the given offset (CSS px) once it has loaded.
+    ///
+    /// ### 中文
+    /// 重建保存的历史（依次加载各条目，再后退到 `current`），并在当前条目加载完成后将其滚动到指定偏移
+    /// （CSS px）。
+    RestoreSession {
+        entries: Vec<Url>,
+        current: usize,
+        scroll_x: f64,
+        scroll_y: f64,
+    },
+    /// ### English
+    /// Sets the default page background (`0xRRGGBBAA`), applied from the next navigation.
+    ///
+    /// ### 中文
+    /// 设置默认页面背景色（`0xRRGGBBAA`），从下一次导航开始生效。
+    SetBackground { rgba: u32 },
+    /// ### English
+    /// Sets the internal render resolution per logical pixel (CSS viewport size is unchanged).
+    ///
+    /// ### 中文
+    /// 设置每逻辑像素对应的内部渲染分辨率（CSS 视口尺寸不变）。
+    SetRenderScale { scale: f32 },
+    /// ### English
+    /// Overrides the CSS viewport size (`0` = follow the view size on that axis).
+    ///
+    /// ### 中文
+    /// 覆盖 CSS 视口尺寸（`0` 表示该轴跟随 view 尺寸）。
+    SetViewport { width: u32, height: u32 },
+    /// ### English
+    /// Sets the quiet period a resize waits for before it is applied (zero = immediate).
+    ///
+    /// ### 中文
+    /// 设置 resize 应用前需等待的静默期（为零表示立即应用）。
+    SetResizeDebounce { debounce: Duration },
+    /// ### English
+    /// Resizes the view right away, bypassing the debounce.
+    ///
+    /// ### 中文
+    /// 绕过防抖立即 resize 该 view。
+    ResizeImmediate { size: PhysicalSize<u32> },
+    /// ### English
+    /// Moves the view to another render configuration (render scale, slot color format, mipmaps)
+    /// without reloading the page.
+    ///
+    /// ### 中文
+    /// 在不重新加载页面的情况下，将 view 迁移到另一种渲染配置（渲染缩放、槽位颜色格式、mipmap）。
+    Move {
+        render_scale: f32,
+        color_format: u32,
+        mipmaps: bool,
+    },
+    /// ### English
+    /// Freezes (drops the triple-buffer storage) or thaws the view.
+    ///
+    /// ### 中文
+    /// 冻结（释放三缓冲存储）或解冻该 view。
+    SetFrozen { frozen: bool },
+    /// ### English
+    /// Enters or leaves snapshot mode (script and painting suspended, last frame pinned).
+    ///
+    /// ### 中文
+    /// 进入或退出快照模式（暂停脚本与绘制，固定最后一帧）。
+    SetSnapshotMode { enabled: bool },
+    /// ### English
+    /// Briefly resumes a view in snapshot mode to render a fresh frame, then pins it.
+    ///
+    /// ### 中文
+    /// 短暂恢复处于快照模式的 view 以渲染一帧新内容，然后将其固定。
+    RefreshSnapshot,
+    /// ### English
+    /// Dispatches an input event (after the queued input) and reports how the page handled it as
+    /// an `INPUT_RESULT` event.
+    ///
+    /// ### 中文
+    /// 派发一个输入事件（在已排队的输入之后），并以 `INPUT_RESULT` 事件上报页面的处理结果。
+    TrackedInput {
+        event: XianWebEngineInputEvent,
+        correlation_id: u64,
+    },
+    /// ### English
+    /// Replays recorded actions into the view with their recorded timing.
+    ///
+    /// ### 中文
+    /// 按录制时的时间间隔将已录制的动作回放到该 view。
+    Replay { entries: Vec<RecordedEntry> },
+    /// ### English
+    /// Feeds synthesized input into the view after the synthesized input still pending.
+    ///
+    /// ### 中文
+    /// 在仍待注入的合成输入之后，将合成输入注入该 view。
+    Synthesize { entries: Vec<RecordedEntry> },
+    /// ### English
+    /// Pauses or resumes the view's refresh driver for debugging.
+    ///
+    /// ### 中文
+    /// 为调试而暂停或恢复该 view 的 refresh driver。
+    SetDebugPaused { paused: bool },
+    /// ### English
+    /// Lets one frame through the paused refresh driver.
+    ///
+    /// ### 中文
+    /// 让暂停中的 refresh driver 放行一帧。
+    DebugStep,
+    /// ### English
+    /// Sets how the view behaves while inactive (`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`).
+    ///
+    /// ### 中文
+    /// 设置 view 在 inactive 时的行为（`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`）。
+    SetBackgroundPolicy { policy: u32 },
+    /// ### English
+    /// Sets the slow-script policy (`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`) and timeout.
+    ///
+    /// ### 中文
+    /// 设置慢脚本策略（`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`）与超时。
+    SetSlowScriptPolicy { policy: u32, timeout_ms: u32 },
+    /// ### English
+    /// Sets the output colorspace (`XIAN_WEB_ENGINE_COLORSPACE_*`) and repaints.
+    ///
+    /// ### 中文
+    /// 设置输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_*`）并重绘。
+    SetColorspace { colorspace: u32 },
+    /// ### English
+    /// Sets the `prefers-color-scheme` reported to pages (`XIAN_WEB_ENGINE_COLOR_SCHEME_*`).
+    ///
+    /// ### 中文
+    /// 设置向页面报告的 `prefers-color-scheme`（`XIAN_WEB_ENGINE_COLOR_SCHEME_*`）。
+    SetColorScheme { scheme: u32 },
+    /// ### English
+    /// Sets whether pages see `prefers-reduced-motion: reduce`.
+    ///
+    /// ### 中文
+    /// 设置页面是否看到 `prefers-reduced-motion: reduce`。
+    SetReducedMotion { reduce: bool },
+    /// ### English
+    /// Sets whether pages see `forced-colors: active`.
+    ///
+    /// ### 中文
+    /// 设置页面是否看到 `forced-colors: active`。
+    SetForcedColors { active: bool },
+    /// ### English
+    /// Holds or releases the view's frame callbacks and freezes or resumes its page timeline.
+    ///
+    /// ### 中文
+    /// 扣住或放开该 view 的帧回调，并冻结或恢复其页面时间线。
+    SetTimePaused { paused: bool },
+    /// ### English
+    /// Adds a user stylesheet applied to every subsequent load.
+    ///
+    /// ### 中文
+    /// 添加一个作用于后续所有加载的用户样式表。
+    AddUserStylesheet { css: String },
+    /// ### English
+    /// Adds a user script run on every subsequent navigation (source already wrapped for its
+    /// injection time).
+    ///
+    /// ### 中文
+    /// 添加一个在后续每次导航时执行的用户脚本（源码已按注入时机包装）。
+    AddUserScript { source: String },
+    /// ### English
+    /// Answers a pending permission request (unknown or expired IDs are ignored).
+    ///
+    /// ### 中文
+    /// 应答一个待处理的权限请求（未知或已超时的 ID 会被忽略）。
+    RespondPermission { request_id: u64, allow: bool },
+    /// ### English
+    /// Releases pointer lock held by the page (e.g. the player pressed Escape).
+    ///
+    /// ### 中文
+    /// 释放页面持有的 pointer lock（例如玩家按下了 Escape）。
+    ExitPointerLock,
+    /// ### English
+    /// Starts dragging host data (`mime` + UTF-8 `data`) over the page.
+    ///
+    /// ### 中文
+    /// 开始在页面上拖拽宿主数据（`mime` + UTF-8 `data`）。
+    BeginDrag { mime: String, data: String },
+    /// ### English
+    /// Moves the current host drag to `(x, y)` (device px).
+    ///
+    /// ### 中文
+    /// 将当前宿主拖拽移动到 `(x, y)`（设备像素）。
+    DragMove { x: f32, y: f32 },
+    /// ### English
+    /// Drops the current host drag at `(x, y)`; reported as a `DROP_COMPLETE` event.
+    ///
+    /// ### 中文
+    /// 在 `(x, y)` 放下当前宿主拖拽；以 `DROP_COMPLETE` 事件上报。
+    Drop { request_id: u64, x: f32, y: f32 },
+    /// ### English
+    /// Cancels the current host drag.
+    ///
+    /// ### 中文
+    /// 取消当前宿主拖拽。
+    CancelDrag,
+    /// ### English
+    /// Answers an open file chooser (empty `paths` cancels it).
+    ///
+    /// ### 中文
+    /// 应答一个已打开的文件选择器（`paths` 为空表示取消）。
+    ProvideFiles {
+        request_id: u64,
+        paths: Vec<PathBuf>,
+    },
+    /// ### English
+    /// Feeds a device orientation quaternion `[x, y, z, w]` and optional acceleration
+    /// (m/s², including gravity) to the page as `deviceorientation` / `devicemotion`.
+    ///
+    /// ### 中文
+    /// 将设备朝向四元数 `[x, y, z, w]` 与可选加速度（m/s²，含重力）以 `deviceorientation` /
+    /// `devicemotion` 的形式提供给页面。
+    UpdateSensors {
+        orientation: [f32; 4],
+        acceleration: Option<[f32; 3]>,
+    },
+    /// ### English
+    /// Scrolls the page to `(x, y)` (CSS px), animated when `smooth`.
+    ///
+    /// ### 中文
+    /// 将页面滚动到 `(x, y)`（CSS px）；`smooth` 为 true 时带动画。
+    SetScroll { x: f32, y: f32, smooth: bool },
+    /// ### English
+    /// Selects the focused text field's contents, or the whole document.
+    ///
+    /// ### 中文
+    /// 选中获得焦点的文本框内容，或整个文档。
+    SelectAll,
+    /// ### English
+    /// Reads the selected text; reported as a `SELECTED_TEXT` event, or as a `COPY` event when
+    /// `copy` (which also runs the page's `copy` handlers).
+    ///
+    /// ### 中文
+    /// 读取选中的文本；以 `SELECTED_TEXT` 事件上报，`copy` 为 true 时以 `COPY` 事件上报（同时执行页面的
+    /// `copy` 处理器）。
+    Selection { request_id: u64, copy: bool },
+    /// ### English
+    /// Enables or disables reporting page console output as `CONSOLE_MESSAGE` events.
+    ///
+    /// ### 中文
+    /// 启用或禁用将页面 console 输出以 `CONSOLE_MESSAGE` 事件上报。
+    SetConsoleCapture { enabled: bool },
+    /// ### English
+    /// Starts streaming frames to a running screencast server, replacing a screencast already
+    /// running (at most `max_fps` frames per second; `0` = 10).
+    ///
+    /// ### 中文
+    /// 开始向运行中的屏幕投射服务器推送帧，并替换正在进行的屏幕投射（每秒最多 `max_fps` 帧；`0` 表示 10）。
+    #[cfg(feature = "screencast")]
+    StartScreencast {
+        server: ScreencastServer,
+        max_fps: u32,
+    },
+    /// ### English
+    /// Stops the running screencast and its server.
+    ///
+    /// ### 中文
+    /// 停止正在进行的屏幕投射及其服务器。
+    #[cfg(feature = "screencast")]
+    StopScreencast,
+    /// ### English
+    /// Delivers a binary message to the page (the buffer comes from, and returns to, the view's
+    /// binary pool).
+    ///
+    /// ### 中文
+    /// 向页面投递一条二进制消息（缓冲区取自并归还到该 view 的二进制缓冲池）。
+    PostBinary { channel: u32, data: Vec<u8> },
+    /// ### English
+    /// Delivers a frame of an embedder texture to a page stream; `fence` is the embedder's
+    /// `GLsync` signaled after rendering (`0` = none).
+    ///
+    /// ### 中文
+    /// 将宿主纹理的一帧投递到页面流；`fence` 为宿主渲染完成后 signal 的 `GLsync`（`0` 表示无）。
+    PushExternalFrame {
+        stream_id: u32,
+        texture: u32,
+        width: u32,
+        height: u32,
+        fence: u64,
+    },
+    /// ### English
+    /// Registers, replaces or (with `None`) removes a host function callable from the page.
+    ///
+    /// ### 中文
+    /// 注册、替换或（为 `None` 时）移除一个可由页面调用的宿主函数。
+    RegisterHostFunction {
+        name: String,
+        function: Option<HostFunction>,
+    },
+    /// ### English
+    /// Answers a host function call (JSON result, or error message when `success` is false).
+    ///
+    /// ### 中文
+    /// 应答一次宿主函数调用（JSON 结果；`success` 为 false 时为错误信息）。
+    RespondHostCall {
+        call_id: u64,
+        success: bool,
+        result: String,
+    },
+}
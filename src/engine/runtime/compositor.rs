@@ -0,0 +1,791 @@
+//! ### English
+//! Optional view compositor running on the embedder's GL context.
+//!
+//! Draws the latest frame of several views into a target framebuffer as textured quads, handling
+//! producer/consumer fences and sRGB conversion, for hosts that do not want to write their own
+//! pipeline.
+//!
+//! ### 中文
+//! 运行在宿主 GL 上下文上的可选 view 合成器。
+//!
+//! 以贴图四边形的方式把多个 view 的最新帧绘制到目标 framebuffer，并处理生产者/消费者 fence 与 sRGB
+//! 转换，供不想自行编写管线的宿主使用。
+
+use std::ffi::CString;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::thread::{self, ThreadId};
+
+use glow::HasContext as _;
+
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8, XIAN_WEB_ENGINE_COLORSPACE_LINEAR,
+};
+use crate::engine::frame::{AcquiredFrame, SharedFrameState};
+use crate::engine::glfw::LoadedGlfwApi;
+
+use super::view_handle::WebEngineViewHandle;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+/// ### English
+/// Where one view is drawn by `xian_web_engine_composite_views`.
+///
+/// Coordinates are in pixels of the current GL viewport with the origin at its top-left corner.
+///
+/// ### 中文
+/// `xian_web_engine_composite_views` 绘制单个 view 的位置。
+///
+/// 坐标以当前 GL viewport 的像素为单位，原点位于其左上角。
+pub struct XianWebEngineCompositeRect {
+    /// ### English
+    /// Left edge in pixels.
+    ///
+    /// ### 中文
+    /// 左边缘（像素）。
+    pub x: f32,
+    /// ### English
+    /// Top edge in pixels.
+    ///
+    /// ### 中文
+    /// 上边缘（像素）。
+    pub y: f32,
+    /// ### English
+    /// Width in pixels (views with a non-positive size are skipped).
+    ///
+    /// ### 中文
+    /// 宽度（像素；尺寸非正的 view 会被跳过）。
+    pub width: f32,
+    /// ### English
+    /// Height in pixels (views with a non-positive size are skipped).
+    ///
+    /// ### 中文
+    /// 高度（像素；尺寸非正的 view 会被跳过）。
+    pub height: f32,
+    /// ### English
+    /// Opacity multiplied into the view (`0.0..=1.0`).
+    ///
+    /// ### 中文
+    /// 乘到 view 上的不透明度（`0.0..=1.0`）。
+    pub opacity: f32,
+}
+
+/// ### English
+/// Shader transfer mode: sample used as-is.
+///
+/// ### 中文
+/// 着色器传递函数模式：采样值原样使用。
+const TRANSFER_NONE: i32 = 0;
+
+/// ### English
+/// Shader transfer mode: sRGB-encode the sample.
+///
+/// ### 中文
+/// 着色器传递函数模式：对采样值做 sRGB 编码。
+const TRANSFER_ENCODE: i32 = 1;
+
+/// ### English
+/// Shader transfer mode: sRGB-decode the sample.
+///
+/// ### 中文
+/// 着色器传递函数模式：对采样值做 sRGB 解码。
+const TRANSFER_DECODE: i32 = 2;
+
+/// ### English
+/// Vertex shader body: expands `gl_VertexID` 0..4 into the quad `u_rect` (NDC, bottom-left/top-right).
+///
+/// ### 中文
+/// 顶点着色器主体：把 `gl_VertexID` 0..4 展开为四边形 `u_rect`（NDC，左下/右上）。
+const VERTEX_SHADER: &str = r#"
+uniform vec4 u_rect;
+out vec2 v_uv;
+void main() {
+    vec2 corner = vec2(float(gl_VertexID & 1), float(gl_VertexID >> 1));
+    v_uv = corner;
+    gl_Position = vec4(mix(u_rect.xy, u_rect.zw, corner), 0.0, 1.0);
+}
+"#;
+
+/// ### English
+/// Fragment shader body: samples the premultiplied frame, applies the transfer mode on
+/// unpremultiplied color and scales by opacity.
+///
+/// ### 中文
+/// 片元着色器主体：采样预乘帧，在反预乘颜色上应用传递函数，并乘以不透明度。
+const FRAGMENT_SHADER: &str = r#"
+uniform sampler2D u_texture;
+uniform float u_opacity;
+uniform int u_transfer;
+in vec2 v_uv;
+out vec4 o_color;
+vec3 srgb_encode(vec3 c) {
+    return mix(c * 12.92, 1.055 * pow(c, vec3(1.0 / 2.4)) - 0.055, step(vec3(0.0031308), c));
+}
+vec3 srgb_decode(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), step(vec3(0.04045), c));
+}
+void main() {
+    vec4 color = texture(u_texture, v_uv);
+    if (u_transfer != 0 && color.a > 0.0) {
+        vec3 rgb = clamp(color.rgb / color.a, 0.0, 1.0);
+        rgb = u_transfer == 1 ? srgb_encode(rgb) : srgb_decode(rgb);
+        color.rgb = rgb * color.a;
+    }
+    o_color = color * u_opacity;
+}
+"#;
+
+/// ### English
+/// Frame a view is currently displayed with (kept acquired until a newer one replaces it).
+///
+/// ### 中文
+/// 某个 view 当前显示的帧（保持 acquire，直到被更新的帧替换）。
+struct HeldFrame {
+    /// ### English
+    /// Frame state of the view (identifies the view and keeps the slot bookkeeping alive).
+    ///
+    /// ### 中文
+    /// 该 view 的帧状态（用于标识 view，并保持槽位记录存活）。
+    state: Arc<SharedFrameState>,
+    /// ### English
+    /// Acquired frame.
+    ///
+    /// ### 中文
+    /// 已 acquire 的帧。
+    frame: AcquiredFrame,
+    /// ### English
+    /// Whether the slot is released with a consumer fence.
+    ///
+    /// ### 中文
+    /// 释放槽位时是否附带 consumer fence。
+    consumer_fence: bool,
+    /// ### English
+    /// Whether the view was passed to the current call.
+    ///
+    /// ### 中文
+    /// 本次调用是否传入了该 view。
+    seen: bool,
+}
+
+/// ### English
+/// GL state touched by the compositor, restored after drawing.
+///
+/// ### 中文
+/// 合成器会修改的 GL 状态，绘制后恢复。
+struct SavedGlState {
+    /// ### English
+    /// Bound draw framebuffer.
+    ///
+    /// ### 中文
+    /// 绑定的绘制 framebuffer。
+    draw_framebuffer: Option<glow::NativeFramebuffer>,
+    /// ### English
+    /// Current program.
+    ///
+    /// ### 中文
+    /// 当前着色器程序。
+    program: Option<glow::NativeProgram>,
+    /// ### English
+    /// Bound vertex array.
+    ///
+    /// ### 中文
+    /// 绑定的顶点数组。
+    vertex_array: Option<glow::NativeVertexArray>,
+    /// ### English
+    /// Active texture unit.
+    ///
+    /// ### 中文
+    /// 当前激活的纹理单元。
+    active_texture: u32,
+    /// ### English
+    /// `TEXTURE_2D` binding of unit 0.
+    ///
+    /// ### 中文
+    /// 单元 0 的 `TEXTURE_2D` 绑定。
+    texture: Option<glow::NativeTexture>,
+    /// ### English
+    /// Sampler binding of unit 0.
+    ///
+    /// ### 中文
+    /// 单元 0 的 sampler 绑定。
+    sampler: Option<glow::NativeSampler>,
+    /// ### English
+    /// Whether blending is enabled.
+    ///
+    /// ### 中文
+    /// 是否启用混合。
+    blend: bool,
+    /// ### English
+    /// Blend factors (src RGB, dst RGB, src alpha, dst alpha).
+    ///
+    /// ### 中文
+    /// 混合因子（源 RGB、目标 RGB、源 alpha、目标 alpha）。
+    blend_func: [u32; 4],
+    /// ### English
+    /// Blend equations (RGB, alpha).
+    ///
+    /// ### 中文
+    /// 混合方程（RGB、alpha）。
+    blend_equation: [u32; 2],
+    /// ### English
+    /// Whether depth testing is enabled.
+    ///
+    /// ### 中文
+    /// 是否启用深度测试。
+    depth_test: bool,
+    /// ### English
+    /// Whether stencil testing is enabled.
+    ///
+    /// ### 中文
+    /// 是否启用模板测试。
+    stencil_test: bool,
+    /// ### English
+    /// Whether scissor testing is enabled.
+    ///
+    /// ### 中文
+    /// 是否启用裁剪测试。
+    scissor_test: bool,
+    /// ### English
+    /// Whether face culling is enabled.
+    ///
+    /// ### 中文
+    /// 是否启用面剔除。
+    cull_face: bool,
+    /// ### English
+    /// Whether `FRAMEBUFFER_SRGB` is enabled (desktop GL only).
+    ///
+    /// ### 中文
+    /// 是否启用 `FRAMEBUFFER_SRGB`（仅桌面 GL）。
+    framebuffer_srgb: bool,
+}
+
+/// ### English
+/// Compositor state bound to the GL context of the thread that first used it.
+///
+/// ### 中文
+/// 绑定到首次使用它的线程之 GL 上下文的合成器状态。
+pub(super) struct ViewCompositor {
+    /// ### English
+    /// GL function table loaded on the compositing context.
+    ///
+    /// ### 中文
+    /// 在合成上下文上加载的 GL 函数表。
+    gl: glow::Context,
+    /// ### English
+    /// Thread whose context owns the GL objects below.
+    ///
+    /// ### 中文
+    /// 其上下文拥有下列 GL 对象的线程。
+    thread: ThreadId,
+    /// ### English
+    /// Quad program.
+    ///
+    /// ### 中文
+    /// 四边形着色器程序。
+    program: glow::NativeProgram,
+    /// ### English
+    /// Empty vertex array (vertices come from `gl_VertexID`).
+    ///
+    /// ### 中文
+    /// 空顶点数组（顶点来自 `gl_VertexID`）。
+    vertex_array: glow::NativeVertexArray,
+    /// ### English
+    /// Location of `u_rect`.
+    ///
+    /// ### 中文
+    /// `u_rect` 的位置。
+    rect_location: Option<glow::NativeUniformLocation>,
+    /// ### English
+    /// Location of `u_opacity`.
+    ///
+    /// ### 中文
+    /// `u_opacity` 的位置。
+    opacity_location: Option<glow::NativeUniformLocation>,
+    /// ### English
+    /// Location of `u_transfer`.
+    ///
+    /// ### 中文
+    /// `u_transfer` 的位置。
+    transfer_location: Option<glow::NativeUniformLocation>,
+    /// ### English
+    /// Whether the context is OpenGL ES.
+    ///
+    /// ### 中文
+    /// 上下文是否为 OpenGL ES。
+    is_gles: bool,
+    /// ### English
+    /// Whether sampler objects exist (their unit-0 binding is saved and cleared).
+    ///
+    /// ### 中文
+    /// 是否支持 sampler 对象（会保存并清除单元 0 的绑定）。
+    has_samplers: bool,
+    /// ### English
+    /// Frames currently displayed, one per view.
+    ///
+    /// ### 中文
+    /// 当前显示的帧，每个 view 一个。
+    held: Vec<HeldFrame>,
+}
+
+impl ViewCompositor {
+    /// ### English
+    /// Loads GL and builds the quad program on the calling thread's current context.
+    ///
+    /// ### 中文
+    /// 在调用线程的当前上下文上加载 GL 并构建四边形着色器程序。
+    pub(super) fn new() -> Result<Self, String> {
+        let glfw = LoadedGlfwApi::load()?;
+        let gl = unsafe {
+            glow::Context::from_loader_function(|name| match CString::new(name) {
+                Ok(name) => glfw.get_proc_address(&name),
+                Err(_) => std::ptr::null(),
+            })
+        };
+
+        let version = gl.version();
+        let is_gles = version.is_embedded;
+        if version.major < 3 {
+            return Err(format!(
+                "Compositing needs OpenGL (ES) 3.0 or newer, the current context is {}.{}",
+                version.major, version.minor
+            ));
+        }
+        let has_samplers = is_gles || (version.major, version.minor) >= (3, 3);
+        let header = if is_gles {
+            "#version 300 es\nprecision mediump float;\n"
+        } else {
+            "#version 150\n"
+        };
+
+        let program = unsafe { link_program(&gl, header) }?;
+        let vertex_array = match unsafe { gl.create_vertex_array() } {
+            Ok(vertex_array) => vertex_array,
+            Err(err) => {
+                unsafe { gl.delete_program(program) };
+                return Err(format!("Failed to create vertex array: {err}"));
+            }
+        };
+        let (rect_location, opacity_location, transfer_location, texture_location) = unsafe {
+            (
+                gl.get_uniform_location(program, "u_rect"),
+                gl.get_uniform_location(program, "u_opacity"),
+                gl.get_uniform_location(program, "u_transfer"),
+                gl.get_uniform_location(program, "u_texture"),
+            )
+        };
+
+        let previous = unsafe { gl.get_parameter_program(glow::CURRENT_PROGRAM) };
+        unsafe {
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(texture_location.as_ref(), 0);
+            gl.use_program(previous);
+        }
+
+        Ok(Self {
+            gl,
+            thread: thread::current().id(),
+            program,
+            vertex_array,
+            rect_location,
+            opacity_location,
+            transfer_location,
+            is_gles,
+            has_samplers,
+            held: Vec::new(),
+        })
+    }
+
+    /// ### English
+    /// Returns whether the calling thread is the one this compositor was created on.
+    ///
+    /// ### 中文
+    /// 返回调用线程是否为创建该合成器的线程。
+    pub(super) fn is_owner_thread(&self) -> bool {
+        thread::current().id() == self.thread
+    }
+
+    /// ### English
+    /// Draws `views` back to front into `target_fbo` and returns how many were drawn.
+    ///
+    /// Each view shows its newest frame, or the frame it showed last time if no newer one is ready.
+    /// Replaced frames (and frames of views no longer passed in) are released with a consumer fence.
+    /// GL state changed for drawing is restored afterwards.
+    ///
+    /// #### Parameters
+    /// - `views`: Views in draw order (`None` entries are skipped).
+    /// - `rects`: Placement of each view.
+    /// - `target_fbo`: Framebuffer to draw into (`0` = default framebuffer).
+    ///
+    /// ### 中文
+    /// 按从后到前的顺序把 `views` 绘制到 `target_fbo`，返回实际绘制的数量。
+    ///
+    /// 每个 view 显示其最新帧；若没有更新的帧就绪，则显示上次的帧。被替换的帧（以及不再传入的 view 的帧）
+    /// 会附带 consumer fence 释放。为绘制而修改的 GL 状态会在之后恢复。
+    ///
+    /// #### 参数
+    /// - `views`：按绘制顺序排列的 view（`None` 项会被跳过）。
+    /// - `rects`：每个 view 的位置。
+    /// - `target_fbo`：目标 framebuffer（`0` 表示默认 framebuffer）。
+    pub(super) fn composite(
+        &mut self,
+        views: &[Option<&WebEngineViewHandle>],
+        rects: &[XianWebEngineCompositeRect],
+        target_fbo: u32,
+    ) -> usize {
+        let saved = unsafe { self.save_state() };
+        let mut released = Vec::new();
+        let mut drawn = 0usize;
+
+        unsafe {
+            let target = NonZeroU32::new(target_fbo).map(glow::NativeFramebuffer);
+            self.gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, target);
+            let srgb_target = self.target_is_srgb(target_fbo);
+            if !self.is_gles {
+                if srgb_target {
+                    self.gl.enable(glow::FRAMEBUFFER_SRGB);
+                } else {
+                    self.gl.disable(glow::FRAMEBUFFER_SRGB);
+                }
+            }
+            let mut viewport = [0i32; 4];
+            self.gl
+                .get_parameter_i32_slice(glow::VIEWPORT, &mut viewport);
+            let (viewport_width, viewport_height) =
+                (viewport[2].max(1) as f32, viewport[3].max(1) as f32);
+
+            self.gl.use_program(Some(self.program));
+            self.gl.bind_vertex_array(Some(self.vertex_array));
+            self.gl.active_texture(glow::TEXTURE0);
+            if self.has_samplers {
+                self.gl.bind_sampler(0, None);
+            }
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.disable(glow::STENCIL_TEST);
+            self.gl.disable(glow::SCISSOR_TEST);
+            self.gl.disable(glow::CULL_FACE);
+            self.gl.enable(glow::BLEND);
+            self.gl.blend_equation(glow::FUNC_ADD);
+            self.gl.blend_func(glow::ONE, glow::ONE_MINUS_SRC_ALPHA);
+
+            for held in &mut self.held {
+                held.seen = false;
+            }
+
+            for (view, rect) in views.iter().zip(rects) {
+                let Some(view) = view else {
+                    continue;
+                };
+                let Some(frame) = self.current_frame(view, &mut released) else {
+                    continue;
+                };
+                if rect.width <= 0.0 || rect.height <= 0.0 || rect.opacity <= 0.0 {
+                    continue;
+                }
+
+                let left = rect.x / viewport_width * 2.0 - 1.0;
+                let right = (rect.x + rect.width) / viewport_width * 2.0 - 1.0;
+                let top = 1.0 - rect.y / viewport_height * 2.0;
+                let bottom = 1.0 - (rect.y + rect.height) / viewport_height * 2.0;
+                let linear_in_srgb_texture = frame.color_format
+                    == XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8
+                    && frame.colorspace == XIAN_WEB_ENGINE_COLORSPACE_LINEAR;
+                let transfer = match (linear_in_srgb_texture, srgb_target) {
+                    (true, false) => TRANSFER_ENCODE,
+                    (false, true) => TRANSFER_DECODE,
+                    _ => TRANSFER_NONE,
+                };
+
+                self.gl
+                    .uniform_4_f32(self.rect_location.as_ref(), left, bottom, right, top);
+                self.gl
+                    .uniform_1_f32(self.opacity_location.as_ref(), rect.opacity.min(1.0));
+                self.gl
+                    .uniform_1_i32(self.transfer_location.as_ref(), transfer);
+                self.gl.bind_texture(
+                    glow::TEXTURE_2D,
+                    NonZeroU32::new(frame.texture_id).map(glow::NativeTexture),
+                );
+                self.gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+                drawn += 1;
+            }
+
+            self.held.retain(|held| {
+                if !held.seen {
+                    released.push((held.state.clone(), held.frame.slot, held.consumer_fence));
+                }
+                held.seen
+            });
+
+            self.restore_state(&saved);
+            for (state, slot, consumer_fence) in released {
+                state.release_slot(slot, self.consumer_fence(consumer_fence));
+            }
+        }
+
+        drawn
+    }
+
+    /// ### English
+    /// Returns the frame to draw for `view`, acquiring a newer one if ready and queueing the replaced
+    /// slot for release.
+    ///
+    /// #### Parameters
+    /// - `view`: View to draw.
+    /// - `released`: Slots to release once drawing is submitted.
+    ///
+    /// ### 中文
+    /// 返回要为 `view` 绘制的帧；若有更新的帧就绪则 acquire，并把被替换的槽位加入待释放列表。
+    ///
+    /// #### 参数
+    /// - `view`：要绘制的 view。
+    /// - `released`：绘制提交后需要释放的槽位。
+    unsafe fn current_frame(
+        &mut self,
+        view: &WebEngineViewHandle,
+        released: &mut Vec<(Arc<SharedFrameState>, usize, bool)>,
+    ) -> Option<AcquiredFrame> {
+        let state = view.frame_state();
+        let index = self
+            .held
+            .iter()
+            .position(|held| Arc::ptr_eq(&held.state, state));
+
+        if let Some(frame) = view.acquire_frame() {
+            if frame.producer_fence != 0 {
+                let fence = glow::NativeFence(frame.producer_fence as usize as *mut _);
+                unsafe { self.gl.wait_sync(fence, 0, glow::TIMEOUT_IGNORED) };
+            }
+            let consumer_fence = view.records_consumer_fence();
+            match index {
+                Some(index) => {
+                    let held = &mut self.held[index];
+                    released.push((held.state.clone(), held.frame.slot, held.consumer_fence));
+                    held.frame = frame;
+                    held.consumer_fence = consumer_fence;
+                    held.seen = true;
+                }
+                None => self.held.push(HeldFrame {
+                    state: state.clone(),
+                    frame,
+                    consumer_fence,
+                    seen: true,
+                }),
+            }
+            return Some(frame);
+        }
+
+        let held = &mut self.held[index?];
+        held.seen = true;
+        Some(held.frame)
+    }
+
+    /// ### English
+    /// Inserts a consumer fence after the submitted draws (`0` if not wanted or unavailable).
+    ///
+    /// #### Parameters
+    /// - `wanted`: Whether the view records consumer fences.
+    ///
+    /// ### 中文
+    /// 在已提交的绘制之后插入 consumer fence（不需要或不可用时为 `0`）。
+    ///
+    /// #### 参数
+    /// - `wanted`：该 view 是否记录 consumer fence。
+    unsafe fn consumer_fence(&self, wanted: bool) -> u64 {
+        if !wanted {
+            return 0;
+        }
+        unsafe { self.gl.fence_sync(glow::SYNC_GPU_COMMANDS_COMPLETE, 0) }
+            .map(|fence| fence.0 as usize as u64)
+            .unwrap_or(0)
+    }
+
+    /// ### English
+    /// Returns whether the color attachment of the bound draw framebuffer is sRGB-encoded.
+    ///
+    /// #### Parameters
+    /// - `target_fbo`: Bound draw framebuffer.
+    ///
+    /// ### 中文
+    /// 返回当前绑定的绘制 framebuffer 的颜色附件是否为 sRGB 编码。
+    ///
+    /// #### 参数
+    /// - `target_fbo`：当前绑定的绘制 framebuffer。
+    unsafe fn target_is_srgb(&self, target_fbo: u32) -> bool {
+        let attachment = match (target_fbo, self.is_gles) {
+            (0, false) => glow::BACK_LEFT,
+            (0, true) => glow::BACK,
+            _ => glow::COLOR_ATTACHMENT0,
+        };
+        let encoding = unsafe {
+            self.gl.get_framebuffer_attachment_parameter_i32(
+                glow::DRAW_FRAMEBUFFER,
+                attachment,
+                glow::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+            )
+        };
+        encoding as u32 == glow::SRGB
+    }
+
+    /// ### English
+    /// Captures the GL state the compositor changes.
+    ///
+    /// ### 中文
+    /// 记录合成器会修改的 GL 状态。
+    unsafe fn save_state(&self) -> SavedGlState {
+        let gl = &self.gl;
+        unsafe {
+            let active_texture = gl.get_parameter_i32(glow::ACTIVE_TEXTURE) as u32;
+            gl.active_texture(glow::TEXTURE0);
+            let saved = SavedGlState {
+                draw_framebuffer: gl.get_parameter_framebuffer(glow::DRAW_FRAMEBUFFER_BINDING),
+                program: gl.get_parameter_program(glow::CURRENT_PROGRAM),
+                vertex_array: gl.get_parameter_vertex_array(glow::VERTEX_ARRAY_BINDING),
+                active_texture,
+                texture: gl.get_parameter_texture(glow::TEXTURE_BINDING_2D),
+                sampler: if self.has_samplers {
+                    gl.get_parameter_sampler(glow::SAMPLER_BINDING)
+                } else {
+                    None
+                },
+                blend: gl.is_enabled(glow::BLEND),
+                blend_func: [
+                    gl.get_parameter_i32(glow::BLEND_SRC_RGB) as u32,
+                    gl.get_parameter_i32(glow::BLEND_DST_RGB) as u32,
+                    gl.get_parameter_i32(glow::BLEND_SRC_ALPHA) as u32,
+                    gl.get_parameter_i32(glow::BLEND_DST_ALPHA) as u32,
+                ],
+                blend_equation: [
+                    gl.get_parameter_i32(glow::BLEND_EQUATION_RGB) as u32,
+                    gl.get_parameter_i32(glow::BLEND_EQUATION_ALPHA) as u32,
+                ],
+                depth_test: gl.is_enabled(glow::DEPTH_TEST),
+                stencil_test: gl.is_enabled(glow::STENCIL_TEST),
+                scissor_test: gl.is_enabled(glow::SCISSOR_TEST),
+                cull_face: gl.is_enabled(glow::CULL_FACE),
+                framebuffer_srgb: !self.is_gles && gl.is_enabled(glow::FRAMEBUFFER_SRGB),
+            };
+            gl.active_texture(active_texture);
+            saved
+        }
+    }
+
+    /// ### English
+    /// Restores state captured by `save_state`.
+    ///
+    /// #### Parameters
+    /// - `saved`: Captured state.
+    ///
+    /// ### 中文
+    /// 恢复 `save_state` 记录的状态。
+    ///
+    /// #### 参数
+    /// - `saved`：记录的状态。
+    unsafe fn restore_state(&self, saved: &SavedGlState) {
+        let gl = &self.gl;
+        let set = |capability: u32, enabled: bool| unsafe {
+            if enabled {
+                gl.enable(capability);
+            } else {
+                gl.disable(capability);
+            }
+        };
+        unsafe {
+            gl.active_texture(glow::TEXTURE0);
+            gl.bind_texture(glow::TEXTURE_2D, saved.texture);
+            if self.has_samplers {
+                gl.bind_sampler(0, saved.sampler);
+            }
+            gl.active_texture(saved.active_texture);
+            gl.bind_vertex_array(saved.vertex_array);
+            gl.use_program(saved.program);
+            gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, saved.draw_framebuffer);
+            let [src_rgb, dst_rgb, src_alpha, dst_alpha] = saved.blend_func;
+            gl.blend_func_separate(src_rgb, dst_rgb, src_alpha, dst_alpha);
+            let [equation_rgb, equation_alpha] = saved.blend_equation;
+            gl.blend_equation_separate(equation_rgb, equation_alpha);
+        }
+        set(glow::BLEND, saved.blend);
+        set(glow::DEPTH_TEST, saved.depth_test);
+        set(glow::STENCIL_TEST, saved.stencil_test);
+        set(glow::SCISSOR_TEST, saved.scissor_test);
+        set(glow::CULL_FACE, saved.cull_face);
+        if !self.is_gles {
+            set(glow::FRAMEBUFFER_SRGB, saved.framebuffer_srgb);
+        }
+    }
+}
+
+impl Drop for ViewCompositor {
+    /// ### English
+    /// Releases held frames; GL objects are deleted only on the owning thread (otherwise they are
+    /// left to the context).
+    ///
+    /// ### 中文
+    /// 释放持有的帧；GL 对象仅在所属线程上删除（否则交由上下文回收）。
+    fn drop(&mut self) {
+        let owner = self.is_owner_thread();
+        for held in std::mem::take(&mut self.held) {
+            let fence = if owner {
+                unsafe { self.consumer_fence(held.consumer_fence) }
+            } else {
+                0
+            };
+            held.state.release_slot(held.frame.slot, fence);
+        }
+        if owner {
+            unsafe {
+                self.gl.delete_vertex_array(self.vertex_array);
+                self.gl.delete_program(self.program);
+            }
+        }
+    }
+}
+
+/// ### English
+/// Compiles and links the quad program.
+///
+/// #### Parameters
+/// - `gl`: GL function table.
+/// - `header`: `#version` line (and precision for GLES) prepended to both shaders.
+///
+/// ### 中文
+/// 编译并链接四边形着色器程序。
+///
+/// #### 参数
+/// - `gl`：GL 函数表。
+/// - `header`：添加到两个着色器之前的 `#version` 行（GLES 还包括精度声明）。
+unsafe fn link_program(gl: &glow::Context, header: &str) -> Result<glow::NativeProgram, String> {
+    unsafe {
+        let program = gl.create_program()?;
+        let mut shaders = Vec::with_capacity(2);
+        for (kind, body) in [
+            (glow::VERTEX_SHADER, VERTEX_SHADER),
+            (glow::FRAGMENT_SHADER, FRAGMENT_SHADER),
+        ] {
+            let shader = gl.create_shader(kind)?;
+            gl.shader_source(shader, &format!("{header}{body}"));
+            gl.compile_shader(shader);
+            gl.attach_shader(program, shader);
+            shaders.push(shader);
+            if !gl.get_shader_compile_status(shader) {
+                let log = gl.get_shader_info_log(shader);
+                for shader in shaders {
+                    gl.delete_shader(shader);
+                }
+                gl.delete_program(program);
+                return Err(format!("Failed to compile compositor shader: {log}"));
+            }
+        }
+        gl.link_program(program);
+        for shader in shaders {
+            gl.detach_shader(program, shader);
+            gl.delete_shader(shader);
+        }
+        if !gl.get_program_link_status(program) {
+            let log = gl.get_program_info_log(program);
+            gl.delete_program(program);
+            return Err(format!("Failed to link compositor program: {log}"));
+        }
+        Ok(program)
+    }
+}
@@ -0,0 +1,140 @@
+//! ### English
+//! Engine configuration passed at creation: how long blocking calls wait for the Servo threads.
+//!
+//! ### 中文
+//! 创建引擎时传入的配置：阻塞调用等待 Servo 线程的时长。
+
+use std::time::{Duration, Instant};
+
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST, XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER,
+};
+
+/// ### English
+/// Timeout used for a field left at `0`, in milliseconds.
+///
+/// ### 中文
+/// 字段为 `0` 时使用的超时时长（毫秒）。
+const DEFAULT_TIMEOUT_MS: u32 = 30_000;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// ### English
+/// Engine configuration (`xian_web_engine_config_default` fills in the defaults).
+///
+/// Timeouts bound how long a blocking call waits for a Servo thread; `0` uses the 30-second default.
+/// A call that times out fails with `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`.
+///
+/// ### 中文
+/// 引擎配置（`xian_web_engine_config_default` 会填入默认值）。
+///
+/// 超时限定阻塞调用等待 Servo 线程的时长；`0` 使用 30 秒默认值。超时的调用会以
+/// `XIAN_WEB_ENGINE_STATUS_TIMED_OUT` 失败。
+pub struct XianWebEngineConfig {
+    /// ### English
+    /// How long engine creation waits for each Servo thread to initialize, in milliseconds.
+    ///
+    /// ### 中文
+    /// 引擎创建等待每个 Servo 线程完成初始化的时长（毫秒）。
+    pub init_timeout_ms: u32,
+    /// ### English
+    /// How long a blocking view creation waits for the view, in milliseconds.
+    ///
+    /// ### 中文
+    /// 阻塞式 view 创建等待 view 的时长（毫秒）。
+    pub view_timeout_ms: u32,
+    /// ### English
+    /// How long other blocking requests (e.g. view group creation) wait for their reply, in
+    /// milliseconds.
+    ///
+    /// ### 中文
+    /// 其他阻塞请求（例如创建 view group）等待回包的时长（毫秒）。
+    pub command_timeout_ms: u32,
+    /// ### English
+    /// `XIAN_WEB_ENGINE_TIMEOUT_POLICY_*`: fail fast (default) or wait forever.
+    ///
+    /// ### 中文
+    /// `XIAN_WEB_ENGINE_TIMEOUT_POLICY_*`：快速失败（默认）或无限等待。
+    pub timeout_policy: u32,
+}
+
+impl Default for XianWebEngineConfig {
+    /// ### English
+    /// Returns 30-second timeouts with the fail-fast policy.
+    ///
+    /// ### 中文
+    /// 返回 30 秒超时与快速失败策略。
+    fn default() -> Self {
+        Self {
+            init_timeout_ms: DEFAULT_TIMEOUT_MS,
+            view_timeout_ms: DEFAULT_TIMEOUT_MS,
+            command_timeout_ms: DEFAULT_TIMEOUT_MS,
+            timeout_policy: XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST,
+        }
+    }
+}
+
+impl XianWebEngineConfig {
+    /// ### English
+    /// Rejects an unknown timeout policy.
+    ///
+    /// ### 中文
+    /// 拒绝未知的超时策略。
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match self.timeout_policy {
+            XIAN_WEB_ENGINE_TIMEOUT_POLICY_FAIL_FAST
+            | XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER => Ok(()),
+            policy => Err(format!("Unknown timeout policy {policy}")),
+        }
+    }
+
+    /// ### English
+    /// Returns the deadline of a Servo thread initialization starting now (`None` = wait forever).
+    ///
+    /// ### 中文
+    /// 返回从现在开始的 Servo 线程初始化的截止时间（`None` 表示无限等待）。
+    pub(super) fn init_deadline(&self) -> Option<Instant> {
+        self.deadline(self.init_timeout_ms)
+    }
+
+    /// ### English
+    /// Returns the deadline of a blocking view creation starting now (`None` = wait forever).
+    ///
+    /// ### 中文
+    /// 返回从现在开始的阻塞式 view 创建的截止时间（`None` 表示无限等待）。
+    pub(super) fn view_deadline(&self) -> Option<Instant> {
+        self.deadline(self.view_timeout_ms)
+    }
+
+    /// ### English
+    /// Returns the deadline of another blocking request starting now (`None` = wait forever).
+    ///
+    /// ### 中文
+    /// 返回从现在开始的其他阻塞请求的截止时间（`None` 表示无限等待）。
+    pub(super) fn command_deadline(&self) -> Option<Instant> {
+        self.deadline(self.command_timeout_ms)
+    }
+
+    /// ### English
+    /// Turns a timeout field into a deadline under the configured policy.
+    ///
+    /// #### Parameters
+    /// - `timeout_ms`: Timeout in milliseconds (`0` = default).
+    ///
+    /// ### 中文
+    /// 按配置的策略将超时字段转换为截止时间。
+    ///
+    /// #### 参数
+    /// - `timeout_ms`：超时时长（毫秒；`0` 表示默认值）。
+    fn deadline(&self, timeout_ms: u32) -> Option<Instant> {
+        if self.timeout_policy == XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER {
+            return None;
+        }
+        let timeout_ms = if timeout_ms == 0 {
+            DEFAULT_TIMEOUT_MS
+        } else {
+            timeout_ms
+        };
+        Some(Instant::now() + Duration::from_millis(u64::from(timeout_ms)))
+    }
+}
@@ -1,35 +1,71 @@
 //! ### English
-//! Engine runtime that spawns and owns the dedicated Servo thread.
+//! Engine runtime that spawns and owns the dedicated Servo threads.
 //!
 //! ### 中文
 //! 创建并持有独立 Servo 线程的引擎运行时。
 
 use std::ffi::c_void;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
 
 use dpi::PhysicalSize;
+use url::Url;
 
+use crate::engine::dns;
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_QUEUE_FULL, XIAN_WEB_ENGINE_STATUS_TIMED_OUT,
+};
 use crate::engine::flags;
 use crate::engine::frame::SharedFrameState;
+use crate::engine::init_progress::{self, XIAN_WEB_ENGINE_INIT_PHASE_READY};
 use crate::engine::input::{CoalescedMouseMove, CoalescedResize, InputEventQueue};
 use crate::engine::lockfree::OneShot;
-use crate::engine::vsync::VsyncCallbackQueue;
+use crate::engine::rendering::{VulkanFrameExports, vulkan_device_installed};
+use crate::engine::resources::{ArchiveResourceReader, BoxedResourceReader, DirResourceReader};
+use crate::engine::thread_config::{ThreadConfig, XianWebEngineThreadPoolConfig};
+use crate::engine::trace;
+use crate::engine::vsync::MAX_REFRESH_RATE_HZ;
+use crate::engine::watchdog::XianWebEngineHealth;
 
+use super::binary_channel::{BinaryChannel, MAX_BINARY_MESSAGE_BYTES};
+use super::binding::ViewBinding;
 use super::coalesced::{CoalescedLoadUrl, PendingWork};
-use super::command::Command;
-use super::pending::PendingIdQueue;
-use super::queue::CommandQueue;
-use super::servo_thread;
-use super::view_handle::{WebEngineViewHandle, WebEngineViewHandleInit};
+use super::command::{Command, CreateViewReply, CreateViewResult};
+use super::compositor::{ViewCompositor, XianWebEngineCompositeRect};
+use super::engine_config::XianWebEngineConfig;
+use super::events::ViewEventQueue;
+use super::image_limits::XianWebEngineImageLimits;
+use super::image_loads::XianWebEngineImageLoadStats;
+use super::network_limits::XianWebEngineNetworkLimits;
+use super::preload;
+use super::queue;
+use super::session::SessionSnapshot;
+use super::shard::ServoShard;
+use super::storage_group::{Setting, ShardParams, StorageGroups};
+use super::view_handle::{UPLOADS_DIR, WebEngineViewHandle, WebEngineViewHandleInit, render_size};
+use super::view_strings::ViewStrings;
 
 /// ### English
-/// Engine runtime that owns the dedicated Servo thread.
+/// Completion callback of `create_view_async`.
 ///
 /// ### 中文
-/// 持有独立 Servo 线程的运行时。
+/// `create_view_async` 的完成回调。
+type CreateViewDone = Box<dyn FnOnce(Result<(), String>) + Send>;
+
+/// ### English
+/// A started view creation: the (unbound) handle, plus the response to wait on for a blocking one.
+///
+/// ### 中文
+/// 已开始的 view 创建：（尚未绑定的）句柄，以及阻塞创建时用于等待的回包。
+type StartedCreateView = (WebEngineViewHandle, Option<Arc<OneShot<CreateViewResult>>>);
+
+/// ### English
+/// Engine runtime that owns the dedicated Servo threads (one per shard).
+///
+/// ### 中文
+/// 持有独立 Servo 线程（每个分片一个）的运行时。
 pub struct EngineRuntime {
     /// ### English
     /// Default view size used when the embedder passes an invalid size.
@@ -38,43 +74,65 @@ pub struct EngineRuntime {
     /// 宿主传入无效尺寸时使用的默认 view 尺寸。
     default_size: PhysicalSize<u32>,
     /// ### English
-    /// Command queue for control messages into the Servo thread.
+    /// Running Servo threads that views are sharded across (empty once shut down).
+    ///
+    /// ### 中文
+    /// view 分片所在的运行中 Servo 线程（关闭后为空）。
+    shards: Vec<ServoShard>,
+    /// ### English
+    /// Round-robin cursor used to place ungrouped views and new groups on a shard.
+    ///
+    /// ### 中文
+    /// 轮询游标：用于为未分组的 view 与新建 group 选择分片。
+    next_shard: AtomicUsize,
+    /// ### English
+    /// Shards of the storage groups, started on demand.
+    ///
+    /// ### 中文
+    /// 各存储组的分片，按需启动。
+    storage_groups: StorageGroups,
+    /// ### English
+    /// Engine configuration (timeouts of blocking calls).
     ///
     /// ### 中文
-    /// 发送到 Servo 线程的控制命令队列。
-    command_queue: Arc<CommandQueue>,
+    /// 引擎配置（阻塞调用的超时设置）。
+    config: XianWebEngineConfig,
     /// ### English
-    /// Join handle for the dedicated Servo thread (owned by this runtime).
+    /// Hidden, parked views created ahead of time (cold path; handed out by `take_prewarmed_view`).
     ///
     /// ### 中文
-    /// 独立 Servo 线程的 join handle（由本运行时持有）。
-    thread: Option<thread::JoinHandle<()>>,
+    /// 预先创建的隐藏 parked view（冷路径；由 `take_prewarmed_view` 取出）。
+    prewarmed: Mutex<Vec<WebEngineViewHandle>>,
     /// ### English
-    /// Thread handle used to wake the Servo thread.
+    /// Hidden view warming the caches for `preload` (replaced by the next call).
     ///
     /// ### 中文
-    /// 用于唤醒 Servo 线程的线程句柄。
-    thread_handle: thread::Thread,
+    /// 为 `preload` 预热缓存的隐藏 view（下一次调用时被替换）。
+    preloader: Mutex<Option<WebEngineViewHandle>>,
     /// ### English
-    /// Queue of vsync callbacks produced by Servo and consumed by the embedder tick.
+    /// Directory for engine-managed disk files (staged uploads), set by `set_cache_config`.
     ///
     /// ### 中文
-    /// Vsync 回调队列：由 Servo 产生、由宿主 tick 消费。
-    vsync_queue: Arc<VsyncCallbackQueue>,
+    /// 引擎管理的磁盘文件目录（暂存的上传文件），由 `set_cache_config` 设置。
+    cache_dir: Arc<Mutex<PathBuf>>,
     /// ### English
-    /// Pending view-id queue used to coalesce per-view work scheduling.
+    /// Compositor created on the first `composite_views` call (bound to that thread's GL context).
     ///
     /// ### 中文
-    /// pending view-id 队列：用于合并每 view 的工作调度。
-    pending_queue: Arc<PendingIdQueue>,
+    /// 首次调用 `composite_views` 时创建的合成器（绑定到该线程的 GL 上下文）。
+    compositor: Mutex<Option<ViewCompositor>>,
 }
 
 impl EngineRuntime {
     /// ### English
-    /// Creates a new engine runtime and initializes the dedicated Servo thread.
+    /// Creates a new engine runtime and initializes the dedicated Servo threads.
+    ///
+    /// `glfw_shared_window` must be the embedder-owned GLFW window whose context will be shared
+    /// (the host `EGLContext` when `engine_flags` contains `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`).
+    /// This function blocks until every Servo thread finishes initialization (or times out).
     ///
-    /// `glfw_shared_window` must be the embedder-owned GLFW window whose context will be shared.
-    /// This function blocks until the Servo thread finishes initialization (or times out).
+    /// `thread_pools.servo_threads` selects how many Servo threads views are sharded across
+    /// (`0` = one). Threads are started one after another so their GL contexts are created serially.
     ///
     /// `thread_pool_cap` controls the maximum worker threads used by Servo's internal thread pools.
     /// `0` means "no cap" (use CPU parallelism).
@@ -85,12 +143,19 @@ impl EngineRuntime {
     /// - `resources_dir`: Optional resource directory override.
     /// - `config_dir`: Optional config directory override.
     /// - `thread_pool_cap`: Servo worker thread cap (`0` means no cap).
+    /// - `thread_pools`: Per-pool worker counts (`0` fields fall back to `thread_pool_cap`).
+    /// - `engine_flags`: Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+    /// - `config`: Timeouts of blocking calls.
     ///
     /// ### 中文
     /// 创建一个新的引擎运行时，并初始化独立的 Servo 线程。
     ///
-    /// `glfw_shared_window` 必须是宿主侧持有、用于共享上下文的 GLFW window。
-    /// 该函数会阻塞等待 Servo 线程完成初始化（或超时）。
+    /// `glfw_shared_window` 必须是宿主侧持有、用于共享上下文的 GLFW window
+    /// （`engine_flags` 含 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 时为宿主 `EGLContext`）。
+    /// 该函数会阻塞等待所有 Servo 线程完成初始化（或超时）。
+    ///
+    /// `thread_pools.servo_threads` 决定 view 分片到多少个 Servo 线程上（`0` 表示一个）。
+    /// 各线程依次启动，以保证它们的 GL 上下文串行创建。
     ///
     /// `thread_pool_cap` 用于限制 Servo 内部线程池的最大工作线程数；
     /// `0` 表示“不封顶”（使用 CPU 并行度）。
@@ -101,100 +166,388 @@ impl EngineRuntime {
     /// - `resources_dir`：可选的资源目录覆盖。
     /// - `config_dir`：可选的配置目录覆盖。
     /// - `thread_pool_cap`：Servo 工作线程上限（`0` 表示不封顶）。
+    /// - `thread_pools`：各线程池的工作线程数（为 `0` 的字段回退到 `thread_pool_cap`）。
+    /// - `engine_flags`：引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+    /// - `config`：阻塞调用的超时设置。
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         glfw_shared_window: *mut c_void,
         default_size: PhysicalSize<u32>,
         resources_dir: Option<PathBuf>,
         config_dir: Option<PathBuf>,
         thread_pool_cap: u32,
+        thread_pools: XianWebEngineThreadPoolConfig,
+        engine_flags: u32,
+        config: XianWebEngineConfig,
     ) -> Result<Self, String> {
+        config.validate()?;
+        let known_flags = flags::XIAN_WEB_ENGINE_ENGINE_FLAG_EGL
+            | flags::XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG
+            | flags::XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL
+            | flags::XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE
+            | flags::XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS
+            | flags::XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY;
+        if engine_flags & !known_flags != 0 {
+            return Err(format!("Unknown engine flags 0x{engine_flags:X}"));
+        }
+        if (engine_flags & flags::XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS) != 0 {
+            check_content_process_host()?;
+        }
         let glfw_shared_window_handle = glfw_shared_window as usize;
 
-        let vsync_queue = Arc::new(VsyncCallbackQueue::with_capacity(4096));
-        let vsync_queue_for_thread = vsync_queue.clone();
-
-        let pending_queue = Arc::new(PendingIdQueue::with_capacity(64 * 1024));
-        let pending_queue_for_thread = pending_queue.clone();
-
-        let command_queue = Arc::new(CommandQueue::new());
-        let command_queue_for_thread = command_queue.clone();
-
-        let init = Arc::new(OneShot::new(thread::current()));
-        let init_for_thread = init.clone();
+        let shard_count = thread_pools.servo_threads.max(1);
+        let mut shards: Vec<ServoShard> = Vec::with_capacity(shard_count as usize);
+        for _ in 0..shard_count {
+            let (shard, init) = ServoShard::spawn(
+                glfw_shared_window_handle,
+                resources_dir.clone(),
+                config_dir.clone(),
+                thread_pool_cap,
+                thread_pools,
+                engine_flags,
+            );
+            shards.push(shard);
+            if let Err(err) = ServoShard::wait_init(&init, config.init_deadline()) {
+                for shard in shards {
+                    shard.shutdown();
+                }
+                return Err(err);
+            }
+        }
+        init_progress::report(XIAN_WEB_ENGINE_INIT_PHASE_READY);
 
-        let thread = thread::spawn(move || {
-            servo_thread::run_servo_thread(
+        Ok(Self {
+            default_size,
+            shards,
+            next_shard: AtomicUsize::new(0),
+            storage_groups: StorageGroups::new(ShardParams {
                 glfw_shared_window_handle,
                 resources_dir,
                 config_dir,
-                vsync_queue_for_thread,
-                pending_queue_for_thread,
-                command_queue_for_thread,
                 thread_pool_cap,
-                init_for_thread,
-            );
-        });
+                thread_pools,
+                engine_flags,
+            }),
+            config,
+            prewarmed: Mutex::new(Vec::new()),
+            preloader: Mutex::new(None),
+            cache_dir: Arc::new(Mutex::new(std::env::temp_dir().join("xian-web-engine"))),
+            compositor: Mutex::new(None),
+        })
+    }
 
-        let thread_handle = thread.thread().clone();
+    /// ### English
+    /// Returns the next shard in round-robin order and its index.
+    ///
+    /// ### 中文
+    /// 按轮询顺序返回下一个分片及其下标。
+    fn next_shard(&self) -> Result<(usize, &ServoShard), String> {
+        if self.shards.is_empty() {
+            return Err("Engine is shut down".to_string());
+        }
+        let index = self.next_shard.fetch_add(1, Ordering::Relaxed) % self.shards.len();
+        Ok((index, &self.shards[index]))
+    }
 
-        match init.recv_timeout(Duration::from_secs(30)) {
-            Some(Ok(())) => Ok(Self {
-                default_size,
-                command_queue,
-                thread: Some(thread),
-                thread_handle,
-                vsync_queue,
-                pending_queue,
-            }),
-            Some(Err(err)) => {
-                thread_handle.unpark();
-                let _ = thread.join();
-                Err(err)
-            }
-            None => {
-                command_queue.push(Command::Shutdown);
-                thread_handle.unpark();
-                let _ = thread.join();
-                Err("Timed out initializing Servo thread".to_string())
-            }
+    /// ### English
+    /// Resolves an engine-wide group ID to its shard and the shard-local group ID.
+    ///
+    /// Engine-wide IDs are `local_id * shard_count + shard_index`, so a single shard keeps the
+    /// shard-local IDs unchanged.
+    ///
+    /// #### Parameters
+    /// - `group`: Engine-wide group ID (non-zero).
+    ///
+    /// ### 中文
+    /// 将引擎级 group ID 解析为其所在分片与分片内 group ID。
+    ///
+    /// 引擎级 ID 为 `local_id * shard_count + shard_index`，因此只有一个分片时与分片内 ID 相同。
+    ///
+    /// #### 参数
+    /// - `group`：引擎级 group ID（非 0）。
+    fn group_shard(&self, group: u32) -> Option<(&ServoShard, u32)> {
+        let count = u32::try_from(self.shards.len())
+            .ok()
+            .filter(|&count| count != 0)?;
+        let shard = self.shards.get((group % count) as usize)?;
+        Some((shard, group / count))
+    }
+
+    /// ### English
+    /// Calls `f` for every running shard, storage group shards included.
+    ///
+    /// #### Parameters
+    /// - `f`: Called with each shard.
+    ///
+    /// ### 中文
+    /// 对每个运行中的分片（包括存储组分片）调用 `f`。
+    ///
+    /// #### 参数
+    /// - `f`：以每个分片调用。
+    fn for_each_shard(&self, mut f: impl FnMut(&ServoShard)) {
+        self.shards.iter().for_each(&mut f);
+        self.storage_groups.for_each(f);
+    }
+
+    /// ### English
+    /// Sends a command to every shard.
+    ///
+    /// Returns `false` if the engine is shut down or any shard is shutting down.
+    ///
+    /// #### Parameters
+    /// - `command`: Builds the command sent to each shard.
+    ///
+    /// ### 中文
+    /// 向每个分片发送命令。
+    ///
+    /// 若引擎已关闭或任一分片正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `command`：为每个分片构建要发送的命令。
+    fn broadcast(&self, command: impl Fn() -> Command) -> bool {
+        let mut sent = !self.shards.is_empty();
+        self.for_each_shard(|shard| sent &= shard.send(command()));
+        sent
+    }
+
+    /// ### English
+    /// Applies an engine-wide setting to every shard and keeps it for storage group shards started
+    /// later.
+    ///
+    /// Returns `false` if the engine is shut down or any shard rejected the setting.
+    ///
+    /// #### Parameters
+    /// - `key`: Setting identity (a later setting with the same key replaces it).
+    /// - `setting`: Applies the setting to one shard.
+    ///
+    /// ### 中文
+    /// 将引擎级设置应用到每个分片，并为之后启动的存储组分片保留它。
+    ///
+    /// 若引擎已关闭或任一分片拒绝该设置，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `key`：设置的标识（之后相同键的设置会替换它）。
+    /// - `setting`：将设置应用到一个分片。
+    fn broadcast_setting(
+        &self,
+        key: String,
+        setting: impl Fn(&ServoShard) -> bool + Send + Sync + 'static,
+    ) -> bool {
+        let mut applied = !self.shards.is_empty();
+        for shard in &self.shards {
+            applied &= setting(shard);
         }
+        let setting: Setting = Box::new(setting);
+        self.storage_groups.set(key, setting) && applied
     }
 
     /// ### English
-    /// Creates one view by sending a `CreateView` command to the Servo thread.
+    /// Creates one view by sending a `CreateView` command to a Servo thread.
     ///
+    /// Grouped views live on their group's shard; other views are placed round-robin.
     /// The returned `WebEngineViewHandle` is thread-safe for the embedder thread to use.
     ///
     /// #### Parameters
     /// - `initial_size`: Requested initial view size (0 is treated as `default_size`).
     /// - `target_fps`: Target FPS for fixed-interval refresh (0 means external-vsync mode).
+    /// - `group`: View group to join (`0` = none; the group's refresh rate overrides `target_fps`).
     /// - `view_flags`: Bitflags controlling safety/performance trade-offs.
     ///
     /// ### 中文
     /// 通过向 Servo 线程发送 `CreateView` 命令来创建一个 view。
     ///
+    /// 已分组的 view 位于其 group 所在的分片；其他 view 按轮询分配。
     /// 返回的 `WebEngineViewHandle` 可供宿主线程安全使用。
     ///
     /// #### 参数
     /// - `initial_size`：请求的初始尺寸（为 0 时使用 `default_size`）。
     /// - `target_fps`：固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `group`：要加入的 view group（`0` 表示不加入；group 的刷新率优先于 `target_fps`）。
     /// - `view_flags`：控制安全/性能权衡的位标志。
     pub fn create_view(
         &self,
         initial_size: PhysicalSize<u32>,
         target_fps: u32,
+        group: u32,
         view_flags: u32,
     ) -> Result<WebEngineViewHandle, String> {
-        if self.thread.is_none() {
-            return Err("Engine is shut down".to_string());
+        let started = self.start_create_view(initial_size, target_fps, group, view_flags, None)?;
+        self.finish_create_view(started)
+    }
+
+    /// ### English
+    /// Waits for a blocking view creation to finish and binds its handle.
+    ///
+    /// #### Parameters
+    /// - `started`: Handle and response returned by `start_create_view`.
+    ///
+    /// ### 中文
+    /// 等待阻塞的 view 创建完成并绑定其句柄。
+    ///
+    /// #### 参数
+    /// - `started`：`start_create_view` 返回的句柄与回包。
+    fn finish_create_view(
+        &self,
+        started: StartedCreateView,
+    ) -> Result<WebEngineViewHandle, String> {
+        let (handle, response) = started;
+        let Some(response) = response else {
+            return Err("Missing view creation response".to_string());
+        };
+
+        match response.recv_until(self.config.view_deadline()) {
+            Some(Ok(ids)) => {
+                handle.settle(Some(ids));
+                Ok(handle)
+            }
+            Some(Err(err)) => Err(err),
+            None => {
+                queue::set_last_status(XIAN_WEB_ENGINE_STATUS_TIMED_OUT);
+                Err("Timed out creating view".to_string())
+            }
         }
+    }
+
+    /// ### English
+    /// Starts creating one view without waiting for the Servo thread and returns its handle right
+    /// away; `done` runs on the Servo thread once the view exists (or creation failed).
+    ///
+    /// The handle can be used immediately: input, resizes and navigations are queued as usual, other
+    /// requests are held back until the view exists, and closing it destroys the view as soon as it
+    /// is created. If creation fails, requests through the handle fail.
+    ///
+    /// #### Parameters
+    /// - `initial_size`: Requested initial view size (0 is treated as `default_size`).
+    /// - `target_fps`: Target FPS for fixed-interval refresh (0 means external-vsync mode).
+    /// - `group`: View group to join (`0` = none; the group's refresh rate overrides `target_fps`).
+    /// - `view_flags`: Bitflags controlling safety/performance trade-offs.
+    /// - `done`: Completion callback (not called if this returns an error).
+    ///
+    /// ### 中文
+    /// 开始创建一个 view 而不等待 Servo 线程，并立即返回其句柄；view 创建完成（或创建失败）后，`done` 会在 Servo
+    /// 线程上运行。
+    ///
+    /// 该句柄可以立即使用：输入、resize 与导航照常排队，其他请求会暂缓到 view 创建完成，关闭句柄则会在 view 创建后
+    /// 立即销毁它。若创建失败，经由该句柄的请求会失败。
+    ///
+    /// #### 参数
+    /// - `initial_size`：请求的初始尺寸（为 0 时使用 `default_size`）。
+    /// - `target_fps`：固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `group`：要加入的 view group（`0` 表示不加入；group 的刷新率优先于 `target_fps`）。
+    /// - `view_flags`：控制安全/性能权衡的位标志。
+    /// - `done`：完成回调（若本函数返回错误则不会被调用）。
+    pub fn create_view_async(
+        &self,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        group: u32,
+        view_flags: u32,
+        done: CreateViewDone,
+    ) -> Result<WebEngineViewHandle, String> {
+        self.start_create_view(initial_size, target_fps, group, view_flags, Some(done))
+            .map(|(handle, _)| handle)
+    }
+
+    /// ### English
+    /// Validates the view flags, sends the `CreateView` command and returns the (unbound) handle,
+    /// plus the one-shot response to wait on when no completion callback is given.
+    ///
+    /// #### Parameters
+    /// - `initial_size`: Requested initial view size (0 is treated as `default_size`).
+    /// - `target_fps`: Target FPS for fixed-interval refresh (0 means external-vsync mode).
+    /// - `group`: View group to join (`0` = none).
+    /// - `view_flags`: Bitflags controlling safety/performance trade-offs.
+    /// - `done`: Completion callback of an async creation (`None` for a blocking one).
+    ///
+    /// ### 中文
+    /// 校验 view 标志，发送 `CreateView` 命令并返回（尚未绑定的）句柄；未提供完成回调时，还会返回用于等待的一次性
+    /// 回包。
+    ///
+    /// #### 参数
+    /// - `initial_size`：请求的初始尺寸（为 0 时使用 `default_size`）。
+    /// - `target_fps`：固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `group`：要加入的 view group（`0` 表示不加入）。
+    /// - `view_flags`：控制安全/性能权衡的位标志。
+    /// - `done`：异步创建的完成回调（阻塞创建时为 `None`）。
+    fn start_create_view(
+        &self,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        group: u32,
+        view_flags: u32,
+        done: Option<CreateViewDone>,
+    ) -> Result<StartedCreateView, String> {
+        let (shard, group) = if group == 0 {
+            (self.next_shard()?.1, 0)
+        } else {
+            self.group_shard(group)
+                .ok_or_else(|| "Engine is shut down".to_string())?
+        };
+        self.start_create_view_on(shard, group, initial_size, target_fps, view_flags, done)
+    }
 
+    /// ### English
+    /// Starts creating one view on `shard` (see `start_create_view`).
+    ///
+    /// #### Parameters
+    /// - `shard`: Shard the view lives on.
+    /// - `group`: Shard-local view group to join (`0` = none).
+    /// - `initial_size`: Requested initial view size (0 is treated as `default_size`).
+    /// - `target_fps`: Target FPS for fixed-interval refresh (0 means external-vsync mode).
+    /// - `view_flags`: Bitflags controlling safety/performance trade-offs.
+    /// - `done`: Completion callback of an async creation (`None` for a blocking one).
+    ///
+    /// ### 中文
+    /// 在 `shard` 上开始创建一个 view（见 `start_create_view`）。
+    ///
+    /// #### 参数
+    /// - `shard`：view 所在的分片。
+    /// - `group`：要加入的分片内 view group（`0` 表示不加入）。
+    /// - `initial_size`：请求的初始尺寸（为 0 时使用 `default_size`）。
+    /// - `target_fps`：固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `view_flags`：view 创建标志。
+    /// - `done`：异步创建的完成回调（阻塞创建时为 `None`）。
+    fn start_create_view_on(
+        &self,
+        shard: &ServoShard,
+        group: u32,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        view_flags: u32,
+        done: Option<CreateViewDone>,
+    ) -> Result<StartedCreateView, String> {
         let unsafe_no_consumer_fence =
             (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE) != 0;
         let unsafe_no_producer_fence =
             (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE) != 0;
         let input_single_producer =
             (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER) != 0;
+        let mipmaps = (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS) != 0;
+        let orphaning_resize =
+            (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE) != 0;
+        let vulkan_exports = if (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT) != 0 {
+            if !vulkan_device_installed() {
+                return Err("Vulkan export requires xian_web_engine_set_vulkan_device".to_string());
+            }
+            if unsafe_no_consumer_fence {
+                return Err(
+                    "Vulkan export cannot be combined with UNSAFE_NO_CONSUMER_FENCE".to_string(),
+                );
+            }
+            Some(Arc::new(VulkanFrameExports::default()))
+        } else {
+            None
+        };
+        let color_format = (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK)
+            >> flags::XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT;
+        if color_format > flags::XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F {
+            return Err(format!("Unknown color format {color_format}"));
+        }
+        let render_scale = if (view_flags & flags::XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X) != 0 {
+            2.0
+        } else {
+            1.0
+        };
 
         let initial_size = if initial_size.width == 0 || initial_size.height == 0 {
             self.default_size
@@ -203,16 +556,46 @@ impl EngineRuntime {
         };
         let initial_size = PhysicalSize::new(initial_size.width.max(1), initial_size.height.max(1));
 
-        let shared = Arc::new(SharedFrameState::new(initial_size));
+        let shared = Arc::new(SharedFrameState::new(render_size(
+            initial_size,
+            render_scale,
+        )));
         let mouse_move = Arc::new(CoalescedMouseMove::default());
         let resize = Arc::new(CoalescedResize::default());
         let input_queue = Arc::new(InputEventQueue::new(input_single_producer));
         let load_url = Arc::new(CoalescedLoadUrl::default());
         let pending = Arc::new(PendingWork::default());
+        let events = Arc::new(ViewEventQueue::default());
+        let strings = Arc::new(ViewStrings::default());
+        let binary = Arc::new(BinaryChannel::default());
+        let binding = Arc::new(ViewBinding::unbound());
 
-        let response = Arc::new(OneShot::new(thread::current()));
+        let (reply, response) = match done {
+            None => {
+                let response = Arc::new(OneShot::new(thread::current()));
+                (CreateViewReply::Wait(response.clone()), Some(response))
+            }
+            Some(done) => {
+                let binding = binding.clone();
+                let command_queue = shard.command_queue.clone();
+                let pending = pending.clone();
+                let pending_queue = shard.pending_queue.clone();
+                let thread_handle = shard.thread_handle.clone();
+                let reply = CreateViewReply::Callback(Box::new(move |result| {
+                    binding.settle(
+                        result.as_ref().ok().copied(),
+                        &command_queue,
+                        &pending,
+                        &pending_queue,
+                        &thread_handle,
+                    );
+                    done(result.map(|_| ()));
+                }));
+                (reply, None)
+            }
+        };
 
-        if !self.command_queue.try_push(Command::CreateView {
+        if !shard.send(Command::CreateView {
             initial_size,
             shared: shared.clone(),
             mouse_move: mouse_move.clone(),
@@ -220,62 +603,1068 @@ impl EngineRuntime {
             input_queue: input_queue.clone(),
             load_url: load_url.clone(),
             pending: pending.clone(),
+            events: events.clone(),
+            strings: strings.clone(),
+            binary: binary.clone(),
             target_fps,
+            group,
+            render_scale,
+            color_format,
+            mipmaps,
+            orphaning_resize,
+            vulkan_exports: vulkan_exports.clone(),
             unsafe_no_consumer_fence,
             unsafe_no_producer_fence,
-            response: response.clone(),
+            response: reply,
         }) {
-            return Err("Engine is shutting down".to_string());
-        }
-        self.thread_handle.unpark();
-
-        match response.recv_timeout(Duration::from_secs(30)) {
-            Some(Ok((id, token))) => Ok(WebEngineViewHandle::new(WebEngineViewHandleInit {
-                id,
-                token,
-                shared,
-                mouse_move,
-                resize,
-                input_queue,
-                load_url,
-                pending,
-                pending_queue: self.pending_queue.clone(),
-                command_queue: self.command_queue.clone(),
-                thread_handle: self.thread_handle.clone(),
-                unsafe_no_consumer_fence,
-            })),
-            Some(Err(err)) => Err(err),
-            None => Err("Timed out creating view".to_string()),
+            return Err(rejection_message());
         }
+
+        let handle = WebEngineViewHandle::new(WebEngineViewHandleInit {
+            binding,
+            shared,
+            mouse_move,
+            resize,
+            input_queue,
+            load_url,
+            pending,
+            events,
+            strings,
+            binary,
+            pending_queue: shard.pending_queue.clone(),
+            command_queue: shard.command_queue.clone(),
+            thread_handle: shard.thread_handle.clone(),
+            cache_dir: self.cache_dir.clone(),
+            unsafe_no_consumer_fence,
+            vulkan_exports,
+            view_flags,
+        });
+        Ok((handle, response))
     }
 
     /// ### English
-    /// Drains pending vsync callbacks (used by the Java side to drive Servo refresh).
+    /// Creates a view and restores a session blob produced by `WebEngineViewHandle::save_session`.
+    ///
+    /// Servo does not let embedders seed back/forward entries, so the history is rebuilt by loading
+    /// every entry in order and then going back to the saved current entry, whose scroll offset is
+    /// re-applied once it has loaded.
+    ///
+    /// #### Parameters
+    /// - `blob`: Serialized session.
+    /// - `initial_size`: Initial view size (0 uses the engine default).
+    /// - `target_fps`: Target FPS (0 means external-vsync mode).
+    /// - `group`: View group to join (`0` = none).
+    /// - `view_flags`: View creation flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
     ///
     /// ### 中文
-    /// drain pending vsync 回调（供 Java 侧驱动 Servo refresh）。
-    pub fn tick(&self) {
-        self.vsync_queue.tick();
+    /// 创建一个 view，并恢复由 `WebEngineViewHandle::save_session` 生成的会话 blob。
+    ///
+    /// Servo 不允许嵌入方预置前进/后退条目，因此会依次加载每个条目来重建历史，然后后退到保存的当前条目，并在其
+    /// 加载完成后重新应用其滚动偏移。
+    ///
+    /// #### 参数
+    /// - `blob`：序列化的会话。
+    /// - `initial_size`：初始 view 尺寸（为 0 时使用引擎默认值）。
+    /// - `target_fps`：目标 FPS（0 表示外部 vsync 模式）。
+    /// - `group`：要加入的 view group（`0` 表示不加入）。
+    /// - `view_flags`：view 创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    pub fn create_view_from_session(
+        &self,
+        blob: &str,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        group: u32,
+        view_flags: u32,
+    ) -> Result<WebEngineViewHandle, String> {
+        let snapshot =
+            SessionSnapshot::decode(blob).ok_or_else(|| "Invalid session blob".to_string())?;
+
+        let handle = self.create_view(initial_size, target_fps, group, view_flags)?;
+        restore_session(&handle, &snapshot);
+        Ok(handle)
     }
 
     /// ### English
-    /// Requests Servo thread shutdown and joins it.
+    /// Creates a view showing the same page as `source`, for splitting a page into a second panel.
+    ///
+    /// The source's current URL and scroll offset are read from its published snapshots (no round
+    /// trip to its Servo thread) and restored like a session blob: the page is loaded again
+    /// (answered by Servo's HTTP cache where it can be) and the scroll offset re-applied once it
+    /// has loaded. Script state, form input and back/forward entries are not copied.
+    ///
+    /// #### Parameters
+    /// - `source`: View to clone.
+    /// - `initial_size`: Initial view size (0 uses the engine default).
+    /// - `target_fps`: Target FPS (0 means external-vsync mode).
+    /// - `view_flags`: View creation flags of the clone (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
     ///
     /// ### 中文
-    /// 请求 Servo 线程退出并 join。
-    pub fn shutdown(&mut self) {
-        if let Some(thread) = self.thread.take() {
-            self.command_queue.push(Command::Shutdown);
-            self.thread_handle.unpark();
-            let _ = thread.join();
-            self.command_queue.close();
+    /// 创建一个与 `source` 显示同一页面的 view，用于把页面拆分到第二个面板。
+    ///
+    /// 源 view 当前的 URL 与滚动偏移取自其已发布的快照（无需与其 Servo 线程往返），并像会话 blob 一样恢复：
+    /// 页面会重新加载（尽可能由 Servo 的 HTTP 缓存应答），并在加载完成后重新应用滚动偏移。脚本状态、表单输入
+    /// 与前进/后退条目不会被复制。
+    ///
+    /// #### 参数
+    /// - `source`：要克隆的 view。
+    /// - `initial_size`：初始 view 尺寸（为 0 时使用引擎默认值）。
+    /// - `target_fps`：目标 FPS（0 表示外部 vsync 模式）。
+    /// - `view_flags`：克隆 view 的创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    pub fn clone_view(
+        &self,
+        source: &WebEngineViewHandle,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        view_flags: u32,
+    ) -> Result<WebEngineViewHandle, String> {
+        let snapshot = source.session_snapshot();
+        if snapshot.current_url().is_none() {
+            return Err("Source view has no document to clone".to_string());
+        }
+
+        let handle = self.create_view(initial_size, target_fps, 0, view_flags)?;
+        restore_session(&handle, &snapshot);
+        Ok(handle)
+    }
+
+    /// ### English
+    /// Creates a view whose storage (cookies, web storage, IndexedDB and HTTP cache) belongs to a
+    /// storage group: views of the same group share it, views of other groups do not see it, and
+    /// `XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL` keeps it in memory only.
+    ///
+    /// Each group runs on its own Servo thread, started (blocking like engine creation) when the
+    /// group's first view is created.
+    ///
+    /// #### Parameters
+    /// - `initial_size`: Requested initial view size (0 is treated as `default_size`).
+    /// - `target_fps`: Target FPS for fixed-interval refresh (0 means external-vsync mode).
+    /// - `view_flags`: View creation flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
+    /// - `storage_group`: `XIAN_WEB_ENGINE_STORAGE_GROUP_*` or an embedder-chosen group ID.
+    ///
+    /// ### 中文
+    /// 创建一个 view，其存储（cookie、Web Storage、IndexedDB 与 HTTP 缓存）归属于一个存储组：同组的 view 共享它，
+    /// 其他组的 view 看不到它；`XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL` 则只把它保存在内存中。
+    ///
+    /// 每个组运行在自己的 Servo 线程上，在该组创建第一个 view 时启动（与创建引擎一样会阻塞）。
+    ///
+    /// #### 参数
+    /// - `initial_size`：请求的初始尺寸（为 0 时使用 `default_size`）。
+    /// - `target_fps`：固定间隔 refresh 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `view_flags`：view 创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    /// - `storage_group`：`XIAN_WEB_ENGINE_STORAGE_GROUP_*` 或宿主自选的组 ID。
+    pub fn create_view_in_storage_group(
+        &self,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        view_flags: u32,
+        storage_group: u32,
+    ) -> Result<WebEngineViewHandle, String> {
+        if storage_group == flags::XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT {
+            return self.create_view(initial_size, target_fps, 0, view_flags);
+        }
+        if self.shards.is_empty() {
+            return Err("Engine is shut down".to_string());
+        }
+
+        let started = self.storage_groups.with_shard(
+            storage_group,
+            self.config.init_deadline(),
+            |shard| self.start_create_view_on(shard, 0, initial_size, target_fps, view_flags, None),
+        )??;
+        self.finish_create_view(started)
+    }
+
+    /// ### English
+    /// Creates `count` hidden, parked views and keeps them in the prewarm pool.
+    ///
+    /// Returns the number of views actually created (creation stops at the first failure).
+    ///
+    /// #### Parameters
+    /// - `count`: Number of views to create.
+    /// - `initial_size`: Initial view size (0 uses the engine default).
+    /// - `target_fps`: Target FPS of the pooled views (0 means external-vsync mode).
+    /// - `view_flags`: View creation flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 创建 `count` 个隐藏的 parked view，并放入预热池。
+    ///
+    /// 返回实际创建的 view 数量（遇到第一次失败即停止）。
+    ///
+    /// #### 参数
+    /// - `count`：要创建的 view 数量。
+    /// - `initial_size`：初始 view 尺寸（为 0 时使用引擎默认值）。
+    /// - `target_fps`：池内 view 的目标 FPS（0 表示外部 vsync 模式）。
+    /// - `view_flags`：view 创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    pub fn prewarm_views(
+        &self,
+        count: u32,
+        initial_size: PhysicalSize<u32>,
+        target_fps: u32,
+        view_flags: u32,
+    ) -> u32 {
+        let mut created = 0;
+        for _ in 0..count {
+            let Ok(handle) = self.create_view(initial_size, target_fps, 0, view_flags) else {
+                break;
+            };
+            if handle.set_active(false) {
+                handle.wake();
+            }
+            let Ok(mut pool) = self.prewarmed.lock() else {
+                break;
+            };
+            pool.push(handle);
+            created += 1;
         }
+        created
+    }
+
+    /// ### English
+    /// Warms the network and image caches for `urls` without blocking (e.g. during a loading screen).
+    ///
+    /// A hidden, parked view requests every URL once; it stays open until the next call or engine
+    /// shutdown. Its creation also compiles the shard's WebRender shaders if no view exists yet.
+    ///
+    /// #### Parameters
+    /// - `urls`: Absolute URLs to request.
+    ///
+    /// ### 中文
+    /// 以非阻塞方式为 `urls` 预热网络与图片缓存（例如在加载界面期间）。
+    ///
+    /// 一个隐藏的 parked view 会将每个 URL 各请求一次；它会一直保留，直到下一次调用或引擎关闭。若尚无 view，
+    /// 创建它也会编译所在分片的 WebRender shader。
+    ///
+    /// #### 参数
+    /// - `urls`：要请求的绝对 URL。
+    pub fn preload(&self, urls: &[&str]) -> Result<(), String> {
+        if urls.is_empty() {
+            return Err("No URLs to preload".to_string());
+        }
+        let urls = urls
+            .iter()
+            .map(|url| Url::parse(url).map_err(|err| format!("Invalid preload URL {url:?}: {err}")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let page = preload::preload_page_url(&urls)
+            .ok_or_else(|| "Failed to build the preload page".to_string())?;
+
+        let handle = self.create_view_async(PhysicalSize::new(1, 1), 0, 0, 0, Box::new(|_| {}))?;
+        let _ = handle.set_active(false);
+        let _ = handle.load_url(page.as_str());
+        handle.wake();
+
+        let mut preloader = self
+            .preloader
+            .lock()
+            .map_err(|_| "Preloader lock is poisoned".to_string())?;
+        *preloader = Some(handle);
+        Ok(())
+    }
+
+    /// ### English
+    /// Takes one view from the prewarm pool, resizes it, and makes it active.
+    ///
+    /// Returns `None` when the pool is empty.
+    ///
+    /// #### Parameters
+    /// - `size`: Requested size (0 keeps the prewarmed size).
+    ///
+    /// ### 中文
+    /// 从预热池取出一个 view，调整尺寸并设为 active。
+    ///
+    /// 池为空时返回 `None`。
+    ///
+    /// #### 参数
+    /// - `size`：请求的尺寸（为 0 时保持预热尺寸）。
+    pub fn take_prewarmed_view(&self, size: PhysicalSize<u32>) -> Option<WebEngineViewHandle> {
+        let handle = self.prewarmed.lock().ok()?.pop()?;
+
+        let mut wake = false;
+        if size.width != 0 && size.height != 0 {
+            wake |= handle.queue_resize(size);
+        }
+        wake |= handle.set_active(true);
+        if wake {
+            handle.wake();
+        }
+        Some(handle)
+    }
+
+    /// ### English
+    /// Creates a view group whose members share one refresh driver and drain order.
+    ///
+    /// The group is placed on a shard round-robin; all its members are created on that shard.
+    ///
+    /// #### Parameters
+    /// - `target_fps`: Target FPS numerator for the shared driver (0 means external-vsync mode).
+    /// - `fps_denominator`: Target FPS denominator (the rate is `target_fps / fps_denominator`).
+    ///
+    /// ### 中文
+    /// 创建一个 view group，其成员共享同一个 refresh driver 与 drain 顺序。
+    ///
+    /// group 按轮询分配到某个分片；其所有成员都会在该分片上创建。
+    ///
+    /// #### 参数
+    /// - `target_fps`：共享 driver 的目标 FPS 分子（0 表示外部 vsync 模式）。
+    /// - `fps_denominator`：目标 FPS 分母（帧率为 `target_fps / fps_denominator`）。
+    pub fn create_view_group(&self, target_fps: u32, fps_denominator: u32) -> Result<u32, String> {
+        let (index, shard) = self.next_shard()?;
+
+        let response = Arc::new(OneShot::new(thread::current()));
+        if !shard.send(Command::CreateViewGroup {
+            target_fps,
+            fps_denominator,
+            response: response.clone(),
+        }) {
+            return Err(rejection_message());
+        }
+
+        let local = match response.recv_until(self.config.command_deadline()) {
+            Some(result) => result?,
+            None => {
+                queue::set_last_status(XIAN_WEB_ENGINE_STATUS_TIMED_OUT);
+                return Err("Timed out creating view group".to_string());
+            }
+        };
+        let count = self.shards.len() as u32;
+        local
+            .checked_mul(count)
+            .and_then(|id| id.checked_add(index as u32))
+            .ok_or_else(|| "Too many view groups".to_string())
+    }
+
+    /// ### English
+    /// Destroys a view group. Views already in the group keep their shared driver.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID returned by `create_view_group`.
+    ///
+    /// ### 中文
+    /// 销毁 view group。已在组内的 view 会继续使用共享 driver。
+    ///
+    /// #### 参数
+    /// - `id`：`create_view_group` 返回的 group ID。
+    pub fn destroy_view_group(&self, id: u32) {
+        if id == 0 {
+            return;
+        }
+        if let Some((shard, id)) = self.group_shard(id) {
+            shard.send(Command::DestroyViewGroup { id });
+        }
+    }
+
+    /// ### English
+    /// Swaps the Servo resources directory; resources read afterwards come from `path`.
+    ///
+    /// Returns `false` if `path` is not a directory or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `path`: New resources directory.
+    ///
+    /// ### 中文
+    /// 替换 Servo 资源目录；此后读取的资源来自 `path`。
+    ///
+    /// 若 `path` 不是目录或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `path`：新的资源目录。
+    pub fn set_resources_dir(&self, path: PathBuf) -> bool {
+        if !path.is_dir() {
+            return false;
+        }
+        self.set_resource_reader(Box::new(DirResourceReader::new(path)))
+    }
+
+    /// ### English
+    /// Swaps the Servo resources to a zip archive file (loaded into memory, never extracted).
+    ///
+    /// Returns `false` if the archive cannot be read/parsed or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `path`: Archive file path (`.zip`/`.jar`/`.pak`).
+    ///
+    /// ### 中文
+    /// 将 Servo 资源替换为 zip 归档文件（载入内存，不会解压到磁盘）。
+    ///
+    /// 若归档无法读取/解析或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `path`：归档文件路径（`.zip`/`.jar`/`.pak`）。
+    pub fn set_resources_archive_file(&self, path: &Path) -> bool {
+        match ArchiveResourceReader::open(path) {
+            Ok(archive) => self.set_resource_reader(Box::new(archive)),
+            Err(_) => false,
+        }
+    }
+
+    /// ### English
+    /// Swaps the Servo resources to an in-memory zip archive (e.g. read from inside a mod jar).
+    ///
+    /// Returns `false` if the archive cannot be parsed or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `data`: Complete archive bytes.
+    ///
+    /// ### 中文
+    /// 将 Servo 资源替换为内存中的 zip 归档（例如从 mod jar 内读取）。
+    ///
+    /// 若归档无法解析或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `data`：完整的归档字节。
+    pub fn set_resources_archive_bytes(&self, data: Vec<u8>) -> bool {
+        match ArchiveResourceReader::from_bytes(data) {
+            Ok(archive) => self.set_resource_reader(Box::new(archive)),
+            Err(_) => false,
+        }
+    }
+
+    /// ### English
+    /// Hands a new resource reader to the Servo thread.
+    ///
+    /// Servo's resource reader is process-wide, so installing it on the first shard covers all.
+    ///
+    /// #### Parameters
+    /// - `reader`: Reader to install.
+    ///
+    /// ### 中文
+    /// 将新的资源读取器交给 Servo 线程。
+    ///
+    /// Servo 的资源读取器是进程级的，因此安装到第一个分片即可覆盖所有分片。
+    ///
+    /// #### 参数
+    /// - `reader`：要安装的读取器。
+    fn set_resource_reader(&self, reader: BoxedResourceReader) -> bool {
+        self.shards
+            .first()
+            .is_some_and(|shard| shard.send(Command::SetResourceReader { reader }))
+    }
+
+    /// ### English
+    /// Sets the OS priority and CPU affinity of every Servo thread and refresh scheduler thread
+    /// (best-effort; Windows only, ignored elsewhere).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `priority`: Thread priority (Windows `THREAD_PRIORITY_*`, `0` = normal).
+    /// - `affinity_mask`: CPU affinity bitmask (`0` keeps the current affinity).
+    ///
+    /// ### 中文
+    /// 设置所有 Servo 线程与 refresh 调度线程的操作系统优先级与 CPU 亲和性（尽力而为；仅 Windows，其他平台忽略）。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `priority`：线程优先级（Windows `THREAD_PRIORITY_*`，`0` 表示普通）。
+    /// - `affinity_mask`：CPU 亲和性位掩码（`0` 表示保持当前亲和性）。
+    pub fn set_thread_config(&self, priority: i32, affinity_mask: u64) -> bool {
+        let config = ThreadConfig {
+            priority,
+            affinity_mask,
+        };
+        self.broadcast_setting("thread_config".to_string(), move |shard| {
+            shard.send(Command::SetThreadConfig { config })
+        })
+    }
+
+    /// ### English
+    /// Registers a font from memory so pages can use it via `font-family` without a system install.
+    ///
+    /// The font applies to every view, existing ones from their next load.
+    ///
+    /// Returns `false` if `family` is empty or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `family`: CSS font-family name.
+    /// - `data`: Font file bytes (TTF/OTF/WOFF/WOFF2).
+    ///
+    /// ### 中文
+    /// 从内存注册字体，使页面无需安装系统字体即可通过 `font-family` 使用。
+    ///
+    /// 字体作用于所有 view；已存在的 view 从下一次加载开始生效。
+    ///
+    /// 若 `family` 为空或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `family`：CSS font-family 名称。
+    /// - `data`：字体文件字节（TTF/OTF/WOFF/WOFF2）。
+    pub fn register_font(&self, family: &str, data: Vec<u8>) -> bool {
+        if family.is_empty() || data.is_empty() {
+            return false;
+        }
+
+        let family = family.to_string();
+        self.broadcast_setting(format!("font:{family}"), move |shard| {
+            shard.send(Command::RegisterFont {
+                family: family.clone(),
+                data: data.clone(),
+            })
+        })
+    }
+
+    /// ### English
+    /// Configures engine caches.
+    ///
+    /// Servo's HTTP cache lives in memory and has no size limit setting, so `max_bytes` only switches it
+    /// on (`> 0`) or off (`0`). `cache_dir` receives the files the engine writes to disk itself.
+    ///
+    /// Returns `false` if `cache_dir` cannot be created or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `cache_dir`: New cache directory (`None` keeps the current one).
+    /// - `max_bytes`: HTTP cache budget (`0` disables the HTTP cache).
+    ///
+    /// ### 中文
+    /// 配置引擎缓存。
+    ///
+    /// Servo 的 HTTP 缓存位于内存中且没有大小上限设置，因此 `max_bytes` 仅用于开启（`> 0`）或关闭（`0`）。
+    /// `cache_dir` 用于存放引擎自身写入磁盘的文件。
+    ///
+    /// 若 `cache_dir` 无法创建或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `cache_dir`：新的缓存目录（`None` 表示保持当前目录）。
+    /// - `max_bytes`：HTTP 缓存预算（`0` 表示关闭 HTTP 缓存）。
+    pub fn set_cache_config(&self, cache_dir: Option<PathBuf>, max_bytes: u64) -> bool {
+        if let Some(cache_dir) = cache_dir {
+            if std::fs::create_dir_all(&cache_dir).is_err() {
+                return false;
+            }
+            if let Ok(mut current) = self.cache_dir.lock() {
+                *current = cache_dir;
+            }
+        }
+
+        self.broadcast_setting("http_cache".to_string(), move |shard| {
+            shard.send(Command::SetHttpCacheEnabled {
+                enabled: max_bytes > 0,
+            })
+        })
+    }
+
+    /// ### English
+    /// Sets the JavaScript heap limit of pages, applied to each page's script event loop when it
+    /// starts.
+    ///
+    /// The limit is Servo's process-wide `js_mem_max` preference, so it is shared by every engine
+    /// in the process (the last call wins) and only covers pages whose event loop starts
+    /// afterwards.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `limit_mb`: Limit in MiB (`0` = unlimited).
+    ///
+    /// ### 中文
+    /// 设置页面的 JavaScript 堆上限，在每个页面的脚本事件循环启动时应用。
+    ///
+    /// 该上限是 Servo 的进程级 `js_mem_max` 首选项，因此由进程内的所有引擎共享（以最后一次调用为准），且只覆盖
+    /// 之后启动事件循环的页面。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `limit_mb`：上限（MiB；`0` 表示不限制）。
+    pub fn set_heap_limit(&self, limit_mb: u32) -> bool {
+        self.broadcast_setting("heap_limit".to_string(), move |shard| {
+            shard.send(Command::SetHeapLimit { limit_mb })
+        })
+    }
+
+    /// ### English
+    /// Sets the limits the loopback proxy applies to all HTTP(S) traffic of the process, effective
+    /// immediately for new reads and connections.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `limits`: New limits (all-zero removes every limit).
+    ///
+    /// ### 中文
+    /// 设置回环代理对进程内全部 HTTP(S) 流量施加的限制，对新的读取与连接立即生效。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `limits`：新的限制（全部为 0 表示移除所有限制）。
+    pub fn set_network_limits(&self, limits: XianWebEngineNetworkLimits) -> bool {
+        if self.shards.is_empty() {
+            return false;
+        }
+        dns::set_traffic_limits(limits.max_connections, limits.max_bytes_per_second);
+        true
+    }
+
+    /// ### English
+    /// Sets the image size limits enforced in every view, from each view's next navigation.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `limits`: New limits (all-zero removes every limit).
+    ///
+    /// ### 中文
+    /// 设置在每个 view 中执行的图片尺寸限制，从各 view 的下一次导航开始生效。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `limits`：新的限制（全部为 0 表示移除所有限制）。
+    pub fn set_image_limits(&self, limits: XianWebEngineImageLimits) -> bool {
+        self.broadcast_setting("image_limits".to_string(), move |shard| {
+            shard.send(Command::SetImageLimits { limits })
+        })
+    }
+
+    /// ### English
+    /// Returns the image load statistics summed over every Servo thread.
+    ///
+    /// ### 中文
+    /// 返回所有 Servo 线程汇总的图片加载统计。
+    pub fn image_load_stats(&self) -> XianWebEngineImageLoadStats {
+        let mut stats = XianWebEngineImageLoadStats::default();
+        self.for_each_shard(|shard| shard.image_loads.add_to(&mut stats));
+        stats
+    }
+
+    /// ### English
+    /// Switches the network mode of every view.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `mode`: New mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`).
+    ///
+    /// ### 中文
+    /// 切换所有 view 的网络模式。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `mode`：新的模式（`XIAN_WEB_ENGINE_NETWORK_MODE_*`）。
+    pub fn set_network_mode(&self, mode: u32) -> bool {
+        self.broadcast_setting("network_mode".to_string(), move |shard| {
+            shard.send(Command::SetNetworkMode { mode })
+        })
+    }
+
+    /// ### English
+    /// Registers (or, with texture `0`, removes) a GL texture pages can fetch with
+    /// `xianHost.getTexture(name)`.
+    ///
+    /// Returns `false` if `name` is empty or contains a line break, the size is zero or its RGBA8
+    /// pixels exceed 16 MiB, or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `name`: Name used by pages.
+    /// - `texture`: GL texture name (`GL_TEXTURE_2D`), or `0` to unregister.
+    /// - `width`: Texture width in pixels.
+    /// - `height`: Texture height in pixels.
+    /// - `flip_y`: Whether row 0 of the texture is the bottom row (render targets).
+    ///
+    /// ### 中文
+    /// 注册（纹理为 `0` 时移除）一个页面可通过 `xianHost.getTexture(name)` 获取的 GL 纹理。
+    ///
+    /// 若 `name` 为空或包含换行、尺寸为 0 或 RGBA8 像素超过 16 MiB，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `name`：页面使用的名称。
+    /// - `texture`：GL 纹理名（`GL_TEXTURE_2D`）；`0` 表示取消注册。
+    /// - `width`：纹理宽度（像素）。
+    /// - `height`：纹理高度（像素）。
+    /// - `flip_y`：纹理第 0 行是否为底行（渲染目标）。
+    pub fn register_texture(
+        &self,
+        name: &str,
+        texture: u32,
+        width: u32,
+        height: u32,
+        flip_y: bool,
+    ) -> bool {
+        if name.is_empty() || name.contains('\n') {
+            return false;
+        }
+        let bytes = u64::from(width) * u64::from(height) * 4;
+        if texture != 0 && (bytes == 0 || bytes > MAX_BINARY_MESSAGE_BYTES as u64) {
+            return false;
+        }
+
+        let name = name.to_string();
+        self.broadcast_setting(format!("texture:{name}"), move |shard| {
+            shard.send(Command::RegisterTexture {
+                name: name.clone(),
+                texture,
+                width,
+                height,
+                flip_y,
+            })
+        })
+    }
+
+    /// ### English
+    /// Returns a health snapshot (Servo loop stall time, queue depths and drop counters, and
+    /// paranoid-GL errors) for hang detection.
+    ///
+    /// With several shards the stall time is the worst shard's and the other values are summed.
+    ///
+    /// ### 中文
+    /// 返回用于卡死检测的健康快照（Servo 循环停滞时间、队列深度与丢弃计数，以及 paranoid GL 错误）。
+    ///
+    /// 存在多个分片时，停滞时间取最差的分片，其余值为各分片之和。
+    pub fn health(&self) -> XianWebEngineHealth {
+        let mut health = XianWebEngineHealth::default();
+        self.for_each_shard(|shard| {
+            health.millis_since_heartbeat = health
+                .millis_since_heartbeat
+                .max(shard.heartbeat.millis_since_beat());
+            health.queued_commands = health
+                .queued_commands
+                .saturating_add(u32::try_from(shard.command_queue.len()).unwrap_or(u32::MAX));
+            health.pending_views = health
+                .pending_views
+                .saturating_add(u32::try_from(shard.pending_queue.len()).unwrap_or(u32::MAX));
+            health.gl_error_frames = health
+                .gl_error_frames
+                .saturating_add(shard.gl_errors.frames_with_errors());
+            health.commands_dropped = health
+                .commands_dropped
+                .saturating_add(shard.command_queue.rejected());
+            health.pending_overflows = health
+                .pending_overflows
+                .saturating_add(shard.pending_queue.overflows());
+            if let Some((op, error)) = shard.gl_errors.last() {
+                health.last_gl_error_op = op;
+                health.last_gl_error = error;
+            }
+        });
+        health
+    }
+
+    /// ### English
+    /// Writes the recorded tracing spans to `path` in Chrome trace JSON format.
+    ///
+    /// Returns `false` if the file cannot be written.
+    ///
+    /// #### Parameters
+    /// - `path`: Output file (created or truncated).
+    ///
+    /// ### 中文
+    /// 将已记录的追踪 span 以 Chrome trace JSON 格式写入 `path`。
+    ///
+    /// 若文件无法写入，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `path`：输出文件（会被创建或截断）。
+    pub fn dump_trace(&self, path: &Path) -> bool {
+        trace::dump(path).is_ok()
+    }
+
+    /// ### English
+    /// Returns the status of the last command the calling thread sent (`XIAN_WEB_ENGINE_STATUS_*`).
+    ///
+    /// ### 中文
+    /// 返回调用线程最近一次发送的命令的状态（`XIAN_WEB_ENGINE_STATUS_*`）。
+    pub fn last_command_status() -> i32 {
+        queue::last_push_status()
+    }
+
+    /// ### English
+    /// Clears Servo's HTTP cache and removes the files staged in the cache directory.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 清空 Servo 的 HTTP 缓存，并删除缓存目录中暂存的文件。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    pub fn clear_http_cache(&self) -> bool {
+        if let Ok(cache_dir) = self.cache_dir.lock() {
+            let _ = std::fs::remove_dir_all(cache_dir.join(UPLOADS_DIR));
+        }
+
+        self.broadcast(|| Command::ClearHttpCache)
+    }
+
+    /// ### English
+    /// Enters or leaves engine idle mode on every Servo thread.
+    ///
+    /// While idle, Servo threads spin their event loop at most a few times per second and the
+    /// fixed-interval refresh scheduler is suspended.
+    ///
+    /// #### Parameters
+    /// - `idle`: Whether the engine is idle.
+    /// - `idle_flags`: `XIAN_WEB_ENGINE_IDLE_FLAG_*` applied when entering idle.
+    ///
+    /// ### 中文
+    /// 在每个 Servo 线程上进入或退出引擎空闲模式。
+    ///
+    /// 空闲期间，Servo 线程每秒最多驱动事件循环数次，固定间隔 refresh 调度器被挂起。
+    ///
+    /// #### 参数
+    /// - `idle`：引擎是否空闲。
+    /// - `idle_flags`：进入空闲时应用的 `XIAN_WEB_ENGINE_IDLE_FLAG_*`。
+    pub fn set_idle(&self, idle: bool, idle_flags: u32) -> Result<(), String> {
+        let unknown = idle_flags & !flags::XIAN_WEB_ENGINE_IDLE_FLAG_RELEASE_MEMORY;
+        if unknown != 0 {
+            return Err(format!("Unknown idle flags {unknown:#x}"));
+        }
+        let release_memory = (idle_flags & flags::XIAN_WEB_ENGINE_IDLE_FLAG_RELEASE_MEMORY) != 0;
+        if !self.broadcast_setting("idle".to_string(), move |shard| {
+            shard.send(Command::SetIdle {
+                idle,
+                release_memory,
+            })
+        }) {
+            return Err(rejection_message());
+        }
+        Ok(())
+    }
+
+    /// ### English
+    /// Starts Servo's WebDriver server on the first Servo thread (views of other shards are not
+    /// reachable through it).
+    ///
+    /// #### Parameters
+    /// - `port`: TCP port to listen on.
+    ///
+    /// ### 中文
+    /// 在第一个 Servo 线程上启动 Servo 的 WebDriver 服务器（无法通过它访问其他分片上的 view）。
+    ///
+    /// #### 参数
+    /// - `port`：要监听的 TCP 端口。
+    pub fn enable_webdriver(&self, port: u16) -> Result<(), String> {
+        let Some(shard) = self.shards.first() else {
+            return Err("Engine is shut down".to_string());
+        };
+
+        let response = Arc::new(OneShot::new(thread::current()));
+        if !shard.send(Command::EnableWebDriver {
+            port,
+            response: response.clone(),
+        }) {
+            return Err(rejection_message());
+        }
+
+        match response.recv_until(self.config.command_deadline()) {
+            Some(result) => result,
+            None => {
+                queue::set_last_status(XIAN_WEB_ENGINE_STATUS_TIMED_OUT);
+                Err("Timed out starting WebDriver".to_string())
+            }
+        }
+    }
+
+    /// ### English
+    /// Releases caches and pooled memory on every Servo thread in response to host memory pressure.
+    ///
+    /// #### Parameters
+    /// - `level`: `XIAN_WEB_ENGINE_MEMORY_PRESSURE_*`.
+    ///
+    /// ### 中文
+    /// 响应宿主的内存压力，在每个 Servo 线程上释放缓存与池化内存。
+    ///
+    /// #### 参数
+    /// - `level`：`XIAN_WEB_ENGINE_MEMORY_PRESSURE_*`。
+    pub fn notify_memory_pressure(&self, level: u32) -> Result<(), String> {
+        let critical = match level {
+            flags::XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE => false,
+            flags::XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL => true,
+            level => return Err(format!("Unknown memory pressure level {level}")),
+        };
+        self.for_each_shard(|shard| shard.vsync_queue.release_free_nodes());
+        if !self.broadcast(|| Command::MemoryPressure { critical }) {
+            return Err(rejection_message());
+        }
+        Ok(())
+    }
+
+    /// ### English
+    /// Drains pending vsync callbacks (used by the Java side to drive Servo refresh).
+    ///
+    /// ### 中文
+    /// drain pending vsync 回调（供 Java 侧驱动 Servo refresh）。
+    pub fn tick(&self) {
+        self.for_each_shard(|shard| shard.vsync_queue.tick());
+    }
+
+    /// ### English
+    /// Reports the host display timing to the vsync-driven views of every Servo thread.
+    ///
+    /// #### Parameters
+    /// - `refresh_rate_hz`: Display refresh rate (`0` = unknown, frames follow `tick` again).
+    /// - `next_vsync_nanos`: Engine monotonic time of an upcoming vsync (`0` = phase unknown).
+    ///
+    /// ### 中文
+    /// 将宿主显示时序上报给每个 Servo 线程中由 vsync 驱动的 view。
+    ///
+    /// #### 参数
+    /// - `refresh_rate_hz`：显示刷新率（`0` 表示未知，帧重新跟随 `tick`）。
+    /// - `next_vsync_nanos`：即将到来的某次 vsync 的引擎单调时间（`0` 表示相位未知）。
+    pub fn set_vsync_info(
+        &self,
+        refresh_rate_hz: f64,
+        next_vsync_nanos: u64,
+    ) -> Result<(), String> {
+        let period_ns = if refresh_rate_hz == 0.0 {
+            0
+        } else if refresh_rate_hz.is_finite()
+            && refresh_rate_hz > 0.0
+            && refresh_rate_hz <= MAX_REFRESH_RATE_HZ
+        {
+            (1_000_000_000.0 / refresh_rate_hz).round() as u64
+        } else {
+            return Err(format!(
+                "Refresh rate {refresh_rate_hz} Hz is outside (0, {MAX_REFRESH_RATE_HZ}]"
+            ));
+        };
+        self.broadcast_setting("vsync".to_string(), move |shard| {
+            shard.vsync_queue.timing().set(period_ns, next_vsync_nanos);
+            true
+        });
+        Ok(())
+    }
+
+    /// ### English
+    /// Draws the latest frames of `views` into `target_fbo` on the calling thread's current GL
+    /// context and returns how many views were drawn.
+    ///
+    /// The compositor is created on the first call and stays bound to that thread; later calls from
+    /// another thread fail.
+    ///
+    /// #### Parameters
+    /// - `views`: Views in back-to-front order (`None` entries are skipped).
+    /// - `rects`: Placement of each view (same length as `views`).
+    /// - `target_fbo`: Framebuffer to draw into (`0` = default framebuffer).
+    ///
+    /// ### 中文
+    /// 在调用线程的当前 GL 上下文上把 `views` 的最新帧绘制到 `target_fbo`，返回实际绘制的 view 数量。
+    ///
+    /// 合成器在首次调用时创建并绑定到该线程；之后从其他线程调用会失败。
+    ///
+    /// #### 参数
+    /// - `views`：按从后到前顺序排列的 view（`None` 项会被跳过）。
+    /// - `rects`：每个 view 的位置（长度与 `views` 相同）。
+    /// - `target_fbo`：目标 framebuffer（`0` 表示默认 framebuffer）。
+    pub fn composite_views(
+        &self,
+        views: &[Option<&WebEngineViewHandle>],
+        rects: &[XianWebEngineCompositeRect],
+        target_fbo: u32,
+    ) -> Result<usize, String> {
+        let mut compositor = self
+            .compositor
+            .lock()
+            .map_err(|_| "Compositor lock is poisoned".to_string())?;
+        let compositor = match &mut *compositor {
+            Some(compositor) if !compositor.is_owner_thread() => {
+                return Err(
+                    "Views must be composited on the thread that first called composite_views"
+                        .to_string(),
+                );
+            }
+            Some(compositor) => compositor,
+            empty => empty.insert(ViewCompositor::new()?),
+        };
+        Ok(compositor.composite(views, rects, target_fbo))
+    }
+
+    /// ### English
+    /// Requests shutdown of every Servo thread and joins them.
+    ///
+    /// ### 中文
+    /// 请求所有 Servo 线程退出并 join。
+    pub fn shutdown(&mut self) {
+        if let Ok(pool) = self.prewarmed.get_mut() {
+            pool.clear();
+        }
+        if let Ok(preloader) = self.preloader.get_mut() {
+            preloader.take();
+        }
+        if let Ok(compositor) = self.compositor.get_mut() {
+            compositor.take();
+        }
+        for shard in self.shards.drain(..) {
+            shard.shutdown();
+        }
+        self.storage_groups.shutdown();
+    }
+}
+
+/// ### English
+/// Restores a session snapshot into a new view: rebuilds its history and queues the current
+/// entry's scroll offset for once it has loaded.
+///
+/// #### Parameters
+/// - `handle`: Newly created view.
+/// - `snapshot`: Session to restore.
+///
+/// ### 中文
+/// 将会话快照恢复到新建的 view：重建其历史，并排队在当前条目加载完成后应用其滚动偏移。
+///
+/// #### 参数
+/// - `handle`：新建的 view。
+/// - `snapshot`：要恢复的会话。
+fn restore_session(handle: &WebEngineViewHandle, snapshot: &SessionSnapshot) {
+    let Some(entries) = snapshot.urls() else {
+        return;
+    };
+    if handle.restore_session(
+        entries,
+        snapshot.current,
+        snapshot.scroll_x,
+        snapshot.scroll_y,
+    ) {
+        handle.wake();
+    }
+}
+
+/// ### English
+/// Checks that Servo's multiprocess mode can start content processes in this process.
+///
+/// Servo has no setting for the content process executable: it always re-runs the current
+/// executable with `--content-process <token>`. That cannot work on Windows (unsupported by Servo)
+/// or under a Java launcher (which cannot hand the token to `xian_web_engine_run_content_process`),
+/// so both are rejected up front instead of failing when the first page loads.
+///
+/// ### 中文
+/// 检查当前进程能否启动 Servo 多进程模式的内容进程。
+///
+/// Servo 没有用于指定内容进程可执行文件的设置：它总是以 `--content-process <token>` 重新运行当前可执行文件。
+/// 这在 Windows（Servo 不支持）或 Java 启动器（无法把 token 交给 `xian_web_engine_run_content_process`）下
+/// 无法工作，因此会提前拒绝，而不是等到第一个页面加载时才失败。
+fn check_content_process_host() -> Result<(), String> {
+    if cfg!(windows) {
+        return Err("Multiprocess mode is not supported on Windows".to_string());
+    }
+    let host = std::env::current_exe()
+        .map_err(|err| format!("Multiprocess mode needs the host executable path: {err}"))?;
+    let launcher = host.file_stem().and_then(|stem| stem.to_str());
+    if matches!(launcher, Some("java" | "javaw")) {
+        return Err(format!(
+            "Multiprocess mode re-runs the host executable ({}) as content processes, and a Java \
+             launcher cannot pass their token to xian_web_engine_run_content_process",
+            host.display()
+        ));
+    }
+    Ok(())
+}
+
+/// ### English
+/// Describes why the calling thread's last command push was rejected.
+///
+/// ### 中文
+/// 描述调用线程最近一次命令 push 被拒绝的原因。
+fn rejection_message() -> String {
+    if queue::last_push_status() == XIAN_WEB_ENGINE_STATUS_QUEUE_FULL {
+        "Command queue is full".to_string()
+    } else {
+        "Engine is shutting down".to_string()
     }
 }
 
 impl Drop for EngineRuntime {
     /// ### English
-    /// Ensures the Servo thread is shut down when the runtime is dropped.
+    /// Ensures the Servo threads are shut down when the runtime is dropped.
     ///
     /// ### 中文
     /// 确保在运行时 drop 时关闭 Servo 线程。
@@ -0,0 +1,307 @@
+//! ### English
+//! Per-view event queue (Servo thread -> embedder, polled in batches).
+//!
+//! ### 中文
+//! 每 view 的事件队列（Servo 线程 -> 宿主，批量轮询）。
+
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_VIEW_EVENT_DROPPED,
+    XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_TEXT_TRUNCATED, XianWebEngineViewEvent,
+};
+use crate::engine::lockfree::BoundedMpscQueue;
+
+/// ### English
+/// Capacity of each per-view event queue (events beyond this are counted as dropped).
+///
+/// ### 中文
+/// 每 view 事件队列的容量（超出部分计为丢弃）。
+const VIEW_EVENT_QUEUE_CAPACITY: usize = 1024;
+
+/// ### English
+/// One queued view event with an owned text payload.
+///
+/// ### 中文
+/// 一个已排队的 view 事件（持有文本载荷）。
+pub(super) struct ViewEvent {
+    /// ### English
+    /// Event kind (`XIAN_WEB_ENGINE_VIEW_EVENT_*`).
+    ///
+    /// ### 中文
+    /// 事件类型（`XIAN_WEB_ENGINE_VIEW_EVENT_*`）。
+    pub kind: u32,
+    /// ### English
+    /// Status code (`XIAN_WEB_ENGINE_STATUS_*`).
+    ///
+    /// ### 中文
+    /// 状态码（`XIAN_WEB_ENGINE_STATUS_*`）。
+    pub status: i32,
+    /// ### English
+    /// Request ID this event completes (`0` for unsolicited events).
+    ///
+    /// ### 中文
+    /// 该事件所完成请求的 ID（主动推送的事件为 `0`）。
+    pub request_id: u64,
+    /// ### English
+    /// First kind-specific value.
+    ///
+    /// ### 中文
+    /// 第一个与类型相关的值。
+    pub value0: i64,
+    /// ### English
+    /// Second kind-specific value.
+    ///
+    /// ### 中文
+    /// 第二个与类型相关的值。
+    pub value1: i64,
+    /// ### English
+    /// Optional UTF-8 text payload.
+    ///
+    /// ### 中文
+    /// 可选的 UTF-8 文本载荷。
+    pub text: Option<String>,
+}
+
+impl ViewEvent {
+    /// ### English
+    /// Creates an event without values or text.
+    ///
+    /// #### Parameters
+    /// - `kind`: Event kind.
+    /// - `status`: Status code.
+    /// - `request_id`: Request ID (`0` for unsolicited events).
+    ///
+    /// ### 中文
+    /// 创建一个不带值与文本的事件。
+    ///
+    /// #### 参数
+    /// - `kind`：事件类型。
+    /// - `status`：状态码。
+    /// - `request_id`：请求 ID（主动推送的事件为 `0`）。
+    #[inline]
+    pub(super) fn new(kind: u32, status: i32, request_id: u64) -> Self {
+        Self {
+            kind,
+            status,
+            request_id,
+            value0: 0,
+            value1: 0,
+            text: None,
+        }
+    }
+}
+
+/// ### English
+/// Bounded per-view event queue.
+///
+/// Producers are the Servo thread and helper threads it spawns; the consumer is whichever embedder
+/// thread polls (concurrent polls are rejected instead of blocking).
+///
+/// ### 中文
+/// 有界的每 view 事件队列。
+///
+/// 生产者为 Servo 线程及其派生的辅助线程；消费者为执行轮询的宿主线程（并发轮询会被拒绝而非阻塞）。
+pub(crate) struct ViewEventQueue {
+    /// ### English
+    /// Queued events.
+    ///
+    /// ### 中文
+    /// 已排队的事件。
+    queue: BoundedMpscQueue<ViewEvent>,
+    /// ### English
+    /// Event popped by a previous poll whose text did not fit (delivered first next time).
+    ///
+    /// ### 中文
+    /// 上一次轮询中因文本放不下而暂存的事件（下次优先交付）。
+    held: UnsafeCell<Option<ViewEvent>>,
+    /// ### English
+    /// Single-consumer guard protecting `held` and the queue's consumer side.
+    ///
+    /// ### 中文
+    /// 单消费者保护位，保护 `held` 与队列的消费端。
+    polling: AtomicBool,
+    /// ### English
+    /// Number of events dropped because the queue was full (reported on the next poll).
+    ///
+    /// ### 中文
+    /// 因队列已满而丢弃的事件数（在下一次轮询时上报）。
+    dropped: AtomicU32,
+    /// ### English
+    /// Events pushed since creation, including dropped ones (never reset).
+    ///
+    /// ### 中文
+    /// 自创建以来 push 的事件数，包括被丢弃的事件（从不清零）。
+    generation: AtomicU64,
+    /// ### English
+    /// Events dropped since creation (never reset, unlike `dropped`).
+    ///
+    /// ### 中文
+    /// 自创建以来丢弃的事件数（与 `dropped` 不同，从不清零）。
+    dropped_total: AtomicU64,
+}
+
+unsafe impl Send for ViewEventQueue {}
+unsafe impl Sync for ViewEventQueue {}
+
+impl Default for ViewEventQueue {
+    /// ### English
+    /// Creates an empty queue with the default capacity.
+    ///
+    /// ### 中文
+    /// 创建一个使用默认容量的空队列。
+    fn default() -> Self {
+        Self {
+            queue: BoundedMpscQueue::with_capacity(VIEW_EVENT_QUEUE_CAPACITY),
+            held: UnsafeCell::new(None),
+            polling: AtomicBool::new(false),
+            dropped: AtomicU32::new(0),
+            generation: AtomicU64::new(0),
+            dropped_total: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ViewEventQueue {
+    /// ### English
+    /// Pushes one event; if the queue is full the event is discarded and counted as dropped.
+    ///
+    /// #### Parameters
+    /// - `event`: Event to enqueue.
+    ///
+    /// ### 中文
+    /// push 一个事件；队列已满时丢弃该事件并计入丢弃计数。
+    ///
+    /// #### 参数
+    /// - `event`：要入队的事件。
+    pub(super) fn push(&self, event: ViewEvent) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        if self.queue.try_push(event).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            self.dropped_total.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// ### English
+    /// Returns the number of events pushed since creation, including dropped ones.
+    ///
+    /// ### 中文
+    /// 返回自创建以来 push 的事件数（包括被丢弃的事件）。
+    pub(super) fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns the number of events dropped since creation.
+    ///
+    /// ### 中文
+    /// 返回自创建以来丢弃的事件数。
+    pub(super) fn dropped_total(&self) -> u64 {
+        self.dropped_total.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Copies queued events into `out_events`, and their text payloads into `text_buf`.
+    ///
+    /// Returns the number of events written. An event whose text does not fit into the remaining
+    /// buffer is kept for the next poll; text is only truncated when it is larger than the whole
+    /// buffer. Returns `0` if another thread is polling concurrently.
+    ///
+    /// #### Parameters
+    /// - `out_events`: Output event array.
+    /// - `text_buf`: Output buffer for UTF-8 text payloads (may be empty).
+    ///
+    /// ### 中文
+    /// 将已排队事件复制到 `out_events`，并将其文本载荷复制到 `text_buf`。
+    ///
+    /// 返回写入的事件数。若某事件的文本放不进剩余缓冲区，则保留到下一次轮询；
+    /// 仅当文本大于整个缓冲区时才会截断。若有其他线程正在并发轮询则返回 `0`。
+    ///
+    /// #### 参数
+    /// - `out_events`：输出事件数组。
+    /// - `text_buf`：UTF-8 文本载荷的输出缓冲区（可为空）。
+    pub(crate) fn poll(
+        &self,
+        out_events: &mut [XianWebEngineViewEvent],
+        text_buf: &mut [u8],
+    ) -> usize {
+        if out_events.is_empty() || self.polling.swap(true, Ordering::Acquire) {
+            return 0;
+        }
+
+        let held = unsafe { &mut *self.held.get() };
+        let mut count = 0usize;
+        let mut text_used = 0usize;
+
+        let dropped = self.dropped.swap(0, Ordering::Relaxed);
+        if dropped != 0 {
+            out_events[0] = XianWebEngineViewEvent {
+                kind: XIAN_WEB_ENGINE_VIEW_EVENT_DROPPED,
+                status: XIAN_WEB_ENGINE_STATUS_OK,
+                value0: i64::from(dropped),
+                ..Default::default()
+            };
+            count = 1;
+        }
+
+        while count < out_events.len() {
+            let Some(event) = held.take().or_else(|| self.queue.pop()) else {
+                break;
+            };
+
+            let text = event.text.as_deref().unwrap_or_default().as_bytes();
+            let remaining = text_buf.len() - text_used;
+            let mut flags = 0;
+            let len = if text.len() <= remaining {
+                text.len()
+            } else if text_used == 0 {
+                flags |= XIAN_WEB_ENGINE_VIEW_EVENT_FLAG_TEXT_TRUNCATED;
+                utf8_floor(text, remaining)
+            } else {
+                *held = Some(event);
+                break;
+            };
+
+            text_buf[text_used..text_used + len].copy_from_slice(&text[..len]);
+            out_events[count] = XianWebEngineViewEvent {
+                kind: event.kind,
+                status: event.status,
+                request_id: event.request_id,
+                value0: event.value0,
+                value1: event.value1,
+                text_offset: text_used as u32,
+                text_len: len as u32,
+                flags,
+                _reserved: 0,
+            };
+            text_used += len;
+            count += 1;
+        }
+
+        self.polling.store(false, Ordering::Release);
+        count
+    }
+}
+
+/// ### English
+/// Returns the largest UTF-8 character boundary in `text` that is `<= max`.
+///
+/// #### Parameters
+/// - `text`: UTF-8 bytes.
+/// - `max`: Upper bound in bytes.
+///
+/// ### 中文
+/// 返回 `text` 中不超过 `max` 的最大 UTF-8 字符边界。
+///
+/// #### 参数
+/// - `text`：UTF-8 字节。
+/// - `max`：字节数上界。
+#[inline]
+pub(super) fn utf8_floor(text: &[u8], max: usize) -> usize {
+    let mut len = max.min(text.len());
+    while len > 0 && len < text.len() && (text[len] & 0xC0) == 0x80 {
+        len -= 1;
+    }
+    len
+}
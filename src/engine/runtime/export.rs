@@ -0,0 +1,212 @@
+//! ### English
+//! Page export encoders (PNG and paginated raster PDF).
+//!
+//! Servo does not expose a print/pagination pipeline to embedders, so exports are produced from a
+//! stitched capture of the whole scrollable document. PDF output slices that image into pages as
+//! wide as the document with A4 proportions (1 device px = 0.75 pt); the last page is top-aligned.
+//!
+//! ### 中文
+//! 页面导出编码器（PNG 与分页栅格 PDF）。
+//!
+//! Servo 未向嵌入方暴露打印/分页管线，因此导出内容来自整个可滚动文档的拼接截图。
+//! PDF 输出会把该图像切分为与文档等宽、A4 比例的页面（1 设备像素 = 0.75 pt）；最后一页顶端对齐。
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::engine::flags::{XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF, XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG};
+
+/// ### English
+/// Page height as a `(numerator, denominator)` multiple of the page width (A4 portrait, 297:210).
+///
+/// ### 中文
+/// 以页面宽度倍数 `(分子, 分母)` 表示的页面高度（A4 纵向，297:210）。
+const PAGE_HEIGHT_RATIO: (u64, u64) = (297, 210);
+
+/// ### English
+/// Encodes RGBA pixels with the given format and writes them to `path`.
+///
+/// #### Parameters
+/// - `path`: Output file path (created or truncated).
+/// - `format`: `XIAN_WEB_ENGINE_EXPORT_FORMAT_*`.
+/// - `width`: Image width in pixels.
+/// - `height`: Image height in pixels.
+/// - `rgba`: Tightly packed RGBA8 pixels, top row first.
+///
+/// ### 中文
+/// 按指定格式编码 RGBA 像素并写入 `path`。
+///
+/// #### 参数
+/// - `path`：输出文件路径（创建或截断）。
+/// - `format`：`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`。
+/// - `width`：图像宽度（像素）。
+/// - `height`：图像高度（像素）。
+/// - `rgba`：紧密排列的 RGBA8 像素，首行在上。
+pub(super) fn write_export(
+    path: &Path,
+    format: u32,
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    match format {
+        XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG => write_png(&mut out, width, height, rgba)?,
+        XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF => write_pdf(&mut out, width, height, rgba)?,
+        _ => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Unknown export format {format}"),
+            ));
+        }
+    }
+    out.flush()
+}
+
+/// ### English
+/// Writes a PNG image.
+///
+/// #### Parameters
+/// - `out`: Output writer.
+/// - `width`: Image width in pixels.
+/// - `height`: Image height in pixels.
+/// - `rgba`: RGBA8 pixels.
+///
+/// ### 中文
+/// 写出 PNG 图像。
+///
+/// #### 参数
+/// - `out`：输出 writer。
+/// - `width`：图像宽度（像素）。
+/// - `height`：图像高度（像素）。
+/// - `rgba`：RGBA8 像素。
+pub(super) fn write_png<W: Write>(
+    out: &mut W,
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> std::io::Result<()> {
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(std::io::Error::other)?;
+    writer
+        .write_image_data(rgba)
+        .map_err(std::io::Error::other)?;
+    writer.finish().map_err(std::io::Error::other)
+}
+
+/// ### English
+/// Writes a minimal paginated PDF, slicing the image into A4-proportioned pages and embedding each
+/// slice as a Flate-compressed RGB XObject.
+///
+/// #### Parameters
+/// - `out`: Output writer.
+/// - `width`: Image width in pixels.
+/// - `height`: Image height in pixels.
+/// - `rgba`: RGBA8 pixels (alpha is dropped).
+///
+/// ### 中文
+/// 写出最小化的分页 PDF：把图像切分为 A4 比例的页面，并将每一片作为 Flate 压缩的 RGB XObject 嵌入。
+///
+/// #### 参数
+/// - `out`：输出 writer。
+/// - `width`：图像宽度（像素）。
+/// - `height`：图像高度（像素）。
+/// - `rgba`：RGBA8 像素（丢弃 alpha）。
+fn write_pdf<W: Write>(out: &mut W, width: u32, height: u32, rgba: &[u8]) -> std::io::Result<()> {
+    let page_rows = (u64::from(width) * PAGE_HEIGHT_RATIO.0).div_ceil(PAGE_HEIGHT_RATIO.1);
+    let page_rows = u32::try_from(page_rows).unwrap_or(u32::MAX).max(1);
+    let pages = height.div_ceil(page_rows).max(1);
+    let page_width = width as f32 * 0.75;
+    let page_height = page_rows as f32 * 0.75;
+    let stride = width as usize * 4;
+
+    let mut pdf: Vec<u8> = Vec::with_capacity(rgba.len() / 4 + 1024);
+    let mut offsets = Vec::with_capacity(2 + pages as usize * 3);
+    pdf.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+    offsets.push(pdf.len());
+    pdf.extend_from_slice(b"1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n");
+
+    offsets.push(pdf.len());
+    let kids: Vec<String> = (0..pages)
+        .map(|page| format!("{} 0 R", 3 + page * 3))
+        .collect();
+    pdf.extend_from_slice(
+        format!(
+            "2 0 obj\n<< /Type /Pages /Kids [{}] /Count {pages} >>\nendobj\n",
+            kids.join(" ")
+        )
+        .as_bytes(),
+    );
+
+    for page in 0..pages {
+        let object = 3 + page * 3;
+        let first_row = page * page_rows;
+        let rows = page_rows.min(height.saturating_sub(first_row)).max(1);
+        let mut rgb = Vec::with_capacity(width as usize * rows as usize * 3);
+        for row in first_row..(first_row + rows).min(height) {
+            let line = &rgba[row as usize * stride..(row as usize + 1) * stride];
+            for pixel in line.chunks_exact(4) {
+                rgb.extend_from_slice(&pixel[..3]);
+            }
+        }
+        rgb.resize(width as usize * rows as usize * 3, 0xFF);
+        let image = miniz_oxide::deflate::compress_to_vec_zlib(&rgb, 6);
+
+        let slice_height = rows as f32 * 0.75;
+        let slice_top = page_height - slice_height;
+        let content =
+            format!("q {page_width:.2} 0 0 {slice_height:.2} 0 {slice_top:.2} cm /Im0 Do Q");
+
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(
+            format!(
+                "{object} 0 obj\n<< /Type /Page /Parent 2 0 R \
+                 /MediaBox [0 0 {page_width:.2} {page_height:.2}] \
+                 /Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>\nendobj\n",
+                object + 1,
+                object + 2
+            )
+            .as_bytes(),
+        );
+
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(
+            format!(
+                "{} 0 obj\n<< /Type /XObject /Subtype /Image /Width {width} /Height {rows} \
+                 /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode /Length {} >>\n\
+                 stream\n",
+                object + 1,
+                image.len()
+            )
+            .as_bytes(),
+        );
+        pdf.extend_from_slice(&image);
+        pdf.extend_from_slice(b"\nendstream\nendobj\n");
+
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(
+            format!(
+                "{} 0 obj\n<< /Length {} >>\nstream\n{content}\nendstream\nendobj\n",
+                object + 2,
+                content.len()
+            )
+            .as_bytes(),
+        );
+    }
+
+    let xref = pdf.len();
+    let size = offsets.len() + 1;
+    pdf.extend_from_slice(format!("xref\n0 {size}\n0000000000 65535 f \n").as_bytes());
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!("trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n").as_bytes(),
+    );
+
+    out.write_all(&pdf)
+}
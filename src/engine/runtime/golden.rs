@@ -0,0 +1,454 @@
+//! ### English
+//! Golden-image rendering test harness (`golden-tests` feature).
+//!
+//! Every `*.html` page of a directory is loaded into a fresh view, once per color format variant;
+//! when the page has loaded and painted twice, the frame is captured through the same readback path
+//! as PNG export and compared against `<page><variant>.png` in the golden directory, with a
+//! per-channel tolerance. This catches regressions in the triple-buffer/sRGB code in-tree.
+//!
+//! Captured frames are kept in the output directory; a mismatch also writes `<page><variant>.diff.png`
+//! (offending pixels in red). In update mode the captured frames become the new goldens.
+//!
+//! ### 中文
+//! golden 图像渲染测试工具（`golden-tests` feature）。
+//!
+//! 目录中的每个 `*.html` 页面会按每种颜色格式变体各加载到一个新建 view 中；页面加载完成并绘制两帧后，
+//! 通过与 PNG 导出相同的读回路径截取帧，并与 golden 目录中的 `<page><variant>.png` 按逐通道容差比较。
+//! 借此在仓库内捕获三缓冲/sRGB 代码中的回归。
+//!
+//! 截取的帧保留在输出目录中；不一致时还会写出 `<page><variant>.diff.png`（越界像素标为红色）。更新模式下，
+//! 截取的帧会成为新的 golden。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use dpi::PhysicalSize;
+use url::Url;
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE,
+    XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE, XianWebEngineViewEvent,
+};
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8, XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8,
+    XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG, XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START,
+    XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN, XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT,
+};
+use crate::engine::log::{XIAN_WEB_ENGINE_LOG_LEVEL_INFO, XIAN_WEB_ENGINE_LOG_LEVEL_WARN, log};
+
+use super::engine_runtime::EngineRuntime;
+use super::export::write_export;
+use super::view_handle::WebEngineViewHandle;
+
+/// ### English
+/// Console message the ready script logs once the page has loaded and painted twice.
+///
+/// ### 中文
+/// 页面加载完成并绘制两帧后，就绪脚本输出的 console 消息。
+const READY_MESSAGE: &str = "xian-golden:ready";
+
+/// ### English
+/// User script that reports readiness after `load` and two animation frames.
+///
+/// ### 中文
+/// 在 `load` 与两个动画帧之后报告就绪的用户脚本。
+const READY_SCRIPT: &str = "addEventListener(\"load\", () => requestAnimationFrame(() => \
+     requestAnimationFrame(() => console.log(\"xian-golden:ready\"))), { once: true });";
+
+/// ### English
+/// Color format variants every page is rendered with: golden file suffix and view flags.
+///
+/// ### 中文
+/// 每个页面要渲染的颜色格式变体：golden 文件后缀与 view 标志。
+const VARIANTS: [(&str, u32); 2] = [
+    (
+        "",
+        XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA8 << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT,
+    ),
+    (
+        ".srgb",
+        XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 << XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT,
+    ),
+];
+
+/// ### English
+/// Fixed refresh rate of the test views (no external vsync is needed).
+///
+/// ### 中文
+/// 测试 view 的固定刷新率（无需外部 vsync）。
+const TARGET_FPS: u32 = 60;
+
+/// ### English
+/// How long one page may take to become ready or to be captured.
+///
+/// ### 中文
+/// 单个页面就绪或完成截取允许花费的时长。
+const PAGE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// ### English
+/// Sleep between two event polls while waiting.
+///
+/// ### 中文
+/// 等待期间两次事件轮询之间的休眠时长。
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// ### English
+/// Size of the text buffer used to poll view events.
+///
+/// ### 中文
+/// 轮询 view 事件时使用的文本缓冲区大小。
+const TEXT_BUF_BYTES: usize = 64 * 1024;
+
+/// ### English
+/// Settings of one golden test run.
+///
+/// ### 中文
+/// 一次 golden 测试运行的设置。
+pub struct GoldenRun {
+    /// ### English
+    /// Directory of the `*.html` test pages.
+    ///
+    /// ### 中文
+    /// `*.html` 测试页面所在目录。
+    pub pages_dir: PathBuf,
+    /// ### English
+    /// Directory of the golden PNGs.
+    ///
+    /// ### 中文
+    /// golden PNG 所在目录。
+    pub golden_dir: PathBuf,
+    /// ### English
+    /// Directory receiving the captured frames and diff images.
+    ///
+    /// ### 中文
+    /// 接收截取帧与差异图的目录。
+    pub output_dir: PathBuf,
+    /// ### English
+    /// View size in pixels.
+    ///
+    /// ### 中文
+    /// view 尺寸（像素）。
+    pub size: PhysicalSize<u32>,
+    /// ### English
+    /// Largest accepted per-channel difference.
+    ///
+    /// ### 中文
+    /// 可接受的最大逐通道差值。
+    pub tolerance: u8,
+    /// ### English
+    /// Whether captured frames replace the goldens instead of being compared.
+    ///
+    /// ### 中文
+    /// 是否用截取的帧替换 golden，而不是进行比较。
+    pub update: bool,
+}
+
+/// ### English
+/// A decoded RGBA8 image.
+///
+/// ### 中文
+/// 已解码的 RGBA8 图像。
+struct RgbaImage {
+    /// ### English
+    /// Width in pixels.
+    ///
+    /// ### 中文
+    /// 宽度（像素）。
+    width: u32,
+    /// ### English
+    /// Height in pixels.
+    ///
+    /// ### 中文
+    /// 高度（像素）。
+    height: u32,
+    /// ### English
+    /// Tightly packed RGBA8 pixels, top row first.
+    ///
+    /// ### 中文
+    /// 紧密排列的 RGBA8 像素，首行在上。
+    pixels: Vec<u8>,
+}
+
+impl EngineRuntime {
+    /// ### English
+    /// Runs the golden-image tests on the calling thread and returns the number of failed cases
+    /// (page × variant). Each case is logged (INFO when it passes, WARN with the reason otherwise).
+    ///
+    /// Returns an error if the pages cannot be listed or the output directory cannot be created.
+    ///
+    /// #### Parameters
+    /// - `run`: Run settings.
+    ///
+    /// ### 中文
+    /// 在调用线程上运行 golden 图像测试，并返回失败用例（页面 × 变体）的数量。每个用例都会记录日志（通过为
+    /// INFO，否则为带原因的 WARN）。
+    ///
+    /// 若无法列出页面或无法创建输出目录，则返回错误。
+    ///
+    /// #### 参数
+    /// - `run`：运行设置。
+    pub fn run_golden_tests(&self, run: &GoldenRun) -> Result<u32, String> {
+        let mut pages = fs::read_dir(&run.pages_dir)
+            .map_err(|err| format!("Cannot list {}: {err}", run.pages_dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "html"))
+            .collect::<Vec<_>>();
+        pages.sort();
+        fs::create_dir_all(&run.output_dir)
+            .map_err(|err| format!("Cannot create {}: {err}", run.output_dir.display()))?;
+        if run.update {
+            fs::create_dir_all(&run.golden_dir)
+                .map_err(|err| format!("Cannot create {}: {err}", run.golden_dir.display()))?;
+        }
+
+        let mut failed = 0;
+        for page in &pages {
+            let Some(name) = page.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            for (suffix, view_flags) in VARIANTS {
+                let case = format!("{name}{suffix}");
+                match self.run_golden_case(run, page, &case, view_flags) {
+                    Ok(()) => log(
+                        XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+                        format_args!("Golden {case}: ok"),
+                    ),
+                    Err(err) => {
+                        failed += 1;
+                        log(
+                            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+                            format_args!("Golden {case}: {err}"),
+                        );
+                    }
+                }
+            }
+        }
+        log(
+            XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+            format_args!(
+                "Golden tests: {} cases, {failed} failed",
+                pages.len() * VARIANTS.len()
+            ),
+        );
+        Ok(failed)
+    }
+
+    /// ### English
+    /// Renders one page with one variant, captures it and compares (or updates) its golden.
+    ///
+    /// #### Parameters
+    /// - `run`: Run settings.
+    /// - `page`: Test page.
+    /// - `case`: Case name (page stem plus variant suffix).
+    /// - `view_flags`: View flags of the variant.
+    ///
+    /// ### 中文
+    /// 以一种变体渲染一个页面，截取后与其 golden 比较（或更新 golden）。
+    ///
+    /// #### 参数
+    /// - `run`：运行设置。
+    /// - `page`：测试页面。
+    /// - `case`：用例名（页面文件名加变体后缀）。
+    /// - `view_flags`：该变体的 view 标志。
+    fn run_golden_case(
+        &self,
+        run: &GoldenRun,
+        page: &Path,
+        case: &str,
+        view_flags: u32,
+    ) -> Result<(), String> {
+        let page = fs::canonicalize(page).map_err(|err| err.to_string())?;
+        let url = Url::from_file_path(&page)
+            .map_err(|()| format!("{} is not a valid file URL", page.display()))?;
+
+        let handle = self.create_view(run.size, TARGET_FPS, 0, view_flags)?;
+        handle.set_console_capture(true);
+        handle.add_user_script(
+            READY_SCRIPT,
+            XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START,
+            XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN,
+        );
+        if handle.load_url(url.as_str()) {
+            handle.wake();
+        }
+        wait_for_event(&handle, "ready", |event, text| {
+            event.kind == XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE
+                && text.ends_with(READY_MESSAGE)
+        })?;
+
+        let actual_path = run.output_dir.join(format!("{case}.png"));
+        let request_id = handle
+            .export(actual_path.clone(), XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG)
+            .ok_or("engine is shutting down")?;
+        let (event, text) = wait_for_event(&handle, "capture", |event, _| {
+            event.kind == XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE
+                && event.request_id == request_id
+        })?;
+        if event.status != XIAN_WEB_ENGINE_STATUS_OK {
+            return Err(format!("capture failed: {text}"));
+        }
+        drop(handle);
+
+        let golden_path = run.golden_dir.join(format!("{case}.png"));
+        if run.update {
+            return fs::copy(&actual_path, &golden_path)
+                .map(|_| ())
+                .map_err(|err| format!("cannot update {}: {err}", golden_path.display()));
+        }
+
+        let actual = read_png(&actual_path)?;
+        let golden = read_png(&golden_path)?;
+        let diff_path = run.output_dir.join(format!("{case}.diff.png"));
+        compare(&actual, &golden, run.tolerance, &diff_path)
+    }
+}
+
+/// ### English
+/// Polls `handle`'s events until one matches, and returns it with its text.
+///
+/// #### Parameters
+/// - `handle`: View to poll.
+/// - `what`: Name of the awaited step (for the timeout message).
+/// - `matches`: Predicate over an event and its text payload.
+///
+/// ### 中文
+/// 轮询 `handle` 的事件直到某个事件匹配，并返回该事件及其文本。
+///
+/// #### 参数
+/// - `handle`：要轮询的 view。
+/// - `what`：等待的步骤名（用于超时消息）。
+/// - `matches`：作用于事件及其文本载荷的判定函数。
+fn wait_for_event(
+    handle: &WebEngineViewHandle,
+    what: &str,
+    matches: impl Fn(&XianWebEngineViewEvent, &str) -> bool,
+) -> Result<(XianWebEngineViewEvent, String), String> {
+    let deadline = Instant::now() + PAGE_TIMEOUT;
+    let mut events = [XianWebEngineViewEvent::default(); 16];
+    let mut text_buf = vec![0u8; TEXT_BUF_BYTES];
+    loop {
+        let count = handle.poll_events(&mut events, &mut text_buf);
+        for event in &events[..count] {
+            let start = event.text_offset as usize;
+            let text = text_buf
+                .get(start..start + event.text_len as usize)
+                .map(String::from_utf8_lossy)
+                .unwrap_or_default();
+            if matches(event, &text) {
+                return Ok((*event, text.into_owned()));
+            }
+        }
+        if Instant::now() >= deadline {
+            return Err(format!("timed out waiting for {what}"));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// ### English
+/// Reads a PNG file as RGBA8.
+///
+/// #### Parameters
+/// - `path`: PNG file.
+///
+/// ### 中文
+/// 以 RGBA8 读取 PNG 文件。
+///
+/// #### 参数
+/// - `path`：PNG 文件。
+fn read_png(path: &Path) -> Result<RgbaImage, String> {
+    let fail = |err: &dyn std::fmt::Display| format!("cannot read {}: {err}", path.display());
+    let file = fs::File::open(path).map_err(|err| fail(&err))?;
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|err| fail(&err))?;
+    let mut buf = vec![0u8; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).map_err(|err| fail(&err))?;
+    buf.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        other => return Err(fail(&format!("unsupported color type {other:?}"))),
+    };
+    Ok(RgbaImage {
+        width: info.width,
+        height: info.height,
+        pixels,
+    })
+}
+
+/// ### English
+/// Compares a captured frame with its golden. On mismatch a diff image (offending pixels in red,
+/// others dimmed) is written to `diff_path`.
+///
+/// #### Parameters
+/// - `actual`: Captured frame.
+/// - `golden`: Expected image.
+/// - `tolerance`: Largest accepted per-channel difference.
+/// - `diff_path`: Where to write the diff image.
+///
+/// ### 中文
+/// 比较截取的帧与其 golden。不一致时会向 `diff_path` 写出差异图（越界像素为红色，其余变暗）。
+///
+/// #### 参数
+/// - `actual`：截取的帧。
+/// - `golden`：期望图像。
+/// - `tolerance`：可接受的最大逐通道差值。
+/// - `diff_path`：差异图的写出位置。
+fn compare(
+    actual: &RgbaImage,
+    golden: &RgbaImage,
+    tolerance: u8,
+    diff_path: &Path,
+) -> Result<(), String> {
+    if (actual.width, actual.height) != (golden.width, golden.height) {
+        return Err(format!(
+            "size {}x{} differs from golden {}x{}",
+            actual.width, actual.height, golden.width, golden.height
+        ));
+    }
+
+    let mut diff = Vec::with_capacity(actual.pixels.len());
+    let mut mismatched = 0usize;
+    let mut max_delta = 0u8;
+    for (a, g) in actual
+        .pixels
+        .chunks_exact(4)
+        .zip(golden.pixels.chunks_exact(4))
+    {
+        let delta = a
+            .iter()
+            .zip(g)
+            .map(|(a, g)| a.abs_diff(*g))
+            .max()
+            .unwrap_or(0);
+        max_delta = max_delta.max(delta);
+        if delta > tolerance {
+            mismatched += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            diff.extend_from_slice(&[a[0] / 4, a[1] / 4, a[2] / 4, 255]);
+        }
+    }
+    if mismatched == 0 {
+        return Ok(());
+    }
+
+    let _ = write_export(
+        diff_path,
+        XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG,
+        actual.width,
+        actual.height,
+        &diff,
+    );
+    Err(format!(
+        "{mismatched} pixels differ by more than {tolerance} (max {max_delta}); see {}",
+        diff_path.display()
+    ))
+}
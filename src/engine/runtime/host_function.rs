@@ -0,0 +1,54 @@
+//! ### English
+//! Host functions pages can call through `window.xianHost.call(name, args)`.
+//!
+//! Calls reach the Servo thread as host messages and are handed to the registered callback with an
+//! engine-assigned call ID; the embedder answers later through the command queue, and the answer
+//! settles the page's Promise.
+//!
+//! ### 中文
+//! 页面可通过 `window.xianHost.call(name, args)` 调用的宿主函数。
+//!
+//! 调用以宿主消息的形式到达 Servo 线程，并连同引擎分配的调用 ID 交给已注册的回调；宿主稍后经由命令队列应答，
+//! 应答会兑现页面的 Promise。
+
+use std::ffi::{c_char, c_void};
+
+/// ### English
+/// Host function callback: receives the call ID, the function name and the arguments as
+/// NUL-terminated UTF-8 JSON (all strings valid only for the duration of the call).
+///
+/// Invoked on the Servo thread; it must return quickly and answer later with
+/// `xian_web_engine_view_respond_host_call` (from any thread).
+///
+/// ### 中文
+/// 宿主函数回调：接收调用 ID、函数名以及以 NUL 结尾 UTF-8 JSON 表示的参数（所有字符串仅在本次调用期间有效）。
+///
+/// 在 Servo 线程上调用；回调必须尽快返回，稍后（可在任意线程）通过 `xian_web_engine_view_respond_host_call`
+/// 应答。
+pub type XianWebEngineHostFunctionFn = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    call_id: u64,
+    name: *const c_char,
+    args_json: *const c_char,
+);
+
+/// ### English
+/// Registered host function callback and its user data.
+///
+/// ### 中文
+/// 已注册的宿主函数回调及其 user data。
+#[derive(Clone, Copy)]
+pub(crate) struct HostFunction {
+    /// ### English
+    /// Embedder callback.
+    ///
+    /// ### 中文
+    /// 宿主回调。
+    pub(crate) callback: XianWebEngineHostFunctionFn,
+    /// ### English
+    /// Opaque user data (stored as an address so the function can cross threads).
+    ///
+    /// ### 中文
+    /// 不透明 user data（以地址形式保存，使其可以跨线程传递）。
+    pub(crate) user_data: usize,
+}
@@ -0,0 +1,73 @@
+//! ### English
+//! Engine-wide limits on the size of images pages display.
+//!
+//! Servo decodes images in its image cache without a size hook, so oversized images are handled
+//! where they enter the page: a user script installed in every view waits for each `<img>` to load,
+//! hides it if it exceeds the limits, redraws it into a canvas at the largest size that fits and
+//! swaps the element to that copy before it is shown, notifying the embedder with an
+//! `IMAGE_DOWNSCALED` event. The full-size image is still decoded once by Servo's image cache, and
+//! cross-origin images (whose pixels a page may not read back) are left as they are. How many
+//! images Servo decodes at once is bounded by `image_cache_threads` in the thread pool
+//! configuration.
+//!
+//! ### 中文
+//! 针对页面所显示图片尺寸的引擎级限制。
+//!
+//! Servo 在其图片缓存中解码图片，没有尺寸钩子，因此超大图片在进入页面处处理：安装到每个 view 的用户脚本会
+//! 等待每个 `<img>` 加载完成，若超出限制则先将其隐藏，再以满足限制的最大尺寸重绘到 canvas 中，并在显示前
+//! 把元素切换为该副本，同时以 `IMAGE_DOWNSCALED` 事件通知宿主。原尺寸图片仍会被 Servo 的图片缓存解码一次；
+//! 跨源图片（页面无法读回其像素）保持原样。Servo 同时解码的图片数量由线程池配置中的 `image_cache_threads`
+//! 限制。
+
+use super::scripts::IMAGE_LIMITS_SCRIPT;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// ### English
+/// Image size limits applied to every view of an engine (`0` in a field means unlimited).
+///
+/// ### 中文
+/// 应用于引擎所有 view 的图片尺寸限制（字段为 `0` 表示不限制）。
+pub struct XianWebEngineImageLimits {
+    /// ### English
+    /// Largest image area in pixels (width × height); larger images are scaled down to fit.
+    ///
+    /// ### 中文
+    /// 图片面积上限（宽 × 高，像素）；更大的图片会被等比缩小到该面积以内。
+    pub max_pixels: u64,
+    /// ### English
+    /// Largest image width in pixels.
+    ///
+    /// ### 中文
+    /// 图片宽度上限（像素）。
+    pub max_width: u32,
+    /// ### English
+    /// Largest image height in pixels.
+    ///
+    /// ### 中文
+    /// 图片高度上限（像素）。
+    pub max_height: u32,
+}
+
+impl XianWebEngineImageLimits {
+    /// ### English
+    /// Returns the user script enforcing these limits, or `None` if every limit is off.
+    ///
+    /// #### Parameters
+    /// - `send`: JavaScript expression of the view's host message sender.
+    ///
+    /// ### 中文
+    /// 返回执行这些限制的用户脚本；若所有限制均关闭则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `send`：该 view 宿主消息发送函数的 JavaScript 表达式。
+    pub(super) fn user_script(&self, send: &str) -> Option<String> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(format!(
+            "({IMAGE_LIMITS_SCRIPT})({send}, {}, {}, {});",
+            self.max_width, self.max_height, self.max_pixels
+        ))
+    }
+}
@@ -0,0 +1,210 @@
+//! ### English
+//! Engine-wide statistics of the images pages load, and how often views load the same image.
+//!
+//! Servo's image cache exposes no statistics, so images are counted where they enter the page: a
+//! user script installed in every view reports each loaded `<img>` (its URL and natural size) and
+//! every Servo thread keeps a least-recently-used table of the image URLs its views loaded. A load
+//! of a URL already in the table is a repeat load, which Servo can serve from its image cache
+//! instead of decoding again, and a repeat load of a URL another view loaded first is a cross-view
+//! repeat load, the sharing HUD views showing the same sprite sheets get. The counts describe what
+//! pages load, not what Servo keeps decoded: Servo decides on its own when to drop decoded images.
+//!
+//! ### 中文
+//! 页面所加载图片的引擎级统计，以及各 view 加载相同图片的频率。
+//!
+//! Servo 的图片缓存不提供统计，因此在图片进入页面处计数：安装到每个 view 的用户脚本会上报每个加载完成的
+//! `<img>`（其 URL 与原始尺寸），每个 Servo 线程维护一张其 view 所加载图片 URL 的 LRU 表。加载表中已有的 URL
+//! 计为重复加载，Servo 可以从其图片缓存提供而无需再次解码；重复加载由其他 view 先加载的 URL 则计为跨 view
+//! 重复加载，即显示相同精灵图的 HUD view 所获得的共享。这些计数描述的是页面加载了什么，而不是 Servo 保留了
+//! 哪些解码结果：Servo 自行决定何时丢弃解码后的图片。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// ### English
+/// Image load statistics of an engine, filled by `xian_web_engine_get_image_load_stats`.
+///
+/// With several Servo threads the values are summed; each thread keeps its own image cache and
+/// table of loaded URLs.
+///
+/// ### 中文
+/// 引擎的图片加载统计，由 `xian_web_engine_get_image_load_stats` 填充。
+///
+/// 存在多个 Servo 线程时各值为其总和；每个线程各自维护其图片缓存与已加载 URL 表。
+pub struct XianWebEngineImageLoadStats {
+    /// ### English
+    /// Image loads reported by pages (monotonic).
+    ///
+    /// ### 中文
+    /// 页面上报的图片加载次数（单调递增）。
+    pub image_loads: u64,
+    /// ### English
+    /// Loads of a URL already loaded on the same Servo thread (monotonic); `repeat_loads /
+    /// image_loads` is the share of loads Servo's image cache can serve.
+    ///
+    /// ### 中文
+    /// 加载同一 Servo 线程上已加载过之 URL 的次数（单调递增）；`repeat_loads / image_loads` 即 Servo
+    /// 图片缓存可提供的加载比例。
+    pub repeat_loads: u64,
+    /// ### English
+    /// Repeat loads of a URL first loaded by another view (monotonic).
+    ///
+    /// ### 中文
+    /// 重复加载由其他 view 先加载之 URL 的次数（单调递增）。
+    pub cross_view_repeat_loads: u64,
+    /// ### English
+    /// URLs dropped from the table, least recently loaded first, to keep it at 16384 URLs per
+    /// Servo thread (monotonic); a later load of a dropped URL counts as a first load.
+    ///
+    /// ### 中文
+    /// 为使表保持在每个 Servo 线程 16384 个 URL 以内、按最久未加载优先移出表的 URL 数（单调递增）；被移出的
+    /// URL 再次加载时计为首次加载。
+    pub dropped_urls: u64,
+    /// ### English
+    /// Distinct URLs currently in the table.
+    ///
+    /// ### 中文
+    /// 表中当前的不同 URL 数。
+    pub distinct_urls: u64,
+    /// ### English
+    /// Decoded size those URLs would take at 4 bytes per pixel of their natural size, in bytes.
+    ///
+    /// ### 中文
+    /// 这些 URL 按原始尺寸每像素 4 字节计算的解码大小（字节）。
+    pub distinct_url_bytes: u64,
+}
+
+/// ### English
+/// Image load counters of one shard (written on the Servo thread, read by embedder threads).
+///
+/// ### 中文
+/// 单个分片的图片加载计数器（Servo 线程写入，宿主线程读取）。
+pub(super) struct ImageLoadLog {
+    /// ### English
+    /// Image loads.
+    ///
+    /// ### 中文
+    /// 图片加载次数。
+    image_loads: AtomicU64,
+    /// ### English
+    /// Loads of a URL already in the table.
+    ///
+    /// ### 中文
+    /// 加载表中已有 URL 的次数。
+    repeat_loads: AtomicU64,
+    /// ### English
+    /// Repeat loads of a URL first loaded by another view.
+    ///
+    /// ### 中文
+    /// 重复加载由其他 view 先加载之 URL 的次数。
+    cross_view_repeat_loads: AtomicU64,
+    /// ### English
+    /// URLs dropped from the full table.
+    ///
+    /// ### 中文
+    /// 因表已满而移出的 URL 数。
+    dropped_urls: AtomicU64,
+    /// ### English
+    /// Distinct URLs in the table.
+    ///
+    /// ### 中文
+    /// 表中的不同 URL 数。
+    distinct_urls: AtomicU64,
+    /// ### English
+    /// Decoded size of those URLs at 4 bytes per pixel.
+    ///
+    /// ### 中文
+    /// 这些 URL 按每像素 4 字节计算的解码大小。
+    distinct_url_bytes: AtomicU64,
+}
+
+impl ImageLoadLog {
+    /// ### English
+    /// Creates empty counters.
+    ///
+    /// ### 中文
+    /// 创建空计数器。
+    pub(super) fn new() -> Self {
+        Self {
+            image_loads: AtomicU64::new(0),
+            repeat_loads: AtomicU64::new(0),
+            cross_view_repeat_loads: AtomicU64::new(0),
+            dropped_urls: AtomicU64::new(0),
+            distinct_urls: AtomicU64::new(0),
+            distinct_url_bytes: AtomicU64::new(0),
+        }
+    }
+
+    /// ### English
+    /// Records one image load.
+    ///
+    /// #### Parameters
+    /// - `repeat`: Whether the URL was already in the table.
+    /// - `cross_view`: Whether another view loaded it first.
+    ///
+    /// ### 中文
+    /// 记录一次图片加载。
+    ///
+    /// #### 参数
+    /// - `repeat`：该 URL 是否已在表中。
+    /// - `cross_view`：是否由其他 view 先加载。
+    pub(super) fn record_load(&self, repeat: bool, cross_view: bool) {
+        self.image_loads.fetch_add(1, Ordering::Relaxed);
+        if repeat {
+            self.repeat_loads.fetch_add(1, Ordering::Relaxed);
+        }
+        if cross_view {
+            self.cross_view_repeat_loads.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// ### English
+    /// Publishes the current table size and adds dropped URLs.
+    ///
+    /// #### Parameters
+    /// - `distinct_urls`: Distinct URLs in the table.
+    /// - `bytes`: Their decoded size at 4 bytes per pixel.
+    /// - `dropped`: URLs dropped since the previous update.
+    ///
+    /// ### 中文
+    /// 发布当前表的大小并累加移出的 URL 数。
+    ///
+    /// #### 参数
+    /// - `distinct_urls`：表中的不同 URL 数。
+    /// - `bytes`：其按每像素 4 字节计算的解码大小。
+    /// - `dropped`：自上次更新以来移出的 URL 数。
+    pub(super) fn set_usage(&self, distinct_urls: u64, bytes: u64, dropped: u64) {
+        self.distinct_urls.store(distinct_urls, Ordering::Relaxed);
+        self.distinct_url_bytes.store(bytes, Ordering::Relaxed);
+        if dropped != 0 {
+            self.dropped_urls.fetch_add(dropped, Ordering::Relaxed);
+        }
+    }
+
+    /// ### English
+    /// Adds this shard's counters to `stats`.
+    ///
+    /// #### Parameters
+    /// - `stats`: Engine-wide statistics being summed.
+    ///
+    /// ### 中文
+    /// 将该分片的计数器累加到 `stats`。
+    ///
+    /// #### 参数
+    /// - `stats`：正在汇总的引擎级统计。
+    pub(super) fn add_to(&self, stats: &mut XianWebEngineImageLoadStats) {
+        let add = |total: &mut u64, counter: &AtomicU64| {
+            *total = total.saturating_add(counter.load(Ordering::Relaxed));
+        };
+        add(&mut stats.image_loads, &self.image_loads);
+        add(&mut stats.repeat_loads, &self.repeat_loads);
+        add(
+            &mut stats.cross_view_repeat_loads,
+            &self.cross_view_repeat_loads,
+        );
+        add(&mut stats.dropped_urls, &self.dropped_urls);
+        add(&mut stats.distinct_urls, &self.distinct_urls);
+        add(&mut stats.distinct_url_bytes, &self.distinct_url_bytes);
+    }
+}
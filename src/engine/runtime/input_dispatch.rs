@@ -5,7 +5,8 @@
 //! ABI 输入事件到 Servo 输入事件的转换与派发。
 use crate::engine::input_types::{
     XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
-    XIAN_WEB_ENGINE_INPUT_KIND_WHEEL, XianWebEngineInputEvent,
+    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE, XIAN_WEB_ENGINE_INPUT_KIND_WHEEL,
+    XianWebEngineInputEvent,
 };
 
 use super::keyboard::{glfw_key_to_code, glfw_key_to_key};
@@ -14,21 +15,42 @@ use super::keyboard::{glfw_key_to_code, glfw_key_to_key};
 /// Dispatches one queued input event into Servo's `WebView`.
 /// Called on the Servo thread only (single consumer).
 ///
+/// Relative mouse motion is handed to the pointer-lock user script, which turns it into a
+/// `mousemove` with `movementX/Y` on the locked element (Servo has no native pointer lock).
+///
+/// Coordinates arrive in the view's logical pixels and are multiplied by `render_scale` to match the
+/// internal render resolution.
+///
+/// Returns the Servo input event id of events sent through Servo's input path (mouse buttons,
+/// wheel and keys), which its input result notification reports back; `None` otherwise.
+///
 /// #### Parameters
 /// - `servo_webview`: Target Servo `WebView`.
 /// - `raw`: ABI input event payload.
+/// - `render_scale`: View render scale (internal pixels per logical pixel).
 ///
 /// ### 中文
 /// 将一个输入事件派发给 Servo 的 `WebView`。
 /// 仅在 Servo 线程调用（单消费者）。
 ///
+/// 相对鼠标移动会交给 pointer-lock 用户脚本，由其在锁定元素上派发带 `movementX/Y` 的 `mousemove`
+/// （Servo 没有原生 pointer lock）。
+///
+/// 坐标以 view 的逻辑像素传入，并乘以 `render_scale` 以匹配内部渲染分辨率。
+///
+/// 对经由 Servo 输入路径发送的事件（鼠标按键、滚轮与按键）返回其 Servo 输入事件 id，Servo 的输入结果通知会
+/// 带回该 id；其他情况返回 `None`。
+///
 /// #### 参数
 /// - `servo_webview`：目标 Servo `WebView`。
 /// - `raw`：ABI 输入事件载荷。
+/// - `render_scale`：view 渲染缩放（每逻辑像素对应的内部像素数）。
 pub(super) fn dispatch_queued_input_event(
     servo_webview: &servo::WebView,
     raw: XianWebEngineInputEvent,
-) {
+    render_scale: f32,
+) -> Option<servo::InputEventId> {
+    let (x, y) = (raw.x * render_scale, raw.y * render_scale);
     match raw.kind {
         XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON => {
             let action = match raw.mouse_action {
@@ -36,10 +58,19 @@ pub(super) fn dispatch_queued_input_event(
                 _ => servo::MouseButtonAction::Up,
             };
             let button = servo::MouseButton::from(raw.mouse_button as u64);
-            let point = servo::WebViewPoint::from(servo::DevicePoint::new(raw.x, raw.y));
-            servo_webview.notify_input_event(servo::InputEvent::MouseButton(
-                servo::MouseButtonEvent::new(action, button, point),
-            ));
+            let point = servo::WebViewPoint::from(servo::DevicePoint::new(x, y));
+            Some(
+                servo_webview.notify_input_event(servo::InputEvent::MouseButton(
+                    servo::MouseButtonEvent::new(action, button, point),
+                )),
+            )
+        }
+        XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE if x.is_finite() && y.is_finite() => {
+            servo_webview.evaluate_javascript(
+                format!("window[Symbol.for(\"xian.pointerLock\")]?.move({x}, {y})"),
+                |_| {},
+            );
+            None
         }
         XIAN_WEB_ENGINE_INPUT_KIND_WHEEL => {
             let mode = match raw.wheel_mode {
@@ -53,10 +84,10 @@ pub(super) fn dispatch_queued_input_event(
                 z: raw.wheel_delta_z,
                 mode,
             };
-            let point = servo::WebViewPoint::from(servo::DevicePoint::new(raw.x, raw.y));
-            servo_webview.notify_input_event(servo::InputEvent::Wheel(servo::WheelEvent::new(
-                delta, point,
-            )));
+            let point = servo::WebViewPoint::from(servo::DevicePoint::new(x, y));
+            Some(servo_webview.notify_input_event(servo::InputEvent::Wheel(
+                servo::WheelEvent::new(delta, point),
+            )))
         }
         XIAN_WEB_ENGINE_INPUT_KIND_KEY => {
             let state = match raw.key_state {
@@ -85,8 +116,8 @@ pub(super) fn dispatch_queued_input_event(
                 repeat,
                 is_composing,
             );
-            servo_webview.notify_input_event(servo::InputEvent::Keyboard(keyboard));
+            Some(servo_webview.notify_input_event(servo::InputEvent::Keyboard(keyboard)))
         }
-        _ => {}
+        _ => None,
     }
 }
@@ -3,16 +3,46 @@
 //!
 //! ### 中文
 //! Servo 运行时编排（对外公开 API）。
+mod binary_channel;
+mod binding;
 mod coalesced;
 mod command;
+mod compositor;
+mod engine_config;
+mod events;
+mod export;
+#[cfg(feature = "golden-tests")]
+mod golden;
+mod host_function;
+mod image_limits;
+mod image_loads;
 mod input_dispatch;
 mod keyboard;
+mod network_limits;
 mod pending;
+mod preload;
 mod queue;
+mod recording;
+#[cfg(feature = "screencast")]
+mod screencast;
+mod scripts;
 mod servo_thread;
+mod session;
+mod shard;
+mod storage_group;
+mod synthesis;
 
 mod engine_runtime;
 mod view_handle;
+mod view_strings;
 
+pub use compositor::XianWebEngineCompositeRect;
+pub use engine_config::XianWebEngineConfig;
 pub use engine_runtime::EngineRuntime;
+#[cfg(feature = "golden-tests")]
+pub use golden::GoldenRun;
+pub use host_function::XianWebEngineHostFunctionFn;
+pub use image_limits::XianWebEngineImageLimits;
+pub use image_loads::XianWebEngineImageLoadStats;
+pub use network_limits::XianWebEngineNetworkLimits;
 pub use view_handle::WebEngineViewHandle;
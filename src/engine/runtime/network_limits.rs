@@ -0,0 +1,37 @@
+//! ### English
+//! Network limits for the HTTP(S) traffic of every engine in the process.
+//!
+//! Servo's networking layer offers no hooks to cap or pace its connections, so the limits are
+//! enforced by the loopback proxy all HTTP(S) traffic goes through (see `engine::dns`). They
+//! therefore cover documents, subresources, `fetch` / XHR and workers alike, and are shared by
+//! every engine.
+//!
+//! ### 中文
+//! 作用于进程内所有引擎 HTTP(S) 流量的网络限制。
+//!
+//! Servo 的网络层没有提供限制连接数或限速的钩子，因此限制由承载全部 HTTP(S) 流量的回环代理执行
+//! （见 `engine::dns`）。它们同样作用于文档、子资源、`fetch` / XHR 与 worker，并由所有引擎共享。
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// ### English
+/// Network limits applied to all HTTP(S) traffic (`0` in a field means unlimited).
+///
+/// ### 中文
+/// 应用于全部 HTTP(S) 流量的网络限制（字段为 `0` 表示不限制）。
+pub struct XianWebEngineNetworkLimits {
+    /// ### English
+    /// Download budget in bytes per second, shared by every connection; reads from servers are
+    /// paced to stay under it.
+    ///
+    /// ### 中文
+    /// 由所有连接共享的下载预算（字节/秒）；从服务器读取的数据会被限速以保持在预算之内。
+    pub max_bytes_per_second: u64,
+    /// ### English
+    /// Maximum concurrent server connections; further connections wait for a free slot, and idle
+    /// keep-alive connections are closed while any are waiting.
+    ///
+    /// ### 中文
+    /// 同时打开的服务器连接上限；更多连接会排队等待空闲名额，且有连接等待时会关闭空闲的 keep-alive 连接。
+    pub max_connections: u32,
+}
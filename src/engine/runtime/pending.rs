@@ -7,7 +7,7 @@
 //! 用于向独立 Servo 线程“信号化有待处理工作”的无锁 `u32` ID 队列。
 //!
 //! 溢出时会设置标记，消费者可回退到扫描兜底以避免漏处理。
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 
 use crate::engine::lockfree::BoundedMpscQueue;
 
@@ -29,6 +29,18 @@ pub(super) struct PendingIdQueue {
     /// ### 中文
     /// 溢出标记：置位时，消费者应回退到全量扫描兜底。
     overflowed: AtomicBool,
+    /// ### English
+    /// Approximate number of queued IDs (for health reporting only).
+    ///
+    /// ### 中文
+    /// 已排队 ID 的近似数量（仅用于健康状态上报）。
+    queued: AtomicUsize,
+    /// ### English
+    /// Number of pushes that overflowed the ring (never reset).
+    ///
+    /// ### 中文
+    /// 溢出 ring 的 push 次数（从不清零）。
+    overflows: AtomicU64,
 }
 
 impl PendingIdQueue {
@@ -47,6 +59,8 @@ impl PendingIdQueue {
         Self {
             ring: BoundedMpscQueue::with_capacity(capacity),
             overflowed: AtomicBool::new(false),
+            queued: AtomicUsize::new(0),
+            overflows: AtomicU64::new(0),
         }
     }
 
@@ -66,9 +80,12 @@ impl PendingIdQueue {
     ///
     /// 成功返回 `true`；若 ring 已满则返回 `false`（并设置 overflow 标记）。
     pub(super) fn push(&self, id: u32) -> bool {
+        self.queued.fetch_add(1, Ordering::Relaxed);
         match self.ring.try_push(id) {
             Ok(()) => true,
             Err(_) => {
+                self.queued.fetch_sub(1, Ordering::Relaxed);
+                self.overflows.fetch_add(1, Ordering::Relaxed);
                 self.overflowed.store(true, Ordering::Release);
                 false
             }
@@ -81,7 +98,27 @@ impl PendingIdQueue {
     /// ### 中文
     /// pop 一个 ID（单消费者 / Servo 线程）。
     pub(super) fn pop(&self) -> Option<u32> {
-        self.ring.pop()
+        let id = self.ring.pop()?;
+        self.queued.fetch_sub(1, Ordering::Relaxed);
+        Some(id)
+    }
+
+    /// ### English
+    /// Returns the approximate number of queued IDs.
+    ///
+    /// ### 中文
+    /// 返回已排队 ID 的近似数量。
+    pub(super) fn len(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns the number of pushes that overflowed the ring.
+    ///
+    /// ### 中文
+    /// 返回溢出 ring 的 push 次数。
+    pub(super) fn overflows(&self) -> u64 {
+        self.overflows.load(Ordering::Relaxed)
     }
 
     /// ### English
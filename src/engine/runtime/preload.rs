@@ -0,0 +1,91 @@
+//! ### English
+//! Cache warming for known UI URLs.
+//!
+//! Servo has no prefetch API, so a hidden view loads a tiny document with one `<img>` per URL:
+//! every URL is requested once through the network stack (filling the HTTP cache), and the ones
+//! that are images are also decoded into the image cache.
+//!
+//! ### 中文
+//! 为已知 UI URL 预热缓存。
+//!
+//! Servo 没有预取 API，因此由一个隐藏 view 加载一个极小的文档，其中每个 URL 对应一个 `<img>`：每个 URL 都会经由
+//! 网络栈请求一次（填充 HTTP 缓存），其中的图片还会被解码进图片缓存。
+
+use std::fmt::Write as _;
+
+use url::Url;
+
+/// ### English
+/// Builds the `data:` URL of the preload document for `urls`.
+///
+/// #### Parameters
+/// - `urls`: URLs to request.
+///
+/// ### 中文
+/// 为 `urls` 构造预加载文档的 `data:` URL。
+///
+/// #### 参数
+/// - `urls`：要请求的 URL。
+pub(super) fn preload_page_url(urls: &[Url]) -> Option<Url> {
+    let mut html = String::with_capacity(128 + urls.len() * 64);
+    html.push_str("<!DOCTYPE html><html><head><meta charset=\"utf-8\"></head><body>");
+    for url in urls {
+        html.push_str("<img alt=\"\" src=\"");
+        push_html_attr_escaped(&mut html, url.as_str());
+        html.push_str("\">");
+    }
+    html.push_str("</body></html>");
+
+    let mut data_url = String::with_capacity(html.len() * 2 + 32);
+    data_url.push_str("data:text/html;charset=utf-8,");
+    push_percent_encoded(&mut data_url, &html);
+    Url::parse(&data_url).ok()
+}
+
+/// ### English
+/// Appends `value` escaped for use inside a double-quoted HTML attribute.
+///
+/// #### Parameters
+/// - `out`: Output buffer.
+/// - `value`: Raw attribute value.
+///
+/// ### 中文
+/// 将 `value` 以双引号 HTML 属性值的形式转义后追加。
+///
+/// #### 参数
+/// - `out`：输出缓冲区。
+/// - `value`：原始属性值。
+fn push_html_attr_escaped(out: &mut String, value: &str) {
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(ch),
+        }
+    }
+}
+
+/// ### English
+/// Appends `value` percent-encoded for a `data:` URL body (only unreserved bytes stay literal).
+///
+/// #### Parameters
+/// - `out`: Output buffer.
+/// - `value`: Raw text.
+///
+/// ### 中文
+/// 将 `value` 按 `data:` URL 正文进行百分号编码后追加（仅保留 unreserved 字节原样）。
+///
+/// #### 参数
+/// - `out`：输出缓冲区。
+/// - `value`：原始文本。
+fn push_percent_encoded(out: &mut String, value: &str) {
+    for &byte in value.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+}
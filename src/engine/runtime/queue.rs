@@ -1,13 +1,150 @@
 //! ### English
 //! Lock-free queues used inside the runtime.
 //!
+//! The command queue has three priority lanes so a flood of ordinary requests can never delay view
+//! destruction (and the GL cleanup it performs) or shutdown:
+//!
+//! 1. Urgent: `DestroyView`, `DestroyViewGroup`, `Shutdown` (unbounded, never rejected while open).
+//! 2. Normal: view creation, engine settings and lightweight per-view requests.
+//! 3. Bulk: large or slow requests (fonts, exports, user scripts/stylesheets, session/a11y dumps).
+//!
+//! Normal and bulk lanes are bounded; a rejected push is reported to the caller through
+//! [`last_push_status`].
+//!
 //! ### 中文
 //! 运行时内部使用的无锁队列实现。
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+//!
+//! 命令队列包含三个优先级通道，使大量普通请求永远不会拖延 view 销毁（及其 GL 清理）或 shutdown：
+//!
+//! 1. 紧急：`DestroyView`、`DestroyViewGroup`、`Shutdown`（无界；队列开启期间从不拒绝）。
+//! 2. 普通：view 创建、引擎设置与轻量的 per-view 请求。
+//! 3. 批量：体积大或耗时的请求（字体、导出、用户脚本/样式表、会话/无障碍树转储）。
+//!
+//! 普通与批量通道有容量上限；被拒绝的 push 会通过 [`last_push_status`] 告知调用方。
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_STATUS_QUEUE_FULL,
+    XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN,
+};
 use crate::engine::lockfree::{Backoff, MpscQueue};
 
-use super::command::Command;
+use super::command::{Command, ViewCommand};
+
+/// ### English
+/// Number of priority lanes.
+///
+/// ### 中文
+/// 优先级通道数量。
+const LANE_COUNT: usize = 3;
+
+/// ### English
+/// Lane index of urgent commands (destruction / shutdown).
+///
+/// ### 中文
+/// 紧急命令（销毁 / shutdown）的通道下标。
+const LANE_URGENT: usize = 0;
+
+/// ### English
+/// Lane index of normal commands.
+///
+/// ### 中文
+/// 普通命令的通道下标。
+const LANE_NORMAL: usize = 1;
+
+/// ### English
+/// Lane index of bulk commands.
+///
+/// ### 中文
+/// 批量命令的通道下标。
+const LANE_BULK: usize = 2;
+
+/// ### English
+/// Capacity of each lane (`usize::MAX` = unbounded).
+///
+/// ### 中文
+/// 各通道的容量（`usize::MAX` 表示无界）。
+const LANE_CAPACITY: [usize; LANE_COUNT] = [usize::MAX, 4096, 256];
+
+thread_local! {
+    /// ### English
+    /// Status of the last command push made on this thread (`XIAN_WEB_ENGINE_STATUS_*`).
+    ///
+    /// ### 中文
+    /// 本线程最近一次命令 push 的状态（`XIAN_WEB_ENGINE_STATUS_*`）。
+    static LAST_PUSH_STATUS: Cell<i32> = const { Cell::new(XIAN_WEB_ENGINE_STATUS_OK) };
+}
+
+/// ### English
+/// Returns the status of the last command push made on the calling thread.
+///
+/// ### 中文
+/// 返回调用线程最近一次命令 push 的状态。
+pub(super) fn last_push_status() -> i32 {
+    LAST_PUSH_STATUS.with(Cell::get)
+}
+
+/// ### English
+/// Overrides the calling thread's last status after a pushed command failed later on (e.g. its
+/// reply timed out).
+///
+/// #### Parameters
+/// - `status`: `XIAN_WEB_ENGINE_STATUS_*`.
+///
+/// ### 中文
+/// 在已 push 的命令随后失败时（例如等待回包超时）覆盖调用线程最近一次的状态。
+///
+/// #### 参数
+/// - `status`：`XIAN_WEB_ENGINE_STATUS_*`。
+pub(super) fn set_last_status(status: i32) {
+    LAST_PUSH_STATUS.with(|last| last.set(status));
+}
+
+/// ### English
+/// Returns the priority lane of a command.
+///
+/// #### Parameters
+/// - `command`: Command to classify.
+///
+/// ### 中文
+/// 返回命令所属的优先级通道。
+///
+/// #### 参数
+/// - `command`：要分类的命令。
+fn lane_of(command: &Command) -> usize {
+    match command {
+        Command::DestroyView { .. } | Command::DestroyViewGroup { .. } | Command::Shutdown => {
+            LANE_URGENT
+        }
+        Command::RegisterFont { .. }
+        | Command::View {
+            command:
+                ViewCommand::Export { .. }
+                | ViewCommand::CaptureElement { .. }
+                | ViewCommand::AccessibilityTree { .. }
+                | ViewCommand::SaveSession { .. }
+                | ViewCommand::AddUserStylesheet { .. }
+                | ViewCommand::AddUserScript { .. }
+                | ViewCommand::ProvideFiles { .. },
+            ..
+        } => LANE_BULK,
+        Command::CreateView { .. }
+        | Command::View { .. }
+        | Command::CreateViewGroup { .. }
+        | Command::EnableWebDriver { .. }
+        | Command::SetResourceReader { .. }
+        | Command::SetThreadConfig { .. }
+        | Command::SetHttpCacheEnabled { .. }
+        | Command::SetHeapLimit { .. }
+        | Command::SetImageLimits { .. }
+        | Command::SetNetworkMode { .. }
+        | Command::RegisterTexture { .. }
+        | Command::ClearHttpCache
+        | Command::SetIdle { .. }
+        | Command::MemoryPressure { .. } => LANE_NORMAL,
+    }
+}
 
 /// ### English
 /// Command queue used by embedder threads to send control messages to the Servo thread.
@@ -16,11 +153,17 @@ use super::command::Command;
 /// 宿主线程向 Servo 线程发送控制消息的命令队列。
 pub(super) struct CommandQueue {
     /// ### English
-    /// Underlying unbounded MPSC queue.
+    /// Underlying MPSC queues, one per priority lane (highest priority first).
+    ///
+    /// ### 中文
+    /// 底层 MPSC 队列，每个优先级通道一个（优先级从高到低）。
+    lanes: [MpscQueue<Command>; LANE_COUNT],
+    /// ### English
+    /// Number of commands queued in each lane (enforces lane capacity).
     ///
     /// ### 中文
-    /// 底层无界 MPSC 队列。
-    queue: MpscQueue<Command>,
+    /// 各通道中已排队的命令数（用于限制通道容量）。
+    lane_len: [AtomicUsize; LANE_COUNT],
     /// ### English
     /// Number of producers currently publishing into the queue.
     ///
@@ -33,6 +176,18 @@ pub(super) struct CommandQueue {
     /// ### 中文
     /// 关闭标记：用于在 shutdown 期间拒绝新命令。
     closed: AtomicBool,
+    /// ### English
+    /// Approximate number of queued commands (for health reporting only).
+    ///
+    /// ### 中文
+    /// 已排队命令的近似数量（仅用于健康状态上报）。
+    queued: AtomicUsize,
+    /// ### English
+    /// Commands rejected because their lane was full (never reset).
+    ///
+    /// ### 中文
+    /// 因通道已满而被拒绝的命令数（从不清零）。
+    rejected: AtomicU64,
 }
 
 impl CommandQueue {
@@ -43,9 +198,16 @@ impl CommandQueue {
     /// 创建一个处于 open 状态的新命令队列。
     pub(super) fn new() -> Self {
         Self {
-            queue: MpscQueue::new(),
+            lanes: [MpscQueue::new(), MpscQueue::new(), MpscQueue::new()],
+            lane_len: [
+                AtomicUsize::new(0),
+                AtomicUsize::new(0),
+                AtomicUsize::new(0),
+            ],
             in_flight: AtomicUsize::new(0),
             closed: AtomicBool::new(false),
+            queued: AtomicUsize::new(0),
+            rejected: AtomicU64::new(0),
         }
     }
 
@@ -71,38 +233,103 @@ impl CommandQueue {
     }
 
     /// ### English
-    /// Tries to push one command; returns `false` if the queue is closed.
+    /// Tries to push one command into its priority lane.
+    ///
+    /// Returns `false` if the queue is closed or the lane is full; the reason is recorded for
+    /// [`last_push_status`].
     ///
     /// #### Parameters
     /// - `command`: Command to push.
     ///
     /// ### 中文
-    /// 尝试 push 一个命令；若队列已关闭则返回 `false`。
+    /// 尝试将一个命令 push 到其优先级通道。
+    ///
+    /// 若队列已关闭或通道已满则返回 `false`；失败原因会记录下来供 [`last_push_status`] 查询。
     ///
     /// #### 参数
     /// - `command`：要 push 的命令。
     pub(super) fn try_push(&self, command: Command) -> bool {
+        let status = self.push_lane(command);
+        LAST_PUSH_STATUS.with(|last| last.set(status));
+        status == XIAN_WEB_ENGINE_STATUS_OK
+    }
+
+    /// ### English
+    /// Pushes one command into its lane and returns a status code.
+    ///
+    /// #### Parameters
+    /// - `command`: Command to push.
+    ///
+    /// ### 中文
+    /// 将一个命令 push 到其通道，并返回状态码。
+    ///
+    /// #### 参数
+    /// - `command`：要 push 的命令。
+    fn push_lane(&self, command: Command) -> i32 {
         if self.closed.load(Ordering::Acquire) {
-            return false;
+            return XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN;
         }
         self.in_flight.fetch_add(1, Ordering::Relaxed);
         if self.closed.load(Ordering::Acquire) {
             self.in_flight.fetch_sub(1, Ordering::Release);
-            return false;
+            return XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN;
+        }
+
+        let lane = lane_of(&command);
+        if self.lane_len[lane].fetch_add(1, Ordering::Relaxed) >= LANE_CAPACITY[lane] {
+            self.lane_len[lane].fetch_sub(1, Ordering::Relaxed);
+            self.in_flight.fetch_sub(1, Ordering::Release);
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return XIAN_WEB_ENGINE_STATUS_QUEUE_FULL;
         }
 
-        self.queue.push(command);
+        self.queued.fetch_add(1, Ordering::Relaxed);
+        self.lanes[lane].push(command);
         self.in_flight.fetch_sub(1, Ordering::Release);
-        true
+        XIAN_WEB_ENGINE_STATUS_OK
     }
 
     /// ### English
-    /// Pops one command from the queue.
+    /// Pops one command, taking higher-priority lanes first.
     ///
     /// ### 中文
-    /// 从队列 pop 一个命令。
+    /// pop 一个命令，优先取高优先级通道。
     pub(super) fn pop(&self) -> Option<Command> {
-        self.queue.pop()
+        let (lane, command) = self
+            .lanes
+            .iter()
+            .enumerate()
+            .find_map(|(lane, queue)| queue.pop().map(|command| (lane, command)))?;
+        self.lane_len[lane].fetch_sub(1, Ordering::Relaxed);
+        self.queued.fetch_sub(1, Ordering::Relaxed);
+        Some(command)
+    }
+
+    /// ### English
+    /// Returns the approximate number of queued commands.
+    ///
+    /// ### 中文
+    /// 返回已排队命令的近似数量。
+    pub(super) fn len(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns the number of commands rejected because their lane was full.
+    ///
+    /// ### 中文
+    /// 返回因通道已满而被拒绝的命令数。
+    pub(super) fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns whether the queue was closed (the engine is shutting down or gone).
+    ///
+    /// ### 中文
+    /// 返回队列是否已关闭（引擎正在关闭或已销毁）。
+    pub(super) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
     }
 
     /// ### English
@@ -110,27 +337,49 @@ impl CommandQueue {
     ///
     /// This waits for in-flight producers to finish publishing.
     /// The wait uses a short spin-then-yield backoff to avoid burning CPU during shutdown.
-    /// While draining, any pending `CreateView` commands are completed with an error to avoid
-    /// leaving callers blocked on their one-shot response.
+    /// While draining, any pending `CreateView`/`CreateViewGroup`/`EnableWebDriver` commands are
+    /// completed with an error to avoid leaving callers blocked on their one-shot response (or
+    /// waiting for an async creation callback that never comes).
     ///
     /// ### 中文
     /// 关闭队列并 drain 所有剩余命令。
     ///
     /// 该操作会等待正在进行中的生产者完成发布。
     /// 等待过程使用短暂自旋 + `yield` 退避，避免 shutdown 时空转占用 CPU。
-    /// drain 过程中会将所有未处理的 `CreateView` 命令用错误回包，以避免调用方卡在 oneshot 等待中。
+    /// drain 过程中会将所有未处理的 `CreateView`/`CreateViewGroup`/`EnableWebDriver` 命令用错误回包，
+    /// 以避免调用方卡在 oneshot 等待中（或等待一个永远不会到来的异步创建回调）。
     pub(super) fn close(&self) {
         self.closed.store(true, Ordering::Release);
         let mut backoff = Backoff::new();
         while self.in_flight.load(Ordering::Acquire) != 0 {
             backoff.snooze();
         }
-        while let Some(command) = self.queue.pop() {
+        while let Some(command) = self.pop() {
             match command {
                 Command::CreateView { response, .. } => {
+                    response.send(Err("Engine is shutting down".to_string()));
+                }
+                Command::CreateViewGroup { response, .. } => {
+                    let _ = response.send(Err("Engine is shutting down".to_string()));
+                }
+                Command::EnableWebDriver { response, .. } => {
                     let _ = response.send(Err("Engine is shutting down".to_string()));
                 }
-                Command::DestroyView { .. } | Command::Shutdown => {}
+                Command::DestroyView { .. }
+                | Command::View { .. }
+                | Command::DestroyViewGroup { .. }
+                | Command::SetResourceReader { .. }
+                | Command::RegisterFont { .. }
+                | Command::SetThreadConfig { .. }
+                | Command::SetHttpCacheEnabled { .. }
+                | Command::SetHeapLimit { .. }
+                | Command::SetImageLimits { .. }
+                | Command::SetNetworkMode { .. }
+                | Command::RegisterTexture { .. }
+                | Command::ClearHttpCache
+                | Command::SetIdle { .. }
+                | Command::MemoryPressure { .. }
+                | Command::Shutdown => {}
             }
         }
     }
@@ -0,0 +1,509 @@
+//! ### English
+//! Recording of the input events, resizes and navigations a view receives through the FFI, and the
+//! file format read back for replay.
+//!
+//! A recording is a little-endian binary file: the [`MAGIC`] header followed by entries made of a
+//! `u64` offset in nanoseconds since recording started, a `u8` action tag and the action payload.
+//! A truncated trailing entry (e.g. the process crashed while recording) is ignored on read.
+//!
+//! ### 中文
+//! 记录 view 经由 FFI 收到的输入事件、resize 与导航，以及回放时读取的文件格式。
+//!
+//! 录制文件为小端二进制：[`MAGIC`] 文件头之后是若干条目，每条由 `u64` 偏移（相对录制开始的纳秒数）、`u8`
+//! 动作标签与动作载荷组成。读取时会忽略被截断的末尾条目（例如录制过程中进程崩溃）。
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use dpi::PhysicalSize;
+
+use crate::engine::input_types::XianWebEngineInputEvent;
+
+/// ### English
+/// File header identifying a view recording (format version 1).
+///
+/// ### 中文
+/// 标识 view 录制文件的文件头（格式版本 1）。
+const MAGIC: &[u8; 8] = b"XIANREC1";
+
+/// ### English
+/// Tag of an input event entry (payload: the `XianWebEngineInputEvent` fields in order).
+///
+/// ### 中文
+/// 输入事件条目的标签（载荷：按顺序排列的 `XianWebEngineInputEvent` 字段）。
+const TAG_INPUT: u8 = 1;
+
+/// ### English
+/// Tag of a resize entry (payload: `u32` width, `u32` height).
+///
+/// ### 中文
+/// resize 条目的标签（载荷：`u32` 宽度、`u32` 高度）。
+const TAG_RESIZE: u8 = 2;
+
+/// ### English
+/// Tag of a navigation entry (payload: `u32` byte length, UTF-8 URL).
+///
+/// ### 中文
+/// 导航条目的标签（载荷：`u32` 字节长度、UTF-8 URL）。
+const TAG_NAVIGATE: u8 = 3;
+
+/// ### English
+/// Largest URL accepted when reading a recording.
+///
+/// ### 中文
+/// 读取录制文件时接受的最大 URL 长度。
+const MAX_URL_BYTES: u32 = 16 * 1024 * 1024;
+
+/// ### English
+/// One recorded action.
+///
+/// ### 中文
+/// 一个已录制的动作。
+pub(super) enum RecordedAction {
+    /// ### English
+    /// An input event (mouse moves are recorded as `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE` events).
+    ///
+    /// ### 中文
+    /// 输入事件（鼠标移动记录为 `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE` 事件）。
+    Input(XianWebEngineInputEvent),
+    /// ### English
+    /// A resize to the given size (already clamped to at least 1x1).
+    ///
+    /// ### 中文
+    /// resize 到给定尺寸（已 clamp 至至少 1x1）。
+    Resize(PhysicalSize<u32>),
+    /// ### English
+    /// A `load_url` navigation.
+    ///
+    /// ### 中文
+    /// 一次 `load_url` 导航。
+    Navigate(String),
+}
+
+/// ### English
+/// A recorded action and when it happened.
+///
+/// ### 中文
+/// 一个已录制的动作及其发生时间。
+pub(super) struct RecordedEntry {
+    /// ### English
+    /// Time since recording started.
+    ///
+    /// ### 中文
+    /// 相对录制开始的时间。
+    pub(super) offset: Duration,
+    /// ### English
+    /// Recorded action.
+    ///
+    /// ### 中文
+    /// 已录制的动作。
+    pub(super) action: RecordedAction,
+}
+
+/// ### English
+/// An open recording file.
+///
+/// ### 中文
+/// 一个已打开的录制文件。
+struct RecordingFile {
+    /// ### English
+    /// Buffered output (flushed when recording stops or the handle is dropped).
+    ///
+    /// ### 中文
+    /// 带缓冲的输出（停止录制或句柄 drop 时 flush）。
+    out: BufWriter<File>,
+    /// ### English
+    /// Time recording started.
+    ///
+    /// ### 中文
+    /// 录制开始时间。
+    start: Instant,
+}
+
+/// ### English
+/// Per-view recorder fed by the embedder-facing handle methods.
+///
+/// The `active` flag keeps the non-recording path to a single relaxed load. A write error stops the
+/// recording.
+///
+/// ### 中文
+/// 由面向宿主的句柄方法写入的每 view 录制器。
+///
+/// `active` 标志使未录制时的路径只需一次 relaxed load。写入出错会停止录制。
+#[derive(Default)]
+pub(super) struct ViewRecorder {
+    /// ### English
+    /// Whether a recording is open.
+    ///
+    /// ### 中文
+    /// 是否有打开的录制。
+    active: AtomicBool,
+    /// ### English
+    /// Open recording, if any.
+    ///
+    /// ### 中文
+    /// 打开的录制（若有）。
+    file: Mutex<Option<RecordingFile>>,
+}
+
+impl ViewRecorder {
+    /// ### English
+    /// Starts recording into `path` (created or truncated), replacing a recording already running.
+    ///
+    /// #### Parameters
+    /// - `path`: Output file.
+    ///
+    /// ### 中文
+    /// 开始录制到 `path`（会被创建或截断），替换正在进行的录制。
+    ///
+    /// #### 参数
+    /// - `path`：输出文件。
+    pub(super) fn start(&self, path: &Path) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(MAGIC)?;
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| io::Error::other("recorder lock poisoned"))?;
+        if let Some(mut previous) = file.take() {
+            let _ = previous.out.flush();
+        }
+        *file = Some(RecordingFile {
+            out,
+            start: Instant::now(),
+        });
+        self.active.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// ### English
+    /// Stops recording and flushes the file. Returns `false` if nothing was being recorded or the
+    /// flush failed.
+    ///
+    /// ### 中文
+    /// 停止录制并 flush 文件。若没有正在进行的录制或 flush 失败，则返回 `false`。
+    pub(super) fn stop(&self) -> bool {
+        let Ok(mut file) = self.file.lock() else {
+            return false;
+        };
+        self.active.store(false, Ordering::Relaxed);
+        file.take()
+            .is_some_and(|mut recording| recording.out.flush().is_ok())
+    }
+
+    #[inline]
+    /// ### English
+    /// Records input events delivered to the view.
+    ///
+    /// #### Parameters
+    /// - `events`: Accepted events, in delivery order.
+    ///
+    /// ### 中文
+    /// 记录投递给 view 的输入事件。
+    ///
+    /// #### 参数
+    /// - `events`：已接收的事件（按投递顺序）。
+    pub(super) fn record_inputs(&self, events: &[XianWebEngineInputEvent]) {
+        if self.active.load(Ordering::Relaxed) {
+            self.append(|out, offset| {
+                events
+                    .iter()
+                    .try_for_each(|event| write_input(out, offset, event))
+            });
+        }
+    }
+
+    #[inline]
+    /// ### English
+    /// Records a resize.
+    ///
+    /// #### Parameters
+    /// - `size`: Requested size (clamped to at least 1x1).
+    ///
+    /// ### 中文
+    /// 记录一次 resize。
+    ///
+    /// #### 参数
+    /// - `size`：请求的尺寸（已 clamp 至至少 1x1）。
+    pub(super) fn record_resize(&self, size: PhysicalSize<u32>) {
+        if self.active.load(Ordering::Relaxed) {
+            self.append(|out, offset| {
+                write_header(out, offset, TAG_RESIZE)?;
+                out.write_all(&size.width.to_le_bytes())?;
+                out.write_all(&size.height.to_le_bytes())
+            });
+        }
+    }
+
+    #[inline]
+    /// ### English
+    /// Records a navigation.
+    ///
+    /// #### Parameters
+    /// - `url`: URL passed to `load_url`.
+    ///
+    /// ### 中文
+    /// 记录一次导航。
+    ///
+    /// #### 参数
+    /// - `url`：传给 `load_url` 的 URL。
+    pub(super) fn record_navigate(&self, url: &str) {
+        if self.active.load(Ordering::Relaxed) {
+            self.append(|out, offset| {
+                write_header(out, offset, TAG_NAVIGATE)?;
+                out.write_all(&(url.len() as u32).to_le_bytes())?;
+                out.write_all(url.as_bytes())
+            });
+        }
+    }
+
+    /// ### English
+    /// Appends entries to the open recording; a write error closes it.
+    ///
+    /// #### Parameters
+    /// - `write`: Writes the entries, given the output and the current offset in nanoseconds.
+    ///
+    /// ### 中文
+    /// 向打开的录制追加条目；写入出错会关闭录制。
+    ///
+    /// #### 参数
+    /// - `write`：写入条目的函数，参数为输出与当前偏移（纳秒）。
+    fn append(&self, write: impl FnOnce(&mut BufWriter<File>, u64) -> io::Result<()>) {
+        let Ok(mut file) = self.file.lock() else {
+            return;
+        };
+        let Some(recording) = file.as_mut() else {
+            return;
+        };
+        let offset = recording.start.elapsed().as_nanos() as u64;
+        if write(&mut recording.out, offset).is_err() {
+            *file = None;
+            self.active.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// ### English
+/// Writes the offset and tag that start an entry.
+///
+/// #### Parameters
+/// - `out`: Output.
+/// - `offset`: Nanoseconds since recording started.
+/// - `tag`: `TAG_*` value.
+///
+/// ### 中文
+/// 写入条目开头的偏移与标签。
+///
+/// #### 参数
+/// - `out`：输出。
+/// - `offset`：相对录制开始的纳秒数。
+/// - `tag`：`TAG_*` 值。
+fn write_header(out: &mut impl Write, offset: u64, tag: u8) -> io::Result<()> {
+    out.write_all(&offset.to_le_bytes())?;
+    out.write_all(&[tag])
+}
+
+/// ### English
+/// Writes one input event entry.
+///
+/// #### Parameters
+/// - `out`: Output.
+/// - `offset`: Nanoseconds since recording started.
+/// - `event`: Event to write.
+///
+/// ### 中文
+/// 写入一个输入事件条目。
+///
+/// #### 参数
+/// - `out`：输出。
+/// - `offset`：相对录制开始的纳秒数。
+/// - `event`：要写入的事件。
+fn write_input(
+    out: &mut impl Write,
+    offset: u64,
+    event: &XianWebEngineInputEvent,
+) -> io::Result<()> {
+    write_header(out, offset, TAG_INPUT)?;
+    out.write_all(&event.kind.to_le_bytes())?;
+    out.write_all(&event.x.to_le_bytes())?;
+    out.write_all(&event.y.to_le_bytes())?;
+    for value in [event.modifiers, event.mouse_button, event.mouse_action] {
+        out.write_all(&value.to_le_bytes())?;
+    }
+    for value in [
+        event.wheel_delta_x,
+        event.wheel_delta_y,
+        event.wheel_delta_z,
+    ] {
+        out.write_all(&value.to_le_bytes())?;
+    }
+    for value in [
+        event.wheel_mode,
+        event.key_state,
+        event.key_location,
+        event.repeat,
+        event.is_composing,
+        event.key_codepoint,
+        event.glfw_key,
+    ] {
+        out.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// ### English
+/// Reads a recording written by [`ViewRecorder`]. Entries are returned in file order (offsets are
+/// non-decreasing).
+///
+/// #### Parameters
+/// - `path`: Recording file.
+///
+/// ### 中文
+/// 读取由 [`ViewRecorder`] 写入的录制文件。条目按文件顺序返回（偏移单调不减）。
+///
+/// #### 参数
+/// - `path`：录制文件。
+pub(super) fn read_recording(path: &Path) -> io::Result<Vec<RecordedEntry>> {
+    let mut input = BufReader::new(File::open(path)?);
+    let mut magic = [0u8; 8];
+    input.read_exact(&mut magic)?;
+    if magic != *MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a view recording",
+        ));
+    }
+
+    let mut entries = Vec::new();
+    loop {
+        match read_entry(&mut input) {
+            Ok(Some(entry)) => entries.push(entry),
+            Ok(None) => break,
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(entries)
+}
+
+/// ### English
+/// Reads one entry; returns `None` at a clean end of file.
+///
+/// #### Parameters
+/// - `input`: Input positioned at an entry boundary.
+///
+/// ### 中文
+/// 读取一个条目；在文件正常结束处返回 `None`。
+///
+/// #### 参数
+/// - `input`：位于条目边界的输入。
+fn read_entry(input: &mut impl Read) -> io::Result<Option<RecordedEntry>> {
+    let mut offset = [0u8; 8];
+    match input.read(&mut offset[..1])? {
+        0 => return Ok(None),
+        _ => input.read_exact(&mut offset[1..])?,
+    }
+    let offset = Duration::from_nanos(u64::from_le_bytes(offset));
+
+    let mut tag = [0u8; 1];
+    input.read_exact(&mut tag)?;
+    let action = match tag[0] {
+        TAG_INPUT => RecordedAction::Input(read_input(input)?),
+        TAG_RESIZE => {
+            let width = read_u32(input)?;
+            let height = read_u32(input)?;
+            RecordedAction::Resize(PhysicalSize::new(width.max(1), height.max(1)))
+        }
+        TAG_NAVIGATE => {
+            let len = read_u32(input)?;
+            if len > MAX_URL_BYTES {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "recorded URL is too long",
+                ));
+            }
+            let mut url = vec![0u8; len as usize];
+            input.read_exact(&mut url)?;
+            let url = String::from_utf8(url)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            RecordedAction::Navigate(url)
+        }
+        tag => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unknown recording entry tag {tag}"),
+            ));
+        }
+    };
+    Ok(Some(RecordedEntry { offset, action }))
+}
+
+/// ### English
+/// Reads the payload of an input event entry.
+///
+/// #### Parameters
+/// - `input`: Input positioned at the payload.
+///
+/// ### 中文
+/// 读取输入事件条目的载荷。
+///
+/// #### 参数
+/// - `input`：位于载荷处的输入。
+fn read_input(input: &mut impl Read) -> io::Result<XianWebEngineInputEvent> {
+    Ok(XianWebEngineInputEvent {
+        kind: read_u32(input)?,
+        x: f32::from_bits(read_u32(input)?),
+        y: f32::from_bits(read_u32(input)?),
+        modifiers: read_u32(input)?,
+        mouse_button: read_u32(input)?,
+        mouse_action: read_u32(input)?,
+        wheel_delta_x: read_f64(input)?,
+        wheel_delta_y: read_f64(input)?,
+        wheel_delta_z: read_f64(input)?,
+        wheel_mode: read_u32(input)?,
+        key_state: read_u32(input)?,
+        key_location: read_u32(input)?,
+        repeat: read_u32(input)?,
+        is_composing: read_u32(input)?,
+        key_codepoint: read_u32(input)?,
+        glfw_key: read_u32(input)?,
+    })
+}
+
+/// ### English
+/// Reads a little-endian `u32`.
+///
+/// #### Parameters
+/// - `input`: Input.
+///
+/// ### 中文
+/// 读取一个小端 `u32`。
+///
+/// #### 参数
+/// - `input`：输入。
+fn read_u32(input: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// ### English
+/// Reads a little-endian `f64`.
+///
+/// #### Parameters
+/// - `input`: Input.
+///
+/// ### 中文
+/// 读取一个小端 `f64`。
+///
+/// #### 参数
+/// - `input`：输入。
+fn read_f64(input: &mut impl Read) -> io::Result<f64> {
+    let mut bytes = [0u8; 8];
+    input.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
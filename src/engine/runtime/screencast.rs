@@ -0,0 +1,352 @@
+//! ### English
+//! Screencast server streaming a view's frames over HTTP for remote debugging (`screencast`
+//! feature).
+//!
+//! Frames are captured through the same readback path as PNG export, encoded to PNG on a helper
+//! thread and served as a `multipart/x-mixed-replace` stream, which browsers display as a live
+//! image: open `http://<host>:<port>/` from any machine that can reach the host. The server listens
+//! on every interface and has no authentication, so it is meant for development builds only.
+//! Frames are encoded once whatever the number of clients; a client that falls behind skips
+//! straight to the latest frame.
+//!
+//! ### 中文
+//! 通过 HTTP 推流 view 帧用于远程调试的屏幕投射服务器（`screencast` feature）。
+//!
+//! 帧通过与 PNG 导出相同的读回路径截取，在辅助线程编码为 PNG，并以 `multipart/x-mixed-replace` 流提供，
+//! 浏览器会将其显示为实时图像：在任何能访问宿主机的机器上打开 `http://<host>:<port>/` 即可。服务器在所有网卡
+//! 上监听且没有鉴权，因此仅用于开发构建。无论客户端多少，每帧只编码一次；落后的客户端会直接跳到最新帧。
+
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use super::export::write_png;
+
+/// ### English
+/// Most clients streaming at once; further connections are refused with `503`.
+///
+/// ### 中文
+/// 同时推流的客户端上限；更多的连接会以 `503` 拒绝。
+const MAX_CLIENTS: usize = 4;
+
+/// ### English
+/// Largest HTTP request head read from a client before the stream starts.
+///
+/// ### 中文
+/// 开始推流前从客户端读取的 HTTP 请求头上限。
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// ### English
+/// Response refusing a client beyond [`MAX_CLIENTS`].
+///
+/// ### 中文
+/// 拒绝超出 [`MAX_CLIENTS`] 的客户端时发送的响应。
+const BUSY_RESPONSE: &[u8] =
+    b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// ### English
+/// Multipart boundary separating the frames of the stream.
+///
+/// ### 中文
+/// 分隔流中各帧的 multipart 边界。
+const BOUNDARY: &str = "xian-frame";
+
+/// ### English
+/// Frame state shared by the Servo thread, the encoder thread and the client threads.
+///
+/// ### 中文
+/// Servo 线程、编码线程与客户端线程共享的帧状态。
+#[derive(Default)]
+struct FrameState {
+    /// ### English
+    /// Latest captured frame not yet encoded (a newer capture replaces it).
+    ///
+    /// ### 中文
+    /// 最新截取但尚未编码的帧（更新的截取会替换它）。
+    raw: Option<servo::RgbaImage>,
+    /// ### English
+    /// Latest encoded PNG frame.
+    ///
+    /// ### 中文
+    /// 最新编码完成的 PNG 帧。
+    png: Option<Arc<Vec<u8>>>,
+    /// ### English
+    /// Sequence number of `png`, bumped for every encoded frame.
+    ///
+    /// ### 中文
+    /// `png` 的序号，每编码一帧递增一次。
+    sequence: u64,
+    /// ### English
+    /// Whether the server was stopped (every helper thread exits).
+    ///
+    /// ### 中文
+    /// 服务器是否已停止（所有辅助线程都会退出）。
+    closed: bool,
+}
+
+/// ### English
+/// Server state shared with the helper threads.
+///
+/// ### 中文
+/// 与辅助线程共享的服务器状态。
+#[derive(Default)]
+struct Shared {
+    /// ### English
+    /// Frame state.
+    ///
+    /// ### 中文
+    /// 帧状态。
+    frames: Mutex<FrameState>,
+    /// ### English
+    /// Signalled when a frame is captured or encoded, or the server stops.
+    ///
+    /// ### 中文
+    /// 截取或编码一帧、或服务器停止时发出通知。
+    changed: Condvar,
+    /// ### English
+    /// Number of clients currently streaming.
+    ///
+    /// ### 中文
+    /// 当前正在推流的客户端数量。
+    clients: AtomicUsize,
+}
+
+/// ### English
+/// Running screencast server of one view; dropping it stops the server.
+///
+/// ### 中文
+/// 单个 view 正在运行的屏幕投射服务器；drop 时停止服务器。
+pub(super) struct ScreencastServer {
+    /// ### English
+    /// State shared with the helper threads.
+    ///
+    /// ### 中文
+    /// 与辅助线程共享的状态。
+    shared: Arc<Shared>,
+    /// ### English
+    /// Listening port.
+    ///
+    /// ### 中文
+    /// 监听端口。
+    port: u16,
+}
+
+impl ScreencastServer {
+    /// ### English
+    /// Binds `port` on every interface and starts the accept and encoder threads.
+    ///
+    /// #### Parameters
+    /// - `port`: TCP port to listen on.
+    ///
+    /// ### 中文
+    /// 在所有网卡上绑定 `port`，并启动 accept 线程与编码线程。
+    ///
+    /// #### 参数
+    /// - `port`：要监听的 TCP 端口。
+    pub(super) fn start(port: u16) -> Result<Self, String> {
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))
+            .map_err(|err| format!("Cannot listen on port {port}: {err}"))?;
+        let shared = Arc::new(Shared::default());
+
+        let encoder_shared = shared.clone();
+        thread::Builder::new()
+            .name("xian-screencast-encode".to_string())
+            .spawn(move || encode_frames(&encoder_shared))
+            .map_err(|err| format!("Failed to spawn screencast encoder thread: {err}"))?;
+
+        let server = Self { shared, port };
+        let accept_shared = server.shared.clone();
+        thread::Builder::new()
+            .name("xian-screencast".to_string())
+            .spawn(move || accept_clients(&listener, &accept_shared))
+            .map_err(|err| format!("Failed to spawn screencast thread: {err}"))?;
+        Ok(server)
+    }
+
+    /// ### English
+    /// Hands a captured frame to the encoder thread, replacing a frame it has not picked up yet.
+    ///
+    /// #### Parameters
+    /// - `image`: Captured RGBA frame.
+    ///
+    /// ### 中文
+    /// 将截取的帧交给编码线程，并替换其尚未取走的帧。
+    ///
+    /// #### 参数
+    /// - `image`：截取的 RGBA 帧。
+    pub(super) fn submit(&self, image: servo::RgbaImage) {
+        let Ok(mut frames) = self.shared.frames.lock() else {
+            return;
+        };
+        frames.raw = Some(image);
+        drop(frames);
+        self.shared.changed.notify_all();
+    }
+}
+
+impl Drop for ScreencastServer {
+    /// ### English
+    /// Stops the server: wakes every helper thread, then unblocks the accept thread with a local
+    /// connection.
+    ///
+    /// ### 中文
+    /// 停止服务器：唤醒所有辅助线程，再通过一次本地连接解除 accept 线程的阻塞。
+    fn drop(&mut self) {
+        if let Ok(mut frames) = self.shared.frames.lock() {
+            frames.closed = true;
+        }
+        self.shared.changed.notify_all();
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+    }
+}
+
+/// ### English
+/// Encoder thread body: encodes every captured frame to PNG until the server stops.
+///
+/// #### Parameters
+/// - `shared`: Server state.
+///
+/// ### 中文
+/// 编码线程主体：在服务器停止前把每个截取的帧编码为 PNG。
+///
+/// #### 参数
+/// - `shared`：服务器状态。
+fn encode_frames(shared: &Shared) {
+    loop {
+        let Ok(mut frames) = shared.frames.lock() else {
+            return;
+        };
+        let image = loop {
+            if frames.closed {
+                return;
+            }
+            if let Some(image) = frames.raw.take() {
+                break image;
+            }
+            frames = match shared.changed.wait(frames) {
+                Ok(frames) => frames,
+                Err(_) => return,
+            };
+        };
+        drop(frames);
+
+        let mut png = Vec::new();
+        if write_png(&mut png, image.width(), image.height(), image.as_raw()).is_err() {
+            continue;
+        }
+        let Ok(mut frames) = shared.frames.lock() else {
+            return;
+        };
+        frames.png = Some(Arc::new(png));
+        frames.sequence += 1;
+        drop(frames);
+        shared.changed.notify_all();
+    }
+}
+
+/// ### English
+/// Accept thread body: serves every incoming connection on its own thread until the server stops.
+///
+/// #### Parameters
+/// - `listener`: Bound listener.
+/// - `shared`: Server state.
+///
+/// ### 中文
+/// accept 线程主体：在服务器停止前，为每个传入连接启动独立线程提供服务。
+///
+/// #### 参数
+/// - `listener`：已绑定的监听器。
+/// - `shared`：服务器状态。
+fn accept_clients(listener: &TcpListener, shared: &Arc<Shared>) {
+    for stream in listener.incoming() {
+        if shared.frames.lock().map_or(true, |frames| frames.closed) {
+            return;
+        }
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        if shared.clients.fetch_add(1, Ordering::AcqRel) >= MAX_CLIENTS {
+            shared.clients.fetch_sub(1, Ordering::AcqRel);
+            let _ = stream.write_all(BUSY_RESPONSE);
+            continue;
+        }
+
+        let client_shared = shared.clone();
+        let spawned = thread::Builder::new()
+            .name("xian-screencast-client".to_string())
+            .spawn(move || {
+                let _ = stream_frames(stream, &client_shared);
+                client_shared.clients.fetch_sub(1, Ordering::AcqRel);
+            });
+        if spawned.is_err() {
+            shared.clients.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+/// ### English
+/// Client thread body: reads the request head, then writes every new frame until the client
+/// disconnects or the server stops.
+///
+/// #### Parameters
+/// - `stream`: Client connection.
+/// - `shared`: Server state.
+///
+/// ### 中文
+/// 客户端线程主体：读取请求头后持续写出每个新帧，直到客户端断开或服务器停止。
+///
+/// #### 参数
+/// - `stream`：客户端连接。
+/// - `shared`：服务器状态。
+fn stream_frames(mut stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buf)?;
+        if read == 0 || head.len() + read > MAX_REQUEST_BYTES {
+            return Ok(());
+        }
+        head.extend_from_slice(&buf[..read]);
+    }
+
+    stream.write_all(
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: multipart/x-mixed-replace; boundary={BOUNDARY}\r\n\
+             Cache-Control: no-store\r\nConnection: close\r\n\r\n"
+        )
+        .as_bytes(),
+    )?;
+
+    let mut sent = 0;
+    loop {
+        let Ok(mut frames) = shared.frames.lock() else {
+            return Ok(());
+        };
+        let png = loop {
+            if frames.closed {
+                return Ok(());
+            }
+            if let Some(png) = frames.png.as_ref().filter(|_| frames.sequence != sent) {
+                sent = frames.sequence;
+                break png.clone();
+            }
+            frames = match shared.changed.wait(frames) {
+                Ok(frames) => frames,
+                Err(_) => return Ok(()),
+            };
+        };
+        drop(frames);
+
+        stream.write_all(
+            format!(
+                "--{BOUNDARY}\r\nContent-Type: image/png\r\nContent-Length: {}\r\n\r\n",
+                png.len()
+            )
+            .as_bytes(),
+        )?;
+        stream.write_all(&png)?;
+        stream.write_all(b"\r\n")?;
+    }
+}
@@ -0,0 +1,269 @@
+//! ### English
+//! Page scripts evaluated by the Servo thread to answer embedder queries.
+//!
+//! ### 中文
+//! Servo 线程为响应宿主查询而执行的页面脚本。
+
+/// ### English
+/// Serializes the page's accessibility tree (roles, names, bounds in CSS px) to a JSON string.
+///
+/// ### 中文
+/// 将页面的无障碍树（role、名称、以 CSS px 表示的边界）序列化为 JSON 字符串。
+pub(super) const ACCESSIBILITY_TREE_SCRIPT: &str = include_str!("scripts/accessibility_tree.js");
+
+/// ### English
+/// Function expression `(paused: boolean) => [mediaCount, playingCount]` that pauses every playing
+/// media element (remembering which ones) or resumes the ones it paused earlier.
+///
+/// ### 中文
+/// 函数表达式 `(paused: boolean) => [mediaCount, playingCount]`：暂停所有正在播放的媒体元素（并记录下来），
+/// 或恢复此前由它暂停的元素。
+pub(super) const MEDIA_CONTROL_SCRIPT: &str = include_str!("scripts/media_control.js");
+
+/// ### English
+/// Fixed part of the console message prefix engine scripts use to talk to the embedder; each view
+/// appends its own nonce (see `HostChannel`), and such messages are consumed by the delegate.
+///
+/// ### 中文
+/// 引擎脚本与宿主通信所用 console 消息前缀的固定部分；每个 view 会在其后追加自己的 nonce（见 `HostChannel`），
+/// 此类消息由 delegate 消费。
+pub(super) const HOST_MESSAGE_PREFIX: &str = "\u{1}xian-host:";
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// implements the Pointer Lock API on top of relative mouse input
+/// (`XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE`). `requestPointerLock()` asks the host and stays
+/// pending until the host settles it (see `PointerLock`).
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：基于相对鼠标输入
+/// （`XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE`）实现 Pointer Lock API。`requestPointerLock()` 会询问宿主，
+/// 并保持待处理直到宿主了结该请求（见 `PointerLock`）。
+pub(super) const POINTER_LOCK_SCRIPT: &str = include_str!("scripts/pointer_lock.js");
+
+/// ### English
+/// User script installed in every view that keeps its own table of timers and animation-frame
+/// requests, so inactive views can run them normally (`0`), at most once per second (`1`) or not at
+/// all until resumed (`2`). Leaving mode `0` cancels the native timers and frames; returning to it
+/// re-arms them with their remaining delay. Cleared entries are dropped at once.
+///
+/// ### 中文
+/// 安装到每个 view 的用户脚本：自行维护定时器与动画帧请求表，使 inactive 的 view 可以正常执行（`0`）、
+/// 每秒至多执行一次（`1`），或在恢复前完全挂起（`2`）。离开模式 `0` 时取消原生定时器与动画帧；回到该模式时
+/// 按剩余延迟重新设定。被清除的条目会立即移除。
+pub(super) const BACKGROUND_TIMERS_SCRIPT: &str = include_str!("scripts/background_timers.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// reports the page scroll offset (CSS px) to the host whenever it changes, so the embedder can
+/// query it without a round trip.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：页面滚动偏移（CSS px）变化时上报给宿主，使宿主无需往返即可查询。
+pub(super) const SCROLL_TRACKER_SCRIPT: &str = include_str!("scripts/scroll_tracker.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// wraps `console.*` and forwards each call to the host with its level
+/// (`XIAN_WEB_ENGINE_LOG_LEVEL_*`), caller source URL and line. Each wrapper keeps the native
+/// function under `Symbol.for("xian.nativeConsole")` so host channels never log through it.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：包装 `console.*`，并把每次调用连同级别（`XIAN_WEB_ENGINE_LOG_LEVEL_*`）、
+/// 调用方源 URL 与行号转发给宿主。每个包装函数在 `Symbol.for("xian.nativeConsole")` 下保留原生函数，使宿主
+/// 通道不会经由包装函数输出。
+pub(super) const CONSOLE_CAPTURE_SCRIPT: &str = include_str!("scripts/console_capture.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// reports uncaught exceptions, unhandled promise rejections, and failed subresource loads
+/// (elements, `fetch`, XHR) to the host; at most 100 reports per document.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：向宿主上报未捕获异常、未处理的 Promise 拒绝以及失败的子资源加载（元素、
+/// `fetch`、XHR）；每个文档最多上报 100 条。
+pub(super) const PAGE_ERRORS_SCRIPT: &str = include_str!("scripts/page_errors.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// reports how many HTTP(S) subresource loads finished, as observed through resource timing
+/// entries. The Servo thread counts the starts itself in `load_web_resource`.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：根据 resource timing 条目上报已完成的 HTTP(S)
+/// 子资源加载数量。加载的开始由 Servo 线程在 `load_web_resource` 中自行计数。
+pub(super) const REQUEST_COMPLETION_SCRIPT: &str = include_str!("scripts/request_completion.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// reports each loaded `<img>` (natural size and URL) to the host for the image load statistics;
+/// `data:` and `blob:` images are skipped, and at most 4096 reports are sent per document.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：把每个加载完成的 `<img>`（原始尺寸与 URL）上报给宿主，用于图片加载统计；
+/// 跳过 `data:` 与 `blob:` 图片，每个文档最多上报 4096 条。
+pub(super) const IMAGE_LOADS_SCRIPT: &str = include_str!("scripts/image_loads.js");
+
+/// ### English
+/// Function expression `(send) => void`, installed in every view through its host channel, that
+/// defines `window.xianHost`: `call(name, args)` sends a JSON call whose Promise is settled by the
+/// embedder's answer, `sendBinary(channel, data)` sends bytes, `binary` events deliver bytes from
+/// the embedder as `ArrayBuffer`s, and `getTexture(name)` resolves to an `ImageBitmap` copy of a
+/// registered embedder texture. `openStream(id)` returns a stream of `ImageBitmap` frames pushed by
+/// the embedder, which `attach(canvas)` draws as they arrive.
+///
+/// ### 中文
+/// 函数表达式 `(send) => void`，经宿主通道安装到每个 view：定义 `window.xianHost`。
+/// `call(name, args)` 以 JSON 形式发送调用，其 Promise 由宿主的应答兑现；`sendBinary(channel, data)`
+/// 发送字节；`binary` 事件以 `ArrayBuffer` 形式投递宿主发来的字节；`getTexture(name)` 兑现为已注册宿主纹理的 `ImageBitmap` 副本；`openStream(id)` 返回由宿主推送的
+/// `ImageBitmap` 帧流，`attach(canvas)` 会在帧到达时将其绘制到 canvas。
+pub(super) const HOST_BRIDGE_SCRIPT: &str = include_str!("scripts/host_bridge.js");
+
+/// ### English
+/// Function expression `(send, maxWidth, maxHeight, maxPixels) => void` that replaces every loaded
+/// `<img>` larger than the limits with a canvas-downscaled copy and reports it to the host (`0`
+/// disables a limit).
+///
+/// ### 中文
+/// 函数表达式 `(send, maxWidth, maxHeight, maxPixels) => void`：把每个加载完成且超出限制的 `<img>` 替换为经
+/// canvas 缩小的副本，并上报给宿主（`0` 表示关闭对应限制）。
+pub(super) const IMAGE_LIMITS_SCRIPT: &str = include_str!("scripts/image_limits.js");
+
+/// ### English
+/// Function expression `(reducedMotion, forcedColors) => void` that answers the
+/// `prefers-reduced-motion` and `forced-colors` media features of `matchMedia` queries (firing
+/// `change` on lists whose result flips) and neutralizes animations and transitions while reduced
+/// motion is on. Running it again on a page updates the values.
+///
+/// ### 中文
+/// 函数表达式 `(reducedMotion, forcedColors) => void`：为 `matchMedia` 查询中的 `prefers-reduced-motion`
+/// 与 `forced-colors` 媒体特性给出结果（结果翻转的列表会触发 `change`），并在减少动态效果开启时消除动画与
+/// 过渡。在页面上再次执行会更新这些值。
+pub(super) const MEDIA_FEATURES_SCRIPT: &str = include_str!("scripts/media_features.js");
+
+/// ### English
+/// Function expression `(paused: boolean) => void` that offsets the page's timeline by the time
+/// spent paused: while paused, `performance.now()` stands still and CSS animations get
+/// `animation-play-state: paused`; on resume the paused span is subtracted from `performance.now()`
+/// and every later `requestAnimationFrame` timestamp, so time continues where it stopped. Running
+/// it again on a page updates the state.
+///
+/// ### 中文
+/// 函数表达式 `(paused: boolean) => void`：按暂停的时长偏移页面时间线。暂停期间 `performance.now()` 停止
+/// 前进，CSS 动画被设为 `animation-play-state: paused`；恢复后 `performance.now()` 与此后每个
+/// `requestAnimationFrame` 时间戳都会减去暂停的时长，因此时间从停止处继续。在页面上再次执行会更新该状态。
+pub(super) const TIME_PAUSE_SCRIPT: &str = include_str!("scripts/time_pause.js");
+
+/// ### English
+/// Function expression `(selector: string) => [x, y, width, height] | string` that returns the
+/// first matching element's bounding rectangle clipped to the viewport (CSS px), or the reason it
+/// cannot be captured.
+///
+/// ### 中文
+/// 函数表达式 `(selector: string) => [x, y, width, height] | string`：返回首个匹配元素裁剪到视口内的边界
+/// 矩形（CSS px），或无法截取的原因。
+pub(super) const ELEMENT_RECT_SCRIPT: &str = include_str!("scripts/element_rect.js");
+
+/// ### English
+/// Function expression `(left: number | null, top: number | null) => [scrollX, scrollY,
+/// documentWidth, documentHeight, viewportWidth, viewportHeight]` (CSS px) that first scrolls
+/// instantly to `(left, top)` unless `left` is `null`.
+///
+/// ### 中文
+/// 函数表达式 `(left: number | null, top: number | null) => [scrollX, scrollY, documentWidth,
+/// documentHeight, viewportWidth, viewportHeight]`（CSS px）：除非 `left` 为 `null`，否则先立即滚动到
+/// `(left, top)`。
+pub(super) const DOCUMENT_EXTENT_SCRIPT: &str = include_str!("scripts/document_extent.js");
+
+/// ### English
+/// Expression that locks the element of the page's pending pointer lock request.
+///
+/// ### 中文
+/// 锁定页面待处理 pointer lock 请求所指元素的表达式。
+pub(super) const POINTER_LOCK_ALLOW_SCRIPT: &str =
+    "window[Symbol.for(\"xian.pointerLock\")]?.settle(true)";
+
+/// ### English
+/// Expression that rejects the page's pending pointer lock request.
+///
+/// ### 中文
+/// 拒绝页面待处理 pointer lock 请求的表达式。
+pub(super) const POINTER_LOCK_DENY_SCRIPT: &str =
+    "window[Symbol.for(\"xian.pointerLock\")]?.settle(false)";
+
+/// ### English
+/// Expression that leaves pointer lock (no-op if the page is not locked).
+///
+/// ### 中文
+/// 退出 pointer lock 的表达式（若页面未锁定则无操作）。
+pub(super) const EXIT_POINTER_LOCK_SCRIPT: &str =
+    "window[Symbol.for(\"xian.pointerLock\")]?.exit()";
+
+/// ### English
+/// Function expression `(phase, x, y, mime, data) => dropEffect` that synthesizes HTML5 drag-and-drop
+/// events for data dragged in from the host (`phase` is `"begin"`, `"move"`, `"drop"` or `"cancel"`;
+/// `x`/`y` in device pixels).
+///
+/// ### 中文
+/// 函数表达式 `(phase, x, y, mime, data) => dropEffect`：为从宿主拖入的数据合成 HTML5 拖放事件
+/// （`phase` 为 `"begin"`、`"move"`、`"drop"` 或 `"cancel"`；`x`/`y` 为设备像素）。
+pub(super) const DRAG_DROP_SCRIPT: &str = include_str!("scripts/drag_drop.js");
+
+/// ### English
+/// Function expression `(x, y) => boolean` telling whether the point (device pixels) lies on a page
+/// element other than `<html>` or `<body>`.
+///
+/// ### 中文
+/// 函数表达式 `(x, y) => boolean`：判断该点（设备像素）是否落在 `<html>` 或 `<body>` 以外的页面元素上。
+pub(super) const HIT_TEST_SCRIPT: &str = include_str!("scripts/hit_test.js");
+
+/// ### English
+/// Function expression `(qx, qy, qz, qw, motion, ax, ay, az) => void` that converts a host
+/// orientation quaternion into W3C `alpha`/`beta`/`gamma` and fires `deviceorientation` (plus
+/// `devicemotion` with `accelerationIncludingGravity` when `motion` is true).
+///
+/// ### 中文
+/// 函数表达式 `(qx, qy, qz, qw, motion, ax, ay, az) => void`：把宿主提供的朝向四元数换算为 W3C 的
+/// `alpha`/`beta`/`gamma` 并派发 `deviceorientation`（`motion` 为 true 时还会派发带
+/// `accelerationIncludingGravity` 的 `devicemotion`）。
+pub(super) const DEVICE_SENSORS_SCRIPT: &str = include_str!("scripts/device_sensors.js");
+
+/// ### English
+/// Function expression `(action) => string` for text selection: `"selectAll"` selects the focused
+/// text field or the whole document, `"get"` returns the selected text, and `"copy"` also runs the
+/// page's `copy` handlers. Password fields never expose their value.
+///
+/// ### 中文
+/// 用于文本选区的函数表达式 `(action) => string`：`"selectAll"` 选中获得焦点的文本框或整个文档，
+/// `"get"` 返回选中的文本，`"copy"` 还会执行页面的 `copy` 处理器。密码框的值永远不会被暴露。
+pub(super) const SELECTION_SCRIPT: &str = include_str!("scripts/selection.js");
+
+/// ### English
+/// Quotes `value` as a JavaScript string literal.
+///
+/// #### Parameters
+/// - `value`: String to quote.
+///
+/// ### 中文
+/// 将 `value` 转为 JavaScript 字符串字面量。
+///
+/// #### 参数
+/// - `value`：要转换的字符串。
+pub(super) fn js_string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 || c == '\u{2028}' || c == '\u{2029}' => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
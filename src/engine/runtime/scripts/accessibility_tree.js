@@ -0,0 +1,142 @@
+(() => {
+  const IMPLICIT_ROLES = {
+    A: (el) => (el.hasAttribute("href") ? "link" : null),
+    ARTICLE: () => "article",
+    ASIDE: () => "complementary",
+    BUTTON: () => "button",
+    DIALOG: () => "dialog",
+    FOOTER: () => "contentinfo",
+    FORM: () => "form",
+    H1: () => "heading",
+    H2: () => "heading",
+    H3: () => "heading",
+    H4: () => "heading",
+    H5: () => "heading",
+    H6: () => "heading",
+    HEADER: () => "banner",
+    HR: () => "separator",
+    IMG: (el) => (el.getAttribute("alt") === "" ? null : "img"),
+    INPUT: (el) => {
+      switch ((el.getAttribute("type") || "text").toLowerCase()) {
+        case "hidden":
+          return null;
+        case "button":
+        case "submit":
+        case "reset":
+        case "image":
+          return "button";
+        case "checkbox":
+          return "checkbox";
+        case "radio":
+          return "radio";
+        case "range":
+          return "slider";
+        case "number":
+          return "spinbutton";
+        case "search":
+          return "searchbox";
+        default:
+          return "textbox";
+      }
+    },
+    LI: () => "listitem",
+    MAIN: () => "main",
+    NAV: () => "navigation",
+    OL: () => "list",
+    OPTION: () => "option",
+    PROGRESS: () => "progressbar",
+    SECTION: () => "region",
+    SELECT: () => "combobox",
+    TABLE: () => "table",
+    TD: () => "cell",
+    TEXTAREA: () => "textbox",
+    TH: () => "columnheader",
+    TR: () => "row",
+    UL: () => "list",
+  };
+  const NAME_FROM_CONTENT = new Set([
+    "button", "cell", "checkbox", "columnheader", "heading", "link", "listitem", "option", "radio",
+    "row", "tab", "menuitem", "treeitem",
+  ]);
+  const MAX_NAME = 256;
+
+  const clip = (text) => {
+    const value = (text || "").replace(/\s+/g, " ").trim();
+    return value.length > MAX_NAME ? value.slice(0, MAX_NAME) : value;
+  };
+
+  const isHidden = (el) => {
+    if (el.hidden || el.getAttribute("aria-hidden") === "true") return true;
+    const style = getComputedStyle(el);
+    return style.display === "none" || style.visibility === "hidden";
+  };
+
+  const roleOf = (el) => {
+    const explicit = el.getAttribute("role");
+    if (explicit) return explicit.split(/\s+/)[0];
+    const implicit = IMPLICIT_ROLES[el.tagName];
+    return implicit ? implicit(el) : null;
+  };
+
+  const nameOf = (el, role) => {
+    const label = el.getAttribute("aria-label");
+    if (label) return clip(label);
+    const labelledBy = el.getAttribute("aria-labelledby");
+    if (labelledBy) {
+      const text = labelledBy
+        .split(/\s+/)
+        .map((id) => document.getElementById(id))
+        .filter(Boolean)
+        .map((node) => node.textContent)
+        .join(" ");
+      if (text.trim()) return clip(text);
+    }
+    if (el.labels && el.labels.length) return clip(el.labels[0].textContent);
+    if (el.tagName === "IMG") return clip(el.getAttribute("alt"));
+    if (el.tagName === "INPUT" && /^(button|submit|reset)$/i.test(el.type)) return clip(el.value);
+    if (role && NAME_FROM_CONTENT.has(role)) return clip(el.textContent);
+    return clip(el.getAttribute("title") || el.getAttribute("placeholder"));
+  };
+
+  const isFocusable = (el) =>
+    el.tabIndex >= 0 && !el.disabled && (el.hasAttribute("tabindex") || el.matches("a[href],button,input,select,textarea,summary,[contenteditable]"));
+
+  const visit = (el, out) => {
+    if (isHidden(el)) return;
+    const role = roleOf(el);
+    const focusable = isFocusable(el);
+    if (!role && !focusable) {
+      for (const child of el.children) visit(child, out);
+      return;
+    }
+
+    const rect = el.getBoundingClientRect();
+    const node = {
+      role: role || "generic",
+      name: nameOf(el, role),
+      bounds: [rect.x, rect.y, rect.width, rect.height],
+      focusable,
+      children: [],
+    };
+    if (el === document.activeElement) node.focused = true;
+    if (el.disabled || el.getAttribute("aria-disabled") === "true") node.disabled = true;
+    if (role === "heading" && /^H[1-6]$/.test(el.tagName)) node.level = Number(el.tagName[1]);
+    if (role === "checkbox" || role === "radio") {
+      node.checked = el.checked === true || el.getAttribute("aria-checked") === "true";
+    }
+    if (el.tagName === "A" && el.href) node.url = el.href;
+    if (/^(INPUT|TEXTAREA|SELECT)$/.test(el.tagName) && el.type !== "password") node.value = clip(el.value);
+    for (const child of el.children) visit(child, node.children);
+    out.push(node);
+  };
+
+  const root = {
+    role: "document",
+    name: clip(document.title),
+    bounds: [0, 0, innerWidth, innerHeight],
+    focusable: false,
+    children: [],
+  };
+  if (document.body) visit(document.body, root.children);
+  return JSON.stringify({ url: location.href, devicePixelRatio, root });
+})()
@@ -0,0 +1,159 @@
+(() => {
+  const key = Symbol.for("xian.backgroundTimers");
+  if (window[key]) return;
+
+  const native = {
+    setTimeout: window.setTimeout,
+    clearTimeout: window.clearTimeout,
+    setInterval: window.setInterval,
+    clearInterval: window.clearInterval,
+    requestAnimationFrame: window.requestAnimationFrame,
+    cancelAnimationFrame: window.cancelAnimationFrame,
+  };
+  const timers = new Map();
+  const frames = new Map();
+  let nextTimer = 0;
+  let nextFrame = 0;
+  let mode = 0;
+  let pump = 0;
+
+  const report = (err) => {
+    native.setTimeout.call(window, () => {
+      throw err;
+    });
+  };
+
+  const armTimer = (id, timer) => {
+    const delay = Math.max(0, timer.due - performance.now());
+    timer.native = native.setTimeout.call(window, () => fireTimer(id), delay);
+  };
+  const fireTimer = (id) => {
+    const timer = timers.get(id);
+    if (!timer) return;
+    timer.native = 0;
+    if (timer.interval) {
+      timer.due = performance.now() + timer.delay;
+      if (mode === 0) armTimer(id, timer);
+    } else {
+      timers.delete(id);
+    }
+    timer.callback.apply(window, timer.args);
+  };
+  const addTimer = (callback, delay, args, interval) => {
+    if (typeof callback !== "function") {
+      const code = String(callback);
+      callback = () => (0, eval)(code);
+    }
+    const id = ++nextTimer;
+    const timer = { callback, args, interval, delay: Math.max(0, Number(delay) || 0) };
+    timer.due = performance.now() + timer.delay;
+    timer.native = 0;
+    timers.set(id, timer);
+    if (mode === 0) armTimer(id, timer);
+    return id;
+  };
+  const clearTimer = (id) => {
+    const timer = timers.get(id);
+    if (!timer) return;
+    timers.delete(id);
+    if (timer.native) native.clearTimeout.call(window, timer.native);
+  };
+
+  const armFrame = (id, frame) => {
+    frame.native = native.requestAnimationFrame.call(window, (time) => fireFrame(id, time));
+  };
+  const fireFrame = (id, time) => {
+    const frame = frames.get(id);
+    if (!frame) return;
+    frames.delete(id);
+    frame.callback.call(window, time);
+  };
+
+  const disarm = () => {
+    for (const timer of timers.values()) {
+      if (timer.native) native.clearTimeout.call(window, timer.native);
+      timer.native = 0;
+    }
+    for (const frame of frames.values()) {
+      if (frame.native) native.cancelAnimationFrame.call(window, frame.native);
+      frame.native = 0;
+    }
+  };
+  const rearm = () => {
+    for (const [id, timer] of timers) {
+      if (!timer.native) armTimer(id, timer);
+    }
+    for (const [id, frame] of frames) {
+      if (!frame.native) armFrame(id, frame);
+    }
+  };
+  const runDue = () => {
+    const now = performance.now();
+    const due = [...timers].filter(([, timer]) => timer.due <= now);
+    due.sort(([, a], [, b]) => a.due - b.due);
+    for (const [id] of due) {
+      try {
+        fireTimer(id);
+      } catch (err) {
+        report(err);
+      }
+    }
+    for (const id of [...frames.keys()]) {
+      try {
+        fireFrame(id, now);
+      } catch (err) {
+        report(err);
+      }
+    }
+  };
+
+  window.setTimeout = function (callback, delay, ...args) {
+    return addTimer(callback, delay, args, false);
+  };
+  window.setInterval = function (callback, delay, ...args) {
+    return addTimer(callback, delay, args, true);
+  };
+  window.clearTimeout = function (id) {
+    clearTimer(id);
+  };
+  window.clearInterval = function (id) {
+    clearTimer(id);
+  };
+  if (native.requestAnimationFrame && native.cancelAnimationFrame) {
+    window.requestAnimationFrame = function (callback) {
+      if (typeof callback !== "function") {
+        throw new TypeError("requestAnimationFrame: argument is not a function");
+      }
+      const id = ++nextFrame;
+      const frame = { callback, native: 0 };
+      frames.set(id, frame);
+      if (mode === 0) armFrame(id, frame);
+      return id;
+    };
+    window.cancelAnimationFrame = function (id) {
+      const frame = frames.get(id);
+      if (!frame) return;
+      frames.delete(id);
+      if (frame.native) native.cancelAnimationFrame.call(window, frame.native);
+    };
+  }
+
+  Object.defineProperty(window, key, {
+    value: {
+      setMode(next) {
+        if (next === mode) return;
+        mode = next;
+        if (pump) {
+          native.clearInterval.call(window, pump);
+          pump = 0;
+        }
+        if (mode === 0) {
+          rearm();
+          return;
+        }
+        disarm();
+        if (mode === 1) pump = native.setInterval.call(window, runDue, 1000);
+      },
+    },
+  });
+})();
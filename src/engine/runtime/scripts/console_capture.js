@@ -0,0 +1,34 @@
+(send) => {
+  const LEVELS = { error: 1, warn: 2, info: 3, log: 3, debug: 4, trace: 5 };
+
+  const format = (value) => {
+    if (typeof value === "string") return value;
+    if (value instanceof Error) return value.stack ? `${value}\n${value.stack}` : String(value);
+    if (typeof value === "object" && value !== null) {
+      try {
+        return JSON.stringify(value);
+      } catch (_) {}
+    }
+    return String(value);
+  };
+
+  const callerLocation = () => {
+    const frames = (new Error().stack || "").split("\n");
+    const match = /@(.*):(\d+):\d+$/.exec(frames[2] || "");
+    return match ? [match[1], match[2]] : [location.href, "0"];
+  };
+
+  for (const [name, level] of Object.entries(LEVELS)) {
+    const original = console[name];
+    if (typeof original !== "function") continue;
+    const wrapper = function (...args) {
+      try {
+        const [source, line] = callerLocation();
+        send(`console:${level}:${line}:${source}\n${args.map(format).join(" ")}`);
+      } catch (_) {}
+      return original.apply(this, args);
+    };
+    Object.defineProperty(wrapper, Symbol.for("xian.nativeConsole"), { value: original });
+    console[name] = wrapper;
+  }
+}
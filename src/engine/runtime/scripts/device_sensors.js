@@ -0,0 +1,80 @@
+((qx, qy, qz, qw, motion, ax, ay, az) => {
+  const norm = Math.hypot(qx, qy, qz, qw) || 1;
+  const [x, y, z, w] = [qx / norm, qy / norm, qz / norm, qw / norm];
+  const deg = 180 / Math.PI;
+  const m00 = 1 - 2 * (y * y + z * z);
+  const m01 = 2 * (x * y - z * w);
+  const m10 = 2 * (x * y + z * w);
+  const m11 = 1 - 2 * (x * x + z * z);
+  const m20 = 2 * (x * z - y * w);
+  const m21 = 2 * (y * z + x * w);
+  const m22 = 1 - 2 * (x * x + y * y);
+
+  let alpha;
+  let beta;
+  let gamma;
+  if (m22 > 0) {
+    alpha = Math.atan2(-m01, m11);
+    beta = Math.asin(Math.max(-1, Math.min(1, m21)));
+    gamma = Math.atan2(-m20, m22);
+  } else if (m22 < 0) {
+    alpha = Math.atan2(m01, -m11);
+    beta = -Math.asin(Math.max(-1, Math.min(1, m21)));
+    beta += beta >= 0 ? -Math.PI : Math.PI;
+    gamma = Math.atan2(m20, -m22);
+  } else if (m20 > 0) {
+    alpha = Math.atan2(-m01, m11);
+    beta = Math.asin(Math.max(-1, Math.min(1, m21)));
+    gamma = -Math.PI / 2;
+  } else {
+    alpha = Math.atan2(m01, -m11);
+    beta = -Math.asin(Math.max(-1, Math.min(1, m21)));
+    beta += beta >= 0 ? -Math.PI : Math.PI;
+    gamma = -Math.PI / 2;
+  }
+  alpha *= deg;
+  if (alpha < 0) alpha += 360;
+  beta *= deg;
+  gamma *= deg;
+
+  const fire = (type, Ctor, init) => {
+    let ev;
+    try {
+      ev = new Ctor(type, init);
+    } catch (_) {
+      ev = new Event(type);
+      for (const [name, value] of Object.entries(init)) {
+        Object.defineProperty(ev, name, { value });
+      }
+    }
+    window.dispatchEvent(ev);
+  };
+
+  fire("deviceorientation", window.DeviceOrientationEvent, {
+    alpha,
+    beta,
+    gamma,
+    absolute: false,
+  });
+
+  if (motion) {
+    const state = (window[Symbol.for("xian.deviceSensors")] ||= { last: null, time: 0 });
+    const now = performance.now();
+    const interval = state.time ? now - state.time : 16;
+    const rotationRate = state.last
+      ? {
+          alpha: ((((alpha - state.last.alpha + 540) % 360) - 180) * 1000) / interval,
+          beta: ((beta - state.last.beta) * 1000) / interval,
+          gamma: ((gamma - state.last.gamma) * 1000) / interval,
+        }
+      : { alpha: 0, beta: 0, gamma: 0 };
+    state.last = { alpha, beta, gamma };
+    state.time = now;
+    fire("devicemotion", window.DeviceMotionEvent, {
+      acceleration: null,
+      accelerationIncludingGravity: { x: ax, y: ay, z: az },
+      rotationRate,
+      interval,
+    });
+  }
+})
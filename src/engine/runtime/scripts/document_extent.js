@@ -0,0 +1,8 @@
+(left, top) => {
+  if (left !== null) window.scrollTo({ left, top, behavior: "instant" });
+  const root = document.documentElement;
+  const body = document.body;
+  const width = Math.max(root.scrollWidth, body ? body.scrollWidth : 0, window.innerWidth);
+  const height = Math.max(root.scrollHeight, body ? body.scrollHeight : 0, window.innerHeight);
+  return [window.scrollX, window.scrollY, width, height, window.innerWidth, window.innerHeight];
+}
@@ -0,0 +1,81 @@
+((phase, x, y, mime, data) => {
+  const key = Symbol.for("xian.hostDrag");
+  const makeDataTransfer = () => {
+    try {
+      return new DataTransfer();
+    } catch (_) {
+      const store = new Map();
+      return {
+        dropEffect: "none",
+        effectAllowed: "all",
+        get types() {
+          return [...store.keys()];
+        },
+        getData: (type) => store.get(type) ?? "",
+        setData: (type, value) => void store.set(type, String(value)),
+        clearData: (type) => (type === undefined ? store.clear() : void store.delete(type)),
+      };
+    }
+  };
+  const fire = (type, target, dataTransfer) => {
+    const scale = window.devicePixelRatio || 1;
+    const init = {
+      bubbles: true,
+      cancelable: type !== "dragleave",
+      composed: true,
+      clientX: x / scale,
+      clientY: y / scale,
+      dataTransfer,
+    };
+    let ev;
+    try {
+      ev = new DragEvent(type, init);
+    } catch (_) {
+      ev = new MouseEvent(type, init);
+    }
+    if (ev.dataTransfer !== dataTransfer) {
+      Object.defineProperty(ev, "dataTransfer", { value: dataTransfer });
+    }
+    target.dispatchEvent(ev);
+    return ev;
+  };
+
+  if (phase === "begin") {
+    const dataTransfer = makeDataTransfer();
+    dataTransfer.setData(mime, data);
+    dataTransfer.effectAllowed = "copyMove";
+    window[key] = { dataTransfer, target: null, accepted: false };
+    return "none";
+  }
+
+  const state = window[key];
+  if (!state) return "none";
+
+  if (phase === "cancel") {
+    if (state.target) fire("dragleave", state.target, state.dataTransfer);
+    delete window[key];
+    return "none";
+  }
+
+  const scale = window.devicePixelRatio || 1;
+  const target = document.elementFromPoint(x / scale, y / scale) || document.documentElement;
+  if (target !== state.target) {
+    if (state.target) fire("dragleave", state.target, state.dataTransfer);
+    fire("dragenter", target, state.dataTransfer);
+    state.target = target;
+  }
+  state.dataTransfer.dropEffect = "copy";
+  state.accepted = fire("dragover", target, state.dataTransfer).defaultPrevented;
+  if (!state.accepted) state.dataTransfer.dropEffect = "none";
+
+  if (phase !== "drop") return state.dataTransfer.dropEffect;
+
+  delete window[key];
+  if (!state.accepted) {
+    fire("dragleave", target, state.dataTransfer);
+    return "none";
+  }
+  return fire("drop", target, state.dataTransfer).defaultPrevented
+    ? state.dataTransfer.dropEffect
+    : "none";
+})
@@ -0,0 +1,11 @@
+(selector) => {
+  const element = document.querySelector(selector);
+  if (!element) return "No element matches the selector";
+  const rect = element.getBoundingClientRect();
+  const left = Math.max(rect.left, 0);
+  const top = Math.max(rect.top, 0);
+  const right = Math.min(rect.right, window.innerWidth);
+  const bottom = Math.min(rect.bottom, window.innerHeight);
+  if (right <= left || bottom <= top) return "Element is empty or outside the viewport";
+  return [left, top, right - left, bottom - top];
+}
@@ -0,0 +1,5 @@
+((x, y) => {
+  const scale = window.devicePixelRatio || 1;
+  const target = document.elementFromPoint(x / scale, y / scale);
+  return !!target && target !== document.documentElement && target !== document.body;
+})
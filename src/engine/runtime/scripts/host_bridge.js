@@ -0,0 +1,173 @@
+(send) => {
+  const MAX_BINARY_BYTES = 16 * 1024 * 1024;
+  const host = new EventTarget();
+  const pending = new Map();
+  const pendingTextures = new Map();
+  const streams = new Map();
+  let nextSeq = 1;
+
+  const call = (name, args) => {
+    name = String(name);
+    if (name.length === 0 || name.includes("\n")) {
+      return Promise.reject(new TypeError(`invalid host function name: ${JSON.stringify(name)}`));
+    }
+    let json;
+    try {
+      json = JSON.stringify(args === undefined ? null : args);
+    } catch (error) {
+      return Promise.reject(error);
+    }
+    return new Promise((resolve, reject) => {
+      const seq = nextSeq++;
+      pending.set(seq, { resolve, reject });
+      send(`host-call:${seq}:${name}\n${json}`);
+    });
+  };
+
+  const settle = (seq, ok, payload) => {
+    const entry = pending.get(seq);
+    if (!entry) return;
+    pending.delete(seq);
+    if (!ok) {
+      entry.reject(new Error(payload));
+      return;
+    }
+    try {
+      entry.resolve(payload === "" ? undefined : JSON.parse(payload));
+    } catch (error) {
+      entry.reject(error);
+    }
+  };
+
+  const toBase64 = (bytes) => {
+    let binary = "";
+    for (let i = 0; i < bytes.length; i += 0x8000) {
+      binary += String.fromCharCode.apply(null, bytes.subarray(i, i + 0x8000));
+    }
+    return btoa(binary);
+  };
+
+  const sendBinary = (channel, data) => {
+    let bytes;
+    if (data instanceof ArrayBuffer) {
+      bytes = new Uint8Array(data);
+    } else if (ArrayBuffer.isView(data)) {
+      bytes = new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    } else {
+      throw new TypeError("sendBinary expects an ArrayBuffer or a typed array");
+    }
+    if (bytes.byteLength > MAX_BINARY_BYTES) {
+      throw new RangeError(`binary message exceeds ${MAX_BINARY_BYTES} bytes`);
+    }
+    send(`binary:${channel >>> 0}:${toBase64(bytes)}`);
+  };
+
+  const fromBase64 = (base64) => {
+    const binary = atob(base64);
+    const bytes = new Uint8Array(binary.length);
+    for (let i = 0; i < binary.length; i++) bytes[i] = binary.charCodeAt(i);
+    return bytes;
+  };
+
+  const receiveBinary = (channel, base64) => {
+    const bytes = fromBase64(base64);
+    const event = new Event("binary");
+    Object.defineProperties(event, {
+      channel: { value: channel, enumerable: true },
+      data: { value: bytes.buffer, enumerable: true },
+    });
+    host.dispatchEvent(event);
+  };
+
+  const getTexture = (name) => {
+    name = String(name);
+    if (name.length === 0 || name.includes("\n")) {
+      return Promise.reject(new TypeError(`invalid texture name: ${JSON.stringify(name)}`));
+    }
+    return new Promise((resolve, reject) => {
+      const seq = nextSeq++;
+      pendingTextures.set(seq, { resolve, reject });
+      send(`texture:${seq}:${name}`);
+    });
+  };
+
+  const settleTexture = (seq, width, height, base64, error) => {
+    const entry = pendingTextures.get(seq);
+    if (!entry) return;
+    pendingTextures.delete(seq);
+    if (base64 === null) {
+      entry.reject(new Error(error));
+      return;
+    }
+    const pixels = new Uint8ClampedArray(fromBase64(base64).buffer);
+    createImageBitmap(new ImageData(pixels, width, height)).then(entry.resolve, entry.reject);
+  };
+
+  const openStream = (id) => {
+    id = id >>> 0;
+    const existing = streams.get(id);
+    if (existing) return existing.stream;
+    const state = { stream: new EventTarget(), latest: null, requested: 0, shown: 0 };
+    const stream = state.stream;
+    Object.defineProperties(stream, {
+      id: { value: id, enumerable: true },
+      latest: { get: () => state.latest, enumerable: true },
+    });
+    stream.attach = (canvas) => {
+      const context = canvas.getContext("2d");
+      const draw = (event) => {
+        if (canvas.width !== event.frame.width || canvas.height !== event.frame.height) {
+          canvas.width = event.frame.width;
+          canvas.height = event.frame.height;
+        }
+        context.drawImage(event.frame, 0, 0);
+      };
+      stream.addEventListener("frame", draw);
+      return () => stream.removeEventListener("frame", draw);
+    };
+    stream.close = () => {
+      if (streams.get(id) !== state) return;
+      streams.delete(id);
+      state.latest?.close?.();
+      state.latest = null;
+      send(`stream-close:${id}`);
+    };
+    streams.set(id, state);
+    send(`stream-open:${id}`);
+    return stream;
+  };
+
+  const receiveFrame = (id, width, height, base64) => {
+    const state = streams.get(id);
+    if (!state) return;
+    const seq = ++state.requested;
+    const pixels = new Uint8ClampedArray(fromBase64(base64).buffer);
+    createImageBitmap(new ImageData(pixels, width, height)).then((frame) => {
+      if (streams.get(id) !== state || seq < state.shown) {
+        frame.close?.();
+        return;
+      }
+      state.shown = seq;
+      state.latest?.close?.();
+      state.latest = frame;
+      const event = new Event("frame");
+      Object.defineProperty(event, "frame", { value: frame, enumerable: true });
+      state.stream.dispatchEvent(event);
+    });
+  };
+
+  host.call = call;
+  host.sendBinary = sendBinary;
+  host.getTexture = getTexture;
+  host.openStream = openStream;
+  Object.defineProperty(window, "xianHost", {
+    value: Object.freeze(host),
+    configurable: false,
+    enumerable: false,
+    writable: false,
+  });
+  Object.defineProperty(window, Symbol.for("xian.hostSettle"), { value: settle });
+  Object.defineProperty(window, Symbol.for("xian.binaryReceive"), { value: receiveBinary });
+  Object.defineProperty(window, Symbol.for("xian.textureSettle"), { value: settleTexture });
+  Object.defineProperty(window, Symbol.for("xian.streamFrame"), { value: receiveFrame });
+}
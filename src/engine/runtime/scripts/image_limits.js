@@ -0,0 +1,63 @@
+((send, maxWidth, maxHeight, maxPixels) => {
+  const copies = new WeakMap();
+
+  const scaleFor = (width, height) => {
+    let scale = 1;
+    if (maxWidth && width > maxWidth) scale = Math.min(scale, maxWidth / width);
+    if (maxHeight && height > maxHeight) scale = Math.min(scale, maxHeight / height);
+    if (maxPixels && width * height > maxPixels) {
+      scale = Math.min(scale, Math.sqrt(maxPixels / (width * height)));
+    }
+    return scale;
+  };
+
+  const downscale = (img) => {
+    if (copies.get(img) === img.src) return;
+    const width = img.naturalWidth;
+    const height = img.naturalHeight;
+    if (!width || !height) return;
+    const scale = scaleFor(width, height);
+    if (scale >= 1) return;
+
+    const source = img.currentSrc || img.src;
+    const visibility = img.style.visibility;
+    const restore = () => {
+      img.style.visibility = visibility;
+    };
+    img.style.visibility = "hidden";
+
+    const canvas = document.createElement("canvas");
+    canvas.width = Math.max(1, Math.floor(width * scale));
+    canvas.height = Math.max(1, Math.floor(height * scale));
+    try {
+      canvas.getContext("2d").drawImage(img, 0, 0, canvas.width, canvas.height);
+      canvas.toBlob((blob) => {
+        if (!blob) {
+          restore();
+          return;
+        }
+        if (!img.hasAttribute("width") && !img.hasAttribute("height")) {
+          img.width = width;
+          img.height = height;
+        }
+        const url = URL.createObjectURL(blob);
+        img.addEventListener("load", () => URL.revokeObjectURL(url), { once: true });
+        img.removeAttribute("srcset");
+        img.src = url;
+        copies.set(img, img.src);
+        restore();
+        send(`image-downscaled:${width},${height},${source}`);
+      });
+    } catch (_) {
+      restore();
+    }
+  };
+
+  document.addEventListener(
+    "load",
+    (event) => {
+      if (event.target instanceof HTMLImageElement) downscale(event.target);
+    },
+    true,
+  );
+})
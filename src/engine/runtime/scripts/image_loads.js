@@ -0,0 +1,23 @@
+(send) => {
+  const MAX_REPORTS = 4096;
+  const reported = new WeakMap();
+  let reports = 0;
+
+  document.addEventListener(
+    "load",
+    (event) => {
+      const img = event.target;
+      if (!(img instanceof HTMLImageElement) || reports >= MAX_REPORTS) return;
+      const source = img.currentSrc || img.src;
+      if (!source || source.startsWith("data:") || source.startsWith("blob:")) return;
+      if (reported.get(img) === source) return;
+      const width = img.naturalWidth;
+      const height = img.naturalHeight;
+      if (!width || !height) return;
+      reported.set(img, source);
+      reports += 1;
+      send(`image-load:${width},${height},${source}`);
+    },
+    true,
+  );
+}
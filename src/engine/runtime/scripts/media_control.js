@@ -0,0 +1,30 @@
+((paused) => {
+  const key = Symbol.for("xian.pausedMedia");
+  const pausedByEngine = (window[key] ||= new Set());
+  const media = [];
+  const collect = (doc) => {
+    media.push(...doc.querySelectorAll("audio, video"));
+    for (const frame of doc.querySelectorAll("iframe")) {
+      try {
+        if (frame.contentDocument) collect(frame.contentDocument);
+      } catch (_) {}
+    }
+  };
+  collect(document);
+
+  if (paused) {
+    for (const el of media) {
+      if (!el.paused) {
+        el.pause();
+        pausedByEngine.add(el);
+      }
+    }
+  } else {
+    for (const el of pausedByEngine) {
+      if (el.isConnected) el.play().catch(() => {});
+    }
+    pausedByEngine.clear();
+  }
+
+  return [media.length, media.filter((el) => !el.paused).length];
+})
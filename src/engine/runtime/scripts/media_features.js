@@ -0,0 +1,91 @@
+((reducedMotion, forcedColors) => {
+  const key = Symbol.for("xian.mediaFeatures");
+  if (window[key]) {
+    window[key].set(reducedMotion, forcedColors);
+    return;
+  }
+
+  const nativeMatchMedia = window.matchMedia;
+  const FEATURE = /\(\s*(prefers-reduced-motion|forced-colors)\s*(?::\s*([a-z-]+)\s*)?\)/gi;
+  const MATCH_ALL = "(min-width: 0px)";
+  const MATCH_NONE = "(max-width: -1px)";
+  const REDUCED_MOTION_CSS =
+    "*, *::before, *::after { animation-duration: 0s !important; " +
+    "animation-delay: 0s !important; animation-iteration-count: 1 !important; " +
+    "transition-duration: 0s !important; transition-delay: 0s !important; " +
+    "scroll-behavior: auto !important; }";
+  const state = { reducedMotion: false, forcedColors: false };
+  const tracked = new Set();
+  let style = null;
+
+  const featureMatches = (feature, value) => {
+    const on = feature.toLowerCase() === "prefers-reduced-motion"
+      ? state.reducedMotion
+      : state.forcedColors;
+    if (value === undefined) return on;
+    switch (value.toLowerCase()) {
+      case "reduce":
+      case "active":
+        return on;
+      case "no-preference":
+      case "none":
+        return !on;
+      default:
+        return false;
+    }
+  };
+  const rewrite = (query) =>
+    query.replace(FEATURE, (_, feature, value) =>
+      featureMatches(feature, value) ? MATCH_ALL : MATCH_NONE,
+    );
+  const evaluate = (query) => nativeMatchMedia.call(window, rewrite(query)).matches;
+
+  window.matchMedia = function (query) {
+    const text = String(query);
+    const list = nativeMatchMedia.call(window, rewrite(text));
+    FEATURE.lastIndex = 0;
+    if (!FEATURE.test(text)) return list;
+
+    tracked.add({ list: new WeakRef(list), text, matches: list.matches });
+    Object.defineProperty(list, "media", { value: text });
+    Object.defineProperty(list, "matches", { get: () => evaluate(text) });
+    return list;
+  };
+
+  const applyStyle = () => {
+    if (!state.reducedMotion) {
+      style?.remove();
+      return;
+    }
+    if (!style) {
+      style = document.createElement("style");
+      style.textContent = REDUCED_MOTION_CSS;
+    }
+    const root = document.head || document.documentElement;
+    if (!root) {
+      document.addEventListener("DOMContentLoaded", applyStyle, { once: true });
+      return;
+    }
+    if (style.parentNode !== root) root.appendChild(style);
+  };
+
+  const set = (nextReducedMotion, nextForcedColors) => {
+    state.reducedMotion = !!nextReducedMotion;
+    state.forcedColors = !!nextForcedColors;
+    applyStyle();
+    for (const entry of tracked) {
+      const list = entry.list.deref();
+      if (!list) {
+        tracked.delete(entry);
+        continue;
+      }
+      const matches = evaluate(entry.text);
+      if (matches === entry.matches) continue;
+      entry.matches = matches;
+      list.dispatchEvent(new MediaQueryListEvent("change", { media: entry.text, matches }));
+    }
+  };
+
+  Object.defineProperty(window, key, { value: { set } });
+  set(reducedMotion, forcedColors);
+})
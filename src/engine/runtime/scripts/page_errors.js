@@ -0,0 +1,92 @@
+(send) => {
+  const MAX_REPORTS = 100;
+  let reports = 0;
+
+  const notifyHost = (message) => {
+    if (reports >= MAX_REPORTS) return;
+    reports += 1;
+    send(message);
+  };
+  const describe = (value) => {
+    if (value instanceof Error) return value.stack ? `${value}\n${value.stack}` : String(value);
+    try {
+      return typeof value === "object" && value !== null ? JSON.stringify(value) : String(value);
+    } catch (_) {
+      return String(value);
+    }
+  };
+  const absolute = (url) => {
+    try {
+      return new URL(url, document.baseURI).href;
+    } catch (_) {
+      return String(url);
+    }
+  };
+  const resourceStatus = (url) => {
+    try {
+      const entries = performance.getEntriesByName(url, "resource");
+      return entries.at(-1)?.responseStatus ?? 0;
+    } catch (_) {
+      return 0;
+    }
+  };
+  const reportResource = (url, status, detail) => {
+    if (!url) return;
+    notifyHost(`resource-error:${status}:${url}\n${detail}`);
+  };
+
+  window.addEventListener(
+    "error",
+    (ev) => {
+      const target = ev.target;
+      if (target && target !== window) {
+        const url = target.currentSrc || target.src || target.href || "";
+        reportResource(url, resourceStatus(url), target.localName || "resource");
+        return;
+      }
+      const message = ev.error !== undefined ? describe(ev.error) : ev.message;
+      notifyHost(`page-error:${ev.lineno || 0}:${ev.colno || 0}:${ev.filename || location.href}\n${message}`);
+    },
+    true,
+  );
+  window.addEventListener("unhandledrejection", (ev) => {
+    notifyHost(`page-error:0:0:${location.href}\nUnhandled promise rejection: ${describe(ev.reason)}`);
+  });
+
+  const fetch = window.fetch;
+  if (typeof fetch === "function") {
+    window.fetch = function (input, init) {
+      const url = input instanceof Request ? input.url : absolute(input);
+      return fetch.call(this, input, init).then(
+        (response) => {
+          if (!response.ok) reportResource(response.url || url, response.status, "fetch");
+          return response;
+        },
+        (err) => {
+          if (err?.name !== "AbortError") reportResource(url, 0, `fetch: ${describe(err)}`);
+          throw err;
+        },
+      );
+    };
+  }
+
+  const requests = new WeakMap();
+  const open = XMLHttpRequest.prototype.open;
+  XMLHttpRequest.prototype.open = function (method, url, ...rest) {
+    if (!requests.has(this)) {
+      this.addEventListener("abort", () => {
+        requests.get(this).aborted = true;
+      });
+      this.addEventListener("loadend", () => {
+        const request = requests.get(this);
+        if (this.status >= 400) {
+          reportResource(this.responseURL || request.url, this.status, "xhr");
+        } else if (this.status === 0 && !request.aborted) {
+          reportResource(request.url, 0, "xhr: network error");
+        }
+      });
+    }
+    requests.set(this, { url: absolute(url), aborted: false });
+    return open.call(this, method, url, ...rest);
+  };
+}
@@ -0,0 +1,107 @@
+(send) => {
+  if ("requestPointerLock" in Element.prototype) return;
+
+  let locked = null;
+  let requested = null;
+  let lastX = 0;
+  let lastY = 0;
+
+  const setLocked = (el) => {
+    if (locked === el) return;
+    const wasLocked = locked !== null;
+    locked = el;
+    if (wasLocked && !el) send("pointer-lock:0");
+    document.dispatchEvent(new Event("pointerlockchange"));
+  };
+  const fail = (message, name) => {
+    document.dispatchEvent(new Event("pointerlockerror"));
+    return new DOMException(message, name);
+  };
+
+  document.addEventListener(
+    "mousemove",
+    (ev) => {
+      if (!locked) {
+        lastX = ev.clientX;
+        lastY = ev.clientY;
+      }
+    },
+    true,
+  );
+
+  Object.defineProperty(Element.prototype, "requestPointerLock", {
+    configurable: true,
+    writable: true,
+    value: function () {
+      if (!this.isConnected) {
+        return Promise.reject(fail("Element is not connected", "WrongDocumentError"));
+      }
+      if (locked) {
+        setLocked(this);
+        return Promise.resolve();
+      }
+      if (requested) {
+        return Promise.reject(fail("A pointer lock request is pending", "InvalidStateError"));
+      }
+      return new Promise((resolve, reject) => {
+        requested = { element: this, resolve, reject };
+        send("pointer-lock-request");
+      });
+    },
+  });
+  Object.defineProperty(Document.prototype, "exitPointerLock", {
+    configurable: true,
+    writable: true,
+    value: function () {
+      setLocked(null);
+    },
+  });
+  Object.defineProperty(Document.prototype, "pointerLockElement", {
+    configurable: true,
+    get() {
+      return locked && locked.isConnected ? locked : null;
+    },
+  });
+
+  Object.defineProperty(window, Symbol.for("xian.pointerLock"), {
+    value: Object.freeze({
+      settle(allow) {
+        const request = requested;
+        requested = null;
+        if (!request) return;
+        if (allow && request.element.isConnected) {
+          setLocked(request.element);
+          request.resolve();
+          return;
+        }
+        if (allow) send("pointer-lock:0");
+        request.reject(fail("Pointer lock was not granted", "NotAllowedError"));
+      },
+      move(dx, dy) {
+        if (!locked) return;
+        if (!locked.isConnected) {
+          setLocked(null);
+          return;
+        }
+        const scale = window.devicePixelRatio || 1;
+        dx /= scale;
+        dy /= scale;
+        const ev = new MouseEvent("mousemove", {
+          bubbles: true,
+          cancelable: true,
+          composed: true,
+          clientX: lastX,
+          clientY: lastY,
+          movementX: dx,
+          movementY: dy,
+        });
+        if (ev.movementX !== dx) Object.defineProperty(ev, "movementX", { value: dx });
+        if (ev.movementY !== dy) Object.defineProperty(ev, "movementY", { value: dy });
+        locked.dispatchEvent(ev);
+      },
+      exit() {
+        setLocked(null);
+      },
+    }),
+  });
+}
@@ -0,0 +1,9 @@
+(send) => {
+  const isNetwork = (entry) => entry.name.startsWith("http:") || entry.name.startsWith("https:");
+  try {
+    new PerformanceObserver((list) => {
+      const finished = list.getEntries().filter(isNetwork).length;
+      if (finished) send(`requests-finished:${finished}`);
+    }).observe({ type: "resource" });
+  } catch (_) {}
+}
@@ -0,0 +1,19 @@
+(send) => {
+  let lastX = NaN;
+  let lastY = NaN;
+
+  const report = () => {
+    const x = window.scrollX;
+    const y = window.scrollY;
+    if (x === lastX && y === lastY) return;
+    lastX = x;
+    lastY = y;
+    send(`scroll:${x},${y}`);
+  };
+
+  window.addEventListener("scroll", report, { passive: true });
+  window.addEventListener("resize", report, { passive: true });
+  window.addEventListener("pageshow", report);
+  window.addEventListener("load", report);
+  report();
+}
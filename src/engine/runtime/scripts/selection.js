@@ -0,0 +1,32 @@
+((action) => {
+  const editable = (el) =>
+    el instanceof HTMLTextAreaElement ||
+    (el instanceof HTMLInputElement &&
+      /^(text|search|url|tel|email|password|number|)$/.test(el.type));
+  const active = document.activeElement;
+
+  if (action === "selectAll") {
+    if (editable(active)) {
+      active.select();
+    } else if (document.body) {
+      window.getSelection()?.selectAllChildren(document.body);
+    }
+    return "";
+  }
+
+  let text = "";
+  if (editable(active) && active.type !== "password") {
+    const start = active.selectionStart ?? 0;
+    const end = active.selectionEnd ?? 0;
+    text = active.value.substring(start, end);
+  } else {
+    text = window.getSelection()?.toString() ?? "";
+  }
+
+  if (action === "copy") {
+    try {
+      document.execCommand("copy");
+    } catch (_) {}
+  }
+  return text;
+})
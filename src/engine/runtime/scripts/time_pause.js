@@ -0,0 +1,60 @@
+((paused) => {
+  const key = Symbol.for("xian.timePause");
+  if (window[key]) {
+    window[key].set(paused);
+    return;
+  }
+
+  const nativeNow = performance.now.bind(performance);
+  const nativeRequestAnimationFrame = window.requestAnimationFrame;
+  const PAUSE_CSS =
+    "*, *::before, *::after { animation-play-state: paused !important; }";
+  let pausedAt = null;
+  let offset = 0;
+  let style = null;
+
+  performance.now = function () {
+    return (pausedAt ?? nativeNow()) - offset;
+  };
+  if (nativeRequestAnimationFrame) {
+    window.requestAnimationFrame = function (callback) {
+      if (typeof callback !== "function") {
+        return nativeRequestAnimationFrame.call(window, callback);
+      }
+      return nativeRequestAnimationFrame.call(window, (timestamp) =>
+        callback.call(window, timestamp - offset),
+      );
+    };
+  }
+
+  const applyStyle = () => {
+    if (pausedAt === null) {
+      style?.remove();
+      return;
+    }
+    if (!style) {
+      style = document.createElement("style");
+      style.textContent = PAUSE_CSS;
+    }
+    const root = document.head || document.documentElement;
+    if (!root) {
+      document.addEventListener("DOMContentLoaded", applyStyle, { once: true });
+      return;
+    }
+    if (style.parentNode !== root) root.appendChild(style);
+  };
+
+  const set = (next) => {
+    if (!!next === (pausedAt !== null)) return;
+    if (next) {
+      pausedAt = nativeNow();
+    } else {
+      offset += nativeNow() - pausedAt;
+      pausedAt = null;
+    }
+    applyStyle();
+  };
+
+  Object.defineProperty(window, key, { value: { set } });
+  set(paused);
+})
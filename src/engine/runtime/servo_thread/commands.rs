@@ -4,18 +4,45 @@
 //! ### 中文
 //! Servo 线程的命令处理（create/destroy/shutdown）。
 
+use std::cell::Cell;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
 
-use crate::engine::refresh::RefreshScheduler;
+use crate::engine::init_progress::{self, XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED};
+use crate::engine::refresh::{FrameCadence, GroupRefreshDriver, RefreshScheduler};
 use crate::engine::rendering::{
     GlfwSharedContext, GlfwTripleBufferContextInit, GlfwTripleBufferRenderingContext,
 };
+use crate::engine::resources;
+use crate::engine::thread_config::{self, ThreadConfig};
 use crate::engine::vsync::VsyncCallbackQueue;
 
 use super::super::command::Command;
+use super::super::image_limits::XianWebEngineImageLimits;
 use super::super::queue::CommandQueue;
+use super::super::scripts::{
+    BACKGROUND_TIMERS_SCRIPT, CONSOLE_CAPTURE_SCRIPT, HOST_BRIDGE_SCRIPT, IMAGE_LOADS_SCRIPT,
+    PAGE_ERRORS_SCRIPT, POINTER_LOCK_SCRIPT, REQUEST_COMPLETION_SCRIPT, SCROLL_TRACKER_SCRIPT,
+};
+use super::super::view_handle::render_size;
+use super::external_streams::ExternalStreams;
+use super::file_picker::FilePickerTracker;
+use super::fonts::FontRegistry;
+use super::group::ViewGroupTable;
+use super::heap_limit;
+use super::host_channel::HostChannel;
+use super::host_functions::HostFunctionTable;
+use super::image_loads::ImageLoadTracker;
+use super::input_results::InputResultTracker;
+use super::network_policy::NetworkPolicy;
+use super::permissions::PermissionTracker;
+use super::pointer_lock::PointerLock;
+use super::retired::RetiredContexts;
+use super::session::SessionTracker;
+use super::textures::SharedTextureTable;
 use super::view::{Delegate, ViewEntry};
+use super::webdriver::WebDriverEndpoint;
 
 /// ### English
 /// Drains control commands (create/destroy/shutdown) from embedder threads.
@@ -28,7 +55,17 @@ use super::view::{Delegate, ViewEntry};
 /// - `vsync_queue`: Vsync callback queue for refresh driving.
 /// - `command_queue`: Control-command queue from embedder threads.
 /// - `refresh_scheduler`: Lazily-created refresh scheduler (shared across views).
+/// - `thread_config`: Priority/affinity settings requested by the embedder, if any.
+/// - `idle`: Whether the engine is in idle mode.
 /// - `views`: Per-view entries owned by the Servo thread.
+/// - `view_groups`: View groups owned by the Servo thread.
+/// - `fonts`: Fonts registered by the embedder.
+/// - `network`: Network mode applied to every view.
+/// - `image_limits`: Image size limits applied to every view.
+/// - `image_loads`: Image URL table shared by every view delegate.
+/// - `webdriver`: WebDriver endpoint of this Servo thread.
+/// - `textures`: Embedder textures pages can fetch.
+/// - `retired`: Destroyed views' rendering contexts awaiting deferred GL deletion.
 /// - `free_view_ids`: Free-list of reusable view IDs.
 /// - `next_view_id`: Monotonic view-id allocator (used when free-list is empty).
 /// - `next_view_token`: Monotonic token allocator used to disambiguate reused IDs.
@@ -44,7 +81,17 @@ use super::view::{Delegate, ViewEntry};
 /// - `vsync_queue`：用于驱动 refresh 的 vsync 回调队列。
 /// - `command_queue`：来自宿主线程的控制命令队列。
 /// - `refresh_scheduler`：按需创建的 refresh 调度器（多 view 共享）。
+/// - `thread_config`：宿主请求的优先级/亲和性设置（若有）。
+/// - `idle`：引擎是否处于空闲模式。
 /// - `views`：由 Servo 线程持有的 per-view 条目表。
+/// - `view_groups`：由 Servo 线程持有的 view group 表。
+/// - `fonts`：宿主注册的字体。
+/// - `network`：应用于每个 view 的网络模式。
+/// - `image_limits`：应用于每个 view 的图片尺寸限制。
+/// - `image_loads`：所有 view delegate 共享的图片 URL 表。
+/// - `webdriver`：该 Servo 线程的 WebDriver 端点。
+/// - `textures`：页面可获取的宿主纹理。
+/// - `retired`：等待延迟 GL 删除的已销毁 view 渲染上下文。
 /// - `free_view_ids`：可复用 view ID 的 free-list。
 /// - `next_view_id`：单调递增的 view-id 分配器（free-list 为空时使用）。
 /// - `next_view_token`：单调递增 token 分配器，用于区分 ID 复用。
@@ -55,7 +102,17 @@ pub(super) fn drain_commands(
     vsync_queue: &Arc<VsyncCallbackQueue>,
     command_queue: &CommandQueue,
     refresh_scheduler: &mut Option<Arc<RefreshScheduler>>,
+    thread_config: &mut Option<ThreadConfig>,
+    idle: &mut bool,
     views: &mut Vec<Option<ViewEntry>>,
+    view_groups: &mut ViewGroupTable,
+    fonts: &mut FontRegistry,
+    network: &mut NetworkPolicy,
+    image_limits: &mut XianWebEngineImageLimits,
+    image_loads: &Rc<ImageLoadTracker>,
+    webdriver: &mut WebDriverEndpoint,
+    textures: &Rc<SharedTextureTable>,
+    retired: &mut RetiredContexts,
     free_view_ids: &mut Vec<u32>,
     next_view_id: &mut u32,
     next_view_token: &mut u64,
@@ -70,58 +127,150 @@ pub(super) fn drain_commands(
                 input_queue,
                 load_url,
                 pending,
+                events,
+                strings,
+                binary,
                 target_fps,
+                group,
+                render_scale,
+                color_format,
+                mipmaps,
+                orphaning_resize,
+                vulkan_exports,
                 unsafe_no_consumer_fence,
                 unsafe_no_producer_fence,
                 response,
             } => {
-                let refresh_scheduler_for_view = if target_fps == 0 {
+                let group_refresh_driver = if group == 0 {
+                    None
+                } else {
+                    let Some(driver) = view_groups.refresh_driver(group) else {
+                        response.send(Err(format!("Unknown view group {group}")));
+                        continue;
+                    };
+                    Some(driver)
+                };
+
+                let refresh_scheduler_for_view = if group_refresh_driver.is_some() {
                     None
                 } else {
-                    Some(
-                        refresh_scheduler
-                            .get_or_insert_with(RefreshScheduler::new)
-                            .clone(),
-                    )
+                    Some(shared_refresh_scheduler(
+                        refresh_scheduler,
+                        *thread_config,
+                        *idle,
+                    ))
+                };
+
+                let channel = match HostChannel::new() {
+                    Ok(channel) => channel,
+                    Err(err) => {
+                        response.send(Err(err));
+                        continue;
+                    }
                 };
 
                 let rendering_context =
                     match GlfwTripleBufferRenderingContext::new(GlfwTripleBufferContextInit {
                         shared_ctx: shared_ctx.clone(),
-                        initial_size,
+                        initial_size: render_size(initial_size, render_scale),
                         shared,
                         vsync_queue: vsync_queue.clone(),
                         target_fps,
+                        color_format,
+                        mipmaps,
+                        orphaning_resize,
                         unsafe_no_consumer_fence,
                         unsafe_no_producer_fence,
                         refresh_scheduler: refresh_scheduler_for_view,
+                        group_refresh_driver,
+                        vulkan_exports,
                     }) {
                         Ok(ctx) => Rc::new(ctx),
                         Err(err) => {
-                            let _ = response.send(Err(err));
+                            response.send(Err(err));
                             continue;
                         }
                     };
 
-                let delegate = Rc::new(Delegate::new(rendering_context.clone()));
+                let token = {
+                    let token = *next_view_token;
+                    *next_view_token = (*next_view_token)
+                        .checked_add(1)
+                        .expect("view token exhausted");
+                    token
+                };
 
+                let session = Rc::new(SessionTracker::default());
+                let permissions = Rc::new(PermissionTracker::default());
+                let pointer_lock = Rc::new(PointerLock::default());
+                let file_pickers = Rc::new(FilePickerTracker::default());
+                let console_capture = Rc::new(Cell::new(false));
+                let host_functions = Rc::new(HostFunctionTable::default());
+                let external_streams = Rc::new(ExternalStreams::default());
+                let input_results = Rc::new(InputResultTracker::default());
+                let shared_render_scale = Rc::new(Cell::new(render_scale));
+                let delegate = Rc::new(Delegate::new(
+                    rendering_context.clone(),
+                    events.clone(),
+                    strings.clone(),
+                    binary.clone(),
+                    session.clone(),
+                    permissions.clone(),
+                    pointer_lock.clone(),
+                    file_pickers.clone(),
+                    console_capture.clone(),
+                    host_functions.clone(),
+                    external_streams.clone(),
+                    input_results.clone(),
+                    network.gate().clone(),
+                    image_loads.clone(),
+                    token,
+                    textures.clone(),
+                    shared_render_scale.clone(),
+                    channel.clone(),
+                ));
+
+                let user_content = Rc::new(servo::UserContentManager::new(servo));
+                user_content.add_script(channel.user_script(POINTER_LOCK_SCRIPT));
+                user_content.add_script(Rc::new(servo::UserScript::new(
+                    BACKGROUND_TIMERS_SCRIPT.to_string(),
+                    None,
+                )));
+                for script in [
+                    SCROLL_TRACKER_SCRIPT,
+                    CONSOLE_CAPTURE_SCRIPT,
+                    PAGE_ERRORS_SCRIPT,
+                    REQUEST_COMPLETION_SCRIPT,
+                    IMAGE_LOADS_SCRIPT,
+                    HOST_BRIDGE_SCRIPT,
+                ] {
+                    user_content.add_script(channel.user_script(script));
+                }
+                let image_limits_script = image_limits
+                    .user_script(&channel.sender())
+                    .map(|source| Rc::new(servo::UserScript::new(source, None)));
+                let image_limits_id = image_limits_script.as_ref().map(|script| script.id());
+                if let Some(script) = image_limits_script {
+                    user_content.add_script(script);
+                }
+                for stylesheet in fonts.stylesheets() {
+                    user_content.add_stylesheet(stylesheet.clone());
+                }
                 let servo_webview = servo::WebViewBuilder::new(servo, rendering_context.clone())
                     .delegate(delegate)
+                    .user_content_manager(user_content.clone())
+                    .hidpi_scale_factor(euclid::Scale::new(render_scale))
                     .build();
                 servo_webview.show();
+                if token == 1 {
+                    init_progress::report(XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED);
+                }
 
                 let id = free_view_ids.pop().unwrap_or_else(|| {
                     let id = *next_view_id;
                     *next_view_id = (*next_view_id).checked_add(1).expect("view id exhausted");
                     id
                 });
-                let token = {
-                    let token = *next_view_token;
-                    *next_view_token = (*next_view_token)
-                        .checked_add(1)
-                        .expect("view token exhausted");
-                    token
-                };
 
                 let index = id as usize;
                 if index >= views.len() {
@@ -136,23 +285,132 @@ pub(super) fn drain_commands(
                     resize,
                     load_url,
                     pending,
+                    events,
+                    strings,
+                    binary,
+                    session,
+                    permissions,
+                    pointer_lock,
+                    file_pickers,
+                    console_capture,
+                    host_functions,
+                    external_streams,
+                    input_results,
+                    user_content,
                     initial_size,
+                    shared_render_scale,
+                    group,
+                    channel,
+                    image_limits_id,
                 ));
+                if group != 0 {
+                    view_groups.add_member(group, id);
+                }
 
-                let _ = response.send(Ok((id, token)));
+                response.send(Ok((id, token)));
             }
             Command::DestroyView { id, token } => {
                 let index = id as usize;
                 if let Some(slot) = views.get_mut(index)
                     && slot.as_ref().is_some_and(|entry| entry.token == token)
                 {
-                    *slot = None;
+                    if let Some(entry) = slot.take() {
+                        if entry.group != 0 {
+                            view_groups.remove_member(entry.group, id);
+                        }
+                        retired.retire(entry.into_rendering_context(), Instant::now());
+                    }
                     free_view_ids.push(id);
                     while views.last().is_some_and(|slot| slot.is_none()) {
                         views.pop();
                     }
                 }
             }
+            Command::View { id, token, command } => {
+                if let Some(entry) = views.get_mut(id as usize).and_then(Option::as_mut)
+                    && entry.token == token
+                {
+                    entry.apply_command(command);
+                }
+            }
+            Command::CreateViewGroup {
+                target_fps,
+                fps_denominator,
+                response,
+            } => {
+                let scheduler = shared_refresh_scheduler(refresh_scheduler, *thread_config, *idle);
+                let driver: Rc<dyn servo::RefreshDriver> = if target_fps == 0 {
+                    GroupRefreshDriver::new_vsync(vsync_queue.clone(), scheduler)
+                } else {
+                    let Some(cadence) = FrameCadence::new(target_fps, fps_denominator) else {
+                        response.send(Err(format!(
+                            "Invalid frame rate {target_fps}/{fps_denominator}"
+                        )));
+                        continue;
+                    };
+                    GroupRefreshDriver::new_fixed_interval(scheduler, cadence)
+                };
+                let result = view_groups
+                    .create(driver)
+                    .ok_or_else(|| "View group id exhausted".to_string());
+                let _ = response.send(result);
+            }
+            Command::SetResourceReader { reader } => resources::set_resource_reader(reader),
+            Command::RegisterFont { family, data } => {
+                let stylesheet = fonts.register(&family, &data);
+                for entry in views.iter().filter_map(Option::as_ref) {
+                    entry.add_shared_stylesheet(stylesheet.clone());
+                }
+            }
+            Command::SetImageLimits { limits } => {
+                *image_limits = limits;
+                for entry in views.iter_mut().flatten() {
+                    entry.set_image_limits(limits);
+                }
+            }
+            Command::SetNetworkMode { mode } => network.set_mode(mode),
+            Command::RegisterTexture {
+                name,
+                texture,
+                width,
+                height,
+                flip_y,
+            } => textures.register(name, texture, width, height, flip_y),
+            Command::SetHttpCacheEnabled { enabled } => {
+                let mut preferences = servo::prefs::get().clone();
+                preferences.network_http_cache_disabled = !enabled;
+                servo::prefs::set(preferences);
+            }
+            Command::SetHeapLimit { limit_mb } => heap_limit::apply(limit_mb),
+            Command::ClearHttpCache => servo.network_manager().clear_cache(),
+            Command::EnableWebDriver { port, response } => {
+                let _ = response.send(webdriver.start(port));
+            }
+            Command::SetIdle {
+                idle: next_idle,
+                release_memory,
+            } => {
+                *idle = next_idle;
+                if let Some(scheduler) = refresh_scheduler {
+                    scheduler.set_suspended(next_idle);
+                }
+                if next_idle && release_memory {
+                    release_memory_pressure(servo, views, free_view_ids, false);
+                }
+            }
+            Command::MemoryPressure { critical } => {
+                release_memory_pressure(servo, views, free_view_ids, critical);
+            }
+            Command::SetThreadConfig { config } => {
+                thread_config::apply_to_current_thread(config);
+                if let Some(scheduler) = refresh_scheduler {
+                    scheduler.set_thread_config(config);
+                }
+                *thread_config = Some(config);
+            }
+            Command::DestroyViewGroup { id } => {
+                view_groups.destroy(id);
+            }
             Command::Shutdown => {
                 command_queue.close();
                 return true;
@@ -162,3 +420,76 @@ pub(super) fn drain_commands(
 
     false
 }
+
+/// ### English
+/// Releases what the Servo thread can give back under memory pressure: every view's spare slot
+/// textures and spare table capacity, plus Servo's in-memory HTTP cache when `critical`.
+///
+/// Servo's embedding API exposes no image-cache purge or garbage-collection hook, so decoded
+/// images and script heaps are left to Servo's own eviction.
+///
+/// #### Parameters
+/// - `servo`: Servo instance owned by the Servo thread.
+/// - `views`: Per-view entries owned by the Servo thread.
+/// - `free_view_ids`: Free-list of reusable view IDs.
+/// - `critical`: Whether the pressure is critical.
+///
+/// ### 中文
+/// 释放 Servo 线程在内存压力下可以交还的内容：每个 view 的备用槽位纹理与表的多余容量；`critical` 时还会清空
+/// Servo 的内存 HTTP 缓存。
+///
+/// Servo 的嵌入 API 没有提供图片缓存清理或垃圾回收钩子，因此解码后的图片与脚本堆交由 Servo 自行回收。
+///
+/// #### 参数
+/// - `servo`：Servo 线程持有的 Servo 实例。
+/// - `views`：由 Servo 线程持有的 per-view 条目表。
+/// - `free_view_ids`：可复用 view ID 的 free-list。
+/// - `critical`：是否为严重压力。
+fn release_memory_pressure(
+    servo: &servo::Servo,
+    views: &mut Vec<Option<ViewEntry>>,
+    free_view_ids: &mut Vec<u32>,
+    critical: bool,
+) {
+    for entry in views.iter().filter_map(Option::as_ref) {
+        entry.release_spare_textures();
+    }
+    views.shrink_to_fit();
+    free_view_ids.shrink_to_fit();
+    if critical {
+        servo.network_manager().clear_cache();
+    }
+}
+
+/// ### English
+/// Returns the shared refresh scheduler, creating it (with the current thread settings and idle
+/// state) on first use.
+///
+/// #### Parameters
+/// - `refresh_scheduler`: Lazily-created refresh scheduler slot.
+/// - `thread_config`: Priority/affinity settings to apply to a newly created scheduler thread.
+/// - `idle`: Whether a newly created scheduler starts suspended (engine idle).
+///
+/// ### 中文
+/// 返回共享的 refresh 调度器；首次使用时创建（并应用当前线程设置与空闲状态）。
+///
+/// #### 参数
+/// - `refresh_scheduler`：按需创建的 refresh 调度器槽位。
+/// - `thread_config`：应用到新建调度线程的优先级/亲和性设置。
+/// - `idle`：新建的调度器是否以挂起状态启动（引擎空闲）。
+fn shared_refresh_scheduler(
+    refresh_scheduler: &mut Option<Arc<RefreshScheduler>>,
+    thread_config: Option<ThreadConfig>,
+    idle: bool,
+) -> Arc<RefreshScheduler> {
+    refresh_scheduler
+        .get_or_insert_with(|| {
+            let scheduler = RefreshScheduler::new();
+            if let Some(config) = thread_config {
+                scheduler.set_thread_config(config);
+            }
+            scheduler.set_suspended(idle);
+            scheduler
+        })
+        .clone()
+}
@@ -0,0 +1,121 @@
+//! ### English
+//! Servo-thread side of host → page frame streams (`xianHost.openStream(id)`).
+//!
+//! The embedder renders into its own texture, inserts a `GLsync` and pushes the frame; the Servo
+//! thread waits on that fence on the GPU, reads the texture back and hands the page an
+//! `ImageBitmap`. This is the triple buffer's fence discipline in reverse: the embedder produces,
+//! the engine consumes and reports when the texture may be rendered into again.
+//!
+//! ### 中文
+//! 宿主 → 页面帧流（`xianHost.openStream(id)`）的 Servo 线程侧实现。
+//!
+//! 宿主渲染到自己的纹理、插入 `GLsync` 并推送该帧；Servo 线程在 GPU 上等待该 fence、读回纹理并交给页面一个
+//! `ImageBitmap`。这与三缓冲的 fence 规则方向相反：宿主生产，引擎消费，并在纹理可再次渲染时通知宿主。
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::engine::base64;
+use crate::engine::rendering::GlfwSharedContext;
+
+/// ### English
+/// Streams the current document has opened, shared between a view's delegate and its entry.
+///
+/// ### 中文
+/// 当前文档已打开的流，在 view 的 delegate 与条目之间共享。
+#[derive(Default)]
+pub(super) struct ExternalStreams {
+    /// ### English
+    /// IDs of open streams.
+    ///
+    /// ### 中文
+    /// 已打开流的 ID。
+    open: RefCell<HashSet<u32>>,
+}
+
+impl ExternalStreams {
+    /// ### English
+    /// Handles a `stream-open:<id>` / `stream-close:<id>` message from the page.
+    ///
+    /// #### Parameters
+    /// - `id`: Stream ID text.
+    /// - `open`: Whether the page opened (`true`) or closed (`false`) the stream.
+    ///
+    /// ### 中文
+    /// 处理页面发送的 `stream-open:<id>` / `stream-close:<id>` 消息。
+    ///
+    /// #### 参数
+    /// - `id`：流 ID 文本。
+    /// - `open`：页面是打开（`true`）还是关闭（`false`）该流。
+    pub(super) fn set_open(&self, id: &str, open: bool) {
+        let Ok(id) = id.parse::<u32>() else {
+            return;
+        };
+        let mut streams = self.open.borrow_mut();
+        if open {
+            streams.insert(id);
+        } else {
+            streams.remove(&id);
+        }
+    }
+
+    /// ### English
+    /// Forgets every open stream (a new document is loading).
+    ///
+    /// ### 中文
+    /// 清除所有已打开的流（新文档正在加载）。
+    pub(super) fn clear(&self) {
+        self.open.borrow_mut().clear();
+    }
+
+    /// ### English
+    /// Delivers a frame to the page if it has the stream open. The fence is always consumed.
+    /// Returns whether the frame reached the page.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: Target WebView.
+    /// - `shared_ctx`: Shared GL context to read the texture back on.
+    /// - `stream_id`: Stream the frame belongs to.
+    /// - `texture`: Embedder GL texture name.
+    /// - `width`: Frame width in pixels.
+    /// - `height`: Frame height in pixels.
+    /// - `fence`: Embedder `GLsync` signaled after rendering the frame (`0` = none).
+    ///
+    /// ### 中文
+    /// 若页面已打开该流，则将帧投递给页面。fence 总会被消费。返回该帧是否到达页面。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：目标 WebView。
+    /// - `shared_ctx`：用于读回纹理的共享 GL 上下文。
+    /// - `stream_id`：帧所属的流。
+    /// - `texture`：宿主 GL 纹理名。
+    /// - `width`：帧宽度（像素）。
+    /// - `height`：帧高度（像素）。
+    /// - `fence`：宿主渲染该帧后创建的 `GLsync`（`0` 表示无）。
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn push(
+        &self,
+        servo_webview: &servo::WebView,
+        shared_ctx: &GlfwSharedContext,
+        stream_id: u32,
+        texture: u32,
+        width: u32,
+        height: u32,
+        fence: u64,
+    ) -> bool {
+        if !self.open.borrow().contains(&stream_id) {
+            shared_ctx.delete_fence(fence);
+            return false;
+        }
+        let Some(pixels) = shared_ctx.read_texture(texture, width, height, true, fence) else {
+            return false;
+        };
+
+        let script = format!(
+            "window[Symbol.for(\"xian.streamFrame\")]?.({stream_id}, {width}, {height}, \"{}\")",
+            base64::encode(&pixels)
+        );
+        servo_webview.evaluate_javascript(script, |_| {});
+        true
+    }
+}
@@ -0,0 +1,111 @@
+//! ### English
+//! Servo-thread file chooser requests waiting for the embedder's answer.
+//!
+//! ### 中文
+//! 在 Servo 线程上等待宿主应答的文件选择请求。
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// ### English
+/// Open file choosers of one view, shared between its delegate and its entry.
+///
+/// Choosers still open when the view is destroyed are dismissed.
+///
+/// ### 中文
+/// 单个 view 当前打开的文件选择器，在其 delegate 与条目之间共享。
+///
+/// view 销毁时仍未关闭的选择器会被取消。
+pub(super) struct FilePickerTracker {
+    /// ### English
+    /// Next request ID handed to the embedder (starts at 1).
+    ///
+    /// ### 中文
+    /// 下一个交给宿主的请求 ID（从 1 开始）。
+    next_id: Cell<u64>,
+    /// ### English
+    /// Open choosers keyed by request ID.
+    ///
+    /// ### 中文
+    /// 按请求 ID 索引的已打开选择器。
+    pending: RefCell<HashMap<u64, servo::FilePicker>>,
+}
+
+impl Default for FilePickerTracker {
+    /// ### English
+    /// Creates an empty tracker whose first request ID is 1.
+    ///
+    /// ### 中文
+    /// 创建一个空的跟踪器，首个请求 ID 为 1。
+    fn default() -> Self {
+        Self {
+            next_id: Cell::new(1),
+            pending: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl FilePickerTracker {
+    /// ### English
+    /// Stores a chooser until the embedder answers it, and returns its request ID.
+    ///
+    /// #### Parameters
+    /// - `picker`: Servo file chooser.
+    ///
+    /// ### 中文
+    /// 保存选择器直至宿主应答，并返回其请求 ID。
+    ///
+    /// #### 参数
+    /// - `picker`：Servo 文件选择器。
+    pub(super) fn insert(&self, picker: servo::FilePicker) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1).max(1));
+        self.pending.borrow_mut().insert(id, picker);
+        id
+    }
+
+    /// ### English
+    /// Answers an open chooser; an empty `paths` dismisses it. Unknown IDs are ignored.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID from the `FILE_PICKER` event.
+    /// - `paths`: Selected files (only the first is used for single-file inputs).
+    ///
+    /// ### 中文
+    /// 应答一个已打开的选择器；`paths` 为空时取消选择。未知 ID 会被忽略。
+    ///
+    /// #### 参数
+    /// - `request_id`：`FILE_PICKER` 事件中的请求 ID。
+    /// - `paths`：所选文件（单文件输入只使用第一个）。
+    pub(super) fn respond(&self, request_id: u64, paths: &[PathBuf]) {
+        let Some(mut picker) = self.pending.borrow_mut().remove(&request_id) else {
+            return;
+        };
+        if paths.is_empty() {
+            picker.dismiss();
+            return;
+        }
+
+        let paths = if picker.allow_select_multiple() {
+            paths
+        } else {
+            &paths[..1]
+        };
+        picker.select(paths);
+        picker.submit();
+    }
+}
+
+impl Drop for FilePickerTracker {
+    /// ### English
+    /// Dismisses every chooser still open when the view goes away.
+    ///
+    /// ### 中文
+    /// view 销毁时取消所有仍处于打开状态的选择器。
+    fn drop(&mut self) {
+        for (_, picker) in self.pending.get_mut().drain() {
+            picker.dismiss();
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! ### English
+//! Engine-wide fonts registered from embedder memory.
+//!
+//! Servo has no API for adding fonts from memory, so every font becomes an `@font-face` rule with a
+//! `data:` URL, installed as a user stylesheet on every view (existing and future).
+//!
+//! ### 中文
+//! 由宿主内存注册的引擎级字体。
+//!
+//! Servo 没有从内存添加字体的 API，因此每个字体会被转换为带 `data:` URL 的 `@font-face` 规则，
+//! 并作为用户样式表安装到每个 view（现有的与之后创建的）上。
+
+use std::rc::Rc;
+
+use url::Url;
+
+use crate::engine::base64;
+
+/// ### English
+/// Font stylesheets registered so far (Servo thread only).
+///
+/// ### 中文
+/// 目前已注册的字体样式表（仅 Servo 线程持有）。
+#[derive(Default)]
+pub(super) struct FontRegistry {
+    /// ### English
+    /// One `@font-face` stylesheet per registered font, in registration order.
+    ///
+    /// ### 中文
+    /// 每个已注册字体对应一个 `@font-face` 样式表，按注册顺序排列。
+    stylesheets: Vec<Rc<servo::UserStyleSheet>>,
+}
+
+impl FontRegistry {
+    /// ### English
+    /// Builds the stylesheet for one font, remembers it, and returns it for installation.
+    ///
+    /// #### Parameters
+    /// - `family`: CSS font-family name.
+    /// - `data`: Font file bytes (TTF/OTF/WOFF/WOFF2).
+    ///
+    /// ### 中文
+    /// 为单个字体构建样式表，记录下来并返回以便安装。
+    ///
+    /// #### 参数
+    /// - `family`：CSS font-family 名称。
+    /// - `data`：字体文件字节（TTF/OTF/WOFF/WOFF2）。
+    pub(super) fn register(&mut self, family: &str, data: &[u8]) -> Rc<servo::UserStyleSheet> {
+        let css = format!(
+            "@font-face {{ font-family: {}; src: url(\"data:{};base64,{}\"); }}",
+            css_string_literal(family),
+            font_mime_type(data),
+            base64::encode(data)
+        );
+        let url = Url::parse("about:xian-font-stylesheet").expect("static URL");
+        let stylesheet = Rc::new(servo::UserStyleSheet::new(css, url));
+        self.stylesheets.push(stylesheet.clone());
+        stylesheet
+    }
+
+    /// ### English
+    /// Returns every registered font stylesheet (installed on newly created views).
+    ///
+    /// ### 中文
+    /// 返回所有已注册的字体样式表（安装到新建的 view 上）。
+    pub(super) fn stylesheets(&self) -> &[Rc<servo::UserStyleSheet>] {
+        &self.stylesheets
+    }
+}
+
+/// ### English
+/// Guesses the font MIME type from the file signature (defaults to TrueType).
+///
+/// #### Parameters
+/// - `data`: Font file bytes.
+///
+/// ### 中文
+/// 根据文件签名推断字体 MIME 类型（默认为 TrueType）。
+///
+/// #### 参数
+/// - `data`：字体文件字节。
+fn font_mime_type(data: &[u8]) -> &'static str {
+    match data.get(..4) {
+        Some(b"wOF2") => "font/woff2",
+        Some(b"wOFF") => "font/woff",
+        Some(b"OTTO") => "font/otf",
+        Some(b"ttcf") => "font/collection",
+        _ => "font/ttf",
+    }
+}
+
+/// ### English
+/// Quotes `value` as a CSS string.
+///
+/// #### Parameters
+/// - `value`: String to quote.
+///
+/// ### 中文
+/// 将 `value` 转为 CSS 字符串。
+///
+/// #### 参数
+/// - `value`：要转换的字符串。
+fn css_string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\{:x} ", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,358 @@
+//! ### English
+//! Full-document capture used by page export.
+//!
+//! Servo only screenshots the viewport and exposes no print pipeline, so the document is walked one
+//! viewport at a time: each tile position is scrolled to instantly, screenshotted, and copied into
+//! one document-sized image at the scroll offset the page actually reached. The original scroll
+//! offset is restored afterwards. Fixed and sticky elements therefore show up in every tile they
+//! cover, and the stitched image is clipped to `MAX_CAPTURE_SIDE` device pixels per side.
+//!
+//! ### 中文
+//! 页面导出使用的整文档截取。
+//!
+//! Servo 只能截取视口且未暴露打印管线，因此按视口逐块遍历文档：每个块位置先立即滚动到位，再截图，并按页面
+//! 实际到达的滚动偏移复制进一张与文档同尺寸的图像。完成后恢复原滚动偏移。因此 fixed/sticky 元素会出现在其
+//! 覆盖的每个块中，拼接结果每边最多 `MAX_CAPTURE_SIDE` 个设备像素。
+
+use super::super::scripts::DOCUMENT_EXTENT_SCRIPT;
+
+/// ### English
+/// Maximum width/height of a stitched capture in device pixels (larger documents are clipped).
+///
+/// ### 中文
+/// 拼接截图的最大宽/高（设备像素；更大的文档会被裁剪）。
+const MAX_CAPTURE_SIDE: u32 = 16_384;
+
+/// ### English
+/// Document-sized RGBA8 image produced by a full-page capture.
+///
+/// ### 中文
+/// 整页截取得到的、与文档同尺寸的 RGBA8 图像。
+pub(super) struct StitchedImage {
+    /// ### English
+    /// Image width in device pixels.
+    ///
+    /// ### 中文
+    /// 图像宽度（设备像素）。
+    pub(super) width: u32,
+    /// ### English
+    /// Image height in device pixels.
+    ///
+    /// ### 中文
+    /// 图像高度（设备像素）。
+    pub(super) height: u32,
+    /// ### English
+    /// Tightly packed RGBA8 pixels, top row first.
+    ///
+    /// ### 中文
+    /// 紧密排列的 RGBA8 像素，首行在上。
+    pub(super) rgba: Vec<u8>,
+}
+
+/// ### English
+/// Scroll position and document/viewport size reported by `DOCUMENT_EXTENT_SCRIPT` (CSS px).
+///
+/// ### 中文
+/// 由 `DOCUMENT_EXTENT_SCRIPT` 报告的滚动位置与文档/视口尺寸（CSS px）。
+#[derive(Clone, Copy)]
+struct Extent {
+    /// ### English
+    /// Horizontal scroll offset.
+    ///
+    /// ### 中文
+    /// 水平滚动偏移。
+    scroll_x: f64,
+    /// ### English
+    /// Vertical scroll offset.
+    ///
+    /// ### 中文
+    /// 垂直滚动偏移。
+    scroll_y: f64,
+    /// ### English
+    /// Scrollable document width.
+    ///
+    /// ### 中文
+    /// 可滚动文档宽度。
+    width: f64,
+    /// ### English
+    /// Scrollable document height.
+    ///
+    /// ### 中文
+    /// 可滚动文档高度。
+    height: f64,
+    /// ### English
+    /// Viewport width.
+    ///
+    /// ### 中文
+    /// 视口宽度。
+    viewport_width: f64,
+    /// ### English
+    /// Viewport height.
+    ///
+    /// ### 中文
+    /// 视口高度。
+    viewport_height: f64,
+}
+
+/// ### English
+/// Completion callback of a full-page capture.
+///
+/// ### 中文
+/// 整页截取的完成回调。
+type CaptureDone = Box<dyn FnOnce(Result<StitchedImage, String>)>;
+
+/// ### English
+/// In-progress capture, moved from callback to callback as tiles complete.
+///
+/// ### 中文
+/// 进行中的截取状态，随各块完成在回调之间传递。
+struct FullPageCapture {
+    /// ### English
+    /// WebView being captured.
+    ///
+    /// ### 中文
+    /// 正在截取的 WebView。
+    webview: servo::WebView,
+    /// ### English
+    /// Extent measured before the first tile (also holds the scroll offset to restore).
+    ///
+    /// ### 中文
+    /// 第一个块之前测得的尺寸信息（同时保存需要恢复的滚动偏移）。
+    origin: Extent,
+    /// ### English
+    /// Scroll positions still to capture, in order.
+    ///
+    /// ### 中文
+    /// 尚待截取的滚动位置（按顺序）。
+    tiles: std::vec::IntoIter<(f64, f64)>,
+    /// ### English
+    /// Stitched image, allocated from the first screenshot's scale.
+    ///
+    /// ### 中文
+    /// 拼接图像，按第一张截图的缩放比例分配。
+    image: Option<StitchedImage>,
+    /// ### English
+    /// Completion callback.
+    ///
+    /// ### 中文
+    /// 完成回调。
+    done: CaptureDone,
+}
+
+/// ### English
+/// Captures the whole scrollable document of `webview` and calls `done` with the stitched image.
+///
+/// #### Parameters
+/// - `webview`: WebView to capture (Servo thread).
+/// - `done`: Called once with the image or the reason the capture failed.
+///
+/// ### 中文
+/// 截取 `webview` 的整个可滚动文档，并以拼接后的图像调用 `done`。
+///
+/// #### 参数
+/// - `webview`：要截取的 WebView（Servo 线程）。
+/// - `done`：以图像或失败原因调用一次。
+pub(super) fn capture_full_page(
+    webview: &servo::WebView,
+    done: impl FnOnce(Result<StitchedImage, String>) + 'static,
+) {
+    let capture_view = webview.clone();
+    webview.evaluate_javascript(extent_script(None), move |result| {
+        match parse_extent(result) {
+            Ok(origin) => FullPageCapture::new(capture_view, origin, Box::new(done)).step(),
+            Err(err) => done(Err(err)),
+        }
+    });
+}
+
+/// ### English
+/// Builds the `DOCUMENT_EXTENT_SCRIPT` call, scrolling to `position` first when given.
+///
+/// #### Parameters
+/// - `position`: Scroll target `(left, top)` in CSS px, or `None` to only measure.
+///
+/// ### 中文
+/// 构造 `DOCUMENT_EXTENT_SCRIPT` 调用；给定 `position` 时先滚动到该位置。
+///
+/// #### 参数
+/// - `position`：滚动目标 `(left, top)`（CSS px）；`None` 表示只测量。
+fn extent_script(position: Option<(f64, f64)>) -> String {
+    match position {
+        Some((left, top)) => format!("({DOCUMENT_EXTENT_SCRIPT})({left}, {top})"),
+        None => format!("({DOCUMENT_EXTENT_SCRIPT})(null, null)"),
+    }
+}
+
+/// ### English
+/// Parses the result of `DOCUMENT_EXTENT_SCRIPT`.
+///
+/// #### Parameters
+/// - `result`: Evaluation result.
+///
+/// ### 中文
+/// 解析 `DOCUMENT_EXTENT_SCRIPT` 的结果。
+///
+/// #### 参数
+/// - `result`：求值结果。
+fn parse_extent(
+    result: Result<servo::JSValue, servo::JavaScriptEvaluationError>,
+) -> Result<Extent, String> {
+    match result {
+        Ok(servo::JSValue::Array(values)) => match values.as_slice() {
+            [
+                servo::JSValue::Number(scroll_x),
+                servo::JSValue::Number(scroll_y),
+                servo::JSValue::Number(width),
+                servo::JSValue::Number(height),
+                servo::JSValue::Number(viewport_width),
+                servo::JSValue::Number(viewport_height),
+            ] => Ok(Extent {
+                scroll_x: *scroll_x,
+                scroll_y: *scroll_y,
+                width: *width,
+                height: *height,
+                viewport_width: viewport_width.max(1.0),
+                viewport_height: viewport_height.max(1.0),
+            }),
+            _ => Err("Unexpected document extent".to_string()),
+        },
+        Ok(other) => Err(format!("Unexpected script result: {other:?}")),
+        Err(err) => Err(format!("Script evaluation failed: {err:?}")),
+    }
+}
+
+impl FullPageCapture {
+    /// ### English
+    /// Plans the tile grid for the measured document.
+    ///
+    /// #### Parameters
+    /// - `webview`: WebView being captured.
+    /// - `origin`: Extent measured before capturing.
+    /// - `done`: Completion callback.
+    ///
+    /// ### 中文
+    /// 为测得的文档规划块网格。
+    ///
+    /// #### 参数
+    /// - `webview`：正在截取的 WebView。
+    /// - `origin`：截取前测得的尺寸信息。
+    /// - `done`：完成回调。
+    fn new(webview: servo::WebView, origin: Extent, done: CaptureDone) -> Self {
+        let limit = f64::from(MAX_CAPTURE_SIDE);
+        let mut tiles = Vec::new();
+        let mut top = 0.0;
+        while top < origin.height.min(limit) {
+            let mut left = 0.0;
+            while left < origin.width.min(limit) {
+                tiles.push((left, top));
+                left += origin.viewport_width;
+            }
+            top += origin.viewport_height;
+        }
+        Self {
+            webview,
+            origin,
+            tiles: tiles.into_iter(),
+            image: None,
+            done,
+        }
+    }
+
+    /// ### English
+    /// Scrolls to the next tile and screenshots it, or finishes when no tile is left.
+    ///
+    /// ### 中文
+    /// 滚动到下一个块并截图；没有剩余块时结束。
+    fn step(mut self) {
+        let Some(position) = self.tiles.next() else {
+            self.finish(None);
+            return;
+        };
+        let webview = self.webview.clone();
+        webview.evaluate_javascript(extent_script(Some(position)), move |result| {
+            let scrolled = match parse_extent(result) {
+                Ok(scrolled) => scrolled,
+                Err(err) => {
+                    self.finish(Some(err));
+                    return;
+                }
+            };
+            let webview = self.webview.clone();
+            webview.take_screenshot(None, move |result| match result {
+                Ok(screenshot) => {
+                    self.blit(&scrolled, &screenshot);
+                    self.step();
+                }
+                Err(err) => self.finish(Some(format!("Screenshot failed: {err:?}"))),
+            });
+        });
+    }
+
+    /// ### English
+    /// Copies one viewport screenshot into the stitched image at the reached scroll offset.
+    ///
+    /// #### Parameters
+    /// - `scrolled`: Extent reported after scrolling to the tile.
+    /// - `screenshot`: Viewport screenshot taken at that offset.
+    ///
+    /// ### 中文
+    /// 把一张视口截图按实际到达的滚动偏移复制进拼接图像。
+    ///
+    /// #### 参数
+    /// - `scrolled`：滚动到该块后报告的尺寸信息。
+    /// - `screenshot`：在该偏移处截取的视口截图。
+    fn blit(&mut self, scrolled: &Extent, screenshot: &servo::RgbaImage) {
+        let scale = f64::from(screenshot.width()) / scrolled.viewport_width;
+        let origin = self.origin;
+        let image = self.image.get_or_insert_with(|| {
+            let side = |css: f64| ((css * scale).ceil() as u32).clamp(1, MAX_CAPTURE_SIDE);
+            let (width, height) = (side(origin.width), side(origin.height));
+            StitchedImage {
+                width,
+                height,
+                rgba: vec![0; width as usize * height as usize * 4],
+            }
+        });
+
+        let dst_x = (scrolled.scroll_x * scale).round() as u32;
+        let dst_y = (scrolled.scroll_y * scale).round() as u32;
+        let columns = screenshot.width().min(image.width.saturating_sub(dst_x)) as usize;
+        if columns == 0 {
+            return;
+        }
+        let source = screenshot.as_raw();
+        let source_stride = screenshot.width() as usize * 4;
+        for row in 0..screenshot.height() {
+            let y = dst_y + row;
+            if y >= image.height {
+                break;
+            }
+            let src = row as usize * source_stride;
+            let dst = (y as usize * image.width as usize + dst_x as usize) * 4;
+            image.rgba[dst..dst + columns * 4].copy_from_slice(&source[src..src + columns * 4]);
+        }
+    }
+
+    /// ### English
+    /// Restores the original scroll offset and reports the result.
+    ///
+    /// #### Parameters
+    /// - `error`: Failure reason, or `None` if every tile was captured.
+    ///
+    /// ### 中文
+    /// 恢复原滚动偏移并上报结果。
+    ///
+    /// #### 参数
+    /// - `error`：失败原因；所有块均已截取时为 `None`。
+    fn finish(self, error: Option<String>) {
+        let restore = (self.origin.scroll_x, self.origin.scroll_y);
+        self.webview
+            .evaluate_javascript(extent_script(Some(restore)), |_| {});
+        let result = match (error, self.image) {
+            (Some(err), _) => Err(err),
+            (None, Some(image)) => Ok(image),
+            (None, None) => Err("Document has nothing to capture".to_string()),
+        };
+        (self.done)(result);
+    }
+}
@@ -0,0 +1,191 @@
+//! ### English
+//! View groups owned by the Servo thread.
+//!
+//! A group owns one shared refresh driver and the ordered member list used to drain pending work
+//! for all members together.
+//!
+//! ### 中文
+//! 由 Servo 线程持有的 view group。
+//!
+//! 每个 group 持有一个共享 refresh driver，以及用于统一 drain 成员 pending work 的有序成员列表。
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// ### English
+/// One view group (Servo thread only).
+///
+/// ### 中文
+/// 单个 view group（仅 Servo 线程持有）。
+struct ViewGroup {
+    /// ### English
+    /// Refresh driver shared by every member.
+    ///
+    /// ### 中文
+    /// 所有成员共享的 refresh driver。
+    refresh_driver: Rc<dyn servo::RefreshDriver>,
+    /// ### English
+    /// Member view IDs in creation order (drain order).
+    ///
+    /// ### 中文
+    /// 按创建顺序排列的成员 view ID（即 drain 顺序）。
+    members: Vec<u32>,
+    /// ### English
+    /// Main-loop epoch in which the members were last drained together.
+    ///
+    /// ### 中文
+    /// 成员上一次被统一 drain 时的主循环 epoch。
+    drained_epoch: u64,
+}
+
+/// ### English
+/// Table of live view groups keyed by group ID (`0` is reserved for "no group").
+///
+/// ### 中文
+/// 以 group ID 为键的存活 view group 表（`0` 保留表示“无 group”）。
+pub(super) struct ViewGroupTable {
+    /// ### English
+    /// Live groups.
+    ///
+    /// ### 中文
+    /// 存活的 group。
+    groups: HashMap<u32, ViewGroup>,
+    /// ### English
+    /// Next group ID to hand out (IDs are never reused).
+    ///
+    /// ### 中文
+    /// 下一个分配的 group ID（ID 不复用）。
+    next_id: u32,
+}
+
+impl ViewGroupTable {
+    /// ### English
+    /// Creates an empty table.
+    ///
+    /// ### 中文
+    /// 创建空表。
+    pub(super) fn new() -> Self {
+        Self {
+            groups: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// ### English
+    /// Registers a new group and returns its ID, or `None` when IDs are exhausted.
+    ///
+    /// #### Parameters
+    /// - `refresh_driver`: Driver shared by the group's members.
+    ///
+    /// ### 中文
+    /// 注册一个新 group 并返回其 ID；ID 耗尽时返回 `None`。
+    ///
+    /// #### 参数
+    /// - `refresh_driver`：组成员共享的 driver。
+    pub(super) fn create(&mut self, refresh_driver: Rc<dyn servo::RefreshDriver>) -> Option<u32> {
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1)?;
+        self.groups.insert(
+            id,
+            ViewGroup {
+                refresh_driver,
+                members: Vec::new(),
+                drained_epoch: 0,
+            },
+        );
+        Some(id)
+    }
+
+    /// ### English
+    /// Removes a group. Existing members keep rendering with the shared driver they already hold.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID to remove.
+    ///
+    /// ### 中文
+    /// 移除一个 group。已有成员会继续使用其已持有的共享 driver 渲染。
+    ///
+    /// #### 参数
+    /// - `id`：要移除的 group ID。
+    pub(super) fn destroy(&mut self, id: u32) {
+        self.groups.remove(&id);
+    }
+
+    /// ### English
+    /// Returns the shared refresh driver of a group.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID.
+    ///
+    /// ### 中文
+    /// 返回某个 group 的共享 refresh driver。
+    ///
+    /// #### 参数
+    /// - `id`：group ID。
+    pub(super) fn refresh_driver(&self, id: u32) -> Option<Rc<dyn servo::RefreshDriver>> {
+        self.groups
+            .get(&id)
+            .map(|group| group.refresh_driver.clone())
+    }
+
+    /// ### English
+    /// Appends a view to a group's drain order.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID.
+    /// - `view_id`: Member view ID.
+    ///
+    /// ### 中文
+    /// 将 view 追加到 group 的 drain 顺序中。
+    ///
+    /// #### 参数
+    /// - `id`：group ID。
+    /// - `view_id`：成员 view ID。
+    pub(super) fn add_member(&mut self, id: u32, view_id: u32) {
+        if let Some(group) = self.groups.get_mut(&id) {
+            group.members.push(view_id);
+        }
+    }
+
+    /// ### English
+    /// Removes a view from a group's drain order.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID.
+    /// - `view_id`: Member view ID.
+    ///
+    /// ### 中文
+    /// 将 view 从 group 的 drain 顺序中移除。
+    ///
+    /// #### 参数
+    /// - `id`：group ID。
+    /// - `view_id`：成员 view ID。
+    pub(super) fn remove_member(&mut self, id: u32, view_id: u32) {
+        if let Some(group) = self.groups.get_mut(&id) {
+            group.members.retain(|&member| member != view_id);
+        }
+    }
+
+    /// ### English
+    /// Returns the group's members if they have not been drained yet in `epoch`, marking them as
+    /// drained.
+    ///
+    /// #### Parameters
+    /// - `id`: Group ID.
+    /// - `epoch`: Current main-loop epoch.
+    ///
+    /// ### 中文
+    /// 若该 group 在 `epoch` 内尚未 drain，则返回其成员并标记为已 drain。
+    ///
+    /// #### 参数
+    /// - `id`：group ID。
+    /// - `epoch`：当前主循环 epoch。
+    pub(super) fn members_to_drain(&mut self, id: u32, epoch: u64) -> Option<&[u32]> {
+        let group = self.groups.get_mut(&id)?;
+        if group.drained_epoch == epoch {
+            return None;
+        }
+        group.drained_epoch = epoch;
+        Some(&group.members)
+    }
+}
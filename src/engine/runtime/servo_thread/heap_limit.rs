@@ -0,0 +1,180 @@
+//! ### English
+//! JavaScript heap limit of pages.
+//!
+//! SpiderMonkey caps a script runtime's GC heap with Servo's `js_mem_max` preference, which Servo
+//! reads when it starts the script event loop of a page. The preference is process-wide, so the
+//! limit is an engine setting shared by every engine in the process and covers pages whose event
+//! loop starts after it is set. When a page runs out of heap, its allocation fails with an
+//! out-of-memory error (reported through the page error script) or its script thread crashes;
+//! either way the view is reported and, unless it crashed, unloaded so the leaking page's scripts
+//! stop and its heap is torn down.
+//!
+//! ### 中文
+//! 页面的 JavaScript 堆上限。
+//!
+//! SpiderMonkey 通过 Servo 的 `js_mem_max` 首选项限制脚本运行时的 GC 堆，Servo 会在启动页面的脚本事件循环时
+//! 读取它。该首选项是进程级的，因此该上限是由进程内所有引擎共享的引擎设置，覆盖设置之后启动事件循环的页面。
+//! 页面堆耗尽时，其分配会以内存不足错误失败（经由页面错误脚本上报），或其脚本线程崩溃；无论哪种情况都会上报
+//! 该 view，且在未崩溃时卸载页面，使泄漏页面的脚本停止运行并拆除其堆。
+
+use std::cell::Cell;
+
+use url::Url;
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT,
+};
+
+use super::super::events::{ViewEvent, ViewEventQueue};
+
+/// ### English
+/// Servo's `js_mem_max` value meaning "no limit".
+///
+/// ### 中文
+/// Servo `js_mem_max` 表示“不限制”的取值。
+const JS_MEM_UNLIMITED: i64 = -1;
+
+/// ### English
+/// Points Servo's process-wide `js_mem_max` preference at a heap limit.
+///
+/// #### Parameters
+/// - `limit_mb`: Limit in MiB (`0` = unlimited).
+///
+/// ### 中文
+/// 将 Servo 进程级的 `js_mem_max` 首选项设为指定的堆上限。
+///
+/// #### 参数
+/// - `limit_mb`：上限（MiB；`0` 表示不限制）。
+pub(super) fn apply(limit_mb: u32) {
+    let js_mem_max = match limit_mb {
+        0 => JS_MEM_UNLIMITED,
+        limit_mb => i64::from(limit_mb),
+    };
+    if servo::prefs::get().js_mem_max == js_mem_max {
+        return;
+    }
+    let mut preferences = servo::prefs::get().clone();
+    preferences.js_mem_max = js_mem_max;
+    servo::prefs::set(preferences);
+}
+
+/// ### English
+/// Heap limit reporting state of one view's current page (Servo thread only).
+///
+/// ### 中文
+/// 单个 view 当前页面的堆上限上报状态（仅 Servo 线程使用）。
+#[derive(Default)]
+pub(super) struct HeapLimit {
+    /// ### English
+    /// Whether the current page already exceeded the limit.
+    ///
+    /// ### 中文
+    /// 当前页面是否已超出上限。
+    exceeded: Cell<bool>,
+}
+
+impl HeapLimit {
+    /// ### English
+    /// Re-arms reporting for a page that started loading.
+    ///
+    /// ### 中文
+    /// 为开始加载的页面重新启用上报。
+    pub(super) fn rearm(&self) {
+        self.exceeded.set(false);
+    }
+
+    /// ### English
+    /// Handles a page error report: if a limit is set and the error is an out-of-memory
+    /// error, reports the view and unloads the page.
+    ///
+    /// #### Parameters
+    /// - `message`: Page error message.
+    /// - `webview`: WebView showing the page.
+    /// - `events`: Per-view event queue receiving `HEAP_LIMIT` events.
+    ///
+    /// ### 中文
+    /// 处理一条页面错误上报：若设有上限且错误为内存不足错误，则上报该 view 并卸载页面。
+    ///
+    /// #### 参数
+    /// - `message`：页面错误消息。
+    /// - `webview`：显示该页面的 WebView。
+    /// - `events`：接收 `HEAP_LIMIT` 事件的每 view 事件队列。
+    pub(super) fn check_page_error(
+        &self,
+        message: &str,
+        webview: &servo::WebView,
+        events: &ViewEventQueue,
+    ) {
+        if !is_out_of_memory(message) || !self.report(false, events) {
+            return;
+        }
+        if let Ok(blank) = Url::parse("about:blank") {
+            webview.load(blank);
+        }
+    }
+
+    /// ### English
+    /// Handles a content crash: if a limit is set and the crash was caused by running out of
+    /// memory, reports the view.
+    ///
+    /// #### Parameters
+    /// - `reason`: Crash reason.
+    /// - `events`: Per-view event queue receiving `HEAP_LIMIT` events.
+    ///
+    /// ### 中文
+    /// 处理一次内容崩溃：若设有上限且崩溃由内存耗尽引起，则上报该 view。
+    ///
+    /// #### 参数
+    /// - `reason`：崩溃原因。
+    /// - `events`：接收 `HEAP_LIMIT` 事件的每 view 事件队列。
+    pub(super) fn check_crash(&self, reason: &str, events: &ViewEventQueue) {
+        if is_out_of_memory(reason) {
+            self.report(true, events);
+        }
+    }
+
+    /// ### English
+    /// Queues a `HEAP_LIMIT` event once per page; returns whether it was queued.
+    ///
+    /// #### Parameters
+    /// - `crashed`: Whether the page's script thread crashed.
+    /// - `events`: Per-view event queue.
+    ///
+    /// ### 中文
+    /// 每个页面至多排入一次 `HEAP_LIMIT` 事件；返回是否已排入。
+    ///
+    /// #### 参数
+    /// - `crashed`：页面的脚本线程是否已崩溃。
+    /// - `events`：每 view 事件队列。
+    fn report(&self, crashed: bool, events: &ViewEventQueue) -> bool {
+        let limit_mb = servo::prefs::get().js_mem_max;
+        if limit_mb <= 0 || self.exceeded.replace(true) {
+            return false;
+        }
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = limit_mb;
+        event.value1 = i64::from(crashed);
+        events.push(event);
+        true
+    }
+}
+
+/// ### English
+/// Returns whether an error message or crash reason describes running out of memory.
+///
+/// #### Parameters
+/// - `message`: Error message or crash reason.
+///
+/// ### 中文
+/// 返回错误消息或崩溃原因是否表示内存耗尽。
+///
+/// #### 参数
+/// - `message`：错误消息或崩溃原因。
+fn is_out_of_memory(message: &str) -> bool {
+    message.to_ascii_lowercase().contains("out of memory")
+}
@@ -0,0 +1,121 @@
+//! ### English
+//! Per-view channel carrying messages from the engine's page scripts to the Servo thread.
+//!
+//! Servo has no embedder message hook, so engine scripts talk to the delegate through console
+//! messages. Anything the page logs reaches the delegate as well, so every view gets a random nonce
+//! that is only ever spelled out in the user scripts installed for it: each script receives a
+//! `send` function (bound to the native `console.log` at document start, unwrapping the console
+//! capture wrapper through its `xian.nativeConsole` symbol) as its first argument and never
+//! publishes it, so page code can neither read the nonce nor forge a host message. Console messages
+//! without the view's prefix are ordinary page output.
+//!
+//! Payloads still carry page data (URLs, error text, host call arguments), so every handler parses
+//! them defensively, and messages longer than `MAX_HOST_MESSAGE_BYTES` are dropped outright.
+//!
+//! ### 中文
+//! 从引擎页面脚本向 Servo 线程传递消息的每 view 通道。
+//!
+//! Servo 没有宿主消息钩子，因此引擎脚本通过 console 消息与 delegate 通信。页面输出的任何内容同样会到达
+//! delegate，因此每个 view 都有一个随机 nonce，它只出现在为该 view 安装的用户脚本中：每个脚本以第一个参数
+//! 获得一个 `send` 函数（在文档开始时绑定到原生 `console.log`，并通过 console 捕获包装函数的
+//! `xian.nativeConsole` symbol 解开包装），且从不公开它，因此页面代码既无法读取 nonce，也无法伪造宿主消息。不带该 view 前缀的 console 消息就是普通的页面输出。
+//!
+//! 载荷仍包含页面数据（URL、错误文本、宿主调用参数），因此每个处理函数都会防御性地解析，长度超过
+//! `MAX_HOST_MESSAGE_BYTES` 的消息会被直接丢弃。
+
+use std::rc::Rc;
+
+use super::super::binary_channel::MAX_BINARY_MESSAGE_BYTES;
+use super::super::scripts::{HOST_MESSAGE_PREFIX, js_string_literal};
+
+/// ### English
+/// Maximum length of one host message in bytes (a base64 binary message plus its header).
+///
+/// ### 中文
+/// 单条宿主消息的最大字节数（一条 base64 二进制消息及其头部）。
+const MAX_HOST_MESSAGE_BYTES: usize = MAX_BINARY_MESSAGE_BYTES / 3 * 4 + 64;
+
+/// ### English
+/// Message prefix and script wrapper of one view.
+///
+/// ### 中文
+/// 单个 view 的消息前缀与脚本包装器。
+pub(super) struct HostChannel {
+    /// ### English
+    /// `HOST_MESSAGE_PREFIX` followed by the view's nonce and `:`.
+    ///
+    /// ### 中文
+    /// `HOST_MESSAGE_PREFIX` 后接该 view 的 nonce 与 `:`。
+    prefix: String,
+}
+
+impl HostChannel {
+    /// ### English
+    /// Creates a channel with a fresh 128-bit nonce read from the OS random source.
+    ///
+    /// Returns an error if the OS random source is unavailable.
+    ///
+    /// ### 中文
+    /// 创建带有全新 128 位 nonce 的通道，nonce 读取自操作系统随机源。
+    ///
+    /// 若操作系统随机源不可用，则返回错误。
+    pub(super) fn new() -> Result<Rc<Self>, String> {
+        let mut nonce = [0u8; 16];
+        getrandom::fill(&mut nonce)
+            .map_err(|err| format!("Cannot generate the host channel nonce: {err}"))?;
+        let nonce: String = nonce.iter().map(|byte| format!("{byte:02x}")).collect();
+        Ok(Rc::new(Self {
+            prefix: format!("{HOST_MESSAGE_PREFIX}{nonce}:"),
+        }))
+    }
+
+    /// ### English
+    /// Returns the JavaScript expression of this view's `send(message)` function.
+    ///
+    /// ### 中文
+    /// 返回该 view 的 `send(message)` 函数的 JavaScript 表达式。
+    pub(super) fn sender(&self) -> String {
+        format!(
+            "((log, prefix) => (message) => log(prefix + message))(\
+             (console.log[Symbol.for(\"xian.nativeConsole\")] ?? console.log).bind(console), {})",
+            js_string_literal(&self.prefix)
+        )
+    }
+
+    /// ### English
+    /// Wraps a script function expression `(send, ...) => ...` into a user script for this view.
+    ///
+    /// #### Parameters
+    /// - `source`: Function expression taking `send` as its first parameter.
+    ///
+    /// ### 中文
+    /// 将脚本函数表达式 `(send, ...) => ...` 包装为该 view 的用户脚本。
+    ///
+    /// #### 参数
+    /// - `source`：第一个参数为 `send` 的函数表达式。
+    pub(super) fn user_script(&self, source: &str) -> Rc<servo::UserScript> {
+        Rc::new(servo::UserScript::new(
+            format!("({source})({});", self.sender()),
+            None,
+        ))
+    }
+
+    /// ### English
+    /// Returns the host message carried by `message`, or `None` for ordinary console output and
+    /// oversized messages.
+    ///
+    /// #### Parameters
+    /// - `message`: Console message text.
+    ///
+    /// ### 中文
+    /// 返回 `message` 携带的宿主消息；普通 console 输出与超长消息返回 `None`。
+    ///
+    /// #### 参数
+    /// - `message`：console 消息文本。
+    pub(super) fn strip<'a>(&self, message: &'a str) -> Option<&'a str> {
+        if message.len() > self.prefix.len() + MAX_HOST_MESSAGE_BYTES {
+            return None;
+        }
+        message.strip_prefix(self.prefix.as_str())
+    }
+}
@@ -0,0 +1,216 @@
+//! ### English
+//! Servo-thread registry of host functions and the page calls waiting for an answer.
+//!
+//! ### 中文
+//! Servo 线程上的宿主函数注册表，以及等待应答的页面调用。
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::{CString, c_void};
+
+use super::super::host_function::HostFunction;
+use super::super::scripts::js_string_literal;
+
+/// ### English
+/// Most calls of one view waiting for an answer; further calls are rejected.
+///
+/// ### 中文
+/// 单个 view 最多同时等待应答的调用数；超出的调用会被拒绝。
+const MAX_PENDING_CALLS: usize = 1024;
+
+/// ### English
+/// Host functions of one view, shared between its delegate and its entry.
+///
+/// Pending calls are forgotten when a new document starts loading, so late answers cannot settle a
+/// Promise of the next page.
+///
+/// ### 中文
+/// 单个 view 的宿主函数，在其 delegate 与条目之间共享。
+///
+/// 新文档开始加载时会丢弃等待中的调用，因此迟到的应答不会兑现下一个页面的 Promise。
+pub(super) struct HostFunctionTable {
+    /// ### English
+    /// Registered functions keyed by name.
+    ///
+    /// ### 中文
+    /// 按名称索引的已注册函数。
+    functions: RefCell<HashMap<String, HostFunction>>,
+    /// ### English
+    /// Next call ID handed to the embedder (starts at 1).
+    ///
+    /// ### 中文
+    /// 下一个交给宿主的调用 ID（从 1 开始）。
+    next_call_id: Cell<u64>,
+    /// ### English
+    /// Page-side sequence number of each pending call, keyed by call ID.
+    ///
+    /// ### 中文
+    /// 每个等待中调用在页面侧的序号，按调用 ID 索引。
+    pending: RefCell<HashMap<u64, u64>>,
+}
+
+impl Default for HostFunctionTable {
+    /// ### English
+    /// Creates an empty table whose first call ID is 1.
+    ///
+    /// ### 中文
+    /// 创建一个空表，首个调用 ID 为 1。
+    fn default() -> Self {
+        Self {
+            functions: RefCell::new(HashMap::new()),
+            next_call_id: Cell::new(1),
+            pending: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl HostFunctionTable {
+    /// ### English
+    /// Registers, replaces or (with `None`) removes a host function.
+    ///
+    /// #### Parameters
+    /// - `name`: Function name used by the page.
+    /// - `function`: Callback, or `None` to unregister.
+    ///
+    /// ### 中文
+    /// 注册、替换或（传 `None` 时）移除一个宿主函数。
+    ///
+    /// #### 参数
+    /// - `name`：页面使用的函数名。
+    /// - `function`：回调；`None` 表示取消注册。
+    pub(super) fn register(&self, name: String, function: Option<HostFunction>) {
+        let mut functions = self.functions.borrow_mut();
+        match function {
+            Some(function) => {
+                functions.insert(name, function);
+            }
+            None => {
+                functions.remove(&name);
+            }
+        }
+    }
+
+    /// ### English
+    /// Handles a `host-call:<seq>:<name>\n<json>` message: hands it to the registered callback, or
+    /// rejects the page's Promise if the function is unknown or too many calls are pending.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that made the call.
+    /// - `message`: Message text after the `host-call:` prefix.
+    ///
+    /// ### 中文
+    /// 处理 `host-call:<seq>:<name>\n<json>` 消息：交给已注册的回调；若函数未知或等待中的调用过多，
+    /// 则拒绝页面的 Promise。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：发起调用的 WebView。
+    /// - `message`：去掉 `host-call:` 前缀后的消息文本。
+    pub(super) fn call(&self, servo_webview: &servo::WebView, message: &str) {
+        let (header, args) = message.split_once('\n').unwrap_or((message, "null"));
+        let Some((seq, name)) = header.split_once(':') else {
+            return;
+        };
+        let Ok(seq) = seq.parse::<u64>() else {
+            return;
+        };
+
+        let Some(function) = self.functions.borrow().get(name).copied() else {
+            settle(
+                servo_webview,
+                seq,
+                false,
+                &format!("unknown host function: {name}"),
+            );
+            return;
+        };
+        if self.pending.borrow().len() >= MAX_PENDING_CALLS {
+            settle(servo_webview, seq, false, "too many pending host calls");
+            return;
+        }
+        let (Ok(c_name), Ok(c_args)) = (CString::new(name), CString::new(args)) else {
+            settle(
+                servo_webview,
+                seq,
+                false,
+                "host call contains a NUL character",
+            );
+            return;
+        };
+
+        let call_id = self.next_call_id.get();
+        self.next_call_id.set(call_id.wrapping_add(1).max(1));
+        self.pending.borrow_mut().insert(call_id, seq);
+        unsafe {
+            (function.callback)(
+                function.user_data as *mut c_void,
+                call_id,
+                c_name.as_ptr(),
+                c_args.as_ptr(),
+            )
+        };
+    }
+
+    /// ### English
+    /// Settles a pending call with the embedder's answer. Unknown or stale IDs are ignored.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that made the call.
+    /// - `call_id`: Call ID passed to the callback.
+    /// - `success`: Whether to resolve (`true`) or reject (`false`) the Promise.
+    /// - `result`: JSON result when resolving (empty = `undefined`), error message when rejecting.
+    ///
+    /// ### 中文
+    /// 以宿主的应答兑现一个等待中的调用。未知或过期的 ID 会被忽略。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：发起调用的 WebView。
+    /// - `call_id`：传给回调的调用 ID。
+    /// - `success`：兑现（`true`）还是拒绝（`false`）Promise。
+    /// - `result`：兑现时为 JSON 结果（空字符串表示 `undefined`），拒绝时为错误信息。
+    pub(super) fn respond(
+        &self,
+        servo_webview: &servo::WebView,
+        call_id: u64,
+        success: bool,
+        result: &str,
+    ) {
+        let Some(seq) = self.pending.borrow_mut().remove(&call_id) else {
+            return;
+        };
+        settle(servo_webview, seq, success, result);
+    }
+
+    /// ### English
+    /// Forgets every pending call (a new document is loading).
+    ///
+    /// ### 中文
+    /// 丢弃所有等待中的调用（新文档正在加载）。
+    pub(super) fn clear_pending(&self) {
+        self.pending.borrow_mut().clear();
+    }
+}
+
+/// ### English
+/// Settles the page-side Promise of call `seq`.
+///
+/// #### Parameters
+/// - `servo_webview`: WebView that made the call.
+/// - `seq`: Page-side sequence number.
+/// - `success`: Whether to resolve or reject.
+/// - `payload`: JSON result or error message.
+///
+/// ### 中文
+/// 兑现页面侧序号为 `seq` 的调用的 Promise。
+///
+/// #### 参数
+/// - `servo_webview`：发起调用的 WebView。
+/// - `seq`：页面侧序号。
+/// - `success`：兑现还是拒绝。
+/// - `payload`：JSON 结果或错误信息。
+fn settle(servo_webview: &servo::WebView, seq: u64, success: bool, payload: &str) {
+    let script = format!(
+        "window[Symbol.for(\"xian.hostSettle\")]?.({seq}, {success}, {})",
+        js_string_literal(payload)
+    );
+    servo_webview.evaluate_javascript(script, |_| {});
+}
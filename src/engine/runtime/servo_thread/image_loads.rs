@@ -0,0 +1,293 @@
+//! ### English
+//! Servo-thread side of the image load statistics: the least-recently-used table of the image URLs
+//! the shard's views loaded.
+//!
+//! The table is a hash map into a slab of entries threaded on a doubly linked recency list, so a
+//! load and the eviction of the least recently loaded URL are both O(1).
+//!
+//! ### 中文
+//! 图片加载统计在 Servo 线程侧的状态：该分片各 view 所加载图片 URL 的 LRU 表。
+//!
+//! 该表是指向条目 slab 的哈希表，条目串在双向链表上表示新旧顺序，因此一次加载与逐出最久未加载的 URL 都是
+//! O(1)。
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::super::image_loads::ImageLoadLog;
+
+/// ### English
+/// Most URLs the table remembers (the least recently loaded are dropped beyond it).
+///
+/// ### 中文
+/// 表最多记录的 URL 数（超出后移出最久未加载的 URL）。
+const MAX_TRACKED_URLS: usize = 16 * 1024;
+
+/// ### English
+/// Bytes per decoded pixel (RGBA8).
+///
+/// ### 中文
+/// 每个解码像素的字节数（RGBA8）。
+const BYTES_PER_PIXEL: u64 = 4;
+
+/// ### English
+/// Slab index marking the end of the recency list.
+///
+/// ### 中文
+/// 表示新旧链表末端的 slab 下标。
+const NIL: usize = usize::MAX;
+
+/// ### English
+/// One URL in the table.
+///
+/// ### 中文
+/// 表中的一个 URL。
+struct TrackedUrl {
+    /// ### English
+    /// Image URL (also the map key, kept here to remove it on eviction).
+    ///
+    /// ### 中文
+    /// 图片 URL（同时是哈希表的键，保存在此以便逐出时删除）。
+    url: Arc<str>,
+    /// ### English
+    /// Decoded size at 4 bytes per pixel.
+    ///
+    /// ### 中文
+    /// 按每像素 4 字节计算的解码大小。
+    bytes: u64,
+    /// ### English
+    /// Token of the view that loaded the URL first.
+    ///
+    /// ### 中文
+    /// 最先加载该 URL 的 view 的 token。
+    view: u64,
+    /// ### English
+    /// More recently loaded neighbour (`NIL` at the head).
+    ///
+    /// ### 中文
+    /// 更近加载的相邻条目（位于链表头时为 `NIL`）。
+    newer: usize,
+    /// ### English
+    /// Less recently loaded neighbour (`NIL` at the tail).
+    ///
+    /// ### 中文
+    /// 更早加载的相邻条目（位于链表尾时为 `NIL`）。
+    older: usize,
+}
+
+/// ### English
+/// Least-recently-used URL table.
+///
+/// ### 中文
+/// 最近最少使用的 URL 表。
+struct UrlTable {
+    /// ### English
+    /// Slab index of each URL.
+    ///
+    /// ### 中文
+    /// 各 URL 的 slab 下标。
+    index: HashMap<Arc<str>, usize>,
+    /// ### English
+    /// Entries (at most `MAX_TRACKED_URLS`; slots are reused once the table is full).
+    ///
+    /// ### 中文
+    /// 条目（至多 `MAX_TRACKED_URLS` 个；表满后复用槽位）。
+    entries: Vec<TrackedUrl>,
+    /// ### English
+    /// Most recently loaded entry (`NIL` while empty).
+    ///
+    /// ### 中文
+    /// 最近加载的条目（表为空时为 `NIL`）。
+    newest: usize,
+    /// ### English
+    /// Least recently loaded entry (`NIL` while empty).
+    ///
+    /// ### 中文
+    /// 最久未加载的条目（表为空时为 `NIL`）。
+    oldest: usize,
+    /// ### English
+    /// Decoded size of every URL in the table.
+    ///
+    /// ### 中文
+    /// 表中所有 URL 的解码大小。
+    bytes: u64,
+}
+
+impl UrlTable {
+    /// ### English
+    /// Unlinks entry `slot` from the recency list.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slab index of a linked entry.
+    ///
+    /// ### 中文
+    /// 将条目 `slot` 从新旧链表中摘下。
+    ///
+    /// #### 参数
+    /// - `slot`：已链接条目的 slab 下标。
+    fn unlink(&mut self, slot: usize) {
+        let (newer, older) = (self.entries[slot].newer, self.entries[slot].older);
+        match newer {
+            NIL => self.newest = older,
+            newer => self.entries[newer].older = older,
+        }
+        match older {
+            NIL => self.oldest = newer,
+            older => self.entries[older].newer = newer,
+        }
+    }
+
+    /// ### English
+    /// Links entry `slot` at the head of the recency list.
+    ///
+    /// #### Parameters
+    /// - `slot`: Slab index of an unlinked entry.
+    ///
+    /// ### 中文
+    /// 将条目 `slot` 链接到新旧链表头部。
+    ///
+    /// #### 参数
+    /// - `slot`：未链接条目的 slab 下标。
+    fn push_newest(&mut self, slot: usize) {
+        self.entries[slot].newer = NIL;
+        self.entries[slot].older = self.newest;
+        match self.newest {
+            NIL => self.oldest = slot,
+            newest => self.entries[newest].newer = slot,
+        }
+        self.newest = slot;
+    }
+
+    /// ### English
+    /// Records a load of `url` and returns the view that loaded it first, or `None` for a new URL.
+    /// A new URL takes the slot of the least recently loaded one once the table is full; returns
+    /// whether a URL was dropped for it as the second value.
+    ///
+    /// #### Parameters
+    /// - `url`: Image URL.
+    /// - `bytes`: Decoded size of the image.
+    /// - `view`: Token of the loading view.
+    ///
+    /// ### 中文
+    /// 记录一次对 `url` 的加载，返回最先加载它的 view；新 URL 返回 `None`。表满后新 URL 会占用最久未加载之
+    /// URL 的槽位；第二个返回值表示是否因此移出了一个 URL。
+    ///
+    /// #### 参数
+    /// - `url`：图片 URL。
+    /// - `bytes`：图片的解码大小。
+    /// - `view`：加载该图片的 view 的 token。
+    fn load(&mut self, url: &str, bytes: u64, view: u64) -> (Option<u64>, bool) {
+        if let Some(&slot) = self.index.get(url) {
+            self.unlink(slot);
+            self.push_newest(slot);
+            return (Some(self.entries[slot].view), false);
+        }
+
+        let url: Arc<str> = Arc::from(url);
+        let entry = TrackedUrl {
+            url: url.clone(),
+            bytes,
+            view,
+            newer: NIL,
+            older: NIL,
+        };
+        let dropped = self.entries.len() >= MAX_TRACKED_URLS && self.oldest != NIL;
+        let slot = if dropped {
+            let slot = self.oldest;
+            self.unlink(slot);
+            let old = std::mem::replace(&mut self.entries[slot], entry);
+            self.index.remove(&old.url);
+            self.bytes = self.bytes.saturating_sub(old.bytes);
+            slot
+        } else {
+            self.entries.push(entry);
+            self.entries.len() - 1
+        };
+        self.index.insert(url, slot);
+        self.bytes = self.bytes.saturating_add(bytes);
+        self.push_newest(slot);
+        (None, dropped)
+    }
+}
+
+/// ### English
+/// Image URL table of one shard, shared by every view delegate (Servo thread only).
+///
+/// ### 中文
+/// 单个分片的图片 URL 表，由所有 view delegate 共享（仅 Servo 线程使用）。
+pub(super) struct ImageLoadTracker {
+    /// ### English
+    /// Counters published to embedder threads.
+    ///
+    /// ### 中文
+    /// 发布给宿主线程的计数器。
+    log: Arc<ImageLoadLog>,
+    /// ### English
+    /// Least-recently-used URL table.
+    ///
+    /// ### 中文
+    /// 最近最少使用的 URL 表。
+    table: RefCell<UrlTable>,
+}
+
+impl ImageLoadTracker {
+    /// ### English
+    /// Creates an empty table publishing to `log`.
+    ///
+    /// #### Parameters
+    /// - `log`: Counters of this shard.
+    ///
+    /// ### 中文
+    /// 创建发布到 `log` 的空表。
+    ///
+    /// #### 参数
+    /// - `log`：该分片的计数器。
+    pub(super) fn new(log: Arc<ImageLoadLog>) -> Self {
+        Self {
+            log,
+            table: RefCell::new(UrlTable {
+                index: HashMap::new(),
+                entries: Vec::new(),
+                newest: NIL,
+                oldest: NIL,
+                bytes: 0,
+            }),
+        }
+    }
+
+    /// ### English
+    /// Records an image loaded by a view (`"<width>,<height>,<url>"`).
+    ///
+    /// #### Parameters
+    /// - `view`: Token of the loading view.
+    /// - `payload`: Host message payload after the `image-load:` tag.
+    ///
+    /// ### 中文
+    /// 记录某个 view 加载的一张图片（`"<width>,<height>,<url>"`）。
+    ///
+    /// #### 参数
+    /// - `view`：加载该图片的 view 的 token。
+    /// - `payload`：`image-load:` 标签之后的宿主消息载荷。
+    pub(super) fn record_load(&self, view: u64, payload: &str) {
+        let mut parts = payload.splitn(3, ',');
+        let (Some(Ok(width)), Some(Ok(height)), Some(url)) = (
+            parts.next().map(str::parse::<u64>),
+            parts.next().map(str::parse::<u64>),
+            parts.next(),
+        ) else {
+            return;
+        };
+
+        let bytes = width.saturating_mul(height).saturating_mul(BYTES_PER_PIXEL);
+        let mut table = self.table.borrow_mut();
+        match table.load(url, bytes, view) {
+            (Some(first_view), _) => self.log.record_load(true, first_view != view),
+            (None, dropped) => {
+                self.log.record_load(false, false);
+                self.log
+                    .set_usage(table.index.len() as u64, table.bytes, u64::from(dropped));
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! ### English
+//! Input events sent with a correlation ID, waiting for Servo to report how the page handled them.
+//!
+//! Servo reports every input event it dispatched through `notify_input_event_handled` with the
+//! flags the page set on it. Pointer events also get a hit test against the page's elements, so
+//! embedders can tell a click on a widget from a click on empty space and pass the latter through
+//! to the game.
+//!
+//! ### 中文
+//! 携带关联 ID 发送、等待 Servo 报告页面处理结果的输入事件。
+//!
+//! Servo 会通过 `notify_input_event_handled` 报告它派发的每个输入事件，并附带页面为其设置的标志。指针事件
+//! 还会针对页面元素进行命中测试，使宿主能区分点击控件与点击空白区域，并把后者穿透给游戏。
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_INPUT_RESULT_CONSUMED, XIAN_WEB_ENGINE_INPUT_RESULT_DEFAULT_PREVENTED,
+    XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT, XIAN_WEB_ENGINE_STATUS_OK,
+    XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT,
+};
+
+use super::super::events::{ViewEvent, ViewEventQueue};
+use super::super::scripts::HIT_TEST_SCRIPT;
+
+/// ### English
+/// Most tracked events awaiting their result; the oldest is forgotten beyond this.
+///
+/// ### 中文
+/// 等待结果的跟踪事件上限；超出时遗忘最早的事件。
+const MAX_TRACKED_INPUTS: usize = 64;
+
+/// ### English
+/// One input event awaiting its result.
+///
+/// ### 中文
+/// 一个等待结果的输入事件。
+struct TrackedInput {
+    /// ### English
+    /// Servo input event ID.
+    ///
+    /// ### 中文
+    /// Servo 输入事件 ID。
+    id: servo::InputEventId,
+    /// ### English
+    /// Embedder correlation ID reported back in the `INPUT_RESULT` event.
+    ///
+    /// ### 中文
+    /// 在 `INPUT_RESULT` 事件中回报的宿主关联 ID。
+    correlation_id: u64,
+    /// ### English
+    /// Pointer position to hit test (device pixels), `None` for keys.
+    ///
+    /// ### 中文
+    /// 需要命中测试的指针位置（设备像素）；按键事件为 `None`。
+    point: Option<(f32, f32)>,
+}
+
+/// ### English
+/// Tracked input events of one view, shared between its delegate and its entry.
+///
+/// ### 中文
+/// 单个 view 的跟踪输入事件，在其 delegate 与条目之间共享。
+#[derive(Default)]
+pub(super) struct InputResultTracker {
+    /// ### English
+    /// Events awaiting their result, oldest first.
+    ///
+    /// ### 中文
+    /// 等待结果的事件，最早的在前。
+    pending: RefCell<VecDeque<TrackedInput>>,
+}
+
+impl InputResultTracker {
+    /// ### English
+    /// Starts tracking a dispatched event.
+    ///
+    /// #### Parameters
+    /// - `id`: Servo input event ID returned by the dispatch.
+    /// - `correlation_id`: Embedder correlation ID.
+    /// - `point`: Pointer position to hit test (device pixels), `None` for keys.
+    ///
+    /// ### 中文
+    /// 开始跟踪一个已派发的事件。
+    ///
+    /// #### 参数
+    /// - `id`：派发返回的 Servo 输入事件 ID。
+    /// - `correlation_id`：宿主关联 ID。
+    /// - `point`：需要命中测试的指针位置（设备像素）；按键事件为 `None`。
+    pub(super) fn track(
+        &self,
+        id: servo::InputEventId,
+        correlation_id: u64,
+        point: Option<(f32, f32)>,
+    ) {
+        let mut pending = self.pending.borrow_mut();
+        if pending.len() == MAX_TRACKED_INPUTS {
+            pending.pop_front();
+        }
+        pending.push_back(TrackedInput {
+            id,
+            correlation_id,
+            point,
+        });
+    }
+
+    /// ### English
+    /// Reports the result of a tracked event as an `INPUT_RESULT` event, after hit testing pointer
+    /// events. Untracked events are ignored.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that handled the event.
+    /// - `id`: Servo input event ID.
+    /// - `result`: Flags the page set on the event.
+    /// - `events`: Per-view event queue.
+    ///
+    /// ### 中文
+    /// 在对指针事件完成命中测试后，以 `INPUT_RESULT` 事件报告跟踪事件的结果。未跟踪的事件会被忽略。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：处理该事件的 WebView。
+    /// - `id`：Servo 输入事件 ID。
+    /// - `result`：页面为该事件设置的标志。
+    /// - `events`：每 view 事件队列。
+    pub(super) fn report(
+        &self,
+        servo_webview: &servo::WebView,
+        id: servo::InputEventId,
+        result: servo::InputEventResult,
+        events: &Arc<ViewEventQueue>,
+    ) {
+        let tracked = {
+            let mut pending = self.pending.borrow_mut();
+            let index = pending.iter().position(|tracked| tracked.id == id);
+            index.and_then(|index| pending.remove(index))
+        };
+        let Some(tracked) = tracked else {
+            return;
+        };
+
+        let mut flags = 0;
+        if result.contains(servo::InputEventResult::DefaultPrevented) {
+            flags |= XIAN_WEB_ENGINE_INPUT_RESULT_DEFAULT_PREVENTED;
+        }
+        if result.contains(servo::InputEventResult::Consumed) {
+            flags |= XIAN_WEB_ENGINE_INPUT_RESULT_CONSUMED;
+        }
+        let Some((x, y)) = tracked.point else {
+            events.push(result_event(tracked.correlation_id, flags));
+            return;
+        };
+
+        let events = events.clone();
+        let correlation_id = tracked.correlation_id;
+        servo_webview.evaluate_javascript(format!("({HIT_TEST_SCRIPT})({x}, {y})"), move |hit| {
+            if matches!(hit, Ok(servo::JSValue::Boolean(true))) {
+                flags |= XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT;
+            }
+            events.push(result_event(correlation_id, flags));
+        });
+    }
+}
+
+/// ### English
+/// Builds an `INPUT_RESULT` event.
+///
+/// #### Parameters
+/// - `correlation_id`: Embedder correlation ID.
+/// - `flags`: `XIAN_WEB_ENGINE_INPUT_RESULT_*` mask.
+///
+/// ### 中文
+/// 构造一个 `INPUT_RESULT` 事件。
+///
+/// #### 参数
+/// - `correlation_id`：宿主关联 ID。
+/// - `flags`：`XIAN_WEB_ENGINE_INPUT_RESULT_*` 掩码。
+fn result_event(correlation_id: u64, flags: u32) -> ViewEvent {
+    let mut event = ViewEvent::new(
+        XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT,
+        XIAN_WEB_ENGINE_STATUS_OK,
+        correlation_id,
+    );
+    event.value0 = i64::from(flags != 0);
+    event.value1 = i64::from(flags);
+    event
+}
@@ -5,25 +5,75 @@
 //! 独立 Servo 线程：持有共享 GL 上下文并驱动 Servo 事件循环。
 use std::ffi::c_void;
 use std::path::PathBuf;
+use std::rc::Rc;
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
 };
 use std::thread;
+use std::time::{Duration, Instant};
 
+use crate::engine::dns;
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS, XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY,
+};
+use crate::engine::init_progress::{
+    self, XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT, XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT,
+};
 use crate::engine::lockfree::OneShot;
+use crate::engine::profiling;
 use crate::engine::refresh::RefreshScheduler;
 use crate::engine::rendering::GlfwSharedContext;
 use crate::engine::resources;
+use crate::engine::thread_config::{ThreadConfig, XianWebEngineThreadPoolConfig};
+use crate::engine::trace::{self, TRACE_SPAN_COMMAND_DRAIN, TRACE_SPAN_SPIN_EVENT_LOOP};
 use crate::engine::vsync::VsyncCallbackQueue;
+use crate::engine::watchdog::{GlErrorLog, Heartbeat};
 
+use super::image_limits::XianWebEngineImageLimits;
+use super::image_loads::ImageLoadLog;
 use super::pending::PendingIdQueue;
 use super::queue::CommandQueue;
 
+use fonts::FontRegistry;
+use group::ViewGroupTable;
+use image_loads::ImageLoadTracker;
+use network_policy::NetworkPolicy;
+use retired::RetiredContexts;
+use textures::SharedTextureTable;
 use view::ViewEntry;
+use webdriver::WebDriverEndpoint;
 
 mod commands;
+mod external_streams;
+mod file_picker;
+mod fonts;
+mod full_page;
+mod group;
+mod heap_limit;
+mod host_channel;
+mod host_functions;
+mod image_loads;
+mod input_results;
+mod network_policy;
+mod permissions;
+mod pointer_lock;
+mod replay;
+mod retired;
+#[cfg(feature = "screencast")]
+mod screencast;
+mod session;
+mod slow_script;
+mod textures;
 mod view;
+mod webdriver;
+
+/// ### English
+/// Shortest time between two event-loop spins while the engine is idle.
+///
+/// ### 中文
+/// 引擎空闲时两次事件循环驱动之间的最短间隔。
+const IDLE_SPIN_INTERVAL: Duration = Duration::from_millis(250);
 
 /// ### English
 /// Servo thread entry function.
@@ -33,27 +83,45 @@ mod view;
 ///
 /// 1. Install process-wide rustls provider (best-effort).
 /// 2. Apply optional resource/config directories.
-/// 3. Build Servo with a coalescing thread waker.
-/// 4. Create a shared offscreen GLFW context (shares objects with the embedder window).
+/// 3. Build Servo with a coalescing thread waker, its HTTP(S) traffic routed through the loopback
+///    proxy when the engine needs one.
+/// 4. Create a shared offscreen GLFW (or EGL) context (shares objects with the embedder context).
 /// 5. Run the main loop:
 ///    - Drain control commands
-///    - Process per-view pending work
+///    - Process per-view pending work (grouped views are drained together, in member order)
 ///    - Spin Servo's internal event loop
-///    - Park until woken
+///    - Deny permission requests that timed out and run slow-script detection
+///    - Delete GL resources of destroyed views once the consumer released them
+///    - Park until woken (or until the next permission / slow-script / deferred-deletion deadline)
+///
+/// Deadline checks run on every pass, including passes that loop straight back because Servo woke
+/// the thread during the spin, so a busy page cannot starve timeouts; the park timeout is always
+/// the nearest of those deadlines.
+///
+/// While the engine is idle (`xian_web_engine_set_idle`), Servo wakes are coalesced so the event
+/// loop spins at most once per `IDLE_SPIN_INTERVAL`; only control commands cut the wait short.
+///
+/// The watchdog heartbeat is refreshed at the top of every iteration and cleared before parking.
 ///
 /// Threading notes:
 /// - Servo's internal worker thread pools can be tuned via the embedder's ABI configuration.
 ///   `thread_pool_cap = 0` means "no cap" (use CPU parallelism); otherwise we cap to
-///   `min(CPU, thread_pool_cap)`.
+///   `min(CPU, thread_pool_cap)`. Individual pools can be sized explicitly via `thread_pools`.
 ///
 /// #### Parameters
-/// - `glfw_shared_window_handle`: Embedder GLFW window handle whose context will be shared.
+/// - `glfw_shared_window_handle`: Embedder GLFW window handle (or `EGLContext` with
+///   `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`) whose context will be shared.
 /// - `resources_dir`: Optional resource directory override.
 /// - `config_dir`: Optional Servo config directory override.
 /// - `vsync_queue`: Vsync callback queue used by Servo refresh.
 /// - `pending_queue`: Pending view-id queue used to schedule per-view work.
 /// - `command_queue`: Control-command queue from embedder threads.
 /// - `thread_pool_cap`: Servo worker thread cap (`0` means no cap).
+/// - `thread_pools`: Per-pool worker counts (`0` fields fall back to `thread_pool_cap`).
+/// - `engine_flags`: Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+/// - `heartbeat`: Watchdog heartbeat updated every loop iteration.
+/// - `gl_errors`: GL error log filled by the rendering contexts in paranoid GL mode.
+/// - `image_loads`: Image load counters published by this thread.
 /// - `init`: One-shot used to report initialization success/failure to the spawner.
 ///
 /// ### 中文
@@ -64,26 +132,43 @@ mod view;
 ///
 /// 1. 进程内一次性安装 rustls provider（尽力而为）。
 /// 2. 应用可选的资源/配置目录。
-/// 3. 构建 Servo，并使用合并唤醒的线程 waker。
-/// 4. 创建共享的离屏 GLFW 上下文（与宿主 window 共享对象）。
+/// 3. 构建 Servo，并使用合并唤醒的线程 waker；引擎需要时其 HTTP(S) 流量经由回环代理转发。
+/// 4. 创建共享的离屏 GLFW（或 EGL）上下文（与宿主上下文共享对象）。
 /// 5. 进入主循环：
 ///    - drain 控制命令
-///    - 处理每 view 的 pending work
+///    - 处理每 view 的 pending work（同组 view 按成员顺序一并 drain）
 ///    - 驱动 Servo 内部事件循环
-///    - park 等待唤醒
+///    - 拒绝已超时的权限请求，并执行慢脚本检测
+///    - 在消费者释放后删除已销毁 view 的 GL 资源
+///    - park 等待唤醒（或直到下一个权限/慢脚本/延迟删除截止时间）
+///
+/// 截止时间检查在每一轮都会执行，包括因 Servo 在驱动期间唤醒线程而直接进入下一轮的情况，因此繁忙的页面
+/// 不会饿死各类超时；park 的超时时间始终取这些截止时间中最近的一个。
+///
+/// 引擎空闲期间（`xian_web_engine_set_idle`），Servo 的唤醒会被合并，使事件循环每个 `IDLE_SPIN_INTERVAL`
+/// 最多驱动一次；只有控制命令会提前结束等待。
+///
+/// 看门狗心跳在每轮迭代开始时刷新，并在 park 之前清除。
 ///
 /// 线程说明：
 /// - Servo 内部工作线程池可通过宿主侧 ABI 配置调优：
 ///   `thread_pool_cap = 0` 表示“不封顶”（使用 CPU 并行度）；否则上限为 `min(CPU, thread_pool_cap)`。
+///   也可通过 `thread_pools` 单独指定各线程池大小。
 ///
 /// #### 参数
-/// - `glfw_shared_window_handle`：宿主 GLFW window 的句柄；其上下文会与 Servo 线程共享。
+/// - `glfw_shared_window_handle`：宿主 GLFW window 的句柄（设置 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`
+///   时为 `EGLContext`）；其上下文会与 Servo 线程共享。
 /// - `resources_dir`：可选的资源目录覆盖。
 /// - `config_dir`：可选的 Servo 配置目录覆盖。
 /// - `vsync_queue`：Servo refresh 使用的 vsync 回调队列。
 /// - `pending_queue`：用于调度每 view 工作的 pending view-id 队列。
 /// - `command_queue`：来自宿主线程的控制命令队列。
 /// - `thread_pool_cap`：Servo 工作线程上限（`0` 表示不封顶）。
+/// - `thread_pools`：各线程池的工作线程数（为 `0` 的字段回退到 `thread_pool_cap`）。
+/// - `engine_flags`：引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+/// - `heartbeat`：每轮循环都会更新的看门狗心跳。
+/// - `gl_errors`：paranoid GL 模式下由渲染上下文填充的 GL 错误记录。
+/// - `image_loads`：该线程发布的图片加载计数器。
 /// - `init`：用于向创建方回报初始化成功/失败的一次性通道。
 #[allow(clippy::too_many_arguments)]
 pub(super) fn run_servo_thread(
@@ -94,6 +179,11 @@ pub(super) fn run_servo_thread(
     pending_queue: Arc<PendingIdQueue>,
     command_queue: Arc<CommandQueue>,
     thread_pool_cap: u32,
+    thread_pools: XianWebEngineThreadPoolConfig,
+    engine_flags: u32,
+    heartbeat: Arc<Heartbeat>,
+    gl_errors: Arc<GlErrorLog>,
+    image_loads: Arc<ImageLoadLog>,
     init: Arc<OneShot<Result<(), String>>>,
 ) {
     /// ### English
@@ -165,13 +255,15 @@ pub(super) fn run_servo_thread(
         thread: thread::current(),
         pending: wake_pending.clone(),
     });
+    let webdriver_waker = waker.clone_box();
 
+    let (time_profiling, time_profiler_trace_path) = profiling::servo_options();
     let opts = servo::Opts {
-        multiprocess: false,
+        multiprocess: (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS) != 0,
         force_ipc: false,
         nonincremental_layout: false,
-        time_profiling: None,
-        time_profiler_trace_path: None,
+        time_profiling,
+        time_profiler_trace_path,
         debug: Default::default(),
         background_hang_monitor: false,
         unminify_js: false,
@@ -193,17 +285,34 @@ pub(super) fn run_servo_thread(
     } else {
         cpu_threads.min(thread_pool_cap as i64).max(1)
     };
+    let pool_threads = |requested: u32| {
+        if requested == 0 {
+            tuned_threads
+        } else {
+            requested as i64
+        }
+    };
+    let storage_threads = pool_threads(thread_pools.storage_threads);
+    let proxy = dns::Proxy::start_if_needed(
+        (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY) != 0,
+    );
+    let proxy_uri = proxy
+        .as_ref()
+        .map(|proxy| proxy.uri().to_string())
+        .unwrap_or_default();
 
     let preferences = servo::Preferences {
         gfx_precache_shaders: true,
-        layout_threads: tuned_threads,
+        layout_threads: pool_threads(thread_pools.layout_threads),
         threadpools_fallback_worker_num: tuned_threads,
-        threadpools_async_runtime_workers_max: tuned_threads,
-        threadpools_image_cache_workers_max: tuned_threads,
-        threadpools_resource_workers_max: tuned_threads,
-        threadpools_webrender_workers_max: tuned_threads,
-        threadpools_indexeddb_workers_max: tuned_threads,
-        threadpools_webstorage_workers_max: tuned_threads,
+        threadpools_async_runtime_workers_max: pool_threads(thread_pools.async_runtime_threads),
+        threadpools_image_cache_workers_max: pool_threads(thread_pools.image_cache_threads),
+        threadpools_resource_workers_max: pool_threads(thread_pools.resource_threads),
+        threadpools_webrender_workers_max: pool_threads(thread_pools.webrender_threads),
+        threadpools_indexeddb_workers_max: storage_threads,
+        threadpools_webstorage_workers_max: storage_threads,
+        network_http_proxy_uri: proxy_uri.clone(),
+        network_https_proxy_uri: proxy_uri,
         ..Default::default()
     };
 
@@ -212,9 +321,10 @@ pub(super) fn run_servo_thread(
         .preferences(preferences)
         .event_loop_waker(waker)
         .build();
+    init_progress::report(XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT);
 
     let glfw_shared_window_ptr = glfw_shared_window_handle as *mut c_void;
-    let shared_ctx = match GlfwSharedContext::new(glfw_shared_window_ptr) {
+    let shared_ctx = match GlfwSharedContext::new(glfw_shared_window_ptr, engine_flags, gl_errors) {
         Ok(ctx) => ctx,
         Err(err) => {
             let _ = init.send(Err(err));
@@ -222,6 +332,7 @@ pub(super) fn run_servo_thread(
         }
     };
 
+    init_progress::report(XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT);
     let _ = init.send(Ok(()));
 
     let mut views: Vec<Option<ViewEntry>> = Vec::with_capacity(64);
@@ -229,26 +340,63 @@ pub(super) fn run_servo_thread(
     let mut next_view_id: u32 = 1;
     let mut next_view_token: u64 = 1;
     let mut refresh_scheduler: Option<Arc<RefreshScheduler>> = None;
+    let mut thread_config: Option<ThreadConfig> = None;
+    let mut view_groups = ViewGroupTable::new();
+    let mut fonts = FontRegistry::default();
+    let mut network = NetworkPolicy::new(proxy);
+    let mut image_limits = XianWebEngineImageLimits::default();
+    let image_loads = Rc::new(ImageLoadTracker::new(image_loads));
+    let mut webdriver = WebDriverEndpoint::new(webdriver_waker);
+    let textures = Rc::new(SharedTextureTable::new(shared_ctx.clone()));
+    let mut retired = RetiredContexts::default();
+    let mut drain_epoch: u64 = 0;
+    let mut idle = false;
 
     loop {
-        if commands::drain_commands(
+        heartbeat.beat();
+        let drain_span = trace::span(TRACE_SPAN_COMMAND_DRAIN);
+        let shutdown = commands::drain_commands(
             &servo,
             &shared_ctx,
             &vsync_queue,
             &command_queue,
             &mut refresh_scheduler,
+            &mut thread_config,
+            &mut idle,
             &mut views,
+            &mut view_groups,
+            &mut fonts,
+            &mut network,
+            &mut image_limits,
+            &image_loads,
+            &mut webdriver,
+            &textures,
+            &mut retired,
             &mut free_view_ids,
             &mut next_view_id,
             &mut next_view_token,
-        ) {
+        );
+        drop(drain_span);
+        if shutdown {
             return;
         }
 
+        drain_epoch = drain_epoch.wrapping_add(1);
         while let Some(id) = pending_queue.pop() {
             let Some(entry) = views.get_mut(id as usize).and_then(Option::as_mut) else {
                 continue;
             };
+            let group = entry.group;
+            if group != 0
+                && let Some(members) = view_groups.members_to_drain(group, drain_epoch)
+            {
+                for &member in members {
+                    if let Some(entry) = views.get_mut(member as usize).and_then(Option::as_mut) {
+                        entry.process_pending();
+                    }
+                }
+                continue;
+            }
             entry.process_pending();
         }
 
@@ -258,12 +406,71 @@ pub(super) fn run_servo_thread(
             }
         }
 
+        webdriver.forward(&servo);
+        let spin_span = trace::span(TRACE_SPAN_SPIN_EVENT_LOOP);
         servo.spin_event_loop();
+        drop(spin_span);
 
-        if wake_pending.swap(false, Ordering::Relaxed) {
+        let woken = wake_pending.swap(false, Ordering::Relaxed);
+        let now = Instant::now();
+        let deadline = views
+            .iter_mut()
+            .filter_map(Option::as_mut)
+            .flat_map(|entry| {
+                [
+                    entry.expire_permissions(now),
+                    entry.check_slow_script(now),
+                    entry.run_replay(now),
+                    entry.run_synthetic(now),
+                    entry.run_resize_debounce(now),
+                    entry.run_snapshot(now),
+                    #[cfg(feature = "screencast")]
+                    entry.run_screencast(now),
+                ]
+            })
+            .flatten()
+            .chain(retired.collect(now))
+            .min();
+        if woken && !idle {
+            continue;
+        }
+        heartbeat.idle();
+        if idle {
+            let throttle = now + IDLE_SPIN_INTERVAL;
+            match deadline.map(|deadline| deadline.max(throttle)) {
+                Some(deadline) => park_idle_until(deadline, &command_queue),
+                None if woken => park_idle_until(throttle, &command_queue),
+                None => thread::park(),
+            }
             continue;
         }
+        match deadline {
+            Some(deadline) => thread::park_timeout(deadline.saturating_duration_since(now)),
+            None => thread::park(),
+        }
+    }
+}
 
-        thread::park();
+/// ### English
+/// Parks the idle Servo thread until `deadline`, ignoring Servo wakes; returns early once a control
+/// command is queued.
+///
+/// #### Parameters
+/// - `deadline`: Time to park until.
+/// - `command_queue`: Control-command queue from embedder threads.
+///
+/// ### 中文
+/// 让空闲的 Servo 线程 park 到 `deadline`，忽略 Servo 的唤醒；一旦有控制命令排队则提前返回。
+///
+/// #### 参数
+/// - `deadline`：park 的截止时间。
+/// - `command_queue`：来自宿主线程的控制命令队列。
+fn park_idle_until(deadline: Instant, command_queue: &CommandQueue) {
+    loop {
+        let now = Instant::now();
+        if now >= deadline || command_queue.len() != 0 {
+            return;
+        }
+        thread::park_timeout(deadline - now);
     }
 }
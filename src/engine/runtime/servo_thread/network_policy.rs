@@ -0,0 +1,218 @@
+//! ### English
+//! Servo-thread side of the engine network mode.
+//!
+//! The mode is enforced natively at two layers: the gate checked for every resource load cancels
+//! requests the mode forbids, and the shard's loopback proxy refuses every upstream connection (and
+//! closes the live ones) while not online, so `CACHE_ONLY` loads can only be answered by Servo's
+//! HTTP cache. WebRTC, whose peer connections go through neither, is switched off with Servo's
+//! `dom_webrtc_enabled` preference while any shard in the process is not online.
+//!
+//! ### 中文
+//! 引擎网络模式在 Servo 线程侧的状态。
+//!
+//! 模式在两层原生执行：每次资源加载都会检查的闸门会取消该模式禁止的请求；分片的回环代理在非在线模式下拒绝
+//! 所有上游连接（并关闭存活的连接），因此 `CACHE_ONLY` 下的加载只能由 Servo 的 HTTP 缓存应答。WebRTC 的
+//! 对等连接不经过这两层，因此只要进程内有任一分片处于非在线模式，就通过 Servo 的 `dom_webrtc_enabled`
+//! 首选项将其关闭。
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use url::Url;
+
+use crate::engine::dns::Proxy;
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY, XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE,
+};
+
+/// ### English
+/// Number of shards in the process that are not online, and the `dom_webrtc_enabled` value to
+/// restore once none is.
+///
+/// ### 中文
+/// 进程内处于非在线模式的分片数，以及全部恢复在线后要还原的 `dom_webrtc_enabled` 值。
+static OFFLINE_SHARDS: Mutex<(usize, bool)> = Mutex::new((0, false));
+
+/// ### English
+/// Network mode gate consulted by view delegates for every resource load (Servo thread only).
+///
+/// ### 中文
+/// view delegate 在每次资源加载时查询的网络模式闸门（仅 Servo 线程持有）。
+pub(super) struct NetworkGate {
+    /// ### English
+    /// Current mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`).
+    ///
+    /// ### 中文
+    /// 当前模式（`XIAN_WEB_ENGINE_NETWORK_MODE_*`）。
+    mode: Cell<u32>,
+    /// ### English
+    /// Whether Servo's HTTP(S) traffic goes through the shard proxy; without it, `CACHE_ONLY`
+    /// cannot keep cache misses off the network and is treated as offline.
+    ///
+    /// ### 中文
+    /// Servo 的 HTTP(S) 流量是否经由分片代理；没有代理时 `CACHE_ONLY` 无法阻止缓存未命中访问网络，
+    /// 因此按离线处理。
+    proxied: bool,
+}
+
+impl NetworkGate {
+    /// ### English
+    /// Returns whether a request for `url` may proceed.
+    ///
+    /// Non-network schemes (`data:`, `blob:`, `file:`, engine resources, ...) are always allowed.
+    /// `CACHE_ONLY` lets `http(s)` through to Servo's HTTP cache, whose misses the offline proxy
+    /// then fails.
+    ///
+    /// #### Parameters
+    /// - `url`: Requested URL.
+    ///
+    /// ### 中文
+    /// 返回对 `url` 的请求是否可以继续。
+    ///
+    /// 非网络 scheme（`data:`、`blob:`、`file:`、引擎资源等）始终放行。`CACHE_ONLY` 会将 `http(s)` 放行给
+    /// Servo 的 HTTP 缓存，缓存未命中的请求随后由离线的代理使其失败。
+    ///
+    /// #### 参数
+    /// - `url`：请求的 URL。
+    pub(super) fn allows(&self, url: &Url) -> bool {
+        if !matches!(url.scheme(), "http" | "https" | "ws" | "wss" | "ftp") {
+            return true;
+        }
+
+        match self.mode.get() {
+            XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE => true,
+            XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY => {
+                self.proxied && matches!(url.scheme(), "http" | "https")
+            }
+            _ => false,
+        }
+    }
+}
+
+/// ### English
+/// Network settings shared by the views of this shard (Servo thread only).
+///
+/// ### 中文
+/// 该分片所有 view 共享的网络设置（仅 Servo 线程持有）。
+pub(super) struct NetworkPolicy {
+    /// ### English
+    /// Mode gate shared with every view delegate.
+    ///
+    /// ### 中文
+    /// 与每个 view delegate 共享的模式闸门。
+    gate: Rc<NetworkGate>,
+    /// ### English
+    /// Loopback proxy carrying this shard's HTTP(S) traffic (`None` if it failed to start).
+    ///
+    /// ### 中文
+    /// 承载该分片 HTTP(S) 流量的回环代理（启动失败时为 `None`）。
+    proxy: Option<Proxy>,
+}
+
+impl NetworkPolicy {
+    /// ### English
+    /// Creates an online policy.
+    ///
+    /// #### Parameters
+    /// - `proxy`: Loopback proxy Servo was configured with, if it started.
+    ///
+    /// ### 中文
+    /// 创建处于在线模式的策略。
+    ///
+    /// #### 参数
+    /// - `proxy`：Servo 所配置的回环代理（若成功启动）。
+    pub(super) fn new(proxy: Option<Proxy>) -> Self {
+        Self {
+            gate: Rc::new(NetworkGate {
+                mode: Cell::new(XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE),
+                proxied: proxy.is_some(),
+            }),
+            proxy,
+        }
+    }
+
+    /// ### English
+    /// Switches the network mode: the gate, the proxy and the process-wide WebRTC switch change at
+    /// once. Documents created while WebRTC is off have no `RTCPeerConnection`.
+    ///
+    /// #### Parameters
+    /// - `mode`: New mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`).
+    ///
+    /// ### 中文
+    /// 切换网络模式：闸门、代理与进程级 WebRTC 开关立即变化。WebRTC 关闭期间创建的文档没有
+    /// `RTCPeerConnection`。
+    ///
+    /// #### 参数
+    /// - `mode`：新的模式（`XIAN_WEB_ENGINE_NETWORK_MODE_*`）。
+    pub(super) fn set_mode(&mut self, mode: u32) {
+        let was_offline = self.offline();
+        self.gate.mode.set(mode);
+        if let Some(proxy) = &self.proxy {
+            proxy.set_online(mode == XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE);
+        }
+        if was_offline != self.offline() {
+            count_offline_shard(self.offline());
+        }
+    }
+
+    /// ### English
+    /// Returns the mode gate (handed to newly created view delegates).
+    ///
+    /// ### 中文
+    /// 返回模式闸门（交给新建 view 的 delegate）。
+    pub(super) fn gate(&self) -> &Rc<NetworkGate> {
+        &self.gate
+    }
+
+    /// ### English
+    /// Returns whether the current mode keeps pages off the network.
+    ///
+    /// ### 中文
+    /// 返回当前模式是否禁止页面访问网络。
+    fn offline(&self) -> bool {
+        self.gate.mode.get() != XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE
+    }
+}
+
+impl Drop for NetworkPolicy {
+    fn drop(&mut self) {
+        if self.offline() {
+            count_offline_shard(false);
+        }
+    }
+}
+
+/// ### English
+/// Records a shard leaving or entering the online mode, turning WebRTC off while at least one shard
+/// is not online and restoring its previous setting afterwards.
+///
+/// #### Parameters
+/// - `offline`: Whether the shard went offline (`true`) or came back online (`false`).
+///
+/// ### 中文
+/// 记录一个分片离开或回到在线模式：只要至少有一个分片处于非在线模式就关闭 WebRTC，之后恢复其原先的设置。
+///
+/// #### 参数
+/// - `offline`：分片是转为非在线（`true`）还是恢复在线（`false`）。
+fn count_offline_shard(offline: bool) {
+    let Ok(mut state) = OFFLINE_SHARDS.lock() else {
+        return;
+    };
+    let (count, restore) = &mut *state;
+    let mut preferences = servo::prefs::get().clone();
+    if offline {
+        if *count == 0 {
+            *restore = preferences.dom_webrtc_enabled;
+            preferences.dom_webrtc_enabled = false;
+            servo::prefs::set(preferences);
+        }
+        *count += 1;
+    } else {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            preferences.dom_webrtc_enabled = *restore;
+            servo::prefs::set(preferences);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! ### English
+//! Servo-thread permission requests waiting for the embedder's answer.
+//!
+//! ### 中文
+//! 在 Servo 线程上等待宿主应答的权限请求。
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_PERMISSION_BACKGROUND_SYNC, XIAN_WEB_ENGINE_PERMISSION_BLUETOOTH,
+    XIAN_WEB_ENGINE_PERMISSION_CAMERA, XIAN_WEB_ENGINE_PERMISSION_DEVICE_INFO,
+    XIAN_WEB_ENGINE_PERMISSION_GEOLOCATION, XIAN_WEB_ENGINE_PERMISSION_MICROPHONE,
+    XIAN_WEB_ENGINE_PERMISSION_MIDI, XIAN_WEB_ENGINE_PERMISSION_NOTIFICATIONS,
+    XIAN_WEB_ENGINE_PERMISSION_PERSISTENT_STORAGE, XIAN_WEB_ENGINE_PERMISSION_PUSH,
+    XIAN_WEB_ENGINE_PERMISSION_SPEAKER,
+};
+
+/// ### English
+/// How long a permission request may stay unanswered before it is denied.
+///
+/// ### 中文
+/// 权限请求在被自动拒绝前可保持未应答的时长。
+pub(super) const PERMISSION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// ### English
+/// Maps a Servo permission feature to its `XIAN_WEB_ENGINE_PERMISSION_*` code.
+///
+/// #### Parameters
+/// - `feature`: Servo permission feature.
+///
+/// ### 中文
+/// 将 Servo 权限特性映射为 `XIAN_WEB_ENGINE_PERMISSION_*` 代码。
+///
+/// #### 参数
+/// - `feature`：Servo 权限特性。
+pub(super) fn permission_feature_code(feature: servo::PermissionFeature) -> i64 {
+    match feature {
+        servo::PermissionFeature::Geolocation => XIAN_WEB_ENGINE_PERMISSION_GEOLOCATION,
+        servo::PermissionFeature::Notifications => XIAN_WEB_ENGINE_PERMISSION_NOTIFICATIONS,
+        servo::PermissionFeature::Push => XIAN_WEB_ENGINE_PERMISSION_PUSH,
+        servo::PermissionFeature::Midi => XIAN_WEB_ENGINE_PERMISSION_MIDI,
+        servo::PermissionFeature::Camera => XIAN_WEB_ENGINE_PERMISSION_CAMERA,
+        servo::PermissionFeature::Microphone => XIAN_WEB_ENGINE_PERMISSION_MICROPHONE,
+        servo::PermissionFeature::Speaker => XIAN_WEB_ENGINE_PERMISSION_SPEAKER,
+        servo::PermissionFeature::DeviceInfo => XIAN_WEB_ENGINE_PERMISSION_DEVICE_INFO,
+        servo::PermissionFeature::BackgroundSync => XIAN_WEB_ENGINE_PERMISSION_BACKGROUND_SYNC,
+        servo::PermissionFeature::Bluetooth => XIAN_WEB_ENGINE_PERMISSION_BLUETOOTH,
+        servo::PermissionFeature::PersistentStorage => {
+            XIAN_WEB_ENGINE_PERMISSION_PERSISTENT_STORAGE
+        }
+    }
+}
+
+/// ### English
+/// Permission request waiting for the embedder's answer.
+///
+/// ### 中文
+/// 等待宿主应答的权限请求。
+pub(super) enum PendingPermission {
+    /// ### English
+    /// Request raised by Servo.
+    ///
+    /// ### 中文
+    /// 由 Servo 发起的请求。
+    Servo(servo::PermissionRequest),
+    /// ### English
+    /// Request raised by the engine itself (e.g. pointer lock), called once with the answer.
+    ///
+    /// ### 中文
+    /// 由引擎自身发起的请求（例如 pointer lock），以应答调用一次。
+    Engine(Box<dyn FnOnce(bool)>),
+}
+
+impl PendingPermission {
+    /// ### English
+    /// Grants or denies the request.
+    ///
+    /// #### Parameters
+    /// - `allow`: Whether to grant the permission.
+    ///
+    /// ### 中文
+    /// 授予或拒绝该请求。
+    ///
+    /// #### 参数
+    /// - `allow`：是否授予权限。
+    fn answer(self, allow: bool) {
+        match self {
+            Self::Servo(request) if allow => request.allow(),
+            Self::Servo(request) => request.deny(),
+            Self::Engine(callback) => callback(allow),
+        }
+    }
+}
+
+/// ### English
+/// Outstanding permission requests of one view, shared between its delegate and its entry.
+///
+/// Requests left unanswered are denied on expiry or when the view is destroyed.
+///
+/// ### 中文
+/// 单个 view 尚未应答的权限请求，在其 delegate 与条目之间共享。
+///
+/// 未应答的请求会在超时或 view 销毁时被拒绝。
+pub(super) struct PermissionTracker {
+    /// ### English
+    /// Next request ID handed to the embedder (starts at 1).
+    ///
+    /// ### 中文
+    /// 下一个交给宿主的请求 ID（从 1 开始）。
+    next_id: Cell<u64>,
+    /// ### English
+    /// Pending requests keyed by request ID, with their deny deadline.
+    ///
+    /// ### 中文
+    /// 按请求 ID 索引的待处理请求及其拒绝截止时间。
+    pending: RefCell<HashMap<u64, (PendingPermission, Instant)>>,
+}
+
+impl Default for PermissionTracker {
+    /// ### English
+    /// Creates an empty tracker whose first request ID is 1.
+    ///
+    /// ### 中文
+    /// 创建一个空的跟踪器，首个请求 ID 为 1。
+    fn default() -> Self {
+        Self {
+            next_id: Cell::new(1),
+            pending: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl PermissionTracker {
+    /// ### English
+    /// Stores a request until it is answered or expires, and returns its request ID.
+    ///
+    /// #### Parameters
+    /// - `request`: Pending permission request.
+    ///
+    /// ### 中文
+    /// 保存请求直至被应答或超时，并返回其请求 ID。
+    ///
+    /// #### 参数
+    /// - `request`：待处理的权限请求。
+    pub(super) fn insert(&self, request: PendingPermission) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1).max(1));
+        self.pending
+            .borrow_mut()
+            .insert(id, (request, Instant::now() + PERMISSION_TIMEOUT));
+        id
+    }
+
+    /// ### English
+    /// Answers a pending request; returns `false` if it is unknown or already expired.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID from the `PERMISSION_REQUEST` event.
+    /// - `allow`: Whether to grant the permission.
+    ///
+    /// ### 中文
+    /// 应答一个待处理请求；若请求未知或已超时则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `request_id`：`PERMISSION_REQUEST` 事件中的请求 ID。
+    /// - `allow`：是否授予权限。
+    pub(super) fn respond(&self, request_id: u64, allow: bool) -> bool {
+        let Some((request, _)) = self.pending.borrow_mut().remove(&request_id) else {
+            return false;
+        };
+        request.answer(allow);
+        true
+    }
+
+    /// ### English
+    /// Denies every request past its deadline and returns the earliest remaining deadline.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 拒绝所有已过截止时间的请求，并返回剩余请求中最早的截止时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn expire(&self, now: Instant) -> Option<Instant> {
+        let expired: Vec<PendingPermission> = {
+            let mut pending = self.pending.borrow_mut();
+            if pending.is_empty() {
+                return None;
+            }
+            let ids: Vec<u64> = pending
+                .iter()
+                .filter(|(_, (_, deadline))| *deadline <= now)
+                .map(|(&id, _)| id)
+                .collect();
+            ids.iter()
+                .filter_map(|id| pending.remove(id))
+                .map(|(request, _)| request)
+                .collect()
+        };
+        for request in expired {
+            request.answer(false);
+        }
+
+        self.pending
+            .borrow()
+            .values()
+            .map(|(_, deadline)| *deadline)
+            .min()
+    }
+}
+
+impl Drop for PermissionTracker {
+    /// ### English
+    /// Denies every request still unanswered when the view goes away.
+    ///
+    /// ### 中文
+    /// view 销毁时拒绝所有仍未应答的请求。
+    fn drop(&mut self) {
+        for (_, (request, _)) in self.pending.get_mut().drain() {
+            request.answer(false);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! ### English
+//! Pointer lock gating for one view.
+//!
+//! Servo has no native pointer lock, so the pointer-lock user script emulates the page API and
+//! asks the host through its channel. A request is only forwarded while the view has transient user
+//! activation (a mouse button or key press the embedder dispatched within
+//! `USER_ACTIVATION_WINDOW`, consumed by the request), and then goes to the embedder as a
+//! `PERMISSION_REQUEST` with feature `POINTER_LOCK`. `POINTER_LOCK` events are raised from here, never
+//! from page-reported state, so a page can at most lock itself without the cursor being captured.
+//!
+//! ### 中文
+//! 单个 view 的 pointer lock 门控。
+//!
+//! Servo 没有原生 pointer lock，因此 pointer-lock 用户脚本模拟页面 API，并通过宿主通道向宿主发起请求。仅当
+//! view 具有瞬时用户激活（宿主在 `USER_ACTIVATION_WINDOW` 内派发过鼠标按键或按键按下，请求会消耗该激活）时
+//! 才转发请求，随后以特性为 `POINTER_LOCK` 的 `PERMISSION_REQUEST` 交给宿主。`POINTER_LOCK` 事件只在此处产生，
+//! 从不依据页面上报的状态，因此页面最多只能在页面内自行锁定，而不会使光标被捕获。
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK,
+};
+use crate::engine::input_types::{
+    XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
+    XianWebEngineInputEvent,
+};
+
+use super::super::events::{ViewEvent, ViewEventQueue};
+use super::super::scripts::{POINTER_LOCK_ALLOW_SCRIPT, POINTER_LOCK_DENY_SCRIPT};
+
+/// ### English
+/// How long a mouse button or key press counts as transient user activation.
+///
+/// ### 中文
+/// 鼠标按键或按键按下被视为瞬时用户激活的时长。
+const USER_ACTIVATION_WINDOW: Duration = Duration::from_secs(5);
+
+/// ### English
+/// GLFW key code of Escape, which does not grant activation (it is the exit gesture).
+///
+/// ### 中文
+/// Escape 的 GLFW 键码；它是退出手势，不授予激活。
+const GLFW_KEY_ESCAPE: u32 = 256;
+
+/// ### English
+/// Pointer lock state of one view, shared by its entry and delegate (Servo thread only).
+///
+/// ### 中文
+/// 单个 view 的 pointer lock 状态，由其条目与 delegate 共享（仅 Servo 线程使用）。
+#[derive(Default)]
+pub(super) struct PointerLock {
+    /// ### English
+    /// Time of the last activating input event not yet consumed.
+    ///
+    /// ### 中文
+    /// 最近一次尚未被消耗的激活输入事件的时间。
+    activation: Cell<Option<Instant>>,
+    /// ### English
+    /// Whether the embedder was told the page is locked.
+    ///
+    /// ### 中文
+    /// 是否已告知宿主页面处于锁定状态。
+    locked: Cell<bool>,
+}
+
+impl PointerLock {
+    /// ### English
+    /// Records transient user activation for mouse button and (non-repeat, non-Escape) key presses.
+    ///
+    /// #### Parameters
+    /// - `raw`: Input event dispatched to the view.
+    ///
+    /// ### 中文
+    /// 为鼠标按键按下与按键按下（非重复、非 Escape）记录瞬时用户激活。
+    ///
+    /// #### 参数
+    /// - `raw`：派发给该 view 的输入事件。
+    pub(super) fn record_input(&self, raw: &XianWebEngineInputEvent) {
+        let activating = match raw.kind {
+            XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON => raw.mouse_action == 0,
+            XIAN_WEB_ENGINE_INPUT_KIND_KEY => {
+                raw.key_state == 0 && raw.repeat == 0 && raw.glfw_key != GLFW_KEY_ESCAPE
+            }
+            _ => false,
+        };
+        if activating {
+            self.activation.set(Some(Instant::now()));
+        }
+    }
+
+    /// ### English
+    /// Consumes transient user activation; returns whether the view had any.
+    ///
+    /// ### 中文
+    /// 消耗瞬时用户激活；返回该 view 是否具有激活。
+    pub(super) fn consume_activation(&self) -> bool {
+        self.activation
+            .take()
+            .is_some_and(|at| at.elapsed() <= USER_ACTIVATION_WINDOW)
+    }
+
+    /// ### English
+    /// Applies the embedder's answer to a pointer lock request: raises `POINTER_LOCK` on grant and
+    /// settles the page's pending request.
+    ///
+    /// #### Parameters
+    /// - `webview`: WebView that requested the lock.
+    /// - `events`: Per-view event queue.
+    /// - `allow`: Whether the embedder granted the lock.
+    ///
+    /// ### 中文
+    /// 应用宿主对 pointer lock 请求的应答：授予时产生 `POINTER_LOCK` 事件，并了结页面待处理的请求。
+    ///
+    /// #### 参数
+    /// - `webview`：请求锁定的 WebView。
+    /// - `events`：每 view 事件队列。
+    /// - `allow`：宿主是否授予锁定。
+    pub(super) fn settle(&self, webview: &servo::WebView, events: &ViewEventQueue, allow: bool) {
+        if !allow {
+            webview.evaluate_javascript(POINTER_LOCK_DENY_SCRIPT, |_| {});
+            return;
+        }
+        if !self.locked.replace(true) {
+            push_event(events, 1);
+        }
+        webview.evaluate_javascript(POINTER_LOCK_ALLOW_SCRIPT, |_| {});
+    }
+
+    /// ### English
+    /// Marks the view unlocked, raising `POINTER_LOCK` with `value0 = 0` if it was locked.
+    ///
+    /// #### Parameters
+    /// - `events`: Per-view event queue.
+    ///
+    /// ### 中文
+    /// 将 view 标记为未锁定；若此前已锁定，则产生 `value0 = 0` 的 `POINTER_LOCK` 事件。
+    ///
+    /// #### 参数
+    /// - `events`：每 view 事件队列。
+    pub(super) fn unlock(&self, events: &ViewEventQueue) {
+        if self.locked.replace(false) {
+            push_event(events, 0);
+        }
+    }
+}
+
+/// ### English
+/// Queues a `POINTER_LOCK` event.
+///
+/// #### Parameters
+/// - `events`: Per-view event queue.
+/// - `locked`: `1` when entering pointer lock, `0` when leaving it.
+///
+/// ### 中文
+/// 排入一个 `POINTER_LOCK` 事件。
+///
+/// #### 参数
+/// - `events`：每 view 事件队列。
+/// - `locked`：进入 pointer lock 时为 `1`，退出时为 `0`。
+fn push_event(events: &ViewEventQueue, locked: i64) {
+    let mut event = ViewEvent::new(
+        XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK,
+        XIAN_WEB_ENGINE_STATUS_OK,
+        0,
+    );
+    event.value0 = locked;
+    events.push(event);
+}
@@ -0,0 +1,127 @@
+//! ### English
+//! Per-view replay of a recording made with `start_recording`.
+//!
+//! Actions are fed straight into the view on the Servo thread (not through the handle), so they keep
+//! their recorded spacing and are not recorded again.
+//!
+//! ### 中文
+//! 每 view 对 `start_recording` 录制内容的回放。
+//!
+//! 动作直接在 Servo 线程注入 view（不经过句柄），因此保持录制时的时间间隔，且不会被再次录制。
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::super::recording::{RecordedAction, RecordedEntry};
+
+/// ### English
+/// A running replay (Servo thread only).
+///
+/// ### 中文
+/// 一次进行中的回放（仅 Servo 线程使用）。
+pub(super) struct Replay {
+    /// ### English
+    /// Time the replay started; entry offsets are measured from it.
+    ///
+    /// ### 中文
+    /// 回放开始时间；条目偏移以此为起点。
+    start: Instant,
+    /// ### English
+    /// Entries not replayed yet, in recorded order.
+    ///
+    /// ### 中文
+    /// 尚未回放的条目（按录制顺序）。
+    entries: VecDeque<RecordedEntry>,
+    /// ### English
+    /// Number of entries replayed so far.
+    ///
+    /// ### 中文
+    /// 已回放的条目数。
+    replayed: u64,
+}
+
+impl Replay {
+    /// ### English
+    /// Starts replaying `entries` at `start`.
+    ///
+    /// #### Parameters
+    /// - `entries`: Recorded entries.
+    /// - `start`: Replay start time.
+    ///
+    /// ### 中文
+    /// 从 `start` 开始回放 `entries`。
+    ///
+    /// #### 参数
+    /// - `entries`：已录制的条目。
+    /// - `start`：回放开始时间。
+    pub(super) fn new(entries: Vec<RecordedEntry>, start: Instant) -> Self {
+        Self {
+            start,
+            entries: entries.into(),
+            replayed: 0,
+        }
+    }
+
+    /// ### English
+    /// Queues `entries` after the actions still pending, with their offsets measured from the later of
+    /// `now` and the last pending action.
+    ///
+    /// #### Parameters
+    /// - `entries`: Entries to queue.
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 将 `entries` 排在仍待执行的动作之后，其偏移从 `now` 与最后一个待执行动作两者中较晚者开始计算。
+    ///
+    /// #### 参数
+    /// - `entries`：要排队的条目。
+    /// - `now`：当前时间。
+    pub(super) fn append(&mut self, entries: Vec<RecordedEntry>, now: Instant) {
+        let base = self
+            .entries
+            .back()
+            .map_or(Duration::ZERO, |entry| entry.offset)
+            .max(now.saturating_duration_since(self.start));
+        self.entries.extend(entries.into_iter().map(|mut entry| {
+            entry.offset += base;
+            entry
+        }));
+    }
+
+    /// ### English
+    /// Pops the next action if it is due.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 若下一个动作已到期，则将其弹出。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn next_due(&mut self, now: Instant) -> Option<RecordedAction> {
+        if self.start + self.entries.front()?.offset > now {
+            return None;
+        }
+        self.replayed += 1;
+        self.entries.pop_front().map(|entry| entry.action)
+    }
+
+    /// ### English
+    /// Returns when the next action is due (`None` once every action was replayed).
+    ///
+    /// ### 中文
+    /// 返回下一个动作的到期时间（全部回放完毕后为 `None`）。
+    pub(super) fn deadline(&self) -> Option<Instant> {
+        self.entries.front().map(|entry| self.start + entry.offset)
+    }
+
+    /// ### English
+    /// Returns the number of actions replayed so far.
+    ///
+    /// ### 中文
+    /// 返回已回放的动作数。
+    pub(super) fn replayed(&self) -> u64 {
+        self.replayed
+    }
+}
@@ -0,0 +1,170 @@
+//! ### English
+//! Deferred GL deletion for destroyed views.
+//!
+//! The embedder may still hold (or be sampling) a slot texture when it destroys a view. Destroyed
+//! views' rendering contexts are therefore parked here and their GL resources deleted only once the
+//! consumer has released every slot (release fences signaled), or after [`RETIRE_TIMEOUT`] unless
+//! the consumer pinned a frame: a pinned frame is kept deliberately and is never deleted under it.
+//!
+//! ### 中文
+//! 已销毁 view 的延迟 GL 删除。
+//!
+//! 宿主销毁 view 时可能仍持有（或正在采样）槽位纹理。因此已销毁 view 的渲染上下文会暂存于此，
+//! 仅当消费者释放所有槽位（释放 fence 已 signal），或超过 [`RETIRE_TIMEOUT`] 且消费者未固定任何帧时，
+//! 才删除其 GL 资源：被固定的帧是消费者有意保留的，绝不会在其使用期间被删除。
+
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::engine::rendering::GlfwTripleBufferRenderingContext;
+
+#[cfg(test)]
+mod tests;
+
+/// ### English
+/// Longest time GL deletion is deferred for a destroyed view.
+///
+/// ### 中文
+/// 已销毁 view 的 GL 删除最多推迟的时长。
+pub(super) const RETIRE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// ### English
+/// Rendering context whose GL deletion can be deferred.
+///
+/// ### 中文
+/// 可以延迟 GL 删除的渲染上下文。
+pub(super) trait Retirable {
+    /// ### English
+    /// Returns whether the consumer is done with every slot texture.
+    ///
+    /// ### 中文
+    /// 返回消费者是否已不再使用任何槽位纹理。
+    fn consumer_released(&self) -> bool;
+
+    /// ### English
+    /// Returns whether the consumer pinned one of the slot frames.
+    ///
+    /// ### 中文
+    /// 返回消费者是否固定了某个槽位帧。
+    fn has_pinned_frame(&self) -> bool;
+
+    /// ### English
+    /// Deletes the GL resources (idempotent).
+    ///
+    /// ### 中文
+    /// 删除 GL 资源（幂等）。
+    fn destroy_gl_resources(&self);
+}
+
+impl Retirable for GlfwTripleBufferRenderingContext {
+    /// ### English
+    /// See [`GlfwTripleBufferRenderingContext::consumer_released`].
+    ///
+    /// ### 中文
+    /// 见 [`GlfwTripleBufferRenderingContext::consumer_released`]。
+    fn consumer_released(&self) -> bool {
+        GlfwTripleBufferRenderingContext::consumer_released(self)
+    }
+
+    /// ### English
+    /// See [`GlfwTripleBufferRenderingContext::has_pinned_frame`].
+    ///
+    /// ### 中文
+    /// 见 [`GlfwTripleBufferRenderingContext::has_pinned_frame`]。
+    fn has_pinned_frame(&self) -> bool {
+        GlfwTripleBufferRenderingContext::has_pinned_frame(self)
+    }
+
+    /// ### English
+    /// See [`GlfwTripleBufferRenderingContext::destroy_gl_resources`].
+    ///
+    /// ### 中文
+    /// 见 [`GlfwTripleBufferRenderingContext::destroy_gl_resources`]。
+    fn destroy_gl_resources(&self) {
+        GlfwTripleBufferRenderingContext::destroy_gl_resources(self);
+    }
+}
+
+/// ### English
+/// Rendering contexts of destroyed views waiting for GL deletion (Servo thread only).
+///
+/// ### 中文
+/// 等待 GL 删除的已销毁 view 的渲染上下文（仅 Servo 线程持有）。
+pub(super) struct RetiredContexts<C: Retirable = GlfwTripleBufferRenderingContext> {
+    /// ### English
+    /// Retired contexts with their forced-deletion deadline.
+    ///
+    /// ### 中文
+    /// 已退役的上下文及其强制删除截止时间。
+    contexts: Vec<(Rc<C>, Instant)>,
+}
+
+impl<C: Retirable> Default for RetiredContexts<C> {
+    /// ### English
+    /// Creates an empty list.
+    ///
+    /// ### 中文
+    /// 创建空列表。
+    fn default() -> Self {
+        Self {
+            contexts: Vec::new(),
+        }
+    }
+}
+
+impl<C: Retirable> RetiredContexts<C> {
+    /// ### English
+    /// Parks a destroyed view's rendering context until the consumer is done with it.
+    ///
+    /// #### Parameters
+    /// - `context`: Rendering context of the destroyed view.
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 暂存已销毁 view 的渲染上下文，直到消费者不再使用它。
+    ///
+    /// #### 参数
+    /// - `context`：已销毁 view 的渲染上下文。
+    /// - `now`：当前时间。
+    pub(super) fn retire(&mut self, context: Rc<C>, now: Instant) {
+        if context.consumer_released() {
+            context.destroy_gl_resources();
+            return;
+        }
+        self.contexts.push((context, now + RETIRE_TIMEOUT));
+    }
+
+    /// ### English
+    /// Deletes the GL resources of contexts that were released or timed out, and returns the
+    /// earliest remaining deadline.
+    ///
+    /// A timed-out context with a pinned frame is kept and checked again after another
+    /// [`RETIRE_TIMEOUT`].
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 删除已释放或已超时的上下文的 GL 资源，并返回剩余条目中最早的截止时间。
+    ///
+    /// 已超时但有被固定帧的上下文会被保留，并在再经过一个 [`RETIRE_TIMEOUT`] 后重新检查。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn collect(&mut self, now: Instant) -> Option<Instant> {
+        self.contexts.retain_mut(|(context, deadline)| {
+            if !context.consumer_released() {
+                if now < *deadline {
+                    return true;
+                }
+                if context.has_pinned_frame() {
+                    *deadline = now + RETIRE_TIMEOUT;
+                    return true;
+                }
+            }
+            context.destroy_gl_resources();
+            false
+        });
+        self.contexts.iter().map(|&(_, deadline)| deadline).min()
+    }
+}
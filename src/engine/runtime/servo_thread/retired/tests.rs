@@ -0,0 +1,134 @@
+//! ### English
+//! Tests for deferred GL deletion against a real `SharedFrameState`.
+//!
+//! ### 中文
+//! 基于真实 `SharedFrameState` 的延迟 GL 删除测试。
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Instant;
+
+use dpi::PhysicalSize;
+
+use crate::engine::frame::{
+    SLOT_FREE, SLOT_HELD, SLOT_PINNED, SLOT_RELEASE_PENDING, SLOT_RENDERING, SharedFrameState,
+    TRIPLE_BUFFER_COUNT,
+};
+
+use super::{RETIRE_TIMEOUT, Retirable, RetiredContexts};
+
+/// ### English
+/// Rendering context stand-in: real slot states, GL deletion only recorded.
+///
+/// ### 中文
+/// 渲染上下文替身：使用真实的槽位状态，GL 删除仅作记录。
+struct FakeContext {
+    /// ### English
+    /// Slot states shared with the consumer.
+    ///
+    /// ### 中文
+    /// 与消费者共享的槽位状态。
+    shared: SharedFrameState,
+    /// ### English
+    /// Whether the GL resources were deleted.
+    ///
+    /// ### 中文
+    /// GL 资源是否已被删除。
+    destroyed: Cell<bool>,
+}
+
+impl FakeContext {
+    /// ### English
+    /// Creates a context with one published frame and returns it with that frame's slot.
+    ///
+    /// ### 中文
+    /// 创建一个已发布一帧的上下文，并返回它与该帧的槽位。
+    fn with_frame() -> (Rc<Self>, usize) {
+        let shared = SharedFrameState::new(PhysicalSize::new(4, 4));
+        let slot = 1;
+        assert!(shared.compare_exchange_state_relaxed(slot, SLOT_FREE, SLOT_RENDERING));
+        shared.publish(slot, 0, 1, 0);
+        let context = Rc::new(Self {
+            shared,
+            destroyed: Cell::new(false),
+        });
+        (context, slot)
+    }
+}
+
+impl Retirable for FakeContext {
+    /// ### English
+    /// Returns whether no slot is held, pinned or waiting for its release fence.
+    ///
+    /// ### 中文
+    /// 返回是否没有被持有、被固定或等待释放 fence 的槽位。
+    fn consumer_released(&self) -> bool {
+        (0..TRIPLE_BUFFER_COUNT).all(|slot| {
+            !matches!(
+                self.shared.slot_state(slot),
+                SLOT_HELD | SLOT_PINNED | SLOT_RELEASE_PENDING
+            )
+        })
+    }
+
+    /// ### English
+    /// Returns whether the consumer pinned a slot.
+    ///
+    /// ### 中文
+    /// 返回消费者是否固定了某个槽位。
+    fn has_pinned_frame(&self) -> bool {
+        self.shared.has_pinned_slot()
+    }
+
+    /// ### English
+    /// Records the deletion.
+    ///
+    /// ### 中文
+    /// 记录删除。
+    fn destroy_gl_resources(&self) {
+        self.destroyed.set(true);
+    }
+}
+
+#[test]
+fn held_frame_is_deleted_after_the_timeout() {
+    let (context, _) = FakeContext::with_frame();
+    let frame = context.shared.try_acquire_front().expect("published frame");
+    assert_eq!(context.shared.slot_state(frame.slot), SLOT_HELD);
+
+    let start = Instant::now();
+    let mut retired = RetiredContexts::default();
+    retired.retire(context.clone(), start);
+    assert_eq!(retired.collect(start), Some(start + RETIRE_TIMEOUT));
+    assert!(!context.destroyed.get());
+
+    assert_eq!(retired.collect(start + RETIRE_TIMEOUT), None);
+    assert!(context.destroyed.get());
+}
+
+#[test]
+fn pinned_frame_survives_the_timeout_until_released() {
+    let (context, slot) = FakeContext::with_frame();
+    let frame = context.shared.try_acquire_front().expect("published frame");
+    assert_eq!(frame.slot, slot);
+    assert!(context.shared.pin_slot(slot));
+
+    let start = Instant::now();
+    let mut retired = RetiredContexts::default();
+    retired.retire(context.clone(), start);
+
+    for round in 1..=4u32 {
+        let now = start + RETIRE_TIMEOUT * round;
+        assert_eq!(retired.collect(now), Some(now + RETIRE_TIMEOUT));
+        assert!(
+            !context.destroyed.get(),
+            "pinned frame deleted after {round} timeouts"
+        );
+        assert_eq!(context.shared.slot_state(slot), SLOT_PINNED);
+    }
+
+    assert!(context.shared.unpin_slot(slot));
+    context.shared.release_slot(slot, 0);
+    assert_eq!(retired.collect(start + RETIRE_TIMEOUT * 5), None);
+    assert!(context.destroyed.get());
+}
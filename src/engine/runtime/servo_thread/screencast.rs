@@ -0,0 +1,132 @@
+//! ### English
+//! Frame capture pacing of a view's screencast (`screencast` feature).
+//!
+//! While the view is active and not frozen, a screenshot is requested at most `max_fps` times per
+//! second and never while the previous one is still pending; each captured frame is handed to the
+//! view's screencast server for encoding.
+//!
+//! ### 中文
+//! view 屏幕投射的帧截取节奏（`screencast` feature）。
+//!
+//! view 处于 active 且未冻结时，每秒最多请求 `max_fps` 次截图，且上一次截图未完成时不会再次请求；
+//! 截取的每一帧都交给该 view 的屏幕投射服务器编码。
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use super::super::screencast::ScreencastServer;
+
+/// ### English
+/// Capture rate used when the embedder passes `0`.
+///
+/// ### 中文
+/// 宿主传入 `0` 时使用的截取帧率。
+const DEFAULT_FPS: u32 = 10;
+
+/// ### English
+/// Highest accepted capture rate.
+///
+/// ### 中文
+/// 允许的最高截取帧率。
+const MAX_FPS: u32 = 60;
+
+/// ### English
+/// Screencast state of one view (Servo thread only).
+///
+/// ### 中文
+/// 单个 view 的屏幕投射状态（仅 Servo 线程使用）。
+pub(super) struct Screencast {
+    /// ### English
+    /// Server receiving the captured frames (also held by a pending capture callback).
+    ///
+    /// ### 中文
+    /// 接收截取帧的服务器（未完成的截取回调也会持有它）。
+    server: Rc<ScreencastServer>,
+    /// ### English
+    /// Time between two captures.
+    ///
+    /// ### 中文
+    /// 两次截取之间的间隔。
+    interval: Duration,
+    /// ### English
+    /// Earliest time of the next capture.
+    ///
+    /// ### 中文
+    /// 下一次截取的最早时间。
+    next_capture: Instant,
+    /// ### English
+    /// Whether a capture is pending (cleared by the screenshot callback).
+    ///
+    /// ### 中文
+    /// 是否有未完成的截取（由截图回调清除）。
+    in_flight: Rc<Cell<bool>>,
+}
+
+impl Screencast {
+    /// ### English
+    /// Creates the screencast state; the first capture is due right away.
+    ///
+    /// #### Parameters
+    /// - `server`: Running server receiving the frames.
+    /// - `max_fps`: Highest capture rate (`0` = 10; capped at 60).
+    ///
+    /// ### 中文
+    /// 创建屏幕投射状态；第一次截取立即到期。
+    ///
+    /// #### 参数
+    /// - `server`：接收帧的运行中服务器。
+    /// - `max_fps`：最高截取帧率（`0` 表示 10；上限为 60）。
+    pub(super) fn new(server: ScreencastServer, max_fps: u32) -> Self {
+        let fps = match max_fps {
+            0 => DEFAULT_FPS,
+            fps => fps.min(MAX_FPS),
+        };
+        Self {
+            server: Rc::new(server),
+            interval: Duration::from_secs(1) / fps,
+            next_capture: Instant::now(),
+            in_flight: Rc::new(Cell::new(false)),
+        }
+    }
+
+    /// ### English
+    /// Requests a screenshot if one is due, and returns when to poll again.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    /// - `visible`: Whether the view is active and not frozen (otherwise it is not captured).
+    /// - `webview`: WebView to capture.
+    ///
+    /// ### 中文
+    /// 若截取已到期则请求一次截图，并返回下一次轮询的时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    /// - `visible`：view 是否处于 active 且未冻结（否则不会被截取）。
+    /// - `webview`：要截取的 WebView。
+    pub(super) fn poll(
+        &mut self,
+        now: Instant,
+        visible: bool,
+        webview: &servo::WebView,
+    ) -> Option<Instant> {
+        if now < self.next_capture {
+            return Some(self.next_capture);
+        }
+        self.next_capture = now + self.interval;
+        if !visible || self.in_flight.replace(true) {
+            return Some(self.next_capture);
+        }
+
+        let server = self.server.clone();
+        let in_flight = self.in_flight.clone();
+        webview.take_screenshot(None, move |result| {
+            in_flight.set(false);
+            if let Ok(image) = result {
+                server.submit(image);
+            }
+        });
+        Some(self.next_capture)
+    }
+}
@@ -0,0 +1,214 @@
+//! ### English
+//! Servo-thread session tracking shared between a view's delegate and its entry.
+//!
+//! ### 中文
+//! 在 view 的 delegate 与其条目之间共享的 Servo 线程会话跟踪状态。
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+
+use url::Url;
+
+/// ### English
+/// Next step of a session restore, issued once the previous one committed.
+///
+/// ### 中文
+/// 会话恢复的下一步，在上一步提交后执行。
+pub(super) enum RestoreStep {
+    /// ### English
+    /// Load the next history entry.
+    ///
+    /// ### 中文
+    /// 加载下一个历史条目。
+    Load(Url),
+    /// ### English
+    /// Go back this many entries to the restored current entry.
+    ///
+    /// ### 中文
+    /// 后退该数量的条目，回到恢复的当前条目。
+    GoBack(usize),
+    /// ### English
+    /// History is rebuilt; the scroll offset (if any) is pending.
+    ///
+    /// ### 中文
+    /// 历史已重建；滚动偏移（若有）等待应用。
+    Done,
+}
+
+/// ### English
+/// Session restore in progress.
+///
+/// ### 中文
+/// 进行中的会话恢复。
+struct Restore {
+    /// ### English
+    /// Entries still to load, oldest first.
+    ///
+    /// ### 中文
+    /// 仍待加载的条目（从旧到新）。
+    pending: VecDeque<Url>,
+    /// ### English
+    /// Entries to go back from the newest one once all are loaded.
+    ///
+    /// ### 中文
+    /// 全部加载后需要从最新条目后退的条目数。
+    back: usize,
+    /// ### English
+    /// History when the last step was issued (a different history means it committed).
+    ///
+    /// ### 中文
+    /// 发出上一步时的历史（历史发生变化即表示该步已提交）。
+    issued: Vec<Url>,
+    /// ### English
+    /// Scroll offset of the restored current entry.
+    ///
+    /// ### 中文
+    /// 恢复的当前条目的滚动偏移。
+    scroll: (f64, f64),
+}
+
+/// ### English
+/// Latest history reported by Servo plus a scroll offset waiting to be restored.
+///
+/// ### 中文
+/// Servo 上报的最新历史，以及等待恢复的滚动偏移。
+#[derive(Default)]
+pub(super) struct SessionTracker {
+    /// ### English
+    /// History entries from the last `notify_history_changed`.
+    ///
+    /// ### 中文
+    /// 最近一次 `notify_history_changed` 上报的历史条目。
+    entries: RefCell<Vec<Url>>,
+    /// ### English
+    /// Index of the current history entry.
+    ///
+    /// ### 中文
+    /// 当前历史条目的索引。
+    current: Cell<usize>,
+    /// ### English
+    /// Scroll offset applied once the next load completes (session restore).
+    ///
+    /// ### 中文
+    /// 下一次加载完成后应用的滚动偏移（会话恢复用）。
+    pending_scroll: Cell<Option<(f64, f64)>>,
+    /// ### English
+    /// Session restore in progress, if any.
+    ///
+    /// ### 中文
+    /// 进行中的会话恢复（如有）。
+    restore: RefCell<Option<Restore>>,
+}
+
+impl SessionTracker {
+    /// ### English
+    /// Records the history reported by Servo.
+    ///
+    /// #### Parameters
+    /// - `entries`: History entry URLs.
+    /// - `current`: Index of the current entry.
+    ///
+    /// ### 中文
+    /// 记录 Servo 上报的历史。
+    ///
+    /// #### 参数
+    /// - `entries`：历史条目 URL。
+    /// - `current`：当前条目的索引。
+    pub(super) fn set_history(&self, entries: Vec<Url>, current: usize) {
+        *self.entries.borrow_mut() = entries;
+        self.current.set(current);
+    }
+
+    /// ### English
+    /// Returns the recorded history as strings plus the current index.
+    ///
+    /// ### 中文
+    /// 以字符串形式返回已记录的历史，以及当前索引。
+    pub(super) fn history(&self) -> (Vec<String>, usize) {
+        let entries = self
+            .entries
+            .borrow()
+            .iter()
+            .map(|url| url.as_str().to_string())
+            .collect();
+        (entries, self.current.get())
+    }
+
+    /// ### English
+    /// Starts rebuilding a saved history; returns the first entry to load.
+    ///
+    /// Servo has no API to seed history, so the entries are loaded one after another (each once
+    /// the previous one committed) and the view then goes back to the saved current entry.
+    ///
+    /// #### Parameters
+    /// - `entries`: History entry URLs, oldest first.
+    /// - `current`: Index of the current entry.
+    /// - `scroll`: Scroll offset of the current entry (CSS px).
+    ///
+    /// ### 中文
+    /// 开始重建保存的历史；返回要加载的第一个条目。
+    ///
+    /// Servo 没有预置历史的 API，因此会依次加载各条目（每个条目在上一个提交后加载），随后 view 后退到保存的
+    /// 当前条目。
+    ///
+    /// #### 参数
+    /// - `entries`：历史条目 URL（从旧到新）。
+    /// - `current`：当前条目的索引。
+    /// - `scroll`：当前条目的滚动偏移（CSS px）。
+    pub(super) fn begin_restore(
+        &self,
+        entries: Vec<Url>,
+        current: usize,
+        scroll: (f64, f64),
+    ) -> Option<Url> {
+        let current = current.min(entries.len().saturating_sub(1));
+        let back = entries.len().saturating_sub(current + 1);
+        let mut pending = VecDeque::from(entries);
+        let first = pending.pop_front()?;
+        *self.restore.borrow_mut() = Some(Restore {
+            pending,
+            back,
+            issued: self.entries.borrow().clone(),
+            scroll,
+        });
+        Some(first)
+    }
+
+    /// ### English
+    /// Advances a session restore after the history changed; returns the next step, or `None` if
+    /// no restore is running or the last step has not committed yet.
+    ///
+    /// ### 中文
+    /// 在历史变化后推进会话恢复；返回下一步。若没有进行中的恢复或上一步尚未提交，则返回 `None`。
+    pub(super) fn advance_restore(&self) -> Option<RestoreStep> {
+        let mut restore = self.restore.borrow_mut();
+        let state = restore.as_mut()?;
+        let entries = self.entries.borrow();
+        if *entries == state.issued {
+            return None;
+        }
+        state.issued.clone_from(&entries);
+
+        if let Some(url) = state.pending.pop_front() {
+            return Some(RestoreStep::Load(url));
+        }
+        if state.back != 0 {
+            return Some(RestoreStep::GoBack(std::mem::take(&mut state.back)));
+        }
+        let scroll = state.scroll;
+        *restore = None;
+        if scroll != (0.0, 0.0) {
+            self.pending_scroll.set(Some(scroll));
+        }
+        Some(RestoreStep::Done)
+    }
+
+    /// ### English
+    /// Takes the pending scroll offset, if any.
+    ///
+    /// ### 中文
+    /// 取出待应用的滚动偏移（如有）。
+    pub(super) fn take_pending_scroll(&self) -> Option<(f64, f64)> {
+        self.pending_scroll.take()
+    }
+}
@@ -0,0 +1,305 @@
+//! ### English
+//! Per-view slow-script detection.
+//!
+//! Servo does not expose SpiderMonkey's interrupt callback to embedders, so the watch pings the
+//! page's script event loop with a trivial evaluation; a ping left unanswered past the timeout means
+//! a script is hogging it. The view is then reported or, under the stop policy, unloaded. A stop is
+//! only reported as successful once the view answers a ping again within another timeout; a script
+//! that keeps the page from unloading is reported as still running.
+//!
+//! ### 中文
+//! 每 view 的慢脚本检测。
+//!
+//! Servo 未向宿主暴露 SpiderMonkey 的中断回调，因此该检测通过一次简单求值来 ping 页面的脚本事件循环；
+//! 若 ping 超时仍未应答，说明有脚本占住了事件循环。此时会上报该 view，或在停止策略下卸载页面。只有当 view
+//! 在又一个超时时长内重新应答 ping 时，才会将停止上报为成功；若脚本使页面无法卸载，则上报其仍在运行。
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT,
+};
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP,
+};
+
+use super::super::events::{ViewEvent, ViewEventQueue};
+
+/// ### English
+/// Unload request awaiting confirmation that the view answers again.
+///
+/// ### 中文
+/// 等待确认 view 重新应答的卸载请求。
+struct StopRequest {
+    /// ### English
+    /// Send time of the ping that went unanswered.
+    ///
+    /// ### 中文
+    /// 未被应答的 ping 的发送时间。
+    stalled_since: Instant,
+    /// ### English
+    /// Time the unload was requested.
+    ///
+    /// ### 中文
+    /// 请求卸载的时间。
+    requested: Instant,
+    /// ### English
+    /// Set by the first confirmation ping that evaluates successfully.
+    ///
+    /// ### 中文
+    /// 由第一个成功求值的确认 ping 置位。
+    answered: Rc<Cell<bool>>,
+}
+
+/// ### English
+/// Slow-script watch state of one view (Servo thread only).
+///
+/// ### 中文
+/// 单个 view 的慢脚本检测状态（仅 Servo 线程使用）。
+pub(super) struct SlowScriptWatch {
+    /// ### English
+    /// Slow-script policy (`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`).
+    ///
+    /// ### 中文
+    /// 慢脚本策略（`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`）。
+    policy: Cell<u32>,
+    /// ### English
+    /// How long a ping may stay unanswered (also the ping interval).
+    ///
+    /// ### 中文
+    /// ping 允许保持未应答的时长（同时也是 ping 间隔）。
+    timeout: Cell<Duration>,
+    /// ### English
+    /// Send time of the outstanding ping (cleared by the evaluation callback).
+    ///
+    /// ### 中文
+    /// 未应答 ping 的发送时间（由求值回调清除）。
+    ping_sent: Rc<Cell<Option<Instant>>>,
+    /// ### English
+    /// Earliest time to send the next ping.
+    ///
+    /// ### 中文
+    /// 下一次发送 ping 的最早时间。
+    next_ping: Cell<Option<Instant>>,
+    /// ### English
+    /// Whether the current stall was already reported.
+    ///
+    /// ### 中文
+    /// 当前停顿是否已上报。
+    reported: Cell<bool>,
+    /// ### English
+    /// Unload request awaiting confirmation.
+    ///
+    /// ### 中文
+    /// 等待确认的卸载请求。
+    stopping: RefCell<Option<StopRequest>>,
+}
+
+impl Default for SlowScriptWatch {
+    /// ### English
+    /// Creates a disabled watch.
+    ///
+    /// ### 中文
+    /// 创建一个处于禁用状态的检测器。
+    fn default() -> Self {
+        Self {
+            policy: Cell::new(XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE),
+            timeout: Cell::new(Duration::ZERO),
+            ping_sent: Rc::new(Cell::new(None)),
+            next_ping: Cell::new(None),
+            reported: Cell::new(false),
+            stopping: RefCell::new(None),
+        }
+    }
+}
+
+impl SlowScriptWatch {
+    /// ### English
+    /// Replaces the policy and timeout, restarting detection.
+    ///
+    /// #### Parameters
+    /// - `policy`: `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*` value.
+    /// - `timeout`: How long a script may block the page's event loop.
+    ///
+    /// ### 中文
+    /// 替换策略与超时，并重新开始检测。
+    ///
+    /// #### 参数
+    /// - `policy`：`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*` 值。
+    /// - `timeout`：脚本可阻塞页面事件循环的时长。
+    pub(super) fn configure(&self, policy: u32, timeout: Duration) {
+        self.policy.set(policy);
+        self.timeout.set(timeout);
+        self.next_ping.set(None);
+        self.reported.set(false);
+        self.stopping.replace(None);
+    }
+
+    /// ### English
+    /// Sends a ping or reports an unanswered one, and returns when to check again.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    /// - `webview`: WebView whose page is watched.
+    /// - `events`: Per-view event queue receiving `SLOW_SCRIPT` events.
+    ///
+    /// ### 中文
+    /// 发送 ping 或上报未应答的 ping，并返回下一次检查的时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    /// - `webview`：被检测页面所属的 WebView。
+    /// - `events`：接收 `SLOW_SCRIPT` 事件的每 view 事件队列。
+    pub(super) fn poll(
+        &self,
+        now: Instant,
+        webview: &servo::WebView,
+        events: &ViewEventQueue,
+    ) -> Option<Instant> {
+        let policy = self.policy.get();
+        if policy == XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE {
+            return None;
+        }
+        let timeout = self.timeout.get();
+
+        if self.stopping.borrow().is_some() {
+            return self.confirm_stop(now, webview, events);
+        }
+
+        if let Some(sent) = self.ping_sent.get() {
+            let deadline = sent + timeout;
+            if now < deadline {
+                return Some(deadline);
+            }
+            if self.reported.replace(true) {
+                return None;
+            }
+
+            if policy != XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP {
+                push_event(events, now.saturating_duration_since(sent), false);
+                return None;
+            }
+            if let Ok(blank) = Url::parse("about:blank") {
+                webview.load(blank);
+            }
+            self.ping_sent.set(None);
+            self.stopping.replace(Some(StopRequest {
+                stalled_since: sent,
+                requested: now,
+                answered: Rc::new(Cell::new(false)),
+            }));
+            return self.confirm_stop(now, webview, events);
+        }
+
+        self.reported.set(false);
+        let ping_at = self.next_ping.get().unwrap_or(now);
+        if now < ping_at {
+            return Some(ping_at);
+        }
+
+        self.ping_sent.set(Some(now));
+        self.next_ping.set(Some(now + timeout));
+        let ping_sent = self.ping_sent.clone();
+        webview.evaluate_javascript("0", move |_| ping_sent.set(None));
+        Some(now + timeout)
+    }
+
+    /// ### English
+    /// Waits for the view to answer after an unload request and reports the outcome.
+    ///
+    /// The page is pinged every quarter timeout, since pings sent before the navigation commits
+    /// still go to the stalled document. Only a successful evaluation counts as an answer. Without
+    /// one within a timeout of the request, the event reports that the script is still running and
+    /// the stall stays reported until the original ping is answered.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    /// - `webview`: WebView whose page is watched.
+    /// - `events`: Per-view event queue receiving `SLOW_SCRIPT` events.
+    ///
+    /// ### 中文
+    /// 在请求卸载后等待 view 应答，并上报结果。
+    ///
+    /// 由于导航提交前发送的 ping 仍会发往停顿的文档，因此每隔四分之一超时时长 ping 一次页面。只有成功的求值
+    /// 才算应答；若在请求后一个超时时长内没有应答，事件会上报脚本仍在运行，且在原 ping 被应答之前不再重复上报。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    /// - `webview`：被检测页面所属的 WebView。
+    /// - `events`：接收 `SLOW_SCRIPT` 事件的每 view 事件队列。
+    fn confirm_stop(
+        &self,
+        now: Instant,
+        webview: &servo::WebView,
+        events: &ViewEventQueue,
+    ) -> Option<Instant> {
+        let timeout = self.timeout.get();
+        let mut stopping = self.stopping.borrow_mut();
+        let request = stopping.as_ref()?;
+        let unresponsive = now.saturating_duration_since(request.stalled_since);
+
+        if request.answered.get() {
+            *stopping = None;
+            self.reported.set(false);
+            self.next_ping.set(Some(now + timeout));
+            push_event(events, unresponsive, true);
+            return Some(now + timeout);
+        }
+
+        let deadline = request.requested + timeout;
+        if now >= deadline {
+            self.ping_sent.set(Some(request.stalled_since));
+            *stopping = None;
+            push_event(events, unresponsive, false);
+            return None;
+        }
+
+        let interval = (timeout / 4).max(Duration::from_millis(1));
+        let ping_at = self
+            .next_ping
+            .get()
+            .filter(|at| *at > request.requested)
+            .unwrap_or(now);
+        if now < ping_at {
+            return Some(ping_at.min(deadline));
+        }
+        self.next_ping.set(Some(now + interval));
+        let answered = request.answered.clone();
+        webview.evaluate_javascript("0", move |result| {
+            if result.is_ok() {
+                answered.set(true);
+            }
+        });
+        Some((now + interval).min(deadline))
+    }
+}
+
+/// ### English
+/// Queues a `SLOW_SCRIPT` event.
+///
+/// #### Parameters
+/// - `events`: Per-view event queue.
+/// - `unresponsive`: How long the page has been unresponsive.
+/// - `stopped`: Whether the page was unloaded and the view answers again.
+///
+/// ### 中文
+/// 排入一个 `SLOW_SCRIPT` 事件。
+///
+/// #### 参数
+/// - `events`：每 view 事件队列。
+/// - `unresponsive`：页面无响应的时长。
+/// - `stopped`：页面是否已被卸载且 view 已重新应答。
+fn push_event(events: &ViewEventQueue, unresponsive: Duration, stopped: bool) {
+    let mut event = ViewEvent::new(
+        XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT,
+        XIAN_WEB_ENGINE_STATUS_OK,
+        0,
+    );
+    event.value0 = unresponsive.as_millis() as i64;
+    event.value1 = i64::from(stopped);
+    events.push(event);
+}
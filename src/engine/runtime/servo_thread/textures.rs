@@ -0,0 +1,211 @@
+//! ### English
+//! Servo-thread registry of embedder GL textures that pages can fetch with
+//! `xianHost.getTexture(name)`.
+//!
+//! Servo cannot import a foreign GL texture into a page's WebGL context, so each request reads the
+//! texture back on the shared context and hands the page an `ImageBitmap` copy.
+//!
+//! ### 中文
+//! Servo 线程上的宿主 GL 纹理注册表，页面可通过 `xianHost.getTexture(name)` 获取。
+//!
+//! Servo 无法将外部 GL 纹理导入页面的 WebGL 上下文，因此每次请求都会在共享上下文上读回纹理，并交给页面一份
+//! `ImageBitmap` 副本。
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::engine::base64;
+use crate::engine::rendering::GlfwSharedContext;
+
+use super::super::scripts::js_string_literal;
+
+/// ### English
+/// A registered embedder texture.
+///
+/// ### 中文
+/// 一个已注册的宿主纹理。
+#[derive(Clone, Copy)]
+struct SharedTexture {
+    /// ### English
+    /// GL texture name (`GL_TEXTURE_2D`).
+    ///
+    /// ### 中文
+    /// GL 纹理名（`GL_TEXTURE_2D`）。
+    texture: u32,
+    /// ### English
+    /// Width in pixels.
+    ///
+    /// ### 中文
+    /// 宽度（像素）。
+    width: u32,
+    /// ### English
+    /// Height in pixels.
+    ///
+    /// ### 中文
+    /// 高度（像素）。
+    height: u32,
+    /// ### English
+    /// Whether row 0 is the bottom row (render targets).
+    ///
+    /// ### 中文
+    /// 第 0 行是否为底行（渲染目标）。
+    flip_y: bool,
+}
+
+/// ### English
+/// Textures registered by the embedder, shared by every view's delegate.
+///
+/// ### 中文
+/// 宿主注册的纹理，由所有 view 的 delegate 共享。
+pub(super) struct SharedTextureTable {
+    /// ### English
+    /// Shared GL context the textures are read back on.
+    ///
+    /// ### 中文
+    /// 用于读回纹理的共享 GL 上下文。
+    shared_ctx: Rc<GlfwSharedContext>,
+    /// ### English
+    /// Registered textures keyed by name.
+    ///
+    /// ### 中文
+    /// 按名称索引的已注册纹理。
+    textures: RefCell<HashMap<String, SharedTexture>>,
+}
+
+impl SharedTextureTable {
+    /// ### English
+    /// Creates an empty table reading back on `shared_ctx`.
+    ///
+    /// #### Parameters
+    /// - `shared_ctx`: Shared GL context of the Servo thread.
+    ///
+    /// ### 中文
+    /// 创建一个在 `shared_ctx` 上读回的空表。
+    ///
+    /// #### 参数
+    /// - `shared_ctx`：Servo 线程的共享 GL 上下文。
+    pub(super) fn new(shared_ctx: Rc<GlfwSharedContext>) -> Self {
+        Self {
+            shared_ctx,
+            textures: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// ### English
+    /// Registers, replaces or (with texture `0`) removes a texture.
+    ///
+    /// #### Parameters
+    /// - `name`: Name used by pages.
+    /// - `texture`: GL texture name, or `0` to unregister.
+    /// - `width`: Width in pixels.
+    /// - `height`: Height in pixels.
+    /// - `flip_y`: Whether row 0 is the bottom row.
+    ///
+    /// ### 中文
+    /// 注册、替换或（纹理为 `0` 时）移除一个纹理。
+    ///
+    /// #### 参数
+    /// - `name`：页面使用的名称。
+    /// - `texture`：GL 纹理名；`0` 表示取消注册。
+    /// - `width`：宽度（像素）。
+    /// - `height`：高度（像素）。
+    /// - `flip_y`：第 0 行是否为底行。
+    pub(super) fn register(
+        &self,
+        name: String,
+        texture: u32,
+        width: u32,
+        height: u32,
+        flip_y: bool,
+    ) {
+        let mut textures = self.textures.borrow_mut();
+        if texture == 0 {
+            textures.remove(&name);
+            return;
+        }
+        textures.insert(
+            name,
+            SharedTexture {
+                texture,
+                width,
+                height,
+                flip_y,
+            },
+        );
+    }
+
+    /// ### English
+    /// Handles a `texture:<seq>:<name>` message: reads the texture back and resolves the page's
+    /// Promise with its pixels, or rejects it if the name is unknown or the readback fails.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that asked for the texture.
+    /// - `message`: Message text after the `texture:` prefix.
+    ///
+    /// ### 中文
+    /// 处理 `texture:<seq>:<name>` 消息：读回纹理并以其像素兑现页面的 Promise；若名称未知或读回失败，
+    /// 则拒绝该 Promise。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：请求纹理的 WebView。
+    /// - `message`：去掉 `texture:` 前缀后的消息文本。
+    pub(super) fn fetch(&self, servo_webview: &servo::WebView, message: &str) {
+        let Some((seq, name)) = message.split_once(':') else {
+            return;
+        };
+        let Ok(seq) = seq.parse::<u64>() else {
+            return;
+        };
+
+        let Some(texture) = self.textures.borrow().get(name).copied() else {
+            reject(servo_webview, seq, &format!("unknown texture: {name}"));
+            return;
+        };
+        let Some(pixels) = self.shared_ctx.read_texture(
+            texture.texture,
+            texture.width,
+            texture.height,
+            texture.flip_y,
+            0,
+        ) else {
+            reject(
+                servo_webview,
+                seq,
+                &format!("texture {name} cannot be read"),
+            );
+            return;
+        };
+
+        let script = format!(
+            "window[Symbol.for(\"xian.textureSettle\")]?.({seq}, {}, {}, \"{}\")",
+            texture.width,
+            texture.height,
+            base64::encode(&pixels)
+        );
+        servo_webview.evaluate_javascript(script, |_| {});
+    }
+}
+
+/// ### English
+/// Rejects the page-side Promise of texture request `seq`.
+///
+/// #### Parameters
+/// - `servo_webview`: WebView that asked for the texture.
+/// - `seq`: Page-side sequence number.
+/// - `error`: Error message.
+///
+/// ### 中文
+/// 拒绝页面侧序号为 `seq` 的纹理请求的 Promise。
+///
+/// #### 参数
+/// - `servo_webview`：请求纹理的 WebView。
+/// - `seq`：页面侧序号。
+/// - `error`：错误信息。
+fn reject(servo_webview: &servo::WebView, seq: u64, error: &str) {
+    let script = format!(
+        "window[Symbol.for(\"xian.textureSettle\")]?.({seq}, 0, 0, null, {})",
+        js_string_literal(error)
+    );
+    servo_webview.evaluate_javascript(script, |_| {});
+}
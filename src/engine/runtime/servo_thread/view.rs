@@ -4,20 +4,102 @@
 //! ### 中文
 //! Servo 线程内的每 view 状态与 delegate 集成。
 
+use std::cell::Cell;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use dpi::PhysicalSize;
 use url::Url;
 
+use crate::engine::base64;
+use crate::engine::event_types::{
+    XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK, XIAN_WEB_ENGINE_STATUS_FAILED,
+    XIAN_WEB_ENGINE_STATUS_IO_ERROR, XIAN_WEB_ENGINE_STATUS_OK,
+    XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_EMAIL, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_MULTILINE,
+    XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NUMBER,
+    XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_OTHER, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_PASSWORD,
+    XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_SEARCH, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEL,
+    XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEXT, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_URL,
+    XIAN_WEB_ENGINE_VIEW_EVENT_ACCESSIBILITY_TREE, XIAN_WEB_ENGINE_VIEW_EVENT_BINARY_MESSAGE,
+    XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE, XIAN_WEB_ENGINE_VIEW_EVENT_COPY,
+    XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED, XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE,
+    XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE, XIAN_WEB_ENGINE_VIEW_EVENT_EXTERNAL_FRAME_RELEASED,
+    XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER, XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED,
+    XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_PLAYBACK, XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_STATE,
+    XIAN_WEB_ENGINE_VIEW_EVENT_PAGE_ERROR, XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST,
+    XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED, XIAN_WEB_ENGINE_VIEW_EVENT_RESOURCE_ERROR,
+    XIAN_WEB_ENGINE_VIEW_EVENT_SELECTED_TEXT, XIAN_WEB_ENGINE_VIEW_EVENT_SESSION_SAVED,
+    XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT,
+};
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL, XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND,
+    XIAN_WEB_ENGINE_COLOR_SCHEME_DARK,
+};
 use crate::engine::input::{CoalescedMouseMove, CoalescedResize, InputEventQueue};
+use crate::engine::input_types::{
+    XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XianWebEngineInputEvent,
+};
+use crate::engine::log::{XIAN_WEB_ENGINE_LOG_LEVEL_INFO, XIAN_WEB_ENGINE_LOG_LEVEL_WARN, log};
 use crate::engine::rendering::GlfwTripleBufferRenderingContext;
+use crate::engine::trace::{self, TRACE_SPAN_PAINT};
 
+use super::super::binary_channel::{BinaryChannel, MAX_BINARY_MESSAGE_BYTES};
 use super::super::coalesced::{
     CoalescedLoadUrl, PENDING_ACTIVE, PENDING_INPUT, PENDING_LOAD_URL, PENDING_MOUSE_MOVE,
     PENDING_RESIZE, PendingWork,
 };
+use super::super::command::{ElementCaptureReply, ViewCommand};
+use super::super::events::{ViewEvent, ViewEventQueue};
+use super::super::export::write_export;
+use super::super::image_limits::XianWebEngineImageLimits;
 use super::super::input_dispatch::dispatch_queued_input_event;
+use super::super::recording::{RecordedAction, RecordedEntry};
+use super::super::scripts::{
+    ACCESSIBILITY_TREE_SCRIPT, DEVICE_SENSORS_SCRIPT, DRAG_DROP_SCRIPT, ELEMENT_RECT_SCRIPT,
+    EXIT_POINTER_LOCK_SCRIPT, MEDIA_CONTROL_SCRIPT, MEDIA_FEATURES_SCRIPT, SELECTION_SCRIPT,
+    TIME_PAUSE_SCRIPT, js_string_literal,
+};
+use super::super::session::SessionSnapshot;
+use super::super::view_handle::render_size;
+use super::super::view_strings::ViewStrings;
+use super::external_streams::ExternalStreams;
+use super::file_picker::FilePickerTracker;
+use super::full_page::capture_full_page;
+use super::heap_limit::HeapLimit;
+use super::host_channel::HostChannel;
+use super::host_functions::HostFunctionTable;
+use super::image_loads::ImageLoadTracker;
+use super::input_results::InputResultTracker;
+use super::network_policy::NetworkGate;
+use super::permissions::{
+    PERMISSION_TIMEOUT, PendingPermission, PermissionTracker, permission_feature_code,
+};
+use super::pointer_lock::PointerLock;
+use super::replay::Replay;
+#[cfg(feature = "screencast")]
+use super::screencast::Screencast;
+use super::session::{RestoreStep, SessionTracker};
+use super::slow_script::SlowScriptWatch;
+use super::textures::SharedTextureTable;
+
+/// ### English
+/// How long a snapshot refresh lets the page run (timers, animation frames, layout) before the new
+/// frame is pinned.
+///
+/// ### 中文
+/// 快照刷新在固定新帧之前让页面运行（定时器、动画帧、布局）的时长。
+const SNAPSHOT_REFRESH_WINDOW: Duration = Duration::from_millis(100);
+
+/// ### English
+/// Interval at which a pinned frame released with a consumer fence is checked until it is READY
+/// again.
+///
+/// ### 中文
+/// 附带 consumer fence 释放的被固定帧在重新变为 READY 之前的检查间隔。
+const SNAPSHOT_FENCE_POLL: Duration = Duration::from_millis(1);
 
 /// ### English
 /// Servo `WebViewDelegate` implementation that drives paint/present for a view.
@@ -31,258 +113,2751 @@ pub(super) struct Delegate {
     /// ### 中文
     /// 在 `notify_new_frame_ready` 中用于 `paint/present` 的渲染上下文。
     rendering_context: Rc<GlfwTripleBufferRenderingContext>,
-}
-
-impl Delegate {
     /// ### English
-    /// Creates a new delegate bound to the given rendering context.
+    /// Per-view event queue used to report page-initiated notifications.
     ///
-    /// #### Parameters
-    /// - `rendering_context`: Rendering context used for `paint/present`.
+    /// ### 中文
+    /// 用于上报页面主动通知的每 view 事件队列。
+    events: Arc<ViewEventQueue>,
+    /// ### English
+    /// Per-view string snapshots updated from delegate callbacks.
     ///
     /// ### 中文
-    /// 创建一个绑定到指定渲染上下文的 delegate。
+    /// 由 delegate 回调更新的每 view 字符串快照。
+    strings: Arc<ViewStrings>,
+    /// ### English
+    /// Binary channel (buffer pool and messages received from the page).
     ///
-    /// #### 参数
-    /// - `rendering_context`：用于 `paint/present` 的渲染上下文。
-    pub(super) fn new(rendering_context: Rc<GlfwTripleBufferRenderingContext>) -> Self {
-        Self { rendering_context }
-    }
-}
-
-impl servo::WebViewDelegate for Delegate {
+    /// ### 中文
+    /// 二进制通道（缓冲池与从页面收到的消息）。
+    binary: Arc<BinaryChannel>,
     /// ### English
-    /// Called by Servo when a new frame can be rendered/presented.
+    /// Session tracking shared with the view entry.
     ///
-    /// #### Parameters
-    /// - `servo_webview`: WebView that should be painted and presented.
+    /// ### 中文
+    /// 与 view 条目共享的会话跟踪状态。
+    session: Rc<SessionTracker>,
+    /// ### English
+    /// Outstanding permission requests shared with the view entry.
     ///
     /// ### 中文
-    /// 当 Servo 通知有新帧可渲染/呈现时调用。
+    /// 与 view 条目共享的待应答权限请求。
+    permissions: Rc<PermissionTracker>,
+    /// ### English
+    /// Pointer lock state shared with the view entry.
     ///
-    /// #### 参数
-    /// - `servo_webview`：需要执行 paint/present 的 WebView。
-    fn notify_new_frame_ready(&self, servo_webview: servo::WebView) {
-        if !self.rendering_context.is_active() {
-            return;
-        }
-        if !self.rendering_context.preflight_reserve_next_back_slot() {
-            return;
-        }
-
-        servo_webview.paint();
-        servo::RenderingContext::present(&*self.rendering_context);
-    }
-}
-
-/// ### English
-/// Per-view bookkeeping stored only on the Servo thread.
-///
-/// ### 中文
-/// 仅 Servo 线程持有的每个 view 状态。
-pub(super) struct ViewEntry {
+    /// ### 中文
+    /// 与 view 条目共享的 pointer lock 状态。
+    pointer_lock: Rc<PointerLock>,
     /// ### English
-    /// Monotonic token associated with this view ID allocation.
+    /// Open file choosers shared with the view entry.
     ///
     /// ### 中文
-    /// 该 view ID 分配时绑定的单调 token，用于忽略“ID 复用后”的陈旧销毁命令。
-    pub(super) token: u64,
+    /// 与 view 条目共享的已打开文件选择器。
+    file_pickers: Rc<FilePickerTracker>,
     /// ### English
-    /// Servo WebView instance (lives on Servo thread only).
+    /// Whether page console output is reported as `CONSOLE_MESSAGE` events (shared with the view
+    /// entry).
     ///
     /// ### 中文
-    /// Servo WebView 实例（仅 Servo 线程持有）。
-    servo_webview: servo::WebView,
+    /// 是否将页面 console 输出以 `CONSOLE_MESSAGE` 事件上报（与 view 条目共享）。
+    console_capture: Rc<Cell<bool>>,
     /// ### English
-    /// Rendering context + triple-buffer resources owned by this view.
+    /// Host functions shared with the view entry.
     ///
     /// ### 中文
-    /// 该 view 持有的渲染上下文 + 三缓冲资源。
-    rendering_context: Rc<GlfwTripleBufferRenderingContext>,
+    /// 与 view 条目共享的宿主函数。
+    host_functions: Rc<HostFunctionTable>,
     /// ### English
-    /// Shared coalesced mouse-move state for this view.
+    /// Frame streams opened by the page, shared with the view entry.
     ///
     /// ### 中文
-    /// 该 view 的鼠标移动合并状态（共享）。
-    mouse_move: Arc<CoalescedMouseMove>,
+    /// 页面已打开的帧流，与 view 条目共享。
+    external_streams: Rc<ExternalStreams>,
     /// ### English
-    /// Per-view bounded input queue (mouse move is handled separately).
+    /// JavaScript heap limit reporting state of the current page.
     ///
     /// ### 中文
-    /// 每 view 的有界输入队列（鼠标移动单独处理）。
-    input_queue: Arc<InputEventQueue>,
+    /// 当前页面的 JavaScript 堆上限上报状态。
+    heap_limit: HeapLimit,
     /// ### English
-    /// Shared coalesced resize state for this view.
+    /// Tracked input events shared with the view entry.
     ///
     /// ### 中文
-    /// 该 view 的 resize 合并状态（共享）。
-    resize: Arc<CoalescedResize>,
+    /// 与 view 条目共享的跟踪输入事件。
+    input_results: Rc<InputResultTracker>,
     /// ### English
-    /// Shared coalesced URL load state (latest URL wins).
+    /// Engine network mode gate checked for every resource load.
     ///
     /// ### 中文
-    /// 共享的 URL 合并状态（只保留最新一次）。
-    load_url: Arc<CoalescedLoadUrl>,
+    /// 每次资源加载时检查的引擎网络模式闸门。
+    network: Rc<NetworkGate>,
     /// ### English
-    /// Per-view pending work bitmask (coalesces wakeups and queueing).
+    /// Image URL table of the shard, fed with this view's image loads.
     ///
     /// ### 中文
-    /// 每 view 的 pending work bitmask（用于合并唤醒与 push）。
-    pending: Arc<PendingWork>,
+    /// 分片的图片 URL 表，接收该 view 的图片加载。
+    image_loads: Rc<ImageLoadTracker>,
     /// ### English
-    /// Last applied active flag (avoids redundant show/hide calls).
+    /// Token of the view, identifying it in the image table.
     ///
     /// ### 中文
-    /// 上一次已应用的 active 值（用于避免重复 show/hide）。
-    last_active: bool,
+    /// 该 view 的 token，用于在图片表中标识它。
+    view_token: u64,
     /// ### English
-    /// Last applied size (avoids redundant resize calls).
+    /// Embedder textures pages can fetch (shared by every view).
     ///
     /// ### 中文
-    /// 上一次已应用的尺寸（用于避免重复 resize）。
-    last_size: PhysicalSize<u32>,
+    /// 页面可获取的宿主纹理（所有 view 共享）。
+    textures: Rc<SharedTextureTable>,
+    /// ### English
+    /// Render scale (internal pixels per logical pixel) shared with the view entry.
+    ///
+    /// ### 中文
+    /// 与 view 条目共享的渲染缩放（每逻辑像素对应的内部像素数）。
+    render_scale: Rc<Cell<f32>>,
+    /// ### English
+    /// Host message channel of this view's engine scripts.
+    ///
+    /// ### 中文
+    /// 该 view 引擎脚本的宿主消息通道。
+    channel: Rc<HostChannel>,
+    /// ### English
+    /// Input method control of the focused editable element, if any.
+    ///
+    /// ### 中文
+    /// 获得焦点的可编辑元素的输入法控件（如有）。
+    text_input: Cell<Option<servo::EmbedderControlId>>,
 }
 
-impl ViewEntry {
+impl Delegate {
     #[allow(clippy::too_many_arguments)]
     /// ### English
-    /// Creates a per-view entry stored only on the Servo thread.
+    /// Creates a new delegate bound to the given rendering context.
     ///
     /// #### Parameters
-    /// - `token`: Monotonic token paired with the view ID.
-    /// - `servo_webview`: Servo WebView instance for this view.
-    /// - `rendering_context`: Rendering context owned by this view.
-    /// - `mouse_move`: Shared coalesced mouse-move state.
-    /// - `input_queue`: Shared bounded input queue.
-    /// - `resize`: Shared coalesced resize state.
-    /// - `load_url`: Shared coalesced URL load state.
-    /// - `pending`: Shared pending-work bitmask.
-    /// - `initial_size`: Initial view size used to seed cached state.
+    /// - `rendering_context`: Rendering context used for `paint/present`.
+    /// - `events`: Per-view event queue.
+    /// - `strings`: Per-view string snapshots.
+    /// - `binary`: Per-view binary channel.
+    /// - `session`: Session tracking shared with the view entry.
+    /// - `permissions`: Permission requests shared with the view entry.
+    /// - `pointer_lock`: Pointer lock state shared with the view entry.
+    /// - `file_pickers`: File choosers shared with the view entry.
+    /// - `console_capture`: Console capture toggle shared with the view entry.
+    /// - `host_functions`: Host functions shared with the view entry.
+    /// - `external_streams`: Frame streams shared with the view entry.
+    /// - `input_results`: Tracked input events shared with the view entry.
+    /// - `network`: Engine network mode gate.
+    /// - `image_loads`: Image URL table of the shard.
+    /// - `view_token`: Token of the view.
+    /// - `textures`: Embedder textures pages can fetch.
+    /// - `render_scale`: Render scale shared with the view entry.
+    /// - `channel`: Host message channel shared with the view entry.
     ///
     /// ### 中文
-    /// 创建一个仅由 Servo 线程持有的 view 条目。
+    /// 创建一个绑定到指定渲染上下文的 delegate。
     ///
     /// #### 参数
-    /// - `token`：与 view ID 配对的单调递增 token。
-    /// - `servo_webview`：该 view 对应的 Servo WebView 实例。
-    /// - `rendering_context`：该 view 持有的渲染上下文。
-    /// - `mouse_move`：共享的鼠标移动合并状态。
-    /// - `input_queue`：共享的有界输入队列。
-    /// - `resize`：共享的 resize 合并状态。
-    /// - `load_url`：共享的 URL 合并状态。
-    /// - `pending`：共享的 pending-work 位图。
-    /// - `initial_size`：用于初始化缓存状态的初始尺寸。
+    /// - `rendering_context`：用于 `paint/present` 的渲染上下文。
+    /// - `events`：每 view 事件队列。
+    /// - `strings`：每 view 字符串快照。
+    /// - `binary`：每 view 的二进制通道。
+    /// - `session`：与 view 条目共享的会话跟踪状态。
+    /// - `permissions`：与 view 条目共享的权限请求。
+    /// - `pointer_lock`：与 view 条目共享的 pointer lock 状态。
+    /// - `file_pickers`：与 view 条目共享的文件选择器。
+    /// - `console_capture`：与 view 条目共享的 console 捕获开关。
+    /// - `host_functions`：与 view 条目共享的宿主函数。
+    /// - `external_streams`：与 view 条目共享的帧流。
+    /// - `input_results`：与 view 条目共享的跟踪输入事件。
+    /// - `network`：引擎网络模式闸门。
+    /// - `image_loads`：分片的图片 URL 表。
+    /// - `view_token`：该 view 的 token。
+    /// - `textures`：页面可获取的宿主纹理。
+    /// - `render_scale`：与 view 条目共享的渲染缩放。
+    /// - `channel`：与 view 条目共享的宿主消息通道。
     pub(super) fn new(
-        token: u64,
-        servo_webview: servo::WebView,
         rendering_context: Rc<GlfwTripleBufferRenderingContext>,
-        mouse_move: Arc<CoalescedMouseMove>,
-        input_queue: Arc<InputEventQueue>,
-        resize: Arc<CoalescedResize>,
-        load_url: Arc<CoalescedLoadUrl>,
-        pending: Arc<PendingWork>,
-        initial_size: PhysicalSize<u32>,
+        events: Arc<ViewEventQueue>,
+        strings: Arc<ViewStrings>,
+        binary: Arc<BinaryChannel>,
+        session: Rc<SessionTracker>,
+        permissions: Rc<PermissionTracker>,
+        pointer_lock: Rc<PointerLock>,
+        file_pickers: Rc<FilePickerTracker>,
+        console_capture: Rc<Cell<bool>>,
+        host_functions: Rc<HostFunctionTable>,
+        external_streams: Rc<ExternalStreams>,
+        input_results: Rc<InputResultTracker>,
+        network: Rc<NetworkGate>,
+        image_loads: Rc<ImageLoadTracker>,
+        view_token: u64,
+        textures: Rc<SharedTextureTable>,
+        render_scale: Rc<Cell<f32>>,
+        channel: Rc<HostChannel>,
     ) -> Self {
         Self {
-            token,
-            servo_webview,
             rendering_context,
-            mouse_move,
-            input_queue,
-            resize,
-            load_url,
-            pending,
-            last_active: true,
-            last_size: initial_size,
+            events,
+            strings,
+            binary,
+            session,
+            permissions,
+            pointer_lock,
+            file_pickers,
+            console_capture,
+            host_functions,
+            external_streams,
+            heap_limit: HeapLimit::default(),
+            input_results,
+            network,
+            image_loads,
+            view_token,
+            textures,
+            render_scale,
+            channel,
+            text_input: Cell::new(None),
         }
     }
 
-    #[inline]
     /// ### English
-    /// Applies a pending resize if present (coalesced; latest wins).
+    /// Records a newly focused editable element and reports it as a `TEXT_INPUT` event, with its
+    /// rectangle converted from device (render) to view pixels.
+    ///
+    /// #### Parameters
+    /// - `input_method`: Input method control of the focused element.
+    ///
+    /// ### 中文
+    /// 记录新获得焦点的可编辑元素并以 `TEXT_INPUT` 事件上报，其矩形从设备（渲染）像素换算为 view 像素。
+    ///
+    /// #### 参数
+    /// - `input_method`：获得焦点元素的输入法控件。
+    fn show_text_input(&self, input_method: &servo::InputMethodControl) {
+        let input_type = match input_method.input_method_type() {
+            _ if input_method.multiline() => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_MULTILINE,
+            servo::InputMethodType::Text => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEXT,
+            servo::InputMethodType::Password => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_PASSWORD,
+            servo::InputMethodType::Number => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NUMBER,
+            servo::InputMethodType::Email => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_EMAIL,
+            servo::InputMethodType::Tel => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_TEL,
+            servo::InputMethodType::Url => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_URL,
+            servo::InputMethodType::Search => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_SEARCH,
+            _ => XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_OTHER,
+        };
+        let scale = self.render_scale.get();
+        let position = input_method.position();
+        let rect = [
+            position.min.x as f32 / scale,
+            position.min.y as f32 / scale,
+            position.width() as f32 / scale,
+            position.height() as f32 / scale,
+        ]
+        .map(|value| value.round() as i32);
+        self.text_input.set(Some(input_method.id()));
+        self.strings.set_text_input(input_type, rect);
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = i64::from(input_type);
+        event.value1 = input_method.insertion_point().map_or(-1, i64::from);
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Decodes a `"<channel>:<base64>"` message from `xianHost.sendBinary` into a pooled buffer and
+    /// announces it as a `BINARY_MESSAGE` event. Malformed, oversized or overflowing messages are
+    /// dropped.
+    ///
+    /// #### Parameters
+    /// - `message`: Message text after the `binary:` prefix.
     ///
     /// ### 中文
-    /// 应用待处理的 resize（合并；只保留最新一次）。
-    fn apply_resize(&mut self) {
-        let Some(size) = self.resize.take() else {
+    /// 将来自 `xianHost.sendBinary` 的 `"<channel>:<base64>"` 消息解码到缓冲池中的缓冲区，并以
+    /// `BINARY_MESSAGE` 事件通知。格式错误、过大或超出未释放上限的消息会被丢弃。
+    ///
+    /// #### 参数
+    /// - `message`：去掉 `binary:` 前缀后的消息文本。
+    fn receive_binary(&self, message: &str) {
+        let Some((channel, data)) = message.split_once(':') else {
             return;
         };
-        if size == self.last_size {
+        let Ok(channel) = channel.parse::<u32>() else {
+            return;
+        };
+        if data.len() / 4 * 3 > MAX_BINARY_MESSAGE_BYTES {
             return;
         }
-        self.last_size = size;
-        self.servo_webview.resize(size);
+
+        let mut buffer = self.binary.buffer();
+        if !base64::decode_into(data, &mut buffer) {
+            self.binary.recycle(buffer);
+            return;
+        }
+        let len = buffer.len();
+        let Some(id) = self.binary.receive(buffer) else {
+            return;
+        };
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_BINARY_MESSAGE,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            id,
+        );
+        event.value0 = i64::from(channel);
+        event.value1 = len as i64;
+        self.events.push(event);
     }
 
-    #[inline]
     /// ### English
-    /// Applies a pending mouse-move if present (coalesced; latest wins).
+    /// Reports one captured console call (`"<level>:<line>:<source>\n<message>"`) as a
+    /// `CONSOLE_MESSAGE` event.
+    ///
+    /// #### Parameters
+    /// - `payload`: Host message payload after the `console:` tag.
     ///
     /// ### 中文
-    /// 应用待处理的鼠标移动（合并；只保留最新一次）。
-    fn apply_mouse_move(&self) {
-        if !self.rendering_context.is_active() {
+    /// 将一条捕获到的 console 调用（`"<level>:<line>:<source>\n<message>"`）以 `CONSOLE_MESSAGE`
+    /// 事件上报。
+    ///
+    /// #### 参数
+    /// - `payload`：`console:` 标签之后的宿主消息载荷。
+    fn report_console_message(&self, payload: &str) {
+        let Some((header, message)) = payload.split_once('\n') else {
             return;
-        }
+        };
+        let mut parts = header.splitn(3, ':');
+        let (Some(level), Some(line), Some(source)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return;
+        };
 
-        let Some((x, y)) = self.mouse_move.take() else {
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_CONSOLE_MESSAGE,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = level
+            .parse()
+            .unwrap_or(i64::from(XIAN_WEB_ENGINE_LOG_LEVEL_INFO));
+        event.value1 = line.parse().unwrap_or(0);
+        event.text = Some(format!("{source}\n{message}"));
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Reports an uncaught exception (`"<line>:<column>:<source>\n<message>"`) as a `PAGE_ERROR`
+    /// event.
+    ///
+    /// #### Parameters
+    /// - `payload`: Host message payload after the `page-error:` tag.
+    ///
+    /// ### 中文
+    /// 将一个未捕获异常（`"<line>:<column>:<source>\n<message>"`）以 `PAGE_ERROR` 事件上报。
+    ///
+    /// #### 参数
+    /// - `payload`：`page-error:` 标签之后的宿主消息载荷。
+    fn report_page_error(&self, payload: &str) {
+        let Some((header, message)) = payload.split_once('\n') else {
+            return;
+        };
+        let mut parts = header.splitn(3, ':');
+        let (Some(line), Some(column), Some(source)) = (parts.next(), parts.next(), parts.next())
+        else {
             return;
         };
 
-        let point = servo::WebViewPoint::from(servo::DevicePoint::new(x, y));
-        self.servo_webview
-            .notify_input_event(servo::InputEvent::MouseMove(servo::MouseMoveEvent::new(
-                point,
-            )));
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_PAGE_ERROR,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = line.parse().unwrap_or(0);
+        event.value1 = column.parse().unwrap_or(0);
+        event.text = Some(format!("{source}\n{message}"));
+        self.events.push(event);
     }
 
-    #[inline]
     /// ### English
-    /// Drains the bounded input queue and dispatches events into Servo.
+    /// Reports a failed subresource load (`"<status>:<url>\n<detail>"`) as a `RESOURCE_ERROR` event.
+    ///
+    /// #### Parameters
+    /// - `payload`: Host message payload after the `resource-error:` tag.
     ///
     /// ### 中文
-    /// drain 有界输入队列并把事件派发到 Servo。
-    fn drain_input_queue(&self) {
-        loop {
-            let active = self.rendering_context.is_active();
-            while let Some(raw) = self.input_queue.pop() {
-                if active {
-                    dispatch_queued_input_event(&self.servo_webview, raw);
-                }
-            }
+    /// 将一次失败的子资源加载（`"<status>:<url>\n<detail>"`）以 `RESOURCE_ERROR` 事件上报。
+    ///
+    /// #### 参数
+    /// - `payload`：`resource-error:` 标签之后的宿主消息载荷。
+    fn report_resource_error(&self, payload: &str) {
+        let Some((status, rest)) = payload.split_once(':') else {
+            return;
+        };
 
-            self.input_queue.clear_pending();
-            let Some(raw) = self.input_queue.pop() else {
-                break;
-            };
-            self.input_queue.mark_pending();
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_RESOURCE_ERROR,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = status.parse().unwrap_or(0);
+        event.text = Some(rest.to_string());
+        self.events.push(event);
+    }
 
-            if self.rendering_context.is_active() {
-                dispatch_queued_input_event(&self.servo_webview, raw);
-            }
-        }
+    /// ### English
+    /// Reports an image replaced by a downscaled copy (`"<width>,<height>,<url>"`) as an
+    /// `IMAGE_DOWNSCALED` event.
+    ///
+    /// #### Parameters
+    /// - `payload`: Host message payload after the `image-downscaled:` tag.
+    ///
+    /// ### 中文
+    /// 将一张被替换为缩小副本的图片（`"<width>,<height>,<url>"`）以 `IMAGE_DOWNSCALED` 事件上报。
+    ///
+    /// #### 参数
+    /// - `payload`：`image-downscaled:` 标签之后的宿主消息载荷。
+    fn report_image_downscaled(&self, payload: &str) {
+        let mut parts = payload.splitn(3, ',');
+        let (Some(width), Some(height), Some(url)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return;
+        };
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = width.parse().unwrap_or(0);
+        event.value1 = height.parse().unwrap_or(0);
+        event.text = Some(url.to_string());
+        self.events.push(event);
     }
 
-    #[inline]
     /// ### English
-    /// Processes all pending work bits for this view.
+    /// Stores a permission request and reports it as a `PERMISSION_REQUEST` event; it is denied if
+    /// the embedder does not answer within `PERMISSION_TIMEOUT`.
+    ///
+    /// #### Parameters
+    /// - `request`: Pending permission request.
+    /// - `feature`: Requested feature (`XIAN_WEB_ENGINE_PERMISSION_*`).
     ///
     /// ### 中文
-    /// 处理该 view 的所有待处理 work bit。
-    pub(super) fn process_pending(&mut self) {
-        if !self.pending.is_marked() {
+    /// 保存权限请求并以 `PERMISSION_REQUEST` 事件上报；若宿主未在 `PERMISSION_TIMEOUT` 内应答，则拒绝该请求。
+    ///
+    /// #### 参数
+    /// - `request`：待处理的权限请求。
+    /// - `feature`：请求的特性（`XIAN_WEB_ENGINE_PERMISSION_*`）。
+    fn forward_permission_request(&self, request: PendingPermission, feature: i64) {
+        let request_id = self.permissions.insert(request);
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            request_id,
+        );
+        event.value0 = feature;
+        event.value1 = PERMISSION_TIMEOUT.as_millis() as i64;
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Handles the page's pointer lock request: denied at once without transient user activation,
+    /// otherwise forwarded to the embedder as a `POINTER_LOCK` permission request.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView requesting the lock.
+    ///
+    /// ### 中文
+    /// 处理页面的 pointer lock 请求：没有瞬时用户激活时立即拒绝，否则作为 `POINTER_LOCK` 权限请求转发给宿主。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：请求锁定的 WebView。
+    fn request_pointer_lock(&self, servo_webview: &servo::WebView) {
+        if !self.pointer_lock.consume_activation() {
+            self.pointer_lock.settle(servo_webview, &self.events, false);
             return;
         }
 
-        loop {
-            let bits = self.pending.take();
+        let pointer_lock = self.pointer_lock.clone();
+        let events = self.events.clone();
+        let webview = servo_webview.clone();
+        self.forward_permission_request(
+            PendingPermission::Engine(Box::new(move |allow| {
+                pointer_lock.settle(&webview, &events, allow);
+            })),
+            XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK,
+        );
+    }
+}
+
+impl servo::WebViewDelegate for Delegate {
+    /// ### English
+    /// Called by Servo when a new frame can be rendered/presented.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that should be painted and presented.
+    ///
+    /// ### 中文
+    /// 当 Servo 通知有新帧可渲染/呈现时调用。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：需要执行 paint/present 的 WebView。
+    fn notify_new_frame_ready(&self, servo_webview: servo::WebView) {
+        if !self.rendering_context.is_active() {
+            return;
+        }
+        if !self.rendering_context.preflight_reserve_next_back_slot() {
+            return;
+        }
+
+        let paint_span = trace::span(TRACE_SPAN_PAINT);
+        servo_webview.paint();
+        drop(paint_span);
+        servo::RenderingContext::present(&*self.rendering_context);
+    }
+
+    /// ### English
+    /// Records the latest history so sessions can be saved, and issues the next step of a session
+    /// restore once the previous one committed.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView whose history changed.
+    /// - `entries`: History entry URLs.
+    /// - `current`: Index of the current entry.
+    ///
+    /// ### 中文
+    /// 记录最新历史以便保存会话，并在会话恢复的上一步提交后执行下一步。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：历史发生变化的 WebView。
+    /// - `entries`：历史条目 URL。
+    /// - `current`：当前条目的索引。
+    fn notify_history_changed(
+        &self,
+        servo_webview: servo::WebView,
+        entries: Vec<Url>,
+        current: usize,
+    ) {
+        self.session.set_history(entries, current);
+        match self.session.advance_restore() {
+            Some(RestoreStep::Load(url)) => servo_webview.load(url),
+            Some(RestoreStep::GoBack(count)) => {
+                servo_webview.go_back(count);
+            }
+            Some(RestoreStep::Done) => {
+                if matches!(servo_webview.load_status(), servo::LoadStatus::Complete) {
+                    apply_pending_scroll(&servo_webview, &self.session);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// ### English
+    /// Tracks document loading for the network activity counter, re-arms heap limit reporting for a
+    /// new page, and applies a pending session-restore scroll offset once loading completes.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView whose load status changed.
+    /// - `status`: New load status.
+    ///
+    /// ### 中文
+    /// 为网络活动计数跟踪文档加载状态，为新页面重新启用堆上限上报，并在加载完成后应用待恢复的会话滚动偏移。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：加载状态发生变化的 WebView。
+    /// - `status`：新的加载状态。
+    fn notify_load_status_changed(&self, servo_webview: servo::WebView, status: servo::LoadStatus) {
+        let complete = matches!(status, servo::LoadStatus::Complete);
+        if matches!(status, servo::LoadStatus::Started) {
+            self.host_functions.clear_pending();
+            self.external_streams.clear();
+            self.pointer_lock.unlock(&self.events);
+            self.heap_limit.rearm();
+        }
+        if matches!(status, servo::LoadStatus::Started) || complete {
+            self.strings.set_document_loading(!complete);
+        }
+        if !complete {
+            return;
+        }
+        apply_pending_scroll(&servo_webview, &self.session);
+    }
+
+    /// ### English
+    /// Fails network requests the engine network mode does not allow; other loads proceed normally.
+    ///
+    /// Allowed HTTP(S) subresource requests (not main-frame navigations or redirects of a request
+    /// already counted) are counted as in flight until the request completion script reports them
+    /// finished.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView issuing the request.
+    /// - `load`: Resource load to allow or fail.
+    ///
+    /// ### 中文
+    /// 让引擎网络模式不允许的网络请求失败；其他加载正常进行。
+    ///
+    /// 被放行的 HTTP(S) 子资源请求（不含主 frame 导航，也不含已计数请求的重定向）会计为进行中，直到请求完成
+    /// 脚本报告其完成。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：发起请求的 WebView。
+    /// - `load`：要放行或使其失败的资源加载。
+    fn load_web_resource(&self, _servo_webview: servo::WebView, load: servo::WebResourceLoad) {
+        let request = load.request();
+        let url = &request.url;
+        if self.network.allows(url) {
+            if matches!(url.scheme(), "http" | "https")
+                && !request.is_for_main_frame
+                && !request.is_redirect
+            {
+                self.strings.begin_request();
+            }
+            return;
+        }
+
+        let response = servo::WebResourceResponse::new(url.clone());
+        load.intercept(response).cancel();
+    }
+
+    /// ### English
+    /// Publishes the new URL of the current page.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView whose URL changed.
+    /// - `url`: New URL.
+    ///
+    /// ### 中文
+    /// 发布当前页面的新 URL。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：URL 发生变化的 WebView。
+    /// - `url`：新的 URL。
+    fn notify_url_changed(&self, _servo_webview: servo::WebView, url: Url) {
+        self.strings.set_url(url.as_str());
+    }
+
+    /// ### English
+    /// Publishes the new title of the current page (empty if it has none).
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView whose title changed.
+    /// - `title`: New title.
+    ///
+    /// ### 中文
+    /// 发布当前页面的新标题（无标题时为空）。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：标题发生变化的 WebView。
+    /// - `title`：新的标题。
+    fn notify_page_title_changed(&self, _servo_webview: servo::WebView, title: Option<String>) {
+        self.strings.set_title(title.as_deref().unwrap_or_default());
+    }
+
+    /// ### English
+    /// Records a content crash as the view's last error and reports it as a `CRASHED` event (plus a
+    /// `HEAP_LIMIT` event if the page ran out of its heap).
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView whose content crashed.
+    /// - `reason`: Crash reason.
+    /// - `backtrace`: Optional backtrace.
+    ///
+    /// ### 中文
+    /// 将内容崩溃记录为该 view 的最近错误并以 `CRASHED` 事件上报（若页面耗尽了其堆，还会以 `HEAP_LIMIT`
+    /// 事件上报）。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：内容崩溃的 WebView。
+    /// - `reason`：崩溃原因。
+    /// - `backtrace`：可选的调用栈。
+    fn notify_crashed(
+        &self,
+        _servo_webview: servo::WebView,
+        reason: String,
+        backtrace: Option<String>,
+    ) {
+        self.strings
+            .set_last_error(&format!("Page crashed: {reason}"));
+        self.heap_limit.check_crash(&reason, &self.events);
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_CRASHED,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.text = Some(match backtrace {
+            Some(backtrace) => format!("{reason}\n{backtrace}"),
+            None => reason,
+        });
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Reports the result of an input event sent with a correlation ID as an `INPUT_RESULT` event.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that handled the event.
+    /// - `id`: Servo input event ID.
+    /// - `result`: Flags the page set on the event.
+    ///
+    /// ### 中文
+    /// 将携带关联 ID 发送的输入事件的结果以 `INPUT_RESULT` 事件上报。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：处理该事件的 WebView。
+    /// - `id`：Servo 输入事件 ID。
+    /// - `result`：页面为该事件设置的标志。
+    fn notify_input_event_handled(
+        &self,
+        servo_webview: servo::WebView,
+        id: servo::InputEventId,
+        result: servo::InputEventResult,
+    ) {
+        self.input_results
+            .report(&servo_webview, id, result, &self.events);
+    }
+
+    /// ### English
+    /// Reports media session playback state changes as `MEDIA_PLAYBACK` events.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView whose media session changed.
+    /// - `event`: Media session event.
+    ///
+    /// ### 中文
+    /// 将媒体会话播放状态变化以 `MEDIA_PLAYBACK` 事件上报。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：媒体会话发生变化的 WebView。
+    /// - `event`：媒体会话事件。
+    fn notify_media_session_event(
+        &self,
+        _servo_webview: servo::WebView,
+        event: servo::MediaSessionEvent,
+    ) {
+        let servo::MediaSessionEvent::PlaybackStateChange(state) = event else {
+            return;
+        };
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_PLAYBACK,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = match state {
+            servo::MediaSessionPlaybackState::Playing => 1,
+            servo::MediaSessionPlaybackState::Paused => 0,
+            servo::MediaSessionPlaybackState::None_ => -1,
+        };
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Forwards a permission request to the embedder as a `PERMISSION_REQUEST` event.
+    ///
+    /// The request is denied if the embedder does not answer within `PERMISSION_TIMEOUT`.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView requesting the permission.
+    /// - `request`: Servo permission request.
+    ///
+    /// ### 中文
+    /// 将权限请求以 `PERMISSION_REQUEST` 事件转发给宿主。
+    ///
+    /// 若宿主未在 `PERMISSION_TIMEOUT` 内应答，则拒绝该请求。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：请求权限的 WebView。
+    /// - `request`：Servo 权限请求。
+    fn request_permission(
+        &self,
+        _servo_webview: servo::WebView,
+        request: servo::PermissionRequest,
+    ) {
+        let feature = permission_feature_code(request.feature());
+        self.forward_permission_request(PendingPermission::Servo(request), feature);
+    }
+
+    /// ### English
+    /// Consumes host messages sent by engine page scripts (console messages carrying this view's
+    /// `HostChannel` prefix); other console output is ignored. Payloads still carry page-controlled
+    /// data and are validated by each handler.
+    ///
+    /// Page console calls arrive here through the console capture script and are reported as
+    /// `CONSOLE_MESSAGE` events while capture is enabled.
+    ///
+    /// #### Parameters
+    /// - `servo_webview`: WebView that logged the message.
+    /// - `_level`: Console log level.
+    /// - `message`: Console message text.
+    ///
+    /// ### 中文
+    /// 消费引擎页面脚本发送的宿主消息（带有该 view `HostChannel` 前缀的 console 消息）；其他 console
+    /// 输出会被忽略。载荷仍包含页面可控的数据，由各处理函数自行校验。
+    ///
+    /// 页面的 console 调用会经由 console 捕获脚本到达此处，并在捕获开启时以 `CONSOLE_MESSAGE` 事件上报。
+    ///
+    /// #### 参数
+    /// - `servo_webview`：输出该消息的 WebView。
+    /// - `_level`：console 日志级别。
+    /// - `message`：console 消息文本。
+    fn show_console_message(
+        &self,
+        servo_webview: servo::WebView,
+        _level: servo::ConsoleLogLevel,
+        message: String,
+    ) {
+        let Some(message) = self.channel.strip(&message) else {
+            return;
+        };
+
+        if let Some(call) = message.strip_prefix("host-call:") {
+            self.host_functions.call(&servo_webview, call);
+            return;
+        }
+        if let Some(binary) = message.strip_prefix("binary:") {
+            self.receive_binary(binary);
+            return;
+        }
+        if let Some(texture) = message.strip_prefix("texture:") {
+            self.textures.fetch(&servo_webview, texture);
+            return;
+        }
+        if let Some(id) = message.strip_prefix("stream-open:") {
+            self.external_streams.set_open(id, true);
+            return;
+        }
+        if let Some(id) = message.strip_prefix("stream-close:") {
+            self.external_streams.set_open(id, false);
+            return;
+        }
+
+        if let Some(console) = message.strip_prefix("console:") {
+            if self.console_capture.get() {
+                self.report_console_message(console);
+            }
+            return;
+        }
+        if let Some(error) = message.strip_prefix("page-error:") {
+            self.report_page_error(error);
+            if let Some((_, text)) = error.split_once('\n') {
+                self.heap_limit
+                    .check_page_error(text, &servo_webview, &self.events);
+            }
+            return;
+        }
+        if let Some(error) = message.strip_prefix("resource-error:") {
+            if error.starts_with("0:") {
+                self.strings.finish_requests(1);
+            }
+            self.report_resource_error(error);
+            return;
+        }
+
+        if let Some(image) = message.strip_prefix("image-downscaled:") {
+            self.report_image_downscaled(image);
+            return;
+        }
+        if let Some(image) = message.strip_prefix("image-load:") {
+            self.image_loads.record_load(self.view_token, image);
+            return;
+        }
+
+        if let Some(finished) = message.strip_prefix("requests-finished:") {
+            if let Ok(finished) = finished.parse() {
+                self.strings.finish_requests(finished);
+            }
+            return;
+        }
+
+        if let Some(offset) = message.strip_prefix("scroll:") {
+            let mut parts = offset.split(',').map(|v| v.parse::<f32>());
+            if let (Some(Ok(x)), Some(Ok(y))) = (parts.next(), parts.next()) {
+                self.strings.set_scroll(x, y);
+            }
+            return;
+        }
+
+        match message {
+            "pointer-lock-request" => self.request_pointer_lock(&servo_webview),
+            "pointer-lock:0" => self.pointer_lock.unlock(&self.events),
+            _ => {}
+        }
+    }
+
+    /// ### English
+    /// Forwards file choosers to the embedder as `FILE_PICKER` events and editable focus as
+    /// `TEXT_INPUT` events; other controls are not supported and are dropped.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView showing the control.
+    /// - `control`: Embedder control to show.
+    ///
+    /// ### 中文
+    /// 将文件选择器以 `FILE_PICKER` 事件、可编辑元素的焦点以 `TEXT_INPUT` 事件转发给宿主；其他控件暂不支持，
+    /// 直接丢弃。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：显示该控件的 WebView。
+    /// - `control`：要显示的宿主控件。
+    fn show_embedder_control(
+        &self,
+        _servo_webview: servo::WebView,
+        control: servo::EmbedderControl,
+    ) {
+        let picker = match control {
+            servo::EmbedderControl::FilePicker(picker) => picker,
+            servo::EmbedderControl::InputMethod(input_method) => {
+                self.show_text_input(&input_method);
+                return;
+            }
+            _ => return,
+        };
+
+        let filters = picker
+            .filter_patterns()
+            .iter()
+            .map(|pattern| pattern.0.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let multiple = picker.allow_select_multiple();
+        let request_id = self.file_pickers.insert(picker);
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            request_id,
+        );
+        event.value0 = multiple as i64;
+        event.text = Some(filters);
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Reports the focused editable element losing focus as a `TEXT_INPUT` event with type `NONE`;
+    /// other controls are ignored.
+    ///
+    /// #### Parameters
+    /// - `_servo_webview`: WebView hiding the control.
+    /// - `control_id`: ID of the control to hide.
+    ///
+    /// ### 中文
+    /// 将获得焦点的可编辑元素失去焦点以类型为 `NONE` 的 `TEXT_INPUT` 事件上报；其他控件会被忽略。
+    ///
+    /// #### 参数
+    /// - `_servo_webview`：隐藏该控件的 WebView。
+    /// - `control_id`：要隐藏的控件 ID。
+    fn hide_embedder_control(
+        &self,
+        _servo_webview: servo::WebView,
+        control_id: servo::EmbedderControlId,
+    ) {
+        if self.text_input.get() != Some(control_id) {
+            return;
+        }
+        self.text_input.set(None);
+        self.strings
+            .set_text_input(XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE, [0; 4]);
+
+        let mut event = ViewEvent::new(
+            XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT,
+            XIAN_WEB_ENGINE_STATUS_OK,
+            0,
+        );
+        event.value0 = i64::from(XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE);
+        event.value1 = -1;
+        self.events.push(event);
+    }
+}
+
+/// ### English
+/// Builds one call of `DRAG_DROP_SCRIPT`.
+///
+/// #### Parameters
+/// - `phase`: `"begin"`, `"move"`, `"drop"` or `"cancel"`.
+/// - `x`: Pointer X in device pixels.
+/// - `y`: Pointer Y in device pixels.
+/// - `mime`: MIME type of the dragged data (`"begin"` only).
+/// - `data`: Dragged data (`"begin"` only).
+///
+/// ### 中文
+/// 构造一次 `DRAG_DROP_SCRIPT` 调用。
+///
+/// #### 参数
+/// - `phase`：`"begin"`、`"move"`、`"drop"` 或 `"cancel"`。
+/// - `x`：指针 X（设备像素）。
+/// - `y`：指针 Y（设备像素）。
+/// - `mime`：被拖拽数据的 MIME 类型（仅 `"begin"`）。
+/// - `data`：被拖拽的数据（仅 `"begin"`）。
+fn drag_script(phase: &str, x: f32, y: f32, mime: &str, data: &str) -> String {
+    format!(
+        "({DRAG_DROP_SCRIPT})(\"{phase}\", {x}, {y}, {}, {})",
+        js_string_literal(mime),
+        js_string_literal(data)
+    )
+}
+
+/// ### English
+/// Scrolls the page to the pending session-restore offset, if any.
+///
+/// #### Parameters
+/// - `webview`: WebView showing the restored page.
+/// - `session`: Session tracking holding the pending offset.
+///
+/// ### 中文
+/// 将页面滚动到待应用的会话恢复偏移（如有）。
+///
+/// #### 参数
+/// - `webview`：显示恢复页面的 WebView。
+/// - `session`：保存待应用偏移的会话跟踪状态。
+fn apply_pending_scroll(webview: &servo::WebView, session: &SessionTracker) {
+    if let Some((x, y)) = session.take_pending_scroll() {
+        webview.evaluate_javascript(format!("scrollTo({x}, {y})"), |_| {});
+    }
+}
+
+/// ### English
+/// Per-view bookkeeping stored only on the Servo thread.
+///
+/// ### 中文
+/// 仅 Servo 线程持有的每个 view 状态。
+pub(super) struct ViewEntry {
+    /// ### English
+    /// Monotonic token associated with this view ID allocation.
+    ///
+    /// ### 中文
+    /// 该 view ID 分配时绑定的单调 token，用于忽略“ID 复用后”的陈旧销毁命令。
+    pub(super) token: u64,
+    /// ### English
+    /// Servo WebView instance (lives on Servo thread only).
+    ///
+    /// ### 中文
+    /// Servo WebView 实例（仅 Servo 线程持有）。
+    servo_webview: servo::WebView,
+    /// ### English
+    /// Rendering context + triple-buffer resources owned by this view.
+    ///
+    /// ### 中文
+    /// 该 view 持有的渲染上下文 + 三缓冲资源。
+    rendering_context: Rc<GlfwTripleBufferRenderingContext>,
+    /// ### English
+    /// Shared coalesced mouse-move state for this view.
+    ///
+    /// ### 中文
+    /// 该 view 的鼠标移动合并状态（共享）。
+    mouse_move: Arc<CoalescedMouseMove>,
+    /// ### English
+    /// Per-view bounded input queue (mouse move is handled separately).
+    ///
+    /// ### 中文
+    /// 每 view 的有界输入队列（鼠标移动单独处理）。
+    input_queue: Arc<InputEventQueue>,
+    /// ### English
+    /// Shared coalesced resize state for this view.
+    ///
+    /// ### 中文
+    /// 该 view 的 resize 合并状态（共享）。
+    resize: Arc<CoalescedResize>,
+    /// ### English
+    /// Shared coalesced URL load state (latest URL wins).
+    ///
+    /// ### 中文
+    /// 共享的 URL 合并状态（只保留最新一次）。
+    load_url: Arc<CoalescedLoadUrl>,
+    /// ### English
+    /// Per-view pending work bitmask (coalesces wakeups and queueing).
+    ///
+    /// ### 中文
+    /// 每 view 的 pending work bitmask（用于合并唤醒与 push）。
+    pending: Arc<PendingWork>,
+    /// ### English
+    /// Per-view event queue (Servo thread -> embedder).
+    ///
+    /// ### 中文
+    /// 每 view 的事件队列（Servo 线程 -> 宿主）。
+    events: Arc<ViewEventQueue>,
+    /// ### English
+    /// Per-view string snapshots (the entry records failures as the last error).
+    ///
+    /// ### 中文
+    /// 每 view 字符串快照（条目会把失败记录为最近错误）。
+    strings: Arc<ViewStrings>,
+    /// ### English
+    /// Binary channel (buffer pool and messages received from the page).
+    ///
+    /// ### 中文
+    /// 二进制通道（缓冲池与从页面收到的消息）。
+    binary: Arc<BinaryChannel>,
+    /// ### English
+    /// Session tracking shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的会话跟踪状态。
+    session: Rc<SessionTracker>,
+    /// ### English
+    /// Outstanding permission requests shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的待应答权限请求。
+    permissions: Rc<PermissionTracker>,
+    /// ### English
+    /// Pointer lock state shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的 pointer lock 状态。
+    pointer_lock: Rc<PointerLock>,
+    /// ### English
+    /// Open file choosers shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的已打开文件选择器。
+    file_pickers: Rc<FilePickerTracker>,
+    /// ### English
+    /// Console capture toggle shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的 console 捕获开关。
+    console_capture: Rc<Cell<bool>>,
+    /// ### English
+    /// Host functions shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的宿主函数。
+    host_functions: Rc<HostFunctionTable>,
+    /// ### English
+    /// Frame streams opened by the page, shared with the delegate.
+    ///
+    /// ### 中文
+    /// 页面已打开的帧流，与 delegate 共享。
+    external_streams: Rc<ExternalStreams>,
+    /// ### English
+    /// Tracked input events shared with the delegate.
+    ///
+    /// ### 中文
+    /// 与 delegate 共享的跟踪输入事件。
+    input_results: Rc<InputResultTracker>,
+    /// ### English
+    /// Per-view user content (user stylesheets/scripts injected into every load).
+    ///
+    /// ### 中文
+    /// 每 view 的用户内容（注入到每次加载的用户样式表/脚本）。
+    user_content: Rc<servo::UserContentManager>,
+    /// ### English
+    /// Stylesheet currently providing the default background (replaced on change).
+    ///
+    /// ### 中文
+    /// 当前提供默认背景色的样式表（变更时替换）。
+    background_stylesheet: Option<servo::UserStyleSheetId>,
+    /// ### English
+    /// Script currently overriding the reduced-motion and forced-colors media features (replaced
+    /// on change).
+    ///
+    /// ### 中文
+    /// 当前覆盖减少动态效果与强制颜色媒体特性的脚本（变更时替换）。
+    media_features_script: Option<servo::UserScriptId>,
+    /// ### English
+    /// Script keeping page time frozen on subsequent loads (installed while paused).
+    ///
+    /// ### 中文
+    /// 让后续加载的页面时间保持冻结的脚本（暂停期间安装）。
+    time_pause_script: Option<servo::UserScriptId>,
+    /// ### English
+    /// Host message channel of this view's engine scripts.
+    ///
+    /// ### 中文
+    /// 该 view 引擎脚本的宿主消息通道。
+    channel: Rc<HostChannel>,
+    /// ### English
+    /// Installed image size limit script (replaced when the engine limits change).
+    ///
+    /// ### 中文
+    /// 已安装的图片尺寸限制脚本（引擎限制变更时替换）。
+    image_limits_script: Option<servo::UserScriptId>,
+    /// ### English
+    /// Whether pages see `prefers-reduced-motion: reduce`.
+    ///
+    /// ### 中文
+    /// 页面是否看到 `prefers-reduced-motion: reduce`。
+    reduced_motion: bool,
+    /// ### English
+    /// Whether pages see `forced-colors: active`.
+    ///
+    /// ### 中文
+    /// 页面是否看到 `forced-colors: active`。
+    forced_colors: bool,
+    /// ### English
+    /// Last applied active flag (avoids redundant show/hide calls).
+    ///
+    /// ### 中文
+    /// 上一次已应用的 active 值（用于避免重复 show/hide）。
+    last_active: bool,
+    /// ### English
+    /// Behavior while inactive (`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`).
+    ///
+    /// ### 中文
+    /// inactive 时的行为（`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`）。
+    background_policy: u32,
+    /// ### English
+    /// Slow-script detection state.
+    ///
+    /// ### 中文
+    /// 慢脚本检测状态。
+    slow_script: SlowScriptWatch,
+    /// ### English
+    /// Last applied size (avoids redundant resize calls).
+    ///
+    /// ### 中文
+    /// 上一次已应用的尺寸（用于避免重复 resize）。
+    last_size: PhysicalSize<u32>,
+    /// ### English
+    /// Quiet period a resize waits for before it is applied (zero = immediate).
+    ///
+    /// ### 中文
+    /// resize 应用前需等待的静默期（为零表示立即应用）。
+    resize_debounce: Duration,
+    /// ### English
+    /// Latest debounced size and when it is applied (each new size restarts the quiet period).
+    ///
+    /// ### 中文
+    /// 最新的防抖尺寸及其应用时间（每个新尺寸都会重新开始静默期）。
+    debounced_resize: Option<(PhysicalSize<u32>, Instant)>,
+    /// ### English
+    /// Internal render resolution per logical pixel (`1.0` = native), shared with the delegate.
+    ///
+    /// ### 中文
+    /// 每逻辑像素对应的内部渲染分辨率（`1.0` 表示原生），与 delegate 共享。
+    render_scale: Rc<Cell<f32>>,
+    /// ### English
+    /// CSS viewport override `(width, height)` in CSS px (`0` = follow the view size on that axis;
+    /// `(0, 0)` = no override).
+    ///
+    /// ### 中文
+    /// CSS 视口覆盖值 `(width, height)`（CSS px；`0` 表示该轴跟随 view 尺寸；`(0, 0)` 表示不覆盖）。
+    viewport: (u32, u32),
+    /// ### English
+    /// View group this view belongs to (`0` = none).
+    ///
+    /// ### 中文
+    /// 该 view 所属的 view group（`0` 表示无）。
+    pub(super) group: u32,
+    /// ### English
+    /// Running replay, if any.
+    ///
+    /// ### 中文
+    /// 进行中的回放（若有）。
+    replay: Option<Replay>,
+    /// ### English
+    /// Synthesized input still being fed (`xian_web_engine_click_at` / `xian_web_engine_type_text`).
+    ///
+    /// ### 中文
+    /// 仍在注入的合成输入（`xian_web_engine_click_at` / `xian_web_engine_type_text`）。
+    synthetic: Option<Replay>,
+    /// ### English
+    /// Running screencast, if any.
+    ///
+    /// ### 中文
+    /// 进行中的屏幕投射（若有）。
+    #[cfg(feature = "screencast")]
+    screencast: Option<Screencast>,
+    /// ### English
+    /// Whether the view is in snapshot mode (script and painting suspended, last frame pinned).
+    ///
+    /// ### 中文
+    /// view 是否处于快照模式（暂停脚本与绘制，固定最后一帧）。
+    snapshot: bool,
+    /// ### English
+    /// End of the running snapshot refresh, if any.
+    ///
+    /// ### 中文
+    /// 进行中的快照刷新的结束时间（若有）。
+    snapshot_refresh: Option<Instant>,
+}
+
+impl ViewEntry {
+    #[allow(clippy::too_many_arguments)]
+    /// ### English
+    /// Creates a per-view entry stored only on the Servo thread.
+    ///
+    /// #### Parameters
+    /// - `token`: Monotonic token paired with the view ID.
+    /// - `servo_webview`: Servo WebView instance for this view.
+    /// - `rendering_context`: Rendering context owned by this view.
+    /// - `mouse_move`: Shared coalesced mouse-move state.
+    /// - `input_queue`: Shared bounded input queue.
+    /// - `resize`: Shared coalesced resize state.
+    /// - `load_url`: Shared coalesced URL load state.
+    /// - `pending`: Shared pending-work bitmask.
+    /// - `events`: Shared per-view event queue.
+    /// - `strings`: Shared per-view string snapshots.
+    /// - `binary`: Shared per-view binary channel.
+    /// - `session`: Session tracking shared with the delegate.
+    /// - `permissions`: Permission requests shared with the delegate.
+    /// - `pointer_lock`: Pointer lock state shared with the delegate.
+    /// - `file_pickers`: File choosers shared with the delegate.
+    /// - `console_capture`: Console capture toggle shared with the delegate.
+    /// - `host_functions`: Host functions shared with the delegate.
+    /// - `external_streams`: Frame streams shared with the delegate.
+    /// - `input_results`: Tracked input events shared with the delegate.
+    /// - `user_content`: Per-view user content manager.
+    /// - `initial_size`: Initial view size used to seed cached state.
+    /// - `render_scale`: Render scale (internal pixels per logical pixel) shared with the delegate.
+    /// - `group`: View group ID (`0` = none).
+    /// - `channel`: Host message channel shared with the delegate.
+    /// - `image_limits_script`: Installed image limits script, if any.
+    ///
+    /// ### 中文
+    /// 创建一个仅由 Servo 线程持有的 view 条目。
+    ///
+    /// #### 参数
+    /// - `token`：与 view ID 配对的单调递增 token。
+    /// - `servo_webview`：该 view 对应的 Servo WebView 实例。
+    /// - `rendering_context`：该 view 持有的渲染上下文。
+    /// - `mouse_move`：共享的鼠标移动合并状态。
+    /// - `input_queue`：共享的有界输入队列。
+    /// - `resize`：共享的 resize 合并状态。
+    /// - `load_url`：共享的 URL 合并状态。
+    /// - `pending`：共享的 pending-work 位图。
+    /// - `events`：共享的每 view 事件队列。
+    /// - `strings`：共享的每 view 字符串快照。
+    /// - `binary`：共享的每 view 二进制通道。
+    /// - `session`：与 delegate 共享的会话跟踪状态。
+    /// - `permissions`：与 delegate 共享的权限请求。
+    /// - `pointer_lock`：与 delegate 共享的 pointer lock 状态。
+    /// - `file_pickers`：与 delegate 共享的文件选择器。
+    /// - `console_capture`：与 delegate 共享的 console 捕获开关。
+    /// - `host_functions`：与 delegate 共享的宿主函数。
+    /// - `external_streams`：与 delegate 共享的帧流。
+    /// - `input_results`：与 delegate 共享的跟踪输入事件。
+    /// - `user_content`：每 view 的用户内容管理器。
+    /// - `initial_size`：用于初始化缓存状态的初始尺寸。
+    /// - `render_scale`：与 delegate 共享的渲染缩放（每逻辑像素对应的内部像素数）。
+    /// - `group`：view group ID（`0` 表示无）。
+    /// - `channel`：与 delegate 共享的宿主消息通道。
+    /// - `image_limits_script`：已安装的图片尺寸限制脚本（若有）。
+    pub(super) fn new(
+        token: u64,
+        servo_webview: servo::WebView,
+        rendering_context: Rc<GlfwTripleBufferRenderingContext>,
+        mouse_move: Arc<CoalescedMouseMove>,
+        input_queue: Arc<InputEventQueue>,
+        resize: Arc<CoalescedResize>,
+        load_url: Arc<CoalescedLoadUrl>,
+        pending: Arc<PendingWork>,
+        events: Arc<ViewEventQueue>,
+        strings: Arc<ViewStrings>,
+        binary: Arc<BinaryChannel>,
+        session: Rc<SessionTracker>,
+        permissions: Rc<PermissionTracker>,
+        pointer_lock: Rc<PointerLock>,
+        file_pickers: Rc<FilePickerTracker>,
+        console_capture: Rc<Cell<bool>>,
+        host_functions: Rc<HostFunctionTable>,
+        external_streams: Rc<ExternalStreams>,
+        input_results: Rc<InputResultTracker>,
+        user_content: Rc<servo::UserContentManager>,
+        initial_size: PhysicalSize<u32>,
+        render_scale: Rc<Cell<f32>>,
+        group: u32,
+        channel: Rc<HostChannel>,
+        image_limits_script: Option<servo::UserScriptId>,
+    ) -> Self {
+        Self {
+            token,
+            servo_webview,
+            rendering_context,
+            mouse_move,
+            input_queue,
+            resize,
+            load_url,
+            pending,
+            events,
+            strings,
+            binary,
+            session,
+            permissions,
+            pointer_lock,
+            file_pickers,
+            console_capture,
+            host_functions,
+            external_streams,
+            input_results,
+            user_content,
+            background_stylesheet: None,
+            media_features_script: None,
+            time_pause_script: None,
+            channel,
+            image_limits_script,
+            reduced_motion: false,
+            forced_colors: false,
+            last_active: true,
+            background_policy: XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL,
+            slow_script: SlowScriptWatch::default(),
+            last_size: initial_size,
+            resize_debounce: Duration::ZERO,
+            debounced_resize: None,
+            render_scale,
+            viewport: (0, 0),
+            group,
+            replay: None,
+            synthetic: None,
+            #[cfg(feature = "screencast")]
+            screencast: None,
+            snapshot: false,
+            snapshot_refresh: None,
+        }
+    }
+
+    #[inline]
+    /// ### English
+    /// Applies a pending resize if present (coalesced; latest wins), or holds it back for the
+    /// debounce quiet period; meanwhile frames keep the previous size.
+    ///
+    /// ### 中文
+    /// 应用待处理的 resize（合并；只保留最新一次），或将其暂缓一个防抖静默期；在此期间帧保持之前的尺寸。
+    fn apply_resize(&mut self) {
+        let Some(size) = self.resize.take() else {
+            return;
+        };
+        if self.resize_debounce.is_zero() {
+            self.resize_to(size);
+        } else {
+            self.debounced_resize = Some((size, Instant::now() + self.resize_debounce));
+        }
+    }
+
+    /// ### English
+    /// Resizes the view right away, dropping a debounced resize.
+    ///
+    /// #### Parameters
+    /// - `size`: New logical size.
+    ///
+    /// ### 中文
+    /// 立即 resize 该 view，并丢弃被防抖暂缓的 resize。
+    ///
+    /// #### 参数
+    /// - `size`：新的逻辑尺寸。
+    fn resize_to(&mut self, size: PhysicalSize<u32>) {
+        self.debounced_resize = None;
+        if size == self.last_size {
+            return;
+        }
+        self.last_size = size;
+        self.servo_webview
+            .resize(render_size(size, self.render_scale.get()));
+        if self.viewport != (0, 0) {
+            self.apply_device_pixel_ratio();
+        }
+    }
+
+    /// ### English
+    /// Sets the resize debounce quiet period; a zero period applies a held-back resize now.
+    ///
+    /// #### Parameters
+    /// - `debounce`: Quiet period (zero = immediate).
+    ///
+    /// ### 中文
+    /// 设置 resize 防抖静默期；静默期为零时会立即应用被暂缓的 resize。
+    ///
+    /// #### 参数
+    /// - `debounce`：静默期（为零表示立即应用）。
+    fn set_resize_debounce(&mut self, debounce: Duration) {
+        self.resize_debounce = debounce;
+        if debounce.is_zero()
+            && let Some((size, _)) = self.debounced_resize
+        {
+            self.resize_to(size);
+        }
+    }
+
+    /// ### English
+    /// Applies the debounced resize once its quiet period is over; returns when the loop must run
+    /// again (`None` if no resize is held back).
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 在静默期结束后应用被防抖暂缓的 resize；返回循环需要再次运行的时间（没有被暂缓的 resize 时为 `None`）。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn run_resize_debounce(&mut self, now: Instant) -> Option<Instant> {
+        let (size, due) = self.debounced_resize?;
+        if due > now {
+            return Some(due);
+        }
+        self.resize_to(size);
+        None
+    }
+
+    /// ### English
+    /// Changes the internal render resolution while keeping the CSS viewport size.
+    ///
+    /// The slot textures are resized to `logical size * scale` and Servo's device pixel ratio is set
+    /// to `scale`, so layout sees the same number of CSS pixels.
+    ///
+    /// #### Parameters
+    /// - `scale`: Internal pixels per logical pixel.
+    ///
+    /// ### 中文
+    /// 在保持 CSS 视口尺寸不变的前提下修改内部渲染分辨率。
+    ///
+    /// 槽位纹理会调整为 `逻辑尺寸 * scale`，并把 Servo 的设备像素比设为 `scale`，因此布局看到的 CSS 像素数不变。
+    ///
+    /// #### 参数
+    /// - `scale`：每逻辑像素对应的内部像素数。
+    fn set_render_scale(&mut self, scale: f32) {
+        if scale == self.render_scale.get() {
+            return;
+        }
+        self.render_scale.set(scale);
+        self.apply_device_pixel_ratio();
+        self.servo_webview
+            .resize(render_size(self.last_size, scale));
+    }
+
+    /// ### English
+    /// Overrides the CSS viewport size independently of the texture size, or removes the override
+    /// with `(0, 0)`.
+    ///
+    /// #### Parameters
+    /// - `width`: CSS viewport width in CSS px (`0` = follow the view width).
+    /// - `height`: CSS viewport height in CSS px (`0` = follow the view height).
+    ///
+    /// ### 中文
+    /// 独立于纹理尺寸覆盖 CSS 视口尺寸，或以 `(0, 0)` 移除覆盖。
+    ///
+    /// #### 参数
+    /// - `width`：CSS 视口宽度（CSS px；`0` 表示跟随 view 宽度）。
+    /// - `height`：CSS 视口高度（CSS px；`0` 表示跟随 view 高度）。
+    fn set_viewport(&mut self, width: u32, height: u32) {
+        if self.viewport == (width, height) {
+            return;
+        }
+        self.viewport = (width, height);
+        self.apply_device_pixel_ratio();
+    }
+
+    /// ### English
+    /// Returns Servo's device pixel ratio: the render scale, or with a viewport override the ratio
+    /// that fits the requested CSS size into the render size (the smaller ratio of the overridden
+    /// axes, so the page gets at least the requested size on both).
+    ///
+    /// ### 中文
+    /// 返回 Servo 的设备像素比：即渲染缩放；若覆盖了视口，则为把请求的 CSS 尺寸放入渲染尺寸的比例（取被覆盖
+    /// 各轴中较小的比例，使页面在两个轴上都至少得到请求的尺寸）。
+    fn device_pixel_ratio(&self) -> f32 {
+        let scale = self.render_scale.get();
+        let size = render_size(self.last_size, scale);
+        let (width, height) = self.viewport;
+        let ratio = |pixels: u32, css: u32| (css != 0).then(|| pixels as f32 / css as f32);
+        match (ratio(size.width, width), ratio(size.height, height)) {
+            (Some(x), Some(y)) => x.min(y),
+            (Some(ratio), None) | (None, Some(ratio)) => ratio,
+            (None, None) => scale,
+        }
+    }
+
+    /// ### English
+    /// Hands the current device pixel ratio to Servo.
+    ///
+    /// ### 中文
+    /// 将当前的设备像素比交给 Servo。
+    fn apply_device_pixel_ratio(&self) {
+        self.servo_webview
+            .set_hidpi_scale_factor(euclid::Scale::new(self.device_pixel_ratio()));
+    }
+
+    /// ### English
+    /// Moves the view to another render configuration without reloading the page: the slot textures
+    /// switch format/mipmaps in place and the render scale follows the new flags.
+    ///
+    /// If the shared context cannot provide the new color format, the old one is kept (logged).
+    ///
+    /// #### Parameters
+    /// - `render_scale`: Internal pixels per logical pixel.
+    /// - `color_format`: `XIAN_WEB_ENGINE_COLOR_FORMAT_*` value.
+    /// - `mipmaps`: Whether slot textures get a mip chain.
+    ///
+    /// ### 中文
+    /// 在不重新加载页面的情况下将 view 迁移到另一种渲染配置：槽位纹理原地切换格式/mipmap，渲染缩放随新标志变化。
+    ///
+    /// 若共享上下文无法提供新的颜色格式，则保留旧格式（并记录日志）。
+    ///
+    /// #### 参数
+    /// - `render_scale`：每逻辑像素对应的内部像素数。
+    /// - `color_format`：`XIAN_WEB_ENGINE_COLOR_FORMAT_*` 值。
+    /// - `mipmaps`：槽位纹理是否带 mip 链。
+    fn move_to(&mut self, render_scale: f32, color_format: u32, mipmaps: bool) {
+        if let Err(err) = self.rendering_context.reconfigure(color_format, mipmaps) {
+            log(
+                XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+                format_args!("Moved view keeps its slot format: {err}"),
+            );
+        }
+        self.set_render_scale(render_scale);
+    }
+
+    #[inline]
+    /// ### English
+    /// Applies a pending mouse-move if present (coalesced; latest wins).
+    ///
+    /// ### 中文
+    /// 应用待处理的鼠标移动（合并；只保留最新一次）。
+    fn apply_mouse_move(&self) {
+        if !self.rendering_context.is_active() {
+            return;
+        }
+
+        let Some((x, y)) = self.mouse_move.take() else {
+            return;
+        };
+
+        let point = servo::WebViewPoint::from(servo::DevicePoint::new(
+            x * self.render_scale.get(),
+            y * self.render_scale.get(),
+        ));
+        self.servo_webview
+            .notify_input_event(servo::InputEvent::MouseMove(servo::MouseMoveEvent::new(
+                point,
+            )));
+    }
+
+    #[inline]
+    /// ### English
+    /// Drains the bounded input queue and dispatches events into Servo.
+    ///
+    /// ### 中文
+    /// drain 有界输入队列并把事件派发到 Servo。
+    fn drain_input_queue(&self) {
+        loop {
+            let active = self.rendering_context.is_active();
+            while let Some(raw) = self.input_queue.pop() {
+                if active {
+                    self.dispatch_input(raw);
+                }
+            }
+
+            self.input_queue.clear_pending();
+            let Some(raw) = self.input_queue.pop() else {
+                break;
+            };
+            self.input_queue.mark_pending();
+
+            if self.rendering_context.is_active() {
+                self.dispatch_input(raw);
+            }
+        }
+    }
+
+    #[inline]
+    /// ### English
+    /// Dispatches one input event into Servo, recording transient user activation first.
+    ///
+    /// #### Parameters
+    /// - `raw`: ABI input event.
+    ///
+    /// ### 中文
+    /// 把一个输入事件派发到 Servo，并先记录瞬时用户激活。
+    ///
+    /// #### 参数
+    /// - `raw`：ABI 输入事件。
+    fn dispatch_input(&self, raw: XianWebEngineInputEvent) -> Option<servo::InputEventId> {
+        self.pointer_lock.record_input(&raw);
+        dispatch_queued_input_event(&self.servo_webview, raw, self.render_scale.get())
+    }
+
+    /// ### English
+    /// Dispatches an input event whose result is reported back, after the mouse move and input
+    /// queued before it. Dropped while the view is inactive.
+    ///
+    /// #### Parameters
+    /// - `event`: ABI input event (mouse button, wheel or key).
+    /// - `correlation_id`: Embedder correlation ID reported in the `INPUT_RESULT` event.
+    ///
+    /// ### 中文
+    /// 在其之前排队的鼠标移动与输入之后，派发一个会回报结果的输入事件。view 不处于 active 时丢弃。
+    ///
+    /// #### 参数
+    /// - `event`：ABI 输入事件（鼠标按键、滚轮或按键）。
+    /// - `correlation_id`：在 `INPUT_RESULT` 事件中回报的宿主关联 ID。
+    fn dispatch_tracked_input(&self, event: XianWebEngineInputEvent, correlation_id: u64) {
+        self.apply_mouse_move();
+        self.drain_input_queue();
+        if !self.rendering_context.is_active() {
+            return;
+        }
+
+        let scale = self.render_scale.get();
+        let Some(id) = self.dispatch_input(event) else {
+            return;
+        };
+        let point = (event.kind != XIAN_WEB_ENGINE_INPUT_KIND_KEY)
+            .then_some((event.x * scale, event.y * scale));
+        self.input_results.track(id, correlation_id, point);
+    }
+
+    /// ### English
+    /// Applies one per-view control request received through the command queue.
+    ///
+    /// #### Parameters
+    /// - `command`: Request to apply.
+    ///
+    /// ### 中文
+    /// 应用一条经由命令队列收到的 per-view 控制请求。
+    ///
+    /// #### 参数
+    /// - `command`：要应用的请求。
+    pub(super) fn apply_command(&mut self, command: ViewCommand) {
+        match command {
+            ViewCommand::Export {
+                request_id,
+                path,
+                format,
+            } => self.export(request_id, path, format),
+            ViewCommand::CaptureElement { selector, reply } => {
+                self.capture_element(&selector, reply)
+            }
+            ViewCommand::AccessibilityTree { request_id } => self.evaluate_to_event(
+                XIAN_WEB_ENGINE_VIEW_EVENT_ACCESSIBILITY_TREE,
+                request_id,
+                ACCESSIBILITY_TREE_SCRIPT,
+            ),
+            ViewCommand::SetMediaPaused { request_id, paused } => {
+                self.set_media_paused(request_id, paused)
+            }
+            ViewCommand::SaveSession { request_id } => self.save_session(request_id),
+            ViewCommand::RestoreSession {
+                entries,
+                current,
+                scroll_x,
+                scroll_y,
+            } => {
+                if let Some(url) =
+                    self.session
+                        .begin_restore(entries, current, (scroll_x, scroll_y))
+                {
+                    self.servo_webview.load(url);
+                }
+            }
+            ViewCommand::SetBackground { rgba } => self.set_background(rgba),
+            ViewCommand::SetRenderScale { scale } => self.set_render_scale(scale),
+            ViewCommand::SetViewport { width, height } => self.set_viewport(width, height),
+            ViewCommand::SetResizeDebounce { debounce } => self.set_resize_debounce(debounce),
+            ViewCommand::ResizeImmediate { size } => self.resize_to(size),
+            ViewCommand::Move {
+                render_scale,
+                color_format,
+                mipmaps,
+            } => self.move_to(render_scale, color_format, mipmaps),
+            ViewCommand::SetFrozen { frozen } => self.set_frozen(frozen),
+            ViewCommand::SetSnapshotMode { enabled } => self.set_snapshot_mode(enabled),
+            ViewCommand::RefreshSnapshot => self.refresh_snapshot(),
+            ViewCommand::TrackedInput {
+                event,
+                correlation_id,
+            } => self.dispatch_tracked_input(event, correlation_id),
+            ViewCommand::Replay { entries } => self.start_replay(entries),
+            ViewCommand::Synthesize { entries } => self.synthesize(entries),
+            ViewCommand::SetDebugPaused { paused } => {
+                self.rendering_context.refresh_driver().set_paused(paused);
+            }
+            ViewCommand::DebugStep => self.rendering_context.refresh_driver().step(),
+            ViewCommand::SetColorspace { colorspace } => self.set_colorspace(colorspace),
+            ViewCommand::SetColorScheme { scheme } => {
+                let theme = if scheme == XIAN_WEB_ENGINE_COLOR_SCHEME_DARK {
+                    servo::Theme::Dark
+                } else {
+                    servo::Theme::Light
+                };
+                self.servo_webview.notify_theme_change(theme);
+            }
+            ViewCommand::SetReducedMotion { reduce } => {
+                self.reduced_motion = reduce;
+                self.apply_media_features();
+            }
+            ViewCommand::SetForcedColors { active } => {
+                self.forced_colors = active;
+                self.apply_media_features();
+            }
+            ViewCommand::SetTimePaused { paused } => self.set_time_paused(paused),
+            ViewCommand::SetBackgroundPolicy { policy } => {
+                self.background_policy = policy;
+                if !self.last_active {
+                    self.apply_active(false);
+                }
+            }
+            ViewCommand::SetSlowScriptPolicy { policy, timeout_ms } => {
+                self.slow_script
+                    .configure(policy, Duration::from_millis(u64::from(timeout_ms)));
+            }
+            ViewCommand::AddUserStylesheet { css } => {
+                self.add_user_stylesheet(css);
+            }
+            ViewCommand::AddUserScript { source } => {
+                let script = Rc::new(servo::UserScript::new(source, None));
+                self.user_content.add_script(script);
+            }
+            ViewCommand::RespondPermission { request_id, allow } => {
+                self.permissions.respond(request_id, allow);
+            }
+            ViewCommand::ExitPointerLock => {
+                self.servo_webview
+                    .evaluate_javascript(EXIT_POINTER_LOCK_SCRIPT, |_| {});
+                self.pointer_lock.unlock(&self.events);
+            }
+            ViewCommand::BeginDrag { mime, data } => {
+                let script = drag_script("begin", 0.0, 0.0, &mime, &data);
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::DragMove { x, y } => {
+                let scale = self.render_scale.get();
+                let script = drag_script("move", x * scale, y * scale, "", "");
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::Drop { request_id, x, y } => self.evaluate_to_event(
+                XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE,
+                request_id,
+                &drag_script(
+                    "drop",
+                    x * self.render_scale.get(),
+                    y * self.render_scale.get(),
+                    "",
+                    "",
+                ),
+            ),
+            ViewCommand::CancelDrag => {
+                let script = drag_script("cancel", 0.0, 0.0, "", "");
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::ProvideFiles { request_id, paths } => {
+                self.file_pickers.respond(request_id, &paths);
+            }
+            ViewCommand::SetScroll { x, y, smooth } => {
+                let behavior = if smooth { "smooth" } else { "instant" };
+                let script = format!("scrollTo({{left: {x}, top: {y}, behavior: \"{behavior}\"}})");
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::UpdateSensors {
+                orientation,
+                acceleration,
+            } => {
+                let [qx, qy, qz, qw] = orientation;
+                let [ax, ay, az] = acceleration.unwrap_or_default();
+                let motion = acceleration.is_some();
+                let script = format!(
+                    "({DEVICE_SENSORS_SCRIPT})({qx}, {qy}, {qz}, {qw}, {motion}, {ax}, {ay}, {az})"
+                );
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::SelectAll => {
+                let script = format!("({SELECTION_SCRIPT})(\"selectAll\")");
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::Selection { request_id, copy } => {
+                let (kind, action) = if copy {
+                    (XIAN_WEB_ENGINE_VIEW_EVENT_COPY, "copy")
+                } else {
+                    (XIAN_WEB_ENGINE_VIEW_EVENT_SELECTED_TEXT, "get")
+                };
+                let script = format!("({SELECTION_SCRIPT})(\"{action}\")");
+                self.evaluate_to_event(kind, request_id, &script);
+            }
+            ViewCommand::SetConsoleCapture { enabled } => self.console_capture.set(enabled),
+            #[cfg(feature = "screencast")]
+            ViewCommand::StartScreencast { server, max_fps } => {
+                self.screencast = Some(Screencast::new(server, max_fps));
+            }
+            #[cfg(feature = "screencast")]
+            ViewCommand::StopScreencast => self.screencast = None,
+            ViewCommand::PostBinary { channel, data } => {
+                let script = format!(
+                    "window[Symbol.for(\"xian.binaryReceive\")]?.({channel}, \"{}\")",
+                    base64::encode(&data)
+                );
+                self.binary.recycle(data);
+                self.servo_webview.evaluate_javascript(script, |_| {});
+            }
+            ViewCommand::RegisterHostFunction { name, function } => {
+                self.host_functions.register(name, function);
+            }
+            ViewCommand::RespondHostCall {
+                call_id,
+                success,
+                result,
+            } => {
+                self.host_functions
+                    .respond(&self.servo_webview, call_id, success, &result);
+            }
+            ViewCommand::PushExternalFrame {
+                stream_id,
+                texture,
+                width,
+                height,
+                fence,
+            } => {
+                let delivered = self.external_streams.push(
+                    &self.servo_webview,
+                    self.rendering_context.shared_context(),
+                    stream_id,
+                    texture,
+                    width,
+                    height,
+                    fence,
+                );
+                let mut event = ViewEvent::new(
+                    XIAN_WEB_ENGINE_VIEW_EVENT_EXTERNAL_FRAME_RELEASED,
+                    if delivered {
+                        XIAN_WEB_ENGINE_STATUS_OK
+                    } else {
+                        XIAN_WEB_ENGINE_STATUS_FAILED
+                    },
+                    0,
+                );
+                event.value0 = i64::from(stream_id);
+                event.value1 = i64::from(texture);
+                self.events.push(event);
+            }
+        }
+    }
+
+    /// ### English
+    /// Denies expired permission requests and returns the earliest remaining deadline.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 拒绝已超时的权限请求，并返回剩余请求中最早的截止时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn expire_permissions(&self, now: Instant) -> Option<Instant> {
+        self.permissions.expire(now)
+    }
+
+    /// ### English
+    /// Releases the view's spare slot textures (engine idle with memory release).
+    ///
+    /// ### 中文
+    /// 释放该 view 的备用槽位纹理（引擎空闲并释放内存时）。
+    pub(super) fn release_spare_textures(&self) {
+        self.rendering_context.release_spare_textures();
+    }
+
+    /// ### English
+    /// Tears down the view and hands back its rendering context, so GL resource deletion can be
+    /// deferred until the consumer has released every slot.
+    ///
+    /// ### 中文
+    /// 拆除该 view 并交还其渲染上下文，以便将 GL 资源删除推迟到消费者释放所有槽位之后。
+    pub(super) fn into_rendering_context(self) -> Rc<GlfwTripleBufferRenderingContext> {
+        self.rendering_context
+    }
+
+    /// ### English
+    /// Runs slow-script detection and returns when it needs to run again.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 执行慢脚本检测，并返回下一次需要执行的时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn check_slow_script(&self, now: Instant) -> Option<Instant> {
+        self.slow_script
+            .poll(now, &self.servo_webview, &self.events)
+    }
+
+    /// ### English
+    /// Captures a screencast frame if one is due and returns when it needs to run again.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 若屏幕投射帧已到期则截取一帧，并返回下一次需要执行的时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    #[cfg(feature = "screencast")]
+    pub(super) fn run_screencast(&mut self, now: Instant) -> Option<Instant> {
+        let visible = self.last_active && !self.rendering_context.is_frozen();
+        self.screencast
+            .as_mut()?
+            .poll(now, visible, &self.servo_webview)
+    }
+
+    /// ### English
+    /// Starts replaying recorded actions, ending (with a `FAILED` finished event) a replay still
+    /// running.
+    ///
+    /// #### Parameters
+    /// - `entries`: Recorded entries.
+    ///
+    /// ### 中文
+    /// 开始回放已录制的动作；仍在进行的回放会被结束（并产生 `FAILED` 的结束事件）。
+    ///
+    /// #### 参数
+    /// - `entries`：已录制的条目。
+    fn start_replay(&mut self, entries: Vec<RecordedEntry>) {
+        if let Some(previous) = self.replay.take() {
+            self.finish_replay(&previous, XIAN_WEB_ENGINE_STATUS_FAILED);
+        }
+        self.replay = Some(Replay::new(entries, Instant::now()));
+    }
+
+    /// ### English
+    /// Feeds the replay actions that are due into the view and returns when the loop must run again:
+    /// right away after an action was fed (so Servo processes it), otherwise when the next one is due.
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 将已到期的回放动作注入 view，并返回循环需要再次运行的时间：注入了动作时立即运行（以便 Servo 处理），
+    /// 否则为下一个动作的到期时间。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn run_replay(&mut self, now: Instant) -> Option<Instant> {
+        let mut replay = self.replay.take()?;
+        let mut fed = false;
+        while let Some(action) = replay.next_due(now) {
+            self.replay_action(action);
+            fed = true;
+        }
+
+        let deadline = replay.deadline();
+        if deadline.is_some() {
+            self.replay = Some(replay);
+        } else {
+            self.finish_replay(&replay, XIAN_WEB_ENGINE_STATUS_OK);
+        }
+        if fed { Some(now) } else { deadline }
+    }
+
+    /// ### English
+    /// Queues synthesized input after the synthesized input still pending.
+    ///
+    /// #### Parameters
+    /// - `entries`: Synthesized entries.
+    ///
+    /// ### 中文
+    /// 将合成输入排在仍待注入的合成输入之后。
+    ///
+    /// #### 参数
+    /// - `entries`：合成的条目。
+    fn synthesize(&mut self, entries: Vec<RecordedEntry>) {
+        let now = Instant::now();
+        match &mut self.synthetic {
+            Some(synthetic) => synthetic.append(entries, now),
+            None => self.synthetic = Some(Replay::new(entries, now)),
+        }
+    }
+
+    /// ### English
+    /// Feeds the synthesized input that is due into the view and returns when the loop must run again
+    /// (as for [`Self::run_replay`]).
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 将已到期的合成输入注入 view，并返回循环需要再次运行的时间（与 [`Self::run_replay`] 相同）。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn run_synthetic(&mut self, now: Instant) -> Option<Instant> {
+        let mut synthetic = self.synthetic.take()?;
+        let mut fed = false;
+        while let Some(action) = synthetic.next_due(now) {
+            self.replay_action(action);
+            fed = true;
+        }
+
+        let deadline = synthetic.deadline();
+        if deadline.is_some() {
+            self.synthetic = Some(synthetic);
+        }
+        if fed { Some(now) } else { deadline }
+    }
+
+    /// ### English
+    /// Applies one recorded action the way the FFI call that produced it would.
+    ///
+    /// #### Parameters
+    /// - `action`: Action to apply.
+    ///
+    /// ### 中文
+    /// 按产生该动作的 FFI 调用的方式应用一个已录制的动作。
+    ///
+    /// #### 参数
+    /// - `action`：要应用的动作。
+    fn replay_action(&mut self, action: RecordedAction) {
+        match action {
+            RecordedAction::Input(event) if event.kind == XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE => {
+                self.mouse_move.set(event.x, event.y);
+                self.apply_mouse_move();
+            }
+            RecordedAction::Input(event) => {
+                if self.rendering_context.is_active() {
+                    self.dispatch_input(event);
+                }
+            }
+            RecordedAction::Resize(size) => self.resize_to(size),
+            RecordedAction::Navigate(url) => self.navigate(&url),
+        }
+    }
+
+    /// ### English
+    /// Reports the end of a replay.
+    ///
+    /// #### Parameters
+    /// - `replay`: Finished replay.
+    /// - `status`: `XIAN_WEB_ENGINE_STATUS_OK` (completed) or `XIAN_WEB_ENGINE_STATUS_FAILED`
+    ///   (replaced).
+    ///
+    /// ### 中文
+    /// 上报回放结束。
+    ///
+    /// #### 参数
+    /// - `replay`：已结束的回放。
+    /// - `status`：`XIAN_WEB_ENGINE_STATUS_OK`（已完成）或 `XIAN_WEB_ENGINE_STATUS_FAILED`（被替换）。
+    fn finish_replay(&self, replay: &Replay, status: i32) {
+        let mut event = ViewEvent::new(XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED, status, 0);
+        event.value0 = replay.replayed() as i64;
+        self.events.push(event);
+    }
+
+    /// ### English
+    /// Loads a URL string; an invalid URL is recorded as the last error.
+    ///
+    /// #### Parameters
+    /// - `request`: URL string.
+    ///
+    /// ### 中文
+    /// 加载一个 URL 字符串；非法 URL 会被记录为最近错误。
+    ///
+    /// #### 参数
+    /// - `request`：URL 字符串。
+    fn navigate(&self, request: &str) {
+        match Url::parse(request) {
+            Ok(url) => self.servo_webview.load(url),
+            Err(err) => self
+                .strings
+                .set_last_error(&format!("Invalid URL {request:?}: {err}")),
+        }
+    }
+
+    /// ### English
+    /// Installs a stylesheet shared across views (e.g. a registered font) for subsequent loads.
+    ///
+    /// #### Parameters
+    /// - `stylesheet`: Shared stylesheet.
+    ///
+    /// ### 中文
+    /// 为后续加载安装一个多 view 共享的样式表（例如已注册的字体）。
+    ///
+    /// #### 参数
+    /// - `stylesheet`：共享样式表。
+    pub(super) fn add_shared_stylesheet(&self, stylesheet: Rc<servo::UserStyleSheet>) {
+        self.user_content.add_stylesheet(stylesheet);
+    }
+
+    /// ### English
+    /// Freezes (drops the slot storage, hides and throttles the WebView) or thaws the view. Thawing
+    /// reallocates the slots and repaints an active view right away.
+    ///
+    /// #### Parameters
+    /// - `frozen`: Whether to freeze (`true`) or thaw (`false`).
+    ///
+    /// ### 中文
+    /// 冻结（释放槽位存储、隐藏并节流 WebView）或解冻该 view。解冻会重新分配槽位，并立即重绘 active 的 view。
+    ///
+    /// #### 参数
+    /// - `frozen`：冻结（`true`）或解冻（`false`）。
+    fn set_frozen(&self, frozen: bool) {
+        if frozen {
+            self.rendering_context.freeze();
+            self.apply_active(self.last_active);
+            return;
+        }
+
+        self.rendering_context.thaw();
+        self.apply_active(self.last_active);
+        self.repaint();
+    }
+
+    /// ### English
+    /// Enters or leaves snapshot mode. Entering hides and throttles the WebView, suspends the
+    /// page's timers, holds painting and pins the latest frame; leaving resumes the page and
+    /// repaints.
+    ///
+    /// #### Parameters
+    /// - `enabled`: Whether to enter snapshot mode.
+    ///
+    /// ### 中文
+    /// 进入或退出快照模式。进入时隐藏并节流 WebView、挂起页面定时器、暂停绘制并固定最新帧；退出时恢复页面并
+    /// 重绘。
+    ///
+    /// #### 参数
+    /// - `enabled`：是否进入快照模式。
+    fn set_snapshot_mode(&mut self, enabled: bool) {
+        if self.snapshot == enabled {
+            return;
+        }
+        self.snapshot = enabled;
+        self.snapshot_refresh = None;
+        self.rendering_context.set_snapshot(enabled);
+        self.apply_active(self.last_active);
+        if !enabled {
+            self.repaint();
+        }
+    }
+
+    /// ### English
+    /// Opens (or extends) a snapshot refresh: the page runs and paints for
+    /// [`SNAPSHOT_REFRESH_WINDOW`], then its last frame is pinned again. Ignored outside snapshot
+    /// mode.
+    ///
+    /// ### 中文
+    /// 开启（或延长）一次快照刷新：页面在 [`SNAPSHOT_REFRESH_WINDOW`] 内运行并绘制，随后再次固定其最后一帧。
+    /// 不处于快照模式时忽略。
+    fn refresh_snapshot(&mut self) {
+        if !self.snapshot {
+            return;
+        }
+        let opening = self.snapshot_refresh.is_none();
+        self.snapshot_refresh = Some(Instant::now() + SNAPSHOT_REFRESH_WINDOW);
+        if opening {
+            self.rendering_context.set_painting_held(false);
+            self.apply_active(self.last_active);
+        }
+        self.repaint();
+    }
+
+    /// ### English
+    /// Ends a due snapshot refresh (painting a final frame and suspending the page again) and makes
+    /// the pinned frame READY again after a fenced release; returns when the loop must run again
+    /// (`None` if nothing is pending).
+    ///
+    /// #### Parameters
+    /// - `now`: Current time.
+    ///
+    /// ### 中文
+    /// 结束已到期的快照刷新（绘制最后一帧并再次挂起页面），并在附带 fence 的 release 之后使被固定帧重新变为
+    /// READY；返回循环需要再次运行的时间（没有待处理工作时为 `None`）。
+    ///
+    /// #### 参数
+    /// - `now`：当前时间。
+    pub(super) fn run_snapshot(&mut self, now: Instant) -> Option<Instant> {
+        if !self.snapshot {
+            return None;
+        }
+        if let Some(due) = self.snapshot_refresh {
+            if due > now {
+                return Some(due);
+            }
+            self.repaint();
+            self.snapshot_refresh = None;
+            self.rendering_context.set_painting_held(true);
+            self.apply_active(self.last_active);
+        }
+        self.rendering_context
+            .restore_pinned_frame()
+            .then(|| now + SNAPSHOT_FENCE_POLL)
+    }
+
+    /// ### English
+    /// Paints and presents a frame right away if the view is active and a slot is available.
+    ///
+    /// ### 中文
+    /// 若 view 处于 active 且有可用槽位，则立即绘制并呈现一帧。
+    fn repaint(&self) {
+        if !self.rendering_context.is_active()
+            || !self.rendering_context.preflight_reserve_next_back_slot()
+        {
+            return;
+        }
+        self.servo_webview.paint();
+        servo::RenderingContext::present(&*self.rendering_context);
+    }
+
+    /// ### English
+    /// Shows or hides the view and applies the background policy to Servo and the page's timers.
+    /// Snapshot mode (outside of a refresh) suspends the page like a hidden view would.
+    ///
+    /// #### Parameters
+    /// - `active`: Whether the view is active.
+    ///
+    /// ### 中文
+    /// 显示或隐藏 view，并将后台策略应用到 Servo 与页面定时器。快照模式（刷新期间除外）会像隐藏的 view 一样
+    /// 挂起页面。
+    ///
+    /// #### 参数
+    /// - `active`：view 是否 active。
+    fn apply_active(&self, active: bool) {
+        let snapshot = self.snapshot && self.snapshot_refresh.is_none();
+        let mode = if snapshot {
+            XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND
+        } else if active {
+            XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL
+        } else {
+            self.background_policy
+        };
+        let frozen = self.rendering_context.is_frozen();
+        if active && !frozen && !snapshot {
+            self.servo_webview.set_throttled(false);
+            self.servo_webview.show();
+        } else {
+            self.servo_webview
+                .set_throttled(frozen || mode != XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL);
+            self.servo_webview.hide();
+        }
+        let script = format!("window[Symbol.for(\"xian.backgroundTimers\")]?.setMode({mode})");
+        self.servo_webview.evaluate_javascript(script, |_| {});
+    }
+
+    /// ### English
+    /// Registers a user stylesheet for subsequent loads and returns its ID.
+    ///
+    /// #### Parameters
+    /// - `css`: Stylesheet source.
+    ///
+    /// ### 中文
+    /// 为后续加载注册一个用户样式表，并返回其 ID。
+    ///
+    /// #### 参数
+    /// - `css`：样式表源码。
+    fn add_user_stylesheet(&self, css: String) -> servo::UserStyleSheetId {
+        let url = Url::parse("about:xian-user-stylesheet").expect("static URL");
+        let stylesheet = Rc::new(servo::UserStyleSheet::new(css, url));
+        let id = stylesheet.id();
+        self.user_content.add_stylesheet(stylesheet);
+        id
+    }
+
+    /// ### English
+    /// Applies a new output colorspace and repaints so the next frame reflects it.
+    ///
+    /// #### Parameters
+    /// - `colorspace`: `XIAN_WEB_ENGINE_COLORSPACE_*` value.
+    ///
+    /// ### 中文
+    /// 应用新的输出色彩空间并重绘，使下一帧即可体现。
+    ///
+    /// #### 参数
+    /// - `colorspace`：`XIAN_WEB_ENGINE_COLORSPACE_*` 值。
+    fn set_colorspace(&self, colorspace: u32) {
+        self.rendering_context.set_colorspace(colorspace);
+        self.repaint();
+    }
+
+    /// ### English
+    /// Replaces the default-background stylesheet.
+    ///
+    /// The rule has user-origin specificity, so pages that set their own background still win.
+    ///
+    /// #### Parameters
+    /// - `rgba`: Background color as `0xRRGGBBAA`.
+    ///
+    /// ### 中文
+    /// 替换提供默认背景色的样式表。
+    ///
+    /// 该规则属于 user 来源，因此页面自行设置的背景仍会生效。
+    ///
+    /// #### 参数
+    /// - `rgba`：背景色，格式为 `0xRRGGBBAA`。
+    fn set_background(&mut self, rgba: u32) {
+        if let Some(id) = self.background_stylesheet.take() {
+            self.user_content.remove_stylesheet(id);
+        }
+
+        let [r, g, b, a] = rgba.to_be_bytes();
+        let css = format!(
+            "html, body {{ background-color: rgba({r}, {g}, {b}, {:.4}); }}",
+            f32::from(a) / 255.0
+        );
+        self.background_stylesheet = Some(self.add_user_stylesheet(css));
+    }
+
+    /// ### English
+    /// Replaces the media feature override script for subsequent loads and updates the current
+    /// page.
+    ///
+    /// ### 中文
+    /// 为后续加载替换媒体特性覆盖脚本，并更新当前页面。
+    fn apply_media_features(&mut self) {
+        let installed = self.media_features_script.take();
+        if let Some(id) = installed {
+            self.user_content.remove_script(id);
+        }
+
+        let source = format!(
+            "({MEDIA_FEATURES_SCRIPT})({}, {});",
+            self.reduced_motion, self.forced_colors
+        );
+        if self.reduced_motion || self.forced_colors {
+            let script = Rc::new(servo::UserScript::new(source.clone(), None));
+            self.media_features_script = Some(script.id());
+            self.user_content.add_script(script);
+        } else if installed.is_none() {
+            return;
+        }
+        self.servo_webview.evaluate_javascript(source, |_| {});
+    }
+
+    /// ### English
+    /// Holds or releases the view's frame callbacks and freezes or resumes the page timeline,
+    /// installing or removing the script that keeps subsequent loads frozen.
+    ///
+    /// #### Parameters
+    /// - `paused`: Whether page time stands still.
+    ///
+    /// ### 中文
+    /// 扣住或放开该 view 的帧回调，冻结或恢复页面时间线，并安装或移除让后续加载保持冻结的脚本。
+    ///
+    /// #### 参数
+    /// - `paused`：页面时间是否停止。
+    fn set_time_paused(&mut self, paused: bool) {
+        let installed = self.time_pause_script.take();
+        if let Some(id) = installed {
+            self.user_content.remove_script(id);
+        }
+
+        let source = format!("({TIME_PAUSE_SCRIPT})({paused});");
+        if paused {
+            let script = Rc::new(servo::UserScript::new(source.clone(), None));
+            self.time_pause_script = Some(script.id());
+            self.user_content.add_script(script);
+        }
+        if paused || installed.is_some() {
+            self.servo_webview.evaluate_javascript(source, |_| {});
+        }
+        self.rendering_context
+            .refresh_driver()
+            .set_time_paused(paused);
+    }
+
+    /// ### English
+    /// Replaces the image size limit script for subsequent loads.
+    ///
+    /// #### Parameters
+    /// - `limits`: New engine image limits.
+    ///
+    /// ### 中文
+    /// 为后续加载替换图片尺寸限制脚本。
+    ///
+    /// #### 参数
+    /// - `limits`：新的引擎图片尺寸限制。
+    pub(super) fn set_image_limits(&mut self, limits: XianWebEngineImageLimits) {
+        if let Some(id) = self.image_limits_script.take() {
+            self.user_content.remove_script(id);
+        }
+        if let Some(source) = limits.user_script(&self.channel.sender()) {
+            let script = Rc::new(servo::UserScript::new(source, None));
+            self.image_limits_script = Some(script.id());
+            self.user_content.add_script(script);
+        }
+    }
+
+    /// ### English
+    /// Resolves the viewport rectangle of the first element matching `selector`, then reads back
+    /// just that region of the back slot through Servo's screenshot path (which waits for the
+    /// pending frame to be painted).
+    ///
+    /// #### Parameters
+    /// - `selector`: CSS selector of the element.
+    /// - `reply`: Completion receiving the pixels or an error.
+    ///
+    /// ### 中文
+    /// 解析首个匹配 `selector` 之元素的视口矩形，然后通过 Servo 的截图路径（会等待待绘制的帧绘制完成）只读回
+    /// back 槽位中的该区域。
+    ///
+    /// #### 参数
+    /// - `selector`：元素的 CSS 选择器。
+    /// - `reply`：接收像素或错误的完成处理。
+    fn capture_element(&self, selector: &str, reply: ElementCaptureReply) {
+        let webview = self.servo_webview.clone();
+        let script = format!("({ELEMENT_RECT_SCRIPT})({})", js_string_literal(selector));
+        self.servo_webview
+            .evaluate_javascript(script, move |result| {
+                let rect = match result {
+                    Ok(servo::JSValue::Array(values)) => match values.as_slice() {
+                        [
+                            servo::JSValue::Number(x),
+                            servo::JSValue::Number(y),
+                            servo::JSValue::Number(width),
+                            servo::JSValue::Number(height),
+                        ] => euclid::Rect::new(
+                            euclid::point2(*x as f32, *y as f32),
+                            euclid::size2(*width as f32, *height as f32),
+                        ),
+                        _ => {
+                            reply.send(Err("Unexpected element rectangle".to_string()));
+                            return;
+                        }
+                    },
+                    Ok(servo::JSValue::String(reason)) => {
+                        reply.send(Err(reason));
+                        return;
+                    }
+                    Ok(other) => {
+                        reply.send(Err(format!("Unexpected script result: {other:?}")));
+                        return;
+                    }
+                    Err(err) => {
+                        reply.send(Err(format!("Script evaluation failed: {err:?}")));
+                        return;
+                    }
+                };
+                webview.take_screenshot(Some(rect), move |result| {
+                    reply.send(result.map_err(|err| format!("Screenshot failed: {err:?}")));
+                });
+            });
+    }
+
+    /// ### English
+    /// Serializes history and the current scroll offset, reported as a `SESSION_SAVED` event.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID allocated by the view handle.
+    ///
+    /// ### 中文
+    /// 序列化历史与当前滚动偏移，并以 `SESSION_SAVED` 事件上报。
+    ///
+    /// #### 参数
+    /// - `request_id`：由 view 句柄分配的请求 ID。
+    fn save_session(&self, request_id: u64) {
+        let (mut entries, mut current) = self.session.history();
+        if entries.is_empty() {
+            entries.extend(self.servo_webview.url().map(|url| url.as_str().to_string()));
+            current = 0;
+        }
+
+        let events = self.events.clone();
+        self.servo_webview
+            .evaluate_javascript("[scrollX, scrollY]", move |result| {
+                let (scroll_x, scroll_y) = match result {
+                    Ok(servo::JSValue::Array(values)) => match values.as_slice() {
+                        [servo::JSValue::Number(x), servo::JSValue::Number(y)] => (*x, *y),
+                        _ => (0.0, 0.0),
+                    },
+                    _ => (0.0, 0.0),
+                };
+
+                let mut event = ViewEvent::new(
+                    XIAN_WEB_ENGINE_VIEW_EVENT_SESSION_SAVED,
+                    XIAN_WEB_ENGINE_STATUS_OK,
+                    request_id,
+                );
+                if entries.is_empty() {
+                    event.status = XIAN_WEB_ENGINE_STATUS_FAILED;
+                    event.text = Some("View has no document to save".to_string());
+                } else {
+                    let snapshot = SessionSnapshot {
+                        entries,
+                        current,
+                        scroll_x,
+                        scroll_y,
+                    };
+                    event.text = Some(snapshot.encode());
+                }
+                events.push(event);
+            });
+    }
+
+    /// ### English
+    /// Pauses every playing media element (or resumes the ones paused this way) and reports the
+    /// resulting counts as a `MEDIA_STATE` event.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID allocated by the view handle.
+    /// - `paused`: `true` to pause, `false` to resume.
+    ///
+    /// ### 中文
+    /// 暂停所有正在播放的媒体元素（或恢复以此方式暂停的元素），并以 `MEDIA_STATE` 事件上报结果计数。
+    ///
+    /// #### 参数
+    /// - `request_id`：由 view 句柄分配的请求 ID。
+    /// - `paused`：`true` 表示暂停，`false` 表示恢复。
+    fn set_media_paused(&self, request_id: u64, paused: bool) {
+        let events = self.events.clone();
+        let script = format!("({MEDIA_CONTROL_SCRIPT})({paused})");
+        self.servo_webview
+            .evaluate_javascript(script, move |result| {
+                let mut event = ViewEvent::new(
+                    XIAN_WEB_ENGINE_VIEW_EVENT_MEDIA_STATE,
+                    XIAN_WEB_ENGINE_STATUS_OK,
+                    request_id,
+                );
+                match result {
+                    Ok(servo::JSValue::Array(values)) => {
+                        if let [
+                            servo::JSValue::Number(total),
+                            servo::JSValue::Number(playing),
+                        ] = values.as_slice()
+                        {
+                            event.value0 = *total as i64;
+                            event.value1 = *playing as i64;
+                        }
+                    }
+                    Ok(other) => {
+                        event.status = XIAN_WEB_ENGINE_STATUS_FAILED;
+                        event.text = Some(format!("Unexpected script result: {other:?}"));
+                    }
+                    Err(err) => {
+                        event.status = XIAN_WEB_ENGINE_STATUS_FAILED;
+                        event.text = Some(format!("Script evaluation failed: {err:?}"));
+                    }
+                }
+                events.push(event);
+            });
+    }
+
+    /// ### English
+    /// Evaluates a script that returns a string and reports that string as the text of one event.
+    ///
+    /// Non-string results and evaluation errors are reported with `XIAN_WEB_ENGINE_STATUS_FAILED`.
+    ///
+    /// #### Parameters
+    /// - `kind`: Event kind to report.
+    /// - `request_id`: Request ID allocated by the view handle.
+    /// - `script`: Script whose completion value is a string.
+    ///
+    /// ### 中文
+    /// 执行一段返回字符串的脚本，并把该字符串作为一个事件的文本上报。
+    ///
+    /// 非字符串结果与执行错误以 `XIAN_WEB_ENGINE_STATUS_FAILED` 上报。
+    ///
+    /// #### 参数
+    /// - `kind`：要上报的事件类型。
+    /// - `request_id`：由 view 句柄分配的请求 ID。
+    /// - `script`：完成值为字符串的脚本。
+    fn evaluate_to_event(&self, kind: u32, request_id: u64, script: &str) {
+        let events = self.events.clone();
+        self.servo_webview
+            .evaluate_javascript(script, move |result| {
+                let mut event = ViewEvent::new(kind, XIAN_WEB_ENGINE_STATUS_OK, request_id);
+                match result {
+                    Ok(servo::JSValue::String(text)) => event.text = Some(text),
+                    Ok(other) => {
+                        event.status = XIAN_WEB_ENGINE_STATUS_FAILED;
+                        event.text = Some(format!("Unexpected script result: {other:?}"));
+                    }
+                    Err(err) => {
+                        event.status = XIAN_WEB_ENGINE_STATUS_FAILED;
+                        event.text = Some(format!("Script evaluation failed: {err:?}"));
+                    }
+                }
+                events.push(event);
+            });
+    }
+
+    /// ### English
+    /// Captures the whole scrollable document and writes it to `path` on a helper thread.
+    ///
+    /// Completion (success or failure) is reported as an `EXPORT_COMPLETE` event carrying
+    /// `request_id`.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID allocated by the view handle.
+    /// - `path`: Output file path.
+    /// - `format`: `XIAN_WEB_ENGINE_EXPORT_FORMAT_*`.
+    ///
+    /// ### 中文
+    /// 截取整个可滚动文档，并在辅助线程把结果写入 `path`。
+    ///
+    /// 完成（成功或失败）后会上报携带 `request_id` 的 `EXPORT_COMPLETE` 事件。
+    ///
+    /// #### 参数
+    /// - `request_id`：由 view 句柄分配的请求 ID。
+    /// - `path`：输出文件路径。
+    /// - `format`：`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`。
+    fn export(&self, request_id: u64, path: PathBuf, format: u32) {
+        let events = self.events.clone();
+        let strings = self.strings.clone();
+        capture_full_page(&self.servo_webview, move |result| {
+            let image = match result {
+                Ok(image) => image,
+                Err(err) => {
+                    let mut event = ViewEvent::new(
+                        XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE,
+                        XIAN_WEB_ENGINE_STATUS_FAILED,
+                        request_id,
+                    );
+                    event.text = Some(err);
+                    strings.set_last_error(event.text.as_deref().unwrap_or_default());
+                    events.push(event);
+                    return;
+                }
+            };
+
+            let writer_events = events.clone();
+            let writer_strings = strings.clone();
+            let spawned = thread::Builder::new()
+                .name("xian-export".to_string())
+                .spawn(move || {
+                    let (width, height) = (image.width, image.height);
+                    let mut event = ViewEvent::new(
+                        XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE,
+                        XIAN_WEB_ENGINE_STATUS_OK,
+                        request_id,
+                    );
+                    match write_export(&path, format, width, height, &image.rgba) {
+                        Ok(()) => {
+                            event.value0 = i64::from(width);
+                            event.value1 = i64::from(height);
+                            event.text = Some(path.to_string_lossy().into_owned());
+                        }
+                        Err(err) => {
+                            event.status = XIAN_WEB_ENGINE_STATUS_IO_ERROR;
+                            writer_strings.set_last_error(&format!("Export failed: {err}"));
+                            event.text = Some(err.to_string());
+                        }
+                    }
+                    writer_events.push(event);
+                });
+            if let Err(err) = spawned {
+                let mut event = ViewEvent::new(
+                    XIAN_WEB_ENGINE_VIEW_EVENT_EXPORT_COMPLETE,
+                    XIAN_WEB_ENGINE_STATUS_FAILED,
+                    request_id,
+                );
+                event.text = Some(format!("Failed to spawn export thread: {err}"));
+                strings.set_last_error(event.text.as_deref().unwrap_or_default());
+                events.push(event);
+            }
+        });
+    }
+
+    #[inline]
+    /// ### English
+    /// Processes all pending work bits for this view.
+    ///
+    /// ### 中文
+    /// 处理该 view 的所有待处理 work bit。
+    pub(super) fn process_pending(&mut self) {
+        if !self.pending.is_marked() {
+            return;
+        }
+
+        loop {
+            let bits = self.pending.take();
 
             if (bits & PENDING_LOAD_URL) != 0
                 && let Some(request) = self.load_url.take()
             {
-                if let Ok(url) = Url::parse(request.as_str()) {
-                    self.servo_webview.load(url);
-                }
+                self.navigate(request.as_str());
                 self.load_url.recycle(request);
             }
 
@@ -290,13 +2865,7 @@ impl ViewEntry {
                 let active = self.rendering_context.is_active();
                 if active != self.last_active {
                     self.last_active = active;
-                    if active {
-                        self.servo_webview.set_throttled(false);
-                        self.servo_webview.show();
-                    } else {
-                        self.servo_webview.set_throttled(true);
-                        self.servo_webview.hide();
-                    }
+                    self.apply_active(active);
                 }
             }
 
@@ -0,0 +1,143 @@
+//! ### English
+//! WebDriver endpoint for automated end-to-end tests (`webdriver` feature).
+//!
+//! The server is Servo's own WebDriver implementation: it listens on the requested port, talks the
+//! W3C WebDriver protocol to standard clients (Selenium, WebdriverIO, ...) and sends the resulting
+//! commands to this Servo thread, which forwards them to Servo once per loop turn. The views are
+//! the embedder's, so window management commands (new window, window rect) are not honored.
+//!
+//! ### 中文
+//! 用于自动化端到端测试的 WebDriver 端点（`webdriver` feature）。
+//!
+//! 服务器使用 Servo 自带的 WebDriver 实现：它在请求的端口上监听，与标准客户端（Selenium、WebdriverIO 等）
+//! 使用 W3C WebDriver 协议通信，并把得到的命令发送到该 Servo 线程；Servo 线程每轮循环将其转发给 Servo。
+//! view 归宿主所有，因此窗口管理命令（新建窗口、窗口矩形）不会生效。
+
+#[cfg(feature = "webdriver")]
+use std::net::{Ipv4Addr, TcpListener};
+
+/// ### English
+/// WebDriver server state of one Servo thread (Servo thread only).
+///
+/// ### 中文
+/// 单个 Servo 线程的 WebDriver 服务器状态（仅 Servo 线程使用）。
+pub(super) struct WebDriverEndpoint {
+    /// ### English
+    /// Waker handed to the server so incoming commands wake the Servo thread.
+    ///
+    /// ### 中文
+    /// 交给服务器的 waker，使到达的命令能唤醒 Servo 线程。
+    #[cfg_attr(not(feature = "webdriver"), allow(dead_code))]
+    waker: Box<dyn servo::EventLoopWaker>,
+    /// ### English
+    /// Listening port and the receiving end of the server's command channel, once started.
+    ///
+    /// ### 中文
+    /// 启动后的监听端口以及服务器命令通道的接收端。
+    #[cfg(feature = "webdriver")]
+    server: Option<(u16, crossbeam_channel::Receiver<servo::WebDriverCommandMsg>)>,
+}
+
+impl WebDriverEndpoint {
+    /// ### English
+    /// Creates a stopped endpoint.
+    ///
+    /// #### Parameters
+    /// - `waker`: Waker of the owning Servo thread.
+    ///
+    /// ### 中文
+    /// 创建一个未启动的端点。
+    ///
+    /// #### 参数
+    /// - `waker`：所属 Servo 线程的 waker。
+    pub(super) fn new(waker: Box<dyn servo::EventLoopWaker>) -> Self {
+        Self {
+            waker,
+            #[cfg(feature = "webdriver")]
+            server: None,
+        }
+    }
+
+    /// ### English
+    /// Starts the server on `port`; succeeds without doing anything if it already listens there.
+    ///
+    /// Servo's server binds the port on its own thread and cannot report a failure, so the port is
+    /// bound here first and an unavailable port is returned as an error.
+    ///
+    /// #### Parameters
+    /// - `port`: TCP port to listen on.
+    ///
+    /// ### 中文
+    /// 在 `port` 上启动服务器；若已在该端口监听，则直接成功。
+    ///
+    /// Servo 的服务器在自己的线程上绑定端口且无法报告失败，因此会先在此绑定该端口，端口不可用时返回错误。
+    ///
+    /// #### 参数
+    /// - `port`：要监听的 TCP 端口。
+    #[cfg(feature = "webdriver")]
+    pub(super) fn start(&mut self, port: u16) -> Result<(), String> {
+        match &self.server {
+            Some((current, _)) if *current == port => Ok(()),
+            Some((current, _)) => Err(format!("WebDriver is already listening on port {current}")),
+            None => {
+                TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))
+                    .map_err(|err| format!("WebDriver cannot listen on port {port}: {err}"))?;
+                let (sender, receiver) = crossbeam_channel::unbounded();
+                webdriver_server::start_server(port, sender, self.waker.clone_box());
+                self.server = Some((port, receiver));
+                Ok(())
+            }
+        }
+    }
+
+    /// ### English
+    /// Always fails: this build has no WebDriver support.
+    ///
+    /// #### Parameters
+    /// - `_port`: TCP port to listen on.
+    ///
+    /// ### 中文
+    /// 总是失败：该构建不支持 WebDriver。
+    ///
+    /// #### 参数
+    /// - `_port`：要监听的 TCP 端口。
+    #[cfg(not(feature = "webdriver"))]
+    pub(super) fn start(&mut self, _port: u16) -> Result<(), String> {
+        Err("WebDriver requires a build with the `webdriver` feature".to_string())
+    }
+
+    /// ### English
+    /// Forwards the commands received since the last call to Servo.
+    ///
+    /// #### Parameters
+    /// - `servo`: Servo instance owned by the Servo thread.
+    ///
+    /// ### 中文
+    /// 将自上次调用以来收到的命令转发给 Servo。
+    ///
+    /// #### 参数
+    /// - `servo`：Servo 线程持有的 Servo 实例。
+    #[cfg(feature = "webdriver")]
+    pub(super) fn forward(&self, servo: &servo::Servo) {
+        let Some((_, receiver)) = &self.server else {
+            return;
+        };
+        while let Ok(command) = receiver.try_recv() {
+            servo.execute_webdriver_command(command);
+        }
+    }
+
+    /// ### English
+    /// Does nothing: this build has no WebDriver support.
+    ///
+    /// #### Parameters
+    /// - `_servo`: Servo instance owned by the Servo thread.
+    ///
+    /// ### 中文
+    /// 不做任何事：该构建不支持 WebDriver。
+    ///
+    /// #### 参数
+    /// - `_servo`：Servo 线程持有的 Servo 实例。
+    #[cfg(not(feature = "webdriver"))]
+    pub(super) fn forward(&self, _servo: &servo::Servo) {}
+}
@@ -0,0 +1,130 @@
+//! ### English
+//! Serialized view session (history entries, current entry, scroll offset).
+//!
+//! The blob is versioned UTF-8 text so embedders can store it as a string; its layout is private.
+//!
+//! ### 中文
+//! 序列化的 view 会话（历史条目、当前条目、滚动偏移）。
+//!
+//! blob 为带版本号的 UTF-8 文本，宿主可以按字符串保存；其内部布局不对外承诺。
+
+use std::fmt::Write as _;
+
+use url::Url;
+
+/// ### English
+/// First line of every session blob (bumped when the layout changes).
+///
+/// ### 中文
+/// 每个会话 blob 的首行（布局变化时递增）。
+const SESSION_HEADER: &str = "xian-web-engine-session/1";
+
+/// ### English
+/// Decoded view session.
+///
+/// ### 中文
+/// 解码后的 view 会话。
+pub(super) struct SessionSnapshot {
+    /// ### English
+    /// History entry URLs, oldest first.
+    ///
+    /// ### 中文
+    /// 历史条目 URL（从旧到新）。
+    pub entries: Vec<String>,
+    /// ### English
+    /// Index of the current entry in `entries`.
+    ///
+    /// ### 中文
+    /// 当前条目在 `entries` 中的索引。
+    pub current: usize,
+    /// ### English
+    /// Horizontal scroll offset of the current entry (CSS px).
+    ///
+    /// ### 中文
+    /// 当前条目的水平滚动偏移（CSS px）。
+    pub scroll_x: f64,
+    /// ### English
+    /// Vertical scroll offset of the current entry (CSS px).
+    ///
+    /// ### 中文
+    /// 当前条目的垂直滚动偏移（CSS px）。
+    pub scroll_y: f64,
+}
+
+impl SessionSnapshot {
+    /// ### English
+    /// Returns the URL of the current entry.
+    ///
+    /// ### 中文
+    /// 返回当前条目的 URL。
+    pub(super) fn current_url(&self) -> Option<&str> {
+        self.entries.get(self.current).map(String::as_str)
+    }
+
+    /// ### English
+    /// Parses the history entry URLs; returns `None` if one of them is not a valid URL.
+    ///
+    /// ### 中文
+    /// 解析历史条目 URL；若其中任一不是合法 URL，则返回 `None`。
+    pub(super) fn urls(&self) -> Option<Vec<Url>> {
+        self.entries
+            .iter()
+            .map(|entry| Url::parse(entry).ok())
+            .collect()
+    }
+
+    /// ### English
+    /// Serializes the session into a blob.
+    ///
+    /// ### 中文
+    /// 将会话序列化为 blob。
+    pub(super) fn encode(&self) -> String {
+        let mut blob =
+            String::with_capacity(64 + self.entries.iter().map(|e| e.len() + 1).sum::<usize>());
+        let _ = writeln!(blob, "{SESSION_HEADER}");
+        let _ = writeln!(blob, "{}", self.current);
+        let _ = writeln!(blob, "{} {}", self.scroll_x, self.scroll_y);
+        for entry in &self.entries {
+            blob.push_str(entry);
+            blob.push('\n');
+        }
+        blob
+    }
+
+    /// ### English
+    /// Parses a blob produced by [`Self::encode`]; returns `None` if it is malformed or from an
+    /// unknown version.
+    ///
+    /// #### Parameters
+    /// - `blob`: Serialized session.
+    ///
+    /// ### 中文
+    /// 解析由 [`Self::encode`] 生成的 blob；若格式错误或版本未知则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `blob`：序列化的会话。
+    pub(super) fn decode(blob: &str) -> Option<Self> {
+        let mut lines = blob.lines();
+        if lines.next()? != SESSION_HEADER {
+            return None;
+        }
+        let current = lines.next()?.parse().ok()?;
+        let (scroll_x, scroll_y) = lines.next()?.split_once(' ')?;
+        let scroll_x: f64 = scroll_x.parse().ok()?;
+        let scroll_y: f64 = scroll_y.parse().ok()?;
+        let entries: Vec<String> = lines
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let snapshot = Self {
+            entries,
+            current,
+            scroll_x: if scroll_x.is_finite() { scroll_x } else { 0.0 },
+            scroll_y: if scroll_y.is_finite() { scroll_y } else { 0.0 },
+        };
+        snapshot.current_url()?;
+        snapshot.urls()?;
+        Some(snapshot)
+    }
+}
@@ -0,0 +1,249 @@
+//! ### English
+//! One Servo worker thread ("shard") and the queues the embedder uses to reach it.
+//!
+//! Views are sharded across shards so a heavy page only janks the views living on the same thread.
+//! Every shard runs its own Servo instance with its own shared offscreen GL context.
+//!
+//! ### 中文
+//! 单个 Servo 工作线程（“分片”）及宿主用于访问它的队列。
+//!
+//! view 会被分配到各分片，因此繁重的页面只会拖慢同一线程上的 view。
+//! 每个分片运行独立的 Servo 实例，并拥有独立的共享离屏 GL 上下文。
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+use crate::engine::event_types::XIAN_WEB_ENGINE_STATUS_TIMED_OUT;
+use crate::engine::glfw;
+use crate::engine::lockfree::OneShot;
+use crate::engine::thread_config::XianWebEngineThreadPoolConfig;
+use crate::engine::vsync::VsyncCallbackQueue;
+use crate::engine::watchdog::{GlErrorLog, Heartbeat};
+
+use super::command::Command;
+use super::image_loads::ImageLoadLog;
+use super::pending::PendingIdQueue;
+use super::queue::{self, CommandQueue};
+use super::servo_thread;
+
+/// ### English
+/// Handle to one running Servo thread.
+///
+/// ### 中文
+/// 单个运行中 Servo 线程的句柄。
+pub(super) struct ServoShard {
+    /// ### English
+    /// Command queue for control messages into this shard.
+    ///
+    /// ### 中文
+    /// 发送到该分片的控制命令队列。
+    pub(super) command_queue: Arc<CommandQueue>,
+    /// ### English
+    /// Pending view-id queue of this shard.
+    ///
+    /// ### 中文
+    /// 该分片的 pending view-id 队列。
+    pub(super) pending_queue: Arc<PendingIdQueue>,
+    /// ### English
+    /// Vsync callbacks produced by this shard (single producer per queue).
+    ///
+    /// ### 中文
+    /// 该分片产生的 vsync 回调（每个队列单生产者）。
+    pub(super) vsync_queue: Arc<VsyncCallbackQueue>,
+    /// ### English
+    /// Watchdog heartbeat of this shard.
+    ///
+    /// ### 中文
+    /// 该分片的看门狗心跳。
+    pub(super) heartbeat: Arc<Heartbeat>,
+    /// ### English
+    /// GL errors recorded by this shard's views in paranoid GL mode.
+    ///
+    /// ### 中文
+    /// paranoid GL 模式下该分片的 view 记录的 GL 错误。
+    pub(super) gl_errors: Arc<GlErrorLog>,
+    /// ### English
+    /// Image load counters of this shard.
+    ///
+    /// ### 中文
+    /// 该分片的图片加载计数器。
+    pub(super) image_loads: Arc<ImageLoadLog>,
+    /// ### English
+    /// Thread handle used to wake this shard.
+    ///
+    /// ### 中文
+    /// 用于唤醒该分片的线程句柄。
+    pub(super) thread_handle: thread::Thread,
+    /// ### English
+    /// Join handle of the shard thread.
+    ///
+    /// ### 中文
+    /// 分片线程的 join handle。
+    thread: thread::JoinHandle<()>,
+}
+
+impl ServoShard {
+    /// ### English
+    /// Spawns a shard thread and returns it together with its initialization one-shot.
+    ///
+    /// #### Parameters
+    /// - `glfw_shared_window_handle`: Embedder GLFW window handle whose context will be shared.
+    /// - `resources_dir`: Optional resource directory override.
+    /// - `config_dir`: Optional Servo config directory override.
+    /// - `thread_pool_cap`: Servo worker thread cap (`0` means no cap).
+    /// - `thread_pools`: Per-pool worker counts (`0` fields fall back to `thread_pool_cap`).
+    /// - `engine_flags`: Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 启动一个分片线程，并将其与初始化一次性通道一并返回。
+    ///
+    /// #### 参数
+    /// - `glfw_shared_window_handle`：宿主 GLFW window 的句柄；其上下文会被共享。
+    /// - `resources_dir`：可选的资源目录覆盖。
+    /// - `config_dir`：可选的 Servo 配置目录覆盖。
+    /// - `thread_pool_cap`：Servo 工作线程上限（`0` 表示不封顶）。
+    /// - `thread_pools`：各线程池的工作线程数（为 `0` 的字段回退到 `thread_pool_cap`）。
+    /// - `engine_flags`：引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+    pub(super) fn spawn(
+        glfw_shared_window_handle: usize,
+        resources_dir: Option<PathBuf>,
+        config_dir: Option<PathBuf>,
+        thread_pool_cap: u32,
+        thread_pools: XianWebEngineThreadPoolConfig,
+        engine_flags: u32,
+    ) -> (Self, Arc<OneShot<Result<(), String>>>) {
+        let vsync_queue = Arc::new(VsyncCallbackQueue::with_capacity(4096));
+        let vsync_queue_for_thread = vsync_queue.clone();
+
+        let pending_queue = Arc::new(PendingIdQueue::with_capacity(64 * 1024));
+        let pending_queue_for_thread = pending_queue.clone();
+
+        let command_queue = Arc::new(CommandQueue::new());
+        let command_queue_for_thread = command_queue.clone();
+
+        let heartbeat = Arc::new(Heartbeat::new());
+        let heartbeat_for_thread = heartbeat.clone();
+
+        let gl_errors = Arc::new(GlErrorLog::new());
+        let gl_errors_for_thread = gl_errors.clone();
+
+        let image_loads = Arc::new(ImageLoadLog::new());
+        let image_loads_for_thread = image_loads.clone();
+
+        let init = Arc::new(OneShot::new(thread::current()));
+        let init_for_thread = init.clone();
+
+        let thread = thread::spawn(move || {
+            servo_thread::run_servo_thread(
+                glfw_shared_window_handle,
+                resources_dir,
+                config_dir,
+                vsync_queue_for_thread,
+                pending_queue_for_thread,
+                command_queue_for_thread,
+                thread_pool_cap,
+                thread_pools,
+                engine_flags,
+                heartbeat_for_thread,
+                gl_errors_for_thread,
+                image_loads_for_thread,
+                init_for_thread,
+            );
+        });
+
+        let shard = Self {
+            command_queue,
+            pending_queue,
+            vsync_queue,
+            heartbeat,
+            gl_errors,
+            image_loads,
+            thread_handle: thread.thread().clone(),
+            thread,
+        };
+        (shard, init)
+    }
+
+    /// ### English
+    /// Waits for the shard to finish initialization.
+    ///
+    /// With a main-thread trampoline installed, the caller runs queued main-thread calls while it
+    /// waits (the shard creates its GLFW window through them).
+    ///
+    /// #### Parameters
+    /// - `init`: One-shot returned by `spawn`.
+    /// - `deadline`: Give-up time (`None` waits indefinitely).
+    ///
+    /// ### 中文
+    /// 等待分片完成初始化。
+    ///
+    /// 若已安装主线程 trampoline，调用方在等待期间会执行排队的主线程调用（分片通过它们创建其 GLFW
+    /// window）。
+    ///
+    /// #### 参数
+    /// - `init`：`spawn` 返回的一次性通道。
+    /// - `deadline`：放弃等待的时间点（`None` 表示无限等待）。
+    pub(super) fn wait_init(
+        init: &OneShot<Result<(), String>>,
+        deadline: Option<Instant>,
+    ) -> Result<(), String> {
+        let result = if glfw::trampoline_installed() {
+            glfw::pump_until(deadline, || init.try_recv())
+        } else {
+            init.recv_until(deadline)
+        };
+        match result {
+            Some(result) => result,
+            None => {
+                queue::set_last_status(XIAN_WEB_ENGINE_STATUS_TIMED_OUT);
+                Err("Timed out initializing Servo thread".to_string())
+            }
+        }
+    }
+
+    /// ### English
+    /// Pushes a command and wakes the shard.
+    ///
+    /// Returns `false` if the shard is shutting down.
+    ///
+    /// #### Parameters
+    /// - `command`: Command to send.
+    ///
+    /// ### 中文
+    /// push 一条命令并唤醒该分片。
+    ///
+    /// 若分片正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `command`：要发送的命令。
+    pub(super) fn send(&self, command: Command) -> bool {
+        if !self.command_queue.try_push(command) {
+            return false;
+        }
+        self.thread_handle.unpark();
+        true
+    }
+
+    /// ### English
+    /// Requests shard shutdown and joins its thread.
+    ///
+    /// With a main-thread trampoline installed, the caller keeps running queued main-thread calls
+    /// (the shard destroys its GLFW window through them) until the thread has finished.
+    ///
+    /// ### 中文
+    /// 请求分片退出并 join 其线程。
+    ///
+    /// 若已安装主线程 trampoline，调用方会持续执行排队的主线程调用（分片通过它们销毁其 GLFW
+    /// window），直到线程结束。
+    pub(super) fn shutdown(self) {
+        self.command_queue.push(Command::Shutdown);
+        self.thread_handle.unpark();
+        if glfw::trampoline_installed() {
+            glfw::pump_until(None, || self.thread.is_finished().then_some(()));
+        }
+        let _ = self.thread.join();
+        self.command_queue.close();
+    }
+}
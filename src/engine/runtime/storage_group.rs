@@ -0,0 +1,274 @@
+//! ### English
+//! Storage isolation groups ("browsing context groups" for storage).
+//!
+//! Servo keeps cookies, web storage, IndexedDB and its HTTP cache per Servo instance, persisted
+//! under the instance's config directory. Every storage group therefore runs on a shard of its
+//! own, started when the group's first view is created: named groups use
+//! `<config_dir>/storage-groups/<group>` (kept in memory when the engine has no config directory),
+//! and the ephemeral group's shard never gets a config directory, so its storage is never written
+//! to disk and is discarded when the engine shuts down. Engine-wide settings sent to the shards
+//! so far are replayed on a group shard before its first view is created.
+//!
+//! ### 中文
+//! 存储隔离组（面向存储的“浏览上下文组”）。
+//!
+//! Servo 按 Servo 实例保存 cookie、Web Storage、IndexedDB 与 HTTP 缓存，并持久化到该实例的配置目录下。
+//! 因此每个存储组都运行在自己的分片上，在该组创建第一个 view 时启动：具名组使用
+//! `<config_dir>/storage-groups/<group>`（引擎没有配置目录时仅保存在内存中）；临时组的分片永远没有配置目录，
+//! 因此其存储不会写入磁盘，并在引擎关闭时丢弃。此前发送给各分片的引擎级设置会在组分片创建第一个 view
+//! 之前重放到该分片上。
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::path::PathBuf;
+use std::sync::{PoisonError, RwLock};
+use std::time::Instant;
+
+use crate::engine::flags::XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL;
+use crate::engine::thread_config::XianWebEngineThreadPoolConfig;
+
+use super::shard::ServoShard;
+
+/// ### English
+/// Engine-wide setting applied to a shard; returns `false` if the shard rejected it.
+///
+/// ### 中文
+/// 应用到某个分片的引擎级设置；分片拒绝时返回 `false`。
+pub(super) type Setting = Box<dyn Fn(&ServoShard) -> bool + Send + Sync>;
+
+/// ### English
+/// Parameters the engine was created with, reused to spawn group shards.
+///
+/// ### 中文
+/// 引擎创建时的参数，用于启动组分片。
+pub(super) struct ShardParams {
+    /// ### English
+    /// Embedder GLFW window handle (or host `EGLContext`) whose context is shared.
+    ///
+    /// ### 中文
+    /// 其上下文会被共享的宿主 GLFW window 句柄（或宿主 `EGLContext`）。
+    pub(super) glfw_shared_window_handle: usize,
+    /// ### English
+    /// Optional resource directory override.
+    ///
+    /// ### 中文
+    /// 可选的资源目录覆盖。
+    pub(super) resources_dir: Option<PathBuf>,
+    /// ### English
+    /// Engine config directory (group directories are created below it).
+    ///
+    /// ### 中文
+    /// 引擎配置目录（组目录创建在其下）。
+    pub(super) config_dir: Option<PathBuf>,
+    /// ### English
+    /// Servo worker thread cap (`0` means no cap).
+    ///
+    /// ### 中文
+    /// Servo 工作线程上限（`0` 表示不封顶）。
+    pub(super) thread_pool_cap: u32,
+    /// ### English
+    /// Per-pool worker counts.
+    ///
+    /// ### 中文
+    /// 各线程池的工作线程数。
+    pub(super) thread_pools: XianWebEngineThreadPoolConfig,
+    /// ### English
+    /// Engine creation flags (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 引擎创建标志（`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+    pub(super) engine_flags: u32,
+}
+
+/// ### English
+/// Running group shards and the settings replayed on new ones.
+///
+/// ### 中文
+/// 运行中的组分片，以及需要重放到新分片上的设置。
+#[derive(Default)]
+struct GroupShards {
+    /// ### English
+    /// Shard of each storage group started so far.
+    ///
+    /// ### 中文
+    /// 已启动的各存储组的分片。
+    shards: HashMap<u32, ServoShard>,
+    /// ### English
+    /// Latest engine-wide settings by key, in the order they were first set.
+    ///
+    /// ### 中文
+    /// 按键保存的最新引擎级设置，按首次设置的顺序排列。
+    settings: Vec<(String, Setting)>,
+}
+
+/// ### English
+/// Storage groups of one engine.
+///
+/// ### 中文
+/// 一个引擎的存储组。
+pub(super) struct StorageGroups {
+    /// ### English
+    /// Parameters used to spawn group shards.
+    ///
+    /// ### 中文
+    /// 启动组分片所用的参数。
+    params: ShardParams,
+    /// ### English
+    /// Group shards and replayed settings.
+    ///
+    /// ### 中文
+    /// 组分片与需要重放的设置。
+    state: RwLock<GroupShards>,
+}
+
+impl StorageGroups {
+    /// ### English
+    /// Creates the (empty) storage groups of an engine.
+    ///
+    /// #### Parameters
+    /// - `params`: Parameters the engine was created with.
+    ///
+    /// ### 中文
+    /// 创建引擎的（空）存储组集合。
+    ///
+    /// #### 参数
+    /// - `params`：引擎创建时的参数。
+    pub(super) fn new(params: ShardParams) -> Self {
+        Self {
+            params,
+            state: RwLock::new(GroupShards::default()),
+        }
+    }
+
+    /// ### English
+    /// Runs `f` on the shard of `group`, starting the shard first if the group has none yet.
+    ///
+    /// #### Parameters
+    /// - `group`: Storage group (not `XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT`).
+    /// - `deadline`: Give-up time for starting the shard (`None` waits indefinitely).
+    /// - `f`: Called with the group's shard.
+    ///
+    /// ### 中文
+    /// 在 `group` 的分片上运行 `f`；若该组尚无分片则先启动它。
+    ///
+    /// #### 参数
+    /// - `group`：存储组（不能是 `XIAN_WEB_ENGINE_STORAGE_GROUP_DEFAULT`）。
+    /// - `deadline`：启动分片的放弃时间点（`None` 表示无限等待）。
+    /// - `f`：以该组的分片调用。
+    pub(super) fn with_shard<R>(
+        &self,
+        group: u32,
+        deadline: Option<Instant>,
+        f: impl FnOnce(&ServoShard) -> R,
+    ) -> Result<R, String> {
+        {
+            let state = self.state.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(shard) = state.shards.get(&group) {
+                return Ok(f(shard));
+            }
+        }
+
+        let config_dir = if group == XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL {
+            None
+        } else {
+            self.params
+                .config_dir
+                .as_ref()
+                .map(|dir| dir.join("storage-groups").join(group.to_string()))
+        };
+        let (shard, init) = ServoShard::spawn(
+            self.params.glfw_shared_window_handle,
+            self.params.resources_dir.clone(),
+            config_dir,
+            self.params.thread_pool_cap,
+            self.params.thread_pools,
+            self.params.engine_flags,
+        );
+        if let Err(err) = ServoShard::wait_init(&init, deadline) {
+            shard.shutdown();
+            return Err(err);
+        }
+
+        let mut guard = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        let state = &mut *guard;
+        let (shard, duplicate) = match state.shards.entry(group) {
+            Entry::Occupied(entry) => (entry.into_mut(), Some(shard)),
+            Entry::Vacant(entry) => {
+                for (_, setting) in &state.settings {
+                    setting(&shard);
+                }
+                (entry.insert(shard), None)
+            }
+        };
+        let result = f(shard);
+        drop(guard);
+        if let Some(duplicate) = duplicate {
+            duplicate.shutdown();
+        }
+        Ok(result)
+    }
+
+    /// ### English
+    /// Applies an engine-wide setting to every group shard and keeps it for shards started later,
+    /// replacing the previous setting with the same key.
+    ///
+    /// Returns `false` if any group shard rejected it.
+    ///
+    /// #### Parameters
+    /// - `key`: Setting identity (a later setting with the same key replaces it).
+    /// - `setting`: Applies the setting to one shard.
+    ///
+    /// ### 中文
+    /// 将引擎级设置应用到每个组分片，并为之后启动的分片保留它（替换相同键的旧设置）。
+    ///
+    /// 若任一组分片拒绝该设置，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `key`：设置的标识（之后相同键的设置会替换它）。
+    /// - `setting`：将设置应用到一个分片。
+    pub(super) fn set(&self, key: String, setting: Setting) -> bool {
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        let mut applied = true;
+        for shard in state.shards.values() {
+            applied &= setting(shard);
+        }
+        match state
+            .settings
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            Some((_, existing)) => *existing = setting,
+            None => state.settings.push((key, setting)),
+        }
+        applied
+    }
+
+    /// ### English
+    /// Calls `f` for every running group shard.
+    ///
+    /// #### Parameters
+    /// - `f`: Called with each shard.
+    ///
+    /// ### 中文
+    /// 对每个运行中的组分片调用 `f`。
+    ///
+    /// #### 参数
+    /// - `f`：以每个分片调用。
+    pub(super) fn for_each(&self, f: impl FnMut(&ServoShard)) {
+        let state = self.state.read().unwrap_or_else(PoisonError::into_inner);
+        state.shards.values().for_each(f);
+    }
+
+    /// ### English
+    /// Shuts every group shard down (discarding the ephemeral group's storage).
+    ///
+    /// ### 中文
+    /// 关闭每个组分片（丢弃临时组的存储）。
+    pub(super) fn shutdown(&mut self) {
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        state.settings.clear();
+        for (_, shard) in state.shards.drain() {
+            shard.shutdown();
+        }
+    }
+}
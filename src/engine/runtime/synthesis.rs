@@ -0,0 +1,156 @@
+//! ### English
+//! Synthesized input sequences for automated UI testing and macros.
+//!
+//! A click or typed text is expanded into the same move/down/up events a user would produce, spaced
+//! by human-like delays, and fed into the view on the Servo thread like a replay.
+//!
+//! ### 中文
+//! 用于 UI 自动化测试与宏的合成输入序列。
+//!
+//! 一次点击或一段输入文本会被展开为与用户操作相同的 move/down/up 事件，以接近真人的间隔排列，并像回放一样在
+//! Servo 线程注入 view。
+
+use std::time::Duration;
+
+use crate::engine::input_types::{
+    XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
+    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XianWebEngineInputEvent,
+};
+
+use super::recording::{RecordedAction, RecordedEntry};
+
+/// ### English
+/// Delay between moving onto the target and pressing the button.
+///
+/// ### 中文
+/// 移动到目标与按下按键之间的延迟。
+const CLICK_MOVE_DELAY: Duration = Duration::from_millis(30);
+
+/// ### English
+/// How long a synthesized mouse button is held.
+///
+/// ### 中文
+/// 合成的鼠标按键保持按下的时长。
+const CLICK_HOLD: Duration = Duration::from_millis(80);
+
+/// ### English
+/// How long a synthesized key is held.
+///
+/// ### 中文
+/// 合成的按键保持按下的时长。
+const KEY_HOLD: Duration = Duration::from_millis(30);
+
+/// ### English
+/// Delay between two synthesized key presses.
+///
+/// ### 中文
+/// 两次合成按键之间的间隔。
+const KEY_INTERVAL: Duration = Duration::from_millis(60);
+
+/// ### English
+/// GLFW key code of Enter (typed for `'\n'`).
+///
+/// ### 中文
+/// Enter 的 GLFW key code（用于输入 `'\n'`）。
+const GLFW_KEY_ENTER: u32 = 257;
+
+/// ### English
+/// GLFW key code of Tab (typed for `'\t'`).
+///
+/// ### 中文
+/// Tab 的 GLFW key code（用于输入 `'\t'`）。
+const GLFW_KEY_TAB: u32 = 258;
+
+/// ### English
+/// Wraps an input event into an entry due at `offset`.
+///
+/// #### Parameters
+/// - `offset`: Time since the sequence starts.
+/// - `event`: Input event.
+///
+/// ### 中文
+/// 将输入事件包装为在 `offset` 到期的条目。
+///
+/// #### 参数
+/// - `offset`：相对序列开始的时间。
+/// - `event`：输入事件。
+fn entry(offset: Duration, event: XianWebEngineInputEvent) -> RecordedEntry {
+    RecordedEntry {
+        offset,
+        action: RecordedAction::Input(event),
+    }
+}
+
+/// ### English
+/// Builds a left click at `(x, y)`: a move onto the point, then a button press and release.
+///
+/// #### Parameters
+/// - `x`: X coordinate in logical pixels.
+/// - `y`: Y coordinate in logical pixels.
+///
+/// ### 中文
+/// 构建在 `(x, y)` 的一次左键点击：先移动到该点，再按下并释放按键。
+///
+/// #### 参数
+/// - `x`：X 坐标（逻辑像素）。
+/// - `y`：Y 坐标（逻辑像素）。
+pub(super) fn click(x: f32, y: f32) -> Vec<RecordedEntry> {
+    let at = XianWebEngineInputEvent {
+        x,
+        y,
+        ..Default::default()
+    };
+    let button = |mouse_action| XianWebEngineInputEvent {
+        kind: XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
+        mouse_action,
+        ..at
+    };
+    vec![
+        entry(
+            Duration::ZERO,
+            XianWebEngineInputEvent {
+                kind: XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE,
+                ..at
+            },
+        ),
+        entry(CLICK_MOVE_DELAY, button(0)),
+        entry(CLICK_MOVE_DELAY + CLICK_HOLD, button(1)),
+    ]
+}
+
+/// ### English
+/// Builds a key press and release for every character of `text`; `'\n'` and `'\t'` press Enter and
+/// Tab, other control characters are skipped.
+///
+/// #### Parameters
+/// - `text`: Text to type.
+///
+/// ### 中文
+/// 为 `text` 的每个字符构建一次按下与释放；`'\n'` 与 `'\t'` 分别按下 Enter 与 Tab，其它控制字符会被跳过。
+///
+/// #### 参数
+/// - `text`：要输入的文本。
+pub(super) fn text(text: &str) -> Vec<RecordedEntry> {
+    let keys = text.chars().filter_map(|ch| match ch {
+        '\n' => Some((0, GLFW_KEY_ENTER)),
+        '\t' => Some((0, GLFW_KEY_TAB)),
+        ch if ch.is_control() => None,
+        ch => Some((u32::from(ch), 0)),
+    });
+
+    let mut entries = Vec::new();
+    let mut offset = Duration::ZERO;
+    for (key_codepoint, glfw_key) in keys {
+        let key = |key_state| XianWebEngineInputEvent {
+            kind: XIAN_WEB_ENGINE_INPUT_KIND_KEY,
+            key_state,
+            key_codepoint,
+            glfw_key,
+            ..Default::default()
+        };
+        entries.push(entry(offset, key(0)));
+        entries.push(entry(offset + KEY_HOLD, key(1)));
+        offset += KEY_INTERVAL;
+    }
+    entries
+}
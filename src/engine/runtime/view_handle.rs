@@ -4,22 +4,109 @@
 //! ### 中文
 //! 宿主用于与 Servo 线程交互的线程安全 view 句柄。
 
-use std::sync::Arc;
+use std::ffi::c_void;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration;
 
 use dpi::PhysicalSize;
+use url::Url;
 
-use crate::engine::frame::{AcquiredFrame, SharedFrameState, TRIPLE_BUFFER_COUNT};
-use crate::engine::input::{CoalescedMouseMove, CoalescedResize, InputEventQueue};
-use crate::engine::input_types::XianWebEngineInputEvent;
+use crate::engine::event_types::{XianWebEngineViewEvent, XianWebEngineViewQueueStats};
+use crate::engine::flags::{
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL, XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND,
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ, XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F,
+    XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8, XIAN_WEB_ENGINE_COLOR_SCHEME_DARK,
+    XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT, XIAN_WEB_ENGINE_COLORSPACE_AUTO,
+    XIAN_WEB_ENGINE_COLORSPACE_LINEAR, XIAN_WEB_ENGINE_COLORSPACE_SRGB,
+    XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF, XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG,
+    XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY,
+    XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP, XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END,
+    XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START, XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN,
+    XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK, XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT,
+    XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER, XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS,
+    XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE, XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X,
+    XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE,
+    XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE, XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT,
+};
+use crate::engine::frame::{
+    AcquiredFrame, SharedFrameState, TRIPLE_BUFFER_COUNT, XianWebEngineFrameFn,
+};
+use crate::engine::input::{
+    CoalescedMouseMove, CoalescedResize, Homography, InputEventQueue, InputTransform,
+};
+use crate::engine::input_types::{XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XianWebEngineInputEvent};
+use crate::engine::rendering::{VulkanFrameExports, XianWebEngineVulkanSlot};
 
+use super::binary_channel::{BinaryChannel, MAX_BINARY_MESSAGE_BYTES};
+use super::binding::ViewBinding;
 use super::coalesced::{
     CoalescedLoadUrl, PENDING_ACTIVE, PENDING_INPUT, PENDING_LOAD_URL, PENDING_MOUSE_MOVE,
     PENDING_RESIZE, PendingWork,
 };
-use super::command::Command;
+use super::command::{Command, ElementCaptureReply, ElementCaptureResult, ViewCommand};
+use super::events::ViewEventQueue;
+use super::host_function::{HostFunction, XianWebEngineHostFunctionFn};
 use super::pending::PendingIdQueue;
 use super::queue::CommandQueue;
+use super::recording::{ViewRecorder, read_recording};
+#[cfg(feature = "screencast")]
+use super::screencast::ScreencastServer;
+use super::session::SessionSnapshot;
+use super::synthesis;
+use super::view_strings::ViewStrings;
+
+/// ### English
+/// Subdirectory of the engine cache directory holding staged file uploads.
+///
+/// ### 中文
+/// 引擎缓存目录下存放暂存上传文件的子目录。
+pub(super) const UPLOADS_DIR: &str = "uploads";
+
+/// ### English
+/// View flags fixed for a view's lifetime (`move_view` rejects changes to them).
+///
+/// ### 中文
+/// 在 view 生命周期内固定的 view 标志（`move_view` 拒绝修改它们）。
+const FIXED_VIEW_FLAGS: u32 = XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_CONSUMER_FENCE
+    | XIAN_WEB_ENGINE_VIEW_FLAG_INPUT_SINGLE_PRODUCER
+    | XIAN_WEB_ENGINE_VIEW_FLAG_UNSAFE_NO_PRODUCER_FENCE
+    | XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT
+    | XIAN_WEB_ENGINE_VIEW_FLAG_ORPHANING_RESIZE;
+
+/// ### English
+/// Smallest accepted render scale.
+///
+/// ### 中文
+/// 可接受的最小渲染缩放。
+const MIN_RENDER_SCALE: f32 = 0.25;
+
+/// ### English
+/// Largest accepted render scale (2x supersampling).
+///
+/// ### 中文
+/// 可接受的最大渲染缩放（2 倍超采样）。
+const MAX_RENDER_SCALE: f32 = 2.0;
+
+/// ### English
+/// Converts a logical view size into the internal render size for `scale` (at least 1x1).
+///
+/// #### Parameters
+/// - `size`: Logical size in pixels.
+/// - `scale`: Internal pixels per logical pixel.
+///
+/// ### 中文
+/// 按 `scale` 将 view 的逻辑尺寸换算为内部渲染尺寸（至少 1x1）。
+///
+/// #### 参数
+/// - `size`：逻辑尺寸（像素）。
+/// - `scale`：每逻辑像素对应的内部像素数。
+pub(super) fn render_size(size: PhysicalSize<u32>, scale: f32) -> PhysicalSize<u32> {
+    let scale = |value: u32| ((value as f32 * scale).round() as u32).max(1);
+    PhysicalSize::new(scale(size.width), scale(size.height))
+}
 
 /// ### English
 /// Internal initializer for `WebEngineViewHandle` (constructed by `EngineRuntime`).
@@ -28,17 +115,11 @@ use super::queue::CommandQueue;
 /// `WebEngineViewHandle` 的内部初始化参数（由 `EngineRuntime` 构造）。
 pub(super) struct WebEngineViewHandleInit {
     /// ### English
-    /// View ID allocated on the Servo thread.
-    ///
-    /// ### 中文
-    /// 在 Servo 线程分配的 view ID。
-    pub id: u32,
-    /// ### English
-    /// Monotonic token paired with `id` to detect stale destroy commands.
+    /// Binding to the view ID and token allocated on the Servo thread.
     ///
     /// ### 中文
-    /// 与 `id` 配对的单调递增 token，用于识别陈旧的销毁命令。
-    pub token: u64,
+    /// 与 Servo 线程分配的 view ID 及 token 的绑定。
+    pub binding: Arc<ViewBinding>,
     /// ### English
     /// Shared triple-buffer frame state for this view.
     ///
@@ -76,6 +157,24 @@ pub(super) struct WebEngineViewHandleInit {
     /// 每 view 的 pending-work 位图。
     pub pending: Arc<PendingWork>,
     /// ### English
+    /// Per-view event queue (Servo thread -> embedder).
+    ///
+    /// ### 中文
+    /// 每 view 的事件队列（Servo 线程 -> 宿主）。
+    pub events: Arc<ViewEventQueue>,
+    /// ### English
+    /// Per-view string snapshots (URL, title, last error).
+    ///
+    /// ### 中文
+    /// 每 view 的字符串快照（URL、标题、最近错误）。
+    pub strings: Arc<ViewStrings>,
+    /// ### English
+    /// Per-view binary channel (buffer pool and messages received from the page).
+    ///
+    /// ### 中文
+    /// 每 view 的二进制通道（缓冲池与从页面收到的消息）。
+    pub binary: Arc<BinaryChannel>,
+    /// ### English
     /// Global pending view-id queue shared with the Servo thread.
     ///
     /// ### 中文
@@ -94,11 +193,29 @@ pub(super) struct WebEngineViewHandleInit {
     /// Servo 线程句柄（用于 `unpark` 唤醒）。
     pub thread_handle: thread::Thread,
     /// ### English
+    /// Engine cache directory (shared with `EngineRuntime`).
+    ///
+    /// ### 中文
+    /// 引擎缓存目录（与 `EngineRuntime` 共享）。
+    pub cache_dir: Arc<Mutex<PathBuf>>,
+    /// ### English
     /// Whether the view runs without recording consumer fences (unsafe, for advanced embedders).
     ///
     /// ### 中文
     /// 是否不记录 consumer fence（不安全，仅供高级宿主使用）。
     pub unsafe_no_consumer_fence: bool,
+    /// ### English
+    /// Vulkan export table when the view uses the Vulkan backend.
+    ///
+    /// ### 中文
+    /// view 使用 Vulkan 后端时的 Vulkan 导出表。
+    pub vulkan_exports: Option<Arc<VulkanFrameExports>>,
+    /// ### English
+    /// Creation flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
+    ///
+    /// ### 中文
+    /// 创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    pub view_flags: u32,
 }
 
 /// ### English
@@ -108,17 +225,12 @@ pub(super) struct WebEngineViewHandleInit {
 /// 单个 view 的不透明句柄（可在宿主线程安全调用）。
 pub struct WebEngineViewHandle {
     /// ### English
-    /// View ID allocated on the Servo thread.
-    ///
-    /// ### 中文
-    /// 在 Servo 线程分配的 view ID。
-    id: u32,
-    /// ### English
-    /// Monotonic token paired with `id` to detect stale destroy commands.
+    /// View ID allocated on the Servo thread, paired with a monotonic token to detect stale
+    /// commands (bound later for views created asynchronously).
     ///
     /// ### 中文
-    /// 与 `id` 配对的单调递增 token，用于识别陈旧的销毁命令。
-    token: u64,
+    /// 在 Servo 线程分配的 view ID，与用于识别陈旧命令的单调递增 token 配对（异步创建的 view 会稍后绑定）。
+    binding: Arc<ViewBinding>,
     /// ### English
     /// Shared triple-buffer frame state for this view.
     ///
@@ -156,6 +268,30 @@ pub struct WebEngineViewHandle {
     /// 每 view 的 pending-work 位图。
     pending: Arc<PendingWork>,
     /// ### English
+    /// Per-view event queue (Servo thread -> embedder).
+    ///
+    /// ### 中文
+    /// 每 view 的事件队列（Servo 线程 -> 宿主）。
+    events: Arc<ViewEventQueue>,
+    /// ### English
+    /// Per-view string snapshots (URL, title, last error).
+    ///
+    /// ### 中文
+    /// 每 view 的字符串快照（URL、标题、最近错误）。
+    strings: Arc<ViewStrings>,
+    /// ### English
+    /// Per-view binary channel (buffer pool and messages received from the page).
+    ///
+    /// ### 中文
+    /// 每 view 的二进制通道（缓冲池与从页面收到的消息）。
+    binary: Arc<BinaryChannel>,
+    /// ### English
+    /// Next request ID handed out for asynchronous requests (starts at 1; `0` means "failed").
+    ///
+    /// ### 中文
+    /// 下一个分配给异步请求的请求 ID（从 1 开始；`0` 表示“失败”）。
+    next_request_id: AtomicU64,
+    /// ### English
     /// Global pending view-id queue shared with the Servo thread.
     ///
     /// ### 中文
@@ -174,11 +310,61 @@ pub struct WebEngineViewHandle {
     /// Servo 线程句柄（用于 `unpark` 唤醒）。
     thread_handle: thread::Thread,
     /// ### English
+    /// Engine cache directory (shared with `EngineRuntime`).
+    ///
+    /// ### 中文
+    /// 引擎缓存目录（与 `EngineRuntime` 共享）。
+    cache_dir: Arc<Mutex<PathBuf>>,
+    /// ### English
     /// Whether the view runs without recording consumer fences (unsafe, for advanced embedders).
     ///
     /// ### 中文
     /// 是否不记录 consumer fence（不安全，仅供高级宿主使用）。
     unsafe_no_consumer_fence: bool,
+    /// ### English
+    /// Vulkan export table when the view uses the Vulkan backend.
+    ///
+    /// ### 中文
+    /// view 使用 Vulkan 后端时的 Vulkan 导出表。
+    vulkan_exports: Option<Arc<VulkanFrameExports>>,
+    /// ### English
+    /// Creation flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`); `move_view` checks the lifetime-fixed bits
+    /// against them.
+    ///
+    /// ### 中文
+    /// 创建标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）；`move_view` 会以此校验生命周期内固定的标志位。
+    view_flags: u32,
+    /// ### English
+    /// Recorder of the input, resizes and navigations delivered through this handle.
+    ///
+    /// ### 中文
+    /// 记录经由该句柄投递的输入、resize 与导航的录制器。
+    recorder: ViewRecorder,
+    /// ### English
+    /// Input events submitted to this view since creation (accepted or not).
+    ///
+    /// ### 中文
+    /// 自创建以来提交给该 view 的输入事件数（无论是否被接收）。
+    input_generation: AtomicU64,
+    /// ### English
+    /// Submitted input events rejected because the input queue was full.
+    ///
+    /// ### 中文
+    /// 因输入队列已满而被拒绝的已提交输入事件数。
+    input_dropped: AtomicU64,
+    /// ### English
+    /// Homography applied to pointer positions submitted through the FFI input path.
+    ///
+    /// ### 中文
+    /// 应用于经由 FFI 输入路径提交的指针位置的单应矩阵。
+    input_transform: InputTransform,
+    /// ### English
+    /// Set once the view was closed (explicitly or by dropping the handle); the Servo-side view is
+    /// gone and commands carrying this handle's token are ignored.
+    ///
+    /// ### 中文
+    /// view 被关闭（显式关闭或 drop 句柄）后置位；Servo 侧的 view 已不存在，携带该句柄 token 的命令会被忽略。
+    closed: AtomicBool,
 }
 
 impl WebEngineViewHandle {
@@ -195,32 +381,48 @@ impl WebEngineViewHandle {
     /// - `init`：由 `EngineRuntime` 构造的初始化参数包。
     pub(super) fn new(init: WebEngineViewHandleInit) -> Self {
         let WebEngineViewHandleInit {
-            id,
-            token,
+            binding,
             shared,
             mouse_move,
             resize,
             input_queue,
             load_url,
             pending,
+            events,
+            strings,
+            binary,
             pending_queue,
             command_queue,
             thread_handle,
+            cache_dir,
             unsafe_no_consumer_fence,
+            vulkan_exports,
+            view_flags,
         } = init;
         Self {
-            id,
-            token,
+            binding,
             shared,
             mouse_move,
             resize,
             input_queue,
             load_url,
             pending,
+            events,
+            strings,
+            binary,
+            next_request_id: AtomicU64::new(1),
             pending_queue,
             command_queue,
             thread_handle,
+            cache_dir,
             unsafe_no_consumer_fence,
+            vulkan_exports,
+            view_flags,
+            recorder: ViewRecorder::default(),
+            input_generation: AtomicU64::new(0),
+            input_dropped: AtomicU64::new(0),
+            input_transform: InputTransform::default(),
+            closed: AtomicBool::new(false),
         }
     }
 
@@ -246,10 +448,78 @@ impl WebEngineViewHandle {
     /// - `bits`：要标记的 work bit。
     #[inline]
     fn mark_pending(&self, bits: u8) -> bool {
-        if !self.pending.mark(bits) {
+        if self.closed.load(Ordering::Relaxed) || !self.pending.mark(bits) {
+            return false;
+        }
+        let id = match self.binding.ids() {
+            Some((id, _)) => id,
+            None if self.binding.is_creating() => return false,
+            None => match self.binding.ids() {
+                Some((id, _)) => id,
+                None => return false,
+            },
+        };
+        let _ = self.pending_queue.push(id);
+        true
+    }
+
+    /// ### English
+    /// Records the outcome of the view's creation and forwards the work deferred meanwhile.
+    ///
+    /// #### Parameters
+    /// - `ids`: `(id, token)` of the created view, or `None` if creation failed.
+    ///
+    /// ### 中文
+    /// 记录 view 的创建结果，并转发期间被延迟的操作。
+    ///
+    /// #### 参数
+    /// - `ids`：已创建 view 的 `(id, token)`；创建失败时为 `None`。
+    pub(super) fn settle(&self, ids: Option<(u32, u64)>) {
+        self.binding.settle(
+            ids,
+            &self.command_queue,
+            &self.pending,
+            &self.pending_queue,
+            &self.thread_handle,
+        );
+    }
+
+    /// ### English
+    /// Returns whether the view exists: it was created (an async creation finished successfully), was
+    /// not closed, and the engine is not shutting down.
+    ///
+    /// ### 中文
+    /// 返回该 view 是否存在：已创建（异步创建已成功完成）、未被关闭，且引擎未在关闭中。
+    pub fn is_alive(&self) -> bool {
+        !self.closed.load(Ordering::Acquire)
+            && self.binding.ids().is_some()
+            && !self.command_queue.is_closed()
+    }
+
+    /// ### English
+    /// Destroys the view on the Servo thread while keeping this handle valid.
+    ///
+    /// Afterwards requests through the handle fail or are ignored, no frames are acquired, and
+    /// [`Self::is_alive`] returns `false`. Returns `false` if the view was already closed.
+    ///
+    /// ### 中文
+    /// 在 Servo 线程销毁该 view，同时保持本句柄有效。
+    ///
+    /// 此后经由该句柄的请求会失败或被忽略，不再 acquire 任何帧，且 [`Self::is_alive`] 返回 `false`。
+    /// 若 view 已被关闭，则返回 `false`。
+    pub fn close(&self) -> bool {
+        if self.closed.swap(true, Ordering::AcqRel) {
             return false;
         }
-        let _ = self.pending_queue.push(self.id);
+        self.shared.set_frame_callback(None, std::ptr::null_mut());
+        self.recorder.stop();
+        if self.binding.ids().is_none() && self.binding.defer_close() {
+            return true;
+        }
+        if let Some((id, token)) = self.binding.ids() {
+            self.command_queue.push(Command::DestroyView { id, token });
+            self.thread_handle.unpark();
+        }
         true
     }
 
@@ -281,6 +551,12 @@ impl WebEngineViewHandle {
     /// - `y`：设备像素坐标 Y（f32）。
     #[must_use = "returns whether the caller should wake the Servo thread"]
     pub fn queue_mouse_move(&self, x: f32, y: f32) -> bool {
+        self.recorder.record_inputs(&[XianWebEngineInputEvent {
+            kind: XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE,
+            x,
+            y,
+            ..XianWebEngineInputEvent::default()
+        }]);
         self.mouse_move.set(x, y);
         self.mark_pending(PENDING_MOUSE_MOVE)
     }
@@ -306,10 +582,59 @@ impl WebEngineViewHandle {
     pub fn queue_resize(&self, size: PhysicalSize<u32>) -> bool {
         let width = size.width.max(1);
         let height = size.height.max(1);
+        self.recorder
+            .record_resize(PhysicalSize::new(width, height));
         self.resize.set(width, height);
         self.mark_pending(PENDING_RESIZE)
     }
 
+    /// ### English
+    /// Sets how long the Servo thread waits for resizes to stop before applying the latest size, so
+    /// dragging a panel does not reallocate the slot textures on every step (`0` = apply each resize
+    /// immediately, the default). Until the size is applied, frames keep the previous size and the
+    /// host stretches them.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `debounce_ms`: Quiet period in milliseconds.
+    ///
+    /// ### 中文
+    /// 设置 Servo 线程在应用最新尺寸前等待 resize 停止的时长，使拖动面板时不会每一步都重新分配槽位纹理（`0` 表示每次
+    /// resize 都立即应用，即默认行为）。尺寸应用之前，帧保持之前的尺寸，由宿主拉伸显示。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `debounce_ms`：静默期（毫秒）。
+    pub fn set_resize_debounce(&self, debounce_ms: u32) -> bool {
+        self.send_view_command(ViewCommand::SetResizeDebounce {
+            debounce: Duration::from_millis(u64::from(debounce_ms)),
+        })
+    }
+
+    /// ### English
+    /// Resizes the view without waiting for the debounce quiet period (e.g. when a drag ends).
+    ///
+    /// The size is clamped to at least 1x1. Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `size`: Requested size (will be clamped to at least 1x1).
+    ///
+    /// ### 中文
+    /// 不等待防抖静默期直接 resize 该 view（例如拖动结束时）。
+    ///
+    /// 尺寸会被 clamp 至至少 1x1。若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `size`：请求的尺寸（会 clamp 至至少 1x1）。
+    pub fn resize_immediate(&self, size: PhysicalSize<u32>) -> bool {
+        let size = PhysicalSize::new(size.width.max(1), size.height.max(1));
+        self.recorder.record_resize(size);
+        self.resize.set(size.width, size.height);
+        self.send_view_command(ViewCommand::ResizeImmediate { size })
+    }
+
     /// ### English
     /// Pushes a slice of input events into the bounded input queue.
     ///
@@ -326,7 +651,97 @@ impl WebEngineViewHandle {
     /// #### 参数
     /// - `events`：要 push 的事件切片。
     pub fn push_input_events(&self, events: &[XianWebEngineInputEvent]) -> usize {
-        self.input_queue.try_push_slice(events)
+        let accepted = self.input_queue.try_push_slice(events);
+        self.recorder.record_inputs(&events[..accepted]);
+        accepted
+    }
+
+    /// ### English
+    /// Sends one input event whose result is reported back as an `INPUT_RESULT` event carrying
+    /// `correlation_id`. It is dispatched after the input queued before it.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `event`: Mouse button, wheel or key event.
+    /// - `correlation_id`: Embedder correlation ID.
+    ///
+    /// ### 中文
+    /// 发送一个输入事件，其结果会以携带 `correlation_id` 的 `INPUT_RESULT` 事件回报。它会在其之前排队的
+    /// 输入之后派发。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `event`：鼠标按键、滚轮或按键事件。
+    /// - `correlation_id`：宿主关联 ID。
+    pub fn send_tracked_input(&self, event: XianWebEngineInputEvent, correlation_id: u64) -> bool {
+        self.recorder.record_inputs(std::slice::from_ref(&event));
+        self.count_input(1, 1);
+        self.send_view_command(ViewCommand::TrackedInput {
+            event,
+            correlation_id,
+        })
+    }
+
+    /// ### English
+    /// Sets (or clears, with `None`) the homography mapping host coordinates to view pixels for
+    /// pointer input submitted afterwards.
+    ///
+    /// Returns `false` (leaving the transform unchanged) if a matrix entry is not finite.
+    ///
+    /// #### Parameters
+    /// - `matrix`: Row-major 3x3 homography, or `None` for identity.
+    ///
+    /// ### 中文
+    /// 为之后提交的指针输入设置（或以 `None` 清除）从宿主坐标到 view 像素的单应矩阵。
+    ///
+    /// 若矩阵中有非有限值，则返回 `false`（变换保持不变）。
+    ///
+    /// #### 参数
+    /// - `matrix`：行主序 3x3 单应矩阵；`None` 表示恒等变换。
+    pub fn set_input_transform(&self, matrix: Option<&[f32; 9]>) -> bool {
+        let homography = match matrix {
+            Some(matrix) => match Homography::new(matrix) {
+                Some(homography) => Some(homography),
+                None => return false,
+            },
+            None => None,
+        };
+        self.input_transform.set(homography);
+        true
+    }
+
+    /// ### English
+    /// Returns the current input homography (`None` = identity).
+    ///
+    /// ### 中文
+    /// 返回当前的输入单应矩阵（`None` 表示恒等变换）。
+    #[inline]
+    pub fn input_transform(&self) -> Option<Homography> {
+        self.input_transform.get()
+    }
+
+    /// ### English
+    /// Counts one `send_input_events` batch for the queue stats.
+    ///
+    /// #### Parameters
+    /// - `submitted`: Events in the batch.
+    /// - `accepted`: Events the view accepted.
+    ///
+    /// ### 中文
+    /// 为队列统计记录一次 `send_input_events` 批次。
+    ///
+    /// #### 参数
+    /// - `submitted`：该批次中的事件数。
+    /// - `accepted`：view 实际接收的事件数。
+    pub fn count_input(&self, submitted: usize, accepted: usize) {
+        self.input_generation
+            .fetch_add(submitted as u64, Ordering::Relaxed);
+        if accepted < submitted {
+            self.input_dropped
+                .fetch_add((submitted - accepted) as u64, Ordering::Relaxed);
+        }
     }
 
     /// ### English
@@ -373,87 +788,1660 @@ impl WebEngineViewHandle {
     /// - `url`：要加载的 URL 字符串（latest-wins）。
     #[must_use = "returns whether the caller should wake the Servo thread"]
     pub fn load_url(&self, url: &str) -> bool {
+        self.recorder.record_navigate(url);
         self.load_url.set_str(url);
         self.mark_pending(PENDING_LOAD_URL)
     }
 
     /// ### English
-    /// Tries to acquire the latest READY frame (consumer-side).
+    /// Sends a per-view control request to the Servo thread and wakes it (held back until an async
+    /// creation finishes).
+    ///
+    /// Returns `false` if the view was closed, its creation failed, or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `command`: Request to apply on the Servo thread.
     ///
     /// ### 中文
-    /// 尝试 acquire 最新 READY 帧（消费者侧）。
-    pub fn acquire_frame(&self) -> Option<AcquiredFrame> {
-        self.shared.try_acquire_front()
+    /// 向 Servo 线程发送 per-view 控制请求并唤醒之（异步创建完成前会暂缓发送）。
+    ///
+    /// 若 view 已关闭、创建失败或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `command`：要在 Servo 线程应用的请求。
+    fn send_view_command(&self, command: ViewCommand) -> bool {
+        if self.closed.load(Ordering::Relaxed) {
+            return false;
+        }
+        let (id, token, command) = match self.binding.ids() {
+            Some((id, token)) => (id, token, command),
+            None => match self.binding.defer(command) {
+                Ok(()) => return true,
+                Err(command) => match self.binding.ids() {
+                    Some((id, token)) => (id, token, command),
+                    None => return false,
+                },
+            },
+        };
+        if !self
+            .command_queue
+            .try_push(Command::View { id, token, command })
+        {
+            return false;
+        }
+        self.thread_handle.unpark();
+        true
     }
 
     /// ### English
-    /// Marks this view active/inactive and applies hide/throttle on Servo thread.
+    /// Sets the default page background (`0xRRGGBBAA`) for subsequent navigations.
     ///
-    /// Returns `true` iff this call changes the active state and the caller should wake the Servo
-    /// thread (see [`Self::wake`]).
+    /// #### Parameters
+    /// - `rgba`: Background color.
+    ///
+    /// ### 中文
+    /// 为后续导航设置默认页面背景色（`0xRRGGBBAA`）。
+    ///
+    /// #### 参数
+    /// - `rgba`：背景色。
+    pub fn set_background(&self, rgba: u32) -> bool {
+        self.send_view_command(ViewCommand::SetBackground { rgba })
+    }
+
+    /// ### English
+    /// Renders the view at `scale` times its logical resolution while keeping the CSS viewport size.
+    ///
+    /// `scale` is clamped to `0.25..=2.0`. Input coordinates and `queue_resize` stay in logical
+    /// pixels; acquired frames report the scaled texture size.
+    ///
+    /// Returns `false` if `scale` is not a positive finite number or the engine is shutting down.
     ///
     /// #### Parameters
-    /// - `active`: Whether the view should be active.
+    /// - `scale`: Internal pixels per logical pixel.
     ///
     /// ### 中文
-    /// 设置该 view 的 active/inactive，并在 Servo 线程应用 hide/throttle。
+    /// 以逻辑分辨率的 `scale` 倍渲染该 view，同时保持 CSS 视口尺寸不变。
     ///
-    /// 仅当返回 `true` 时表示 active 状态发生变化，且建议唤醒 Servo 线程（见 [`Self::wake`]）。
+    /// `scale` 会被 clamp 到 `0.25..=2.0`。输入坐标与 `queue_resize` 仍使用逻辑像素；acquire 到的帧报告缩放后的
+    /// 纹理尺寸。
+    ///
+    /// 若 `scale` 不是正的有限数或引擎正在关闭，则返回 `false`。
     ///
     /// #### 参数
-    /// - `active`：是否将该 view 设为 active。
-    #[must_use = "returns whether the caller should wake the Servo thread"]
-    pub fn set_active(&self, active: bool) -> bool {
-        if self.shared.is_active() == active {
+    /// - `scale`：每逻辑像素对应的内部像素数。
+    pub fn set_render_scale(&self, scale: f32) -> bool {
+        if !scale.is_finite() || scale <= 0.0 {
             return false;
         }
+        let scale = scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+        self.send_view_command(ViewCommand::SetRenderScale { scale })
+    }
 
-        self.shared.set_active(active);
-        self.mark_pending(PENDING_ACTIVE)
+    /// ### English
+    /// Lays the page out at a fixed CSS size scaled to fill the texture, independently of the view
+    /// size; a `0` dimension follows the view size on that axis and `(0, 0)` removes the override.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `width`: CSS viewport width in CSS px.
+    /// - `height`: CSS viewport height in CSS px.
+    ///
+    /// ### 中文
+    /// 让页面以固定的 CSS 尺寸布局并缩放以填满纹理，与 view 尺寸无关；为 `0` 的维度跟随 view 在该轴上的
+    /// 尺寸，`(0, 0)` 移除覆盖。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `width`：CSS 视口宽度（CSS px）。
+    /// - `height`：CSS 视口高度（CSS px）。
+    pub fn set_viewport(&self, width: u32, height: u32) -> bool {
+        self.send_view_command(ViewCommand::SetViewport { width, height })
     }
 
     /// ### English
-    /// Releases a previously acquired slot, optionally recording a consumer fence.
+    /// Moves the view to a new size and render configuration without reloading the page (e.g. when
+    /// the host switches between a windowed panel and a fullscreen browser).
     ///
-    /// If the view is in `unsafe_no_consumer_fence` mode, the fence value is ignored (treated as 0).
+    /// `view_flags` may change `SUPERSAMPLE_2X`, `MIPMAPS` and the color format; the fence, input and
+    /// Vulkan export flags are fixed for the view's lifetime and must match the creation flags. The
+    /// render scale is reset to the one implied by `SUPERSAMPLE_2X`.
+    ///
+    /// Returns `false` if a fixed flag differs, the color format is unknown, or the engine is
+    /// shutting down. The Servo thread is woken by this call.
     ///
     /// #### Parameters
-    /// - `slot`: Triple-buffer slot index (0..=2).
-    /// - `consumer_fence`: Consumer fence handle (`GLsync` cast to `u64`), or 0 to skip.
+    /// - `size`: New logical size (clamped to at least 1x1).
+    /// - `view_flags`: New view flags (`XIAN_WEB_ENGINE_VIEW_FLAG_*`).
     ///
     /// ### 中文
-    /// 释放之前 acquire 的槽位，并可选记录 consumer fence。
+    /// 在不重新加载页面的情况下，将 view 迁移到新的尺寸与渲染配置（例如宿主在窗口面板与全屏浏览器模式之间切换）。
     ///
-    /// 若 view 处于 `unsafe_no_consumer_fence` 模式，则 fence 会被忽略（视为 0）。
+    /// `view_flags` 可以改变 `SUPERSAMPLE_2X`、`MIPMAPS` 与颜色格式；fence、输入与 Vulkan 导出标志在 view
+    /// 生命周期内固定，必须与创建标志一致。渲染缩放会重置为 `SUPERSAMPLE_2X` 所对应的值。
+    ///
+    /// 若固定标志不一致、颜色格式未知或引擎正在关闭，则返回 `false`。该调用会唤醒 Servo 线程。
     ///
     /// #### 参数
-    /// - `slot`：三缓冲槽位索引（0..=2）。
-    /// - `consumer_fence`：consumer fence 句柄（`GLsync` 转 `u64`），为 0 则跳过。
-    pub fn release_slot_with_fence(&self, slot: u32, consumer_fence: u64) {
-        let slot = slot as usize;
-        if slot >= TRIPLE_BUFFER_COUNT {
-            return;
+    /// - `size`：新的逻辑尺寸（会 clamp 至至少 1x1）。
+    /// - `view_flags`：新的 view 标志（`XIAN_WEB_ENGINE_VIEW_FLAG_*`）。
+    pub fn move_view(&self, size: PhysicalSize<u32>, view_flags: u32) -> bool {
+        if (view_flags ^ self.view_flags) & FIXED_VIEW_FLAGS != 0 {
+            return false;
         }
-
-        if self.unsafe_no_consumer_fence {
-            self.shared.release_slot(slot, 0);
+        let color_format = (view_flags & XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_MASK)
+            >> XIAN_WEB_ENGINE_VIEW_FLAG_COLOR_FORMAT_SHIFT;
+        if color_format > XIAN_WEB_ENGINE_COLOR_FORMAT_RGBA16F {
+            return false;
+        }
+        let render_scale = if (view_flags & XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X) != 0 {
+            2.0
         } else {
-            self.shared.release_slot(slot, consumer_fence);
+            1.0
+        };
+
+        if !self.send_view_command(ViewCommand::Move {
+            render_scale,
+            color_format,
+            mipmaps: (view_flags & XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS) != 0,
+        }) {
+            return false;
+        }
+        if self.queue_resize(size) {
+            self.wake();
         }
+        true
     }
-}
 
-impl Drop for WebEngineViewHandle {
     /// ### English
-    /// Sends a `DestroyView` command to the Servo thread on drop.
+    /// Freezes the view: its triple-buffer textures are shrunk away and the WebView is hidden and
+    /// throttled, but the page stays loaded. No frames can be acquired until [`Self::thaw`].
+    ///
+    /// Returns `false` if the engine is shutting down.
     ///
     /// ### 中文
-    /// drop 时向 Servo 线程发送 `DestroyView` 命令。
-    fn drop(&mut self) {
-        self.command_queue.push(Command::DestroyView {
-            id: self.id,
-            token: self.token,
-        });
-        self.thread_handle.unpark();
+    /// 冻结该 view：其三缓冲纹理被缩减释放，WebView 被隐藏并节流，但页面保持加载。在 [`Self::thaw`] 之前无法
+    /// acquire 任何帧。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    pub fn freeze(&self) -> bool {
+        self.send_view_command(ViewCommand::SetFrozen { frozen: true })
+    }
+
+    /// ### English
+    /// Thaws a frozen view: the textures are recreated and an active view repaints immediately.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 解冻已冻结的 view：重新创建纹理，active 的 view 会立即重绘。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    pub fn thaw(&self) -> bool {
+        self.send_view_command(ViewCommand::SetFrozen { frozen: false })
+    }
+
+    /// ### English
+    /// Enters or leaves snapshot mode. In snapshot mode the page stops running (hidden, throttled,
+    /// timers suspended) and nothing is painted, while the last frame stays acquirable
+    /// indefinitely: it is pinned so the producer never reclaims it, and releasing it makes it
+    /// READY again.
+    /// [`Self::refresh_snapshot`] renders a fresh frame on demand.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `enabled`: Whether to enter snapshot mode.
+    ///
+    /// ### 中文
+    /// 进入或退出快照模式。快照模式下页面停止运行（隐藏、节流、挂起定时器）且不再绘制，而最后一帧可被无限期
+    /// acquire：它被固定，生产者永不回收它，release 后它会重新变为 READY。[`Self::refresh_snapshot`] 可按需
+    /// 渲染一帧新内容。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `enabled`：是否进入快照模式。
+    pub fn set_snapshot_mode(&self, enabled: bool) -> bool {
+        self.send_view_command(ViewCommand::SetSnapshotMode { enabled })
+    }
+
+    /// ### English
+    /// Lets a view in snapshot mode run briefly to render a fresh frame, which is then pinned in
+    /// place of the previous one (ignored outside snapshot mode).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 让处于快照模式的 view 短暂运行以渲染一帧新内容，随后用它替换之前的帧并固定（不处于快照模式时忽略）。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    pub fn refresh_snapshot(&self) -> bool {
+        self.send_view_command(ViewCommand::RefreshSnapshot)
+    }
+
+    /// ### English
+    /// Starts recording every input event, resize and navigation delivered to this view, with
+    /// timestamps, into `path` (replacing a recording already running).
+    ///
+    /// Returns `false` if the file cannot be created.
+    ///
+    /// #### Parameters
+    /// - `path`: Recording file (created or truncated).
+    ///
+    /// ### 中文
+    /// 开始将投递给该 view 的所有输入事件、resize 与导航连同时间戳录制到 `path`（替换正在进行的录制）。
+    ///
+    /// 若文件无法创建，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `path`：录制文件（会被创建或截断）。
+    pub fn start_recording(&self, path: &Path) -> bool {
+        self.recorder.start(path).is_ok()
+    }
+
+    /// ### English
+    /// Stops recording and flushes the file.
+    ///
+    /// Returns `false` if nothing was being recorded or the file could not be written.
+    ///
+    /// ### 中文
+    /// 停止录制并 flush 文件。
+    ///
+    /// 若没有正在进行的录制或文件无法写入，则返回 `false`。
+    pub fn stop_recording(&self) -> bool {
+        self.recorder.stop()
+    }
+
+    /// ### English
+    /// Replays a recording into this view on the Servo thread, with the recorded timing measured from
+    /// the moment the Servo thread receives the request. A `REPLAY_FINISHED` event follows the last
+    /// action; a new replay replaces one still running.
+    ///
+    /// Replayed actions bypass the recorder. Returns `false` if the file cannot be read or is not a
+    /// recording, or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `path`: Recording file written by [`Self::start_recording`].
+    ///
+    /// ### 中文
+    /// 在 Servo 线程上将录制回放到该 view，录制时的时间间隔从 Servo 线程收到请求的时刻开始计算。最后一个动作之后
+    /// 会产生 `REPLAY_FINISHED` 事件；新的回放会替换仍在进行的回放。
+    ///
+    /// 回放的动作不会被录制器记录。若文件无法读取或不是录制文件，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `path`：由 [`Self::start_recording`] 写入的录制文件。
+    pub fn replay(&self, path: &Path) -> bool {
+        let Ok(entries) = read_recording(path) else {
+            return false;
+        };
+        self.send_view_command(ViewCommand::Replay { entries })
+    }
+
+    /// ### English
+    /// Starts streaming this view's frames over HTTP on `port` (every interface), replacing a
+    /// screencast already running on another port.
+    ///
+    /// #### Parameters
+    /// - `port`: TCP port to listen on.
+    /// - `max_fps`: Highest capture rate (`0` = 10; capped at 60).
+    ///
+    /// ### 中文
+    /// 开始在 `port`（所有网卡）上通过 HTTP 推流该 view 的帧，并替换在其他端口上正在进行的屏幕投射。
+    ///
+    /// #### 参数
+    /// - `port`：要监听的 TCP 端口。
+    /// - `max_fps`：最高截取帧率（`0` 表示 10；上限为 60）。
+    #[cfg(feature = "screencast")]
+    pub fn start_screencast(&self, port: u16, max_fps: u32) -> Result<(), String> {
+        let server = ScreencastServer::start(port)?;
+        if self.send_view_command(ViewCommand::StartScreencast { server, max_fps }) {
+            Ok(())
+        } else {
+            Err("Engine is shutting down".to_string())
+        }
+    }
+
+    /// ### English
+    /// Always fails: this build has no screencast support.
+    ///
+    /// #### Parameters
+    /// - `_port`: TCP port to listen on.
+    /// - `_max_fps`: Highest capture rate.
+    ///
+    /// ### 中文
+    /// 总是失败：该构建不支持屏幕投射。
+    ///
+    /// #### 参数
+    /// - `_port`：要监听的 TCP 端口。
+    /// - `_max_fps`：最高截取帧率。
+    #[cfg(not(feature = "screencast"))]
+    pub fn start_screencast(&self, _port: u16, _max_fps: u32) -> Result<(), String> {
+        Err("Screencast requires a build with the `screencast` feature".to_string())
+    }
+
+    /// ### English
+    /// Stops the screencast and closes its server.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 停止屏幕投射并关闭其服务器。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    #[cfg(feature = "screencast")]
+    pub fn stop_screencast(&self) -> bool {
+        self.send_view_command(ViewCommand::StopScreencast)
+    }
+
+    /// ### English
+    /// Always fails: this build has no screencast support.
+    ///
+    /// ### 中文
+    /// 总是失败：该构建不支持屏幕投射。
+    #[cfg(not(feature = "screencast"))]
+    pub fn stop_screencast(&self) -> bool {
+        false
+    }
+
+    /// ### English
+    /// Synthesizes a left click at `(x, y)` on the Servo thread: a mouse move onto the point, then a
+    /// button press and release with human-like delays. Queued after synthesized input still pending.
+    ///
+    /// Synthesized input bypasses the recorder. Returns `false` if a coordinate is not finite or the
+    /// engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `x`: X coordinate in logical pixels.
+    /// - `y`: Y coordinate in logical pixels.
+    ///
+    /// ### 中文
+    /// 在 Servo 线程上于 `(x, y)` 合成一次左键点击：先将鼠标移动到该点，再以接近真人的间隔按下并释放按键。
+    /// 排在仍待注入的合成输入之后。
+    ///
+    /// 合成输入不会被录制器记录。若坐标不是有限值，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `x`：X 坐标（逻辑像素）。
+    /// - `y`：Y 坐标（逻辑像素）。
+    pub fn click_at(&self, x: f32, y: f32) -> bool {
+        if !x.is_finite() || !y.is_finite() {
+            return false;
+        }
+        self.send_view_command(ViewCommand::Synthesize {
+            entries: synthesis::click(x, y),
+        })
+    }
+
+    /// ### English
+    /// Synthesizes typing `text` on the Servo thread: a key press and release per character with
+    /// human-like delays (`'\n'` and `'\t'` press Enter and Tab, other control characters are
+    /// skipped). Queued after synthesized input still pending.
+    ///
+    /// Synthesized input bypasses the recorder. Returns `false` if `text` has nothing to type or the
+    /// engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `text`: Text to type.
+    ///
+    /// ### 中文
+    /// 在 Servo 线程上合成输入 `text`：每个字符以接近真人的间隔按下并释放一次（`'\n'` 与 `'\t'` 分别按下 Enter
+    /// 与 Tab，其它控制字符会被跳过）。排在仍待注入的合成输入之后。
+    ///
+    /// 合成输入不会被录制器记录。若 `text` 中没有可输入的内容，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `text`：要输入的文本。
+    pub fn type_text(&self, text: &str) -> bool {
+        let entries = synthesis::text(text);
+        if entries.is_empty() {
+            return false;
+        }
+        self.send_view_command(ViewCommand::Synthesize { entries })
+    }
+
+    /// ### English
+    /// Pauses or resumes the view's refresh driver for debugging. While paused, animations and
+    /// `requestAnimationFrame` callbacks stop until [`Self::debug_step`] lets a frame through;
+    /// resuming continues from the held frame.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `paused`: Whether to pause.
+    ///
+    /// ### 中文
+    /// 为调试而暂停或恢复该 view 的 refresh driver。暂停期间，动画与 `requestAnimationFrame` 回调会停止，直到
+    /// [`Self::debug_step`] 放行一帧；恢复时从被扣住的帧继续。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `paused`：是否暂停。
+    pub fn set_debug_paused(&self, paused: bool) -> bool {
+        self.send_view_command(ViewCommand::SetDebugPaused { paused })
+    }
+
+    /// ### English
+    /// Advances a view paused with [`Self::set_debug_paused`] by one refresh-driver frame (ignored if
+    /// the view is not paused).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 让通过 [`Self::set_debug_paused`] 暂停的 view 前进一个 refresh driver 帧（view 未暂停时会被忽略）。
+    ///
+    /// 若引擎正在关闭，则返回 `false`。
+    pub fn debug_step(&self) -> bool {
+        self.send_view_command(ViewCommand::DebugStep)
+    }
+
+    /// ### English
+    /// Forces the output colorspace (`XIAN_WEB_ENGINE_COLORSPACE_*`); applies from the next frame.
+    ///
+    /// Returns `false` if `colorspace` is unknown, sRGB is requested for a non-`SRGB8_A8` view, or the
+    /// engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `colorspace`: Output colorspace.
+    ///
+    /// ### 中文
+    /// 强制指定输出色彩空间（`XIAN_WEB_ENGINE_COLORSPACE_*`）；从下一帧开始生效。
+    ///
+    /// 若 `colorspace` 未知、对非 `SRGB8_A8` 的 view 请求 sRGB，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `colorspace`：输出色彩空间。
+    pub fn set_colorspace(&self, colorspace: u32) -> bool {
+        match colorspace {
+            XIAN_WEB_ENGINE_COLORSPACE_SRGB
+                if self.shared.color_format() != XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8 =>
+            {
+                false
+            }
+            XIAN_WEB_ENGINE_COLORSPACE_AUTO
+            | XIAN_WEB_ENGINE_COLORSPACE_SRGB
+            | XIAN_WEB_ENGINE_COLORSPACE_LINEAR => {
+                self.send_view_command(ViewCommand::SetColorspace { colorspace })
+            }
+            _ => false,
+        }
+    }
+
+    /// ### English
+    /// Sets the `prefers-color-scheme` reported to the view's pages
+    /// (`XIAN_WEB_ENGINE_COLOR_SCHEME_*`); applies immediately.
+    ///
+    /// Returns `false` if `scheme` is unknown or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `scheme`: Color scheme.
+    ///
+    /// ### 中文
+    /// 设置向该 view 页面报告的 `prefers-color-scheme`（`XIAN_WEB_ENGINE_COLOR_SCHEME_*`）；立即生效。
+    ///
+    /// 若 `scheme` 未知或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `scheme`：配色方案。
+    pub fn set_color_scheme(&self, scheme: u32) -> bool {
+        match scheme {
+            XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT | XIAN_WEB_ENGINE_COLOR_SCHEME_DARK => {
+                self.send_view_command(ViewCommand::SetColorScheme { scheme })
+            }
+            _ => false,
+        }
+    }
+
+    /// ### English
+    /// Sets whether the view's pages see `prefers-reduced-motion: reduce`; applies immediately.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `reduce`: Whether to report reduced motion.
+    ///
+    /// ### 中文
+    /// 设置该 view 的页面是否看到 `prefers-reduced-motion: reduce`；立即生效。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `reduce`：是否报告减少动态效果。
+    pub fn set_reduced_motion(&self, reduce: bool) -> bool {
+        self.send_view_command(ViewCommand::SetReducedMotion { reduce })
+    }
+
+    /// ### English
+    /// Sets whether the view's pages see `forced-colors: active`; applies immediately.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `active`: Whether to report forced colors.
+    ///
+    /// ### 中文
+    /// 设置该 view 的页面是否看到 `forced-colors: active`；立即生效。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `active`：是否报告强制颜色。
+    pub fn set_forced_colors(&self, active: bool) -> bool {
+        self.send_view_command(ViewCommand::SetForcedColors { active })
+    }
+
+    /// ### English
+    /// Pauses or resumes the view's animation clock by holding its frame callbacks and offsetting
+    /// the page timeline by the time spent paused; applies immediately and persists across
+    /// navigations.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `paused`: Whether the animation clock stands still.
+    ///
+    /// ### 中文
+    /// 通过扣住帧回调并按暂停时长偏移页面时间线，暂停或恢复该 view 的动画时钟；立即生效，且在导航之间保持。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `paused`：动画时钟是否停止。
+    pub fn set_time_paused(&self, paused: bool) -> bool {
+        self.send_view_command(ViewCommand::SetTimePaused { paused })
+    }
+
+    /// ### English
+    /// Sets how the view behaves while inactive (`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`).
+    ///
+    /// Returns `false` if `policy` is unknown or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `policy`: Background policy.
+    ///
+    /// ### 中文
+    /// 设置 view 在 inactive 时的行为（`XIAN_WEB_ENGINE_BACKGROUND_POLICY_*`）。
+    ///
+    /// 若 `policy` 未知或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `policy`：后台策略。
+    pub fn set_background_policy(&self, policy: u32) -> bool {
+        match policy {
+            XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL
+            | XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ
+            | XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND => {
+                self.send_view_command(ViewCommand::SetBackgroundPolicy { policy })
+            }
+            _ => false,
+        }
+    }
+
+    /// ### English
+    /// Sets the slow-script policy (`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`).
+    ///
+    /// Returns `false` if `policy` is unknown, `timeout_ms` is `0` for an enabled policy, or the
+    /// engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `policy`: Slow-script policy.
+    /// - `timeout_ms`: How long a script may block the page before the policy applies.
+    ///
+    /// ### 中文
+    /// 设置慢脚本策略（`XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_*`）。
+    ///
+    /// 若 `policy` 未知、启用策略时 `timeout_ms` 为 `0`，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `policy`：慢脚本策略。
+    /// - `timeout_ms`：脚本阻塞页面多久后应用该策略。
+    pub fn set_slow_script_policy(&self, policy: u32, timeout_ms: u32) -> bool {
+        match policy {
+            XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE => {}
+            XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY | XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP
+                if timeout_ms != 0 => {}
+            _ => return false,
+        }
+        self.send_view_command(ViewCommand::SetSlowScriptPolicy { policy, timeout_ms })
+    }
+
+    /// ### English
+    /// Adds a user stylesheet applied to every subsequent load.
+    ///
+    /// #### Parameters
+    /// - `css`: Stylesheet source.
+    ///
+    /// ### 中文
+    /// 添加一个作用于后续所有加载的用户样式表。
+    ///
+    /// #### 参数
+    /// - `css`：样式表源码。
+    pub fn add_user_stylesheet(&self, css: &str) -> bool {
+        self.send_view_command(ViewCommand::AddUserStylesheet {
+            css: css.to_string(),
+        })
+    }
+
+    /// ### English
+    /// Registers a user script run automatically on every subsequent navigation.
+    ///
+    /// Returns `false` if `injection_time`/`world` is unsupported or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `source`: Script source.
+    /// - `injection_time`: `XIAN_WEB_ENGINE_USER_SCRIPT_AT_*`.
+    /// - `world`: `XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_*`.
+    ///
+    /// ### 中文
+    /// 注册一个在后续每次导航时自动执行的用户脚本。
+    ///
+    /// 若 `injection_time`/`world` 不受支持或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `source`：脚本源码。
+    /// - `injection_time`：`XIAN_WEB_ENGINE_USER_SCRIPT_AT_*`。
+    /// - `world`：`XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_*`。
+    pub fn add_user_script(&self, source: &str, injection_time: u32, world: u32) -> bool {
+        if world != XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN {
+            return false;
+        }
+
+        let source = match injection_time {
+            XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START => source.to_string(),
+            XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END => format!(
+                "(() => {{ const run = () => {{\n{source}\n}}; \
+                 if (document.readyState === \"loading\") \
+                 document.addEventListener(\"DOMContentLoaded\", run, {{ once: true }}); \
+                 else run(); }})();"
+            ),
+            _ => return false,
+        };
+        self.send_view_command(ViewCommand::AddUserScript { source })
+    }
+
+    /// ### English
+    /// Answers a permission request reported by a `PERMISSION_REQUEST` event.
+    ///
+    /// Returns `false` if the engine is shutting down; unknown or expired IDs are ignored.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID from the event.
+    /// - `allow`: Whether to grant the permission.
+    ///
+    /// ### 中文
+    /// 应答由 `PERMISSION_REQUEST` 事件上报的权限请求。
+    ///
+    /// 若引擎正在关闭则返回 `false`；未知或已超时的 ID 会被忽略。
+    ///
+    /// #### 参数
+    /// - `request_id`：事件中的请求 ID。
+    /// - `allow`：是否授予权限。
+    pub fn respond_permission(&self, request_id: u64, allow: bool) -> bool {
+        self.send_view_command(ViewCommand::RespondPermission { request_id, allow })
+    }
+
+    /// ### English
+    /// Releases pointer lock held by the page (reported back as a `POINTER_LOCK` event).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 释放页面持有的 pointer lock（结果以 `POINTER_LOCK` 事件回报）。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    pub fn exit_pointer_lock(&self) -> bool {
+        self.send_view_command(ViewCommand::ExitPointerLock)
+    }
+
+    /// ### English
+    /// Starts dragging host data over the page (HTML5 `dragenter`/`dragover` follow on move).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `mime`: MIME type exposed through `DataTransfer` (e.g. `application/json`).
+    /// - `data`: Dragged data.
+    ///
+    /// ### 中文
+    /// 开始在页面上拖拽宿主数据（移动时会派发 HTML5 `dragenter`/`dragover`）。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `mime`：通过 `DataTransfer` 暴露的 MIME 类型（例如 `application/json`）。
+    /// - `data`：被拖拽的数据。
+    pub fn begin_drag(&self, mime: &str, data: &str) -> bool {
+        self.send_view_command(ViewCommand::BeginDrag {
+            mime: mime.to_string(),
+            data: data.to_string(),
+        })
+    }
+
+    /// ### English
+    /// Moves the current host drag.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `x`: Pointer X in device pixels.
+    /// - `y`: Pointer Y in device pixels.
+    ///
+    /// ### 中文
+    /// 移动当前宿主拖拽。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `x`：指针 X（设备像素）。
+    /// - `y`：指针 Y（设备像素）。
+    pub fn drag_move(&self, x: f32, y: f32) -> bool {
+        if !x.is_finite() || !y.is_finite() {
+            return false;
+        }
+        self.send_view_command(ViewCommand::DragMove { x, y })
+    }
+
+    /// ### English
+    /// Feeds device orientation (and optionally acceleration) to the page as
+    /// `deviceorientation` / `devicemotion` events.
+    ///
+    /// Returns `false` if a component is not finite, the quaternion is zero, or the engine is
+    /// shutting down.
+    ///
+    /// #### Parameters
+    /// - `orientation`: Rotation quaternion `[x, y, z, w]` from the Earth frame to the device frame.
+    /// - `acceleration`: Acceleration including gravity in m/s² (`None` skips `devicemotion`).
+    ///
+    /// ### 中文
+    /// 将设备朝向（以及可选的加速度）以 `deviceorientation` / `devicemotion` 事件提供给页面。
+    ///
+    /// 若某个分量不是有限值、四元数为零，或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `orientation`：从地球坐标系到设备坐标系的旋转四元数 `[x, y, z, w]`。
+    /// - `acceleration`：含重力的加速度，单位 m/s²（`None` 表示不派发 `devicemotion`）。
+    pub fn update_sensors(&self, orientation: [f32; 4], acceleration: Option<[f32; 3]>) -> bool {
+        if !orientation.iter().all(|v| v.is_finite())
+            || orientation.iter().all(|&v| v == 0.0)
+            || !acceleration.is_none_or(|a| a.iter().all(|v| v.is_finite()))
+        {
+            return false;
+        }
+        self.send_view_command(ViewCommand::UpdateSensors {
+            orientation,
+            acceleration,
+        })
+    }
+
+    /// ### English
+    /// Drops the current host drag (reported as a `DROP_COMPLETE` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `x`: Pointer X in device pixels.
+    /// - `y`: Pointer Y in device pixels.
+    ///
+    /// ### 中文
+    /// 放下当前宿主拖拽（以 `DROP_COMPLETE` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `x`：指针 X（设备像素）。
+    /// - `y`：指针 Y（设备像素）。
+    pub fn drop_drag(&self, x: f32, y: f32) -> Option<u64> {
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::Drop { request_id, x, y })
+            .then_some(request_id)
+    }
+
+    /// ### English
+    /// Cancels the current host drag (the page receives `dragleave`).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 取消当前宿主拖拽（页面会收到 `dragleave`）。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    pub fn cancel_drag(&self) -> bool {
+        self.send_view_command(ViewCommand::CancelDrag)
+    }
+
+    /// ### English
+    /// Answers a `FILE_PICKER` event with files on disk (an empty list cancels the chooser).
+    ///
+    /// Returns `false` if the engine is shutting down; unknown IDs are ignored.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID from the event.
+    /// - `paths`: Selected files.
+    ///
+    /// ### 中文
+    /// 以磁盘上的文件应答 `FILE_PICKER` 事件（空列表表示取消选择）。
+    ///
+    /// 若引擎正在关闭则返回 `false`；未知 ID 会被忽略。
+    ///
+    /// #### 参数
+    /// - `request_id`：事件中的请求 ID。
+    /// - `paths`：所选文件。
+    pub fn provide_files(&self, request_id: u64, paths: Vec<PathBuf>) -> bool {
+        self.send_view_command(ViewCommand::ProvideFiles { request_id, paths })
+    }
+
+    /// ### English
+    /// Answers a `FILE_PICKER` event with an in-memory file.
+    ///
+    /// The bytes are written to a file under the engine cache directory, which is then selected.
+    ///
+    /// Returns `false` if the file cannot be written or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `request_id`: Request ID from the event.
+    /// - `name`: File name reported to the page (directories are stripped).
+    /// - `data`: File contents.
+    ///
+    /// ### 中文
+    /// 以内存中的文件应答 `FILE_PICKER` 事件。
+    ///
+    /// 字节会被写入引擎缓存目录下的文件，随后选中该文件。
+    ///
+    /// 若文件无法写入或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `request_id`：事件中的请求 ID。
+    /// - `name`：报告给页面的文件名（会去除目录部分）。
+    /// - `data`：文件内容。
+    pub fn provide_file_data(&self, request_id: u64, name: &str, data: &[u8]) -> bool {
+        let Some(name) = Path::new(name).file_name() else {
+            return false;
+        };
+
+        let Some((_, token)) = self.binding.ids() else {
+            return false;
+        };
+        let Ok(cache_dir) = self.cache_dir.lock().map(|dir| dir.clone()) else {
+            return false;
+        };
+        let dir = cache_dir.join(UPLOADS_DIR).join(format!(
+            "{}-{}-{request_id}",
+            std::process::id(),
+            token
+        ));
+        let path = dir.join(name);
+        if std::fs::create_dir_all(&dir).is_err() || std::fs::write(&path, data).is_err() {
+            return false;
+        }
+
+        self.provide_files(request_id, vec![path])
+    }
+
+    /// ### English
+    /// Allocates a request ID for an asynchronous request (never `0`).
+    ///
+    /// ### 中文
+    /// 为异步请求分配请求 ID（永不为 `0`）。
+    #[inline]
+    fn next_request_id(&self) -> u64 {
+        self.next_request_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Requests an export of the current page to `path`.
+    ///
+    /// Returns the request ID reported by the matching `EXPORT_COMPLETE` event, or `None` if the
+    /// format is unknown or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `path`: Output file path.
+    /// - `format`: `XIAN_WEB_ENGINE_EXPORT_FORMAT_*`.
+    ///
+    /// ### 中文
+    /// 请求把当前页面导出到 `path`。
+    ///
+    /// 返回请求 ID（对应的 `EXPORT_COMPLETE` 事件会携带该 ID）；若格式未知或引擎正在关闭则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `path`：输出文件路径。
+    /// - `format`：`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`。
+    pub fn export(&self, path: PathBuf, format: u32) -> Option<u64> {
+        if !matches!(
+            format,
+            XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG | XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF
+        ) {
+            return None;
+        }
+
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::Export {
+            request_id,
+            path,
+            format,
+        })
+        .then_some(request_id)
+    }
+
+    /// ### English
+    /// Requests a readback of the viewport region of the first element matching `selector`.
+    ///
+    /// `done` runs exactly once: on the Servo thread with the pixels or an error, or (with an
+    /// error) on the thread that rejects or drops the request. Returns `false` if the request was
+    /// rejected (the view was closed, the queue is full or the engine is shutting down).
+    ///
+    /// #### Parameters
+    /// - `selector`: CSS selector of the element.
+    /// - `done`: Callback receiving the RGBA pixels (top row first) or an error.
+    ///
+    /// ### 中文
+    /// 请求读回首个匹配 `selector` 之元素所在的视口区域。
+    ///
+    /// `done` 恰好执行一次：在 Servo 线程上收到像素或错误；或者（带错误地）在拒绝或丢弃该请求的线程上执行。若
+    /// 该请求被拒绝（view 已关闭、队列已满或引擎正在关闭），则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `selector`：元素的 CSS 选择器。
+    /// - `done`：接收 RGBA 像素（首行在前）或错误的回调。
+    pub fn capture_element(
+        &self,
+        selector: &str,
+        done: Box<dyn FnOnce(ElementCaptureResult) + Send>,
+    ) -> bool {
+        self.send_view_command(ViewCommand::CaptureElement {
+            selector: selector.to_string(),
+            reply: ElementCaptureReply::new(done),
+        })
+    }
+
+    /// ### English
+    /// Requests an accessibility tree snapshot (reported as an `ACCESSIBILITY_TREE` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 请求无障碍树快照（以 `ACCESSIBILITY_TREE` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn request_accessibility_tree(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::AccessibilityTree { request_id })
+            .then_some(request_id)
+    }
+
+    /// ### English
+    /// Pauses every playing media element (reported as a `MEDIA_STATE` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 暂停所有正在播放的媒体元素（以 `MEDIA_STATE` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn pause_all_media(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::SetMediaPaused {
+            request_id,
+            paused: true,
+        })
+        .then_some(request_id)
+    }
+
+    /// ### English
+    /// Resumes media elements paused by [`Self::pause_all_media`] (reported as a `MEDIA_STATE` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 恢复由 [`Self::pause_all_media`] 暂停的媒体元素（以 `MEDIA_STATE` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn resume_all_media(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::SetMediaPaused {
+            request_id,
+            paused: false,
+        })
+        .then_some(request_id)
+    }
+
+    /// ### English
+    /// Requests a serialized session (reported as a `SESSION_SAVED` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 请求序列化会话（以 `SESSION_SAVED` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn save_session(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::SaveSession { request_id })
+            .then_some(request_id)
+    }
+
+    /// ### English
+    /// Selects the focused text field's contents, or the whole document.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 选中获得焦点的文本框内容，或整个文档。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    pub fn select_all(&self) -> bool {
+        self.send_view_command(ViewCommand::SelectAll)
+    }
+
+    /// ### English
+    /// Requests the selected text (reported as a `SELECTED_TEXT` event).
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 请求读取选中的文本（以 `SELECTED_TEXT` 事件上报）。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn request_selected_text(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::Selection {
+            request_id,
+            copy: false,
+        })
+        .then_some(request_id)
+    }
+
+    /// ### English
+    /// Copies the selection: runs the page's `copy` handlers and reports the text as a `COPY` event.
+    ///
+    /// Returns the request ID, or `None` if the engine is shutting down.
+    ///
+    /// ### 中文
+    /// 复制选区：执行页面的 `copy` 处理器，并以 `COPY` 事件上报文本。
+    ///
+    /// 返回请求 ID；若引擎正在关闭则返回 `None`。
+    pub fn copy_selection(&self) -> Option<u64> {
+        let request_id = self.next_request_id();
+        self.send_view_command(ViewCommand::Selection {
+            request_id,
+            copy: true,
+        })
+        .then_some(request_id)
+    }
+
+    /// ### English
+    /// Enables or disables reporting page console output as `CONSOLE_MESSAGE` events.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `enabled`: Whether to capture console output.
+    ///
+    /// ### 中文
+    /// 启用或禁用将页面 console 输出以 `CONSOLE_MESSAGE` 事件上报。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `enabled`：是否捕获 console 输出。
+    pub fn set_console_capture(&self, enabled: bool) -> bool {
+        self.send_view_command(ViewCommand::SetConsoleCapture { enabled })
+    }
+
+    /// ### English
+    /// Registers a host function the page can call through `window.xianHost.call(name, args)`
+    /// (`None` unregisters it).
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `name`: Function name used by the page.
+    /// - `callback`: Callback invoked on the Servo thread for every call, or `None`.
+    /// - `user_data`: Opaque pointer passed back to `callback`.
+    ///
+    /// ### 中文
+    /// 注册一个页面可通过 `window.xianHost.call(name, args)` 调用的宿主函数（`None` 表示取消注册）。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `name`：页面使用的函数名。
+    /// - `callback`：每次调用时在 Servo 线程上执行的回调，或 `None`。
+    /// - `user_data`：回传给 `callback` 的不透明指针。
+    pub fn register_host_function(
+        &self,
+        name: String,
+        callback: Option<XianWebEngineHostFunctionFn>,
+        user_data: *mut c_void,
+    ) -> bool {
+        let function = callback.map(|callback| HostFunction {
+            callback,
+            user_data: user_data as usize,
+        });
+        self.send_view_command(ViewCommand::RegisterHostFunction { name, function })
+    }
+
+    /// ### English
+    /// Answers a host function call; unknown or stale call IDs are ignored.
+    ///
+    /// Returns `false` if the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `call_id`: Call ID passed to the callback.
+    /// - `success`: Whether to resolve (`true`) or reject (`false`) the page's Promise.
+    /// - `result`: JSON result when resolving (empty = `undefined`), error message when rejecting.
+    ///
+    /// ### 中文
+    /// 应答一次宿主函数调用；未知或过期的调用 ID 会被忽略。
+    ///
+    /// 若引擎正在关闭则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `call_id`：传给回调的调用 ID。
+    /// - `success`：兑现（`true`）还是拒绝（`false`）页面的 Promise。
+    /// - `result`：兑现时为 JSON 结果（空字符串表示 `undefined`），拒绝时为错误信息。
+    pub fn respond_host_call(&self, call_id: u64, success: bool, result: String) -> bool {
+        self.send_view_command(ViewCommand::RespondHostCall {
+            call_id,
+            success,
+            result,
+        })
+    }
+
+    /// ### English
+    /// Sends bytes to the page, which receives them as an `ArrayBuffer` in a `binary` event on
+    /// `window.xianHost`. The bytes are copied into a pooled buffer.
+    ///
+    /// Returns `false` if `data` exceeds `MAX_BINARY_MESSAGE_BYTES` or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `channel`: Embedder-defined channel number, passed to the page.
+    /// - `data`: Message bytes.
+    ///
+    /// ### 中文
+    /// 向页面发送字节；页面会在 `window.xianHost` 的 `binary` 事件中以 `ArrayBuffer` 收到它们。字节会被复制到
+    /// 缓冲池中的缓冲区。
+    ///
+    /// 若 `data` 超过 `MAX_BINARY_MESSAGE_BYTES` 或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `channel`：由宿主定义的通道号，会传给页面。
+    /// - `data`：消息字节。
+    pub fn post_binary(&self, channel: u32, data: &[u8]) -> bool {
+        if data.len() > MAX_BINARY_MESSAGE_BYTES {
+            return false;
+        }
+        let mut buffer = self.binary.buffer();
+        buffer.extend_from_slice(data);
+        self.send_view_command(ViewCommand::PostBinary {
+            channel,
+            data: buffer,
+        })
+    }
+
+    /// ### English
+    /// Pushes a frame the embedder rendered into `texture` to the page stream `stream_id`; an
+    /// `EXTERNAL_FRAME_RELEASED` event reports when the texture may be rendered into again.
+    ///
+    /// Returns `false` if `texture` is 0, the size is zero or its RGBA8 pixels exceed
+    /// `MAX_BINARY_MESSAGE_BYTES`, or the engine is shutting down (the fence is then not consumed).
+    ///
+    /// #### Parameters
+    /// - `stream_id`: Stream the page opened with `xianHost.openStream(id)`.
+    /// - `texture`: GL texture name (`GL_TEXTURE_2D`, row 0 at the bottom).
+    /// - `width`: Frame width in pixels.
+    /// - `height`: Frame height in pixels.
+    /// - `fence`: `GLsync` (cast to `u64`) inserted after rendering the frame, or `0`.
+    ///
+    /// ### 中文
+    /// 将宿主渲染到 `texture` 的一帧推送到页面流 `stream_id`；`EXTERNAL_FRAME_RELEASED` 事件会通知何时可以
+    /// 再次渲染该纹理。
+    ///
+    /// 若 `texture` 为 0、尺寸为 0 或 RGBA8 像素超过 `MAX_BINARY_MESSAGE_BYTES`，或引擎正在关闭，则返回
+    /// `false`（此时 fence 不会被消费）。
+    ///
+    /// #### 参数
+    /// - `stream_id`：页面通过 `xianHost.openStream(id)` 打开的流。
+    /// - `texture`：GL 纹理名（`GL_TEXTURE_2D`，第 0 行在底部）。
+    /// - `width`：帧宽度（像素）。
+    /// - `height`：帧高度（像素）。
+    /// - `fence`：渲染该帧后插入的 `GLsync`（转为 `u64`），或 `0`。
+    pub fn push_external_frame(
+        &self,
+        stream_id: u32,
+        texture: u32,
+        width: u32,
+        height: u32,
+        fence: u64,
+    ) -> bool {
+        let bytes = u64::from(width) * u64::from(height) * 4;
+        if texture == 0 || bytes == 0 || bytes > MAX_BINARY_MESSAGE_BYTES as u64 {
+            return false;
+        }
+        self.send_view_command(ViewCommand::PushExternalFrame {
+            stream_id,
+            texture,
+            width,
+            height,
+            fence,
+        })
+    }
+
+    /// ### English
+    /// Returns the address and length of a binary message received from the page (announced by a
+    /// `BINARY_MESSAGE` event); the bytes stay valid until `release_binary_message`.
+    ///
+    /// #### Parameters
+    /// - `id`: Message ID from the event.
+    ///
+    /// ### 中文
+    /// 返回从页面收到的二进制消息（由 `BINARY_MESSAGE` 事件通知）的地址与长度；在
+    /// `release_binary_message` 之前这些字节一直有效。
+    ///
+    /// #### 参数
+    /// - `id`：事件中的消息 ID。
+    pub fn binary_message(&self, id: u64) -> Option<(*const u8, usize)> {
+        self.binary.message(id)
+    }
+
+    /// ### English
+    /// Releases a binary message received from the page, returning its buffer to the pool. Returns
+    /// `false` for unknown IDs.
+    ///
+    /// #### Parameters
+    /// - `id`: Message ID from the event.
+    ///
+    /// ### 中文
+    /// 释放一条从页面收到的二进制消息，并将其缓冲区归还到缓冲池。未知 ID 返回 `false`。
+    ///
+    /// #### 参数
+    /// - `id`：事件中的消息 ID。
+    pub fn release_binary_message(&self, id: u64) -> bool {
+        self.binary.release(id)
+    }
+
+    /// ### English
+    /// Rebuilds a saved session in this view: loads `entries` in order, goes back to `current`
+    /// and scrolls it to `(scroll_x, scroll_y)` once it has loaded.
+    ///
+    /// #### Parameters
+    /// - `entries`: History entry URLs, oldest first.
+    /// - `current`: Index of the current entry.
+    /// - `scroll_x`: Horizontal offset (CSS px).
+    /// - `scroll_y`: Vertical offset (CSS px).
+    ///
+    /// ### 中文
+    /// 在该 view 中重建保存的会话：依次加载 `entries`，后退到 `current`，并在其加载完成后滚动到
+    /// `(scroll_x, scroll_y)`。
+    ///
+    /// #### 参数
+    /// - `entries`：历史条目 URL（从旧到新）。
+    /// - `current`：当前条目的索引。
+    /// - `scroll_x`：水平偏移（CSS px）。
+    /// - `scroll_y`：垂直偏移（CSS px）。
+    pub(super) fn restore_session(
+        &self,
+        entries: Vec<Url>,
+        current: usize,
+        scroll_x: f64,
+        scroll_y: f64,
+    ) -> bool {
+        self.send_view_command(ViewCommand::RestoreSession {
+            entries,
+            current,
+            scroll_x,
+            scroll_y,
+        })
+    }
+
+    /// ### English
+    /// Polls queued view events (see `ViewEventQueue::poll`).
+    ///
+    /// #### Parameters
+    /// - `out_events`: Output event array.
+    /// - `text_buf`: Output buffer for UTF-8 text payloads.
+    ///
+    /// ### 中文
+    /// 轮询已排队的 view 事件（见 `ViewEventQueue::poll`）。
+    ///
+    /// #### 参数
+    /// - `out_events`：输出事件数组。
+    /// - `text_buf`：UTF-8 文本载荷的输出缓冲区。
+    pub fn poll_events(
+        &self,
+        out_events: &mut [XianWebEngineViewEvent],
+        text_buf: &mut [u8],
+    ) -> usize {
+        self.events.poll(out_events, text_buf)
+    }
+
+    /// ### English
+    /// Returns the monotonic delivery counters of this view's queues.
+    ///
+    /// ### 中文
+    /// 返回该 view 各队列的单调递增投递计数。
+    pub fn queue_stats(&self) -> XianWebEngineViewQueueStats {
+        XianWebEngineViewQueueStats {
+            event_generation: self.events.generation(),
+            events_dropped: self.events.dropped_total(),
+            input_generation: self.input_generation.load(Ordering::Relaxed),
+            input_dropped: self.input_dropped.load(Ordering::Relaxed),
+            binary_messages_dropped: self.binary.dropped(),
+        }
+    }
+
+    /// ### English
+    /// Copies the URL of the current page into `buf` as a NUL-terminated string (truncated if needed)
+    /// and returns its full length in bytes.
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer (may be empty).
+    ///
+    /// ### 中文
+    /// 将当前页面的 URL 以 NUL 结尾字符串复制到 `buf`（必要时截断），并返回其完整字节长度。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区（可为空）。
+    pub fn copy_url(&self, buf: &mut [u8]) -> usize {
+        self.strings.copy_url(buf)
+    }
+
+    /// ### English
+    /// Copies the title of the current page into `buf` as a NUL-terminated string (truncated if needed)
+    /// and returns its full length in bytes.
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer (may be empty).
+    ///
+    /// ### 中文
+    /// 将当前页面的标题以 NUL 结尾字符串复制到 `buf`（必要时截断），并返回其完整字节长度。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区（可为空）。
+    pub fn copy_title(&self, buf: &mut [u8]) -> usize {
+        self.strings.copy_title(buf)
+    }
+
+    /// ### English
+    /// Returns the page scroll offset `(x, y)` in CSS px, as last reported by the page.
+    ///
+    /// The snapshot is updated asynchronously whenever the page scrolls, so it may lag a scroll that
+    /// is still being processed; it keeps its value while the view is hidden.
+    ///
+    /// ### 中文
+    /// 返回页面最近上报的滚动偏移 `(x, y)`（CSS px）。
+    ///
+    /// 快照会在页面滚动时异步更新，因此可能落后于仍在处理中的滚动；view 隐藏期间保持原值。
+    pub fn scroll(&self) -> (f32, f32) {
+        self.strings.scroll()
+    }
+
+    /// ### English
+    /// Returns a session snapshot of the current page (URL and scroll offset) taken from the
+    /// published snapshots, without a round trip to the Servo thread. `entries` is empty before the
+    /// first navigation commits.
+    ///
+    /// ### 中文
+    /// 基于已发布的快照返回当前页面的会话快照（URL 与滚动偏移），无需与 Servo 线程往返。首次导航提交之前
+    /// `entries` 为空。
+    pub(super) fn session_snapshot(&self) -> SessionSnapshot {
+        let url = self.strings.url();
+        let (scroll_x, scroll_y) = self.strings.scroll();
+        SessionSnapshot {
+            entries: (!url.is_empty())
+                .then(|| url.to_string())
+                .into_iter()
+                .collect(),
+            current: 0,
+            scroll_x: f64::from(scroll_x),
+            scroll_y: f64::from(scroll_y),
+        }
+    }
+
+    /// ### English
+    /// Returns the `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the focused editable element (`NONE` if
+    /// there is none) and its `[x, y, width, height]` in view pixels, as last reported by Servo.
+    ///
+    /// ### 中文
+    /// 返回 Servo 最近上报的获得焦点可编辑元素的 `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*`（没有时为 `NONE`）及其
+    /// view 像素下的 `[x, y, width, height]`。
+    pub fn text_input(&self) -> (u32, [i32; 4]) {
+        self.strings.text_input()
+    }
+
+    /// ### English
+    /// Returns the number of in-flight network requests of the page (HTTP(S) subresources, plus one
+    /// while the main document is loading).
+    ///
+    /// ### 中文
+    /// 返回页面进行中的网络请求数（HTTP(S) 子资源；主文档加载期间另计一个）。
+    pub fn in_flight_requests(&self) -> u32 {
+        self.strings.in_flight()
+    }
+
+    /// ### English
+    /// Scrolls the page to `(x, y)` in CSS px.
+    ///
+    /// Returns `false` if a coordinate is not finite or the engine is shutting down.
+    ///
+    /// #### Parameters
+    /// - `x`: Horizontal offset (CSS px).
+    /// - `y`: Vertical offset (CSS px).
+    /// - `smooth`: Animate the scroll instead of jumping.
+    ///
+    /// ### 中文
+    /// 将页面滚动到 `(x, y)`（CSS px）。
+    ///
+    /// 若坐标非有限值或引擎正在关闭，则返回 `false`。
+    ///
+    /// #### 参数
+    /// - `x`：水平偏移（CSS px）。
+    /// - `y`：垂直偏移（CSS px）。
+    /// - `smooth`：是否以动画滚动而非直接跳转。
+    pub fn set_scroll(&self, x: f32, y: f32, smooth: bool) -> bool {
+        if !x.is_finite() || !y.is_finite() {
+            return false;
+        }
+        self.send_view_command(ViewCommand::SetScroll { x, y, smooth })
+    }
+
+    /// ### English
+    /// Copies the last error message of this view into `buf` as a NUL-terminated string (truncated if
+    /// needed) and returns its full length in bytes.
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer (may be empty).
+    ///
+    /// ### 中文
+    /// 将该 view 的最近错误消息以 NUL 结尾字符串复制到 `buf`（必要时截断），并返回其完整字节长度。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区（可为空）。
+    pub fn copy_last_error(&self, buf: &mut [u8]) -> usize {
+        self.strings.copy_last_error(buf)
+    }
+
+    /// ### English
+    /// Tries to acquire the latest READY frame (consumer-side).
+    ///
+    /// ### 中文
+    /// 尝试 acquire 最新 READY 帧（消费者侧）。
+    pub fn acquire_frame(&self) -> Option<AcquiredFrame> {
+        if self.closed.load(Ordering::Acquire) {
+            return None;
+        }
+        self.shared.try_acquire_front()
+    }
+
+    /// ### English
+    /// Returns the view's triple-buffer state (used by the compositor to track held frames).
+    ///
+    /// ### 中文
+    /// 返回该 view 的三缓冲状态（合成器用它跟踪持有的帧）。
+    pub(super) fn frame_state(&self) -> &Arc<SharedFrameState> {
+        &self.shared
+    }
+
+    /// ### English
+    /// Returns whether released slots should carry a consumer fence (`false` in
+    /// `unsafe_no_consumer_fence` mode).
+    ///
+    /// ### 中文
+    /// 返回释放槽位时是否应附带 consumer fence（`unsafe_no_consumer_fence` 模式下为 `false`）。
+    pub(super) fn records_consumer_fence(&self) -> bool {
+        !self.unsafe_no_consumer_fence
+    }
+
+    /// ### English
+    /// Installs (or removes, with `None`) the callback invoked on the Servo thread after each frame
+    /// is published.
+    ///
+    /// #### Parameters
+    /// - `callback`: Frame callback, or `None` to remove it.
+    /// - `user_data`: Opaque pointer passed back to `callback`.
+    ///
+    /// ### 中文
+    /// 安装（或以 `None` 移除）每帧发布后在 Servo 线程上调用的回调。
+    ///
+    /// #### 参数
+    /// - `callback`：帧回调；`None` 表示移除。
+    /// - `user_data`：回传给 `callback` 的不透明指针。
+    pub fn set_frame_callback(
+        &self,
+        callback: Option<XianWebEngineFrameFn>,
+        user_data: *mut c_void,
+    ) {
+        self.shared.set_frame_callback(callback, user_data);
+    }
+
+    /// ### English
+    /// Marks this view active/inactive and applies hide/throttle on Servo thread.
+    ///
+    /// Returns `true` iff this call changes the active state and the caller should wake the Servo
+    /// thread (see [`Self::wake`]).
+    ///
+    /// #### Parameters
+    /// - `active`: Whether the view should be active.
+    ///
+    /// ### 中文
+    /// 设置该 view 的 active/inactive，并在 Servo 线程应用 hide/throttle。
+    ///
+    /// 仅当返回 `true` 时表示 active 状态发生变化，且建议唤醒 Servo 线程（见 [`Self::wake`]）。
+    ///
+    /// #### 参数
+    /// - `active`：是否将该 view 设为 active。
+    #[must_use = "returns whether the caller should wake the Servo thread"]
+    pub fn set_active(&self, active: bool) -> bool {
+        if self.shared.is_active() == active {
+            return false;
+        }
+
+        self.shared.set_active(active);
+        self.mark_pending(PENDING_ACTIVE)
+    }
+
+    /// ### English
+    /// Releases a previously acquired slot, optionally recording a consumer fence.
+    ///
+    /// If the view is in `unsafe_no_consumer_fence` mode, the fence value is ignored (treated as 0).
+    /// Releasing the pinned snapshot frame with a fence wakes the Servo thread, which makes the
+    /// frame READY again once the fence signals.
+    ///
+    /// #### Parameters
+    /// - `slot`: Triple-buffer slot index (0..=2).
+    /// - `consumer_fence`: Consumer fence handle (`GLsync` cast to `u64`), or 0 to skip.
+    ///
+    /// ### 中文
+    /// 释放之前 acquire 的槽位，并可选记录 consumer fence。
+    ///
+    /// 若 view 处于 `unsafe_no_consumer_fence` 模式，则 fence 会被忽略（视为 0）。
+    /// 附带 fence 释放被固定的快照帧会唤醒 Servo 线程，由其在 fence signal 后使该帧重新变为 READY。
+    ///
+    /// #### 参数
+    /// - `slot`：三缓冲槽位索引（0..=2）。
+    /// - `consumer_fence`：consumer fence 句柄（`GLsync` 转 `u64`），为 0 则跳过。
+    pub fn release_slot_with_fence(&self, slot: u32, consumer_fence: u64) {
+        let slot = slot as usize;
+        if slot >= TRIPLE_BUFFER_COUNT {
+            return;
+        }
+
+        if self.unsafe_no_consumer_fence || consumer_fence == 0 {
+            self.shared.release_slot(slot, 0);
+            return;
+        }
+        self.shared.release_slot(slot, consumer_fence);
+        if self.shared.snapshot_slot() == Some(slot) {
+            self.wake();
+        }
+    }
+
+    /// ### English
+    /// Pins a frame held by the consumer so it can be kept across many frames; returns whether the
+    /// slot was held. The producer keeps rendering double-buffered on the other two slots.
+    ///
+    /// #### Parameters
+    /// - `slot`: Triple-buffer slot index (0..=2) of an acquired frame.
+    ///
+    /// ### 中文
+    /// 固定一个被消费者持有的帧，使其可以跨多帧保留；返回该槽位是否处于持有状态。生产者在另外两个槽位上继续以
+    /// 双缓冲方式渲染。
+    ///
+    /// #### 参数
+    /// - `slot`：已 acquire 帧的三缓冲槽位索引（0..=2）。
+    pub fn pin_frame(&self, slot: u32) -> bool {
+        self.shared.pin_slot(slot as usize)
+    }
+
+    /// ### English
+    /// Unpins a frame pinned by [`Self::pin_frame`]; returns whether the slot was pinned. The frame
+    /// is held again and must be released as usual.
+    ///
+    /// #### Parameters
+    /// - `slot`: Triple-buffer slot index (0..=2) of a pinned frame.
+    ///
+    /// ### 中文
+    /// 取消由 [`Self::pin_frame`] 固定的帧；返回该槽位是否处于固定状态。该帧重新处于持有状态，必须照常释放。
+    ///
+    /// #### 参数
+    /// - `slot`：被固定帧的三缓冲槽位索引（0..=2）。
+    pub fn unpin_frame(&self, slot: u32) -> bool {
+        self.shared.unpin_slot(slot as usize)
+    }
+
+    /// ### English
+    /// Returns the Vulkan import description of `slot`.
+    ///
+    /// Returns `None` if the view was not created with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` or
+    /// the slot has no image yet.
+    ///
+    /// #### Parameters
+    /// - `slot`: Triple-buffer slot index (0..=2).
+    ///
+    /// ### 中文
+    /// 返回 `slot` 的 Vulkan 导入描述。
+    ///
+    /// 若 view 创建时未使用 `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`，或该槽位尚无 image，则返回 `None`。
+    ///
+    /// #### 参数
+    /// - `slot`：三缓冲槽位索引（0..=2）。
+    pub fn vulkan_slot(&self, slot: u32) -> Option<XianWebEngineVulkanSlot> {
+        self.vulkan_exports.as_ref()?.get(slot as usize)
+    }
+}
+
+impl Drop for WebEngineViewHandle {
+    /// ### English
+    /// Closes the view (see [`WebEngineViewHandle::close`]) unless it was already closed.
+    ///
+    /// ### 中文
+    /// 关闭该 view（见 [`WebEngineViewHandle::close`]），若已关闭则不做任何事。
+    fn drop(&mut self) {
+        self.close();
     }
 }
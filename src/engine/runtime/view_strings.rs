@@ -0,0 +1,424 @@
+//! ### English
+//! Per-view string snapshots (current URL, title, last error), the page scroll offset and network
+//! activity, readable from any thread.
+//!
+//! ### 中文
+//! 每 view 的字符串快照（当前 URL、标题、最近错误）、页面滚动偏移以及网络活动，可在任意线程读取。
+
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::events::utf8_floor;
+
+/// ### English
+/// Latest value of one string, published as an immutable `Arc<str>`.
+///
+/// Writers swap in a new `Arc` (allocating only when the value actually changes); readers clone the
+/// current `Arc` under a lock held just for the refcount increment, so reading never allocates and
+/// never blocks on a copy in progress.
+///
+/// ### 中文
+/// 某个字符串的最新值，以不可变 `Arc<str>` 发布。
+///
+/// 写端替换为新的 `Arc`（仅在值确实变化时分配）；读端在仅覆盖引用计数递增的锁内克隆当前 `Arc`，
+/// 因此读取从不分配，也不会被正在进行的复制阻塞。
+struct StringSnapshot {
+    /// ### English
+    /// Current value (empty until first set).
+    ///
+    /// ### 中文
+    /// 当前值（首次设置前为空）。
+    value: Mutex<Arc<str>>,
+}
+
+impl Default for StringSnapshot {
+    /// ### English
+    /// Creates an empty snapshot.
+    ///
+    /// ### 中文
+    /// 创建一个空快照。
+    fn default() -> Self {
+        Self {
+            value: Mutex::new(Arc::from("")),
+        }
+    }
+}
+
+impl StringSnapshot {
+    /// ### English
+    /// Publishes `value` (no-op if it equals the current value).
+    ///
+    /// #### Parameters
+    /// - `value`: New value.
+    ///
+    /// ### 中文
+    /// 发布 `value`（与当前值相同时不做任何操作）。
+    ///
+    /// #### 参数
+    /// - `value`：新值。
+    fn store(&self, value: &str) {
+        let Ok(mut current) = self.value.lock() else {
+            return;
+        };
+        if **current != *value {
+            *current = Arc::from(value);
+        }
+    }
+
+    /// ### English
+    /// Returns the current value.
+    ///
+    /// ### 中文
+    /// 返回当前值。
+    fn load(&self) -> Arc<str> {
+        match self.value.lock() {
+            Ok(current) => current.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// ### English
+    /// Copies the current value into `buf` and returns its full length in bytes (see
+    /// `copy_to_buffer`).
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer (may be empty).
+    ///
+    /// ### 中文
+    /// 将当前值复制到 `buf`，并返回其完整字节长度（见 `copy_to_buffer`）。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区（可为空）。
+    fn copy_to(&self, buf: &mut [u8]) -> usize {
+        copy_to_buffer(&self.load(), buf)
+    }
+}
+
+/// ### English
+/// Copies `text` into `buf` as a NUL-terminated string and returns the length of `text` in bytes
+/// (excluding the NUL).
+///
+/// If `text` does not fit, the longest UTF-8 prefix that leaves room for the NUL is written instead;
+/// callers detect this by comparing the result with `buf.len()`. Nothing is written into an empty
+/// `buf`.
+///
+/// #### Parameters
+/// - `text`: Source string.
+/// - `buf`: Output buffer.
+///
+/// ### 中文
+/// 将 `text` 以 NUL 结尾字符串的形式复制到 `buf`，并返回 `text` 的字节长度（不含 NUL）。
+///
+/// 若放不下，则写入能为 NUL 留出空间的最长 UTF-8 前缀；调用方可通过比较返回值与 `buf.len()` 判断截断。
+/// `buf` 为空时不写入任何内容。
+///
+/// #### 参数
+/// - `text`：源字符串。
+/// - `buf`：输出缓冲区。
+fn copy_to_buffer(text: &str, buf: &mut [u8]) -> usize {
+    let text = text.as_bytes();
+    let Some(room) = buf.len().checked_sub(1) else {
+        return text.len();
+    };
+    let len = utf8_floor(text, room);
+    buf[..len].copy_from_slice(&text[..len]);
+    buf[len] = 0;
+    text.len()
+}
+
+/// ### English
+/// String snapshots (and the scroll offset) of one view, shared between the Servo thread (writer)
+/// and the view handle.
+///
+/// ### 中文
+/// 单个 view 的字符串快照（以及滚动偏移），由 Servo 线程（写端）与 view 句柄共享。
+#[derive(Default)]
+pub(super) struct ViewStrings {
+    /// ### English
+    /// URL of the current page.
+    ///
+    /// ### 中文
+    /// 当前页面的 URL。
+    url: StringSnapshot,
+    /// ### English
+    /// Title of the current page (empty if it has none).
+    ///
+    /// ### 中文
+    /// 当前页面的标题（无标题时为空）。
+    title: StringSnapshot,
+    /// ### English
+    /// Message of the most recent failure reported for the view.
+    ///
+    /// ### 中文
+    /// 该 view 最近一次失败的消息。
+    last_error: StringSnapshot,
+    /// ### English
+    /// Page scroll offset in CSS px, packed as two `f32` bit patterns (`x` high, `y` low) so both
+    /// axes are read together.
+    ///
+    /// ### 中文
+    /// 页面滚动偏移（CSS px），打包为两个 `f32` 位模式（高位 `x`、低位 `y`），使两个轴同时读取。
+    scroll: AtomicU64,
+    /// ### English
+    /// Whether the main document is loading (between `LoadStatus::Started` and `Complete`).
+    ///
+    /// ### 中文
+    /// 主文档是否正在加载（处于 `LoadStatus::Started` 与 `Complete` 之间）。
+    document_loading: AtomicBool,
+    /// ### English
+    /// Subresource requests let through `load_web_resource` and not yet reported finished.
+    ///
+    /// ### 中文
+    /// 经 `load_web_resource` 放行、尚未报告完成的子资源请求数。
+    requests: AtomicU32,
+    /// ### English
+    /// `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the focused editable element (`NONE` = none).
+    ///
+    /// ### 中文
+    /// 获得焦点的可编辑元素的 `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*`（`NONE` 表示没有）。
+    text_input_type: AtomicU32,
+    /// ### English
+    /// Rectangle of the focused editable element in view pixels, packed as four 16-bit fields
+    /// (`x`, `y` signed; `width`, `height` unsigned; `x` highest) so it is read in one load.
+    ///
+    /// ### 中文
+    /// 获得焦点的可编辑元素的矩形（view 像素），打包为四个 16 位字段（`x`、`y` 有符号；`width`、`height`
+    /// 无符号；`x` 在最高位），使其可以一次读取。
+    text_input_rect: AtomicU64,
+}
+
+impl ViewStrings {
+    /// ### English
+    /// Records the URL of the current page.
+    ///
+    /// #### Parameters
+    /// - `url`: Page URL.
+    ///
+    /// ### 中文
+    /// 记录当前页面的 URL。
+    ///
+    /// #### 参数
+    /// - `url`：页面 URL。
+    pub(super) fn set_url(&self, url: &str) {
+        self.url.store(url);
+    }
+
+    /// ### English
+    /// Records the title of the current page.
+    ///
+    /// #### Parameters
+    /// - `title`: Page title (empty if the page has none).
+    ///
+    /// ### 中文
+    /// 记录当前页面的标题。
+    ///
+    /// #### 参数
+    /// - `title`：页面标题（无标题时为空）。
+    pub(super) fn set_title(&self, title: &str) {
+        self.title.store(title);
+    }
+
+    /// ### English
+    /// Records the message of a failure.
+    ///
+    /// #### Parameters
+    /// - `message`: Failure description.
+    ///
+    /// ### 中文
+    /// 记录一次失败的消息。
+    ///
+    /// #### 参数
+    /// - `message`：失败描述。
+    pub(super) fn set_last_error(&self, message: &str) {
+        self.last_error.store(message);
+    }
+
+    /// ### English
+    /// Records the page scroll offset.
+    ///
+    /// #### Parameters
+    /// - `x`: Horizontal offset (CSS px).
+    /// - `y`: Vertical offset (CSS px).
+    ///
+    /// ### 中文
+    /// 记录页面滚动偏移。
+    ///
+    /// #### 参数
+    /// - `x`：水平偏移（CSS px）。
+    /// - `y`：垂直偏移（CSS px）。
+    pub(super) fn set_scroll(&self, x: f32, y: f32) {
+        let packed = (u64::from(x.to_bits()) << 32) | u64::from(y.to_bits());
+        self.scroll.store(packed, Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Returns the last reported page scroll offset `(x, y)` in CSS px.
+    ///
+    /// ### 中文
+    /// 返回最近上报的页面滚动偏移 `(x, y)`（CSS px）。
+    pub(super) fn scroll(&self) -> (f32, f32) {
+        let packed = self.scroll.load(Ordering::Relaxed);
+        (
+            f32::from_bits((packed >> 32) as u32),
+            f32::from_bits(packed as u32),
+        )
+    }
+
+    /// ### English
+    /// Records the focused editable element; the rectangle is stored before the type, so a reader
+    /// that sees the type also sees its rectangle.
+    ///
+    /// #### Parameters
+    /// - `input_type`: `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` (`NONE` when focus left it).
+    /// - `rect`: `[x, y, width, height]` in view pixels (saturated to 16 bits).
+    ///
+    /// ### 中文
+    /// 记录获得焦点的可编辑元素；矩形先于类型写入，因此读到该类型的读取方也能读到其矩形。
+    ///
+    /// #### 参数
+    /// - `input_type`：`XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*`（焦点离开时为 `NONE`）。
+    /// - `rect`：view 像素下的 `[x, y, width, height]`（饱和到 16 位）。
+    pub(super) fn set_text_input(&self, input_type: u32, rect: [i32; 4]) {
+        let [x, y, width, height] = rect;
+        let signed = |value: i32| u64::from(value.clamp(i16::MIN.into(), i16::MAX.into()) as u16);
+        let unsigned = |value: i32| u64::from(value.clamp(0, u16::MAX.into()) as u16);
+        let packed =
+            (signed(x) << 48) | (signed(y) << 32) | (unsigned(width) << 16) | unsigned(height);
+        self.text_input_rect.store(packed, Ordering::Relaxed);
+        self.text_input_type.store(input_type, Ordering::Release);
+    }
+
+    /// ### English
+    /// Returns the focused editable element's type and `[x, y, width, height]` in view pixels.
+    ///
+    /// ### 中文
+    /// 返回获得焦点的可编辑元素的类型与 view 像素下的 `[x, y, width, height]`。
+    pub(super) fn text_input(&self) -> (u32, [i32; 4]) {
+        let input_type = self.text_input_type.load(Ordering::Acquire);
+        let packed = self.text_input_rect.load(Ordering::Relaxed);
+        let rect = [
+            i32::from((packed >> 48) as u16 as i16),
+            i32::from((packed >> 32) as u16 as i16),
+            i32::from((packed >> 16) as u16),
+            i32::from(packed as u16),
+        ];
+        (input_type, rect)
+    }
+
+    /// ### English
+    /// Records whether the main document is loading; a new load also resets the request count of
+    /// the previous document.
+    ///
+    /// #### Parameters
+    /// - `loading`: Whether a document load is in progress.
+    ///
+    /// ### 中文
+    /// 记录主文档是否正在加载；新的加载还会重置上一个文档的请求计数。
+    ///
+    /// #### 参数
+    /// - `loading`：是否有文档加载正在进行。
+    pub(super) fn set_document_loading(&self, loading: bool) {
+        if loading {
+            self.requests.store(0, Ordering::Relaxed);
+        }
+        self.document_loading.store(loading, Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Counts a subresource request that started.
+    ///
+    /// ### 中文
+    /// 记录一个已开始的子资源请求。
+    pub(super) fn begin_request(&self) {
+        let _ = self
+            .requests
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |requests| {
+                Some(requests.saturating_add(1))
+            });
+    }
+
+    /// ### English
+    /// Counts subresource requests that finished (the count never drops below zero).
+    ///
+    /// #### Parameters
+    /// - `finished`: Number of finished requests.
+    ///
+    /// ### 中文
+    /// 记录已完成的子资源请求（计数不会低于零）。
+    ///
+    /// #### 参数
+    /// - `finished`：已完成的请求数。
+    pub(super) fn finish_requests(&self, finished: u32) {
+        let _ = self
+            .requests
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |requests| {
+                Some(requests.saturating_sub(finished))
+            });
+    }
+
+    /// ### English
+    /// Returns the in-flight request count (the loading main document counts as one).
+    ///
+    /// ### 中文
+    /// 返回进行中的请求数（正在加载的主文档计为一个）。
+    pub(super) fn in_flight(&self) -> u32 {
+        let document = u32::from(self.document_loading.load(Ordering::Relaxed));
+        self.requests
+            .load(Ordering::Relaxed)
+            .saturating_add(document)
+    }
+
+    /// ### English
+    /// Copies the current URL into `buf` (see `copy_to_buffer`).
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer.
+    ///
+    /// ### 中文
+    /// 将当前 URL 复制到 `buf`（见 `copy_to_buffer`）。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区。
+    pub(super) fn copy_url(&self, buf: &mut [u8]) -> usize {
+        self.url.copy_to(buf)
+    }
+
+    /// ### English
+    /// Returns the current URL (empty before the first navigation commits).
+    ///
+    /// ### 中文
+    /// 返回当前 URL（首次导航提交之前为空）。
+    pub(super) fn url(&self) -> Arc<str> {
+        self.url.load()
+    }
+
+    /// ### English
+    /// Copies the current title into `buf` (see `copy_to_buffer`).
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer.
+    ///
+    /// ### 中文
+    /// 将当前标题复制到 `buf`（见 `copy_to_buffer`）。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区。
+    pub(super) fn copy_title(&self, buf: &mut [u8]) -> usize {
+        self.title.copy_to(buf)
+    }
+
+    /// ### English
+    /// Copies the last error message into `buf` (see `copy_to_buffer`).
+    ///
+    /// #### Parameters
+    /// - `buf`: Output buffer.
+    ///
+    /// ### 中文
+    /// 将最近错误消息复制到 `buf`（见 `copy_to_buffer`）。
+    ///
+    /// #### 参数
+    /// - `buf`：输出缓冲区。
+    pub(super) fn copy_last_error(&self, buf: &mut [u8]) -> usize {
+        self.last_error.copy_to(buf)
+    }
+}
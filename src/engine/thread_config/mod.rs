@@ -0,0 +1,104 @@
+//! ### English
+//! Thread configuration: Servo worker pool sizes, and scheduling controls (priority / CPU affinity)
+//! for engine-owned threads.
+//!
+//! Scheduling controls are only implemented on Windows; other targets accept them and ignore them.
+//!
+//! ### 中文
+//! 线程配置：Servo 工作线程池大小，以及引擎自有线程的调度控制（优先级 / CPU 亲和性）。
+//!
+//! 调度控制目前仅在 Windows 上实现；其他平台接受配置但忽略。
+#[cfg(windows)]
+mod windows;
+
+#[cfg(not(windows))]
+mod stub;
+
+#[cfg(windows)]
+pub(crate) use windows::apply_to_current_thread;
+
+#[cfg(not(windows))]
+pub(crate) use stub::apply_to_current_thread;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// ### English
+/// Scheduling settings applied to the Servo thread and the refresh scheduler thread.
+///
+/// ### 中文
+/// 应用到 Servo 线程与 refresh 调度线程的调度设置。
+pub(crate) struct ThreadConfig {
+    /// ### English
+    /// OS thread priority (Windows `THREAD_PRIORITY_*`, `0` = normal).
+    ///
+    /// ### 中文
+    /// 操作系统线程优先级（Windows `THREAD_PRIORITY_*`，`0` 表示普通）。
+    pub priority: i32,
+    /// ### English
+    /// CPU affinity bitmask (bit N = logical CPU N; `0` keeps the current affinity).
+    ///
+    /// ### 中文
+    /// CPU 亲和性位掩码（第 N 位对应逻辑 CPU N；`0` 表示保持当前亲和性）。
+    pub affinity_mask: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// ### English
+/// Per-pool worker thread counts for Servo, passed at engine creation.
+///
+/// Every field is optional: `0` falls back to the engine-wide `thread_pool_cap` default.
+///
+/// ### 中文
+/// Servo 各线程池的工作线程数，在创建引擎时传入。
+///
+/// 每个字段均可选：`0` 表示回退到引擎级 `thread_pool_cap` 默认值。
+pub struct XianWebEngineThreadPoolConfig {
+    /// ### English
+    /// Parallel layout/style (Stylo) workers.
+    ///
+    /// ### 中文
+    /// 并行 layout/style（Stylo）工作线程数。
+    pub layout_threads: u32,
+    /// ### English
+    /// Async runtime workers (script-side fetches, timers and other async tasks).
+    ///
+    /// ### 中文
+    /// 异步运行时工作线程数（脚本侧 fetch、定时器等异步任务）。
+    pub async_runtime_threads: u32,
+    /// ### English
+    /// Network resource workers.
+    ///
+    /// ### 中文
+    /// 网络资源工作线程数。
+    pub resource_threads: u32,
+    /// ### English
+    /// Image decoding workers.
+    ///
+    /// ### 中文
+    /// 图片解码工作线程数。
+    pub image_cache_threads: u32,
+    /// ### English
+    /// WebRender workers (scene building / rasterization).
+    ///
+    /// ### 中文
+    /// WebRender 工作线程数（场景构建/光栅化）。
+    pub webrender_threads: u32,
+    /// ### English
+    /// Storage workers (IndexedDB and Web Storage).
+    ///
+    /// ### 中文
+    /// 存储工作线程数（IndexedDB 与 Web Storage）。
+    pub storage_threads: u32,
+    /// ### English
+    /// Servo threads that views are sharded across (`0` = one).
+    ///
+    /// Each thread runs its own Servo instance (with its own worker pools sized as above) and its own
+    /// shared offscreen GL context, so a heavy page only stalls the views on the same thread.
+    ///
+    /// ### 中文
+    /// 用于分片承载 view 的 Servo 线程数（`0` 表示一个）。
+    ///
+    /// 每个线程运行独立的 Servo 实例（其工作线程池大小同上）以及独立的共享离屏 GL 上下文，
+    /// 因此繁重的页面只会拖慢同一线程上的 view。
+    pub servo_threads: u32,
+}
@@ -0,0 +1,22 @@
+//! ### English
+//! Non-Windows placeholder implementation of thread scheduling controls.
+//!
+//! ### 中文
+//! 线程调度控制的非 Windows 占位实现。
+
+use super::ThreadConfig;
+
+/// ### English
+/// Not supported on non-Windows builds; always returns `false`.
+///
+/// #### Parameters
+/// - `_config`: Scheduling settings (ignored on this stub implementation).
+///
+/// ### 中文
+/// 非 Windows 构建下不支持；总是返回 `false`。
+///
+/// #### 参数
+/// - `_config`：调度设置（该占位实现中忽略）。
+pub(crate) fn apply_to_current_thread(_config: ThreadConfig) -> bool {
+    false
+}
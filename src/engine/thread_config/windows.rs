@@ -0,0 +1,52 @@
+//! ### English
+//! Windows implementation of thread scheduling controls (`SetThreadPriority` /
+//! `SetThreadAffinityMask`).
+//!
+//! ### 中文
+//! 线程调度控制的 Windows 实现（`SetThreadPriority` / `SetThreadAffinityMask`）。
+
+use std::ffi::c_void;
+
+use super::ThreadConfig;
+
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    /// ### English
+    /// Returns a pseudo handle for the calling thread.
+    ///
+    /// ### 中文
+    /// 返回调用线程的伪句柄。
+    fn GetCurrentThread() -> *mut c_void;
+    /// ### English
+    /// Sets the priority of a thread; returns non-zero on success.
+    ///
+    /// ### 中文
+    /// 设置线程优先级；成功时返回非 0。
+    fn SetThreadPriority(thread: *mut c_void, priority: i32) -> i32;
+    /// ### English
+    /// Sets the CPU affinity of a thread; returns the previous mask, or 0 on failure.
+    ///
+    /// ### 中文
+    /// 设置线程的 CPU 亲和性；返回之前的掩码，失败时返回 0。
+    fn SetThreadAffinityMask(thread: *mut c_void, mask: usize) -> usize;
+}
+
+/// ### English
+/// Applies `config` to the calling thread; returns `false` if the OS rejected any setting.
+///
+/// #### Parameters
+/// - `config`: Scheduling settings.
+///
+/// ### 中文
+/// 将 `config` 应用到调用线程；若操作系统拒绝任一设置则返回 `false`。
+///
+/// #### 参数
+/// - `config`：调度设置。
+pub(crate) fn apply_to_current_thread(config: ThreadConfig) -> bool {
+    let thread = unsafe { GetCurrentThread() };
+    let mut ok = unsafe { SetThreadPriority(thread, config.priority) } != 0;
+    if config.affinity_mask != 0 {
+        ok &= unsafe { SetThreadAffinityMask(thread, config.affinity_mask as usize) } != 0;
+    }
+    ok
+}
@@ -0,0 +1,338 @@
+//! ### English
+//! Lightweight tracing spans for diagnosing frame hitches end-to-end.
+//!
+//! Spans (command drain, `spin_event_loop`, paint, present, acquire) are recorded into a fixed,
+//! process-wide lock-free ring that keeps the most recent [`RING_CAPACITY`] spans, and can be
+//! exported in the Chrome trace event format (`chrome://tracing`, Perfetto).
+//!
+//! ### 中文
+//! 用于端到端诊断掉帧的轻量级追踪 span。
+//!
+//! span（命令 drain、`spin_event_loop`、paint、present、acquire）会记录到一个固定大小、进程级的无锁环形缓冲区，
+//! 其中保留最近的 [`RING_CAPACITY`] 个 span，并可导出为 Chrome trace 事件格式（`chrome://tracing`、Perfetto）。
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering, fence};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Instant;
+
+/// ### English
+/// Servo thread draining the engine command queue.
+///
+/// ### 中文
+/// Servo 线程 drain 引擎命令队列。
+pub(crate) const TRACE_SPAN_COMMAND_DRAIN: u32 = 0;
+
+/// ### English
+/// Servo thread running `Servo::spin_event_loop`.
+///
+/// ### 中文
+/// Servo 线程执行 `Servo::spin_event_loop`。
+pub(crate) const TRACE_SPAN_SPIN_EVENT_LOOP: u32 = 1;
+
+/// ### English
+/// WebRender painting a view into its back slot.
+///
+/// ### 中文
+/// WebRender 将 view 绘制到其 back 槽位。
+pub(crate) const TRACE_SPAN_PAINT: u32 = 2;
+
+/// ### English
+/// Publishing a painted back slot as READY.
+///
+/// ### 中文
+/// 将已绘制的 back 槽位发布为 READY。
+pub(crate) const TRACE_SPAN_PRESENT: u32 = 3;
+
+/// ### English
+/// Embedder acquiring a frame (consumer side).
+///
+/// ### 中文
+/// 宿主 acquire 一帧（消费者侧）。
+pub(crate) const TRACE_SPAN_ACQUIRE: u32 = 4;
+
+/// ### English
+/// Event names of the `TRACE_SPAN_*` kinds, indexed by kind.
+///
+/// ### 中文
+/// 各 `TRACE_SPAN_*` 种类的事件名，按种类索引。
+const SPAN_NAMES: [&str; 5] = [
+    "command_drain",
+    "spin_event_loop",
+    "paint",
+    "present",
+    "acquire",
+];
+
+/// ### English
+/// Number of spans kept in the ring (older spans are overwritten).
+///
+/// ### 中文
+/// 环形缓冲区中保留的 span 数量（更旧的 span 会被覆盖）。
+const RING_CAPACITY: usize = 16 * 1024;
+
+/// ### English
+/// One ring entry. `stamp` works as a sequence lock: `0` while the entry is being written, otherwise
+/// the ring position plus one, so readers can skip torn entries.
+///
+/// ### 中文
+/// 环形缓冲区中的一个条目。`stamp` 充当顺序锁：写入期间为 `0`，否则为环形位置加一，读者据此跳过被撕裂的条目。
+struct TraceRecord {
+    /// ### English
+    /// Sequence stamp (`0` = empty or being written).
+    ///
+    /// ### 中文
+    /// 顺序戳（`0` 表示空或正在写入）。
+    stamp: AtomicU64,
+    /// ### English
+    /// Span kind in the high 32 bits, trace thread ID in the low 32 bits.
+    ///
+    /// ### 中文
+    /// 高 32 位为 span 种类，低 32 位为追踪线程 ID。
+    kind_thread: AtomicU64,
+    /// ### English
+    /// Start time in nanoseconds since the trace epoch.
+    ///
+    /// ### 中文
+    /// 相对追踪起点的开始时间（纳秒）。
+    start_ns: AtomicU64,
+    /// ### English
+    /// Duration in nanoseconds.
+    ///
+    /// ### 中文
+    /// 持续时间（纳秒）。
+    duration_ns: AtomicU64,
+}
+
+impl TraceRecord {
+    /// ### English
+    /// Creates an empty entry.
+    ///
+    /// ### 中文
+    /// 创建一个空条目。
+    const fn new() -> Self {
+        Self {
+            stamp: AtomicU64::new(0),
+            kind_thread: AtomicU64::new(0),
+            start_ns: AtomicU64::new(0),
+            duration_ns: AtomicU64::new(0),
+        }
+    }
+}
+
+/// ### English
+/// The span ring.
+///
+/// ### 中文
+/// span 环形缓冲区。
+static RING: [TraceRecord; RING_CAPACITY] = [const { TraceRecord::new() }; RING_CAPACITY];
+
+/// ### English
+/// Next ring position to write.
+///
+/// ### 中文
+/// 下一个要写入的环形位置。
+static NEXT_POSITION: AtomicU64 = AtomicU64::new(0);
+
+/// ### English
+/// Time origin of all spans (set by the first span).
+///
+/// ### 中文
+/// 所有 span 的时间起点（由第一个 span 设置）。
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// ### English
+/// Next trace thread ID (starts at 1).
+///
+/// ### 中文
+/// 下一个追踪线程 ID（从 1 开始）。
+static NEXT_THREAD_ID: AtomicU32 = AtomicU32::new(1);
+
+/// ### English
+/// Names of the threads that recorded spans, keyed by trace thread ID.
+///
+/// ### 中文
+/// 记录过 span 的线程名称，按追踪线程 ID 索引。
+static THREAD_NAMES: Mutex<Vec<(u32, String)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// ### English
+    /// Trace thread ID of the current thread (registered on first use).
+    ///
+    /// ### 中文
+    /// 当前线程的追踪线程 ID（首次使用时注册）。
+    static THREAD_ID: u32 = register_thread();
+}
+
+/// ### English
+/// Assigns a trace thread ID to the current thread and remembers its name.
+///
+/// ### 中文
+/// 为当前线程分配追踪线程 ID，并记录其名称。
+fn register_thread() -> u32 {
+    let id = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+    let name = thread::current()
+        .name()
+        .map_or_else(|| format!("thread-{id}"), str::to_string);
+    if let Ok(mut names) = THREAD_NAMES.lock() {
+        names.push((id, name));
+    }
+    id
+}
+
+/// ### English
+/// A running span; recorded into the ring when dropped.
+///
+/// ### 中文
+/// 一个进行中的 span；drop 时记录到环形缓冲区。
+pub(crate) struct Span {
+    /// ### English
+    /// `TRACE_SPAN_*` kind.
+    ///
+    /// ### 中文
+    /// `TRACE_SPAN_*` 种类。
+    kind: u32,
+    /// ### English
+    /// Start time.
+    ///
+    /// ### 中文
+    /// 开始时间。
+    start: Instant,
+}
+
+/// ### English
+/// Starts a span that ends when the returned guard is dropped.
+///
+/// #### Parameters
+/// - `kind`: `TRACE_SPAN_*` kind.
+///
+/// ### 中文
+/// 开始一个 span，在返回的守卫被 drop 时结束。
+///
+/// #### 参数
+/// - `kind`：`TRACE_SPAN_*` 种类。
+#[inline]
+pub(crate) fn span(kind: u32) -> Span {
+    EPOCH.get_or_init(Instant::now);
+    Span {
+        kind,
+        start: Instant::now(),
+    }
+}
+
+impl Drop for Span {
+    /// ### English
+    /// Records the finished span.
+    ///
+    /// ### 中文
+    /// 记录已结束的 span。
+    fn drop(&mut self) {
+        let Some(epoch) = EPOCH.get() else {
+            return;
+        };
+        let start_ns = self.start.saturating_duration_since(*epoch).as_nanos() as u64;
+        let duration_ns = self.start.elapsed().as_nanos() as u64;
+        let Ok(thread_id) = THREAD_ID.try_with(|id| *id) else {
+            return;
+        };
+
+        let position = NEXT_POSITION.fetch_add(1, Ordering::Relaxed);
+        let record = &RING[(position % RING_CAPACITY as u64) as usize];
+        record.stamp.store(0, Ordering::Relaxed);
+        fence(Ordering::Release);
+        record.kind_thread.store(
+            (u64::from(self.kind) << 32) | u64::from(thread_id),
+            Ordering::Relaxed,
+        );
+        record.start_ns.store(start_ns, Ordering::Relaxed);
+        record.duration_ns.store(duration_ns, Ordering::Relaxed);
+        record.stamp.store(position + 1, Ordering::Release);
+    }
+}
+
+/// ### English
+/// Writes the spans currently in the ring to `path` as a Chrome trace JSON file.
+///
+/// Spans being written concurrently are skipped. Times are in microseconds since the first span.
+///
+/// #### Parameters
+/// - `path`: Output file (created or truncated).
+///
+/// ### 中文
+/// 将环形缓冲区中当前的 span 以 Chrome trace JSON 文件写入 `path`。
+///
+/// 正被并发写入的 span 会被跳过。时间单位为微秒，起点为第一个 span。
+///
+/// #### 参数
+/// - `path`：输出文件（会被创建或截断）。
+pub(crate) fn dump(path: &Path) -> io::Result<()> {
+    let mut spans = Vec::new();
+    for record in &RING {
+        let stamp = record.stamp.load(Ordering::Acquire);
+        if stamp == 0 {
+            continue;
+        }
+        let kind_thread = record.kind_thread.load(Ordering::Relaxed);
+        let start_ns = record.start_ns.load(Ordering::Relaxed);
+        let duration_ns = record.duration_ns.load(Ordering::Relaxed);
+        fence(Ordering::Acquire);
+        if record.stamp.load(Ordering::Relaxed) != stamp {
+            continue;
+        }
+        spans.push((start_ns, duration_ns, kind_thread));
+    }
+    spans.sort_unstable_by_key(|&(start_ns, ..)| start_ns);
+
+    let thread_names = THREAD_NAMES
+        .lock()
+        .map(|names| names.clone())
+        .unwrap_or_default();
+    let pid = std::process::id();
+
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(b"{\"traceEvents\":[")?;
+    let mut first = true;
+    for (thread_id, name) in &thread_names {
+        if !first {
+            out.write_all(b",")?;
+        }
+        first = false;
+        let name: String = name
+            .chars()
+            .map(|c| {
+                if c == '"' || c == '\\' || c.is_control() {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .collect();
+        write!(
+            out,
+            "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":{pid},\"tid\":{thread_id},\"args\":{{\"name\":\"{name}\"}}}}"
+        )?;
+    }
+    for (start_ns, duration_ns, kind_thread) in spans {
+        let Some(name) = SPAN_NAMES.get((kind_thread >> 32) as usize) else {
+            continue;
+        };
+        if !first {
+            out.write_all(b",")?;
+        }
+        first = false;
+        write!(
+            out,
+            "{{\"name\":\"{name}\",\"cat\":\"xian\",\"ph\":\"X\",\"ts\":{}.{:03},\"dur\":{}.{:03},\"pid\":{pid},\"tid\":{}}}",
+            start_ns / 1000,
+            start_ns % 1000,
+            duration_ns / 1000,
+            duration_ns % 1000,
+            kind_thread as u32
+        )?;
+    }
+    out.write_all(b"],\"displayTimeUnit\":\"ms\"}")?;
+    out.flush()
+}
@@ -19,5 +19,7 @@ type VsyncCallback = Box<dyn Fn() + Send + 'static>;
 
 mod overflow;
 mod queue;
+mod timing;
 
 pub use queue::VsyncCallbackQueue;
+pub use timing::{MAX_REFRESH_RATE_HZ, VsyncTiming};
@@ -15,8 +15,8 @@ use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
 
 use crate::engine::cache::{pad_after, pad_after3};
 
-use super::VsyncCallback;
 use super::overflow::{VsyncCallbackNode, drop_vsync_list, drop_vsync_raw_list};
+use super::{VsyncCallback, VsyncTiming};
 
 const VSYNC_OVERFLOW_NODE_PREALLOC: usize = 1024;
 const VSYNC_OVERFLOW_MAX: usize = 8192;
@@ -130,6 +130,12 @@ pub struct VsyncCallbackQueue {
     /// ### 中文
     /// 当前排队的溢出回调数量（tick 停滞时用于限制增长）。
     overflow_len: AtomicUsize,
+    /// ### English
+    /// Display timing reported by the host (see `VsyncTiming`).
+    ///
+    /// ### 中文
+    /// 宿主上报的显示时序（见 `VsyncTiming`）。
+    timing: VsyncTiming,
 }
 
 unsafe impl Sync for VsyncCallbackQueue {}
@@ -182,9 +188,20 @@ impl VsyncCallbackQueue {
             callbacks: AtomicPtr::new(ptr::null_mut()),
             free: AtomicPtr::new(free_head),
             overflow_len: AtomicUsize::new(0),
+            timing: VsyncTiming::default(),
         }
     }
 
+    /// ### English
+    /// Returns the display timing reported by the host for this queue's views.
+    ///
+    /// ### 中文
+    /// 返回宿主为该队列的 view 上报的显示时序。
+    #[inline]
+    pub fn timing(&self) -> &VsyncTiming {
+        &self.timing
+    }
+
     /// ### English
     /// Pushes one vsync callback into the queue.
     ///
@@ -260,6 +277,16 @@ impl VsyncCallbackQueue {
 
         self.drain_overflow_list(overflow);
     }
+
+    /// ### English
+    /// Frees the recycled overflow nodes kept after a tick stall (memory pressure); later overflow
+    /// allocates again. Safe to call from any thread.
+    ///
+    /// ### 中文
+    /// 释放 tick 停滞后保留下来的可复用 overflow 节点（内存压力时）；之后的 overflow 会重新分配。可在任意线程调用。
+    pub fn release_free_nodes(&self) {
+        drop_vsync_list(&self.free);
+    }
 }
 
 impl Drop for VsyncCallbackQueue {
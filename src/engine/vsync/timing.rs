@@ -0,0 +1,99 @@
+//! ### English
+//! Host-reported display timing (refresh period and vsync phase).
+//!
+//! Once the host reports its refresh rate and the time of an upcoming vsync, vsync-driven views
+//! stop waiting for `xian_web_engine_tick`: their frame callbacks are started on the refresh
+//! scheduler at the predicted vsync instants, so Servo's animation timing follows the display
+//! instead of whenever the host happens to tick.
+//!
+//! ### 中文
+//! 宿主上报的显示时序（刷新周期与 vsync 相位）。
+//!
+//! 宿主上报刷新率与即将到来的某次 vsync 时间后，vsync 驱动的 view 不再等待 `xian_web_engine_tick`：其帧回调会在
+//! refresh 调度器上于预测的 vsync 时刻启动，使 Servo 的动画时序跟随显示器，而不是宿主恰好 tick 的时刻。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::engine::clock;
+
+/// ### English
+/// Highest refresh rate the host may report, in hertz.
+///
+/// ### 中文
+/// 宿主可上报的最高刷新率（赫兹）。
+pub const MAX_REFRESH_RATE_HZ: f64 = 1000.0;
+
+/// ### English
+/// Display timing shared by the host (writer) and the vsync refresh drivers (readers).
+///
+/// The two fields are updated independently; a reader racing a change may briefly combine the old
+/// period with the new phase, which only shifts one frame.
+///
+/// ### 中文
+/// 宿主（写入方）与 vsync refresh driver（读取方）共享的显示时序。
+///
+/// 两个字段分别更新；与更新竞争的读取方可能短暂地把旧周期与新相位组合使用，这只会让一帧发生偏移。
+#[derive(Default)]
+pub struct VsyncTiming {
+    /// ### English
+    /// Refresh period in nanoseconds (`0` = unknown, frames follow the host tick).
+    ///
+    /// ### 中文
+    /// 刷新周期（纳秒；`0` 表示未知，帧跟随宿主 tick）。
+    period_ns: AtomicU64,
+    /// ### English
+    /// Engine monotonic time of one vsync (the phase reference).
+    ///
+    /// ### 中文
+    /// 某次 vsync 的引擎单调时间（相位参考点）。
+    anchor_ns: AtomicU64,
+}
+
+impl VsyncTiming {
+    /// ### English
+    /// Sets the refresh period and the time of one vsync (`period_ns == 0` clears the timing).
+    ///
+    /// #### Parameters
+    /// - `period_ns`: Refresh period in nanoseconds.
+    /// - `vsync_ns`: Engine monotonic time of a vsync (`0` = phase unknown, now is used).
+    ///
+    /// ### 中文
+    /// 设置刷新周期与某次 vsync 的时间（`period_ns == 0` 表示清除时序）。
+    ///
+    /// #### 参数
+    /// - `period_ns`：刷新周期（纳秒）。
+    /// - `vsync_ns`：某次 vsync 的引擎单调时间（`0` 表示相位未知，使用当前时间）。
+    pub fn set(&self, period_ns: u64, vsync_ns: u64) {
+        let anchor = if vsync_ns == 0 {
+            clock::monotonic_nanos()
+        } else {
+            vsync_ns
+        };
+        self.anchor_ns.store(anchor, Ordering::Relaxed);
+        self.period_ns.store(period_ns, Ordering::Release);
+    }
+
+    /// ### English
+    /// Returns how long until the next predicted vsync (`None` while no timing is set).
+    ///
+    /// ### 中文
+    /// 返回距下一次预测 vsync 的时长（未设置时序时返回 `None`）。
+    pub fn until_next_vsync(&self) -> Option<Duration> {
+        let period = self.period_ns.load(Ordering::Acquire);
+        if period == 0 {
+            return None;
+        }
+        let anchor = self.anchor_ns.load(Ordering::Relaxed);
+        let now = clock::monotonic_nanos();
+        let delay = if anchor >= now {
+            (anchor - now) % period
+        } else {
+            match (now - anchor) % period {
+                0 => 0,
+                phase => period - phase,
+            }
+        };
+        Some(Duration::from_nanos(delay))
+    }
+}
@@ -0,0 +1,277 @@
+//! ### English
+//! Servo thread watchdog: a heartbeat updated by the Servo loop, and the health snapshot reported
+//! to the embedder.
+//!
+//! The heartbeat is cleared while the Servo thread is parked, so an idle engine never looks stuck;
+//! only a thread that stays busy inside one loop iteration (e.g. a hung script) accumulates time.
+//! In paranoid GL mode the rendering contexts also record GL errors here.
+//!
+//! ### 中文
+//! Servo 线程看门狗：由 Servo 主循环更新的心跳，以及上报给宿主的健康快照。
+//!
+//! Servo 线程 park 时心跳会被清除，因此空闲的引擎不会被误判为卡死；只有在单次循环迭代中持续忙碌的线程
+//!（例如脚本卡死）才会累计时间。paranoid GL 模式下，渲染上下文也会在此记录 GL 错误。
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+/// ### English
+/// GL operation tag: rendering-context creation (slot textures, depth buffer).
+///
+/// ### 中文
+/// GL 操作标记：渲染上下文创建（槽位纹理、深度缓冲）。
+pub const XIAN_WEB_ENGINE_GL_OP_CREATE: u32 = 1;
+/// ### English
+/// GL operation tag: preparing the back slot for rendering (sRGB state, FBO binding).
+///
+/// ### 中文
+/// GL 操作标记：为渲染准备 back 槽位（sRGB 状态、FBO 绑定）。
+pub const XIAN_WEB_ENGINE_GL_OP_PREPARE: u32 = 2;
+/// ### English
+/// GL operation tag: presenting a frame (WebRender output, mipmaps, fence).
+///
+/// ### 中文
+/// GL 操作标记：呈现一帧（WebRender 输出、mipmap、fence）。
+pub const XIAN_WEB_ENGINE_GL_OP_PRESENT: u32 = 3;
+/// ### English
+/// GL operation tag: resizing slot textures.
+///
+/// ### 中文
+/// GL 操作标记：resize 槽位纹理。
+pub const XIAN_WEB_ENGINE_GL_OP_RESIZE: u32 = 4;
+/// ### English
+/// GL operation tag: reading pixels back (screenshots).
+///
+/// ### 中文
+/// GL 操作标记：读回像素（截图）。
+pub const XIAN_WEB_ENGINE_GL_OP_READ_PIXELS: u32 = 5;
+
+/// ### English
+/// Returns a short name for a `XIAN_WEB_ENGINE_GL_OP_*` tag (for log messages).
+///
+/// #### Parameters
+/// - `op`: `XIAN_WEB_ENGINE_GL_OP_*` value.
+///
+/// ### 中文
+/// 返回 `XIAN_WEB_ENGINE_GL_OP_*` 标记的简短名称（用于日志消息）。
+///
+/// #### 参数
+/// - `op`：`XIAN_WEB_ENGINE_GL_OP_*` 值。
+pub(crate) fn gl_op_name(op: u32) -> &'static str {
+    match op {
+        XIAN_WEB_ENGINE_GL_OP_CREATE => "create",
+        XIAN_WEB_ENGINE_GL_OP_PREPARE => "prepare_for_rendering",
+        XIAN_WEB_ENGINE_GL_OP_PRESENT => "present",
+        XIAN_WEB_ENGINE_GL_OP_RESIZE => "resize",
+        XIAN_WEB_ENGINE_GL_OP_READ_PIXELS => "read_to_image",
+        _ => "unknown",
+    }
+}
+
+/// ### English
+/// Heartbeat shared between the Servo thread (writer) and embedder threads (readers).
+///
+/// ### 中文
+/// Servo 线程（写入方）与宿主线程（读取方）共享的心跳。
+pub(crate) struct Heartbeat {
+    /// ### English
+    /// Reference point for `last_beat`.
+    ///
+    /// ### 中文
+    /// `last_beat` 的时间基准。
+    epoch: Instant,
+    /// ### English
+    /// Nanoseconds since `epoch` of the last beat, plus one (`0` = idle/parked).
+    ///
+    /// ### 中文
+    /// 上一次心跳距 `epoch` 的纳秒数加一（`0` 表示空闲/park 中）。
+    last_beat: AtomicU64,
+}
+
+impl Heartbeat {
+    /// ### English
+    /// Creates an idle heartbeat.
+    ///
+    /// ### 中文
+    /// 创建处于空闲状态的心跳。
+    pub(crate) fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+            last_beat: AtomicU64::new(0),
+        }
+    }
+
+    /// ### English
+    /// Records that the Servo thread started a new loop iteration.
+    ///
+    /// ### 中文
+    /// 记录 Servo 线程开始了新的一轮循环迭代。
+    pub(crate) fn beat(&self) {
+        let nanos = self.epoch.elapsed().as_nanos() as u64;
+        self.last_beat
+            .store(nanos.saturating_add(1), Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Records that the Servo thread is about to park (idle).
+    ///
+    /// ### 中文
+    /// 记录 Servo 线程即将 park（进入空闲）。
+    pub(crate) fn idle(&self) {
+        self.last_beat.store(0, Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Returns milliseconds spent in the current loop iteration (`0` while idle).
+    ///
+    /// ### 中文
+    /// 返回当前循环迭代已耗费的毫秒数（空闲时为 `0`）。
+    pub(crate) fn millis_since_beat(&self) -> u64 {
+        let last = self.last_beat.load(Ordering::Relaxed);
+        if last == 0 {
+            return 0;
+        }
+        let now = self.epoch.elapsed().as_nanos() as u64;
+        now.saturating_sub(last - 1) / 1_000_000
+    }
+}
+
+/// ### English
+/// GL errors recorded by a shard's rendering contexts in paranoid GL mode (written on the Servo
+/// thread, read by embedder threads).
+///
+/// ### 中文
+/// paranoid GL 模式下分片的渲染上下文记录的 GL 错误（Servo 线程写入，宿主线程读取）。
+pub(crate) struct GlErrorLog {
+    /// ### English
+    /// Frames that hit at least one GL error.
+    ///
+    /// ### 中文
+    /// 至少出现过一个 GL 错误的帧数。
+    frames: AtomicU64,
+    /// ### English
+    /// Latest recorded error packed as `op << 32 | error` (`0` = none yet).
+    ///
+    /// ### 中文
+    /// 最近一次记录的错误，打包为 `op << 32 | error`（`0` 表示尚无）。
+    last: AtomicU64,
+}
+
+impl GlErrorLog {
+    /// ### English
+    /// Creates an empty log.
+    ///
+    /// ### 中文
+    /// 创建空的错误记录。
+    pub(crate) fn new() -> Self {
+        Self {
+            frames: AtomicU64::new(0),
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// ### English
+    /// Records the first GL error of a frame.
+    ///
+    /// #### Parameters
+    /// - `op`: `XIAN_WEB_ENGINE_GL_OP_*` of the operation that raised it.
+    /// - `error`: `glGetError` value.
+    ///
+    /// ### 中文
+    /// 记录一帧中的第一个 GL 错误。
+    ///
+    /// #### 参数
+    /// - `op`：触发该错误的操作（`XIAN_WEB_ENGINE_GL_OP_*`）。
+    /// - `error`：`glGetError` 返回值。
+    pub(crate) fn record(&self, op: u32, error: u32) {
+        self.frames.fetch_add(1, Ordering::Relaxed);
+        self.last
+            .store((u64::from(op) << 32) | u64::from(error), Ordering::Relaxed);
+    }
+
+    /// ### English
+    /// Returns the number of frames that hit a GL error.
+    ///
+    /// ### 中文
+    /// 返回出现过 GL 错误的帧数。
+    pub(crate) fn frames_with_errors(&self) -> u64 {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    /// ### English
+    /// Returns the latest recorded `(op, error)`, if any.
+    ///
+    /// ### 中文
+    /// 返回最近一次记录的 `(op, error)`（若有）。
+    pub(crate) fn last(&self) -> Option<(u32, u32)> {
+        let last = self.last.load(Ordering::Relaxed);
+        (last != 0).then_some(((last >> 32) as u32, last as u32))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// ### English
+/// Health snapshot of the engine, filled by `xian_web_engine_get_health`.
+///
+/// ### 中文
+/// 引擎健康快照，由 `xian_web_engine_get_health` 填充。
+pub struct XianWebEngineHealth {
+    /// ### English
+    /// Milliseconds the Servo thread has been busy in its current loop iteration (`0` = idle).
+    ///
+    /// A value that keeps growing means the thread is stuck (e.g. a hung script); hosts can show a
+    /// "web UI not responding" overlay or destroy the engine.
+    ///
+    /// ### 中文
+    /// Servo 线程在当前循环迭代中已忙碌的毫秒数（`0` 表示空闲）。
+    ///
+    /// 若该值持续增长，说明线程卡住（例如脚本卡死）；宿主可显示“网页 UI 无响应”遮罩或销毁引擎。
+    pub millis_since_heartbeat: u64,
+    /// ### English
+    /// Control commands queued for the Servo thread but not yet processed.
+    ///
+    /// ### 中文
+    /// 已排队等待 Servo 线程处理的控制命令数。
+    pub queued_commands: u32,
+    /// ### English
+    /// Views with pending work (input/resize/navigation) not yet processed.
+    ///
+    /// ### 中文
+    /// 有待处理工作（输入/resize/导航）且尚未被处理的 view 数。
+    pub pending_views: u32,
+    /// ### English
+    /// Frames that hit at least one GL error (`XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL` only;
+    /// always `0` otherwise).
+    ///
+    /// ### 中文
+    /// 至少出现过一个 GL 错误的帧数（仅 `XIAN_WEB_ENGINE_ENGINE_FLAG_PARANOID_GL`；否则始终为 `0`）。
+    pub gl_error_frames: u64,
+    /// ### English
+    /// First `glGetError` value of the latest failing frame (`0` = none).
+    ///
+    /// ### 中文
+    /// 最近一个出错帧的第一个 `glGetError` 值（`0` 表示无）。
+    pub last_gl_error: u32,
+    /// ### English
+    /// Operation that raised `last_gl_error` (`XIAN_WEB_ENGINE_GL_OP_*`, `0` = none).
+    ///
+    /// ### 中文
+    /// 触发 `last_gl_error` 的操作（`XIAN_WEB_ENGINE_GL_OP_*`，`0` 表示无）。
+    pub last_gl_error_op: u32,
+    /// ### English
+    /// Control commands rejected with `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL` since the engine was created
+    /// (monotonic).
+    ///
+    /// ### 中文
+    /// 自引擎创建以来以 `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL` 被拒绝的控制命令数（单调递增）。
+    pub commands_dropped: u64,
+    /// ### English
+    /// Times the pending-view queue overflowed since the engine was created (monotonic). No work is
+    /// lost (the Servo thread then scans every view), but a growing value means wakeups are arriving
+    /// faster than they are drained.
+    ///
+    /// ### 中文
+    /// 自引擎创建以来待处理 view 队列溢出的次数（单调递增）。不会丢失工作（Servo 线程随后会扫描所有 view），
+    /// 但该值持续增长说明唤醒到达速度快于处理速度。
+    pub pending_overflows: u64,
+}
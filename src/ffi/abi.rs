@@ -1,8 +1,12 @@
 //! ### English
-//! C ABI version query.
+//! C ABI version query and feature detection.
 //!
 //! ### 中文
-//! C ABI 版本查询接口。
+//! C ABI 版本查询与功能探测接口。
+
+use crate::engine::capabilities;
+
+use super::validate::ffi_entry;
 
 #[unsafe(no_mangle)]
 /// ### English
@@ -11,5 +15,40 @@
 /// ### 中文
 /// 返回 C ABI 版本号。
 pub extern "C" fn xian_web_engine_abi_version() -> u32 {
+    ffi_entry!(xian_web_engine_abi_version);
     super::XIAN_WEB_ENGINE_ABI_VERSION
 }
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns whether bindings written against ABI `version` can use this library.
+///
+/// The library accepts every version from the last struct-layout change up to its own version;
+/// bindings newer than the library are rejected, since they may call functions it does not export.
+/// Call this once after loading and fall back (or report a clear error) when it returns `false`.
+///
+/// ### 中文
+/// 返回基于 ABI `version` 编写的绑定能否使用本库。
+///
+/// 本库接受从最近一次结构体布局变更到自身版本之间的所有版本；比本库更新的绑定会被拒绝，
+/// 因为它们可能调用本库未导出的函数。请在加载后调用一次，返回 `false` 时回退（或给出明确的错误）。
+pub extern "C" fn xian_web_engine_require_abi(version: u32) -> bool {
+    ffi_entry!(xian_web_engine_require_abi, version);
+    (super::XIAN_WEB_ENGINE_ABI_MIN_COMPATIBLE..=super::XIAN_WEB_ENGINE_ABI_VERSION)
+        .contains(&version)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns the `XIAN_WEB_ENGINE_CAPABILITY_*` bits supported by this build on this platform.
+///
+/// Unknown bits must be ignored; bits are only ever added.
+///
+/// ### 中文
+/// 返回本构建在当前平台上支持的 `XIAN_WEB_ENGINE_CAPABILITY_*` 位。
+///
+/// 未知位必须忽略；能力位只会新增。
+pub extern "C" fn xian_web_engine_get_capabilities() -> u64 {
+    ffi_entry!(xian_web_engine_get_capabilities);
+    capabilities()
+}
@@ -0,0 +1,89 @@
+//! ### English
+//! C ABI binding for the optional view compositor.
+//!
+//! ### 中文
+//! 可选 view 合成器的 C ABI 绑定。
+
+use crate::engine::XianWebEngineCompositeRect;
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+
+use super::validate::{self, ffi_entry};
+use super::{XianWebEngine, XianWebEngineView};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Draws the latest frames of `count` views into `target_fbo` and returns how many were drawn.
+///
+/// Views are drawn in array order (back to front) as premultiplied-alpha quads at `rects[i]`, in
+/// pixels of the current GL viewport with a top-left origin. Each view shows its newest frame, or the
+/// one it showed last time if no newer frame is ready; the compositor keeps that frame acquired and
+/// releases it with a consumer fence once replaced, so do not acquire/release these views yourself.
+/// Views no longer passed in are released on the next call. sRGB conversion follows the view's
+/// colorspace and the encoding of the target attachment. `views[i]` may be NULL to skip an entry;
+/// `target_fbo = 0` draws into the default framebuffer. GL state changed for drawing is restored.
+///
+/// Must be called on the host render thread with a GL context current that shares objects with the
+/// engine. The compositor's GL objects belong to the first thread that calls this; destroy the
+/// engine on that thread to free them.
+///
+/// Returns `0` if an argument is NULL/invalid or the compositor cannot be created.
+///
+/// ### 中文
+/// 将 `count` 个 view 的最新帧绘制到 `target_fbo`，返回实际绘制的数量。
+///
+/// view 按数组顺序（从后到前）以预乘 alpha 四边形绘制在 `rects[i]` 处，坐标为当前 GL viewport 的像素，
+/// 原点在左上角。每个 view 显示其最新帧；若没有更新的帧就绪，则显示上次的帧。合成器会保持该帧 acquire，
+/// 并在被替换后附带 consumer fence 释放，因此请勿再自行 acquire/release 这些 view。不再传入的 view
+/// 会在下一次调用时被释放。sRGB 转换取决于 view 的色彩空间与目标附件的编码。`views[i]` 可为 NULL 以跳过该项；
+/// `target_fbo = 0` 表示绘制到默认 framebuffer。为绘制而修改的 GL 状态会被恢复。
+///
+/// 必须在宿主渲染线程上调用，且当前 GL 上下文需与引擎共享对象。合成器的 GL 对象属于首次调用本函数的线程；
+/// 请在该线程上销毁引擎以释放它们。
+///
+/// 若参数为空/非法或无法创建合成器，则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_composite_views(
+    engine: *mut XianWebEngine,
+    views: *const *mut XianWebEngineView,
+    rects: *const XianWebEngineCompositeRect,
+    count: u32,
+    target_fbo: u32,
+) -> u32 {
+    let call = ffi_entry!(
+        xian_web_engine_composite_views,
+        engine,
+        views,
+        rects,
+        count,
+        target_fbo
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return 0;
+    };
+    let Some(views) = (unsafe { validate::slice(call, "views", views, count as usize) }) else {
+        return 0;
+    };
+    let Some(rects) = (unsafe { validate::slice(call, "rects", rects, count as usize) }) else {
+        return 0;
+    };
+
+    let mut handles = Vec::with_capacity(views.len());
+    for &view in views {
+        if view.is_null() {
+            handles.push(None);
+            continue;
+        }
+        let Some(handle) = (unsafe { validate::view(call, view) }) else {
+            return 0;
+        };
+        handles.push(Some(handle));
+    }
+
+    let handles: Vec<_> = handles.iter().map(Option::as_deref).collect();
+    match runtime.composite_views(&handles, rects, target_fbo) {
+        Ok(drawn) => drawn as u32,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            0
+        }
+    }
+}
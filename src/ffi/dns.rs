@@ -0,0 +1,39 @@
+//! ### English
+//! C ABI bindings for the embedder DNS resolver.
+//!
+//! ### 中文
+//! 宿主 DNS 解析器的 C ABI 绑定。
+
+use std::ffi::c_void;
+
+use crate::engine::dns::{XianWebEngineDnsResolveFn, set_dns_resolver};
+
+use super::validate::ffi_entry;
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the process-wide DNS resolver callback (`NULL` removes it), for environments that block
+/// system DNS or need split-horizon names for LAN servers.
+///
+/// May be installed or replaced at any time: engines running a loopback proxy send their HTTP(S)
+/// traffic through it, and it asks the callback for every new target connection and falls back to
+/// the system resolver when it returns `false` or no callback is installed. An engine runs a proxy
+/// if it was created with `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` or while a callback or
+/// network limit was installed; other engines keep using the system resolver. TLS is still verified
+/// against the original host name. DNS-over-HTTPS can be implemented inside the callback. WebSocket
+/// connections always use the system resolver.
+///
+/// ### 中文
+/// 安装进程级 DNS 解析回调（传 `NULL` 表示移除），用于屏蔽了系统 DNS 或需要为局域网服务器做分离解析的环境。
+///
+/// 可在任意时刻安装或替换：运行回环代理的引擎会将 HTTP(S) 流量经由代理转发，代理针对每个新的目标连接调用该
+/// 回调，回调返回 `false` 或未安装回调时回退到系统解析器。以 `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`
+/// 创建、或在已安装回调或网络限制时创建的引擎才会运行代理；其他引擎继续使用系统解析器。TLS 仍按原始主机名校验。DNS-over-HTTPS 可在回调内部实现。
+/// WebSocket 连接始终使用系统解析器。
+pub unsafe extern "C" fn xian_web_engine_set_dns_resolver(
+    callback: Option<XianWebEngineDnsResolveFn>,
+    user_data: *mut c_void,
+) {
+    ffi_entry!(xian_web_engine_set_dns_resolver, callback, user_data);
+    set_dns_resolver(callback, user_data);
+}
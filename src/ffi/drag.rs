@@ -0,0 +1,127 @@
+//! ### English
+//! C ABI bindings for dragging host data (e.g. inventory items) into a page.
+//!
+//! The page sees regular HTML5 drag-and-drop events (`dragenter`/`dragover`/`dragleave`/`drop`) whose
+//! `dataTransfer` carries the host data under its MIME type.
+//!
+//! ### 中文
+//! 将宿主数据（例如背包物品）拖入页面的 C ABI 绑定。
+//!
+//! 页面会收到常规的 HTML5 拖放事件（`dragenter`/`dragover`/`dragleave`/`drop`），其 `dataTransfer`
+//! 以对应 MIME 类型携带宿主数据。
+
+use std::ffi::c_char;
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Starts dragging `len` bytes of UTF-8 data with the given MIME type (NUL-terminated) over the view.
+///
+/// Follow with `xian_web_engine_view_drag_move` as the cursor moves, then
+/// `xian_web_engine_view_drop` or `xian_web_engine_view_cancel_drag`. Starting a new drag replaces the
+/// current one.
+///
+/// Returns `false` if an argument is NULL, the data is not valid UTF-8, or the engine is shutting down.
+///
+/// ### 中文
+/// 开始在 view 上拖拽 `len` 字节的 UTF-8 数据，MIME 类型由 `mime`（NUL 结尾）指定。
+///
+/// 随后在光标移动时调用 `xian_web_engine_view_drag_move`，最后调用 `xian_web_engine_view_drop` 或
+/// `xian_web_engine_view_cancel_drag`。开始新的拖拽会替换当前拖拽。
+///
+/// 若参数为空指针、数据不是合法 UTF-8，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_begin_drag(
+    view: *mut XianWebEngineView,
+    mime: *const c_char,
+    bytes: *const u8,
+    len: usize,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_begin_drag, view, mime, bytes, len);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(mime) = (unsafe { validate::cstr(call, "mime", mime) }) else {
+        return false;
+    };
+    let Some(bytes) = (unsafe { validate::slice(call, "bytes", bytes, len) }) else {
+        return false;
+    };
+    let Ok(data) = std::str::from_utf8(bytes) else {
+        return false;
+    };
+
+    handle.begin_drag(mime, data)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Moves the current drag to `(x, y)` in device pixels.
+///
+/// Returns `false` if `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+///
+/// ### 中文
+/// 将当前拖拽移动到 `(x, y)`（设备像素）。
+///
+/// 若 `view` 为空指针、坐标非有限值，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_drag_move(
+    view: *mut XianWebEngineView,
+    x: f32,
+    y: f32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_drag_move, view, x, y);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.drag_move(x, y)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Drops the current drag at `(x, y)` in device pixels.
+///
+/// The outcome is reported as a `XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE` event whose text is the drop
+/// effect (`"none"` if the page rejected the data, so the item can stay in the inventory).
+///
+/// Returns the request ID, or `0` on failure.
+///
+/// ### 中文
+/// 在 `(x, y)`（设备像素）放下当前拖拽。
+///
+/// 结果以 `XIAN_WEB_ENGINE_VIEW_EVENT_DROP_COMPLETE` 事件上报，其文本为 drop effect（若页面拒绝该数据则为
+/// `"none"`，此时物品可留在背包中）。
+///
+/// 返回请求 ID；失败时返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_drop(
+    view: *mut XianWebEngineView,
+    x: f32,
+    y: f32,
+) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_drop, view, x, y);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.drop_drag(x, y).unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Cancels the current drag (e.g. the cursor left the view).
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 取消当前拖拽（例如光标离开了 view）。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_cancel_drag(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_cancel_drag, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.cancel_drag()
+}
@@ -0,0 +1,44 @@
+//! ### English
+//! C ABI bindings for installing the embedder-provided EGL entry point.
+//!
+//! ### 中文
+//! 安装宿主提供的 EGL 入口函数的 C ABI 绑定。
+
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+use crate::engine::{EmbedderEglApi, install_embedder_egl_api};
+
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the embedder's `eglGetProcAddress` and `EGLDisplay` (once per process).
+///
+/// This must be called before `xian_web_engine_create_with_flags` with
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`. The display must be the one that owns the `EGLContext`
+/// passed to it (e.g. `glfwGetEGLDisplay()` when GLFW runs on ANGLE).
+///
+/// Returns `true` on success.
+///
+/// ### 中文
+/// 安装宿主的 `eglGetProcAddress` 与 `EGLDisplay`（每个进程一次）。
+///
+/// 必须在以 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 调用 `xian_web_engine_create_with_flags` 之前调用。
+/// display 必须是持有传入 `EGLContext` 的那个 display（例如 GLFW 运行在 ANGLE 上时的
+/// `glfwGetEGLDisplay()`）。
+///
+/// 成功返回 `true`。
+pub unsafe extern "C" fn xian_web_engine_set_egl_api(api: *const EmbedderEglApi) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_egl_api, api);
+    if !validate::non_null(call, "api", api) {
+        return false;
+    }
+
+    let api = unsafe { std::ptr::read_unaligned(api) };
+    match install_embedder_egl_api(api) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            false
+        }
+    }
+}
@@ -1,15 +1,28 @@
 //! ### English
-//! C ABI bindings for engine lifecycle (create/destroy/tick).
+//! C ABI bindings for engine lifecycle (create/destroy/tick) and engine-wide settings.
 //!
 //! ### 中文
-//! 引擎生命周期相关的 C ABI 绑定（create/destroy/tick）。
+//! 引擎生命周期（create/destroy/tick）与引擎级设置相关的 C ABI 绑定。
 
 use std::ffi::{c_char, c_void};
 
 use dpi::PhysicalSize;
 
 use super::XianWebEngine;
-use crate::engine::EngineRuntime;
+use super::validate::{self, ffi_entry};
+use crate::engine::fence_stats::{XianWebEngineFenceStats, fence_stats};
+use crate::engine::init_progress::{XianWebEngineInitProgressFn, set_init_progress_sink};
+use crate::engine::log::{
+    XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, XIAN_WEB_ENGINE_LOG_LEVEL_INFO, XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+};
+use crate::engine::profiling;
+use crate::engine::{
+    EngineRuntime, XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE,
+    XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY, XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE,
+    XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE, XianWebEngineConfig, XianWebEngineHealth,
+    XianWebEngineImageLimits, XianWebEngineImageLoadStats, XianWebEngineNetworkLimits,
+    XianWebEngineThreadPoolConfig,
+};
 
 #[unsafe(no_mangle)]
 /// ### English
@@ -39,7 +52,16 @@ pub extern "C" fn xian_web_engine_create(
     config_dir: *const c_char,
     thread_pool_cap: u32,
 ) -> *mut XianWebEngine {
-    if glfw_shared_window.is_null() {
+    let call = ffi_entry!(
+        xian_web_engine_create,
+        glfw_shared_window,
+        default_width,
+        default_height,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+    );
+    if !validate::non_null(call, "glfw_shared_window", glfw_shared_window) {
         return std::ptr::null_mut();
     }
 
@@ -48,17 +70,291 @@ pub extern "C" fn xian_web_engine_create(
     let resources_dir = unsafe { super::cstr_to_path(resources_dir) };
     let config_dir = unsafe { super::cstr_to_path(config_dir) };
 
-    let Ok(runtime) = EngineRuntime::new(
+    let runtime = match EngineRuntime::new(
         glfw_shared_window,
         default_size,
         resources_dir,
         config_dir,
         thread_pool_cap,
-    ) else {
+        XianWebEngineThreadPoolConfig::default(),
+        0,
+        XianWebEngineConfig::default(),
+    ) {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let engine = Box::into_raw(Box::new(XianWebEngine {
+        runtime,
+        handle_table: false,
+    }));
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+        call,
+        format_args!("created engine {engine:p}"),
+    );
+    engine
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Same as `xian_web_engine_create`, but also sizes Servo's worker pools individually.
+///
+/// `thread_pools` is optional (NULL = all defaults). Each non-zero field sets the worker count of one
+/// pool (layout/style, async runtime, network, image decoding, WebRender, storage); `0` fields fall
+/// back to the `thread_pool_cap` rule. Use small values on low-end machines so the game and the
+/// browser do not oversubscribe the CPU.
+///
+/// `servo_threads` shards views across several Servo threads, isolating heavy pages from the rest.
+/// Views created in a group live on the group's thread; other views are spread round-robin.
+///
+/// ### 中文
+/// 与 `xian_web_engine_create` 相同，但可单独设置 Servo 各工作线程池的大小。
+///
+/// `thread_pools` 为可选参数（NULL 表示全部使用默认值）。每个非 0 字段设置一个线程池的工作线程数
+/// （layout/style、异步运行时、网络、图片解码、WebRender、存储）；为 `0` 的字段回退到 `thread_pool_cap`
+/// 规则。在低端机器上可使用较小的值，避免游戏与浏览器争抢 CPU。
+///
+/// `servo_threads` 会将 view 分片到多个 Servo 线程上，使繁重页面与其他页面隔离。
+/// 在 group 中创建的 view 位于该 group 所在的线程；其他 view 按轮询分布。
+pub unsafe extern "C" fn xian_web_engine_create_with_thread_pools(
+    glfw_shared_window: *mut c_void,
+    default_width: u32,
+    default_height: u32,
+    resources_dir: *const c_char,
+    config_dir: *const c_char,
+    thread_pool_cap: u32,
+    thread_pools: *const XianWebEngineThreadPoolConfig,
+) -> *mut XianWebEngine {
+    let call = ffi_entry!(
+        xian_web_engine_create_with_thread_pools,
+        glfw_shared_window,
+        default_width,
+        default_height,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+        thread_pools,
+    );
+    if !validate::non_null(call, "glfw_shared_window", glfw_shared_window) {
         return std::ptr::null_mut();
+    }
+
+    let default_size = PhysicalSize::new(default_width.max(1), default_height.max(1));
+
+    let resources_dir = unsafe { super::cstr_to_path(resources_dir) };
+    let config_dir = unsafe { super::cstr_to_path(config_dir) };
+    let thread_pools = if thread_pools.is_null() {
+        XianWebEngineThreadPoolConfig::default()
+    } else if validate::pointer(call, "thread_pools", thread_pools) {
+        unsafe { *thread_pools }
+    } else {
+        return std::ptr::null_mut();
+    };
+
+    let runtime = match EngineRuntime::new(
+        glfw_shared_window,
+        default_size,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+        thread_pools,
+        0,
+        XianWebEngineConfig::default(),
+    ) {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
     };
 
-    Box::into_raw(Box::new(XianWebEngine { runtime }))
+    let engine = Box::into_raw(Box::new(XianWebEngine {
+        runtime,
+        handle_table: false,
+    }));
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+        call,
+        format_args!("created engine {engine:p}"),
+    );
+    engine
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Same as `xian_web_engine_create_with_thread_pools`, but also takes engine creation flags
+/// (`XIAN_WEB_ENGINE_ENGINE_FLAG_*`).
+///
+/// With `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`, `shared_context` is the host's `EGLContext` and the Servo
+/// threads create their contexts through the EGL API installed by `xian_web_engine_set_egl_api`
+/// (e.g. Minecraft running on ANGLE or a GLES-only ARM driver). Otherwise it is the
+/// `GLFWwindow*` as for `xian_web_engine_create`. `XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG` creates
+/// debug contexts and forwards their GL debug messages to the log callback.
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE` hands out the engine's views as validated IDs instead
+/// of pointers (through the process-global handle table).
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS` runs page content in separate processes (fails on
+/// Windows and under a `java`/`javaw` launcher, see the flag).
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` routes HTTP(S) through the loopback proxy even if no
+/// resolver or network limit is installed yet.
+///
+/// ### 中文
+/// 与 `xian_web_engine_create_with_thread_pools` 相同，但额外接受引擎创建标志
+/// （`XIAN_WEB_ENGINE_ENGINE_FLAG_*`）。
+///
+/// 设置 `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL` 时，`shared_context` 为宿主的 `EGLContext`，Servo 线程通过
+/// `xian_web_engine_set_egl_api` 安装的 EGL API 创建上下文（例如运行在 ANGLE 或仅支持 GLES 的 ARM 驱动上的
+/// Minecraft）；否则它与 `xian_web_engine_create` 一样是 `GLFWwindow*`。
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_GL_DEBUG` 会创建 debug 上下文，并将其 GL debug 消息转发给日志回调。
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE` 会以经过校验的 ID 而非指针交出该引擎的 view（经由进程级句柄表）。
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS` 会在独立进程中运行页面内容（在 Windows 上或 `java`/`javaw`
+/// 启动器下会失败，见该标志）。
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` 即使尚未安装解析器或网络限制，也让 HTTP(S) 经由回环代理转发。
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn xian_web_engine_create_with_flags(
+    shared_context: *mut c_void,
+    default_width: u32,
+    default_height: u32,
+    resources_dir: *const c_char,
+    config_dir: *const c_char,
+    thread_pool_cap: u32,
+    thread_pools: *const XianWebEngineThreadPoolConfig,
+    engine_flags: u32,
+) -> *mut XianWebEngine {
+    ffi_entry!(
+        xian_web_engine_create_with_flags,
+        shared_context,
+        default_width,
+        default_height,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+        thread_pools,
+        engine_flags,
+    );
+    unsafe {
+        xian_web_engine_create_with_config(
+            shared_context,
+            default_width,
+            default_height,
+            resources_dir,
+            config_dir,
+            thread_pool_cap,
+            thread_pools,
+            engine_flags,
+            std::ptr::null(),
+        )
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Same as `xian_web_engine_create_with_flags`, but also takes an engine configuration (NULL = all
+/// defaults; see `xian_web_engine_config_default`).
+///
+/// The configuration sets how long engine creation, blocking view creation and other blocking
+/// requests wait for the Servo threads, or disables those timeouts. When a wait times out the call
+/// fails and `xian_web_engine_last_command_status` returns `XIAN_WEB_ENGINE_STATUS_TIMED_OUT` on the
+/// calling thread.
+///
+/// ### 中文
+/// 与 `xian_web_engine_create_with_flags` 相同，但额外接受引擎配置（NULL 表示全部使用默认值；见
+/// `xian_web_engine_config_default`）。
+///
+/// 该配置决定引擎创建、阻塞式 view 创建与其他阻塞请求等待 Servo 线程的时长，或关闭这些超时。等待超时时调用失败，
+/// 且调用线程上的 `xian_web_engine_last_command_status` 返回 `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`。
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn xian_web_engine_create_with_config(
+    shared_context: *mut c_void,
+    default_width: u32,
+    default_height: u32,
+    resources_dir: *const c_char,
+    config_dir: *const c_char,
+    thread_pool_cap: u32,
+    thread_pools: *const XianWebEngineThreadPoolConfig,
+    engine_flags: u32,
+    config: *const XianWebEngineConfig,
+) -> *mut XianWebEngine {
+    let call = ffi_entry!(
+        xian_web_engine_create_with_config,
+        shared_context,
+        default_width,
+        default_height,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+        thread_pools,
+        engine_flags,
+        config,
+    );
+    if !validate::non_null(call, "shared_context", shared_context) {
+        return std::ptr::null_mut();
+    }
+
+    let default_size = PhysicalSize::new(default_width.max(1), default_height.max(1));
+
+    let resources_dir = unsafe { super::cstr_to_path(resources_dir) };
+    let config_dir = unsafe { super::cstr_to_path(config_dir) };
+    let thread_pools = if thread_pools.is_null() {
+        XianWebEngineThreadPoolConfig::default()
+    } else if validate::pointer(call, "thread_pools", thread_pools) {
+        unsafe { *thread_pools }
+    } else {
+        return std::ptr::null_mut();
+    };
+    let config = if config.is_null() {
+        XianWebEngineConfig::default()
+    } else if validate::pointer(call, "config", config) {
+        unsafe { *config }
+    } else {
+        return std::ptr::null_mut();
+    };
+
+    let runtime = match EngineRuntime::new(
+        shared_context,
+        default_size,
+        resources_dir,
+        config_dir,
+        thread_pool_cap,
+        thread_pools,
+        engine_flags,
+        config,
+    ) {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let engine = Box::into_raw(Box::new(XianWebEngine {
+        runtime,
+        handle_table: (engine_flags & XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE) != 0,
+    }));
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+        call,
+        format_args!("created engine {engine:p}"),
+    );
+    engine
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Fills `config` with the default engine configuration (30-second timeouts, fail fast), to be
+/// adjusted and passed to `xian_web_engine_create_with_config`.
+///
+/// ### 中文
+/// 以默认引擎配置（30 秒超时、快速失败）填充 `config`，调整后传给 `xian_web_engine_create_with_config`。
+pub unsafe extern "C" fn xian_web_engine_config_default(config: *mut XianWebEngineConfig) {
+    let call = ffi_entry!(xian_web_engine_config_default, config);
+    if !validate::pointer(call, "config", config) {
+        return;
+    }
+    unsafe { config.write(XianWebEngineConfig::default()) };
 }
 
 #[unsafe(no_mangle)]
@@ -73,12 +369,61 @@ pub extern "C" fn xian_web_engine_create(
 ///
 /// 该操作会关闭 Servo 线程并销毁该引擎创建的所有剩余 view/资源；engine destroy 之后不要再使用任何 view。
 pub unsafe extern "C" fn xian_web_engine_destroy(engine: *mut XianWebEngine) {
-    if engine.is_null() {
+    let call = ffi_entry!(xian_web_engine_destroy, engine);
+    if engine.is_null() || !validate::pointer(call, "engine", engine) {
         return;
     }
     unsafe {
         drop(Box::from_raw(engine));
     }
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_INFO,
+        call,
+        format_args!("destroyed engine {engine:p}"),
+    );
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Runs a Servo content process for an engine created with
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS`.
+///
+/// Servo starts content processes by re-running the host executable with the arguments
+/// `--content-process <token>`. The host's entry point must detect them and call this function with
+/// the token before initializing anything else; it returns when the content process exits, after
+/// which the process should exit too.
+///
+/// Returns `false` right away if `token` is NULL/invalid or multiprocess mode is not supported on
+/// this platform (Windows).
+///
+/// ### 中文
+/// 为以 `XIAN_WEB_ENGINE_ENGINE_FLAG_MULTIPROCESS` 创建的引擎运行一个 Servo 内容进程。
+///
+/// Servo 通过以参数 `--content-process <token>` 重新运行宿主可执行文件来启动内容进程。宿主入口必须识别这些参数，
+/// 并在初始化其他任何内容之前以该 token 调用本函数；内容进程退出时本函数返回，随后该进程也应退出。
+///
+/// 若 `token` 为空/非法，或当前平台（Windows）不支持多进程模式，则立即返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_run_content_process(token: *const c_char) -> bool {
+    let call = ffi_entry!(xian_web_engine_run_content_process, token);
+    let Some(token) = (unsafe { validate::cstr(call, "token", token) }) else {
+        return false;
+    };
+
+    #[cfg(windows)]
+    {
+        let _ = token;
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+            call,
+            format_args!("multiprocess mode is not supported on Windows"),
+        );
+        false
+    }
+    #[cfg(not(windows))]
+    {
+        servo::run_content_process(token.to_string());
+        true
+    }
 }
 
 #[unsafe(no_mangle)]
@@ -88,9 +433,896 @@ pub unsafe extern "C" fn xian_web_engine_destroy(engine: *mut XianWebEngine) {
 /// ### 中文
 /// 执行待处理的 vsync 回调（由 Java 驱动 refresh）。
 pub unsafe extern "C" fn xian_web_engine_tick(engine: *mut XianWebEngine) {
-    if engine.is_null() {
+    let call = ffi_entry!(xian_web_engine_tick, engine);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
         return;
+    };
+
+    runtime.tick();
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Enters or leaves engine idle mode (e.g. while the game shows its pause menu).
+///
+/// While idle, Servo threads coalesce their wakes and spin at most a few times per second, and
+/// fixed-interval views stop refreshing, so background CPU use drops to near zero. Input and
+/// commands still reach the views, with up to a quarter second of extra latency. Vsync-driven
+/// views keep following `xian_web_engine_tick`; stop ticking to pause them too.
+///
+/// #### Parameters
+/// - `idle`: Whether the engine is idle.
+/// - `idle_flags`: `XIAN_WEB_ENGINE_IDLE_FLAG_*` applied when entering idle (`0` = none).
+///
+/// ### 中文
+/// 进入或退出引擎空闲模式（例如游戏显示暂停菜单期间）。
+///
+/// 空闲期间，Servo 线程会合并唤醒，每秒最多驱动数次事件循环，固定间隔的 view 停止刷新，使后台 CPU 占用降至接近
+/// 零。输入与命令仍会送达 view，但最多额外延迟四分之一秒。vsync 驱动的 view 仍跟随 `xian_web_engine_tick`；停止
+/// tick 即可一并暂停它们。
+///
+/// #### 参数
+/// - `idle`：引擎是否空闲。
+/// - `idle_flags`：进入空闲时应用的 `XIAN_WEB_ENGINE_IDLE_FLAG_*`（`0` 表示无）。
+pub unsafe extern "C" fn xian_web_engine_set_idle(
+    engine: *mut XianWebEngine,
+    idle: bool,
+    idle_flags: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_idle, engine, idle, idle_flags);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    match runtime.set_idle(idle, idle_flags) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Tells the engine the host is low on memory (e.g. the JVM is close to its heap limit or the OS
+/// signalled low memory) so it releases what it can.
+///
+/// `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` frees the recycled vsync overflow nodes and every
+/// view's spare slot textures; `XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL` also clears Servo's
+/// in-memory HTTP cache. Servo exposes no image-cache or garbage-collection hook, so decoded
+/// images and script heaps are left to Servo. Views keep running; released memory is reallocated on
+/// demand.
+///
+/// #### Parameters
+/// - `level`: `XIAN_WEB_ENGINE_MEMORY_PRESSURE_*`.
+///
+/// ### 中文
+/// 通知引擎宿主内存不足（例如 JVM 接近堆上限或操作系统发出低内存信号），使其尽可能释放内存。
+///
+/// `XIAN_WEB_ENGINE_MEMORY_PRESSURE_MODERATE` 会释放可复用的 vsync overflow 节点与每个 view 的备用槽位纹理；
+/// `XIAN_WEB_ENGINE_MEMORY_PRESSURE_CRITICAL` 还会清空 Servo 的内存 HTTP 缓存。Servo 没有提供图片缓存或垃圾回收
+/// 钩子，因此解码后的图片与脚本堆交由 Servo 处理。view 会继续运行；释放的内存会按需重新分配。
+///
+/// #### 参数
+/// - `level`：`XIAN_WEB_ENGINE_MEMORY_PRESSURE_*`。
+pub unsafe extern "C" fn xian_web_engine_notify_memory_pressure(
+    engine: *mut XianWebEngine,
+    level: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_notify_memory_pressure, engine, level);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    match runtime.notify_memory_pressure(level) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Starts a WebDriver server on `port` so QA can script the embedded UI of the running client with
+/// standard W3C WebDriver tooling (Selenium, WebdriverIO, ...).
+///
+/// The server is Servo's; it drives the views of the first Servo thread (all views unless
+/// `servo_threads` is above `1`). Views are created by the host, so window management commands
+/// (new window, window rect) are not honored. Calling again with the same port does nothing. Only
+/// builds with the `webdriver` feature support it (`XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER`); the
+/// server accepts unauthenticated connections, so never enable it in release clients.
+///
+/// Returns `false` if `engine` is NULL, `port` is `0` or cannot be bound (e.g. already in use), the
+/// server is already listening on another port, WebDriver is not built in, or the engine is
+/// shutting down.
+///
+/// ### 中文
+/// 在 `port` 上启动 WebDriver 服务器，使 QA 能用标准的 W3C WebDriver 工具（Selenium、WebdriverIO 等）
+/// 对运行中客户端的内嵌 UI 编写自动化脚本。
+///
+/// 服务器由 Servo 提供，驱动第一个 Servo 线程上的 view（`servo_threads` 不大于 `1` 时即所有 view）。view
+/// 由宿主创建，因此窗口管理命令（新建窗口、窗口矩形）不会生效。以相同端口再次调用不做任何事。仅启用
+/// `webdriver` feature 的构建支持该功能（`XIAN_WEB_ENGINE_CAPABILITY_WEBDRIVER`）；服务器接受未经认证的连接，
+/// 切勿在发布版客户端中启用。
+///
+/// 若 `engine` 为空指针、`port` 为 `0` 或无法绑定（例如已被占用）、服务器已在其他端口监听、构建未包含
+/// WebDriver，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_enable_webdriver(
+    engine: *mut XianWebEngine,
+    port: u16,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_enable_webdriver, engine, port);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if port == 0 {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!("port must not be 0"),
+        );
+        return false;
+    }
+
+    match runtime.enable_webdriver(port) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Reports the display timing to vsync-driven views so Servo starts frames at the predicted vsync
+/// instants instead of whenever `xian_web_engine_tick` runs.
+///
+/// Keep calling `xian_web_engine_tick`: it still drains callbacks queued before the timing was set.
+/// Call again when the refresh rate changes or the prediction drifts.
+///
+/// #### Parameters
+/// - `refresh_rate_hz`: Display refresh rate in `(0, 1000]` (`0` returns to tick-driven frames).
+/// - `next_vsync_nanos`: Time of an upcoming vsync on the `xian_web_engine_monotonic_time_ns`
+///   clock (`0` = phase unknown, vsyncs are assumed to start now).
+///
+/// ### 中文
+/// 向由 vsync 驱动的 view 上报显示时序，使 Servo 在预测的 vsync 时刻启动帧，而不是在 `xian_web_engine_tick`
+/// 恰好运行时。
+///
+/// 仍需继续调用 `xian_web_engine_tick`：它仍会执行设置时序之前排队的回调。刷新率变化或预测发生漂移时请再次调用。
+///
+/// #### 参数
+/// - `refresh_rate_hz`：显示刷新率，范围 `(0, 1000]`（`0` 表示恢复由 tick 驱动的帧）。
+/// - `next_vsync_nanos`：即将到来的某次 vsync 的时间，基于 `xian_web_engine_monotonic_time_ns` 时钟（`0` 表示
+///   相位未知，假定 vsync 从现在开始）。
+pub unsafe extern "C" fn xian_web_engine_set_vsync_info(
+    engine: *mut XianWebEngine,
+    refresh_rate_hz: f64,
+    next_vsync_nanos: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_vsync_info,
+        engine,
+        refresh_rate_hz,
+        next_vsync_nanos
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    match runtime.set_vsync_info(refresh_rate_hz, next_vsync_nanos) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the process-wide engine initialization progress callback (`NULL` removes it).
+///
+/// Install it before `xian_web_engine_create*` to drive a loading screen: each Servo thread reports
+/// `XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT` and `XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT`, then
+/// `XIAN_WEB_ENGINE_INIT_PHASE_READY` is reported once right before creation returns.
+/// `XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED` follows per Servo thread when its first view is
+/// created (e.g. by `xian_web_engine_preload`). The callback runs on the Servo threads or the
+/// creating thread and must return quickly.
+///
+/// ### 中文
+/// 安装进程级的引擎初始化进度回调（传 `NULL` 表示移除）。
+///
+/// 在 `xian_web_engine_create*` 之前安装即可驱动加载界面：每个 Servo 线程依次上报
+/// `XIAN_WEB_ENGINE_INIT_PHASE_SERVO_BUILT` 与 `XIAN_WEB_ENGINE_INIT_PHASE_GL_CONTEXT`，随后在创建返回前上报一次
+/// `XIAN_WEB_ENGINE_INIT_PHASE_READY`。每个 Servo 线程创建第一个 view 时（例如由 `xian_web_engine_preload`
+/// 触发）会再上报 `XIAN_WEB_ENGINE_INIT_PHASE_SHADERS_CACHED`。回调在 Servo 线程或创建引擎的线程上执行，必须
+/// 尽快返回。
+pub unsafe extern "C" fn xian_web_engine_set_init_progress_callback(
+    callback: Option<XianWebEngineInitProgressFn>,
+    user_data: *mut c_void,
+) {
+    ffi_entry!(
+        xian_web_engine_set_init_progress_callback,
+        callback,
+        user_data
+    );
+    set_init_progress_sink(callback, user_data);
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Warms the network and image caches for `count` known UI URLs (NUL-terminated UTF-8, absolute),
+/// e.g. while a loading screen is shown. Returns immediately.
+///
+/// A hidden view requests every URL once and stays open until the next call or engine destruction;
+/// if no view exists yet, creating it also precompiles WebRender shaders.
+///
+/// Returns `false` if an argument is invalid, a URL cannot be parsed, or the engine is shutting
+/// down.
+///
+/// ### 中文
+/// 为 `count` 个已知 UI URL（NUL 结尾的 UTF-8 绝对 URL）预热网络与图片缓存，例如在显示加载界面期间。
+/// 本函数立即返回。
+///
+/// 一个隐藏 view 会将每个 URL 各请求一次，并保留到下一次调用或引擎销毁；若尚无 view，创建它还会预编译
+/// WebRender shader。
+///
+/// 若参数非法、URL 无法解析或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_preload(
+    engine: *mut XianWebEngine,
+    urls: *const *const c_char,
+    count: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_preload, engine, urls, count);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    let Some(urls) = (unsafe { validate::slice(call, "urls", urls, count as usize) }) else {
+        return false;
+    };
+
+    let mut list = Vec::with_capacity(urls.len());
+    for &url in urls {
+        let Some(url) = (unsafe { validate::cstr(call, "urls[]", url) }) else {
+            return false;
+        };
+        list.push(url);
+    }
+
+    match runtime.preload(&list) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Swaps the Servo resources directory at runtime (e.g. after a resource pack change).
+///
+/// The swap happens on the Servo thread; resources read afterwards come from `path`, while resources
+/// Servo already loaded keep their current contents.
+///
+/// Returns `false` if an argument is NULL/invalid, `path` is not a directory, or the engine is shutting
+/// down.
+///
+/// ### 中文
+/// 在运行时替换 Servo 资源目录（例如资源包变更后）。
+///
+/// 替换在 Servo 线程上进行；此后读取的资源来自 `path`，Servo 已加载的资源保持当前内容。
+///
+/// 若参数为空/非法、`path` 不是目录，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_resources_dir(
+    engine: *mut XianWebEngine,
+    path: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_resources_dir, engine, path);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return false;
+    };
+
+    runtime.set_resources_dir(path)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Swaps the Servo resources to a zip archive (`.zip`/`.jar`/`.pak`) at `path`.
+///
+/// The archive is read into memory and entries are inflated on demand; resource files are looked up
+/// at the archive root or under `resources/`. Like `xian_web_engine_set_resources_dir`, the swap
+/// happens on the Servo thread and affects resources read afterwards.
+///
+/// Returns `false` if an argument is NULL/invalid, the archive cannot be read or parsed, or the engine
+/// is shutting down.
+///
+/// ### 中文
+/// 将 Servo 资源替换为位于 `path` 的 zip 归档（`.zip`/`.jar`/`.pak`）。
+///
+/// 归档会被读入内存，条目按需解压；资源文件在归档根目录或 `resources/` 下查找。与
+/// `xian_web_engine_set_resources_dir` 相同，替换在 Servo 线程上进行，并影响此后读取的资源。
+///
+/// 若参数为空/非法、归档无法读取或解析，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_resources_archive(
+    engine: *mut XianWebEngine,
+    path: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_resources_archive, engine, path);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return false;
+    };
+
+    runtime.set_resources_archive_file(&path)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Same as `xian_web_engine_set_resources_archive`, but takes the archive as `len` bytes in memory
+/// (e.g. read from a mod jar on the Java side). The bytes are copied.
+///
+/// Returns `false` if an argument is NULL/invalid, the archive cannot be parsed, or the engine is
+/// shutting down.
+///
+/// ### 中文
+/// 与 `xian_web_engine_set_resources_archive` 相同，但以内存中的 `len` 字节传入归档（例如由 Java 侧从
+/// mod jar 读取）。字节会被复制。
+///
+/// 若参数为空/非法、归档无法解析，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_resources_archive_bytes(
+    engine: *mut XianWebEngine,
+    bytes: *const u8,
+    len: usize,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_resources_archive_bytes,
+        engine,
+        bytes,
+        len
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if len == 0 {
+        return false;
+    }
+    let Some(data) = (unsafe { validate::slice(call, "bytes", bytes, len) }) else {
+        return false;
+    };
+
+    runtime.set_resources_archive_bytes(data.to_vec())
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets the OS scheduling of the engine's own threads (the Servo thread and the refresh scheduler
+/// thread), e.g. to keep web work off the cores used by the game's render thread.
+///
+/// - `servo_thread_priority`: Windows `THREAD_PRIORITY_*` value (`-2` lowest .. `2` highest,
+///   `0` = normal).
+/// - `affinity_mask`: bit N allows logical CPU N; `0` keeps the current affinity.
+///
+/// Applied asynchronously and best-effort (Windows `SetThreadPriority`/`SetThreadAffinityMask`);
+/// ignored on other platforms. Servo's internal worker pools are not affected.
+///
+/// Returns `false` if `engine` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 设置引擎自有线程（Servo 线程与 refresh 调度线程）的操作系统调度，例如让网页工作避开游戏渲染线程所用的核心。
+///
+/// - `servo_thread_priority`：Windows `THREAD_PRIORITY_*` 值（`-2` 最低 .. `2` 最高，`0` 表示普通）。
+/// - `affinity_mask`：第 N 位允许逻辑 CPU N；`0` 表示保持当前亲和性。
+///
+/// 异步且尽力而为地应用（Windows `SetThreadPriority`/`SetThreadAffinityMask`）；其他平台忽略。
+/// 不影响 Servo 内部的工作线程池。
+///
+/// 若 `engine` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_thread_config(
+    engine: *mut XianWebEngine,
+    servo_thread_priority: i32,
+    affinity_mask: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_thread_config,
+        engine,
+        servo_thread_priority,
+        affinity_mask,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    runtime.set_thread_config(servo_thread_priority, affinity_mask)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Registers a font from `len` bytes in memory (TTF/OTF/WOFF/WOFF2) under the NUL-terminated UTF-8
+/// CSS family name `family_name`, so pages can use it via `font-family` without installing it.
+///
+/// The font applies to every view (existing views from their next load). The bytes are copied.
+///
+/// Returns `false` if an argument is NULL/empty/invalid or the engine is shutting down.
+///
+/// ### 中文
+/// 从内存中的 `len` 字节（TTF/OTF/WOFF/WOFF2）注册字体，CSS family 名称为 NUL 结尾 UTF-8 字符串
+/// `family_name`，使页面无需安装即可通过 `font-family` 使用。
+///
+/// 字体作用于所有 view（已存在的 view 从下一次加载开始生效）。字节会被复制。
+///
+/// 若参数为空指针/为空/非法，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_register_font(
+    engine: *mut XianWebEngine,
+    family_name: *const c_char,
+    bytes: *const u8,
+    len: usize,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_register_font,
+        engine,
+        family_name,
+        bytes,
+        len
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    let Some(family) = (unsafe { validate::cstr(call, "family_name", family_name) }) else {
+        return false;
+    };
+    if len == 0 {
+        return false;
+    }
+    let Some(data) = (unsafe { validate::slice(call, "bytes", bytes, len) }) else {
+        return false;
+    };
+
+    runtime.register_font(family, data.to_vec())
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Configures engine caches.
+///
+/// - `cache_dir`: optional NUL-terminated UTF-8 directory for files the engine writes to disk itself
+///   (e.g. staged uploads); NULL or empty keeps the current directory (default: a folder in the system
+///   temp directory).
+/// - `max_bytes`: HTTP cache budget. Servo's HTTP cache lives in memory and has no size limit setting,
+///   so `0` disables it and any other value enables it.
+///
+/// Returns `false` if `engine` is NULL, `cache_dir` cannot be created, or the engine is shutting down.
+///
+/// ### 中文
+/// 配置引擎缓存。
+///
+/// - `cache_dir`：可选的 NUL 结尾 UTF-8 目录，用于存放引擎自身写入磁盘的文件（例如暂存的上传文件）；
+///   NULL 或空字符串表示保持当前目录（默认位于系统临时目录下）。
+/// - `max_bytes`：HTTP 缓存预算。Servo 的 HTTP 缓存位于内存中且没有大小上限设置，因此 `0` 表示关闭，
+///   其他值表示开启。
+///
+/// 若 `engine` 为空指针、`cache_dir` 无法创建，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_cache_config(
+    engine: *mut XianWebEngine,
+    cache_dir: *const c_char,
+    max_bytes: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_cache_config,
+        engine,
+        cache_dir,
+        max_bytes
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    let cache_dir = unsafe { super::cstr_to_path(cache_dir) };
+    runtime.set_cache_config(cache_dir, max_bytes)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets a hard JavaScript heap limit for pages, protecting the engine's process from a leaking page
+/// (e.g. server-provided content).
+///
+/// The limit is Servo's process-wide `js_mem_max` preference: it is shared by every engine in the
+/// process (the last call wins) and applies to the script event loop of every page whose event loop
+/// starts afterwards; pages already running keep the limit they started with. A page that runs out
+/// of it stops running scripts: the engine unloads it (`about:blank`) and queues a
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT` event, which also reports a page whose script thread
+/// crashed instead. `limit_mb = 0` removes the limit (default).
+///
+/// Returns `false` if `engine` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 为页面设置硬性 JavaScript 堆上限，保护引擎所在进程不受泄漏页面（例如服务器提供的内容）影响。
+///
+/// 该上限是 Servo 的进程级 `js_mem_max` 首选项：由进程内的所有引擎共享（以最后一次调用为准），作用于之后启动
+/// 事件循环的每个页面的脚本事件循环；已在运行的页面保留其启动时的上限。耗尽上限的页面将停止执行脚本：引擎会
+/// 卸载它（`about:blank`）并排入 `XIAN_WEB_ENGINE_VIEW_EVENT_HEAP_LIMIT` 事件；若页面的脚本线程因此崩溃，
+/// 也会以该事件上报。`limit_mb = 0` 表示取消上限（默认）。
+///
+/// 若 `engine` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_heap_limit(
+    engine: *mut XianWebEngine,
+    limit_mb: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_heap_limit, engine, limit_mb);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    runtime.set_heap_limit(limit_mb)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Clears Servo's HTTP cache and deletes the files staged in the engine cache directory.
+///
+/// Returns `false` if `engine` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 清空 Servo 的 HTTP 缓存，并删除引擎缓存目录中暂存的文件。
+///
+/// 若 `engine` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_clear_http_cache(engine: *mut XianWebEngine) -> bool {
+    let call = ffi_entry!(xian_web_engine_clear_http_cache, engine);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    runtime.clear_http_cache()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Limits the network traffic pages can generate, so a web UI cannot saturate the player's connection
+/// (e.g. during multiplayer).
+///
+/// The limits are enforced by the loopback proxy that carries all HTTP(S) traffic, so they cover
+/// documents, subresources, `fetch` / XHR and workers of every engine running a proxy (engines
+/// created with `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` or after limits or a resolver were
+/// installed), and take effect immediately: at most
+/// `max_connections` server connections are open at once (the rest wait; idle keep-alive
+/// connections are closed for them), and reads from servers are paced to `max_bytes_per_second` in
+/// total.
+/// WebSocket connections are not limited. The limits are process-wide: every engine shares them and
+/// the last call wins. A `0` field disables that limit; all-zero limits (the default) remove the
+/// enforcement entirely.
+///
+/// Returns `false` if `engine` or `limits` is NULL, or the engine is shutting down.
+///
+/// ### 中文
+/// 限制页面可产生的网络流量，避免网页 UI 占满玩家的网络连接（例如多人游戏期间）。
+///
+/// 限制由承载全部 HTTP(S) 流量的回环代理执行，因此作用于每个运行代理的引擎（以
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY` 创建，或在安装限制或解析器之后创建的引擎）的文档、子资源、
+/// `fetch` / XHR 与 worker，并立即生效：
+/// 同时最多打开 `max_connections` 个服务器连接（其余排队等待，并为其关闭空闲的 keep-alive 连接），
+/// 从服务器读取的数据总计按 `max_bytes_per_second` 限速。WebSocket 连接不受限制。限制为进程级：
+/// 所有引擎共享，以最后一次调用为准。字段为 `0` 表示关闭对应限制；全部为 0（默认）则完全不做限制。
+///
+/// 若 `engine` 或 `limits` 为空指针，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_network_limits(
+    engine: *mut XianWebEngine,
+    limits: *const XianWebEngineNetworkLimits,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_network_limits, engine, limits);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "limits", limits) {
+        return false;
+    }
+
+    runtime.set_network_limits(unsafe { *limits })
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Limits the size of images pages display, so a page showing a huge image (e.g. 16k × 16k) does
+/// not spend a frame's worth of time and VRAM on it.
+///
+/// From each view's next navigation, every `<img>` wider than `max_width`, taller than
+/// `max_height` or with more than `max_pixels` pixels is scaled down (keeping its aspect ratio and
+/// layout size) before it is shown, and a `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED` event is
+/// queued. Servo still decodes the original once, and cross-origin images are left as they are.
+/// How many images decode at once is set by `image_cache_threads` in the thread pool config. A `0`
+/// field disables that limit; all-zero limits (the default) remove the enforcement entirely.
+///
+/// Returns `false` if `engine` or `limits` is NULL, or the engine is shutting down.
+///
+/// ### 中文
+/// 限制页面所显示图片的尺寸，避免页面显示超大图片（例如 16k × 16k）时耗费整帧时间与大量显存。
+///
+/// 从各 view 的下一次导航开始，宽度超过 `max_width`、高度超过 `max_height` 或像素数超过 `max_pixels` 的
+/// `<img>` 会在显示前被等比缩小（保持其布局尺寸），并排入 `XIAN_WEB_ENGINE_VIEW_EVENT_IMAGE_DOWNSCALED`
+/// 事件。Servo 仍会解码一次原图，跨源图片保持原样。同时解码的图片数量由线程池配置中的
+/// `image_cache_threads` 设置。字段为 `0` 表示关闭对应限制；全部为 0（默认）则完全不做限制。
+///
+/// 若 `engine` 或 `limits` 为空指针，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_image_limits(
+    engine: *mut XianWebEngine,
+    limits: *const XianWebEngineImageLimits,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_image_limits, engine, limits);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "limits", limits) {
+        return false;
+    }
+
+    runtime.set_image_limits(unsafe { *limits })
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the image load statistics of the engine into `out`: how many image loads pages made, how
+/// many of them loaded a URL already loaded on the same Servo thread (which Servo's image cache can
+/// serve without decoding again), how many of those repeats came from another view than the first
+/// load, and the distinct URLs loaded with their size at 4 bytes per pixel.
+///
+/// Loads are reported for `<img>` elements (not CSS images, `data:` or `blob:` URLs) and counted
+/// per Servo thread, since each one has its own image cache. The statistics describe what pages
+/// load; Servo exposes neither the contents nor the size of its image cache. Safe to call from any
+/// thread.
+///
+/// Returns `false` if `engine` or `out` is NULL.
+///
+/// ### 中文
+/// 将引擎的图片加载统计写入 `out`：页面发起的图片加载次数、其中加载同一 Servo 线程上已加载过之 URL 的次数
+/// （Servo 的图片缓存无需再次解码即可提供）、这些重复加载中来自首次加载者以外 view 的次数，以及已加载的不同 URL
+/// 及其按每像素 4 字节计算的大小。
+///
+/// 加载按 `<img>` 元素上报（不含 CSS 图片、`data:` 与 `blob:` URL），并按 Servo 线程分别统计，因为每个线程
+/// 有各自的图片缓存。这些统计描述的是页面加载了什么；Servo 既不公开其图片缓存的内容也不公开其大小。可在任意
+/// 线程调用。
+///
+/// 若 `engine` 或 `out` 为空指针，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_get_image_load_stats(
+    engine: *mut XianWebEngine,
+    out: *mut XianWebEngineImageLoadStats,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_get_image_load_stats, engine, out);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "out", out) {
+        return false;
+    }
+
+    unsafe { *out = runtime.image_load_stats() };
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Switches the engine network mode (`XIAN_WEB_ENGINE_NETWORK_MODE_*`), e.g. to guarantee no
+/// traffic leaves the game while the player is offline.
+///
+/// `OFFLINE` fails every `http(s)` / `ws(s)` / `ftp` request; `CACHE_ONLY` only serves `http(s)`
+/// responses Servo's HTTP cache holds and can use without contacting the server. Local schemes
+/// (`data:`, `blob:`, `file:`, engine resources) always work. Besides cancelling requests, the
+/// engine's loopback proxy refuses every connection to a server and closes the open ones while not
+/// online; an engine running without a proxy (see `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`)
+/// relies on request cancellation alone and treats `CACHE_ONLY` as `OFFLINE`. The mode applies to
+/// every view immediately, including the pages currently shown. Blocked requests show up to the
+/// page as network errors.
+///
+/// WebRTC is switched off through Servo's `dom_webrtc_enabled` preference, which is process-wide:
+/// while any engine of the process is not online, documents created anywhere in the process have no
+/// `RTCPeerConnection`; documents created earlier keep theirs.
+///
+/// Returns `false` if `engine` is NULL, `mode` is unknown, or the engine is shutting down.
+///
+/// ### 中文
+/// 切换引擎网络模式（`XIAN_WEB_ENGINE_NETWORK_MODE_*`），例如保证玩家离线时不会有任何流量离开游戏。
+///
+/// `OFFLINE` 会让所有 `http(s)` / `ws(s)` / `ftp` 请求失败；`CACHE_ONLY` 只提供 Servo HTTP 缓存中无需联系
+/// 服务器即可使用的 `http(s)` 响应。本地 scheme（`data:`、`blob:`、`file:`、引擎资源）始终可用。除取消请求外，
+/// 非在线模式下引擎的回环代理会拒绝所有到服务器的连接并关闭已打开的连接；未运行代理的引擎（见
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_NETWORK_PROXY`）只依靠取消请求，并将 `CACHE_ONLY` 按 `OFFLINE` 处理。
+/// 模式立即应用到所有 view，包括当前显示的页面。被拦截的请求在页面看来是网络错误。
+///
+/// WebRTC 通过 Servo 的 `dom_webrtc_enabled` 首选项关闭，该首选项是进程级的：只要进程内任一引擎处于非在线
+/// 模式，进程内新创建的文档都没有 `RTCPeerConnection`；此前创建的文档保留原有的接口。
+///
+/// 若 `engine` 为空指针、`mode` 未知或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_network_mode(
+    engine: *mut XianWebEngine,
+    mode: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_network_mode, engine, mode);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    let modes = [
+        XIAN_WEB_ENGINE_NETWORK_MODE_ONLINE,
+        XIAN_WEB_ENGINE_NETWORK_MODE_OFFLINE,
+        XIAN_WEB_ENGINE_NETWORK_MODE_CACHE_ONLY,
+    ];
+    if !validate::enum_value(call, "mode", mode, &modes) {
+        return false;
     }
 
-    unsafe { (*engine).runtime.tick() };
+    runtime.set_network_mode(mode)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes a health snapshot of the engine into `out`, so the host can detect a stuck Servo thread
+/// (e.g. a hung script) and show a "web UI not responding" overlay or destroy the engine.
+///
+/// `millis_since_heartbeat` is how long the Servo thread has been busy in its current loop iteration
+/// (`0` while idle). Safe to call from any thread, even while the Servo thread is stuck.
+///
+/// Returns `false` if `engine` or `out` is NULL.
+///
+/// ### 中文
+/// 将引擎健康快照写入 `out`，使宿主能检测 Servo 线程卡住（例如脚本卡死），并显示“网页 UI 无响应”
+/// 遮罩或销毁引擎。
+///
+/// `millis_since_heartbeat` 表示 Servo 线程在当前循环迭代中已忙碌的时长（空闲时为 `0`）。可在任意线程调用，
+/// 即使 Servo 线程已卡住。
+///
+/// 若 `engine` 或 `out` 为空指针，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_get_health(
+    engine: *mut XianWebEngine,
+    out: *mut XianWebEngineHealth,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_get_health, engine, out);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "out", out) {
+        return false;
+    }
+
+    unsafe { *out = runtime.health() };
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the process-wide producer fence statistics into `out`: how many `GLsync` fences the
+/// Servo threads of every engine created and deleted, in total and per second.
+///
+/// Fences are not pooled (a `GLsync` cannot be re-armed), so `created_per_second` tracks the
+/// published frame rate of fenced views. The rates cover the last window of at least one second and
+/// roll over on query, so poll about once per second. Safe to call from any thread.
+///
+/// Returns `false` if `out` is NULL.
+///
+/// ### 中文
+/// 将进程级的生产者 fence 统计写入 `out`：所有引擎的 Servo 线程创建与删除的 `GLsync` fence 总数及每秒数量。
+///
+/// fence 不会被池化（`GLsync` 无法重新布置），因此 `created_per_second` 反映使用 fence 的 view 的发布帧率。速率
+/// 覆盖最近一个不短于 1 秒的窗口，并在查询时滚动，因此建议约每秒轮询一次。可在任意线程调用。
+///
+/// 若 `out` 为空指针，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_get_fence_stats(
+    out: *mut XianWebEngineFenceStats,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_get_fence_stats, out);
+    if !validate::pointer(call, "out", out) {
+        return false;
+    }
+
+    unsafe { *out = fence_stats() };
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the most recent tracing spans to `path` as a Chrome trace JSON file (open it in
+/// `chrome://tracing` or Perfetto) to diagnose frame hitches end-to-end.
+///
+/// Spans are always recorded into a fixed process-wide ring (the latest 16384 are kept): Servo
+/// command drains, `spin_event_loop`, paint and present on the Servo thread(s), and frame acquires
+/// on the embedder's threads. Safe to call from any thread.
+///
+/// Returns `false` if an argument is NULL/invalid or the file cannot be written.
+///
+/// ### 中文
+/// 将最近的追踪 span 以 Chrome trace JSON 文件写入 `path`（可在 `chrome://tracing` 或 Perfetto 中打开），
+/// 用于端到端诊断掉帧。
+///
+/// span 始终记录在一个固定大小的进程级环形缓冲区中（保留最近 16384 个）：Servo 线程上的命令 drain、
+/// `spin_event_loop`、paint 与 present，以及宿主线程上的帧 acquire。可在任意线程调用。
+///
+/// 若参数为空/非法或文件无法写入，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_dump_trace(
+    engine: *mut XianWebEngine,
+    path: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_dump_trace, engine, path);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return false;
+    };
+
+    runtime.dump_trace(&path)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Turns Servo's time profiler on or off without rebuilding the library.
+///
+/// `categories` is a combination of `XIAN_WEB_ENGINE_PROFILING_*` (`0` turns profiling off);
+/// `output_path` is required unless `categories` is `0`. Servo reads its profiler options once when
+/// it starts, so the setting is process-wide and applies to engines created afterwards: to profile a
+/// running UI, call this and then recreate the engine (destroy + create restarts Servo). Reports are
+/// written when that Servo instance shuts down. Engines already running are not affected, so the
+/// call takes no engine handle.
+///
+/// Returns `false` if `categories` has unknown bits or `output_path` is NULL/invalid while
+/// profiling is requested.
+///
+/// ### 中文
+/// 无需重新构建库即可开启或关闭 Servo 时间分析器。
+///
+/// `categories` 为 `XIAN_WEB_ENGINE_PROFILING_*` 的组合（`0` 表示关闭）；除非 `categories` 为 `0`，否则
+/// `output_path` 必填。Servo 只在启动时读取一次分析选项，因此该设置是进程级的，作用于之后创建的引擎：
+/// 若要分析正在运行的 UI，请调用本函数后重新创建引擎（销毁 + 创建会重启 Servo）。报告会在该 Servo 实例关闭
+/// 时写出。已在运行的引擎不受影响，因此本函数不接收引擎句柄。
+///
+/// 若 `categories` 含未知位，或请求分析时 `output_path` 为空/非法，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_profiling(
+    categories: u32,
+    output_path: *const c_char,
+) -> bool {
+    ffi_entry!(xian_web_engine_set_profiling, categories, output_path);
+    let output_path = unsafe { super::cstr_to_path(output_path) };
+    profiling::set_profiling(categories, output_path)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns the status of the last request the calling thread sent to the engine
+/// (`XIAN_WEB_ENGINE_STATUS_*`).
+///
+/// Call it after a request function returned `false` to tell a transient rejection apart from a
+/// permanent one:
+///
+/// - `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL`: the request's command lane is full; retry later.
+/// - `XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN`: the engine is shutting down.
+/// - `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`: a blocking call (engine or view creation, view group
+///   creation) gave up waiting for a Servo thread (see `xian_web_engine_create_with_config`).
+///
+/// View destruction and engine shutdown use an unbounded urgent lane and are never rejected for
+/// capacity; bulky requests (fonts, exports, user scripts) use a small lane so they cannot crowd out
+/// ordinary ones.
+///
+/// ### 中文
+/// 返回调用线程最近一次发送给引擎的请求的状态（`XIAN_WEB_ENGINE_STATUS_*`）。
+///
+/// 在请求函数返回 `false` 后调用，可区分暂时性拒绝与永久性拒绝：
+///
+/// - `XIAN_WEB_ENGINE_STATUS_QUEUE_FULL`：请求所在的命令通道已满；请稍后重试。
+/// - `XIAN_WEB_ENGINE_STATUS_SHUTTING_DOWN`：引擎正在关闭。
+/// - `XIAN_WEB_ENGINE_STATUS_TIMED_OUT`：阻塞调用（引擎或 view 创建、view group 创建）放弃了对 Servo 线程的等待
+///   （见 `xian_web_engine_create_with_config`）。
+///
+/// view 销毁与引擎关闭使用无界的紧急通道，永远不会因容量而被拒绝；体积大的请求（字体、导出、用户脚本）
+/// 使用较小的通道，避免挤占普通请求。
+pub extern "C" fn xian_web_engine_last_command_status() -> i32 {
+    ffi_entry!(xian_web_engine_last_command_status);
+    EngineRuntime::last_command_status()
 }
@@ -0,0 +1,94 @@
+//! ### English
+//! C ABI bindings for polling view events.
+//!
+//! ### 中文
+//! 轮询 view 事件的 C ABI 绑定。
+
+use crate::engine::{XianWebEngineViewEvent, XianWebEngineViewQueueStats};
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Polls queued events of a view into `out_events` (at most `capacity` entries).
+///
+/// Text payloads are copied into `text_buf` and referenced by `text_offset/text_len`; `text_buf` may
+/// be NULL when `text_buf_capacity = 0`. An event whose text does not fit into the remaining buffer is
+/// kept for the next call. Returns the number of events written.
+///
+/// ### 中文
+/// 将 view 的已排队事件轮询到 `out_events`（最多 `capacity` 个）。
+///
+/// 文本载荷会复制到 `text_buf`，并通过 `text_offset/text_len` 引用；当 `text_buf_capacity = 0` 时
+/// `text_buf` 可为 NULL。若某事件的文本放不进剩余缓冲区，则会保留到下一次调用。返回写入的事件数。
+pub unsafe extern "C" fn xian_web_engine_view_poll_events(
+    view: *mut XianWebEngineView,
+    out_events: *mut XianWebEngineViewEvent,
+    capacity: u32,
+    text_buf: *mut u8,
+    text_buf_capacity: u32,
+) -> u32 {
+    let call = ffi_entry!(
+        xian_web_engine_view_poll_events,
+        view,
+        out_events,
+        capacity,
+        text_buf,
+        text_buf_capacity,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    if capacity == 0 {
+        return 0;
+    }
+    let Some(out_events) =
+        (unsafe { validate::slice_mut(call, "out_events", out_events, capacity as usize) })
+    else {
+        return 0;
+    };
+    let text_buf: &mut [u8] = if text_buf.is_null() || text_buf_capacity == 0 {
+        &mut []
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(text_buf, text_buf_capacity as usize) }
+    };
+
+    handle.poll_events(out_events, text_buf) as u32
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the monotonic delivery counters of `view`'s queues into `out`.
+///
+/// Event and input queues are bounded and drop on overflow. Keep the previous snapshot and compare:
+/// if `events_dropped`, `input_dropped` or `binary_messages_dropped` grew, something was lost since
+/// then and the embedder should resync instead of silently desyncing (e.g. re-query the URL/title
+/// with `xian_web_engine_view_get_url` / `xian_web_engine_view_get_title`). Safe to call from any
+/// thread.
+///
+/// Returns `false` if `view` or `out` is NULL.
+///
+/// ### 中文
+/// 将 `view` 各队列单调递增的投递计数写入 `out`。
+///
+/// 事件队列与输入队列都是有界的，溢出时会丢弃。保留上一次快照并进行比较：若 `events_dropped`、`input_dropped`
+/// 或 `binary_messages_dropped` 增长，说明期间有内容丢失，宿主应重新同步而不是悄然失步（例如通过
+/// `xian_web_engine_view_get_url` / `xian_web_engine_view_get_title` 重新查询 URL/标题）。可在任意线程调用。
+///
+/// 若 `view` 或 `out` 为空指针，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_get_queue_stats(
+    view: *mut XianWebEngineView,
+    out: *mut XianWebEngineViewQueueStats,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_get_queue_stats, view, out);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "out", out) {
+        return false;
+    }
+
+    unsafe { *out = handle.queue_stats() };
+    true
+}
@@ -4,6 +4,12 @@
 //! ### 中文
 //! 帧获取与释放相关的 C ABI 绑定。
 
+use std::ffi::c_void;
+
+use crate::engine::XianWebEngineFrameFn;
+use crate::engine::clock;
+
+use super::validate::{self, ffi_entry};
 use super::{XianWebEngineFrame, XianWebEngineView};
 
 #[unsafe(no_mangle)]
@@ -36,22 +42,36 @@ pub unsafe extern "C" fn xian_web_engine_views_acquire_frames(
     out_frames: *mut XianWebEngineFrame,
     count: u32,
 ) -> u32 {
-    if views.is_null() || out_view_indices.is_null() || out_frames.is_null() || count == 0 {
-        return 0;
-    }
-
+    let call = ffi_entry!(
+        xian_web_engine_views_acquire_frames,
+        views,
+        out_view_indices,
+        out_frames,
+        count,
+    );
     let count = count as usize;
-    let view_ptrs = unsafe { std::slice::from_raw_parts(views, count) };
-    let indices_out = unsafe { std::slice::from_raw_parts_mut(out_view_indices, count) };
-    let frames_out = unsafe { std::slice::from_raw_parts_mut(out_frames, count) };
+    let Some(view_ptrs) = (unsafe { validate::slice(call, "views", views, count) }) else {
+        return 0;
+    };
+    let Some(indices_out) =
+        (unsafe { validate::slice_mut(call, "out_view_indices", out_view_indices, count) })
+    else {
+        return 0;
+    };
+    let Some(frames_out) = (unsafe { validate::slice_mut(call, "out_frames", out_frames, count) })
+    else {
+        return 0;
+    };
 
     let mut acquired = 0usize;
     for (i, &view_ptr) in view_ptrs.iter().enumerate() {
         if view_ptr.is_null() {
             continue;
         }
+        let Some(view_handle) = (unsafe { validate::view(call, view_ptr) }) else {
+            continue;
+        };
 
-        let view_handle = unsafe { &(*view_ptr).handle };
         if let Some(frame) = view_handle.acquire_frame() {
             indices_out[acquired] = i as u32;
             frames_out[acquired] = frame.into();
@@ -95,36 +115,167 @@ pub unsafe extern "C" fn xian_web_engine_views_release_frames(
     consumer_fences: *const u64,
     count: u32,
 ) {
-    if views.is_null() || slots.is_null() || count == 0 {
-        return;
-    }
-
+    let call = ffi_entry!(
+        xian_web_engine_views_release_frames,
+        views,
+        slots,
+        consumer_fences,
+        count,
+    );
     let count = count as usize;
-    let view_ptrs = unsafe { std::slice::from_raw_parts(views, count) };
-    let slot_values = unsafe { std::slice::from_raw_parts(slots, count) };
-
-    if consumer_fences.is_null() {
-        for i in 0..count {
-            let view = view_ptrs[i];
-            if view.is_null() {
-                continue;
-            }
-            unsafe { (*view).handle.release_slot_with_fence(slot_values[i], 0) };
-        }
+    let Some(view_ptrs) = (unsafe { validate::slice(call, "views", views, count) }) else {
         return;
-    }
+    };
+    let Some(slot_values) = (unsafe { validate::slice(call, "slots", slots, count) }) else {
+        return;
+    };
+    let consumer_fence_values = if consumer_fences.is_null() {
+        None
+    } else {
+        let Some(values) =
+            (unsafe { validate::slice(call, "consumer_fences", consumer_fences, count) })
+        else {
+            return;
+        };
+        Some(values)
+    };
 
-    let consumer_fence_values = unsafe { std::slice::from_raw_parts(consumer_fences, count) };
     for i in 0..count {
         let view = view_ptrs[i];
         if view.is_null() {
             continue;
         }
-
-        unsafe {
-            (*view)
-                .handle
-                .release_slot_with_fence(slot_values[i], consumer_fence_values[i])
+        let Some(handle) = (unsafe { validate::view(call, view) }) else {
+            continue;
         };
+        if !validate::slot(call, slot_values[i]) {
+            continue;
+        }
+
+        let consumer_fence = consumer_fence_values.map_or(0, |values| values[i]);
+        handle.release_slot_with_fence(slot_values[i], consumer_fence);
     }
 }
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Pins a frame acquired from `view` so it can be kept across many game frames (e.g. for map render
+/// caching or screenshots) without starving the view.
+///
+/// A held frame is already never overwritten, but while one slot is kept the producer has only two
+/// left and would keep reclaiming the newest READY frame ahead of painting, so the next acquire
+/// often finds nothing. A pinned slot is left out of rotation instead: the producer runs
+/// double-buffered on the other two slots and only takes the previous frame once the new one is
+/// done, so `xian_web_engine_views_acquire_frames` keeps returning fresh frames. Releasing a pinned
+/// slot is ignored; call `xian_web_engine_unpin_view_frame` first, then release it as usual. Unpin
+/// and release every pinned frame before destroying the view.
+///
+/// Returns `false` if `view` is NULL, `slot` is out of range, or the slot is not currently held.
+///
+/// ### 中文
+/// 固定一个从 `view` acquire 的帧，使其可以跨多个游戏帧保留（例如用于地图渲染缓存或截图），而不会让该 view
+/// 无帧可用。
+///
+/// 被持有的帧本就不会被覆盖，但保留一个槽位时生产者只剩两个槽位，并会在绘制之前不断回收最新的 READY 帧，导致
+/// 下一次 acquire 经常一无所获。被固定的槽位则会被排除在轮转之外：生产者在另外两个槽位上以双缓冲方式运行，
+/// 并且只在新帧完成后才取得上一帧，因此 `xian_web_engine_views_acquire_frames` 能持续返回新帧。释放被固定
+/// 的槽位会被忽略；请先调用 `xian_web_engine_unpin_view_frame`，再照常释放。销毁 view 之前请取消固定并释放
+/// 所有被固定的帧。
+///
+/// 若 `view` 为空指针、`slot` 越界，或该槽位当前未被持有，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_pin_view_frame(
+    view: *mut XianWebEngineView,
+    slot: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_pin_view_frame, view, slot);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::slot(call, slot) {
+        return false;
+    }
+
+    handle.pin_frame(slot)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Unpins a frame pinned by `xian_web_engine_pin_view_frame`. The frame is held again: release it
+/// with `xian_web_engine_views_release_frames` as usual once done with it.
+///
+/// Returns `false` if `view` is NULL, `slot` is out of range, or the slot is not pinned.
+///
+/// ### 中文
+/// 取消由 `xian_web_engine_pin_view_frame` 固定的帧。该帧重新处于持有状态：用完后照常通过
+/// `xian_web_engine_views_release_frames` 释放。
+///
+/// 若 `view` 为空指针、`slot` 越界，或该槽位未被固定，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_unpin_view_frame(
+    view: *mut XianWebEngineView,
+    slot: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_unpin_view_frame, view, slot);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::slot(call, slot) {
+        return false;
+    }
+
+    handle.unpin_frame(slot)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the view's frame-available callback (`NULL` removes it).
+///
+/// The callback runs on the Servo thread right after a new frame is published and receives its
+/// `frame_seq`, so an embedder can wake its render thread only when there is something to acquire
+/// instead of polling every frame. Acquire/release still happen on the render thread. The callback
+/// must return quickly and must not replace or remove its own view's callback. Replacing or
+/// removing the callback (including destroying the view, which removes it) waits for a call in
+/// flight, so once this function returns the previous callback is not running and will not run
+/// again, and its `user_data` may be freed.
+///
+/// ### 中文
+/// 安装该 view 的帧可用回调（传 `NULL` 表示移除）。
+///
+/// 回调在 Servo 线程上、新帧发布后立即执行，并接收其 `frame_seq`；宿主可据此仅在有帧可 acquire 时唤醒
+/// 渲染线程，而无需每帧轮询。acquire/release 仍在渲染线程上进行。回调必须尽快返回，且不应替换或移除其所属
+/// view 的回调。替换或移除回调（包括会移除回调的 view 销毁）会等待正在进行的调用，因此本函数返回后上一个
+/// 回调既不在执行、也不会再被调用，其 `user_data` 可以释放。
+pub unsafe extern "C" fn xian_web_engine_set_frame_callback(
+    view: *mut XianWebEngineView,
+    callback: Option<XianWebEngineFrameFn>,
+    user_data: *mut c_void,
+) {
+    let call = ffi_entry!(
+        xian_web_engine_set_frame_callback,
+        view,
+        callback,
+        user_data
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return;
+    };
+
+    handle.set_frame_callback(callback, user_data);
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns the current time on the engine monotonic clock, in nanoseconds: the clock
+/// `XianWebEngineFrame.present_time_ns` is measured on.
+///
+/// Subtract a frame's `present_time_ns` from it to get the frame's age, or sample it next to the
+/// host's own clock once to convert between the two. Safe to call from any thread.
+///
+/// ### 中文
+/// 返回引擎单调时钟上的当前时间（纳秒），即 `XianWebEngineFrame.present_time_ns` 所使用的时钟。
+///
+/// 用它减去某帧的 `present_time_ns` 即可得到该帧的“年龄”；或与宿主自身时钟同时采样一次，以便在两者之间换算。
+/// 可在任意线程调用。
+pub extern "C" fn xian_web_engine_monotonic_time_ns() -> u64 {
+    ffi_entry!(xian_web_engine_monotonic_time_ns);
+    clock::monotonic_nanos()
+}
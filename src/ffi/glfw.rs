@@ -1,17 +1,25 @@
 //! ### English
-//! C ABI bindings for installing the embedder-provided GLFW API table.
+//! C ABI bindings for installing the embedder-provided GLFW API table and main-thread trampoline.
 //!
 //! ### 中文
-//! 安装宿主提供的 GLFW API 函数表的 C ABI 绑定。
+//! 安装宿主提供的 GLFW API 函数表与主线程 trampoline 的 C ABI 绑定。
 
-use crate::engine::{EmbedderGlfwApi, install_embedder_glfw_api};
+use std::ffi::c_void;
+
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+use crate::engine::{
+    EmbedderGlfwApi, XianWebEngineMainThreadFn, install_embedder_glfw_api,
+    install_main_thread_trampoline,
+};
+
+use super::validate::{self, ffi_entry};
 
 #[unsafe(no_mangle)]
 /// ### English
 /// Installs an embedder-provided GLFW function table.
 ///
 /// This must be called before `xian_web_engine_create`.
-/// The engine will not attempt to locate `glfw3.dll/glfw.dll` by name.
+/// The engine will not attempt to locate `glfw3.dll` / `libglfw.so` by name.
 ///
 /// All function pointers must come from the same GLFW library instance that produced the
 /// `GLFWwindow*` passed to `xian_web_engine_create`.
@@ -21,17 +29,67 @@ use crate::engine::{EmbedderGlfwApi, install_embedder_glfw_api};
 /// ### 中文
 /// 安装由宿主提供的 GLFW 函数表。
 ///
-/// 必须在 `xian_web_engine_create` 之前调用；引擎不会再按名称查找/加载 `glfw3.dll/glfw.dll`。
+/// 必须在 `xian_web_engine_create` 之前调用；引擎不会再按名称查找/加载 `glfw3.dll` / `libglfw.so`。
 ///
 /// 所有函数指针必须来自同一个 GLFW 库实例（也就是创建 `xian_web_engine_create` 传入的
 /// `GLFWwindow*` 的那个实例）。
 ///
 /// 成功返回 `true`。
 pub unsafe extern "C" fn xian_web_engine_set_glfw_api(api: *const EmbedderGlfwApi) -> bool {
-    if api.is_null() {
+    let call = ffi_entry!(xian_web_engine_set_glfw_api, api);
+    if !validate::non_null(call, "api", api) {
         return false;
     }
 
     let api = unsafe { std::ptr::read_unaligned(api) };
     install_embedder_glfw_api(api).is_ok()
 }
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the main-thread trampoline (once per process, before `xian_web_engine_create`).
+///
+/// GLFW only allows window creation/destruction on the main thread, and macOS enforces it. With a
+/// trampoline installed, the Servo threads hand those calls to `callback`, which must arrange for
+/// `task(task_data)` to run on the main thread (immediately or on the next frame) without waiting
+/// for it. While the main thread is blocked in `xian_web_engine_create*` or
+/// `xian_web_engine_destroy`, it runs the queued calls itself, so those must be called on the main
+/// thread too. Required on macOS; optional elsewhere.
+///
+/// Returns `false` if `callback` is NULL or a trampoline is already installed.
+///
+/// ### 中文
+/// 安装主线程 trampoline（每个进程一次，须在 `xian_web_engine_create` 之前）。
+///
+/// GLFW 只允许在主线程上创建/销毁 window，macOS 会强制执行这一点。安装 trampoline 后，Servo 线程会把
+/// 这些调用交给 `callback`；它必须安排 `task(task_data)` 在主线程上执行（立即或在下一帧），且不等待其
+/// 完成。主线程阻塞在 `xian_web_engine_create*` 或 `xian_web_engine_destroy` 中时会自行执行排队的调用，
+/// 因此这些函数也必须在主线程上调用。macOS 上必需，其他平台可选。
+///
+/// 若 `callback` 为 NULL 或已安装过 trampoline，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_main_thread_trampoline(
+    callback: Option<XianWebEngineMainThreadFn>,
+    user_data: *mut c_void,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_main_thread_trampoline,
+        callback,
+        user_data
+    );
+    let Some(callback) = callback else {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+            call,
+            format_args!("callback is NULL"),
+        );
+        return false;
+    };
+
+    match install_main_thread_trampoline(callback, user_data) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            false
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! ### English
+//! C ABI binding of the golden-image rendering test harness (`golden-tests` feature only).
+//!
+//! ### 中文
+//! golden 图像渲染测试工具的 C ABI 绑定（仅限 `golden-tests` feature）。
+
+use std::ffi::c_char;
+
+use dpi::PhysicalSize;
+
+use super::XianWebEngine;
+use super::validate::{self, ffi_entry};
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+use crate::engine::{GoldenRun, XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Runs the golden-image rendering tests on the calling thread and returns the number of failed
+/// cases, or `-1` if an argument is NULL/invalid or the pages cannot be listed. Only exported by
+/// builds with the `golden-tests` feature (`XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS`).
+///
+/// Create `engine` on a context nobody presents (a hidden GLFW window or a surfaceless EGL context).
+/// Every `*.html` page in `pages_dir` (e.g. the bundled `tests/golden/pages`) is loaded into a fresh
+/// `width`x`height` view, once as `RGBA8` and once as `SRGB8_A8`; after `load` and two animation
+/// frames the frame is read back as for PNG export and compared against `<page>.png` /
+/// `<page>.srgb.png` in `golden_dir`, allowing `tolerance` (0..=255) per channel. Captured frames and,
+/// on mismatch, `<case>.diff.png` (offending pixels in red) are written to `output_dir`; each case is
+/// logged. `XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE` in `flags` stores the captured frames as the new
+/// goldens instead.
+///
+/// ### 中文
+/// 在调用线程上运行 golden 图像渲染测试，返回失败用例数；若参数为空/非法或无法列出页面，则返回 `-1`。
+/// 仅由启用 `golden-tests` feature 的构建导出（`XIAN_WEB_ENGINE_CAPABILITY_GOLDEN_TESTS`）。
+///
+/// 请在不会被呈现的上下文（隐藏的 GLFW 窗口或 surfaceless EGL 上下文）上创建 `engine`。`pages_dir` 中的每个
+/// `*.html` 页面（例如仓库自带的 `tests/golden/pages`）都会加载到新建的 `width`x`height` view 中，分别以
+/// `RGBA8` 与 `SRGB8_A8` 各渲染一次；在 `load` 与两个动画帧之后，按 PNG 导出的方式读回帧，并与 `golden_dir`
+/// 中的 `<page>.png` / `<page>.srgb.png` 比较，每通道允许 `tolerance`（0..=255）的差值。截取的帧以及不一致时的
+/// `<case>.diff.png`（越界像素为红色）写入 `output_dir`；每个用例都会记录日志。`flags` 中的
+/// `XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE` 会改为把截取的帧存为新的 golden。
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn xian_web_engine_run_golden_tests(
+    engine: *mut XianWebEngine,
+    pages_dir: *const c_char,
+    golden_dir: *const c_char,
+    output_dir: *const c_char,
+    width: u32,
+    height: u32,
+    tolerance: u32,
+    flags: u32,
+) -> i32 {
+    let call = ffi_entry!(
+        xian_web_engine_run_golden_tests,
+        engine,
+        pages_dir,
+        golden_dir,
+        output_dir,
+        width,
+        height,
+        tolerance,
+        flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return -1;
+    };
+    let (Some(pages_dir), Some(golden_dir), Some(output_dir)) = (unsafe {
+        (
+            super::cstr_to_path(pages_dir),
+            super::cstr_to_path(golden_dir),
+            super::cstr_to_path(output_dir),
+        )
+    }) else {
+        return -1;
+    };
+
+    let run = GoldenRun {
+        pages_dir,
+        golden_dir,
+        output_dir,
+        size: PhysicalSize::new(width.max(1), height.max(1)),
+        tolerance: tolerance.min(255) as u8,
+        update: (flags & XIAN_WEB_ENGINE_GOLDEN_FLAG_UPDATE) != 0,
+    };
+    match runtime.run_golden_tests(&run) {
+        Ok(failed) => failed.min(i32::MAX as u32) as i32,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            -1
+        }
+    }
+}
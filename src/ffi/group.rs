@@ -0,0 +1,154 @@
+//! ### English
+//! C ABI bindings for view groups (views sharing one refresh tick).
+//!
+//! ### 中文
+//! view group（共享同一 refresh tick 的多个 view）的 C ABI 绑定。
+
+use dpi::PhysicalSize;
+
+use crate::engine::log::{XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG, XIAN_WEB_ENGINE_LOG_LEVEL_WARN};
+
+use super::validate::{self, ffi_entry};
+use super::{XianWebEngine, XianWebEngineView};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates a view group and returns its non-zero ID (`0` on failure).
+///
+/// Views created into the group share one refresh driver, so their frames are scheduled in the same
+/// tick and published together. `target_fps = 0` means the group is driven by external vsync
+/// (`xian_web_engine_tick`).
+///
+/// ### 中文
+/// 创建一个 view group 并返回其非 0 ID（失败返回 `0`）。
+///
+/// 加入该组的 view 共享同一个 refresh driver，因此其帧会在同一次 tick 中调度并一起发布。
+/// `target_fps = 0` 表示该组由外部 vsync（`xian_web_engine_tick`）驱动。
+pub unsafe extern "C" fn xian_web_engine_view_group_create(
+    engine: *mut XianWebEngine,
+    target_fps: u32,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_group_create, engine, target_fps);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return 0;
+    };
+
+    runtime.create_view_group(target_fps, 1).unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates a view group ticking at a fractional rate of `fps_numerator / fps_denominator` frames
+/// per second and returns its non-zero ID (`0` on failure).
+///
+/// Use it to match a display whose refresh rate is not a whole number (e.g. `14386 / 100` for
+/// 143.86 Hz, `60000 / 1001` for 59.94 Hz). Ticks follow an exact frame grid, so long-running
+/// views do not drift against the display. `fps_numerator = 0` means the group is driven by
+/// external vsync, as with `xian_web_engine_view_group_create`.
+///
+/// ### 中文
+/// 创建一个以 `fps_numerator / fps_denominator` 帧每秒的小数帧率 tick 的 view group，并返回其非 0 ID（失败返回
+/// `0`）。
+///
+/// 用于匹配刷新率不是整数的显示器（例如 143.86 Hz 对应 `14386 / 100`，59.94 Hz 对应 `60000 / 1001`）。tick
+/// 遵循精确的帧网格，因此长时间运行的 view 不会相对显示器漂移。`fps_numerator = 0` 表示该组由外部 vsync 驱动，
+/// 与 `xian_web_engine_view_group_create` 相同。
+pub unsafe extern "C" fn xian_web_engine_view_group_create_fractional(
+    engine: *mut XianWebEngine,
+    fps_numerator: u32,
+    fps_denominator: u32,
+) -> u32 {
+    let call = ffi_entry!(
+        xian_web_engine_view_group_create_fractional,
+        engine,
+        fps_numerator,
+        fps_denominator,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return 0;
+    };
+
+    match runtime.create_view_group(fps_numerator, fps_denominator) {
+        Ok(group) => group,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            0
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Destroys a view group.
+///
+/// Views already created into the group keep running on the shared tick; no new views can join.
+///
+/// ### 中文
+/// 销毁一个 view group。
+///
+/// 已加入该组的 view 会继续在共享 tick 上运行；此后不能再有新 view 加入。
+pub unsafe extern "C" fn xian_web_engine_view_group_destroy(
+    engine: *mut XianWebEngine,
+    group: u32,
+) {
+    let call = ffi_entry!(xian_web_engine_view_group_destroy, engine, group);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return;
+    };
+    if group == 0 {
+        return;
+    }
+
+    runtime.destroy_view_group(group);
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates one view inside a view group (refresh rate comes from the group).
+///
+/// Returns NULL if `engine` is NULL or the group does not exist.
+///
+/// ### 中文
+/// 在 view group 内创建一个 view（刷新率由该组决定）。
+///
+/// 若 `engine` 为空指针或该 group 不存在，则返回 NULL。
+pub unsafe extern "C" fn xian_web_engine_view_create_in_group(
+    engine: *mut XianWebEngine,
+    group: u32,
+    width: u32,
+    height: u32,
+    view_flags: u32,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(
+        xian_web_engine_view_create_in_group,
+        engine,
+        group,
+        width,
+        height,
+        view_flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return std::ptr::null_mut();
+    };
+    if group == 0 {
+        return std::ptr::null_mut();
+    }
+
+    let size = PhysicalSize::new(width, height);
+    let handle = runtime.create_view(size, 0, group, view_flags);
+    let handle = match handle {
+        Ok(handle) => handle,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let view = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("created view {view:p}"),
+    );
+    view
+}
@@ -0,0 +1,252 @@
+//! ### English
+//! Generational view handle table (`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`).
+//!
+//! Views of an engine created with the flag are not handed out as `Box` pointers but as opaque
+//! 64-bit IDs passed in the same `XianWebEngineView*` parameters: `generation << 32 | index << 1 | 1`.
+//! The low bit can never be set in a real (aligned) pointer, so both kinds coexist and every call
+//! resolves the ID against the table; a stale ID (use-after-destroy, double destroy) is rejected
+//! with an error instead of dereferencing freed memory.
+//!
+//! The table is process-global: every engine created with the flag shares it, so the index space
+//! (`2^31` live views) is shared across engines and an ID stays unique within the process rather
+//! than within one engine. IDs need the full 64 bits, so the crate only builds for 64-bit targets.
+//!
+//! ### 中文
+//! 分代的 view 句柄表（`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`）。
+//!
+//! 以该标志创建的引擎所创建的 view 不再以 `Box` 指针交给宿主，而是以同样通过 `XianWebEngineView*` 参数传递的
+//! 不透明 64 位 ID 表示：`generation << 32 | index << 1 | 1`。真实（已对齐）指针的最低位永远不会被置位，
+//! 因此两种句柄可以共存；每次调用都会在表中解析该 ID，陈旧 ID（销毁后使用、重复销毁）会以错误被拒绝，
+//! 而不会解引用已释放的内存。
+//!
+//! 该表是进程级的：所有以该标志创建的引擎共享同一张表，因此下标空间（`2^31` 个存活 view）由各引擎共享，
+//! ID 在整个进程内唯一，而非仅在单个引擎内唯一。ID 需要完整的 64 位，因此本 crate 只能为 64 位目标构建。
+
+#[cfg(not(target_pointer_width = "64"))]
+compile_error!("the view handle table packs 64-bit IDs into pointers; build for a 64-bit target");
+
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+use crate::engine::WebEngineViewHandle;
+
+use super::XianWebEngineView;
+
+/// ### English
+/// Bit set in every table ID (never set in an aligned pointer).
+///
+/// ### 中文
+/// 所有表 ID 中都会置位的比特（对齐指针中永远不会置位）。
+const HANDLE_TAG: usize = 1;
+
+/// ### English
+/// Largest table index (31 bits between the tag and the generation).
+///
+/// ### 中文
+/// 最大的表下标（位于标记位与代数之间的 31 位）。
+const MAX_INDEX: usize = (1 << 31) - 1;
+
+/// ### English
+/// One table slot.
+///
+/// ### 中文
+/// 表中的一个槽位。
+struct HandleEntry {
+    /// ### English
+    /// Generation of the slot, bumped on every removal so old IDs stop matching (never 0).
+    ///
+    /// ### 中文
+    /// 槽位的代数；每次移除都会递增，使旧 ID 不再匹配（永不为 0）。
+    generation: u32,
+    /// ### English
+    /// View stored in the slot (`None` while free).
+    ///
+    /// ### 中文
+    /// 槽位中存放的 view（空闲时为 `None`）。
+    view: Option<Arc<XianWebEngineView>>,
+}
+
+/// ### English
+/// Process-wide table of views handed out as IDs.
+///
+/// ### 中文
+/// 以 ID 形式交出的 view 的进程级表。
+struct HandleTable {
+    /// ### English
+    /// Slots indexed by the ID's index bits.
+    ///
+    /// ### 中文
+    /// 按 ID 下标位索引的槽位。
+    entries: Vec<HandleEntry>,
+    /// ### English
+    /// Indices of free slots, reused before the table grows.
+    ///
+    /// ### 中文
+    /// 空闲槽位的下标，在表增长前优先复用。
+    free: Vec<u32>,
+}
+
+/// ### English
+/// The view handle table (cold path: taken for reading once per call, for writing on create/destroy).
+///
+/// ### 中文
+/// view 句柄表（冷路径：每次调用读锁一次，创建/销毁时写锁）。
+static VIEWS: RwLock<HandleTable> = RwLock::new(HandleTable {
+    entries: Vec::new(),
+    free: Vec::new(),
+});
+
+/// ### English
+/// A resolved view: borrowed through a raw pointer, or kept alive by the table entry for the
+/// duration of the call.
+///
+/// ### 中文
+/// 已解析的 view：通过原始指针借用，或在本次调用期间由表项保持存活。
+pub(super) enum ViewRef<'a> {
+    /// ### English
+    /// View behind a `Box` pointer.
+    ///
+    /// ### 中文
+    /// `Box` 指针背后的 view。
+    Borrowed(&'a WebEngineViewHandle),
+    /// ### English
+    /// View resolved from the handle table.
+    ///
+    /// ### 中文
+    /// 从句柄表解析出的 view。
+    Table(Arc<XianWebEngineView>),
+}
+
+impl Deref for ViewRef<'_> {
+    type Target = WebEngineViewHandle;
+
+    /// ### English
+    /// Returns the view handle.
+    ///
+    /// ### 中文
+    /// 返回 view 句柄。
+    fn deref(&self) -> &WebEngineViewHandle {
+        match self {
+            Self::Borrowed(handle) => handle,
+            Self::Table(view) => &view.handle,
+        }
+    }
+}
+
+/// ### English
+/// Returns whether `view` is a table ID rather than a pointer.
+///
+/// #### Parameters
+/// - `view`: View argument passed by the embedder.
+///
+/// ### 中文
+/// 返回 `view` 是否为表 ID 而非指针。
+///
+/// #### 参数
+/// - `view`：宿主传入的 view 参数。
+#[inline]
+pub(super) fn is_table_id(view: *mut XianWebEngineView) -> bool {
+    view.addr() & HANDLE_TAG != 0
+}
+
+/// ### English
+/// Splits a table ID into `(index, generation)`.
+///
+/// #### Parameters
+/// - `view`: Table ID.
+///
+/// ### 中文
+/// 将表 ID 拆分为 `(index, generation)`。
+///
+/// #### 参数
+/// - `view`：表 ID。
+#[inline]
+fn decode(view: *mut XianWebEngineView) -> (usize, u32) {
+    let id = view.addr() as u64;
+    (
+        ((id & u64::from(u32::MAX)) >> 1) as usize,
+        (id >> 32) as u32,
+    )
+}
+
+/// ### English
+/// Stores `view` in the table and returns its ID, or NULL if the table is full.
+///
+/// #### Parameters
+/// - `view`: View to hand out.
+///
+/// ### 中文
+/// 将 `view` 存入表中并返回其 ID；表已满时返回 NULL。
+///
+/// #### 参数
+/// - `view`：要交给宿主的 view。
+pub(super) fn insert(view: XianWebEngineView) -> *mut XianWebEngineView {
+    let Ok(mut table) = VIEWS.write() else {
+        return std::ptr::null_mut();
+    };
+    let index = match table.free.pop() {
+        Some(index) => index as usize,
+        None if table.entries.len() <= MAX_INDEX => {
+            table.entries.push(HandleEntry {
+                generation: 1,
+                view: None,
+            });
+            table.entries.len() - 1
+        }
+        None => return std::ptr::null_mut(),
+    };
+    let entry = &mut table.entries[index];
+    entry.view = Some(Arc::new(view));
+    let id = (u64::from(entry.generation) << 32) | ((index as u64) << 1) | HANDLE_TAG as u64;
+    std::ptr::without_provenance_mut(id as usize)
+}
+
+/// ### English
+/// Resolves a table ID, or returns `None` if it is stale or was never handed out.
+///
+/// #### Parameters
+/// - `view`: Table ID.
+///
+/// ### 中文
+/// 解析表 ID；若其已陈旧或从未分配过，则返回 `None`。
+///
+/// #### 参数
+/// - `view`：表 ID。
+pub(super) fn get(view: *mut XianWebEngineView) -> Option<Arc<XianWebEngineView>> {
+    let (index, generation) = decode(view);
+    let table = VIEWS.read().ok()?;
+    let entry = table.entries.get(index)?;
+    if entry.generation != generation {
+        return None;
+    }
+    entry.view.clone()
+}
+
+/// ### English
+/// Removes a table ID so it never resolves again, and returns its view (dropped by the caller
+/// once calls still in flight on other threads have finished with it).
+///
+/// Returns `None` if the ID is stale or was never handed out.
+///
+/// #### Parameters
+/// - `view`: Table ID.
+///
+/// ### 中文
+/// 移除一个表 ID，使其不再能被解析，并返回其 view（在其他线程上仍在进行的调用结束后由调用方 drop）。
+///
+/// 若该 ID 已陈旧或从未分配过，则返回 `None`。
+///
+/// #### 参数
+/// - `view`：表 ID。
+pub(super) fn remove(view: *mut XianWebEngineView) -> Option<Arc<XianWebEngineView>> {
+    let (index, generation) = decode(view);
+    let mut table = VIEWS.write().ok()?;
+    let entry = table.entries.get_mut(index)?;
+    if entry.generation != generation {
+        return None;
+    }
+    let view = entry.view.take()?;
+    entry.generation = entry.generation.wrapping_add(1).max(1);
+    table.free.push(index as u32);
+    Some(view)
+}
@@ -0,0 +1,328 @@
+//! ### English
+//! C ABI bindings for the page bridge (`window.xianHost`): host functions, binary messages and
+//! shared textures.
+//!
+//! A page calls `window.xianHost.call(name, args)`, which returns a Promise. The registered callback
+//! receives the call with its ID and JSON arguments on the Servo thread; the embedder answers it later
+//! with `xian_web_engine_view_respond_host_call`, from any thread. Byte buffers travel both ways
+//! through a per-view pool and reach the page as `ArrayBuffer`s. Registered GL textures are copied
+//! to the page as `ImageBitmap`s on request.
+//!
+//! ### 中文
+//! 页面桥接（`window.xianHost`）的 C ABI 绑定：宿主函数、二进制消息与共享纹理。
+//!
+//! 页面调用 `window.xianHost.call(name, args)` 并得到一个 Promise。已注册的回调在 Servo 线程上收到该调用及其
+//! ID 与 JSON 参数；宿主稍后（可在任意线程）通过 `xian_web_engine_view_respond_host_call` 应答。字节缓冲区
+//! 经由每 view 的缓冲池双向传输，并以 `ArrayBuffer` 形式到达页面。已注册的 GL 纹理会在页面请求时以
+//! `ImageBitmap` 副本交给页面。
+
+use std::ffi::{c_char, c_void};
+
+use crate::engine::XianWebEngineHostFunctionFn;
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+
+use super::validate::{self, ffi_entry};
+use super::{XianWebEngine, XianWebEngineView};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Registers a host function the page can call as `window.xianHost.call(name, args)` (`NULL`
+/// `callback` unregisters it); registrations survive navigations.
+///
+/// `args` is serialized with `JSON.stringify` (`undefined` becomes `null`). The callback runs on the
+/// Servo thread and must return quickly; answer each call with
+/// `xian_web_engine_view_respond_host_call`. Calls to unregistered names are rejected at once, and
+/// calls still unanswered when the page navigates away are dropped.
+///
+/// Returns `false` if an argument is invalid or the engine is shutting down.
+///
+/// ### 中文
+/// 注册一个页面可通过 `window.xianHost.call(name, args)` 调用的宿主函数（`callback` 为 `NULL` 表示取消注册）；
+/// 注册在导航后仍然有效。
+///
+/// `args` 通过 `JSON.stringify` 序列化（`undefined` 变为 `null`）。回调在 Servo 线程上执行且必须尽快返回；
+/// 请通过 `xian_web_engine_view_respond_host_call` 应答每次调用。调用未注册的名称会立即被拒绝；页面离开时
+/// 仍未应答的调用会被丢弃。
+///
+/// 若参数非法或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_register_host_function(
+    view: *mut XianWebEngineView,
+    name: *const c_char,
+    callback: Option<XianWebEngineHostFunctionFn>,
+    user_data: *mut c_void,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_register_host_function,
+        view,
+        name,
+        callback,
+        user_data,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(name) = (unsafe { validate::cstr(call, "name", name) }) else {
+        return false;
+    };
+    if name.is_empty() || name.contains('\n') {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+            call,
+            format_args!("`name` must be non-empty and must not contain a line break"),
+        );
+        return false;
+    }
+
+    handle.register_host_function(name.to_string(), callback, user_data)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Answers a host function call: `success` resolves the page's Promise with `result` parsed as JSON
+/// (`NULL` or empty resolves with `undefined`); otherwise the Promise is rejected with an `Error`
+/// whose message is `result`. Unknown or stale call IDs are ignored.
+///
+/// Returns `false` if an argument is invalid or the engine is shutting down.
+///
+/// ### 中文
+/// 应答一次宿主函数调用：`success` 为 true 时以解析为 JSON 的 `result` 兑现页面的 Promise（`NULL` 或空字符串
+/// 兑现为 `undefined`）；否则以消息为 `result` 的 `Error` 拒绝该 Promise。未知或过期的调用 ID 会被忽略。
+///
+/// 若参数非法或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_respond_host_call(
+    view: *mut XianWebEngineView,
+    call_id: u64,
+    success: bool,
+    result: *const c_char,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_respond_host_call,
+        view,
+        call_id,
+        success,
+        result,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let result = if result.is_null() {
+        ""
+    } else {
+        let Some(result) = (unsafe { validate::cstr(call, "result", result) }) else {
+            return false;
+        };
+        result
+    };
+
+    handle.respond_host_call(call_id, success, result.to_string())
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sends `len` bytes to the page, which receives them as an `ArrayBuffer`:
+/// `xianHost.addEventListener("binary", e => draw(e.channel, e.data))`. The bytes are copied into a
+/// pooled buffer, so `data` can be reused as soon as this returns. Messages are delivered in order
+/// and dropped if no page is listening.
+///
+/// Returns `false` if an argument is invalid, `len` exceeds 16 MiB, or the engine is shutting down.
+///
+/// ### 中文
+/// 向页面发送 `len` 字节，页面以 `ArrayBuffer` 形式接收：
+/// `xianHost.addEventListener("binary", e => draw(e.channel, e.data))`。字节会被复制到缓冲池中的缓冲区，
+/// 因此本函数返回后即可复用 `data`。消息按顺序投递；若页面没有监听则被丢弃。
+///
+/// 若参数非法、`len` 超过 16 MiB 或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_post_binary(
+    view: *mut XianWebEngineView,
+    channel: u32,
+    data: *const u8,
+    len: usize,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_post_binary, view, channel, data, len);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(data) = (unsafe { validate::slice(call, "data", data, len) }) else {
+        return false;
+    };
+
+    handle.post_binary(channel, data)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Looks up a binary message the page sent with `xianHost.sendBinary(channel, typedArray)`
+/// (announced by a `BINARY_MESSAGE` event) and writes its address and length to `out_data` /
+/// `out_len`. The bytes are owned by the engine and stay valid until
+/// `xian_web_engine_view_release_binary_message`.
+///
+/// Returns `false` if an argument is invalid or the message is unknown or already released.
+///
+/// ### 中文
+/// 查找页面通过 `xianHost.sendBinary(channel, typedArray)` 发送的二进制消息（由 `BINARY_MESSAGE` 事件通知），
+/// 并将其地址与长度写入 `out_data` / `out_len`。这些字节归引擎所有，在
+/// `xian_web_engine_view_release_binary_message` 之前一直有效。
+///
+/// 若参数非法，或消息未知/已释放，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_get_binary_message(
+    view: *mut XianWebEngineView,
+    message_id: u64,
+    out_data: *mut *const u8,
+    out_len: *mut usize,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_get_binary_message,
+        view,
+        message_id,
+        out_data,
+        out_len,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "out_data", out_data)
+        || !validate::pointer(call, "out_len", out_len)
+    {
+        return false;
+    }
+    let Some((data, len)) = handle.binary_message(message_id) else {
+        return false;
+    };
+
+    unsafe {
+        out_data.write(data);
+        out_len.write(len);
+    }
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Releases a binary message received from the page; its buffer returns to the view's pool. Unreleased
+/// messages count against a limit of 256, beyond which further page messages are dropped.
+///
+/// Returns `false` if `view` is NULL or the message is unknown or already released.
+///
+/// ### 中文
+/// 释放一条从页面收到的二进制消息，其缓冲区会归还到该 view 的缓冲池。未释放的消息最多 256 条，超出后页面
+/// 发送的消息会被丢弃。
+///
+/// 若 `view` 为空指针，或消息未知/已释放，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_release_binary_message(
+    view: *mut XianWebEngineView,
+    message_id: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_release_binary_message,
+        view,
+        message_id
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.release_binary_message(message_id)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Registers a GL texture of the embedder context (`GL_TEXTURE_2D`, e.g. the player's skin or a map)
+/// under `name` for every view (`texture` 0 unregisters it). Pages fetch it with
+/// `await xianHost.getTexture(name)`, which resolves to an `ImageBitmap` that can be drawn on a
+/// canvas or uploaded with `texImage2D`.
+///
+/// Each request reads the texture back on the Servo thread's shared context and copies it into the
+/// page (Servo cannot import foreign textures into WebGL), so it reflects the texture at the time of
+/// the request; fetch again after the embedder updates it. Set `flip_y` for render targets whose
+/// row 0 is the bottom row. The texture must stay alive while registered.
+///
+/// Returns `false` if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+/// the engine is shutting down.
+///
+/// ### 中文
+/// 以 `name` 为所有 view 注册宿主上下文中的一个 GL 纹理（`GL_TEXTURE_2D`，例如玩家皮肤或地图）（`texture`
+/// 为 0 表示取消注册）。页面通过 `await xianHost.getTexture(name)` 获取，得到的 `ImageBitmap` 可绘制到
+/// canvas 或通过 `texImage2D` 上传。
+///
+/// 每次请求都会在 Servo 线程的共享上下文上读回纹理并复制到页面（Servo 无法将外部纹理导入 WebGL），因此得到的
+/// 是请求时刻的纹理内容；宿主更新纹理后需重新获取。对第 0 行为底行的渲染目标请设置 `flip_y`。注册期间纹理
+/// 必须保持存活。
+///
+/// 若参数非法、尺寸为 0 或 RGBA8 像素超过 16 MiB，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_register_texture(
+    engine: *mut XianWebEngine,
+    name: *const c_char,
+    texture: u32,
+    width: u32,
+    height: u32,
+    flip_y: bool,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_register_texture,
+        engine,
+        name,
+        texture,
+        width,
+        height,
+        flip_y,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return false;
+    };
+    let Some(name) = (unsafe { validate::cstr(call, "name", name) }) else {
+        return false;
+    };
+
+    runtime.register_texture(name, texture, width, height, flip_y)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Pushes a frame the embedder rendered into `texture_id` (`GL_TEXTURE_2D` of the embedder context,
+/// row 0 at the bottom as rendered into an FBO) to stream `stream_id` of the page, which receives it
+/// as an `ImageBitmap`: `xianHost.openStream(id).attach(canvas)` or the stream's `frame` event.
+///
+/// This mirrors the triple buffer with the roles swapped. Insert a `GLsync` after rendering and
+/// pass it as `fence` (or 0), and flush; the engine waits for it on the GPU, copies the texture and
+/// deletes the fence. An `EXTERNAL_FRAME_RELEASED` event then reports that the texture may be
+/// rendered into again, so rotating two or three textures per stream avoids stalls. Frames for
+/// streams the page has not opened are released without being read. Keep frames small: each one is
+/// copied through the CPU.
+///
+/// Returns `false` if an argument is invalid, the size is zero or its RGBA8 pixels exceed 16 MiB, or
+/// the engine is shutting down; the caller still owns `fence` in that case.
+///
+/// ### 中文
+/// 将宿主渲染到 `texture_id`（宿主上下文中的 `GL_TEXTURE_2D`，第 0 行在底部，与渲染到 FBO 时一致）的一帧
+/// 推送到页面的流 `stream_id`，页面以 `ImageBitmap` 形式接收：`xianHost.openStream(id).attach(canvas)` 或流的
+/// `frame` 事件。
+///
+/// 这与三缓冲相同，只是角色互换。渲染完成后插入 `GLsync` 并作为 `fence` 传入（或传 0），然后 flush；引擎会在
+/// GPU 上等待它、复制纹理并删除该 fence。随后 `EXTERNAL_FRAME_RELEASED` 事件通知可再次渲染该纹理，因此每个流
+/// 轮换使用两到三个纹理即可避免停顿。页面未打开的流的帧会直接释放而不读取。请保持帧尺寸较小：每帧都会经过 CPU
+/// 复制。
+///
+/// 若参数非法、尺寸为 0 或 RGBA8 像素超过 16 MiB，或引擎正在关闭，则返回 `false`；此时 `fence` 仍归调用方所有。
+pub unsafe extern "C" fn xian_web_engine_push_external_frame(
+    view: *mut XianWebEngineView,
+    stream_id: u32,
+    texture_id: u32,
+    width: u32,
+    height: u32,
+    fence: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_push_external_frame,
+        view,
+        stream_id,
+        texture_id,
+        width,
+        height,
+        fence,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.push_external_frame(stream_id, texture_id, width, height, fence)
+}
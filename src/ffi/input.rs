@@ -4,50 +4,76 @@
 //! ### 中文
 //! 向 view 发送输入事件的 C ABI 绑定。
 
+use std::ffi::c_char;
+
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_WARN;
 use crate::engine::{
     XIAN_WEB_ENGINE_INPUT_KIND_KEY, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
-    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XIAN_WEB_ENGINE_INPUT_KIND_WHEEL,
-    XianWebEngineInputEvent,
+    XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_MOVE, XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE,
+    XIAN_WEB_ENGINE_INPUT_KIND_WHEEL, XianWebEngineInputEvent,
 };
 
 use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
 
 #[unsafe(no_mangle)]
 /// ### English
 /// Sends a batch of input events to a view.
 ///
+/// Pointer positions are mapped through the view's input transform first, if one is set
+/// (`xian_web_engine_set_view_input_transform`).
+///
 /// Returns the number of accepted events (may be less than `count` if the queue is full).
 /// If the view is inactive, events are treated as accepted and dropped (fast path).
-/// Unknown event kinds are treated as accepted and dropped.
+/// Unknown event kinds are treated as accepted and dropped (and reported through the log callback).
 ///
 /// ### 中文
 /// 向 view 发送一批输入事件。
 ///
+/// 若设置了输入变换（`xian_web_engine_set_view_input_transform`），指针位置会先经过它映射。
+///
 /// 返回实际接收的事件数量（若队列满，可能小于 `count`）。
 /// 若 view 处于 inactive，则会把事件视为“已接收”并直接丢弃（快路径）。
-/// 未知事件类型会视为“已接收”并直接丢弃。
+/// 未知事件类型会视为“已接收”并直接丢弃（同时通过日志回调上报）。
 pub unsafe extern "C" fn xian_web_engine_view_send_input_events(
     view: *mut XianWebEngineView,
     events: *const XianWebEngineInputEvent,
     count: u32,
 ) -> u32 {
-    if view.is_null() || events.is_null() || count == 0 {
+    let call = ffi_entry!(xian_web_engine_view_send_input_events, view, events, count);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    let Some(event_slice) = (unsafe { validate::slice(call, "events", events, count as usize) })
+    else {
+        return 0;
+    };
+    if count == 0 {
         return 0;
     }
 
-    let handle = unsafe { &(*view).handle };
-
     if !handle.is_active() {
         return count;
     }
 
+    let transformed: Vec<XianWebEngineInputEvent>;
+    let event_slice = match handle.input_transform() {
+        Some(homography) => {
+            transformed = event_slice
+                .iter()
+                .map(|ev| homography.map_event(ev))
+                .collect();
+            transformed.as_slice()
+        }
+        None => event_slice,
+    };
+
     let mut accepted: u32 = 0;
     let mut wake_needed = false;
     let mut last_mouse_move: Option<(f32, f32)> = None;
     let mut input_pending = false;
 
     let count = count as usize;
-    let event_slice = unsafe { std::slice::from_raw_parts(events, count) };
     let mut index: usize = 0;
     while index < count {
         let ev = event_slice[index];
@@ -58,6 +84,7 @@ pub unsafe extern "C" fn xian_web_engine_view_send_input_events(
                 index += 1;
             }
             XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON
+            | XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE
             | XIAN_WEB_ENGINE_INPUT_KIND_WHEEL
             | XIAN_WEB_ENGINE_INPUT_KIND_KEY => {
                 let start = index;
@@ -65,6 +92,7 @@ pub unsafe extern "C" fn xian_web_engine_view_send_input_events(
                 while index < count {
                     let kind = event_slice[index].kind;
                     if kind == XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON
+                        || kind == XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE
                         || kind == XIAN_WEB_ENGINE_INPUT_KIND_WHEEL
                         || kind == XIAN_WEB_ENGINE_INPUT_KIND_KEY
                     {
@@ -85,12 +113,19 @@ pub unsafe extern "C" fn xian_web_engine_view_send_input_events(
                 }
             }
             _ => {
+                validate::report(
+                    XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+                    call,
+                    format_args!("dropped event with unknown `kind` ({})", ev.kind),
+                );
                 accepted += 1;
                 index += 1;
             }
         }
     }
 
+    handle.count_input(count, accepted as usize);
+
     if let Some((x, y)) = last_mouse_move {
         wake_needed |= handle.queue_mouse_move(x, y);
     }
@@ -105,3 +140,183 @@ pub unsafe extern "C" fn xian_web_engine_view_send_input_events(
 
     accepted
 }
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sends one mouse button, wheel or key event and reports how the page handled it, so embedders can
+/// pass clicks on empty page areas through to the game.
+///
+/// The event is dispatched after the input sent before it (its pointer position is mapped through
+/// the view's input transform like `xian_web_engine_view_send_input_events`). Once the page handled
+/// it, a `XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT` event with `request_id = correlation_id` reports
+/// whether the page consumed it; pointer events are also hit tested against the page's elements
+/// (`XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT`). Input sent with
+/// `xian_web_engine_view_send_input_events` afterwards may be dispatched first, so send the
+/// matching release through this function too. Events sent while the view is inactive are dropped
+/// without a result.
+///
+/// Returns `false` if `view` or `event` is NULL, the event kind is not tracked, `correlation_id` is
+/// `0`, or the engine is shutting down.
+///
+/// ### 中文
+/// 发送一个鼠标按键、滚轮或按键事件并报告页面的处理结果，使宿主能把落在页面空白区域的点击穿透给游戏。
+///
+/// 该事件会在其之前发送的输入之后派发（其指针位置与 `xian_web_engine_view_send_input_events` 一样经过 view 的
+/// 输入变换映射）。页面处理完毕后，会以 `request_id = correlation_id` 的
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_INPUT_RESULT` 事件报告页面是否消费了它；指针事件还会针对页面元素进行命中测试
+/// （`XIAN_WEB_ENGINE_INPUT_RESULT_HIT_ELEMENT`）。之后通过 `xian_web_engine_view_send_input_events` 发送的
+/// 输入可能先于它派发，因此对应的释放事件也请通过本函数发送。view 处于 inactive 时发送的事件会被丢弃且不会
+/// 回报结果。
+///
+/// 若 `view` 或 `event` 为空指针、事件类型不支持跟踪、`correlation_id` 为 `0`，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_send_tracked_input_event(
+    view: *mut XianWebEngineView,
+    event: *const XianWebEngineInputEvent,
+    correlation_id: u64,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_send_tracked_input_event,
+        view,
+        event,
+        correlation_id
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "event", event) {
+        return false;
+    }
+    let event = unsafe { *event };
+    if !validate::enum_value(
+        call,
+        "event.kind",
+        event.kind,
+        &[
+            XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_BUTTON,
+            XIAN_WEB_ENGINE_INPUT_KIND_WHEEL,
+            XIAN_WEB_ENGINE_INPUT_KIND_KEY,
+        ],
+    ) {
+        return false;
+    }
+    if correlation_id == 0 {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!("correlation_id must not be 0"),
+        );
+        return false;
+    }
+
+    let event = match handle.input_transform() {
+        Some(homography) => homography.map_event(&event),
+        None => event,
+    };
+    handle.send_tracked_input(event, correlation_id)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets the view's input transform: a row-major 3x3 homography (9 floats) mapping host coordinates
+/// `(x, y, 1)` to view pixels `(x', y', w)`, divided by `w`. Once set, the pointer positions of
+/// mouse move, button and wheel events passed to `xian_web_engine_view_send_input_events` are in host
+/// coordinates, so a view textured onto an in-world 3D quad can take raw screen or ray hit
+/// coordinates. Relative mouse deltas and keys are not affected; points on the homography's
+/// vanishing line are sent off the view at `(-1, -1)`.
+///
+/// `matrix = NULL` clears the transform (identity). Returns `false` (leaving the transform unchanged)
+/// if `view` is NULL or a matrix entry is not finite.
+///
+/// ### 中文
+/// 设置 view 的输入变换：一个行主序 3x3 单应矩阵（9 个 float），把宿主坐标 `(x, y, 1)` 映射为 view 像素
+/// `(x', y', w)` 再除以 `w`。设置后，传给 `xian_web_engine_view_send_input_events` 的鼠标移动、按键与滚轮事件的
+/// 指针位置均为宿主坐标，因此贴到游戏内 3D 四边形上的 view 可以直接接收原始屏幕坐标或射线命中坐标。相对鼠标
+/// 增量与按键不受影响；位于单应矩阵消失线上的点会以 `(-1, -1)` 发送到 view 之外。
+///
+/// `matrix = NULL` 清除变换（恒等变换）。若 `view` 为空指针或矩阵中有非有限值，则返回 `false`（变换保持不变）。
+pub unsafe extern "C" fn xian_web_engine_set_view_input_transform(
+    view: *mut XianWebEngineView,
+    matrix: *const f32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_view_input_transform, view, matrix);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if matrix.is_null() {
+        return handle.set_input_transform(None);
+    }
+    let Some(matrix) = (unsafe { validate::slice(call, "matrix", matrix, 9) }) else {
+        return false;
+    };
+    let Ok(matrix) = <&[f32; 9]>::try_from(matrix) else {
+        return false;
+    };
+
+    if !handle.set_input_transform(Some(matrix)) {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!("rejected `matrix` with a non-finite entry"),
+        );
+        return false;
+    }
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Synthesizes a left click at `(x, y)` (logical pixels): a mouse move onto the point, then a button
+/// press and release, fed on the Servo thread with human-like delays so pages see the same event
+/// order as from a real user. Clicks and typed text are queued one after another.
+///
+/// Meant for integration tests and macros; synthesized input is not recorded. Returns `false` if
+/// `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+///
+/// ### 中文
+/// 在 `(x, y)`（逻辑像素）合成一次左键点击：先将鼠标移动到该点，再按下并释放按键；这些事件在 Servo 线程上以接近
+/// 真人的间隔注入，使页面看到与真实用户相同的事件顺序。点击与输入的文本会依次排队。
+///
+/// 用于集成测试与宏；合成输入不会被录制。若 `view` 为空指针、坐标不是有限值，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_click_at(
+    view: *mut XianWebEngineView,
+    x: f32,
+    y: f32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_click_at, view, x, y);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.click_at(x, y)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Synthesizes typing the NUL-terminated UTF-8 `text`: one key press and release per character, fed
+/// on the Servo thread with human-like delays into the focused element. `'\n'` and `'\t'` press
+/// Enter and Tab; other control characters are skipped. Clicks and typed text are queued one after
+/// another.
+///
+/// Meant for integration tests and macros; synthesized input is not recorded. Returns `false` if an
+/// argument is NULL/invalid, `text` has nothing to type, or the engine is shutting down.
+///
+/// ### 中文
+/// 合成输入以 NUL 结尾的 UTF-8 文本 `text`：每个字符按下并释放一次，在 Servo 线程上以接近真人的间隔注入到获得
+/// 焦点的元素。`'\n'` 与 `'\t'` 分别按下 Enter 与 Tab；其它控制字符会被跳过。点击与输入的文本会依次排队。
+///
+/// 用于集成测试与宏；合成输入不会被录制。若参数为空/非法、`text` 中没有可输入的内容，或引擎正在关闭，则返回
+/// `false`。
+pub unsafe extern "C" fn xian_web_engine_type_text(
+    view: *mut XianWebEngineView,
+    text: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_type_text, view, text);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(text) = (unsafe { validate::cstr(call, "text", text) }) else {
+        return false;
+    };
+
+    handle.type_text(text)
+}
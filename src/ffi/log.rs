@@ -0,0 +1,49 @@
+//! ### English
+//! C ABI bindings for the embedder log callback.
+//!
+//! ### 中文
+//! 宿主日志回调的 C ABI 绑定。
+
+use std::ffi::c_void;
+
+use crate::engine::log::{XIAN_WEB_ENGINE_LOG_LEVEL_TRACE, XianWebEngineLogFn, set_log_sink};
+
+use super::validate::ffi_entry;
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the process-wide log callback (`NULL` removes it).
+///
+/// Messages at `max_level` or less verbose (`XIAN_WEB_ENGINE_LOG_LEVEL_*`) are forwarded. Rejected
+/// FFI arguments are reported at `ERROR` with the function and argument name; debug builds of the
+/// library additionally report every FFI call with its arguments at `TRACE`. The callback may run on
+/// any thread and may still be running briefly after it is replaced.
+///
+/// Returns `false` if `max_level` is not a defined level.
+///
+/// ### 中文
+/// 安装进程级日志回调（传 `NULL` 表示移除）。
+///
+/// 级别不超过 `max_level`（`XIAN_WEB_ENGINE_LOG_LEVEL_*`）的消息会被转发。被拒绝的 FFI 参数会以
+/// `ERROR` 级别连同函数名与参数名上报；debug 构建的库还会以 `TRACE` 级别上报每次 FFI 调用及其参数。
+/// 回调可能在任意线程执行，且在被替换后仍可能短暂地执行。
+///
+/// 若 `max_level` 不是已定义的级别，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_log_callback(
+    callback: Option<XianWebEngineLogFn>,
+    user_data: *mut c_void,
+    max_level: u32,
+) -> bool {
+    if max_level > XIAN_WEB_ENGINE_LOG_LEVEL_TRACE {
+        return false;
+    }
+
+    set_log_sink(callback, user_data, max_level);
+    ffi_entry!(
+        xian_web_engine_set_log_callback,
+        callback,
+        user_data,
+        max_level
+    );
+    true
+}
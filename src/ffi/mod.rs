@@ -12,11 +12,29 @@
 //! Java/Panama 传入的字符串必须是以 NUL 结尾的 UTF-8（C 字符串）；Rust 会校验 UTF-8，
 //! 且在遇到第一个 NUL 字节处截断。
 mod abi;
+mod compositor;
+mod dns;
+mod drag;
+mod egl;
 mod engine;
+mod events;
 mod frame;
 mod glfw;
+#[cfg(feature = "golden-tests")]
+mod golden;
+mod group;
+mod handles;
+mod host;
 mod input;
+mod log;
+mod pool;
+mod replay;
+mod screencast;
+mod sensors;
+mod strings;
+mod validate;
 mod view;
+mod vulkan;
 
 use std::ffi::{CStr, c_char};
 use std::path::PathBuf;
@@ -36,6 +54,12 @@ pub struct XianWebEngine {
     /// ### 中文
     /// 引擎运行时，持有独立的 Servo 线程。
     runtime: EngineRuntime,
+    /// ### English
+    /// Whether views are handed out as handle table IDs (`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`).
+    ///
+    /// ### 中文
+    /// view 是否以句柄表 ID 的形式交出（`XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`）。
+    handle_table: bool,
 }
 
 #[repr(C)]
@@ -67,10 +91,11 @@ pub struct XianWebEngineFrame {
     /// 三缓冲槽位索引（0..=2）。
     pub slot: u32,
     /// ### English
-    /// GL texture ID containing the frame.
+    /// GL texture ID containing the frame. It can change between frames of the same slot (e.g. after
+    /// a resize), so read it from every acquired frame.
     ///
     /// ### 中文
-    /// 包含该帧的 GL 纹理 ID。
+    /// 包含该帧的 GL 纹理 ID。同一槽位的不同帧之间它可能变化（例如 resize 之后），因此应从每个 acquire 到的帧中读取。
     pub texture_id: u32,
     /// ### English
     /// Producer fence handle (`GLsync` cast to `u64`), or 0 if unavailable.
@@ -132,6 +157,52 @@ pub struct XianWebEngineFrame {
     /// ### 中文
     /// 帧高度（像素）。
     pub height: u32,
+    /// ### English
+    /// Frames produced since this view's previous acquire that were never acquired (`0` = keeping up).
+    ///
+    /// A consistently non-zero value means the consumer is falling behind the producer and may want
+    /// to lower its composite rate or the view's resolution.
+    ///
+    /// ### 中文
+    /// 自该 view 上一次 acquire 以来已生成但从未被 acquire 的帧数（`0` 表示跟得上）。
+    ///
+    /// 若该值持续非 0，说明消费者落后于生产者，可考虑降低合成频率或 view 分辨率。
+    pub frames_skipped: u32,
+    /// ### English
+    /// Color format of `texture_id` (`XIAN_WEB_ENGINE_COLOR_FORMAT_*`; `AUTO` is resolved to
+    /// `SRGB8_A8` or `RGBA8`).
+    ///
+    /// ### 中文
+    /// `texture_id` 的颜色格式（`XIAN_WEB_ENGINE_COLOR_FORMAT_*`；`AUTO` 会被解析为 `SRGB8_A8` 或
+    /// `RGBA8`）。
+    pub color_format: u32,
+    /// ### English
+    /// Effective output colorspace of the frame: `XIAN_WEB_ENGINE_COLORSPACE_SRGB` (values were
+    /// sRGB-encoded on write) or `XIAN_WEB_ENGINE_COLORSPACE_LINEAR` (values were written as-is).
+    ///
+    /// ### 中文
+    /// 该帧实际的输出色彩空间：`XIAN_WEB_ENGINE_COLORSPACE_SRGB`（写入时已做 sRGB 编码）或
+    /// `XIAN_WEB_ENGINE_COLORSPACE_LINEAR`（原样写入）。
+    pub colorspace: u32,
+    /// ### English
+    /// Reserved (always 0).
+    ///
+    /// ### 中文
+    /// 保留字段（始终为 0）。
+    pub _reserved: u32,
+    /// ### English
+    /// When the producer presented the frame, in nanoseconds on the engine monotonic clock (read the
+    /// current value with `xian_web_engine_monotonic_time_ns`).
+    ///
+    /// Hosts can align web animation timing with game-world interpolation, e.g. by measuring how old
+    /// the frame is when it is composited.
+    ///
+    /// ### 中文
+    /// 生产者 present 该帧的时间（引擎单调时钟上的纳秒数；当前值可通过 `xian_web_engine_monotonic_time_ns`
+    /// 读取）。
+    ///
+    /// 宿主可据此让网页动画时间与游戏世界插值对齐，例如测量合成时该帧的“年龄”。
+    pub present_time_ns: u64,
 }
 
 /// ### English
@@ -139,7 +210,20 @@ pub struct XianWebEngineFrame {
 ///
 /// ### 中文
 /// `xian_web_engine` 的 C ABI 版本号。
-const XIAN_WEB_ENGINE_ABI_VERSION: u32 = 1;
+const XIAN_WEB_ENGINE_ABI_VERSION: u32 = 2;
+
+/// ### English
+/// Oldest ABI version whose bindings can still drive this library (struct layouts unchanged since).
+///
+/// ABI 2 grew `XianWebEngineFrame` from 24 to 48 bytes, so ABI 1 bindings would pass arrays too
+/// small for `xian_web_engine_views_acquire_frames`.
+///
+/// ### 中文
+/// 仍可驱动本库的最旧绑定 ABI 版本（自该版本起结构体布局未变）。
+///
+/// ABI 2 将 `XianWebEngineFrame` 从 24 字节扩展为 48 字节，因此 ABI 1 的绑定传给
+/// `xian_web_engine_views_acquire_frames` 的数组会过小。
+const XIAN_WEB_ENGINE_ABI_MIN_COMPATIBLE: u32 = 2;
 
 impl From<AcquiredFrame> for XianWebEngineFrame {
     /// ### English
@@ -160,10 +244,48 @@ impl From<AcquiredFrame> for XianWebEngineFrame {
             producer_fence: value.producer_fence,
             width: value.width,
             height: value.height,
+            frames_skipped: value.frames_skipped,
+            color_format: value.color_format,
+            colorspace: value.colorspace,
+            present_time_ns: value.present_time_ns,
+            _reserved: 0,
         }
     }
 }
 
+/// ### English
+/// Hands a newly created view to the embedder: as a handle table ID if `engine` was created with
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE`, otherwise as a `Box` pointer.
+///
+/// # Safety
+/// `engine` must have been validated.
+///
+/// #### Parameters
+/// - `engine`: Engine that created the view.
+/// - `handle`: View handle.
+///
+/// ### 中文
+/// 将新创建的 view 交给宿主：若 `engine` 以 `XIAN_WEB_ENGINE_ENGINE_FLAG_HANDLE_TABLE` 创建，则以句柄表 ID
+/// 的形式交出，否则以 `Box` 指针交出。
+///
+/// # Safety
+/// `engine` 必须已通过校验。
+///
+/// #### 参数
+/// - `engine`：创建该 view 的引擎。
+/// - `handle`：view 句柄。
+unsafe fn new_view(
+    engine: *mut XianWebEngine,
+    handle: WebEngineViewHandle,
+) -> *mut XianWebEngineView {
+    let view = XianWebEngineView { handle };
+    if unsafe { (*engine).handle_table } {
+        handles::insert(view)
+    } else {
+        Box::into_raw(Box::new(view))
+    }
+}
+
 /// ### English
 /// Converts an optional NUL-terminated UTF-8 C string into a `PathBuf`.
 ///
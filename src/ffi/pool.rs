@@ -0,0 +1,77 @@
+//! ### English
+//! C ABI bindings for the prewarmed view pool.
+//!
+//! ### 中文
+//! 预热 view 池的 C ABI 绑定。
+
+use dpi::PhysicalSize;
+
+use super::validate::{self, ffi_entry};
+use super::{XianWebEngine, XianWebEngineView};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates `count` hidden, parked views ahead of time so opening a UI screen does not pay the
+/// WebView + GL resource creation cost.
+///
+/// Blocks until the views are created. Returns the number of views added to the pool.
+///
+/// ### 中文
+/// 预先创建 `count` 个隐藏的 parked view，使打开 UI 界面时无需承担 WebView + GL 资源的创建开销。
+///
+/// 会阻塞直到 view 创建完成。返回加入池中的 view 数量。
+pub unsafe extern "C" fn xian_web_engine_prewarm_views(
+    engine: *mut XianWebEngine,
+    count: u32,
+    width: u32,
+    height: u32,
+    target_fps: u32,
+    view_flags: u32,
+) -> u32 {
+    let call = ffi_entry!(
+        xian_web_engine_prewarm_views,
+        engine,
+        count,
+        width,
+        height,
+        target_fps,
+        view_flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return 0;
+    };
+
+    let size = PhysicalSize::new(width, height);
+    runtime.prewarm_views(count, size, target_fps, view_flags)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Takes one prewarmed view, resizes it to `width x height` (0 keeps its size), and activates it.
+///
+/// The view keeps the `target_fps`/`view_flags` it was prewarmed with. Returns NULL when the pool is
+/// empty; destroy the result with `xian_web_engine_view_destroy` as usual.
+///
+/// ### 中文
+/// 取出一个预热 view，将其调整为 `width x height`（为 0 时保持原尺寸）并激活。
+///
+/// 该 view 保留预热时的 `target_fps`/`view_flags`。池为空时返回 NULL；结果照常用
+/// `xian_web_engine_view_destroy` 销毁。
+pub unsafe extern "C" fn xian_web_engine_take_prewarmed_view(
+    engine: *mut XianWebEngine,
+    width: u32,
+    height: u32,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(xian_web_engine_take_prewarmed_view, engine, width, height);
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return std::ptr::null_mut();
+    };
+
+    let size = PhysicalSize::new(width, height);
+    let handle = runtime.take_prewarmed_view(size);
+    let Some(handle) = handle else {
+        return std::ptr::null_mut();
+    };
+
+    unsafe { super::new_view(engine, handle) }
+}
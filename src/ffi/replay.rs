@@ -0,0 +1,103 @@
+//! ### English
+//! C ABI bindings for recording a view's input, resizes and navigations and replaying them later.
+//!
+//! A recording made while reproducing a user-reported UI bug can be replayed with the same timing on
+//! a fresh engine, which also makes it usable as an automated regression test of the embedding
+//! layer.
+//!
+//! ### 中文
+//! 录制 view 的输入、resize 与导航并在之后回放的 C ABI 绑定。
+//!
+//! 复现用户反馈的 UI 问题时录下的内容可以在新的引擎上按相同的时间间隔回放，因此也可用作嵌入层的自动化回归测试。
+
+use std::ffi::c_char;
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Starts recording every input event, resize and navigation delivered to `view` through this API
+/// (`xian_web_engine_view_send_input_events`, `xian_web_engine_view_resize`,
+/// `xian_web_engine_view_load_url`, ...) into `path`, with timestamps. A recording already running on
+/// the view is finished and replaced.
+///
+/// Input is recorded as accepted by the view (events dropped by a full queue are not recorded).
+/// Returns `false` if an argument is NULL/invalid or the file cannot be created.
+///
+/// ### 中文
+/// 开始将经由本 API（`xian_web_engine_view_send_input_events`、`xian_web_engine_view_resize`、
+/// `xian_web_engine_view_load_url` 等）投递给 `view` 的所有输入事件、resize 与导航连同时间戳录制到 `path`。
+/// 该 view 上正在进行的录制会被结束并替换。
+///
+/// 输入按 view 实际接收的情况录制（因队列已满而丢弃的事件不会被录制）。若参数为空/非法或文件无法创建，
+/// 则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_start_recording(
+    view: *mut XianWebEngineView,
+    path: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_start_recording, view, path);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return false;
+    };
+
+    handle.start_recording(&path)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Stops recording `view` and flushes the recording file (destroying the view also closes it).
+///
+/// Returns `false` if `view` is NULL, nothing was being recorded, or the file could not be written.
+///
+/// ### 中文
+/// 停止录制 `view` 并 flush 录制文件（销毁 view 也会关闭该文件）。
+///
+/// 若 `view` 为空指针、没有正在进行的录制，或文件无法写入，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_stop_recording(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_stop_recording, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.stop_recording()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Replays a recording made with `xian_web_engine_view_start_recording` into `view`, feeding every
+/// action back with its recorded timing, measured from when the Servo thread picks up the request.
+/// Use a freshly created view (and engine) of the recorded size for a faithful reproduction.
+///
+/// A `XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED` event is queued after the last action. Starting
+/// another replay on the view replaces the running one. Replayed actions are not recorded again, and
+/// input is dropped while the view is inactive, as it is for live input.
+///
+/// Returns `false` if an argument is NULL/invalid, the file cannot be read or is not a recording, or
+/// the engine is shutting down.
+///
+/// ### 中文
+/// 将由 `xian_web_engine_view_start_recording` 录制的内容回放到 `view`，按录制时的时间间隔重新注入每个动作，
+/// 时间从 Servo 线程接手该请求时开始计算。为了忠实复现，请使用新创建的、与录制时尺寸相同的 view（及引擎）。
+///
+/// 最后一个动作之后会排入 `XIAN_WEB_ENGINE_VIEW_EVENT_REPLAY_FINISHED` 事件。在该 view 上开始另一次回放会
+/// 替换正在进行的回放。回放的动作不会被再次录制；与实时输入一样，view 处于 inactive 时输入会被丢弃。
+///
+/// 若参数为空/非法、文件无法读取或不是录制文件，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_replay(
+    view: *mut XianWebEngineView,
+    path: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_replay, view, path);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return false;
+    };
+
+    handle.replay(&path)
+}
@@ -0,0 +1,90 @@
+//! ### English
+//! C ABI bindings for streaming a view's frames over HTTP for remote debugging.
+//!
+//! Lets developers watch an embedded view from another machine (any browser renders the stream)
+//! while the game runs fullscreen. Only builds with the `screencast` feature support it.
+//!
+//! ### 中文
+//! 通过 HTTP 推流 view 帧以进行远程调试的 C ABI 绑定。
+//!
+//! 游戏全屏运行时，开发者可以在另一台机器上观看内嵌 view（任何浏览器都能显示该流）。仅启用 `screencast`
+//! feature 的构建支持该功能。
+
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_WARN;
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Starts streaming `view`'s frames on `port`: open `http://<host>:<port>/` in a browser to watch
+/// the view live. A screencast already running on the view is replaced (use a different port, or
+/// stop it first).
+///
+/// Frames are captured through the PNG export readback path while the view is active and not
+/// frozen, at most `max_fps` times per second (`0` = 10; capped at 60), and sent as a
+/// `multipart/x-mixed-replace` stream of PNG images to at most 4 clients. The server listens on
+/// every interface without authentication, so only use it in development builds; builds with the
+/// `screencast` feature support it (`XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST`).
+///
+/// Returns `false` if `view` is NULL, `port` is `0`, the port cannot be bound, screencasting is not
+/// built in, or the engine is shutting down.
+///
+/// ### 中文
+/// 开始在 `port` 上推流 `view` 的帧：在浏览器中打开 `http://<host>:<port>/` 即可实时观看该 view。该 view 上
+/// 正在进行的屏幕投射会被替换（请使用不同端口，或先停止它）。
+///
+/// view 处于 active 且未冻结时，帧通过 PNG 导出的读回路径截取，每秒最多 `max_fps` 次（`0` 表示 10；上限为
+/// 60），并以 PNG 图像组成的 `multipart/x-mixed-replace` 流发送给最多 4 个客户端。服务器在所有网卡上监听且
+/// 没有鉴权，因此仅应在开发构建中使用；仅启用 `screencast` feature 的构建支持该功能
+/// （`XIAN_WEB_ENGINE_CAPABILITY_SCREENCAST`）。
+///
+/// 若 `view` 为空指针、`port` 为 `0`、端口无法绑定、构建未包含屏幕投射，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_start_screencast(
+    view: *mut XianWebEngineView,
+    port: u16,
+    max_fps: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_start_screencast, view, port, max_fps);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if port == 0 {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!("port must not be 0"),
+        );
+        return false;
+    }
+
+    match handle.start_screencast(port, max_fps) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Stops `view`'s screencast and closes its server (destroying the view also stops it).
+///
+/// Returns `false` if `view` is NULL, screencasting is not built in, or the engine is shutting
+/// down.
+///
+/// ### 中文
+/// 停止 `view` 的屏幕投射并关闭其服务器（销毁 view 也会停止它）。
+///
+/// 若 `view` 为空指针、构建未包含屏幕投射，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_stop_screencast(
+    view: *mut XianWebEngineView,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_stop_screencast, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.stop_screencast()
+}
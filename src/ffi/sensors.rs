@@ -0,0 +1,62 @@
+//! ### English
+//! C ABI bindings for feeding device sensors (e.g. the in-game camera) to a page.
+//!
+//! The page sees regular `deviceorientation` / `devicemotion` events, so parallax and 3D UI effects
+//! written for phones react to camera movement.
+//!
+//! ### 中文
+//! 向页面提供设备传感器数据（例如游戏内相机）的 C ABI 绑定。
+//!
+//! 页面会收到常规的 `deviceorientation` / `devicemotion` 事件，因此为手机编写的视差与 3D UI 效果会随相机
+//! 移动而响应。
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Updates the view's device orientation and, optionally, its acceleration.
+///
+/// - `orientation`: 4 floats, the rotation quaternion `[x, y, z, w]` from the Earth frame
+///   (X east, Y north, Z up) to the device frame (X right, Y up, Z out of the screen). It is
+///   normalized and reported as `deviceorientation` `alpha`/`beta`/`gamma` in degrees.
+/// - `acceleration`: 3 floats `[x, y, z]` in m/s² including gravity, in the device frame, reported
+///   as `devicemotion` `accelerationIncludingGravity`; NULL fires only `deviceorientation`.
+///
+/// Call it once per host frame while the effect is wanted. Returns `false` if `view`/`orientation`
+/// is NULL, a value is not finite, the quaternion is zero, or the engine is shutting down.
+///
+/// ### 中文
+/// 更新 view 的设备朝向，以及可选的加速度。
+///
+/// - `orientation`：4 个 float，从地球坐标系（X 向东、Y 向北、Z 向上）到设备坐标系（X 向右、Y 向上、
+///   Z 垂直屏幕向外）的旋转四元数 `[x, y, z, w]`。会被归一化，并以 `deviceorientation` 的
+///   `alpha`/`beta`/`gamma`（角度）上报。
+/// - `acceleration`：3 个 float `[x, y, z]`，设备坐标系下含重力的加速度（m/s²），以 `devicemotion` 的
+///   `accelerationIncludingGravity` 上报；为 NULL 时只派发 `deviceorientation`。
+///
+/// 需要该效果时每个宿主帧调用一次。若 `view`/`orientation` 为空指针、某个值不是有限值、四元数为零，或引擎
+/// 正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_update_sensors(
+    view: *mut XianWebEngineView,
+    orientation: *const f32,
+    acceleration: *const f32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_update_sensors,
+        view,
+        orientation,
+        acceleration,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::non_null(call, "orientation", orientation) {
+        return false;
+    }
+    let orientation = unsafe { orientation.cast::<[f32; 4]>().read_unaligned() };
+    let acceleration = (!acceleration.is_null())
+        .then(|| unsafe { acceleration.cast::<[f32; 3]>().read_unaligned() });
+
+    handle.update_sensors(orientation, acceleration)
+}
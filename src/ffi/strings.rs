@@ -0,0 +1,103 @@
+//! ### English
+//! C ABI bindings for reading per-view strings (current URL, title, last error).
+//!
+//! All getters follow the same convention: the string is copied into `buf` as NUL-terminated UTF-8
+//! (truncated at a character boundary if `capacity` is too small) and the full length in bytes,
+//! excluding the NUL, is returned. A result `>= capacity` means the copy was truncated; call again
+//! with a buffer of at least `result + 1` bytes. `buf` may be NULL when `capacity = 0` to query the
+//! length only. The values are snapshots published by the Servo thread, so reading them never blocks
+//! on it.
+//!
+//! ### 中文
+//! 读取每 view 字符串（当前 URL、标题、最近错误）的 C ABI 绑定。
+//!
+//! 所有 getter 遵循相同约定：字符串以 NUL 结尾 UTF-8 复制到 `buf`（若 `capacity` 不足则在字符边界处截断），
+//! 并返回不含 NUL 的完整字节长度。返回值 `>= capacity` 表示发生了截断；请用至少 `返回值 + 1` 字节的
+//! 缓冲区再次调用。`capacity = 0` 时 `buf` 可为 NULL，仅查询长度。这些值是 Servo 线程发布的快照，
+//! 读取时不会等待 Servo 线程。
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Copies the URL of the view's current page into `buf` and returns its length (see the module
+/// docs). Empty until the first navigation commits.
+///
+/// Returns `0` if an argument is NULL/invalid.
+///
+/// ### 中文
+/// 将 view 当前页面的 URL 复制到 `buf` 并返回其长度（见模块文档）。首次导航提交前为空。
+///
+/// 若参数为空/非法，则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_get_url(
+    view: *mut XianWebEngineView,
+    buf: *mut u8,
+    capacity: u32,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_get_url, view, buf, capacity);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    let Some(buf) = (unsafe { validate::slice_mut(call, "buf", buf, capacity as usize) }) else {
+        return 0;
+    };
+
+    handle.copy_url(buf) as u32
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Copies the title of the view's current page into `buf` and returns its length (see the module
+/// docs). Empty if the page has no title.
+///
+/// Returns `0` if an argument is NULL/invalid.
+///
+/// ### 中文
+/// 将 view 当前页面的标题复制到 `buf` 并返回其长度（见模块文档）。页面无标题时为空。
+///
+/// 若参数为空/非法，则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_get_title(
+    view: *mut XianWebEngineView,
+    buf: *mut u8,
+    capacity: u32,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_get_title, view, buf, capacity);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    let Some(buf) = (unsafe { validate::slice_mut(call, "buf", buf, capacity as usize) }) else {
+        return 0;
+    };
+
+    handle.copy_title(buf) as u32
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Copies the message of the view's most recent failure (invalid URL, failed export, page crash)
+/// into `buf` and returns its length (see the module docs). Empty if nothing has failed yet; the
+/// message is kept until the next failure.
+///
+/// Returns `0` if an argument is NULL/invalid.
+///
+/// ### 中文
+/// 将 view 最近一次失败（非法 URL、导出失败、页面崩溃）的消息复制到 `buf` 并返回其长度（见模块文档）。
+/// 尚未发生失败时为空；该消息会保留到下一次失败。
+///
+/// 若参数为空/非法，则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_get_last_error(
+    view: *mut XianWebEngineView,
+    buf: *mut u8,
+    capacity: u32,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_get_last_error, view, buf, capacity);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    let Some(buf) = (unsafe { validate::slice_mut(call, "buf", buf, capacity as usize) }) else {
+        return 0;
+    };
+
+    handle.copy_last_error(buf) as u32
+}
@@ -0,0 +1,420 @@
+//! ### English
+//! Centralized FFI argument validation and debug-build call tracing.
+//!
+//! Every entry point goes through these helpers instead of open-coding NULL checks, so embedder
+//! integration mistakes (NULL or misaligned handles, out-of-range slots, unknown enum values) are
+//! rejected the same way everywhere and reported through the log callback
+//! (`xian_web_engine_set_log_callback`) with the name of the offending function and argument.
+//!
+//! ### 中文
+//! 集中式 FFI 参数校验与 debug 构建下的调用追踪。
+//!
+//! 所有入口都通过这些辅助函数完成校验，而不是各自手写 NULL 检查；因此宿主集成错误（NULL 或未对齐的
+//! 句柄、越界槽位、未知枚举值）在各处都以相同方式被拒绝，并通过日志回调
+//! （`xian_web_engine_set_log_callback`）上报出错的函数名与参数名。
+
+use std::ffi::{CStr, c_char};
+use std::fmt;
+
+use crate::engine::log::{
+    XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, XIAN_WEB_ENGINE_LOG_LEVEL_TRACE, log, log_enabled,
+};
+use crate::engine::{EngineRuntime, TRIPLE_BUFFER_COUNT};
+
+use super::handles::{self, ViewRef};
+use super::{XianWebEngine, XianWebEngineView};
+
+/// ### English
+/// Marks an FFI entry point: in debug builds logs the call with its arguments at
+/// `XIAN_WEB_ENGINE_LOG_LEVEL_TRACE`, and evaluates to the function name for use in validation
+/// messages.
+///
+/// ### 中文
+/// 标记 FFI 入口：debug 构建下以 `XIAN_WEB_ENGINE_LOG_LEVEL_TRACE` 记录调用及其参数，
+/// 并求值为函数名，供校验消息使用。
+macro_rules! ffi_entry {
+    ($function:ident $(, $arg:ident)* $(,)?) => {{
+        if cfg!(debug_assertions) {
+            $crate::ffi::validate::trace(
+                stringify!($function),
+                &[$((stringify!($arg), &$arg as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+        stringify!($function)
+    }};
+}
+
+pub(super) use ffi_entry;
+
+/// ### English
+/// Logs one FFI call with its arguments.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `args`: Argument names and values.
+///
+/// ### 中文
+/// 记录一次 FFI 调用及其参数。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `args`：参数名与参数值。
+pub(super) fn trace(function: &str, args: &[(&str, &dyn fmt::Debug)]) {
+    if !log_enabled(XIAN_WEB_ENGINE_LOG_LEVEL_TRACE) {
+        return;
+    }
+    let args = args
+        .iter()
+        .map(|(name, value)| format!("{name}={value:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    log(
+        XIAN_WEB_ENGINE_LOG_LEVEL_TRACE,
+        format_args!("{function}({args})"),
+    );
+}
+
+/// ### English
+/// Logs a message attributed to an FFI function.
+///
+/// #### Parameters
+/// - `level`: `XIAN_WEB_ENGINE_LOG_LEVEL_*`.
+/// - `function`: Exported function name.
+/// - `message`: Message text.
+///
+/// ### 中文
+/// 记录一条归属于某个 FFI 函数的消息。
+///
+/// #### 参数
+/// - `level`：`XIAN_WEB_ENGINE_LOG_LEVEL_*`。
+/// - `function`：导出函数名。
+/// - `message`：消息文本。
+pub(super) fn report(level: u32, function: &str, message: fmt::Arguments<'_>) {
+    log(level, format_args!("{function}: {message}"));
+}
+
+/// ### English
+/// Reports a rejected argument.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `problem`: What is wrong with it.
+///
+/// ### 中文
+/// 上报一个被拒绝的参数。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `problem`：参数的问题所在。
+fn reject(function: &str, arg: &str, problem: fmt::Arguments<'_>) {
+    report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+        function,
+        format_args!("`{arg}` {problem}"),
+    );
+}
+
+/// ### English
+/// Checks that a required pointer is non-NULL (for arguments read without alignment requirements).
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `ptr`: Pointer to check.
+///
+/// ### 中文
+/// 检查必填指针非 NULL（用于无需对齐即可读取的参数）。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `ptr`：要检查的指针。
+pub(super) fn non_null<T>(function: &str, arg: &str, ptr: *const T) -> bool {
+    if ptr.is_null() {
+        reject(function, arg, format_args!("is NULL"));
+        return false;
+    }
+    true
+}
+
+/// ### English
+/// Checks that a required pointer is non-NULL and aligned for `T`.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `ptr`: Pointer to check.
+///
+/// ### 中文
+/// 检查必填指针非 NULL 且按 `T` 对齐。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `ptr`：要检查的指针。
+pub(super) fn pointer<T>(function: &str, arg: &str, ptr: *const T) -> bool {
+    if !non_null(function, arg, ptr) {
+        return false;
+    }
+    if !ptr.is_aligned() {
+        reject(
+            function,
+            arg,
+            format_args!(
+                "({ptr:p}) is not aligned to {} bytes",
+                std::mem::align_of::<T>()
+            ),
+        );
+        return false;
+    }
+    true
+}
+
+/// ### English
+/// Validates an engine handle.
+///
+/// # Safety
+/// A non-NULL, aligned `engine` must come from `xian_web_engine_create*` and not be destroyed yet.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `engine`: Engine handle.
+///
+/// ### 中文
+/// 校验引擎句柄。
+///
+/// # Safety
+/// 非 NULL 且对齐的 `engine` 必须来自 `xian_web_engine_create*` 且尚未销毁。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `engine`：引擎句柄。
+pub(super) unsafe fn engine<'a>(
+    function: &str,
+    engine: *mut XianWebEngine,
+) -> Option<&'a EngineRuntime> {
+    if !pointer(function, "engine", engine) {
+        return None;
+    }
+    Some(unsafe { &(*engine).runtime })
+}
+
+/// ### English
+/// Validates a view handle: a table ID is resolved (and rejected if stale), a pointer is checked for
+/// NULL/alignment.
+///
+/// # Safety
+/// A non-NULL, aligned `view` pointer must come from a view-creating function and not be destroyed
+/// yet.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `view`: View handle.
+///
+/// ### 中文
+/// 校验 view 句柄：表 ID 会被解析（陈旧时拒绝），指针会检查 NULL/对齐。
+///
+/// # Safety
+/// 非 NULL 且对齐的 `view` 指针必须来自创建 view 的函数且尚未销毁。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `view`：view 句柄。
+pub(super) unsafe fn view<'a>(function: &str, view: *mut XianWebEngineView) -> Option<ViewRef<'a>> {
+    if handles::is_table_id(view) {
+        let resolved = handles::get(view);
+        if resolved.is_none() {
+            stale_view(function, view);
+        }
+        return resolved.map(ViewRef::Table);
+    }
+    if !pointer(function, "view", view) {
+        return None;
+    }
+    Some(ViewRef::Borrowed(unsafe { &(*view).handle }))
+}
+
+/// ### English
+/// Reports a view table ID that is stale (destroyed) or was never handed out.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `view`: Rejected table ID.
+///
+/// ### 中文
+/// 上报一个已陈旧（已销毁）或从未分配过的 view 表 ID。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `view`：被拒绝的表 ID。
+pub(super) fn stale_view(function: &str, view: *mut XianWebEngineView) {
+    reject(
+        function,
+        "view",
+        format_args!("({view:p}) is a destroyed or unknown view handle"),
+    );
+}
+
+/// ### English
+/// Validates an input array of `count` elements (`count == 0` yields an empty slice, even for NULL).
+///
+/// # Safety
+/// A non-NULL `ptr` must be valid for reads of `count` elements for the returned lifetime.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `ptr`: Array pointer.
+/// - `count`: Element count.
+///
+/// ### 中文
+/// 校验长度为 `count` 的输入数组（`count == 0` 时返回空切片，即使指针为 NULL）。
+///
+/// # Safety
+/// 非 NULL 的 `ptr` 在返回的生命周期内必须可读取 `count` 个元素。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `ptr`：数组指针。
+/// - `count`：元素数量。
+pub(super) unsafe fn slice<'a, T>(
+    function: &str,
+    arg: &str,
+    ptr: *const T,
+    count: usize,
+) -> Option<&'a [T]> {
+    if count == 0 {
+        return Some(&[]);
+    }
+    if !pointer(function, arg, ptr) {
+        return None;
+    }
+    Some(unsafe { std::slice::from_raw_parts(ptr, count) })
+}
+
+/// ### English
+/// Validates an output array of `count` elements (`count == 0` yields an empty slice, even for NULL).
+///
+/// # Safety
+/// A non-NULL `ptr` must be valid for writes of `count` elements for the returned lifetime.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `ptr`: Array pointer.
+/// - `count`: Element count.
+///
+/// ### 中文
+/// 校验长度为 `count` 的输出数组（`count == 0` 时返回空切片，即使指针为 NULL）。
+///
+/// # Safety
+/// 非 NULL 的 `ptr` 在返回的生命周期内必须可写入 `count` 个元素。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `ptr`：数组指针。
+/// - `count`：元素数量。
+pub(super) unsafe fn slice_mut<'a, T>(
+    function: &str,
+    arg: &str,
+    ptr: *mut T,
+    count: usize,
+) -> Option<&'a mut [T]> {
+    if count == 0 {
+        return Some(&mut []);
+    }
+    if !pointer(function, arg, ptr) {
+        return None;
+    }
+    Some(unsafe { std::slice::from_raw_parts_mut(ptr, count) })
+}
+
+/// ### English
+/// Checks that a frame slot index is in range.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `slot`: Slot index from `XianWebEngineFrame::slot`.
+///
+/// ### 中文
+/// 检查帧槽位索引是否在范围内。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `slot`：来自 `XianWebEngineFrame::slot` 的槽位索引。
+pub(super) fn slot(function: &str, slot: u32) -> bool {
+    if (slot as usize) < TRIPLE_BUFFER_COUNT {
+        return true;
+    }
+    reject(
+        function,
+        "slot",
+        format_args!("({slot}) is out of range 0..{TRIPLE_BUFFER_COUNT}"),
+    );
+    false
+}
+
+/// ### English
+/// Checks that an enum-like argument is one of the values the ABI defines.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `value`: Passed value.
+/// - `valid`: Values defined by the ABI.
+///
+/// ### 中文
+/// 检查枚举类参数是否为 ABI 定义的取值之一。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `value`：传入的值。
+/// - `valid`：ABI 定义的取值。
+pub(super) fn enum_value(function: &str, arg: &str, value: u32, valid: &[u32]) -> bool {
+    if valid.contains(&value) {
+        return true;
+    }
+    reject(
+        function,
+        arg,
+        format_args!("({value}) is not one of {valid:?}"),
+    );
+    false
+}
+
+/// ### English
+/// Validates a required NUL-terminated UTF-8 string argument.
+///
+/// # Safety
+/// A non-NULL `ptr` must point to a NUL-terminated string valid for the returned lifetime.
+///
+/// #### Parameters
+/// - `function`: Exported function name.
+/// - `arg`: Argument name.
+/// - `ptr`: C string pointer.
+///
+/// ### 中文
+/// 校验必填的 NUL 结尾 UTF-8 字符串参数。
+///
+/// # Safety
+/// 非 NULL 的 `ptr` 必须指向在返回的生命周期内有效的 NUL 结尾字符串。
+///
+/// #### 参数
+/// - `function`：导出函数名。
+/// - `arg`：参数名。
+/// - `ptr`：C 字符串指针。
+pub(super) unsafe fn cstr<'a>(function: &str, arg: &str, ptr: *const c_char) -> Option<&'a str> {
+    if !non_null(function, arg, ptr) {
+        return None;
+    }
+    let Ok(value) = unsafe { CStr::from_ptr(ptr) }.to_str() else {
+        reject(function, arg, format_args!("is not valid UTF-8"));
+        return None;
+    };
+    Some(value)
+}
@@ -4,10 +4,32 @@
 //! ### 中文
 //! view 生命周期与 view 级别请求的 C ABI 绑定。
 
-use std::ffi::{CStr, c_char};
+use std::ffi::{c_char, c_void};
+use std::path::PathBuf;
 
 use dpi::PhysicalSize;
 
+use crate::engine::log::{
+    XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG, XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+    XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+};
+use crate::engine::{
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL, XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND,
+    XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ, XIAN_WEB_ENGINE_COLOR_SCHEME_DARK,
+    XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT, XIAN_WEB_ENGINE_COLORSPACE_AUTO,
+    XIAN_WEB_ENGINE_COLORSPACE_LINEAR, XIAN_WEB_ENGINE_COLORSPACE_SRGB,
+    XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF, XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS, XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN, XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS,
+    XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE,
+    XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY, XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP,
+    XIAN_WEB_ENGINE_STATUS_FAILED, XIAN_WEB_ENGINE_STATUS_OK, XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE,
+    XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END, XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START,
+    XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN,
+};
+
+use super::handles;
+use super::validate::{self, ffi_entry};
 use super::{XianWebEngine, XianWebEngineView};
 
 #[unsafe(no_mangle)]
@@ -27,37 +49,287 @@ pub unsafe extern "C" fn xian_web_engine_view_create(
     target_fps: u32,
     view_flags: u32,
 ) -> *mut XianWebEngineView {
-    if engine.is_null() {
+    let call = ffi_entry!(
+        xian_web_engine_view_create,
+        engine,
+        width,
+        height,
+        target_fps,
+        view_flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
         return std::ptr::null_mut();
-    }
+    };
+
+    let size = PhysicalSize::new(width, height);
+    let handle = runtime.create_view(size, target_fps, 0, view_flags);
+    let handle = match handle {
+        Ok(handle) => handle,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let view = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("created view {view:p}"),
+    );
+    view
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates one view in a storage group (`XIAN_WEB_ENGINE_STORAGE_GROUP_*` or any embedder-chosen ID):
+/// views created with the same group share cookies, `localStorage`, `sessionStorage`, IndexedDB and
+/// the HTTP cache per origin (e.g. all pages of the mod's own site), views of other groups cannot
+/// see them, and `XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL` keeps the storage in memory only
+/// (e.g. untrusted server content).
+///
+/// Every group runs its own Servo instance (thread, GL context and storage), started when the
+/// group's first view is created; that call blocks like engine creation.
+///
+/// ### 中文
+/// 在一个存储组（`XIAN_WEB_ENGINE_STORAGE_GROUP_*` 或宿主自选的任意 ID）中创建一个 view：以相同组创建的 view
+/// 按 origin 共享 cookie、`localStorage`、`sessionStorage`、IndexedDB 与 HTTP 缓存（例如 mod 自身站点的所有页面），
+/// 其他组的 view 看不到它们；`XIAN_WEB_ENGINE_STORAGE_GROUP_EPHEMERAL` 则只把存储保存在内存中（例如不受信任的
+/// 服务器内容）。
+///
+/// 每个组运行独立的 Servo 实例（线程、GL 上下文与存储），在该组创建第一个 view 时启动；该次调用与创建引擎一样
+/// 会阻塞。
+pub unsafe extern "C" fn xian_web_engine_view_create_in_storage_group(
+    engine: *mut XianWebEngine,
+    width: u32,
+    height: u32,
+    target_fps: u32,
+    view_flags: u32,
+    storage_group: u32,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(
+        xian_web_engine_view_create_in_storage_group,
+        engine,
+        width,
+        height,
+        target_fps,
+        view_flags,
+        storage_group,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return std::ptr::null_mut();
+    };
 
     let size = PhysicalSize::new(width, height);
-    let handle = unsafe { (*engine).runtime.create_view(size, target_fps, view_flags) };
-    let Ok(handle) = handle else {
+    let handle =
+        match runtime.create_view_in_storage_group(size, target_fps, view_flags, storage_group) {
+            Ok(handle) => handle,
+            Err(err) => {
+                validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+                return std::ptr::null_mut();
+            }
+        };
+
+    let view = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("created view {view:p} in storage group {storage_group}"),
+    );
+    view
+}
+
+/// ### English
+/// Completion callback of `xian_web_engine_create_view_async`: `status` is
+/// `XIAN_WEB_ENGINE_STATUS_OK` once the view exists, or `XIAN_WEB_ENGINE_STATUS_FAILED` if it could
+/// not be created (the reason is logged).
+///
+/// Invoked once, on the Servo thread (or on the thread shutting the engine down); it must return
+/// quickly.
+///
+/// ### 中文
+/// `xian_web_engine_create_view_async` 的完成回调：view 创建完成时 `status` 为 `XIAN_WEB_ENGINE_STATUS_OK`，
+/// 无法创建时为 `XIAN_WEB_ENGINE_STATUS_FAILED`（原因会记录到日志）。
+///
+/// 只会调用一次，在 Servo 线程（或正在关闭引擎的线程）上调用；回调必须尽快返回。
+pub type XianWebEngineViewCreatedFn = unsafe extern "C" fn(user_data: *mut c_void, status: i32);
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates one view without blocking: returns a provisional handle right away and reports through
+/// `callback` once the Servo thread has created the view, so the calling (render) thread never
+/// stalls on the Servo thread the way `xian_web_engine_view_create` can (for up to the engine's
+/// configured `view_timeout_ms`, or indefinitely under
+/// `XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER`).
+///
+/// The handle is usable immediately. Input, resizes and `load_url` are queued as usual; other
+/// requests are held back and applied once the view exists; closing or destroying it before then
+/// destroys the view as soon as it is created. No frames are published and
+/// `xian_web_engine_view_is_alive` returns `false` until creation has finished. If creation fails,
+/// requests through the handle fail; destroy it as usual.
+///
+/// `callback` may be NULL. Returns NULL (and never invokes `callback`) if an argument is invalid or
+/// the engine is shutting down.
+///
+/// ### 中文
+/// 以非阻塞方式创建一个 view：立即返回一个临时句柄，并在 Servo 线程创建完 view 后通过 `callback` 通知，
+/// 从而使调用方（渲染）线程不会像 `xian_web_engine_view_create` 那样卡在 Servo 线程上（最长为引擎配置的
+/// `view_timeout_ms`，在 `XIAN_WEB_ENGINE_TIMEOUT_POLICY_WAIT_FOREVER` 下则可能无限期等待）。
+///
+/// 该句柄可以立即使用。输入、resize 与 `load_url` 照常排队；其他请求会暂缓，并在 view 创建完成后应用；在此之前
+/// 关闭或销毁句柄，会使 view 在创建后立即被销毁。创建完成之前不会发布任何帧，`xian_web_engine_view_is_alive`
+/// 也返回 `false`。若创建失败，经由该句柄的请求会失败；请照常销毁句柄。
+///
+/// `callback` 可以为 NULL。若参数非法或引擎正在关闭，则返回 NULL（且永远不会调用 `callback`）。
+pub unsafe extern "C" fn xian_web_engine_create_view_async(
+    engine: *mut XianWebEngine,
+    width: u32,
+    height: u32,
+    target_fps: u32,
+    view_flags: u32,
+    callback: Option<XianWebEngineViewCreatedFn>,
+    user_data: *mut c_void,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(
+        xian_web_engine_create_view_async,
+        engine,
+        width,
+        height,
+        target_fps,
+        view_flags,
+        callback,
+        user_data,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
         return std::ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(XianWebEngineView { handle }))
+    let user_data = user_data as usize;
+    let done = Box::new(move |result: Result<(), String>| {
+        let status = match result {
+            Ok(()) => XIAN_WEB_ENGINE_STATUS_OK,
+            Err(err) => {
+                validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+                XIAN_WEB_ENGINE_STATUS_FAILED
+            }
+        };
+        if let Some(callback) = callback {
+            unsafe { callback(user_data as *mut c_void, status) };
+        }
+    });
+    let size = PhysicalSize::new(width, height);
+    let handle = match runtime.create_view_async(size, target_fps, 0, view_flags, done) {
+        Ok(handle) => handle,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let view = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("creating view {view:p}"),
+    );
+    view
 }
 
 #[unsafe(no_mangle)]
 /// ### English
-/// Destroys a view created by `xian_web_engine_view_create`.
+/// Destroys a view created by `xian_web_engine_view_create` and frees the handle.
+///
+/// Release acquired frames before destroying the view, and do not sample any textures from this
+/// view afterwards. GL deletion of the slot textures is deferred until every release fence has
+/// signaled, so GPU work already submitted against them stays valid; frames still held at
+/// destruction keep their textures alive for at most one more second.
 ///
-/// The caller must ensure there are no outstanding acquired frames, and must not sample any textures
-/// from this view after destruction.
+/// If the view was already closed with `xian_web_engine_close_view`, this only frees the handle.
+/// `view` must not be used after this call.
 ///
 /// ### 中文
-/// 销毁由 `xian_web_engine_view_create` 创建的 view。
+/// 销毁由 `xian_web_engine_view_create` 创建的 view，并释放句柄。
 ///
-/// 宿主必须确保没有未释放的 acquired frame，并且 destroy 之后不再采样该 view 的纹理。
+/// 请在销毁 view 之前释放已 acquire 的帧，且 destroy 之后不要再采样该 view 的纹理。槽位纹理的 GL 删除
+/// 会推迟到所有释放 fence 均已 signal 之后，因此已针对它们提交的 GPU 工作仍然有效；销毁时仍被持有的帧
+/// 最多会让其纹理再存活一秒。
+///
+/// 若该 view 已通过 `xian_web_engine_close_view` 关闭，则本函数只释放句柄。调用之后不得再使用 `view`。
 pub unsafe extern "C" fn xian_web_engine_view_destroy(view: *mut XianWebEngineView) {
-    if view.is_null() {
+    let call = ffi_entry!(xian_web_engine_view_destroy, view);
+    if handles::is_table_id(view) {
+        if handles::remove(view).is_none() {
+            validate::stale_view(call, view);
+            return;
+        }
+    } else if view.is_null() || !validate::pointer(call, "view", view) {
         return;
+    } else {
+        unsafe {
+            drop(Box::from_raw(view));
+        }
     }
-    unsafe {
-        drop(Box::from_raw(view));
-    }
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("destroyed view {view:p}"),
+    );
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Closes a view: the page is torn down on the Servo thread exactly as by
+/// `xian_web_engine_view_destroy`, but the handle stays valid until `xian_web_engine_view_destroy`
+/// frees it.
+///
+/// This decouples the view's lifetime from the handle's, for GC-based hosts: close the view
+/// deterministically (e.g. when its screen closes) and let the object that owns the pointer free
+/// the handle whenever it is collected. Other references may keep calling through the handle in
+/// the meantime; requests then fail or are ignored, no frames are acquired, and
+/// `xian_web_engine_view_is_alive` returns `false`. The same rules as for destroying apply to held
+/// frames and textures.
+///
+/// Returns `false` if `view` is NULL or already closed.
+///
+/// ### 中文
+/// 关闭一个 view：页面在 Servo 线程上的销毁方式与 `xian_web_engine_view_destroy` 完全相同，但句柄在
+/// `xian_web_engine_view_destroy` 释放它之前保持有效。
+///
+/// 这使 view 的生命周期与句柄解耦，便于基于 GC 的宿主：确定性地关闭 view（例如其界面关闭时），而持有指针的
+/// 对象在被回收时再释放句柄。在此期间其他引用仍可通过该句柄调用；此时请求会失败或被忽略，不再 acquire 任何帧，
+/// 且 `xian_web_engine_view_is_alive` 返回 `false`。持有的帧与纹理遵循与销毁相同的规则。
+///
+/// 若 `view` 为空指针或已被关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_close_view(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_close_view, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.close()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns whether the view behind `view` still exists: it was neither closed with
+/// `xian_web_engine_close_view` nor lost to engine shutdown. Commands to a view are matched by its
+/// ID and a per-view token, so a view ID reused after close never makes a stale handle look alive.
+///
+/// Safe to call from any thread for as long as the handle has not been destroyed. Returns `false` if
+/// `view` is NULL, or if it is a handle-table ID that was already destroyed.
+///
+/// ### 中文
+/// 返回 `view` 背后的 view 是否仍然存在：既没有被 `xian_web_engine_close_view` 关闭，也没有因引擎关闭而失效。
+/// 发往 view 的命令按其 ID 与每 view 的 token 匹配，因此关闭后被复用的 view ID 不会让陈旧句柄看起来仍然存活。
+///
+/// 在句柄被销毁之前可在任意线程调用。若 `view` 为空指针，或是已被销毁的句柄表 ID，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_is_alive(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_is_alive, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.is_alive()
 }
 
 #[unsafe(no_mangle)]
@@ -67,11 +339,11 @@ pub unsafe extern "C" fn xian_web_engine_view_destroy(view: *mut XianWebEngineVi
 /// ### 中文
 /// 设置 view 是否 active（active 的 view 才会渲染并接收输入）。
 pub unsafe extern "C" fn xian_web_engine_view_set_active(view: *mut XianWebEngineView, active: u8) {
-    if view.is_null() {
+    let call = ffi_entry!(xian_web_engine_view_set_active, view, active);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
         return;
-    }
+    };
 
-    let handle = unsafe { &(*view).handle };
     if handle.set_active(active != 0) {
         handle.wake();
     }
@@ -99,16 +371,14 @@ pub unsafe extern "C" fn xian_web_engine_view_load_url(
     view: *mut XianWebEngineView,
     url: *const c_char,
 ) -> bool {
-    if view.is_null() || url.is_null() {
+    let call = ffi_entry!(xian_web_engine_view_load_url, view, url);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(url_str) = (unsafe { validate::cstr(call, "url", url) }) else {
         return false;
-    }
-
-    let url_str = match unsafe { CStr::from_ptr(url) }.to_str() {
-        Ok(s) => s,
-        Err(_) => return false,
     };
 
-    let handle = unsafe { &(*view).handle };
     if handle.load_url(url_str) {
         handle.wake();
     }
@@ -119,23 +389,1631 @@ pub unsafe extern "C" fn xian_web_engine_view_load_url(
 /// ### English
 /// Requests a resize (in pixels).
 ///
-/// This call is coalesced: only the latest size is kept until the Servo thread drains it.
+/// This call is coalesced: only the latest size is kept until the Servo thread drains it (and, with
+/// a resize debounce set, until resizes stop for the debounce period).
 ///
 /// ### 中文
 /// 请求 resize（单位：像素）。
 ///
-/// 该调用会被合并：只保留最新尺寸，等待 Servo 线程 drain。
+/// 该调用会被合并：只保留最新尺寸，等待 Servo 线程 drain（若设置了 resize 防抖，还需等待 resize 停止达到防抖时长）。
 pub unsafe extern "C" fn xian_web_engine_view_resize(
     view: *mut XianWebEngineView,
     width: u32,
     height: u32,
 ) {
-    if view.is_null() {
+    let call = ffi_entry!(xian_web_engine_view_resize, view, width, height);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
         return;
-    }
+    };
 
-    let handle = unsafe { &(*view).handle };
     if handle.queue_resize(PhysicalSize::new(width.max(1), height.max(1))) {
         handle.wake();
     }
 }
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets the view's resize debounce: resizes are held back until none arrived for `debounce_ms`, then
+/// only the latest size is applied, avoiding a slot texture reallocation per step while a panel is
+/// dragged. Until then frames keep the previous size (`XianWebEngineFrame` reports it), so the host
+/// draws them stretched. `0` (default) applies every resize immediately.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 设置 view 的 resize 防抖：resize 会被暂缓，直到 `debounce_ms` 内没有新的 resize 到达，然后只应用最新尺寸，
+/// 从而避免拖动面板时每一步都重新分配槽位纹理。在此之前帧保持之前的尺寸（`XianWebEngineFrame` 会报告该尺寸），
+/// 由宿主拉伸绘制。`0`（默认）表示每次 resize 都立即应用。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_resize_debounce(
+    view: *mut XianWebEngineView,
+    debounce_ms: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_resize_debounce, view, debounce_ms);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_resize_debounce(debounce_ms)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Resizes the view (in pixels) immediately, bypassing the resize debounce (e.g. when a drag ends);
+/// a resize held back by the debounce is dropped.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 绕过 resize 防抖立即 resize 该 view（单位：像素；例如拖动结束时）；被防抖暂缓的 resize 会被丢弃。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_resize_immediate(
+    view: *mut XianWebEngineView,
+    width: u32,
+    height: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_resize_immediate, view, width, height);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.resize_immediate(PhysicalSize::new(width, height))
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Checks per-view sandbox restrictions (`XIAN_WEB_ENGINE_SANDBOX_*` bits; `0` means none).
+///
+/// Servo applies sandboxing to nested browsing contexts (`<iframe sandbox>`), not to the top-level
+/// document of a WebView, so only restrictions the engine enforces itself are accepted:
+/// `XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS` always holds, because the engine never opens popups or
+/// auxiliary windows. The other restrictions cannot be enforced on the page the view loads and are
+/// rejected instead of being silently ignored; show untrusted HTML (e.g. server MOTD) inside an
+/// `<iframe sandbox>` of a page the embedder controls.
+///
+/// Returns `false` if `view` is NULL or `sandbox_flags` contains unknown bits or a restriction that
+/// cannot be enforced.
+///
+/// ### 中文
+/// 检查 per-view 沙箱限制（`XIAN_WEB_ENGINE_SANDBOX_*` 位；`0` 表示无限制）。
+///
+/// Servo 只对嵌套浏览上下文（`<iframe sandbox>`）应用沙箱，而不作用于 WebView 的顶层文档，因此只接受引擎
+/// 自身能执行的限制：`XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS` 始终成立，因为引擎从不打开弹窗或辅助窗口。
+/// 其他限制无法作用于 view 加载的页面，会被拒绝而不是被静默忽略；请在宿主控制的页面中以 `<iframe sandbox>`
+/// 展示不可信 HTML（例如服务器 MOTD）。
+///
+/// 若 `view` 为空指针，或 `sandbox_flags` 含未知位或无法执行的限制，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_sandbox(
+    view: *mut XianWebEngineView,
+    sandbox_flags: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_sandbox, view, sandbox_flags);
+    if unsafe { validate::view(call, view) }.is_none() {
+        return false;
+    }
+
+    let known = XIAN_WEB_ENGINE_SANDBOX_DENY_SCRIPTS
+        | XIAN_WEB_ENGINE_SANDBOX_DENY_FORMS
+        | XIAN_WEB_ENGINE_SANDBOX_DENY_SUBFRAMES
+        | XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS
+        | XIAN_WEB_ENGINE_SANDBOX_DENY_SAME_ORIGIN;
+    if sandbox_flags & !known != 0 {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!("unknown sandbox flags {sandbox_flags:#x}"),
+        );
+        return false;
+    }
+    let unenforceable = sandbox_flags & !XIAN_WEB_ENGINE_SANDBOX_DENY_POPUPS;
+    if unenforceable != 0 {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_WARN,
+            call,
+            format_args!(
+                "sandbox flags {unenforceable:#x} cannot be enforced on a view's top-level document"
+            ),
+        );
+        return false;
+    }
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Requests an asynchronous export of the current page (`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`).
+///
+/// The capture is taken on the Servo thread and encoded/written on a helper thread; completion is
+/// reported through `xian_web_engine_view_poll_events` as an `EXPORT_COMPLETE` event carrying the
+/// returned request ID. Servo has no print pipeline yet, so the whole document is captured by
+/// scrolling it viewport by viewport and stitching the screenshots (fixed/sticky elements repeat
+/// per viewport, and the original scroll offset is restored); PDF output paginates that image into
+/// A4-proportioned pages.
+///
+/// Returns the request ID, or `0` if an argument is invalid or the engine is shutting down.
+///
+/// ### 中文
+/// 请求异步导出当前页面（`XIAN_WEB_ENGINE_EXPORT_FORMAT_*`）。
+///
+/// 截图在 Servo 线程进行，编码/写入在辅助线程完成；完成后会通过 `xian_web_engine_view_poll_events`
+/// 上报携带所返回请求 ID 的 `EXPORT_COMPLETE` 事件。Servo 尚无打印管线，因此通过逐个视口滚动并拼接截图来
+/// 截取整个文档（fixed/sticky 元素会在每个视口重复出现，完成后恢复原滚动偏移）；PDF 输出会把该图像分页为
+/// A4 比例的页面。
+///
+/// 返回请求 ID；若参数非法或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_export(
+    view: *mut XianWebEngineView,
+    path: *const c_char,
+    format: u32,
+) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_export, view, path, format);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+    let Some(path) = (unsafe { super::cstr_to_path(path) }) else {
+        return 0;
+    };
+    let formats = [
+        XIAN_WEB_ENGINE_EXPORT_FORMAT_PNG,
+        XIAN_WEB_ENGINE_EXPORT_FORMAT_PDF,
+    ];
+    if !validate::enum_value(call, "format", format, &formats) {
+        return 0;
+    }
+
+    handle.export(path, format).unwrap_or(0)
+}
+
+/// ### English
+/// Completion callback of `xian_web_engine_capture_element`. On `XIAN_WEB_ENGINE_STATUS_OK`,
+/// `pixels` holds `width * height` RGBA8 pixels (tightly packed, top row first, in the view's
+/// color format encoding); otherwise `width` and `height` are `0`, `pixels` is NULL and the reason
+/// is logged. `pixels` is only valid during the call.
+///
+/// Invoked exactly once, on the Servo thread (or, with `XIAN_WEB_ENGINE_STATUS_FAILED`, on the
+/// thread that closes the view or shuts the engine down first); it must return quickly.
+///
+/// ### 中文
+/// `xian_web_engine_capture_element` 的完成回调。`XIAN_WEB_ENGINE_STATUS_OK` 时 `pixels` 保存
+/// `width * height` 个 RGBA8 像素（紧密排列、首行在前，采用 view 颜色格式的编码）；否则 `width` 与 `height`
+/// 为 `0`，`pixels` 为空指针，原因会记录到日志。`pixels` 仅在回调期间有效。
+///
+/// 恰好调用一次，在 Servo 线程上调用（或者以 `XIAN_WEB_ENGINE_STATUS_FAILED` 在先关闭该 view 或引擎的线程上
+/// 调用）；回调必须尽快返回。
+pub type XianWebEngineElementCaptureFn = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    status: i32,
+    width: u32,
+    height: u32,
+    pixels: *const u8,
+);
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Captures the first element matching `css_selector` (NUL-terminated UTF-8), so hosts can
+/// rasterize individual widgets (e.g. item tooltips) into their own atlases.
+///
+/// The element's bounding rectangle is resolved on the Servo thread and clipped to the viewport,
+/// then only that region of the back slot is read back once the pending frame has been painted.
+/// Parts of the element outside the viewport are not captured, and whatever is painted over it
+/// (overlapping elements, the default background) is captured with it. An invalid selector, no
+/// match, or an element entirely outside the viewport fail.
+///
+/// `callback` is invoked exactly once if this returns `true`, and never if it returns `false`
+/// because an argument is invalid. Returns `false` (after invoking `callback` with
+/// `XIAN_WEB_ENGINE_STATUS_FAILED`) if the view was closed, the command queue is full or the engine
+/// is shutting down.
+///
+/// ### 中文
+/// 截取首个匹配 `css_selector`（以 NUL 结尾的 UTF-8）的元素，使宿主可以把单个控件（例如物品提示框）栅格化
+/// 到自己的图集中。
+///
+/// 元素的边界矩形在 Servo 线程上解析并裁剪到视口内，随后在待绘制的帧绘制完成后只读回 back 槽位中的该区域。
+/// 元素位于视口之外的部分不会被截取，绘制在其上方的内容（重叠的元素、默认背景）会一并截取。选择器非法、
+/// 没有匹配的元素，或元素完全位于视口之外时截取失败。
+///
+/// 返回 `true` 时 `callback` 恰好被调用一次；因参数非法而返回 `false` 时永远不会调用。若 view 已关闭、命令
+/// 队列已满或引擎正在关闭，则返回 `false`（此前会以 `XIAN_WEB_ENGINE_STATUS_FAILED` 调用 `callback`）。
+pub unsafe extern "C" fn xian_web_engine_capture_element(
+    view: *mut XianWebEngineView,
+    css_selector: *const c_char,
+    callback: Option<XianWebEngineElementCaptureFn>,
+    user_data: *mut c_void,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_capture_element,
+        view,
+        css_selector,
+        callback,
+        user_data,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(selector) = (unsafe { validate::cstr(call, "css_selector", css_selector) }) else {
+        return false;
+    };
+    let Some(callback) = callback else {
+        validate::report(
+            XIAN_WEB_ENGINE_LOG_LEVEL_ERROR,
+            call,
+            format_args!("callback is NULL"),
+        );
+        return false;
+    };
+
+    let user_data = user_data as usize;
+    let done = Box::new(
+        move |result: Result<servo::RgbaImage, String>| match result {
+            Ok(image) => unsafe {
+                callback(
+                    user_data as *mut c_void,
+                    XIAN_WEB_ENGINE_STATUS_OK,
+                    image.width(),
+                    image.height(),
+                    image.as_raw().as_ptr(),
+                );
+            },
+            Err(err) => {
+                validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+                unsafe {
+                    callback(
+                        user_data as *mut c_void,
+                        XIAN_WEB_ENGINE_STATUS_FAILED,
+                        0,
+                        0,
+                        std::ptr::null(),
+                    );
+                }
+            }
+        },
+    );
+    handle.capture_element(selector, done)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Requests an asynchronous snapshot of the page's accessibility tree.
+///
+/// The result is reported through `xian_web_engine_view_poll_events` as an `ACCESSIBILITY_TREE`
+/// event whose text is a JSON document (roles, accessible names, and bounds in CSS pixels relative to
+/// the viewport). Hosts can use it for controller/keyboard navigation or screen-reader integration.
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 请求异步生成页面无障碍树快照。
+///
+/// 结果会通过 `xian_web_engine_view_poll_events` 以 `ACCESSIBILITY_TREE` 事件上报，其文本为 JSON 文档
+/// （role、无障碍名称，以及相对视口、以 CSS 像素表示的边界）。宿主可据此实现手柄/键盘导航或读屏集成。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_request_accessibility_tree(
+    view: *mut XianWebEngineView,
+) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_request_accessibility_tree, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.request_accessibility_tree().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Pauses every playing `<audio>`/`<video>` element of the page (including same-origin iframes).
+///
+/// Completion is reported as a `MEDIA_STATE` event carrying the returned request ID. Media session
+/// playback changes are reported separately as `MEDIA_PLAYBACK` events.
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 暂停页面中所有正在播放的 `<audio>`/`<video>` 元素（包括同源 iframe）。
+///
+/// 完成后以携带所返回请求 ID 的 `MEDIA_STATE` 事件上报。媒体会话的播放状态变化另以 `MEDIA_PLAYBACK` 事件上报。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_pause_all_media(view: *mut XianWebEngineView) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_pause_all_media, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.pause_all_media().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Resumes the media elements paused by `xian_web_engine_view_pause_all_media`.
+///
+/// Media the page paused on its own stays paused. Completion is reported as a `MEDIA_STATE` event.
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 恢复由 `xian_web_engine_view_pause_all_media` 暂停的媒体元素。
+///
+/// 页面自行暂停的媒体保持暂停。完成后以 `MEDIA_STATE` 事件上报。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_resume_all_media(
+    view: *mut XianWebEngineView,
+) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_resume_all_media, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.resume_all_media().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Requests an opaque session blob (history entries, current URL, scroll offset) for this view.
+///
+/// The blob is reported as the text of a `SESSION_SAVED` event carrying the returned request ID and can
+/// be passed to `xian_web_engine_view_create_from_session` later (e.g. when a world is reloaded).
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 请求该 view 的不透明会话 blob（历史条目、当前 URL、滚动偏移）。
+///
+/// blob 会作为携带所返回请求 ID 的 `SESSION_SAVED` 事件文本上报，之后可传给
+/// `xian_web_engine_view_create_from_session`（例如重新加载世界时）。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_save_session(view: *mut XianWebEngineView) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_save_session, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.save_session().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Selects the contents of the focused text field, or the whole document if no text field has focus.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 选中获得焦点的文本框内容；若没有文本框获得焦点，则选中整个文档。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_select_all(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_select_all, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.select_all()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Requests the page's selected text.
+///
+/// The text is reported as a `SELECTED_TEXT` event carrying the returned request ID (empty if nothing
+/// is selected). Selections inside password fields are never reported.
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 请求读取页面中选中的文本。
+///
+/// 文本会以携带所返回请求 ID 的 `SELECTED_TEXT` 事件上报（未选中任何内容时为空）。密码框中的选区永远不会
+/// 被上报。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_request_selected_text(
+    view: *mut XianWebEngineView,
+) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_request_selected_text, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.request_selected_text().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Copies the page's selection, e.g. for a host-drawn copy button over a read-only view.
+///
+/// The page's `copy` handlers run first; the selected text is then reported as a `COPY` event
+/// carrying the returned request ID. The engine does not touch the system clipboard: the embedder
+/// places the text there itself (e.g. with `glfwSetClipboardString`).
+///
+/// Returns the request ID, or `0` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 复制页面中的选区，例如用于覆盖在只读 view 上、由宿主绘制的复制按钮。
+///
+/// 先执行页面的 `copy` 处理器，随后以携带所返回请求 ID 的 `COPY` 事件上报选中的文本。引擎不会操作系统
+/// 剪贴板：由宿主自行写入（例如通过 `glfwSetClipboardString`）。
+///
+/// 返回请求 ID；若 `view` 为空指针或引擎正在关闭则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_copy_selection(view: *mut XianWebEngineView) -> u64 {
+    let call = ffi_entry!(xian_web_engine_view_copy_selection, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.copy_selection().unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Enables or disables capturing the page's `console.*` output (disabled by default).
+///
+/// While enabled, every console call is reported as a `CONSOLE_MESSAGE` event with its level, source
+/// URL and line, so page errors can be forwarded to the game log without attaching devtools. Calls
+/// made while capture is disabled are not reported retroactively.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 启用或禁用捕获页面的 `console.*` 输出（默认禁用）。
+///
+/// 启用期间，每次 console 调用都会以 `CONSOLE_MESSAGE` 事件上报（含级别、源 URL 与行号），从而无需连接
+/// devtools 即可把页面错误转发到游戏日志。禁用期间的调用不会在之后补报。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_console_capture(
+    view: *mut XianWebEngineView,
+    enabled: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_console_capture, view, enabled);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_console_capture(enabled)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates one view and restores a session blob from a `SESSION_SAVED` event.
+///
+/// `session` must be a NUL-terminated UTF-8 string. The back/forward history is rebuilt by loading
+/// the saved entries in order and going back to the saved current entry, whose scroll offset is
+/// re-applied after it has loaded.
+///
+/// Returns NULL if an argument is invalid, the blob is malformed, or view creation fails.
+///
+/// ### 中文
+/// 创建一个 view，并恢复来自 `SESSION_SAVED` 事件的会话 blob。
+///
+/// `session` 必须是 NUL 结尾的 UTF-8 字符串。会依次加载保存的条目并后退到保存的当前条目来重建前进/后退
+/// 历史，并在当前条目加载完成后重新应用其滚动偏移。
+///
+/// 若参数非法、blob 格式错误或 view 创建失败，则返回 NULL。
+pub unsafe extern "C" fn xian_web_engine_view_create_from_session(
+    engine: *mut XianWebEngine,
+    session: *const c_char,
+    width: u32,
+    height: u32,
+    target_fps: u32,
+    view_flags: u32,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(
+        xian_web_engine_view_create_from_session,
+        engine,
+        session,
+        width,
+        height,
+        target_fps,
+        view_flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return std::ptr::null_mut();
+    };
+    let Some(blob) = (unsafe { validate::cstr(call, "session", session) }) else {
+        return std::ptr::null_mut();
+    };
+
+    let size = PhysicalSize::new(width, height);
+    let handle = runtime.create_view_from_session(blob, size, target_fps, 0, view_flags);
+    let handle = match handle {
+        Ok(handle) => handle,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let view = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("created view {view:p}"),
+    );
+    view
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Creates a view showing the same page as `view`, e.g. to split a page into a second panel.
+///
+/// This is the `xian_web_engine_view_create_from_session` path without the `SESSION_SAVED` round
+/// trip: the source's current URL and scroll offset are taken from its published snapshots, the
+/// page is loaded again (from Servo's HTTP cache where it can be) and the scroll offset re-applied
+/// after loading. Script state, form input and back/forward entries are not copied, and the clone
+/// does not join the source's view group. `view_flags` apply to the clone only.
+///
+/// Returns NULL if an argument is invalid, the source has no document yet, or view creation fails.
+///
+/// ### 中文
+/// 创建一个与 `view` 显示同一页面的 view，例如把页面拆分到第二个面板。
+///
+/// 这是省去 `SESSION_SAVED` 往返的 `xian_web_engine_view_create_from_session` 路径：源 view 当前的 URL
+/// 与滚动偏移取自其已发布的快照，页面会重新加载（尽可能来自 Servo 的 HTTP 缓存），并在加载完成后重新应用
+/// 滚动偏移。脚本状态、表单输入与前进/后退条目不会被复制，克隆的 view 也不会加入源 view 的 view group。
+/// `view_flags` 只作用于克隆的 view。
+///
+/// 若参数非法、源 view 尚无文档或 view 创建失败，则返回 NULL。
+pub unsafe extern "C" fn xian_web_engine_clone_view(
+    engine: *mut XianWebEngine,
+    view: *mut XianWebEngineView,
+    width: u32,
+    height: u32,
+    target_fps: u32,
+    view_flags: u32,
+) -> *mut XianWebEngineView {
+    let call = ffi_entry!(
+        xian_web_engine_clone_view,
+        engine,
+        view,
+        width,
+        height,
+        target_fps,
+        view_flags,
+    );
+    let Some(runtime) = (unsafe { validate::engine(call, engine) }) else {
+        return std::ptr::null_mut();
+    };
+    let Some(source) = (unsafe { validate::view(call, view) }) else {
+        return std::ptr::null_mut();
+    };
+
+    let size = PhysicalSize::new(width, height);
+    let handle = match runtime.clone_view(&source, size, target_fps, view_flags) {
+        Ok(handle) => handle,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_WARN, call, format_args!("{err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let clone = unsafe { super::new_view(engine, handle) };
+    validate::report(
+        XIAN_WEB_ENGINE_LOG_LEVEL_DEBUG,
+        call,
+        format_args!("cloned view {view:p} as {clone:p}"),
+    );
+    clone
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets the default page background as `0xRRGGBBAA` (e.g. `0x00000000` for a transparent HUD).
+///
+/// The color applies from the next navigation and only where the page does not set its own
+/// background.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 设置默认页面背景色，格式为 `0xRRGGBBAA`（例如透明 HUD 使用 `0x00000000`）。
+///
+/// 颜色从下一次导航开始生效，且仅在页面未自行设置背景时生效。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_background(
+    view: *mut XianWebEngineView,
+    rgba: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_background, view, rgba);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_background(rgba)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Renders the view at a different internal resolution (dynamic resolution scaling) while the page
+/// keeps the same CSS viewport size.
+///
+/// `scale < 1.0` trades sharpness for frame rate on heavy pages; `scale > 1.0` supersamples. The value
+/// is clamped to `0.25..=2.0`. Input coordinates and `xian_web_engine_view_resize` stay in logical
+/// pixels; acquired frames report the scaled texture size, so sample them over the view's logical
+/// quad.
+///
+/// Returns `false` if `view` is NULL, `scale` is not a positive finite number, or the engine is
+/// shutting down.
+///
+/// ### 中文
+/// 以不同的内部分辨率渲染该 view（动态分辨率缩放），页面的 CSS 视口尺寸保持不变。
+///
+/// `scale < 1.0` 可在重型页面上以清晰度换取帧率；`scale > 1.0` 为超采样。该值会被 clamp 到 `0.25..=2.0`。
+/// 输入坐标与 `xian_web_engine_view_resize` 仍使用逻辑像素；acquire 到的帧报告缩放后的纹理尺寸，
+/// 请按 view 的逻辑矩形采样。
+///
+/// 若 `view` 为空指针、`scale` 不是正的有限数，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_render_scale(
+    view: *mut XianWebEngineView,
+    scale: f32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_render_scale, view, scale);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_render_scale(scale)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Decouples the page's CSS layout size from the view's texture size, like a mobile viewport meta
+/// tag: the page lays out `css_width` x `css_height` CSS px and is scaled to fill the texture, so a
+/// 4K texture can render a fixed 1280 px wide layout and physical resizes only rescale it instead
+/// of reflowing the page.
+///
+/// A `0` dimension follows the view size on that axis (`css_width = 1280, css_height = 0` keeps the
+/// width fixed and the aspect ratio of the view). When both are set and the aspect ratios differ,
+/// the page gets at least the requested size on both axes. `(0, 0)` removes the override. Input
+/// coordinates stay in view pixels and are mapped onto the scaled page.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 像移动端 viewport meta 标签一样，将页面的 CSS 布局尺寸与 view 的纹理尺寸解耦：页面按 `css_width` x
+/// `css_height` CSS px 布局并缩放以填满纹理，因此 4K 纹理可以渲染固定 1280 px 宽的布局，物理尺寸变化只会
+/// 重新缩放而不会使页面重排。
+///
+/// 为 `0` 的维度跟随 view 在该轴上的尺寸（`css_width = 1280, css_height = 0` 固定宽度并沿用 view 的宽高比）。
+/// 两者都设置且宽高比不同时，页面在两个轴上都至少得到请求的尺寸。`(0, 0)` 移除覆盖。输入坐标仍使用 view
+/// 像素，并映射到缩放后的页面上。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_view_viewport(
+    view: *mut XianWebEngineView,
+    css_width: u32,
+    css_height: u32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_set_view_viewport,
+        view,
+        css_width,
+        css_height
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_viewport(css_width, css_height)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Moves the view to a new size and render configuration without reloading the page, e.g. when the
+/// host switches between a windowed panel and a fullscreen browser.
+///
+/// `new_flags` may change `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X`, `XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`
+/// and the color format; `UNSAFE_NO_CONSUMER_FENCE`, `INPUT_SINGLE_PRODUCER`,
+/// `UNSAFE_NO_PRODUCER_FENCE`, `VULKAN_EXPORT` and `ORPHANING_RESIZE` are fixed at creation and must
+/// match. The render
+/// scale is reset to the one implied by `SUPERSAMPLE_2X`. Slot textures are reallocated in place, so
+/// frames acquired after the move report the new size and color format; a frame still held keeps
+/// the old one until it is released. If the shared context cannot provide the new color format, the
+/// old one is kept and a warning is logged.
+///
+/// Returns `false` if `view` is NULL, a fixed flag differs, the color format is unknown, or the
+/// engine is shutting down.
+///
+/// ### 中文
+/// 在不重新加载页面的情况下，将 view 迁移到新的尺寸与渲染配置，例如宿主在窗口面板与全屏浏览器之间切换时。
+///
+/// `new_flags` 可以改变 `XIAN_WEB_ENGINE_VIEW_FLAG_SUPERSAMPLE_2X`、`XIAN_WEB_ENGINE_VIEW_FLAG_MIPMAPS`
+/// 与颜色格式；`UNSAFE_NO_CONSUMER_FENCE`、`INPUT_SINGLE_PRODUCER`、`UNSAFE_NO_PRODUCER_FENCE`、
+/// `VULKAN_EXPORT` 与 `ORPHANING_RESIZE` 在创建时固定，必须保持一致。渲染缩放会重置为 `SUPERSAMPLE_2X` 所对应的值。槽位纹理原地重新
+/// 分配，因此迁移后 acquire 到的帧报告新的尺寸与颜色格式；仍被持有的帧在释放前保持旧配置。若共享上下文无法
+/// 提供新的颜色格式，则保留旧格式并记录警告日志。
+///
+/// 若 `view` 为空指针、固定标志不一致、颜色格式未知，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_move_view(
+    view: *mut XianWebEngineView,
+    new_width: u32,
+    new_height: u32,
+    new_flags: u32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_move_view,
+        view,
+        new_width,
+        new_height,
+        new_flags
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.move_view(PhysicalSize::new(new_width, new_height), new_flags)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Freezes the view to reclaim GPU memory: its three slot textures are shrunk to 1x1 and the WebView
+/// is hidden and throttled, while the page, its scripts and its state stay alive.
+///
+/// While frozen, `xian_web_engine_views_acquire_frames` acquires nothing for the view. Release held frames before
+/// freezing; a slot still held keeps its texture until the view is thawed. Resizes and moves are
+/// recorded and applied on thaw.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 冻结该 view 以回收显存：其三个槽位纹理缩小为 1x1，WebView 被隐藏并节流，而页面、脚本及其状态保持存活。
+///
+/// 冻结期间 `xian_web_engine_views_acquire_frames` 不会为该 view acquire 任何帧。请在冻结前释放持有的帧；仍被持有的槽位会保留其
+/// 纹理直到解冻。冻结期间的 resize 与迁移会被记录并在解冻时应用。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_freeze_view(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_freeze_view, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.freeze()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Thaws a view frozen by `xian_web_engine_freeze_view`: the slot textures are recreated at the
+/// current size and an active view repaints right away, so the next acquire gets a fresh frame.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 解冻由 `xian_web_engine_freeze_view` 冻结的 view：按当前尺寸重新创建槽位纹理，active 的 view 会立即重绘，
+/// 因此下一次 acquire 即可得到新帧。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_thaw_view(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_thaw_view, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.thaw()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Enters (`enabled = true`) or leaves snapshot mode, for static panels that only change on demand.
+///
+/// In snapshot mode the page stops running (the WebView is hidden and throttled and its timers are
+/// suspended) and nothing is painted, but the last frame stays acquirable indefinitely: it is
+/// pinned so it is never reclaimed, and releasing it makes it READY again, so
+/// `xian_web_engine_views_acquire_frames` keeps returning it every time. Release it with a consumer
+/// fence of `0` to reacquire it immediately; with a fence it becomes acquirable again once the
+/// engine sees the fence signaled. Use `xian_web_engine_view_refresh_snapshot` to render a fresh
+/// frame. Leaving snapshot mode resumes the page and repaints.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 进入（`enabled = true`）或退出快照模式，适用于仅按需变化的静态面板。
+///
+/// 快照模式下页面停止运行（WebView 被隐藏并节流，定时器被挂起）且不再绘制，但最后一帧可被无限期 acquire：它
+/// 被固定而永不被回收，release 后会重新变为 READY，因此 `xian_web_engine_views_acquire_frames` 每次都会返回
+/// 它。以 `0` 作为 consumer fence 释放即可立即再次 acquire；附带 fence 时，它会在引擎确认 fence 已 signal
+/// 后重新变为可 acquire。使用 `xian_web_engine_view_refresh_snapshot` 渲染一帧新内容。退出快照模式会恢复页面
+/// 并重绘。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_snapshot_mode(
+    view: *mut XianWebEngineView,
+    enabled: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_snapshot_mode, view, enabled);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_snapshot_mode(enabled)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Renders a fresh frame of a view in snapshot mode: the page runs for about 100 ms (timers,
+/// animation frames and layout catch up), then its last frame is pinned in place of the previous
+/// one. Ignored if the view is not in snapshot mode.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 为处于快照模式的 view 渲染一帧新内容：页面运行约 100 ms（定时器、动画帧与布局得以追上），随后用其最后一帧
+/// 替换之前的帧并固定。view 不处于快照模式时忽略。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_refresh_snapshot(
+    view: *mut XianWebEngineView,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_refresh_snapshot, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.refresh_snapshot()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Pauses the view's refresh driver for debugging: CSS animations, transitions and
+/// `requestAnimationFrame` callbacks stop advancing until `xian_web_engine_debug_step_frame` lets a
+/// frame through, so a misbehaving animation can be inspected frame by frame (e.g. with captures).
+/// Input, navigation and repaints not driven by the refresh driver keep working.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 为调试而暂停该 view 的 refresh driver：CSS 动画、过渡与 `requestAnimationFrame` 回调会停止推进，直到
+/// `xian_web_engine_debug_step_frame` 放行一帧，从而可以逐帧检查（例如配合截图）行为异常的动画。
+/// 输入、导航以及不由 refresh driver 驱动的重绘仍照常进行。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_debug_pause_view(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_debug_pause_view, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_debug_paused(true)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Resumes a view paused with `xian_web_engine_debug_pause_view`; animations continue from the
+/// frame they were held at.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 恢复通过 `xian_web_engine_debug_pause_view` 暂停的 view；动画从被扣住的帧继续。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_debug_resume_view(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_debug_resume_view, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_debug_paused(false)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Advances a view paused with `xian_web_engine_debug_pause_view` by one frame, on the next tick of
+/// its refresh driver (vsync, fixed interval or group). Ignored if the view is not paused or its
+/// animation clock is paused (`xian_web_engine_set_view_time_paused`).
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 让通过 `xian_web_engine_debug_pause_view` 暂停的 view 在其 refresh driver（vsync、固定间隔或 group）的下一次
+/// tick 前进一帧。view 未暂停或其动画时钟已暂停（`xian_web_engine_set_view_time_paused`）时会被忽略。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_debug_step_frame(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_debug_step_frame, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.debug_step()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Overrides the view's output colorspace, so a host doing its own gamma-correct compositing does
+/// not apply gamma twice:
+///
+/// - `XIAN_WEB_ENGINE_COLORSPACE_AUTO` (default): follow the color format (sRGB-encode only for
+///   `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8`).
+/// - `XIAN_WEB_ENGINE_COLORSPACE_SRGB`: sRGB-encode writes (requires an `SRGB8_A8` view).
+/// - `XIAN_WEB_ENGINE_COLORSPACE_LINEAR`: write values as-is.
+///
+/// Applies from the next frame; `XianWebEngineFrame.colorspace` reports the colorspace of each frame.
+///
+/// Returns `false` if `view` is NULL, `colorspace` is unknown or unsupported by the view's color
+/// format, or the engine is shutting down.
+///
+/// ### 中文
+/// 覆盖该 view 的输出色彩空间，避免自行做 gamma 正确合成的宿主重复施加 gamma：
+///
+/// - `XIAN_WEB_ENGINE_COLORSPACE_AUTO`（默认）：跟随颜色格式（仅 `XIAN_WEB_ENGINE_COLOR_FORMAT_SRGB8_A8`
+///   做 sRGB 编码）。
+/// - `XIAN_WEB_ENGINE_COLORSPACE_SRGB`：写入时做 sRGB 编码（要求 view 为 `SRGB8_A8`）。
+/// - `XIAN_WEB_ENGINE_COLORSPACE_LINEAR`：原样写入。
+///
+/// 从下一帧开始生效；`XianWebEngineFrame.colorspace` 会报告每一帧的色彩空间。
+///
+/// 若 `view` 为空指针、`colorspace` 未知或该 view 的颜色格式不支持，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_colorspace(
+    view: *mut XianWebEngineView,
+    colorspace: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_colorspace, view, colorspace);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let colorspaces = [
+        XIAN_WEB_ENGINE_COLORSPACE_AUTO,
+        XIAN_WEB_ENGINE_COLORSPACE_SRGB,
+        XIAN_WEB_ENGINE_COLORSPACE_LINEAR,
+    ];
+    if !validate::enum_value(call, "colorspace", colorspace, &colorspaces) {
+        return false;
+    }
+
+    handle.set_colorspace(colorspace)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets the `prefers-color-scheme` media feature the view's pages see, so they follow the game's
+/// theme:
+///
+/// - `XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT` (default): `light`.
+/// - `XIAN_WEB_ENGINE_COLOR_SCHEME_DARK`: `dark`.
+///
+/// Applies immediately: CSS `@media` rules and `matchMedia` lists (with their `change` events)
+/// follow, and the setting persists across navigations.
+///
+/// Returns `false` if `view` is NULL, `scheme` is unknown, or the engine is shutting down.
+///
+/// ### 中文
+/// 设置该 view 的页面看到的 `prefers-color-scheme` 媒体特性，使其跟随游戏主题：
+///
+/// - `XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT`（默认）：`light`。
+/// - `XIAN_WEB_ENGINE_COLOR_SCHEME_DARK`：`dark`。
+///
+/// 立即生效：CSS `@media` 规则与 `matchMedia` 列表（及其 `change` 事件）会随之变化，且该设置在导航之间保持。
+///
+/// 若 `view` 为空指针、`scheme` 未知，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_color_scheme(
+    view: *mut XianWebEngineView,
+    scheme: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_color_scheme, view, scheme);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let schemes = [
+        XIAN_WEB_ENGINE_COLOR_SCHEME_LIGHT,
+        XIAN_WEB_ENGINE_COLOR_SCHEME_DARK,
+    ];
+    if !validate::enum_value(call, "scheme", scheme, &schemes) {
+        return false;
+    }
+
+    handle.set_color_scheme(scheme)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets whether the view's pages see `prefers-reduced-motion: reduce` (default: `no-preference`),
+/// mirroring the game's accessibility setting.
+///
+/// Servo does not evaluate this media feature in CSS, so the engine answers it in `matchMedia`
+/// queries (firing `change` on lists whose result flips) and, while on, also disables CSS
+/// animations, transitions and smooth scrolling. Applies to the current page immediately and
+/// persists across navigations.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 设置该 view 的页面是否看到 `prefers-reduced-motion: reduce`（默认：`no-preference`），以对应游戏的
+/// 无障碍设置。
+///
+/// Servo 不会在 CSS 中计算该媒体特性，因此引擎在 `matchMedia` 查询中给出其结果（结果翻转的列表会触发
+/// `change`），并在开启期间同时禁用 CSS 动画、过渡与平滑滚动。立即作用于当前页面，且在导航之间保持。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_reduced_motion(
+    view: *mut XianWebEngineView,
+    reduce: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_reduced_motion, view, reduce);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_reduced_motion(reduce)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets whether the view's pages see `forced-colors: active` (default: `none`), mirroring the
+/// game's high-contrast setting.
+///
+/// Servo has no forced-colors mode, so the engine only answers the media feature in `matchMedia`
+/// queries (firing `change` on lists whose result flips); pages that restyle themselves from script
+/// adapt, CSS `@media (forced-colors)` rules do not. Applies to the current page immediately and
+/// persists across navigations.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 设置该 view 的页面是否看到 `forced-colors: active`（默认：`none`），以对应游戏的高对比度设置。
+///
+/// Servo 没有强制颜色模式，因此引擎只在 `matchMedia` 查询中给出该媒体特性的结果（结果翻转的列表会触发
+/// `change`）；通过脚本自行调整样式的页面会随之适配，CSS `@media (forced-colors)` 规则则不会。立即作用于
+/// 当前页面，且在导航之间保持。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_forced_colors(
+    view: *mut XianWebEngineView,
+    active: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_forced_colors, view, active);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_forced_colors(active)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Pauses or resumes the view's animation clock, so in-world UI panels visually pause while the
+/// game is paused.
+///
+/// While paused, the view's refresh driver holds the frame callback Servo requests, so
+/// `requestAnimationFrame` callbacks, CSS animations and transitions stop advancing, and
+/// `performance.now()` stands still. On resume the held callback runs on the next frame and the
+/// page's timeline is offset by the time spent paused: `performance.now()` and
+/// `requestAnimationFrame` timestamps continue exactly where they stopped, and CSS animations
+/// (held with `animation-play-state: paused`) resume from the same point. CSS transitions follow
+/// Servo's own clock and jump to where they would be had they kept running; `Date` and timers are
+/// not affected. Combines with the debug pause (`xian_web_engine_debug_pause_view`): frames flow
+/// again once neither holds them. Applies immediately and persists across navigations.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 暂停或恢复该 view 的动画时钟，使游戏内的 UI 面板在游戏暂停时在视觉上同步暂停。
+///
+/// 暂停期间，该 view 的 refresh driver 会扣住 Servo 请求的帧回调，因此 `requestAnimationFrame` 回调、CSS
+/// 动画与过渡停止推进，`performance.now()` 也停止前进。恢复后被扣住的回调在下一帧执行，页面时间线按暂停的
+/// 时长偏移：`performance.now()` 与 `requestAnimationFrame` 时间戳从停止处精确继续，CSS 动画（以
+/// `animation-play-state: paused` 扣住）也从同一位置继续。CSS 过渡跟随 Servo 自身的时钟，会跳到若一直运行时
+/// 应处的位置；`Date` 与定时器不受影响。与调试暂停（`xian_web_engine_debug_pause_view`）叠加：两者都不再扣住时
+/// 帧才会恢复。立即生效，且在导航之间保持。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_set_view_time_paused(
+    view: *mut XianWebEngineView,
+    paused: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_view_time_paused, view, paused);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_time_paused(paused)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets how the view behaves while inactive (see `xian_web_engine_view_set_active`):
+///
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL` (default): hidden, timers and animation frames keep
+///   running.
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ`: hidden and throttled, native timers are
+///   cancelled and due callbacks run at most once per second.
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND`: hidden and throttled, native timers are cancelled
+///   and re-armed with their remaining delay once the view becomes active again.
+///
+/// A cleared timer or cancelled animation frame never runs afterwards, and a throttled view holds
+/// at most one pending run per live timer or frame request.
+///
+/// Takes effect immediately if the view is already inactive.
+///
+/// Returns `false` if `view` is NULL, `policy` is unknown, or the engine is shutting down.
+///
+/// ### 中文
+/// 设置 view 在 inactive 时的行为（见 `xian_web_engine_view_set_active`）：
+///
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL`（默认）：隐藏，定时器与动画帧照常运行。
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ`：隐藏并节流，原生定时器被取消，到期回调每秒至多执行
+///   一次。
+/// - `XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND`：隐藏并节流，原生定时器被取消，待 view 重新变为 active 时
+///   按剩余延迟重新设定。
+///
+/// 已清除的定时器或已取消的动画帧之后绝不会执行；节流中的 view 对每个存活的定时器或帧请求至多保留一次待执行。
+///
+/// 若 view 当前已是 inactive，则立即生效。
+///
+/// 若 `view` 为空指针、`policy` 未知，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_background_policy(
+    view: *mut XianWebEngineView,
+    policy: u32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_background_policy, view, policy);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let policies = [
+        XIAN_WEB_ENGINE_BACKGROUND_POLICY_NORMAL,
+        XIAN_WEB_ENGINE_BACKGROUND_POLICY_THROTTLE_1HZ,
+        XIAN_WEB_ENGINE_BACKGROUND_POLICY_SUSPEND,
+    ];
+    if !validate::enum_value(call, "policy", policy, &policies) {
+        return false;
+    }
+
+    handle.set_background_policy(policy)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Sets how the engine reacts when a script blocks the view's page for longer than `timeout_ms`
+/// (a misbehaving page would otherwise stall every view sharing its script event loop):
+///
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE` (default): no detection.
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY`: emit a `XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT` event.
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP`: unload the page (`about:blank`) and emit the event.
+///
+/// Detection pings the page every `timeout_ms`, so a stall is reported between `timeout_ms` and
+/// `2 * timeout_ms` after it starts. Servo gives embedders no way to interrupt a running script, so
+/// a stop only takes effect if the page can still be navigated away from. The event's `value1` is
+/// `1` only once the view answers again, which is waited for up to another `timeout_ms`; a script
+/// that keeps the page from unloading is reported with `value1 = 0`.
+///
+/// Returns `false` if `view` is NULL, `policy` is unknown, `timeout_ms` is `0` for an enabled policy,
+/// or the engine is shutting down.
+///
+/// ### 中文
+/// 设置当脚本阻塞该 view 的页面超过 `timeout_ms` 时引擎的反应（否则异常页面会拖住共享其脚本事件循环的
+/// 所有 view）：
+///
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE`（默认）：不检测。
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY`：发出 `XIAN_WEB_ENGINE_VIEW_EVENT_SLOW_SCRIPT` 事件。
+/// - `XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP`：卸载页面（`about:blank`），然后发出事件。
+///
+/// 检测会每隔 `timeout_ms` ping 一次页面，因此停顿会在发生后 `timeout_ms` 到 `2 * timeout_ms` 之间被上报。
+/// Servo 不向宿主提供中断正在运行的脚本的途径，因此只有页面仍能被导航离开时停止才会生效。只有在 view 重新应答
+/// 后事件的 `value1` 才为 `1`，最多再等待一个 `timeout_ms`；若脚本使页面无法卸载，则以 `value1 = 0` 上报。
+///
+/// 若 `view` 为空指针、`policy` 未知、启用策略时 `timeout_ms` 为 `0`，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_slow_script_policy(
+    view: *mut XianWebEngineView,
+    policy: u32,
+    timeout_ms: u32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_set_slow_script_policy,
+        view,
+        policy,
+        timeout_ms,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let policies = [
+        XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NONE,
+        XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_NOTIFY,
+        XIAN_WEB_ENGINE_SLOW_SCRIPT_POLICY_STOP,
+    ];
+    if !validate::enum_value(call, "policy", policy, &policies) {
+        return false;
+    }
+
+    handle.set_slow_script_policy(policy, timeout_ms)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Injects a user stylesheet (NUL-terminated UTF-8 CSS) into every subsequent load of this view.
+///
+/// Use it to theme pages (dark mode, custom fonts) without modifying their content. Stylesheets
+/// accumulate; each call adds one more.
+///
+/// Returns `false` if `view`/`css` is NULL, the string is not valid UTF-8, or the engine is shutting
+/// down.
+///
+/// ### 中文
+/// 向该 view 后续的每次加载注入一个用户样式表（NUL 结尾的 UTF-8 CSS）。
+///
+/// 可用于在不修改页面内容的情况下设置主题（暗色模式、自定义字体）。样式表会累加；每次调用新增一个。
+///
+/// 若 `view`/`css` 为空指针、字符串不是合法 UTF-8，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_inject_user_stylesheet(
+    view: *mut XianWebEngineView,
+    css: *const c_char,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_inject_user_stylesheet, view, css);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(css) = (unsafe { validate::cstr(call, "css", css) }) else {
+        return false;
+    };
+
+    handle.add_user_stylesheet(css)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Registers a user script (NUL-terminated UTF-8 JavaScript) run automatically on every subsequent
+/// navigation of this view, like a WebExtension content script.
+///
+/// - `injection_time`: `XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START` runs before page scripts;
+///   `XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END` runs once the DOM is parsed (the source is wrapped
+///   in a function, so top-level declarations stay local; assign to `window` to export them).
+/// - `world`: must be `XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN` (Servo has no isolated worlds), so
+///   globals the script defines (e.g. `window.mc`) are visible to the page.
+///
+/// Returns `false` if an argument is invalid/unsupported or the engine is shutting down.
+///
+/// ### 中文
+/// 注册一个用户脚本（NUL 结尾的 UTF-8 JavaScript），在该 view 后续每次导航时自动执行，类似 WebExtension
+/// 的 content script。
+///
+/// - `injection_time`：`XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START` 在页面脚本之前执行；
+///   `XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END` 在 DOM 解析完成后执行（源码会被包装进函数，
+///   顶层声明仅为局部变量；如需导出请赋值到 `window`）。
+/// - `world`：必须为 `XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN`（Servo 不支持隔离 world），
+///   因此脚本定义的全局对象（例如 `window.mc`）对页面可见。
+///
+/// 若参数非法/不受支持，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_add_user_script(
+    view: *mut XianWebEngineView,
+    script: *const c_char,
+    injection_time: u32,
+    world: u32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_add_user_script,
+        view,
+        script,
+        injection_time,
+        world,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(script) = (unsafe { validate::cstr(call, "script", script) }) else {
+        return false;
+    };
+    let injection_times = [
+        XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_START,
+        XIAN_WEB_ENGINE_USER_SCRIPT_AT_DOCUMENT_END,
+    ];
+    if !validate::enum_value(call, "injection_time", injection_time, &injection_times)
+        || !validate::enum_value(
+            call,
+            "world",
+            world,
+            &[XIAN_WEB_ENGINE_USER_SCRIPT_WORLD_MAIN],
+        )
+    {
+        return false;
+    }
+
+    handle.add_user_script(script, injection_time, world)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Answers a permission request (geolocation, notifications, camera, ...) reported by a
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST` event.
+///
+/// Requests not answered within the event's `value1` milliseconds are denied automatically, as are
+/// requests still pending when the view is destroyed.
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down; unknown or expired IDs are
+/// ignored.
+///
+/// ### 中文
+/// 应答由 `XIAN_WEB_ENGINE_VIEW_EVENT_PERMISSION_REQUEST` 事件上报的权限请求（地理位置、通知、摄像头等）。
+///
+/// 未在事件 `value1` 毫秒内应答的请求会被自动拒绝；view 销毁时仍未应答的请求同样会被拒绝。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`；未知或已超时的 ID 会被忽略。
+pub unsafe extern "C" fn xian_web_engine_view_respond_permission(
+    view: *mut XianWebEngineView,
+    request_id: u64,
+    allow: bool,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_respond_permission,
+        view,
+        request_id,
+        allow
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.respond_permission(request_id, allow)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Releases pointer lock held by the page, e.g. when the player presses Escape or the game opens a
+/// menu.
+///
+/// Pointer lock is requested by the page (`element.requestPointerLock()`) during transient user
+/// activation, asked through a `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK` permission request, and
+/// reported as a `XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK` event once granted; while locked, send
+/// mouse motion as `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` deltas. Leaving the lock is reported
+/// with the same event (`value0 = 0`).
+///
+/// Returns `false` if `view` is NULL or the engine is shutting down.
+///
+/// ### 中文
+/// 释放页面持有的 pointer lock，例如玩家按下 Escape 或游戏打开菜单时。
+///
+/// pointer lock 由页面在瞬时用户激活期间发起（`element.requestPointerLock()`），经
+/// `XIAN_WEB_ENGINE_PERMISSION_POINTER_LOCK` 权限请求询问宿主，授予后以
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_POINTER_LOCK` 事件上报；锁定期间请以
+/// `XIAN_WEB_ENGINE_INPUT_KIND_MOUSE_RELATIVE` 增量发送鼠标移动。退出锁定时会上报同一事件（`value0 = 0`）。
+///
+/// 若 `view` 为空指针或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_exit_pointer_lock(
+    view: *mut XianWebEngineView,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_exit_pointer_lock, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.exit_pointer_lock()
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Answers a `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` event with `count` file paths (NUL-terminated
+/// UTF-8). `count = 0` cancels the chooser; single-file inputs use only the first path.
+///
+/// Returns `false` if an argument is invalid or the engine is shutting down; unknown IDs are ignored.
+///
+/// ### 中文
+/// 以 `count` 个文件路径（NUL 结尾的 UTF-8）应答 `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` 事件。
+/// `count = 0` 表示取消选择；单文件输入只使用第一个路径。
+///
+/// 若参数非法或引擎正在关闭，则返回 `false`；未知 ID 会被忽略。
+pub unsafe extern "C" fn xian_web_engine_view_provide_files(
+    view: *mut XianWebEngineView,
+    request_id: u64,
+    paths: *const *const c_char,
+    count: u32,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_provide_files,
+        view,
+        request_id,
+        paths,
+        count
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(paths) = (unsafe { validate::slice(call, "paths", paths, count as usize) }) else {
+        return false;
+    };
+
+    let mut selected = Vec::with_capacity(paths.len());
+    for &path in paths {
+        let Some(path) = (unsafe { validate::cstr(call, "paths[]", path) }) else {
+            return false;
+        };
+        selected.push(PathBuf::from(path));
+    }
+
+    handle.provide_files(request_id, selected)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Answers a `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` event with an in-memory file of `len` bytes named
+/// `name` (NUL-terminated UTF-8), e.g. a screenshot the game just took.
+///
+/// The data is written to a file under the system temp directory before being selected.
+///
+/// Returns `false` if an argument is invalid, the file cannot be written, or the engine is shutting
+/// down.
+///
+/// ### 中文
+/// 以名为 `name`（NUL 结尾的 UTF-8）、长度为 `len` 字节的内存文件应答
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_FILE_PICKER` 事件，例如游戏刚截取的截图。
+///
+/// 数据会先写入系统临时目录下的文件，再被选中。
+///
+/// 若参数非法、文件无法写入或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_provide_file_data(
+    view: *mut XianWebEngineView,
+    request_id: u64,
+    name: *const c_char,
+    bytes: *const u8,
+    len: usize,
+) -> bool {
+    let call = ffi_entry!(
+        xian_web_engine_view_provide_file_data,
+        view,
+        request_id,
+        name,
+        bytes,
+        len,
+    );
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    let Some(name) = (unsafe { validate::cstr(call, "name", name) }) else {
+        return false;
+    };
+    let Some(data) = (unsafe { validate::slice(call, "bytes", bytes, len) }) else {
+        return false;
+    };
+
+    handle.provide_file_data(request_id, name, data)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the page scroll offset (CSS px) into `out_x` / `out_y`.
+///
+/// The value is a snapshot the page reports whenever it scrolls, so this never blocks on the Servo
+/// thread; it may lag a scroll still in flight. It survives hiding the view, so hosts can save it
+/// before `xian_web_engine_view_set_active(view, 0)` and restore it with
+/// `xian_web_engine_view_set_scroll` later.
+///
+/// Returns `false` if `view`, `out_x` or `out_y` is NULL.
+///
+/// ### 中文
+/// 将页面滚动偏移（CSS px）写入 `out_x` / `out_y`。
+///
+/// 该值是页面每次滚动时上报的快照，因此不会阻塞等待 Servo 线程；它可能落后于仍在进行的滚动。隐藏 view
+/// 后该值仍会保留，宿主可在 `xian_web_engine_view_set_active(view, 0)` 之前保存它，之后再通过
+/// `xian_web_engine_view_set_scroll` 恢复。
+///
+/// 若 `view`、`out_x` 或 `out_y` 为空指针，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_get_scroll(
+    view: *mut XianWebEngineView,
+    out_x: *mut f32,
+    out_y: *mut f32,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_get_scroll, view, out_x, out_y);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::pointer(call, "out_x", out_x) || !validate::pointer(call, "out_y", out_y) {
+        return false;
+    }
+
+    let (x, y) = handle.scroll();
+    unsafe {
+        *out_x = x;
+        *out_y = y;
+    }
+    true
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns the `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*` of the view's focused editable element, and
+/// writes its rectangle `[x, y, width, height]` in view pixels into `out_rect` (4 entries; may be
+/// NULL) so the host can place an on-screen keyboard or its IME composition window next to it.
+///
+/// Like the scroll offset, this is a snapshot updated whenever focus moves (announced by
+/// `XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT`), so it never blocks on the Servo thread. Coordinates
+/// are saturated to 16 bits. Returns `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE` (with an all-zero
+/// rectangle) if no editable element has focus or `view` is NULL.
+///
+/// ### 中文
+/// 返回 view 中获得焦点的可编辑元素的 `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_*`，并将其 view 像素下的矩形
+/// `[x, y, width, height]` 写入 `out_rect`（4 个元素；可为空指针），使宿主能在其旁边放置屏幕键盘或 IME
+/// 组字窗口。
+///
+/// 与滚动偏移一样，这是焦点每次移动时更新的快照（通过 `XIAN_WEB_ENGINE_VIEW_EVENT_TEXT_INPUT` 通知），因此
+/// 不会阻塞等待 Servo 线程。坐标会饱和到 16 位。若没有可编辑元素获得焦点或 `view` 为空指针，则返回
+/// `XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE`（矩形全为 0）。
+pub unsafe extern "C" fn xian_web_engine_view_get_text_input(
+    view: *mut XianWebEngineView,
+    out_rect: *mut i32,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_get_text_input, view, out_rect);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE;
+    };
+
+    let (input_type, rect) = handle.text_input();
+    if !out_rect.is_null() {
+        let Some(out_rect) = (unsafe { validate::slice_mut(call, "out_rect", out_rect, 4) }) else {
+            return XIAN_WEB_ENGINE_TEXT_INPUT_TYPE_NONE;
+        };
+        out_rect.copy_from_slice(&rect);
+    }
+    input_type
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Scrolls the page to `(x, y)` in CSS px; `smooth` animates the scroll instead of jumping.
+///
+/// Returns `false` if `view` is NULL, a coordinate is not finite, or the engine is shutting down.
+///
+/// ### 中文
+/// 将页面滚动到 `(x, y)`（CSS px）；`smooth` 为 true 时以动画滚动，而非直接跳转。
+///
+/// 若 `view` 为空指针、坐标非有限值，或引擎正在关闭，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_set_scroll(
+    view: *mut XianWebEngineView,
+    x: f32,
+    y: f32,
+    smooth: bool,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_set_scroll, view, x, y, smooth);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.set_scroll(x, y, smooth)
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns whether the view has network activity (the main document is loading or HTTP(S)
+/// subresource requests are in flight), e.g. to draw a loading spinner.
+///
+/// This reads a counter the engine keeps per view (requests are counted when Servo's networking
+/// delegate lets them through and until they complete or fail), so it is cheap enough to call
+/// every frame and needs no event subscription. Returns `false` if `view` is NULL.
+///
+/// ### 中文
+/// 返回该 view 是否有网络活动（主文档正在加载，或有进行中的 HTTP(S) 子资源请求），例如用于绘制加载动画。
+///
+/// 该函数读取引擎为每个 view 维护的计数器（请求在 Servo 网络 delegate 放行时计入，直到完成或失败），
+/// 开销足够低，可每帧调用，且无需订阅事件。若 `view` 为空指针，
+/// 则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_is_loading(view: *mut XianWebEngineView) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_is_loading, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+
+    handle.in_flight_requests() > 0
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Returns the number of in-flight network requests of the view (HTTP(S) subresource requests,
+/// plus one while the main document is loading).
+///
+/// Returns `0` if `view` is NULL.
+///
+/// ### 中文
+/// 返回该 view 进行中的网络请求数（HTTP(S) 子资源请求；主文档加载期间另计一个）。
+///
+/// 若 `view` 为空指针，则返回 `0`。
+pub unsafe extern "C" fn xian_web_engine_view_get_in_flight_requests(
+    view: *mut XianWebEngineView,
+) -> u32 {
+    let call = ffi_entry!(xian_web_engine_view_get_in_flight_requests, view);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return 0;
+    };
+
+    handle.in_flight_requests()
+}
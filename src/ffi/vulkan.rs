@@ -0,0 +1,102 @@
+//! ### English
+//! C ABI bindings for the Vulkan external-memory backend.
+//!
+//! ### 中文
+//! Vulkan 外部内存后端的 C ABI 绑定。
+
+use crate::engine::log::XIAN_WEB_ENGINE_LOG_LEVEL_ERROR;
+use crate::engine::{
+    EmbedderVulkanDevice, XianWebEngineVulkanSlot, install_embedder_vulkan_device,
+};
+
+use super::XianWebEngineView;
+use super::validate::{self, ffi_entry};
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Installs the embedder's Vulkan device (once per process).
+///
+/// Views created with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` allocate their slot images on this
+/// device. It must be the GPU the GL context runs on, and must have `VK_KHR_external_memory_fd` +
+/// `VK_KHR_external_semaphore_fd` (Linux) or `VK_KHR_external_memory_win32` +
+/// `VK_KHR_external_semaphore_win32` (Windows) enabled. The device must outlive the engine.
+///
+/// Returns `true` on success.
+///
+/// ### 中文
+/// 安装宿主的 Vulkan device（每个进程一次）。
+///
+/// 使用 `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` 创建的 view 会在该 device 上分配槽位 image。
+/// 该 device 必须与 GL 上下文位于同一块 GPU，且必须启用 `VK_KHR_external_memory_fd` +
+/// `VK_KHR_external_semaphore_fd`（Linux）或 `VK_KHR_external_memory_win32` +
+/// `VK_KHR_external_semaphore_win32`（Windows）。device 的生命周期必须长于引擎。
+///
+/// 成功返回 `true`。
+pub unsafe extern "C" fn xian_web_engine_set_vulkan_device(
+    device: *const EmbedderVulkanDevice,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_set_vulkan_device, device);
+    if !validate::non_null(call, "device", device) {
+        return false;
+    }
+
+    let device = unsafe { std::ptr::read_unaligned(device) };
+    match install_embedder_vulkan_device(device) {
+        Ok(()) => true,
+        Err(err) => {
+            validate::report(XIAN_WEB_ENGINE_LOG_LEVEL_ERROR, call, format_args!("{err}"));
+            false
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+/// ### English
+/// Writes the Vulkan import description of `slot` into `out_slot`.
+///
+/// Consumer contract for views created with `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT`:
+///
+/// - Import the memory/semaphores once per `generation` (re-query after acquire when
+///   `XianWebEngineFrame.texture_id` changes). Handles stay owned by the engine; duplicate them
+///   before an import call that takes ownership.
+/// - After acquiring a frame, wait on `ready_semaphore` before sampling the image, which is in
+///   `SHADER_READ_ONLY_OPTIMAL` layout (acquire it from the external queue family).
+/// - When done, leave the image in `SHADER_READ_ONLY_OPTIMAL`, submit a signal of
+///   `release_semaphore`, then release the frame with `XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE` as
+///   its consumer fence. Every acquired frame must be released this way.
+///
+/// Returns `false` if the view has no Vulkan export or the slot has no image.
+///
+/// ### 中文
+/// 将 `slot` 的 Vulkan 导入描述写入 `out_slot`。
+///
+/// 对使用 `XIAN_WEB_ENGINE_VIEW_FLAG_VULKAN_EXPORT` 创建的 view，消费者需遵守：
+///
+/// - 每个 `generation` 导入一次内存/semaphore（acquire 后若 `XianWebEngineFrame.texture_id` 变化则重新
+///   查询）。句柄归引擎所有；传给会接管所有权的导入调用之前请先复制。
+/// - acquire 帧后，采样前先等待 `ready_semaphore`；image 处于 `SHADER_READ_ONLY_OPTIMAL` 布局，
+///   需从 external queue family 获取所有权。
+/// - 用完后保持 image 为 `SHADER_READ_ONLY_OPTIMAL`，提交对 `release_semaphore` 的 signal，然后以
+///   `XIAN_WEB_ENGINE_VULKAN_RELEASE_SEMAPHORE` 作为 consumer fence 释放该帧。每个 acquire 的帧都必须
+///   这样释放。
+///
+/// 若 view 没有 Vulkan 导出或该槽位没有 image，则返回 `false`。
+pub unsafe extern "C" fn xian_web_engine_view_get_vulkan_slot(
+    view: *mut XianWebEngineView,
+    slot: u32,
+    out_slot: *mut XianWebEngineVulkanSlot,
+) -> bool {
+    let call = ffi_entry!(xian_web_engine_view_get_vulkan_slot, view, slot, out_slot);
+    let Some(handle) = (unsafe { validate::view(call, view) }) else {
+        return false;
+    };
+    if !validate::slot(call, slot) || !validate::non_null(call, "out_slot", out_slot) {
+        return false;
+    }
+
+    let Some(info) = handle.vulkan_slot(slot) else {
+        return false;
+    };
+    unsafe { std::ptr::write_unaligned(out_slot, info) };
+    true
+}
@@ -9,3 +9,7 @@
 //! 通过 `ffi` 导出 C ABI；核心实现位于 `engine` 模块。
 mod engine;
 mod ffi;
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub use engine::bench;
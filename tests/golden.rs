@@ -0,0 +1,254 @@
+//! ### English
+//! Golden-image rendering test (`cargo test --features golden-tests --test golden`).
+//!
+//! Creates an engine on a surfaceless OpenGL ES 3 context (Mesa's surfaceless EGL platform when
+//! available, so no display server is needed), renders every page of `tests/golden/pages` and
+//! compares the frames against `tests/golden/expected`. Captured frames and diff images of failing
+//! cases land in `target/tmp/golden`.
+//!
+//! ### 中文
+//! golden 图像渲染测试（`cargo test --features golden-tests --test golden`）。
+//!
+//! 在 surfaceless 的 OpenGL ES 3 上下文上创建引擎（可用时使用 Mesa 的 surfaceless EGL 平台，无需显示服务器），
+//! 渲染 `tests/golden/pages` 中的每个页面，并与 `tests/golden/expected` 比较。截取的帧与失败用例的差异图写入
+//! `target/tmp/golden`。
+
+use std::ffi::{CString, c_char, c_void};
+use std::path::Path;
+use std::ptr;
+
+use xian_web_engine as _;
+
+/// ### English
+/// View size the goldens were produced at.
+///
+/// ### 中文
+/// golden 生成时使用的 view 尺寸。
+const SIZE: u32 = 256;
+
+/// ### English
+/// Largest accepted per-channel difference (rounding and gradient dithering).
+///
+/// ### 中文
+/// 可接受的最大逐通道差值（舍入与渐变抖动）。
+const TOLERANCE: u32 = 2;
+
+/// ### English
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`.
+///
+/// ### 中文
+/// `XIAN_WEB_ENGINE_ENGINE_FLAG_EGL`。
+const ENGINE_FLAG_EGL: u32 = 1 << 0;
+
+/// ### English
+/// `EGL_PLATFORM_SURFACELESS_MESA`.
+///
+/// ### 中文
+/// `EGL_PLATFORM_SURFACELESS_MESA`。
+const EGL_PLATFORM_SURFACELESS_MESA: u32 = 0x31DD;
+
+/// ### English
+/// `EGL_OPENGL_ES_API`.
+///
+/// ### 中文
+/// `EGL_OPENGL_ES_API`。
+const EGL_OPENGL_ES_API: u32 = 0x30A0;
+
+/// ### English
+/// Config attributes: `EGL_RED/GREEN/BLUE/ALPHA_SIZE = 8`,
+/// `EGL_RENDERABLE_TYPE = EGL_OPENGL_ES3_BIT`, `EGL_SURFACE_TYPE = EGL_PBUFFER_BIT`.
+///
+/// ### 中文
+/// config 属性：`EGL_RED/GREEN/BLUE/ALPHA_SIZE = 8`、`EGL_RENDERABLE_TYPE = EGL_OPENGL_ES3_BIT`、
+/// `EGL_SURFACE_TYPE = EGL_PBUFFER_BIT`。
+const CONFIG_ATTRIBS: [i32; 13] = [
+    0x3024, 8, 0x3023, 8, 0x3022, 8, 0x3021, 8, 0x3040, 0x40, 0x3033, 0x1, 0x3038,
+];
+
+/// ### English
+/// Context attributes: `EGL_CONTEXT_CLIENT_VERSION = 3`.
+///
+/// ### 中文
+/// 上下文属性：`EGL_CONTEXT_CLIENT_VERSION = 3`。
+const CONTEXT_ATTRIBS: [i32; 3] = [0x3098, 3, 0x3038];
+
+/// ### English
+/// Mirror of `EmbedderEglApi` (`xian_web_engine_set_egl_api`).
+///
+/// ### 中文
+/// `EmbedderEglApi`（`xian_web_engine_set_egl_api`）的镜像。
+#[repr(C)]
+struct EglApi {
+    /// ### English
+    /// Address of `eglGetProcAddress`.
+    ///
+    /// ### 中文
+    /// `eglGetProcAddress` 的地址。
+    egl_get_proc_address: usize,
+    /// ### English
+    /// `EGLDisplay` owning the host context.
+    ///
+    /// ### 中文
+    /// 持有宿主上下文的 `EGLDisplay`。
+    egl_display: usize,
+}
+
+/// ### English
+/// `eglGetPlatformDisplayEXT`.
+///
+/// ### 中文
+/// `eglGetPlatformDisplayEXT`。
+type GetPlatformDisplayFn =
+    unsafe extern "C" fn(platform: u32, native: *mut c_void, attribs: *const i32) -> *mut c_void;
+
+unsafe extern "C" {
+    fn xian_web_engine_set_egl_api(api: *const EglApi) -> bool;
+    fn xian_web_engine_create_with_flags(
+        shared_context: *mut c_void,
+        default_width: u32,
+        default_height: u32,
+        resources_dir: *const c_char,
+        config_dir: *const c_char,
+        thread_pool_cap: u32,
+        thread_pools: *const c_void,
+        engine_flags: u32,
+    ) -> *mut c_void;
+    fn xian_web_engine_run_golden_tests(
+        engine: *mut c_void,
+        pages_dir: *const c_char,
+        golden_dir: *const c_char,
+        output_dir: *const c_char,
+        width: u32,
+        height: u32,
+        tolerance: u32,
+        flags: u32,
+    ) -> i32;
+    fn xian_web_engine_destroy(engine: *mut c_void);
+}
+
+#[link(name = "EGL")]
+unsafe extern "C" {
+    fn eglGetProcAddress(name: *const c_char) -> *mut c_void;
+    fn eglGetDisplay(native: *mut c_void) -> *mut c_void;
+    fn eglInitialize(display: *mut c_void, major: *mut i32, minor: *mut i32) -> u32;
+    fn eglBindAPI(api: u32) -> u32;
+    fn eglChooseConfig(
+        display: *mut c_void,
+        attribs: *const i32,
+        configs: *mut *mut c_void,
+        size: i32,
+        count: *mut i32,
+    ) -> u32;
+    fn eglCreateContext(
+        display: *mut c_void,
+        config: *mut c_void,
+        share: *mut c_void,
+        attribs: *const i32,
+    ) -> *mut c_void;
+    fn eglDestroyContext(display: *mut c_void, context: *mut c_void) -> u32;
+}
+
+/// ### English
+/// Opens the surfaceless EGL display, falling back to the default display.
+///
+/// ### 中文
+/// 打开 surfaceless EGL display，失败时回退到默认 display。
+fn open_display() -> *mut c_void {
+    let proc_address = unsafe { eglGetProcAddress(c"eglGetPlatformDisplayEXT".as_ptr()) };
+    if !proc_address.is_null() {
+        let get_platform_display: GetPlatformDisplayFn =
+            unsafe { std::mem::transmute(proc_address) };
+        let display = unsafe {
+            get_platform_display(EGL_PLATFORM_SURFACELESS_MESA, ptr::null_mut(), ptr::null())
+        };
+        if !display.is_null() {
+            return display;
+        }
+    }
+    unsafe { eglGetDisplay(ptr::null_mut()) }
+}
+
+/// ### English
+/// Returns a NUL-terminated path inside the repository.
+///
+/// #### Parameters
+/// - `path`: Path relative to the repository root.
+///
+/// ### 中文
+/// 返回仓库内某路径的 NUL 结尾字符串。
+///
+/// #### 参数
+/// - `path`：相对仓库根目录的路径。
+fn repo_path(path: &str) -> CString {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    CString::new(path.to_string_lossy().into_owned()).expect("path contains NUL")
+}
+
+#[test]
+fn golden_pages_match() {
+    let display = open_display();
+    assert!(!display.is_null(), "no EGL display");
+    let (mut major, mut minor) = (0, 0);
+    assert_ne!(
+        unsafe { eglInitialize(display, &mut major, &mut minor) },
+        0,
+        "eglInitialize failed"
+    );
+    assert_ne!(
+        unsafe { eglBindAPI(EGL_OPENGL_ES_API) },
+        0,
+        "eglBindAPI failed"
+    );
+    let mut config = ptr::null_mut();
+    let mut count = 0;
+    let chosen =
+        unsafe { eglChooseConfig(display, CONFIG_ATTRIBS.as_ptr(), &mut config, 1, &mut count) };
+    assert!(chosen != 0 && count > 0, "no RGBA8 OpenGL ES 3 EGL config");
+    let context =
+        unsafe { eglCreateContext(display, config, ptr::null_mut(), CONTEXT_ATTRIBS.as_ptr()) };
+    assert!(!context.is_null(), "eglCreateContext failed");
+
+    let api = EglApi {
+        egl_get_proc_address: eglGetProcAddress as *const () as usize,
+        egl_display: display as usize,
+    };
+    assert!(unsafe { xian_web_engine_set_egl_api(&api) });
+    let engine = unsafe {
+        xian_web_engine_create_with_flags(
+            context,
+            SIZE,
+            SIZE,
+            ptr::null(),
+            ptr::null(),
+            0,
+            ptr::null(),
+            ENGINE_FLAG_EGL,
+        )
+    };
+    assert!(!engine.is_null(), "engine creation failed");
+
+    let output_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    let output_dir = CString::new(output_dir.to_string_lossy().into_owned()).expect("NUL in path");
+    let failed = unsafe {
+        xian_web_engine_run_golden_tests(
+            engine,
+            repo_path("tests/golden/pages").as_ptr(),
+            repo_path("tests/golden/expected").as_ptr(),
+            output_dir.as_ptr(),
+            SIZE,
+            SIZE,
+            TOLERANCE,
+            0,
+        )
+    };
+    unsafe {
+        xian_web_engine_destroy(engine);
+        eglDestroyContext(display, context);
+    }
+    assert_eq!(
+        failed,
+        0,
+        "golden cases failed; see the diffs in {}",
+        output_dir.to_string_lossy()
+    );
+}
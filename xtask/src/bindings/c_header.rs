@@ -0,0 +1,253 @@
+//! ### English
+//! C header renderer.
+//!
+//! ### 中文
+//! C 头文件渲染器。
+
+use std::fmt::Write;
+
+use super::model::{FfiFunction, FfiSurface, FfiType, Primitive};
+
+/// ### English
+/// Renders `xian_web_engine.h`.
+///
+/// Every struct is forward-declared first so callbacks, struct fields and prototypes can reference
+/// each other regardless of source order.
+///
+/// #### Parameters
+/// - `surface`: Collected FFI surface.
+///
+/// ### 中文
+/// 渲染 `xian_web_engine.h`。
+///
+/// 所有结构体都会先前置声明，使回调、结构体字段与函数原型可以互相引用，而不受源码顺序影响。
+///
+/// #### 参数
+/// - `surface`：收集到的 FFI 接口。
+pub fn render(surface: &FfiSurface) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "/*\n * Generated by `cargo xtask bindings` from the Rust FFI surface. Do not edit.\n */\n\n",
+    );
+    out.push_str("#ifndef XIAN_WEB_ENGINE_H\n#define XIAN_WEB_ENGINE_H\n\n");
+    out.push_str("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n\n");
+    out.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+
+    if !surface.consts.is_empty() {
+        out.push('\n');
+    }
+    for constant in &surface.consts {
+        doc_comment(&mut out, &constant.doc, "");
+        let _ = writeln!(
+            out,
+            "#define {} {}",
+            constant.name,
+            literal(constant.ty, constant.value)
+        );
+    }
+
+    if !surface.structs.is_empty() {
+        out.push('\n');
+    }
+    for item in &surface.structs {
+        let _ = writeln!(out, "typedef struct {0} {0};", item.name);
+    }
+
+    for callback in &surface.callbacks {
+        out.push('\n');
+        doc_comment(&mut out, &callback.doc, "");
+        let _ = writeln!(
+            out,
+            "typedef {};",
+            declare(
+                &callback.ret,
+                &format!("(*{})({})", callback.name, params(callback))
+            )
+        );
+    }
+
+    for item in &surface.structs {
+        let Some(fields) = &item.fields else {
+            continue;
+        };
+        out.push('\n');
+        doc_comment(&mut out, &item.doc, "");
+        let _ = writeln!(out, "struct {} {{", item.name);
+        for field in fields {
+            doc_comment(&mut out, &field.doc, "    ");
+            let _ = writeln!(out, "    {};", declare(&field.ty, &field.name));
+        }
+        out.push_str("};\n");
+    }
+
+    for function in &surface.functions {
+        out.push('\n');
+        doc_comment(&mut out, &function.doc, "");
+        let _ = writeln!(
+            out,
+            "{};",
+            declare(
+                &function.ret,
+                &format!("{}({})", function.name, params(function))
+            )
+        );
+    }
+
+    out.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+    out
+}
+
+/// ### English
+/// Writes a `/** ... */` comment.
+///
+/// #### Parameters
+/// - `out`: Output buffer.
+/// - `doc`: Comment lines (nothing is written when empty).
+/// - `indent`: Indentation prefix.
+///
+/// ### 中文
+/// 写入 `/** ... */` 注释。
+///
+/// #### 参数
+/// - `out`：输出缓冲区。
+/// - `doc`：注释行（为空时不写入）。
+/// - `indent`：缩进前缀。
+fn doc_comment(out: &mut String, doc: &[String], indent: &str) {
+    if doc.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "{indent}/**");
+    for line in doc {
+        let line = line.replace("*/", "* /");
+        if line.is_empty() {
+            let _ = writeln!(out, "{indent} *");
+        } else {
+            let _ = writeln!(out, "{indent} * {line}");
+        }
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+/// ### English
+/// Formats an integer constant with a suffix matching its Rust type.
+///
+/// #### Parameters
+/// - `ty`: Rust type of the constant.
+/// - `value`: Constant value.
+///
+/// ### 中文
+/// 以与 Rust 类型匹配的后缀格式化整数常量。
+///
+/// #### 参数
+/// - `ty`：常量的 Rust 类型。
+/// - `value`：常量值。
+fn literal(ty: Primitive, value: i128) -> String {
+    let suffix = match ty {
+        Primitive::U8 | Primitive::U16 | Primitive::U32 => "u",
+        Primitive::U64 | Primitive::Usize => "ull",
+        Primitive::I64 | Primitive::Isize => "ll",
+        _ => "",
+    };
+    if value < 0 {
+        format!("({value}{suffix})")
+    } else {
+        format!("{value}{suffix}")
+    }
+}
+
+/// ### English
+/// Returns the C spelling of a non-array type.
+///
+/// #### Parameters
+/// - `ty`: FFI type.
+///
+/// ### 中文
+/// 返回非数组类型的 C 写法。
+///
+/// #### 参数
+/// - `ty`：FFI 类型。
+fn c_type(ty: &FfiType) -> String {
+    match ty {
+        FfiType::Void => "void".to_string(),
+        FfiType::Primitive(primitive) => match primitive {
+            Primitive::Bool => "bool",
+            Primitive::Char => "char",
+            Primitive::U8 => "uint8_t",
+            Primitive::U16 => "uint16_t",
+            Primitive::U32 => "uint32_t",
+            Primitive::U64 => "uint64_t",
+            Primitive::I8 => "int8_t",
+            Primitive::I16 => "int16_t",
+            Primitive::I32 => "int32_t",
+            Primitive::I64 => "int64_t",
+            Primitive::Usize => "uintptr_t",
+            Primitive::Isize => "intptr_t",
+            Primitive::F32 => "float",
+            Primitive::F64 => "double",
+        }
+        .to_string(),
+        FfiType::Pointer { mutable, pointee } => {
+            let inner = c_type(pointee);
+            match (matches!(**pointee, FfiType::Pointer { .. }), mutable) {
+                (false, false) => format!("const {inner} *"),
+                (false, true) => format!("{inner} *"),
+                (true, false) => format!("{inner}const *"),
+                (true, true) => format!("{inner}*"),
+            }
+        }
+        FfiType::Struct(name) | FfiType::Callback(name) => name.clone(),
+        FfiType::Array(elem, _) => c_type(elem),
+    }
+}
+
+/// ### English
+/// Returns a C declaration of `declarator` with type `ty`.
+///
+/// #### Parameters
+/// - `ty`: FFI type.
+/// - `declarator`: Name, function declarator or function-pointer declarator.
+///
+/// ### 中文
+/// 返回类型为 `ty` 的 `declarator` 的 C 声明。
+///
+/// #### 参数
+/// - `ty`：FFI 类型。
+/// - `declarator`：名称、函数声明符或函数指针声明符。
+fn declare(ty: &FfiType, declarator: &str) -> String {
+    if let FfiType::Array(elem, len) = ty {
+        return declare(elem, &format!("{declarator}[{len}]"));
+    }
+    let ty = c_type(ty);
+    if ty.ends_with('*') {
+        format!("{ty}{declarator}")
+    } else {
+        format!("{ty} {declarator}")
+    }
+}
+
+/// ### English
+/// Returns the C parameter list of a signature (`void` when empty).
+///
+/// #### Parameters
+/// - `function`: Function or callback signature.
+///
+/// ### 中文
+/// 返回签名的 C 参数列表（为空时为 `void`）。
+///
+/// #### 参数
+/// - `function`：函数或回调签名。
+fn params(function: &FfiFunction) -> String {
+    if function.params.is_empty() {
+        return "void".to_string();
+    }
+    function
+        .params
+        .iter()
+        .map(|(name, ty)| {
+            declare(ty, name.trim_start_matches('_'))
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,132 @@
+//! ### English
+//! `bindings` task: C header and Java Panama layouts generated from the FFI surface.
+//!
+//! The Rust sources are the single source of truth: every `#[unsafe(no_mangle)] extern "C"` function,
+//! every `#[repr(C)]` struct reachable from them, every `extern "C"` callback alias, and every
+//! `XIAN_WEB_ENGINE_*` constant is read from `src/` and emitted to both outputs, so the FFI layer and
+//! the Java side cannot drift.
+//!
+//! ### 中文
+//! `bindings` 任务：根据 FFI 接口生成 C 头文件与 Java Panama 布局。
+//!
+//! Rust 源码是唯一的事实来源：所有 `#[unsafe(no_mangle)] extern "C"` 函数、由其可达的 `#[repr(C)]`
+//! 结构体、`extern "C"` 回调别名以及 `XIAN_WEB_ENGINE_*` 常量都从 `src/` 读取，并输出到两个文件中，
+//! 从而保证 FFI 层与 Java 侧不会出现偏差。
+
+mod c_header;
+mod model;
+mod panama;
+
+use std::path::{Path, PathBuf};
+
+/// ### English
+/// File name of the generated C header.
+///
+/// ### 中文
+/// 生成的 C 头文件名。
+const HEADER_FILE: &str = "xian_web_engine.h";
+
+/// ### English
+/// File name of the generated Java layout class.
+///
+/// ### 中文
+/// 生成的 Java 布局类文件名。
+const JAVA_FILE: &str = "XianWebEngineLayouts.java";
+
+/// ### English
+/// Options of the `bindings` task.
+///
+/// ### 中文
+/// `bindings` 任务的选项。
+pub struct Options {
+    /// ### English
+    /// Crate source directory to scan.
+    ///
+    /// ### 中文
+    /// 要扫描的 crate 源码目录。
+    pub src_dir: PathBuf,
+    /// ### English
+    /// Directory receiving the generated files.
+    ///
+    /// ### 中文
+    /// 接收生成文件的目录。
+    pub out_dir: PathBuf,
+    /// ### English
+    /// Java package of the layout class (`None` = default package).
+    ///
+    /// ### 中文
+    /// 布局类所在的 Java 包（`None` 表示默认包）。
+    pub java_package: Option<String>,
+    /// ### English
+    /// Compare against the files on disk instead of writing them.
+    ///
+    /// ### 中文
+    /// 与磁盘上的文件比较，而不是写入。
+    pub check: bool,
+}
+
+/// ### English
+/// Runs the `bindings` task.
+///
+/// #### Parameters
+/// - `options`: Task options.
+///
+/// ### 中文
+/// 执行 `bindings` 任务。
+///
+/// #### 参数
+/// - `options`：任务选项。
+pub fn run(options: &Options) -> Result<(), String> {
+    let surface = model::FfiSurface::collect(&options.src_dir)?;
+    let outputs = [
+        (HEADER_FILE, c_header::render(&surface)),
+        (
+            JAVA_FILE,
+            panama::render(&surface, options.java_package.as_deref()),
+        ),
+    ];
+
+    if options.check {
+        let stale: Vec<&str> = outputs
+            .iter()
+            .filter(|(name, contents)| {
+                std::fs::read_to_string(options.out_dir.join(name))
+                    .ok()
+                    .as_deref()
+                    != Some(contents.as_str())
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        if !stale.is_empty() {
+            return Err(format!(
+                "out of date: {} (run `cargo xtask bindings`)",
+                stale.join(", ")
+            ));
+        }
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&options.out_dir)
+        .map_err(|err| format!("{}: {err}", options.out_dir.display()))?;
+    for (name, contents) in &outputs {
+        write_file(&options.out_dir.join(name), contents)?;
+    }
+    Ok(())
+}
+
+/// ### English
+/// Writes one generated file.
+///
+/// #### Parameters
+/// - `path`: Destination path.
+/// - `contents`: File contents.
+///
+/// ### 中文
+/// 写入一个生成文件。
+///
+/// #### 参数
+/// - `path`：目标路径。
+/// - `contents`：文件内容。
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
@@ -0,0 +1,914 @@
+//! ### English
+//! FFI surface model, collected from the crate sources with `syn`.
+//!
+//! ### 中文
+//! FFI 接口模型，使用 `syn` 从 crate 源码中收集。
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use syn::{Attribute, BinOp, Expr, Fields, FnArg, Item, Lit, Pat, ReturnType, Type, UnOp};
+
+/// ### English
+/// Prefix shared by every exported constant.
+///
+/// ### 中文
+/// 所有导出常量共享的前缀。
+const CONST_PREFIX: &str = "XIAN_WEB_ENGINE_";
+
+/// ### English
+/// Primitive C types used by the FFI surface (64-bit targets).
+///
+/// ### 中文
+/// FFI 接口使用的基本 C 类型（64 位目标）。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primitive {
+    Bool,
+    Char,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    Usize,
+    Isize,
+    F32,
+    F64,
+}
+
+impl Primitive {
+    /// ### English
+    /// Maps a Rust primitive type name.
+    ///
+    /// #### Parameters
+    /// - `name`: Rust type name (`u32`, `c_char`, ...).
+    ///
+    /// ### 中文
+    /// 映射 Rust 基本类型名。
+    ///
+    /// #### 参数
+    /// - `name`：Rust 类型名（`u32`、`c_char` 等）。
+    fn from_rust(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => Self::Bool,
+            "c_char" => Self::Char,
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "usize" => Self::Usize,
+            "isize" => Self::Isize,
+            "f32" => Self::F32,
+            "f64" => Self::F64,
+            _ => return None,
+        })
+    }
+
+    /// ### English
+    /// Returns the size (and alignment) in bytes.
+    ///
+    /// ### 中文
+    /// 返回字节大小（同时也是对齐）。
+    pub fn size(self) -> usize {
+        match self {
+            Self::Bool | Self::Char | Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::Usize | Self::Isize | Self::F64 => 8,
+        }
+    }
+
+    /// ### English
+    /// Returns whether this is an integer type usable for constants.
+    ///
+    /// ### 中文
+    /// 返回是否为可用于常量的整数类型。
+    fn is_integer(self) -> bool {
+        !matches!(self, Self::Bool | Self::F32 | Self::F64)
+    }
+}
+
+/// ### English
+/// Type of a field, parameter or return value.
+///
+/// ### 中文
+/// 字段、参数或返回值的类型。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FfiType {
+    Void,
+    Primitive(Primitive),
+    Pointer {
+        mutable: bool,
+        pointee: Box<FfiType>,
+    },
+    Struct(String),
+    Callback(String),
+    Array(Box<FfiType>, usize),
+}
+
+/// ### English
+/// Exported integer constant.
+///
+/// ### 中文
+/// 导出的整数常量。
+pub struct FfiConst {
+    pub name: String,
+    pub ty: Primitive,
+    pub value: i128,
+    pub doc: Vec<String>,
+}
+
+/// ### English
+/// Field of a `#[repr(C)]` struct.
+///
+/// ### 中文
+/// `#[repr(C)]` 结构体的字段。
+pub struct FfiField {
+    pub name: String,
+    pub ty: FfiType,
+    pub doc: Vec<String>,
+}
+
+/// ### English
+/// `#[repr(C)]` struct; `fields` is `None` for opaque handles.
+///
+/// ### 中文
+/// `#[repr(C)]` 结构体；不透明句柄的 `fields` 为 `None`。
+pub struct FfiStruct {
+    pub name: String,
+    pub fields: Option<Vec<FfiField>>,
+    pub doc: Vec<String>,
+}
+
+/// ### English
+/// Exported function or callback signature.
+///
+/// ### 中文
+/// 导出函数或回调的签名。
+pub struct FfiFunction {
+    pub name: String,
+    pub params: Vec<(String, FfiType)>,
+    pub ret: FfiType,
+    pub doc: Vec<String>,
+}
+
+/// ### English
+/// Everything the bindings expose, in emission order.
+///
+/// ### 中文
+/// 绑定所暴露的全部内容（按输出顺序排列）。
+pub struct FfiSurface {
+    pub consts: Vec<FfiConst>,
+    /// ### English
+    /// Structs reachable from exported functions, dependencies first.
+    ///
+    /// ### 中文
+    /// 由导出函数可达的结构体，依赖项在前。
+    pub structs: Vec<FfiStruct>,
+    pub callbacks: Vec<FfiFunction>,
+    pub functions: Vec<FfiFunction>,
+}
+
+/// ### English
+/// Raw items gathered from the sources before type resolution.
+///
+/// ### 中文
+/// 类型解析之前从源码中收集的原始条目。
+#[derive(Default)]
+struct RawItems {
+    consts: Vec<(String, Primitive, Expr, Vec<String>)>,
+    structs: Vec<syn::ItemStruct>,
+    callbacks: Vec<syn::ItemType>,
+    functions: Vec<syn::ItemFn>,
+}
+
+impl FfiSurface {
+    /// ### English
+    /// Scans every `.rs` file under `src_dir` and builds the FFI surface.
+    ///
+    /// #### Parameters
+    /// - `src_dir`: Crate source directory.
+    ///
+    /// ### 中文
+    /// 扫描 `src_dir` 下的所有 `.rs` 文件并构建 FFI 接口。
+    ///
+    /// #### 参数
+    /// - `src_dir`：crate 源码目录。
+    pub fn collect(src_dir: &Path) -> Result<Self, String> {
+        let mut files = Vec::new();
+        rust_files(src_dir, &mut files)?;
+
+        let mut raw = RawItems::default();
+        for path in &files {
+            let source = std::fs::read_to_string(path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            let file =
+                syn::parse_file(&source).map_err(|err| format!("{}: {err}", path.display()))?;
+            raw.gather(&file.items);
+        }
+
+        let callback_names: HashSet<String> = raw
+            .callbacks
+            .iter()
+            .map(|item| item.ident.to_string())
+            .collect();
+
+        let callbacks = raw
+            .callbacks
+            .iter()
+            .map(|item| callback_signature(item, &callback_names))
+            .collect::<Result<Vec<_>, _>>()?;
+        let functions = raw
+            .functions
+            .iter()
+            .map(|item| {
+                signature(
+                    item.sig.ident.to_string(),
+                    item.sig.inputs.iter().filter_map(|arg| match arg {
+                        FnArg::Typed(arg) => Some((pat_name(&arg.pat), &*arg.ty)),
+                        FnArg::Receiver(_) => None,
+                    }),
+                    &item.sig.output,
+                    doc_lines(&item.attrs),
+                    &callback_names,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let structs = resolve_structs(&raw.structs, &callbacks, &functions, &callback_names)?;
+        let consts = evaluate_consts(raw.consts)?;
+
+        Ok(Self {
+            consts,
+            structs,
+            callbacks,
+            functions,
+        })
+    }
+}
+
+impl RawItems {
+    /// ### English
+    /// Gathers FFI-relevant items (recursing into inline modules).
+    ///
+    /// #### Parameters
+    /// - `items`: Items of one file or inline module.
+    ///
+    /// ### 中文
+    /// 收集与 FFI 相关的条目（递归进入内联模块）。
+    ///
+    /// #### 参数
+    /// - `items`：单个文件或内联模块中的条目。
+    fn gather(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Const(item) => {
+                    let name = item.ident.to_string();
+                    if !name.starts_with(CONST_PREFIX) {
+                        continue;
+                    }
+                    let Some(ty) = type_name(&item.ty)
+                        .as_deref()
+                        .and_then(Primitive::from_rust)
+                    else {
+                        continue;
+                    };
+                    if ty.is_integer() {
+                        self.consts
+                            .push((name, ty, (*item.expr).clone(), doc_lines(&item.attrs)));
+                    }
+                }
+                Item::Struct(item) if is_repr_c(&item.attrs) => self.structs.push(item.clone()),
+                Item::Type(item)
+                    if matches!(item.vis, syn::Visibility::Public(_))
+                        && is_extern_c_fn_type(&item.ty) =>
+                {
+                    self.callbacks.push(item.clone())
+                }
+                Item::Fn(item) if is_exported_fn(item) => self.functions.push(item.clone()),
+                Item::Mod(item) => {
+                    if let Some((_, items)) = &item.content {
+                        self.gather(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// ### English
+/// Collects `.rs` files under `dir`, sorted for deterministic output.
+///
+/// #### Parameters
+/// - `dir`: Directory to scan.
+/// - `out`: Receives the file paths.
+///
+/// ### 中文
+/// 收集 `dir` 下的 `.rs` 文件，并排序以保证输出确定。
+///
+/// #### 参数
+/// - `dir`：要扫描的目录。
+/// - `out`：接收文件路径。
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|err| format!("{}: {err}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            rust_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// ### English
+/// Returns the English section of an item's doc comment.
+///
+/// #### Parameters
+/// - `attrs`: Item attributes.
+///
+/// ### 中文
+/// 返回条目文档注释中的英文部分。
+///
+/// #### 参数
+/// - `attrs`：条目属性。
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(text),
+                    ..
+                }) => Some(text.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line == "### English")
+        .map_or(0, |index| index + 1);
+    let end = lines
+        .iter()
+        .position(|line| line == "### 中文")
+        .filter(|&end| end >= start)
+        .unwrap_or(lines.len());
+
+    let mut section = lines[start..end].to_vec();
+    while section.first().is_some_and(String::is_empty) {
+        section.remove(0);
+    }
+    while section.last().is_some_and(String::is_empty) {
+        section.pop();
+    }
+    section
+}
+
+/// ### English
+/// Returns whether the attributes contain `#[repr(C)]`.
+///
+/// #### Parameters
+/// - `attrs`: Item attributes.
+///
+/// ### 中文
+/// 返回属性中是否包含 `#[repr(C)]`。
+///
+/// #### 参数
+/// - `attrs`：条目属性。
+fn is_repr_c(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let mut repr_c = false;
+        if attr.path().is_ident("repr") {
+            let _ = attr.parse_nested_meta(|meta| {
+                repr_c |= meta.path.is_ident("C");
+                Ok(())
+            });
+        }
+        repr_c
+    })
+}
+
+/// ### English
+/// Returns whether a function is an exported `extern "C"` symbol.
+///
+/// #### Parameters
+/// - `item`: Function item.
+///
+/// ### 中文
+/// 返回函数是否为导出的 `extern "C"` 符号。
+///
+/// #### 参数
+/// - `item`：函数条目。
+fn is_exported_fn(item: &syn::ItemFn) -> bool {
+    let extern_c = item
+        .sig
+        .abi
+        .as_ref()
+        .and_then(|abi| abi.name.as_ref())
+        .is_some_and(|name| name.value() == "C");
+    let no_mangle = item.attrs.iter().any(|attr| {
+        attr.path().is_ident("no_mangle")
+            || (attr.path().is_ident("unsafe")
+                && attr
+                    .parse_args::<syn::Ident>()
+                    .is_ok_and(|ident| ident == "no_mangle"))
+    });
+    extern_c && no_mangle
+}
+
+/// ### English
+/// Returns whether a type is an `extern "C"` function pointer.
+///
+/// #### Parameters
+/// - `ty`: Type to inspect.
+///
+/// ### 中文
+/// 返回类型是否为 `extern "C"` 函数指针。
+///
+/// #### 参数
+/// - `ty`：要检查的类型。
+fn is_extern_c_fn_type(ty: &Type) -> bool {
+    match ty {
+        Type::BareFn(bare) => bare
+            .abi
+            .as_ref()
+            .and_then(|abi| abi.name.as_ref())
+            .is_some_and(|name| name.value() == "C"),
+        _ => false,
+    }
+}
+
+/// ### English
+/// Returns the last path segment name of a plain path type.
+///
+/// #### Parameters
+/// - `ty`: Type to inspect.
+///
+/// ### 中文
+/// 返回普通路径类型最后一段的名称。
+///
+/// #### 参数
+/// - `ty`：要检查的类型。
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// ### English
+/// Returns the binding name of a parameter pattern (`_` for anything but identifiers).
+///
+/// #### Parameters
+/// - `pat`: Parameter pattern.
+///
+/// ### 中文
+/// 返回参数模式绑定的名称（非标识符模式返回 `_`）。
+///
+/// #### 参数
+/// - `pat`：参数模式。
+fn pat_name(pat: &Pat) -> String {
+    match pat {
+        Pat::Ident(ident) => ident.ident.to_string(),
+        _ => "_".to_string(),
+    }
+}
+
+/// ### English
+/// Maps a Rust type to an FFI type.
+///
+/// #### Parameters
+/// - `ty`: Rust type.
+/// - `callbacks`: Names of known callback aliases.
+///
+/// ### 中文
+/// 将 Rust 类型映射为 FFI 类型。
+///
+/// #### 参数
+/// - `ty`：Rust 类型。
+/// - `callbacks`：已知回调别名的名称集合。
+fn ffi_type(ty: &Type, callbacks: &HashSet<String>) -> Option<FfiType> {
+    match ty {
+        Type::Ptr(ptr) => Some(FfiType::Pointer {
+            mutable: ptr.mutability.is_some(),
+            pointee: Box::new(ffi_type(&ptr.elem, callbacks)?),
+        }),
+        Type::Array(array) => {
+            let Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(len), ..
+            }) = &array.len
+            else {
+                return None;
+            };
+            Some(FfiType::Array(
+                Box::new(ffi_type(&array.elem, callbacks)?),
+                len.base10_parse().ok()?,
+            ))
+        }
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Some(FfiType::Void),
+        Type::Paren(paren) => ffi_type(&paren.elem, callbacks),
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            let name = segment.ident.to_string();
+            if name == "Option" {
+                let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    return None;
+                };
+                let Some(syn::GenericArgument::Type(inner)) = args.args.first() else {
+                    return None;
+                };
+                return match ffi_type(inner, callbacks)? {
+                    FfiType::Callback(name) => Some(FfiType::Callback(name)),
+                    _ => None,
+                };
+            }
+            if name == "c_void" {
+                return Some(FfiType::Void);
+            }
+            if let Some(primitive) = Primitive::from_rust(&name) {
+                return Some(FfiType::Primitive(primitive));
+            }
+            if callbacks.contains(&name) {
+                return Some(FfiType::Callback(name));
+            }
+            Some(FfiType::Struct(name))
+        }
+        _ => None,
+    }
+}
+
+/// ### English
+/// Builds a function signature.
+///
+/// #### Parameters
+/// - `name`: Symbol name.
+/// - `params`: Parameter names and Rust types.
+/// - `output`: Rust return type.
+/// - `doc`: English doc lines.
+/// - `callbacks`: Names of known callback aliases.
+///
+/// ### 中文
+/// 构建函数签名。
+///
+/// #### 参数
+/// - `name`：符号名。
+/// - `params`：参数名与 Rust 类型。
+/// - `output`：Rust 返回类型。
+/// - `doc`：英文文档行。
+/// - `callbacks`：已知回调别名的名称集合。
+fn signature<'a>(
+    name: String,
+    params: impl Iterator<Item = (String, &'a Type)>,
+    output: &ReturnType,
+    doc: Vec<String>,
+    callbacks: &HashSet<String>,
+) -> Result<FfiFunction, String> {
+    let params = params
+        .map(|(param, ty)| {
+            ffi_type(ty, callbacks)
+                .map(|ty| (param.clone(), ty))
+                .ok_or_else(|| format!("{name}: unsupported type of parameter `{param}`"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let ret = match output {
+        ReturnType::Default => FfiType::Void,
+        ReturnType::Type(_, ty) => {
+            ffi_type(ty, callbacks).ok_or_else(|| format!("{name}: unsupported return type"))?
+        }
+    };
+    Ok(FfiFunction {
+        name,
+        params,
+        ret,
+        doc,
+    })
+}
+
+/// ### English
+/// Builds the signature of a callback alias.
+///
+/// #### Parameters
+/// - `item`: `type X = unsafe extern "C" fn(...)` item.
+/// - `callbacks`: Names of known callback aliases.
+///
+/// ### 中文
+/// 构建回调别名的签名。
+///
+/// #### 参数
+/// - `item`：`type X = unsafe extern "C" fn(...)` 条目。
+/// - `callbacks`：已知回调别名的名称集合。
+fn callback_signature(
+    item: &syn::ItemType,
+    callbacks: &HashSet<String>,
+) -> Result<FfiFunction, String> {
+    let Type::BareFn(bare) = &*item.ty else {
+        return Err(format!("{}: not a function pointer", item.ident));
+    };
+    signature(
+        item.ident.to_string(),
+        bare.inputs.iter().map(|arg| {
+            let name = arg
+                .name
+                .as_ref()
+                .map_or_else(|| "_".to_string(), |(ident, _)| ident.to_string());
+            (name, &arg.ty)
+        }),
+        &bare.output,
+        doc_lines(&item.attrs),
+        callbacks,
+    )
+}
+
+/// ### English
+/// Resolves the structs reachable from callbacks and functions, dependencies first.
+///
+/// A struct whose fields are private or not FFI-representable is emitted as an opaque handle.
+///
+/// #### Parameters
+/// - `items`: All `#[repr(C)]` structs found in the sources.
+/// - `callbacks`: Callback signatures.
+/// - `functions`: Exported function signatures.
+/// - `callback_names`: Names of known callback aliases.
+///
+/// ### 中文
+/// 解析由回调与函数可达的结构体，依赖项在前。
+///
+/// 字段为私有或无法用 FFI 表示的结构体会作为不透明句柄输出。
+///
+/// #### 参数
+/// - `items`：源码中找到的全部 `#[repr(C)]` 结构体。
+/// - `callbacks`：回调签名。
+/// - `functions`：导出函数签名。
+/// - `callback_names`：已知回调别名的名称集合。
+fn resolve_structs(
+    items: &[syn::ItemStruct],
+    callbacks: &[FfiFunction],
+    functions: &[FfiFunction],
+    callback_names: &HashSet<String>,
+) -> Result<Vec<FfiStruct>, String> {
+    let by_name: HashMap<String, &syn::ItemStruct> = items
+        .iter()
+        .map(|item| (item.ident.to_string(), item))
+        .collect();
+
+    let mut resolved: HashMap<String, FfiStruct> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+    let mut visiting: HashSet<String> = HashSet::new();
+
+    for function in callbacks.iter().chain(functions) {
+        let types = function
+            .params
+            .iter()
+            .map(|(_, ty)| ty)
+            .chain(std::iter::once(&function.ret));
+        for ty in types {
+            if let Some(name) = struct_name(ty) {
+                resolve_struct(
+                    name,
+                    &function.name,
+                    &by_name,
+                    callback_names,
+                    &mut resolved,
+                    &mut order,
+                    &mut visiting,
+                )?;
+            }
+        }
+    }
+
+    Ok(order
+        .into_iter()
+        .filter_map(|name| resolved.remove(&name))
+        .collect())
+}
+
+/// ### English
+/// Returns the struct named by a type, looking through pointers and arrays.
+///
+/// #### Parameters
+/// - `ty`: Type to inspect.
+///
+/// ### 中文
+/// 返回类型所引用的结构体名（穿透指针与数组）。
+///
+/// #### 参数
+/// - `ty`：要检查的类型。
+fn struct_name(ty: &FfiType) -> Option<&str> {
+    match ty {
+        FfiType::Struct(name) => Some(name),
+        FfiType::Pointer { pointee, .. } => struct_name(pointee),
+        FfiType::Array(elem, _) => struct_name(elem),
+        _ => None,
+    }
+}
+
+/// ### English
+/// Resolves one struct (and, recursively, its by-value dependencies).
+///
+/// #### Parameters
+/// - `name`: Struct name.
+/// - `user`: Item referencing the struct (for error messages).
+/// - `by_name`: All `#[repr(C)]` structs by name.
+/// - `callback_names`: Names of known callback aliases.
+/// - `resolved`: Structs resolved so far.
+/// - `order`: Emission order.
+/// - `visiting`: Structs on the current resolution path (cycle detection).
+///
+/// ### 中文
+/// 解析一个结构体（并递归解析其按值依赖）。
+///
+/// #### 参数
+/// - `name`：结构体名。
+/// - `user`：引用该结构体的条目（用于错误信息）。
+/// - `by_name`：按名称索引的全部 `#[repr(C)]` 结构体。
+/// - `callback_names`：已知回调别名的名称集合。
+/// - `resolved`：已解析的结构体。
+/// - `order`：输出顺序。
+/// - `visiting`：当前解析路径上的结构体（用于检测环）。
+fn resolve_struct(
+    name: &str,
+    user: &str,
+    by_name: &HashMap<String, &syn::ItemStruct>,
+    callback_names: &HashSet<String>,
+    resolved: &mut HashMap<String, FfiStruct>,
+    order: &mut Vec<String>,
+    visiting: &mut HashSet<String>,
+) -> Result<(), String> {
+    if resolved.contains_key(name) || !visiting.insert(name.to_string()) {
+        return Ok(());
+    }
+    let Some(item) = by_name.get(name) else {
+        return Err(format!("{user}: `{name}` is not a #[repr(C)] struct"));
+    };
+
+    let mut fields = Some(Vec::new());
+    if let Fields::Named(named) = &item.fields {
+        for field in &named.named {
+            let public = matches!(field.vis, syn::Visibility::Public(_));
+            let ty = ffi_type(&field.ty, callback_names)
+                .filter(|ty| struct_name(ty).is_none_or(|inner| by_name.contains_key(inner)));
+            match (public, ty, fields.as_mut()) {
+                (true, Some(ty), Some(list)) => list.push(FfiField {
+                    name: field
+                        .ident
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    ty,
+                    doc: doc_lines(&field.attrs),
+                }),
+                _ => fields = None,
+            }
+        }
+    } else {
+        fields = None;
+    }
+
+    if let Some(list) = &fields {
+        for field in list {
+            if let Some(inner) = struct_name(&field.ty) {
+                resolve_struct(
+                    inner,
+                    name,
+                    by_name,
+                    callback_names,
+                    resolved,
+                    order,
+                    visiting,
+                )?;
+            }
+        }
+    }
+
+    visiting.remove(name);
+    order.push(name.to_string());
+    resolved.insert(
+        name.to_string(),
+        FfiStruct {
+            name: name.to_string(),
+            fields,
+            doc: doc_lines(&item.attrs),
+        },
+    );
+    Ok(())
+}
+
+/// ### English
+/// Evaluates constant expressions, resolving references between constants.
+///
+/// #### Parameters
+/// - `raw`: Constants with their unevaluated expressions.
+///
+/// ### 中文
+/// 计算常量表达式，并解析常量之间的引用。
+///
+/// #### 参数
+/// - `raw`：带有未计算表达式的常量。
+fn evaluate_consts(
+    raw: Vec<(String, Primitive, Expr, Vec<String>)>,
+) -> Result<Vec<FfiConst>, String> {
+    let mut values: HashMap<String, i128> = HashMap::new();
+    loop {
+        let before = values.len();
+        for (name, _, expr, _) in &raw {
+            if !values.contains_key(name)
+                && let Some(value) = eval(expr, &values)
+            {
+                values.insert(name.clone(), value);
+            }
+        }
+        if values.len() == before {
+            break;
+        }
+    }
+
+    raw.into_iter()
+        .map(|(name, ty, _, doc)| match values.get(&name) {
+            Some(&value) => Ok(FfiConst {
+                name,
+                ty,
+                value,
+                doc,
+            }),
+            None => Err(format!("{name}: unsupported constant expression")),
+        })
+        .collect()
+}
+
+/// ### English
+/// Evaluates an integer constant expression.
+///
+/// Returns `None` for unsupported expressions or references to constants not evaluated yet.
+///
+/// #### Parameters
+/// - `expr`: Expression to evaluate.
+/// - `values`: Constants evaluated so far.
+///
+/// ### 中文
+/// 计算整数常量表达式。
+///
+/// 对于不支持的表达式或引用了尚未计算的常量，返回 `None`。
+///
+/// #### 参数
+/// - `expr`：要计算的表达式。
+/// - `values`：已计算的常量。
+fn eval(expr: &Expr, values: &HashMap<String, i128>) -> Option<i128> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Paren(paren) => eval(&paren.expr, values),
+        Expr::Group(group) => eval(&group.expr, values),
+        Expr::Cast(cast) => eval(&cast.expr, values),
+        Expr::Unary(unary) => match unary.op {
+            UnOp::Neg(_) => eval(&unary.expr, values).map(|value| -value),
+            _ => None,
+        },
+        Expr::Binary(binary) => {
+            let lhs = eval(&binary.left, values)?;
+            let rhs = eval(&binary.right, values)?;
+            match binary.op {
+                BinOp::Add(_) => lhs.checked_add(rhs),
+                BinOp::Sub(_) => lhs.checked_sub(rhs),
+                BinOp::Mul(_) => lhs.checked_mul(rhs),
+                BinOp::Shl(_) => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shl(rhs)),
+                BinOp::Shr(_) => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shr(rhs)),
+                BinOp::BitOr(_) => Some(lhs | rhs),
+                BinOp::BitAnd(_) => Some(lhs & rhs),
+                _ => None,
+            }
+        }
+        Expr::Path(path) => path
+            .path
+            .segments
+            .last()
+            .and_then(|segment| values.get(&segment.ident.to_string()).copied()),
+        _ => None,
+    }
+}
@@ -0,0 +1,341 @@
+//! ### English
+//! Java Panama (`java.lang.foreign`) layout renderer.
+//!
+//! Struct layouts spell out C padding explicitly (64-bit targets), so `StructLayout` byte sizes
+//! match `size_of` on the Rust side.
+//!
+//! ### 中文
+//! Java Panama（`java.lang.foreign`）布局渲染器。
+//!
+//! 结构体布局会显式写出 C 填充（64 位目标），使 `StructLayout` 的字节大小与 Rust 侧的 `size_of` 一致。
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::model::{FfiFunction, FfiSurface, FfiType, Primitive};
+
+/// ### English
+/// Name of the generated Java class.
+///
+/// ### 中文
+/// 生成的 Java 类名。
+const CLASS_NAME: &str = "XianWebEngineLayouts";
+
+/// ### English
+/// Renders `XianWebEngineLayouts.java`.
+///
+/// #### Parameters
+/// - `surface`: Collected FFI surface.
+/// - `package`: Java package (`None` = default package).
+///
+/// ### 中文
+/// 渲染 `XianWebEngineLayouts.java`。
+///
+/// #### 参数
+/// - `surface`：收集到的 FFI 接口。
+/// - `package`：Java 包（`None` 表示默认包）。
+pub fn render(surface: &FfiSurface, package: Option<&str>) -> String {
+    let mut out = String::new();
+    out.push_str("/*\n * Generated by `cargo xtask bindings` from the Rust FFI surface. Do not edit.\n */\n\n");
+    if let Some(package) = package {
+        let _ = writeln!(out, "package {package};\n");
+    }
+    out.push_str("import java.lang.foreign.FunctionDescriptor;\n");
+    out.push_str("import java.lang.foreign.MemoryLayout;\n");
+    out.push_str("import java.lang.foreign.StructLayout;\n");
+    out.push_str("import java.lang.foreign.ValueLayout;\n\n");
+    out.push_str("/**\n * Panama layouts, function descriptors and constants of the Xian Web Engine C ABI.\n */\n");
+    let _ = writeln!(out, "public final class {CLASS_NAME} {{");
+    let _ = writeln!(out, "    private {CLASS_NAME}() {{}}");
+
+    for constant in &surface.consts {
+        out.push('\n');
+        doc_comment(&mut out, &constant.doc);
+        let (java_type, value) = java_constant(constant.ty, constant.value);
+        let _ = writeln!(
+            out,
+            "    public static final {java_type} {} = {value};",
+            constant.name
+        );
+    }
+
+    let mut sizes: HashMap<&str, (usize, usize)> = HashMap::new();
+    for item in &surface.structs {
+        let Some(fields) = &item.fields else {
+            continue;
+        };
+        let mut members = Vec::new();
+        let mut offset = 0;
+        let mut struct_align = 1;
+        for field in fields {
+            let (size, align) = size_align(&field.ty, &sizes);
+            if offset % align != 0 {
+                let padding = align - offset % align;
+                members.push(format!("MemoryLayout.paddingLayout({padding})"));
+                offset += padding;
+            }
+            members.push(format!(
+                "{}.withName(\"{}\")",
+                value_layout(&field.ty),
+                field.name
+            ));
+            offset += size;
+            struct_align = struct_align.max(align);
+        }
+        if offset % struct_align != 0 {
+            let padding = struct_align - offset % struct_align;
+            members.push(format!("MemoryLayout.paddingLayout({padding})"));
+            offset += padding;
+        }
+        sizes.insert(&item.name, (offset, struct_align));
+
+        out.push('\n');
+        doc_comment(&mut out, &item.doc);
+        let _ = writeln!(
+            out,
+            "    public static final StructLayout {} = MemoryLayout.structLayout(",
+            layout_name(&item.name)
+        );
+        let _ = writeln!(out, "            {}", members.join(",\n            "));
+        let _ = writeln!(out, "    ).withName(\"{}\");", item.name);
+    }
+
+    for function in surface.callbacks.iter().chain(&surface.functions) {
+        out.push('\n');
+        doc_comment(&mut out, &function.doc);
+        let _ = writeln!(
+            out,
+            "    public static final FunctionDescriptor {} = {};",
+            descriptor_name(&function.name),
+            descriptor(function)
+        );
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// ### English
+/// Writes a Javadoc comment (Markdown code spans become `{@code ...}`).
+///
+/// #### Parameters
+/// - `out`: Output buffer.
+/// - `doc`: Comment lines (nothing is written when empty).
+///
+/// ### 中文
+/// 写入 Javadoc 注释（Markdown 代码片段转换为 `{@code ...}`）。
+///
+/// #### 参数
+/// - `out`：输出缓冲区。
+/// - `doc`：注释行（为空时不写入）。
+fn doc_comment(out: &mut String, doc: &[String]) {
+    if doc.is_empty() {
+        return;
+    }
+    out.push_str("    /**\n");
+    for line in doc {
+        if line.is_empty() {
+            out.push_str("     *\n");
+        } else {
+            let _ = writeln!(out, "     * {}", javadoc_line(line));
+        }
+    }
+    out.push_str("     */\n");
+}
+
+/// ### English
+/// Converts one Markdown doc line to Javadoc.
+///
+/// #### Parameters
+/// - `line`: Markdown line.
+///
+/// ### 中文
+/// 将一行 Markdown 文档转换为 Javadoc。
+///
+/// #### 参数
+/// - `line`：Markdown 行。
+fn javadoc_line(line: &str) -> String {
+    let mut result = String::new();
+    for (index, part) in line.split('`').enumerate() {
+        if index % 2 == 1 {
+            let _ = write!(result, "{{@code {part}}}");
+        } else {
+            result.push_str(
+                &part
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;"),
+            );
+        }
+    }
+    result.replace("*/", "*&#47;")
+}
+
+/// ### English
+/// Returns the Java type and literal of an integer constant.
+///
+/// Unsigned values above the signed range are written as hex bit patterns.
+///
+/// #### Parameters
+/// - `ty`: Rust type of the constant.
+/// - `value`: Constant value.
+///
+/// ### 中文
+/// 返回整数常量的 Java 类型与字面量。
+///
+/// 超出有符号范围的无符号值以十六进制位模式写出。
+///
+/// #### 参数
+/// - `ty`：常量的 Rust 类型。
+/// - `value`：常量值。
+fn java_constant(ty: Primitive, value: i128) -> (&'static str, String) {
+    match ty.size() {
+        1 => ("byte", format!("(byte) {value}")),
+        2 => ("short", format!("(short) {value}")),
+        4 if value > i128::from(i32::MAX) => ("int", format!("0x{value:X}")),
+        4 => ("int", value.to_string()),
+        _ if value > i128::from(i64::MAX) => ("long", format!("0x{value:X}L")),
+        _ => ("long", format!("{value}L")),
+    }
+}
+
+/// ### English
+/// Returns `(size, alignment)` of a type under C rules on 64-bit targets.
+///
+/// #### Parameters
+/// - `ty`: FFI type.
+/// - `structs`: Sizes of the structs laid out so far.
+///
+/// ### 中文
+/// 返回 64 位目标上按 C 规则计算的类型 `(大小, 对齐)`。
+///
+/// #### 参数
+/// - `ty`：FFI 类型。
+/// - `structs`：已完成布局的结构体大小。
+fn size_align(ty: &FfiType, structs: &HashMap<&str, (usize, usize)>) -> (usize, usize) {
+    match ty {
+        FfiType::Void => (0, 1),
+        FfiType::Primitive(primitive) => (primitive.size(), primitive.size()),
+        FfiType::Pointer { .. } | FfiType::Callback(_) => (8, 8),
+        FfiType::Struct(name) => structs.get(name.as_str()).copied().unwrap_or((8, 8)),
+        FfiType::Array(elem, len) => {
+            let (size, align) = size_align(elem, structs);
+            (size * len, align)
+        }
+    }
+}
+
+/// ### English
+/// Returns the Java expression of a field/parameter layout.
+///
+/// #### Parameters
+/// - `ty`: FFI type.
+///
+/// ### 中文
+/// 返回字段/参数布局的 Java 表达式。
+///
+/// #### 参数
+/// - `ty`：FFI 类型。
+fn value_layout(ty: &FfiType) -> String {
+    match ty {
+        FfiType::Void => "ValueLayout.ADDRESS".to_string(),
+        FfiType::Primitive(primitive) => match primitive {
+            Primitive::Bool => "ValueLayout.JAVA_BOOLEAN",
+            Primitive::Char | Primitive::U8 | Primitive::I8 => "ValueLayout.JAVA_BYTE",
+            Primitive::U16 | Primitive::I16 => "ValueLayout.JAVA_SHORT",
+            Primitive::U32 | Primitive::I32 => "ValueLayout.JAVA_INT",
+            Primitive::U64 | Primitive::I64 | Primitive::Usize | Primitive::Isize => {
+                "ValueLayout.JAVA_LONG"
+            }
+            Primitive::F32 => "ValueLayout.JAVA_FLOAT",
+            Primitive::F64 => "ValueLayout.JAVA_DOUBLE",
+        }
+        .to_string(),
+        FfiType::Pointer { .. } | FfiType::Callback(_) => "ValueLayout.ADDRESS".to_string(),
+        FfiType::Struct(name) => layout_name(name),
+        FfiType::Array(elem, len) => {
+            format!("MemoryLayout.sequenceLayout({len}, {})", value_layout(elem))
+        }
+    }
+}
+
+/// ### English
+/// Returns the `FunctionDescriptor` expression of a signature.
+///
+/// #### Parameters
+/// - `function`: Function or callback signature.
+///
+/// ### 中文
+/// 返回签名的 `FunctionDescriptor` 表达式。
+///
+/// #### 参数
+/// - `function`：函数或回调签名。
+fn descriptor(function: &FfiFunction) -> String {
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|(_, ty)| value_layout(ty))
+        .collect();
+    match function.ret {
+        FfiType::Void => format!("FunctionDescriptor.ofVoid({})", params.join(", ")),
+        ref ret => {
+            let mut args = vec![value_layout(ret)];
+            args.extend(params);
+            format!("FunctionDescriptor.of({})", args.join(", "))
+        }
+    }
+}
+
+/// ### English
+/// Converts a `CamelCase` or `snake_case` name to `UPPER_SNAKE_CASE`.
+///
+/// #### Parameters
+/// - `name`: Source name.
+///
+/// ### 中文
+/// 将 `CamelCase` 或 `snake_case` 名称转换为 `UPPER_SNAKE_CASE`。
+///
+/// #### 参数
+/// - `name`：源名称。
+fn upper_snake(name: &str) -> String {
+    let mut result = String::new();
+    let mut previous_lower = false;
+    for ch in name.chars() {
+        if ch.is_ascii_uppercase() && previous_lower {
+            result.push('_');
+        }
+        previous_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        result.push(ch.to_ascii_uppercase());
+    }
+    result
+}
+
+/// ### English
+/// Returns the Java field name of a struct layout.
+///
+/// #### Parameters
+/// - `name`: Struct name.
+///
+/// ### 中文
+/// 返回结构体布局的 Java 字段名。
+///
+/// #### 参数
+/// - `name`：结构体名。
+fn layout_name(name: &str) -> String {
+    format!("{}_LAYOUT", upper_snake(name))
+}
+
+/// ### English
+/// Returns the Java field name of a function descriptor.
+///
+/// #### Parameters
+/// - `name`: Function or callback name.
+///
+/// ### 中文
+/// 返回函数描述符的 Java 字段名。
+///
+/// #### 参数
+/// - `name`：函数或回调名。
+fn descriptor_name(name: &str) -> String {
+    format!("{}_DESC", upper_snake(name))
+}
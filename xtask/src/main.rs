@@ -0,0 +1,100 @@
+//! ### English
+//! Repository automation tasks (`cargo xtask <task>`).
+//!
+//! Tasks:
+//! - `bindings [--out <dir>] [--java-package <name>] [--check]`: generates the C header and the Java
+//!   Panama layout file from the crate's FFI surface. `--check` fails instead of writing when the
+//!   files on disk are out of date.
+//!
+//! ### 中文
+//! 仓库自动化任务（`cargo xtask <task>`）。
+//!
+//! 任务：
+//! - `bindings [--out <dir>] [--java-package <name>] [--check]`：根据 crate 的 FFI 接口生成 C 头文件与
+//!   Java Panama 布局文件。`--check` 在磁盘上的文件过期时报错而不是写入。
+
+mod bindings;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// ### English
+/// Prints usage to stderr.
+///
+/// ### 中文
+/// 向 stderr 输出用法。
+fn usage() {
+    eprintln!("usage: cargo xtask bindings [--out <dir>] [--java-package <name>] [--check]");
+}
+
+/// ### English
+/// Returns the repository root (parent of the `xtask` crate).
+///
+/// ### 中文
+/// 返回仓库根目录（`xtask` crate 的上级目录）。
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// ### English
+/// Entry point: dispatches the requested task.
+///
+/// ### 中文
+/// 入口：分发请求的任务。
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(task) = args.next() else {
+        usage();
+        return ExitCode::FAILURE;
+    };
+
+    match task.as_str() {
+        "bindings" => {
+            let root = repo_root();
+            let mut options = bindings::Options {
+                src_dir: root.join("src"),
+                out_dir: root.join("bindings"),
+                java_package: None,
+                check: false,
+            };
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--out" => match args.next() {
+                        Some(dir) => options.out_dir = PathBuf::from(dir),
+                        None => {
+                            usage();
+                            return ExitCode::FAILURE;
+                        }
+                    },
+                    "--java-package" => match args.next() {
+                        Some(name) => options.java_package = Some(name),
+                        None => {
+                            usage();
+                            return ExitCode::FAILURE;
+                        }
+                    },
+                    "--check" => options.check = true,
+                    _ => {
+                        usage();
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            match bindings::run(&options) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        _ => {
+            usage();
+            ExitCode::FAILURE
+        }
+    }
+}